@@ -263,7 +263,11 @@ export async function graphPopulateAllCommand(
     const ingestionService = new GraphIngestionService(
       graphAdapter,
       entityExtractor,
-      relationshipExtractor
+      relationshipExtractor,
+      {
+        normalizeSignatures: Bun.env["GRAPH_NORMALIZE_SIGNATURES"] !== "false",
+        captureCallArguments: Bun.env["GRAPH_CAPTURE_CALL_ARGUMENTS"] === "true",
+      }
     );
 
     // Step 5: Process each repository sequentially
@@ -201,7 +201,11 @@ export async function graphPopulateCommand(
     const ingestionService = new GraphIngestionService(
       graphAdapter,
       entityExtractor,
-      relationshipExtractor
+      relationshipExtractor,
+      {
+        normalizeSignatures: Bun.env["GRAPH_NORMALIZE_SIGNATURES"] !== "false",
+        captureCallArguments: Bun.env["GRAPH_CAPTURE_CALL_ARGUMENTS"] === "true",
+      }
     );
 
     // Step 5: Progress callback
@@ -8,6 +8,7 @@
 
 import chalk from "chalk";
 import type { CliDependencies } from "../utils/dependency-init.js";
+import { IndexingCancelledError } from "../../services/ingestion-errors.js";
 import {
   createIndexSpinner,
   updateIndexSpinner,
@@ -34,6 +35,14 @@ export interface IndexCommandOptions {
   watch?: boolean;
   /** Whether the local-folder watcher should follow symlinks. Default false. */
   followSymlinks?: boolean;
+  /** Whether to resume an interrupted build (`--no-resume` sets false). Default true. */
+  resume?: boolean;
+  /** Glob patterns to exclude in addition to the scanner defaults. */
+  exclude?: string[];
+  /** Whether to skip files ignored by `.gitignore` (`--no-gitignore` sets false). Default true. */
+  gitignore?: boolean;
+  /** Number of file batches between checkpoint saves. */
+  checkpointInterval?: number;
 }
 
 import { resolve, normalize, basename } from "node:path";
@@ -178,6 +187,12 @@ export async function indexCommand(
   // Create spinner
   const spinner = createIndexSpinner(repositoryName);
 
+  // Ctrl-C cancels at the next safe point so the ingestion service can save
+  // a checkpoint; rerunning the same command then resumes from it.
+  const controller = new AbortController();
+  const onInterrupt = (): void => controller.abort();
+  process.once("SIGINT", onInterrupt);
+
   try {
     // Resolve watch default by source: local-folder → true unless --no-watch
     // explicitly disables it; everything else → false.
@@ -191,6 +206,11 @@ export async function indexCommand(
       tier: options.tier,
       watch: effectiveWatch,
       followSymlinks: options.followSymlinks ?? false,
+      excludePatterns: options.exclude,
+      respectGitignore: options.gitignore,
+      resume: options.resume,
+      checkpointInterval: options.checkpointInterval,
+      signal: controller.signal,
       onProgress: (progress) => {
         updateIndexSpinner(spinner, progress);
       },
@@ -232,6 +252,17 @@ export async function indexCommand(
         error instanceof Error ? error.message : String(error)
       );
     }
+    if (error instanceof IndexingCancelledError) {
+      console.log(
+        chalk.yellow("\nIndexing interrupted. Progress has been saved; rerun to resume: ") +
+          chalk.gray(
+            `pk-mcp index ${url}${options.name ? ` --name ${options.name}` : ""}` +
+              (options.force ? " --force" : "")
+          )
+      );
+    }
     throw error;
+  } finally {
+    process.removeListener("SIGINT", onInterrupt);
   }
 }
@@ -185,6 +185,7 @@ export async function updateRepositoryCommand(
         branch: repo.branch,
         force: true,
         tier: repo.tier,
+        respectGitignore: repo.respectGitignore,
         onProgress: (progress) => {
           spinner.text = `Force re-indexing ${chalk.cyan(repositoryName)} - ${progress.phase}...`;
         },
@@ -147,6 +147,16 @@ program
     "--follow-symlinks",
     "Follow symlinks inside the local folder. Out-of-folder targets are rejected even when set (default: skip)"
   )
+  .option("--no-resume", "Start over instead of resuming an interrupted build of this repository")
+  .option(
+    "--exclude <globs...>",
+    "Glob patterns to exclude in addition to the defaults and .gitignore (e.g. 'docs/**')"
+  )
+  .option("--no-gitignore", "Index files ignored by .gitignore, relying on --exclude globs alone")
+  .option(
+    "--checkpoint-interval <batches>",
+    "Save resume progress every N batches of 50 files (1-1000, default: 5)"
+  )
   .action(async (url: string, options: Record<string, unknown>) => {
     try {
       const validatedOptions = IndexCommandOptionsSchema.parse(options);
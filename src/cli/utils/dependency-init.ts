@@ -36,6 +36,8 @@ import { initializeLogger, getComponentLogger, type LogLevel } from "../../loggi
 import { createGraphAdapter } from "../../graph/adapters/index.js";
 import { getDefaultAdapterType, getAdapterConfig, getAdapterDisplayName } from "./graph-config.js";
 import { GraphIngestionService } from "../../graph/ingestion/GraphIngestionService.js";
+import { parseFileGuards, parseTodoTags } from "../../graph/extraction/types.js";
+import { parseCargoWorkspace } from "../../graph/ingestion/crate-roots.js";
 import { EntityExtractor } from "../../graph/extraction/EntityExtractor.js";
 import { RelationshipExtractor } from "../../graph/extraction/RelationshipExtractor.js";
 import { DocumentChunker } from "../../documents/DocumentChunker.js";
@@ -43,6 +45,7 @@ import { DocumentTypeDetector } from "../../documents/DocumentTypeDetector.js";
 import { WatchedFolderStoreImpl } from "../../services/watched-folder-store.js";
 import type { WatchedFolderStoreService } from "../../services/watched-folder-store.js";
 import { FileManifestStoreImpl } from "../../services/file-manifest-store.js";
+import { IndexCheckpointStoreImpl } from "../../services/index-checkpoint-store.js";
 import { pruneOrphanManifests } from "../../services/orphan-manifest-reaper.js";
 
 /**
@@ -376,12 +379,21 @@ export async function initializeDependencies(
           );
 
           // Create graph ingestion service for incremental updates
-          const entityExtractor = new EntityExtractor();
-          const relationshipExtractor = new RelationshipExtractor();
+          const fileGuards = parseFileGuards(Bun.env["GRAPH_FILE_GUARDS"]);
+          const entityExtractor = new EntityExtractor({
+            todoTags: parseTodoTags(Bun.env["GRAPH_TODO_TAGS"]),
+            indexStringLiterals: Bun.env["GRAPH_INDEX_STRING_LITERALS"] === "true",
+            ...fileGuards,
+          });
+          const relationshipExtractor = new RelationshipExtractor(fileGuards);
           graphIngestionService = new GraphIngestionService(
             graphAdapter,
             entityExtractor,
-            relationshipExtractor
+            relationshipExtractor,
+            {
+              normalizeSignatures: Bun.env["GRAPH_NORMALIZE_SIGNATURES"] !== "false",
+              captureCallArguments: Bun.env["GRAPH_CAPTURE_CALL_ARGUMENTS"] === "true",
+            }
           );
           logger.debug("Graph ingestion service initialized");
         } else {
@@ -439,7 +451,14 @@ export async function initializeDependencies(
       embeddingProvider,
       chromaClient,
       repositoryService,
-      { documentChunker, documentTypeDetector, graphIngestionService }
+      {
+        documentChunker,
+        documentTypeDetector,
+        graphIngestionService,
+        // Lets `cli index` resume an interrupted build instead of starting over
+        checkpointStore: IndexCheckpointStoreImpl.getInstance(config.data.path),
+        cargoWorkspace: parseCargoWorkspace(Bun.env["GRAPH_CARGO_WORKSPACE"]),
+      }
     );
     logger.debug(
       { graphEnabled: !!graphIngestionService },
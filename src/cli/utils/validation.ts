@@ -42,6 +42,26 @@ export const IndexCommandOptionsSchema = z.object({
   // The handler treats `undefined` as "default by source" — true for local-folder.
   watch: z.boolean().optional(),
   followSymlinks: z.boolean().optional(),
+  // Commander surfaces `--no-resume` as `resume: false`; otherwise `resume: true`.
+  resume: z.boolean().optional(),
+  exclude: z.array(z.string().trim().min(1, "Exclude pattern cannot be empty")).optional(),
+  // Commander surfaces `--no-gitignore` as `gitignore: false`; otherwise `gitignore: true`.
+  gitignore: z.boolean().optional(),
+  checkpointInterval: z
+    .string()
+    .optional()
+    .transform((val) => (val ? parseInt(val, 10) : undefined))
+    .pipe(
+      z
+        .number()
+        .int()
+        .min(1)
+        .max(1000)
+        .refine((n) => !isNaN(n), {
+          message: "checkpoint-interval must be a valid number between 1-1000",
+        })
+        .optional()
+    ),
 });
 
 /**
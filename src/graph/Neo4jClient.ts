@@ -37,6 +37,7 @@ import type {
   GraphTraverseResult,
   GraphDependenciesInput,
   GraphDependenciesResult,
+  DependencyDirection,
   GraphContextInput,
   GraphContextResult,
   DependencyInfo,
@@ -1146,7 +1147,7 @@ export class Neo4jStorageClientImpl implements GraphStorageAdapter {
     const startTime = Date.now();
     const session = this.createSession();
 
-    const { target, direction, transitive = false, maxDepth = 3 } = input;
+    const { target, direction, transitive = false, maxDepth = 3, dedup = true } = input;
     const depthLimit = Math.min(maxDepth, 5);
 
     try {
@@ -1187,7 +1188,8 @@ export class Neo4jStorageClientImpl implements GraphStorageAdapter {
               MATCH ${targetMatch}
               OPTIONAL MATCH path = (target)-[:IMPORTS|CALLS|REFERENCES*2..${depthLimit}]->(dep)
               WHERE dep <> target
-              RETURN dep, 'TRANSITIVE' as relType, length(path) as depth
+              RETURN dep, 'TRANSITIVE' as relType, length(path) as depth,
+                     [n IN nodes(path) | coalesce(n.path, n.name, n.id)] as via
             `;
           }
           break;
@@ -1202,7 +1204,8 @@ export class Neo4jStorageClientImpl implements GraphStorageAdapter {
               MATCH ${targetMatch}
               OPTIONAL MATCH path = (dep)-[:IMPORTS|CALLS|REFERENCES*2..${depthLimit}]->(target)
               WHERE dep <> target
-              RETURN dep, 'TRANSITIVE' as relType, length(path) as depth
+              RETURN dep, 'TRANSITIVE' as relType, length(path) as depth,
+                     [n IN nodes(path) | coalesce(n.path, n.name, n.id)] as via
             `;
           }
           break;
@@ -1234,7 +1237,7 @@ export class Neo4jStorageClientImpl implements GraphStorageAdapter {
           const converted = this.convertNode(depNode);
           const depId = converted["id"] as string;
 
-          if (!seenDeps.has(depId)) {
+          if (!seenDeps.has(depId) || !dedup) {
             seenDeps.add(depId);
             directDeps.push({
               type: this.getNodeType(converted["labels"] as string[]),
@@ -1242,10 +1245,13 @@ export class Neo4jStorageClientImpl implements GraphStorageAdapter {
               repository: (converted["repository"] as string) ?? target.repository,
               relationshipType: record.get("relType") as RelationshipType,
               depth: 1,
+              id: depId,
+              via: [],
             });
           }
         }
       }
+      const directCount = seenDeps.size;
 
       // Execute transitive dependencies query if requested
       let transitiveDeps: DependencyInfo[] | undefined;
@@ -1263,7 +1269,7 @@ export class Neo4jStorageClientImpl implements GraphStorageAdapter {
             const converted = this.convertNode(depNode);
             const depId = converted["id"] as string;
 
-            if (!seenDeps.has(depId)) {
+            if (!seenDeps.has(depId) || !dedup) {
               seenDeps.add(depId);
               transitiveDeps.push({
                 type: this.getNodeType(converted["labels"] as string[]),
@@ -1271,14 +1277,16 @@ export class Neo4jStorageClientImpl implements GraphStorageAdapter {
                 repository: (converted["repository"] as string) ?? target.repository,
                 relationshipType: "REFERENCES" as RelationshipType, // Transitive
                 depth: this.toNumber(record.get("depth")) as number,
+                id: depId,
+                via: this.pathIntermediates(record.get("via"), direction),
               });
             }
           }
         }
       }
 
-      // Calculate impact score
-      const totalDeps = directDeps.length + (transitiveDeps?.length ?? 0);
+      // Calculate impact score from distinct entities
+      const totalDeps = seenDeps.size;
       const impactScore = Math.min(
         1,
         totalDeps / Neo4jStorageClientImpl.IMPACT_SCORE_NORMALIZATION
@@ -1290,8 +1298,8 @@ export class Neo4jStorageClientImpl implements GraphStorageAdapter {
         transitive: transitiveDeps,
         impactScore,
         metadata: {
-          directCount: directDeps.length,
-          transitiveCount: transitiveDeps?.length ?? 0,
+          directCount,
+          transitiveCount: seenDeps.size - directCount,
           queryTimeMs: durationMs,
         },
       };
@@ -1300,8 +1308,8 @@ export class Neo4jStorageClientImpl implements GraphStorageAdapter {
         {
           metric: "neo4j.analyze_dependencies_ms",
           value: durationMs,
-          directCount: directDeps.length,
-          transitiveCount: transitiveDeps?.length ?? 0,
+          directCount,
+          transitiveCount: seenDeps.size - directCount,
         },
         "Dependency analysis complete"
       );
@@ -1576,4 +1584,13 @@ export class Neo4jStorageClientImpl implements GraphStorageAdapter {
       ""
     );
   }
+
+  /**
+   * Identifiers of the nodes strictly inside a transitive dependency path,
+   * nearest the target first (dependent paths run towards the target)
+   */
+  private pathIntermediates(via: unknown, direction: DependencyDirection): string[] {
+    const identifiers = Array.isArray(via) ? via.slice(1, -1).map(String) : [];
+    return direction === "dependedOnBy" ? identifiers.reverse() : identifiers;
+  }
 }
@@ -36,6 +36,7 @@ import type {
   GraphTraverseResult,
   GraphDependenciesInput,
   GraphDependenciesResult,
+  DependencyDirection,
   GraphContextInput,
   GraphContextResult,
   DependencyInfo,
@@ -1106,7 +1107,7 @@ export class FalkorDBAdapter implements GraphStorageAdapter {
 
     const startTime = Date.now();
 
-    const { target, direction, transitive = false, maxDepth = 3 } = input;
+    const { target, direction, transitive = false, maxDepth = 3, dedup = true } = input;
     const depthLimit = Math.min(maxDepth, 5);
 
     try {
@@ -1142,7 +1143,8 @@ export class FalkorDBAdapter implements GraphStorageAdapter {
               MATCH ${targetMatch}
               OPTIONAL MATCH path = (target)-[:IMPORTS|CALLS|REFERENCES*2..${depthLimit}]->(dep)
               WHERE dep <> target
-              RETURN dep, 'TRANSITIVE' as relType, length(path) as depth
+              RETURN dep, 'TRANSITIVE' as relType, length(path) as depth,
+                     [n IN nodes(path) | coalesce(n.path, n.name, n.id)] as via
             `;
           }
           break;
@@ -1157,7 +1159,8 @@ export class FalkorDBAdapter implements GraphStorageAdapter {
               MATCH ${targetMatch}
               OPTIONAL MATCH path = (dep)-[:IMPORTS|CALLS|REFERENCES*2..${depthLimit}]->(target)
               WHERE dep <> target
-              RETURN dep, 'TRANSITIVE' as relType, length(path) as depth
+              RETURN dep, 'TRANSITIVE' as relType, length(path) as depth,
+                     [n IN nodes(path) | coalesce(n.path, n.name, n.id)] as via
             `;
           }
           break;
@@ -1192,7 +1195,7 @@ export class FalkorDBAdapter implements GraphStorageAdapter {
         if (record.dep) {
           const depId = String(record.dep["id"]);
 
-          if (!seenDeps.has(depId)) {
+          if (!seenDeps.has(depId) || !dedup) {
             seenDeps.add(depId);
             directDeps.push({
               type: this.getNodeType((record.dep["labels"] as string[] | undefined) ?? []),
@@ -1200,10 +1203,13 @@ export class FalkorDBAdapter implements GraphStorageAdapter {
               repository: (record.dep["repository"] as string | undefined) ?? target.repository,
               relationshipType: record.relType as RelationshipType,
               depth: 1,
+              id: depId,
+              via: [],
             });
           }
         }
       }
+      const directCount = seenDeps.size;
 
       // Execute transitive dependencies query if requested
       let transitiveDeps: DependencyInfo[] | undefined;
@@ -1217,6 +1223,7 @@ export class FalkorDBAdapter implements GraphStorageAdapter {
           dep: Record<string, unknown> | null;
           relType: string;
           depth: number;
+          via: unknown;
         }>(transitiveResult);
 
         transitiveDeps = [];
@@ -1224,7 +1231,7 @@ export class FalkorDBAdapter implements GraphStorageAdapter {
           if (record.dep) {
             const depId = String(record.dep["id"]);
 
-            if (!seenDeps.has(depId)) {
+            if (!seenDeps.has(depId) || !dedup) {
               seenDeps.add(depId);
               transitiveDeps.push({
                 type: this.getNodeType((record.dep["labels"] as string[] | undefined) ?? []),
@@ -1232,14 +1239,16 @@ export class FalkorDBAdapter implements GraphStorageAdapter {
                 repository: (record.dep["repository"] as string | undefined) ?? target.repository,
                 relationshipType: "REFERENCES" as RelationshipType, // Transitive
                 depth: record.depth,
+                id: depId,
+                via: this.pathIntermediates(record.via, direction),
               });
             }
           }
         }
       }
 
-      // Calculate impact score
-      const totalDeps = directDeps.length + (transitiveDeps?.length ?? 0);
+      // Calculate impact score from distinct entities
+      const totalDeps = seenDeps.size;
       const impactScore = Math.min(1, totalDeps / FalkorDBAdapter.IMPACT_SCORE_NORMALIZATION);
 
       const durationMs = Date.now() - startTime;
@@ -1248,8 +1257,8 @@ export class FalkorDBAdapter implements GraphStorageAdapter {
         transitive: transitiveDeps,
         impactScore,
         metadata: {
-          directCount: directDeps.length,
-          transitiveCount: transitiveDeps?.length ?? 0,
+          directCount,
+          transitiveCount: seenDeps.size - directCount,
           queryTimeMs: durationMs,
         },
       };
@@ -1258,8 +1267,8 @@ export class FalkorDBAdapter implements GraphStorageAdapter {
         {
           metric: "falkordb.analyze_dependencies_ms",
           value: durationMs,
-          directCount: directDeps.length,
-          transitiveCount: transitiveDeps?.length ?? 0,
+          directCount,
+          transitiveCount: seenDeps.size - directCount,
         },
         "Dependency analysis complete"
       );
@@ -1528,6 +1537,15 @@ export class FalkorDBAdapter implements GraphStorageAdapter {
       ""
     );
   }
+
+  /**
+   * Identifiers of the nodes strictly inside a transitive dependency path,
+   * nearest the target first (dependent paths run towards the target)
+   */
+  private pathIntermediates(via: unknown, direction: DependencyDirection): string[] {
+    const identifiers = Array.isArray(via) ? via.slice(1, -1).map(String) : [];
+    return direction === "dependedOnBy" ? identifiers.reverse() : identifiers;
+  }
 }
 
 // =============================================================================
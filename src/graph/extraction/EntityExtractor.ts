@@ -40,6 +40,7 @@ import type pino from "pino";
 import { getComponentLogger } from "../../logging/index.js";
 import { CodeParser } from "../parsing/CodeParser.js";
 import type { CodeEntity, SupportedLanguage } from "../parsing/types.js";
+import { getSymbolKind } from "../parsing/symbol-kinds.js";
 import type {
   EntityExtractorConfig,
   ExtractOptions,
@@ -75,7 +76,11 @@ export class EntityExtractor {
       extractDocumentation: this.config.extractDocumentation,
       includeAnonymous: this.config.includeAnonymous,
       maxFileSizeBytes: this.config.maxFileSizeBytes,
+      maxLines: this.config.maxLines,
+      languageGuards: this.config.languageGuards,
       parseTimeoutMs: this.config.parseTimeoutMs,
+      todoTags: this.config.todoTags,
+      indexStringLiterals: this.config.indexStringLiterals,
     });
   }
 
@@ -135,6 +140,11 @@ export class EntityExtractor {
       entities,
       filePath: parseResult.filePath,
       language: parseResult.language,
+      ...(parseResult.todos && { todos: parseResult.todos }),
+      ...(parseResult.strings && { strings: parseResult.strings }),
+      ...(parseResult.moduleDoc && { moduleDoc: parseResult.moduleDoc }),
+      ...(parseResult.implementations && { implementations: parseResult.implementations }),
+      calls: parseResult.calls,
       parseTimeMs: parseResult.parseTimeMs,
       errors: parseResult.errors,
       success: parseResult.success,
@@ -342,6 +352,12 @@ export class EntityExtractor {
       filtered = filtered.filter((entity) => typeSet.has(entity.type));
     }
 
+    // Filter by symbol kinds
+    if (options.kinds && options.kinds.length > 0) {
+      const kindSet = new Set(options.kinds);
+      filtered = filtered.filter((entity) => kindSet.has(getSymbolKind(entity)));
+    }
+
     // Filter by export status
     if (options.exportedOnly) {
       filtered = filtered.filter((entity) => entity.isExported);
@@ -81,6 +81,8 @@ export class RelationshipExtractor {
       extractDocumentation: false, // Not needed for relationships
       includeAnonymous: false,
       maxFileSizeBytes: this.config.maxFileSizeBytes,
+      maxLines: this.config.maxLines,
+      languageGuards: this.config.languageGuards,
       parseTimeoutMs: this.config.parseTimeoutMs,
     });
   }
@@ -36,7 +36,9 @@ export type {
   BatchExtractionSummary,
 } from "./types.js";
 
-export { DEFAULT_EXTRACTOR_CONFIG } from "./types.js";
+export type { FileGuardSettings } from "./types.js";
+
+export { DEFAULT_EXTRACTOR_CONFIG, parseTodoTags, parseFileGuards } from "./types.js";
 
 // =============================================================================
 // Relationship Extraction
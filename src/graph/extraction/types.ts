@@ -7,6 +7,9 @@
  * @module graph/extraction/types
  */
 
+import type { FileGuards, SupportedLanguage } from "../parsing/types.js";
+import { EXTENSION_TO_LANGUAGE } from "../parsing/types.js";
+
 // Re-export core types from parsing module
 export type {
   SupportedLanguage,
@@ -17,7 +20,11 @@ export type {
   ParseError,
   ImportInfo,
   ExportInfo,
+  TodoComment,
+  StringLiteral,
+  FileGuards,
 } from "../parsing/types.js";
+export type { SymbolKind } from "../parsing/symbol-kinds.js";
 
 /**
  * Configuration options for the EntityExtractor.
@@ -44,11 +51,37 @@ export interface EntityExtractorConfig {
    */
   maxFileSizeBytes?: number;
 
+  /**
+   * Maximum number of lines to process; 0 disables the guard.
+   * Files with more lines will throw FileTooLongError.
+   * @default 0
+   */
+  maxLines?: number;
+
+  /**
+   * Per-language overrides of `maxFileSizeBytes` and `maxLines`.
+   * @default {}
+   */
+  languageGuards?: Partial<Record<SupportedLanguage, FileGuards>>;
+
   /**
    * Timeout for parsing a single file in milliseconds.
    * @default 5000
    */
   parseTimeoutMs?: number;
+
+  /**
+   * Comment marker tags extracted as TODO items. Empty disables extraction.
+   * @default ["TODO", "FIXME"]
+   */
+  todoTags?: string[];
+
+  /**
+   * Whether to extract string literals for full-text search. Opt-in because
+   * it considerably increases index size.
+   * @default false
+   */
+  indexStringLiterals?: boolean;
 }
 
 /**
@@ -58,9 +91,105 @@ export const DEFAULT_EXTRACTOR_CONFIG: Required<EntityExtractorConfig> = {
   extractDocumentation: true,
   includeAnonymous: false,
   maxFileSizeBytes: 1048576, // 1MB
+  maxLines: 0,
+  languageGuards: {},
   parseTimeoutMs: 5000,
+  todoTags: ["TODO", "FIXME"],
+  indexStringLiterals: false,
 };
 
+/**
+ * Parse a comma-separated TODO tag list (e.g. the `GRAPH_TODO_TAGS` env var).
+ *
+ * @param value - Raw list such as "TODO,FIXME,HACK"
+ * @returns Trimmed non-empty tags, or the default tags when unset
+ */
+export function parseTodoTags(value: string | undefined): string[] {
+  if (value === undefined) {
+    return [...DEFAULT_EXTRACTOR_CONFIG.todoTags];
+  }
+  return value
+    .split(",")
+    .map((tag) => tag.trim())
+    .filter((tag) => tag.length > 0);
+}
+
+/**
+ * File skip guards shared by the entity and relationship extractors.
+ */
+export type FileGuardSettings = Pick<
+  EntityExtractorConfig,
+  "maxFileSizeBytes" | "maxLines" | "languageGuards"
+>;
+
+/**
+ * Parse file skip guards from JSON (e.g. the `GRAPH_FILE_GUARDS` env var).
+ *
+ * The object maps language names to `{ maxFileSizeBytes, maxLines }`; the
+ * `default` key sets the limits for languages without an entry, e.g.
+ * `{"default":{"maxLines":20000},"rust":{"maxFileSizeBytes":4194304}}`.
+ *
+ * @param value - Raw JSON, or undefined to keep the built-in limits
+ * @returns Settings to spread into an extractor configuration
+ * @throws {Error} If the JSON is malformed, names an unknown language, or a
+ *   limit is not a non-negative integer
+ */
+export function parseFileGuards(value: string | undefined): FileGuardSettings {
+  if (value === undefined || value.trim() === "") {
+    return {};
+  }
+
+  let parsed: unknown;
+  try {
+    parsed = JSON.parse(value);
+  } catch (error) {
+    const message = error instanceof Error ? error.message : String(error);
+    throw new Error(`Invalid file guards JSON: ${message}`);
+  }
+  if (typeof parsed !== "object" || parsed === null || Array.isArray(parsed)) {
+    throw new Error("File guards must be a JSON object keyed by language");
+  }
+
+  const languages = new Set<string>(Object.values(EXTENSION_TO_LANGUAGE));
+  const settings: FileGuardSettings = {};
+  const languageGuards: Partial<Record<SupportedLanguage, FileGuards>> = {};
+
+  for (const [key, raw] of Object.entries(parsed)) {
+    if (key !== "default" && !languages.has(key)) {
+      throw new Error(`Unknown language in file guards: ${key}`);
+    }
+    const guards = parseGuardLimits(key, raw);
+    if (key === "default") {
+      Object.assign(settings, guards);
+    } else {
+      languageGuards[key as SupportedLanguage] = guards;
+    }
+  }
+
+  return { ...settings, languageGuards };
+}
+
+/**
+ * Validate the limits of one `parseFileGuards` entry.
+ */
+function parseGuardLimits(key: string, raw: unknown): FileGuards {
+  if (typeof raw !== "object" || raw === null || Array.isArray(raw)) {
+    throw new Error(`File guards for ${key} must be an object`);
+  }
+
+  const guards: FileGuards = {};
+  for (const [field, limit] of Object.entries(raw)) {
+    if (field !== "maxFileSizeBytes" && field !== "maxLines") {
+      throw new Error(`Unknown file guard for ${key}: ${field}`);
+    }
+    if (typeof limit !== "number" || !Number.isInteger(limit) || limit < 0) {
+      throw new Error(`File guard ${key}.${field} must be a non-negative integer`);
+    }
+    guards[field] = limit;
+  }
+  return guards;
+}
+
 /**
  * Options for filtering extraction results.
  *
@@ -89,6 +218,14 @@ export interface ExtractOptions {
     | "property"
   )[];
 
+  /**
+   * Filter to specific language-neutral symbol kinds.
+   * Unlike `entityTypes`, `"method"` here also matches Rust impl methods and
+   * Python class methods, which the parser extracts as functions.
+   * If not provided, all kinds are included.
+   */
+  kinds?: readonly import("../parsing/symbol-kinds.js").SymbolKind[];
+
   /**
    * If true, only include entities that are exported.
    * @default false
@@ -121,6 +258,21 @@ export interface ExtractionResult {
   /** Detected language of the file */
   language: import("../parsing/types.js").SupportedLanguage;
 
+  /** Marker comments (TODO, FIXME, ...) found in the file, if the parser reports them */
+  todos?: import("../parsing/types.js").TodoComment[];
+
+  /** String literals found in the file, when `indexStringLiterals` is enabled */
+  strings?: import("../parsing/types.js").StringLiteral[];
+
+  /** Documentation of the file's module (Rust `//!` comments), when present */
+  moduleDoc?: string;
+
+  /** Call sites found in the file, used to build CALLS relationships */
+  calls?: import("../parsing/types.js").CallInfo[];
+
+  /** Impl blocks found in the file (Rust), used to build Impl nodes */
+  implementations?: import("../parsing/types.js").ImplEdge[];
+
   /** Time taken for parsing and extraction in milliseconds */
   parseTimeMs: number;
 
@@ -168,6 +320,19 @@ export interface RelationshipExtractorConfig {
    */
   maxFileSizeBytes?: number;
 
+  /**
+   * Maximum number of lines to process; 0 disables the guard.
+   * Files with more lines will throw FileTooLongError.
+   * @default 0
+   */
+  maxLines?: number;
+
+  /**
+   * Per-language overrides of `maxFileSizeBytes` and `maxLines`.
+   * @default {}
+   */
+  languageGuards?: Partial<Record<SupportedLanguage, FileGuards>>;
+
   /**
    * Timeout for parsing a single file in milliseconds.
    * @default 5000
@@ -180,6 +345,8 @@ export interface RelationshipExtractorConfig {
  */
 export const DEFAULT_RELATIONSHIP_EXTRACTOR_CONFIG: Required<RelationshipExtractorConfig> = {
   maxFileSizeBytes: 1048576, // 1MB
+  maxLines: 0,
+  languageGuards: {},
   parseTimeoutMs: 5000,
 };
 
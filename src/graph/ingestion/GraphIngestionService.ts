@@ -13,7 +13,26 @@ import { getComponentLogger } from "../../logging/index.js";
 import type { GraphStorageAdapter } from "../adapters/types.js";
 import { EntityExtractor } from "../extraction/EntityExtractor.js";
 import { RelationshipExtractor } from "../extraction/RelationshipExtractor.js";
-import type { CodeEntity, ParameterInfo } from "../parsing/types.js";
+import type {
+  CodeEntity,
+  CallInfo,
+  ParameterInfo,
+  TodoComment,
+  StringLiteral,
+  ImplEdge,
+} from "../parsing/types.js";
+import { getSymbolKind } from "../parsing/symbol-kinds.js";
+import { parseGenericBounds, splitSupertypes } from "../parsing/type-hierarchy.js";
+import {
+  baseTypeName,
+  buildImportScope,
+  resolveCallEdges,
+  type CallableSymbol,
+  type CallEdge,
+} from "./call-resolution.js";
+import { findOwningCrate, isBuildOrMacroPath, type CrateRoot } from "./crate-roots.js";
+import { normalizeSignature } from "./signature-normalization.js";
+import { splitIdentifier } from "../parsing/identifier-tokens.js";
 import type {
   ImportRelationship,
   ExtractionResult,
@@ -31,6 +50,7 @@ import type {
   GraphIngestionServiceStatus,
   GraphIngestionPhase,
   GraphFileDeletionResult,
+  GraphFileDeletionOptions,
 } from "./types.js";
 import { DEFAULT_GRAPH_INGESTION_CONFIG } from "./types.js";
 import {
@@ -41,6 +61,121 @@ import {
   toGraphIngestionError,
 } from "./errors.js";
 
+/**
+ * Longest argument text stored for a call site; longer arguments, such as
+ * inline closures, are cut to this many characters
+ */
+const MAX_CALL_ARGUMENT_LENGTH = 200;
+
+/**
+ * Properties written to every code entity node, shared by single-file and
+ * full ingestion so both write the same shape
+ */
+const ENTITY_PROPERTY_NAMES = [
+  "name",
+  "filePath",
+  "repository",
+  "startLine",
+  "endLine",
+  "signature",
+  "rawSignature",
+  "entityType",
+  "kind",
+  "nameTokens",
+  "parentName",
+  "whereClause",
+  "paramNames",
+  "paramTypes",
+  "returnType",
+  "isAsync",
+  "localNames",
+  "localTypes",
+  "localLines",
+  "attributes",
+  "cfg",
+  "visibility",
+  "entryPointKind",
+  "isUnsafe",
+  "unsafeBlockStarts",
+  "unsafeBlockEnds",
+  "complexity",
+  "structureHash",
+  "structureSize",
+  "structureMinHash",
+  "superclass",
+  "interfaces",
+  "typeParameters",
+  "supertraits",
+  "startColumn",
+  "endColumn",
+  "nameLine",
+  "nameStartColumn",
+  "nameEndColumn",
+  "variantNames",
+  "variantStartLines",
+  "variantStartColumns",
+  "variantEndLines",
+  "variantEndColumns",
+  "valueKind",
+  "value",
+  "valueText",
+  "isExported",
+  "doc",
+  "implementedTrait",
+  "buildOrMacro",
+] as const;
+
+/** SET clause writing {@link ENTITY_PROPERTY_NAMES} of `e` from same-named parameters */
+const SET_ENTITY_PROPERTIES =
+  "SET " + ENTITY_PROPERTY_NAMES.map((name) => `e.${name} = $${name}`).join(", ");
+
+/**
+ * A relationship from outside a file into an entity the file defines, such
+ * as a call from another file, captured before the file's nodes are deleted
+ */
+interface InboundEdge {
+  sourceId: string;
+  type: string;
+  properties: Record<string, unknown>;
+  targetLabel: string;
+  targetName: string;
+  targetParentName: string | null;
+  targetLine: number;
+}
+
+/**
+ * Read an InboundEdge back from the properties of its PendingInboundEdge
+ * node. The relationship's own properties are stored on the node next to
+ * the `pending*` bookkeeping properties.
+ */
+function toInboundEdge(node: Record<string, unknown>): InboundEdge | null {
+  const properties: Record<string, unknown> = {};
+  for (const [key, value] of Object.entries(node)) {
+    if (!key.startsWith("pending")) {
+      properties[key] = value;
+    }
+  }
+  const { pendingSourceId, pendingType, pendingTargetLabel, pendingTargetName } = node;
+  if (
+    typeof pendingSourceId !== "string" ||
+    typeof pendingType !== "string" ||
+    typeof pendingTargetLabel !== "string" ||
+    typeof pendingTargetName !== "string"
+  ) {
+    return null;
+  }
+  return {
+    sourceId: pendingSourceId,
+    type: pendingType,
+    properties,
+    targetLabel: pendingTargetLabel,
+    targetName: pendingTargetName,
+    targetParentName:
+      typeof node["pendingTargetParentName"] === "string" ? node["pendingTargetParentName"] : null,
+    targetLine: Number(node["pendingTargetLine"] ?? 0),
+  };
+}
+
 /**
  * Service for orchestrating graph ingestion operations.
  *
@@ -216,6 +351,7 @@ export class GraphIngestionService {
         contains: 0,
         defines: 0,
         imports: 0,
+        calls: 0,
       },
     };
 
@@ -266,7 +402,13 @@ export class GraphIngestionService {
 
       // Phase 3: Create Repository node
       this.reportProgress(options, "creating_repository_node", 25, {});
-      await this.createRepositoryNode(options.repository, options.repositoryUrl);
+      const crateRoots = options.crateRoots ?? [];
+      await this.createRepositoryNode(
+        options.repository,
+        options.repositoryUrl,
+        crateRoots,
+        options.buildOrMacroPaths ?? []
+      );
       stats.nodesCreated++;
       stats.nodesByType!.repository = 1;
 
@@ -285,8 +427,12 @@ export class GraphIngestionService {
         filesProcessed: stats.filesProcessed,
         totalFiles: files.length,
       });
+      const lossyPaths = new Set(
+        files.filter((file) => file.encodingWarning !== undefined).map((file) => file.path)
+      );
       const entityNodeResult = await this.createEntityNodes(
         entityResults,
+        lossyPaths,
         options.repository,
         options,
         errors
@@ -312,7 +458,26 @@ export class GraphIngestionService {
       stats.nodesByType!.module = moduleResult.nodesCreated;
       stats.relationshipsByType!.imports = moduleResult.relationshipsCreated;
 
-      // Phase 7: Verify graph integrity
+      // Phase 7: Create CALLS relationships between functions
+      this.reportProgress(options, "creating_relationships", 85, {
+        relationshipsCreated: stats.relationshipsCreated,
+      });
+      const callsCreated = await this.createCallRelationships(
+        entityResults,
+        relationshipResults,
+        options.repository,
+        crateRoots,
+        errors
+      );
+      stats.relationshipsCreated += callsCreated;
+      stats.relationshipsByType!.calls = callsCreated;
+
+      // Link Rust traits to the supertraits they require
+      const supertraitsLinked = await this.linkSupertraits(options.repository);
+      stats.relationshipsCreated += supertraitsLinked;
+      stats.relationshipsByType!.supertraits = supertraitsLinked;
+
+      // Phase 8: Verify graph integrity
       this.reportProgress(options, "verifying", 95, {
         nodesCreated: stats.nodesCreated,
         relationshipsCreated: stats.relationshipsCreated,
@@ -406,7 +571,7 @@ export class GraphIngestionService {
       `
       MATCH (r:Repository {name: $repositoryName})
       OPTIONAL MATCH (r)-[:CONTAINS]->(f:File)
-      OPTIONAL MATCH (f)-[:DEFINES]->(entity)
+      OPTIONAL MATCH (f)-[:DEFINES|HAS_TODO|HAS_STRING_LITERAL|HAS_IMPL|HAS_DOCTEST]->(entity)
       OPTIONAL MATCH (f)-[:IMPORTS]->(module:Module)
       OPTIONAL MATCH (f)-[:HAS_CHUNK]->(chunk:Chunk)
       DETACH DELETE entity, module, chunk, f, r
@@ -427,6 +592,15 @@ export class GraphIngestionService {
       { repositoryName }
     );
 
+    // Relationships captured for files that were never re-ingested
+    await this.graphAdapter.runQuery(
+      `
+      MATCH (p:PendingInboundEdge {pendingRepository: $repositoryName})
+      DELETE p
+      `,
+      { repositoryName }
+    );
+
     const durationMs = Math.round(performance.now() - startTime);
     this.logger.info(
       {
@@ -448,8 +622,14 @@ export class GraphIngestionService {
    * Used for incremental updates when a file is deleted or modified
    * (delete old data before re-ingesting).
    *
+   * With `reingest`, relationships from outside into the file's entities,
+   * like calls from other files, are stored as PendingInboundEdge nodes in
+   * the same query that deletes the file, and `ingestFile` restores them.
+   * Without it, any such nodes left for the file are removed.
+   *
    * @param repositoryName - Name of the repository containing the file
    * @param filePath - File path relative to repository root
+   * @param options - Whether the file is about to be ingested again
    * @returns Result containing deletion statistics and success status
    *
    * @example
@@ -460,7 +640,11 @@ export class GraphIngestionService {
    * }
    * ```
    */
-  async deleteFileData(repositoryName: string, filePath: string): Promise<GraphFileDeletionResult> {
+  async deleteFileData(
+    repositoryName: string,
+    filePath: string,
+    options: GraphFileDeletionOptions = {}
+  ): Promise<GraphFileDeletionResult> {
     this.validateRepositoryName(repositoryName);
 
     // Validate file path
@@ -474,6 +658,27 @@ export class GraphIngestionService {
     const startTime = performance.now();
 
     try {
+      // Relationships from nodes outside the file become PendingInboundEdge
+      // nodes, written in the delete query so a restart cannot lose them
+      const captureInbound = options.reingest
+        ? `
+        OPTIONAL MATCH (source)-[inbound]->(target)<-[:DEFINES]-(f)
+        WHERE source <> f AND NOT source IN entities
+        FOREACH (present IN CASE WHEN source IS NULL THEN [] ELSE [1] END |
+          CREATE (p:PendingInboundEdge)
+          SET p += properties(inbound),
+              p.pendingFileId = f.id,
+              p.pendingRepository = f.repository,
+              p.pendingSourceId = source.id,
+              p.pendingType = type(inbound),
+              p.pendingTargetLabel = labels(target)[0],
+              p.pendingTargetName = target.name,
+              p.pendingTargetParentName = target.parentName,
+              p.pendingTargetLine = target.startLine
+        )
+        WITH DISTINCT f, entities, chunks, nodeCount, relCount`
+        : "";
+
       // Delete File node, its entities (Functions, Classes), and chunks
       // Module nodes are preserved as they may be shared across files
       // We use a single query to count and delete atomically
@@ -485,7 +690,7 @@ export class GraphIngestionService {
       }>(
         `
         MATCH (f:File {id: $fileId})
-        OPTIONAL MATCH (f)-[:DEFINES]->(entity)
+        OPTIONAL MATCH (f)-[:DEFINES|HAS_TODO|HAS_STRING_LITERAL|HAS_IMPL|HAS_DOCTEST]->(entity)
         OPTIONAL MATCH (f)-[:HAS_CHUNK]->(chunk:Chunk)
         WITH f, collect(DISTINCT entity) as entities, collect(DISTINCT chunk) as chunks
         WITH f, entities, chunks,
@@ -493,6 +698,7 @@ export class GraphIngestionService {
         // Count relationships before deletion
         OPTIONAL MATCH (f)-[r]-()
         WITH f, entities, chunks, nodeCount, count(r) as relCount
+        ${captureInbound}
         // Delete entities and chunks (filter nulls)
         FOREACH (e IN [x IN entities WHERE x IS NOT NULL] | DETACH DELETE e)
         FOREACH (c IN [x IN chunks WHERE x IS NOT NULL] | DETACH DELETE c)
@@ -530,6 +736,18 @@ export class GraphIngestionService {
       );
       const docStats = docDeletion[0] ?? { nodesDeleted: 0, relsDeleted: 0 };
 
+      // The file is gone for good: nothing will restore relationships
+      // captured by an earlier delete that was meant to be followed by an ingest
+      if (!options.reingest) {
+        await this.graphAdapter.runQuery(
+          `
+          MATCH (p:PendingInboundEdge {pendingFileId: $fileId})
+          DELETE p
+          `,
+          { fileId }
+        );
+      }
+
       this.logger.debug(
         {
           metric: "graph_ingestion.delete_file_ms",
@@ -601,6 +819,12 @@ export class GraphIngestionService {
         file.path
       );
 
+      // Workspace crates and build-time code recorded by the last full ingestion (Rust only)
+      const { crateRoots, buildOrMacroPaths } = file.path.endsWith(".rs")
+        ? await this.loadCargoLayout(repositoryName)
+        : { crateRoots: [], buildOrMacroPaths: [] };
+      const buildOrMacro = isBuildOrMacroPath(file.path, buildOrMacroPaths);
+
       // Create File node using runQuery for flexibility
       const fileNodeId = this.generateFileNodeId(repositoryName, file.path);
       await this.graphAdapter.runQuery(
@@ -610,6 +834,10 @@ export class GraphIngestionService {
             f.extension = $extension,
             f.hash = $hash,
             f.repository = $repository,
+            f.encodingWarning = $encodingWarning,
+            f.moduleDoc = $moduleDoc,
+            f.crateName = $crateName,
+            f.buildOrMacro = $buildOrMacro,
             f.labels = ['File']
         `,
         {
@@ -618,6 +846,10 @@ export class GraphIngestionService {
           extension: this.getExtension(file.path),
           hash: file.hash ?? "",
           repository: repositoryName,
+          encodingWarning: file.encodingWarning ?? null,
+          moduleDoc: entityResult.moduleDoc ?? null,
+          crateName: findOwningCrate(file.path, crateRoots)?.name ?? null,
+          buildOrMacro,
         }
       );
       nodesCreated++;
@@ -642,23 +874,14 @@ export class GraphIngestionService {
         await this.graphAdapter.runQuery(
           `
           MERGE (e:${nodeLabel} {id: $id})
-          SET e.name = $name,
-              e.filePath = $filePath,
-              e.repository = $repository,
-              e.startLine = $startLine,
-              e.endLine = $endLine,
-              e.signature = $signature,
-              e.entityType = $entityType
+          ${SET_ENTITY_PROPERTIES}
           `,
           {
             id: entityNodeId,
-            name: entity.name,
-            filePath: file.path,
-            repository: repositoryName,
-            startLine: entity.lineStart,
-            endLine: entity.lineEnd,
-            signature: this.buildFunctionSignature(entity),
-            entityType: entity.type,
+            ...this.buildEntityProperties(repositoryName, file.path, entity, {
+              linesOnly: file.encodingWarning !== undefined,
+              buildOrMacro,
+            }),
           }
         );
         nodesCreated++;
@@ -680,6 +903,43 @@ export class GraphIngestionService {
         relationshipsCreated++;
       }
 
+      // Create Todo nodes and HAS_TODO relationships
+      const todosCreated = await this.createTodoNodes(
+        repositoryName,
+        file.path,
+        entityResult.todos ?? []
+      );
+      nodesCreated += todosCreated;
+      relationshipsCreated += todosCreated;
+
+      // Create StringLiteral nodes and HAS_STRING_LITERAL relationships (opt-in)
+      const stringsCreated = await this.createStringLiteralNodes(
+        repositoryName,
+        file.path,
+        entityResult.strings ?? []
+      );
+      nodesCreated += stringsCreated;
+      relationshipsCreated += stringsCreated;
+
+      // Create Impl nodes and HAS_IMPL relationships (Rust)
+      const implsCreated = await this.createImplNodes(
+        repositoryName,
+        file.path,
+        entityResult.implementations ?? [],
+        file.encodingWarning !== undefined
+      );
+      nodesCreated += implsCreated;
+      relationshipsCreated += implsCreated;
+
+      // Create Doctest nodes with HAS_DOCTEST and DOCTEST_OF relationships (Rust)
+      const doctestsCreated = await this.createDoctestNodes(
+        repositoryName,
+        file.path,
+        entityResult.entities
+      );
+      nodesCreated += doctestsCreated;
+      relationshipsCreated += doctestsCreated * 2;
+
       // Create Module nodes and IMPORTS relationships
       for (const importRel of relationshipResult.imports) {
         const moduleNodeId = this.generateModuleNodeId(importRel);
@@ -704,18 +964,61 @@ export class GraphIngestionService {
           MATCH (m:Module {id: $moduleId})
           MERGE (f)-[r:IMPORTS]->(m)
           SET r.importType = $importType,
-              r.importedSymbols = $importedSymbols
+              r.importedSymbols = $importedSymbols,
+              r.aliases = $aliases,
+              r.resolvedPath = $resolvedPath,
+              r.visibility = $visibility,
+              r.line = $line
           `,
           {
             fileId: fileNodeId,
             moduleId: moduleNodeId,
             importType: importRel.importInfo.isTypeOnly ? "type" : "value",
-            importedSymbols: importRel.importInfo.importedNames,
+            ...this.buildImportEdgeProperties(importRel),
           }
         );
         relationshipsCreated++;
       }
 
+      // Create CALLS relationships, resolving names not defined in this file
+      // against functions already in the graph
+      const localCallables = this.toCallableSymbols(
+        repositoryName,
+        file.path,
+        entityResult.entities
+      );
+      const remoteCallables = await this.findRemoteCallables(
+        repositoryName,
+        file.path,
+        entityResult.calls ?? [],
+        localCallables
+      );
+      const callEdges = resolveCallEdges(
+        file.path,
+        entityResult.calls ?? [],
+        localCallables,
+        (name) => remoteCallables.get(name) ?? [],
+        buildImportScope(relationshipResult.imports.map((rel) => rel.importInfo)),
+        crateRoots
+      );
+      relationshipsCreated += await this.writeCallEdges(callEdges);
+
+      // Restore relationships from outside, such as calls from other files,
+      // that deleteFileData dropped with the file's previous entities
+      relationshipsCreated += await this.restoreInboundEdges(
+        repositoryName,
+        file.path,
+        entityResult.entities
+      );
+
+      // Re-link supertraits, which may cross into or out of this file
+      relationshipsCreated += await this.linkSupertraits(repositoryName, {
+        filePath: file.path,
+        traitNames: entityResult.entities
+          .filter((entity) => entity.type === "interface")
+          .map((entity) => entity.name),
+      });
+
       return {
         filePath: file.path,
         success: true,
@@ -849,8 +1152,17 @@ export class GraphIngestionService {
 
   /**
    * Create the Repository node.
+   *
+   * Workspace crate roots are stored as parallel `crateNames`/`cratePaths`
+   * lists, and build-time code paths as `buildOrMacroPaths`, so single-file
+   * ingestion can resolve and tag files the same way.
    */
-  private async createRepositoryNode(repositoryName: string, repositoryUrl: string): Promise<void> {
+  private async createRepositoryNode(
+    repositoryName: string,
+    repositoryUrl: string,
+    crateRoots: CrateRoot[],
+    buildOrMacroPaths: string[]
+  ): Promise<void> {
     const nodeId = this.generateRepositoryNodeId(repositoryName);
     await this.graphAdapter.runQuery(
       `
@@ -858,7 +1170,10 @@ export class GraphIngestionService {
       SET r.name = $name,
           r.url = $url,
           r.lastIndexed = $lastIndexed,
-          r.status = $status
+          r.status = $status,
+          r.crateNames = $crateNames,
+          r.cratePaths = $cratePaths,
+          r.buildOrMacroPaths = $buildOrMacroPaths
       `,
       {
         id: nodeId,
@@ -866,10 +1181,46 @@ export class GraphIngestionService {
         url: repositoryUrl,
         lastIndexed: new Date().toISOString(),
         status: "ready",
+        crateNames: crateRoots.map((root) => root.name),
+        cratePaths: crateRoots.map((root) => root.path),
+        buildOrMacroPaths,
       }
     );
   }
 
+  /**
+   * Read the workspace crate roots and build-time code paths stored on a
+   * Repository node.
+   *
+   * @returns Crate roots, empty when the repository was not ingested as a
+   *   workspace, and build-time code paths
+   */
+  private async loadCargoLayout(
+    repositoryName: string
+  ): Promise<{ crateRoots: CrateRoot[]; buildOrMacroPaths: string[] }> {
+    const rows = await this.graphAdapter.runQuery<{
+      crateNames: string[] | null;
+      cratePaths: string[] | null;
+      buildOrMacroPaths: string[] | null;
+    }>(
+      `
+      MATCH (r:Repository {id: $id})
+      RETURN r.crateNames AS crateNames,
+             r.cratePaths AS cratePaths,
+             r.buildOrMacroPaths AS buildOrMacroPaths
+      `,
+      { id: this.generateRepositoryNodeId(repositoryName) }
+    );
+    const row = rows[0];
+    return {
+      crateRoots: (row?.crateNames ?? []).map((name, i) => ({
+        name,
+        path: row?.cratePaths?.[i] ?? "",
+      })),
+      buildOrMacroPaths: row?.buildOrMacroPaths ?? [],
+    };
+  }
+
   /**
    * Create File nodes with CONTAINS relationships from Repository.
    */
@@ -906,7 +1257,10 @@ export class GraphIngestionService {
             SET f.path = $path,
                 f.extension = $extension,
                 f.hash = $hash,
-                f.repository = $repository
+                f.repository = $repository,
+                f.encodingWarning = $encodingWarning,
+                f.crateName = $crateName,
+                f.buildOrMacro = $buildOrMacro
             `,
             {
               id: fileNodeId,
@@ -914,6 +1268,9 @@ export class GraphIngestionService {
               extension: this.getExtension(file.path),
               hash: file.hash ?? "",
               repository: repositoryName,
+              encodingWarning: file.encodingWarning ?? null,
+              crateName: findOwningCrate(file.path, options.crateRoots ?? [])?.name ?? null,
+              buildOrMacro: isBuildOrMacroPath(file.path, options.buildOrMacroPaths ?? []),
             }
           );
           nodesCreated++;
@@ -943,9 +1300,13 @@ export class GraphIngestionService {
 
   /**
    * Create entity nodes (Function, Class) with DEFINES relationships.
+   *
+   * Files in `lossyPaths` were decoded lossily, so only their line numbers
+   * are stored.
    */
   private async createEntityNodes(
     entityResults: Map<string, ExtractionResult>,
+    lossyPaths: ReadonlySet<string>,
     repositoryName: string,
     options: GraphIngestionOptions,
     errors: GraphIngestionError[]
@@ -989,23 +1350,14 @@ export class GraphIngestionService {
           await this.graphAdapter.runQuery(
             `
             MERGE (e:${nodeLabel} {id: $id})
-            SET e.name = $name,
-                e.filePath = $filePath,
-                e.repository = $repository,
-                e.startLine = $startLine,
-                e.endLine = $endLine,
-                e.signature = $signature,
-                e.entityType = $entityType
+            ${SET_ENTITY_PROPERTIES}
             `,
             {
               id: entityNodeId,
-              name: entity.name,
-              filePath,
-              repository: repositoryName,
-              startLine: entity.lineStart,
-              endLine: entity.lineEnd,
-              signature: this.buildFunctionSignature(entity),
-              entityType: entity.type,
+              ...this.buildEntityProperties(repositoryName, filePath, entity, {
+                linesOnly: lossyPaths.has(filePath),
+                buildOrMacro: isBuildOrMacroPath(filePath, options.buildOrMacroPaths ?? []),
+              }),
             }
           );
           nodesCreated++;
@@ -1050,9 +1402,670 @@ export class GraphIngestionService {
       }
     }
 
+    // Marker comments hang off their File node rather than being entities
+    for (const [filePath, result] of entityResults) {
+      try {
+        const todosCreated = await this.createTodoNodes(
+          repositoryName,
+          filePath,
+          result.todos ?? []
+        );
+        nodesCreated += todosCreated;
+        relationshipsCreated += todosCreated;
+      } catch (error) {
+        errors.push({
+          type: "node_error",
+          filePath,
+          message: `Failed to create todo nodes: ${error instanceof Error ? error.message : String(error)}`,
+          originalError: error,
+        });
+      }
+
+      try {
+        const stringsCreated = await this.createStringLiteralNodes(
+          repositoryName,
+          filePath,
+          result.strings ?? []
+        );
+        nodesCreated += stringsCreated;
+        relationshipsCreated += stringsCreated;
+      } catch (error) {
+        errors.push({
+          type: "node_error",
+          filePath,
+          message: `Failed to create string literal nodes: ${error instanceof Error ? error.message : String(error)}`,
+          originalError: error,
+        });
+      }
+
+      try {
+        const implsCreated = await this.createImplNodes(
+          repositoryName,
+          filePath,
+          result.implementations ?? [],
+          lossyPaths.has(filePath)
+        );
+        nodesCreated += implsCreated;
+        relationshipsCreated += implsCreated;
+      } catch (error) {
+        errors.push({
+          type: "node_error",
+          filePath,
+          message: `Failed to create impl nodes: ${error instanceof Error ? error.message : String(error)}`,
+          originalError: error,
+        });
+      }
+
+      try {
+        const doctestsCreated = await this.createDoctestNodes(
+          repositoryName,
+          filePath,
+          result.entities
+        );
+        nodesCreated += doctestsCreated;
+        relationshipsCreated += doctestsCreated * 2;
+      } catch (error) {
+        errors.push({
+          type: "node_error",
+          filePath,
+          message: `Failed to create doctest nodes: ${error instanceof Error ? error.message : String(error)}`,
+          originalError: error,
+        });
+      }
+
+      // File nodes are created before parsing, so the module doc is set here
+      if (result.moduleDoc !== undefined) {
+        try {
+          await this.graphAdapter.runQuery(
+            `
+            MATCH (f:File {id: $fileId})
+            SET f.moduleDoc = $moduleDoc
+            `,
+            {
+              fileId: this.generateFileNodeId(repositoryName, filePath),
+              moduleDoc: result.moduleDoc,
+            }
+          );
+        } catch (error) {
+          errors.push({
+            type: "node_error",
+            filePath,
+            message: `Failed to set module doc: ${error instanceof Error ? error.message : String(error)}`,
+            originalError: error,
+          });
+        }
+      }
+    }
+
     return { nodesCreated, relationshipsCreated, functionCount, classCount };
   }
 
+  /**
+   * Create CALLS relationships for every file of a batch ingestion.
+   *
+   * Runs after all entity nodes exist, so calls can resolve to functions
+   * defined in any file of the batch, and method calls to the methods of
+   * receiver types imported from any other file.
+   *
+   * @returns Number of CALLS relationships created
+   */
+  private async createCallRelationships(
+    entityResults: Map<string, ExtractionResult>,
+    relationshipResults: Map<string, RelationshipExtractionResult>,
+    repositoryName: string,
+    crateRoots: CrateRoot[],
+    errors: GraphIngestionError[]
+  ): Promise<number> {
+    const callablesByFile = new Map<string, CallableSymbol[]>();
+    const callablesByName = new Map<string, CallableSymbol[]>();
+    for (const [filePath, result] of entityResults) {
+      const callables = this.toCallableSymbols(repositoryName, filePath, result.entities);
+      callablesByFile.set(filePath, callables);
+      for (const callable of callables) {
+        const named = callablesByName.get(callable.name) ?? [];
+        named.push(callable);
+        callablesByName.set(callable.name, named);
+      }
+    }
+
+    let relationshipsCreated = 0;
+    for (const [filePath, result] of entityResults) {
+      try {
+        const edges = resolveCallEdges(
+          filePath,
+          result.calls ?? [],
+          callablesByFile.get(filePath) ?? [],
+          (name) => callablesByName.get(name) ?? [],
+          buildImportScope(
+            relationshipResults.get(filePath)?.imports.map((rel) => rel.importInfo) ?? []
+          ),
+          crateRoots
+        );
+        relationshipsCreated += await this.writeCallEdges(edges);
+      } catch (error) {
+        errors.push({
+          type: "relationship_error",
+          filePath,
+          message: `Failed to create call relationships: ${error instanceof Error ? error.message : String(error)}`,
+          originalError: error,
+        });
+      }
+    }
+
+    return relationshipsCreated;
+  }
+
+  /**
+   * Look up functions defined outside a file that its calls may target.
+   *
+   * Used by single-file ingestion, where the rest of the repository is only
+   * available in the graph.
+   *
+   * Names the file defines itself are looked up too, since a typed method
+   * call may target a method of the same name declared on another type.
+   *
+   * @returns Functions by name, for names the file calls
+   */
+  private async findRemoteCallables(
+    repositoryName: string,
+    filePath: string,
+    calls: CallInfo[],
+    localCallables: CallableSymbol[]
+  ): Promise<Map<string, CallableSymbol[]>> {
+    const names = [...new Set(calls.map((c) => c.calledName))];
+    const byName = new Map<string, CallableSymbol[]>();
+    if (names.length === 0 || localCallables.length === 0) {
+      return byName;
+    }
+
+    const rows = await this.graphAdapter.runQuery<
+      Omit<CallableSymbol, "parentName"> & { parentName: string | null }
+    >(
+      `
+      MATCH (e:Function {repository: $repository})
+      WHERE e.name IN $names AND e.filePath <> $filePath
+      RETURN e.id AS id,
+             e.name AS name,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine,
+             e.parentName AS parentName
+      `,
+      { repository: repositoryName, names, filePath }
+    );
+
+    for (const row of rows) {
+      const named = byName.get(row.name) ?? [];
+      named.push({ ...row, parentName: row.parentName ?? undefined });
+      byName.set(row.name, named);
+    }
+    return byName;
+  }
+
+  /**
+   * Write resolved call edges in one UNWIND query.
+   *
+   * With `captureCallArguments` on, each edge also stores its call sites as
+   * parallel lists: the line of every site and its arguments as a JSON array
+   * of source texts, each cut to `MAX_CALL_ARGUMENT_LENGTH` characters. With
+   * it off both are null so re-ingestion clears previously captured sites.
+   *
+   * @returns Number of CALLS relationships written
+   */
+  private async writeCallEdges(edges: CallEdge[]): Promise<number> {
+    if (edges.length === 0) {
+      return 0;
+    }
+
+    const capture = this.config.captureCallArguments;
+    await this.graphAdapter.runQuery(
+      `
+      UNWIND $edges AS edge
+      MATCH (caller:Function {id: edge.fromId})
+      MATCH (callee:Function {id: edge.toId})
+      MERGE (caller)-[r:CALLS]->(callee)
+      SET r.callCount = edge.callCount,
+          r.line = edge.line,
+          r.typeArguments = edge.typeArguments,
+          r.callSiteLines = edge.callSiteLines,
+          r.callSiteArguments = edge.callSiteArguments
+      `,
+      {
+        edges: edges.map((edge) => ({
+          fromId: edge.fromId,
+          toId: edge.toId,
+          callCount: edge.callCount,
+          line: edge.line,
+          typeArguments: edge.typeArguments,
+          callSiteLines: capture ? edge.sites.map((site) => site.line) : null,
+          callSiteArguments: capture
+            ? edge.sites.map((site) =>
+                JSON.stringify(
+                  (site.arguments ?? []).map((text) => text.slice(0, MAX_CALL_ARGUMENT_LENGTH))
+                )
+              )
+            : null,
+        })),
+      }
+    );
+
+    return edges.length;
+  }
+
+  /**
+   * Re-create relationships from outside a re-ingested file into its entities.
+   *
+   * Reads the PendingInboundEdge nodes `deleteFileData` stored for the file.
+   * Each captured relationship is re-attached, with its properties, to the
+   * entity of the same label, name and parent type, nearest to the old
+   * target's line when the name is declared more than once; relationships to
+   * entities the file no longer defines are dropped. Sources are matched by
+   * ID, so relationships from nodes deleted since are skipped. The pending
+   * nodes are removed once the relationships are written.
+   *
+   * @returns Number of relationships restored
+   */
+  private async restoreInboundEdges(
+    repositoryName: string,
+    filePath: string,
+    entities: CodeEntity[]
+  ): Promise<number> {
+    const fileNodeId = this.generateFileNodeId(repositoryName, filePath);
+    const pending = await this.graphAdapter.runQuery<{ edge: Record<string, unknown> }>(
+      `
+      MATCH (p:PendingInboundEdge {pendingFileId: $fileId})
+      RETURN properties(p) AS edge
+      `,
+      { fileId: fileNodeId }
+    );
+    if (pending.length === 0) {
+      return 0;
+    }
+    const captured = pending
+      .map((row) => toInboundEdge(row.edge))
+      .filter((edge): edge is InboundEdge => edge !== null);
+
+    const byType = new Map<
+      string,
+      { sourceId: string; targetId: string; properties: Record<string, unknown> }[]
+    >();
+    for (const edge of captured) {
+      // Types come back from the graph but are interpolated into the query
+      if (!/^[A-Z][A-Z0-9_]*$/.test(edge.type)) {
+        continue;
+      }
+      const target = entities
+        .filter(
+          (entity) =>
+            entity.name === edge.targetName &&
+            (entity.parentName ?? null) === (edge.targetParentName ?? null) &&
+            this.getEntityNodeType(entity) === edge.targetLabel
+        )
+        .sort(
+          (a, b) =>
+            Math.abs(a.lineStart - edge.targetLine) - Math.abs(b.lineStart - edge.targetLine)
+        )[0];
+      if (!target) {
+        continue;
+      }
+      const list = byType.get(edge.type) ?? [];
+      list.push({
+        sourceId: edge.sourceId,
+        targetId: this.generateEntityNodeId(repositoryName, filePath, target),
+        properties: edge.properties ?? {},
+      });
+      byType.set(edge.type, list);
+    }
+
+    let restored = 0;
+    for (const [type, edges] of byType) {
+      const rows = await this.graphAdapter.runQuery<{ restored: number }>(
+        `
+        UNWIND $edges AS edge
+        MATCH (source {id: edge.sourceId})
+        MATCH (target {id: edge.targetId})
+        MERGE (source)-[r:${type}]->(target)
+        SET r += edge.properties
+        RETURN count(*) AS restored
+        `,
+        { edges }
+      );
+      restored += rows[0]?.restored ?? 0;
+    }
+
+    await this.graphAdapter.runQuery(
+      `
+      MATCH (p:PendingInboundEdge {pendingFileId: $fileId})
+      DELETE p
+      `,
+      { fileId: fileNodeId }
+    );
+
+    return restored;
+  }
+
+  /**
+   * Create SUPERTRAIT_OF relationships between the traits of a repository.
+   *
+   * Without a scope this runs over the whole repository, because a trait's
+   * supertraits are usually declared in other files. With a scope it only
+   * links the traits defined in that file and the traits elsewhere whose
+   * supertraits name one of them, which is all a single-file re-ingest can
+   * change. Supertraits are matched by base name (`std::fmt::Display` ->
+   * `Display`); ones not defined in the repository, like std traits, stay as
+   * names on the trait's `supertraits` property.
+   *
+   * @param scope - Re-ingested file and the names of the traits it defines
+   * @returns Number of SUPERTRAIT_OF relationships written
+   */
+  private async linkSupertraits(
+    repositoryName: string,
+    scope?: { filePath: string; traitNames: string[] }
+  ): Promise<number> {
+    if (scope?.traitNames.length === 0) {
+      return 0;
+    }
+
+    // Coarse filter; exact base-name matching happens below
+    const scopeFilter = scope
+      ? `AND (t.filePath = $filePath
+           OR any(s IN t.supertraits WHERE any(n IN $traitNames WHERE s CONTAINS n)))`
+      : "";
+    const traits = await this.graphAdapter.runQuery<{
+      id: string;
+      filePath: string;
+      supertraits: string[];
+    }>(
+      `
+      MATCH (t:Class {repository: $repository, entityType: "interface"})
+      WHERE size(coalesce(t.supertraits, [])) > 0
+      ${scopeFilter}
+      RETURN t.id AS id, t.filePath AS filePath, t.supertraits AS supertraits
+      `,
+      scope
+        ? { repository: repositoryName, filePath: scope.filePath, traitNames: scope.traitNames }
+        : { repository: repositoryName }
+    );
+
+    const edges = traits.flatMap((trait) =>
+      [...new Set(trait.supertraits.map(baseTypeName))]
+        .filter((name): name is string => name !== undefined)
+        .filter(
+          (name) => !scope || trait.filePath === scope.filePath || scope.traitNames.includes(name)
+        )
+        .map((name) => ({ subId: trait.id, name }))
+    );
+    if (edges.length === 0) {
+      return 0;
+    }
+
+    const rows = await this.graphAdapter.runQuery<{ linked: number }>(
+      `
+      UNWIND $edges AS edge
+      MATCH (sub:Class {id: edge.subId})
+      MATCH (sup:Class {repository: $repository, entityType: "interface", name: edge.name})
+      WHERE sup.id <> sub.id
+      MERGE (sup)-[:SUPERTRAIT_OF]->(sub)
+      RETURN count(*) AS linked
+      `,
+      { repository: repositoryName, edges }
+    );
+
+    return rows[0]?.linked ?? 0;
+  }
+
+  /**
+   * Functions and methods of a file as call resolution targets
+   */
+  private toCallableSymbols(
+    repositoryName: string,
+    filePath: string,
+    entities: CodeEntity[]
+  ): CallableSymbol[] {
+    return entities
+      .filter((entity) => entity.type === "function" || entity.type === "method")
+      .map((entity) => ({
+        id: this.generateEntityNodeId(repositoryName, filePath, entity),
+        name: entity.name,
+        filePath,
+        startLine: entity.lineStart,
+        endLine: entity.lineEnd,
+        parentName: entity.parentName,
+        params: entity.metadata?.parameters,
+        locals: entity.metadata?.locals,
+      }));
+  }
+
+  /**
+   * Create Todo nodes with HAS_TODO relationships from their File node.
+   *
+   * Written in one UNWIND query per file. Ids end in an ordinal among the
+   * file's markers with the same line and tag, so two `TODO`s in one line's
+   * comments get separate nodes.
+   *
+   * @returns Number of Todo nodes created (one HAS_TODO relationship each)
+   */
+  private async createTodoNodes(
+    repositoryName: string,
+    filePath: string,
+    todos: TodoComment[]
+  ): Promise<number> {
+    if (todos.length === 0) {
+      return 0;
+    }
+
+    const ordinals = new Map<string, number>();
+    await this.graphAdapter.runQuery(
+      `
+      MATCH (f:File {id: $fileId})
+      UNWIND $todos AS todo
+      MERGE (t:Todo {id: todo.id})
+      SET t.tag = todo.tag,
+          t.message = todo.message,
+          t.filePath = $filePath,
+          t.repository = $repository,
+          t.line = todo.line
+      MERGE (f)-[:HAS_TODO]->(t)
+      `,
+      {
+        fileId: this.generateFileNodeId(repositoryName, filePath),
+        filePath,
+        repository: repositoryName,
+        todos: todos.map((todo) => {
+          const key = `${todo.line}:${todo.tag}`;
+          const ordinal = ordinals.get(key) ?? 0;
+          ordinals.set(key, ordinal + 1);
+          return {
+            id: `Todo:${repositoryName}:${filePath}:${key}:${ordinal}`,
+            tag: todo.tag,
+            message: todo.message,
+            line: todo.line,
+          };
+        }),
+      }
+    );
+
+    return todos.length;
+  }
+
+  /**
+   * Create Doctest nodes for the doc comment examples of a file's entities.
+   *
+   * Each example hangs off its File node (HAS_DOCTEST) so file deletion
+   * removes it, and links to the item it documents (DOCTEST_OF). The names
+   * it calls are stored as a list for usage example lookups.
+   *
+   * @returns Number of Doctest nodes created (two relationships each)
+   */
+  private async createDoctestNodes(
+    repositoryName: string,
+    filePath: string,
+    entities: CodeEntity[]
+  ): Promise<number> {
+    const doctests = entities.flatMap((entity) =>
+      (entity.metadata?.doctests ?? []).map((doctest) => ({
+        id: `Doctest:${repositoryName}:${filePath}:${doctest.lineStart}`,
+        entityId: this.generateEntityNodeId(repositoryName, filePath, entity),
+        info: doctest.info,
+        mode: doctest.mode,
+        code: doctest.code,
+        calls: doctest.calls,
+        definedSymbols: doctest.definedSymbols,
+        startLine: doctest.lineStart,
+        endLine: doctest.lineEnd,
+      }))
+    );
+    if (doctests.length === 0) {
+      return 0;
+    }
+
+    await this.graphAdapter.runQuery(
+      `
+      MATCH (f:File {id: $fileId})
+      UNWIND $doctests AS doctest
+      MATCH (e {id: doctest.entityId})
+      MERGE (d:Doctest {id: doctest.id})
+      SET d.info = doctest.info,
+          d.mode = doctest.mode,
+          d.code = doctest.code,
+          d.calls = doctest.calls,
+          d.definedSymbols = doctest.definedSymbols,
+          d.filePath = $filePath,
+          d.repository = $repository,
+          d.startLine = doctest.startLine,
+          d.endLine = doctest.endLine
+      MERGE (f)-[:HAS_DOCTEST]->(d)
+      MERGE (d)-[:DOCTEST_OF]->(e)
+      `,
+      {
+        fileId: this.generateFileNodeId(repositoryName, filePath),
+        filePath,
+        repository: repositoryName,
+        doctests,
+      }
+    );
+
+    return doctests.length;
+  }
+
+  /**
+   * Create StringLiteral nodes with HAS_STRING_LITERAL relationships from
+   * their File node.
+   *
+   * Files can hold hundreds of literals, so they are written in one
+   * UNWIND query per file rather than one query per literal.
+   *
+   * @returns Number of StringLiteral nodes created (one relationship each)
+   */
+  private async createStringLiteralNodes(
+    repositoryName: string,
+    filePath: string,
+    literals: StringLiteral[]
+  ): Promise<number> {
+    if (literals.length === 0) {
+      return 0;
+    }
+
+    await this.graphAdapter.runQuery(
+      `
+      MATCH (f:File {id: $fileId})
+      UNWIND $literals AS literal
+      MERGE (s:StringLiteral {id: literal.id})
+      SET s.value = literal.value,
+          s.filePath = $filePath,
+          s.repository = $repository,
+          s.line = literal.line,
+          s.column = literal.column,
+          s.enclosingSymbol = literal.enclosingSymbol
+      MERGE (f)-[:HAS_STRING_LITERAL]->(s)
+      `,
+      {
+        fileId: this.generateFileNodeId(repositoryName, filePath),
+        filePath,
+        repository: repositoryName,
+        literals: literals.map((literal) => ({
+          id: `StringLiteral:${repositoryName}:${filePath}:${literal.line}:${literal.column}`,
+          value: literal.value,
+          line: literal.line,
+          column: literal.column,
+          enclosingSymbol: literal.enclosingSymbol ?? null,
+        })),
+      }
+    );
+
+    return literals.length;
+  }
+
+  /**
+   * Create Impl nodes with HAS_IMPL relationships from their File node.
+   *
+   * Impl blocks often live in a different file than the type they
+   * implement, so each node records the implementing type's base name
+   * (`selfType`) for lookup by name. Blanket impls over a generic parameter
+   * (`impl<T: Display> ToText for T`) have no single implementing type and
+   * get a null `selfType`.
+   *
+   * @param linesOnly - Omit columns, for files decoded lossily
+   * @returns Number of Impl nodes created (one HAS_IMPL relationship each)
+   */
+  private async createImplNodes(
+    repositoryName: string,
+    filePath: string,
+    implementations: ImplEdge[],
+    linesOnly = false
+  ): Promise<number> {
+    if (implementations.length === 0) {
+      return 0;
+    }
+
+    await this.graphAdapter.runQuery(
+      `
+      MATCH (f:File {id: $fileId})
+      UNWIND $impls AS impl
+      MERGE (i:Impl {id: impl.id})
+      SET i.typeName = impl.typeName,
+          i.selfType = impl.selfType,
+          i.traitName = impl.traitName,
+          i.typeParameters = impl.typeParameters,
+          i.whereClause = impl.whereClause,
+          i.isNegative = impl.isNegative,
+          i.isUnsafe = impl.isUnsafe,
+          i.filePath = $filePath,
+          i.repository = $repository,
+          i.line = impl.line,
+          i.endLine = impl.endLine,
+          i.startColumn = impl.startColumn,
+          i.endColumn = impl.endColumn
+      MERGE (f)-[:HAS_IMPL]->(i)
+      `,
+      {
+        fileId: this.generateFileNodeId(repositoryName, filePath),
+        filePath,
+        repository: repositoryName,
+        impls: implementations.map((impl) => {
+          const genericNames = parseGenericBounds(impl.typeParameters).map((b) => b.name);
+          const selfType = baseTypeName(impl.typeName);
+          return {
+            id: `Impl:${repositoryName}:${filePath}:${impl.line}`,
+            typeName: impl.typeName,
+            selfType: selfType !== undefined && !genericNames.includes(selfType) ? selfType : null,
+            traitName: impl.traitName ?? null,
+            typeParameters: impl.typeParameters,
+            whereClause: impl.whereClause,
+            isNegative: impl.isNegative,
+            isUnsafe: impl.isUnsafe,
+            line: impl.line,
+            endLine: impl.range?.lineEnd ?? null,
+            startColumn: linesOnly ? null : (impl.range?.columnStart ?? null),
+            endColumn: linesOnly ? null : (impl.range?.columnEnd ?? null),
+          };
+        }),
+      }
+    );
+
+    return implementations.length;
+  }
+
   /**
    * Create Module nodes with IMPORTS relationships.
    */
@@ -1120,13 +2133,17 @@ export class GraphIngestionService {
             MATCH (m:Module {id: $moduleId})
             MERGE (f)-[r:IMPORTS]->(m)
             SET r.importType = $importType,
-                r.importedSymbols = $importedSymbols
+                r.importedSymbols = $importedSymbols,
+                r.aliases = $aliases,
+                r.resolvedPath = $resolvedPath,
+                r.visibility = $visibility,
+                r.line = $line
             `,
             {
               fileId: fileNodeId,
               moduleId: moduleNodeId,
               importType: this.getImportType(importRel),
-              importedSymbols: importRel.importInfo.importedNames,
+              ...this.buildImportEdgeProperties(importRel),
             }
           );
           relationshipsCreated++;
@@ -1203,6 +2220,269 @@ export class GraphIngestionService {
     return `${asyncPrefix}${entity.name}(${params}): ${returnType}`;
   }
 
+  /**
+   * Build the {@link SET_ENTITY_PROPERTIES} parameters of a code entity.
+   *
+   * @param options.linesOnly - Omit columns, for files decoded lossily
+   * @param options.buildOrMacro - Whether the file is build-time code
+   */
+  private buildEntityProperties(
+    repositoryName: string,
+    filePath: string,
+    entity: CodeEntity,
+    options: { linesOnly: boolean; buildOrMacro: boolean }
+  ): Record<(typeof ENTITY_PROPERTY_NAMES)[number], unknown> {
+    return {
+      name: entity.name,
+      filePath,
+      repository: repositoryName,
+      startLine: entity.lineStart,
+      endLine: entity.lineEnd,
+      ...this.buildSignatureProperties(entity),
+      entityType: entity.type,
+      kind: getSymbolKind(entity),
+      nameTokens: splitIdentifier(entity.name),
+      parentName: entity.parentName ?? null,
+      ...this.buildParameterProperties(entity),
+      ...this.buildLocalProperties(entity),
+      attributes: entity.metadata?.attributes ?? null,
+      cfg: entity.metadata?.cfg ?? null,
+      visibility: entity.metadata?.visibility ?? null,
+      entryPointKind: entity.metadata?.entryPoint?.kind ?? null,
+      ...this.buildUnsafeProperties(entity),
+      complexity: entity.metadata?.complexity ?? null,
+      ...this.buildStructureProperties(entity),
+      ...this.buildSupertypeProperties(entity),
+      ...this.buildRangeProperties(entity, options.linesOnly),
+      valueKind: entity.metadata?.value?.kind ?? null,
+      value: entity.metadata?.value?.value ?? null,
+      valueText: entity.metadata?.valueText ?? null,
+      isExported: entity.isExported,
+      doc: entity.metadata?.doc ?? null,
+      implementedTrait: entity.metadata?.implementedTrait ?? null,
+      buildOrMacro: options.buildOrMacro,
+    };
+  }
+
+  /**
+   * Build the signature properties of a symbol.
+   *
+   * With `normalizeSignatures` on, the signature and where clause are stored
+   * with canonical spacing so formatting-only edits do not change them, and
+   * the signature as written is kept as `rawSignature` for display.
+   */
+  private buildSignatureProperties(entity: CodeEntity): {
+    signature: string;
+    rawSignature: string;
+    whereClause: string | null;
+  } {
+    const rawSignature = this.buildFunctionSignature(entity);
+    const whereClause = entity.metadata?.whereClause ?? null;
+    if (!this.config.normalizeSignatures) {
+      return { signature: rawSignature, rawSignature, whereClause };
+    }
+    return {
+      signature: normalizeSignature(rawSignature),
+      rawSignature,
+      whereClause: whereClause !== null ? normalizeSignature(whereClause) : null,
+    };
+  }
+
+  /**
+   * Build the structured parameter, return type and async properties of a symbol.
+   *
+   * Parameters are stored as parallel name/type lists because graph
+   * properties cannot hold maps; an unannotated parameter has an empty type.
+   * The return type of an async function is its declared (resolved) type.
+   * Non-callable entities get nulls so re-ingestion clears stale values.
+   */
+  private buildParameterProperties(entity: CodeEntity): {
+    paramNames: string[] | null;
+    paramTypes: string[] | null;
+    returnType: string | null;
+    isAsync: boolean | null;
+  } {
+    if (entity.type !== "function" && entity.type !== "method") {
+      return { paramNames: null, paramTypes: null, returnType: null, isAsync: null };
+    }
+    const parameters = entity.metadata?.parameters ?? [];
+    return {
+      paramNames: parameters.map((p: ParameterInfo) => p.name),
+      paramTypes: parameters.map((p: ParameterInfo) => p.type ?? ""),
+      returnType: entity.metadata?.returnType ?? null,
+      isAsync: entity.metadata?.isAsync === true,
+    };
+  }
+
+  /**
+   * Build the local variable properties of a function.
+   *
+   * `let` bindings are stored as parallel name/type/line lists because graph
+   * properties cannot hold maps; untyped locals get an empty type. Other
+   * entities get nulls so re-ingestion clears stale values.
+   */
+  private buildLocalProperties(entity: CodeEntity): {
+    localNames: string[] | null;
+    localTypes: string[] | null;
+    localLines: number[] | null;
+  } {
+    if (entity.type !== "function" && entity.type !== "method") {
+      return { localNames: null, localTypes: null, localLines: null };
+    }
+    const locals = entity.metadata?.locals ?? [];
+    return {
+      localNames: locals.map((local) => local.name),
+      localTypes: locals.map((local) => local.type ?? ""),
+      localLines: locals.map((local) => local.line),
+    };
+  }
+
+  /**
+   * Build the unsafe-code properties of a symbol.
+   *
+   * Unsafe block spans are stored as parallel start/end line lists because
+   * graph properties cannot hold maps. Symbols without unsafe code get
+   * `isUnsafe: false` and empty lists so re-ingestion clears stale values.
+   */
+  private buildUnsafeProperties(entity: CodeEntity): {
+    isUnsafe: boolean;
+    unsafeBlockStarts: number[];
+    unsafeBlockEnds: number[];
+  } {
+    const blocks = entity.metadata?.unsafeBlocks ?? [];
+    return {
+      isUnsafe: entity.metadata?.isUnsafe ?? false,
+      unsafeBlockStarts: blocks.map((block) => block.lineStart),
+      unsafeBlockEnds: blocks.map((block) => block.lineEnd),
+    };
+  }
+
+  /**
+   * Build the structural fingerprint properties of a function. Entities
+   * without a body get nulls so re-ingestion clears stale fingerprints.
+   */
+  private buildStructureProperties(entity: CodeEntity): {
+    structureHash: string | null;
+    structureSize: number | null;
+    structureMinHash: number[] | null;
+  } {
+    const structure = entity.metadata?.structure;
+    return {
+      structureHash: structure?.hash ?? null,
+      structureSize: structure?.size ?? null,
+      structureMinHash: structure?.minHash ?? null,
+    };
+  }
+
+  /**
+   * Build the supertype and generic parameter properties of a type.
+   *
+   * Extends/implements clauses are split into bare supertype names. Only
+   * classes, interfaces, enums and type aliases carry them; other entities
+   * get nulls so re-ingestion clears stale values (Go methods reuse
+   * `extends` for their receiver, which is not a supertype). Rust trait
+   * supertraits are kept as written; `linkSupertraits` resolves them.
+   */
+  private buildSupertypeProperties(entity: CodeEntity): {
+    superclass: string | null;
+    interfaces: string[] | null;
+    typeParameters: string[] | null;
+    supertraits: string[] | null;
+  } {
+    if (
+      entity.type !== "class" &&
+      entity.type !== "interface" &&
+      entity.type !== "enum" &&
+      entity.type !== "type_alias"
+    ) {
+      return { superclass: null, interfaces: null, typeParameters: null, supertraits: null };
+    }
+    const metadata = entity.metadata;
+    return {
+      superclass: splitSupertypes(metadata?.extends ? [metadata.extends] : [])[0] ?? null,
+      interfaces: splitSupertypes(metadata?.implements ?? []),
+      typeParameters: metadata?.typeParameters ?? [],
+      supertraits: metadata?.supertraits ?? [],
+    };
+  }
+
+  /**
+   * Build the source range properties of a symbol.
+   *
+   * Columns complement the start/end lines so editors can select the whole
+   * symbol and its name. Enum variant ranges are stored as parallel lists
+   * because graph properties cannot hold maps; non-enums get nulls so
+   * re-ingestion clears stale values.
+   *
+   * Columns are omitted when `linesOnly` is set: in a lossily decoded file
+   * they count replacement characters rather than the original bytes.
+   */
+  private buildRangeProperties(
+    entity: CodeEntity,
+    linesOnly = false
+  ): {
+    startColumn: number | null;
+    endColumn: number | null;
+    nameLine: number | null;
+    nameStartColumn: number | null;
+    nameEndColumn: number | null;
+    variantNames: string[] | null;
+    variantStartLines: number[] | null;
+    variantStartColumns: number[] | null;
+    variantEndLines: number[] | null;
+    variantEndColumns: number[] | null;
+  } {
+    const variants = entity.type === "enum" ? (entity.metadata?.variants ?? []) : null;
+    const columnVariants = linesOnly ? null : variants;
+    return {
+      startColumn: linesOnly ? null : (entity.columnStart ?? null),
+      endColumn: linesOnly ? null : (entity.columnEnd ?? null),
+      nameLine: entity.nameRange?.lineStart ?? null,
+      nameStartColumn: linesOnly ? null : (entity.nameRange?.columnStart ?? null),
+      nameEndColumn: linesOnly ? null : (entity.nameRange?.columnEnd ?? null),
+      variantNames: variants?.map((v) => v.name) ?? null,
+      variantStartLines: variants?.map((v) => v.range?.lineStart ?? v.line) ?? null,
+      variantStartColumns: columnVariants?.map((v) => v.range?.columnStart ?? 0) ?? null,
+      variantEndLines: variants?.map((v) => v.range?.lineEnd ?? v.line) ?? null,
+      variantEndColumns: columnVariants?.map((v) => v.range?.columnEnd ?? 0) ?? null,
+    };
+  }
+
+  /**
+   * Build the IMPORTS edge properties shared by single-file and batch ingestion.
+   *
+   * Aliases are flattened to `"original as alias"` strings because graph
+   * properties cannot hold maps. Default and namespace imports are recorded
+   * as aliases of `default` and `*` respectively so consumers can rebuild the
+   * full import statement. `visibility` marks Rust re-exports (`pub use`).
+   */
+  private buildImportEdgeProperties(importRel: ImportRelationship): {
+    importedSymbols: string[];
+    aliases: string[];
+    resolvedPath: string | null;
+    visibility: string | null;
+    line: number;
+  } {
+    const info = importRel.importInfo;
+    const aliases = Object.entries(info.aliases ?? {}).map(
+      ([original, alias]) => `${original} as ${alias}`
+    );
+    if (info.defaultImport) {
+      aliases.push(`default as ${info.defaultImport}`);
+    }
+    if (info.namespaceImport && info.namespaceImport !== "*") {
+      aliases.push(`* as ${info.namespaceImport}`);
+    }
+
+    return {
+      importedSymbols: info.importedNames,
+      aliases,
+      resolvedPath: importRel.resolvedPath ?? null,
+      visibility: info.visibility ?? null,
+      line: info.line,
+    };
+  }
+
   private getImportType(importRel: ImportRelationship): string {
     if (importRel.importInfo.isTypeOnly) {
       return "type";
@@ -0,0 +1,395 @@
+/**
+ * Call target resolution for CALLS relationships.
+ *
+ * The parser reports each call site with the bare name being called. This
+ * module turns call sites into caller -> callee edges between Function nodes:
+ * the caller is the innermost function or method enclosing the call, and the
+ * callee is the single function or method with the called name, preferring
+ * definitions in the same file. Calls whose target is ambiguous or not
+ * defined in the repository (library calls, chained expressions) produce no
+ * edge rather than a guessed one.
+ *
+ * Method calls whose receiver type can be read off the call site are bound to
+ * the methods of that type first, wherever in the index the type is declared:
+ * `self.save()` uses the enclosing impl or class, `User::new()` and
+ * `user.save()` (for a parameter `user: &User`) use the named type, looked up
+ * through the file's imports and aliases and narrowed by the module the type
+ * was imported from. Receivers that don't resolve to an indexed type, such as
+ * types from external crates, fall back to name-only resolution. In a Cargo
+ * workspace, `crate::`, `self::` and `super::` modules are looked up in the
+ * importing file's own crate, and paths starting with a workspace crate's
+ * name (`crate_b::models`) in that crate.
+ *
+ * Single-file ingestion resolves the file's outgoing calls against functions
+ * already in the graph. Calls into the file from other files are dropped with
+ * its old entity nodes and return when those files are ingested again.
+ *
+ * @module graph/ingestion/call-resolution
+ */
+
+import { posix } from "node:path";
+import type { CallInfo, ImportInfo } from "../parsing/types.js";
+import { findOwningCrate, rustCrateIdentifier, type CrateRoot } from "./crate-roots.js";
+
+/**
+ * A function or method that can take part in a CALLS relationship
+ */
+export interface CallableSymbol {
+  /** Graph node ID */
+  id: string;
+  /** Function or method name */
+  name: string;
+  /** File path relative to the repository root */
+  filePath: string;
+  /** First line of the definition (1-based) */
+  startLine: number;
+  /** Last line of the definition (1-based) */
+  endLine: number;
+  /** Type the method is declared on (class, or implementing type of a Rust impl) */
+  parentName?: string;
+  /** Parameters with their declared types, used to type method receivers */
+  params?: { name: string; type?: string }[];
+  /** Locals bound by `let` with their known types, used to type method receivers */
+  locals?: { name: string; type?: string; line: number }[];
+}
+
+/**
+ * A name brought into a file's scope by an import
+ */
+export interface ImportedName {
+  /** Name as declared in its defining module */
+  name: string;
+  /** Module the name is imported from, as written (e.g. "crate::models", "./models") */
+  module: string;
+}
+
+/**
+ * Names a file imports, keyed by local name (the alias, if any)
+ */
+export type ImportScope = Map<string, ImportedName>;
+
+/**
+ * A receiver type read off a call site
+ */
+interface ReceiverType {
+  /** Type name as declared */
+  name: string;
+  /** Module the type was imported from, when known */
+  module?: string;
+}
+
+/**
+ * One call site of a CALLS edge
+ */
+export interface CallSite {
+  /** Line of the call (1-based) */
+  line: number;
+  /** Source text of each argument expression, when the parser captured it */
+  arguments?: string[];
+}
+
+/**
+ * A resolved caller -> callee edge, aggregated over all call sites
+ */
+export interface CallEdge {
+  /** Node ID of the calling function */
+  fromId: string;
+  /** Node ID of the called function */
+  toId: string;
+  /** Number of call sites from caller to callee */
+  callCount: number;
+  /** Line of the first call site (1-based) */
+  line: number;
+  /** Distinct explicit type arguments across the call sites (e.g. ["String"]) */
+  typeArguments: string[];
+  /** Every call site from caller to callee, ordered by line */
+  sites: CallSite[];
+}
+
+/**
+ * Resolve the call sites of one file into CALLS edges.
+ *
+ * @param filePath - File the calls were parsed from
+ * @param calls - Call sites reported by the parser
+ * @param localCallables - Functions and methods defined in the file
+ * @param findCallables - Lookup of functions and methods by name across the
+ *        repository; may include the file's own definitions
+ * @param scope - Names the file imports, used to resolve receiver types
+ * @param crates - Crate roots of a Cargo workspace; empty outside workspace mode
+ * @returns Edges ordered by first call site
+ */
+export function resolveCallEdges(
+  filePath: string,
+  calls: CallInfo[],
+  localCallables: CallableSymbol[],
+  findCallables: (name: string) => CallableSymbol[],
+  scope: ImportScope = new Map(),
+  crates: CrateRoot[] = []
+): CallEdge[] {
+  const edges = new Map<string, CallEdge>();
+
+  for (const call of calls) {
+    const caller = findEnclosingCallable(localCallables, call.line);
+    if (!caller) continue;
+
+    const receiver = resolveReceiverType(call, caller, scope);
+    const method = receiver
+      ? resolveMethod(filePath, call.calledName, receiver, localCallables, findCallables, crates)
+      : undefined;
+    const callee =
+      method ?? resolveCallee(filePath, call.calledName, localCallables, findCallables);
+    if (!callee) continue;
+
+    const key = `${caller.id}->${callee.id}`;
+    const site: CallSite = call.arguments
+      ? { line: call.line, arguments: call.arguments }
+      : { line: call.line };
+    const edge = edges.get(key);
+    if (edge) {
+      edge.callCount++;
+      edge.line = Math.min(edge.line, call.line);
+      for (const typeArgument of call.typeArguments ?? []) {
+        if (!edge.typeArguments.includes(typeArgument)) edge.typeArguments.push(typeArgument);
+      }
+      edge.sites.push(site);
+      continue;
+    }
+
+    edges.set(key, {
+      fromId: caller.id,
+      toId: callee.id,
+      callCount: 1,
+      line: call.line,
+      typeArguments: [...new Set(call.typeArguments ?? [])],
+      sites: [site],
+    });
+  }
+
+  for (const edge of edges.values()) {
+    edge.sites.sort((a, b) => a.line - b.line);
+  }
+  return [...edges.values()].sort((a, b) => a.line - b.line);
+}
+
+/**
+ * Find the innermost callable whose line range contains a line
+ */
+function findEnclosingCallable(
+  callables: CallableSymbol[],
+  line: number
+): CallableSymbol | undefined {
+  let innermost: CallableSymbol | undefined;
+  for (const callable of callables) {
+    if (callable.startLine > line || callable.endLine < line) continue;
+    const span = callable.endLine - callable.startLine;
+    if (!innermost || span < innermost.endLine - innermost.startLine) {
+      innermost = callable;
+    }
+  }
+  return innermost;
+}
+
+/**
+ * Resolve a called name to a single definition
+ *
+ * A unique definition in the same file wins; otherwise the name must be
+ * defined exactly once in the rest of the repository.
+ */
+function resolveCallee(
+  filePath: string,
+  calledName: string,
+  localCallables: CallableSymbol[],
+  findCallables: (name: string) => CallableSymbol[]
+): CallableSymbol | undefined {
+  const local = localCallables.filter((c) => c.name === calledName);
+  if (local.length > 0) {
+    return local.length === 1 ? local[0] : undefined;
+  }
+
+  const remote = findCallables(calledName).filter((c) => c.filePath !== filePath);
+  return remote.length === 1 ? remote[0] : undefined;
+}
+
+/**
+ * Build the import scope of a file from its parsed imports.
+ *
+ * Rust imports carry the full path (`crate::models::User`); the module is the
+ * path without the imported name. Other languages import names from a module
+ * specifier (`./models`).
+ */
+export function buildImportScope(imports: ImportInfo[]): ImportScope {
+  const scope: ImportScope = new Map();
+  for (const info of imports) {
+    for (const name of info.importedNames) {
+      const module = info.source.endsWith(`::${name}`)
+        ? info.source.slice(0, -(name.length + 2))
+        : info.source;
+      scope.set(info.aliases?.[name] ?? name, { name, module });
+    }
+  }
+  return scope;
+}
+
+/**
+ * Read the receiver type of a method call off its call expression
+ *
+ * Handles `self`/`this` receivers, type-qualified calls (`User::new`,
+ * `models::User::new`), and parameters and locals with a known type. A local
+ * bound before the call shadows a parameter or earlier local of the same
+ * name. Other receivers (fields, chained calls) are not typed.
+ */
+function resolveReceiverType(
+  call: CallInfo,
+  caller: CallableSymbol,
+  scope: ImportScope
+): ReceiverType | undefined {
+  const expression = stripTypeArguments(call.calledExpression);
+  const separator = ["::", "->", "."].find((sep) =>
+    expression.endsWith(`${sep}${call.calledName}`)
+  );
+  if (!separator) return undefined;
+
+  const receiver = expression.slice(0, -(separator.length + call.calledName.length));
+  if (SELF_RECEIVERS.has(receiver)) {
+    return caller.parentName ? { name: caller.parentName } : undefined;
+  }
+
+  if (separator === "::") {
+    const segments = receiver.split("::");
+    const name = segments[segments.length - 1]!;
+    if (segments.length === 1) {
+      return scope.get(name) ?? { name };
+    }
+    const head = scope.get(segments[0]!);
+    const rest = segments.slice(1, -1);
+    const module = head
+      ? [head.module, head.name, ...rest].join("::")
+      : segments.slice(0, -1).join("::");
+    return { name, module };
+  }
+
+  if (!/^[A-Za-z_$][\w$]*$/.test(receiver)) return undefined;
+  const local = caller.locals
+    ?.filter((binding) => binding.name === receiver && binding.line <= call.line)
+    .pop();
+  const type = local
+    ? local.type
+    : caller.params?.find((param) => param.name === receiver)?.type;
+  const typeName = type ? baseTypeName(type) : undefined;
+  if (!typeName) return undefined;
+  return scope.get(typeName) ?? { name: typeName };
+}
+
+/**
+ * Receivers that refer to the enclosing type
+ */
+const SELF_RECEIVERS = new Set(["self", "Self", "this", "$this", "static"]);
+
+/**
+ * Remove generic arguments from an expression (`Vec::<u8>::new` -> `Vec::new`)
+ */
+function stripTypeArguments(expression: string): string {
+  let stripped = expression;
+  let previous: string;
+  do {
+    previous = stripped;
+    stripped = stripped.replace(/(::)?<[^<>]*>/g, "");
+  } while (stripped !== previous);
+  return stripped;
+}
+
+/**
+ * Name of the type behind a declared parameter type
+ * (`&mut models::User<'a>` -> `User`), or undefined for tuples and the like.
+ */
+export function baseTypeName(type: string): string | undefined {
+  const base = stripTypeArguments(type)
+    .replace(/^(&\s*('\w+\s+)?|\*\s*|(mut|const|dyn|impl|readonly)\s+)+/, "")
+    .split(/[\s|[?]/)[0]!;
+  const name = base.split(/::|\./).pop();
+  return name && /^[A-Za-z_$][\w$]*$/.test(name) ? name : undefined;
+}
+
+/**
+ * Resolve a method call to a method of its receiver type
+ *
+ * When the type is declared more than once in the index, the module the type
+ * was imported from picks between them.
+ */
+function resolveMethod(
+  filePath: string,
+  calledName: string,
+  receiver: ReceiverType,
+  localCallables: CallableSymbol[],
+  findCallables: (name: string) => CallableSymbol[],
+  crates: CrateRoot[]
+): CallableSymbol | undefined {
+  const candidates = [
+    ...localCallables.filter((c) => c.name === calledName),
+    ...findCallables(calledName).filter((c) => c.filePath !== filePath),
+  ].filter((c) => c.parentName === receiver.name);
+  if (candidates.length === 1) return candidates[0];
+  if (candidates.length === 0 || receiver.module === undefined) return undefined;
+
+  const module = receiver.module;
+  const inModule = candidates.filter((c) => isInModule(c.filePath, module, filePath, crates));
+  return inModule.length === 1 ? inModule[0] : undefined;
+}
+
+/**
+ * Whether a file is the module an import refers to
+ *
+ * Rust paths (`crate::models`, `super::db`) are compared with the module path
+ * of the file (`src/models.rs`, `src/models/mod.rs`); relative specifiers
+ * (`./models`) with the file path without its extension. With workspace
+ * crates, a Rust path only matches files of the crate it refers to.
+ */
+export function isInModule(
+  filePath: string,
+  module: string,
+  importingFile: string,
+  crates: CrateRoot[] = []
+): boolean {
+  const withoutExtension = filePath.replace(/\.[^./]+$/, "");
+
+  if (module.startsWith("./") || module.startsWith("../")) {
+    const target = posix.join(posix.dirname(importingFile), module);
+    return withoutExtension === target || withoutExtension === `${target}/index`;
+  }
+
+  const segments = module.split("::");
+  const namedCrate = crates.find((root) => rustCrateIdentifier(root.name) === segments[0]);
+  let targetCrate = findOwningCrate(importingFile, crates);
+  let resolved: string[];
+  if (segments[0] === "self" || segments[0] === "super") {
+    resolved = rustModulePath(importingFile);
+    for (const segment of segments) {
+      if (segment === "super") resolved = resolved.slice(0, -1);
+      else if (segment !== "self") resolved.push(segment);
+    }
+  } else if (segments[0] === "crate") {
+    resolved = segments.slice(1);
+  } else if (namedCrate) {
+    targetCrate = namedCrate;
+    resolved = segments.slice(1);
+  } else {
+    resolved = segments;
+  }
+  if (findOwningCrate(filePath, crates) !== targetCrate) return false;
+  return rustModulePath(filePath).join("::") === resolved.join("::");
+}
+
+/**
+ * Rust module path of a source file relative to its crate root
+ * (`src/models/user.rs` -> ["models", "user"], `src/lib.rs` -> []).
+ */
+export function rustModulePath(filePath: string): string[] {
+  const srcIndex = filePath.lastIndexOf("src/");
+  const relative = srcIndex === -1 ? filePath : filePath.slice(srcIndex + 4);
+  const segments = relative.replace(/\.rs$/, "").split("/");
+  const last = segments[segments.length - 1];
+  if (last === "mod" || (segments.length === 1 && (last === "lib" || last === "main"))) {
+    segments.pop();
+  }
+  return segments;
+}
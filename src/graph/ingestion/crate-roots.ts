@@ -0,0 +1,260 @@
+/**
+ * Crate roots of a Cargo workspace.
+ *
+ * Rust paths are relative to the crate a file belongs to, so in a workspace
+ * `crate-a/src/lib.rs` and `crate-b/src/lib.rs` are both `crate`. When the
+ * crates of a repository are known, qualified names are prefixed with the
+ * owning crate (`crate_a::Point`, `crate_b::Point`) and `crate::`, `self::`
+ * and `super::` paths only resolve within the crate of the file using them.
+ *
+ * Crates are either listed explicitly or detected from the `[package]`
+ * section of every `Cargo.toml` in the repository. Manifests without a
+ * package, such as a virtual workspace root, are not crates.
+ *
+ * The same manifests mark code that runs at build time rather than in the
+ * application: build scripts (`build.rs` beside a manifest, or the file its
+ * `build` key names) and proc-macro crates (`[lib] proc-macro = true`).
+ * Such files are tagged `buildOrMacro` so searches can leave them out.
+ *
+ * @module graph/ingestion/crate-roots
+ */
+
+import { readdir, readFile } from "fs/promises";
+import { join, posix } from "node:path";
+
+/**
+ * A crate of a Cargo workspace
+ */
+export interface CrateRoot {
+  /** Package name as declared in `Cargo.toml` (e.g., "crate-a") */
+  name: string;
+  /** Directory of the crate's `Cargo.toml` relative to the repository root ("" for the root) */
+  path: string;
+}
+
+/**
+ * How the crates of a repository are determined: detected from its
+ * `Cargo.toml` files, or listed explicitly
+ */
+export type CargoWorkspaceSetting = "detect" | CrateRoot[];
+
+/**
+ * Directories never searched for manifests
+ */
+const SKIPPED_DIRECTORIES = new Set(["target", "node_modules"]);
+
+/**
+ * Parse the Cargo workspace setting (e.g. the `GRAPH_CARGO_WORKSPACE` env var).
+ *
+ * `detect` finds the crates from the repository's `Cargo.toml` files; a
+ * comma-separated list of `name=path` pairs names them explicitly, e.g.
+ * `crate-a=crates/a,crate-b=crates/b`.
+ *
+ * @param value - Raw setting, or undefined to leave workspace mode off
+ * @returns The setting, or undefined when workspace mode is off
+ * @throws {Error} If a list entry is not a `name=path` pair
+ */
+export function parseCargoWorkspace(value: string | undefined): CargoWorkspaceSetting | undefined {
+  const trimmed = value?.trim() ?? "";
+  if (trimmed === "" || trimmed === "false") {
+    return undefined;
+  }
+  if (trimmed === "detect" || trimmed === "true") {
+    return "detect";
+  }
+
+  return trimmed.split(",").map((entry) => {
+    const [name, path, ...rest] = entry.split("=").map((part) => part.trim());
+    if (!name || path === undefined || rest.length > 0) {
+      throw new Error(`Invalid Cargo workspace crate "${entry}": expected name=path`);
+    }
+    return { name, path: normalizeCratePath(path) };
+  });
+}
+
+/**
+ * Resolve a workspace setting to the crates of a repository.
+ *
+ * @param setting - Workspace setting
+ * @param repositoryPath - Local checkout of the repository, searched when detecting
+ * @returns Crate roots ordered by path
+ */
+export async function resolveCrateRoots(
+  setting: CargoWorkspaceSetting,
+  repositoryPath: string
+): Promise<CrateRoot[]> {
+  const roots = setting === "detect" ? await detectCrateRoots(repositoryPath) : setting;
+  return [...roots].sort((a, b) => a.path.localeCompare(b.path));
+}
+
+/**
+ * Find the crates of a repository from its `Cargo.toml` files.
+ *
+ * Hidden directories, `target` and `node_modules` are not searched.
+ *
+ * @param repositoryPath - Local checkout of the repository
+ * @returns One root per manifest declaring a package
+ */
+export async function detectCrateRoots(repositoryPath: string): Promise<CrateRoot[]> {
+  const roots: CrateRoot[] = [];
+  await visitManifests(repositoryPath, (relativeDir, manifest) => {
+    const name = parseCargoPackageName(manifest);
+    if (name !== undefined) {
+      roots.push({ name, path: relativeDir });
+    }
+  });
+  return roots;
+}
+
+/**
+ * Find the build-time code of a repository from its `Cargo.toml` files:
+ * the directories of proc-macro crates and build scripts named by a
+ * `build` key. Default `build.rs` scripts need no manifest entry; see
+ * `isBuildOrMacroPath`.
+ *
+ * @param repositoryPath - Local checkout of the repository
+ * @returns Repository-relative directories and files, ordered by path
+ */
+export async function detectBuildOrMacroPaths(repositoryPath: string): Promise<string[]> {
+  const paths: string[] = [];
+  await visitManifests(repositoryPath, (relativeDir, manifest) => {
+    const { procMacro, buildScript } = parseCargoBuildTargets(manifest);
+    if (procMacro) {
+      paths.push(relativeDir);
+    }
+    if (buildScript !== undefined) {
+      paths.push(normalizeCratePath(posix.join(relativeDir, buildScript)));
+    }
+  });
+  return paths.sort((a, b) => a.localeCompare(b));
+}
+
+/**
+ * Whether a file is build-time code: a `build.rs` outside any `src`
+ * directory, or a file at or beneath one of the detected paths.
+ *
+ * @param filePath - File path relative to the repository root
+ * @param buildOrMacroPaths - Paths from `detectBuildOrMacroPaths`
+ */
+export function isBuildOrMacroPath(filePath: string, buildOrMacroPaths: string[]): boolean {
+  if (!filePath.endsWith(".rs")) {
+    return false;
+  }
+  const segments = filePath.split("/");
+  if (segments[segments.length - 1] === "build.rs" && !segments.includes("src")) {
+    return true;
+  }
+  return buildOrMacroPaths.some(
+    (path) => path === "" || filePath === path || filePath.startsWith(`${path}/`)
+  );
+}
+
+/**
+ * Read the package name from the content of a `Cargo.toml`.
+ *
+ * @returns The `name` of the `[package]` section, or undefined when there is none
+ */
+export function parseCargoPackageName(manifest: string): string | undefined {
+  let inPackage = false;
+  for (const rawLine of manifest.split("\n")) {
+    const line = rawLine.replace(/#.*$/, "").trim();
+    if (line.startsWith("[")) {
+      inPackage = line === "[package]";
+      continue;
+    }
+    const match = inPackage ? /^name\s*=\s*["']([^"']+)["']$/.exec(line) : null;
+    if (match) {
+      return match[1];
+    }
+  }
+  return undefined;
+}
+
+/**
+ * Read the build-time targets from the content of a `Cargo.toml`.
+ *
+ * @returns Whether `[lib]` declares a proc-macro crate, and the build script
+ *   named by the `[package]` `build` key, if any
+ */
+export function parseCargoBuildTargets(manifest: string): {
+  procMacro: boolean;
+  buildScript?: string;
+} {
+  let section = "";
+  let procMacro = false;
+  let buildScript: string | undefined;
+  for (const rawLine of manifest.split("\n")) {
+    const line = rawLine.replace(/#.*$/, "").trim();
+    if (line.startsWith("[")) {
+      section = line;
+      continue;
+    }
+    if (section === "[lib]" && /^proc[-_]macro\s*=\s*true$/.test(line)) {
+      procMacro = true;
+    }
+    const build = section === "[package]" ? /^build\s*=\s*["']([^"']+)["']$/.exec(line) : null;
+    if (build) {
+      buildScript = build[1];
+    }
+  }
+  return buildScript !== undefined ? { procMacro, buildScript } : { procMacro };
+}
+
+/**
+ * Find the crate a file belongs to: the root with the longest path
+ * containing it.
+ *
+ * @param filePath - File path relative to the repository root
+ * @param roots - Crate roots of the repository
+ */
+export function findOwningCrate(filePath: string, roots: CrateRoot[]): CrateRoot | undefined {
+  let owner: CrateRoot | undefined;
+  for (const root of roots) {
+    const contains = root.path === "" || filePath.startsWith(`${root.path}/`);
+    if (contains && (!owner || root.path.length > owner.path.length)) {
+      owner = root;
+    }
+  }
+  return owner;
+}
+
+/**
+ * Name a crate is referred to by in Rust paths (`crate-a` -> `crate_a`).
+ */
+export function rustCrateIdentifier(name: string): string {
+  return name.replace(/-/g, "_");
+}
+
+/**
+ * Call a function with the directory and content of every `Cargo.toml` in
+ * a repository, skipping hidden directories, `target` and `node_modules`.
+ */
+async function visitManifests(
+  repositoryPath: string,
+  onManifest: (relativeDir: string, manifest: string) => void
+): Promise<void> {
+  const visit = async (relativeDir: string): Promise<void> => {
+    const entries = await readdir(join(repositoryPath, relativeDir), { withFileTypes: true });
+    for (const entry of entries) {
+      const relativePath = relativeDir === "" ? entry.name : `${relativeDir}/${entry.name}`;
+      if (entry.isDirectory()) {
+        if (!entry.name.startsWith(".") && !SKIPPED_DIRECTORIES.has(entry.name)) {
+          await visit(relativePath);
+        }
+      } else if (entry.isFile() && entry.name === "Cargo.toml") {
+        onManifest(relativeDir, await readFile(join(repositoryPath, relativePath), "utf-8"));
+      }
+    }
+  };
+
+  await visit("");
+}
+
+/**
+ * Normalize a crate directory to a repository-relative POSIX path without
+ * leading `./` or trailing slashes ("" for the root).
+ */
+function normalizeCratePath(path: string): string {
+  const normalized = posix.normalize(path.replace(/\\/g, "/")).replace(/\/+$/, "");
+  return normalized === "." ? "" : normalized.replace(/^\.\//, "");
+}
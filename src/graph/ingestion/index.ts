@@ -26,10 +26,28 @@ export type {
   GraphIngestionServiceStatus,
   BatchCreationResult,
   GraphFileDeletionResult,
+  GraphFileDeletionOptions,
 } from "./types.js";
 
 export { DEFAULT_GRAPH_INGESTION_CONFIG } from "./types.js";
 
+// Cargo workspaces
+export type { CrateRoot, CargoWorkspaceSetting } from "./crate-roots.js";
+export {
+  parseCargoWorkspace,
+  resolveCrateRoots,
+  detectCrateRoots,
+  parseCargoPackageName,
+  detectBuildOrMacroPaths,
+  parseCargoBuildTargets,
+  isBuildOrMacroPath,
+  findOwningCrate,
+  rustCrateIdentifier,
+} from "./crate-roots.js";
+
+// Signatures
+export { normalizeSignature } from "./signature-normalization.js";
+
 // Errors
 export {
   GraphIngestionError as GraphIngestionErrorClass,
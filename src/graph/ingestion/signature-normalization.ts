@@ -0,0 +1,50 @@
+/**
+ * Signature normalization.
+ *
+ * Signatures are assembled from source text, so they keep whatever spacing
+ * the author used: `Pair<K,V>` and `Pair< K, V >` are the same type but
+ * hash differently, and symbol snapshots report a formatting-only edit as a
+ * signature change. Normalizing before storing makes equivalent signatures
+ * identical:
+ *
+ * | Source                       | Normalized                |
+ * | ---------------------------- | ------------------------- |
+ * | `Pair< K ,V >`               | `Pair<K, V>`              |
+ * | `HashMap<K, Vec<V>,>`        | `HashMap<K, Vec<V>>`      |
+ * | `fn(a:u8)->Result< () >`     | `fn(a: u8) -> Result<()>` |
+ * | `std :: io :: Error`         | `std::io::Error`          |
+ * | `T : Clone,\n    U: Send`    | `T: Clone, U: Send`       |
+ *
+ * Only spacing changes (plus trailing commas, which are insignificant in
+ * every list they can end); the tokens themselves are kept. String literals
+ * inside a signature, such as default parameter values, are normalized too.
+ *
+ * @module graph/ingestion/signature-normalization
+ */
+
+/**
+ * Normalize the spacing of a signature or type expression.
+ *
+ * @param text - Signature text as assembled from source
+ * @returns The canonical form; normalizing it again returns it unchanged
+ */
+export function normalizeSignature(text: string): string {
+  return (
+    text
+      .replace(/\s+/g, " ")
+      .replace(/\s*::\s*/g, "::")
+      .replace(/\s*->\s*/g, " -> ")
+      // Generic brackets hug their contents and the name they follow
+      .replace(/([\w>\]])\s+</g, "$1<")
+      .replace(/<\s+/g, "<")
+      .replace(/\s+>/g, ">")
+      .replace(/([([])\s+/g, "$1")
+      .replace(/\s+([)\]])/g, "$1")
+      .replace(/(\w)\s+\(/g, "$1(")
+      .replace(/\s*,\s*/g, ", ")
+      .replace(/,\s*([>)\]])/g, "$1")
+      .replace(/\s*(?<!:):(?!:)\s*/g, ": ")
+      .replace(/\s*;\s*/g, "; ")
+      .trim()
+  );
+}
@@ -7,6 +7,8 @@
  * @module graph/ingestion/types
  */
 
+import type { CrateRoot } from "./crate-roots.js";
+
 // =============================================================================
 // Configuration Types
 // =============================================================================
@@ -49,6 +51,22 @@ export interface GraphIngestionConfig {
    * @default 30000
    */
   transactionTimeoutMs?: number;
+
+  /**
+   * Store signatures and where clauses with canonical spacing (see
+   * `normalizeSignature`), so formatting-only edits do not register as
+   * signature changes. The signature as written is kept as `rawSignature`.
+   * @default true
+   */
+  normalizeSignatures?: boolean;
+
+  /**
+   * Store the source text of each call site's arguments on CALLS edges, so
+   * callers can be listed with the literal values they pass. Off by default
+   * because it stores a string per call site.
+   * @default false
+   */
+  captureCallArguments?: boolean;
 }
 
 /**
@@ -58,6 +76,8 @@ export const DEFAULT_GRAPH_INGESTION_CONFIG: Required<GraphIngestionConfig> = {
   nodeBatchSize: 20,
   relationshipBatchSize: 50,
   transactionTimeoutMs: 30000,
+  normalizeSignatures: true,
+  captureCallArguments: false,
 };
 
 // =============================================================================
@@ -100,6 +120,22 @@ export interface GraphIngestionOptions {
    * @default false
    */
   force?: boolean;
+
+  /**
+   * Crate roots of a Cargo workspace (see `resolveCrateRoots`).
+   * When set, Rust qualified names are prefixed with the owning crate and
+   * `crate::`/`super::` paths resolve within the crate of the file using
+   * them. Stored on the Repository node for later single-file ingestion.
+   */
+  crateRoots?: CrateRoot[];
+
+  /**
+   * Proc-macro crate directories and build script files (see
+   * `detectBuildOrMacroPaths`). Files beneath them, and `build.rs` files
+   * outside `src`, are tagged `buildOrMacro` so searches can filter them.
+   * Stored on the Repository node for later single-file ingestion.
+   */
+  buildOrMacroPaths?: string[];
 }
 
 /**
@@ -120,6 +156,14 @@ export interface FileInput {
    * Optional SHA256 content hash for change detection.
    */
   hash?: string;
+
+  /**
+   * Set when the file was not valid UTF-8 and `content` is a lossy decode.
+   *
+   * Line numbers still match the original file but columns may not, so
+   * entity columns are not stored for such files.
+   */
+  encodingWarning?: string;
 }
 
 // =============================================================================
@@ -338,6 +382,8 @@ export interface GraphIngestionStats {
     contains?: number;
     defines?: number;
     imports?: number;
+    calls?: number;
+    supertraits?: number;
   };
 }
 
@@ -494,6 +540,18 @@ export interface BatchCreationResult {
 // File Deletion Types
 // =============================================================================
 
+/**
+ * Options for deleting graph data for a single file.
+ */
+export interface GraphFileDeletionOptions {
+  /**
+   * The file is ingested again right after the delete, as for a modified
+   * file. Relationships from other files into its entities are kept in the
+   * graph until `ingestFile` restores them.
+   */
+  reingest?: boolean;
+}
+
 /**
  * Result of deleting graph data for a single file.
  *
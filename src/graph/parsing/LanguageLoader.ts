@@ -4,6 +4,10 @@
  * Handles lazy loading and caching of tree-sitter WASM language grammars.
  * Uses singleton pattern to ensure parser is initialized only once.
  *
+ * Grammars load independently: a grammar that fails to load (missing WASM
+ * file, ABI version mismatch) disables only its own language, and the other
+ * languages stay available. Disabled languages are reported by getStatus().
+ *
  * @module graph/parsing/LanguageLoader
  */
 
@@ -23,13 +27,47 @@ import { LanguageLoadError, ParserInitializationError } from "./errors.js";
  * C# (csharp) uses Roslyn instead of tree-sitter and is
  * handled by the RoslynParser class.
  */
-interface WasmPathConfig {
+export interface WasmPathConfig {
   /** Path to the main tree-sitter WASM module */
   treeSitterWasm: string;
   /** Paths to language-specific WASM files (tree-sitter only) */
   languages: Record<TreeSitterLanguage, string>;
 }
 
+/**
+ * All tree-sitter languages, in preload order.
+ *
+ * Note: C# uses Roslyn and is not listed here.
+ */
+export const TREE_SITTER_LANGUAGES: readonly TreeSitterLanguage[] = [
+  "typescript",
+  "tsx",
+  "javascript",
+  "jsx",
+  "python",
+  "java",
+  "go",
+  "rust",
+  "c",
+  "cpp",
+  "ruby",
+  "php",
+];
+
+/**
+ * Load status of the language loader
+ */
+export interface LanguageLoaderStatus {
+  /** Whether the tree-sitter runtime is initialized */
+  initialized: boolean;
+  /** Languages whose grammar is loaded and usable */
+  loadedLanguages: TreeSitterLanguage[];
+  /** Failure reason for each language whose grammar failed to load */
+  disabledLanguages: Record<string, string>;
+  /** Configured WASM paths */
+  wasmPaths: WasmPathConfig;
+}
+
 /**
  * Get the directory containing this module.
  * Used to resolve relative paths to WASM files.
@@ -148,6 +186,13 @@ export class LanguageLoader {
    * Note: Only tree-sitter languages are cached here. C# uses Roslyn (see RoslynParser).
    */
   private languages: Map<TreeSitterLanguage, Language> = new Map();
+  /**
+   * Languages whose grammar failed to load, keyed to the failure reason.
+   *
+   * A failed grammar is not retried on every parse; it stays disabled until
+   * cleanup() so a broken WASM file costs one warning instead of one error per file.
+   */
+  private disabledLanguages: Map<TreeSitterLanguage, string> = new Map();
   private initPromise: Promise<void> | null = null;
   private readonly wasmPaths: WasmPathConfig;
   private _logger: pino.Logger | null = null;
@@ -298,6 +343,16 @@ export class LanguageLoader {
     return this.languages.has(language);
   }
 
+  /**
+   * Check if a language was disabled because its grammar failed to load.
+   *
+   * @param language - The tree-sitter language to check
+   * @returns true if the language is disabled
+   */
+  isLanguageDisabled(language: TreeSitterLanguage): boolean {
+    return this.disabledLanguages.has(language);
+  }
+
   /**
    * Get a loaded language grammar.
    *
@@ -306,7 +361,7 @@ export class LanguageLoader {
    *
    * @param language - The tree-sitter language to load
    * @returns The loaded language grammar
-   * @throws {LanguageLoadError} If the language fails to load
+   * @throws {LanguageLoadError} If the language fails to load or is disabled
    */
   async getLanguage(language: TreeSitterLanguage): Promise<Language> {
     // Return cached language
@@ -315,6 +370,11 @@ export class LanguageLoader {
       return cached;
     }
 
+    const disabledReason = this.disabledLanguages.get(language);
+    if (disabledReason !== undefined) {
+      throw new LanguageLoadError(language, new Error(`Grammar disabled: ${disabledReason}`));
+    }
+
     // Ensure parser is initialized
     await this.initialize();
 
@@ -350,7 +410,11 @@ export class LanguageLoader {
       return lang;
     } catch (error) {
       const err = error instanceof Error ? error : new Error(String(error));
-      this.logger.error({ err, language, wasmPath }, "Failed to load language grammar");
+      this.disabledLanguages.set(language, err.message);
+      this.logger.warn(
+        { err, language, wasmPath },
+        "Failed to load language grammar - language disabled"
+      );
       throw new LanguageLoadError(language, err);
     }
   }
@@ -359,29 +423,17 @@ export class LanguageLoader {
    * Preload all tree-sitter languages.
    *
    * Useful for warming up the cache during application startup.
+   * Never throws: a grammar that fails to load disables its language and the
+   * remaining languages are still loaded.
    * Note: C# uses Roslyn and is not preloaded here.
    *
    * @returns Map of languages to their load status (true = success)
    */
   async preloadAllLanguages(): Promise<Map<TreeSitterLanguage, boolean>> {
     const results = new Map<TreeSitterLanguage, boolean>();
-    const languages: TreeSitterLanguage[] = [
-      "typescript",
-      "tsx",
-      "javascript",
-      "jsx",
-      "python",
-      "java",
-      "go",
-      "rust",
-      "c",
-      "cpp",
-      "ruby",
-      "php",
-    ];
 
     await Promise.all(
-      languages.map(async (lang) => {
+      TREE_SITTER_LANGUAGES.map(async (lang) => {
         try {
           await this.getLanguage(lang);
           results.set(lang, true);
@@ -391,22 +443,27 @@ export class LanguageLoader {
       })
     );
 
+    const disabled = TREE_SITTER_LANGUAGES.filter((lang) => results.get(lang) === false);
+    if (disabled.length > 0) {
+      this.logger.warn(
+        { disabled, active: this.languages.size },
+        "Some language grammars failed to load - those languages are disabled"
+      );
+    }
+
     return results;
   }
 
   /**
    * Get information about loaded languages.
    *
-   * @returns Object with initialization status and loaded tree-sitter languages
+   * @returns Initialization status, loaded and disabled tree-sitter languages
    */
-  getStatus(): {
-    initialized: boolean;
-    loadedLanguages: TreeSitterLanguage[];
-    wasmPaths: WasmPathConfig;
-  } {
+  getStatus(): LanguageLoaderStatus {
     return {
       initialized: this.parser !== null,
       loadedLanguages: Array.from(this.languages.keys()),
+      disabledLanguages: Object.fromEntries(this.disabledLanguages),
       wasmPaths: this.wasmPaths,
     };
   }
@@ -414,7 +471,8 @@ export class LanguageLoader {
   /**
    * Clean up resources.
    *
-   * Deletes the parser instance and clears the language cache.
+   * Deletes the parser instance, clears the language cache and re-enables
+   * disabled languages.
    * The loader can be reinitialized after cleanup.
    */
   cleanup(): void {
@@ -423,6 +481,7 @@ export class LanguageLoader {
       this.parser = null;
     }
     this.languages.clear();
+    this.disabledLanguages.clear();
     this.initPromise = null;
     this.logger.debug("LanguageLoader cleaned up");
   }
@@ -21,22 +21,34 @@
  * @module graph/parsing/TreeSitterParser
  */
 
-import type { Node } from "web-tree-sitter";
+import type { Node, Parser } from "web-tree-sitter";
 import type pino from "pino";
 import path from "node:path";
 import { getComponentLogger } from "../../logging/index.js";
 import { LanguageLoader } from "./LanguageLoader.js";
-import { LanguageNotSupportedError, FileTooLargeError, ParseTimeoutError } from "./errors.js";
+import {
+  LanguageNotSupportedError,
+  FileTooLargeError,
+  FileTooLongError,
+  ParseTimeoutError,
+} from "./errors.js";
 import {
   type SupportedLanguage,
   type TreeSitterLanguage,
   type CodeEntity,
   type EntityType,
   type EntityMetadata,
+  type EnumVariant,
+  type LineSpan,
+  type SourceRange,
   type ParameterInfo,
   type ImportInfo,
   type ExportInfo,
   type CallInfo,
+  type Doctest,
+  type ImplEdge,
+  type TodoComment,
+  type StringLiteral,
   type ParseResult,
   type ParseError,
   type ParserConfig,
@@ -45,6 +57,23 @@ import {
   isSupportedExtension,
   isTreeSitterLanguage,
 } from "./types.js";
+import { checkFileGuards } from "./file-guards.js";
+import { resolveSymbolKind, type SymbolKind } from "./symbol-kinds.js";
+import { detectRustEntryPoint } from "./entry-points.js";
+import { cfgConditionFromAttributes } from "./cfg-predicates.js";
+import { normalizeRustVisibility } from "./rust-visibility.js";
+import { parseGenericBounds } from "./type-hierarchy.js";
+import { rustComplexity } from "./complexity.js";
+import { rustStructuralFingerprint } from "./structural-fingerprint.js";
+import { rustLiteralValue } from "./literal-values.js";
+import { rustLocalBindings } from "./local-bindings.js";
+import { classifyDocComment, extractDocText } from "./doc-comments.js";
+import {
+  classifyRustFence,
+  docCommentLines,
+  findFencedCodeBlocks,
+  unhideDoctestLines,
+} from "./doctests.js";
 
 /**
  * Node type to entity type mapping for TypeScript/JavaScript.
@@ -174,6 +203,26 @@ const PHP_NODE_TO_ENTITY_TYPE: Record<string, EntityType> = {
  */
 export const ENTITY_NODE_TYPES = Object.keys(NODE_TO_ENTITY_TYPE);
 
+/**
+ * String literal node types across the supported grammars.
+ * Character literals are deliberately excluded.
+ */
+const STRING_LITERAL_NODE_TYPES = new Set([
+  "string", // JavaScript, TypeScript, Python, Ruby, PHP
+  "template_string", // JavaScript, TypeScript
+  "string_literal", // Java, Rust, C, C++, C#
+  "raw_string_literal", // Go, Rust, C++, C#
+  "interpreted_string_literal", // Go
+  "verbatim_string_literal", // C#
+  "text_block", // Java
+  "encapsed_string", // PHP
+]);
+
+/**
+ * Maximum stored length of a string literal's contents
+ */
+const MAX_STRING_LITERAL_LENGTH = 500;
+
 /**
  * Tree-sitter based parser for TypeScript and JavaScript files.
  *
@@ -245,19 +294,14 @@ export class TreeSitterParser {
    * @param filePath - File path (used for extension detection and in results)
    * @returns Parse result with entities, imports, and errors
    * @throws {LanguageNotSupportedError} If file extension is not supported
-   * @throws {FileTooLargeError} If file exceeds max size
+   * @throws {FileTooLargeError} If file exceeds its language's max size
+   * @throws {FileTooLongError} If file exceeds its language's max line count
    * @throws {ParseTimeoutError} If parsing exceeds configured timeout
    */
   async parseFile(content: string, filePath: string): Promise<ParseResult> {
     const startTime = performance.now();
     const errors: ParseError[] = [];
 
-    // Check file size
-    const sizeBytes = new TextEncoder().encode(content).length;
-    if (sizeBytes > this.config.maxFileSizeBytes) {
-      throw new FileTooLargeError(filePath, sizeBytes, this.config.maxFileSizeBytes);
-    }
-
     // Get language from extension
     const extension = path.extname(filePath).toLowerCase();
     const detectedLanguage = getLanguageFromExtension(extension);
@@ -266,6 +310,9 @@ export class TreeSitterParser {
       throw new LanguageNotSupportedError(filePath, extension);
     }
 
+    // Check file size and length against the language's guards
+    const sizeBytes = checkFileGuards(content, filePath, this.config, detectedLanguage);
+
     // C# uses Roslyn, not tree-sitter - this parser shouldn't receive C# files
     if (!isTreeSitterLanguage(detectedLanguage)) {
       throw new LanguageNotSupportedError(
@@ -277,6 +324,16 @@ export class TreeSitterParser {
 
     const language: TreeSitterLanguage = detectedLanguage;
 
+    // A grammar that failed to load disables its language; skip its files like
+    // unsupported ones instead of failing each parse
+    if (this.languageLoader.isLanguageDisabled(language)) {
+      throw new LanguageNotSupportedError(
+        filePath,
+        extension,
+        `${language} grammar failed to load and is disabled`
+      );
+    }
+
     this.logger.debug({ filePath, language, sizeBytes }, "Parsing file");
 
     // Create timeout promise for enforcing parseTimeoutMs
@@ -325,6 +382,11 @@ export class TreeSitterParser {
       // Extract entities (language-aware)
       const entities = this.extractEntities(tree.rootNode, filePath, language);
 
+      // Parse doc comment examples as embedded snippets (Rust only)
+      if (language === "rust") {
+        this.analyzeRustDoctests(parser, entities, filePath);
+      }
+
       // Extract imports (language-aware)
       const imports = this.extractImports(tree.rootNode, language);
 
@@ -334,6 +396,24 @@ export class TreeSitterParser {
       // Extract function calls (language-aware)
       const calls = this.extractCalls(tree.rootNode, language);
 
+      // Extract impl blocks (Rust only)
+      const implementations =
+        language === "rust" ? this.extractRustImpls(tree.rootNode) : undefined;
+
+      // Extract TODO/FIXME marker comments
+      const todos = this.extractTodos(tree.rootNode);
+
+      // Extract string literals (opt-in)
+      const strings = this.config.indexStringLiterals
+        ? this.extractStringLiterals(tree.rootNode, entities)
+        : undefined;
+
+      // Extract the module's own documentation (Rust only)
+      const moduleDoc =
+        language === "rust" && this.config.extractDocumentation
+          ? this.extractRustModuleDoc(tree.rootNode)
+          : undefined;
+
       const parseTimeMs = performance.now() - startTime;
 
       this.logger.info(
@@ -345,6 +425,8 @@ export class TreeSitterParser {
           importCount: imports.length,
           exportCount: exports.length,
           callCount: calls.length,
+          todoCount: todos.length,
+          ...(strings && { stringCount: strings.length }),
           errorCount: errors.length,
         },
         "File parsed successfully"
@@ -357,6 +439,10 @@ export class TreeSitterParser {
         imports,
         exports,
         calls,
+        ...(implementations && { implementations }),
+        todos,
+        ...(strings && { strings }),
+        ...(moduleDoc && { moduleDoc }),
         parseTimeMs,
         errors,
         success: true,
@@ -376,7 +462,11 @@ export class TreeSitterParser {
         throw error;
       }
 
-      if (error instanceof LanguageNotSupportedError || error instanceof FileTooLargeError) {
+      if (
+        error instanceof LanguageNotSupportedError ||
+        error instanceof FileTooLargeError ||
+        error instanceof FileTooLongError
+      ) {
         throw error;
       }
 
@@ -449,7 +539,12 @@ export class TreeSitterParser {
     const isRuby = language === "ruby";
     const isPhp = language === "php";
 
-    const processNode = (node: Node, isExported: boolean = false): void => {
+    const processNode = (
+      node: Node,
+      isExported: boolean = false,
+      parentName?: string,
+      parentKind?: SymbolKind
+    ): void => {
       // Use language-specific node type mapping
       let nodeTypeMapping: Record<string, EntityType>;
       if (isPython) {
@@ -478,16 +573,31 @@ export class TreeSitterParser {
         // Extract the actual definition from inside the decorated_definition
         const definition = node.childForFieldName("definition");
         if (definition) {
-          processNode(definition, isExported);
+          processNode(definition, isExported, parentName, parentKind);
         }
         return; // Don't recurse into already processed decorated definition
       }
 
+      // Descendants of an entity are nested in it
+      let childParentName = parentName;
+      let childParentKind = parentKind;
+
       if (entityType) {
         try {
           const entity = this.extractEntity(node, filePath, entityType, isExported, language);
           if (entity) {
+            if (parentName) {
+              entity.parentName = parentName;
+            }
+            entity.kind = resolveSymbolKind(entityType, language, {
+              nodeType: isGo ? this.goDeclaredTypeNodeType(node) : node.type,
+              parentKind,
+            });
             entities.push(entity);
+            if (entity.name !== "<anonymous>") {
+              childParentName = entity.name;
+              childParentKind = entity.kind;
+            }
           }
         } catch (error) {
           this.logger.warn(
@@ -507,16 +617,25 @@ export class TreeSitterParser {
         // Process the declaration inside the export
         const declaration = node.childForFieldName("declaration");
         if (declaration) {
-          processNode(declaration, true);
+          processNode(declaration, true, parentName, parentKind);
         }
         return; // Don't recurse into already processed export
       }
 
+      // Items of a Rust impl block belong to the implementing type
+      if (isRust && node.type === "impl_item") {
+        const typeNode = node.childForFieldName("type");
+        if (typeNode) {
+          childParentName = this.rustBaseTypeName(typeNode.text);
+          childParentKind = "class";
+        }
+      }
+
       // Process children
       for (let i = 0; i < node.childCount; i++) {
         const child = node.child(i);
         if (child) {
-          processNode(child, isExported);
+          processNode(child, isExported, childParentName, childParentKind);
         }
       }
     };
@@ -586,6 +705,16 @@ export class TreeSitterParser {
     } else {
       metadata = this.extractMetadata(node, entityType);
     }
+    if (metadata.documentation) {
+      const doc = extractDocText(
+        language,
+        metadata.documentation,
+        this.config.docCommentStyles[language]
+      );
+      if (doc) {
+        metadata.doc = doc;
+      }
+    }
 
     // For Go, determine export status by naming convention (uppercase first letter)
     let finalIsExported = isExported;
@@ -615,6 +744,8 @@ export class TreeSitterParser {
       finalIsExported = true;
     }
 
+    const nameNode = name ? this.findNameNode(node, name) : null;
+
     return {
       type: entityType,
       name: name ?? "<anonymous>",
@@ -623,6 +754,7 @@ export class TreeSitterParser {
       lineEnd: node.endPosition.row + 1,
       columnStart: node.startPosition.column,
       columnEnd: node.endPosition.column,
+      ...(nameNode && { nameRange: this.sourceRange(nameNode) }),
       isExported: finalIsExported,
       metadata,
     };
@@ -878,6 +1010,37 @@ export class TreeSitterParser {
     return params;
   }
 
+  /**
+   * Last source row a node occupies. Some grammars (tree-sitter-rust line
+   * doc comments) include the trailing newline, so a node ending at column 0
+   * of a later row really ends on the row before.
+   */
+  private lastRowOf(node: Node): number {
+    const { row, column } = node.endPosition;
+    return column === 0 && row > node.startPosition.row ? row - 1 : row;
+  }
+
+  /**
+   * Whether a comment (or attribute) is close enough to the following
+   * declaration, or to the next collected comment starting at `boundaryRow`,
+   * to be associated with it. At most `maxDocCommentBlankLines` blank lines
+   * may separate the two.
+   */
+  private isWithinDocCommentDistance(node: Node, boundaryRow: number): boolean {
+    const blankLines = boundaryRow - this.lastRowOf(node) - 1;
+    return blankLines <= this.config.maxDocCommentBlankLines;
+  }
+
+  /**
+   * Whether a comment trails other code on the same line (e.g.
+   * `fn a() {} // note`), in which case it belongs to that code rather than
+   * to the declaration that follows.
+   */
+  private isTrailingComment(comment: Node): boolean {
+    const prev = comment.previousSibling;
+    return prev !== null && this.lastRowOf(prev) === comment.startPosition.row;
+  }
+
   /**
    * Extract JSDoc documentation.
    */
@@ -963,12 +1126,16 @@ export class TreeSitterParser {
       }
     }
 
-    // Extract base classes for Python classes
+    // Extract base classes for Python classes: the first is the superclass,
+    // further bases (mixins, ABCs, protocols) are recorded as implemented
     if (entityType === "class") {
-      const superclass = this.extractPythonSuperclass(node);
+      const [superclass, ...otherBases] = this.extractPythonBaseClasses(node);
       if (superclass) {
         metadata.extends = superclass;
       }
+      if (otherBases.length > 0) {
+        metadata.implements = otherBases;
+      }
     }
 
     // Extract docstring as documentation
@@ -1108,18 +1275,17 @@ export class TreeSitterParser {
   }
 
   /**
-   * Extract superclass from Python class definition.
+   * Extract base classes from Python class definition, in declaration order.
+   * Keyword arguments such as `metaclass=ABCMeta` are skipped.
    */
-  private extractPythonSuperclass(node: Node): string | null {
+  private extractPythonBaseClasses(node: Node): string[] {
     const superclassNode = node.childForFieldName("superclasses");
-    if (superclassNode) {
-      // Get the first base class (primary inheritance)
-      const firstBase = this.findFirstChild(superclassNode, ["identifier", "attribute"]);
-      if (firstBase) {
-        return firstBase.text;
-      }
+    if (!superclassNode) {
+      return [];
     }
-    return null;
+    return superclassNode.namedChildren.flatMap((child) =>
+      child?.type === "identifier" || child?.type === "attribute" ? [child.text] : []
+    );
   }
 
   /**
@@ -1368,6 +1534,7 @@ export class TreeSitterParser {
       line: node.startPosition.row + 1,
       column: node.startPosition.column,
       callerName,
+      arguments: this.extractCallArguments(node),
     };
   }
 
@@ -1997,6 +2164,21 @@ export class TreeSitterParser {
     return null;
   }
 
+  /**
+   * Get the node type that decides the symbol kind of a Go entity.
+   *
+   * A `type_declaration` covers structs, interfaces and other named types, so
+   * for those the node type of the declared type (e.g. `interface_type`) is
+   * returned instead.
+   */
+  private goDeclaredTypeNodeType(node: Node): string {
+    if (node.type !== "type_declaration") {
+      return node.type;
+    }
+    const typeSpec = this.findFirstChild(node, ["type_spec"]);
+    return typeSpec?.childForFieldName("type")?.type ?? node.type;
+  }
+
   /**
    * Extract metadata from a Go entity node.
    */
@@ -2156,12 +2338,21 @@ export class TreeSitterParser {
     const docLines: string[] = [];
     let prevSibling = node.previousSibling;
 
+    let boundaryRow = node.startPosition.row;
+
     // Collect consecutive comment lines
     while (prevSibling) {
       if (prevSibling.type === "comment") {
+        if (
+          !this.isWithinDocCommentDistance(prevSibling, boundaryRow) ||
+          this.isTrailingComment(prevSibling)
+        ) {
+          break;
+        }
         // Go uses // for comments
         const text = prevSibling.text;
         docLines.unshift(text);
+        boundaryRow = prevSibling.startPosition.row;
       } else if (prevSibling.type !== "\n") {
         // Stop at non-comment, non-newline
         break;
@@ -2755,6 +2946,46 @@ export class TreeSitterParser {
 
   // ==================== Helper Methods ====================
 
+  /**
+   * Get the source range of a node.
+   */
+  private sourceRange(node: Node): SourceRange {
+    return {
+      lineStart: node.startPosition.row + 1,
+      lineEnd: node.endPosition.row + 1,
+      columnStart: node.startPosition.column,
+      columnEnd: node.endPosition.column,
+    };
+  }
+
+  /**
+   * Find the identifier that spells an entity's name.
+   *
+   * Prefers the grammar's `name` field, otherwise takes the first leaf below
+   * the node whose text is the name (variable declarators, Go type specs, C
+   * declarators, ...).
+   */
+  private findNameNode(node: Node, name: string): Node | null {
+    const field = node.childForFieldName("name");
+    if (field?.text === name) {
+      return field;
+    }
+
+    const visit = (current: Node): Node | null => {
+      if (current.childCount === 0) {
+        return current.text === name ? current : null;
+      }
+      for (const child of current.children) {
+        const found = child ? visit(child) : null;
+        if (found) {
+          return found;
+        }
+      }
+      return null;
+    };
+    return visit(node);
+  }
+
   /**
    * Find the first child of specific types.
    */
@@ -2969,9 +3200,35 @@ export class TreeSitterParser {
       line: node.startPosition.row + 1,
       column: node.startPosition.column,
       callerName,
+      arguments: this.extractCallArguments(node),
     };
   }
 
+  /**
+   * Extract the source text of each argument of a call node.
+   *
+   * Reads the `arguments` field shared by the JavaScript, TypeScript, Python
+   * and Rust grammars; comments between arguments are skipped.
+   *
+   * @param node - The call node
+   * @returns Argument texts in order, empty when the call has no argument list
+   */
+  private extractCallArguments(node: Node): string[] {
+    const argumentsNode = node.childForFieldName("arguments");
+    if (!argumentsNode) {
+      return [];
+    }
+
+    const texts: string[] = [];
+    for (let i = 0; i < argumentsNode.namedChildCount; i++) {
+      const child = argumentsNode.namedChild(i);
+      if (child && !child.type.includes("comment")) {
+        texts.push(child.text);
+      }
+    }
+    return texts;
+  }
+
   /**
    * Extract the name and expression from a call target.
    *
@@ -3120,6 +3377,15 @@ export class TreeSitterParser {
     return false;
   }
 
+  /**
+   * Extract the visibility modifier of a Rust item or `use` declaration,
+   * normalized (e.g., "pub(crate)"), or undefined when it has none.
+   */
+  private extractRustVisibility(node: Node): string | undefined {
+    const modifier = node.namedChildren.find((child) => child?.type === "visibility_modifier");
+    return modifier ? normalizeRustVisibility(modifier.text) : undefined;
+  }
+
   /**
    * Extract metadata from a Rust entity node.
    */
@@ -3141,6 +3407,92 @@ export class TreeSitterParser {
 
       // Check for async fn
       metadata.isAsync = this.isRustAsync(node);
+
+      const unsafeBlocks = this.extractRustUnsafeBlocks(node);
+      if (unsafeBlocks.length > 0) {
+        metadata.unsafeBlocks = unsafeBlocks;
+      }
+
+      const complexity = rustComplexity(node);
+      if (complexity !== undefined) {
+        metadata.complexity = complexity;
+      }
+
+      const structure = rustStructuralFingerprint(node);
+      if (structure) {
+        metadata.structure = structure;
+      }
+
+      const locals = rustLocalBindings(node);
+      if (locals.length > 0) {
+        metadata.locals = locals;
+      }
+    }
+
+    if ((entityType === "function" || entityType === "interface") && this.isRustUnsafe(node)) {
+      metadata.isUnsafe = true;
+    }
+
+    const attributes = this.extractRustAttributes(node);
+    if (attributes.length > 0) {
+      metadata.attributes = attributes;
+    }
+
+    const cfg = cfgConditionFromAttributes([
+      ...this.extractEnclosingRustAttributes(node),
+      ...attributes,
+    ]);
+    if (cfg !== undefined) {
+      metadata.cfg = cfg;
+    }
+
+    const visibility = this.extractRustVisibility(node);
+    if (visibility !== undefined) {
+      metadata.visibility = visibility;
+    }
+
+    if (entityType === "function") {
+      const container = node.parent?.parent;
+      const containerType = container?.type;
+      const entryPoint = detectRustEntryPoint(
+        node.childForFieldName("name")?.text ?? "",
+        attributes,
+        containerType === "impl_item" || containerType === "trait_item"
+      );
+      if (entryPoint) {
+        metadata.entryPoint = entryPoint;
+      }
+
+      const implementedTrait =
+        containerType === "impl_item" ? container?.childForFieldName("trait") : null;
+      if (implementedTrait) {
+        metadata.implementedTrait = implementedTrait.text;
+      }
+    }
+
+    if (entityType === "interface") {
+      const supertraits = this.extractRustSupertraits(node);
+      if (supertraits.length > 0) {
+        metadata.supertraits = supertraits;
+      }
+    }
+
+    if (node.type === "const_item" || node.type === "static_item") {
+      const valueNode = node.childForFieldName("value");
+      if (valueNode) {
+        metadata.valueText = valueNode.text;
+        const value = rustLiteralValue(valueNode);
+        if (value) {
+          metadata.value = value;
+        }
+      }
+    }
+
+    if (entityType === "enum") {
+      const variants = this.extractRustEnumVariants(node);
+      if (variants.length > 0) {
+        metadata.variants = variants;
+      }
     }
 
     // Extract type parameters (generics) for structs, enums, traits, and functions
@@ -3154,34 +3506,167 @@ export class TreeSitterParser {
       if (typeParams.length > 0) {
         metadata.typeParameters = typeParams;
       }
+
+      const whereNode = node.children.find((child) => child?.type === "where_clause");
+      if (whereNode) {
+        metadata.whereClause = whereNode.text;
+      }
     }
 
-    // Extract documentation (Rust doc comments)
+    // Extract documentation (Rust doc comments) and its code examples
     if (this.config.extractDocumentation) {
-      const doc = this.extractRustDocumentation(node);
-      if (doc) {
-        metadata.documentation = doc;
+      const comments = this.collectRustDocComments(node);
+      if (comments.length > 0) {
+        metadata.documentation = comments.map((comment) => comment.text).join("\n");
+
+        const doctests = this.extractRustDoctests(comments);
+        if (doctests.length > 0) {
+          metadata.doctests = doctests;
+        }
       }
     }
 
     return metadata;
   }
 
+  /**
+   * Extract the outer attributes (`#[...]`) applied to a Rust item.
+   *
+   * Attributes are sibling nodes preceding the item, possibly interleaved
+   * with comments. Returns the attribute contents in source order with
+   * whitespace runs collapsed, e.g. `#[derive(Debug,\n Clone)]` becomes
+   * "derive(Debug, Clone)".
+   */
+  private extractRustAttributes(node: Node): string[] {
+    const attributes: string[] = [];
+    let prevSibling = node.previousSibling;
+
+    while (prevSibling) {
+      if (prevSibling.type === "attribute_item") {
+        const attribute = prevSibling.namedChildren.find((child) => child?.type === "attribute");
+        if (attribute) {
+          attributes.unshift(attribute.text.replace(/\s+/g, " ").trim());
+        }
+      } else if (prevSibling.type !== "line_comment" && prevSibling.type !== "block_comment") {
+        break;
+      }
+      prevSibling = prevSibling.previousSibling;
+    }
+
+    return attributes;
+  }
+
+  /**
+   * Extract the outer attributes of the impls, traits and modules enclosing
+   * a Rust item, outermost first.
+   */
+  private extractEnclosingRustAttributes(node: Node): string[] {
+    const attributes: string[] = [];
+    for (let ancestor = node.parent; ancestor; ancestor = ancestor.parent) {
+      if (
+        ancestor.type === "impl_item" ||
+        ancestor.type === "trait_item" ||
+        ancestor.type === "mod_item"
+      ) {
+        attributes.unshift(...this.extractRustAttributes(ancestor));
+      }
+    }
+    return attributes;
+  }
+
+  /**
+   * Extract the variants of a Rust enum with their explicit discriminants.
+   *
+   * C-like variants may assign a value (`Red = 1`, `Flag = 1 << 3`); the
+   * expression is kept as written since it need not be a plain literal.
+   */
+  private extractRustEnumVariants(node: Node): EnumVariant[] {
+    const body = node.childForFieldName("body");
+    if (!body) {
+      return [];
+    }
+
+    const variants: EnumVariant[] = [];
+    for (const child of body.namedChildren) {
+      if (child?.type !== "enum_variant") continue;
+
+      const name = child.childForFieldName("name")?.text;
+      if (!name) continue;
+
+      const value = child.childForFieldName("value");
+      variants.push({
+        name,
+        ...(value && { discriminant: value.text }),
+        line: child.startPosition.row + 1,
+        range: this.sourceRange(child),
+      });
+    }
+
+    return variants;
+  }
+
   /**
    * Check if a Rust function is async.
+   *
+   * The keyword sits inside `function_modifiers` alongside any others
+   * (`async unsafe fn`, `const async fn`). The declared return type of an
+   * async function is the resolved type; the future wrapping it is implicit.
    */
   private isRustAsync(node: Node): boolean {
-    // Look for "async" keyword before "fn"
-    for (let i = 0; i < node.childCount; i++) {
-      const child = node.child(i);
-      if (child?.type === "fn") {
-        break; // Stop when we reach "fn" keyword
-      }
-      if (child?.text === "async") {
-        return true;
+    return node.children.some(
+      (child) =>
+        child?.type === "function_modifiers" &&
+        child.children.some((modifier) => modifier?.type === "async")
+    );
+  }
+
+  /**
+   * Check if a Rust function, trait or impl is declared `unsafe`.
+   *
+   * Functions carry the keyword inside `function_modifiers`; traits and
+   * impls carry it as a direct child.
+   */
+  private isRustUnsafe(node: Node): boolean {
+    return node.children.some(
+      (child) =>
+        child?.type === "unsafe" ||
+        (child?.type === "function_modifiers" &&
+          child.children.some((modifier) => modifier?.type === "unsafe"))
+    );
+  }
+
+  /**
+   * Find the `unsafe { ... }` blocks in a Rust function body.
+   *
+   * Nested function items are skipped because their blocks are recorded on
+   * them. Closures are not separate entities, so blocks inside a closure
+   * count toward the enclosing function. Blocks nested in another unsafe
+   * block are covered by the outer span and not reported again.
+   */
+  private extractRustUnsafeBlocks(node: Node): LineSpan[] {
+    const blocks: LineSpan[] = [];
+
+    const visit = (current: Node): void => {
+      for (const child of current.children) {
+        if (!child || child.type === "function_item") {
+          continue;
+        }
+        if (child.type === "unsafe_block") {
+          blocks.push({
+            lineStart: child.startPosition.row + 1,
+            lineEnd: child.endPosition.row + 1,
+          });
+        } else {
+          visit(child);
+        }
       }
+    };
+
+    const body = node.childForFieldName("body");
+    if (body) {
+      visit(body);
     }
-    return false;
+    return blocks;
   }
 
   /**
@@ -3286,46 +3771,445 @@ export class TreeSitterParser {
   }
 
   /**
-   * Extract Rust doc comments from preceding comments.
+   * Extract the supertraits of a Rust trait.
+   *
+   * Handles:
+   * - Bound lists: trait Pet: Animal + Clone {}
+   * - Paths and generics: trait Shape: std::fmt::Debug + From<u8> {}
+   * - Self predicates: trait Loud where Self: Animal {}
+   *
+   * Lifetime bounds (`trait Handle: 'static`) and `?Sized` relaxations are not
+   * supertraits and are skipped.
+   */
+  private extractRustSupertraits(node: Node): string[] {
+    const supertraits: string[] = [];
+    const add = (bound: string): void => {
+      const normalized = bound.replace(/\s+/g, " ").trim();
+      if (/^[^'?]/.test(normalized) && !supertraits.includes(normalized)) {
+        supertraits.push(normalized);
+      }
+    };
+
+    const boundsNode = node.childForFieldName("bounds");
+    for (const child of boundsNode?.namedChildren ?? []) {
+      if (child && child.type !== "lifetime" && child.type !== "removed_trait_bound") {
+        add(child.text);
+      }
+    }
+
+    const selfBounds = parseGenericBounds([], this.extractRustWhereClause(node)).find(
+      (bound) => bound.name === "Self"
+    );
+    selfBounds?.bounds.forEach(add);
+
+    return supertraits;
+  }
+
+  /**
+   * Extract impl blocks from a Rust parse tree.
+   *
+   * Handles:
+   * - Inherent impls: impl Point {}
+   * - Trait impls: impl Animal for Dog {}
+   * - Conditional impls: impl<T> Animal for Wrapper<T> where T: Animal {}
+   * - Blanket impls: impl<T: Display> ToText for T {}
+   * - Negative impls: impl !Send for Handle {}
+   */
+  private extractRustImpls(root: Node): ImplEdge[] {
+    const impls: ImplEdge[] = [];
+
+    const processNode = (node: Node): void => {
+      if (node.type === "impl_item") {
+        const typeNode = node.childForFieldName("type");
+        if (typeNode) {
+          const traitNode = node.childForFieldName("trait");
+          const impl: ImplEdge = {
+            typeName: typeNode.text,
+            typeParameters: this.extractRustTypeParameters(node),
+            whereClause: this.extractRustWhereClause(node),
+            isNegative: node.children.some((child) => child?.type === "!"),
+            isUnsafe: this.isRustUnsafe(node),
+            line: node.startPosition.row + 1,
+            range: this.sourceRange(node),
+          };
+          if (traitNode) {
+            impl.traitName = traitNode.text;
+          }
+          impls.push(impl);
+        }
+      }
+
+      // Impls can be nested in modules and function bodies
+      for (let i = 0; i < node.childCount; i++) {
+        const child = node.child(i);
+        if (child) {
+          processNode(child);
+        }
+      }
+    };
+
+    processNode(root);
+    return impls;
+  }
+
+  /**
+   * Extract marker comments matching the configured `todoTags`.
+   *
+   * Comment node types differ by grammar (`comment`, `line_comment`,
+   * `block_comment`), so any node type ending in "comment" is scanned. Each
+   * line of a comment is matched separately, so a block comment can hold
+   * several markers. Accepts `TAG:`, `TAG(owner):`, and bare `TAG message`.
+   */
+  private extractTodos(root: Node): TodoComment[] {
+    const todos: TodoComment[] = [];
+    if (this.config.todoTags.length === 0) {
+      return todos;
+    }
+
+    const tags = this.config.todoTags.map((tag) => tag.replace(/[.*+?^${}()|[\]\\]/g, "\\$&"));
+    const pattern = new RegExp(`(?:^|[^\\w])(${tags.join("|")})(?:\\([^)]*\\))?(?![\\w]):?(.*)$`);
+
+    const processNode = (node: Node): void => {
+      if (node.type.endsWith("comment")) {
+        const lines = node.text.split("\n");
+        for (let i = 0; i < lines.length; i++) {
+          const match = pattern.exec(lines[i] ?? "");
+          if (match?.[1]) {
+            todos.push({
+              tag: match[1],
+              message: (match[2] ?? "").replace(/\*+\/\s*$/, "").trim(),
+              line: node.startPosition.row + i + 1,
+            });
+          }
+        }
+        return;
+      }
+
+      for (let i = 0; i < node.childCount; i++) {
+        const child = node.child(i);
+        if (child) {
+          processNode(child);
+        }
+      }
+    };
+
+    processNode(root);
+    return todos;
+  }
+
+  /**
+   * Extract string literals with the innermost entity enclosing each.
+   *
+   * Import sources and empty literals are skipped. Literals nested inside
+   * another literal (e.g. in a template substitution) are not reported
+   * separately.
+   */
+  private extractStringLiterals(root: Node, entities: CodeEntity[]): StringLiteral[] {
+    const literals: StringLiteral[] = [];
+
+    const processNode = (node: Node): void => {
+      if (STRING_LITERAL_NODE_TYPES.has(node.type)) {
+        const parentType = node.parent?.type ?? "";
+        const value = this.stripStringDelimiters(node.text);
+        if (value.length > 0 && !parentType.includes("import")) {
+          const line = node.startPosition.row + 1;
+          const enclosingSymbol = this.findEnclosingSymbol(entities, line);
+          literals.push({
+            value: value.slice(0, MAX_STRING_LITERAL_LENGTH),
+            line,
+            column: node.startPosition.column,
+            ...(enclosingSymbol !== undefined && { enclosingSymbol }),
+          });
+        }
+        return;
+      }
+
+      for (let i = 0; i < node.childCount; i++) {
+        const child = node.child(i);
+        if (child) {
+          processNode(child);
+        }
+      }
+    };
+
+    processNode(root);
+    return literals;
+  }
+
+  /**
+   * Remove quotes and literal prefixes (`r#"`, `b"`, `f'`, `@"`, triple quotes).
+   */
+  private stripStringDelimiters(text: string): string {
+    return text
+      .replace(/^[A-Za-z0-9_@$]{0,3}#*(?:"""|'''|["'`])/, "")
+      .replace(/(?:"""|'''|["'`])#*$/, "");
+  }
+
+  /**
+   * Find the innermost entity whose line range contains a line.
+   *
+   * @returns The entity name qualified with its parent, or undefined at file scope
+   */
+  private findEnclosingSymbol(entities: CodeEntity[], line: number): string | undefined {
+    let innermost: CodeEntity | undefined;
+    for (const entity of entities) {
+      if (entity.lineStart > line || entity.lineEnd < line) {
+        continue;
+      }
+      const span = entity.lineEnd - entity.lineStart;
+      if (!innermost || span < innermost.lineEnd - innermost.lineStart) {
+        innermost = entity;
+      }
+    }
+    if (!innermost) {
+      return undefined;
+    }
+    return innermost.parentName !== undefined
+      ? `${innermost.parentName}.${innermost.name}`
+      : innermost.name;
+  }
+
+  /**
+   * Strip generic arguments and references from a Rust type as written.
+   *
+   * e.g. "Wrapper<T>" -> "Wrapper", "&'a mut Buffer" -> "Buffer"
+   */
+  private rustBaseTypeName(typeText: string): string {
+    const withoutGenerics = typeText.split("<")[0] ?? typeText;
+    return withoutGenerics.replace(/^&\s*('\w+\s+)?(mut\s+)?/, "").trim();
+  }
+
+  /**
+   * Extract where-clause predicates from a Rust item.
+   *
+   * Returns each predicate with whitespace collapsed, e.g. "K: Eq + std::hash::Hash".
+   */
+  private extractRustWhereClause(node: Node): string[] {
+    const predicates: string[] = [];
+
+    const whereNode = node.children.find((child) => child?.type === "where_clause");
+    if (!whereNode) {
+      return predicates;
+    }
+
+    for (let i = 0; i < whereNode.childCount; i++) {
+      const child = whereNode.child(i);
+      if (child?.type === "where_predicate") {
+        predicates.push(child.text.replace(/\s+/g, " ").trim());
+      }
+    }
+
+    return predicates;
+  }
+
+  /**
+   * Extract the documentation of a Rust file's module: the inner doc comments
+   * (`//!`, `/*! ... *\/`) at the top of the file, before its first item.
+   * Inner attributes such as `#![deny(missing_docs)]` may be interleaved.
+   */
+  private extractRustModuleDoc(root: Node): string | undefined {
+    const comments: string[] = [];
+    for (const child of root.children) {
+      if (!child) continue;
+      if (child.type === "line_comment" || child.type === "block_comment") {
+        if (classifyDocComment(child.text) === "inner_doc") {
+          comments.push(child.text.trimEnd());
+        }
+      } else if (child.type !== "inner_attribute_item") {
+        break;
+      }
+    }
+    if (comments.length === 0) {
+      return undefined;
+    }
+    return extractDocText("rust", comments.join("\n"), this.config.docCommentStyles.rust);
+  }
+
+  /**
+   * Collect the Rust doc comments preceding an item, in source order.
    *
    * In Rust, doc comments are:
    * - /// for outer doc comments (document the following item)
    * - //! for inner doc comments (document the enclosing item)
    */
-  private extractRustDocumentation(node: Node): string | null {
-    const docLines: string[] = [];
+  private collectRustDocComments(node: Node): Node[] {
+    const docComments: Node[] = [];
     let prevSibling = node.previousSibling;
+    let boundaryRow = node.startPosition.row;
 
-    // Collect consecutive doc comment lines
+    // Collect consecutive doc comment lines, looking past attributes
     while (prevSibling) {
+      if (prevSibling.type === "\n") {
+        prevSibling = prevSibling.previousSibling;
+        continue;
+      }
+      if (!this.isWithinDocCommentDistance(prevSibling, boundaryRow)) {
+        break;
+      }
+
       if (prevSibling.type === "line_comment") {
         const text = prevSibling.text;
         // Check if it's a doc comment (starts with /// or //!)
-        if (text.startsWith("///") || text.startsWith("//!")) {
-          docLines.unshift(text);
+        if (
+          (text.startsWith("///") || text.startsWith("//!")) &&
+          !this.isTrailingComment(prevSibling)
+        ) {
+          docComments.unshift(prevSibling);
         } else {
-          // Stop at regular comment
+          // Stop at regular or trailing comment
           break;
         }
       } else if (prevSibling.type === "block_comment") {
         // Block doc comments /** ... */ or /*! ... */
         const text = prevSibling.text;
-        if (text.startsWith("/**") || text.startsWith("/*!")) {
-          docLines.unshift(text);
+        if (
+          (text.startsWith("/**") || text.startsWith("/*!")) &&
+          !this.isTrailingComment(prevSibling)
+        ) {
+          docComments.unshift(prevSibling);
         }
         break; // Block comments don't chain like line comments
-      } else if (prevSibling.type !== "\n" && prevSibling.type !== "attribute_item") {
-        // Stop at non-comment, non-newline, non-attribute
+      } else if (prevSibling.type !== "attribute_item") {
+        // Stop at non-comment, non-attribute
         break;
       }
+      boundaryRow = prevSibling.startPosition.row;
       prevSibling = prevSibling.previousSibling;
     }
 
-    if (docLines.length > 0) {
-      return docLines.join("\n");
+    return docComments;
+  }
+
+  /**
+   * Extract the Rust code examples from an item's doc comments.
+   *
+   * Fenced blocks are classified by their info string as rustdoc would;
+   * blocks in another language are skipped. `calls` and `definedSymbols` are
+   * filled in by analyzeRustDoctests once the snippets are parsed.
+   */
+  private extractRustDoctests(comments: Node[]): Doctest[] {
+    const lines = comments.flatMap((comment) =>
+      docCommentLines(comment.text, comment.startPosition.row + 1)
+    );
+
+    const doctests: Doctest[] = [];
+    for (const block of findFencedCodeBlocks(lines)) {
+      const fence = classifyRustFence(block.info);
+      if (!fence.isRust) {
+        continue;
+      }
+      doctests.push({
+        info: block.info,
+        mode: fence.mode,
+        code: unhideDoctestLines(block.code),
+        lineStart: block.lineStart,
+        lineEnd: block.lineEnd,
+        calls: [],
+        definedSymbols: [],
+      });
     }
 
-    return null;
+    return doctests;
+  }
+
+  /**
+   * Parse the doc comment examples of Rust entities as embedded snippets and
+   * record what each example calls and defines.
+   *
+   * rustdoc wraps an example without `fn main` in one, so top-level
+   * statements are examined directly. Calls inside macro arguments such as
+   * `assert_eq!(add(1, 2), 3)` are included, since examples are mostly
+   * written that way.
+   */
+  private analyzeRustDoctests(parser: Parser, entities: CodeEntity[], filePath: string): void {
+    for (const entity of entities) {
+      for (const doctest of entity.metadata?.doctests ?? []) {
+        const snippet = this.parseSnippet(parser, doctest.code, "rust", filePath);
+        if (!snippet) {
+          continue;
+        }
+        doctest.calls = [...new Set(snippet.calls.map((call) => call.calledName))];
+        doctest.definedSymbols = snippet.entities
+          .filter((defined) => !defined.parentName)
+          .map((defined) => defined.name);
+      }
+    }
+  }
+
+  /**
+   * Parse a code snippet embedded in a file, such as a doc comment example.
+   *
+   * Uses the parser already set to the file's language. The snippet tree is
+   * freed once its entities and calls are extracted.
+   *
+   * @returns Entities and calls of the snippet, or null if it could not be parsed
+   */
+  private parseSnippet(
+    parser: Parser,
+    content: string,
+    language: TreeSitterLanguage,
+    filePath: string
+  ): { entities: CodeEntity[]; calls: CallInfo[] } | null {
+    const tree = parser.parse(content);
+    if (!tree) {
+      return null;
+    }
+
+    try {
+      const calls = this.extractCalls(tree.rootNode, language);
+      if (language === "rust") {
+        calls.push(...this.extractRustMacroArgumentCalls(tree.rootNode));
+      }
+      return { entities: this.extractEntities(tree.rootNode, filePath, language), calls };
+    } catch (error) {
+      this.logger.warn({ err: error, filePath }, "Failed to analyze embedded snippet");
+      return null;
+    } finally {
+      tree.delete();
+    }
+  }
+
+  /**
+   * Extract calls written inside Rust macro arguments.
+   *
+   * Macro arguments are unparsed token trees, so a call is recognized as an
+   * identifier directly followed by a parenthesized token tree, e.g. `add` in
+   * `assert_eq!(add(1, 2), 3)`. Nested macros (`vec![...]`) are not calls.
+   */
+  private extractRustMacroArgumentCalls(root: Node): CallInfo[] {
+    const calls: CallInfo[] = [];
+
+    const processNode = (node: Node): void => {
+      if (node.type === "token_tree") {
+        for (let i = 0; i < node.childCount - 1; i++) {
+          const child = node.child(i);
+          const next = node.child(i + 1);
+          if (
+            child?.type === "identifier" &&
+            next?.type === "token_tree" &&
+            next.child(0)?.type === "("
+          ) {
+            calls.push({
+              calledName: child.text,
+              calledExpression: child.text,
+              isAsync: false,
+              line: child.startPosition.row + 1,
+              column: child.startPosition.column,
+            });
+          }
+        }
+      }
+
+      for (let i = 0; i < node.childCount; i++) {
+        const child = node.child(i);
+        if (child) {
+          processNode(child);
+        }
+      }
+    };
+
+    processNode(root);
+    return calls;
   }
 
   /**
@@ -3397,11 +4281,18 @@ export class TreeSitterParser {
       if (pathNode) {
         infos.push(this.createRustImportInfo(pathNode.text, node.startPosition.row + 1));
       }
-      return infos;
+    } else {
+      // Extract all imports from the use tree
+      this.extractRustUseTree(useTree, "", infos, node.startPosition.row + 1);
     }
 
-    // Extract all imports from the use tree
-    this.extractRustUseTree(useTree, "", infos, node.startPosition.row + 1);
+    // `pub use` re-exports the imported names
+    const visibility = this.extractRustVisibility(node);
+    if (visibility !== undefined) {
+      for (const info of infos) {
+        info.visibility = visibility;
+      }
+    }
 
     return infos;
   }
@@ -3582,6 +4473,8 @@ export class TreeSitterParser {
       return null;
     }
 
+    const typeArguments = this.extractRustTurbofishArguments(functionNode);
+
     // Rust doesn't have async/await at call site (uses .await suffix on futures)
     return {
       calledName: callTarget.name,
@@ -3590,9 +4483,46 @@ export class TreeSitterParser {
       line: node.startPosition.row + 1,
       column: node.startPosition.column,
       callerName,
+      ...(typeArguments.length > 0 && { typeArguments }),
+      arguments: this.extractCallArguments(node),
     };
   }
 
+  /**
+   * Extract explicit turbofish type arguments from a Rust call target.
+   *
+   * Handles `foo::<T>()` and `x.collect::<Vec<_>>()` (generic_function) as well
+   * as `Vec::<u8>::new()` (turbofish on the path of a scoped identifier). An
+   * argument on the function itself takes precedence over one on its path.
+   *
+   * @returns Type argument texts, empty when the call has no turbofish
+   */
+  private extractRustTurbofishArguments(node: Node): string[] {
+    let typeArgumentsNode: Node | null = null;
+
+    if (node.type === "generic_function") {
+      typeArgumentsNode = node.childForFieldName("type_arguments");
+    } else if (node.type === "scoped_identifier") {
+      const pathNode = node.childForFieldName("path");
+      if (pathNode?.type === "generic_type_with_turbofish" || pathNode?.type === "generic_type") {
+        typeArgumentsNode = pathNode.childForFieldName("type_arguments");
+      }
+    }
+
+    if (!typeArgumentsNode) {
+      return [];
+    }
+
+    const typeArguments: string[] = [];
+    for (let i = 0; i < typeArgumentsNode.namedChildCount; i++) {
+      const child = typeArgumentsNode.namedChild(i);
+      if (child && child.type !== "line_comment" && child.type !== "block_comment") {
+        typeArguments.push(child.text);
+      }
+    }
+    return typeArguments;
+  }
+
   /**
    * Extract call target from Rust call expression.
    */
@@ -3924,9 +4854,18 @@ export class TreeSitterParser {
     const docLines: string[] = [];
     let prevSibling = node.previousSibling;
 
+    let boundaryRow = node.startPosition.row;
+
     // Collect consecutive comment lines
     while (prevSibling) {
       if (prevSibling.type === "comment") {
+        if (
+          !this.isWithinDocCommentDistance(prevSibling, boundaryRow) ||
+          this.isTrailingComment(prevSibling)
+        ) {
+          break;
+        }
+        boundaryRow = prevSibling.startPosition.row;
         const text = prevSibling.text;
         // Check for C-style doc comments (/** or /*!)
         if (text.startsWith("/**") || text.startsWith("/*!") || text.startsWith("/*")) {
@@ -4413,8 +5352,15 @@ export class TreeSitterParser {
     const comments: string[] = [];
     let prevSibling = node.previousSibling;
 
+    let boundaryRow = node.startPosition.row;
+
     // Collect preceding comment lines
-    while (prevSibling && prevSibling.type === "comment") {
+    while (
+      prevSibling?.type === "comment" &&
+      this.isWithinDocCommentDistance(prevSibling, boundaryRow) &&
+      !this.isTrailingComment(prevSibling)
+    ) {
+      boundaryRow = prevSibling.startPosition.row;
       const text = prevSibling.text;
       // Ruby comments start with #
       if (text.startsWith("#")) {
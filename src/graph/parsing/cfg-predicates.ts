@@ -0,0 +1,319 @@
+/**
+ * Conditional compilation predicates.
+ *
+ * Rust items gated by `#[cfg(...)]` only exist on some builds, and crates
+ * commonly define the same function once per platform:
+ *
+ * ```rust
+ * #[cfg(unix)]
+ * fn home_dir() -> PathBuf { ... }
+ *
+ * #[cfg(target_os = "windows")]
+ * fn home_dir() -> PathBuf { ... }
+ * ```
+ *
+ * The cfg attributes of an item (and of the impl, trait or module enclosing
+ * it) are combined into one condition, stored with the symbol, and evaluated
+ * against a target platform at query time:
+ *
+ * | Condition                              | `x86_64-unknown-linux-gnu` | `windows` |
+ * | -------------------------------------- | -------------------------- | --------- |
+ * | `unix`                                 | true                       | false     |
+ * | `target_os = "windows"`                | false                      | true      |
+ * | `all(unix, not(target_os = "macos"))`  | true                       | false     |
+ * | `feature = "serde"`                    | unknown                    | unknown   |
+ *
+ * Only target predicates are known for a platform; features, `test`,
+ * `debug_assertions` and the like evaluate to unknown, and a condition is
+ * only ruled out when it is false whatever their values.
+ *
+ * @module graph/parsing/cfg-predicates
+ */
+
+import { attributePath } from "./entry-points.js";
+
+/**
+ * Parsed cfg predicate
+ */
+export type CfgPredicate =
+  | { kind: "option"; name: string; value?: string }
+  | { kind: "all" | "any"; items: CfgPredicate[] }
+  | { kind: "not"; item: CfgPredicate };
+
+/**
+ * Target configuration a cfg condition is evaluated against. Unset fields
+ * are unknown, e.g. the OS of the `unix` platform.
+ */
+export interface TargetPlatform {
+  /** `target_os` (e.g., "linux", "macos", "windows", "none") */
+  os?: string;
+  /** `target_family` values; `unix` and `windows` are shorthand for these */
+  families?: string[];
+  /** `target_arch` (e.g., "x86_64", "aarch64", "wasm32") */
+  arch?: string;
+  /** `target_vendor` (e.g., "apple", "pc", "unknown") */
+  vendor?: string;
+  /** `target_env` (e.g., "gnu", "musl", "msvc", "") */
+  env?: string;
+  /** `target_pointer_width` (e.g., "64") */
+  pointerWidth?: string;
+}
+
+/**
+ * Operating systems of the `unix` target family
+ */
+const UNIX_OSES = new Set([
+  "linux",
+  "android",
+  "macos",
+  "ios",
+  "tvos",
+  "watchos",
+  "freebsd",
+  "netbsd",
+  "openbsd",
+  "dragonfly",
+  "solaris",
+  "illumos",
+  "haiku",
+  "redox",
+  "emscripten",
+]);
+
+/**
+ * Operating systems outside the `unix` and `windows` families
+ */
+const OTHER_OSES = new Set(["wasi", "fuchsia", "uefi", "none"]);
+
+/**
+ * Combine the cfg attributes of an item into one condition.
+ *
+ * @param attributes - Attributes as written inside `#[...]`, e.g. `cfg(unix)`
+ * @returns The canonical condition (several cfgs are joined with `all`), or
+ *   undefined when no attribute is a well-formed cfg
+ */
+export function cfgConditionFromAttributes(attributes: readonly string[]): string | undefined {
+  const predicates: CfgPredicate[] = [];
+  for (const attribute of attributes) {
+    if (attributePath(attribute) !== "cfg") continue;
+    const open = attribute.indexOf("(");
+    const predicate =
+      open !== -1 && attribute.endsWith(")")
+        ? parseCfgPredicate(attribute.slice(open + 1, -1))
+        : undefined;
+    if (predicate) {
+      predicates.push(predicate);
+    }
+  }
+
+  const [first, ...others] = predicates;
+  if (!first) {
+    return undefined;
+  }
+  return formatCfgPredicate(others.length === 0 ? first : { kind: "all", items: predicates });
+}
+
+/**
+ * Parse the predicate inside `cfg(...)`.
+ *
+ * @param text - Predicate text, e.g. `all(unix, target_arch = "x86_64")`
+ * @returns The predicate, or undefined when the text is not a well-formed predicate
+ */
+export function parseCfgPredicate(text: string): CfgPredicate | undefined {
+  const tokens = text.match(/"(?:[^"\\]|\\.)*"|[A-Za-z_][\w:]*|[(),=]|\S/g) ?? [];
+  let position = 0;
+
+  const parse = (): CfgPredicate | undefined => {
+    const name = tokens[position++];
+    if (name === undefined || !/^[A-Za-z_]/.test(name)) return undefined;
+
+    if (tokens[position] === "=") {
+      const value = tokens[position + 1];
+      if (value === undefined || !value.startsWith('"')) return undefined;
+      position += 2;
+      return { kind: "option", name, value: value.slice(1, -1) };
+    }
+    if (tokens[position] !== "(") {
+      return { kind: "option", name };
+    }
+    if (name !== "all" && name !== "any" && name !== "not") return undefined;
+
+    position++;
+    const items: CfgPredicate[] = [];
+    while (tokens[position] !== ")") {
+      const item = parse();
+      if (!item) return undefined;
+      items.push(item);
+      if (tokens[position] === ",") position++;
+      else if (tokens[position] !== ")") return undefined;
+    }
+    position++;
+
+    if (name === "not") {
+      const [item] = items;
+      return items.length === 1 && item ? { kind: "not", item } : undefined;
+    }
+    return { kind: name, items };
+  };
+
+  const predicate = parse();
+  return predicate && position === tokens.length ? predicate : undefined;
+}
+
+/**
+ * Write a predicate in canonical form: `name = "value"` options and
+ * comma-space separated lists.
+ */
+export function formatCfgPredicate(predicate: CfgPredicate): string {
+  switch (predicate.kind) {
+    case "option":
+      return predicate.value !== undefined
+        ? `${predicate.name} = "${predicate.value}"`
+        : predicate.name;
+    case "not":
+      return `not(${formatCfgPredicate(predicate.item)})`;
+    default:
+      return `${predicate.kind}(${predicate.items.map(formatCfgPredicate).join(", ")})`;
+  }
+}
+
+/**
+ * Parse a target platform: a target triple (`x86_64-pc-windows-msvc`,
+ * `aarch64-apple-darwin`, `wasm32-unknown-unknown`), an OS name (`linux`,
+ * `macos`, `windows`, ...) or a family (`unix`, `windows`, `wasm`).
+ *
+ * @returns The platform, or undefined when the text is not recognized
+ */
+export function parseTargetPlatform(spec: string): TargetPlatform | undefined {
+  const text = spec.trim().toLowerCase();
+  if (text === "unix") {
+    return { families: ["unix"] };
+  }
+  if (text === "wasm") {
+    return { families: ["wasm"] };
+  }
+  if (text === "windows" || UNIX_OSES.has(text) || OTHER_OSES.has(text)) {
+    return { os: text, families: familiesOf(text) };
+  }
+
+  const parts = text.split("-");
+  const [arch, vendor, ...rest] = parts;
+  if (parts.length < 3 || !arch || !vendor) {
+    return undefined;
+  }
+  // Bare-metal triples such as thumbv7em-none-eabihf name the OS second
+  const os = rest.map(tripleOs).find((name) => name !== undefined) ?? tripleOs(vendor);
+  if (os === undefined) {
+    return undefined;
+  }
+
+  const targetArch = normalizeArch(arch);
+  const envPart = rest[rest.length - 1];
+  const env = ["gnu", "musl", "msvc", "sgx", "uclibc"].find((name) => envPart?.startsWith(name));
+  const families = familiesOf(os);
+  if (targetArch.startsWith("wasm")) {
+    families.push("wasm");
+  }
+  return {
+    os,
+    families,
+    arch: targetArch,
+    vendor: vendor !== "unknown" && tripleOs(vendor) !== undefined ? "unknown" : vendor,
+    env: env ?? "",
+    pointerWidth: /64|s390x/.test(targetArch) ? "64" : "32",
+  };
+}
+
+/**
+ * Whether code gated by a cfg condition can be compiled for a platform.
+ *
+ * @param condition - Canonical condition from `cfgConditionFromAttributes`
+ * @param platform - Platform from `parseTargetPlatform`
+ * @returns False only when the condition is false for every value of the
+ *   predicates the platform does not determine
+ */
+export function isCfgSatisfiable(condition: string, platform: TargetPlatform): boolean {
+  const predicate = parseCfgPredicate(condition);
+  return predicate === undefined || evaluate(predicate, platform) !== false;
+}
+
+/**
+ * Evaluate a predicate in three-valued logic; undefined means unknown.
+ */
+function evaluate(predicate: CfgPredicate, platform: TargetPlatform): boolean | undefined {
+  switch (predicate.kind) {
+    case "not": {
+      const value = evaluate(predicate.item, platform);
+      return value === undefined ? undefined : !value;
+    }
+    case "all":
+    case "any": {
+      const values = predicate.items.map((item) => evaluate(item, platform));
+      const decisive = predicate.kind === "any";
+      if (values.includes(decisive)) return decisive;
+      return values.includes(undefined) ? undefined : !decisive;
+    }
+    case "option":
+      return evaluateOption(predicate.name, predicate.value, platform);
+  }
+}
+
+/**
+ * Evaluate a single option such as `unix` or `target_os = "linux"`
+ */
+function evaluateOption(
+  name: string,
+  value: string | undefined,
+  platform: TargetPlatform
+): boolean | undefined {
+  if (value === undefined) {
+    return name === "unix" || name === "windows" ? platform.families?.includes(name) : undefined;
+  }
+
+  const known = new Map([
+    ["target_os", platform.os],
+    ["target_arch", platform.arch],
+    ["target_vendor", platform.vendor],
+    ["target_env", platform.env],
+    ["target_pointer_width", platform.pointerWidth],
+  ]);
+  if (name === "target_family") {
+    return platform.families?.includes(value);
+  }
+  const actual = known.get(name);
+  return actual === undefined ? undefined : actual === value;
+}
+
+/**
+ * Target families of an operating system
+ */
+function familiesOf(os: string): string[] {
+  if (os === "windows") return ["windows"];
+  if (UNIX_OSES.has(os)) return ["unix"];
+  return [];
+}
+
+/**
+ * `target_os` of the OS component of a triple, or undefined when the
+ * component is not an OS (e.g. the `gnu` environment)
+ */
+function tripleOs(part: string): string | undefined {
+  if (part === "darwin") return "macos";
+  if (part === "androideabi") return "android";
+  if (part === "windows" || part === "unknown" || UNIX_OSES.has(part) || OTHER_OSES.has(part)) {
+    return part;
+  }
+  return undefined;
+}
+
+/**
+ * `target_arch` of the architecture component of a triple
+ */
+function normalizeArch(arch: string): string {
+  if (/^i[3-6]86$/.test(arch)) return "x86";
+  if (arch === "arm64") return "aarch64";
+  if (arch.startsWith("armv") || arch.startsWith("thumbv")) return "arm";
+  if (arch.startsWith("riscv64")) return "riscv64";
+  if (arch.startsWith("riscv32")) return "riscv32";
+  return arch;
+}
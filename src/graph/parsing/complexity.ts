@@ -0,0 +1,90 @@
+/**
+ * Approximate cyclomatic complexity.
+ *
+ * Gives every function a number for ranking risky code: 1 for the straight
+ * path through the body, plus 1 for each construct that introduces another
+ * path. It follows McCabe's metric closely enough to compare functions, but
+ * it is a count over the parse tree, not a control-flow graph.
+ *
+ * | Rust construct                           | Adds                    |
+ * | ---------------------------------------- | ----------------------- |
+ * | `if` / `if let` (each `else if` too)     | 1                       |
+ * | `while` / `while let`, `for`, `loop`     | 1                       |
+ * | `match` with n arms                      | n - 1                   |
+ * | match arm guard (`Some(x) if x > 0 =>`)  | 1                       |
+ * | `&&`, `||`                               | 1 per operator          |
+ * | `?`                                      | 1 (early return)        |
+ *
+ * A plain `else` adds nothing; the branch was already counted at its `if`.
+ * Closures count toward the enclosing function since they are not separate
+ * symbols, while nested `fn` items are skipped because they get their own
+ * complexity. Macro invocations are opaque token trees, so branches inside
+ * `assert!(a && b)` or `matches!(..)` are not counted.
+ *
+ * @module graph/parsing/complexity
+ */
+
+import type { Node } from "web-tree-sitter";
+
+/**
+ * Node types that each add one path.
+ */
+const RUST_BRANCH_NODES = new Set([
+  "if_expression",
+  "while_expression",
+  "for_expression",
+  "loop_expression",
+  "try_expression",
+]);
+
+/**
+ * Compute the approximate cyclomatic complexity of a Rust function.
+ *
+ * @param node - `function_item` node
+ * @returns Complexity, or undefined when the node has no body
+ */
+export function rustComplexity(node: Node): number | undefined {
+  const body = node.childForFieldName("body");
+  if (!body) {
+    return undefined;
+  }
+
+  let complexity = 1;
+  const visit = (current: Node): void => {
+    for (const child of current.children) {
+      if (!child || child.type === "function_item") {
+        continue;
+      }
+      complexity += rustBranchCount(child);
+      visit(child);
+    }
+  };
+  visit(body);
+
+  return complexity;
+}
+
+/**
+ * Paths a single node adds, not counting its descendants.
+ */
+function rustBranchCount(node: Node): number {
+  if (RUST_BRANCH_NODES.has(node.type)) {
+    return 1;
+  }
+  switch (node.type) {
+    case "match_expression": {
+      const arms =
+        node.childForFieldName("body")?.namedChildren.filter((arm) => arm?.type === "match_arm")
+          .length ?? 0;
+      return Math.max(arms - 1, 0);
+    }
+    case "match_pattern":
+      return node.childForFieldName("condition") ? 1 : 0;
+    case "binary_expression": {
+      const operator = node.childForFieldName("operator")?.type;
+      return operator === "&&" || operator === "||" ? 1 : 0;
+    }
+    default:
+      return 0;
+  }
+}
@@ -0,0 +1,210 @@
+/**
+ * Documentation text of code entities.
+ *
+ * Each language's metadata extractor collects the documentation of an entity
+ * as written — comment markers, decoration and indentation included — into
+ * `EntityMetadata.documentation`. This module turns that into plain text for
+ * `EntityMetadata.doc`, so a Rust `///` comment, a JSDoc block and a Python
+ * docstring all yield the same kind of value.
+ *
+ * Every comment is classified by its opening marker:
+ *
+ * | Style       | Written as                       | Honored by default in         |
+ * | ----------- | -------------------------------- | ----------------------------- |
+ * | `doc_block` | `/** ... *\/`                    | TS/JS, Java, PHP, Rust, C/C++ |
+ * | `doc_line`  | `/// ...`                        | Rust, C/C++, C#               |
+ * | `inner_doc` | `//! ...`, `/*! ... *\/`         | Rust, C/C++                   |
+ * | `line`      | `// ...`, `# ...`                | Go, Ruby, C/C++               |
+ * | `block`     | `/* ... *\/`                     | Go, C/C++                     |
+ * | `docstring` | first string literal of the body | Python                        |
+ *
+ * Comments in a style the language does not honor are left out of the text.
+ * The styles per language can be overridden with `ParserConfig.docCommentStyles`.
+ *
+ * @module graph/parsing/doc-comments
+ */
+
+import type { DocCommentStyle, SupportedLanguage } from "./types.js";
+
+/**
+ * All doc comment styles, for validation and configuration.
+ */
+export const DOC_COMMENT_STYLES: readonly DocCommentStyle[] = [
+  "doc_block",
+  "doc_line",
+  "inner_doc",
+  "line",
+  "block",
+  "docstring",
+];
+
+/**
+ * Doc comment styles honored per language when not configured otherwise.
+ */
+export const DEFAULT_DOC_COMMENT_STYLES: Readonly<
+  Record<SupportedLanguage, readonly DocCommentStyle[]>
+> = {
+  typescript: ["doc_block"],
+  tsx: ["doc_block"],
+  javascript: ["doc_block"],
+  jsx: ["doc_block"],
+  python: ["docstring"],
+  java: ["doc_block"],
+  go: ["line", "block"],
+  rust: ["doc_line", "doc_block", "inner_doc"],
+  c: ["doc_block", "doc_line", "inner_doc", "line", "block"],
+  cpp: ["doc_block", "doc_line", "inner_doc", "line", "block"],
+  ruby: ["line"],
+  php: ["doc_block"],
+  csharp: ["doc_line"],
+};
+
+/**
+ * Python string prefixes and quotes (`r"""`, `'''`, `"`).
+ */
+const DOCSTRING_QUOTES = /^([rRuUbBfF]{0,2})("""|'''|"|')([\s\S]*)\2$/;
+
+/**
+ * Classify a single comment (or docstring) by its opening marker.
+ *
+ * @param comment - Comment text as written in the source
+ * @returns The style, or null when the text is not a comment or string
+ */
+export function classifyDocComment(comment: string): DocCommentStyle | null {
+  const text = comment.trimStart();
+  if (text.startsWith("/**") && !text.startsWith("/**/")) return "doc_block";
+  if (text.startsWith("/*!") || text.startsWith("//!")) return "inner_doc";
+  if (text.startsWith("/*")) return "block";
+  // Four slashes are an ordinary comment in rustdoc and Doxygen
+  if (text.startsWith("///") && !text.startsWith("////")) return "doc_line";
+  if (text.startsWith("//") || text.startsWith("#")) return "line";
+  if (DOCSTRING_QUOTES.test(text)) return "docstring";
+  return null;
+}
+
+/**
+ * Split collected documentation into individual comments.
+ *
+ * Extractors join consecutive comments with newlines; a line starting a
+ * block comment runs to the line closing it. A docstring is a single piece.
+ */
+export function splitDocComments(documentation: string): string[] {
+  const text = documentation.replace(/\r\n/g, "\n");
+  if (classifyDocComment(text) === "docstring") {
+    return [text.trim()];
+  }
+
+  const comments: string[] = [];
+  const lines = text.split("\n");
+  for (let i = 0; i < lines.length; i++) {
+    const line = lines[i] ?? "";
+    if (!line.trimStart().startsWith("/*")) {
+      if (line.trim() !== "") comments.push(line.trim());
+      continue;
+    }
+    const block = [line];
+    // The closing marker must follow the opening one (`/*/` does not close)
+    let closed = line.trimStart().slice(2).includes("*/");
+    while (!closed && i + 1 < lines.length) {
+      const next = lines[++i] ?? "";
+      block.push(next);
+      closed = next.includes("*/");
+    }
+    comments.push(block.join("\n").trim());
+  }
+  return comments;
+}
+
+/**
+ * Remove the markers of one comment and return its lines of text.
+ *
+ * @param comment - Comment text as written in the source
+ * @param style - Style of the comment, from {@link classifyDocComment}
+ */
+export function stripDocCommentMarkers(comment: string, style: DocCommentStyle): string[] {
+  switch (style) {
+    case "doc_line":
+    case "line":
+      return [comment.replace(/^(\/\/[/!]?|#+)/, "").replace(/^ /, "")];
+    case "inner_doc":
+      return comment.startsWith("//!")
+        ? [comment.slice(3).replace(/^ /, "")]
+        : blockCommentLines(comment.slice(3));
+    case "doc_block":
+      return blockCommentLines(comment.slice(3));
+    case "block":
+      return blockCommentLines(comment.slice(2));
+    case "docstring": {
+      const match = DOCSTRING_QUOTES.exec(comment);
+      return match ? docstringLines(match[3] ?? "") : [];
+    }
+  }
+}
+
+/**
+ * Plain documentation text of an entity.
+ *
+ * @param language - Language of the file declaring the entity
+ * @param documentation - Documentation as collected by the parser
+ * @param styles - Styles to honor (default: the language's defaults)
+ * @returns The text with markers and common indentation removed, or undefined
+ *          when no honored comment has any text
+ */
+export function extractDocText(
+  language: SupportedLanguage,
+  documentation: string,
+  styles: readonly DocCommentStyle[] = DEFAULT_DOC_COMMENT_STYLES[language]
+): string | undefined {
+  const lines: string[] = [];
+  for (const comment of splitDocComments(documentation)) {
+    const style = classifyDocComment(comment);
+    if (style && styles.includes(style)) {
+      lines.push(...stripDocCommentMarkers(comment, style));
+    }
+  }
+
+  const text = dedent(lines).join("\n").trim();
+  return text === "" ? undefined : text;
+}
+
+/**
+ * Summary of documentation text: its first paragraph on one line, as
+ * rustdoc and most doc generators show it in item lists.
+ *
+ * @param doc - Text from {@link extractDocText}
+ */
+export function docSummary(doc: string): string {
+  const [paragraph = ""] = doc.trim().split(/\n\s*\n/);
+  return paragraph.replace(/\s+/g, " ").trim();
+}
+
+/**
+ * Lines of a block comment body (opening marker already removed), without
+ * the closing marker or the ` * ` decoration of continuation lines.
+ */
+function blockCommentLines(body: string): string[] {
+  const lines = body.replace(/\*\/\s*$/, "").split("\n");
+  return lines.map((line, index) =>
+    index === 0 ? line.replace(/^ /, "") : line.replace(/^\s*\*(?!\/) ?/, "")
+  );
+}
+
+/**
+ * Lines of a docstring body following PEP 257: the first line is taken as
+ * is, the indentation shared by the others is removed.
+ */
+function docstringLines(body: string): string[] {
+  const [first = "", ...rest] = body.split("\n");
+  return [first.trim(), ...dedent(rest)];
+}
+
+/**
+ * Remove the indentation shared by all non-blank lines.
+ */
+function dedent(lines: string[]): string[] {
+  const indents = lines
+    .filter((line) => line.trim() !== "")
+    .map((line) => /^[ \t]*/.exec(line)?.[0].length ?? 0);
+  const common = indents.length > 0 ? Math.min(...indents) : 0;
+  return lines.map((line) => line.slice(common).trimEnd());
+}
@@ -0,0 +1,231 @@
+/**
+ * Doctest extraction from Rust doc comments.
+ *
+ * Finds the fenced code blocks of a doc comment and classifies them the way
+ * rustdoc does, so the examples can be parsed as code and linked to the item
+ * they document.
+ *
+ * | Fence info string                        | Rust | DoctestMode    |
+ * | ---------------------------------------- | ---- | -------------- |
+ * | (empty), `rust`                          | yes  | `run`          |
+ * | `no_run`                                 | yes  | `no_run`       |
+ * | `ignore`, `ignore-wasm32`, ...           | yes  | `ignore`       |
+ * | `should_panic`                           | yes  | `should_panic` |
+ * | `compile_fail`, `compile_fail,E0308`     | yes  | `compile_fail` |
+ * | `text`, `toml`, `rust,text`, ...         | no   | -              |
+ *
+ * @module graph/parsing/doctests
+ */
+
+import type { DoctestMode } from "./types.js";
+
+/**
+ * A line of doc comment text with its comment markers removed.
+ */
+export interface DocCommentLine {
+  /** Text after the `///`, `//!` or block comment prefix */
+  text: string;
+  /** Line number in the source file (1-based) */
+  line: number;
+}
+
+/**
+ * A fenced code block found in a doc comment.
+ */
+export interface FencedCodeBlock {
+  /** Fence info string as written, e.g. "rust,no_run" or "" */
+  info: string;
+  /** Code lines between the fences, joined with newlines */
+  code: string;
+  /** Source line of the first code line (1-based) */
+  lineStart: number;
+  /** Source line of the last code line (1-based, inclusive) */
+  lineEnd: number;
+}
+
+/**
+ * How rustdoc treats a fenced code block.
+ */
+export interface RustFence {
+  /** Whether rustdoc treats the block as Rust code */
+  isRust: boolean;
+  /** How the block is tested; meaningful only when `isRust` */
+  mode: DoctestMode;
+}
+
+/**
+ * All doctest modes, for validation schemas and tool definitions.
+ */
+export const DOCTEST_MODES: readonly DoctestMode[] = [
+  "run",
+  "no_run",
+  "ignore",
+  "should_panic",
+  "compile_fail",
+];
+
+/**
+ * Test modes in order of precedence when a fence combines several, e.g.
+ * `ignore,no_run` is not compiled at all.
+ */
+const MODE_PRECEDENCE: readonly DoctestMode[] = [
+  "ignore",
+  "compile_fail",
+  "no_run",
+  "should_panic",
+];
+
+/**
+ * Split a doc comment into lines of text, removing the comment markers.
+ *
+ * Handles `///` and `//!` line comments and `/**` and `/*!` block comments,
+ * including the leading ` * ` decoration of block comment lines.
+ *
+ * @param comment - Comment text as written in the source
+ * @param startLine - Source line the comment starts on (1-based)
+ */
+export function docCommentLines(comment: string, startLine: number): DocCommentLine[] {
+  const text = comment.replace(/\r\n/g, "\n").replace(/\n$/, "");
+
+  if (text.startsWith("///") || text.startsWith("//!")) {
+    return [{ text: text.slice(3).replace(/^ /, ""), line: startLine }];
+  }
+
+  if (text.startsWith("/**") || text.startsWith("/*!")) {
+    const body = text.slice(3).replace(/\*\/$/, "");
+    return body.split("\n").map((line, index) => ({
+      text: index === 0 ? line.replace(/^ /, "") : line.replace(/^\s*\*(?!\/) ?/, ""),
+      line: startLine + index,
+    }));
+  }
+
+  return [];
+}
+
+/**
+ * Find the fenced code blocks (``` or ~~~) in doc comment lines.
+ *
+ * A block closes at a fence of the same character at least as long as the
+ * opening one; an unclosed block runs to the end of the comment, as in
+ * CommonMark. Empty blocks are skipped.
+ */
+export function findFencedCodeBlocks(lines: readonly DocCommentLine[]): FencedCodeBlock[] {
+  const blocks: FencedCodeBlock[] = [];
+  let open: { fence: string; info: string; code: DocCommentLine[] } | null = null;
+
+  for (const docLine of lines) {
+    const fence = /^ {0,3}(`{3,}|~{3,})(.*)$/.exec(docLine.text);
+
+    if (open) {
+      if (
+        fence?.[1] &&
+        fence[1][0] === open.fence[0] &&
+        fence[1].length >= open.fence.length &&
+        fence[2]?.trim() === ""
+      ) {
+        pushBlock(blocks, open.info, open.code);
+        open = null;
+      } else {
+        open.code.push(docLine);
+      }
+      continue;
+    }
+
+    // Backtick fences may not have backticks in their info string
+    if (fence?.[1] && !(fence[1][0] === "`" && fence[2]?.includes("`"))) {
+      open = { fence: fence[1], info: (fence[2] ?? "").trim(), code: [] };
+    }
+  }
+
+  if (open) {
+    pushBlock(blocks, open.info, open.code);
+  }
+
+  return blocks;
+}
+
+function pushBlock(blocks: FencedCodeBlock[], info: string, code: DocCommentLine[]): void {
+  const first = code[0];
+  const last = code[code.length - 1];
+  if (!first || !last || code.every((line) => line.text.trim() === "")) {
+    return;
+  }
+  blocks.push({
+    info,
+    code: code.map((line) => line.text).join("\n"),
+    lineStart: first.line,
+    lineEnd: last.line,
+  });
+}
+
+/**
+ * Classify a fence info string as rustdoc does.
+ *
+ * Blocks without an info string are Rust. Otherwise every comma- or
+ * space-separated token must be a rustdoc attribute (`rust`, `no_run`,
+ * `ignore`, `ignore-<target>`, `should_panic`, `compile_fail`, error codes
+ * such as `E0308`, `editionNNNN`, `test_harness`, `standalone_crate`); any
+ * other token names a different language and the block is not Rust.
+ */
+export function classifyRustFence(info: string): RustFence {
+  const tokens = info
+    .replace(/^\{|\}$/g, "")
+    .split(/[\s,]+/)
+    .filter((token) => token.length > 0)
+    .map((token) => token.replace(/^\./, ""));
+
+  let isRust = true;
+  const flags = new Set<DoctestMode>();
+  for (const token of tokens) {
+    if (token.startsWith("ignore-")) {
+      flags.add("ignore");
+    } else if (
+      token === "rust" ||
+      token === "test_harness" ||
+      token === "standalone_crate" ||
+      /^edition\d{4}$/.test(token) ||
+      /^E\d{4}$/.test(token)
+    ) {
+      continue;
+    } else if (
+      token === "ignore" ||
+      token === "no_run" ||
+      token === "should_panic" ||
+      token === "compile_fail"
+    ) {
+      flags.add(token as DoctestMode);
+    } else {
+      isRust = false;
+    }
+  }
+
+  const mode = MODE_PRECEDENCE.find((candidate) => flags.has(candidate)) ?? "run";
+
+  return { isRust, mode };
+}
+
+/**
+ * Restore the lines rustdoc hides from rendered docs.
+ *
+ * A line whose first non-blank characters are `# ` (or a lone `#`) is
+ * compiled but hidden; the marker is removed. `##` escapes a literal `#`.
+ * Lines are kept one-for-one so snippet line numbers map back to the source.
+ */
+export function unhideDoctestLines(code: string): string {
+  return code
+    .split("\n")
+    .map((line) => {
+      const trimmed = line.trimStart();
+      if (trimmed === "#") {
+        return "";
+      }
+      if (trimmed.startsWith("##")) {
+        return line.replace("##", "#");
+      }
+      if (trimmed.startsWith("# ")) {
+        return trimmed.slice(2);
+      }
+      return line;
+    })
+    .join("\n");
+}
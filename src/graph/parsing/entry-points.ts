@@ -0,0 +1,68 @@
+/**
+ * Entry point detection.
+ *
+ * Flags the functions a crate is run through, so tooling can show how code
+ * is invoked and dead-code style analyses can leave them alone even though
+ * nothing in the repository calls them.
+ *
+ * | Rust construct                                  | EntryPointKind |
+ * | ----------------------------------------------- | -------------- |
+ * | free `fn main`                                  | `main`         |
+ * | `#[tokio::main]`, `#[actix_web::main]`, ...     | `async_main`   |
+ * | `#[test]`, `#[tokio::test]`, ...                | `test`         |
+ * | `#[bench]`                                      | `bench`        |
+ *
+ * @module graph/parsing/entry-points
+ */
+
+import type { EntryPoint, EntryPointKind } from "./types.js";
+
+/**
+ * All entry point kinds, for validation schemas and tool definitions.
+ */
+export const ENTRY_POINT_KINDS: readonly EntryPointKind[] = ["main", "async_main", "test", "bench"];
+
+/**
+ * Path of an attribute as written inside `#[...]`, without its arguments
+ * ("tokio::main(flavor = \"current_thread\")" → "tokio::main").
+ */
+export function attributePath(attribute: string): string {
+  const argsStart = attribute.search(/[(=]/);
+  return (argsStart === -1 ? attribute : attribute.slice(0, argsStart)).replace(/\s+/g, "");
+}
+
+/**
+ * Classify a Rust function as an entry point.
+ *
+ * Attributes take precedence over the name, so `#[tokio::main] async fn main`
+ * is an `async_main` entry point.
+ *
+ * @param name - Function name
+ * @param attributes - Outer attributes as written inside `#[...]`
+ * @param isAssociated - Whether the function is declared in an `impl` or `trait`
+ * @returns The entry point, or null for ordinary functions
+ */
+export function detectRustEntryPoint(
+  name: string,
+  attributes: readonly string[],
+  isAssociated: boolean
+): EntryPoint | null {
+  for (const attribute of attributes) {
+    const path = attributePath(attribute);
+    if (path === "test" || path.endsWith("::test")) {
+      return { kind: "test", attribute: path };
+    }
+    if (path === "bench") {
+      return { kind: "bench", attribute: path };
+    }
+    if (path.endsWith("::main")) {
+      return { kind: "async_main", attribute: path };
+    }
+  }
+
+  if (name === "main" && !isAssociated) {
+    return { kind: "main" };
+  }
+
+  return null;
+}
@@ -174,6 +174,28 @@ export class FileTooLargeError extends ParsingError {
   }
 }
 
+/**
+ * Error thrown when a file has too many lines to parse.
+ *
+ * Long files are usually generated (bundles, protobuf output) and would
+ * flood the index with entities nobody navigates to.
+ */
+export class FileTooLongError extends ParsingError {
+  public readonly lineCount: number;
+  public readonly maxLines: number;
+
+  constructor(filePath: string, lineCount: number, maxLines: number) {
+    super(
+      `File has ${lineCount} lines, exceeding maximum ${maxLines}`,
+      filePath,
+      "FILE_TOO_LONG_ERROR"
+    );
+    this.name = "FileTooLongError";
+    this.lineCount = lineCount;
+    this.maxLines = maxLines;
+  }
+}
+
 /**
  * Error thrown when AST extraction fails after successful parsing.
  *
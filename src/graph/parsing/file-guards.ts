@@ -0,0 +1,73 @@
+/**
+ * File skip guards.
+ *
+ * Files beyond a size or line limit are skipped instead of parsed. The limits
+ * can be set per language, so a repository can admit large hand-written Rust
+ * files while keeping huge generated JavaScript bundles out of the index.
+ * Limits a language does not set fall back to the parser-wide settings.
+ *
+ * @module graph/parsing/file-guards
+ */
+
+import { FileTooLargeError, FileTooLongError } from "./errors.js";
+import type { FileGuards, ParserConfig, SupportedLanguage } from "./types.js";
+
+/**
+ * Parser settings the guards are resolved from.
+ */
+export type FileGuardConfig = Pick<
+  Required<ParserConfig>,
+  "maxFileSizeBytes" | "maxLines" | "languageGuards"
+>;
+
+/**
+ * Resolve the skip guards that apply to a language's files.
+ *
+ * @param config - Parser configuration with defaults applied
+ * @param language - Language of the file being parsed
+ * @returns The language's guards, with unset fields taken from the parser-wide settings
+ */
+export function resolveFileGuards(
+  config: FileGuardConfig,
+  language: SupportedLanguage
+): Required<FileGuards> {
+  const override = config.languageGuards[language];
+  return {
+    maxFileSizeBytes: override?.maxFileSizeBytes ?? config.maxFileSizeBytes,
+    maxLines: override?.maxLines ?? config.maxLines,
+  };
+}
+
+/**
+ * Check a file against its language's skip guards.
+ *
+ * @param content - File content
+ * @param filePath - File path, for the error
+ * @param config - Parser configuration with defaults applied
+ * @param language - Language of the file
+ * @returns File size in bytes
+ * @throws {FileTooLargeError} If the file exceeds the language's max size
+ * @throws {FileTooLongError} If the file exceeds the language's max line count
+ */
+export function checkFileGuards(
+  content: string,
+  filePath: string,
+  config: FileGuardConfig,
+  language: SupportedLanguage
+): number {
+  const guards = resolveFileGuards(config, language);
+
+  const sizeBytes = new TextEncoder().encode(content).length;
+  if (sizeBytes > guards.maxFileSizeBytes) {
+    throw new FileTooLargeError(filePath, sizeBytes, guards.maxFileSizeBytes);
+  }
+
+  if (guards.maxLines > 0) {
+    const lineCount = content.split("\n").length;
+    if (lineCount > guards.maxLines) {
+      throw new FileTooLongError(filePath, lineCount, guards.maxLines);
+    }
+  }
+
+  return sizeBytes;
+}
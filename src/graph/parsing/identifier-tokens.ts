@@ -0,0 +1,114 @@
+/**
+ * Identifier tokenization for symbol name search.
+ *
+ * Splits identifiers into lowercase subtokens on underscores, hyphens,
+ * dollar signs, digit boundaries and camelCase/PascalCase boundaries, so a
+ * query for `scale` can match `scale_factor`, `ScaleFactor` and
+ * `SCALE_FACTOR` alike. Acronym runs are kept together: `HTTPServer` becomes
+ * `["http", "server"]`.
+ *
+ * @module graph/parsing/identifier-tokens
+ */
+
+/**
+ * How a symbol name matched a search query, from strongest to weakest.
+ */
+export type NameMatchType = "exact" | "exact_ci" | "prefix" | "substring" | "token";
+
+/**
+ * Score of each match type. Full-name matches always outrank subtoken matches,
+ * which are scaled by the fraction of query subtokens found in the name.
+ */
+export const NAME_MATCH_SCORES: Record<NameMatchType, number> = {
+  exact: 1.0,
+  exact_ci: 0.95,
+  prefix: 0.8,
+  substring: 0.6,
+  token: 0.5,
+};
+
+/**
+ * Default minimum score of a subtoken search: at least half of the query
+ * subtokens must appear in the name. Full-name matches always clear it.
+ */
+export const DEFAULT_TOKEN_MATCH_MIN_SCORE = 0.25;
+
+/**
+ * Result of scoring a symbol name against a query.
+ */
+export interface NameMatch {
+  /** How the name matched */
+  type: NameMatchType;
+  /** Relevance score (0.0-1.0) */
+  score: number;
+}
+
+/**
+ * Split an identifier into lowercase subtokens.
+ *
+ * @param identifier - Identifier as written (e.g. "parseHTTPResponse_v2")
+ * @returns Subtokens in order (e.g. ["parse", "http", "response", "v", "2"])
+ */
+export function splitIdentifier(identifier: string): string[] {
+  return identifier
+    .replace(/([a-z])([A-Z])/g, "$1 $2")
+    .replace(/([A-Z]+)([A-Z][a-z])/g, "$1 $2")
+    .replace(/([A-Za-z])(\d)/g, "$1 $2")
+    .replace(/(\d)([A-Za-z])/g, "$1 $2")
+    .split(/[^A-Za-z0-9]+/)
+    .filter((token) => token.length > 0)
+    .map((token) => token.toLowerCase());
+}
+
+/**
+ * Score a symbol name against a search query.
+ *
+ * Without `tokenMatch`, only full-name matches (exact, case-insensitive,
+ * prefix, substring) count. With `tokenMatch`, a name also matches when query
+ * subtokens equal name subtokens, regardless of naming convention.
+ *
+ * @param name - Symbol name
+ * @param query - Search text
+ * @param tokenMatch - Whether to fall back to subtoken matching
+ * @returns The strongest match, or null when the name does not match
+ */
+export function scoreSymbolName(
+  name: string,
+  query: string,
+  tokenMatch: boolean
+): NameMatch | null {
+  if (name === query) {
+    return { type: "exact", score: NAME_MATCH_SCORES.exact };
+  }
+
+  const lowerName = name.toLowerCase();
+  const lowerQuery = query.toLowerCase();
+  if (lowerName === lowerQuery) {
+    return { type: "exact_ci", score: NAME_MATCH_SCORES.exact_ci };
+  }
+  if (lowerName.startsWith(lowerQuery)) {
+    return { type: "prefix", score: NAME_MATCH_SCORES.prefix };
+  }
+  if (lowerName.includes(lowerQuery)) {
+    return { type: "substring", score: NAME_MATCH_SCORES.substring };
+  }
+
+  if (!tokenMatch) {
+    return null;
+  }
+
+  const queryTokens = splitIdentifier(query);
+  if (queryTokens.length === 0) {
+    return null;
+  }
+  const nameTokens = new Set(splitIdentifier(name));
+  const matched = queryTokens.filter((token) => nameTokens.has(token)).length;
+  if (matched === 0) {
+    return null;
+  }
+
+  return {
+    type: "token",
+    score: Math.round(NAME_MATCH_SCORES.token * (matched / queryTokens.length) * 1000) / 1000,
+  };
+}
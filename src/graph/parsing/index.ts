@@ -27,16 +27,115 @@ export type {
   TreeSitterLanguage,
   EntityType,
   ParameterInfo,
+  EntryPointKind,
+  EntryPoint,
+  EnumVariant,
+  LiteralValue,
+  LiteralValueKind,
+  LocalBinding,
+  LineSpan,
+  SourceRange,
+  StructuralFingerprint,
+  DoctestMode,
+  Doctest,
+  DocCommentStyle,
   EntityMetadata,
   CodeEntity,
   ImportInfo,
   ExportInfo,
   CallInfo,
+  ImplEdge,
+  TodoComment,
+  StringLiteral,
   ParseError,
   ParseResult,
   ParserConfig,
+  FileGuards,
 } from "./types.js";
 
+// Re-export symbol kind normalization
+export type { SymbolKind, SymbolKindContext } from "./symbol-kinds.js";
+export {
+  SYMBOL_KINDS,
+  DEFAULT_SYMBOL_KINDS,
+  LANGUAGE_SYMBOL_KIND_OVERRIDES,
+  resolveSymbolKind,
+  getSymbolKind,
+} from "./symbol-kinds.js";
+
+// Re-export LSP symbol kind mapping
+export type { LspSymbolKind } from "./lsp-symbol-kinds.js";
+export { LSP_SYMBOL_KIND, toLspSymbolKind } from "./lsp-symbol-kinds.js";
+
+// Re-export entry point detection
+export { ENTRY_POINT_KINDS, attributePath, detectRustEntryPoint } from "./entry-points.js";
+
+// Re-export conditional compilation predicates
+export type { CfgPredicate, TargetPlatform } from "./cfg-predicates.js";
+export {
+  cfgConditionFromAttributes,
+  parseCfgPredicate,
+  formatCfgPredicate,
+  parseTargetPlatform,
+  isCfgSatisfiable,
+} from "./cfg-predicates.js";
+
+// Re-export Rust visibility rules
+export {
+  normalizeRustVisibility,
+  rustVisibilityScope,
+  isRustVisibleFrom,
+} from "./rust-visibility.js";
+
+// Re-export doctest extraction
+export type { DocCommentLine, FencedCodeBlock, RustFence } from "./doctests.js";
+export {
+  DOCTEST_MODES,
+  docCommentLines,
+  findFencedCodeBlocks,
+  classifyRustFence,
+  unhideDoctestLines,
+} from "./doctests.js";
+
+// Re-export type hierarchy helpers
+export type { GenericBound } from "./type-hierarchy.js";
+export { splitSupertypes, derivedTraits, parseGenericBounds } from "./type-hierarchy.js";
+
+// Re-export complexity metric
+export { rustComplexity } from "./complexity.js";
+
+// Re-export structural fingerprints
+export {
+  MIN_HASH_SLOTS,
+  rustStructuralFingerprint,
+  structuralSimilarity,
+} from "./structural-fingerprint.js";
+
+// Re-export constant literal values
+export { LITERAL_VALUE_KINDS, rustLiteralValue } from "./literal-values.js";
+
+// Re-export local variable bindings
+export { rustLocalBindings } from "./local-bindings.js";
+
+// Re-export doc comment normalization
+export {
+  DOC_COMMENT_STYLES,
+  DEFAULT_DOC_COMMENT_STYLES,
+  classifyDocComment,
+  splitDocComments,
+  stripDocCommentMarkers,
+  extractDocText,
+  docSummary,
+} from "./doc-comments.js";
+
+// Re-export identifier tokenization
+export type { NameMatch, NameMatchType } from "./identifier-tokens.js";
+export { NAME_MATCH_SCORES, splitIdentifier, scoreSymbolName } from "./identifier-tokens.js";
+
+// Re-export file skip guards
+export type { FileGuardConfig } from "./file-guards.js";
+export { resolveFileGuards, checkFileGuards } from "./file-guards.js";
+
 // Re-export type utilities
 export {
   DEFAULT_PARSER_CONFIG,
@@ -54,13 +153,19 @@ export {
   ParserInitializationError,
   ParseTimeoutError,
   FileTooLargeError,
+  FileTooLongError,
   ExtractionError,
   RoslynNotAvailableError,
   isRetryableParsingError,
 } from "./errors.js";
 
 // Re-export main classes
-export { LanguageLoader } from "./LanguageLoader.js";
+export {
+  LanguageLoader,
+  TREE_SITTER_LANGUAGES,
+  type LanguageLoaderStatus,
+  type WasmPathConfig,
+} from "./LanguageLoader.js";
 export { TreeSitterParser } from "./TreeSitterParser.js";
 export { CodeParser } from "./CodeParser.js";
 
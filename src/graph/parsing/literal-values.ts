@@ -0,0 +1,145 @@
+/**
+ * Literal values of constants.
+ *
+ * Config-style constants (`const MAX_SIZE: usize = 1024;`) are easier to
+ * audit when their value can be compared, not just their source text. When a
+ * `const` or `static` is initialized with a literal, the literal is decoded
+ * into a typed value:
+ *
+ * | Initializer                              | Value                       |
+ * | ---------------------------------------- | --------------------------- |
+ * | `1024`, `1_000usize`, `0xFF`, `-1`       | `integer` (suffix dropped)  |
+ * | `2.5`, `1e-3`, `1f64`, `-0.5`            | `float`                     |
+ * | `"text"` (escapes decoded), `r#"raw"#`   | `string`                    |
+ * | `true`, `false`                          | `bool`                      |
+ *
+ * Anything else — arithmetic, `usize::MAX`, calls, arrays, byte strings,
+ * chars — has no value; callers keep the initializer text instead. Integers
+ * beyond JavaScript's safe integer range are treated the same way rather
+ * than stored rounded.
+ *
+ * @module graph/parsing/literal-values
+ */
+
+import type { Node } from "web-tree-sitter";
+import type { LiteralValue, LiteralValueKind } from "./types.js";
+
+/**
+ * All literal value kinds, for validation schemas and tool definitions.
+ */
+export const LITERAL_VALUE_KINDS: readonly LiteralValueKind[] = [
+  "integer",
+  "float",
+  "string",
+  "bool",
+];
+
+/**
+ * Integer type suffixes (`1u8`, `1_000usize`).
+ */
+const INTEGER_SUFFIX = /[iu](?:8|16|32|64|128|size)$/;
+
+/**
+ * Float type suffixes (`2.5f32`, `1f64`).
+ */
+const FLOAT_SUFFIX = /f(?:32|64)$/;
+
+/**
+ * Escape sequences of a Rust string literal, including line continuations.
+ */
+const STRING_ESCAPE = /\\(u\{[0-9a-fA-F_]+\}|x[0-9a-fA-F]{2}|\r?\n\s*|.)/g;
+
+/**
+ * Decode the literal initializer of a Rust constant.
+ *
+ * @param node - Initializer expression (the `value` field of a `const_item` or `static_item`)
+ * @returns The typed value, or undefined when the initializer is not a literal
+ */
+export function rustLiteralValue(node: Node): LiteralValue | undefined {
+  switch (node.type) {
+    case "parenthesized_expression": {
+      const inner = node.namedChildren[0];
+      return inner && node.namedChildCount === 1 ? rustLiteralValue(inner) : undefined;
+    }
+    case "unary_expression": {
+      const operand = node.namedChildren[0];
+      if (node.child(0)?.type !== "-" || !operand) {
+        return undefined;
+      }
+      const literal = rustLiteralValue(operand);
+      return literal?.kind === "integer" || literal?.kind === "float"
+        ? { ...literal, value: -literal.value }
+        : undefined;
+    }
+    case "integer_literal":
+      return parseRustInteger(node.text);
+    case "float_literal": {
+      const value = Number(node.text.replace(/_/g, "").replace(FLOAT_SUFFIX, ""));
+      return Number.isFinite(value) ? { kind: "float", value } : undefined;
+    }
+    case "string_literal":
+      // Byte strings (b"...") are byte arrays, not text
+      return node.text.startsWith('"')
+        ? { kind: "string", value: unescapeRustString(node.text.slice(1, -1)) }
+        : undefined;
+    case "raw_string_literal": {
+      const match = /^r(#*)"([\s\S]*)"\1$/.exec(node.text);
+      return match ? { kind: "string", value: match[2] ?? "" } : undefined;
+    }
+    case "boolean_literal":
+      return { kind: "bool", value: node.text === "true" };
+    default:
+      return undefined;
+  }
+}
+
+/**
+ * Parse an integer literal, which may carry a radix prefix, digit separators
+ * and a type suffix.
+ */
+function parseRustInteger(text: string): LiteralValue | undefined {
+  const digits = text.replace(/_/g, "");
+
+  // A float suffix on a decimal literal (`1f64`) makes it a float; in hex it is digits
+  if (!digits.startsWith("0x") && FLOAT_SUFFIX.test(digits)) {
+    return { kind: "float", value: Number(digits.replace(FLOAT_SUFFIX, "")) };
+  }
+
+  let value: number;
+  try {
+    // BigInt understands the 0x/0o/0b prefixes and keeps large values exact
+    value = Number(BigInt(digits.replace(INTEGER_SUFFIX, "")));
+  } catch {
+    return undefined;
+  }
+  return Number.isSafeInteger(value) ? { kind: "integer", value } : undefined;
+}
+
+/**
+ * Decode the escape sequences of a Rust string literal body.
+ */
+function unescapeRustString(body: string): string {
+  return body.replace(STRING_ESCAPE, (_match, escape: string) => {
+    if (escape.startsWith("u{")) {
+      return String.fromCodePoint(parseInt(escape.slice(2, -1).replace(/_/g, ""), 16));
+    }
+    if (escape.startsWith("x")) {
+      return String.fromCharCode(parseInt(escape.slice(1), 16));
+    }
+    if (escape.startsWith("\n") || escape.startsWith("\r")) {
+      return "";
+    }
+    switch (escape) {
+      case "n":
+        return "\n";
+      case "r":
+        return "\r";
+      case "t":
+        return "\t";
+      case "0":
+        return "\0";
+      default:
+        return escape;
+    }
+  });
+}
@@ -0,0 +1,142 @@
+/**
+ * Local variable bindings.
+ *
+ * Lists the names a Rust function binds with `let`, so tooling can show what
+ * a function works with and resolve method calls on its locals. Each binding
+ * gets a type when one can be read off the statement without type checking:
+ *
+ * | Statement                              | Type                   |
+ * | -------------------------------------- | ---------------------- |
+ * | `let map: HashMap<String, i32> = ..;`  | `HashMap<String, i32>` |
+ * | `let point = Point::new(3.0, 4.0);`    | `Point`                |
+ * | `let user = models::User { .. };`      | `models::User`         |
+ * | `let items = Vec::<u8>::new();`        | `Vec<u8>`              |
+ * | `let file = File::open(path)?;`        | `File`                 |
+ *
+ * Calls are only typed when their path ends in a type-like (capitalized)
+ * segment, assuming the associated function returns that type, as
+ * constructors do. Other initializers (`simple_function()`, `point.distance()`,
+ * macros) leave the type unset. Tuple patterns bind each element, typed from
+ * a tuple annotation when there is one. Nested `fn` items are skipped; their
+ * locals belong to them.
+ *
+ * @module graph/parsing/local-bindings
+ */
+
+import type { Node } from "web-tree-sitter";
+import type { LocalBinding } from "./types.js";
+
+/**
+ * List the `let` bindings of a Rust function body.
+ *
+ * @param node - A `function_item` node
+ * @returns Bindings in source order; empty when the function has no body
+ */
+export function rustLocalBindings(node: Node): LocalBinding[] {
+  const bindings: LocalBinding[] = [];
+
+  const visit = (current: Node): void => {
+    for (const child of current.children) {
+      if (!child || child.type === "function_item") {
+        continue;
+      }
+      if (child.type === "let_declaration") {
+        const pattern = child.childForFieldName("pattern");
+        const annotation = child.childForFieldName("type");
+        const value = child.childForFieldName("value");
+        const type = annotation?.text ?? (value ? constructedType(value) : undefined);
+        if (pattern) {
+          bindPattern(pattern, annotation, type, child.startPosition.row + 1, bindings);
+        }
+      }
+      visit(child);
+    }
+  };
+
+  const body = node.childForFieldName("body");
+  if (body) {
+    visit(body);
+  }
+  return bindings;
+}
+
+/**
+ * Record the names a pattern binds.
+ *
+ * @param pattern - Pattern node of a `let`
+ * @param annotation - Type annotation matching the pattern, if any
+ * @param type - Type of the whole pattern, if known
+ * @param line - Line of the `let` (1-based)
+ * @param bindings - Bindings to append to
+ */
+function bindPattern(
+  pattern: Node,
+  annotation: Node | null,
+  type: string | undefined,
+  line: number,
+  bindings: LocalBinding[]
+): void {
+  switch (pattern.type) {
+    case "identifier":
+      bindings.push(
+        type !== undefined ? { name: pattern.text, type, line } : { name: pattern.text, line }
+      );
+      return;
+    case "mut_pattern":
+    case "ref_pattern": {
+      const inner = pattern.namedChildren.find((child) => child?.type !== "mutable_specifier");
+      if (inner) {
+        bindPattern(inner, annotation, type, line, bindings);
+      }
+      return;
+    }
+    case "tuple_pattern": {
+      const elementTypes = annotation?.type === "tuple_type" ? annotation.namedChildren : [];
+      pattern.namedChildren.forEach((element, i) => {
+        const elementType = elementTypes[i] ?? null;
+        if (element) {
+          bindPattern(element, elementType, elementType?.text, line, bindings);
+        }
+      });
+      return;
+    }
+    default:
+      // Struct, slice and enum patterns destructure types we cannot see here
+      return;
+  }
+}
+
+/**
+ * Type constructed by a `let` initializer, when the expression names it.
+ */
+function constructedType(value: Node): string | undefined {
+  switch (value.type) {
+    case "try_expression": {
+      const inner = value.namedChildren[0];
+      return inner ? constructedType(inner) : undefined;
+    }
+    case "struct_expression": {
+      const name = value.childForFieldName("name");
+      return name ? withoutTurbofish(name.text) : undefined;
+    }
+    case "call_expression": {
+      const callee = value.childForFieldName("function");
+      const path = callee?.type === "scoped_identifier" ? callee.childForFieldName("path") : null;
+      if (!path) {
+        return undefined;
+      }
+      const typePath = withoutTurbofish(path.text);
+      const lastSegment = typePath.replace(/<.*>$/, "").split("::").pop() ?? "";
+      return /^[A-Z]/.test(lastSegment) ? typePath : undefined;
+    }
+    default:
+      return undefined;
+  }
+}
+
+/**
+ * Write `Vec::<u8>` as the type `Vec<u8>`.
+ */
+function withoutTurbofish(path: string): string {
+  return path.replace(/::</g, "<");
+}
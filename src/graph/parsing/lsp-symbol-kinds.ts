@@ -0,0 +1,88 @@
+/**
+ * LSP symbol kinds.
+ *
+ * Maps language-neutral `SymbolKind`s onto the numeric `SymbolKind` of the
+ * Language Server Protocol, so symbols can be served to editors as
+ * `textDocument/documentSymbol` results.
+ *
+ * | SymbolKind   | LSP kind                                      |
+ * | ------------ | --------------------------------------------- |
+ * | `function`   | `Function`                                    |
+ * | `method`     | `Method`                                      |
+ * | `class`      | `Struct` in Rust, Go and C, `Class` otherwise |
+ * | `interface`  | `Interface`                                   |
+ * | `field`      | `Field`                                       |
+ * | `namespace`  | `Module` in Rust, `Namespace` otherwise       |
+ * | `enum`       | `Enum`                                        |
+ * | `type_alias` | `TypeParameter` (LSP has no alias kind)       |
+ * | `variable`   | `Variable`                                    |
+ *
+ * Enum variants are `EnumMember` and Rust impl blocks are `Object`, matching
+ * rust-analyzer.
+ *
+ * @module graph/parsing/lsp-symbol-kinds
+ */
+
+import type { SymbolKind } from "./symbol-kinds.js";
+import type { SupportedLanguage } from "./types.js";
+
+/**
+ * Numeric LSP `SymbolKind` values used by the mapping.
+ */
+export const LSP_SYMBOL_KIND = {
+  Module: 2,
+  Namespace: 3,
+  Class: 5,
+  Method: 6,
+  Field: 8,
+  Enum: 10,
+  Interface: 11,
+  Function: 12,
+  Variable: 13,
+  Object: 19,
+  EnumMember: 22,
+  Struct: 23,
+  TypeParameter: 26,
+} as const;
+
+/**
+ * A numeric LSP `SymbolKind` value.
+ */
+export type LspSymbolKind = (typeof LSP_SYMBOL_KIND)[keyof typeof LSP_SYMBOL_KIND];
+
+/**
+ * Languages whose `class` symbols are declared as structs.
+ */
+const STRUCT_LANGUAGES: ReadonlySet<SupportedLanguage> = new Set(["rust", "go", "c"]);
+
+/**
+ * Map a symbol kind to its LSP `SymbolKind`.
+ *
+ * @param kind - Language-neutral symbol kind
+ * @param language - Language of the file declaring the symbol, when known
+ * @returns The LSP symbol kind
+ */
+export function toLspSymbolKind(kind: SymbolKind, language?: SupportedLanguage): LspSymbolKind {
+  switch (kind) {
+    case "function":
+      return LSP_SYMBOL_KIND.Function;
+    case "method":
+      return LSP_SYMBOL_KIND.Method;
+    case "class":
+      return language && STRUCT_LANGUAGES.has(language)
+        ? LSP_SYMBOL_KIND.Struct
+        : LSP_SYMBOL_KIND.Class;
+    case "interface":
+      return LSP_SYMBOL_KIND.Interface;
+    case "field":
+      return LSP_SYMBOL_KIND.Field;
+    case "namespace":
+      return language === "rust" ? LSP_SYMBOL_KIND.Module : LSP_SYMBOL_KIND.Namespace;
+    case "enum":
+      return LSP_SYMBOL_KIND.Enum;
+    case "type_alias":
+      return LSP_SYMBOL_KIND.TypeParameter;
+    case "variable":
+      return LSP_SYMBOL_KIND.Variable;
+  }
+}
@@ -12,6 +12,7 @@ import * as path from "path";
 import type pino from "pino";
 import { getComponentLogger } from "../../../logging/index.js";
 import { RoslynNotAvailableError } from "../errors.js";
+import { checkFileGuards } from "../file-guards.js";
 import type { ParseResult, ParserConfig } from "../types.js";
 import { isDotNetAvailable, detectDotNet } from "./RoslynDetector.js";
 
@@ -34,9 +35,15 @@ export class RoslynParser {
   constructor(config?: ParserConfig) {
     const defaults: Required<ParserConfig> = {
       extractDocumentation: true,
+      maxDocCommentBlankLines: 1, // Accepted for parity; Roslyn attaches XML docs itself
+      docCommentStyles: {}, // Accepted for parity; the analyzer does not report documentation
       includeAnonymous: false,
       maxFileSizeBytes: 1048576,
+      maxLines: 0,
+      languageGuards: {},
       parseTimeoutMs: 30000, // Higher timeout for Roslyn startup
+      todoTags: ["TODO", "FIXME"], // Accepted for parity; the analyzer does not report TODOs
+      indexStringLiterals: false, // Accepted for parity; the analyzer does not report literals
     };
     this.config = { ...defaults, ...config };
   }
@@ -66,10 +73,14 @@ export class RoslynParser {
    * @param filePath - Path to the file (for error messages and metadata)
    * @returns Parse result with entities, imports, and calls
    * @throws RoslynNotAvailableError if .NET SDK is not installed
+   * @throws {FileTooLargeError} If file exceeds the C# max size
+   * @throws {FileTooLongError} If file exceeds the C# max line count
    */
   async parseFile(content: string, filePath: string): Promise<ParseResult> {
     const startTime = performance.now();
 
+    checkFileGuards(content, filePath, this.config, "csharp");
+
     // Check if Roslyn is available
     if (!(await this.isAvailable())) {
       throw new RoslynNotAvailableError(filePath);
@@ -0,0 +1,82 @@
+/**
+ * Rust visibility.
+ *
+ * An item is visible in the module that restricts it and that module's
+ * descendants:
+ *
+ * | Visibility            | Visible in                         |
+ * | --------------------- | ---------------------------------- |
+ * | (none), `pub(self)`   | the defining module                |
+ * | `pub(super)`          | the parent of the defining module  |
+ * | `pub(in a::b)`        | module `a::b`                      |
+ * | `pub(crate)`          | the whole crate                    |
+ * | `pub`                 | the whole crate, and other crates  |
+ *
+ * Module paths are lists of segments below the crate root, as returned by
+ * `rustModulePath` (`src/geometry/shapes.rs` -> ["geometry", "shapes"]).
+ *
+ * @module graph/parsing/rust-visibility
+ */
+
+/**
+ * Normalize a visibility modifier as written (`pub ( crate )` -> `pub(crate)`,
+ * `pub(in crate :: a)` -> `pub(in crate::a)`).
+ */
+export function normalizeRustVisibility(text: string): string {
+  return text
+    .replace(/\s+/g, " ")
+    .replace(/\s*::\s*/g, "::")
+    .replace(/\s*\(\s*/, "(")
+    .replace(/\s*\)\s*$/, ")")
+    .trim();
+}
+
+/**
+ * The module an item with a visibility is restricted to, or undefined for
+ * `pub`, which is not restricted to its crate.
+ *
+ * @param visibility - Normalized visibility, or undefined for private items
+ * @param itemModule - Module defining the item
+ */
+export function rustVisibilityScope(
+  visibility: string | undefined,
+  itemModule: readonly string[]
+): string[] | undefined {
+  if (visibility === "pub") return undefined;
+  if (visibility === "pub(crate)") return [];
+  if (visibility === "pub(super)") return itemModule.slice(0, -1);
+
+  const restricted = visibility !== undefined ? /^pub\(in (.+)\)$/.exec(visibility) : null;
+  if (!restricted?.[1]) {
+    // Private items and pub(self)
+    return [...itemModule];
+  }
+
+  let scope: string[] = [];
+  const segments = restricted[1].split("::");
+  if (segments[0] === "self" || segments[0] === "super") {
+    scope = [...itemModule];
+  }
+  for (const segment of segments) {
+    if (segment === "super") scope = scope.slice(0, -1);
+    else if (segment !== "crate" && segment !== "self") scope.push(segment);
+  }
+  return scope;
+}
+
+/**
+ * Whether code in one module of a crate can name an item defined in
+ * another module of the same crate.
+ *
+ * @param visibility - Normalized visibility, or undefined for private items
+ * @param itemModule - Module defining the item
+ * @param callerModule - Module of the code naming it
+ */
+export function isRustVisibleFrom(
+  visibility: string | undefined,
+  itemModule: readonly string[],
+  callerModule: readonly string[]
+): boolean {
+  const scope = rustVisibilityScope(visibility, itemModule);
+  return scope === undefined || scope.every((segment, i) => callerModule[i] === segment);
+}
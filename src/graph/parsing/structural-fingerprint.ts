@@ -0,0 +1,155 @@
+/**
+ * Structural fingerprints of function bodies.
+ *
+ * Duplicated logic is usually copied and then renamed, so comparing source
+ * text misses it. A fingerprint describes only the shape of the body's parse
+ * tree: the sequence of node types in pre-order, with a marker closing each
+ * node that has children. Identifiers and literals contribute their node type
+ * but not their text, and comments are dropped.
+ *
+ * | Part      | Detects                                                          |
+ * | --------- | ---------------------------------------------------------------- |
+ * | `hash`    | Exact structural clones: same shape, any names and literals      |
+ * | `minHash` | Near clones: bodies sharing most 5-grams of the node-type stream |
+ *
+ * Two functions with equal hashes are exact clones. Otherwise the share of
+ * equal `minHash` slots estimates the Jaccard similarity of their 5-gram sets
+ * (±0.1 with 32 slots), which stays high when a statement is added, removed
+ * or reordered.
+ *
+ * Nested `fn` items are opaque: they get their own fingerprint and only mark
+ * their position in the enclosing one. Macro invocations are token trees, so
+ * their bodies are compared token by token rather than as expressions.
+ *
+ * @module graph/parsing/structural-fingerprint
+ */
+
+import { createHash } from "node:crypto";
+import type { Node } from "web-tree-sitter";
+import type { StructuralFingerprint } from "./types.js";
+
+/**
+ * Number of MinHash slots per fingerprint.
+ */
+export const MIN_HASH_SLOTS = 32;
+
+/**
+ * Length of the node-type sequences compared for near clones.
+ */
+const SHINGLE_LENGTH = 5;
+
+/**
+ * Leaf node types whose text is ignored; all literals share one token so
+ * `x + 1` and `x + 2.5` have the same shape.
+ */
+const RUST_LITERAL_NODES = new Set([
+  "integer_literal",
+  "float_literal",
+  "string_literal",
+  "raw_string_literal",
+  "char_literal",
+  "boolean_literal",
+]);
+
+/**
+ * Compute the structural fingerprint of a Rust function body.
+ *
+ * @param node - `function_item` node
+ * @returns The fingerprint, or undefined when the node has no body
+ */
+export function rustStructuralFingerprint(node: Node): StructuralFingerprint | undefined {
+  const body = node.childForFieldName("body");
+  if (!body) {
+    return undefined;
+  }
+
+  const tokens: string[] = [];
+  let size = 0;
+  const visit = (current: Node): void => {
+    size++;
+    if (RUST_LITERAL_NODES.has(current.type)) {
+      tokens.push("literal");
+      return;
+    }
+    tokens.push(current.type);
+    if (current.type === "function_item") {
+      return;
+    }
+
+    const children = current.children.filter(
+      (child): child is Node => child !== null && !child.type.endsWith("comment")
+    );
+    if (children.length === 0) {
+      return;
+    }
+    for (const child of children) {
+      visit(child);
+    }
+    tokens.push(")");
+  };
+  visit(body);
+
+  return {
+    hash: createHash("sha1").update(tokens.join(" ")).digest("hex").slice(0, 16),
+    size,
+    minHash: minHashSignature(tokens),
+  };
+}
+
+/**
+ * Estimate the similarity of two fingerprints' bodies (0 to 1).
+ *
+ * @returns 1 for exact clones, otherwise the share of equal MinHash slots
+ */
+export function structuralSimilarity(a: StructuralFingerprint, b: StructuralFingerprint): number {
+  if (a.hash === b.hash) {
+    return 1;
+  }
+  const slots = Math.min(a.minHash.length, b.minHash.length);
+  if (slots === 0) {
+    return 0;
+  }
+  let equal = 0;
+  for (let i = 0; i < slots; i++) {
+    if (a.minHash[i] === b.minHash[i]) {
+      equal++;
+    }
+  }
+  return equal / slots;
+}
+
+/**
+ * MinHash signature of the token stream's shingles.
+ *
+ * Slot `i` keeps the minimum of `h1 + i * h2` over all shingles, where `h1`
+ * and `h2` are two FNV-1a hashes of the shingle (double hashing stands in for
+ * independent hash functions).
+ */
+function minHashSignature(tokens: string[]): number[] {
+  const signature = new Array<number>(MIN_HASH_SLOTS).fill(0xffffffff);
+  const count = Math.max(tokens.length - SHINGLE_LENGTH + 1, 1);
+  for (let start = 0; start < count; start++) {
+    const shingle = tokens.slice(start, start + SHINGLE_LENGTH).join(" ");
+    const h1 = fnv1a(shingle, 0x811c9dc5);
+    const h2 = fnv1a(shingle, 0x01000193) | 1;
+    for (let i = 0; i < MIN_HASH_SLOTS; i++) {
+      const value = (h1 + Math.imul(i, h2)) >>> 0;
+      if (value < (signature[i] ?? 0)) {
+        signature[i] = value;
+      }
+    }
+  }
+  return signature;
+}
+
+/**
+ * 32-bit FNV-1a hash with a configurable offset basis.
+ */
+function fnv1a(text: string, basis: number): number {
+  let hash = basis;
+  for (let i = 0; i < text.length; i++) {
+    hash ^= text.charCodeAt(i);
+    hash = Math.imul(hash, 0x01000193);
+  }
+  return hash >>> 0;
+}
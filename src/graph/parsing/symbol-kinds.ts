@@ -0,0 +1,153 @@
+/**
+ * Language-neutral symbol kinds.
+ *
+ * `EntityType` records what the parser saw in each grammar, which does not
+ * line up across languages: a Rust impl method and a Python class method are
+ * both extracted as `"function"`, while a Go method is a `"method"`. A
+ * `SymbolKind` normalizes those differences so a query for `"method"` matches
+ * all three.
+ *
+ * Resolution starts from the entity type (see `DEFAULT_SYMBOL_KINDS`), applies
+ * the per-language AST node overrides below, and finally promotes functions
+ * declared directly inside a class or interface to methods.
+ *
+ * | Language   | AST construct                          | SymbolKind  |
+ * | ---------- | -------------------------------------- | ----------- |
+ * | TS/JS      | `method_definition`                    | `method`    |
+ * | TS/JS      | `public_field_definition`, properties  | `field`     |
+ * | Python     | `def` inside a class (incl. decorated) | `method`    |
+ * | Java       | `field_declaration`                    | `field`     |
+ * | Go         | `func (r T) name()`                    | `method`    |
+ * | Go         | `type T interface {}`                  | `interface` |
+ * | Go         | `type T struct {}`                     | `class`     |
+ * | Rust       | `fn` inside `impl` or `trait`          | `method`    |
+ * | Rust       | `struct`                               | `class`     |
+ * | Rust       | `trait`                                | `interface` |
+ * | C/C++      | `struct`, `union`, `class`             | `class`     |
+ * | Ruby       | `module`                               | `namespace` |
+ * | Ruby       | `def`, `def self.name`                 | `method`    |
+ * | PHP        | `trait`                                | `class`     |
+ * | PHP        | `property_declaration`                 | `field`     |
+ *
+ * @module graph/parsing/symbol-kinds
+ */
+
+import type { CodeEntity, EntityType, SupportedLanguage } from "./types.js";
+
+/**
+ * Language-neutral kind of a code symbol.
+ */
+export type SymbolKind =
+  | "function"
+  | "method"
+  | "class"
+  | "interface"
+  | "field"
+  | "namespace"
+  | "enum"
+  | "type_alias"
+  | "variable";
+
+/**
+ * All symbol kinds, for validation schemas and tool definitions.
+ */
+export const SYMBOL_KINDS: readonly SymbolKind[] = [
+  "function",
+  "method",
+  "class",
+  "interface",
+  "field",
+  "namespace",
+  "enum",
+  "type_alias",
+  "variable",
+];
+
+/**
+ * Symbol kind for each entity type before language-specific adjustments.
+ */
+export const DEFAULT_SYMBOL_KINDS: Record<EntityType, SymbolKind> = {
+  function: "function",
+  method: "method",
+  class: "class",
+  interface: "interface",
+  type_alias: "type_alias",
+  enum: "enum",
+  variable: "variable",
+  property: "field",
+};
+
+/**
+ * Per-language overrides keyed by AST node type.
+ *
+ * For Go type declarations the key is the node type of the declared type
+ * (`struct_type`, `interface_type`), since `type_declaration` covers both.
+ */
+export const LANGUAGE_SYMBOL_KIND_OVERRIDES: Partial<
+  Record<SupportedLanguage, Record<string, SymbolKind>>
+> = {
+  go: {
+    interface_type: "interface",
+  },
+  ruby: {
+    module: "namespace",
+  },
+};
+
+/**
+ * Kinds whose directly nested functions are methods.
+ */
+const METHOD_CONTAINER_KINDS: ReadonlySet<SymbolKind> = new Set(["class", "interface"]);
+
+/**
+ * Context available when resolving the kind of a freshly extracted entity.
+ */
+export interface SymbolKindContext {
+  /** AST node type the entity was extracted from */
+  nodeType?: string;
+  /** Kind of the enclosing symbol, if any */
+  parentKind?: SymbolKind;
+}
+
+/**
+ * Resolve the language-neutral kind of an entity.
+ *
+ * @param entityType - Entity type assigned by the parser
+ * @param language - Language of the source file
+ * @param context - AST node type and enclosing symbol kind
+ * @returns The symbol kind
+ */
+export function resolveSymbolKind(
+  entityType: EntityType,
+  language: SupportedLanguage,
+  context: SymbolKindContext = {}
+): SymbolKind {
+  const override =
+    context.nodeType !== undefined
+      ? LANGUAGE_SYMBOL_KIND_OVERRIDES[language]?.[context.nodeType]
+      : undefined;
+  if (override) {
+    return override;
+  }
+
+  const kind = DEFAULT_SYMBOL_KINDS[entityType];
+  if (
+    kind === "function" &&
+    context.parentKind !== undefined &&
+    METHOD_CONTAINER_KINDS.has(context.parentKind)
+  ) {
+    return "method";
+  }
+  return kind;
+}
+
+/**
+ * Get the symbol kind of an entity, falling back to the default for its
+ * entity type when the parser did not record one.
+ *
+ * @param entity - Extracted code entity
+ * @returns The symbol kind
+ */
+export function getSymbolKind(entity: Pick<CodeEntity, "type" | "kind">): SymbolKind {
+  return entity.kind ?? DEFAULT_SYMBOL_KINDS[entity.type];
+}
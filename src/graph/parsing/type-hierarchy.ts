@@ -0,0 +1,147 @@
+/**
+ * Type hierarchy helpers.
+ *
+ * Turns the supertype, derive and generic parameter strings recorded at
+ * ingestion into structured form for type hierarchy queries:
+ *
+ * | Source                                   | Helper                 |
+ * | ---------------------------------------- | ---------------------- |
+ * | `extends Base`, `implements A, B<T>`     | `splitSupertypes`      |
+ * | `#[derive(Debug, Clone)]`                | `derivedTraits`        |
+ * | `<T: Clone + Send>`, `where K: Hash`     | `parseGenericBounds`   |
+ *
+ * @module graph/parsing/type-hierarchy
+ */
+
+import { attributePath } from "./entry-points.js";
+
+/**
+ * A generic parameter with the bounds placed on it.
+ */
+export interface GenericBound {
+  /** Parameter (or where-clause subject) as written, e.g. "T", "'a", "Vec<T>" */
+  name: string;
+  /** Bounds in declaration order, e.g. ["Clone", "Send"] */
+  bounds: string[];
+}
+
+/**
+ * Split on a separator at bracket depth zero only, so commas and `+` inside
+ * generic arguments and `Fn(A, B) -> C` signatures stay put.
+ */
+function splitTopLevel(text: string, separator: string): string[] {
+  const parts: string[] = [];
+  let depth = 0;
+  let start = 0;
+  for (let i = 0; i < text.length; i++) {
+    const ch = text[i];
+    if (ch === "<" || ch === "(" || ch === "[") depth++;
+    else if ((ch === ">" && text[i - 1] !== "-") || ch === ")" || ch === "]") depth--;
+    else if (depth === 0 && text.startsWith(separator, i)) {
+      parts.push(text.slice(start, i));
+      start = i + separator.length;
+    }
+  }
+  parts.push(text.slice(start));
+  return parts.map((part) => part.trim()).filter((part) => part.length > 0);
+}
+
+/**
+ * Split a parameter or predicate into its subject, bound list and bound
+ * separator at the first top-level `:` (Rust, bounds joined with `+`) or
+ * ` extends ` (TypeScript and Java, bounds joined with `&`).
+ */
+function splitSubject(text: string): [string, string | undefined, string] {
+  let depth = 0;
+  for (let i = 0; i < text.length; i++) {
+    const ch = text[i];
+    if (ch === "<" || ch === "(" || ch === "[") depth++;
+    else if ((ch === ">" && text[i - 1] !== "-") || ch === ")" || ch === "]") depth--;
+    else if (depth === 0 && ch === ":" && text[i + 1] !== ":" && text[i - 1] !== ":") {
+      return [text.slice(0, i).trim(), text.slice(i + 1), "+"];
+    } else if (depth === 0 && text.startsWith(" extends ", i)) {
+      return [text.slice(0, i).trim(), text.slice(i + " extends ".length), "&"];
+    }
+  }
+  return [text.trim(), undefined, "+"];
+}
+
+/**
+ * Supertype names from an extends/implements clause as recorded by the
+ * parser, with the leading keyword removed
+ * ("implements Runnable, Comparable<Task>" → ["Runnable", "Comparable<Task>"]).
+ */
+export function splitSupertypes(clauses: readonly string[]): string[] {
+  return clauses.flatMap((clause) =>
+    splitTopLevel(clause.replace(/^\s*(?:extends|implements)\b/, ""), ",")
+  );
+}
+
+/**
+ * Traits listed in `#[derive(...)]` attributes, in source order
+ * (["derive(Debug, Clone)", "serde(rename_all = \"camelCase\")"] → ["Debug", "Clone"]).
+ */
+export function derivedTraits(attributes: readonly string[]): string[] {
+  return attributes.flatMap((attribute) => {
+    const argsStart = attribute.indexOf("(");
+    if (attributePath(attribute) !== "derive" || argsStart === -1) {
+      return [];
+    }
+    return splitTopLevel(attribute.slice(argsStart + 1, attribute.lastIndexOf(")")), ",");
+  });
+}
+
+/**
+ * Combine generic parameters and `where` predicates into one bound list per
+ * subject.
+ *
+ * Inline bounds (`T: Clone`) and where-clause bounds on the same parameter
+ * are merged; default values (`T = String`) and const parameter types are
+ * dropped. Where predicates on other subjects (`Vec<T>: Debug`) get their
+ * own entry.
+ *
+ * @param typeParameters - Generic parameters as written, e.g. ["T: Clone", "'a", "const N: usize"]
+ * @param whereClause - Where clause text including the keyword, or its predicates
+ * @returns Parameters in declaration order, followed by other where subjects
+ *
+ * @example
+ * ```typescript
+ * parseGenericBounds(["K", "V: Clone"], "where\n    K: Eq + Hash,");
+ * // [{ name: "K", bounds: ["Eq", "Hash"] }, { name: "V", bounds: ["Clone"] }]
+ * ```
+ */
+export function parseGenericBounds(
+  typeParameters: readonly string[],
+  whereClause?: string | readonly string[]
+): GenericBound[] {
+  const bySubject = new Map<string, string[]>();
+  const add = (subject: string, boundList: string | undefined, separator = "+"): void => {
+    const bounds = bySubject.get(subject) ?? [];
+    for (const bound of boundList !== undefined ? splitTopLevel(boundList, separator) : []) {
+      const normalized = bound.replace(/\s+/g, " ");
+      if (!bounds.includes(normalized)) bounds.push(normalized);
+    }
+    bySubject.set(subject, bounds);
+  };
+
+  for (const parameter of typeParameters) {
+    const withoutDefault = splitTopLevel(parameter, "=")[0] ?? "";
+    if (/^const\s/.test(withoutDefault)) {
+      add(splitSubject(withoutDefault.replace(/^const\s+/, ""))[0], undefined);
+      continue;
+    }
+    const [subject, bounds, separator] = splitSubject(withoutDefault);
+    if (subject.length > 0) add(subject, bounds, separator);
+  }
+
+  const predicates =
+    typeof whereClause === "string"
+      ? splitTopLevel(whereClause.replace(/^\s*where\b/, ""), ",")
+      : (whereClause ?? []);
+  for (const predicate of predicates) {
+    const [subject, bounds, separator] = splitSubject(predicate.replace(/\s+/g, " "));
+    if (subject.length > 0) add(subject, bounds, separator);
+  }
+
+  return [...bySubject].map(([name, bounds]) => ({ name, bounds }));
+}
@@ -0,0 +1,192 @@
+/**
+ * Type-string matching for type-based symbol search.
+ *
+ * Compares parameter and return type annotations exactly as the parser
+ * recorded them (e.g. "&str", "Result<Vec<Point>, Error>") against a query
+ * type. Whitespace differences are ignored. Exact matching requires the
+ * whole annotation to equal the query; contains matching also looks through
+ * reference wrappers (`&`, `&mut`, `&'a`, raw pointers) and generic
+ * arguments, so "Result" and "Point" both match "Result<Vec<Point>, Error>".
+ *
+ * @module graph/parsing/type-matching
+ */
+
+/**
+ * How a type annotation matched a query type.
+ */
+export type TypeMatchType = "exact" | "contains";
+
+/**
+ * Canonicalize whitespace: runs collapse to one space and spaces around
+ * punctuation are dropped, so "Result< String , E >" becomes
+ * "Result<String,E>" while "&mut Point" keeps the space it needs.
+ */
+function normalizeType(type: string): string {
+  return type
+    .trim()
+    .replace(/\s+/g, " ")
+    .replace(/\s*([<>,()[\];&*:])\s*/g, "$1");
+}
+
+/**
+ * Strip leading reference, raw-pointer and trait-object wrappers (`&`,
+ * `&mut`, `&'a`, `&'a mut`, `*const`, `*mut`, `dyn`, `impl`) from a
+ * normalized type.
+ */
+function stripReferences(type: string): string {
+  let current = type;
+  for (;;) {
+    const next = current
+      .replace(/^&(?:'[A-Za-z_]\w*\s?)?(?:mut(?:\s|(?=[[(])))?/, "")
+      .replace(/^\*(?:const|mut)\s/, "")
+      .replace(/^(?:dyn|impl)\s/, "");
+    if (next === current) return current;
+    current = next;
+  }
+}
+
+/**
+ * Split a normalized type list on top-level commas only.
+ */
+function splitTopLevel(list: string): string[] {
+  const parts: string[] = [];
+  let depth = 0;
+  let start = 0;
+  for (let i = 0; i < list.length; i++) {
+    const ch = list[i];
+    if (ch === "<" || ch === "(" || ch === "[") depth++;
+    else if (ch === ">" || ch === ")" || ch === "]") depth--;
+    else if (ch === "," && depth === 0) {
+      parts.push(list.slice(start, i));
+      start = i + 1;
+    }
+  }
+  parts.push(list.slice(start));
+  return parts.filter((part) => part.length > 0);
+}
+
+/**
+ * Collect every type nested in a normalized annotation: the annotation
+ * itself with references stripped, its base name (before any generic
+ * arguments), and recursively each generic, tuple, slice or array element.
+ */
+function collectComponents(type: string, out: Set<string>): void {
+  const stripped = stripReferences(type);
+  if (stripped.length === 0) return;
+  out.add(stripped);
+
+  const genericStart = stripped.indexOf("<");
+  if (genericStart > 0 && stripped.endsWith(">")) {
+    out.add(stripped.slice(0, genericStart));
+    for (const arg of splitTopLevel(stripped.slice(genericStart + 1, -1))) {
+      collectComponents(arg, out);
+    }
+    return;
+  }
+
+  // Tuples "(A, B)", slices "[T]" and arrays "[T; N]"
+  if (
+    (stripped.startsWith("(") && stripped.endsWith(")")) ||
+    (stripped.startsWith("[") && stripped.endsWith("]"))
+  ) {
+    const inner = stripped.slice(1, -1).split(";")[0] ?? "";
+    for (const element of splitTopLevel(inner)) {
+      collectComponents(element, out);
+    }
+  }
+}
+
+/**
+ * Last segment of a path-qualified type name ("std::string::String" → "String").
+ */
+function lastPathSegment(type: string): string {
+  const index = type.lastIndexOf("::");
+  return index === -1 ? type : type.slice(index + 2);
+}
+
+/**
+ * Match a type annotation against a query type.
+ *
+ * @param annotation - Type as recorded by the parser (e.g. "&mut Vec<Point>")
+ * @param query - Type to look for (e.g. "Vec", "&str", "Result<String>")
+ * @param contains - Also match through reference and generic wrappers
+ * @returns How the annotation matched, or null when it does not
+ *
+ * @example
+ * ```typescript
+ * matchType("&str", "&str", false);                    // "exact"
+ * matchType("Result<String, Error>", "Result", false); // null
+ * matchType("Result<String, Error>", "Result", true);  // "contains"
+ * matchType("&'a mut Point", "Point", true);           // "contains"
+ * ```
+ */
+export function matchType(
+  annotation: string,
+  query: string,
+  contains: boolean
+): TypeMatchType | null {
+  const candidate = normalizeType(annotation);
+  const wanted = normalizeType(query);
+  if (candidate.length === 0 || wanted.length === 0) return null;
+
+  if (candidate === wanted) return "exact";
+  if (!contains) return null;
+
+  const wantedStripped = stripReferences(wanted);
+  const components = new Set<string>();
+  collectComponents(candidate, components);
+
+  for (const component of components) {
+    if (component === wantedStripped || lastPathSegment(component) === wantedStripped) {
+      return "contains";
+    }
+  }
+
+  // "Result<String>" also matches "Result<String, Error>" by base name + args
+  const wantedGeneric = wantedStripped.indexOf("<");
+  if (wantedGeneric > 0) {
+    const wantedBase = wantedStripped.slice(0, wantedGeneric);
+    const wantedArgs = new Set<string>();
+    collectComponents(wantedStripped, wantedArgs);
+    wantedArgs.delete(wantedStripped);
+    wantedArgs.delete(wantedBase);
+    const baseMatched = [...components].some(
+      (component) => component === wantedBase || lastPathSegment(component) === wantedBase
+    );
+    if (baseMatched && [...wantedArgs].every((arg) => components.has(arg))) {
+      return "contains";
+    }
+  }
+
+  return null;
+}
+
+/**
+ * Named types an annotation refers to, path-qualified as written, in order
+ * of appearance and without duplicates. Bounds of trait objects and `impl`
+ * types and associated type bindings (`Iterator<Item = Point>`) are
+ * included; lifetimes are not.
+ *
+ * @example
+ * ```typescript
+ * referencedTypeNames("&[Option<geometry::Point>]"); // ["Option", "geometry::Point"]
+ * referencedTypeNames("Box<dyn Shape + Send>");      // ["Box", "Shape", "Send"]
+ * ```
+ */
+export function referencedTypeNames(annotation: string): string[] {
+  const components = new Set<string>();
+  collectComponents(normalizeType(annotation), components);
+
+  const names = new Set<string>();
+  for (const component of components) {
+    // Split `Shape + Send` bounds, and drop `Item = ` from associated type bindings
+    const bounds = /[<([]/.test(component) ? [component] : component.split(/\s*\+\s*/);
+    for (const bound of bounds) {
+      const name = stripReferences(bound.replace(/^[A-Za-z_]\w*\s?=\s?/, ""));
+      if (/^[A-Za-z_]\w*(?:::[A-Za-z_]\w*)*$/.test(name)) {
+        names.add(name);
+      }
+    }
+  }
+  return [...names];
+}
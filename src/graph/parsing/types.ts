@@ -7,6 +7,8 @@
  * @module graph/parsing/types
  */
 
+import type { SymbolKind } from "./symbol-kinds.js";
+
 /**
  * Languages supported by tree-sitter for AST parsing.
  *
@@ -67,6 +69,139 @@ export interface ParameterInfo {
   isRest: boolean;
 }
 
+/**
+ * How a program, test harness or benchmark runner enters the code.
+ *
+ * - `main`: a free `fn main`
+ * - `async_main`: a function under a runtime attribute such as `#[tokio::main]`
+ * - `test`: a `#[test]` (or runtime `#[tokio::test]`) function
+ * - `bench`: a `#[bench]` function
+ */
+export type EntryPointKind = "main" | "async_main" | "test" | "bench";
+
+/**
+ * Entry point marker attached to function metadata.
+ */
+export interface EntryPoint {
+  /** Kind of entry point */
+  kind: EntryPointKind;
+  /** Attribute path that made the function an entry point (e.g. "tokio::main") */
+  attribute?: string;
+}
+
+/**
+ * A range of source lines.
+ */
+export interface LineSpan {
+  /** First line of the range (1-based) */
+  lineStart: number;
+  /** Last line of the range (1-based, inclusive) */
+  lineEnd: number;
+}
+
+/**
+ * A range of source text with columns, as an editor selects it.
+ *
+ * Columns are tree-sitter byte offsets, which match editor character offsets
+ * on ASCII lines.
+ */
+export interface SourceRange extends LineSpan {
+  /** Column of the first character (0-based) */
+  columnStart: number;
+  /** Column just past the last character (0-based) */
+  columnEnd: number;
+}
+
+/**
+ * Kind of a literal constant value.
+ */
+export type LiteralValueKind = "integer" | "float" | "string" | "bool";
+
+/**
+ * The value of a constant initialized with a literal, e.g. `1024` in
+ * `const MAX_SIZE: usize = 1024;` (see `graph/parsing/literal-values`).
+ */
+export type LiteralValue =
+  | { kind: "integer"; value: number }
+  | { kind: "float"; value: number }
+  | { kind: "string"; value: string }
+  | { kind: "bool"; value: boolean };
+
+/**
+ * A local variable bound by a `let` in a function body (Rust).
+ */
+export interface LocalBinding {
+  /** Bound name */
+  name: string;
+  /**
+   * Declared type, or the type the initializer constructs (`Point` for
+   * `Point::new(..)` or `Point { .. }`); absent when neither says
+   */
+  type?: string;
+  /** Line of the `let` (1-based) */
+  line: number;
+}
+
+/**
+ * A variant declared in an enum body (Rust).
+ */
+export interface EnumVariant {
+  /** Variant name */
+  name: string;
+  /**
+   * Explicit discriminant expression as written after `=`, e.g. "1" or "0x10";
+   * absent for variants that rely on implicit numbering or carry data
+   */
+  discriminant?: string;
+  /** Line number where the variant is declared (1-based) */
+  line: number;
+  /** Full extent of the variant, including any fields and discriminant */
+  range?: SourceRange;
+}
+
+/**
+ * How rustdoc tests a doc comment example, from its fence info string.
+ *
+ * - `run`: compiled and run (no attribute, or `rust`)
+ * - `no_run`: compiled but not run
+ * - `ignore`: neither compiled nor run (`ignore`, `ignore-<target>`)
+ * - `should_panic`: run and expected to panic
+ * - `compile_fail`: expected to fail to compile
+ */
+export type DoctestMode = "run" | "no_run" | "ignore" | "should_panic" | "compile_fail";
+
+/**
+ * A Rust code example from a doc comment, parsed as an embedded snippet.
+ *
+ * Only fenced blocks rustdoc treats as Rust are recorded; blocks tagged with
+ * another language (e.g. `text` or `toml`) are not code examples.
+ */
+export interface Doctest extends LineSpan {
+  /** Fence info string as written, e.g. "rust,no_run" or "" */
+  info: string;
+  /** How rustdoc tests the example */
+  mode: DoctestMode;
+  /** Example code with rustdoc's hidden-line markers (`# `) removed */
+  code: string;
+  /** Names of the functions and methods the example calls, deduplicated */
+  calls: string[];
+  /** Names of the items the example itself defines (e.g. a wrapping `fn main`) */
+  definedSymbols: string[];
+}
+
+/**
+ * Shape of a function body, for finding duplicated logic; see
+ * `graph/parsing/structural-fingerprint`.
+ */
+export interface StructuralFingerprint {
+  /** Hash of the normalized node-type stream; equal for exact structural clones */
+  hash: string;
+  /** Number of parse tree nodes in the body */
+  size: number;
+  /** MinHash signature of the stream's 5-grams, for estimating near-clone similarity */
+  minHash: number[];
+}
+
 /**
  * Additional metadata for code entities.
  *
@@ -92,10 +227,98 @@ export interface EntityMetadata {
   implements?: string[];
   /** Generic type parameters */
   typeParameters?: string[];
+  /**
+   * Supertraits a trait requires, in declaration order (Rust), from both
+   * `trait Foo: Display + Clone` and `where Self: Display` forms. Lifetime
+   * bounds are omitted.
+   */
+  supertraits?: string[];
+  /**
+   * Generic `where` clause exactly as written, including the `where` keyword
+   * (Rust), e.g. "where\n    K: Eq + std::hash::Hash,"
+   */
+  whereClause?: string;
+  /**
+   * Outer attributes as written inside `#[...]` (Rust), in source order,
+   * e.g. ["derive(Debug, Clone)", "tokio::main"]
+   */
+  attributes?: string[];
+  /**
+   * Conditional compilation condition gating the item (Rust): its `cfg`
+   * attributes and those of the enclosing impl, trait or module, joined
+   * with `all`, e.g. `all(unix, feature = "serde")`
+   */
+  cfg?: string;
+  /**
+   * Visibility modifier of the item (Rust), normalized, e.g. "pub",
+   * "pub(crate)" or "pub(in crate::geometry)"; absent for private items
+   */
+  visibility?: string;
+  /** Set when the function is a program, test or benchmark entry point */
+  entryPoint?: EntryPoint;
+  /** Whether the function or trait is declared `unsafe` (Rust) */
+  isUnsafe?: boolean;
+  /**
+   * `unsafe { ... }` blocks in the function body, in source order (Rust).
+   * Blocks inside nested function items belong to those functions.
+   */
+  unsafeBlocks?: LineSpan[];
+  /**
+   * Approximate cyclomatic complexity of the function body (Rust); see
+   * `graph/parsing/complexity` for the counting rules.
+   */
+  complexity?: number;
+  /** Structural fingerprint of the function body (Rust) */
+  structure?: StructuralFingerprint;
+  /**
+   * Locals bound by `let` in the function body, in source order (Rust); see
+   * `graph/parsing/local-bindings` for how types are inferred
+   */
+  locals?: LocalBinding[];
+  /** Variants of an enum, in declaration order (Rust) */
+  variants?: EnumVariant[];
+  /**
+   * Value of a `const` or `static` whose initializer is a literal (Rust);
+   * absent when the initializer is any other expression
+   */
+  value?: LiteralValue;
+  /** Initializer of a `const` or `static` as written (Rust) */
+  valueText?: string;
+  /** Rust code examples from the doc comment, in source order (Rust) */
+  doctests?: Doctest[];
+  /**
+   * Trait implemented by the `impl` block declaring this method, as written
+   * (Rust); such methods are documented by the trait declaration
+   */
+  implementedTrait?: string;
   /** JSDoc comment, if present */
   documentation?: string;
+  /**
+   * Documentation as plain text: comment markers, decoration and common
+   * indentation removed, in any language. Only comments in the styles the
+   * language honors contribute (see `ParserConfig.docCommentStyles`).
+   */
+  doc?: string;
 }
 
+/**
+ * Syntax a documentation comment is written in.
+ *
+ * - `doc_block`: `/** ... *\/` (JSDoc, Javadoc, PHPDoc, rustdoc, Doxygen)
+ * - `doc_line`: `/// ...` (rustdoc, Doxygen, C# XML docs)
+ * - `inner_doc`: `//! ...` and `/*! ... *\/` (rustdoc module docs, Doxygen)
+ * - `line`: plain `// ...` or `# ...` comments
+ * - `block`: plain `/* ... *\/` comments
+ * - `docstring`: first string literal of a Python body
+ */
+export type DocCommentStyle =
+  | "doc_block"
+  | "doc_line"
+  | "inner_doc"
+  | "line"
+  | "block"
+  | "docstring";
+
 /**
  * A code entity extracted from source file AST.
  *
@@ -136,10 +359,23 @@ export interface CodeEntity {
   columnStart?: number;
   /** Ending column (0-based), if available */
   columnEnd?: number;
+  /** Extent of the identifier naming the entity, if it could be located */
+  nameRange?: SourceRange;
   /** Whether the entity is exported (export keyword) */
   isExported: boolean;
   /** Whether this is the default export */
   isDefault?: boolean;
+  /**
+   * Name of the enclosing entity (class, trait, function) or, for Rust impl
+   * items, the implementing type. Undefined for top-level declarations.
+   */
+  parentName?: string;
+  /**
+   * Language-neutral kind of the entity (see `graph/parsing/symbol-kinds`).
+   * Set by the tree-sitter parser; use `getSymbolKind` to read it with a
+   * fallback for entities from other parsers.
+   */
+  kind?: SymbolKind;
   /** Additional entity-specific metadata */
   metadata?: EntityMetadata;
 }
@@ -180,6 +416,11 @@ export interface ImportInfo {
   isTypeOnly: boolean;
   /** Whether this is a side-effect import (import './styles.css') */
   isSideEffect: boolean;
+  /**
+   * Visibility of a Rust `use` declaration that re-exports its names
+   * (e.g., "pub" for `pub use`); absent for private imports
+   */
+  visibility?: string;
   /** Line number where the import appears (1-based) */
   line: number;
 }
@@ -230,6 +471,16 @@ export interface ExportInfo {
  *   line: 20,
  *   callerName: 'handleEvent'
  * };
+ *
+ * // let n = s.parse::<u32>(); (Rust turbofish)
+ * const genericCall: CallInfo = {
+ *   calledName: 'parse',
+ *   calledExpression: 's.parse',
+ *   isAsync: false,
+ *   line: 25,
+ *   callerName: 'read_port',
+ *   typeArguments: ['u32']
+ * };
  * ```
  */
 export interface CallInfo {
@@ -245,6 +496,107 @@ export interface CallInfo {
   column?: number;
   /** Name of the containing function/method (caller context), if available */
   callerName?: string;
+  /**
+   * Explicit type arguments at the call site (e.g., ["String"] for `Foo::<String>::new()`).
+   * Only present when the call spells them out; inferred type arguments are not captured.
+   */
+  typeArguments?: string[];
+  /**
+   * Source text of each argument expression, in order (e.g., ['"World"', "3"]
+   * for `function_with_params("World", 3)`). Empty for calls without arguments;
+   * absent where the argument list is not parsed, such as calls inside macros.
+   */
+  arguments?: string[];
+}
+
+/**
+ * Information about an `impl` block.
+ *
+ * Captures trait implementations and inherent impls (Rust) including their
+ * generic parameters and `where` clause, so conditional and blanket impls
+ * are not mistaken for unconditional ones.
+ *
+ * @example
+ * ```typescript
+ * // impl<T> Animal for Wrapper<T> where T: Animal { ... }
+ * const implEdge: ImplEdge = {
+ *   traitName: 'Animal',
+ *   typeName: 'Wrapper<T>',
+ *   typeParameters: ['T'],
+ *   whereClause: ['T: Animal'],
+ *   line: 42
+ * };
+ * ```
+ */
+export interface ImplEdge {
+  /** Implemented trait as written (e.g., "Animal", "From<u8>"); undefined for inherent impls */
+  traitName?: string;
+  /** Implementing type as written (e.g., "Wrapper<T>") */
+  typeName: string;
+  /** Generic parameters declared on the impl (e.g., ["T", "U: Clone", "'a"]) */
+  typeParameters: string[];
+  /** Where-clause predicates with whitespace normalized (e.g., ["T: Animal"]) */
+  whereClause: string[];
+  /** Whether this is a negative impl (`impl !Send for T`) */
+  isNegative: boolean;
+  /** Whether this is an unsafe impl (`unsafe impl Send for T`) */
+  isUnsafe: boolean;
+  /** Line number where the impl starts (1-based) */
+  line: number;
+  /** Full extent of the impl block */
+  range?: SourceRange;
+}
+
+/**
+ * A marker comment (e.g. `// TODO: ...`) found in a source file.
+ *
+ * @example
+ * ```typescript
+ * // FIXME(auth): token refresh races with logout
+ * const todo: TodoComment = {
+ *   tag: 'FIXME',
+ *   message: 'token refresh races with logout',
+ *   line: 42
+ * };
+ * ```
+ */
+export interface TodoComment {
+  /** Marker tag as configured (e.g. "TODO", "FIXME") */
+  tag: string;
+  /** Text following the tag, with comment delimiters stripped */
+  message: string;
+  /** Line number of the marker (1-based) */
+  line: number;
+}
+
+/**
+ * A string literal found in a source file.
+ *
+ * Only extracted when `indexStringLiterals` is enabled.
+ *
+ * @example
+ * ```typescript
+ * // throw new Error("Session expired, please log in again");
+ * const literal: StringLiteral = {
+ *   value: 'Session expired, please log in again',
+ *   line: 87,
+ *   column: 20,
+ *   enclosingSymbol: 'AuthService.refresh'
+ * };
+ * ```
+ */
+export interface StringLiteral {
+  /** Literal contents without quotes or prefixes, truncated to 500 characters */
+  value: string;
+  /** Line number where the literal starts (1-based) */
+  line: number;
+  /** Column where the literal starts (0-based) */
+  column: number;
+  /**
+   * Innermost entity containing the literal, qualified with its parent
+   * (e.g. "AuthService.refresh"). Undefined for literals at file scope.
+   */
+  enclosingSymbol?: string;
 }
 
 /**
@@ -295,6 +647,14 @@ export interface ParseResult {
   exports: ExportInfo[];
   /** Function calls found in the file */
   calls: CallInfo[];
+  /** Impl blocks found in the file (Rust only) */
+  implementations?: ImplEdge[];
+  /** Marker comments matching the configured `todoTags` */
+  todos?: TodoComment[];
+  /** String literals, present only when `indexStringLiterals` is enabled */
+  strings?: StringLiteral[];
+  /** Documentation of the file's module, from its leading `//!` comments (Rust only) */
+  moduleDoc?: string;
   /** Time taken to parse the file in milliseconds */
   parseTimeMs: number;
   /** Any errors encountered during parsing */
@@ -313,6 +673,23 @@ export interface ParserConfig {
    */
   extractDocumentation?: boolean;
 
+  /**
+   * Maximum number of blank lines allowed between a doc comment and the
+   * declaration it documents (or between consecutive comment lines).
+   * Attributes such as `#[derive(...)]` between the comment and the item do
+   * not count. Comments further away are not attached.
+   * @default 1
+   */
+  maxDocCommentBlankLines?: number;
+
+  /**
+   * Doc comment styles to honor per language, replacing that language's
+   * defaults (`DEFAULT_DOC_COMMENT_STYLES`). For example `{ rust: ["doc_line"] }`
+   * leaves `//!` module docs and `/** *\/` blocks out of Rust entities' `doc`.
+   * @default {}
+   */
+  docCommentStyles?: Partial<Record<SupportedLanguage, DocCommentStyle[]>>;
+
   /**
    * Whether to include anonymous functions in entity extraction.
    * @default false
@@ -326,11 +703,54 @@ export interface ParserConfig {
    */
   maxFileSizeBytes?: number;
 
+  /**
+   * Maximum number of lines to parse. Files with more lines will be skipped.
+   * 0 disables the guard.
+   * @default 0
+   */
+  maxLines?: number;
+
+  /**
+   * Skip guards per language, overriding `maxFileSizeBytes` and `maxLines`
+   * for that language's files. For example
+   * `{ rust: { maxFileSizeBytes: 4194304 }, javascript: { maxLines: 20000 } }`
+   * admits large Rust files while skipping long generated JavaScript bundles.
+   * @default {}
+   */
+  languageGuards?: Partial<Record<SupportedLanguage, FileGuards>>;
+
   /**
    * Timeout for parsing a single file in milliseconds.
    * @default 5000
    */
   parseTimeoutMs?: number;
+
+  /**
+   * Comment marker tags to extract as TODO items (matched case-sensitively
+   * as whole words). An empty array disables TODO extraction.
+   * @default ["TODO", "FIXME"]
+   */
+  todoTags?: string[];
+
+  /**
+   * Whether to extract string literal contents for full-text search.
+   * Off by default because it considerably increases index size.
+   * @default false
+   */
+  indexStringLiterals?: boolean;
+}
+
+/**
+ * Limits beyond which a file is skipped instead of parsed.
+ *
+ * Unset fields fall back to the parser-wide setting of the same name.
+ */
+export interface FileGuards {
+  /** Maximum file size in bytes */
+  maxFileSizeBytes?: number;
+
+  /** Maximum number of lines; 0 disables the guard */
+  maxLines?: number;
 }
 
 /**
@@ -338,9 +758,15 @@ export interface ParserConfig {
  */
 export const DEFAULT_PARSER_CONFIG: Required<ParserConfig> = {
   extractDocumentation: true,
+  maxDocCommentBlankLines: 1,
+  docCommentStyles: {},
   includeAnonymous: false,
   maxFileSizeBytes: 1048576, // 1MB
+  maxLines: 0,
+  languageGuards: {},
   parseTimeoutMs: 5000,
+  todoTags: ["TODO", "FIXME"],
+  indexStringLiterals: false,
 };
 
 /**
@@ -279,12 +279,30 @@ export enum RelationshipType {
   /** Class extends another class */
   EXTENDS = "EXTENDS",
 
+  /** Trait is a supertrait required by another trait (Rust) */
+  SUPERTRAIT_OF = "SUPERTRAIT_OF",
+
   /** File references another file (e.g., documentation link) */
   REFERENCES = "REFERENCES",
 
   /** File has an associated vector chunk */
   HAS_CHUNK = "HAS_CHUNK",
 
+  /** File contains a TODO/FIXME marker comment */
+  HAS_TODO = "HAS_TODO",
+
+  /** File contains a string literal (indexed only when enabled) */
+  HAS_STRING_LITERAL = "HAS_STRING_LITERAL",
+
+  /** File contains an impl block (Rust) */
+  HAS_IMPL = "HAS_IMPL",
+
+  /** File contains a doc comment code example (Rust) */
+  HAS_DOCTEST = "HAS_DOCTEST",
+
+  /** Doc comment code example documents an entity (Rust) */
+  DOCTEST_OF = "DOCTEST_OF",
+
   /** Concept is related to another concept */
   RELATED_TO = "RELATED_TO",
 
@@ -509,6 +527,12 @@ export interface GraphDependenciesInput {
 
   /** Maximum depth for transitive analysis (default: 3) */
   maxDepth?: number;
+
+  /**
+   * Return each entity once, for the first relationship found (default: true).
+   * When false, every relationship and path reaching an entity is returned.
+   */
+  dedup?: boolean;
 }
 
 /**
@@ -529,6 +553,15 @@ export interface DependencyInfo {
 
   /** Depth from the target (1 = direct, 2+ = transitive) */
   depth: number;
+
+  /** Graph node ID of the entity */
+  id?: string;
+
+  /**
+   * Identifiers of the entities between the target and this entity, nearest
+   * the target first; empty for direct relationships
+   */
+  via?: string[];
 }
 
 /**
@@ -17,6 +17,7 @@ export {
 // Types
 export type {
   HealthResponse,
+  LanguageStatus,
   ExpressMiddleware,
   ExpressErrorMiddleware,
   HttpServerInstance,
@@ -7,7 +7,7 @@
 
 import { Router } from "express";
 import type { Request, Response } from "express";
-import type { HealthResponse } from "../types.js";
+import type { HealthResponse, LanguageStatus } from "../types.js";
 import { getComponentLogger } from "../../logging/index.js";
 
 /**
@@ -33,6 +33,8 @@ export interface HealthCheckDependencies {
   checkChromaDb: () => Promise<boolean>;
   /** Check Neo4j connectivity (optional - gracefully degrades if not configured) */
   checkNeo4j?: () => Promise<boolean>;
+  /** Report tree-sitter grammar availability (optional - only if graph parsing is configured) */
+  getLanguageStatus?: () => LanguageStatus;
 }
 
 /**
@@ -94,6 +96,7 @@ export function createHealthRouter(deps: HealthCheckDependencies): Router {
             neo4j: neo4jHealthy ? "connected" : "disconnected",
           }),
         },
+        ...(deps.getLanguageStatus && { languages: deps.getLanguageStatus() }),
       };
 
       const statusCode = status === "healthy" ? 200 : 503;
@@ -29,7 +29,7 @@ import {
   createOidcRouter,
 } from "./routes/index.js";
 import type { HttpTransportConfig } from "../mcp/types.js";
-import type { HttpServerInstance } from "./types.js";
+import type { HttpServerInstance, LanguageStatus } from "./types.js";
 import { getComponentLogger } from "../logging/index.js";
 import type { TokenService } from "../auth/types.js";
 import { createAuthMiddleware } from "../auth/middleware.js";
@@ -64,6 +64,9 @@ export interface HttpServerDependencies {
   /** Health check function for Neo4j (optional - only if Neo4j is configured) */
   checkNeo4j?: () => Promise<boolean>;
 
+  /** Tree-sitter grammar status (optional - only if graph parsing is configured) */
+  getLanguageStatus?: () => LanguageStatus;
+
   /** Token service for authentication (optional for backward compatibility) */
   tokenService?: TokenService;
 
@@ -123,6 +126,7 @@ export function createHttpApp(deps: HttpServerDependencies): Express {
     createHealthRouter({
       checkChromaDb: deps.checkChromaDb,
       checkNeo4j: deps.checkNeo4j,
+      getLanguageStatus: deps.getLanguageStatus,
     })
   );
 
@@ -30,6 +30,23 @@ export interface HealthResponse {
     /** Neo4j connection status (optional - only present if Neo4j is configured) */
     neo4j?: "connected" | "disconnected";
   };
+
+  /**
+   * Tree-sitter grammar status (optional - only present if graph parsing is configured)
+   *
+   * Languages whose grammar failed to load are disabled; the rest stay usable.
+   */
+  languages?: LanguageStatus;
+}
+
+/**
+ * Tree-sitter grammar availability
+ */
+export interface LanguageStatus {
+  /** Languages whose grammar is loaded */
+  active: string[];
+  /** Languages whose grammar failed to load, with the failure reason */
+  disabled: Record<string, string>;
 }
 
 /**
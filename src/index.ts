@@ -24,19 +24,28 @@ import {
 import { evaluateRecoveryStrategy } from "./services/interrupted-update-recovery.js";
 import { pruneOrphanManifests } from "./services/orphan-manifest-reaper.js";
 import { FileManifestStoreImpl } from "./services/file-manifest-store.js";
+import { IndexCheckpointStoreImpl } from "./services/index-checkpoint-store.js";
 import {
   createHttpApp,
   startHttpServer,
   loadHttpConfig,
   startSessionCleanup,
   startStreamableSessionCleanup,
+  type LanguageStatus,
 } from "./http/index.js";
 import { loadInstanceConfig, getEnabledInstances } from "./config/index.js";
 import { createGraphAdapter } from "./graph/adapters/index.js";
 import type { GraphStorageAdapter } from "./graph/adapters/types.js";
 import { createInstanceRouter } from "./mcp/instance-router.js";
 import { GraphServiceImpl } from "./services/graph-service.js";
+import { GraphAnalysisServiceImpl } from "./services/graph-analysis-service.js";
+import {
+  SymbolDiffServiceImpl,
+  DEFAULT_SNAPSHOT_HISTORY_RETENTION,
+} from "./services/symbol-diff-service.js";
+import { SymbolSnapshotStoreImpl } from "./services/symbol-snapshot-store.js";
 import type { GraphService } from "./services/graph-service-types.js";
+import type { GraphAnalysisService } from "./services/graph-analysis-types.js";
 
 // Incremental update dependencies
 import { FileChunker } from "./ingestion/file-chunker.js";
@@ -50,16 +59,20 @@ import { LocalFolderUpdateCoordinator } from "./services/local-folder-update-coo
 import { IndexCompletenessChecker } from "./services/index-completeness-checker.js";
 import { MCPRateLimiter } from "./mcp/rate-limiter.js";
 import { JobTracker } from "./mcp/job-tracker.js";
+import { loadToolConcurrencyConfig } from "./mcp/concurrency-limiter.js";
 import { GraphIngestionService } from "./graph/ingestion/GraphIngestionService.js";
+import { parseFileGuards, parseTodoTags } from "./graph/extraction/types.js";
+import { parseCargoWorkspace } from "./graph/ingestion/crate-roots.js";
 import { EntityExtractor } from "./graph/extraction/EntityExtractor.js";
 import { RelationshipExtractor } from "./graph/extraction/RelationshipExtractor.js";
+import { LanguageLoader } from "./graph/parsing/LanguageLoader.js";
 import { resolveGitHubPAT } from "./services/github-pat-resolver.js";
 import { DocumentSearchServiceImpl } from "./services/document-search-service.js";
 import { FolderWatcherService } from "./services/folder-watcher-service.js";
 import { WatchedFolderStoreImpl } from "./services/watched-folder-store.js";
 import { ChangeDetectionService } from "./services/change-detection-service.js";
 import { FolderDocumentIndexingService } from "./services/folder-document-indexing-service.js";
-import { FolderEventRouter } from "./services/folder-event-router.js";
+import { FolderEventRouter, resolveLocalFolderDebounceMs } from "./services/folder-event-router.js";
 import { LocalFolderRepoWatchManager } from "./services/local-folder-repo-watch-manager.js";
 import { ListWatchedFoldersServiceImpl } from "./services/list-watched-folders-service.js";
 import { DocumentTypeDetector } from "./documents/DocumentTypeDetector.js";
@@ -226,23 +239,47 @@ async function main(): Promise<void> {
 
     // Step 3c: Initialize graph service (if adapter available)
     let graphService: GraphService | undefined;
+    let graphAnalysisService: GraphAnalysisService | undefined;
     let graphIngestionService: GraphIngestionService | undefined;
     if (graphAdapter) {
-      graphService = new GraphServiceImpl(graphAdapter);
+      graphService = new GraphServiceImpl(graphAdapter, {
+        cache: {
+          maxMemoryBytes: parseInt(Bun.env["GRAPH_CACHE_MAX_MEMORY_BYTES"] || "0", 10),
+        },
+      });
+      graphAnalysisService = new GraphAnalysisServiceImpl(graphAdapter);
       logger.info("Graph service initialized");
 
       // Hoisted out of the per-feature blocks below so every consumer
       // (incremental pipeline, register_local_folder ingestion service)
       // shares one instance and the Phase 5 graph step in `IngestionService`
       // (issue #580) actually fires when graph storage is configured.
-      const entityExtractor = new EntityExtractor();
-      const relationshipExtractor = new RelationshipExtractor();
+      const fileGuards = parseFileGuards(Bun.env["GRAPH_FILE_GUARDS"]);
+      const entityExtractor = new EntityExtractor({
+        todoTags: parseTodoTags(Bun.env["GRAPH_TODO_TAGS"]),
+        indexStringLiterals: Bun.env["GRAPH_INDEX_STRING_LITERALS"] === "true",
+        ...fileGuards,
+      });
+      const relationshipExtractor = new RelationshipExtractor(fileGuards);
       graphIngestionService = new GraphIngestionService(
         graphAdapter,
         entityExtractor,
-        relationshipExtractor
+        relationshipExtractor,
+        {
+          normalizeSignatures: Bun.env["GRAPH_NORMALIZE_SIGNATURES"] !== "false",
+          captureCallArguments: Bun.env["GRAPH_CAPTURE_CALL_ARGUMENTS"] === "true",
+        }
       );
       logger.debug("Graph ingestion service initialized");
+
+      // Load tree-sitter grammars up front. A grammar that fails to load only
+      // disables its own language; startup continues with the rest.
+      const grammarResults = await LanguageLoader.getInstance().preloadAllLanguages();
+      const activeLanguages = [...grammarResults].filter(([, ok]) => ok).map(([lang]) => lang);
+      logger.info(
+        { active: activeLanguages.length, total: grammarResults.size },
+        "Tree-sitter grammars loaded"
+      );
     }
 
     // Step 4: Initialize repository metadata service (singleton)
@@ -250,6 +287,23 @@ async function main(): Promise<void> {
     const repositoryService = RepositoryMetadataStoreImpl.getInstance(config.data.path);
     logger.info("Repository metadata service initialized");
 
+    // Symbol diff service for diff_symbols_between_refs, scope_to_diff,
+    // snapshot and what_changed. It parses changed files from git blobs, so
+    // ref diffs work without graph storage; when the graph is available,
+    // indexed base symbols are reused and snapshots can be taken.
+    const symbolDiffService = new SymbolDiffServiceImpl(
+      repositoryService,
+      new EntityExtractor(),
+      graphAdapter,
+      {
+        snapshotStore: SymbolSnapshotStoreImpl.getInstance(config.data.path),
+        historyRetention: parseInt(
+          Bun.env["SNAPSHOT_HISTORY_RETENTION"] || String(DEFAULT_SNAPSHOT_HISTORY_RETENTION),
+          10
+        ),
+      }
+    );
+
     // Step 4a: Reap orphan FileManifests left behind by crashed registrations.
     // A `local-folder` registration that crashes between manifest write and
     // metadata write would otherwise leave a manifest the metadata store has
@@ -418,7 +472,13 @@ async function main(): Promise<void> {
     // router subscribes to `folderWatcherService.onFileEvent` alongside Phase
     // 6's `ChangeDetectionService`; each subscriber inspects `event.folderId`
     // and acts only on the namespace it owns.
-    const localFolderWatchManager = new LocalFolderRepoWatchManager(folderWatcherService);
+    const localFolderDebounceMs = resolveLocalFolderDebounceMs(
+      Bun.env["LOCAL_FOLDER_WATCH_DEBOUNCE_MS"]
+    );
+    const localFolderWatchManager = new LocalFolderRepoWatchManager(
+      folderWatcherService,
+      localFolderDebounceMs
+    );
 
     // Default local-folder coordinator (no graph ingestion) — used when no
     // GITHUB_PAT is set. The PAT branch below replaces this with a
@@ -439,26 +499,34 @@ async function main(): Promise<void> {
     // up the new coordinator on the next event without re-wiring. The guard
     // (review M-2) handles the case where the PAT branch's catch sets the
     // coordinator back to `undefined` mid-session.
-    const folderEventRouter = new FolderEventRouter(repositoryService, async (repo) => {
-      if (!localFolderCoordinator) {
-        logger.warn(
-          { repository: repo.name },
-          "Local-folder watcher dispatch fired with no coordinator wired; dropping event"
-        );
-        return;
-      }
-      try {
-        await localFolderCoordinator.updateRepository(repo.name);
-      } catch (error) {
-        logger.error(
-          {
-            repository: repo.name,
-            error: error instanceof Error ? error.message : String(error),
-          },
-          "Local-folder watcher dispatch failed"
+    const folderEventRouter = new FolderEventRouter(
+      repositoryService,
+      async (repo, changes) => {
+        if (!localFolderCoordinator) {
+          logger.warn(
+            { repository: repo.name },
+            "Local-folder watcher dispatch fired with no coordinator wired; dropping event"
+          );
+          return;
+        }
+        logger.info(
+          { repository: repo.name, changeCount: changes.length },
+          "Local-folder watcher dispatching coalesced changes"
         );
-      }
-    });
+        try {
+          await localFolderCoordinator.updateRepository(repo.name);
+        } catch (error) {
+          logger.error(
+            {
+              repository: repo.name,
+              error: error instanceof Error ? error.message : String(error),
+            },
+            "Local-folder watcher dispatch failed"
+          );
+        }
+      },
+      { defaultDebounceMs: localFolderDebounceMs }
+    );
     folderWatcherService.onFileEvent(folderEventRouter.asEventHandler());
     logger.info("FolderEventRouter wired alongside Phase 6 ChangeDetectionService");
 
@@ -630,6 +698,8 @@ async function main(): Promise<void> {
         // configure FalkorDB/Neo4j — IngestionService gates the graph step
         // on this field, so ChromaDB-only behavior is preserved.
         graphIngestionService,
+        checkpointStore: IndexCheckpointStoreImpl.getInstance(config.data.path),
+        cargoWorkspace: parseCargoWorkspace(Bun.env["GRAPH_CARGO_WORKSPACE"]),
       }
     );
     logger.debug(
@@ -648,9 +718,12 @@ async function main(): Promise<void> {
         capabilities: {
           tools: true,
         },
+        concurrency: loadToolConcurrencyConfig(),
       },
       {
         graphService,
+        graphAnalysisService,
+        symbolDiffService,
         updateCoordinator,
         localFolderCoordinator,
         rateLimiter,
@@ -708,6 +781,12 @@ async function main(): Promise<void> {
         createServerForStreamableHttp: () => mcpServer.createServerForStreamableHttp(),
         checkChromaDb: () => chromaClient.healthCheck(),
         checkNeo4j: graphAdapter ? () => graphAdapter.healthCheck() : undefined,
+        getLanguageStatus: graphIngestionService
+          ? (): LanguageStatus => {
+              const status = LanguageLoader.getInstance().getStatus();
+              return { active: status.loadedLanguages, disabled: status.disabledLanguages };
+            }
+          : undefined,
       });
 
       const httpServer = await startHttpServer(app, httpConfig);
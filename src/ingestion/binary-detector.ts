@@ -0,0 +1,89 @@
+/**
+ * Heuristic detection of binary files with source-code extensions.
+ *
+ * Extension filtering lets through files that only look like source: a `.rs`
+ * that is really a compiled artifact, a `.ts` video container, a corrupted
+ * checkout. Reading them as UTF-8 yields replacement-character noise that the
+ * parser chokes on and the chunker embeds as junk. Text files essentially
+ * never contain NUL bytes while nearly all binary formats do, so a NUL byte in
+ * the first few kilobytes is a reliable, cheap signal (the same check `git`
+ * and `grep` use).
+ *
+ * @module ingestion/binary-detector
+ */
+
+import { open } from "fs/promises";
+
+/**
+ * Default number of leading bytes inspected for NUL bytes.
+ *
+ * Matches git's `FIRST_FEW_BYTES` sample size.
+ */
+export const DEFAULT_BINARY_DETECTION_BYTES = 8192;
+
+/**
+ * Result of a positive binary detection.
+ */
+export interface BinaryDetection {
+  /** Offset of the first NUL byte (or NUL character for string samples) */
+  offset: number;
+
+  /** Human-readable explanation of why the file was flagged */
+  detail: string;
+}
+
+/**
+ * Check whether the start of a file looks binary.
+ *
+ * Inspects at most `sampleBytes` leading bytes (or UTF-16 code units when
+ * given already-decoded text, e.g. from an archive source) for a NUL.
+ *
+ * @param sample - Leading file content as raw bytes or decoded text
+ * @param sampleBytes - Number of leading bytes to inspect; `0` disables detection
+ * @returns Detection details when a NUL is found, otherwise `null`
+ *
+ * @example
+ * ```typescript
+ * const detection = detectBinary(await readFileHead(path, 8192), 8192);
+ * if (detection) {
+ *   logger.debug({ path, detail: detection.detail }, "Skipping binary file");
+ * }
+ * ```
+ */
+export function detectBinary(
+  sample: Uint8Array | string,
+  sampleBytes: number = DEFAULT_BINARY_DETECTION_BYTES
+): BinaryDetection | null {
+  if (sampleBytes <= 0) return null;
+
+  const limit = Math.min(sample.length, sampleBytes);
+  for (let i = 0; i < limit; i++) {
+    const code = typeof sample === "string" ? sample.charCodeAt(i) : sample[i];
+    if (code === 0) {
+      return {
+        offset: i,
+        detail: `NUL byte at offset ${i} within the first ${sampleBytes} bytes`,
+      };
+    }
+  }
+
+  return null;
+}
+
+/**
+ * Read up to `length` leading bytes of a file without loading the rest.
+ *
+ * @param path - Absolute file path
+ * @param length - Maximum number of bytes to read
+ * @returns The bytes read (shorter than `length` for small files)
+ */
+export async function readFileHead(path: string, length: number): Promise<Uint8Array> {
+  const handle = await open(path, "r");
+  try {
+    const buffer = new Uint8Array(length);
+    const { bytesRead } = await handle.read(buffer, 0, length, 0);
+    return buffer.subarray(0, bytesRead);
+  } finally {
+    await handle.close();
+  }
+}
@@ -0,0 +1,103 @@
+/**
+ * Embedding input preparation.
+ *
+ * Embedding models reject (OpenAI) or silently cut (local models) inputs
+ * above their token limit. Text is therefore fitted to a token budget before
+ * it is sent: text over the budget keeps its leading part, cut at a line
+ * boundary. Chunks carry no separate signature or doc comment, so the whole
+ * chunk is cut as one body. The same input always yields the same output, so
+ * re-indexing unchanged content produces the same embedding.
+ *
+ * Token counts use the `estimateTokens` chars/4 heuristic shared with the
+ * chunkers, which can under-count token-dense content; budgets are kept
+ * below the provider's hard limit to leave headroom.
+ *
+ * @module ingestion/embedding-input
+ */
+
+import { estimateTokens } from "./chunk-utils.js";
+
+/**
+ * Environment variable overriding the embedding input token budget.
+ */
+export const EMBEDDING_MAX_INPUT_TOKENS_ENV = "EMBEDDING_MAX_INPUT_TOKENS";
+
+/**
+ * Embedding input fitted to a token budget.
+ */
+export interface PreparedEmbeddingInput {
+  /** Text to send to the embedding provider */
+  text: string;
+
+  /** Whether the text was cut to fit the budget */
+  truncated: boolean;
+
+  /** Estimated tokens of the untruncated input */
+  originalTokens: number;
+}
+
+/**
+ * Resolve the token budget for a single embedding input.
+ *
+ * Uses `EMBEDDING_MAX_INPUT_TOKENS` when set to a positive integer. Otherwise
+ * the budget is derived from `CHUNK_MAX_TOKENS` as twice the configured chunk
+ * size, floored at 6,000 and capped at 8,000 to stay under OpenAI's 8,192
+ * limit. Either way it never exceeds the provider's own limit.
+ *
+ * @param providerMaxTokens - `maxTokensPerText` of the embedding provider
+ * @returns Maximum estimated tokens per embedding input
+ */
+export function resolveEmbeddingInputTokens(providerMaxTokens?: number): number {
+  const override = parseInt(process.env[EMBEDDING_MAX_INPUT_TOKENS_ENV] ?? "", 10);
+  let budget: number;
+  if (!isNaN(override) && override > 0) {
+    budget = override;
+  } else {
+    const chunkMax = parseInt(process.env["CHUNK_MAX_TOKENS"] ?? "", 10);
+    const configured = !isNaN(chunkMax) && chunkMax > 0 ? chunkMax : 500;
+    budget = Math.min(8000, Math.max(6000, configured * 2));
+  }
+  return providerMaxTokens !== undefined && providerMaxTokens > 0
+    ? Math.min(budget, providerMaxTokens)
+    : budget;
+}
+
+/**
+ * Cut text to at most `maxTokens` estimated tokens.
+ *
+ * Keeps the leading characters and backs off to the last line break within
+ * them, unless that would drop more than half of what fits (a single very
+ * long line is cut mid-line instead).
+ *
+ * @param text - Text to cut
+ * @param maxTokens - Maximum estimated tokens to keep
+ * @returns The text unchanged when it fits, otherwise its cut prefix
+ */
+export function truncateToTokens(text: string, maxTokens: number): string {
+  if (estimateTokens(text) <= maxTokens) {
+    return text;
+  }
+  if (maxTokens <= 0) {
+    return "";
+  }
+
+  // estimateTokens counts code points, so slice by code point too
+  const head = [...text].slice(0, maxTokens * 4).join("");
+  const lineEnd = head.lastIndexOf("\n");
+  return lineEnd >= head.length / 2 ? head.slice(0, lineEnd) : head;
+}
+
+/**
+ * Fit embedding input to a token budget.
+ *
+ * @param text - Text to embed
+ * @param maxTokens - Maximum estimated tokens of the input
+ * @returns The text to embed and whether it was cut
+ */
+export function prepareEmbeddingInput(text: string, maxTokens: number): PreparedEmbeddingInput {
+  const originalTokens = estimateTokens(text);
+  if (originalTokens <= maxTokens) {
+    return { text, truncated: false, originalTokens };
+  }
+  return { text: truncateToTokens(text, maxTokens), truncated: true, originalTokens };
+}
@@ -0,0 +1,170 @@
+/**
+ * Decoding of source files that are not valid UTF-8.
+ *
+ * Most repositories are UTF-8, but older code often carries a Latin-1 or
+ * Windows-1252 comment or string literal. Reading such a file as UTF-8 should
+ * not cost the whole file: the invalid bytes are replaced with U+FFFD so the
+ * rest still parses, and the file is flagged with an encoding warning. A file
+ * where a large share of the bytes is invalid is not text in any encoding we
+ * could index, and is rejected instead.
+ *
+ * Replacement keeps every newline, so line numbers in a lossily decoded file
+ * match the original. Columns and character offsets do not: one replacement
+ * character stands in for each invalid byte. Warnings therefore report lines,
+ * and consumers should prefer line-based positions for flagged files.
+ *
+ * @module ingestion/encoding-detector
+ */
+
+import { UndecodableFileError } from "./errors.js";
+
+/**
+ * Default maximum share of invalid UTF-8 bytes a file may contain before it
+ * is treated as undecodable.
+ */
+export const DEFAULT_MAX_INVALID_BYTE_RATIO = 0.1;
+
+/**
+ * Invalid UTF-8 found in a file that was still decoded.
+ */
+export interface EncodingWarning {
+  /** Number of bytes that are not part of a valid UTF-8 sequence */
+  invalidBytes: number;
+
+  /** 1-based line of the first invalid byte */
+  firstLine: number;
+
+  /** Human-readable summary, e.g. "3 invalid UTF-8 bytes on 2 lines, first on line 14" */
+  detail: string;
+}
+
+/**
+ * File content decoded for indexing.
+ */
+export interface DecodedText {
+  /** Decoded text; invalid bytes are replaced with U+FFFD */
+  text: string;
+
+  /** Set when the file was not valid UTF-8 and was decoded lossily */
+  encodingWarning: EncodingWarning | null;
+}
+
+/**
+ * Decode file content as UTF-8, falling back to a lossy decode.
+ *
+ * A leading byte order mark is removed.
+ *
+ * @param bytes - Raw file content
+ * @param maxInvalidByteRatio - Largest share of invalid bytes that is still decoded
+ * @returns The decoded text and, for lossy decodes, a warning
+ * @throws {UndecodableFileError} If more than `maxInvalidByteRatio` of the bytes are invalid
+ *
+ * @example
+ * ```typescript
+ * const { text, encodingWarning } = decodeSourceText(await readFile(path));
+ * if (encodingWarning) {
+ *   logger.warn({ path, detail: encodingWarning.detail }, "File is not valid UTF-8");
+ * }
+ * ```
+ */
+export function decodeSourceText(
+  bytes: Uint8Array,
+  maxInvalidByteRatio: number = DEFAULT_MAX_INVALID_BYTE_RATIO
+): DecodedText {
+  try {
+    return { text: new TextDecoder("utf-8", { fatal: true }).decode(bytes), encodingWarning: null };
+  } catch {
+    // Not valid UTF-8; measure how much is invalid before decoding lossily
+  }
+
+  const { invalidBytes, lines } = findInvalidUtf8(bytes);
+  const lineCount = lines.length;
+  const firstLine = lines[0] ?? 1;
+  const detail =
+    `${invalidBytes} invalid UTF-8 byte${invalidBytes === 1 ? "" : "s"} on ` +
+    `${lineCount} line${lineCount === 1 ? "" : "s"}, first on line ${firstLine}`;
+
+  if (invalidBytes > bytes.length * maxInvalidByteRatio) {
+    throw new UndecodableFileError(
+      `${detail} (more than ${Math.round(maxInvalidByteRatio * 100)}% of the file)`
+    );
+  }
+
+  return {
+    text: new TextDecoder("utf-8").decode(bytes),
+    encodingWarning: { invalidBytes, firstLine, detail },
+  };
+}
+
+/**
+ * Find the bytes that are not part of a well-formed UTF-8 sequence.
+ *
+ * Follows the well-formed byte sequence table of the Unicode standard, so
+ * overlong encodings and surrogates count as invalid.
+ *
+ * @returns The number of invalid bytes and the distinct 1-based lines containing them
+ */
+function findInvalidUtf8(bytes: Uint8Array): { invalidBytes: number; lines: number[] } {
+  let invalidBytes = 0;
+  const lines: number[] = [];
+  let line = 1;
+  let i = 0;
+
+  while (i < bytes.length) {
+    const byte = bytes[i] ?? 0;
+    if (byte < 0x80) {
+      if (byte === 0x0a) line++;
+      i++;
+      continue;
+    }
+
+    const length = sequenceLength(bytes, i);
+    if (length > 0) {
+      i += length;
+      continue;
+    }
+
+    invalidBytes++;
+    if (lines[lines.length - 1] !== line) {
+      lines.push(line);
+    }
+    i++;
+  }
+
+  return { invalidBytes, lines };
+}
+
+/**
+ * Length of the well-formed multi-byte sequence starting at `start`, or 0 if
+ * the bytes there do not form one.
+ */
+function sequenceLength(bytes: Uint8Array, start: number): number {
+  const lead = bytes[start] ?? 0;
+  let length: number;
+  // Allowed range of the second byte, which rules out overlongs and surrogates
+  let secondMin = 0x80;
+  let secondMax = 0xbf;
+
+  if (lead >= 0xc2 && lead <= 0xdf) {
+    length = 2;
+  } else if (lead >= 0xe0 && lead <= 0xef) {
+    length = 3;
+    if (lead === 0xe0) secondMin = 0xa0;
+    if (lead === 0xed) secondMax = 0x9f;
+  } else if (lead >= 0xf0 && lead <= 0xf4) {
+    length = 4;
+    if (lead === 0xf0) secondMin = 0x90;
+    if (lead === 0xf4) secondMax = 0x8f;
+  } else {
+    return 0;
+  }
+
+  if (start + length > bytes.length) return 0;
+  const second = bytes[start + 1] ?? 0;
+  if (second < secondMin || second > secondMax) return 0;
+  for (let k = 2; k < length; k++) {
+    const continuation = bytes[start + k] ?? 0;
+    if (continuation < 0x80 || continuation > 0xbf) return 0;
+  }
+  return length;
+}
@@ -169,6 +169,19 @@ export class ChunkingError extends RepositoryError {
   }
 }
 
+/**
+ * Error thrown when a file's content cannot be decoded as text.
+ *
+ * Raised by `decodeSourceText` when too much of a file is invalid UTF-8 for a
+ * lossy decode to be useful. Callers skip the file and log the reason.
+ */
+export class UndecodableFileError extends RepositoryError {
+  constructor(message: string) {
+    super(message, "UNDECODABLE_FILE");
+    this.name = "UndecodableFileError";
+  }
+}
+
 /**
  * Error thrown when fetching latest changes from remote fails.
  *
@@ -29,9 +29,15 @@
  * @module ingestion/file-eligibility
  */
 
-import { stat as fsStat } from "node:fs/promises";
+import { stat as fsStat, readFile as fsReadFile } from "node:fs/promises";
 import type { Stats } from "node:fs";
 import type { GitignoreFilter } from "./gitignore-filter.js";
+import {
+  detectMinified,
+  mayExceedMinifiedThresholds,
+  type MinifiedThresholds,
+} from "./minified-detector.js";
+import { detectBinary, readFileHead } from "./binary-detector.js";
 
 /**
  * Default per-pattern exclusions applied by `FileScanner`. Mirrored here so
@@ -112,6 +118,29 @@ export interface FileEligibilityOptions {
    * Tests pass in fakes here; production callers should leave undefined.
    */
   stat?: (path: string) => Promise<Stats>;
+  /**
+   * When provided, files whose content trips the minified heuristic (see
+   * `detectMinified`) are excluded, matching `FileScanner`'s default.
+   * Leave undefined to skip the content read (e.g. the size guardrail, which
+   * only needs a conservative upper bound).
+   */
+  minifiedThresholds?: MinifiedThresholds;
+  /**
+   * Optional injection hook for reading file content during minified
+   * detection. Defaults to `node:fs/promises.readFile` (UTF-8).
+   */
+  readFile?: (path: string) => Promise<string>;
+  /**
+   * When provided, empty files and files with a NUL byte within this many
+   * leading bytes are excluded, matching `FileScanner`'s default. Leave
+   * undefined to skip the content read.
+   */
+  binaryDetectionBytes?: number;
+  /**
+   * Optional injection hook for reading a file's leading bytes during binary
+   * detection. Defaults to `readFileHead`.
+   */
+  readFileHead?: (path: string, length: number) => Promise<Uint8Array>;
 }
 
 /**
@@ -156,6 +185,8 @@ export function shouldDescendDir(
  *   - apply the gitignore filter
  *   - require the file extension to be in the whitelist
  *   - reject files larger than `maxSizeBytes`
+ *   - reject empty and likely binary files when `binaryDetectionBytes` is provided
+ *   - reject likely minified files when `minifiedThresholds` is provided
  *
  * `stat` is invoked only after the cheap predicates pass (extension and name
  * checks), to avoid an `fs.stat` per ineligible file.
@@ -205,5 +236,30 @@ export async function shouldIncludeFile(
   if (!st.isFile()) return { eligible: false };
   if (st.size > opts.maxSizeBytes) return { eligible: false };
 
+  if (opts.binaryDetectionBytes !== undefined) {
+    if (st.size === 0) return { eligible: false };
+    if (opts.binaryDetectionBytes > 0) {
+      const readHead = opts.readFileHead ?? readFileHead;
+      try {
+        const head = await readHead(absPath, opts.binaryDetectionBytes);
+        if (detectBinary(head, opts.binaryDetectionBytes)) return { eligible: false };
+      } catch {
+        return { eligible: false };
+      }
+    }
+  }
+
+  const thresholds = opts.minifiedThresholds;
+  if (thresholds && mayExceedMinifiedThresholds(st.size, thresholds)) {
+    const read = opts.readFile ?? ((p: string): Promise<string> => fsReadFile(p, "utf-8"));
+    let content: string;
+    try {
+      content = await read(absPath);
+    } catch {
+      return { eligible: false };
+    }
+    if (detectMinified(content, thresholds)) return { eligible: false };
+  }
+
   return { eligible: true, stats: st };
 }
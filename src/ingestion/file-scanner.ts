@@ -9,15 +9,24 @@
 
 import { glob } from "glob";
 import ignore from "ignore";
+import picomatch from "picomatch";
 import { stat, access, readFile } from "fs/promises";
 import { join, resolve, extname, sep, isAbsolute } from "path";
 import { posix } from "path";
 import type pino from "pino";
 import { getComponentLogger } from "../logging/index.js";
-import type { ScanOptions, FileInfo, FileScannerConfig } from "./types.js";
+import type { ScanOptions, FileInfo, FileScannerConfig, SkippedFile } from "./types.js";
 import { ValidationError, FileScanError } from "./errors.js";
 import { DEFAULT_EXTENSIONS } from "./default-extensions.js";
 import { GitignoreFilter } from "./gitignore-filter.js";
+import type { FileSource, FileSourceEntry } from "./file-source.js";
+import {
+  detectMinified,
+  mayExceedMinifiedThresholds,
+  DEFAULT_MINIFIED_THRESHOLDS,
+  type MinifiedThresholds,
+} from "./minified-detector.js";
+import { detectBinary, readFileHead, DEFAULT_BINARY_DETECTION_BYTES } from "./binary-detector.js";
 
 /**
  * Scans repository directories to identify files for indexing.
@@ -28,6 +37,8 @@ import { GitignoreFilter } from "./gitignore-filter.js";
  * - Configurable file extension filtering
  * - Default exclusion patterns (node_modules, build artifacts)
  * - File size filtering (>1MB excluded)
+ * - Minified/generated file detection (long-line heuristic)
+ * - Empty and binary file detection (NUL-byte heuristic)
  * - Cross-platform path handling
  * - Optional progress reporting
  *
@@ -54,6 +65,7 @@ import { GitignoreFilter } from "./gitignore-filter.js";
 export class FileScanner {
   private readonly logger: pino.Logger;
   private readonly config: Required<FileScannerConfig>;
+  private readonly minifiedThresholds: MinifiedThresholds;
 
   /**
    * Default patterns to exclude from scans.
@@ -90,6 +102,12 @@ export class FileScanner {
     this.config = {
       maxFileSizeBytes: config.maxFileSizeBytes ?? this.MAX_FILE_SIZE_BYTES,
       allowedBasePaths: config.allowedBasePaths ?? [],
+      minifiedThresholds: config.minifiedThresholds ?? {},
+      binaryDetectionBytes: config.binaryDetectionBytes ?? DEFAULT_BINARY_DETECTION_BYTES,
+    };
+    this.minifiedThresholds = {
+      ...DEFAULT_MINIFIED_THRESHOLDS,
+      ...this.config.minifiedThresholds,
     };
   }
 
@@ -98,12 +116,12 @@ export class FileScanner {
    *
    * Algorithm:
    * 1. Validate repository path exists and is accessible
-   * 2. Load .gitignore rules if present
+   * 2. Load .gitignore rules if present (unless `respectGitignore` is false)
    * 3. Build glob patterns from extensions
    * 4. Execute glob with exclusion patterns
    * 5. Apply gitignore filtering
    * 6. Stat each file for metadata
-   * 7. Filter by file size
+   * 7. Filter by file size, empty/binary content and minified-content heuristic
    * 8. Normalize paths to POSIX format
    * 9. Return sorted results
    *
@@ -144,10 +162,13 @@ export class FileScanner {
       // 2. Load .gitignore rules. For nested mode (local-folder / local-git),
       //    walk the tree to merge every .gitignore; otherwise use the cheap
       //    root-only loader that has shipped since before this feature.
-      const nestedFilter = options.respectNestedGitignore
-        ? await GitignoreFilter.load(normalizedRepoPath)
-        : null;
-      const gitignore = nestedFilter ? null : await this.loadGitignore(normalizedRepoPath);
+      const respectGitignore = options.respectGitignore !== false;
+      const nestedFilter =
+        respectGitignore && options.respectNestedGitignore
+          ? await GitignoreFilter.load(normalizedRepoPath)
+          : null;
+      const gitignore =
+        respectGitignore && !nestedFilter ? await this.loadGitignore(normalizedRepoPath) : null;
 
       // 3. Determine extensions and exclusions
       const extensions = options.includeExtensions ?? [...DEFAULT_EXTENSIONS];
@@ -161,7 +182,9 @@ export class FileScanner {
       // 5. Apply gitignore filtering
       const filteredPaths = nestedFilter
         ? this.applyNestedGitignoreFilter(normalizedRepoPath, matchedPaths, nestedFilter)
-        : this.applyGitignoreFilter(matchedPaths, gitignore!);
+        : gitignore
+          ? this.applyGitignoreFilter(matchedPaths, gitignore)
+          : matchedPaths;
 
       this.logger.debug(
         { before: matchedPaths.length, after: filteredPaths.length },
@@ -169,11 +192,7 @@ export class FileScanner {
       );
 
       // 6. Collect file metadata with progress reporting
-      const fileInfos = await this.collectFileMetadata(
-        normalizedRepoPath,
-        filteredPaths,
-        options.onProgress
-      );
+      const fileInfos = await this.collectFileMetadata(normalizedRepoPath, filteredPaths, options);
 
       // 7. Sort by relative path for consistent output
       fileInfos.sort((a, b) => a.relativePath.localeCompare(b.relativePath));
@@ -214,6 +233,140 @@ export class FileScanner {
     }
   }
 
+  /**
+   * Scan a FileSource (directory or archive) for indexable files.
+   *
+   * Applies the same filtering as scanFiles(): extensions, default and custom
+   * exclusions, dotfiles, the root .gitignore (unless `respectGitignore` is
+   * false), file size, empty and binary content, and the minified heuristic.
+   * Returned files carry their source, so content is read with
+   * `readFileContent()` rather than from `absolutePath`.
+   *
+   * @param source - Source to scan
+   * @param options - Scan configuration options (`respectNestedGitignore` is ignored)
+   * @returns Array of file metadata sorted by relative path
+   * @throws {FileScanError} If the source cannot be listed
+   *
+   * @example
+   * ```typescript
+   * const source = await ArchiveFileSource.open('/downloads/serde-1.0.0.crate');
+   * const files = await scanner.scanSource(source, { includeExtensions: ['.rs'] });
+   * const content = await readFileContent(files[0]);
+   * ```
+   */
+  async scanSource(source: FileSource, options: ScanOptions = {}): Promise<FileInfo[]> {
+    const startTime = Date.now();
+    this.logger.info({ source: source.location, kind: source.kind }, "Starting source scan");
+
+    let entries: FileSourceEntry[];
+    try {
+      entries = await source.listEntries();
+    } catch (error) {
+      if (error instanceof FileScanError) throw error;
+      throw new FileScanError(
+        `Failed to list source: ${error instanceof Error ? error.message : "unknown error"}`,
+        source.location,
+        error instanceof Error ? error : undefined
+      );
+    }
+
+    const extensions = options.includeExtensions ?? [...DEFAULT_EXTENSIONS];
+    const isExcluded = picomatch([...this.DEFAULT_EXCLUSIONS, ...(options.excludePatterns ?? [])]);
+    const gitignore = ignore();
+    if (
+      options.respectGitignore !== false &&
+      entries.some((entry) => entry.relativePath === ".gitignore")
+    ) {
+      gitignore.add(await source.readText(".gitignore"));
+    }
+
+    const candidates = entries.filter(
+      (entry) =>
+        extensions.some((ext) => entry.relativePath.endsWith(ext)) &&
+        !entry.relativePath.split("/").some((segment) => segment.startsWith(".")) &&
+        !isExcluded(entry.relativePath) &&
+        !gitignore.ignores(entry.relativePath)
+    );
+
+    const results: FileInfo[] = [];
+    for (const [i, entry] of candidates.entries()) {
+      if (entry.sizeBytes > this.config.maxFileSizeBytes) {
+        this.reportSkip(options.onSkip, {
+          relativePath: entry.relativePath,
+          reason: "oversized",
+          detail: `${entry.sizeBytes} bytes exceeds limit of ${this.config.maxFileSizeBytes} bytes`,
+        });
+        continue;
+      }
+
+      if (entry.sizeBytes === 0) {
+        this.logger.debug({ relativePath: entry.relativePath }, "Skipping empty file");
+        continue;
+      }
+
+      // Archive entries are only readable as decoded text; a NUL byte
+      // survives UTF-8 decoding unchanged, so the check still applies.
+      let content: string | undefined;
+      if (this.config.binaryDetectionBytes > 0) {
+        content = await source.readText(entry.relativePath);
+        const binary = detectBinary(content, this.config.binaryDetectionBytes);
+        if (binary) {
+          this.reportSkip(options.onSkip, {
+            relativePath: entry.relativePath,
+            reason: "binary",
+            detail: binary.detail,
+          });
+          continue;
+        }
+      }
+
+      if (
+        !options.includeMinified &&
+        mayExceedMinifiedThresholds(entry.sizeBytes, this.minifiedThresholds)
+      ) {
+        const detection = detectMinified(
+          content ?? (await source.readText(entry.relativePath)),
+          this.minifiedThresholds
+        );
+        if (detection) {
+          this.reportSkip(options.onSkip, {
+            relativePath: entry.relativePath,
+            reason: "minified",
+            detail: detection.detail,
+          });
+          continue;
+        }
+      }
+
+      results.push({
+        relativePath: entry.relativePath,
+        absolutePath: source.displayPath(entry.relativePath),
+        extension: extname(entry.relativePath).toLowerCase(),
+        sizeBytes: entry.sizeBytes,
+        modifiedAt: entry.modifiedAt,
+        source,
+      });
+
+      if (options.onProgress && i % 100 === 0) {
+        options.onProgress(i + 1, candidates.length);
+      }
+    }
+
+    options.onProgress?.(results.length, candidates.length);
+
+    this.logger.info(
+      {
+        metric: "file_scan.duration_ms",
+        value: Date.now() - startTime,
+        fileCount: results.length,
+        source: source.location,
+      },
+      "Source scan complete"
+    );
+
+    return results;
+  }
+
   /**
    * Validate that the repository path exists and is accessible.
    *
@@ -393,20 +546,26 @@ export class FileScanner {
    * Collect file metadata for all paths.
    *
    * Individual file errors are logged but don't fail the entire scan.
-   * Files larger than maxFileSizeBytes are excluded.
+   * Files larger than maxFileSizeBytes are excluded, as are binary files and
+   * files that look minified unless `options.includeMinified` is set. Each
+   * exclusion is reported through `options.onSkip`; empty files are dropped
+   * without a report.
    *
    * @param repoPath - Repository root path
    * @param relativePaths - Relative file paths
-   * @param onProgress - Optional progress callback
+   * @param options - Scan options (progress, skip reporting, minified override)
    * @returns Array of file metadata
    */
   private async collectFileMetadata(
     repoPath: string,
     relativePaths: string[],
-    onProgress?: (scanned: number, total: number) => void
+    options: ScanOptions
   ): Promise<FileInfo[]> {
+    const { onProgress, onSkip } = options;
     const results: FileInfo[] = [];
     const total = relativePaths.length;
+    let minifiedCount = 0;
+    let binaryCount = 0;
 
     for (let i = 0; i < relativePaths.length; i++) {
       const relativePath = relativePaths[i];
@@ -427,6 +586,11 @@ export class FileScanner {
             },
             "Skipping oversized file"
           );
+          this.reportSkip(onSkip, {
+            relativePath: this.normalizeToPosix(relativePath),
+            reason: "oversized",
+            detail: `${stats.size} bytes exceeds limit of ${this.config.maxFileSizeBytes} bytes`,
+          });
           continue;
         }
 
@@ -436,6 +600,55 @@ export class FileScanner {
           continue;
         }
 
+        // Empty files carry nothing to index; skip without reporting
+        if (stats.size === 0) {
+          this.logger.debug({ relativePath }, "Skipping empty file");
+          continue;
+        }
+
+        // Skip binary content behind a source extension before it reaches
+        // the parser or chunker. Only the leading bytes are read.
+        if (this.config.binaryDetectionBytes > 0) {
+          const head = await readFileHead(absolutePath, this.config.binaryDetectionBytes);
+          const binary = detectBinary(head, this.config.binaryDetectionBytes);
+          if (binary) {
+            binaryCount++;
+            this.logger.debug(
+              { relativePath, sizeBytes: stats.size, detail: binary.detail },
+              "Skipping likely binary file"
+            );
+            this.reportSkip(onSkip, {
+              relativePath: this.normalizeToPosix(relativePath),
+              reason: "binary",
+              detail: binary.detail,
+            });
+            continue;
+          }
+        }
+
+        // Skip likely minified/generated files. Content shorter than the
+        // smallest enabled threshold cannot trip either check, so avoid the read.
+        if (
+          !options.includeMinified &&
+          mayExceedMinifiedThresholds(stats.size, this.minifiedThresholds)
+        ) {
+          const content = await readFile(absolutePath, "utf-8");
+          const detection = detectMinified(content, this.minifiedThresholds);
+          if (detection) {
+            minifiedCount++;
+            this.logger.debug(
+              { relativePath, sizeBytes: stats.size, detail: detection.detail },
+              "Skipping likely minified file"
+            );
+            this.reportSkip(onSkip, {
+              relativePath: this.normalizeToPosix(relativePath),
+              reason: "minified",
+              detail: detection.detail,
+            });
+            continue;
+          }
+        }
+
         const extension = extname(relativePath).toLowerCase();
 
         results.push({
@@ -456,6 +669,13 @@ export class FileScanner {
       }
     }
 
+    if (minifiedCount > 0) {
+      this.logger.info({ minifiedCount }, "Skipped likely minified files");
+    }
+    if (binaryCount > 0) {
+      this.logger.info({ binaryCount }, "Skipped likely binary files");
+    }
+
     // Final progress callback
     if (onProgress) {
       onProgress(results.length, total);
@@ -464,6 +684,24 @@ export class FileScanner {
     return results;
   }
 
+  /**
+   * Invoke the caller's skip callback, isolating the scan from callback errors.
+   *
+   * @param onSkip - Optional skip callback from scan options
+   * @param skipped - Skipped file record
+   */
+  private reportSkip(
+    onSkip: ((skipped: SkippedFile) => void) | undefined,
+    skipped: SkippedFile
+  ): void {
+    if (!onSkip) return;
+    try {
+      onSkip(skipped);
+    } catch (error) {
+      this.logger.warn({ err: error, relativePath: skipped.relativePath }, "onSkip callback threw");
+    }
+  }
+
   /**
    * Normalize Windows paths to POSIX format.
    *
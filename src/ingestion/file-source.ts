@@ -0,0 +1,461 @@
+/**
+ * File sources for repository indexing.
+ *
+ * A FileSource lists and reads the files of something to index, so the
+ * scanner and parser do not care whether files live in a directory or inside
+ * an archive. Two implementations are provided:
+ *
+ * - {@link DirectoryFileSource}: files under a filesystem directory
+ * - {@link ArchiveFileSource}: entries of a `.zip`, `.tar`, `.tar.gz`, `.tgz`
+ *   or `.crate` (gzipped tar) archive, read without extracting to disk
+ *
+ * Paths are always POSIX and relative to the source root.
+ *
+ * @module ingestion/file-source
+ */
+
+import { readFile, stat } from "fs/promises";
+import { gunzipSync } from "zlib";
+import { join, resolve, posix, sep } from "path";
+import { glob } from "glob";
+import JSZip from "jszip";
+import { FileScanError, ValidationError } from "./errors.js";
+import { decodeSourceText, type DecodedText } from "./encoding-detector.js";
+import type { FileInfo } from "./types.js";
+
+/**
+ * A file listed by a FileSource
+ */
+export interface FileSourceEntry {
+  /** Path relative to the source root, with POSIX separators */
+  relativePath: string;
+  /** File size in bytes */
+  sizeBytes: number;
+  /** Last modification timestamp */
+  modifiedAt: Date;
+}
+
+/**
+ * Something whose files can be listed and read for indexing
+ */
+export interface FileSource {
+  /** Source kind */
+  readonly kind: "directory" | "archive";
+
+  /** Absolute path of the directory or archive file */
+  readonly location: string;
+
+  /**
+   * List every regular file in the source.
+   *
+   * @returns Entries sorted by relative path
+   */
+  listEntries(): Promise<FileSourceEntry[]>;
+
+  /**
+   * Read a file's content as UTF-8 text.
+   *
+   * @param relativePath - Path as returned by listEntries()
+   * @throws {FileScanError} If the file does not exist in the source
+   */
+  readText(relativePath: string): Promise<string>;
+
+  /**
+   * Read a file's raw content.
+   *
+   * @param relativePath - Path as returned by listEntries()
+   * @throws {FileScanError} If the file does not exist in the source
+   */
+  readBytes(relativePath: string): Promise<Uint8Array>;
+
+  /**
+   * Path reported as FileInfo.absolutePath for an entry.
+   *
+   * Directory sources return the real filesystem path; archive sources
+   * return `<archive>!/<entry>`, which identifies the entry but cannot be
+   * opened with filesystem APIs.
+   */
+  displayPath(relativePath: string): string;
+}
+
+/**
+ * Archive formats supported by ArchiveFileSource
+ */
+export type ArchiveFormat = "zip" | "tar" | "tar.gz";
+
+/**
+ * Detect an archive format from a file name.
+ *
+ * `.crate` files (packaged Rust crates) are gzipped tarballs.
+ *
+ * @param filePath - Archive file name or path
+ * @returns The archive format, or null if the name is not a supported archive
+ */
+export function detectArchiveFormat(filePath: string): ArchiveFormat | null {
+  const lower = filePath.toLowerCase();
+  if (lower.endsWith(".zip")) return "zip";
+  if (lower.endsWith(".tar.gz") || lower.endsWith(".tgz") || lower.endsWith(".crate")) {
+    return "tar.gz";
+  }
+  if (lower.endsWith(".tar")) return "tar";
+  return null;
+}
+
+/**
+ * Files under a filesystem directory
+ *
+ * Lists every regular file except those inside `.git`; filtering by
+ * extension, exclusions and .gitignore is left to the scanner.
+ */
+export class DirectoryFileSource implements FileSource {
+  readonly kind = "directory";
+  readonly location: string;
+
+  /**
+   * @param directoryPath - Directory to read from
+   */
+  constructor(directoryPath: string) {
+    this.location = resolve(directoryPath);
+  }
+
+  async listEntries(): Promise<FileSourceEntry[]> {
+    const paths = await glob("**/*", {
+      cwd: this.location,
+      ignore: [".git/**"],
+      nodir: true,
+      dot: true,
+    });
+
+    const entries: FileSourceEntry[] = [];
+    for (const path of paths) {
+      const stats = await stat(join(this.location, path));
+      if (!stats.isFile()) continue;
+      entries.push({
+        relativePath: path.split(sep).join(posix.sep),
+        sizeBytes: stats.size,
+        modifiedAt: stats.mtime,
+      });
+    }
+
+    return entries.sort((a, b) => a.relativePath.localeCompare(b.relativePath));
+  }
+
+  async readText(relativePath: string): Promise<string> {
+    try {
+      return await readFile(this.displayPath(relativePath), "utf-8");
+    } catch (error) {
+      throw new FileScanError(
+        `Failed to read ${relativePath}: ${error instanceof Error ? error.message : String(error)}`,
+        this.location,
+        error instanceof Error ? error : undefined
+      );
+    }
+  }
+
+  async readBytes(relativePath: string): Promise<Uint8Array> {
+    try {
+      return new Uint8Array(await readFile(this.displayPath(relativePath)));
+    } catch (error) {
+      throw new FileScanError(
+        `Failed to read ${relativePath}: ${error instanceof Error ? error.message : String(error)}`,
+        this.location,
+        error instanceof Error ? error : undefined
+      );
+    }
+  }
+
+  displayPath(relativePath: string): string {
+    return join(this.location, ...relativePath.split(posix.sep));
+  }
+}
+
+/**
+ * A file held in memory by an ArchiveFileSource
+ */
+interface ArchiveEntry {
+  data: Uint8Array;
+  modifiedAt: Date;
+}
+
+/**
+ * Entries of a zip or tar archive, read without extraction
+ *
+ * The archive is decompressed into memory when opened, so this suits release
+ * artifacts and dependency archives rather than multi-gigabyte bundles.
+ *
+ * When every entry sits under one top-level directory, as in a `.crate`
+ * (`serde-1.0.0/src/lib.rs`) or a GitHub source zip, that directory is
+ * treated as the archive root and stripped from the paths. Entries with
+ * absolute paths or `..` segments are ignored.
+ *
+ * @example
+ * ```typescript
+ * const source = await ArchiveFileSource.open("/downloads/serde-1.0.0.crate");
+ * const entries = await source.listEntries(); // [{ relativePath: "Cargo.toml", ... }, ...]
+ * const lib = await source.readText("src/lib.rs");
+ * ```
+ */
+export class ArchiveFileSource implements FileSource {
+  readonly kind = "archive";
+
+  private constructor(
+    readonly location: string,
+    readonly format: ArchiveFormat,
+    private readonly entries: Map<string, ArchiveEntry>
+  ) {}
+
+  /**
+   * Open an archive.
+   *
+   * @param archivePath - Path to the archive file
+   * @param format - Archive format; detected from the file name when omitted
+   * @throws {ValidationError} If the format is not supported
+   * @throws {FileScanError} If the archive cannot be read or is malformed
+   */
+  static async open(archivePath: string, format?: ArchiveFormat): Promise<ArchiveFileSource> {
+    const location = resolve(archivePath);
+    const resolvedFormat = format ?? detectArchiveFormat(location);
+    if (!resolvedFormat) {
+      throw new ValidationError(
+        `Unsupported archive format: ${archivePath} (expected .zip, .tar, .tar.gz, .tgz or .crate)`,
+        "archivePath"
+      );
+    }
+
+    let raw: Map<string, ArchiveEntry>;
+    try {
+      const bytes = new Uint8Array(await readFile(location));
+      raw =
+        resolvedFormat === "zip"
+          ? await readZipEntries(bytes)
+          : readTarEntries(resolvedFormat === "tar.gz" ? gunzipSync(bytes) : bytes);
+    } catch (error) {
+      throw new FileScanError(
+        `Failed to read archive: ${error instanceof Error ? error.message : String(error)}`,
+        location,
+        error instanceof Error ? error : undefined
+      );
+    }
+
+    return new ArchiveFileSource(location, resolvedFormat, stripSharedRoot(raw));
+  }
+
+  listEntries(): Promise<FileSourceEntry[]> {
+    const entries = [...this.entries].map(([relativePath, entry]) => ({
+      relativePath,
+      sizeBytes: entry.data.byteLength,
+      modifiedAt: entry.modifiedAt,
+    }));
+    return Promise.resolve(entries.sort((a, b) => a.relativePath.localeCompare(b.relativePath)));
+  }
+
+  readText(relativePath: string): Promise<string> {
+    const entry = this.entries.get(relativePath);
+    if (!entry) {
+      return Promise.reject(
+        new FileScanError(`Archive has no entry ${relativePath}`, this.location)
+      );
+    }
+    return Promise.resolve(new TextDecoder().decode(entry.data));
+  }
+
+  readBytes(relativePath: string): Promise<Uint8Array> {
+    const entry = this.entries.get(relativePath);
+    if (!entry) {
+      return Promise.reject(
+        new FileScanError(`Archive has no entry ${relativePath}`, this.location)
+      );
+    }
+    return Promise.resolve(entry.data);
+  }
+
+  displayPath(relativePath: string): string {
+    return `${this.location}!/${relativePath}`;
+  }
+}
+
+/**
+ * Open a directory or archive as a FileSource.
+ *
+ * @param path - Directory or archive file path
+ * @throws {ValidationError} If the path is neither a directory nor a supported archive
+ */
+export async function openFileSource(path: string): Promise<FileSource> {
+  const stats = await stat(path);
+  if (stats.isDirectory()) {
+    return new DirectoryFileSource(path);
+  }
+  if (!detectArchiveFormat(path)) {
+    throw new ValidationError(`Not a directory or supported archive: ${path}`, "path");
+  }
+  return ArchiveFileSource.open(path);
+}
+
+/**
+ * Read a scanned file's content as UTF-8 text.
+ *
+ * Reads through the file's source when it has one, so archive entries work
+ * the same as files on disk.
+ *
+ * @param fileInfo - File returned by FileScanner
+ */
+export async function readFileContent(fileInfo: FileInfo): Promise<string> {
+  if (fileInfo.source) {
+    return fileInfo.source.readText(fileInfo.relativePath);
+  }
+  return readFile(fileInfo.absolutePath, "utf-8");
+}
+
+/**
+ * Read a scanned file's content for indexing, tolerating invalid UTF-8.
+ *
+ * Files that are mostly valid UTF-8 are decoded lossily and returned with an
+ * encoding warning (see `decodeSourceText`).
+ *
+ * @param fileInfo - File returned by FileScanner
+ * @throws {UndecodableFileError} If too much of the file is invalid UTF-8
+ */
+export async function readSourceText(fileInfo: FileInfo): Promise<DecodedText> {
+  const bytes = fileInfo.source
+    ? await fileInfo.source.readBytes(fileInfo.relativePath)
+    : new Uint8Array(await readFile(fileInfo.absolutePath));
+  return decodeSourceText(bytes);
+}
+
+/**
+ * Normalize an archive entry name, rejecting names that escape the archive root
+ */
+function normalizeEntryName(name: string): string | null {
+  const normalized = posix.normalize(name.replace(/\\/g, "/")).replace(/^\.\//, "");
+  if (
+    normalized === "." ||
+    normalized.startsWith("/") ||
+    normalized === ".." ||
+    normalized.startsWith("../")
+  ) {
+    return null;
+  }
+  return normalized.replace(/\/+$/, "");
+}
+
+/**
+ * Strip a top-level directory shared by every entry
+ */
+function stripSharedRoot(entries: Map<string, ArchiveEntry>): Map<string, ArchiveEntry> {
+  const paths = [...entries.keys()];
+  const root = paths[0]?.split("/")[0];
+  if (!root || !paths.every((path) => path.startsWith(`${root}/`))) {
+    return entries;
+  }
+  return new Map([...entries].map(([path, entry]) => [path.slice(root.length + 1), entry]));
+}
+
+/**
+ * Read the regular files of a zip archive
+ */
+async function readZipEntries(bytes: Uint8Array): Promise<Map<string, ArchiveEntry>> {
+  const zip = await JSZip.loadAsync(bytes);
+  const entries = new Map<string, ArchiveEntry>();
+
+  for (const file of Object.values(zip.files)) {
+    if (file.dir) continue;
+    const name = normalizeEntryName(file.name);
+    if (!name) continue;
+    entries.set(name, { data: await file.async("uint8array"), modifiedAt: file.date });
+  }
+
+  return entries;
+}
+
+/** Size of a tar header and of the blocks file data is padded to */
+const TAR_BLOCK_SIZE = 512;
+
+/**
+ * Read the regular files of an uncompressed tar archive
+ *
+ * Supports ustar names (with the 155-byte prefix field), GNU long names
+ * (`L` entries) and pax `path` records, which covers archives written by
+ * cargo, npm, GNU tar and bsdtar.
+ */
+function readTarEntries(bytes: Uint8Array): Map<string, ArchiveEntry> {
+  const entries = new Map<string, ArchiveEntry>();
+  let offset = 0;
+  let longName: string | null = null;
+
+  while (offset + TAR_BLOCK_SIZE <= bytes.length) {
+    const header = bytes.subarray(offset, offset + TAR_BLOCK_SIZE);
+    if (header.every((byte) => byte === 0)) break; // End-of-archive marker
+
+    const size = parseOctal(header.subarray(124, 136));
+    const mtime = parseOctal(header.subarray(136, 148));
+    const typeFlag = String.fromCharCode(header[156] ?? 0);
+    const dataStart = offset + TAR_BLOCK_SIZE;
+    const data = bytes.subarray(dataStart, dataStart + size);
+    offset = dataStart + Math.ceil(size / TAR_BLOCK_SIZE) * TAR_BLOCK_SIZE;
+
+    if (typeFlag === "L") {
+      longName = readCString(data);
+      continue;
+    }
+    if (typeFlag === "x") {
+      longName = parsePaxPath(data) ?? longName;
+      continue;
+    }
+    if (typeFlag !== "0" && typeFlag !== "\0") {
+      // Directories, links, global pax headers and devices carry no file content
+      longName = null;
+      continue;
+    }
+
+    const prefix = readCString(header.subarray(345, 500));
+    const baseName = readCString(header.subarray(0, 100));
+    const name = normalizeEntryName(
+      longName ?? (prefix && isUstar(header) ? `${prefix}/${baseName}` : baseName)
+    );
+    longName = null;
+
+    if (name) {
+      entries.set(name, { data, modifiedAt: new Date(mtime * 1000) });
+    }
+  }
+
+  return entries;
+}
+
+/**
+ * Whether a tar header uses the ustar layout (and therefore has a prefix field)
+ */
+function isUstar(header: Uint8Array): boolean {
+  return readCString(header.subarray(257, 263)).startsWith("ustar");
+}
+
+/**
+ * Read a NUL-terminated string from a header field
+ */
+function readCString(field: Uint8Array): string {
+  const end = field.indexOf(0);
+  return new TextDecoder().decode(end === -1 ? field : field.subarray(0, end));
+}
+
+/**
+ * Parse an octal number from a header field
+ */
+function parseOctal(field: Uint8Array): number {
+  const text = readCString(field).trim();
+  return text ? parseInt(text, 8) : 0;
+}
+
+/**
+ * Extract the `path` record from a pax extended header
+ *
+ * Records have the form "<length> <key>=<value>\n".
+ */
+function parsePaxPath(data: Uint8Array): string | null {
+  for (const record of new TextDecoder().decode(data).split("\n")) {
+    const match = /^\d+ path=(.*)$/.exec(record);
+    if (match?.[1] !== undefined) {
+      return match[1];
+    }
+  }
+  return null;
+}
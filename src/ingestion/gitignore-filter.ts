@@ -80,6 +80,16 @@ export class GitignoreFilter {
     return new GitignoreFilter(root, rules);
   }
 
+  /**
+   * A filter without rules, for folders indexed with `.gitignore` support
+   * disabled. Paths outside `rootPath` are still reported as ignored.
+   *
+   * @param rootPath - Absolute path to the repository / folder root.
+   */
+  static empty(rootPath: string): GitignoreFilter {
+    return new GitignoreFilter(resolve(rootPath), []);
+  }
+
   /**
    * Recursively collect `.gitignore` rules under `currentDir`.
    *
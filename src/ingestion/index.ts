@@ -9,7 +9,29 @@
 export { RepositoryCloner } from "./repository-cloner.js";
 export { FileScanner } from "./file-scanner.js";
 export { FileChunker } from "./file-chunker.js";
+export {
+  DirectoryFileSource,
+  ArchiveFileSource,
+  openFileSource,
+  readFileContent,
+  readSourceText,
+  detectArchiveFormat,
+} from "./file-source.js";
+export type { FileSource, FileSourceEntry, ArchiveFormat } from "./file-source.js";
 export { DEFAULT_EXTENSIONS } from "./default-extensions.js";
+export { detectMinified, DEFAULT_MINIFIED_THRESHOLDS } from "./minified-detector.js";
+export type { MinifiedThresholds, MinifiedDetection } from "./minified-detector.js";
+export { detectBinary, DEFAULT_BINARY_DETECTION_BYTES } from "./binary-detector.js";
+export type { BinaryDetection } from "./binary-detector.js";
+export { decodeSourceText, DEFAULT_MAX_INVALID_BYTE_RATIO } from "./encoding-detector.js";
+export type { DecodedText, EncodingWarning } from "./encoding-detector.js";
+export {
+  prepareEmbeddingInput,
+  resolveEmbeddingInputTokens,
+  truncateToTokens,
+  EMBEDDING_MAX_INPUT_TOKENS_ENV,
+} from "./embedding-input.js";
+export type { PreparedEmbeddingInput } from "./embedding-input.js";
 export { detectLanguage, SUPPORTED_LANGUAGES } from "./language-detector.js";
 export type { ProgrammingLanguage } from "./language-detector.js";
 export type {
@@ -19,6 +41,8 @@ export type {
   ScanOptions,
   FileInfo,
   FileScannerConfig,
+  FileSkipReason,
+  SkippedFile,
   FileChunk,
   ChunkerConfig,
 } from "./types.js";
@@ -29,4 +53,5 @@ export {
   AuthenticationError,
   FileScanError,
   ChunkingError,
+  UndecodableFileError,
 } from "./errors.js";
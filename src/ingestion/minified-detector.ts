@@ -0,0 +1,153 @@
+/**
+ * Heuristic detection of minified / generated single-line files.
+ *
+ * Minified bundles (`vendor.js`, inlined CSS, compiled templates) are usually
+ * one enormous line. They are expensive to parse, produce meaningless symbols
+ * and chunks, and pollute search results. The `*.min.js` / `*.min.css`
+ * default exclusions only catch bundles that follow that naming convention;
+ * this module catches the rest by looking at line shape instead of file name.
+ *
+ * A file is flagged when EITHER:
+ *   - its longest single line exceeds `maxLineBytes`, or
+ *   - its average line length exceeds `maxAverageLineLength`.
+ *
+ * Hand-written source essentially never trips either threshold at the
+ * defaults, so the heuristic is safe to apply to every scanned file.
+ *
+ * @module ingestion/minified-detector
+ */
+
+/**
+ * Thresholds used by {@link detectMinified}.
+ */
+export interface MinifiedThresholds {
+  /**
+   * Files whose average line length (characters per line) exceeds this value
+   * are flagged. Set to `0` to disable the average check.
+   *
+   * @default 1000
+   */
+  maxAverageLineLength: number;
+
+  /**
+   * Files containing any single line longer than this many characters are
+   * flagged. Set to `0` to disable the single-line check.
+   *
+   * @default 20000
+   */
+  maxLineBytes: number;
+}
+
+/**
+ * Default thresholds for minified detection.
+ *
+ * Chosen well above anything a formatter would emit (Prettier/rustfmt/black
+ * wrap at 80-120 columns) while still catching real bundles, which typically
+ * have lines in the hundreds of kilobytes.
+ */
+export const DEFAULT_MINIFIED_THRESHOLDS: Readonly<MinifiedThresholds> = {
+  maxAverageLineLength: 1000,
+  maxLineBytes: 20_000,
+};
+
+/**
+ * Result of a positive minified detection.
+ */
+export interface MinifiedDetection {
+  /** Number of lines in the file */
+  lineCount: number;
+
+  /** Length of the longest line in characters */
+  longestLine: number;
+
+  /** Average line length in characters (rounded down) */
+  averageLineLength: number;
+
+  /** Human-readable explanation of which threshold was exceeded */
+  detail: string;
+}
+
+/**
+ * Whether a file of the given size could trip any enabled threshold.
+ *
+ * Both checks require at least one line longer than the threshold, so content
+ * no larger than the smallest enabled threshold can never be flagged. Callers
+ * use this to avoid reading small files at all.
+ *
+ * @param sizeBytes - File size in bytes
+ * @param thresholds - Detection thresholds
+ * @returns true if the content needs to be inspected
+ */
+export function mayExceedMinifiedThresholds(
+  sizeBytes: number,
+  thresholds: MinifiedThresholds = DEFAULT_MINIFIED_THRESHOLDS
+): boolean {
+  const enabled = [thresholds.maxAverageLineLength, thresholds.maxLineBytes].filter(
+    (threshold) => threshold > 0
+  );
+  if (enabled.length === 0) return false;
+  return sizeBytes > Math.min(...enabled);
+}
+
+/**
+ * Check whether file content looks minified or machine-generated.
+ *
+ * Runs in a single pass over the string without allocating per-line
+ * substrings, so it is cheap enough to run on every file up to the scanner's
+ * 1 MiB size ceiling.
+ *
+ * @param content - Full file content
+ * @param thresholds - Detection thresholds (defaults to {@link DEFAULT_MINIFIED_THRESHOLDS})
+ * @returns Detection details when the file looks minified, otherwise `null`
+ *
+ * @example
+ * ```typescript
+ * const detection = detectMinified(await readFile(path, "utf-8"));
+ * if (detection) {
+ *   logger.debug({ path, detail: detection.detail }, "Skipping minified file");
+ * }
+ * ```
+ */
+export function detectMinified(
+  content: string,
+  thresholds: MinifiedThresholds = DEFAULT_MINIFIED_THRESHOLDS
+): MinifiedDetection | null {
+  if (content.length === 0) return null;
+
+  let lineCount = 0;
+  let longestLine = 0;
+  let lineStart = 0;
+
+  for (let i = 0; i <= content.length; i++) {
+    if (i === content.length || content.charCodeAt(i) === 10 /* \n */) {
+      const lineLength = i - lineStart;
+      // A trailing newline does not start a new (empty) line
+      if (i < content.length || lineLength > 0) {
+        lineCount++;
+        if (lineLength > longestLine) longestLine = lineLength;
+      }
+      lineStart = i + 1;
+    }
+  }
+
+  if (lineCount === 0) return null;
+
+  const averageLineLength = Math.floor(content.length / lineCount);
+  const stats = { lineCount, longestLine, averageLineLength };
+
+  if (thresholds.maxLineBytes > 0 && longestLine > thresholds.maxLineBytes) {
+    return {
+      ...stats,
+      detail: `longest line is ${longestLine} characters (limit ${thresholds.maxLineBytes})`,
+    };
+  }
+
+  if (thresholds.maxAverageLineLength > 0 && averageLineLength > thresholds.maxAverageLineLength) {
+    return {
+      ...stats,
+      detail: `average line length is ${averageLineLength} characters (limit ${thresholds.maxAverageLineLength})`,
+    };
+  }
+
+  return null;
+}
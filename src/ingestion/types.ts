@@ -5,6 +5,8 @@
  */
 
 import type { RetryConfig } from "../utils/retry.js";
+import type { MinifiedThresholds } from "./minified-detector.js";
+import type { FileSource } from "./file-source.js";
 
 /**
  * Options for cloning a repository.
@@ -172,6 +174,18 @@ export interface ScanOptions {
    */
   excludePatterns?: string[];
 
+  /**
+   * Whether to skip files and directories ignored by `.gitignore`.
+   *
+   * Applies on top of `excludePatterns`: a file is skipped when either a
+   * glob or a `.gitignore` rule matches it. Set to `false` to index ignored
+   * files too (e.g. generated code checked out next to the sources), relying
+   * on `excludePatterns` alone.
+   *
+   * @default true
+   */
+  respectGitignore?: boolean;
+
   /**
    * Optional progress callback invoked during scanning.
    *
@@ -210,9 +224,58 @@ export interface ScanOptions {
    * clones the default `false` is fine because the remote already excluded
    * ignored files at clone time.
    *
+   * Has no effect when `respectGitignore` is `false`.
+   *
    * @default false
    */
   respectNestedGitignore?: boolean;
+
+  /**
+   * When `true`, files that look minified or machine-generated (see
+   * `detectMinified`) are included instead of skipped.
+   *
+   * Minified detection is on by default because single-line bundles either
+   * fail to parse or yield a single meaningless chunk. Set this when a
+   * repository legitimately stores long-line content you want indexed.
+   *
+   * @default false
+   */
+  includeMinified?: boolean;
+
+  /**
+   * Optional callback invoked once for every file that matched the glob and
+   * gitignore filters but was dropped by a content/size check.
+   *
+   * Lets callers record why a file is missing from the index (e.g. surface
+   * "skipped 3 minified bundles" in CLI output) without re-deriving the
+   * scanner's rules.
+   *
+   * @param skipped - The skipped file and the reason it was skipped
+   */
+  onSkip?: (skipped: SkippedFile) => void;
+}
+
+/**
+ * Reason a file was dropped by the scanner after matching its glob patterns.
+ *
+ * - `oversized`: file exceeds `FileScannerConfig.maxFileSizeBytes`
+ * - `minified`: file looks minified/generated (long average or single line)
+ * - `binary`: file has a NUL byte within `FileScannerConfig.binaryDetectionBytes`
+ */
+export type FileSkipReason = "oversized" | "minified" | "binary";
+
+/**
+ * A file excluded by the scanner, reported through `ScanOptions.onSkip`.
+ */
+export interface SkippedFile {
+  /** Path relative to the repository root (POSIX separators) */
+  relativePath: string;
+
+  /** Why the file was skipped */
+  reason: FileSkipReason;
+
+  /** Human-readable detail (e.g. which threshold was exceeded) */
+  detail: string;
 }
 
 /**
@@ -276,6 +339,15 @@ export interface FileInfo {
    * Useful for incremental indexing and cache invalidation.
    */
   modifiedAt: Date;
+
+  /**
+   * Source the file was scanned from, when scanned with
+   * `FileScanner.scanSource()`.
+   *
+   * Archive entries have no filesystem path, so read content with
+   * `readFileContent()` instead of opening `absolutePath` directly.
+   */
+  source?: FileSource;
 }
 
 /**
@@ -323,6 +395,29 @@ export interface FileScannerConfig {
    * ```
    */
   allowedBasePaths?: string[];
+
+  /**
+   * Thresholds for flagging files as likely minified/generated.
+   *
+   * Files exceeding either threshold are skipped unless
+   * `ScanOptions.includeMinified` is set. Individual thresholds can be
+   * disabled by setting them to `0`.
+   *
+   * @default { maxAverageLineLength: 1000, maxLineBytes: 20000 }
+   */
+  minifiedThresholds?: Partial<MinifiedThresholds>;
+
+  /**
+   * Number of leading bytes inspected for NUL bytes when deciding whether a
+   * file is binary despite its extension.
+   *
+   * Files with a NUL byte in this range are skipped with reason `binary`.
+   * Empty files are always skipped silently. Set to `0` to disable binary
+   * detection.
+   *
+   * @default 8192
+   */
+  binaryDetectionBytes?: number;
 }
 
 /**
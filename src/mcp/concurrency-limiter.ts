@@ -0,0 +1,309 @@
+/**
+ * MCP Tool Concurrency Limiter
+ *
+ * Bounds the number of tool invocations executing at once so a burst of
+ * CallTool requests cannot spike memory and CPU. Excess requests wait in a
+ * FIFO queue; when the queue is full, or a request waits longer than the
+ * queue timeout, it is rejected with a "server busy" error instead.
+ *
+ * Long-running tools (reindexing, folder registration, ref diffs) run in a
+ * separate lane with its own, smaller limit. They never occupy the general
+ * slots, so quick searches keep flowing while a reindex is in progress.
+ *
+ * A request cancelled by its client while still queued leaves the queue
+ * immediately instead of waiting for a slot it will never use.
+ *
+ * @module mcp/concurrency-limiter
+ */
+
+import { getComponentLogger } from "../logging/index.js";
+
+/**
+ * Configuration for the tool concurrency limiter
+ */
+export interface ToolConcurrencyConfig {
+  /** Maximum tool invocations executing at once in the general lane (default: 8) */
+  maxConcurrent: number;
+
+  /** Maximum invocations waiting for a slot, per lane (default: 100) */
+  maxQueued: number;
+
+  /** Maximum time in milliseconds a request may wait for a slot (default: 30 seconds) */
+  queueTimeoutMs: number;
+
+  /** Maximum long-running tool invocations executing at once (default: 1) */
+  maxConcurrentLongRunning: number;
+
+  /** Tools that run in the long-running lane */
+  longRunningTools: string[];
+}
+
+/**
+ * Default concurrency configuration
+ *
+ * Can be overridden via the MCP_MAX_CONCURRENT_TOOLS, MCP_MAX_QUEUED_TOOLS,
+ * MCP_TOOL_QUEUE_TIMEOUT_MS and MCP_MAX_CONCURRENT_LONG_RUNNING_TOOLS
+ * environment variables.
+ */
+export const DEFAULT_TOOL_CONCURRENCY_CONFIG: ToolConcurrencyConfig = {
+  maxConcurrent: 8,
+  maxQueued: 100,
+  queueTimeoutMs: 30 * 1000,
+  maxConcurrentLongRunning: 1,
+  longRunningTools: [
+    "reindex",
+    "trigger_incremental_update",
+    "register_local_folder",
+    "diff_symbols_between_refs",
+  ],
+};
+
+/**
+ * Lane a tool invocation runs in
+ */
+export type ConcurrencyLane = "general" | "long_running";
+
+/**
+ * Thrown when a tool invocation cannot be admitted
+ */
+export class ConcurrencyLimitError extends Error {
+  constructor(
+    public readonly toolName: string,
+    public readonly reason: "queue_full" | "queue_timeout" | "cancelled"
+  ) {
+    super(
+      reason === "queue_full"
+        ? `Server is busy: too many pending requests, ${toolName} was not queued`
+        : reason === "queue_timeout"
+          ? `Server is busy: ${toolName} timed out waiting for an execution slot`
+          : `${toolName} was cancelled while waiting for an execution slot`
+    );
+    this.name = "ConcurrencyLimitError";
+  }
+}
+
+/**
+ * Snapshot of a lane's utilization
+ */
+export interface LaneStats {
+  /** Invocations currently executing */
+  active: number;
+  /** Invocations waiting for a slot */
+  queued: number;
+  /** Maximum concurrent invocations */
+  limit: number;
+}
+
+/**
+ * A request waiting for a slot
+ */
+interface Waiter {
+  resolve: () => void;
+  timer: ReturnType<typeof setTimeout>;
+  /** Detaches the cancellation listener, if any */
+  detach: () => void;
+}
+
+/**
+ * Counting semaphore with a bounded FIFO wait queue
+ */
+class Semaphore {
+  private active = 0;
+  private readonly waiters: Waiter[] = [];
+
+  constructor(
+    readonly limit: number,
+    private readonly maxQueued: number,
+    private readonly queueTimeoutMs: number
+  ) {}
+
+  /**
+   * Wait for a slot
+   *
+   * @throws {ConcurrencyLimitError} If the queue is full, the wait times out,
+   *         or the signal aborts before a slot is free
+   */
+  async acquire(toolName: string, signal?: AbortSignal): Promise<void> {
+    if (signal?.aborted) {
+      throw new ConcurrencyLimitError(toolName, "cancelled");
+    }
+    if (this.active < this.limit) {
+      this.active++;
+      return;
+    }
+    if (this.waiters.length >= this.maxQueued) {
+      throw new ConcurrencyLimitError(toolName, "queue_full");
+    }
+
+    await new Promise<void>((resolve, reject) => {
+      const leave = (reason: "queue_timeout" | "cancelled"): void => {
+        const index = this.waiters.indexOf(waiter);
+        if (index !== -1) this.waiters.splice(index, 1);
+        clearTimeout(waiter.timer);
+        waiter.detach();
+        reject(new ConcurrencyLimitError(toolName, reason));
+      };
+      const onAbort = (): void => leave("cancelled");
+      const waiter: Waiter = {
+        resolve: () => {
+          waiter.detach();
+          resolve();
+        },
+        timer: setTimeout(() => leave("queue_timeout"), this.queueTimeoutMs),
+        detach: () => signal?.removeEventListener("abort", onAbort),
+      };
+      signal?.addEventListener("abort", onAbort, { once: true });
+      this.waiters.push(waiter);
+    });
+  }
+
+  /**
+   * Release a slot, handing it directly to the next waiter if any
+   */
+  release(): void {
+    const next = this.waiters.shift();
+    if (next) {
+      clearTimeout(next.timer);
+      next.resolve();
+      return;
+    }
+    this.active = Math.max(0, this.active - 1);
+  }
+
+  stats(): LaneStats {
+    return { active: this.active, queued: this.waiters.length, limit: this.limit };
+  }
+}
+
+/**
+ * Tool Concurrency Limiter
+ *
+ * Async-safe due to JavaScript's single-threaded event loop - slot counts
+ * change atomically between await points.
+ *
+ * @example
+ * ```typescript
+ * const limiter = new ToolConcurrencyLimiter({ maxConcurrent: 4 });
+ *
+ * const result = await limiter.run("semantic_search", () => handler(args));
+ * ```
+ */
+export class ToolConcurrencyLimiter {
+  private readonly config: ToolConcurrencyConfig;
+  private readonly lanes: Record<ConcurrencyLane, Semaphore>;
+  private readonly longRunningTools: ReadonlySet<string>;
+  private logger = getComponentLogger("mcp:concurrency-limiter");
+
+  constructor(config: Partial<ToolConcurrencyConfig> = {}) {
+    this.config = { ...DEFAULT_TOOL_CONCURRENCY_CONFIG, ...config };
+    this.longRunningTools = new Set(this.config.longRunningTools);
+    this.lanes = {
+      general: new Semaphore(
+        this.config.maxConcurrent,
+        this.config.maxQueued,
+        this.config.queueTimeoutMs
+      ),
+      long_running: new Semaphore(
+        this.config.maxConcurrentLongRunning,
+        this.config.maxQueued,
+        this.config.queueTimeoutMs
+      ),
+    };
+  }
+
+  /**
+   * Get the lane a tool runs in
+   */
+  getLane(toolName: string): ConcurrencyLane {
+    return this.longRunningTools.has(toolName) ? "long_running" : "general";
+  }
+
+  /**
+   * Run a tool invocation once a slot in its lane is free
+   *
+   * @param toolName - Name of the tool being invoked
+   * @param operation - The tool invocation
+   * @param signal - Aborts the wait for a slot when the request is cancelled
+   * @returns The operation's result
+   * @throws {ConcurrencyLimitError} If the invocation could not be admitted
+   */
+  async run<T>(toolName: string, operation: () => Promise<T>, signal?: AbortSignal): Promise<T> {
+    const lane = this.getLane(toolName);
+    const semaphore = this.lanes[lane];
+
+    const waitStart = performance.now();
+    try {
+      await semaphore.acquire(toolName, signal);
+    } catch (error) {
+      this.logger.warn({ toolName, lane, ...semaphore.stats() }, "Tool invocation rejected");
+      throw error;
+    }
+
+    const waitedMs = Math.round(performance.now() - waitStart);
+    if (waitedMs > 0) {
+      this.logger.debug({ toolName, lane, waitedMs }, "Tool invocation admitted after queueing");
+    }
+
+    try {
+      return await operation();
+    } finally {
+      semaphore.release();
+    }
+  }
+
+  /**
+   * Get the current utilization of each lane
+   */
+  getStats(): Record<ConcurrencyLane, LaneStats> {
+    return {
+      general: this.lanes.general.stats(),
+      long_running: this.lanes.long_running.stats(),
+    };
+  }
+}
+
+/**
+ * Load tool concurrency configuration from environment variables
+ *
+ * Invalid or non-positive values fall back to the defaults.
+ *
+ * @returns Tool concurrency configuration
+ */
+export function loadToolConcurrencyConfig(): ToolConcurrencyConfig {
+  return {
+    ...DEFAULT_TOOL_CONCURRENCY_CONFIG,
+    maxConcurrent: parsePositiveInt(
+      "MCP_MAX_CONCURRENT_TOOLS",
+      DEFAULT_TOOL_CONCURRENCY_CONFIG.maxConcurrent
+    ),
+    maxQueued: parsePositiveInt("MCP_MAX_QUEUED_TOOLS", DEFAULT_TOOL_CONCURRENCY_CONFIG.maxQueued),
+    queueTimeoutMs: parsePositiveInt(
+      "MCP_TOOL_QUEUE_TIMEOUT_MS",
+      DEFAULT_TOOL_CONCURRENCY_CONFIG.queueTimeoutMs
+    ),
+    maxConcurrentLongRunning: parsePositiveInt(
+      "MCP_MAX_CONCURRENT_LONG_RUNNING_TOOLS",
+      DEFAULT_TOOL_CONCURRENCY_CONFIG.maxConcurrentLongRunning
+    ),
+  };
+}
+
+/**
+ * Parse a positive integer environment variable with default
+ */
+function parsePositiveInt(envVar: string, defaultValue: number): number {
+  const value = process.env[envVar];
+  if (!value) {
+    return defaultValue;
+  }
+
+  const parsed = parseInt(value, 10);
+  if (isNaN(parsed) || parsed < 1) {
+    getComponentLogger("mcp:concurrency-limiter").warn(
+      { envVar, value, defaultValue },
+      `Invalid ${envVar} value, using default`
+    );
+    return defaultValue;
+  }
+  return parsed;
+}
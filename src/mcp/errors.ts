@@ -20,6 +20,7 @@ import {
   GraphServiceValidationError,
   EntityNotFoundError,
   GraphServiceTimeoutError,
+  GraphServiceCancelledError,
   GraphServiceOperationError,
 } from "../services/graph-service-errors.js";
 import {
@@ -160,6 +161,11 @@ export function mapToMCPError(error: unknown): McpError {
     );
   }
 
+  if (error instanceof GraphServiceCancelledError) {
+    log.info({ error: error.message }, "GraphService operation cancelled");
+    return new McpError(ErrorCode.InternalError, "Graph query was cancelled.");
+  }
+
   if (error instanceof GraphServiceOperationError) {
     log.error(
       { error: error.message, retryable: error.retryable },
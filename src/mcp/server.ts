@@ -11,14 +11,27 @@
 
 import { Server } from "@modelcontextprotocol/sdk/server/index.js";
 import { StdioServerTransport } from "@modelcontextprotocol/sdk/server/stdio.js";
-import { ListToolsRequestSchema, CallToolRequestSchema } from "@modelcontextprotocol/sdk/types.js";
+import {
+  ListToolsRequestSchema,
+  CallToolRequestSchema,
+  type ProgressToken,
+  type ServerNotification,
+  type ServerRequest,
+} from "@modelcontextprotocol/sdk/types.js";
+import type { RequestHandlerExtra } from "@modelcontextprotocol/sdk/shared/protocol.js";
 import type { SearchService } from "../services/types.js";
 import type { RepositoryMetadataService } from "../repositories/types.js";
-import type { MCPServerConfig, ToolRegistry, MCPServerOptionalDeps } from "./types.js";
+import type {
+  MCPServerConfig,
+  ToolRegistry,
+  ToolCallContext,
+  MCPServerOptionalDeps,
+} from "./types.js";
 import { createToolRegistry, getToolDefinitions, getToolHandler } from "./tools/index.js";
 import { createMethodNotFoundError } from "./errors.js";
 import { getComponentLogger } from "../logging/index.js";
 import { debugLog, toolDebugLog } from "./debug-logger.js";
+import { ToolConcurrencyLimiter, ConcurrencyLimitError } from "./concurrency-limiter.js";
 
 /** Default server configuration */
 const DEFAULT_CONFIG: MCPServerConfig = {
@@ -51,6 +64,7 @@ export class PersonalKnowledgeMCPServer {
   private server: Server;
   private toolRegistry: ToolRegistry;
   private config: MCPServerConfig;
+  private concurrencyLimiter: ToolConcurrencyLimiter;
   private logger = getComponentLogger("mcp:server");
   private isShuttingDown = false;
   private shutdownHandlersRegistered = false;
@@ -84,6 +98,9 @@ export class PersonalKnowledgeMCPServer {
     // Store config for creating additional server instances (SSE sessions)
     this.config = config;
 
+    // Shared by every transport session so limits apply server-wide
+    this.concurrencyLimiter = new ToolConcurrencyLimiter(config.concurrency);
+
     // Initialize primary MCP SDK server (used for stdio transport)
     this.server = this.createSdkServer();
 
@@ -98,6 +115,8 @@ export class PersonalKnowledgeMCPServer {
         rateLimiter: optionalDeps.rateLimiter,
         jobTracker: optionalDeps.jobTracker,
         graphService: optionalDeps.graphService,
+        graphAnalysisService: optionalDeps.graphAnalysisService,
+        symbolDiffService: optionalDeps.symbolDiffService,
         documentSearchService: optionalDeps.documentSearchService,
         imageSearchService: optionalDeps.imageSearchService,
         listWatchedFoldersService: optionalDeps.listWatchedFoldersService,
@@ -192,7 +211,7 @@ export class PersonalKnowledgeMCPServer {
     });
 
     // Handle CallTool request
-    server.setRequestHandler(CallToolRequestSchema, async (request) => {
+    server.setRequestHandler(CallToolRequestSchema, async (request, extra) => {
       const { name: toolName, arguments: args } = request.params;
       const callStart = performance.now();
 
@@ -218,10 +237,23 @@ export class PersonalKnowledgeMCPServer {
         };
       }
 
+      // Let the tool turn the call away before it queues for a slot
+      const admission = this.toolRegistry[toolName]?.admit?.(args);
+      if (admission && "rejected" in admission) {
+        debugLog(`CallTool NOT_ADMITTED: ${toolName}`);
+        this.logger.info({ toolName }, "CallTool rejected before queueing");
+        return admission.rejected;
+      }
+
       // Execute tool handler
       try {
         debugLog(`CallTool EXECUTING: ${toolName}`);
-        const result = await handler(args);
+        const context = this.createToolCallContext(request.params._meta?.progressToken, extra);
+        const result = await this.concurrencyLimiter.run(
+          toolName,
+          () => handler(args, context),
+          extra.signal
+        );
 
         const durationMs = Math.round(performance.now() - callStart);
         debugLog(
@@ -239,6 +271,22 @@ export class PersonalKnowledgeMCPServer {
 
         return result;
       } catch (error) {
+        if (error instanceof ConcurrencyLimitError) {
+          debugLog(`CallTool REJECTED: ${toolName} reason=${error.reason}`);
+          if (error.reason === "cancelled") {
+            return { content: [{ type: "text", text: `Error: ${error.message}` }], isError: true };
+          }
+          return {
+            content: [
+              {
+                type: "text",
+                text: `Error: ${error.message}. Please retry shortly.`,
+              },
+            ],
+            isError: true,
+          };
+        }
+
         // This should rarely happen as handlers catch their own errors
         // But we handle it defensively to prevent server crashes
         const durationMs = Math.round(performance.now() - callStart);
@@ -255,10 +303,47 @@ export class PersonalKnowledgeMCPServer {
           ],
           isError: true,
         };
+      } finally {
+        admission?.release();
       }
     });
   }
 
+  /**
+   * Builds the per-call context handed to tool handlers
+   *
+   * The SDK aborts `extra.signal` on `notifications/cancelled` and when the
+   * transport closes, so handlers receive it as their cancellation token.
+   * Progress reporting is only wired when the client asked for it by
+   * supplying a progress token. Notification failures are logged and
+   * swallowed so a disconnected client never fails the tool itself.
+   *
+   * @param progressToken - Token from the request's `_meta`, if any
+   * @param extra - Request handler extras from the MCP SDK
+   */
+  private createToolCallContext(
+    progressToken: ProgressToken | undefined,
+    extra: RequestHandlerExtra<ServerRequest, ServerNotification>
+  ): ToolCallContext {
+    if (progressToken === undefined) {
+      return { signal: extra.signal };
+    }
+
+    return {
+      signal: extra.signal,
+      reportProgress: (progress, total, message) => {
+        extra
+          .sendNotification({
+            method: "notifications/progress",
+            params: { progressToken, progress, total, message },
+          })
+          .catch((error: unknown) => {
+            this.logger.debug({ error }, "Failed to send progress notification");
+          });
+      },
+    };
+  }
+
   /**
    * Starts the MCP server with stdio transport
    *
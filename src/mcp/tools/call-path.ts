@@ -0,0 +1,178 @@
+/**
+ * call_path MCP Tool Implementation
+ *
+ * This module implements the call_path tool for the MCP server. It traces how
+ * control flow reaches one function from another by searching the call graph
+ * recorded at ingestion breadth-first, and returns the shortest chains of
+ * calls connecting them.
+ *
+ * Unlike find_path, which follows any relationship in either direction, only
+ * CALLS edges are followed, and only from caller to callee.
+ *
+ * @module mcp/tools/call-path
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type { GraphAnalysisService, CallPathResult } from "../../services/graph-analysis-types.js";
+import { validateCallPathArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:call-path");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for call_path
+ */
+export const callPathToolDefinition: Tool = {
+  name: "call_path",
+  description:
+    "Find how control flow reaches one function from another: the shortest chains of calls " +
+    "from 'from' to 'to', following call edges in the caller-to-callee direction only. " +
+    "Returns every shortest path up to 'limit', or no paths when 'to' is not reachable " +
+    "within 'max_depth' calls. Only calls that resolve to a function defined in the " +
+    "repository are followed.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      from: {
+        type: "string",
+        description:
+          "Calling function: name (e.g., 'handleLogin'), file-qualified name " +
+          "(e.g., 'src/routes/api.ts::handleLogin'), or graph node ID",
+      },
+      to: {
+        type: "string",
+        description:
+          "Called function: name (e.g., 'findUser'), file-qualified name " +
+          "(e.g., 'src/db/users.ts::findUser'), or graph node ID",
+      },
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      max_depth: {
+        type: "number",
+        description: "Maximum number of calls in a path (1-20)",
+        minimum: 1,
+        maximum: 20,
+        default: 10,
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of shortest paths to return (1-50)",
+        minimum: 1,
+        maximum: 50,
+        default: 5,
+      },
+    },
+    required: ["from", "to", "repository"],
+  },
+};
+
+/**
+ * Creates the call_path tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes call path queries
+ */
+export function createCallPathHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateCallPathArgs(args);
+
+      log.info(
+        {
+          from: validatedArgs.from,
+          to: validatedArgs.to,
+          repository: validatedArgs.repository,
+          max_depth: validatedArgs.max_depth,
+          limit: validatedArgs.limit,
+        },
+        "Executing call_path tool"
+      );
+
+      const response = await analysisService.findCallPaths({
+        from: validatedArgs.from,
+        to: validatedArgs.to,
+        repository: validatedArgs.repository,
+        max_depth: validatedArgs.max_depth,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatCallPathResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          pathCount: response.metadata.path_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "call_path completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "call_path failed");
+      toolDebugLog("call_path", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats CallPathResult as MCP TextContent
+ *
+ * @param response - Call path result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatCallPathResponse(response: CallPathResult): TextContent {
+  const output = {
+    from: response.from,
+    to: response.to,
+    repository: response.repository,
+    found: response.paths.length > 0,
+    paths: response.paths,
+    ...(response.paths.length === 0 && {
+      message:
+        `No call path from '${response.from}' to '${response.to}' ` +
+        `within ${response.metadata.max_depth} calls`,
+    }),
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
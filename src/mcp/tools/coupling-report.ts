@@ -0,0 +1,182 @@
+/**
+ * coupling_report MCP Tool Implementation
+ *
+ * This module implements the coupling_report tool for the MCP server. It
+ * scores the modules of a repository with the standard package coupling
+ * metrics: afferent coupling (Ca), efferent coupling (Ce) and instability
+ * I = Ce / (Ce + Ca), computed from the cross-module call and import edges
+ * of the knowledge graph, and lists the module pairs with the most edges
+ * between them.
+ *
+ * @module mcp/tools/coupling-report
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  CouplingReportResult,
+} from "../../services/graph-analysis-types.js";
+import { validateCouplingReportArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:coupling-report");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for coupling_report
+ */
+export const couplingReportToolDefinition: Tool = {
+  name: "coupling_report",
+  description:
+    "Score how entangled the modules (files, or directories) of a repository are. For each " +
+    "module: afferent coupling Ca (modules depending on it), efferent coupling Ce (modules " +
+    "it depends on) and instability I = Ce/(Ce+Ca), from 0 (depended on, stable) to 1 " +
+    "(depends on others, unstable), plus the call and import edge counts between each pair " +
+    "of modules. Sorted by instability unless sort_by says otherwise. Use to find modules " +
+    "that are too coupled or stable modules that depend on unstable ones.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to analyze",
+      },
+      granularity: {
+        type: "string",
+        enum: ["file", "directory"],
+        description: "What counts as a module: each file, or each directory of files",
+        default: "file",
+      },
+      path: {
+        type: "string",
+        description:
+          "Only report modules in this file (e.g., 'src/parser.rs') or beneath this " +
+          "directory (e.g., 'src/codec'), relative to the repository root",
+      },
+      sort_by: {
+        type: "string",
+        enum: ["instability", "afferent", "efferent", "module"],
+        description: "Metric to sort modules by",
+        default: "instability",
+      },
+      order: {
+        type: "string",
+        enum: ["asc", "desc"],
+        description: "Sort direction",
+        default: "desc",
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of modules and of module pairs to return (1-500)",
+        minimum: 1,
+        maximum: 500,
+        default: 50,
+      },
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Creates the coupling_report tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes coupling reports
+ */
+export function createCouplingReportHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateCouplingReportArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          granularity: validatedArgs.granularity,
+          path: validatedArgs.path,
+          sort_by: validatedArgs.sort_by,
+          order: validatedArgs.order,
+          limit: validatedArgs.limit,
+        },
+        "Executing coupling_report tool"
+      );
+
+      const response = await analysisService.getCouplingReport({
+        repository: validatedArgs.repository,
+        granularity: validatedArgs.granularity,
+        path: validatedArgs.path,
+        sort_by: validatedArgs.sort_by,
+        order: validatedArgs.order,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatCouplingReportResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          moduleCount: response.metadata.module_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "coupling_report completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "coupling_report failed");
+      toolDebugLog("coupling_report", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats CouplingReportResult as MCP TextContent
+ *
+ * @param response - Coupling report from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatCouplingReportResponse(response: CouplingReportResult): TextContent {
+  const output = {
+    repository: response.repository,
+    granularity: response.granularity,
+    modules: response.modules,
+    pairs: response.pairs,
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
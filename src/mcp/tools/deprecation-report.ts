@@ -0,0 +1,170 @@
+/**
+ * deprecation_report MCP Tool Implementation
+ *
+ * This module implements the deprecation_report tool for the MCP server. It
+ * lists every `#[deprecated]` symbol in a repository with its deprecation
+ * message and the call sites still referencing it, so migrations can be
+ * prioritized. Deprecated functions with no remaining callers are flagged as
+ * safe to remove.
+ *
+ * @module mcp/tools/deprecation-report
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  DeprecationReportResult,
+} from "../../services/graph-analysis-types.js";
+import { validateDeprecationReportArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:deprecation-report");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for deprecation_report
+ */
+export const deprecationReportToolDefinition: Tool = {
+  name: "deprecation_report",
+  description:
+    "Maintenance report of #[deprecated] symbols: each symbol with its deprecation message " +
+    "('note' or #[deprecated = \"...\"]), 'since' version and the call sites still " +
+    "referencing it, most-called first. Deprecated functions with no callers are flagged " +
+    "'safe_to_remove'. Only calls are tracked, so deprecated types and constants report " +
+    "'references_tracked: false' and are never flagged.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      path: {
+        type: "string",
+        description:
+          "File path (e.g., 'src/api.rs') or directory path (e.g., 'src/legacy') relative to " +
+          "the repository root",
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of deprecated symbols to return (1-500)",
+        minimum: 1,
+        maximum: 500,
+        default: 100,
+      },
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Creates the deprecation_report tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes deprecation report queries
+ */
+export function createDeprecationReportHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateDeprecationReportArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          path: validatedArgs.path,
+          limit: validatedArgs.limit,
+        },
+        "Executing deprecation_report tool"
+      );
+
+      const response = await analysisService.getDeprecationReport({
+        repository: validatedArgs.repository,
+        path: validatedArgs.path,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatDeprecationReportResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          totalDeprecated: response.metadata.total_deprecated,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "deprecation_report completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "deprecation_report failed");
+      toolDebugLog("deprecation_report", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats DeprecationReportResult as MCP TextContent
+ *
+ * @param response - Deprecation report from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatDeprecationReportResponse(response: DeprecationReportResult): TextContent {
+  const output = {
+    repository: response.repository,
+    symbols: response.symbols.map((symbol) => ({
+      name: symbol.name,
+      entity_type: symbol.entity_type,
+      file_path: symbol.file_path,
+      start_line: symbol.start_line,
+      end_line: symbol.end_line,
+      message: symbol.message,
+      since: symbol.since,
+      safe_to_remove: symbol.safe_to_remove,
+      references_tracked: symbol.references_tracked,
+      call_sites: symbol.call_sites.map((site) => ({
+        caller: site.caller.name,
+        file_path: site.caller.file_path,
+        line: site.line,
+        call_count: site.call_count,
+      })),
+    })),
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
@@ -0,0 +1,148 @@
+/**
+ * diff_symbols_between_refs MCP Tool Implementation
+ *
+ * This module implements the diff_symbols_between_refs tool for the MCP
+ * server. It returns the symbol-level diff between two git refs of an indexed
+ * repository: which functions, classes and variables the changed files add,
+ * remove or modify. Intended for PR review, where a file-level diff does not
+ * say which APIs changed.
+ *
+ * @module mcp/tools/diff-symbols-between-refs
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type { SymbolDiffService, SymbolDiffResult } from "../../services/symbol-diff-types.js";
+import { validateDiffSymbolsBetweenRefsArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:diff-symbols-between-refs");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for diff_symbols_between_refs
+ */
+export const diffSymbolsBetweenRefsToolDefinition: Tool = {
+  name: "diff_symbols_between_refs",
+  description:
+    "Get the symbol-level diff between two git refs of an indexed repository: the functions, " +
+    "classes, and variables added, removed, or modified in the changed files. Only files in " +
+    "the git diff are parsed; unchanged files are not reindexed. Use for PR review, e.g. " +
+    "base_ref='main' and head_ref='feature/auth'.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name (must be a git repository)",
+      },
+      base_ref: {
+        type: "string",
+        description: "Base branch, tag, or commit SHA (e.g., 'main')",
+      },
+      head_ref: {
+        type: "string",
+        description: "Head branch, tag, or commit SHA",
+        default: "HEAD",
+      },
+      path: {
+        type: "string",
+        description:
+          "Only diff files at or beneath this path (e.g., 'src/auth'), relative to the " +
+          "repository root",
+      },
+    },
+    required: ["repository", "base_ref"],
+  },
+};
+
+/**
+ * Creates the diff_symbols_between_refs tool handler
+ *
+ * @param diffService - Injected SymbolDiffService instance
+ * @returns Tool handler function that executes symbol diffs
+ */
+export function createDiffSymbolsBetweenRefsHandler(diffService: SymbolDiffService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateDiffSymbolsBetweenRefsArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          base_ref: validatedArgs.base_ref,
+          head_ref: validatedArgs.head_ref,
+          path: validatedArgs.path,
+        },
+        "Executing diff_symbols_between_refs tool"
+      );
+
+      const response = await diffService.diffSymbols({
+        repository: validatedArgs.repository,
+        base_ref: validatedArgs.base_ref,
+        head_ref: validatedArgs.head_ref,
+        path: validatedArgs.path,
+      });
+
+      const content = formatDiffSymbolsResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          summary: response.summary,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "diff_symbols_between_refs completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "diff_symbols_between_refs failed");
+      toolDebugLog("diff_symbols_between_refs", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats SymbolDiffResult as MCP TextContent
+ *
+ * @param response - Symbol diff result from SymbolDiffService
+ * @returns MCP text content with formatted JSON
+ */
+function formatDiffSymbolsResponse(response: SymbolDiffResult): TextContent {
+  return {
+    type: "text",
+    text: JSON.stringify(response, null, 2),
+  };
+}
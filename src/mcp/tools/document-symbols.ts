@@ -0,0 +1,147 @@
+/**
+ * document_symbols MCP Tool Implementation
+ *
+ * This module implements the document_symbols tool for the MCP server. It
+ * returns the symbols of one file as a tree shaped like the result of the
+ * LSP `textDocument/documentSymbol` request: names, numeric SymbolKinds,
+ * ranges and selection ranges, with methods nested in their impl blocks or
+ * classes and enum variants nested in their enums. An editor integration can
+ * serve the result directly instead of re-parsing the file.
+ *
+ * @module mcp/tools/document-symbols
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  DocumentSymbolsResult,
+} from "../../services/graph-analysis-types.js";
+import { validateDocumentSymbolsArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:document-symbols");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for document_symbols
+ */
+export const documentSymbolsToolDefinition: Tool = {
+  name: "document_symbols",
+  description:
+    "Get the symbols of a file as an LSP DocumentSymbol tree (the shape of a " +
+    "textDocument/documentSymbol response): name, detail (signature), numeric LSP " +
+    "SymbolKind, 0-based range and selectionRange, and nested children. Methods nest in " +
+    "their impl block or class, enum variants in their enum. Use to back an editor's " +
+    "outline or breadcrumbs without re-parsing the file.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      path: {
+        type: "string",
+        description: "File path relative to the repository root (e.g., 'src/lib.rs')",
+      },
+    },
+    required: ["repository", "path"],
+  },
+};
+
+/**
+ * Creates the document_symbols tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes document symbol queries
+ */
+export function createDocumentSymbolsHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateDocumentSymbolsArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          path: validatedArgs.path,
+        },
+        "Executing document_symbols tool"
+      );
+
+      const response = await analysisService.getDocumentSymbols({
+        repository: validatedArgs.repository,
+        path: validatedArgs.path,
+      });
+
+      const content = formatDocumentSymbolsResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          symbolCount: response.metadata.symbol_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "document_symbols completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "document_symbols failed");
+      toolDebugLog("document_symbols", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats DocumentSymbolsResult as MCP TextContent
+ *
+ * Symbols are passed through unchanged so they keep the LSP field names.
+ *
+ * @param response - Document symbols result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatDocumentSymbolsResponse(response: DocumentSymbolsResult): TextContent {
+  const output = {
+    repository: response.repository,
+    file_path: response.file_path,
+    symbols: response.symbols,
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
@@ -0,0 +1,141 @@
+/**
+ * external_dependencies MCP Tool Implementation
+ *
+ * This module implements the external_dependencies tool for the MCP server.
+ * It groups the `use` paths of a Rust repository that resolve to nothing
+ * indexed by their root crate, giving a picture of which dependencies the
+ * code actually uses, to compare against those declared in `Cargo.toml`.
+ *
+ * @module mcp/tools/external-dependencies
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  ExternalDependenciesResult,
+} from "../../services/graph-analysis-types.js";
+import { validateExternalDependenciesArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:external-dependencies");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for external_dependencies
+ */
+export const externalDependenciesToolDefinition: Tool = {
+  name: "external_dependencies",
+  description:
+    "List the external crates a Rust repository imports from, derived from `use` paths " +
+    "that do not resolve to anything indexed (paths rooted at crate, self, super, a " +
+    "workspace crate, a local module or a local symbol are internal). Crates are listed " +
+    "most referenced first with reference and file counts and the paths imported, and " +
+    "standard library crates (std, core, alloc, ...) are flagged. Use to audit which " +
+    "dependencies are actually used versus declared in Cargo.toml.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      include_std: {
+        type: "boolean",
+        description: "List standard library crates alongside third-party ones",
+        default: true,
+      },
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Creates the external_dependencies tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes external dependency queries
+ */
+export function createExternalDependenciesHandler(
+  analysisService: GraphAnalysisService
+): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateExternalDependenciesArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          include_std: validatedArgs.include_std,
+        },
+        "Executing external_dependencies tool"
+      );
+
+      const response = await analysisService.findExternalDependencies({
+        repository: validatedArgs.repository,
+        include_std: validatedArgs.include_std,
+      });
+
+      const content = formatExternalDependenciesResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          stdCount: response.metadata.std_count,
+          thirdPartyCount: response.metadata.third_party_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "external_dependencies completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "external_dependencies failed");
+      toolDebugLog("external_dependencies", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats ExternalDependenciesResult as MCP TextContent
+ *
+ * @param response - External dependencies result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatExternalDependenciesResponse(response: ExternalDependenciesResult): TextContent {
+  return {
+    type: "text",
+    text: JSON.stringify(response, null, 2),
+  };
+}
@@ -0,0 +1,189 @@
+/**
+ * find_by_attribute MCP Tool Implementation
+ *
+ * This module implements the find_by_attribute tool for the MCP server. It
+ * lists the symbols annotated with an attribute such as `#[deprecated]`,
+ * `#[serde(...)]` or `#[async_trait]`, matched on the attribute path with
+ * optional filtering on its arguments, along with how many functions call
+ * each symbol.
+ *
+ * @module mcp/tools/find-by-attribute
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  AttributeSearchResult,
+} from "../../services/graph-analysis-types.js";
+import { FIND_BY_ATTRIBUTE_FIELDS, validateFindByAttributeArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+import { fieldsProperty, projectFields } from "./utils/field-projection.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:find-by-attribute");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for find_by_attribute
+ */
+export const findByAttributeToolDefinition: Tool = {
+  name: "find_by_attribute",
+  description:
+    "Find symbols annotated with an attribute, e.g. 'deprecated', 'serde', 'async_trait' or " +
+    "'tokio::test'. Matches the attribute path regardless of its arguments unless 'arguments' " +
+    "is given. Each match lists its matching attributes and caller_count (functions calling " +
+    "it), so e.g. deprecated items that are still referenced stand out.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      name: {
+        type: "string",
+        description:
+          "Attribute path without '#[...]' or arguments (e.g., 'deprecated', 'serde'). A bare " +
+          "name also matches paths ending in it ('async_trait' matches 'async_trait::async_trait')",
+      },
+      arguments: {
+        type: "string",
+        description:
+          "Only match attributes whose arguments contain this text (e.g., 'rename' for " +
+          "#[serde(rename = \"id\")])",
+      },
+      path: {
+        type: "string",
+        description:
+          "File path (e.g., 'src/lib.rs') or directory path (e.g., 'src/api') relative to the " +
+          "repository root",
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of symbols to return (1-1000)",
+        minimum: 1,
+        maximum: 1000,
+        default: 100,
+      },
+      fields: fieldsProperty(FIND_BY_ATTRIBUTE_FIELDS),
+    },
+    required: ["repository", "name"],
+  },
+};
+
+/**
+ * Creates the find_by_attribute tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes attribute search queries
+ */
+export function createFindByAttributeHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateFindByAttributeArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          name: validatedArgs.name,
+          arguments: validatedArgs.arguments,
+          path: validatedArgs.path,
+          limit: validatedArgs.limit,
+        },
+        "Executing find_by_attribute tool"
+      );
+
+      const response = await analysisService.findByAttribute({
+        repository: validatedArgs.repository,
+        name: validatedArgs.name,
+        arguments: validatedArgs.arguments,
+        path: validatedArgs.path,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatFindByAttributeResponse(response, validatedArgs.fields);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          totalMatches: response.metadata.total_matches,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "find_by_attribute completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "find_by_attribute failed");
+      toolDebugLog("find_by_attribute", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats AttributeSearchResult as MCP TextContent
+ *
+ * @param response - Attribute search result from GraphAnalysisService
+ * @param fields - Match fields to keep (default: all)
+ * @returns MCP text content with formatted JSON
+ */
+function formatFindByAttributeResponse(
+  response: AttributeSearchResult,
+  fields?: string[]
+): TextContent {
+  const output = {
+    name: response.name,
+    repository: response.repository,
+    matches: response.matches.map((match) =>
+      projectFields(
+        {
+          name: match.name,
+          entity_type: match.entity_type,
+          file_path: match.file_path,
+          start_line: match.start_line,
+          end_line: match.end_line,
+          attributes: match.attributes,
+          caller_count: match.caller_count,
+        },
+        fields
+      )
+    ),
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
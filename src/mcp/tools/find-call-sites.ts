@@ -0,0 +1,149 @@
+/**
+ * find_call_sites MCP Tool Implementation
+ *
+ * This module implements the find_call_sites tool for the MCP server. It
+ * lists every call of a function with the caller, line and source text of
+ * the arguments passed, so an agent can see `function_with_params("World", 3)`
+ * rather than only that a call exists, and search for calls passing a value.
+ *
+ * @module mcp/tools/find-call-sites
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type { GraphAnalysisService, CallSitesResult } from "../../services/graph-analysis-types.js";
+import { validateFindCallSitesArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:find-call-sites");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for find_call_sites
+ */
+export const findCallSitesToolDefinition: Tool = {
+  name: "find_call_sites",
+  description:
+    "List the calls of a function, each with its caller, line and the source text of every " +
+    "argument (e.g., ['\"World\"', '3'] for `function_with_params(\"World\", 3)`). Filter " +
+    "to calls with an argument containing some text to find where a value is passed. " +
+    "Arguments are only available when the repository was indexed with " +
+    "GRAPH_CAPTURE_CALL_ARGUMENTS=true; otherwise each caller is listed with null arguments.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      symbol: {
+        type: "string",
+        description:
+          "Function name (e.g., 'function_with_params'), qualified name " +
+          "(e.g., 'Point::distance'), or graph node ID",
+      },
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      argument_contains: {
+        type: "string",
+        description: "Only return calls with an argument containing this text (case-sensitive)",
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of call sites to return (1-500)",
+        minimum: 1,
+        maximum: 500,
+        default: 100,
+      },
+    },
+    required: ["symbol", "repository"],
+  },
+};
+
+/**
+ * Creates the find_call_sites tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes call site queries
+ */
+export function createFindCallSitesHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateFindCallSitesArgs(args);
+
+      log.info(
+        {
+          symbol: validatedArgs.symbol,
+          repository: validatedArgs.repository,
+          argument_contains: validatedArgs.argument_contains,
+        },
+        "Executing find_call_sites tool"
+      );
+
+      const response = await analysisService.findCallSites({
+        symbol: validatedArgs.symbol,
+        repository: validatedArgs.repository,
+        argument_contains: validatedArgs.argument_contains,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatFindCallSitesResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          totalCount: response.metadata.total_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "find_call_sites completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "find_call_sites failed");
+      toolDebugLog("find_call_sites", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats CallSitesResult as MCP TextContent
+ *
+ * @param response - Call sites result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatFindCallSitesResponse(response: CallSitesResult): TextContent {
+  return {
+    type: "text",
+    text: JSON.stringify(response, null, 2),
+  };
+}
@@ -0,0 +1,193 @@
+/**
+ * find_consts_by_value_range MCP Tool Implementation
+ *
+ * This module implements the find_consts_by_value_range tool for the MCP
+ * server. It finds the constants and statics of a Rust repository whose
+ * literal numeric initializer falls within a range, e.g. every size limit
+ * above 1024, using the values decoded at ingestion.
+ *
+ * @module mcp/tools/find-consts-by-value-range
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  ConstValueRangeResult,
+} from "../../services/graph-analysis-types.js";
+import { FIND_CONSTS_FIELDS, validateFindConstsByValueRangeArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+import { fieldsProperty, projectFields } from "./utils/field-projection.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:find-consts-by-value-range");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for find_consts_by_value_range
+ */
+export const findConstsByValueRangeToolDefinition: Tool = {
+  name: "find_consts_by_value_range",
+  description:
+    "Find Rust constants and statics whose literal numeric value lies within a range, " +
+    "smallest first. Values are decoded at ingestion from literal initializers (digit " +
+    "separators, type suffixes and hex/octal/binary prefixes handled, e.g. 1_024usize = " +
+    "1024); computed initializers such as 4 * 1024 or usize::MAX have no value and are not " +
+    "matched. Use to audit configuration constants, e.g. all buffer sizes above 1024.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      min: {
+        type: "number",
+        description: "Smallest value to include (inclusive; unbounded when omitted)",
+      },
+      max: {
+        type: "number",
+        description: "Largest value to include (inclusive; unbounded when omitted)",
+      },
+      kind: {
+        type: "string",
+        enum: ["integer", "float"],
+        description: "Restrict to integer or float constants (default: both)",
+      },
+      path: {
+        type: "string",
+        description:
+          "File path (e.g., 'src/config.rs') or directory path (e.g., 'src/net') relative " +
+          "to the repository root",
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of constants to return (1-500)",
+        minimum: 1,
+        maximum: 500,
+        default: 100,
+      },
+      fields: fieldsProperty(FIND_CONSTS_FIELDS),
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Creates the find_consts_by_value_range tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes constant value range queries
+ */
+export function createFindConstsByValueRangeHandler(
+  analysisService: GraphAnalysisService
+): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateFindConstsByValueRangeArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          min: validatedArgs.min,
+          max: validatedArgs.max,
+          kind: validatedArgs.kind,
+          path: validatedArgs.path,
+          limit: validatedArgs.limit,
+        },
+        "Executing find_consts_by_value_range tool"
+      );
+
+      const response = await analysisService.findConstsByValueRange({
+        repository: validatedArgs.repository,
+        min: validatedArgs.min,
+        max: validatedArgs.max,
+        kind: validatedArgs.kind,
+        path: validatedArgs.path,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatFindConstsResponse(response, validatedArgs.fields);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          totalCount: response.metadata.total_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "find_consts_by_value_range completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "find_consts_by_value_range failed");
+      toolDebugLog("find_consts_by_value_range", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats ConstValueRangeResult as MCP TextContent
+ *
+ * @param response - Constant value range result from GraphAnalysisService
+ * @param fields - Constant fields to keep (default: all)
+ * @returns MCP text content with formatted JSON
+ */
+function formatFindConstsResponse(response: ConstValueRangeResult, fields?: string[]): TextContent {
+  const output = {
+    repository: response.repository,
+    min: response.min,
+    max: response.max,
+    constants: response.constants.map((constant) =>
+      projectFields(
+        {
+          name: constant.name,
+          entity_type: constant.entity_type,
+          file_path: constant.file_path,
+          start_line: constant.start_line,
+          end_line: constant.end_line,
+          value_kind: constant.value_kind,
+          value: constant.value,
+          value_text: constant.value_text,
+        },
+        fields
+      )
+    ),
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
@@ -0,0 +1,169 @@
+/**
+ * find_cycles MCP Tool Implementation
+ *
+ * This module implements the find_cycles tool for the MCP server. It reports
+ * circular dependencies between files of a repository: strongly-connected
+ * components of the file import graph that contain more than one file, with
+ * the import edges forming each cycle. Self-imports are listed separately and
+ * cycles made only of type-only imports are marked as non-runtime.
+ *
+ * @module mcp/tools/find-cycles
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  ModuleCyclesResult,
+} from "../../services/graph-analysis-types.js";
+import { validateFindCyclesArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:find-cycles");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for find_cycles
+ */
+export const findCyclesToolDefinition: Tool = {
+  name: "find_cycles",
+  description:
+    "Detect circular module dependencies in a repository. Returns each group of files that " +
+    "import each other (directly or transitively) with the import edges forming the cycle. " +
+    "'mutual' cycles involve two files, 'cycle' three or more; cycles made only of type-only " +
+    "imports are marked runtime=false. Files importing themselves are reported separately.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to analyze",
+      },
+      include_type_only: {
+        type: "boolean",
+        description:
+          "Whether type-only imports (e.g., TypeScript 'import type') count as cycle edges",
+        default: true,
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of cycles to return, largest first (1-100)",
+        minimum: 1,
+        maximum: 100,
+        default: 20,
+      },
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Creates the find_cycles tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes module cycle detection
+ */
+export function createFindCyclesHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateFindCyclesArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          include_type_only: validatedArgs.include_type_only,
+          limit: validatedArgs.limit,
+        },
+        "Executing find_cycles tool"
+      );
+
+      const response = await analysisService.findModuleCycles({
+        repository: validatedArgs.repository,
+        include_type_only: validatedArgs.include_type_only,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatModuleCyclesResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          cycleCount: response.metadata.cycle_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "find_cycles completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "find_cycles failed");
+      toolDebugLog("find_cycles", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats ModuleCyclesResult as MCP TextContent
+ *
+ * @param response - Module cycles result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatModuleCyclesResponse(response: ModuleCyclesResult): TextContent {
+  const output = {
+    repository: response.repository,
+    cycles: response.cycles.map((cycle) => ({
+      kind: cycle.kind,
+      runtime: cycle.runtime,
+      files: cycle.files,
+      edges: cycle.edges.map((edge) => ({
+        from: edge.from,
+        to: edge.to,
+        module: edge.module,
+        line: edge.line,
+        ...(edge.type_only && { type_only: true }),
+      })),
+    })),
+    self_imports: response.self_imports.map((edge) => ({
+      file: edge.from,
+      module: edge.module,
+      line: edge.line,
+    })),
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
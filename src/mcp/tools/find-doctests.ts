@@ -0,0 +1,175 @@
+/**
+ * find_doctests MCP Tool Implementation
+ *
+ * This module implements the find_doctests tool for the MCP server. It finds
+ * the Rust code examples in doc comments that show how a symbol is used:
+ * examples in the symbol's own documentation, and examples elsewhere that
+ * call it. Examples are extracted from fenced code blocks at indexing time,
+ * classified by their fence info string as rustdoc would.
+ *
+ * @module mcp/tools/find-doctests
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  DoctestSearchResult,
+} from "../../services/graph-analysis-types.js";
+import { DOCTEST_MODES } from "../../graph/parsing/doctests.js";
+import { validateFindDoctestsArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:find-doctests");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for find_doctests
+ */
+export const findDoctestsToolDefinition: Tool = {
+  name: "find_doctests",
+  description:
+    "Find usage examples for a Rust symbol from doc comment code blocks (doctests): examples " +
+    "in the symbol's own docs come first, then examples in other items' docs that call it. " +
+    "Each example includes its code, fence info string, test mode (run, no_run, ignore, " +
+    "should_panic, compile_fail) and the item it documents. Blocks tagged with another " +
+    "language, such as 'text', are not indexed.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      symbol: {
+        type: "string",
+        description: "Function, method or type name (e.g., 'parse_config')",
+      },
+      mode: {
+        type: "string",
+        enum: [...DOCTEST_MODES],
+        description: "Only return examples tested in this mode",
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of examples to return (1-500)",
+        minimum: 1,
+        maximum: 500,
+        default: 50,
+      },
+    },
+    required: ["repository", "symbol"],
+  },
+};
+
+/**
+ * Creates the find_doctests tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes doctest search queries
+ */
+export function createFindDoctestsHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateFindDoctestsArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          symbol: validatedArgs.symbol,
+          mode: validatedArgs.mode,
+          limit: validatedArgs.limit,
+        },
+        "Executing find_doctests tool"
+      );
+
+      const response = await analysisService.findDoctests({
+        repository: validatedArgs.repository,
+        symbol: validatedArgs.symbol,
+        mode: validatedArgs.mode,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatFindDoctestsResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          totalCount: response.metadata.total_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "find_doctests completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "find_doctests failed");
+      toolDebugLog("find_doctests", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats DoctestSearchResult as MCP TextContent
+ *
+ * @param response - Doctest search result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatFindDoctestsResponse(response: DoctestSearchResult): TextContent {
+  const output = {
+    repository: response.repository,
+    symbol: response.symbol,
+    examples: response.examples.map((example) => ({
+      file_path: example.file_path,
+      start_line: example.start_line,
+      end_line: example.end_line,
+      info: example.info,
+      mode: example.mode,
+      documents_symbol: example.documents_symbol,
+      calls_symbol: example.calls_symbol,
+      documented: {
+        name: example.documented.name,
+        entity_type: example.documented.entity_type,
+        start_line: example.documented.start_line,
+      },
+      code: example.code,
+    })),
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
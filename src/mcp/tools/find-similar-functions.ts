@@ -0,0 +1,189 @@
+/**
+ * find_similar_functions MCP Tool Implementation
+ *
+ * This module implements the find_similar_functions tool for the MCP server.
+ * It groups Rust functions whose bodies share the same or a similar parse
+ * tree shape, ignoring names, literals and comments, to surface copied and
+ * lightly edited logic worth consolidating.
+ *
+ * @module mcp/tools/find-similar-functions
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  SimilarFunctionsResult,
+} from "../../services/graph-analysis-types.js";
+import { validateFindSimilarFunctionsArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:find-similar-functions");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for find_similar_functions
+ */
+export const findSimilarFunctionsToolDefinition: Tool = {
+  name: "find_similar_functions",
+  description:
+    "Find potentially duplicated Rust functions by comparing the structure of their bodies " +
+    "(the shape of the parse tree, ignoring identifiers, literals and comments). Groups of " +
+    "kind 'exact' have identical shapes, e.g. copies with renamed variables; groups of kind " +
+    "'near' have an estimated similarity at or above min_similarity, e.g. copies with a " +
+    "statement added or changed. Functions sharing a fingerprint are exact clones of each " +
+    "other. Use to find copy-pasted logic worth extracting into a shared helper.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      path: {
+        type: "string",
+        description:
+          "File path (e.g., 'src/parser.rs') or directory path (e.g., 'src/codec') relative " +
+          "to the repository root",
+      },
+      min_similarity: {
+        type: "number",
+        description:
+          "Minimum estimated similarity for near clones (0.5-1); 1 reports exact clones only",
+        minimum: 0.5,
+        maximum: 1,
+        default: 0.8,
+      },
+      min_size: {
+        type: "number",
+        description:
+          "Minimum body size in parse tree nodes; smaller bodies such as getters are ignored",
+        minimum: 1,
+        default: 30,
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of groups to return (1-200)",
+        minimum: 1,
+        maximum: 200,
+        default: 20,
+      },
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Creates the find_similar_functions tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes clone detection
+ */
+export function createFindSimilarFunctionsHandler(
+  analysisService: GraphAnalysisService
+): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateFindSimilarFunctionsArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          path: validatedArgs.path,
+          min_similarity: validatedArgs.min_similarity,
+          min_size: validatedArgs.min_size,
+          limit: validatedArgs.limit,
+        },
+        "Executing find_similar_functions tool"
+      );
+
+      const response = await analysisService.findSimilarFunctions({
+        repository: validatedArgs.repository,
+        path: validatedArgs.path,
+        min_similarity: validatedArgs.min_similarity,
+        min_size: validatedArgs.min_size,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatFindSimilarFunctionsResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          exactGroups: response.metadata.exact_groups,
+          nearGroups: response.metadata.near_groups,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "find_similar_functions completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "find_similar_functions failed");
+      toolDebugLog("find_similar_functions", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats SimilarFunctionsResult as MCP TextContent
+ *
+ * @param response - Clone groups from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatFindSimilarFunctionsResponse(response: SimilarFunctionsResult): TextContent {
+  const output = {
+    repository: response.repository,
+    min_similarity: response.min_similarity,
+    groups: response.groups.map((group) => ({
+      kind: group.kind,
+      similarity: group.similarity,
+      functions: group.functions.map((fn) => ({
+        name: fn.name,
+        entity_type: fn.entity_type,
+        file_path: fn.file_path,
+        start_line: fn.start_line,
+        end_line: fn.end_line,
+        size: fn.size,
+        fingerprint: fn.fingerprint,
+      })),
+    })),
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
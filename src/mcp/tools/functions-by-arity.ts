@@ -0,0 +1,167 @@
+/**
+ * functions_by_arity MCP Tool Implementation
+ *
+ * This module implements the functions_by_arity tool for the MCP server. It
+ * finds functions whose parameter count falls within a range, surfacing
+ * functions that take too many arguments (a refactoring smell) or, with a
+ * maximum of zero, nullary ones.
+ *
+ * @module mcp/tools/functions-by-arity
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type { GraphAnalysisService, ArityResult } from "../../services/graph-analysis-types.js";
+import { validateFunctionsByArityArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:functions-by-arity");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for functions_by_arity
+ */
+export const functionsByArityToolDefinition: Tool = {
+  name: "functions_by_arity",
+  description:
+    "Find functions and methods whose parameter count falls within a range, most " +
+    "parameters first. A Rust `self` receiver is not counted unless count_receiver is " +
+    "set. Combine min_params with exported_only to answer 'which public functions take " +
+    "more than 5 arguments', or set max_params to 0 to list nullary functions.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      min_params: {
+        type: "number",
+        description: "Minimum number of parameters",
+        minimum: 0,
+        default: 0,
+      },
+      max_params: {
+        type: "number",
+        description: "Maximum number of parameters (unbounded when omitted)",
+        minimum: 0,
+      },
+      count_receiver: {
+        type: "boolean",
+        description: "Count a Rust `self` receiver as a parameter",
+        default: false,
+      },
+      exported_only: {
+        type: "boolean",
+        description: "Only return exported (`pub`) functions",
+        default: false,
+      },
+      path: {
+        type: "string",
+        description: "Restrict to a file or directory (relative to the repository root)",
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of functions to return (1-500)",
+        minimum: 1,
+        maximum: 500,
+        default: 50,
+      },
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Creates the functions_by_arity tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes arity queries
+ */
+export function createFunctionsByArityHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateFunctionsByArityArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          min_params: validatedArgs.min_params,
+          max_params: validatedArgs.max_params,
+          exported_only: validatedArgs.exported_only,
+        },
+        "Executing functions_by_arity tool"
+      );
+
+      const response = await analysisService.findFunctionsByArity({
+        repository: validatedArgs.repository,
+        min_params: validatedArgs.min_params,
+        ...(validatedArgs.max_params !== undefined && { max_params: validatedArgs.max_params }),
+        count_receiver: validatedArgs.count_receiver,
+        exported_only: validatedArgs.exported_only,
+        ...(validatedArgs.path !== undefined && { path: validatedArgs.path }),
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatFunctionsByArityResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          totalCount: response.metadata.total_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "functions_by_arity completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "functions_by_arity failed");
+      toolDebugLog("functions_by_arity", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats ArityResult as MCP TextContent
+ *
+ * @param response - Arity result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatFunctionsByArityResponse(response: ArityResult): TextContent {
+  return {
+    type: "text",
+    text: JSON.stringify(response, null, 2),
+  };
+}
@@ -0,0 +1,146 @@
+/**
+ * get_coupling_metrics MCP Tool Implementation
+ *
+ * This module implements the get_coupling_metrics tool for the MCP server. It
+ * returns a function's fan-in (distinct callers) and fan-out (distinct callees)
+ * from the call graph recorded at ingestion, and whether the function is on a
+ * call cycle, for assessing how coupled an individual symbol is.
+ *
+ * @module mcp/tools/get-coupling-metrics
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  CouplingMetricsResult,
+} from "../../services/graph-analysis-types.js";
+import { validateGetCouplingMetricsArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:get-coupling-metrics");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for get_coupling_metrics
+ */
+export const getCouplingMetricsToolDefinition: Tool = {
+  name: "get_coupling_metrics",
+  description:
+    "Get call-graph coupling metrics for a function or method: fan-in (number of distinct " +
+    "callers), fan-out (number of distinct callees), and whether it is recursive or part of " +
+    "a call cycle. Only calls that resolve to a single function defined in the repository " +
+    "are counted; library calls are not.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      symbol: {
+        type: "string",
+        description:
+          "Function name (e.g., 'validateToken'), file-qualified name " +
+          "(e.g., 'src/auth/middleware.ts::validateToken'), or graph node ID",
+      },
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      file_path: {
+        type: "string",
+        description:
+          "Optional file path to disambiguate when the function is defined in several files",
+      },
+    },
+    required: ["symbol", "repository"],
+  },
+};
+
+/**
+ * Creates the get_coupling_metrics tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes coupling metrics queries
+ */
+export function createGetCouplingMetricsHandler(
+  analysisService: GraphAnalysisService
+): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateGetCouplingMetricsArgs(args);
+
+      log.info(
+        {
+          symbol: validatedArgs.symbol,
+          repository: validatedArgs.repository,
+          file_path: validatedArgs.file_path,
+        },
+        "Executing get_coupling_metrics tool"
+      );
+
+      const response = await analysisService.getCouplingMetrics({
+        symbol: validatedArgs.symbol,
+        repository: validatedArgs.repository,
+        file_path: validatedArgs.file_path,
+      });
+
+      const content = formatGetCouplingMetricsResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          matchCount: response.metadata.match_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "get_coupling_metrics completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "get_coupling_metrics failed");
+      toolDebugLog("get_coupling_metrics", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats CouplingMetricsResult as MCP TextContent
+ *
+ * @param response - Coupling metrics result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatGetCouplingMetricsResponse(response: CouplingMetricsResult): TextContent {
+  return {
+    type: "text",
+    text: JSON.stringify(response, null, 2),
+  };
+}
@@ -83,6 +83,20 @@ export const getDependenciesToolDefinition: Tool = {
         },
         description: "Filter to specific relationship types. Omit for all types.",
       },
+      dedup: {
+        type: "boolean",
+        description:
+          "Collapse dependencies reached through several relationships or paths into one entry " +
+          "at the shortest depth. Default: true.",
+        default: true,
+      },
+      include_paths: {
+        type: "boolean",
+        description:
+          "List the paths by which each dependency was reached, as the entities in between " +
+          "(nearest first; [] for a direct relationship). Default: false.",
+        default: false,
+      },
     },
     required: ["entity_type", "entity_path", "repository"],
   },
@@ -137,6 +151,8 @@ export function createGetDependenciesHandler(graphService: GraphService): ToolHa
         depth: validatedArgs.depth,
         include_transitive: validatedArgs.depth > 1,
         relationship_types: mapMCPRelationshipTypes(validatedArgs.relationship_types),
+        dedup: validatedArgs.dedup,
+        include_paths: validatedArgs.include_paths,
       };
 
       // Step 3: Call GraphService with validated parameters
@@ -212,6 +228,7 @@ function formatDependencyResponse(
       path: dep.path,
       relationship: relationshipToString(dep.relationship_type),
       depth: dep.depth,
+      ...(dep.paths && { paths: dep.paths }),
       metadata: dep.metadata,
     })),
     metadata: {
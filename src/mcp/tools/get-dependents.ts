@@ -83,6 +83,20 @@ export const getDependentsToolDefinition: Tool = {
         description: "Include dependents from other repositories. Default: false.",
         default: false,
       },
+      dedup: {
+        type: "boolean",
+        description:
+          "Collapse dependents reached through several relationships or paths into one entry " +
+          "at the shortest depth. Default: true.",
+        default: true,
+      },
+      include_paths: {
+        type: "boolean",
+        description:
+          "List the paths by which each dependent was reached, as the entities in between " +
+          "(nearest first; [] for a direct relationship). Default: false.",
+        default: false,
+      },
     },
     required: ["entity_type", "entity_path"],
   },
@@ -141,6 +155,8 @@ export function createGetDependentsHandler(graphService: GraphService): ToolHand
         repository: validatedArgs.repository,
         depth: validatedArgs.depth,
         include_cross_repo: validatedArgs.include_cross_repo,
+        dedup: validatedArgs.dedup,
+        include_paths: validatedArgs.include_paths,
       };
 
       // Step 3: Call GraphService with validated parameters
@@ -211,6 +227,7 @@ function formatDependentResponse(response: DependentResult, _args: GetDependents
       repository: dep.repository,
       relationship: dep.relationship_type.toLowerCase(),
       depth: dep.depth,
+      ...(dep.paths && { paths: dep.paths }),
       metadata: dep.metadata,
     })),
     impact_analysis: {
@@ -0,0 +1,154 @@
+/**
+ * get_local_bindings MCP Tool Implementation
+ *
+ * This module implements the get_local_bindings tool for the MCP server. It
+ * lists the local variables a function binds with `let`, each with the type
+ * read off its annotation or constructor call, so an agent can reason about
+ * a function's internals without reading the whole body.
+ *
+ * @module mcp/tools/get-local-bindings
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  LocalBindingsResult,
+} from "../../services/graph-analysis-types.js";
+import { validateGetLocalBindingsArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:get-local-bindings");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for get_local_bindings
+ */
+export const getLocalBindingsToolDefinition: Tool = {
+  name: "get_local_bindings",
+  description:
+    "List the local variables a function binds with `let`, in source order, with their " +
+    "line and type. Types come from the annotation (`let map: HashMap<K, V>`) or the " +
+    "constructor the initializer calls (`Point::new(..)` or `Point { .. }` gives `Point`); " +
+    "other locals have a null type. Rust only. Use it to understand what a function " +
+    "works with, or to find the type a method is called on.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      symbol: {
+        type: "string",
+        description:
+          "Function name (e.g., 'function_with_calls'), qualified name " +
+          "(e.g., 'Point::distance'), or graph node ID",
+      },
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+    },
+    required: ["symbol", "repository"],
+  },
+};
+
+/**
+ * Creates the get_local_bindings tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes local bindings queries
+ */
+export function createGetLocalBindingsHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateGetLocalBindingsArgs(args);
+
+      log.info(
+        {
+          symbol: validatedArgs.symbol,
+          repository: validatedArgs.repository,
+        },
+        "Executing get_local_bindings tool"
+      );
+
+      const response = await analysisService.getLocalBindings({
+        symbol: validatedArgs.symbol,
+        repository: validatedArgs.repository,
+      });
+
+      const content = formatGetLocalBindingsResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          functionCount: response.metadata.function_count,
+          localCount: response.metadata.local_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "get_local_bindings completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "get_local_bindings failed");
+      toolDebugLog("get_local_bindings", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats LocalBindingsResult as MCP TextContent
+ *
+ * @param response - Local bindings result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatGetLocalBindingsResponse(response: LocalBindingsResult): TextContent {
+  const output = {
+    symbol: response.symbol,
+    repository: response.repository,
+    functions: response.functions.map((fn) => ({
+      id: fn.id,
+      name: fn.name,
+      entity_type: fn.entity_type,
+      file_path: fn.file_path,
+      start_line: fn.start_line,
+      end_line: fn.end_line,
+      locals: fn.locals,
+    })),
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
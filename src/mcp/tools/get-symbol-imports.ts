@@ -0,0 +1,166 @@
+/**
+ * get_symbol_imports MCP Tool Implementation
+ *
+ * This module implements the get_symbol_imports tool for the MCP server. Given a
+ * symbol, it returns the import statements of the file that defines it, so an
+ * agent writing code that references the symbol knows which imports (and
+ * aliases) are already in scope instead of guessing import paths.
+ *
+ * @module mcp/tools/get-symbol-imports
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  SymbolImportsResult,
+} from "../../services/graph-analysis-types.js";
+import { validateGetSymbolImportsArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:get-symbol-imports");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for get_symbol_imports
+ */
+export const getSymbolImportsToolDefinition: Tool = {
+  name: "get_symbol_imports",
+  description:
+    "Get the import statements of the file that defines a symbol (function, class, " +
+    "variable). Returns each imported module with its resolved path, imported names, and " +
+    "aliases. Use this before generating code that references the symbol to reuse the " +
+    "imports already in scope rather than guessing import paths.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      symbol: {
+        type: "string",
+        description:
+          "Symbol name (e.g., 'validateToken'), file-qualified name " +
+          "(e.g., 'src/auth/middleware.ts::validateToken'), or graph node ID",
+      },
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      file_path: {
+        type: "string",
+        description:
+          "Optional file path to disambiguate when the symbol is defined in several files",
+      },
+    },
+    required: ["symbol", "repository"],
+  },
+};
+
+/**
+ * Creates the get_symbol_imports tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes symbol import queries
+ *
+ * @example
+ * ```typescript
+ * const handler = createGetSymbolImportsHandler(new GraphAnalysisServiceImpl(adapter));
+ * const result = await handler({ symbol: "validateToken", repository: "my-project" });
+ * ```
+ */
+export function createGetSymbolImportsHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateGetSymbolImportsArgs(args);
+
+      log.info(
+        {
+          symbol: validatedArgs.symbol,
+          repository: validatedArgs.repository,
+          file_path: validatedArgs.file_path,
+        },
+        "Executing get_symbol_imports tool"
+      );
+
+      const response = await analysisService.getSymbolImports({
+        symbol: validatedArgs.symbol,
+        repository: validatedArgs.repository,
+        file_path: validatedArgs.file_path,
+      });
+
+      const content = formatSymbolImportsResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          matchCount: response.matches.length,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "get_symbol_imports completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "get_symbol_imports failed");
+      toolDebugLog("get_symbol_imports", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats SymbolImportsResult as MCP TextContent
+ *
+ * @param response - Symbol imports result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatSymbolImportsResponse(response: SymbolImportsResult): TextContent {
+  const output = {
+    symbol: response.symbol,
+    repository: response.repository,
+    matches: response.matches.map((match) => ({
+      symbol: {
+        id: match.symbol.id,
+        name: match.symbol.name,
+        type: match.symbol.entity_type,
+        file_path: match.symbol.file_path,
+        line: match.symbol.start_line,
+      },
+      imports: match.imports,
+    })),
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
@@ -0,0 +1,160 @@
+/**
+ * grep_strings MCP Tool Implementation
+ *
+ * This module implements the grep_strings tool for the MCP server. It finds
+ * string literals containing a piece of text, with the file, line and
+ * enclosing symbol of each, for locating user-facing messages and magic
+ * strings that symbol search cannot see. Literals are only indexed when
+ * GRAPH_INDEX_STRING_LITERALS is enabled at ingestion.
+ *
+ * @module mcp/tools/grep-strings
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  StringLiteralSearchResult,
+} from "../../services/graph-analysis-types.js";
+import { validateGrepStringsArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:grep-strings");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for grep_strings
+ */
+export const grepStringsToolDefinition: Tool = {
+  name: "grep_strings",
+  description:
+    "Find string literals in a repository's source code that contain the given text, with " +
+    "the file, line, and enclosing function or class of each. Use to locate where an error " +
+    "message, log line, or magic string is defined. Requires string literal indexing " +
+    "(GRAPH_INDEX_STRING_LITERALS=true) when the repository was indexed; otherwise returns " +
+    "no matches.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      query: {
+        type: "string",
+        description: "Text the string literal must contain (e.g., 'Session expired')",
+      },
+      case_sensitive: {
+        type: "boolean",
+        description: "Whether matching is case-sensitive",
+        default: false,
+      },
+      path: {
+        type: "string",
+        description:
+          "File path (e.g., 'src/auth/middleware.ts') or directory path (e.g., 'src/auth') " +
+          "relative to the repository root",
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of literals to return (1-500)",
+        minimum: 1,
+        maximum: 500,
+        default: 50,
+      },
+    },
+    required: ["repository", "query"],
+  },
+};
+
+/**
+ * Creates the grep_strings tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes string literal searches
+ */
+export function createGrepStringsHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateGrepStringsArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          case_sensitive: validatedArgs.case_sensitive,
+          path: validatedArgs.path,
+          limit: validatedArgs.limit,
+        },
+        "Executing grep_strings tool"
+      );
+
+      const response = await analysisService.grepStrings({
+        repository: validatedArgs.repository,
+        query: validatedArgs.query,
+        case_sensitive: validatedArgs.case_sensitive,
+        path: validatedArgs.path,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatGrepStringsResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          totalMatches: response.metadata.total_matches,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "grep_strings completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "grep_strings failed");
+      toolDebugLog("grep_strings", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats StringLiteralSearchResult as MCP TextContent
+ *
+ * @param response - String literal search result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatGrepStringsResponse(response: StringLiteralSearchResult): TextContent {
+  return {
+    type: "text",
+    text: JSON.stringify(response, null, 2),
+  };
+}
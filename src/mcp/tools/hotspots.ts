@@ -0,0 +1,158 @@
+/**
+ * hotspots MCP Tool Implementation
+ *
+ * This module implements the hotspots tool for the MCP server. It ranks a
+ * repository's symbols by incoming references — calls from other functions
+ * and named imports from other files — to surface the load-bearing functions
+ * and types worth understanding first.
+ *
+ * @module mcp/tools/hotspots
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type { GraphAnalysisService, HotspotsResult } from "../../services/graph-analysis-types.js";
+import { HOTSPOTS_FIELDS, validateHotspotsArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+import { fieldsProperty, projectFields } from "./utils/field-projection.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:hotspots");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for hotspots
+ */
+export const hotspotsToolDefinition: Tool = {
+  name: "hotspots",
+  description:
+    "List the most referenced symbols of a repository: functions, classes and other types " +
+    "ranked by fan-in (distinct calling functions plus files importing them by name), with " +
+    "the modules that reference each. Use to find the core abstractions to read first.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to rank",
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of symbols to return (1-100)",
+        minimum: 1,
+        maximum: 100,
+        default: 20,
+      },
+      fields: fieldsProperty(HOTSPOTS_FIELDS),
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Creates the hotspots tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes hotspot rankings
+ */
+export function createHotspotsHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateHotspotsArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          limit: validatedArgs.limit,
+        },
+        "Executing hotspots tool"
+      );
+
+      const response = await analysisService.findHotspots({
+        repository: validatedArgs.repository,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatHotspotsResponse(response, validatedArgs.fields);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          totalCount: response.metadata.total_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "hotspots completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "hotspots failed");
+      toolDebugLog("hotspots", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats HotspotsResult as MCP TextContent
+ *
+ * @param response - Hotspot ranking from GraphAnalysisService
+ * @param fields - Symbol fields to keep (default: all)
+ * @returns MCP text content with formatted JSON
+ */
+function formatHotspotsResponse(response: HotspotsResult, fields?: string[]): TextContent {
+  const output = {
+    repository: response.repository,
+    hotspots: response.hotspots.map((symbol) =>
+      projectFields(
+        {
+          name: symbol.name,
+          entity_type: symbol.entity_type,
+          file_path: symbol.file_path,
+          start_line: symbol.start_line,
+          end_line: symbol.end_line,
+          fan_in: symbol.fan_in,
+          caller_count: symbol.caller_count,
+          importer_count: symbol.importer_count,
+          referencing_modules: symbol.referencing_modules,
+        },
+        fields
+      )
+    ),
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
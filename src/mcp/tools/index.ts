@@ -13,6 +13,8 @@ import type { LocalFolderUpdateCoordinator } from "../../services/local-folder-u
 import type { MCPRateLimiter } from "../rate-limiter.js";
 import type { JobTracker } from "../job-tracker.js";
 import type { GraphService } from "../../services/graph-service-types.js";
+import type { GraphAnalysisService } from "../../services/graph-analysis-types.js";
+import type { SymbolDiffService } from "../../services/symbol-diff-types.js";
 import type { DocumentSearchService } from "../../services/document-search-types.js";
 import type { ImageSearchService } from "../../services/image-search-types.js";
 import type { ListWatchedFoldersService } from "../../services/list-watched-folders-types.js";
@@ -44,10 +46,97 @@ import {
   getGraphMetricsToolDefinition,
   createGetGraphMetricsHandler,
 } from "./get-graph-metrics.js";
+import {
+  getSymbolImportsToolDefinition,
+  createGetSymbolImportsHandler,
+} from "./get-symbol-imports.js";
+import { findCyclesToolDefinition, createFindCyclesHandler } from "./find-cycles.js";
+import { topLevelItemsToolDefinition, createTopLevelItemsHandler } from "./top-level-items.js";
+import {
+  documentSymbolsToolDefinition,
+  createDocumentSymbolsHandler,
+} from "./document-symbols.js";
+import { listTodosToolDefinition, createListTodosHandler } from "./list-todos.js";
+import { searchSymbolsToolDefinition, createSearchSymbolsHandler } from "./search-symbols.js";
+import { searchByTypeToolDefinition, createSearchByTypeHandler } from "./search-by-type.js";
+import {
+  listEntryPointsToolDefinition,
+  createListEntryPointsHandler,
+} from "./list-entry-points.js";
+import {
+  findByAttributeToolDefinition,
+  createFindByAttributeHandler,
+} from "./find-by-attribute.js";
+import { grepStringsToolDefinition, createGrepStringsHandler } from "./grep-strings.js";
+import {
+  getCouplingMetricsToolDefinition,
+  createGetCouplingMetricsHandler,
+} from "./get-coupling-metrics.js";
+import { mostCoupledToolDefinition, createMostCoupledHandler } from "./most-coupled.js";
+import { hotspotsToolDefinition, createHotspotsHandler } from "./hotspots.js";
+import { couplingReportToolDefinition, createCouplingReportHandler } from "./coupling-report.js";
+import { callPathToolDefinition, createCallPathHandler } from "./call-path.js";
+import {
+  typeHierarchyToolDefinition,
+  createTypeHierarchyHandler,
+} from "./type-hierarchy.js";
+import {
+  deprecationReportToolDefinition,
+  createDeprecationReportHandler,
+} from "./deprecation-report.js";
+import { listUnsafeToolDefinition, createListUnsafeHandler } from "./list-unsafe.js";
+import { listComplexToolDefinition, createListComplexHandler } from "./list-complex.js";
+import {
+  findSimilarFunctionsToolDefinition,
+  createFindSimilarFunctionsHandler,
+} from "./find-similar-functions.js";
+import {
+  findConstsByValueRangeToolDefinition,
+  createFindConstsByValueRangeHandler,
+} from "./find-consts-by-value-range.js";
+import { findDoctestsToolDefinition, createFindDoctestsHandler } from "./find-doctests.js";
+import { missingDocsToolDefinition, createMissingDocsHandler } from "./missing-docs.js";
+import { resolveNameToolDefinition, createResolveNameHandler } from "./resolve-name.js";
+import {
+  topologicalOrderToolDefinition,
+  createTopologicalOrderHandler,
+} from "./topological-order.js";
+import {
+  getLocalBindingsToolDefinition,
+  createGetLocalBindingsHandler,
+} from "./get-local-bindings.js";
+import { largeTypesToolDefinition, createLargeTypesHandler } from "./large-types.js";
+import { symbolsAtLinesToolDefinition, createSymbolsAtLinesHandler } from "./symbols-at-lines.js";
+import {
+  externalDependenciesToolDefinition,
+  createExternalDependenciesHandler,
+} from "./external-dependencies.js";
+import {
+  functionsByArityToolDefinition,
+  createFunctionsByArityHandler,
+} from "./functions-by-arity.js";
+import { findCallSitesToolDefinition, createFindCallSitesHandler } from "./find-call-sites.js";
+import { suggestImportToolDefinition, createSuggestImportHandler } from "./suggest-import.js";
+import { leakyApiToolDefinition, createLeakyApiHandler } from "./leaky-api.js";
+import {
+  diffSymbolsBetweenRefsToolDefinition,
+  createDiffSymbolsBetweenRefsHandler,
+} from "./diff-symbols-between-refs.js";
+import { scopeToDiffToolDefinition, createScopeToDiffHandler } from "./scope-to-diff.js";
+import { snapshotToolDefinition, createSnapshotHandler } from "./snapshot.js";
+import { whatChangedToolDefinition, createWhatChangedHandler } from "./what-changed.js";
+import { recordSnapshotToolDefinition, createRecordSnapshotHandler } from "./record-snapshot.js";
+import { snapshotHistoryToolDefinition, createSnapshotHistoryHandler } from "./snapshot-history.js";
 import {
   registerLocalFolderToolDefinition,
   createRegisterLocalFolderHandler,
 } from "./register-local-folder.js";
+import {
+  reindexToolDefinition,
+  createReindexHandler,
+  createReindexAdmission,
+  createReindexFlight,
+} from "./reindex.js";
 import type { IngestionService } from "../../services/ingestion-service.js";
 
 /**
@@ -75,6 +164,10 @@ export interface ToolRegistryDependencies {
   jobTracker?: JobTracker;
   /** Optional: GraphService for graph-based dependency queries */
   graphService?: GraphService;
+  /** Optional: GraphAnalysisService for symbol-level graph analysis tools */
+  graphAnalysisService?: GraphAnalysisService;
+  /** Optional: SymbolDiffService for the symbol diff and snapshot tools */
+  symbolDiffService?: SymbolDiffService;
   /** Optional: DocumentSearchService for document semantic search */
   documentSearchService?: DocumentSearchService;
   /** Optional: ImageSearchService for image metadata search */
@@ -82,8 +175,8 @@ export interface ToolRegistryDependencies {
   /** Optional: ListWatchedFoldersService for listing watched folders */
   listWatchedFoldersService?: ListWatchedFoldersService;
   /**
-   * Optional: IngestionService for the `register_local_folder` tool (Phase C).
-   * When omitted the tool is not registered.
+   * Optional: IngestionService for the `register_local_folder` and `reindex`
+   * tools (Phase C). When omitted neither tool is registered.
    */
   ingestionService?: IngestionService;
   /** Optional: Human-readable reason why update tools are unavailable */
@@ -245,6 +338,207 @@ export function createToolRegistry(
     };
   }
 
+  // Conditionally add symbol-level analysis tools when GraphAnalysisService is provided
+  if (deps.graphAnalysisService) {
+    registry["get_symbol_imports"] = {
+      definition: getSymbolImportsToolDefinition,
+      handler: createGetSymbolImportsHandler(deps.graphAnalysisService),
+    };
+
+    registry["find_cycles"] = {
+      definition: findCyclesToolDefinition,
+      handler: createFindCyclesHandler(deps.graphAnalysisService),
+    };
+
+    registry["top_level_items"] = {
+      definition: topLevelItemsToolDefinition,
+      handler: createTopLevelItemsHandler(deps.graphAnalysisService),
+    };
+
+    registry["document_symbols"] = {
+      definition: documentSymbolsToolDefinition,
+      handler: createDocumentSymbolsHandler(deps.graphAnalysisService),
+    };
+
+    registry["list_todos"] = {
+      definition: listTodosToolDefinition,
+      handler: createListTodosHandler(deps.graphAnalysisService),
+    };
+
+    registry["search_symbols"] = {
+      definition: searchSymbolsToolDefinition,
+      handler: createSearchSymbolsHandler(deps.graphAnalysisService),
+    };
+
+    registry["search_by_type"] = {
+      definition: searchByTypeToolDefinition,
+      handler: createSearchByTypeHandler(deps.graphAnalysisService),
+    };
+
+    registry["list_entry_points"] = {
+      definition: listEntryPointsToolDefinition,
+      handler: createListEntryPointsHandler(deps.graphAnalysisService),
+    };
+
+    registry["find_by_attribute"] = {
+      definition: findByAttributeToolDefinition,
+      handler: createFindByAttributeHandler(deps.graphAnalysisService),
+    };
+
+    registry["grep_strings"] = {
+      definition: grepStringsToolDefinition,
+      handler: createGrepStringsHandler(deps.graphAnalysisService),
+    };
+
+    registry["get_coupling_metrics"] = {
+      definition: getCouplingMetricsToolDefinition,
+      handler: createGetCouplingMetricsHandler(deps.graphAnalysisService),
+    };
+
+    registry["most_coupled"] = {
+      definition: mostCoupledToolDefinition,
+      handler: createMostCoupledHandler(deps.graphAnalysisService),
+    };
+
+    registry["hotspots"] = {
+      definition: hotspotsToolDefinition,
+      handler: createHotspotsHandler(deps.graphAnalysisService),
+    };
+
+    registry["coupling_report"] = {
+      definition: couplingReportToolDefinition,
+      handler: createCouplingReportHandler(deps.graphAnalysisService),
+    };
+
+    registry["call_path"] = {
+      definition: callPathToolDefinition,
+      handler: createCallPathHandler(deps.graphAnalysisService),
+    };
+
+    registry["type_hierarchy"] = {
+      definition: typeHierarchyToolDefinition,
+      handler: createTypeHierarchyHandler(deps.graphAnalysisService),
+    };
+
+    registry["deprecation_report"] = {
+      definition: deprecationReportToolDefinition,
+      handler: createDeprecationReportHandler(deps.graphAnalysisService),
+    };
+
+    registry["list_unsafe"] = {
+      definition: listUnsafeToolDefinition,
+      handler: createListUnsafeHandler(deps.graphAnalysisService),
+    };
+
+    registry["list_complex"] = {
+      definition: listComplexToolDefinition,
+      handler: createListComplexHandler(deps.graphAnalysisService),
+    };
+
+    registry["find_similar_functions"] = {
+      definition: findSimilarFunctionsToolDefinition,
+      handler: createFindSimilarFunctionsHandler(deps.graphAnalysisService),
+    };
+
+    registry["find_consts_by_value_range"] = {
+      definition: findConstsByValueRangeToolDefinition,
+      handler: createFindConstsByValueRangeHandler(deps.graphAnalysisService),
+    };
+
+    registry["find_doctests"] = {
+      definition: findDoctestsToolDefinition,
+      handler: createFindDoctestsHandler(deps.graphAnalysisService),
+    };
+
+    registry["missing_docs"] = {
+      definition: missingDocsToolDefinition,
+      handler: createMissingDocsHandler(deps.graphAnalysisService),
+    };
+
+    registry["resolve_name"] = {
+      definition: resolveNameToolDefinition,
+      handler: createResolveNameHandler(deps.graphAnalysisService),
+    };
+
+    registry["topological_order"] = {
+      definition: topologicalOrderToolDefinition,
+      handler: createTopologicalOrderHandler(deps.graphAnalysisService),
+    };
+
+    registry["get_local_bindings"] = {
+      definition: getLocalBindingsToolDefinition,
+      handler: createGetLocalBindingsHandler(deps.graphAnalysisService),
+    };
+
+    registry["large_types"] = {
+      definition: largeTypesToolDefinition,
+      handler: createLargeTypesHandler(deps.graphAnalysisService),
+    };
+
+    registry["symbols_at_lines"] = {
+      definition: symbolsAtLinesToolDefinition,
+      handler: createSymbolsAtLinesHandler(deps.graphAnalysisService),
+    };
+
+    registry["external_dependencies"] = {
+      definition: externalDependenciesToolDefinition,
+      handler: createExternalDependenciesHandler(deps.graphAnalysisService),
+    };
+
+    registry["functions_by_arity"] = {
+      definition: functionsByArityToolDefinition,
+      handler: createFunctionsByArityHandler(deps.graphAnalysisService),
+    };
+
+    registry["find_call_sites"] = {
+      definition: findCallSitesToolDefinition,
+      handler: createFindCallSitesHandler(deps.graphAnalysisService),
+    };
+
+    registry["suggest_import"] = {
+      definition: suggestImportToolDefinition,
+      handler: createSuggestImportHandler(deps.graphAnalysisService),
+    };
+
+    registry["leaky_api"] = {
+      definition: leakyApiToolDefinition,
+      handler: createLeakyApiHandler(deps.graphAnalysisService),
+    };
+  }
+
+  // Conditionally add symbol diff tools when SymbolDiffService is provided
+  if (deps.symbolDiffService) {
+    registry["diff_symbols_between_refs"] = {
+      definition: diffSymbolsBetweenRefsToolDefinition,
+      handler: createDiffSymbolsBetweenRefsHandler(deps.symbolDiffService),
+    };
+
+    registry["scope_to_diff"] = {
+      definition: scopeToDiffToolDefinition,
+      handler: createScopeToDiffHandler(deps.symbolDiffService),
+    };
+
+    registry["snapshot"] = {
+      definition: snapshotToolDefinition,
+      handler: createSnapshotHandler(deps.symbolDiffService),
+    };
+
+    registry["what_changed"] = {
+      definition: whatChangedToolDefinition,
+      handler: createWhatChangedHandler(deps.symbolDiffService),
+    };
+
+    registry["record_snapshot"] = {
+      definition: recordSnapshotToolDefinition,
+      handler: createRecordSnapshotHandler(deps.symbolDiffService),
+    };
+
+    registry["snapshot_history"] = {
+      definition: snapshotHistoryToolDefinition,
+      handler: createSnapshotHistoryHandler(deps.symbolDiffService),
+    };
+  }
+
   // Conditionally add document search tool when DocumentSearchService is provided
   if (deps.documentSearchService) {
     registry["search_documents"] = {
@@ -282,6 +576,19 @@ export function createToolRegistry(
         jobTracker: deps.jobTracker,
       }),
     };
+
+    const reindexFlight = createReindexFlight();
+    registry["reindex"] = {
+      definition: reindexToolDefinition,
+      handler: createReindexHandler(
+        {
+          ingestionService: deps.ingestionService,
+          repositoryService: deps.repositoryService,
+        },
+        reindexFlight
+      ),
+      admit: createReindexAdmission(reindexFlight),
+    };
   }
 
   return registry;
@@ -0,0 +1,160 @@
+/**
+ * large_types MCP Tool Implementation
+ *
+ * This module implements the large_types tool for the MCP server. It counts
+ * the methods each type defines across all its impl blocks, inherent and
+ * trait, and reports the types at or above a threshold. Types that keep
+ * accumulating methods are candidates for being split up.
+ *
+ * @module mcp/tools/large-types
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  LargeTypesResult,
+} from "../../services/graph-analysis-types.js";
+import { validateLargeTypesArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:large-types");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for large_types
+ */
+export const largeTypesToolDefinition: Tool = {
+  name: "large_types",
+  description:
+    "List Rust types whose impl blocks together define at least a threshold of methods, " +
+    "largest first. Methods are counted across inherent and trait impls, and every impl " +
+    "block is listed with its file, lines, trait and own count. Associated consts and " +
+    "types can optionally count as members. Types are matched by name. Use to find " +
+    "god objects worth splitting up.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      method_threshold: {
+        type: "number",
+        description: "Minimum number of members a type needs to be listed",
+        minimum: 1,
+        default: 20,
+      },
+      include_associated_items: {
+        type: "boolean",
+        description: "Count associated consts and types toward the total",
+        default: false,
+      },
+      order: {
+        type: "string",
+        enum: ["asc", "desc"],
+        description: "Sort direction on the member count",
+        default: "desc",
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of types to return (1-500)",
+        minimum: 1,
+        maximum: 500,
+        default: 50,
+      },
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Creates the large_types tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes large types queries
+ */
+export function createLargeTypesHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateLargeTypesArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          method_threshold: validatedArgs.method_threshold,
+          include_associated_items: validatedArgs.include_associated_items,
+        },
+        "Executing large_types tool"
+      );
+
+      const response = await analysisService.findLargeTypes({
+        repository: validatedArgs.repository,
+        method_threshold: validatedArgs.method_threshold,
+        include_associated_items: validatedArgs.include_associated_items,
+        order: validatedArgs.order,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatLargeTypesResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          totalCount: response.metadata.total_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "large_types completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "large_types failed");
+      toolDebugLog("large_types", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats LargeTypesResult as MCP TextContent
+ *
+ * @param response - Large types result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatLargeTypesResponse(response: LargeTypesResult): TextContent {
+  return {
+    type: "text",
+    text: JSON.stringify(response, null, 2),
+  };
+}
@@ -0,0 +1,141 @@
+/**
+ * leaky_api MCP Tool Implementation
+ *
+ * This module implements the leaky_api tool for the MCP server. It finds
+ * `pub` Rust functions and methods whose parameter or return types name
+ * types of the crate that are not `pub`, such as a `pub fn` returning a
+ * `pub(crate)` struct, and reports each with the location of the type.
+ *
+ * @module mcp/tools/leaky-api
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type { GraphAnalysisService, LeakyApiResult } from "../../services/graph-analysis-types.js";
+import { validateLeakyApiArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:leaky-api");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for leaky_api
+ */
+export const leakyApiToolDefinition: Tool = {
+  name: "leaky_api",
+  description:
+    "Find public Rust API that leaks private types: `pub` functions and methods whose " +
+    "parameter or return types name a type of the crate that is private, `pub(crate)`, " +
+    "`pub(super)` or `pub(in path)`. Each leak reports the function, the parameter (or " +
+    "null for the return type), the type annotation, and the private type with its " +
+    "location and visibility. Methods of non-public types and trait impl methods are skipped.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      path: {
+        type: "string",
+        description: "Restrict to a file or directory (relative to the repository root)",
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of leaks to return (1-500)",
+        minimum: 1,
+        maximum: 500,
+        default: 100,
+      },
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Creates the leaky_api tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes leaky API queries
+ */
+export function createLeakyApiHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateLeakyApiArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          path: validatedArgs.path,
+        },
+        "Executing leaky_api tool"
+      );
+
+      const response = await analysisService.findLeakyApi({
+        repository: validatedArgs.repository,
+        ...(validatedArgs.path !== undefined && { path: validatedArgs.path }),
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatLeakyApiResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          totalCount: response.metadata.total_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "leaky_api completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "leaky_api failed");
+      toolDebugLog("leaky_api", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats LeakyApiResult as MCP TextContent
+ *
+ * @param response - Leaky API result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatLeakyApiResponse(response: LeakyApiResult): TextContent {
+  return {
+    type: "text",
+    text: JSON.stringify(response, null, 2),
+  };
+}
@@ -0,0 +1,177 @@
+/**
+ * list_complex MCP Tool Implementation
+ *
+ * This module implements the list_complex tool for the MCP server. It ranks
+ * the functions of a Rust repository by approximate cyclomatic complexity,
+ * computed at ingestion from the branch points of each function body, to
+ * surface the code most likely to hide bugs or need tests.
+ *
+ * @module mcp/tools/list-complex
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  ComplexityResult,
+} from "../../services/graph-analysis-types.js";
+import { LIST_COMPLEX_FIELDS, validateListComplexArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+import { fieldsProperty, projectFields } from "./utils/field-projection.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:list-complex");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for list_complex
+ */
+export const listComplexToolDefinition: Tool = {
+  name: "list_complex",
+  description:
+    "List Rust functions whose approximate cyclomatic complexity is at or above a threshold, " +
+    "most complex first. Complexity is 1 plus one per if/else if, while, for, loop, match " +
+    "arm beyond the first, match guard, && or || operator and ? operator in the body; " +
+    "closures count toward the enclosing function and macro bodies are not counted. Use to " +
+    "find risky functions worth reviewing or testing.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      threshold: {
+        type: "number",
+        description: "Minimum complexity a function needs to be listed",
+        minimum: 1,
+        default: 10,
+      },
+      path: {
+        type: "string",
+        description:
+          "File path (e.g., 'src/parser.rs') or directory path (e.g., 'src/codec') relative " +
+          "to the repository root",
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of functions to return (1-500)",
+        minimum: 1,
+        maximum: 500,
+        default: 50,
+      },
+      fields: fieldsProperty(LIST_COMPLEX_FIELDS),
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Creates the list_complex tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes complexity rankings
+ */
+export function createListComplexHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateListComplexArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          threshold: validatedArgs.threshold,
+          path: validatedArgs.path,
+          limit: validatedArgs.limit,
+        },
+        "Executing list_complex tool"
+      );
+
+      const response = await analysisService.listComplex({
+        repository: validatedArgs.repository,
+        threshold: validatedArgs.threshold,
+        path: validatedArgs.path,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatListComplexResponse(response, validatedArgs.fields);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          totalCount: response.metadata.total_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "list_complex completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "list_complex failed");
+      toolDebugLog("list_complex", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats ComplexityResult as MCP TextContent
+ *
+ * @param response - Complexity ranking from GraphAnalysisService
+ * @param fields - Function fields to keep (default: all)
+ * @returns MCP text content with formatted JSON
+ */
+function formatListComplexResponse(response: ComplexityResult, fields?: string[]): TextContent {
+  const output = {
+    repository: response.repository,
+    threshold: response.threshold,
+    functions: response.functions.map((symbol) =>
+      projectFields(
+        {
+          name: symbol.name,
+          entity_type: symbol.entity_type,
+          file_path: symbol.file_path,
+          start_line: symbol.start_line,
+          end_line: symbol.end_line,
+          complexity: symbol.complexity,
+        },
+        fields
+      )
+    ),
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
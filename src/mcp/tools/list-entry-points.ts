@@ -0,0 +1,169 @@
+/**
+ * list_entry_points MCP Tool Implementation
+ *
+ * This module implements the list_entry_points tool for the MCP server. It
+ * lists the functions a repository is run through, as flagged at ingestion:
+ * free `fn main` functions, runtime mains such as `#[tokio::main]`, and
+ * `#[test]`/`#[bench]` functions, grouped by kind.
+ *
+ * @module mcp/tools/list-entry-points
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  EntryPointsResult,
+} from "../../services/graph-analysis-types.js";
+import { ENTRY_POINT_KINDS } from "../../graph/parsing/entry-points.js";
+import { validateListEntryPointsArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:list-entry-points");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for list_entry_points
+ */
+export const listEntryPointsToolDefinition: Tool = {
+  name: "list_entry_points",
+  description:
+    "List how a repository is run: 'main' (free fn main), 'async_main' (runtime attributes " +
+    "such as #[tokio::main] or #[actix_web::main]), 'test' (#[test], #[tokio::test]) and " +
+    "'bench' (#[bench]) functions, grouped by kind with file and line. Filter by kind or by " +
+    "file/directory path.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      kind: {
+        type: "string",
+        enum: [...ENTRY_POINT_KINDS],
+        description: "Only return entry points of this kind",
+      },
+      path: {
+        type: "string",
+        description:
+          "File path (e.g., 'src/main.rs') or directory path (e.g., 'src/bin') relative to " +
+          "the repository root",
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of entry points to return across all kinds (1-1000)",
+        minimum: 1,
+        maximum: 1000,
+        default: 200,
+      },
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Creates the list_entry_points tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes entry point listing queries
+ */
+export function createListEntryPointsHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateListEntryPointsArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          kind: validatedArgs.kind,
+          path: validatedArgs.path,
+          limit: validatedArgs.limit,
+        },
+        "Executing list_entry_points tool"
+      );
+
+      const response = await analysisService.listEntryPoints({
+        repository: validatedArgs.repository,
+        kind: validatedArgs.kind,
+        path: validatedArgs.path,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatListEntryPointsResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          totalCount: response.metadata.total_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "list_entry_points completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "list_entry_points failed");
+      toolDebugLog("list_entry_points", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats EntryPointsResult as MCP TextContent
+ *
+ * @param response - Entry point listing result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatListEntryPointsResponse(response: EntryPointsResult): TextContent {
+  const output = {
+    repository: response.repository,
+    groups: response.groups.map((group) => ({
+      kind: group.kind,
+      entry_points: group.entry_points.map((entryPoint) => ({
+        name: entryPoint.name,
+        file_path: entryPoint.file_path,
+        start_line: entryPoint.start_line,
+        end_line: entryPoint.end_line,
+        ...(entryPoint.attributes !== undefined && { attributes: entryPoint.attributes }),
+      })),
+    })),
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
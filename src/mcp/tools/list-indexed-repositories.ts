@@ -7,9 +7,11 @@
 
 import type { Tool, CallToolResult } from "@modelcontextprotocol/sdk/types.js";
 import type { RepositoryMetadataService, RepositoryInfo } from "../../repositories/types.js";
+import { validateListIndexedRepositoriesArgs } from "../validation.js";
 import { mapToMCPError } from "../errors.js";
 import { getComponentLogger } from "../../logging/index.js";
 import type { ToolHandler } from "../types.js";
+import { absolutePathsProperty, displayPath, getProjectRoot } from "./utils/path-display.js";
 
 /**
  * Response format for a single indexed repository (external API format)
@@ -52,7 +54,9 @@ interface IndexedRepositoryResponse {
   /** Error message if status is "error" */
   error_message?: string;
   /**
-   * Absolute filesystem path of the indexed repository.
+   * Filesystem path of the indexed repository: relative to the configured
+   * project root when it lies beneath it, absolute otherwise (or when the
+   * caller passes `absolute_paths`).
    *
    * Present for `local-git` and `local-folder` sources (where `url` is null
    * or only of historical interest). Omitted for `git-remote` repositories,
@@ -108,10 +112,12 @@ function getLogger(): ReturnType<typeof getComponentLogger> {
  * and calculates summary statistics across all repositories.
  *
  * @param repositories - Array of repository metadata from service
+ * @param renderPath - Renders a repository's absolute local path for output
  * @returns Formatted response with repositories and summary
  */
 function formatListRepositoriesResponse(
-  repositories: RepositoryInfo[]
+  repositories: RepositoryInfo[],
+  renderPath: (localPath: string) => string
 ): ListIndexedRepositoriesResponse {
   // Map each repository to external API format
   const formattedRepos: IndexedRepositoryResponse[] = repositories.map((repo) => ({
@@ -130,7 +136,8 @@ function formatListRepositoriesResponse(
     // repositories the localPath is an internal clone-cache directory and
     // exposing it would invite users to edit it (which would race with the
     // next git fetch + reset --hard).
-    ...(repo.source !== "git-remote" && repo.localPath && { local_path: repo.localPath }),
+    ...(repo.source !== "git-remote" &&
+      repo.localPath && { local_path: renderPath(repo.localPath) }),
     ...(repo.docGraphCoverage &&
       repo.docGraphCoverage.length > 0 && {
         doc_graph_coverage: [...repo.docGraphCoverage],
@@ -162,10 +169,13 @@ export const listIndexedRepositoriesToolDefinition: Tool = {
     "Lists all repositories currently indexed in the knowledge base. " +
     "Returns repository names, URLs, indexing status (ready/indexing/error), " +
     "file and chunk counts, last indexed timestamps, and summary statistics. " +
-    "Use this to discover what repositories are available for semantic search.",
+    "Use this to discover what repositories are available for semantic search. " +
+    "Local paths are relative to the configured project root unless absolute_paths is set.",
   inputSchema: {
     type: "object",
-    properties: {},
+    properties: {
+      absolute_paths: absolutePathsProperty,
+    },
     required: [],
   },
 };
@@ -177,6 +187,8 @@ export const listIndexedRepositoriesToolDefinition: Tool = {
  * allowing for easier testing and loose coupling between MCP layer and business logic.
  *
  * @param repositoryService - Injected repository metadata service instance
+ * @param projectRoot - Root that local paths are emitted relative to
+ *   (defaults to MCP_PROJECT_ROOT)
  * @returns Tool handler function that lists all indexed repositories
  *
  * @example
@@ -187,20 +199,25 @@ export const listIndexedRepositoriesToolDefinition: Tool = {
  * ```
  */
 export function createListRepositoriesHandler(
-  repositoryService: RepositoryMetadataService
+  repositoryService: RepositoryMetadataService,
+  projectRoot: string | undefined = getProjectRoot()
 ): ToolHandler {
-  return async (_args: unknown): Promise<CallToolResult> => {
+  return async (args: unknown): Promise<CallToolResult> => {
     const log = getLogger();
     const startTime = performance.now();
 
     try {
+      const validatedArgs = validateListIndexedRepositoriesArgs(args);
+
       log.debug("Listing indexed repositories");
 
       // Fetch all repositories from metadata service
       const repositories = await repositoryService.listRepositories();
 
       // Format response with summary statistics
-      const response = formatListRepositoriesResponse(repositories);
+      const response = formatListRepositoriesResponse(repositories, (localPath) =>
+        displayPath(localPath, projectRoot, validatedArgs.absolute_paths)
+      );
 
       const duration = performance.now() - startTime;
       log.info(
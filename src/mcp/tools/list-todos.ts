@@ -0,0 +1,154 @@
+/**
+ * list_todos MCP Tool Implementation
+ *
+ * This module implements the list_todos tool for the MCP server. It lists the
+ * TODO/FIXME marker comments recorded for a repository at ingestion, with the
+ * tag, message, file and line of each, optionally filtered by tag or path,
+ * for a backlog view of actionable notes left in the code.
+ *
+ * @module mcp/tools/list-todos
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type { GraphAnalysisService, TodoListResult } from "../../services/graph-analysis-types.js";
+import { validateListTodosArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:list-todos");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for list_todos
+ */
+export const listTodosToolDefinition: Tool = {
+  name: "list_todos",
+  description:
+    "List TODO/FIXME marker comments found in a repository's source files, with the tag, " +
+    "message, file, and line of each. Filter by tag (e.g., 'FIXME') or by file/directory " +
+    "path. Returns per-tag counts so large backlogs can be summarized before paging.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      tag: {
+        type: "string",
+        description: "Only return markers with this tag (case-insensitive, e.g., 'TODO', 'FIXME')",
+      },
+      path: {
+        type: "string",
+        description:
+          "File path (e.g., 'src/auth/middleware.ts') or directory path (e.g., 'src/auth') " +
+          "relative to the repository root",
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of markers to return (1-500)",
+        minimum: 1,
+        maximum: 500,
+        default: 100,
+      },
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Creates the list_todos tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes TODO listing queries
+ */
+export function createListTodosHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateListTodosArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          tag: validatedArgs.tag,
+          path: validatedArgs.path,
+          limit: validatedArgs.limit,
+        },
+        "Executing list_todos tool"
+      );
+
+      const response = await analysisService.listTodos({
+        repository: validatedArgs.repository,
+        tag: validatedArgs.tag,
+        path: validatedArgs.path,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatListTodosResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          totalCount: response.metadata.total_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "list_todos completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "list_todos failed");
+      toolDebugLog("list_todos", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats TodoListResult as MCP TextContent
+ *
+ * @param response - TODO listing result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatListTodosResponse(response: TodoListResult): TextContent {
+  const output = {
+    repository: response.repository,
+    todos: response.todos,
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
@@ -0,0 +1,170 @@
+/**
+ * list_unsafe MCP Tool Implementation
+ *
+ * This module implements the list_unsafe tool for the MCP server. It lists
+ * the unsafe code of a Rust repository for security review: `unsafe fn`
+ * declarations, `unsafe trait`s, functions containing `unsafe { ... }` blocks
+ * with each block's line span, and `unsafe impl` blocks.
+ *
+ * @module mcp/tools/list-unsafe
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  UnsafeCodeResult,
+} from "../../services/graph-analysis-types.js";
+import { LIST_UNSAFE_FIELDS, validateListUnsafeArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+import { fieldsProperty, projectFields } from "./utils/field-projection.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:list-unsafe");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for list_unsafe
+ */
+export const listUnsafeToolDefinition: Tool = {
+  name: "list_unsafe",
+  description:
+    "Audit unsafe Rust code: lists 'unsafe fn' and 'unsafe trait' declarations " +
+    "(is_unsafe: true), functions containing 'unsafe { }' blocks with the line span of each " +
+    "block, and 'unsafe impl' blocks such as 'unsafe impl Send for T'. Filter by file or " +
+    "directory path.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      path: {
+        type: "string",
+        description:
+          "File path (e.g., 'src/ffi.rs') or directory path (e.g., 'src/sys') relative to " +
+          "the repository root",
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of symbols, and separately of impls, to return (1-1000)",
+        minimum: 1,
+        maximum: 1000,
+        default: 200,
+      },
+      fields: fieldsProperty(LIST_UNSAFE_FIELDS),
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Creates the list_unsafe tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes unsafe code listing queries
+ */
+export function createListUnsafeHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateListUnsafeArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          path: validatedArgs.path,
+          limit: validatedArgs.limit,
+        },
+        "Executing list_unsafe tool"
+      );
+
+      const response = await analysisService.listUnsafe({
+        repository: validatedArgs.repository,
+        path: validatedArgs.path,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatListUnsafeResponse(response, validatedArgs.fields);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          unsafeSymbolCount: response.metadata.unsafe_symbol_count,
+          unsafeBlockCount: response.metadata.unsafe_block_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "list_unsafe completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "list_unsafe failed");
+      toolDebugLog("list_unsafe", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats UnsafeCodeResult as MCP TextContent
+ *
+ * @param response - Unsafe code listing from GraphAnalysisService
+ * @param fields - Symbol fields to keep (default: all)
+ * @returns MCP text content with formatted JSON
+ */
+function formatListUnsafeResponse(response: UnsafeCodeResult, fields?: string[]): TextContent {
+  const output = {
+    repository: response.repository,
+    symbols: response.symbols.map((symbol) =>
+      projectFields(
+        {
+          name: symbol.name,
+          entity_type: symbol.entity_type,
+          file_path: symbol.file_path,
+          start_line: symbol.start_line,
+          end_line: symbol.end_line,
+          is_unsafe: symbol.is_unsafe,
+          unsafe_blocks: symbol.unsafe_blocks,
+        },
+        fields
+      )
+    ),
+    impls: response.impls,
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
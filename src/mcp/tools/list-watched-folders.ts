@@ -17,6 +17,7 @@ import { validateListWatchedFoldersArgs } from "../validation.js";
 import { mapToMCPError } from "../errors.js";
 import { getComponentLogger } from "../../logging/index.js";
 import type { ToolHandler } from "../types.js";
+import { absolutePathsProperty, displayPath, getProjectRoot } from "./utils/path-display.js";
 
 /**
  * Lazy-initialized logger to avoid initialization at module load time
@@ -40,10 +41,13 @@ export const listWatchedFoldersToolDefinition: Tool = {
   name: "list_watched_folders",
   description:
     "List all configured watched folders and their indexing status. " +
-    "Use this to understand what document sources are available for search.",
+    "Use this to understand what document sources are available for search. " +
+    "Folder paths are relative to the configured project root unless absolute_paths is set.",
   inputSchema: {
     type: "object",
-    properties: {},
+    properties: {
+      absolute_paths: absolutePathsProperty,
+    },
   },
 };
 
@@ -54,6 +58,8 @@ export const listWatchedFoldersToolDefinition: Tool = {
  * allowing for easier testing and loose coupling between MCP layer and business logic.
  *
  * @param listWatchedFoldersService - Injected service for listing watched folders
+ * @param projectRoot - Root that folder paths are emitted relative to
+ *   (defaults to MCP_PROJECT_ROOT)
  * @returns Tool handler function that lists watched folders
  *
  * @example
@@ -64,23 +70,26 @@ export const listWatchedFoldersToolDefinition: Tool = {
  * ```
  */
 export function createListWatchedFoldersHandler(
-  listWatchedFoldersService: ListWatchedFoldersService
+  listWatchedFoldersService: ListWatchedFoldersService,
+  projectRoot: string | undefined = getProjectRoot()
 ): ToolHandler {
-  return async (_args: unknown): Promise<CallToolResult> => {
+  return async (args: unknown): Promise<CallToolResult> => {
     const startTime = performance.now();
     const log = getLogger();
 
     try {
       log.info("Executing list_watched_folders tool");
 
-      // Step 1: Validate MCP arguments (no parameters, but validates input is an object)
-      validateListWatchedFoldersArgs(_args);
+      // Step 1: Validate MCP arguments
+      const validatedArgs = validateListWatchedFoldersArgs(args);
 
       // Call service to get folder list
       const response = await listWatchedFoldersService.listWatchedFolders();
 
       // Format response for MCP
-      const content = formatListWatchedFoldersResponse(response);
+      const content = formatListWatchedFoldersResponse(response, (folderPath) =>
+        displayPath(folderPath, projectRoot, validatedArgs.absolute_paths)
+      );
 
       const duration = performance.now() - startTime;
       log.info(
@@ -119,14 +128,18 @@ export function createListWatchedFoldersHandler(
  * Formats ListWatchedFoldersResponse as MCP TextContent
  *
  * @param response - Response from ListWatchedFoldersService
+ * @param renderPath - Renders a folder's absolute path for output
  * @returns MCP text content with formatted JSON
  */
-function formatListWatchedFoldersResponse(response: ListWatchedFoldersResponse): TextContent {
+function formatListWatchedFoldersResponse(
+  response: ListWatchedFoldersResponse,
+  renderPath: (folderPath: string) => string
+): TextContent {
   const output = {
     folders: response.folders.map((folder) => ({
       id: folder.id,
       name: folder.name,
-      path: folder.path,
+      path: renderPath(folder.path),
       enabled: folder.enabled,
       documentCount: folder.documentCount,
       imageCount: folder.imageCount,
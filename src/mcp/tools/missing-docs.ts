@@ -0,0 +1,173 @@
+/**
+ * missing_docs MCP Tool Implementation
+ *
+ * This module implements the missing_docs tool for the MCP server. It reports
+ * documentation debt: the public symbols of a repository whose doc comment,
+ * docstring or JSDoc block is missing, grouped by module, with coverage
+ * counts. Trait impl methods are skipped because they inherit the trait's
+ * documentation, and an allowlist covers items left undocumented on purpose.
+ *
+ * @module mcp/tools/missing-docs
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  MissingDocsResult,
+} from "../../services/graph-analysis-types.js";
+import { validateMissingDocsArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:missing-docs");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for missing_docs
+ */
+export const missingDocsToolDefinition: Tool = {
+  name: "missing_docs",
+  description:
+    "List public symbols (pub items, exported declarations) that have no documentation, " +
+    "grouped by module (file), with documented and public counts and overall coverage. " +
+    "Methods of trait impls inherit the trait's docs and are skipped, as are " +
+    "#[doc(hidden)] items. Use 'allow' for symbols left undocumented on purpose. " +
+    "Requires a repository indexed after documentation tracking was added.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      path: {
+        type: "string",
+        description:
+          "File path, or directory path to cover every module beneath it (e.g., 'src/parser')",
+      },
+      allow: {
+        type: "array",
+        items: { type: "string" },
+        description:
+          "Symbols intentionally left undocumented: names ('new'), qualified names " +
+          "('Point::new') or file-qualified names ('src/point.rs::new')",
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of undocumented symbols to return (1-1000)",
+        minimum: 1,
+        maximum: 1000,
+        default: 200,
+      },
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Creates the missing_docs tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes missing docs queries
+ */
+export function createMissingDocsHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateMissingDocsArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          path: validatedArgs.path,
+          allowCount: validatedArgs.allow.length,
+          limit: validatedArgs.limit,
+        },
+        "Executing missing_docs tool"
+      );
+
+      const response = await analysisService.listMissingDocs({
+        repository: validatedArgs.repository,
+        path: validatedArgs.path,
+        allow: validatedArgs.allow,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatMissingDocsResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          missingCount: response.metadata.missing_count,
+          coverage: response.metadata.coverage,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "missing_docs completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "missing_docs failed");
+      toolDebugLog("missing_docs", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats MissingDocsResult as MCP TextContent
+ *
+ * @param response - Missing docs result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatMissingDocsResponse(response: MissingDocsResult): TextContent {
+  const output = {
+    repository: response.repository,
+    modules: response.modules.map((module) => ({
+      module: module.module,
+      public_count: module.public_count,
+      documented_count: module.documented_count,
+      symbols: module.symbols.map((symbol) => ({
+        name: symbol.name,
+        entity_type: symbol.entity_type,
+        start_line: symbol.start_line,
+        end_line: symbol.end_line,
+      })),
+    })),
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
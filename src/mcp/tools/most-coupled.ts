@@ -0,0 +1,144 @@
+/**
+ * most_coupled MCP Tool Implementation
+ *
+ * This module implements the most_coupled tool for the MCP server. It ranks a
+ * repository's functions by combined fan-in and fan-out over the call graph
+ * recorded at ingestion, to surface refactoring targets.
+ *
+ * @module mcp/tools/most-coupled
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  MostCoupledResult,
+} from "../../services/graph-analysis-types.js";
+import { validateMostCoupledArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:most-coupled");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for most_coupled
+ */
+export const mostCoupledToolDefinition: Tool = {
+  name: "most_coupled",
+  description:
+    "Rank the functions and methods of a repository by call-graph coupling (fan-in + " +
+    "fan-out), most coupled first, with cycle membership for each. Use to find refactoring " +
+    "targets; scope to a file or directory with path.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to rank",
+      },
+      path: {
+        type: "string",
+        description:
+          "File path (e.g., 'src/auth/middleware.ts') or directory path (e.g., 'src/auth') " +
+          "relative to the repository root",
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of functions to return (1-100)",
+        minimum: 1,
+        maximum: 100,
+        default: 20,
+      },
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Creates the most_coupled tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes most-coupled rankings
+ */
+export function createMostCoupledHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateMostCoupledArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          path: validatedArgs.path,
+          limit: validatedArgs.limit,
+        },
+        "Executing most_coupled tool"
+      );
+
+      const response = await analysisService.findMostCoupled({
+        repository: validatedArgs.repository,
+        path: validatedArgs.path,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatMostCoupledResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          totalCount: response.metadata.total_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "most_coupled completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "most_coupled failed");
+      toolDebugLog("most_coupled", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats MostCoupledResult as MCP TextContent
+ *
+ * @param response - Most-coupled ranking from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatMostCoupledResponse(response: MostCoupledResult): TextContent {
+  return {
+    type: "text",
+    text: JSON.stringify(response, null, 2),
+  };
+}
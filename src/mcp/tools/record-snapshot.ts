@@ -0,0 +1,124 @@
+/**
+ * record_snapshot MCP Tool Implementation
+ *
+ * This module implements the record_snapshot tool for the MCP server. It
+ * appends a timestamped fingerprint of a repository's indexed symbols to a
+ * rolling history, recording the symbol count and how many symbols were
+ * added, removed or changed signature since the previous entry. The
+ * snapshot_history tool reads the entries back as a trend.
+ *
+ * @module mcp/tools/record-snapshot
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type { SymbolDiffService, RecordSnapshotResult } from "../../services/symbol-diff-types.js";
+import { validateRecordSnapshotArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:record-snapshot");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for record_snapshot
+ */
+export const recordSnapshotToolDefinition: Tool = {
+  name: "record_snapshot",
+  description:
+    "Append a timestamped fingerprint of an indexed repository's symbols to its snapshot " +
+    "history, recording the symbol count and the symbols added, removed or changed in " +
+    "signature since the previous entry. The oldest entries are dropped beyond the " +
+    "retention limit. Record after each reindex and use snapshot_history to chart API " +
+    "growth and churn over time.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to record",
+      },
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Creates the record_snapshot tool handler
+ *
+ * @param diffService - Injected SymbolDiffService instance
+ * @returns Tool handler function that appends snapshot history entries
+ */
+export function createRecordSnapshotHandler(diffService: SymbolDiffService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateRecordSnapshotArgs(args);
+
+      log.info({ repository: validatedArgs.repository }, "Executing record_snapshot tool");
+
+      const response = await diffService.recordSnapshot({
+        repository: validatedArgs.repository,
+      });
+
+      const content = formatRecordSnapshotResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          symbolCount: response.symbol_count,
+          historyLength: response.history_length,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "record_snapshot completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "record_snapshot failed");
+      toolDebugLog("record_snapshot", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats RecordSnapshotResult as MCP TextContent
+ *
+ * @param response - New history entry from SymbolDiffService
+ * @returns MCP text content with formatted JSON
+ */
+function formatRecordSnapshotResponse(response: RecordSnapshotResult): TextContent {
+  return {
+    type: "text",
+    text: JSON.stringify(response, null, 2),
+  };
+}
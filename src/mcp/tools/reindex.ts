@@ -0,0 +1,400 @@
+/**
+ * reindex MCP Tool Implementation
+ *
+ * Forces a full rebuild of an already-indexed repository: the existing
+ * collection is dropped and every eligible file is re-scanned, re-chunked,
+ * re-embedded and (when graph storage is configured) re-written to the
+ * knowledge graph. This is the remediation path when the completeness check
+ * reports an incomplete index or `trigger_incremental_update` reports drift.
+ *
+ * Features:
+ * - Progress notifications (files processed / total) when the client supplies
+ *   a progress token
+ * - Summary of symbols indexed, files skipped and errors on completion
+ * - Single-flight guard: a second reindex while one is running is rejected
+ *   with `reindex_in_progress` instead of queueing behind it
+ * - Cancellation: when the client cancels the request or disconnects, the
+ *   run stops at the next file and reports `reindex_cancelled`
+ *
+ * The tool runs in the concurrency limiter's long-running lane. Its admission
+ * check applies the single-flight guard before a call queues for a slot, so
+ * a duplicate reindex is rejected straight away instead of waiting in line.
+ *
+ * @module mcp/tools/reindex
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type { IngestionService } from "../../services/ingestion-service.js";
+import type { RepositoryMetadataService } from "../../repositories/types.js";
+import type { IndexResult } from "../../services/ingestion-types.js";
+import {
+  IndexingInProgressError,
+  IndexingCancelledError,
+} from "../../services/ingestion-errors.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import type {
+  ToolHandler,
+  ToolCallContext,
+  ToolAdmission,
+  ToolAdmissionCheck,
+} from "../types.js";
+
+/**
+ * Maximum number of error messages echoed back in the summary
+ */
+const MAX_REPORTED_ERRORS = 20;
+
+/**
+ * Error codes for reindex responses
+ */
+type ReindexErrorCode =
+  | "invalid_argument"
+  | "repository_not_found"
+  | "reindex_in_progress"
+  | "reindex_cancelled"
+  | "reindex_failed"
+  | "internal_error";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:reindex");
+  }
+  return logger;
+}
+
+/**
+ * Validated tool arguments
+ */
+interface ReindexArgs {
+  repository: string;
+}
+
+/**
+ * Summary returned when the reindex completes
+ */
+interface SuccessResponse {
+  success: true;
+  repository: string;
+  status: "success" | "partial";
+  files_scanned: number;
+  files_processed: number;
+  files_failed: number;
+  files_skipped: number;
+  chunks_created: number;
+  symbols_indexed?: number;
+  duration_ms: number;
+  error_count: number;
+  errors?: string[];
+}
+
+/**
+ * Error response format
+ */
+interface ErrorResponse {
+  success: false;
+  error: ReindexErrorCode;
+  message: string;
+}
+
+/**
+ * MCP tool definition for reindex
+ */
+export const reindexToolDefinition: Tool = {
+  name: "reindex",
+  description:
+    "Forces a full rebuild of an already-indexed repository: the existing index is discarded and " +
+    "every file is re-scanned, re-embedded and re-written to the knowledge graph. Use this when " +
+    "trigger_incremental_update or a completeness check reports that the index is stale or incomplete. " +
+    "Sends progress notifications (files processed / total) when the request carries a progress token " +
+    "and returns a summary with symbols indexed, files skipped and errors. Only one reindex can run " +
+    "at a time; a concurrent request fails with reindex_in_progress.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description:
+          "Repository name (must already be indexed). Use list_indexed_repositories to see available repositories.",
+        minLength: 1,
+      },
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Validate and extract arguments
+ */
+function validateArgs(args: unknown): ReindexArgs {
+  if (typeof args !== "object" || args === null) {
+    throw new Error("Arguments must be an object");
+  }
+
+  const obj = args as Record<string, unknown>;
+
+  if (typeof obj["repository"] !== "string" || obj["repository"].trim() === "") {
+    throw new Error("repository must be a non-empty string");
+  }
+
+  return { repository: obj["repository"].trim() };
+}
+
+/**
+ * Format error response as TextContent
+ */
+function formatErrorResponse(code: ReindexErrorCode, message: string): TextContent {
+  const response: ErrorResponse = { success: false, error: code, message };
+  return { type: "text", text: JSON.stringify(response, null, 2) };
+}
+
+/**
+ * Format the completion summary as TextContent
+ */
+function formatSuccessResponse(result: IndexResult): TextContent {
+  const response: SuccessResponse = {
+    success: true,
+    repository: result.repository,
+    status: result.status === "partial" ? "partial" : "success",
+    files_scanned: result.stats.filesScanned,
+    files_processed: result.stats.filesProcessed,
+    files_failed: result.stats.filesFailed,
+    files_skipped: result.stats.filesSkipped ?? 0,
+    chunks_created: result.stats.chunksCreated,
+    duration_ms: Math.round(result.stats.durationMs),
+    error_count: result.errors.length,
+  };
+
+  if (result.stats.symbolsIndexed !== undefined) {
+    response.symbols_indexed = result.stats.symbolsIndexed;
+  }
+
+  if (result.errors.length > 0) {
+    response.errors = result.errors
+      .slice(0, MAX_REPORTED_ERRORS)
+      .map((e) => (e.filePath ? `${e.filePath}: ${e.message}` : e.message));
+  }
+
+  return { type: "text", text: JSON.stringify(response, null, 2) };
+}
+
+/**
+ * Dependencies required by the reindex handler
+ */
+export interface ReindexDependencies {
+  ingestionService: IngestionService;
+  repositoryService: RepositoryMetadataService;
+}
+
+/**
+ * Single-flight state shared by the reindex handler and its admission check
+ */
+export interface ReindexFlight {
+  /** Repository being reindexed by a running handler */
+  active: string | null;
+
+  /** Repository of a call admitted to queue for a concurrency slot */
+  admitted: string | null;
+}
+
+/**
+ * Creates empty single-flight state
+ */
+export function createReindexFlight(): ReindexFlight {
+  return { active: null, admitted: null };
+}
+
+/**
+ * Build the `reindex_in_progress` rejection for a second reindex
+ */
+function inProgressResult(repository: string): CallToolResult {
+  return {
+    content: [
+      formatErrorResponse(
+        "reindex_in_progress",
+        `A reindex of '${repository}' is already in progress. Wait for it to finish before starting another.`
+      ),
+    ],
+    isError: true,
+  };
+}
+
+/**
+ * Creates the reindex admission check
+ *
+ * Runs before the call queues for a long-running slot. A call is rejected
+ * while another reindex is running or already admitted; otherwise it claims
+ * the admission until the server releases it. Invalid arguments are let
+ * through so the handler reports them.
+ *
+ * @param flight - State shared with the handler from `createReindexHandler`
+ * @returns Admission check for the tool registry entry
+ */
+export function createReindexAdmission(flight: ReindexFlight): ToolAdmissionCheck {
+  return (args: unknown): ToolAdmission => {
+    const busyWith = flight.active ?? flight.admitted;
+    if (busyWith !== null) {
+      getLogger().info({ active: busyWith }, "Reindex rejected before queueing");
+      return { rejected: inProgressResult(busyWith) };
+    }
+
+    let repository: string;
+    try {
+      repository = validateArgs(args).repository;
+    } catch {
+      return { release: () => {} };
+    }
+
+    flight.admitted = repository;
+    return {
+      release: () => {
+        flight.admitted = null;
+      },
+    };
+  };
+}
+
+/**
+ * Creates the reindex tool handler
+ *
+ * The single-flight guard lives in the handler closure so it covers the
+ * metadata lookup as well as the indexing run. `IngestionService` enforces
+ * its own guard too, which additionally catches overlap with other indexing
+ * entry points such as `register_local_folder`.
+ *
+ * @param deps - Ingestion and repository metadata services
+ * @param flight - State shared with the admission check from `createReindexAdmission`
+ * @returns Tool handler function
+ */
+export function createReindexHandler(
+  deps: ReindexDependencies,
+  flight: ReindexFlight = createReindexFlight()
+): ToolHandler {
+  const { ingestionService, repositoryService } = deps;
+
+  return async (args: unknown, context?: ToolCallContext): Promise<CallToolResult> => {
+    const log = getLogger();
+
+    let validated: ReindexArgs;
+    try {
+      validated = validateArgs(args);
+    } catch (validationError) {
+      const message =
+        validationError instanceof Error ? validationError.message : String(validationError);
+      log.warn({ error: message }, "Argument validation failed");
+      return {
+        content: [formatErrorResponse("invalid_argument", message)],
+        isError: true,
+      };
+    }
+
+    if (flight.active !== null) {
+      log.info(
+        { repository: validated.repository, active: flight.active },
+        "Reindex rejected: another reindex is running"
+      );
+      return inProgressResult(flight.active);
+    }
+
+    flight.active = validated.repository;
+    try {
+      const repo = await repositoryService.getRepository(validated.repository);
+      if (!repo) {
+        return {
+          content: [
+            formatErrorResponse(
+              "repository_not_found",
+              `Repository '${validated.repository}' not found. Use list_indexed_repositories to see available repositories.`
+            ),
+          ],
+          isError: true,
+        };
+      }
+
+      log.info({ repository: repo.name }, "Starting full reindex");
+
+      // local-folder repositories have no clone URL; their registered path is
+      // the source (same rule as `update --force` and interrupted-update recovery).
+      const sourceForReindex = repo.url ?? repo.localPath;
+      const result = await ingestionService.indexRepository(sourceForReindex, {
+        name: repo.name,
+        branch: repo.branch,
+        force: true,
+        tier: repo.tier,
+        respectGitignore: repo.respectGitignore,
+        signal: context?.signal,
+        onProgress: (progress) => {
+          const { filesProcessed, totalFiles } = progress.details;
+          if (filesProcessed !== undefined && totalFiles !== undefined) {
+            context?.reportProgress?.(
+              filesProcessed,
+              totalFiles,
+              `${progress.phase}: ${filesProcessed}/${totalFiles} files`
+            );
+          }
+        },
+      });
+
+      const handled = result.stats.filesProcessed + result.stats.filesFailed;
+      context?.reportProgress?.(handled, handled, "complete");
+
+      log.info(
+        {
+          repository: repo.name,
+          status: result.status,
+          filesProcessed: result.stats.filesProcessed,
+          errorCount: result.errors.length,
+          duration_ms: Math.round(result.stats.durationMs),
+        },
+        "Reindex completed"
+      );
+
+      if (result.status === "failed") {
+        const message = result.errors[0]?.message ?? "Reindex failed without details";
+        return {
+          content: [formatErrorResponse("reindex_failed", message)],
+          isError: true,
+        };
+      }
+
+      return {
+        content: [formatSuccessResponse(result)],
+        isError: false,
+      };
+    } catch (error) {
+      if (error instanceof IndexingInProgressError) {
+        return {
+          content: [formatErrorResponse("reindex_in_progress", error.message)],
+          isError: true,
+        };
+      }
+
+      if (error instanceof IndexingCancelledError) {
+        log.info({ repository: validated.repository }, "Reindex cancelled by client");
+        return {
+          content: [
+            formatErrorResponse(
+              "reindex_cancelled",
+              `${error.message}. The index is incomplete; run reindex again to rebuild it.`
+            ),
+          ],
+          isError: true,
+        };
+      }
+
+      log.error({ error, repository: validated.repository }, "Reindex threw");
+      const mcpError = mapToMCPError(error);
+      return {
+        content: [formatErrorResponse("internal_error", mcpError.message)],
+        isError: true,
+      };
+    } finally {
+      flight.active = null;
+    }
+  };
+}
@@ -0,0 +1,165 @@
+/**
+ * resolve_name MCP Tool Implementation
+ *
+ * This module implements the resolve_name tool for the MCP server. It turns a
+ * short or partially qualified name (`Point`, `geometry::Point`) into the
+ * concrete symbols it may refer to, with their qualified paths and kinds.
+ * Names shared by fewer symbols rank higher, so the candidates a caller most
+ * likely means come first.
+ *
+ * @module mcp/tools/resolve-name
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  NameResolutionResult,
+} from "../../services/graph-analysis-types.js";
+import { validateResolveNameArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:resolve-name");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for resolve_name
+ */
+export const resolveNameToolDefinition: Tool = {
+  name: "resolve_name",
+  description:
+    "Resolve a short or partially qualified name to the symbols it may refer to. Returns " +
+    "every symbol whose name or qualified path suffix matches (e.g., 'Point', " +
+    "'geometry::Point', 'Point::new'), with its qualified path, kind and location. " +
+    "Exact matches come before case-insensitive ones; among them, names shared by fewer " +
+    "symbols rank higher. Use it to pick a concrete symbol before go-to-definition.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      partial: {
+        type: "string",
+        description:
+          "Symbol name or qualified path suffix; '::' and '.' both separate segments " +
+          "(e.g., 'Point', 'crate::geometry::Point', 'AuthService.login')",
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of candidates to return (1-200)",
+        minimum: 1,
+        maximum: 200,
+        default: 20,
+      },
+    },
+    required: ["repository", "partial"],
+  },
+};
+
+/**
+ * Creates the resolve_name tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes name resolution queries
+ */
+export function createResolveNameHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateResolveNameArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          partial: validatedArgs.partial,
+          limit: validatedArgs.limit,
+        },
+        "Executing resolve_name tool"
+      );
+
+      const response = await analysisService.resolveName({
+        repository: validatedArgs.repository,
+        partial: validatedArgs.partial,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatResolveNameResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          totalCount: response.metadata.total_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "resolve_name completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "resolve_name failed");
+      toolDebugLog("resolve_name", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats NameResolutionResult as MCP TextContent
+ *
+ * @param response - Name resolution result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatResolveNameResponse(response: NameResolutionResult): TextContent {
+  const output = {
+    partial: response.partial,
+    repository: response.repository,
+    candidates: response.candidates.map((candidate) => ({
+      qualified_name: candidate.qualified_name,
+      name: candidate.name,
+      kind: candidate.kind,
+      entity_type: candidate.entity_type,
+      file_path: candidate.file_path,
+      start_line: candidate.start_line,
+      end_line: candidate.end_line,
+      match_type: candidate.match_type,
+      name_count: candidate.name_count,
+    })),
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
@@ -0,0 +1,182 @@
+/**
+ * scope_to_diff MCP Tool Implementation
+ *
+ * This module implements the scope_to_diff tool for the MCP server. It
+ * narrows a repository to the symbols touched by the changes since a base
+ * ref: only the changed files are parsed, at the head ref, and only symbols
+ * whose spans overlap a changed line range are returned. Nothing is read from
+ * or written to the index, so CI checks can run against a PR's changes
+ * without keeping an index up to date.
+ *
+ * @module mcp/tools/scope-to-diff
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type { SymbolDiffService, DiffScopeResult } from "../../services/symbol-diff-types.js";
+import { SYMBOL_KINDS } from "../../graph/parsing/symbol-kinds.js";
+import { validateScopeToDiffArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:scope-to-diff");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for scope_to_diff
+ */
+export const scopeToDiffToolDefinition: Tool = {
+  name: "scope_to_diff",
+  description:
+    "List only the symbols touched by the changes since base_ref: the changed files are " +
+    "parsed at head_ref and symbols whose line spans overlap an added, modified, or deleted " +
+    "line are returned with the changed line ranges. Does not use or update the index, so " +
+    "it suits CI gates on a PR. Filter by name text or symbol kind. Results are marked " +
+    "scope='diff'.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name (must be a git repository)",
+      },
+      base_ref: {
+        type: "string",
+        description: "Base branch, tag, or commit SHA the changes are measured from (e.g., 'main')",
+      },
+      head_ref: {
+        type: "string",
+        description: "Head branch, tag, or commit SHA whose symbols are returned",
+        default: "HEAD",
+      },
+      path: {
+        type: "string",
+        description:
+          "Only scope files at or beneath this path (e.g., 'src/auth'), relative to the " +
+          "repository root",
+      },
+      query: {
+        type: "string",
+        description: "Only return symbols whose name contains this text (case-insensitive)",
+      },
+      kind: {
+        type: "string",
+        enum: [...SYMBOL_KINDS],
+        description: "Only return symbols of this kind",
+      },
+    },
+    required: ["repository", "base_ref"],
+  },
+};
+
+/**
+ * Creates the scope_to_diff tool handler
+ *
+ * @param diffService - Injected SymbolDiffService instance
+ * @returns Tool handler function that executes diff scope queries
+ */
+export function createScopeToDiffHandler(diffService: SymbolDiffService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateScopeToDiffArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          base_ref: validatedArgs.base_ref,
+          head_ref: validatedArgs.head_ref,
+          path: validatedArgs.path,
+          query: validatedArgs.query,
+          kind: validatedArgs.kind,
+        },
+        "Executing scope_to_diff tool"
+      );
+
+      const response = await diffService.scopeToDiff({
+        repository: validatedArgs.repository,
+        base_ref: validatedArgs.base_ref,
+        head_ref: validatedArgs.head_ref,
+        path: validatedArgs.path,
+        query: validatedArgs.query,
+        kind: validatedArgs.kind,
+      });
+
+      const content = formatScopeToDiffResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          symbolCount: response.metadata.symbol_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "scope_to_diff completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "scope_to_diff failed");
+      toolDebugLog("scope_to_diff", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats DiffScopeResult as MCP TextContent
+ *
+ * The scope marker and refs lead the output so a reader sees at once that
+ * the symbols are limited to the diff rather than the whole repository.
+ *
+ * @param response - Diff scope result from SymbolDiffService
+ * @returns MCP text content with formatted JSON
+ */
+function formatScopeToDiffResponse(response: DiffScopeResult): TextContent {
+  const output = {
+    scope: response.scope,
+    scope_description:
+      `Limited to symbols overlapping lines changed between ${response.base_ref} ` +
+      `(${response.base_sha.slice(0, 12)}) and ${response.head_ref} ` +
+      `(${response.head_sha.slice(0, 12)})`,
+    repository: response.repository,
+    base_ref: response.base_ref,
+    head_ref: response.head_ref,
+    base_sha: response.base_sha,
+    head_sha: response.head_sha,
+    files: response.files,
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
@@ -0,0 +1,245 @@
+/**
+ * search_by_type MCP Tool Implementation
+ *
+ * This module implements the search_by_type tool for the MCP server. It finds
+ * functions and methods that accept a type as a parameter or return it, using
+ * the structured parameter and return types recorded at ingestion. With
+ * contains enabled, reference and generic wrappers are looked through so
+ * "Point" also finds `&mut Point` parameters and "Result" finds
+ * `Result<String, Error>` returns.
+ *
+ * @module mcp/tools/search-by-type
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  TypeSearchResult,
+} from "../../services/graph-analysis-types.js";
+import { SEARCH_BY_TYPE_FIELDS, validateSearchByTypeArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler, OutputFormat } from "../types.js";
+import {
+  formatDetails,
+  formatLocation,
+  formatParams,
+  markdownContent,
+  outputFormatProperty,
+} from "./utils/markdown.js";
+import { fieldsProperty, projectFields } from "./utils/field-projection.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:search-by-type");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for search_by_type
+ */
+export const searchByTypeToolDefinition: Tool = {
+  name: "search_by_type",
+  description:
+    "Find functions and methods by parameter or return type, e.g. what returns 'Point' or " +
+    "what takes '&str'. By default the whole annotation must equal the type. Set " +
+    "contains=true to also look through references and generic wrappers, so 'Result' " +
+    "matches a 'Result<String, Error>' return and 'Point' matches a '&mut Point' parameter. " +
+    "Exact matches are listed first. Set output_format='markdown' for a human-readable " +
+    "rendering.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      type: {
+        type: "string",
+        description: "Type as it would be written in a signature (e.g., '&str', 'Point')",
+      },
+      position: {
+        type: "string",
+        enum: ["param", "return", "any"],
+        description: "Match parameter types, the return type, or either",
+        default: "any",
+      },
+      contains: {
+        type: "boolean",
+        description: "Also match types nested in references and generic arguments",
+        default: false,
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of functions to return (1-100)",
+        minimum: 1,
+        maximum: 100,
+        default: 20,
+      },
+      output_format: outputFormatProperty,
+      fields: fieldsProperty(SEARCH_BY_TYPE_FIELDS),
+    },
+    required: ["repository", "type"],
+  },
+};
+
+/**
+ * Creates the search_by_type tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes type searches
+ */
+export function createSearchByTypeHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateSearchByTypeArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          type: validatedArgs.type,
+          position: validatedArgs.position,
+          contains: validatedArgs.contains,
+        },
+        "Executing search_by_type tool"
+      );
+
+      const response = await analysisService.searchByType({
+        repository: validatedArgs.repository,
+        type: validatedArgs.type,
+        position: validatedArgs.position,
+        contains: validatedArgs.contains,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatSearchByTypeResponse(
+        response,
+        validatedArgs.output_format,
+        validatedArgs.fields
+      );
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          totalMatches: response.metadata.total_matches,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "search_by_type completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "search_by_type failed");
+      toolDebugLog("search_by_type", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats TypeSearchResult as MCP TextContent
+ *
+ * @param response - Type search result from GraphAnalysisService
+ * @param outputFormat - JSON (default) or Markdown rendering
+ * @param fields - Match fields to keep in JSON output (default: all)
+ * @returns MCP text content with formatted JSON or Markdown
+ */
+function formatSearchByTypeResponse(
+  response: TypeSearchResult,
+  outputFormat: OutputFormat = "json",
+  fields?: string[]
+): TextContent {
+  if (outputFormat === "markdown") {
+    return formatSearchByTypeMarkdown(response);
+  }
+
+  const output = {
+    type: response.type,
+    repository: response.repository,
+    position: response.position,
+    matches: response.matches.map((match) =>
+      projectFields(
+        {
+          name: match.name,
+          type: match.entity_type,
+          ...(match.kind !== undefined && { kind: match.kind }),
+          file_path: match.file_path,
+          start_line: match.start_line,
+          end_line: match.end_line,
+          params: match.params,
+          ...(match.return_type !== undefined && { return_type: match.return_type }),
+          ...(match.is_async !== undefined && { is_async: match.is_async }),
+          matched_params: match.matched_params,
+          matched_return: match.matched_return,
+          match_type: match.match_type,
+        },
+        fields
+      )
+    ),
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
+
+/**
+ * Renders TypeSearchResult as Markdown, one heading per function
+ */
+function formatSearchByTypeMarkdown(response: TypeSearchResult): TextContent {
+  const sections = [
+    `# Functions using \`${response.type}\` (${response.position}) in ${response.repository}`,
+    `${response.matches.length} of ${response.metadata.total_matches} matches ` +
+      `(${response.metadata.query_time_ms} ms)`,
+  ];
+
+  for (const match of response.matches) {
+    const matchedIn = [
+      ...match.matched_params.map((name) => `\`${name}\``),
+      ...(match.matched_return ? ["return type"] : []),
+    ];
+    sections.push(
+      [
+        `## \`${match.name}\` (${match.kind ?? match.entity_type})`,
+        formatLocation(match.file_path, match.start_line, match.end_line),
+        formatDetails({
+          match: match.match_type,
+          "matched in": matchedIn.join(", "),
+          params: formatParams(match.params),
+          returns: match.return_type !== undefined ? `\`${match.return_type}\`` : undefined,
+          async: match.is_async === true ? true : undefined,
+        }),
+      ].join("\n\n")
+    );
+  }
+
+  return markdownContent(sections);
+}
@@ -157,7 +157,7 @@ export function createSearchDocumentsHandler(
       });
 
       // Step 3: Format response for MCP
-      const content = formatDocumentSearchResponse(response);
+      const content = formatDocumentSearchResponse(response, validatedArgs.threshold);
 
       const duration = performance.now() - startTime;
       log.info(
@@ -201,9 +201,13 @@ export function createSearchDocumentsHandler(
  * readability in Claude Code's interface.
  *
  * @param response - Document search response from DocumentSearchService
+ * @param threshold - Minimum similarity score the search was executed with
  * @returns MCP text content with formatted JSON
  */
-function formatDocumentSearchResponse(response: DocumentSearchResponse): TextContent {
+function formatDocumentSearchResponse(
+  response: DocumentSearchResponse,
+  threshold: number
+): TextContent {
   const output = {
     results: response.results.map((result) => ({
       content: result.content,
@@ -227,6 +231,7 @@ function formatDocumentSearchResponse(response: DocumentSearchResponse): TextCon
       queryTimeMs: response.metadata.queryTimeMs,
       searchedFolders: response.metadata.searchedFolders,
       searchedDocumentTypes: response.metadata.searchedDocumentTypes,
+      thresholdApplied: threshold,
       ...(response.metadata.warnings?.length && { warnings: response.metadata.warnings }),
     },
   };
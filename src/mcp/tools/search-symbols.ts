@@ -0,0 +1,303 @@
+/**
+ * search_symbols MCP Tool Implementation
+ *
+ * This module implements the search_symbols tool for the MCP server. It finds
+ * functions, classes and variables by name, ranking exact and prefix matches
+ * above substring matches. With token_match enabled, identifiers are also
+ * split into snake_case/camelCase subtokens so a query for "scale" finds
+ * "scale_factor" and "ScaleFactor".
+ *
+ * @module mcp/tools/search-symbols
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  SymbolSearchResult,
+} from "../../services/graph-analysis-types.js";
+import { SYMBOL_KINDS } from "../../graph/parsing/symbol-kinds.js";
+import { SEARCH_SYMBOLS_FIELDS, validateSearchSymbolsArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler, OutputFormat } from "../types.js";
+import {
+  codeFence,
+  fenceLanguage,
+  formatDetails,
+  formatLocation,
+  formatParams,
+  markdownContent,
+  outputFormatProperty,
+} from "./utils/markdown.js";
+import { fieldsProperty, projectFields } from "./utils/field-projection.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:search-symbols");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for search_symbols
+ */
+export const searchSymbolsToolDefinition: Tool = {
+  name: "search_symbols",
+  description:
+    "Find functions, classes, and variables by name. Exact and prefix matches rank above " +
+    "substring matches. Set token_match=true to also match identifier subtokens split on " +
+    "underscores and camelCase boundaries (e.g., 'scale' finds 'scale_factor' and " +
+    "'ScaleFactor'); full-name matches still rank higher. Prefix the query with 'where:' to " +
+    "search generic where clauses instead of names (e.g., 'where:Hash' finds items bounded " +
+    "by Hash). Set include_context=true to attach each symbol's doc summary and its " +
+    "module's doc summary (Rust //! comments) for judging relevance. Symbols in Rust " +
+    "build scripts and proc-macro crates are flagged build_or_macro; filter on it to " +
+    "keep build-time helpers out of application code searches, or to find only them. " +
+    "Symbols gated by #[cfg(...)] report their condition as cfg; set platform to a target " +
+    "triple or OS (e.g., 'x86_64-pc-windows-msvc', 'linux', 'unix') to keep only the " +
+    "variants compiled for it, such as the unix version of a per-platform function. " +
+    "Matches scoring below min_score are dropped before the limit; it defaults to 0.25 " +
+    "with token_match (names sharing under half of the query's subtokens) and 0 otherwise, " +
+    "and the value used is echoed as metadata.min_score_applied.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      query: {
+        type: "string",
+        description:
+          "Symbol name or name fragment to search for, or 'where:<text>' to match where clauses",
+      },
+      token_match: {
+        type: "boolean",
+        description: "Also match snake_case/camelCase subtokens of symbol names",
+        default: false,
+      },
+      kind: {
+        type: "string",
+        enum: [...SYMBOL_KINDS],
+        description: "Only return symbols of this kind (e.g., 'method', 'class')",
+      },
+      build_or_macro: {
+        type: "boolean",
+        description:
+          "true: only symbols in build scripts and proc-macro crates; " +
+          "false: leave them out. Both when omitted.",
+      },
+      platform: {
+        type: "string",
+        description:
+          "Only return symbols compiled for this target: a target triple " +
+          "(e.g., 'aarch64-apple-darwin'), an OS name (e.g., 'windows') or a family " +
+          "('unix', 'windows', 'wasm'). Feature-gated symbols are kept.",
+      },
+      include_context: {
+        type: "boolean",
+        description: "Attach the first paragraph of each symbol's and its module's documentation",
+        default: false,
+      },
+      min_score: {
+        type: "number",
+        description:
+          "Minimum match score (0.0-1.0). Defaults to 0.25 with token_match, otherwise 0",
+        minimum: 0,
+        maximum: 1,
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of symbols to return (1-100)",
+        minimum: 1,
+        maximum: 100,
+        default: 20,
+      },
+      output_format: outputFormatProperty,
+      fields: fieldsProperty(SEARCH_SYMBOLS_FIELDS),
+    },
+    required: ["repository", "query"],
+  },
+};
+
+/**
+ * Creates the search_symbols tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes symbol name searches
+ */
+export function createSearchSymbolsHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateSearchSymbolsArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          query: validatedArgs.query,
+          token_match: validatedArgs.token_match,
+          kind: validatedArgs.kind,
+          build_or_macro: validatedArgs.build_or_macro,
+          platform: validatedArgs.platform,
+          include_context: validatedArgs.include_context,
+          min_score: validatedArgs.min_score,
+        },
+        "Executing search_symbols tool"
+      );
+
+      const response = await analysisService.searchSymbols({
+        repository: validatedArgs.repository,
+        query: validatedArgs.query,
+        token_match: validatedArgs.token_match,
+        kind: validatedArgs.kind,
+        ...(validatedArgs.build_or_macro !== undefined && {
+          build_or_macro: validatedArgs.build_or_macro,
+        }),
+        ...(validatedArgs.platform !== undefined && { platform: validatedArgs.platform }),
+        include_context: validatedArgs.include_context,
+        ...(validatedArgs.min_score !== undefined && { min_score: validatedArgs.min_score }),
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatSearchSymbolsResponse(
+        response,
+        validatedArgs.output_format,
+        validatedArgs.fields
+      );
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          totalMatches: response.metadata.total_matches,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "search_symbols completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "search_symbols failed");
+      toolDebugLog("search_symbols", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats SymbolSearchResult as MCP TextContent
+ *
+ * @param response - Symbol search result from GraphAnalysisService
+ * @param outputFormat - JSON (default) or Markdown rendering
+ * @param fields - Match fields to keep in JSON output (default: all)
+ * @returns MCP text content with formatted JSON or Markdown
+ */
+function formatSearchSymbolsResponse(
+  response: SymbolSearchResult,
+  outputFormat: OutputFormat = "json",
+  fields?: string[]
+): TextContent {
+  if (outputFormat === "markdown") {
+    return formatSearchSymbolsMarkdown(response);
+  }
+
+  const output = {
+    query: response.query,
+    repository: response.repository,
+    matches: response.matches.map((match) =>
+      projectFields(
+        {
+          name: match.name,
+          type: match.entity_type,
+          ...(match.kind !== undefined && { kind: match.kind }),
+          file_path: match.file_path,
+          start_line: match.start_line,
+          end_line: match.end_line,
+          match_type: match.match_type,
+          score: match.score,
+          ...(match.where_clause !== undefined && { where_clause: match.where_clause }),
+          ...(match.params !== undefined && { params: match.params }),
+          ...(match.return_type !== undefined && { return_type: match.return_type }),
+          ...(match.is_async !== undefined && { is_async: match.is_async }),
+          ...(match.build_or_macro && { build_or_macro: true }),
+          ...(match.cfg !== undefined && { cfg: match.cfg }),
+          ...(match.doc_summary !== undefined && { doc_summary: match.doc_summary }),
+          ...(match.module_doc_summary !== undefined && {
+            module_doc_summary: match.module_doc_summary,
+          }),
+        },
+        fields
+      )
+    ),
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
+
+/**
+ * Renders SymbolSearchResult as Markdown, one heading per symbol
+ */
+function formatSearchSymbolsMarkdown(response: SymbolSearchResult): TextContent {
+  const sections = [
+    `# Symbols matching \`${response.query}\` in ${response.repository}`,
+    `${response.matches.length} of ${response.metadata.total_matches} matches ` +
+      `scoring at least ${response.metadata.min_score_applied} ` +
+      `(${response.metadata.query_time_ms} ms)`,
+  ];
+
+  for (const match of response.matches) {
+    const lines = [
+      `## \`${match.name}\` (${match.kind ?? match.entity_type})`,
+      formatLocation(match.file_path, match.start_line, match.end_line),
+      formatDetails({
+        match: match.match_type,
+        score: match.score,
+        params: match.params !== undefined ? formatParams(match.params) : undefined,
+        returns: match.return_type !== undefined ? `\`${match.return_type}\`` : undefined,
+        async: match.is_async === true ? true : undefined,
+        build_or_macro: match.build_or_macro,
+        cfg: match.cfg !== undefined ? `\`${match.cfg}\`` : undefined,
+      }),
+    ];
+    if (match.where_clause !== undefined) {
+      lines.push(codeFence(match.where_clause, fenceLanguage(match.file_path)));
+    }
+    if (match.doc_summary !== undefined) {
+      lines.push(match.doc_summary);
+    }
+    if (match.module_doc_summary !== undefined) {
+      lines.push(`Module: ${match.module_doc_summary}`);
+    }
+    sections.push(lines.join("\n\n"));
+  }
+
+  return markdownContent(sections);
+}
@@ -17,7 +17,15 @@ import { validateSemanticSearchArgs } from "../validation.js";
 import { mapToMCPError } from "../errors.js";
 import { getComponentLogger } from "../../logging/index.js";
 import { toolDebugLog } from "../debug-logger.js";
-import type { ToolHandler } from "../types.js";
+import type { ToolHandler, OutputFormat } from "../types.js";
+import {
+  codeFence,
+  fenceLanguage,
+  formatDetails,
+  formatLocation,
+  markdownContent,
+  outputFormatProperty,
+} from "./utils/markdown.js";
 
 /**
  * Lazy-initialized logger to avoid initialization at module load time
@@ -45,7 +53,8 @@ export const semanticSearchToolDefinition: Tool = {
     "and repository information. Use this to find code examples, understand implementations, " +
     "or locate specific functionality across your indexed codebases. " +
     "Set include_documents=true to also search indexed documents (PDFs, DOCX, Markdown, TXT) " +
-    "and get merged results ranked by similarity score.",
+    "and get merged results ranked by similarity score. " +
+    "Set output_format='markdown' for a human-readable rendering.",
   inputSchema: {
     type: "object",
     properties: {
@@ -70,9 +79,11 @@ export const semanticSearchToolDefinition: Tool = {
       threshold: {
         type: "number",
         description:
-          "Minimum similarity score threshold (0.0-1.0). Higher values (e.g., 0.8) return " +
+          "Minimum similarity score threshold (0.0-1.0). Results scoring below it are " +
+          "dropped before the limit is applied. Higher values (e.g., 0.8) return " +
           "only highly relevant results. Lower values (e.g., 0.5) cast a wider net. " +
-          "Default 0.7 provides a good balance.",
+          "Default 0.7 provides a good balance. The threshold actually applied is " +
+          "echoed back as metadata.threshold_applied.",
         minimum: 0.0,
         maximum: 1.0,
         default: 0.7,
@@ -111,6 +122,7 @@ export const semanticSearchToolDefinition: Tool = {
           "includes a source_type field ('code' or 'document'). Default: false.",
         default: false,
       },
+      output_format: outputFormatProperty,
     },
     required: ["query"],
   },
@@ -154,6 +166,7 @@ export function createSemanticSearchHandler(
           repository: validatedArgs.repository,
           language: validatedArgs.language,
           include_documents: validatedArgs.include_documents,
+          output_format: validatedArgs.output_format,
         },
         "Executing semantic_search tool"
       );
@@ -187,7 +200,11 @@ export function createSemanticSearchHandler(
       });
 
       // Step 3: Format response for MCP (legacy format)
-      const content = formatSearchResponse(response);
+      const content = formatSearchResponse(
+        response,
+        validatedArgs.threshold,
+        validatedArgs.output_format
+      );
 
       const duration = performance.now() - startTime;
       log.info(
@@ -245,6 +262,7 @@ async function executeIncludeDocumentsSearch(
     threshold: number;
     repository?: string;
     language?: string;
+    output_format: OutputFormat;
   },
   log: ReturnType<typeof getComponentLogger>
 ): Promise<TextContent> {
@@ -261,7 +279,13 @@ async function executeIncludeDocumentsSearch(
   if (!documentSearchService) {
     log.warn("include_documents=true but DocumentSearchService not available, returning code only");
     const codeResponse = await codeSearchPromise;
-    return formatMergedResponse(codeResponse, null, validatedArgs.limit);
+    return formatMergedResponse(
+      codeResponse,
+      null,
+      validatedArgs.limit,
+      validatedArgs.threshold,
+      validatedArgs.output_format
+    );
   }
 
   // Build document search promise
@@ -290,7 +314,13 @@ async function executeIncludeDocumentsSearch(
     log.error({ error: docError }, "Document search failed during include_documents search");
   }
 
-  return formatMergedResponse(codeResponse, docResponse, validatedArgs.limit);
+  return formatMergedResponse(
+    codeResponse,
+    docResponse,
+    validatedArgs.limit,
+    validatedArgs.threshold,
+    validatedArgs.output_format
+  );
 }
 
 /**
@@ -306,18 +336,27 @@ interface MergedResult {
 /**
  * Formats merged code + document results as MCP TextContent
  *
- * Results from both sources are tagged with source_type, merged, sorted by
- * similarity_score descending, and truncated to the requested limit.
+ * Results from both sources are tagged with source_type, merged, filtered by
+ * the similarity threshold, sorted by similarity_score descending, and
+ * truncated to the requested limit.
+ *
+ * Both services already apply the threshold during vector search; it is
+ * re-applied here so the merged list honours the floor even if a service
+ * falls back to its own default.
  *
  * @param codeResponse - Code search response
  * @param docResponse - Document search response (null if unavailable)
  * @param limit - Maximum total results to return
- * @returns MCP text content with formatted JSON
+ * @param threshold - Minimum similarity score applied to the merged results
+ * @param outputFormat - JSON (default) or Markdown rendering
+ * @returns MCP text content with formatted JSON or Markdown
  */
 function formatMergedResponse(
   codeResponse: SearchResponse,
   docResponse: DocumentSearchResponse | null,
-  limit: number
+  limit: number,
+  threshold: number,
+  outputFormat: OutputFormat = "json"
 ): TextContent {
   const warnings: string[] = [];
 
@@ -359,8 +398,10 @@ function formatMergedResponse(
     warnings.push("Document search service is not available. Only code results are included.");
   }
 
-  // Merge, sort by similarity_score descending, and truncate to limit
+  // Merge, drop results below the floor, sort by similarity_score descending,
+  // and truncate to limit
   const merged = [...codeResults, ...docResults]
+    .filter((r) => r.similarity_score >= threshold)
     .sort((a, b) => b.similarity_score - a.similarity_score)
     .slice(0, limit);
 
@@ -386,6 +427,7 @@ function formatMergedResponse(
       embedding_time_ms: codeResponse.metadata.embedding_time_ms,
       search_time_ms: codeResponse.metadata.search_time_ms,
       repositories_searched: codeResponse.metadata.repositories_searched,
+      threshold_applied: threshold,
       ...(docResponse && {
         document_folders_searched: docResponse.metadata.searchedFolders,
       }),
@@ -393,6 +435,10 @@ function formatMergedResponse(
     },
   };
 
+  if (outputFormat === "markdown") {
+    return formatMergedMarkdown(merged, threshold, allWarnings);
+  }
+
   return {
     type: "text",
     text: JSON.stringify(output, null, 2),
@@ -407,9 +453,19 @@ function formatMergedResponse(
  * readability in Claude Code's interface.
  *
  * @param response - Search response from SearchService
- * @returns MCP text content with formatted JSON
+ * @param threshold - Minimum similarity score the search was executed with
+ * @param outputFormat - JSON (default) or Markdown rendering
+ * @returns MCP text content with formatted JSON or Markdown
  */
-function formatSearchResponse(response: SearchResponse): TextContent {
+function formatSearchResponse(
+  response: SearchResponse,
+  threshold: number,
+  outputFormat: OutputFormat = "json"
+): TextContent {
+  if (outputFormat === "markdown") {
+    return formatSearchMarkdown(response, threshold);
+  }
+
   const output = {
     results: response.results.map((result) => ({
       content: result.content_snippet,
@@ -440,6 +496,7 @@ function formatSearchResponse(response: SearchResponse): TextContent {
       embedding_time_ms: response.metadata.embedding_time_ms,
       search_time_ms: response.metadata.search_time_ms,
       repositories_searched: response.metadata.repositories_searched,
+      threshold_applied: threshold,
       ...(response.metadata.warnings?.length && { warnings: response.metadata.warnings }),
     },
   };
@@ -449,3 +506,80 @@ function formatSearchResponse(response: SearchResponse): TextContent {
     text: JSON.stringify(output, null, 2),
   };
 }
+
+/**
+ * Renders a code-only SearchResponse as Markdown, one heading per result
+ * with the chunk in a code fence tagged by source language.
+ */
+function formatSearchMarkdown(response: SearchResponse, threshold: number): TextContent {
+  const sections = [
+    "# Search results",
+    formatDetails({
+      results: response.results.length,
+      "repositories searched": response.metadata.repositories_searched.join(", "),
+      threshold,
+      "query time (ms)": response.metadata.query_time_ms,
+    }),
+    ...(response.metadata.warnings ?? []).map((warning) => `> **Warning:** ${warning.message}`),
+  ];
+
+  response.results.forEach((result, index) => {
+    sections.push(
+      [
+        `## ${index + 1}. ${formatLocation(result.file_path)} (${result.repository})`,
+        formatDetails({
+          score: result.similarity_score.toFixed(3),
+          chunk: result.chunk_index,
+          section: result.metadata.section_heading,
+          page: result.metadata.page_number,
+        }),
+        codeFence(result.content_snippet, fenceLanguage(result.file_path)),
+      ].join("\n\n")
+    );
+  });
+
+  return markdownContent(sections);
+}
+
+/**
+ * Renders merged code + document results as Markdown
+ */
+function formatMergedMarkdown(
+  merged: MergedResult[],
+  threshold: number,
+  warnings: string[]
+): TextContent {
+  const sections = [
+    "# Search results",
+    formatDetails({
+      results: merged.length,
+      code: merged.filter((r) => r.source_type === "code").length,
+      documents: merged.filter((r) => r.source_type === "document").length,
+      threshold,
+    }),
+    ...warnings.map((warning) => `> **Warning:** ${warning}`),
+  ];
+
+  merged.forEach((result, index) => {
+    const path = String(result.metadata["file_path"] ?? result.metadata["document_path"] ?? "");
+    const title = result.metadata["document_title"];
+    const heading =
+      result.source_type === "code"
+        ? `${formatLocation(path)} (${String(result.metadata["repository"])})`
+        : `${typeof title === "string" ? `${title} — ` : ""}${formatLocation(path)}`;
+    sections.push(
+      [
+        `## ${index + 1}. ${heading}`,
+        formatDetails({
+          source: result.source_type,
+          score: result.similarity_score.toFixed(3),
+          section: result.metadata["section_heading"] as string | undefined,
+          page: result.metadata["page_number"] as number | undefined,
+        }),
+        codeFence(result.content, result.source_type === "code" ? fenceLanguage(path) : ""),
+      ].join("\n\n")
+    );
+  });
+
+  return markdownContent(sections);
+}
@@ -0,0 +1,135 @@
+/**
+ * snapshot_history MCP Tool Implementation
+ *
+ * This module implements the snapshot_history tool for the MCP server. It
+ * returns the most recent entries of a repository's snapshot history, each
+ * with its symbol count and changes since the previous entry, plus the
+ * trend across them, so API growth and churn can be charted over time.
+ *
+ * @module mcp/tools/snapshot-history
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type { SymbolDiffService, SnapshotHistoryResult } from "../../services/symbol-diff-types.js";
+import { validateSnapshotHistoryArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:snapshot-history");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for snapshot_history
+ */
+export const snapshotHistoryToolDefinition: Tool = {
+  name: "snapshot_history",
+  description:
+    "Show the snapshot history recorded with record_snapshot, oldest entry first: each " +
+    "entry's timestamp, commit, symbol count, change in symbol count and the symbols " +
+    "added, removed or changed in signature since the previous entry, plus the trend " +
+    "across the returned entries. Use to track how a repository's API grows or churns.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name whose history to show",
+      },
+      limit: {
+        type: "number",
+        description: "Number of most recent entries to return (1-1000)",
+        minimum: 1,
+        maximum: 1000,
+        default: 50,
+      },
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Creates the snapshot_history tool handler
+ *
+ * @param diffService - Injected SymbolDiffService instance
+ * @returns Tool handler function that reads snapshot histories
+ */
+export function createSnapshotHistoryHandler(diffService: SymbolDiffService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateSnapshotHistoryArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          limit: validatedArgs.limit,
+        },
+        "Executing snapshot_history tool"
+      );
+
+      const response = await diffService.getSnapshotHistory({
+        repository: validatedArgs.repository,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatSnapshotHistoryResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          entryCount: response.entries.length,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "snapshot_history completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "snapshot_history failed");
+      toolDebugLog("snapshot_history", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats SnapshotHistoryResult as MCP TextContent
+ *
+ * @param response - Snapshot history from SymbolDiffService
+ * @returns MCP text content with formatted JSON
+ */
+function formatSnapshotHistoryResponse(response: SnapshotHistoryResult): TextContent {
+  return {
+    type: "text",
+    text: JSON.stringify(response, null, 2),
+  };
+}
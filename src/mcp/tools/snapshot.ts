@@ -0,0 +1,132 @@
+/**
+ * snapshot MCP Tool Implementation
+ *
+ * This module implements the snapshot tool for the MCP server. It records a
+ * named, lightweight fingerprint of a repository's indexed symbols (qualified
+ * path → signature hash) that the what_changed tool later compares the index
+ * against, to track an API over time without keeping a second index.
+ *
+ * @module mcp/tools/snapshot
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type { SymbolDiffService, SymbolSnapshotResult } from "../../services/symbol-diff-types.js";
+import { validateSnapshotArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:snapshot");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for snapshot
+ */
+export const snapshotToolDefinition: Tool = {
+  name: "snapshot",
+  description:
+    "Record a named snapshot of an indexed repository's symbols: a fingerprint of each " +
+    "function, class and variable signature, replacing any earlier snapshot with the same " +
+    "name. Use with what_changed to see which symbols were added, removed or changed " +
+    "signature since, e.g. name='v1.2'.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to snapshot",
+      },
+      name: {
+        type: "string",
+        description: "Snapshot name (letters, digits, '.', '_' and '-')",
+      },
+    },
+    required: ["repository", "name"],
+  },
+};
+
+/**
+ * Creates the snapshot tool handler
+ *
+ * @param diffService - Injected SymbolDiffService instance
+ * @returns Tool handler function that records symbol snapshots
+ */
+export function createSnapshotHandler(diffService: SymbolDiffService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateSnapshotArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          name: validatedArgs.name,
+        },
+        "Executing snapshot tool"
+      );
+
+      const response = await diffService.createSnapshot({
+        repository: validatedArgs.repository,
+        name: validatedArgs.name,
+      });
+
+      const content = formatSnapshotResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          symbolCount: response.symbol_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "snapshot completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "snapshot failed");
+      toolDebugLog("snapshot", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats SymbolSnapshotResult as MCP TextContent
+ *
+ * @param response - Snapshot summary from SymbolDiffService
+ * @returns MCP text content with formatted JSON
+ */
+function formatSnapshotResponse(response: SymbolSnapshotResult): TextContent {
+  return {
+    type: "text",
+    text: JSON.stringify(response, null, 2),
+  };
+}
@@ -0,0 +1,145 @@
+/**
+ * suggest_import MCP Tool Implementation
+ *
+ * This module implements the suggest_import tool for the MCP server. Given a
+ * Rust symbol and the file that will use it, it returns the `use`
+ * declarations to add, choosing the shortest path through the defining
+ * module or a `pub use` re-export, or explains why the symbol is private to
+ * modules the file is not in.
+ *
+ * @module mcp/tools/suggest-import
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  ImportSuggestionResult,
+} from "../../services/graph-analysis-types.js";
+import { validateSuggestImportArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:suggest-import");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for suggest_import
+ */
+export const suggestImportToolDefinition: Tool = {
+  name: "suggest_import",
+  description:
+    "Get the `use` declarations a Rust file needs to name a symbol, e.g. " +
+    "`use crate::geometry::Point;`. Paths go through the defining module or a `pub use` " +
+    "re-export, whichever is shorter, and respect `pub`, `pub(crate)`, `pub(super)` and " +
+    "`pub(in path)` visibility. Methods import their type (and trait, for trait methods). " +
+    "Each definition is reported as in_scope, importable, or inaccessible with the reason.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      symbol: {
+        type: "string",
+        description:
+          "Symbol name (e.g., 'Point'), qualified name (e.g., 'crate::geometry::Point'), " +
+          "or graph node ID",
+      },
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      from_path: {
+        type: "string",
+        description: "Rust file that will use the symbol (e.g., 'src/render/canvas.rs')",
+      },
+    },
+    required: ["symbol", "repository", "from_path"],
+  },
+};
+
+/**
+ * Creates the suggest_import tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes import suggestion queries
+ */
+export function createSuggestImportHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateSuggestImportArgs(args);
+
+      log.info(
+        {
+          symbol: validatedArgs.symbol,
+          repository: validatedArgs.repository,
+          from_path: validatedArgs.from_path,
+        },
+        "Executing suggest_import tool"
+      );
+
+      const response = await analysisService.suggestImport({
+        symbol: validatedArgs.symbol,
+        repository: validatedArgs.repository,
+        from_path: validatedArgs.from_path,
+      });
+
+      const content = formatSuggestImportResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          totalCount: response.metadata.total_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "suggest_import completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "suggest_import failed");
+      toolDebugLog("suggest_import", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats ImportSuggestionResult as MCP TextContent
+ *
+ * @param response - Import suggestion result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatSuggestImportResponse(response: ImportSuggestionResult): TextContent {
+  return {
+    type: "text",
+    text: JSON.stringify(response, null, 2),
+  };
+}
@@ -0,0 +1,148 @@
+/**
+ * symbols_at_lines MCP Tool Implementation
+ *
+ * This module implements the symbols_at_lines tool for the MCP server. It
+ * maps a line range of a file, such as a stack trace frame or a diff hunk,
+ * to the symbols whose stored spans overlap it, innermost first.
+ *
+ * @module mcp/tools/symbols-at-lines
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  SymbolsAtLinesResult,
+} from "../../services/graph-analysis-types.js";
+import { validateSymbolsAtLinesArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:symbols-at-lines");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for symbols_at_lines
+ */
+export const symbolsAtLinesToolDefinition: Tool = {
+  name: "symbols_at_lines",
+  description:
+    "List the symbols whose span overlaps a line range of a file, innermost first: a " +
+    "line inside a method returns the method, then its impl block. Each symbol says " +
+    "whether it covers the whole range. Use to map a stack trace line or a diff hunk " +
+    "back to the responsible function.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      path: {
+        type: "string",
+        description: "File path relative to the repository root (e.g., 'src/geometry.rs')",
+      },
+      start: {
+        type: "number",
+        description: "First line of the range (1-based)",
+        minimum: 1,
+      },
+      end: {
+        type: "number",
+        description: "Last line of the range (1-based, inclusive). Defaults to start.",
+        minimum: 1,
+      },
+    },
+    required: ["repository", "path", "start"],
+  },
+};
+
+/**
+ * Creates the symbols_at_lines tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes symbols at lines queries
+ */
+export function createSymbolsAtLinesHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateSymbolsAtLinesArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          path: validatedArgs.path,
+          start: validatedArgs.start,
+          end: validatedArgs.end,
+        },
+        "Executing symbols_at_lines tool"
+      );
+
+      const response = await analysisService.getSymbolsAtLines({
+        repository: validatedArgs.repository,
+        path: validatedArgs.path,
+        start_line: validatedArgs.start,
+        ...(validatedArgs.end !== undefined && { end_line: validatedArgs.end }),
+      });
+
+      const content = formatSymbolsAtLinesResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          symbolCount: response.metadata.symbol_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "symbols_at_lines completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "symbols_at_lines failed");
+      toolDebugLog("symbols_at_lines", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats SymbolsAtLinesResult as MCP TextContent
+ *
+ * @param response - Symbols at lines result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatSymbolsAtLinesResponse(response: SymbolsAtLinesResult): TextContent {
+  return {
+    type: "text",
+    text: JSON.stringify(response, null, 2),
+  };
+}
@@ -0,0 +1,171 @@
+/**
+ * top_level_items MCP Tool Implementation
+ *
+ * This module implements the top_level_items tool for the MCP server. It
+ * returns only the top-level declarations (functions, classes, structs,
+ * traits, enums, constants) of a file or directory, omitting nested methods,
+ * fields and local definitions, for a coarse overview of a module. An option
+ * expands one level to include the direct children of each item.
+ *
+ * @module mcp/tools/top-level-items
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  SymbolLocation,
+  TopLevelSymbolsResult,
+} from "../../services/graph-analysis-types.js";
+import { validateTopLevelItemsArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:top-level-items");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for top_level_items
+ */
+export const topLevelItemsToolDefinition: Tool = {
+  name: "top_level_items",
+  description:
+    "List only the top-level declarations (functions, classes/structs, interfaces/traits, " +
+    "enums, constants) of a file or directory, omitting nested methods, fields, and local " +
+    "definitions. Use for a quick overview of a module. Set expand=true to also include " +
+    "the direct children of each item (e.g., a class's methods).",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      path: {
+        type: "string",
+        description:
+          "File path (e.g., 'src/auth/middleware.ts') or directory path (e.g., 'src/auth') " +
+          "relative to the repository root",
+      },
+      expand: {
+        type: "boolean",
+        description: "Include the direct children of each top-level item",
+        default: false,
+      },
+    },
+    required: ["repository", "path"],
+  },
+};
+
+/**
+ * Creates the top_level_items tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes top-level symbol queries
+ */
+export function createTopLevelItemsHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateTopLevelItemsArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          path: validatedArgs.path,
+          expand: validatedArgs.expand,
+        },
+        "Executing top_level_items tool"
+      );
+
+      const response = await analysisService.getTopLevelSymbols({
+        repository: validatedArgs.repository,
+        path: validatedArgs.path,
+        expand: validatedArgs.expand,
+      });
+
+      const content = formatTopLevelItemsResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          symbolCount: response.metadata.symbol_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "top_level_items completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "top_level_items failed");
+      toolDebugLog("top_level_items", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats a symbol for output
+ */
+function formatSymbol(symbol: SymbolLocation): Record<string, unknown> {
+  return {
+    name: symbol.name,
+    type: symbol.entity_type,
+    start_line: symbol.start_line,
+    end_line: symbol.end_line,
+  };
+}
+
+/**
+ * Formats TopLevelSymbolsResult as MCP TextContent
+ *
+ * @param response - Top-level symbols result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatTopLevelItemsResponse(response: TopLevelSymbolsResult): TextContent {
+  const output = {
+    repository: response.repository,
+    path: response.path,
+    files: response.files.map((file) => ({
+      file_path: file.file_path,
+      items: file.symbols.map((symbol) => ({
+        ...formatSymbol(symbol),
+        ...(symbol.children && { children: symbol.children.map(formatSymbol) }),
+      })),
+    })),
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
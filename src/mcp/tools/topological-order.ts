@@ -0,0 +1,164 @@
+/**
+ * topological_order MCP Tool Implementation
+ *
+ * This module implements the topological_order tool for the MCP server. It
+ * lists the functions of a repository in dependency order, so that every
+ * function appears after the functions it calls. Mutually recursive
+ * functions are reported together as one cyclic group.
+ *
+ * @module mcp/tools/topological-order
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  TopologicalOrderResult,
+} from "../../services/graph-analysis-types.js";
+import { validateTopologicalOrderArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:topological-order");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for topological_order
+ */
+export const topologicalOrderToolDefinition: Tool = {
+  name: "topological_order",
+  description:
+    "List functions in dependency order over the call graph: every function comes after " +
+    "the functions it calls. Functions that call each other in a cycle are grouped " +
+    "together and flagged as cyclic. Each group carries a level, the length of the longest " +
+    "call chain below it (0 for leaves). Ties are broken by file and line, so the order " +
+    "is stable. Use it to plan bottom-up reviews, refactors or ports.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      path: {
+        type: "string",
+        description:
+          "File path, or directory path to cover every file beneath it (e.g., 'src/parser'). " +
+          "Calls leaving the path are ignored.",
+      },
+      limit: {
+        type: "number",
+        description: "Maximum number of functions to return (1-5000); groups are never split",
+        minimum: 1,
+        maximum: 5000,
+        default: 500,
+      },
+    },
+    required: ["repository"],
+  },
+};
+
+/**
+ * Creates the topological_order tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes topological order queries
+ */
+export function createTopologicalOrderHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateTopologicalOrderArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          path: validatedArgs.path,
+          limit: validatedArgs.limit,
+        },
+        "Executing topological_order tool"
+      );
+
+      const response = await analysisService.getTopologicalOrder({
+        repository: validatedArgs.repository,
+        path: validatedArgs.path,
+        limit: validatedArgs.limit,
+      });
+
+      const content = formatTopologicalOrderResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          symbolCount: response.metadata.symbol_count,
+          cyclicGroupCount: response.metadata.cyclic_group_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "topological_order completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "topological_order failed");
+      toolDebugLog("topological_order", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats TopologicalOrderResult as MCP TextContent
+ *
+ * @param response - Topological order result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatTopologicalOrderResponse(response: TopologicalOrderResult): TextContent {
+  const output = {
+    repository: response.repository,
+    groups: response.groups.map((group) => ({
+      level: group.level,
+      cyclic: group.cyclic,
+      symbols: group.symbols.map((symbol) => ({
+        name: symbol.name,
+        entity_type: symbol.entity_type,
+        file_path: symbol.file_path,
+        start_line: symbol.start_line,
+        end_line: symbol.end_line,
+      })),
+    })),
+    metadata: response.metadata,
+  };
+
+  return {
+    type: "text",
+    text: JSON.stringify(output, null, 2),
+  };
+}
@@ -22,9 +22,10 @@ import {
   dispatchCoordinator,
   type UpdateCoordinatorLike,
 } from "../../services/update-coordinator-dispatch.js";
+import { IndexingCancelledError } from "../../services/ingestion-errors.js";
 import { mapToMCPError } from "../errors.js";
 import { getComponentLogger } from "../../logging/index.js";
-import type { ToolHandler } from "../types.js";
+import type { ToolHandler, ToolCallContext } from "../types.js";
 import type { MCPRateLimiter } from "../rate-limiter.js";
 import type { JobTracker } from "../job-tracker.js";
 import { buildDriftRecoveryHint } from "./utils/drift-recovery-hint.js";
@@ -43,6 +44,7 @@ type TriggerErrorCode =
   | "update_in_progress"
   | "update_failed"
   | "timeout"
+  | "update_cancelled"
   | "service_unavailable"
   | "internal_error";
 
@@ -301,35 +303,40 @@ export interface TriggerUpdateDependencies {
 /**
  * Execute update with timeout
  *
- * Note: The AbortController is used only for timeout detection via Promise.race.
- * The signal is NOT passed to the coordinator because IncrementalUpdateCoordinator
- * does not currently support AbortSignal cancellation. This means:
+ * The coordinator receives a signal that aborts when the timeout fires or,
+ * for synchronous calls, when the client cancels the request. It checks the
+ * signal before each file and embedding batch, then stops with
+ * `IndexingCancelledError`, clears its in-progress flag and keeps the
+ * previous baseline, so the next update re-applies the same changes.
  *
- * 1. On timeout, this function rejects immediately with a timeout error
- * 2. The actual update operation continues running in the background until completion
- * 3. The rate limiter is still marked complete when the background operation finishes
+ * The race against the timeout still rejects as soon as the timeout fires,
+ * because the git and network steps ahead of the pipeline do not check the
+ * signal; the update then stops at its next safe point in the background.
  *
- * This "abandon but don't abort" behavior is acceptable for MVP because:
- * - Updates are idempotent and completing in the background is harmless
- * - The rate limiter prevents rapid re-triggering regardless
- * - Adding true cancellation would require changes to git and embedding operations
- *
- * Future enhancement: Pass signal to coordinator for proper cooperative cancellation.
- * See: https://github.com/sethb75/PersonalKnowledgeMCP/issues/126#issuecomment-X
+ * @param requestSignal - Aborted when the MCP request is cancelled. Omitted for
+ *   async jobs, which outlive the request that started them.
  */
 async function executeWithTimeout(
   coordinator: UpdateCoordinatorLike,
   repositoryName: string,
-  timeoutMs: number
+  timeoutMs: number,
+  requestSignal?: AbortSignal
 ): Promise<CoordinatorResult> {
   const controller = new AbortController();
   const timeoutId = setTimeout(() => controller.abort(), timeoutMs);
 
+  // Stops the update on timeout or when the request is cancelled
+  const update = new AbortController();
+  const cancelUpdate = (): void => update.abort();
+  controller.signal.addEventListener("abort", cancelUpdate, { once: true });
+  if (requestSignal?.aborted) {
+    update.abort();
+  }
+  requestSignal?.addEventListener("abort", cancelUpdate, { once: true });
+
   try {
-    // Race: update vs timeout. Note: update continues in background if timeout wins.
-    // See function documentation for rationale on "abandon but don't abort" behavior.
     const result = await Promise.race([
-      coordinator.updateRepository(repositoryName),
+      coordinator.updateRepository(repositoryName, { signal: update.signal }),
       new Promise<never>((_, reject) => {
         controller.signal.addEventListener("abort", () => {
           reject(new Error("Update timed out after 10 minutes"));
@@ -340,6 +347,7 @@ async function executeWithTimeout(
     return result;
   } finally {
     clearTimeout(timeoutId);
+    requestSignal?.removeEventListener("abort", cancelUpdate);
   }
 }
 
@@ -367,7 +375,7 @@ export function createTriggerUpdateHandler(deps: TriggerUpdateDependencies): Too
   const { repositoryService, updateCoordinator, localFolderCoordinator, rateLimiter, jobTracker } =
     deps;
 
-  return async (args: unknown): Promise<CallToolResult> => {
+  return async (args: unknown, context?: ToolCallContext): Promise<CallToolResult> => {
     const startTime = performance.now();
     const log = getLogger();
 
@@ -556,7 +564,8 @@ export function createTriggerUpdateHandler(deps: TriggerUpdateDependencies): Too
         const result = await executeWithTimeout(
           coordinatorForRepo,
           repositoryName,
-          UPDATE_TIMEOUT_MS
+          UPDATE_TIMEOUT_MS,
+          context?.signal
         );
 
         const duration = performance.now() - startTime;
@@ -591,6 +600,19 @@ export function createTriggerUpdateHandler(deps: TriggerUpdateDependencies): Too
       }
     } catch (error) {
       const duration = performance.now() - startTime;
+      if (error instanceof IndexingCancelledError) {
+        log.info({ repository: repositoryName }, "Update cancelled by client");
+        return {
+          content: [
+            formatErrorResponse(
+              "update_cancelled",
+              `${error.message}. Changes applied so far stay indexed; trigger the update again to finish it.`
+            ),
+          ],
+          isError: true,
+        };
+      }
+
       log.error({ error, duration_ms: Math.round(duration) }, "trigger_incremental_update failed");
 
       // Check for timeout
@@ -0,0 +1,156 @@
+/**
+ * type_hierarchy MCP Tool Implementation
+ *
+ * This module implements the type_hierarchy tool for the MCP server. It
+ * assembles everything a type inherits, implements and derives in one call:
+ * for Rust, the traits implemented through impl blocks anywhere in the
+ * repository plus `#[derive(...)]` traits, and for traits their supertraits;
+ * for Java, Python, TypeScript and similar languages, the superclass and
+ * interfaces. Generic parameter bounds
+ * (including `where` predicates) are included for every language, and
+ * supertypes defined in the repository are followed up to `max_depth`.
+ *
+ * @module mcp/tools/type-hierarchy
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type {
+  GraphAnalysisService,
+  TypeHierarchyResult,
+} from "../../services/graph-analysis-types.js";
+import { validateTypeHierarchyArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler, ToolCallContext } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:type-hierarchy");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for type_hierarchy
+ */
+export const typeHierarchyToolDefinition: Tool = {
+  name: "type_hierarchy",
+  description:
+    "Show everything a type inherits, implements and derives: for Rust, traits implemented " +
+    "via impl blocks (with the bounds of conditional impls), #[derive] traits and, for " +
+    "traits, the supertraits they require; for Java/Python/TypeScript, the superclass and " +
+    "interfaces. Includes bounds on the type's " +
+    "generic parameters and where clause. Supertypes defined in the repository are " +
+    "followed up to 'max_depth' levels and returned as further entries, linked through " +
+    "'definition_ids'.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      type_name: {
+        type: "string",
+        description:
+          "Type: name (e.g., 'User'), file-qualified name (e.g., 'src/models.rs::User'), " +
+          "or graph node ID",
+      },
+      repository: {
+        type: "string",
+        description: "Repository name to scope the query",
+      },
+      max_depth: {
+        type: "number",
+        description: "Maximum number of supertype levels to follow (1-10)",
+        minimum: 1,
+        maximum: 10,
+        default: 3,
+      },
+    },
+    required: ["type_name", "repository"],
+  },
+};
+
+/**
+ * Creates the type_hierarchy tool handler
+ *
+ * @param analysisService - Injected GraphAnalysisService instance
+ * @returns Tool handler function that executes type hierarchy queries
+ */
+export function createTypeHierarchyHandler(analysisService: GraphAnalysisService): ToolHandler {
+  return async (args: unknown, context?: ToolCallContext): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateTypeHierarchyArgs(args);
+
+      log.info(
+        {
+          type_name: validatedArgs.type_name,
+          repository: validatedArgs.repository,
+          max_depth: validatedArgs.max_depth,
+        },
+        "Executing type_hierarchy tool"
+      );
+
+      const response = await analysisService.getTypeHierarchy(
+        {
+          type_name: validatedArgs.type_name,
+          repository: validatedArgs.repository,
+          max_depth: validatedArgs.max_depth,
+        },
+        context?.signal
+      );
+
+      const content = formatTypeHierarchyResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          supertypeCount: response.metadata.supertype_count,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "type_hierarchy completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "type_hierarchy failed");
+      toolDebugLog("type_hierarchy", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats TypeHierarchyResult as MCP TextContent
+ *
+ * @param response - Type hierarchy result from GraphAnalysisService
+ * @returns MCP text content with formatted JSON
+ */
+function formatTypeHierarchyResponse(response: TypeHierarchyResult): TextContent {
+  return {
+    type: "text",
+    text: JSON.stringify(response, null, 2),
+  };
+}
@@ -0,0 +1,49 @@
+/**
+ * Result field projection for list-style tool responses.
+ *
+ * Symbol listings repeat the same record shape many times over, and callers
+ * often only need a name and a location. Tools that accept `fields` keep
+ * just the requested keys of each result record; metadata and other
+ * envelope properties are always returned. Field names are validated
+ * against the tool's record keys before the query runs (see
+ * `fieldsSchema` in `mcp/validation`).
+ *
+ * @module mcp/tools/utils/field-projection
+ */
+
+/**
+ * JSON Schema property for `fields`, shared by tool definitions.
+ *
+ * @param fields - Keys of the tool's result records, in output order
+ */
+export function fieldsProperty(fields: readonly string[]): Record<string, unknown> {
+  return {
+    type: "array",
+    items: { type: "string", enum: [...fields] },
+    minItems: 1,
+    description:
+      "Only include these fields in each result (default: all fields). Shrinks list " +
+      "responses when only names and locations are needed. Applies to JSON output.",
+  };
+}
+
+/**
+ * Keep only the requested keys of a result record, in the record's own order.
+ *
+ * Optional keys absent from the record stay absent. Without a projection the
+ * record is returned unchanged.
+ *
+ * @param record - Formatted result record
+ * @param fields - Keys to keep, or undefined for all
+ */
+export function projectFields<T extends object>(
+  record: T,
+  fields: readonly string[] | undefined
+): Partial<T> {
+  if (fields === undefined) {
+    return record;
+  }
+  return Object.fromEntries(
+    Object.entries(record).filter(([key]) => fields.includes(key))
+  ) as Partial<T>;
+}
@@ -0,0 +1,97 @@
+/**
+ * Markdown rendering helpers for tool responses.
+ *
+ * Tools return pretty-printed JSON by default. When a caller passes
+ * `output_format: "markdown"`, the same result structs are rendered as
+ * Markdown for relaying to a human: a heading per result, `file:line`
+ * locations and code fences tagged with the source language.
+ *
+ * @module mcp/tools/utils/markdown
+ */
+
+import type { TextContent } from "@modelcontextprotocol/sdk/types.js";
+import { getLanguageFromExtension } from "../../../graph/parsing/types.js";
+import { OUTPUT_FORMATS } from "../../validation.js";
+
+/**
+ * JSON Schema property for `output_format`, shared by tool definitions.
+ */
+export const outputFormatProperty = {
+  type: "string",
+  enum: [...OUTPUT_FORMATS],
+  description:
+    "Response format: 'json' (default) for machine consumption, or 'markdown' for results " +
+    "rendered with headings, file:line locations and language-tagged code fences",
+  default: "json",
+};
+
+/**
+ * Language tag for a code fence, derived from the file extension.
+ *
+ * Source files use the parser language name (e.g. "rust", "tsx"); other
+ * files fall back to the bare extension ("json", "yaml"), or no tag at all.
+ */
+export function fenceLanguage(filePath: string): string {
+  const lastDot = filePath.lastIndexOf(".");
+  if (lastDot === -1 || lastDot < filePath.lastIndexOf("/")) {
+    return "";
+  }
+  const extension = filePath.slice(lastDot).toLowerCase();
+  return getLanguageFromExtension(extension) ?? extension.slice(1);
+}
+
+/**
+ * Wrap content in a fenced code block. The fence is made longer than any
+ * backtick run inside the content so embedded fences cannot close it early.
+ */
+export function codeFence(content: string, language: string): string {
+  const longestRun = Math.max(0, ...(content.match(/`+/g) ?? []).map((run) => run.length));
+  const fence = "`".repeat(Math.max(3, longestRun + 1));
+  return `${fence}${language}\n${content.replace(/\n+$/, "")}\n${fence}`;
+}
+
+/**
+ * Render a source location as `file:line` (or `file:start-end` for spans).
+ */
+export function formatLocation(filePath: string, startLine?: number, endLine?: number): string {
+  if (startLine === undefined) {
+    return `\`${filePath}\``;
+  }
+  const span =
+    endLine !== undefined && endLine !== startLine ? `${startLine}-${endLine}` : `${startLine}`;
+  return `\`${filePath}:${span}\``;
+}
+
+/**
+ * Render a `key: value` bullet list, skipping undefined values.
+ */
+export function formatDetails(
+  details: Record<string, string | number | boolean | undefined>
+): string {
+  return Object.entries(details)
+    .filter(([, value]) => value !== undefined)
+    .map(([key, value]) => `- **${key}:** ${String(value)}`)
+    .join("\n");
+}
+
+/**
+ * Render a parameter list as inline code ("`name: &str`, `count: i32`").
+ */
+export function formatParams(params: { name: string; type?: string }[]): string {
+  if (params.length === 0) {
+    return "none";
+  }
+  return params
+    .map((param) => `\`${param.type ? `${param.name}: ${param.type}` : param.name}\``)
+    .join(", ");
+}
+
+/**
+ * Wrap rendered sections as MCP TextContent, separated by blank lines.
+ */
+export function markdownContent(sections: string[]): TextContent {
+  return {
+    type: "text",
+    text: sections.filter((section) => section.length > 0).join("\n\n"),
+  };
+}
@@ -0,0 +1,71 @@
+/**
+ * Filesystem path rendering for tool responses.
+ *
+ * Some tools report where content lives on the host (local repository
+ * folders, watched folders). Absolute paths leak the machine's directory
+ * layout and make results non-portable, so when a project root is configured
+ * through `MCP_PROJECT_ROOT`, paths beneath it are emitted relative to it.
+ * Callers that need the real location pass `absolute_paths: true`.
+ *
+ * Paths outside the project root, and all paths when no root is configured,
+ * are returned unchanged.
+ *
+ * @module mcp/tools/utils/path-display
+ */
+
+import path from "node:path";
+
+/**
+ * Environment variable naming the root that emitted paths are relative to
+ */
+export const PROJECT_ROOT_ENV = "MCP_PROJECT_ROOT";
+
+/**
+ * JSON Schema property for `absolute_paths`, shared by tool definitions.
+ */
+export const absolutePathsProperty = {
+  type: "boolean",
+  description:
+    "Return absolute filesystem paths instead of paths relative to the configured " +
+    "project root (MCP_PROJECT_ROOT). Has no effect when no project root is configured.",
+  default: false,
+};
+
+/**
+ * Read the configured project root, resolved to an absolute path.
+ *
+ * @returns The project root, or undefined when `MCP_PROJECT_ROOT` is unset or blank
+ */
+export function getProjectRoot(): string | undefined {
+  const value = process.env[PROJECT_ROOT_ENV]?.trim();
+  return value ? path.resolve(value) : undefined;
+}
+
+/**
+ * Render a filesystem path for a tool response.
+ *
+ * @param filePath - Absolute path on the host
+ * @param projectRoot - Configured project root, if any
+ * @param absolute - Whether the caller asked for absolute paths
+ * @returns The path relative to the project root with `/` separators ("." for
+ *          the root itself), or `filePath` unchanged when it is outside the
+ *          root, no root is configured, or `absolute` is set
+ */
+export function displayPath(
+  filePath: string,
+  projectRoot: string | undefined,
+  absolute = false
+): string {
+  if (absolute || projectRoot === undefined) {
+    return filePath;
+  }
+
+  const relative = path.relative(projectRoot, path.resolve(filePath));
+  if (relative === "") {
+    return ".";
+  }
+  if (relative === ".." || relative.startsWith(`..${path.sep}`) || path.isAbsolute(relative)) {
+    return filePath;
+  }
+  return relative.split(path.sep).join("/");
+}
@@ -0,0 +1,140 @@
+/**
+ * what_changed MCP Tool Implementation
+ *
+ * This module implements the what_changed tool for the MCP server. It diffs a
+ * repository's current indexed symbols against a snapshot recorded by the
+ * snapshot tool and returns the symbols added, removed or whose signature
+ * changed since.
+ *
+ * @module mcp/tools/what-changed
+ */
+
+import type { Tool, CallToolResult, TextContent } from "@modelcontextprotocol/sdk/types.js";
+import type { SymbolDiffService, WhatChangedResult } from "../../services/symbol-diff-types.js";
+import { validateWhatChangedArgs } from "../validation.js";
+import { mapToMCPError } from "../errors.js";
+import { getComponentLogger } from "../../logging/index.js";
+import { toolDebugLog } from "../debug-logger.js";
+import type { ToolHandler } from "../types.js";
+
+/**
+ * Lazy-initialized logger to avoid initialization at module load time
+ */
+let logger: ReturnType<typeof getComponentLogger> | null = null;
+
+function getLogger(): ReturnType<typeof getComponentLogger> {
+  if (!logger) {
+    logger = getComponentLogger("mcp:what-changed");
+  }
+  return logger;
+}
+
+/**
+ * MCP tool definition for what_changed
+ */
+export const whatChangedToolDefinition: Tool = {
+  name: "what_changed",
+  description:
+    "List the symbols of an indexed repository that changed since a named snapshot taken " +
+    "with the snapshot tool: added and removed functions, classes and variables, and those " +
+    "whose signature changed (body-only edits are not reported). Reflects the index, so " +
+    "reindex or update the repository first.",
+  inputSchema: {
+    type: "object",
+    properties: {
+      repository: {
+        type: "string",
+        description: "Repository name to compare",
+      },
+      name: {
+        type: "string",
+        description: "Name of the snapshot to compare against",
+      },
+      path: {
+        type: "string",
+        description:
+          "Only report symbols in files at or beneath this path (e.g., 'src/api'), relative " +
+          "to the repository root",
+      },
+    },
+    required: ["repository", "name"],
+  },
+};
+
+/**
+ * Creates the what_changed tool handler
+ *
+ * @param diffService - Injected SymbolDiffService instance
+ * @returns Tool handler function that compares the index against snapshots
+ */
+export function createWhatChangedHandler(diffService: SymbolDiffService): ToolHandler {
+  return async (args: unknown): Promise<CallToolResult> => {
+    const startTime = performance.now();
+    const log = getLogger();
+
+    try {
+      const validatedArgs = validateWhatChangedArgs(args);
+
+      log.info(
+        {
+          repository: validatedArgs.repository,
+          name: validatedArgs.name,
+          path: validatedArgs.path,
+        },
+        "Executing what_changed tool"
+      );
+
+      const response = await diffService.whatChanged({
+        repository: validatedArgs.repository,
+        name: validatedArgs.name,
+        path: validatedArgs.path,
+      });
+
+      const content = formatWhatChangedResponse(response);
+
+      const duration = performance.now() - startTime;
+      log.info(
+        {
+          summary: response.summary,
+          duration_ms: Math.round(duration),
+          repository: validatedArgs.repository,
+        },
+        "what_changed completed successfully"
+      );
+
+      return {
+        content: [content],
+        isError: false,
+      };
+    } catch (error) {
+      const duration = performance.now() - startTime;
+      log.error({ error, duration_ms: Math.round(duration) }, "what_changed failed");
+      toolDebugLog("what_changed", error);
+
+      const mcpError = mapToMCPError(error);
+
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Error: ${mcpError.message}`,
+          },
+        ],
+        isError: true,
+      };
+    }
+  };
+}
+
+/**
+ * Formats WhatChangedResult as MCP TextContent
+ *
+ * @param response - Snapshot comparison from SymbolDiffService
+ * @returns MCP text content with formatted JSON
+ */
+function formatWhatChangedResponse(response: WhatChangedResult): TextContent {
+  return {
+    type: "text",
+    text: JSON.stringify(response, null, 2),
+  };
+}
@@ -9,12 +9,46 @@ import type { Tool, CallToolResult } from "@modelcontextprotocol/sdk/types.js";
 import type { IncrementalUpdateCoordinator } from "../services/incremental-update-coordinator.js";
 import type { LocalFolderUpdateCoordinator } from "../services/local-folder-update-coordinator.js";
 import type { GraphService } from "../services/graph-service-types.js";
+import type { GraphAnalysisService } from "../services/graph-analysis-types.js";
+import type { SymbolDiffService } from "../services/symbol-diff-types.js";
 import type { DocumentSearchService } from "../services/document-search-types.js";
 import type { ImageSearchService } from "../services/image-search-types.js";
+import type { SymbolKind } from "../graph/parsing/symbol-kinds.js";
+import type { DoctestMode, EntryPointKind } from "../graph/parsing/types.js";
 import type { ListWatchedFoldersService } from "../services/list-watched-folders-types.js";
 import type { IngestionService } from "../services/ingestion-service.js";
 import type { MCPRateLimiter } from "./rate-limiter.js";
 import type { JobTracker } from "./job-tracker.js";
+import type { ToolConcurrencyConfig } from "./concurrency-limiter.js";
+
+/**
+ * Per-invocation context passed to tool handlers by the MCP server
+ *
+ * Most handlers ignore it; long-running tools use it to stream progress and
+ * to stop early when the request is cancelled.
+ */
+export interface ToolCallContext {
+  /**
+   * Send an MCP progress notification for this call
+   *
+   * Only present when the client supplied a `progressToken` in the request's
+   * `_meta`; handlers must treat it as optional.
+   *
+   * @param progress - Units of work completed so far
+   * @param total - Total units of work, when known
+   * @param message - Short human-readable status
+   */
+  reportProgress?: (progress: number, total?: number, message?: string) => void;
+
+  /**
+   * Aborted when the client cancels the request or its connection closes
+   *
+   * Long-running handlers check it at safe points (between files, between
+   * query rounds) and stop there; a response to a cancelled request is
+   * discarded by the transport, so partial or no results are fine.
+   */
+  signal?: AbortSignal;
+}
 
 /**
  * MCP tool handler function signature
@@ -23,9 +57,27 @@ import type { JobTracker } from "./job-tracker.js";
  * content (text, images, etc.) and an error status.
  *
  * @param args - Tool-specific arguments (validated before handler is called)
+ * @param context - Optional per-invocation context (progress reporting, cancellation)
  * @returns Promise resolving to MCP-compliant tool result
  */
-export type ToolHandler = (args: unknown) => Promise<CallToolResult>;
+export type ToolHandler = (args: unknown, context?: ToolCallContext) => Promise<CallToolResult>;
+
+/**
+ * Outcome of a tool's admission check
+ *
+ * `rejected` answers the call straight away without queueing it for a
+ * concurrency slot. `release` is called once an admitted call finishes, or
+ * is turned away by the concurrency limiter before its handler runs.
+ */
+export type ToolAdmission = { rejected: CallToolResult } | { release: () => void };
+
+/**
+ * Admission check run before a tool call waits for a concurrency slot
+ *
+ * @param args - Raw tool arguments (not yet validated)
+ * @returns Whether the call may queue for a slot
+ */
+export type ToolAdmissionCheck = (args: unknown) => ToolAdmission;
 
 /**
  * Registry entry for a single MCP tool
@@ -39,6 +91,9 @@ export interface ToolRegistryEntry {
 
   /** Handler function that executes the tool logic */
   handler: ToolHandler;
+
+  /** Optional check that can reject a call before it queues for a concurrency slot */
+  admit?: ToolAdmissionCheck;
 }
 
 /**
@@ -75,8 +130,19 @@ export interface MCPServerConfig {
     /** Whether the server provides prompts (future) */
     prompts?: boolean;
   };
+
+  /** Limits on concurrently executing tool invocations (defaults apply when omitted) */
+  concurrency?: Partial<ToolConcurrencyConfig>;
 }
 
+/**
+ * Response rendering for tools that accept `output_format`.
+ *
+ * - "json": pretty-printed JSON for machine consumption (default)
+ * - "markdown": headings, file:line locations and code fences for humans
+ */
+export type OutputFormat = "json" | "markdown";
+
 /**
  * Validated semantic_search tool arguments
  *
@@ -108,6 +174,9 @@ export interface SemanticSearchArgs {
    * by similarity score. Default: false (code-only, backward compatible).
    */
   include_documents: boolean;
+
+  /** Response rendering (default: "json") */
+  output_format: OutputFormat;
 }
 
 /**
@@ -154,6 +223,12 @@ export interface MCPServerOptionalDeps {
   /** GraphService for graph-based dependency queries */
   graphService?: GraphService;
 
+  /** GraphAnalysisService for symbol-level graph analysis tools */
+  graphAnalysisService?: GraphAnalysisService;
+
+  /** SymbolDiffService for symbol-level diffs between git refs */
+  symbolDiffService?: SymbolDiffService;
+
   /** DocumentSearchService for document semantic search */
   documentSearchService?: DocumentSearchService;
 
@@ -164,8 +239,8 @@ export interface MCPServerOptionalDeps {
   listWatchedFoldersService?: ListWatchedFoldersService;
 
   /**
-   * IngestionService for the `register_local_folder` tool (Phase C / #566).
-   * When omitted the tool is not registered.
+   * IngestionService for the `register_local_folder` and `reindex` tools
+   * (Phase C / #566). When omitted neither tool is registered.
    */
   ingestionService?: IngestionService;
 
@@ -287,6 +362,12 @@ export interface GetDependenciesArgs {
 
   /** Filter to specific relationship types (optional, all types if omitted) */
   relationship_types?: DependencyRelationshipType[];
+
+  /** Collapse entities reached more than once into one entry (default: true) */
+  dedup: boolean;
+
+  /** List the paths by which each entity was reached (default: false) */
+  include_paths: boolean;
 }
 
 /**
@@ -315,6 +396,12 @@ export interface GetDependentsArgs {
 
   /** Include dependents from other repositories (default: false) */
   include_cross_repo: boolean;
+
+  /** Collapse entities reached more than once into one entry (default: true) */
+  dedup: boolean;
+
+  /** List the paths by which each entity was reached (default: false) */
+  include_paths: boolean;
 }
 
 /**
@@ -383,3 +470,773 @@ export interface FindPathArgs {
   /** Filter to specific relationship types (optional, all types if omitted) */
   relationship_types?: DependencyRelationshipType[];
 }
+
+/**
+ * Validated get_symbol_imports tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface GetSymbolImportsArgs {
+  /**
+   * Symbol reference: name (e.g., 'validateToken'), qualified name
+   * (e.g., 'src/auth.ts::validateToken') or graph node ID
+   */
+  symbol: string;
+
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Optional file path to disambiguate symbols defined in several files */
+  file_path?: string;
+}
+
+/**
+ * Validated find_cycles tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface FindCyclesArgs {
+  /** Repository name to analyze */
+  repository: string;
+
+  /** Whether type-only imports count as cycle edges (default: true) */
+  include_type_only: boolean;
+
+  /** Maximum number of cycles to return (1-100, default: 20) */
+  limit: number;
+}
+
+/**
+ * Validated top_level_items tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface TopLevelItemsArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** File path, or directory path to cover every file beneath it */
+  path: string;
+
+  /** Whether to include the direct children of each top-level item (default: false) */
+  expand: boolean;
+}
+
+/**
+ * Validated document_symbols tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface DocumentSymbolsArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** File path relative to the repository root */
+  path: string;
+}
+
+/**
+ * Validated list_todos tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface ListTodosArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Only return markers with this tag (e.g. "FIXME"), case-insensitive */
+  tag?: string;
+
+  /** File path, or directory path to cover every file beneath it */
+  path?: string;
+
+  /** Maximum number of markers to return (1-500, default: 100) */
+  limit: number;
+}
+
+/**
+ * Validated search_symbols tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface SearchSymbolsArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Symbol name or name fragment to search for */
+  query: string;
+
+  /** Whether to also match snake_case/camelCase subtokens (default: false) */
+  token_match: boolean;
+
+  /** Only return symbols of this language-neutral kind */
+  kind?: SymbolKind;
+
+  /** Only return build-time code when true, or leave it out when false */
+  build_or_macro?: boolean;
+
+  /** Only return symbols compiled for this target triple, OS or family */
+  platform?: string;
+
+  /** Whether to attach symbol and module doc summaries (default: false) */
+  include_context: boolean;
+
+  /** Drop matches scoring below this (0.25 with token_match, otherwise 0) */
+  min_score?: number;
+
+  /** Maximum number of symbols to return (1-100, default: 20) */
+  limit: number;
+
+  /** Response rendering (default: "json") */
+  output_format: OutputFormat;
+
+  /** Result record fields to return (default: all) */
+  fields?: string[];
+}
+
+/**
+ * Validated search_by_type tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface SearchByTypeArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Type to look for (e.g. "&str", "Point") */
+  type: string;
+
+  /** Signature position to match (default: "any") */
+  position: "param" | "return" | "any";
+
+  /** Whether to match through reference and generic wrappers (default: false) */
+  contains: boolean;
+
+  /** Maximum number of functions to return (1-100, default: 20) */
+  limit: number;
+
+  /** Response rendering (default: "json") */
+  output_format: OutputFormat;
+
+  /** Result record fields to return (default: all) */
+  fields?: string[];
+}
+
+/**
+ * Validated list_entry_points tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface ListEntryPointsArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Only return entry points of this kind */
+  kind?: EntryPointKind;
+
+  /** File path, or directory path to cover every file beneath it */
+  path?: string;
+
+  /** Maximum number of entry points to return (1-1000, default: 200) */
+  limit: number;
+}
+
+/**
+ * Validated find_by_attribute tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface FindByAttributeArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Attribute path without arguments (e.g., "deprecated", "serde") */
+  name: string;
+
+  /** Text the attribute's arguments must contain */
+  arguments?: string;
+
+  /** File path, or directory path to cover every file beneath it */
+  path?: string;
+
+  /** Maximum number of symbols to return (1-1000, default: 100) */
+  limit: number;
+
+  /** Result record fields to return (default: all) */
+  fields?: string[];
+}
+
+/**
+ * Validated diff_symbols_between_refs tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface DiffSymbolsBetweenRefsArgs {
+  /** Repository name to diff */
+  repository: string;
+
+  /** Base ref (branch, tag or commit SHA) */
+  base_ref: string;
+
+  /** Head ref (default: "HEAD") */
+  head_ref: string;
+
+  /** Only diff files at or beneath this path */
+  path?: string;
+}
+
+/**
+ * Validated scope_to_diff tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface ScopeToDiffArgs {
+  /** Repository name to scope */
+  repository: string;
+
+  /** Base ref the changes are measured from */
+  base_ref: string;
+
+  /** Head ref (default: "HEAD") */
+  head_ref: string;
+
+  /** Only scope files at or beneath this path */
+  path?: string;
+
+  /** Only return symbols whose name contains this text */
+  query?: string;
+
+  /** Only return symbols of this kind */
+  kind?: SymbolKind;
+}
+
+/**
+ * Validated snapshot tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface SnapshotArgs {
+  /** Repository name to snapshot */
+  repository: string;
+
+  /** Snapshot name */
+  name: string;
+}
+
+/**
+ * Validated what_changed tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface WhatChangedArgs {
+  /** Repository name to compare */
+  repository: string;
+
+  /** Snapshot name to compare against */
+  name: string;
+
+  /** Only report symbols in files at or beneath this path */
+  path?: string;
+}
+
+/**
+ * Validated record_snapshot tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface RecordSnapshotArgs {
+  /** Repository name to record */
+  repository: string;
+}
+
+/**
+ * Validated snapshot_history tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface SnapshotHistoryArgs {
+  /** Repository name whose history to read */
+  repository: string;
+
+  /** Number of most recent entries to return (default: 50) */
+  limit: number;
+}
+
+/**
+ * Validated grep_strings tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface GrepStringsArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Text the string literal must contain */
+  query: string;
+
+  /** Whether matching is case-sensitive (default: false) */
+  case_sensitive: boolean;
+
+  /** File path, or directory path to cover every file beneath it */
+  path?: string;
+
+  /** Maximum number of literals to return (1-500, default: 50) */
+  limit: number;
+}
+
+/**
+ * Validated get_coupling_metrics tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface GetCouplingMetricsArgs {
+  /** Function reference: name, file-qualified name or graph node ID */
+  symbol: string;
+
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Optional file path to disambiguate functions defined in several files */
+  file_path?: string;
+}
+
+/**
+ * Validated most_coupled tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface MostCoupledArgs {
+  /** Repository name to rank */
+  repository: string;
+
+  /** File path, or directory path to cover every file beneath it */
+  path?: string;
+
+  /** Maximum number of functions to return (1-100, default: 20) */
+  limit: number;
+
+  /** Response rendering (default: "json") */
+  output_format: OutputFormat;
+}
+
+/**
+ * Validated hotspots tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface HotspotsArgs {
+  /** Repository name to rank */
+  repository: string;
+
+  /** Maximum number of symbols to return (1-100, default: 20) */
+  limit: number;
+
+  /** Result record fields to return (default: all) */
+  fields?: string[];
+}
+
+/**
+ * Validated coupling_report tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface CouplingReportArgs {
+  /** Repository name to analyze */
+  repository: string;
+
+  /** What counts as a module (default: "file") */
+  granularity: "file" | "directory";
+
+  /** Optional file or directory path limiting the reported modules */
+  path?: string;
+
+  /** Metric to sort modules by (default: "instability") */
+  sort_by: "instability" | "afferent" | "efferent" | "module";
+
+  /** Sort direction (default: "desc") */
+  order: "asc" | "desc";
+
+  /** Maximum number of modules and module pairs to return (1-500, default: 50) */
+  limit: number;
+}
+
+/**
+ * Validated call_path tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface CallPathArgs {
+  /** Calling function reference: name, file-qualified name or graph node ID */
+  from: string;
+
+  /** Called function reference: name, file-qualified name or graph node ID */
+  to: string;
+
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Maximum number of calls in a path (1-20, default: 10) */
+  max_depth: number;
+
+  /** Maximum number of shortest paths to return (1-50, default: 5) */
+  limit: number;
+}
+
+/**
+ * Validated type_hierarchy tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface TypeHierarchyArgs {
+  /** Type reference: name, file-qualified name or graph node ID */
+  type_name: string;
+
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Maximum number of supertype levels to follow (1-10, default: 3) */
+  max_depth: number;
+}
+
+/**
+ * Validated deprecation_report tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface DeprecationReportArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Optional file or directory path filter, relative to the repository root */
+  path?: string;
+
+  /** Maximum number of deprecated symbols to return (1-500, default: 100) */
+  limit: number;
+}
+
+/**
+ * Validated list_unsafe tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface ListUnsafeArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Optional file or directory path filter, relative to the repository root */
+  path?: string;
+
+  /** Maximum number of symbols, and of impls, to return (1-1000, default: 200) */
+  limit: number;
+
+  /** Result record fields to return (default: all) */
+  fields?: string[];
+}
+
+/**
+ * Validated list_complex tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface ListComplexArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Minimum complexity a function needs to be listed (default: 10) */
+  threshold: number;
+
+  /** Optional file or directory path filter, relative to the repository root */
+  path?: string;
+
+  /** Maximum number of functions to return (1-500, default: 50) */
+  limit: number;
+
+  /** Result record fields to return (default: all) */
+  fields?: string[];
+}
+
+/**
+ * Validated find_similar_functions tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface FindSimilarFunctionsArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Optional file or directory path filter, relative to the repository root */
+  path?: string;
+
+  /** Minimum estimated similarity for near clones (0.5-1, default: 0.8) */
+  min_similarity: number;
+
+  /** Minimum body size in parse tree nodes (default: 30) */
+  min_size: number;
+
+  /** Maximum number of groups to return (1-200, default: 20) */
+  limit: number;
+}
+
+/**
+ * Validated find_consts_by_value_range tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface FindConstsByValueRangeArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Smallest value to include (default: unbounded) */
+  min?: number;
+
+  /** Largest value to include (default: unbounded) */
+  max?: number;
+
+  /** Restrict to integer or float constants (default: both) */
+  kind?: "integer" | "float";
+
+  /** Optional file or directory path filter, relative to the repository root */
+  path?: string;
+
+  /** Maximum number of constants to return (1-500, default: 100) */
+  limit: number;
+
+  /** Result record fields to return (default: all) */
+  fields?: string[];
+}
+
+/**
+ * Validated find_doctests tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface FindDoctestsArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Symbol whose documenting or calling examples are returned */
+  symbol: string;
+
+  /** Optional doctest mode filter */
+  mode?: DoctestMode;
+
+  /** Maximum number of examples to return (1-500, default: 50) */
+  limit: number;
+}
+
+/**
+ * Validated missing_docs tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface MissingDocsArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Optional file or directory path filter */
+  path?: string;
+
+  /** Symbols intentionally left undocumented (default: none) */
+  allow: string[];
+
+  /** Maximum number of undocumented symbols to return (1-1000, default: 200) */
+  limit: number;
+}
+
+/**
+ * Validated resolve_name tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface ResolveNameArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Short or partially qualified name to resolve */
+  partial: string;
+
+  /** Maximum number of candidates to return (1-200, default: 20) */
+  limit: number;
+}
+
+/**
+ * Validated topological_order tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface TopologicalOrderArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Optional file or directory path filter */
+  path?: string;
+
+  /** Maximum number of functions to return (1-5000, default: 500) */
+  limit: number;
+}
+
+/**
+ * Validated get_local_bindings tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface GetLocalBindingsArgs {
+  /** Function name, qualified name, or graph node ID */
+  symbol: string;
+
+  /** Repository name to scope the query */
+  repository: string;
+}
+
+/**
+ * Validated large_types tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface LargeTypesArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Minimum member count a type needs to be listed (default: 20) */
+  method_threshold: number;
+
+  /** Whether associated consts and types count as members (default: false) */
+  include_associated_items: boolean;
+
+  /** Sort direction on the member count (default: "desc") */
+  order: "asc" | "desc";
+
+  /** Maximum number of types to return (1-500, default: 50) */
+  limit: number;
+}
+
+/**
+ * Validated symbols_at_lines tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface SymbolsAtLinesArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** File path relative to the repository root */
+  path: string;
+
+  /** First line of the range (1-based) */
+  start: number;
+
+  /** Last line of the range (1-based, inclusive; defaults to start) */
+  end?: number;
+}
+
+/**
+ * Validated external_dependencies tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface ExternalDependenciesArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Whether to list standard library crates (default: true) */
+  include_std: boolean;
+}
+
+/**
+ * Validated functions_by_arity tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface FunctionsByArityArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Minimum number of parameters (default: 0) */
+  min_params: number;
+
+  /** Maximum number of parameters; unbounded when omitted */
+  max_params?: number;
+
+  /** Whether a Rust `self` receiver counts as a parameter (default: false) */
+  count_receiver: boolean;
+
+  /** Only return exported functions (default: false) */
+  exported_only: boolean;
+
+  /** Restrict to a file or directory */
+  path?: string;
+
+  /** Maximum number of functions to return (default: 50) */
+  limit: number;
+}
+
+/**
+ * Validated find_call_sites tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface FindCallSitesArgs {
+  /** Function name, qualified name, or graph node ID */
+  symbol: string;
+
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Only return call sites with an argument containing this text */
+  argument_contains?: string;
+
+  /** Maximum number of call sites to return (default: 100) */
+  limit: number;
+}
+
+/**
+ * Validated suggest_import tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface SuggestImportArgs {
+  /** Symbol name, qualified name, or graph node ID */
+  symbol: string;
+
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Rust file that will use the symbol */
+  from_path: string;
+}
+
+/**
+ * Validated leaky_api tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface LeakyApiArgs {
+  /** Repository name to scope the query */
+  repository: string;
+
+  /** Restrict to a file or directory */
+  path?: string;
+
+  /** Maximum number of leaks to return (default: 100) */
+  limit: number;
+}
+
+/**
+ * Validated list_indexed_repositories tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface ListIndexedRepositoriesArgs {
+  /** Return absolute local paths instead of paths relative to the project root (default: false) */
+  absolute_paths: boolean;
+}
+
+/**
+ * Validated list_watched_folders tool arguments
+ *
+ * This interface represents the tool arguments after Zod schema validation.
+ */
+export interface ListWatchedFoldersArgs {
+  /** Return absolute folder paths instead of paths relative to the project root (default: false) */
+  absolute_paths: boolean;
+}
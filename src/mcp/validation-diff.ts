@@ -0,0 +1,318 @@
+/**
+ * MCP input validation: diff and snapshot tools
+ *
+ * Zod schemas and validation functions for the git diff and graph snapshot
+ * tools. Re-exported from validation.ts.
+ */
+
+import { z } from "zod";
+import { createValidationError } from "./errors.js";
+import { SYMBOL_KINDS, type SymbolKind } from "../graph/parsing/symbol-kinds.js";
+import type {
+  DiffSymbolsBetweenRefsArgs,
+  ScopeToDiffArgs,
+  SnapshotArgs,
+  WhatChangedArgs,
+  RecordSnapshotArgs,
+  SnapshotHistoryArgs,
+} from "./types.js";
+
+/**
+ * Git ref schema for diff_symbols_between_refs
+ *
+ * Refs are passed to git as arguments, so a leading "-", whitespace and
+ * range syntax are rejected.
+ */
+const gitRefSchema = (label: string): z.ZodString =>
+  z
+    .string()
+    .trim()
+    .min(1, `${label} cannot be empty`)
+    .max(250, `${label} exceeds maximum length of 250 characters`)
+    .regex(/^[^-\s][^\s]*$/, `${label} must be a single ref without whitespace`)
+    .refine((ref) => !ref.includes(".."), `${label} must be a single ref, not a range`);
+
+/**
+ * Zod schema for diff_symbols_between_refs tool arguments
+ */
+export const DiffSymbolsBetweenRefsArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    base_ref: gitRefSchema("Base ref"),
+
+    head_ref: gitRefSchema("Head ref").optional().default("HEAD"),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters")
+      .optional(),
+  })
+  .strict();
+
+/**
+ * Validates and parses diff_symbols_between_refs tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateDiffSymbolsBetweenRefsArgs(args: unknown): DiffSymbolsBetweenRefsArgs {
+  const result = DiffSymbolsBetweenRefsArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid diff_symbols_between_refs arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for scope_to_diff tool arguments
+ */
+export const ScopeToDiffArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    base_ref: gitRefSchema("Base ref"),
+
+    head_ref: gitRefSchema("Head ref").optional().default("HEAD"),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters")
+      .optional(),
+
+    query: z
+      .string()
+      .trim()
+      .min(1, "Query cannot be empty")
+      .max(200, "Query exceeds maximum length of 200 characters")
+      .optional(),
+
+    kind: z.enum(SYMBOL_KINDS as [SymbolKind, ...SymbolKind[]]).optional(),
+  })
+  .strict();
+
+/**
+ * Validates and parses scope_to_diff tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateScopeToDiffArgs(args: unknown): ScopeToDiffArgs {
+  const result = ScopeToDiffArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid scope_to_diff arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Snapshot name schema shared by the snapshot and what_changed tools
+ */
+const snapshotNameSchema = z
+  .string()
+  .trim()
+  .min(1, "Snapshot name cannot be empty")
+  .max(100, "Snapshot name exceeds maximum length of 100 characters")
+  .regex(
+    /^[A-Za-z0-9][A-Za-z0-9._-]*$/,
+    "Snapshot name may only contain letters, digits, '.', '_' and '-'"
+  );
+
+/**
+ * Zod schema for snapshot tool arguments
+ */
+export const SnapshotArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    name: snapshotNameSchema,
+  })
+  .strict();
+
+/**
+ * Validates and parses snapshot tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateSnapshotArgs(args: unknown): SnapshotArgs {
+  const result = SnapshotArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid snapshot arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for what_changed tool arguments
+ */
+export const WhatChangedArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    name: snapshotNameSchema,
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters")
+      .optional(),
+  })
+  .strict();
+
+/**
+ * Validates and parses what_changed tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateWhatChangedArgs(args: unknown): WhatChangedArgs {
+  const result = WhatChangedArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid what_changed arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for record_snapshot tool arguments
+ */
+export const RecordSnapshotArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+  })
+  .strict();
+
+/**
+ * Validates and parses record_snapshot tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateRecordSnapshotArgs(args: unknown): RecordSnapshotArgs {
+  const result = RecordSnapshotArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid record_snapshot arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for snapshot_history tool arguments
+ */
+export const SnapshotHistoryArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(1000, "Limit cannot exceed 1000")
+      .optional()
+      .default(50),
+  })
+  .strict();
+
+/**
+ * Validates and parses snapshot_history tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateSnapshotHistoryArgs(args: unknown): SnapshotHistoryArgs {
+  const result = SnapshotHistoryArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid snapshot_history arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
@@ -0,0 +1,289 @@
+/**
+ * MCP input validation: hierarchy and call graph tools
+ *
+ * Zod schemas and validation functions for the module cycle, topological
+ * order, call site, call path and type hierarchy tools. Re-exported from
+ * validation.ts.
+ */
+
+import { z } from "zod";
+import { createValidationError } from "./errors.js";
+import type {
+  FindCyclesArgs,
+  CallPathArgs,
+  TypeHierarchyArgs,
+  TopologicalOrderArgs,
+  FindCallSitesArgs,
+} from "./types.js";
+
+/**
+ * Zod schema for find_cycles tool arguments
+ */
+export const FindCyclesArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    include_type_only: z.boolean().optional().default(true),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(100, "Limit cannot exceed 100")
+      .optional()
+      .default(20),
+  })
+  .strict();
+
+/**
+ * Validates and parses find_cycles tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateFindCyclesArgs(args: unknown): FindCyclesArgs {
+  const result = FindCyclesArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid find_cycles arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for call_path tool arguments
+ */
+export const CallPathArgsSchema = z
+  .object({
+    from: z
+      .string()
+      .trim()
+      .min(1, "From cannot be empty")
+      .max(500, "From exceeds maximum length of 500 characters"),
+
+    to: z
+      .string()
+      .trim()
+      .min(1, "To cannot be empty")
+      .max(500, "To exceeds maximum length of 500 characters"),
+
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    max_depth: z.coerce
+      .number()
+      .int("Max depth must be an integer")
+      .min(1, "Max depth must be at least 1")
+      .max(20, "Max depth cannot exceed 20")
+      .optional()
+      .default(10),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(50, "Limit cannot exceed 50")
+      .optional()
+      .default(5),
+  })
+  .strict();
+
+/**
+ * Validates and parses call_path tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateCallPathArgs(args: unknown): CallPathArgs {
+  const result = CallPathArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid call_path arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for type_hierarchy tool arguments
+ */
+export const TypeHierarchyArgsSchema = z
+  .object({
+    type_name: z
+      .string()
+      .trim()
+      .min(1, "Type name cannot be empty")
+      .max(500, "Type name exceeds maximum length of 500 characters"),
+
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    max_depth: z.coerce
+      .number()
+      .int("Max depth must be an integer")
+      .min(1, "Max depth must be at least 1")
+      .max(10, "Max depth cannot exceed 10")
+      .optional()
+      .default(3),
+  })
+  .strict();
+
+/**
+ * Validates and parses type_hierarchy tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateTypeHierarchyArgs(args: unknown): TypeHierarchyArgs {
+  const result = TypeHierarchyArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid type_hierarchy arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for topological_order tool arguments
+ */
+export const TopologicalOrderArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters")
+      .optional(),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(5000, "Limit cannot exceed 5000")
+      .optional()
+      .default(500),
+  })
+  .strict();
+
+/**
+ * Validates and parses topological_order tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateTopologicalOrderArgs(args: unknown): TopologicalOrderArgs {
+  const result = TopologicalOrderArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid topological_order arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for find_call_sites tool arguments
+ */
+export const FindCallSitesArgsSchema = z
+  .object({
+    symbol: z
+      .string()
+      .trim()
+      .min(1, "Symbol cannot be empty")
+      .max(500, "Symbol exceeds maximum length of 500 characters"),
+
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    argument_contains: z
+      .string()
+      .min(1, "Argument filter cannot be empty")
+      .max(200, "Argument filter exceeds maximum length of 200 characters")
+      .optional(),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(500, "Limit cannot exceed 500")
+      .optional()
+      .default(100),
+  })
+  .strict();
+
+/**
+ * Validates and parses find_call_sites tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateFindCallSitesArgs(args: unknown): FindCallSitesArgs {
+  const result = FindCallSitesArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid find_call_sites arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
@@ -0,0 +1,207 @@
+/**
+ * MCP input validation: import tools
+ *
+ * Zod schemas and validation functions for the symbol import, external
+ * dependency, import suggestion and leaky API tools. Re-exported from
+ * validation.ts.
+ */
+
+import { z } from "zod";
+import { createValidationError } from "./errors.js";
+import type {
+  GetSymbolImportsArgs,
+  ExternalDependenciesArgs,
+  SuggestImportArgs,
+  LeakyApiArgs,
+} from "./types.js";
+
+/**
+ * Zod schema for get_symbol_imports tool arguments
+ */
+export const GetSymbolImportsArgsSchema = z
+  .object({
+    symbol: z
+      .string()
+      .trim()
+      .min(1, "Symbol cannot be empty")
+      .max(500, "Symbol exceeds maximum length of 500 characters"),
+
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    file_path: z
+      .string()
+      .trim()
+      .min(1, "File path cannot be empty")
+      .max(500, "File path exceeds maximum length of 500 characters")
+      .optional(),
+  })
+  .strict();
+
+/**
+ * Validates and parses get_symbol_imports tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateGetSymbolImportsArgs(args: unknown): GetSymbolImportsArgs {
+  const result = GetSymbolImportsArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid get_symbol_imports arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for external_dependencies tool arguments
+ */
+export const ExternalDependenciesArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    include_std: z.boolean().optional().default(true),
+  })
+  .strict();
+
+/**
+ * Validates and parses external_dependencies tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateExternalDependenciesArgs(args: unknown): ExternalDependenciesArgs {
+  const result = ExternalDependenciesArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid external_dependencies arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for suggest_import tool arguments
+ */
+export const SuggestImportArgsSchema = z
+  .object({
+    symbol: z
+      .string()
+      .trim()
+      .min(1, "Symbol cannot be empty")
+      .max(500, "Symbol exceeds maximum length of 500 characters"),
+
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    from_path: z
+      .string()
+      .trim()
+      .min(1, "File path cannot be empty")
+      .max(500, "File path exceeds maximum length of 500 characters")
+      .refine((path) => path.endsWith(".rs"), "File path must be a Rust source file"),
+  })
+  .strict();
+
+/**
+ * Validates and parses suggest_import tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateSuggestImportArgs(args: unknown): SuggestImportArgs {
+  const result = SuggestImportArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid suggest_import arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for leaky_api tool arguments
+ */
+export const LeakyApiArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters")
+      .optional(),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(500, "Limit cannot exceed 500")
+      .optional()
+      .default(100),
+  })
+  .strict();
+
+/**
+ * Validates and parses leaky_api tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateLeakyApiArgs(args: unknown): LeakyApiArgs {
+  const result = LeakyApiArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid leaky_api arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
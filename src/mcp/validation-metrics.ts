@@ -0,0 +1,541 @@
+/**
+ * MCP input validation: metrics and hotspot tools
+ *
+ * Zod schemas and validation functions for the coupling, hotspot, complexity,
+ * unsafe-code, documentation and deprecation tools. Re-exported from
+ * validation.ts.
+ */
+
+import { z } from "zod";
+import { createValidationError } from "./errors.js";
+import type {
+  GetCouplingMetricsArgs,
+  MostCoupledArgs,
+  HotspotsArgs,
+  CouplingReportArgs,
+  DeprecationReportArgs,
+  ListUnsafeArgs,
+  ListComplexArgs,
+  MissingDocsArgs,
+  LargeTypesArgs,
+} from "./types.js";
+import {
+  fieldsSchema,
+  HOTSPOTS_FIELDS,
+  LIST_COMPLEX_FIELDS,
+  LIST_UNSAFE_FIELDS,
+} from "./validation-shared.js";
+
+/**
+ * Zod schema for get_coupling_metrics tool arguments
+ */
+export const GetCouplingMetricsArgsSchema = z
+  .object({
+    symbol: z
+      .string()
+      .trim()
+      .min(1, "Symbol cannot be empty")
+      .max(500, "Symbol exceeds maximum length of 500 characters"),
+
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    file_path: z
+      .string()
+      .trim()
+      .min(1, "File path cannot be empty")
+      .max(500, "File path exceeds maximum length of 500 characters")
+      .optional(),
+  })
+  .strict();
+
+/**
+ * Validates and parses get_coupling_metrics tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateGetCouplingMetricsArgs(args: unknown): GetCouplingMetricsArgs {
+  const result = GetCouplingMetricsArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid get_coupling_metrics arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for most_coupled tool arguments
+ */
+export const MostCoupledArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters")
+      .optional(),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(100, "Limit cannot exceed 100")
+      .optional()
+      .default(20),
+  })
+  .strict();
+
+/**
+ * Validates and parses most_coupled tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateMostCoupledArgs(args: unknown): MostCoupledArgs {
+  const result = MostCoupledArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid most_coupled arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for hotspots tool arguments
+ */
+export const HotspotsArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(100, "Limit cannot exceed 100")
+      .optional()
+      .default(20),
+
+    fields: fieldsSchema(HOTSPOTS_FIELDS),
+  })
+  .strict();
+
+/**
+ * Validates and parses hotspots tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateHotspotsArgs(args: unknown): HotspotsArgs {
+  const result = HotspotsArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid hotspots arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for coupling_report tool arguments
+ */
+export const CouplingReportArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    granularity: z
+      .enum(["file", "directory"], {
+        message: "Granularity must be 'file' or 'directory'",
+      })
+      .optional()
+      .default("file"),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters")
+      .optional(),
+
+    sort_by: z
+      .enum(["instability", "afferent", "efferent", "module"], {
+        message: "sort_by must be one of: instability, afferent, efferent, module",
+      })
+      .optional()
+      .default("instability"),
+
+    order: z
+      .enum(["asc", "desc"], {
+        message: "Order must be 'asc' or 'desc'",
+      })
+      .optional()
+      .default("desc"),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(500, "Limit cannot exceed 500")
+      .optional()
+      .default(50),
+  })
+  .strict();
+
+/**
+ * Validates and parses coupling_report tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateCouplingReportArgs(args: unknown): CouplingReportArgs {
+  const result = CouplingReportArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid coupling_report arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for deprecation_report tool arguments
+ */
+export const DeprecationReportArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters")
+      .optional(),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(500, "Limit cannot exceed 500")
+      .optional()
+      .default(100),
+  })
+  .strict();
+
+/**
+ * Validates and parses deprecation_report tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateDeprecationReportArgs(args: unknown): DeprecationReportArgs {
+  const result = DeprecationReportArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid deprecation_report arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for list_unsafe tool arguments
+ */
+export const ListUnsafeArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters")
+      .optional(),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(1000, "Limit cannot exceed 1000")
+      .optional()
+      .default(200),
+
+    fields: fieldsSchema(LIST_UNSAFE_FIELDS),
+  })
+  .strict();
+
+/**
+ * Validates and parses list_unsafe tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateListUnsafeArgs(args: unknown): ListUnsafeArgs {
+  const result = ListUnsafeArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid list_unsafe arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for list_complex tool arguments
+ */
+export const ListComplexArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    threshold: z.coerce
+      .number()
+      .int("Threshold must be an integer")
+      .min(1, "Threshold must be at least 1")
+      .optional()
+      .default(10),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters")
+      .optional(),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(500, "Limit cannot exceed 500")
+      .optional()
+      .default(50),
+
+    fields: fieldsSchema(LIST_COMPLEX_FIELDS),
+  })
+  .strict();
+
+/**
+ * Validates and parses list_complex tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateListComplexArgs(args: unknown): ListComplexArgs {
+  const result = ListComplexArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid list_complex arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for missing_docs tool arguments
+ */
+export const MissingDocsArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters")
+      .optional(),
+
+    allow: z
+      .array(
+        z
+          .string()
+          .trim()
+          .min(1, "Allowlist entries cannot be empty")
+          .max(500, "Allowlist entry exceeds maximum length of 500 characters")
+      )
+      .max(1000, "Allowlist cannot exceed 1000 entries")
+      .optional()
+      .default([]),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(1000, "Limit cannot exceed 1000")
+      .optional()
+      .default(200),
+  })
+  .strict();
+
+/**
+ * Validates and parses missing_docs tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateMissingDocsArgs(args: unknown): MissingDocsArgs {
+  const result = MissingDocsArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid missing_docs arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for large_types tool arguments
+ */
+export const LargeTypesArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    method_threshold: z.coerce
+      .number()
+      .int("Method threshold must be an integer")
+      .min(1, "Method threshold must be at least 1")
+      .optional()
+      .default(20),
+
+    include_associated_items: z.boolean().optional().default(false),
+
+    order: z
+      .enum(["asc", "desc"], {
+        message: "Order must be 'asc' or 'desc'",
+      })
+      .optional()
+      .default("desc"),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(500, "Limit cannot exceed 500")
+      .optional()
+      .default(50),
+  })
+  .strict();
+
+/**
+ * Validates and parses large_types tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateLargeTypesArgs(args: unknown): LargeTypesArgs {
+  const result = LargeTypesArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid large_types arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
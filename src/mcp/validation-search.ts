@@ -0,0 +1,718 @@
+/**
+ * MCP input validation: graph search tools
+ *
+ * Zod schemas and validation functions for the symbol, type, attribute,
+ * literal and name-resolution search tools. Re-exported from validation.ts.
+ */
+
+import { z } from "zod";
+import { createValidationError } from "./errors.js";
+import { SYMBOL_KINDS, type SymbolKind } from "../graph/parsing/symbol-kinds.js";
+import { ENTRY_POINT_KINDS } from "../graph/parsing/entry-points.js";
+import { DOCTEST_MODES } from "../graph/parsing/doctests.js";
+import type { DoctestMode, EntryPointKind } from "../graph/parsing/types.js";
+import type {
+  ListTodosArgs,
+  SearchSymbolsArgs,
+  SearchByTypeArgs,
+  ListEntryPointsArgs,
+  FindByAttributeArgs,
+  GrepStringsArgs,
+  FindSimilarFunctionsArgs,
+  FindConstsByValueRangeArgs,
+  FindDoctestsArgs,
+  ResolveNameArgs,
+  FunctionsByArityArgs,
+} from "./types.js";
+import {
+  fieldsSchema,
+  FIND_BY_ATTRIBUTE_FIELDS,
+  FIND_CONSTS_FIELDS,
+  OUTPUT_FORMATS,
+  SEARCH_BY_TYPE_FIELDS,
+  SEARCH_SYMBOLS_FIELDS,
+} from "./validation-shared.js";
+
+/**
+ * Zod schema for list_todos tool arguments
+ */
+export const ListTodosArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    tag: z
+      .string()
+      .trim()
+      .min(1, "Tag cannot be empty")
+      .max(50, "Tag exceeds maximum length of 50 characters")
+      .optional(),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters")
+      .optional(),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(500, "Limit cannot exceed 500")
+      .optional()
+      .default(100),
+  })
+  .strict();
+
+/**
+ * Validates and parses list_todos tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateListTodosArgs(args: unknown): ListTodosArgs {
+  const result = ListTodosArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid list_todos arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for search_symbols tool arguments
+ */
+export const SearchSymbolsArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    query: z
+      .string()
+      .trim()
+      .min(1, "Query cannot be empty")
+      .max(200, "Query exceeds maximum length of 200 characters"),
+
+    token_match: z.boolean().optional().default(false),
+
+    kind: z.enum(SYMBOL_KINDS as [SymbolKind, ...SymbolKind[]]).optional(),
+
+    build_or_macro: z.boolean().optional(),
+
+    platform: z
+      .string()
+      .trim()
+      .min(1, "Platform cannot be empty")
+      .max(100, "Platform exceeds maximum length of 100 characters")
+      .optional(),
+
+    include_context: z.boolean().optional().default(false),
+
+    min_score: z.coerce
+      .number()
+      .min(0.0, "Minimum score must be between 0.0 and 1.0")
+      .max(1.0, "Minimum score must be between 0.0 and 1.0")
+      .optional(),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(100, "Limit cannot exceed 100")
+      .optional()
+      .default(20),
+
+    output_format: z.enum(OUTPUT_FORMATS).optional().default("json"),
+
+    fields: fieldsSchema(SEARCH_SYMBOLS_FIELDS),
+  })
+  .strict();
+
+/**
+ * Validates and parses search_symbols tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateSearchSymbolsArgs(args: unknown): SearchSymbolsArgs {
+  const result = SearchSymbolsArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid search_symbols arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for search_by_type tool arguments
+ */
+export const SearchByTypeArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    type: z
+      .string()
+      .trim()
+      .min(1, "Type cannot be empty")
+      .max(200, "Type exceeds maximum length of 200 characters"),
+
+    position: z.enum(["param", "return", "any"]).optional().default("any"),
+
+    contains: z.boolean().optional().default(false),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(100, "Limit cannot exceed 100")
+      .optional()
+      .default(20),
+
+    output_format: z.enum(OUTPUT_FORMATS).optional().default("json"),
+
+    fields: fieldsSchema(SEARCH_BY_TYPE_FIELDS),
+  })
+  .strict();
+
+/**
+ * Validates and parses search_by_type tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateSearchByTypeArgs(args: unknown): SearchByTypeArgs {
+  const result = SearchByTypeArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid search_by_type arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for list_entry_points tool arguments
+ */
+export const ListEntryPointsArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    kind: z.enum(ENTRY_POINT_KINDS as [EntryPointKind, ...EntryPointKind[]]).optional(),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters")
+      .optional(),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(1000, "Limit cannot exceed 1000")
+      .optional()
+      .default(200),
+  })
+  .strict();
+
+/**
+ * Validates and parses list_entry_points tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateListEntryPointsArgs(args: unknown): ListEntryPointsArgs {
+  const result = ListEntryPointsArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid list_entry_points arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for find_by_attribute tool arguments
+ */
+export const FindByAttributeArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    name: z
+      .string()
+      .trim()
+      .min(1, "Attribute name cannot be empty")
+      .max(200, "Attribute name exceeds maximum length of 200 characters"),
+
+    arguments: z
+      .string()
+      .trim()
+      .min(1, "Arguments cannot be empty")
+      .max(500, "Arguments exceed maximum length of 500 characters")
+      .optional(),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters")
+      .optional(),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(1000, "Limit cannot exceed 1000")
+      .optional()
+      .default(100),
+
+    fields: fieldsSchema(FIND_BY_ATTRIBUTE_FIELDS),
+  })
+  .strict();
+
+/**
+ * Validates and parses find_by_attribute tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateFindByAttributeArgs(args: unknown): FindByAttributeArgs {
+  const result = FindByAttributeArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid find_by_attribute arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for grep_strings tool arguments
+ */
+export const GrepStringsArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    // Not trimmed: leading/trailing spaces can be part of the message searched for
+    query: z
+      .string()
+      .min(1, "Query cannot be empty")
+      .max(500, "Query exceeds maximum length of 500 characters"),
+
+    case_sensitive: z.boolean().optional().default(false),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters")
+      .optional(),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(500, "Limit cannot exceed 500")
+      .optional()
+      .default(50),
+  })
+  .strict();
+
+/**
+ * Validates and parses grep_strings tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateGrepStringsArgs(args: unknown): GrepStringsArgs {
+  const result = GrepStringsArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid grep_strings arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for find_similar_functions tool arguments
+ */
+export const FindSimilarFunctionsArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters")
+      .optional(),
+
+    min_similarity: z.coerce
+      .number()
+      .min(0.5, "min_similarity must be between 0.5 and 1.0")
+      .max(1.0, "min_similarity must be between 0.5 and 1.0")
+      .optional()
+      .default(0.8),
+
+    min_size: z.coerce
+      .number()
+      .int("min_size must be an integer")
+      .min(1, "min_size must be at least 1")
+      .optional()
+      .default(30),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(200, "Limit cannot exceed 200")
+      .optional()
+      .default(20),
+  })
+  .strict();
+
+/**
+ * Validates and parses find_similar_functions tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateFindSimilarFunctionsArgs(args: unknown): FindSimilarFunctionsArgs {
+  const result = FindSimilarFunctionsArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid find_similar_functions arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for find_consts_by_value_range tool arguments
+ */
+export const FindConstsByValueRangeArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    min: z.coerce.number().finite("Min must be a finite number").optional(),
+
+    max: z.coerce.number().finite("Max must be a finite number").optional(),
+
+    kind: z
+      .enum(["integer", "float"], {
+        message: "Kind must be 'integer' or 'float'",
+      })
+      .optional(),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters")
+      .optional(),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(500, "Limit cannot exceed 500")
+      .optional()
+      .default(100),
+
+    fields: fieldsSchema(FIND_CONSTS_FIELDS),
+  })
+  .strict()
+  .refine((data) => data.min === undefined || data.max === undefined || data.min <= data.max, {
+    message: "min must not exceed max",
+  });
+
+/**
+ * Validates and parses find_consts_by_value_range tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateFindConstsByValueRangeArgs(args: unknown): FindConstsByValueRangeArgs {
+  const result = FindConstsByValueRangeArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid find_consts_by_value_range arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for find_doctests tool arguments
+ */
+export const FindDoctestsArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    symbol: z
+      .string()
+      .trim()
+      .min(1, "Symbol cannot be empty")
+      .max(500, "Symbol exceeds maximum length of 500 characters"),
+
+    mode: z.enum(DOCTEST_MODES as [DoctestMode, ...DoctestMode[]]).optional(),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(500, "Limit cannot exceed 500")
+      .optional()
+      .default(50),
+  })
+  .strict();
+
+/**
+ * Validates and parses find_doctests tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateFindDoctestsArgs(args: unknown): FindDoctestsArgs {
+  const result = FindDoctestsArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid find_doctests arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for resolve_name tool arguments
+ */
+export const ResolveNameArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    partial: z
+      .string()
+      .trim()
+      .min(1, "Partial name cannot be empty")
+      .max(500, "Partial name exceeds maximum length of 500 characters"),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(200, "Limit cannot exceed 200")
+      .optional()
+      .default(20),
+  })
+  .strict();
+
+/**
+ * Validates and parses resolve_name tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateResolveNameArgs(args: unknown): ResolveNameArgs {
+  const result = ResolveNameArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid resolve_name arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for functions_by_arity tool arguments
+ */
+export const FunctionsByArityArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    min_params: z.coerce
+      .number()
+      .int("Minimum parameter count must be an integer")
+      .min(0, "Minimum parameter count cannot be negative")
+      .optional()
+      .default(0),
+
+    max_params: z.coerce
+      .number()
+      .int("Maximum parameter count must be an integer")
+      .min(0, "Maximum parameter count cannot be negative")
+      .optional(),
+
+    count_receiver: z.boolean().optional().default(false),
+
+    exported_only: z.boolean().optional().default(false),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters")
+      .optional(),
+
+    limit: z.coerce
+      .number()
+      .int("Limit must be an integer")
+      .min(1, "Limit must be at least 1")
+      .max(500, "Limit cannot exceed 500")
+      .optional()
+      .default(50),
+  })
+  .strict()
+  .refine((data) => data.max_params === undefined || data.min_params <= data.max_params, {
+    message: "min_params must not exceed max_params",
+  });
+
+/**
+ * Validates and parses functions_by_arity tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateFunctionsByArityArgs(args: unknown): FunctionsByArityArgs {
+  const result = FunctionsByArityArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid functions_by_arity arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
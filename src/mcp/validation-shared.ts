@@ -0,0 +1,129 @@
+/**
+ * MCP input validation: shared output options
+ *
+ * The output formats and the result record fields each tool lets clients
+ * select through `fields`, with the schema that validates such a selection.
+ */
+
+import { z } from "zod";
+
+/**
+ * Valid output_format values for tools that can render Markdown
+ */
+export const OUTPUT_FORMATS = ["json", "markdown"] as const;
+
+/**
+ * Result record fields selectable through `fields`, per tool
+ */
+export const SEARCH_SYMBOLS_FIELDS = [
+  "name",
+  "type",
+  "kind",
+  "file_path",
+  "start_line",
+  "end_line",
+  "match_type",
+  "score",
+  "where_clause",
+  "params",
+  "return_type",
+  "is_async",
+  "build_or_macro",
+  "cfg",
+  "doc_summary",
+  "module_doc_summary",
+] as const;
+
+export const SEARCH_BY_TYPE_FIELDS = [
+  "name",
+  "type",
+  "kind",
+  "file_path",
+  "start_line",
+  "end_line",
+  "params",
+  "return_type",
+  "is_async",
+  "matched_params",
+  "matched_return",
+  "match_type",
+] as const;
+
+export const FIND_BY_ATTRIBUTE_FIELDS = [
+  "name",
+  "entity_type",
+  "file_path",
+  "start_line",
+  "end_line",
+  "attributes",
+  "caller_count",
+] as const;
+
+export const HOTSPOTS_FIELDS = [
+  "name",
+  "entity_type",
+  "file_path",
+  "start_line",
+  "end_line",
+  "fan_in",
+  "caller_count",
+  "importer_count",
+  "referencing_modules",
+] as const;
+
+export const LIST_UNSAFE_FIELDS = [
+  "name",
+  "entity_type",
+  "file_path",
+  "start_line",
+  "end_line",
+  "is_unsafe",
+  "unsafe_blocks",
+] as const;
+
+export const LIST_COMPLEX_FIELDS = [
+  "name",
+  "entity_type",
+  "file_path",
+  "start_line",
+  "end_line",
+  "complexity",
+] as const;
+
+/**
+ * Fields of a find_consts_by_value_range result record
+ */
+export const FIND_CONSTS_FIELDS = [
+  "name",
+  "entity_type",
+  "file_path",
+  "start_line",
+  "end_line",
+  "value_kind",
+  "value",
+  "value_text",
+] as const;
+
+/**
+ * Schema for a `fields` projection over a tool's result records
+ *
+ * Unknown names are rejected with the list of valid fields rather than
+ * silently dropped, so a typo does not look like a missing value.
+ */
+export function fieldsSchema(allowed: readonly string[]): z.ZodOptional<z.ZodArray<z.ZodString>> {
+  return z
+    .array(z.string().trim())
+    .min(1, "Fields cannot be empty")
+    .superRefine((fields, ctx) => {
+      const unknown = [...new Set(fields.filter((field) => !allowed.includes(field)))];
+      if (unknown.length > 0) {
+        const noun = unknown.length > 1 ? "fields" : "field";
+        const names = unknown.map((field) => `'${field}'`).join(", ");
+        ctx.addIssue({
+          code: "custom",
+          message: `Unknown ${noun} ${names}; valid fields are ${allowed.join(", ")}`,
+        });
+      }
+    })
+    .optional();
+}
@@ -0,0 +1,203 @@
+/**
+ * MCP input validation: file structure tools
+ *
+ * Zod schemas and validation functions for the file outline and local binding
+ * tools. Re-exported from validation.ts.
+ */
+
+import { z } from "zod";
+import { createValidationError } from "./errors.js";
+import type {
+  TopLevelItemsArgs,
+  DocumentSymbolsArgs,
+  GetLocalBindingsArgs,
+  SymbolsAtLinesArgs,
+} from "./types.js";
+
+/**
+ * Zod schema for top_level_items tool arguments
+ */
+export const TopLevelItemsArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters"),
+
+    expand: z.boolean().optional().default(false),
+  })
+  .strict();
+
+/**
+ * Validates and parses top_level_items tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateTopLevelItemsArgs(args: unknown): TopLevelItemsArgs {
+  const result = TopLevelItemsArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid top_level_items arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for document_symbols tool arguments
+ */
+export const DocumentSymbolsArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters"),
+  })
+  .strict();
+
+/**
+ * Validates and parses document_symbols tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateDocumentSymbolsArgs(args: unknown): DocumentSymbolsArgs {
+  const result = DocumentSymbolsArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid document_symbols arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for get_local_bindings tool arguments
+ */
+export const GetLocalBindingsArgsSchema = z
+  .object({
+    symbol: z
+      .string()
+      .trim()
+      .min(1, "Symbol cannot be empty")
+      .max(500, "Symbol exceeds maximum length of 500 characters"),
+
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+  })
+  .strict();
+
+/**
+ * Validates and parses get_local_bindings tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateGetLocalBindingsArgs(args: unknown): GetLocalBindingsArgs {
+  const result = GetLocalBindingsArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid get_local_bindings arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for symbols_at_lines tool arguments
+ */
+export const SymbolsAtLinesArgsSchema = z
+  .object({
+    repository: z
+      .string()
+      .trim()
+      .min(1, "Repository name cannot be empty")
+      .max(200, "Repository name exceeds maximum length of 200 characters"),
+
+    path: z
+      .string()
+      .trim()
+      .min(1, "Path cannot be empty")
+      .max(500, "Path exceeds maximum length of 500 characters"),
+
+    start: z.coerce
+      .number()
+      .int("Start line must be an integer")
+      .min(1, "Start line must be at least 1"),
+
+    end: z.coerce
+      .number()
+      .int("End line must be an integer")
+      .min(1, "End line must be at least 1")
+      .optional(),
+  })
+  .strict()
+  .refine((data) => data.end === undefined || data.start <= data.end, {
+    message: "start must not exceed end",
+  });
+
+/**
+ * Validates and parses symbols_at_lines tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateSymbolsAtLinesArgs(args: unknown): SymbolsAtLinesArgs {
+  const result = SymbolsAtLinesArgsSchema.safeParse(args);
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid symbols_at_lines arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
@@ -4,6 +4,9 @@
  * This module provides Zod schemas and validation functions for MCP tool arguments.
  * Validation happens at the MCP layer before calling the SearchService to enforce
  * MCP-specific constraints (e.g., character limits, parameter ranges).
+ *
+ * Schemas for the graph analysis tools live in validation-<feature>.ts and
+ * are re-exported from here.
  */
 
 import { z } from "zod";
@@ -16,7 +19,18 @@ import type {
   GetDependentsArgs,
   GetArchitectureArgs,
   FindPathArgs,
+  ListIndexedRepositoriesArgs,
+  ListWatchedFoldersArgs,
 } from "./types.js";
+import { OUTPUT_FORMATS } from "./validation-shared.js";
+
+export * from "./validation-shared.js";
+export * from "./validation-search.js";
+export * from "./validation-structure.js";
+export * from "./validation-hierarchy.js";
+export * from "./validation-metrics.js";
+export * from "./validation-imports.js";
+export * from "./validation-diff.js";
 
 /**
  * Zod schema for semantic_search tool arguments
@@ -72,6 +86,8 @@ export const SemanticSearchArgsSchema = z
       .optional(),
 
     include_documents: z.boolean().optional().default(false),
+
+    output_format: z.enum(OUTPUT_FORMATS).optional().default("json"),
   })
   .strict();
 
@@ -283,6 +299,10 @@ export const GetDependenciesArgsSchema = z
         })
       )
       .optional(),
+
+    dedup: z.boolean().optional().default(true),
+
+    include_paths: z.boolean().optional().default(false),
   })
   .strict();
 
@@ -381,6 +401,10 @@ export const GetDependentsArgsSchema = z
       .default(1),
 
     include_cross_repo: z.boolean().optional().default(false),
+
+    dedup: z.boolean().optional().default(true),
+
+    include_paths: z.boolean().optional().default(false),
   })
   .strict();
 
@@ -752,17 +776,76 @@ export function validateSearchImagesArgs(args: unknown): SearchImagesArgs {
   return result.data;
 }
 
+/**
+ * Zod schema for list_indexed_repositories tool arguments
+ *
+ * Not strict: the tool historically took no parameters and ignored whatever
+ * clients sent, so unknown keys are still dropped rather than rejected.
+ */
+export const ListIndexedRepositoriesArgsSchema = z.object({
+  absolute_paths: z.boolean().optional().default(false),
+});
+
+/**
+ * Validates and parses list_indexed_repositories tool arguments
+ *
+ * @param args - Raw arguments from MCP CallTool request (undefined/null allowed)
+ * @returns Validated arguments with defaults applied
+ * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
+ */
+export function validateListIndexedRepositoriesArgs(args: unknown): ListIndexedRepositoriesArgs {
+  const result = ListIndexedRepositoriesArgsSchema.safeParse(args ?? {});
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid list_indexed_repositories arguments: ${errorMessage}`);
+  }
+
+  return result.data;
+}
+
+/**
+ * Zod schema for list_watched_folders tool arguments
+ *
+ * Not strict, for the same reason as ListIndexedRepositoriesArgsSchema.
+ */
+export const ListWatchedFoldersArgsSchema = z.object({
+  absolute_paths: z.boolean().optional().default(false),
+});
+
 /**
  * Validates list_watched_folders tool arguments
  *
- * This tool has an empty input schema (no parameters), so validation
- * just ensures the input is an object (or undefined/null).
+ * The input must be an object (or undefined/null); its only parameter is
+ * the optional `absolute_paths` flag.
  *
  * @param args - Raw arguments from MCP CallTool request
+ * @returns Validated arguments with defaults applied
  * @throws {McpError} If validation fails (ErrorCode.InvalidParams)
  */
-export function validateListWatchedFoldersArgs(args: unknown): void {
+export function validateListWatchedFoldersArgs(args: unknown): ListWatchedFoldersArgs {
   if (args !== undefined && args !== null && typeof args !== "object") {
     throw createValidationError("Invalid list_watched_folders arguments: expected an object");
   }
+
+  const result = ListWatchedFoldersArgsSchema.safeParse(args ?? {});
+
+  if (!result.success) {
+    const errorMessage = result.error.issues
+      .map((e) => {
+        const path = e.path.length > 0 ? `${e.path.join(".")}: ` : "";
+        return `${path}${e.message}`;
+      })
+      .join("; ");
+
+    throw createValidationError(`Invalid list_watched_folders arguments: ${errorMessage}`);
+  }
+
+  return result.data;
 }
@@ -190,6 +190,14 @@ export interface RepositoryInfo {
    */
   excludePatterns: string[];
 
+  /**
+   * Whether `.gitignore` rules are applied on top of `excludePatterns`.
+   *
+   * Only written when disabled at indexing time; absent (legacy records
+   * included) means `.gitignore` is respected.
+   */
+  respectGitignore?: boolean;
+
   /**
    * Document formats actually encountered when populating the document graph.
    *
@@ -487,6 +495,16 @@ export interface UpdateHistoryEntry {
    */
   chunksSkipped?: number;
 
+  /**
+   * Number of chunks whose embedding input was truncated to the token budget.
+   *
+   * Optional so history entries written before truncation replaced skipping
+   * remain valid.
+   *
+   * @example 1
+   */
+  chunksTruncated?: number;
+
   /**
    * Duration of the pipeline processing in milliseconds
    *
@@ -10,11 +10,21 @@
  * `watched-folder-store.ts`. Each repository gets its own manifest file under
  * `{DATA_PATH}/manifests/<sanitized-repo-name>.json`.
  *
+ * Incremental updates do not rewrite that snapshot. They append one record
+ * per changed file to a sibling `.log` file (JSON Lines, each record carrying
+ * a sequence number and its own checksum), which is folded back into the
+ * snapshot once it grows past `FILE_MANIFEST_COMPACTION_THRESHOLD` records.
+ * Loading replays the log over the snapshot. A crash mid-append leaves at
+ * worst an unterminated last record, which is discarded on load; a crash
+ * mid-compaction is harmless because the snapshot records the sequence it
+ * covers and replay skips records at or below it.
+ *
  * @module services/file-manifest-store
  */
 
 import { join, dirname } from "path";
-import { rename, unlink, mkdir, readdir, readFile } from "fs/promises";
+import { rename, unlink, mkdir, readdir, readFile, open, truncate } from "fs/promises";
+import { createHash } from "crypto";
 import type { Logger } from "pino";
 import { z } from "zod";
 import { getComponentLogger } from "../logging/index.js";
@@ -44,6 +54,12 @@ export type FileManifestVersion = typeof FILE_MANIFEST_VERSION;
  */
 export const FILE_MANIFEST_EMPTY_GENERATED_AT = "1970-01-01T00:00:00.000Z";
 
+/**
+ * Number of log records after which a repository's update log is compacted
+ * into its snapshot.
+ */
+export const FILE_MANIFEST_COMPACTION_THRESHOLD = 1000;
+
 /**
  * Per-file fingerprint record stored in a manifest.
  *
@@ -81,6 +97,59 @@ export interface FileManifest {
   files: Record<string, FileManifestEntry>;
 }
 
+/**
+ * Change to one file's fingerprint, appended to the manifest log by
+ * `appendUpdates`.
+ */
+export interface FileManifestUpdate {
+  /** POSIX-normalized relative file path (relative to the repository root). */
+  path: string;
+  /** New fingerprint, or null when the file leaves the manifest. */
+  entry: FileManifestEntry | null;
+}
+
+/**
+ * Result of `loadManifestOrReset`.
+ */
+export interface ManifestLoadResult {
+  /** Persisted manifest, or an empty one when a corrupt manifest was discarded. */
+  manifest: FileManifest;
+  /**
+   * True when a corrupt manifest was discarded. Files deleted since the last
+   * update are then missing from any diff, so callers must reconcile against
+   * the index instead of trusting the empty manifest.
+   */
+  wasReset: boolean;
+}
+
+/**
+ * Thrown by `loadManifest` when a persisted manifest cannot be trusted.
+ *
+ * Covers truncated or malformed JSON, schema violations, and payloads whose
+ * recorded SHA-256 checksum no longer matches their content, in the snapshot
+ * or anywhere in the update log but its unterminated last record. Callers that can
+ * rebuild from scratch should use `loadManifestOrReset` instead of handling
+ * this error themselves.
+ */
+export class FileManifestCorruptError extends Error {
+  /** Repository whose manifest failed verification. */
+  public readonly repository: string;
+
+  /** On-disk path of the corrupt manifest file. */
+  public readonly filePath: string;
+
+  constructor(repository: string, filePath: string, reason: string) {
+    super(`Manifest for '${repository}' is corrupt (${filePath}): ${reason}`);
+    this.name = "FileManifestCorruptError";
+    this.repository = repository;
+    this.filePath = filePath;
+
+    if (Error.captureStackTrace) {
+      Error.captureStackTrace(this, this.constructor);
+    }
+  }
+}
+
 /**
  * Service interface for persisting per-repository file manifests.
  */
@@ -95,10 +164,31 @@ export interface FileManifestStoreService {
    * consecutive misses return byte-identical results — callers can compare
    * against the sentinel to detect "never persisted".
    *
+   * Manifests written by this store carry a SHA-256 checksum of their payload
+   * that is verified here; manifests written before checksums were introduced
+   * load without verification. Records in the update log are replayed over
+   * the snapshot; an unterminated last record (a crash mid-append) is
+   * discarded.
+   *
    * @param repository - Repository name (matches `RepositoryInfo.name`).
+   * @throws {FileManifestCorruptError} If the file is unparseable, fails schema
+   *         validation, or its checksum does not match its content.
    */
   loadManifest(repository: string): Promise<FileManifest>;
 
+  /**
+   * Load the manifest for a repository, discarding it if it is corrupt.
+   *
+   * Behaves like `loadManifest`, except a `FileManifestCorruptError` is logged,
+   * the corrupt file is removed, and an empty manifest is returned with
+   * `wasReset: true`. Diffing against the empty manifest reports every file
+   * as added and no file as deleted, so the caller must purge what is no
+   * longer on disk itself.
+   *
+   * @param repository - Repository name (matches `RepositoryInfo.name`).
+   */
+  loadManifestOrReset(repository: string): Promise<ManifestLoadResult>;
+
   /**
    * Persist a manifest for a repository using an atomic temp-file + rename.
    *
@@ -113,7 +203,38 @@ export interface FileManifestStoreService {
   saveManifest(repository: string, manifest: FileManifest): Promise<void>;
 
   /**
-   * Remove the manifest for a repository.
+   * Record per-file fingerprint changes by appending them to the update log.
+   *
+   * Each update becomes one checksummed log record, flushed to disk before
+   * this call resolves, so a crash loses at most the batch being written.
+   * The log is compacted into the snapshot once it reaches
+   * `FILE_MANIFEST_COMPACTION_THRESHOLD` records. Serialized with the other
+   * writes through the internal write queue. No-op for an empty batch.
+   *
+   * @param repository - Repository name (matches `RepositoryInfo.name`).
+   * @param updates - Changed fingerprints, applied in order.
+   * @param generatedAt - ISO 8601 timestamp the manifest carries after the update.
+   * @throws {FileManifestCorruptError} If the existing manifest cannot be loaded.
+   */
+  appendUpdates(
+    repository: string,
+    updates: FileManifestUpdate[],
+    generatedAt: string
+  ): Promise<void>;
+
+  /**
+   * Fold the update log into the snapshot and remove the log.
+   *
+   * Runs automatically from `appendUpdates`; exposed for maintenance. No-op
+   * when the repository has no update log.
+   *
+   * @param repository - Repository name (matches `RepositoryInfo.name`).
+   * @throws {FileManifestCorruptError} If the existing manifest cannot be loaded.
+   */
+  compactManifest(repository: string): Promise<void>;
+
+  /**
+   * Remove the manifest for a repository, including its update log.
    *
    * Idempotent — succeeds with no error when the manifest file does not exist.
    */
@@ -130,8 +251,9 @@ export interface FileManifestStoreService {
    * manifests whose repository names are absent from the metadata store.
    *
    * Returns an empty array when the manifests directory does not exist yet
-   * (fresh install). Manifests with malformed JSON or missing `repository`
-   * fields are skipped with a debug log rather than failing the boot.
+   * (fresh install). Repositories with only an update log are included.
+   * Manifests with malformed JSON or missing `repository` fields are skipped
+   * with a debug log rather than failing the boot.
    */
   listManifests(): Promise<string[]>;
 }
@@ -149,8 +271,42 @@ const FileManifestSchema = z.object({
   repository: z.string(),
   generatedAt: z.string(),
   files: z.record(z.string(), FileManifestEntrySchema),
+  // Optional so manifests persisted before the update log was added still load
+  logSequence: z.number().int().nonnegative().optional(),
+  // Optional so manifests persisted before checksums were added still load
+  checksum: z.string().optional(),
+});
+
+/** Zod schema for one update log record (validated on replay). */
+const FileManifestLogRecordSchema = z.object({
+  seq: z.number().int().positive(),
+  repository: z.string(),
+  generatedAt: z.string(),
+  path: z.string(),
+  entry: FileManifestEntrySchema.nullable(),
+  checksum: z.string(),
 });
 
+/** An update log record without its checksum. */
+type FileManifestLogRecord = Omit<z.infer<typeof FileManifestLogRecordSchema>, "checksum">;
+
+/**
+ * A repository's manifest as reconstructed from its snapshot and update log.
+ */
+interface ManifestState {
+  /** Current manifest. */
+  manifest: FileManifest;
+  /** Sequence number of the last update applied (0 when none). */
+  sequence: number;
+  /** Records in the update log that the snapshot does not cover yet. */
+  logRecords: number;
+  /**
+   * Length in bytes of the intact part of the log when it ends with an
+   * unterminated record; the next append truncates the log to it first.
+   */
+  tornLogLength?: number;
+}
+
 /**
  * Singleton implementation of the file manifest store.
  *
@@ -165,7 +321,7 @@ export class FileManifestStoreImpl implements FileManifestStoreService {
   private _logger: Logger | null = null;
 
   /** In-memory cache keyed by repository name. */
-  private readonly cache: Map<string, FileManifest> = new Map();
+  private readonly cache: Map<string, ManifestState> = new Map();
 
   /** Serialized promise queue to prevent concurrent read-modify-write races. */
   private writeQueue: Promise<void> = Promise.resolve();
@@ -244,44 +400,32 @@ export class FileManifestStoreImpl implements FileManifestStoreService {
     return repository;
   }
 
-  async loadManifest(repository: string): Promise<FileManifest> {
-    const cached = this.cache.get(repository);
-    if (cached) {
-      return cloneManifest(cached);
-    }
-
-    const filePath = this.getManifestPath(repository);
-    const file = Bun.file(filePath);
-    const exists = await file.exists();
+  /**
+   * Resolve the on-disk path for a repository's update log, a sibling of the
+   * snapshot at `getManifestPath`.
+   */
+  public getLogPath(repository: string): string {
+    return this.getManifestPath(repository).replace(/\.json$/, ".log");
+  }
 
-    if (!exists) {
-      this.logger.debug(
-        { filePath, repository },
-        "Manifest file not found - returning empty manifest"
-      );
-      return emptyManifest(repository);
-    }
+  async loadManifest(repository: string): Promise<FileManifest> {
+    const state = await this.loadState(repository);
+    return cloneManifest(state.manifest);
+  }
 
+  async loadManifestOrReset(repository: string): Promise<ManifestLoadResult> {
     try {
-      const content = await file.text();
-      const parsed: unknown = JSON.parse(content);
-      const manifest = FileManifestSchema.parse(parsed);
-      this.cache.set(repository, manifest);
-      this.logger.debug(
-        { filePath, repository, fileCount: Object.keys(manifest.files).length },
-        "Manifest loaded from disk"
-      );
-      return cloneManifest(manifest);
+      return { manifest: await this.loadManifest(repository), wasReset: false };
     } catch (error) {
-      this.logger.error(
-        {
-          filePath,
-          repository,
-          error: error instanceof Error ? error.message : String(error),
-        },
-        "Failed to load manifest"
+      if (!(error instanceof FileManifestCorruptError)) {
+        throw error;
+      }
+      this.logger.warn(
+        { filePath: error.filePath, repository, error: error.message },
+        "Discarding corrupt manifest - falling back to a clean rebuild"
       );
-      throw error;
+      await this.deleteManifest(repository);
+      return { manifest: emptyManifest(repository), wasReset: true };
     }
   }
 
@@ -300,6 +444,28 @@ export class FileManifestStoreImpl implements FileManifestStoreService {
     await next;
   }
 
+  async appendUpdates(
+    repository: string,
+    updates: FileManifestUpdate[],
+    generatedAt: string
+  ): Promise<void> {
+    const next = this.writeQueue.then(
+      () => this.appendUpdatesInternal(repository, updates, generatedAt),
+      () => this.appendUpdatesInternal(repository, updates, generatedAt)
+    );
+    this.writeQueue = next.catch(() => undefined);
+    await next;
+  }
+
+  async compactManifest(repository: string): Promise<void> {
+    const next = this.writeQueue.then(
+      () => this.compactManifestInternal(repository),
+      () => this.compactManifestInternal(repository)
+    );
+    this.writeQueue = next.catch(() => undefined);
+    await next;
+  }
+
   async deleteManifest(repository: string): Promise<void> {
     const next = this.writeQueue.then(
       () => this.deleteManifestInternal(repository),
@@ -325,14 +491,19 @@ export class FileManifestStoreImpl implements FileManifestStoreService {
     }
 
     const repositories: string[] = [];
+    const snapshots = new Set(entries.filter((entry) => entry.endsWith(".json")));
     for (const entry of entries) {
-      if (!entry.endsWith(".json")) continue;
       const filePath = join(this.manifestsDir, entry);
       try {
-        const content = await readFile(filePath, "utf-8");
-        const parsed: unknown = JSON.parse(content);
-        const manifest = FileManifestSchema.parse(parsed);
-        repositories.push(manifest.repository);
+        if (snapshots.has(entry)) {
+          const content = await readFile(filePath, "utf-8");
+          repositories.push(parseManifestContent(content).manifest.repository);
+        } else if (entry.endsWith(".log") && !snapshots.has(entry.replace(/\.log$/, ".json"))) {
+          // A repository whose only updates so far are in its log
+          const content = await readFile(filePath, "utf-8");
+          const firstLine = content.slice(0, content.indexOf("\n"));
+          repositories.push(parseLogRecord(firstLine).repository);
+        }
       } catch (err) {
         // Malformed / partial manifests are skipped rather than failing the
         // entire boot — the reaper can clean them up on a future run after
@@ -343,7 +514,291 @@ export class FileManifestStoreImpl implements FileManifestStoreService {
     return repositories;
   }
 
+  /**
+   * Reconstruct a repository's manifest from its snapshot and update log,
+   * serving from the cache when possible.
+   *
+   * The result is cached only when at least one of the two files exists.
+   *
+   * @throws {FileManifestCorruptError} If either file cannot be trusted
+   */
+  private async loadState(repository: string): Promise<ManifestState> {
+    const cached = this.cache.get(repository);
+    if (cached) {
+      return cached;
+    }
+
+    const filePath = this.getManifestPath(repository);
+    const logPath = this.getLogPath(repository);
+    const snapshotFile = Bun.file(filePath);
+    const logFile = Bun.file(logPath);
+    const [snapshotExists, logExists] = await Promise.all([
+      snapshotFile.exists(),
+      logFile.exists(),
+    ]);
+
+    if (!snapshotExists && !logExists) {
+      this.logger.debug(
+        { filePath, repository },
+        "Manifest file not found - returning empty manifest"
+      );
+      return { manifest: emptyManifest(repository), sequence: 0, logRecords: 0 };
+    }
+
+    let state: ManifestState = { manifest: emptyManifest(repository), sequence: 0, logRecords: 0 };
+    if (snapshotExists) {
+      try {
+        const { manifest, sequence } = parseManifestContent(await snapshotFile.text());
+        state = { manifest, sequence, logRecords: 0 };
+      } catch (error) {
+        const reason = error instanceof Error ? error.message : String(error);
+        this.logger.error({ filePath, repository, error: reason }, "Failed to load manifest");
+        throw new FileManifestCorruptError(repository, filePath, reason);
+      }
+    }
+
+    if (logExists) {
+      try {
+        state = this.replayLog(repository, state, await logFile.text(), logPath);
+      } catch (error) {
+        const reason = error instanceof Error ? error.message : String(error);
+        this.logger.error(
+          { filePath: logPath, repository, error: reason },
+          "Failed to replay manifest log"
+        );
+        throw new FileManifestCorruptError(repository, logPath, reason);
+      }
+    }
+
+    this.cache.set(repository, state);
+    this.logger.debug(
+      {
+        filePath,
+        repository,
+        fileCount: Object.keys(state.manifest.files).length,
+        logRecords: state.logRecords,
+      },
+      "Manifest loaded from disk"
+    );
+    return state;
+  }
+
+  /**
+   * Apply the records of an update log to a snapshot's state.
+   *
+   * Records at or below the snapshot's sequence were already compacted into
+   * it and are skipped. A final line without a trailing newline, or one that
+   * fails to parse, is the remains of an interrupted append: it is dropped,
+   * and its offset is kept so the next append overwrites it.
+   *
+   * @throws {Error} If a complete record is invalid, out of sequence, or
+   *         belongs to another repository
+   */
+  private replayLog(
+    repository: string,
+    state: ManifestState,
+    content: string,
+    logPath: string
+  ): ManifestState {
+    const files = { ...state.manifest.files };
+    let generatedAt = state.manifest.generatedAt;
+    let sequence = state.sequence;
+    let logRecords = 0;
+    let offset = 0;
+    let tornLogLength: number | undefined;
+
+    while (offset < content.length) {
+      const end = content.indexOf("\n", offset);
+      const line = content.slice(offset, end === -1 ? content.length : end);
+      const isLast = end === -1 || end === content.length - 1;
+
+      let record: FileManifestLogRecord;
+      try {
+        if (end === -1) {
+          throw new Error("record is not terminated");
+        }
+        record = parseLogRecord(line);
+      } catch (error) {
+        if (!isLast) {
+          throw error;
+        }
+        tornLogLength = Buffer.byteLength(content.slice(0, offset));
+        this.logger.warn(
+          { filePath: logPath, repository, error: error instanceof Error ? error.message : error },
+          "Discarding incomplete trailing manifest log record"
+        );
+        break;
+      }
+
+      offset = end + 1;
+      if (record.repository !== repository) {
+        throw new Error(`record ${record.seq} belongs to repository ${record.repository}`);
+      }
+      if (record.seq <= state.sequence) {
+        // Already part of the snapshot (compaction stopped before removing the log)
+        continue;
+      }
+      if (record.seq !== sequence + 1) {
+        throw new Error(`record ${record.seq} out of sequence (expected ${sequence + 1})`);
+      }
+
+      if (record.entry) {
+        files[record.path] = record.entry;
+      } else {
+        delete files[record.path];
+      }
+      generatedAt = record.generatedAt;
+      sequence = record.seq;
+      logRecords++;
+    }
+
+    return {
+      manifest: { version: FILE_MANIFEST_VERSION, repository, generatedAt, files },
+      sequence,
+      logRecords,
+      ...(tornLogLength !== undefined && { tornLogLength }),
+    };
+  }
+
   private async saveManifestInternal(repository: string, manifest: FileManifest): Promise<void> {
+    // Keep the sequence moving forward so records still in the log are not
+    // replayed over the new snapshot if removing the log fails.
+    const sequence = await this.lastSequence(repository);
+    await this.writeSnapshot(repository, manifest, sequence);
+  }
+
+  private async appendUpdatesInternal(
+    repository: string,
+    updates: FileManifestUpdate[],
+    generatedAt: string
+  ): Promise<void> {
+    if (updates.length === 0) {
+      return;
+    }
+
+    const state = await this.loadState(repository);
+    const logPath = this.getLogPath(repository);
+    await mkdir(dirname(logPath), { recursive: true });
+
+    let sequence = state.sequence;
+    const lines = updates.map((update) => {
+      sequence++;
+      const record: FileManifestLogRecord = {
+        seq: sequence,
+        repository,
+        generatedAt,
+        path: update.path,
+        entry: update.entry,
+      };
+      return JSON.stringify({ ...record, checksum: computeLogRecordChecksum(record) }) + "\n";
+    });
+
+    try {
+      if (state.tornLogLength !== undefined) {
+        await truncate(logPath, state.tornLogLength);
+      }
+      const handle = await open(logPath, "a");
+      try {
+        await handle.write(lines.join(""));
+        await handle.sync();
+      } finally {
+        await handle.close();
+      }
+    } catch (error) {
+      // The log may now end with a partial batch; re-read it on next access
+      this.cache.delete(repository);
+      this.logger.error(
+        {
+          filePath: logPath,
+          repository,
+          error: error instanceof Error ? error.message : String(error),
+        },
+        "Failed to append manifest updates"
+      );
+      throw error;
+    }
+
+    const files = { ...state.manifest.files };
+    for (const update of updates) {
+      if (update.entry) {
+        files[update.path] = update.entry;
+      } else {
+        delete files[update.path];
+      }
+    }
+    const next: ManifestState = {
+      manifest: { version: FILE_MANIFEST_VERSION, repository, generatedAt, files },
+      sequence,
+      logRecords: state.logRecords + updates.length,
+    };
+    this.cache.set(repository, next);
+    this.logger.debug(
+      { filePath: logPath, repository, updateCount: updates.length, sequence },
+      "Manifest updates appended to log"
+    );
+
+    if (next.logRecords >= FILE_MANIFEST_COMPACTION_THRESHOLD) {
+      await this.compactManifestInternal(repository);
+    }
+  }
+
+  private async compactManifestInternal(repository: string): Promise<void> {
+    const state = await this.loadState(repository);
+    if (state.logRecords === 0 && state.tornLogLength === undefined) {
+      return;
+    }
+    await this.writeSnapshot(repository, state.manifest, state.sequence);
+    this.logger.debug(
+      { repository, logRecords: state.logRecords, sequence: state.sequence },
+      "Manifest log compacted"
+    );
+  }
+
+  /**
+   * Sequence number of the last update recorded for a repository.
+   *
+   * Falls back to a lenient scan of the files when the manifest cannot be
+   * loaded, so that a snapshot replacing a corrupt one still supersedes
+   * every intact log record.
+   */
+  private async lastSequence(repository: string): Promise<number> {
+    try {
+      return (await this.loadState(repository)).sequence;
+    } catch {
+      let sequence = 0;
+      try {
+        const content = await readFile(this.getManifestPath(repository), "utf-8");
+        sequence = FileManifestSchema.parse(JSON.parse(content)).logSequence ?? 0;
+      } catch {
+        // Unreadable snapshot - rely on the log alone
+      }
+      try {
+        const content = await readFile(this.getLogPath(repository), "utf-8");
+        for (const line of content.split("\n")) {
+          try {
+            sequence = Math.max(sequence, parseLogRecord(line).seq);
+          } catch {
+            // Skip damaged records
+          }
+        }
+      } catch {
+        // No log
+      }
+      return sequence;
+    }
+  }
+
+  /**
+   * Atomically replace a repository's snapshot, then remove its update log.
+   *
+   * The snapshot records `sequence`, so a crash before the log is removed
+   * leaves records that replay skips.
+   */
+  private async writeSnapshot(
+    repository: string,
+    manifest: FileManifest,
+    sequence: number
+  ): Promise<void> {
     const filePath = this.getManifestPath(repository);
     const tempPath = `${filePath}.tmp`;
 
@@ -358,15 +813,24 @@ export class FileManifestStoreImpl implements FileManifestStoreService {
     };
 
     try {
-      const content = JSON.stringify(payload, null, 2);
+      const content = JSON.stringify(
+        {
+          ...payload,
+          ...(sequence > 0 && { logSequence: sequence }),
+          checksum: computeManifestChecksum(payload, sequence),
+        },
+        null,
+        2
+      );
       await Bun.write(tempPath, content);
       await rename(tempPath, filePath);
-      this.cache.set(repository, payload);
+      this.cache.set(repository, { manifest: payload, sequence, logRecords: 0 });
       this.logger.debug(
         { filePath, repository, fileCount: Object.keys(payload.files).length },
         "Manifest saved to disk"
       );
     } catch (error) {
+      this.cache.delete(repository);
       try {
         await unlink(tempPath);
       } catch {
@@ -382,10 +846,35 @@ export class FileManifestStoreImpl implements FileManifestStoreService {
       );
       throw error;
     }
+
+    const logPath = this.getLogPath(repository);
+    try {
+      await unlink(logPath);
+    } catch (error) {
+      if ((error as NodeJS.ErrnoException).code !== "ENOENT") {
+        // The snapshot already covers every record, so a stale log is harmless
+        this.logger.warn(
+          {
+            filePath: logPath,
+            repository,
+            error: error instanceof Error ? error.message : String(error),
+          },
+          "Failed to remove compacted manifest log"
+        );
+      }
+    }
   }
 
   private async deleteManifestInternal(repository: string): Promise<void> {
     const filePath = this.getManifestPath(repository);
+    try {
+      await unlink(this.getLogPath(repository));
+    } catch (error) {
+      if ((error as NodeJS.ErrnoException).code !== "ENOENT") {
+        this.cache.delete(repository);
+        throw error;
+      }
+    }
     try {
       await unlink(filePath);
       this.logger.debug({ filePath, repository }, "Manifest deleted");
@@ -427,6 +916,104 @@ function emptyManifest(repository: string): FileManifest {
   };
 }
 
+/**
+ * Compute the SHA-256 checksum stored alongside a persisted manifest.
+ *
+ * Hashes the compact JSON serialization of the payload fields in a fixed
+ * order. `files` keeps its insertion order across a JSON round-trip, so the
+ * digest recomputed on load matches the one computed on save. The log
+ * sequence is only hashed when non-zero, so snapshots written before the
+ * update log existed keep verifying.
+ */
+function computeManifestChecksum(manifest: FileManifest, logSequence = 0): string {
+  const canonical = JSON.stringify({
+    version: manifest.version,
+    repository: manifest.repository,
+    generatedAt: manifest.generatedAt,
+    files: manifest.files,
+    ...(logSequence > 0 && { logSequence }),
+  });
+  return createHash("sha256").update(canonical).digest("hex");
+}
+
+/**
+ * Parse and verify the content of a manifest file.
+ *
+ * @returns The manifest and the last log sequence number it covers
+ * @throws {Error} If the content is not valid JSON, fails schema validation,
+ *         or carries a checksum that does not match the payload
+ */
+function parseManifestContent(content: string): { manifest: FileManifest; sequence: number } {
+  const parsed: unknown = JSON.parse(content);
+  const { checksum, logSequence = 0, ...manifest } = FileManifestSchema.parse(parsed);
+  if (checksum !== undefined) {
+    const actual = computeManifestChecksum(manifest, logSequence);
+    if (actual !== checksum) {
+      throw new Error(`checksum mismatch (expected ${checksum}, got ${actual})`);
+    }
+  }
+  return { manifest, sequence: logSequence };
+}
+
+/** Compute the SHA-256 checksum stored in an update log record. */
+function computeLogRecordChecksum(record: FileManifestLogRecord): string {
+  const canonical = JSON.stringify({
+    seq: record.seq,
+    repository: record.repository,
+    generatedAt: record.generatedAt,
+    path: record.path,
+    entry: record.entry,
+  });
+  return createHash("sha256").update(canonical).digest("hex");
+}
+
+/**
+ * Parse and verify one line of an update log.
+ *
+ * @throws {Error} If the line is not valid JSON, fails schema validation,
+ *         or carries a checksum that does not match the record
+ */
+function parseLogRecord(line: string): FileManifestLogRecord {
+  const parsed: unknown = JSON.parse(line);
+  const { checksum, ...record } = FileManifestLogRecordSchema.parse(parsed);
+  const actual = computeLogRecordChecksum(record);
+  if (actual !== checksum) {
+    throw new Error(`record checksum mismatch (expected ${checksum}, got ${actual})`);
+  }
+  return record;
+}
+
+/**
+ * List the per-file changes that turn one manifest's files into another's.
+ *
+ * @param prior - Files currently recorded
+ * @param next - Files to record
+ * @returns One update per added, changed, or removed path, for `appendUpdates`
+ */
+export function diffManifestFiles(
+  prior: Record<string, FileManifestEntry>,
+  next: Record<string, FileManifestEntry>
+): FileManifestUpdate[] {
+  const updates: FileManifestUpdate[] = [];
+  for (const [path, entry] of Object.entries(next)) {
+    const previous = prior[path];
+    if (
+      !previous ||
+      previous.sha256 !== entry.sha256 ||
+      previous.mtimeMs !== entry.mtimeMs ||
+      previous.sizeBytes !== entry.sizeBytes
+    ) {
+      updates.push({ path, entry });
+    }
+  }
+  for (const path of Object.keys(prior)) {
+    if (!(path in next)) {
+      updates.push({ path, entry: null });
+    }
+  }
+  return updates;
+}
+
 /** Deep-copy a manifest so callers can mutate the result without polluting the cache. */
 function cloneManifest(manifest: FileManifest): FileManifest {
   return {
@@ -13,7 +13,16 @@
  *   2. Otherwise → no-op (Phase 6's `ChangeDetectionService` handles it).
  *
  * Per-repo debounce coalesces noisy editor saves into a single coordinator
- * call. The debounce window is `repo.watchDebounceMs ?? DEFAULT_DEBOUNCE_MS`.
+ * call. The debounce window is `repo.watchDebounceMs ?? defaultDebounceMs`,
+ * where the default comes from `LOCAL_FOLDER_WATCH_DEBOUNCE_MS`.
+ *
+ * Bulk operations (`cargo fmt`, a branch checkout) emit a burst of events.
+ * Within a window the router keeps one net change per file: repeated events
+ * collapse, a change followed by a delete becomes the delete, and a file
+ * created and deleted again within the window drops out entirely. A burst
+ * that nets out to nothing dispatches nothing. While a dispatch for a repo
+ * is still running, further events accumulate and are dispatched as one
+ * batch once it finishes, so overlapping bursts never race the coordinator.
  *
  * @module services/folder-event-router
  */
@@ -22,7 +31,8 @@ import { realpath } from "node:fs/promises";
 import { sep } from "node:path";
 import type { Logger } from "pino";
 import { getComponentLogger } from "../logging/index.js";
-import type { FileEvent } from "./folder-watcher-types.js";
+import type { FileEvent, FileEventType } from "./folder-watcher-types.js";
+import { MIN_DEBOUNCE_MS, MAX_DEBOUNCE_MS } from "../utils/debounce.js";
 import type { RepositoryMetadataService, RepositoryInfo } from "../repositories/types.js";
 
 /** Synthetic `WatchedFolder.id` prefix used by Phase C local-folder repos. */
@@ -31,6 +41,41 @@ export const LOCAL_FOLDER_ID_PREFIX = "local-folder:";
 /** Default per-repo debounce window in milliseconds. */
 export const DEFAULT_LOCAL_FOLDER_DEBOUNCE_MS = 2000;
 
+/**
+ * Resolve the default debounce window from the `LOCAL_FOLDER_WATCH_DEBOUNCE_MS`
+ * environment value. Unset or non-numeric values fall back to
+ * {@link DEFAULT_LOCAL_FOLDER_DEBOUNCE_MS}; others are clamped to the shared
+ * debounce bounds (100ms - 300000ms).
+ */
+export function resolveLocalFolderDebounceMs(value: string | undefined): number {
+  const parsed = value === undefined ? NaN : parseInt(value, 10);
+  if (isNaN(parsed)) return DEFAULT_LOCAL_FOLDER_DEBOUNCE_MS;
+  return Math.max(MIN_DEBOUNCE_MS, Math.min(MAX_DEBOUNCE_MS, parsed));
+}
+
+/**
+ * Combine the pending event type for a file with a newer event for the same
+ * file. Returns `null` when the two cancel out — a file created and deleted
+ * within one window never reached the index, so nothing needs processing.
+ */
+export function coalesceFileEventType(
+  pending: FileEventType | undefined,
+  next: FileEventType
+): FileEventType | null {
+  switch (pending) {
+    case undefined:
+      return next;
+    case "add":
+      // Still new to the index however often it was rewritten
+      return next === "unlink" ? null : "add";
+    case "change":
+      return next === "unlink" ? "unlink" : "change";
+    case "unlink":
+      // Deleted then recreated: the indexed copy is stale, not gone
+      return next === "unlink" ? "unlink" : "change";
+  }
+}
+
 /**
  * Build the synthetic folder-id for a local-folder repository. The id is
  * deterministic from the repo name so restoration on server restart and
@@ -53,8 +98,14 @@ export function repositoryNameFromLocalFolderId(folderId: string): string | null
  * Function called by the router when a debounced batch of events for a
  * local-folder repo is ready to be processed. Production wires this to
  * `LocalFolderUpdateCoordinator.updateRepository(repositoryName)`.
+ *
+ * `changes` holds one coalesced event per file, in first-seen order; it is
+ * never empty.
  */
-export type LocalFolderDispatch = (repository: RepositoryInfo) => void | Promise<void>;
+export type LocalFolderDispatch = (
+  repository: RepositoryInfo,
+  changes: FileEvent[]
+) => void | Promise<void>;
 
 /**
  * Optional config for the router. Tests pass overrides to shorten timers and
@@ -74,6 +125,10 @@ export interface FolderEventRouterConfig {
 
 export class FolderEventRouter {
   private readonly debounceTimers = new Map<string, ReturnType<typeof setTimeout>>();
+  /** Net pending change per file (keyed by absolute path), per repo. */
+  private readonly pendingChanges = new Map<string, Map<string, FileEvent>>();
+  /** Repos whose dispatch is currently running. */
+  private readonly inFlight = new Set<string>();
   private readonly defaultDebounceMs: number;
   private readonly schedule: (cb: () => void, ms: number) => ReturnType<typeof setTimeout>;
   private readonly cancel: (handle: ReturnType<typeof setTimeout>) => void;
@@ -166,9 +221,30 @@ export class FolderEventRouter {
       }
     }
 
+    this.recordChange(repo.name, event);
     this.scheduleDebouncedDispatch(repo);
   }
 
+  /**
+   * Fold an event into the repo's pending batch, keeping one net change per
+   * file.
+   */
+  private recordChange(repositoryName: string, event: FileEvent): void {
+    let pending = this.pendingChanges.get(repositoryName);
+    if (!pending) {
+      pending = new Map();
+      this.pendingChanges.set(repositoryName, pending);
+    }
+
+    const previous = pending.get(event.absolutePath);
+    const type = coalesceFileEventType(previous?.type, event.type);
+    if (type === null) {
+      pending.delete(event.absolutePath);
+    } else {
+      pending.set(event.absolutePath, { ...event, type });
+    }
+  }
+
   /**
    * Check whether `eventAbsolutePath` resolves (via realpath) to a location
    * inside `repoLocalPath`'s real root. Returns false on any realpath failure
@@ -208,28 +284,67 @@ export class FolderEventRouter {
     const debounceMs = repo.watchDebounceMs ?? this.defaultDebounceMs;
     const timer = this.schedule(() => {
       this.debounceTimers.delete(repo.name);
-      void Promise.resolve(this.localFolderDispatch(repo)).catch((error) => {
-        this.logger.error(
-          {
-            repository: repo.name,
-            error: error instanceof Error ? error.message : String(error),
-          },
-          "FolderEventRouter: localFolderDispatch threw"
-        );
-      });
+      void this.dispatchPending(repo);
     }, debounceMs);
 
     this.debounceTimers.set(repo.name, timer);
   }
 
   /**
-   * Cancel all pending debounce timers. Used on server shutdown so we don't
-   * leave timers holding refs after the watcher fleet has stopped.
+   * Hand the repo's pending batch to the dispatch. When a dispatch is already
+   * running the batch stays pending and is picked up when that one finishes.
+   */
+  private async dispatchPending(repo: RepositoryInfo): Promise<void> {
+    if (this.inFlight.has(repo.name)) return;
+
+    const pending = this.pendingChanges.get(repo.name);
+    this.pendingChanges.delete(repo.name);
+    if (!pending || pending.size === 0) {
+      this.logger.debug(
+        { repository: repo.name },
+        "FolderEventRouter: event burst coalesced to no net changes; skipping dispatch"
+      );
+      return;
+    }
+
+    const changes = Array.from(pending.values());
+    this.logger.debug(
+      { repository: repo.name, changeCount: changes.length },
+      "FolderEventRouter: dispatching coalesced changes"
+    );
+
+    this.inFlight.add(repo.name);
+    try {
+      await this.localFolderDispatch(repo, changes);
+    } catch (error) {
+      this.logger.error(
+        {
+          repository: repo.name,
+          error: error instanceof Error ? error.message : String(error),
+        },
+        "FolderEventRouter: localFolderDispatch threw"
+      );
+    } finally {
+      this.inFlight.delete(repo.name);
+    }
+
+    // Events that arrived during the dispatch and whose debounce window has
+    // already closed are waiting on us; a still-open window dispatches itself.
+    if (this.pendingChanges.has(repo.name) && !this.debounceTimers.has(repo.name)) {
+      await this.dispatchPending(repo);
+    }
+  }
+
+  /**
+   * Cancel all pending debounce timers and discard pending changes. Used on
+   * server shutdown so we don't leave timers holding refs after the watcher
+   * fleet has stopped.
    */
   shutdown(): void {
     for (const timer of this.debounceTimers.values()) {
       this.cancel(timer);
     }
     this.debounceTimers.clear();
+    this.pendingChanges.clear();
   }
 }
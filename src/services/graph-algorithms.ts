@@ -0,0 +1,260 @@
+/**
+ * @module services/graph-algorithms
+ *
+ * Generic graph algorithms used by GraphAnalysisService.
+ *
+ * These operate on in-memory adjacency maps built from Cypher query rows, so
+ * analyses that need whole-graph passes (cycles, orderings) don't depend on
+ * what the underlying graph database supports natively.
+ */
+
+/**
+ * Directed graph as an adjacency map: node -> set of successor nodes.
+ *
+ * Every node must appear as a key, even when it has no outgoing edges.
+ */
+export type AdjacencyMap = Map<string, Set<string>>;
+
+/**
+ * Find strongly-connected components with Tarjan's algorithm.
+ *
+ * Implemented iteratively so deep import chains in large repositories do not
+ * overflow the call stack.
+ *
+ * @param graph - Adjacency map of the directed graph
+ * @returns Components in reverse topological order; each component lists its nodes
+ *          in discovery order
+ */
+export function findStronglyConnectedComponents(graph: AdjacencyMap): string[][] {
+  let nextIndex = 0;
+  const index = new Map<string, number>();
+  const lowLink = new Map<string, number>();
+  const onStack = new Set<string>();
+  const stack: string[] = [];
+  const components: string[][] = [];
+  const lowOf = (node: string): number => lowLink.get(node) ?? 0;
+
+  for (const root of graph.keys()) {
+    if (index.has(root)) continue;
+
+    // Each frame holds a node and an iterator over its remaining successors
+    const callStack: Array<{ node: string; successors: Iterator<string> }> = [];
+
+    const visit = (node: string): void => {
+      index.set(node, nextIndex);
+      lowLink.set(node, nextIndex);
+      nextIndex++;
+      stack.push(node);
+      onStack.add(node);
+      callStack.push({ node, successors: (graph.get(node) ?? new Set()).values() });
+    };
+
+    visit(root);
+
+    let frame = callStack[callStack.length - 1];
+    while (frame) {
+      const next = frame.successors.next();
+
+      if (!next.done) {
+        const successor = next.value;
+        if (!index.has(successor)) {
+          visit(successor);
+        } else if (onStack.has(successor)) {
+          lowLink.set(frame.node, Math.min(lowOf(frame.node), index.get(successor) ?? 0));
+        }
+        frame = callStack[callStack.length - 1];
+        continue;
+      }
+
+      // All successors processed: pop the frame and propagate the low-link
+      callStack.pop();
+      const parent = callStack[callStack.length - 1];
+      if (parent) {
+        lowLink.set(parent.node, Math.min(lowOf(parent.node), lowOf(frame.node)));
+      }
+
+      if (lowLink.get(frame.node) === index.get(frame.node)) {
+        const component: string[] = [];
+        let member: string | undefined;
+        do {
+          member = stack.pop();
+          if (member === undefined) break;
+          onStack.delete(member);
+          component.push(member);
+        } while (member !== frame.node);
+        components.push(component.reverse());
+      }
+
+      frame = parent;
+    }
+  }
+
+  return components;
+}
+
+/**
+ * Shortest paths found by {@link findShortestPaths}
+ */
+export interface ShortestPaths {
+  /** Paths as node sequences from a source to a target, all of the same length */
+  paths: string[][];
+
+  /** Whether more shortest paths exist than were returned */
+  truncated: boolean;
+}
+
+/**
+ * Find the shortest directed paths from any source to any target with a
+ * breadth-first search.
+ *
+ * Every predecessor on the previous BFS level is recorded, so all paths of
+ * the minimal length can be enumerated, not just the first one found. A path
+ * always follows at least one edge: a node that is both a source and a target
+ * is only matched by a cycle back to itself.
+ *
+ * @param graph - Adjacency map of the directed graph
+ * @param sources - Start nodes
+ * @param targets - End nodes
+ * @param maxDepth - Maximum number of edges in a path
+ * @param limit - Maximum number of paths to return
+ * @returns Shortest paths grouped by target in node ID order, or none when no
+ *          target is reachable within `maxDepth` edges
+ */
+export function findShortestPaths(
+  graph: AdjacencyMap,
+  sources: Iterable<string>,
+  targets: ReadonlySet<string>,
+  maxDepth: number,
+  limit: number
+): ShortestPaths {
+  // Predecessors of each visited node on the previous level; sources have none
+  const predecessors = new Map<string, string[]>();
+  // Predecessors of each target reached on the final level
+  const targetPredecessors = new Map<string, string[]>();
+
+  let frontier = [...new Set(sources)];
+  for (const source of frontier) predecessors.set(source, []);
+
+  for (let depth = 1; depth <= maxDepth && frontier.length > 0; depth++) {
+    const next = new Map<string, string[]>();
+
+    for (const node of frontier) {
+      for (const successor of graph.get(node) ?? []) {
+        if (targets.has(successor)) {
+          const reachedFrom = targetPredecessors.get(successor) ?? [];
+          reachedFrom.push(node);
+          targetPredecessors.set(successor, reachedFrom);
+        }
+        if (predecessors.has(successor)) continue;
+        const levelPredecessors = next.get(successor) ?? [];
+        levelPredecessors.push(node);
+        next.set(successor, levelPredecessors);
+      }
+    }
+
+    if (targetPredecessors.size > 0) break;
+
+    for (const [node, levelPredecessors] of next) predecessors.set(node, levelPredecessors);
+    frontier = [...next.keys()];
+  }
+
+  // Walk predecessor lists back to the sources, stopping one path past the limit
+  const paths: string[][] = [];
+  const walkBack = (node: string, suffix: string[]): void => {
+    const previous = predecessors.get(node) ?? [];
+    if (previous.length === 0) {
+      paths.push([node, ...suffix]);
+      return;
+    }
+    for (const predecessor of [...previous].sort()) {
+      if (paths.length > limit) return;
+      walkBack(predecessor, [node, ...suffix]);
+    }
+  };
+
+  for (const target of [...targetPredecessors.keys()].sort()) {
+    for (const predecessor of [...(targetPredecessors.get(target) ?? [])].sort()) {
+      if (paths.length > limit) break;
+      walkBack(predecessor, [target]);
+    }
+  }
+
+  return { paths: paths.slice(0, limit), truncated: paths.length > limit };
+}
+
+/**
+ * A group of nodes placed together by {@link findTopologicalOrder}
+ */
+export interface TopologicalGroup {
+  /** Nodes of one strongly-connected component, in comparator order */
+  nodes: string[];
+
+  /**
+   * Length of the longest chain of groups this group depends on: 0 for
+   * groups without successors, otherwise one more than the deepest successor
+   */
+  level: number;
+}
+
+/**
+ * Order nodes so that every node comes after its successors ("definitions
+ * before uses" when edges point from a user to what it uses).
+ *
+ * Cycles are condensed first: the nodes of each strongly-connected component
+ * form one group. The condensed graph is then emitted level by level, leaves
+ * first, and groups within a level are ordered by their first node. Sorting
+ * with the comparator at every step makes the order independent of the
+ * adjacency map's insertion order.
+ *
+ * @param graph - Adjacency map of the directed graph
+ * @param compare - Comparator for nodes (default: string order)
+ * @returns Groups in dependency order
+ */
+export function findTopologicalOrder(
+  graph: AdjacencyMap,
+  compare: (a: string, b: string) => number = (a, b) => a.localeCompare(b)
+): TopologicalGroup[] {
+  const components = findStronglyConnectedComponents(graph).map((component) =>
+    [...component].sort(compare)
+  );
+  const componentOf = new Map<string, number>();
+  components.forEach((component, i) => {
+    for (const node of component) componentOf.set(node, i);
+  });
+
+  // Edges between components: a group waits for the groups it points to
+  const waitingOn = components.map(() => new Set<number>());
+  const waitedOnBy = components.map(() => new Set<number>());
+  for (const [node, successors] of graph) {
+    const from = componentOf.get(node) ?? 0;
+    for (const successor of successors) {
+      const to = componentOf.get(successor);
+      if (to === undefined || to === from) continue;
+      waitingOn[from]?.add(to);
+      waitedOnBy[to]?.add(from);
+    }
+  }
+
+  const compareComponents = (a: number, b: number): number =>
+    compare(components[a]?.[0] ?? "", components[b]?.[0] ?? "");
+  const remaining = waitingOn.map((successors) => successors.size);
+  const groups: TopologicalGroup[] = [];
+  let level = 0;
+  let ready = components.map((_, i) => i).filter((i) => remaining[i] === 0);
+
+  while (ready.length > 0) {
+    ready.sort(compareComponents);
+    const next: number[] = [];
+    for (const i of ready) {
+      groups.push({ nodes: components[i] ?? [], level });
+      for (const dependent of waitedOnBy[i] ?? []) {
+        remaining[dependent] = (remaining[dependent] ?? 0) - 1;
+        if (remaining[dependent] === 0) next.push(dependent);
+      }
+    }
+    ready = next;
+    level++;
+  }
+
+  return groups;
+}
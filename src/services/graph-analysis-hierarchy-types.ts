@@ -0,0 +1,420 @@
+/**
+ * @module services/graph-analysis-hierarchy-types
+ *
+ * Type definitions for the GraphAnalysisService module, call graph and type hierarchy queries.
+ */
+
+import type { GenericBound } from "../graph/parsing/type-hierarchy.js";
+import type { SymbolLocation } from "./graph-analysis-types.js";
+
+// =============================================================================
+// Module Cycles
+// =============================================================================
+
+/**
+ * Query for circular module dependencies in a repository
+ */
+export interface ModuleCyclesQuery {
+  /** Repository to analyze */
+  repository: string;
+
+  /**
+   * Whether type-only imports count as edges. When false, cycles that exist
+   * only through `import type` are not reported.
+   * @default true
+   */
+  include_type_only?: boolean;
+
+  /**
+   * Maximum number of cycles to return (largest first)
+   * @default 20
+   */
+  limit?: number;
+}
+
+/**
+ * An import edge between two files of the repository
+ */
+export interface ModuleImportEdge {
+  /** Importing file */
+  from: string;
+
+  /** Imported file */
+  to: string;
+
+  /** Module specifier as written in the importing file */
+  module: string;
+
+  /** Line of the import statement (1-based), when known */
+  line: number | null;
+
+  /** Whether the import is type-only (erased at runtime) */
+  type_only: boolean;
+}
+
+/**
+ * A strongly-connected component of the file import graph
+ */
+export interface ModuleCycle {
+  /**
+   * `mutual` for two files importing each other, `cycle` for longer loops
+   */
+  kind: "mutual" | "cycle";
+
+  /**
+   * Whether the cycle exists at runtime. False when every edge of the
+   * component is type-only, which is usually a legitimate mutual reference.
+   */
+  runtime: boolean;
+
+  /** Files in the component, sorted by path */
+  files: string[];
+
+  /** Import edges between files of the component */
+  edges: ModuleImportEdge[];
+}
+
+/**
+ * Result of a module cycles query
+ */
+export interface ModuleCyclesResult {
+  /** Repository analyzed */
+  repository: string;
+
+  /** Components with more than one file, largest first */
+  cycles: ModuleCycle[];
+
+  /** Files importing themselves, reported separately from cycles */
+  self_imports: ModuleImportEdge[];
+
+  /** Query metadata */
+  metadata: {
+    /** Files in the repository's import graph */
+    files_analyzed: number;
+    /** Resolved file-to-file import edges */
+    edges_analyzed: number;
+    /** Total cycles found (before applying the limit) */
+    cycle_count: number;
+    /** Whether cycles were omitted because of the limit */
+    truncated: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Topological Order
+// =============================================================================
+
+/**
+ * Query for the functions of a repository in dependency order
+ */
+export interface TopologicalOrderQuery {
+  /** Repository to order */
+  repository: string;
+
+  /**
+   * File path, or directory path to cover every file beneath it. Calls to
+   * functions outside the path are ignored.
+   */
+  path?: string;
+
+  /**
+   * Maximum number of functions to return; groups are never split
+   * @default 500
+   */
+  limit?: number;
+}
+
+/**
+ * Functions placed together in the dependency order
+ */
+export interface TopologicalOrderGroup {
+  /**
+   * Length of the longest call chain below the group: 0 when its functions
+   * call nothing in scope
+   */
+  level: number;
+
+  /** Whether the functions call each other in a cycle (more than one function) */
+  cyclic: boolean;
+
+  /** Functions of the group, ordered by file and line */
+  symbols: SymbolLocation[];
+}
+
+/**
+ * Result of a topological order query
+ */
+export interface TopologicalOrderResult {
+  /** Repository ordered */
+  repository: string;
+
+  /** Groups in dependency order: every function comes after the functions it calls */
+  groups: TopologicalOrderGroup[];
+
+  /** Query metadata */
+  metadata: {
+    /** Functions in scope, before the limit */
+    symbol_count: number;
+    /** Groups in scope, before the limit */
+    group_count: number;
+    /** Groups of mutually recursive functions, before the limit */
+    cyclic_group_count: number;
+    /** Call edges between functions in scope */
+    edge_count: number;
+    /** Whether the limit cut off groups */
+    truncated: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Call Sites
+// =============================================================================
+
+/**
+ * Query for the call sites of a function, with the arguments passed
+ */
+export interface CallSitesQuery {
+  /** Function name, qualified name, or graph node ID */
+  symbol: string;
+
+  /** Repository to search */
+  repository: string;
+
+  /** Only return call sites with an argument containing this text (case-sensitive) */
+  argument_contains?: string;
+
+  /**
+   * Maximum number of call sites to return
+   * @default 100
+   */
+  limit?: number;
+}
+
+/**
+ * One call of the queried function
+ */
+export interface CallSiteMatch {
+  /** Function making the call */
+  caller: SymbolLocation;
+
+  /** Graph node ID of the function called */
+  callee_id: string;
+
+  /** Line of the call (1-based) */
+  line: number;
+
+  /**
+   * Source text of each argument expression, or null when arguments were not
+   * captured at ingestion (`GRAPH_CAPTURE_CALL_ARGUMENTS`)
+   */
+  arguments: string[] | null;
+}
+
+/**
+ * Result of a call sites query
+ */
+export interface CallSitesResult {
+  /** Symbol as queried */
+  symbol: string;
+
+  /** Repository searched */
+  repository: string;
+
+  /** Matching call sites, ordered by caller file and line */
+  call_sites: CallSiteMatch[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of functions the symbol resolved to */
+    callee_count: number;
+    /** Number of matching call sites, before the limit */
+    total_count: number;
+    /** Whether any call site had its arguments captured */
+    arguments_captured: boolean;
+    /** Whether the results were truncated by the limit */
+    truncated: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Call Path
+// =============================================================================
+
+/**
+ * Query for the shortest call paths from one function to another
+ */
+export interface CallPathQuery {
+  /** Calling function, in any form accepted by SymbolImportsQuery.symbol */
+  from: string;
+
+  /** Called function, in any form accepted by SymbolImportsQuery.symbol */
+  to: string;
+
+  /** Repository containing both functions */
+  repository: string;
+
+  /**
+   * Maximum number of calls in a path
+   * @default 10
+   */
+  max_depth?: number;
+
+  /**
+   * Maximum number of shortest paths to return
+   * @default 5
+   */
+  limit?: number;
+}
+
+/**
+ * One chain of calls from the starting function to the target function
+ */
+export interface CallPath {
+  /** Number of calls in the path */
+  length: number;
+
+  /** Functions along the path, starting with the caller and ending with the callee */
+  functions: SymbolLocation[];
+}
+
+/**
+ * Result of a call path query
+ */
+export interface CallPathResult {
+  /** Calling function reference as given */
+  from: string;
+
+  /** Called function reference as given */
+  to: string;
+
+  /** Repository searched */
+  repository: string;
+
+  /** Shortest paths, all of the same length; empty when no path exists */
+  paths: CallPath[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of paths returned */
+    path_count: number;
+    /** Whether more shortest paths exist than were returned */
+    truncated: boolean;
+    /** Maximum number of calls searched */
+    max_depth: number;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Type Hierarchy
+// =============================================================================
+
+/**
+ * Query for the supertypes, trait implementations and generic bounds of a type
+ */
+export interface TypeHierarchyQuery {
+  /** Type, in any form accepted by SymbolImportsQuery.symbol */
+  type_name: string;
+
+  /** Repository containing the type */
+  repository: string;
+
+  /**
+   * Maximum number of supertype levels to follow within the repository
+   * @default 3
+   */
+  max_depth?: number;
+}
+
+/**
+ * A supertype or trait named by a type
+ */
+export interface TypeReference {
+  /** Name as written (e.g., "Comparable<Task>", "models.Base", "From<u8>") */
+  name: string;
+
+  /** IDs of the repository types with this name; empty for external types */
+  definition_ids: string[];
+}
+
+/**
+ * A Rust trait implemented through an `impl Trait for Type` block
+ */
+export interface TraitImplementation extends TypeReference {
+  /** File containing the impl block */
+  file_path: string;
+
+  /** Line of the impl block (1-based) */
+  line: number;
+
+  /** Bounds the impl is conditional on; empty for unconditional impls */
+  conditions: GenericBound[];
+
+  /** Whether this is a negative impl (`impl !Send for T`) */
+  negative: boolean;
+}
+
+/**
+ * A type with everything it inherits, implements and derives
+ */
+export interface TypeHierarchyEntry extends SymbolLocation {
+  /** 0 for the queried type, n for a supertype n levels above it */
+  depth: number;
+
+  /** Superclass (`extends`), or null */
+  superclass: TypeReference | null;
+
+  /** Implemented interfaces, extended interfaces or further base classes */
+  interfaces: TypeReference[];
+
+  /** Traits implemented through impl blocks anywhere in the repository (Rust) */
+  traits: TraitImplementation[];
+
+  /** Supertraits a trait requires its implementors to implement as well (Rust) */
+  supertraits: TypeReference[];
+
+  /** Traits listed in `#[derive(...)]` (Rust) */
+  derives: string[];
+
+  /** Bounds on the type's generic parameters, including `where` predicates */
+  generic_bounds: GenericBound[];
+}
+
+/**
+ * Result of a type hierarchy query
+ */
+export interface TypeHierarchyResult {
+  /** Type reference as given */
+  type_name: string;
+
+  /** Repository searched */
+  repository: string;
+
+  /**
+   * The queried type (every definition matching the reference) followed by
+   * its supertypes defined in the repository, nearest first. References
+   * between entries use `definition_ids`.
+   */
+  types: TypeHierarchyEntry[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of definitions matching the reference */
+    definition_count: number;
+    /** Number of supertypes returned */
+    supertype_count: number;
+    /** Maximum number of supertype levels followed */
+    max_depth: number;
+    /** Whether supertypes beyond max_depth were left out */
+    truncated: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
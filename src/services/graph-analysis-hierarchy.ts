@@ -0,0 +1,637 @@
+/**
+ * @module services/graph-analysis-hierarchy
+ *
+ * Hierarchy and call graph queries of GraphAnalysisService: type
+ * hierarchies, call paths, call sites, module import cycles and topological
+ * order.
+ */
+
+import path from "node:path";
+import type {
+  ValidatedModuleCyclesQuery,
+  ValidatedCallPathQuery,
+  ValidatedTypeHierarchyQuery,
+  ValidatedTopologicalOrderQuery,
+  ValidatedCallSitesQuery,
+} from "./graph-analysis-validation.js";
+import {
+  derivedTraits,
+  parseGenericBounds,
+  splitSupertypes,
+} from "../graph/parsing/type-hierarchy.js";
+import { baseTypeName } from "../graph/ingestion/call-resolution.js";
+import {
+  findStronglyConnectedComponents,
+  findShortestPaths,
+  findTopologicalOrder,
+  type AdjacencyMap,
+} from "./graph-algorithms.js";
+import { GraphServiceCancelledError, EntityNotFoundError } from "./graph-service-errors.js";
+import type {
+  SymbolLocation,
+  ModuleCyclesResult,
+  ModuleCycle,
+  ModuleImportEdge,
+  TopologicalOrderResult,
+  CallSitesResult,
+  CallSiteMatch,
+  CallPathResult,
+  TypeHierarchyResult,
+  TypeHierarchyEntry,
+  TypeReference,
+  TraitImplementation,
+} from "./graph-analysis-types.js";
+import { GraphAnalysisQueries, type SymbolRow } from "./graph-analysis-queries.js";
+
+/**
+ * Raw row returned by the type hierarchy definitions query
+ */
+interface TypeDefinitionRow extends SymbolRow {
+  superclass: string | null;
+  interfaces: string[] | null;
+  typeParameters: string[] | null;
+  supertraits: string[] | null;
+  whereClause: string | null;
+  attributes: string[] | null;
+}
+
+/**
+ * Raw row returned by the trait impls query
+ */
+interface TraitImplRow {
+  selfType: string;
+  traitName: string;
+  filePath: string;
+  line: number;
+  typeParameters: string[] | null;
+  whereClause: string[] | null;
+  isNegative: boolean | null;
+}
+
+/**
+ * CALLS edge row returned by the call sites query
+ */
+interface CallSiteRow extends SymbolRow {
+  calleeId: string;
+  line: number | null;
+  callSiteLines: number[] | null;
+  callSiteArguments: string[] | null;
+}
+
+/**
+ * Extensions tried, in order, when resolving an extensionless local import
+ */
+const RESOLVABLE_EXTENSIONS = [
+  ".ts",
+  ".tsx",
+  ".mts",
+  ".cts",
+  ".js",
+  ".jsx",
+  ".mjs",
+  ".cjs",
+  ".py",
+] as const;
+
+/**
+ * Index files tried when a local import points at a directory
+ */
+const INDEX_FILES = ["index.ts", "index.tsx", "index.js", "index.jsx", "__init__.py"] as const;
+
+/**
+ * Raw row returned by the local import edges query
+ */
+interface LocalImportRow {
+  source: string;
+  module: string;
+  importType: string | null;
+  line: number | null;
+}
+
+/**
+ * Hierarchy and call graph queries of GraphAnalysisService
+ */
+export class HierarchyQueries extends GraphAnalysisQueries {
+  async executeModuleCyclesQuery(query: ValidatedModuleCyclesQuery): Promise<ModuleCyclesResult> {
+    const edges = await this.getFileImportEdges(query.repository);
+    const graphEdges = query.include_type_only ? edges : edges.filter((e) => !e.type_only);
+
+    const graph: AdjacencyMap = new Map();
+    const selfImports: ModuleImportEdge[] = [];
+    for (const edge of graphEdges) {
+      const successors = graph.get(edge.from) ?? new Set<string>();
+      graph.set(edge.from, successors);
+      if (!graph.has(edge.to)) graph.set(edge.to, new Set());
+      if (edge.from === edge.to) {
+        selfImports.push(edge);
+        continue;
+      }
+      successors.add(edge.to);
+    }
+
+    const cycles: ModuleCycle[] = findStronglyConnectedComponents(graph)
+      .filter((component) => component.length > 1)
+      .map((component) => {
+        const members = new Set(component);
+        const cycleEdges = graphEdges
+          .filter((e) => e.from !== e.to && members.has(e.from) && members.has(e.to))
+          .sort((a, b) => a.from.localeCompare(b.from) || (a.line ?? 0) - (b.line ?? 0));
+        return {
+          kind: component.length === 2 ? ("mutual" as const) : ("cycle" as const),
+          runtime: cycleEdges.some((e) => !e.type_only),
+          files: [...component].sort(),
+          edges: cycleEdges,
+        };
+      })
+      .sort(
+        (a, b) =>
+          b.files.length - a.files.length || (a.files[0] ?? "").localeCompare(b.files[0] ?? "")
+      );
+
+    return {
+      repository: query.repository,
+      cycles: cycles.slice(0, query.limit),
+      self_imports: selfImports,
+      metadata: {
+        files_analyzed: graph.size,
+        edges_analyzed: graphEdges.length,
+        cycle_count: cycles.length,
+        truncated: cycles.length > query.limit,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeTopologicalOrderQuery(
+    query: ValidatedTopologicalOrderQuery
+  ): Promise<TopologicalOrderResult> {
+    const normalizedPath = query.path
+      ?.replace(/\\/g, "/")
+      .replace(/^\.\//, "")
+      .replace(/\/+$/, "");
+
+    const rows = await this.graphAdapter.runQuery<SymbolRow>(
+      `
+      MATCH (e:Function {repository: $repository})
+      WHERE $path IS NULL OR e.filePath = $path OR e.filePath STARTS WITH $dirPrefix
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine
+      `,
+      {
+        repository: query.repository,
+        path: normalizedPath ?? null,
+        dirPrefix: normalizedPath !== undefined ? `${normalizedPath}/` : null,
+      }
+    );
+    const locations = new Map(rows.map((row) => [row.id, this.toSymbolLocation(row)]));
+
+    const graph: AdjacencyMap = new Map();
+    for (const id of locations.keys()) graph.set(id, new Set());
+    let edgeCount = 0;
+    for (const edge of await this.getCallEdges(query.repository)) {
+      const callees = graph.get(edge.fromId);
+      if (!callees || !graph.has(edge.toId) || callees.has(edge.toId)) continue;
+      callees.add(edge.toId);
+      edgeCount++;
+    }
+
+    const compareLocations = (a: string, b: string): number => {
+      const left = locations.get(a);
+      const right = locations.get(b);
+      return (
+        (left?.file_path ?? "").localeCompare(right?.file_path ?? "") ||
+        (left?.start_line ?? 0) - (right?.start_line ?? 0) ||
+        a.localeCompare(b)
+      );
+    };
+    const groups = findTopologicalOrder(graph, compareLocations).map((group) => ({
+      level: group.level,
+      cyclic: group.nodes.length > 1,
+      symbols: group.nodes.flatMap((id) => locations.get(id) ?? []),
+    }));
+
+    // The limit applies to functions; a group is returned whole or not at all
+    const returned: typeof groups = [];
+    let returnedCount = 0;
+    for (const group of groups) {
+      if (returnedCount + group.symbols.length > query.limit) break;
+      returned.push(group);
+      returnedCount += group.symbols.length;
+    }
+
+    return {
+      repository: query.repository,
+      groups: returned,
+      metadata: {
+        symbol_count: locations.size,
+        group_count: groups.length,
+        cyclic_group_count: groups.filter((group) => group.cyclic).length,
+        edge_count: edgeCount,
+        truncated: returned.length < groups.length,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeCallSitesQuery(query: ValidatedCallSitesQuery): Promise<CallSitesResult> {
+    const functions = (await this.resolveSymbols(query.symbol, query.repository)).filter(
+      (symbol) => symbol.id.startsWith("Function:")
+    );
+    if (functions.length === 0) {
+      throw new EntityNotFoundError("function", query.symbol, query.repository);
+    }
+
+    const rows = await this.graphAdapter.runQuery<CallSiteRow>(
+      `
+      MATCH (caller:Function)-[c:CALLS]->(e:Function)
+      WHERE e.id IN $ids
+      RETURN e.id AS calleeId,
+             caller.id AS id,
+             caller.name AS name,
+             caller.entityType AS entityType,
+             caller.filePath AS filePath,
+             caller.startLine AS startLine,
+             caller.endLine AS endLine,
+             c.line AS line,
+             c.callSiteLines AS callSiteLines,
+             c.callSiteArguments AS callSiteArguments
+      `,
+      { ids: functions.map((symbol) => symbol.id) }
+    );
+
+    let argumentsCaptured = false;
+    const sites: CallSiteMatch[] = [];
+    for (const row of rows) {
+      const caller = this.toSymbolLocation(row);
+      if (row.callSiteLines === null || row.callSiteArguments === null) {
+        if (query.argument_contains === undefined) {
+          sites.push({
+            caller,
+            callee_id: row.calleeId,
+            line: Number(row.line ?? caller.start_line),
+            arguments: null,
+          });
+        }
+        continue;
+      }
+
+      argumentsCaptured = true;
+      row.callSiteLines.forEach((line, i) => {
+        const args = this.parseCallSiteArguments(row.callSiteArguments?.[i]);
+        const filter = query.argument_contains;
+        if (filter === undefined || args.some((text) => text.includes(filter))) {
+          sites.push({ caller, callee_id: row.calleeId, line: Number(line), arguments: args });
+        }
+      });
+    }
+
+    sites.sort((a, b) => a.caller.file_path.localeCompare(b.caller.file_path) || a.line - b.line);
+
+    return {
+      symbol: query.symbol,
+      repository: query.repository,
+      call_sites: sites.slice(0, query.limit),
+      metadata: {
+        callee_count: functions.length,
+        total_count: sites.length,
+        arguments_captured: argumentsCaptured,
+        truncated: sites.length > query.limit,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  /**
+   * Decode the JSON-encoded argument list stored for one call site
+   */
+  private parseCallSiteArguments(encoded: string | undefined): string[] {
+    try {
+      const parsed: unknown = JSON.parse(encoded ?? "[]");
+      return Array.isArray(parsed) ? parsed.map(String) : [];
+    } catch {
+      return [];
+    }
+  }
+
+  async executeCallPathQuery(query: ValidatedCallPathQuery): Promise<CallPathResult> {
+    const resolveFunctions = async (reference: string): Promise<SymbolLocation[]> => {
+      const functions = (await this.resolveSymbols(reference, query.repository)).filter((symbol) =>
+        symbol.id.startsWith("Function:")
+      );
+      if (functions.length === 0) {
+        throw new EntityNotFoundError("function", reference, query.repository);
+      }
+      return functions;
+    };
+    const sources = await resolveFunctions(query.from);
+    const targets = await resolveFunctions(query.to);
+
+    const graph: AdjacencyMap = new Map();
+    for (const edge of await this.getCallEdges(query.repository)) {
+      const callees = graph.get(edge.fromId) ?? new Set<string>();
+      callees.add(edge.toId);
+      graph.set(edge.fromId, callees);
+    }
+
+    const { paths, truncated } = findShortestPaths(
+      graph,
+      sources.map((symbol) => symbol.id),
+      new Set(targets.map((symbol) => symbol.id)),
+      query.max_depth,
+      query.limit
+    );
+
+    const locations = await this.getFunctionLocations([...new Set(paths.flat())]);
+
+    return {
+      from: query.from,
+      to: query.to,
+      repository: query.repository,
+      paths: paths.map((ids) => ({
+        length: ids.length - 1,
+        functions: ids.map(
+          (id) =>
+            locations.get(id) ??
+            this.toSymbolLocation({
+              id,
+              name: this.parseSymbolReference(id).name,
+              entityType: null,
+              filePath: "",
+              startLine: null,
+              endLine: null,
+            })
+        ),
+      })),
+      metadata: {
+        path_count: paths.length,
+        truncated,
+        max_depth: query.max_depth,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeTypeHierarchyQuery(
+    query: ValidatedTypeHierarchyQuery,
+    signal?: AbortSignal
+  ): Promise<TypeHierarchyResult> {
+    const rootIds = (await this.resolveSymbols(query.type_name, query.repository))
+      .filter((symbol) => symbol.id.startsWith("Class:"))
+      .map((symbol) => symbol.id);
+    if (rootIds.length === 0) {
+      throw new EntityNotFoundError("type", query.type_name, query.repository);
+    }
+
+    const types: TypeHierarchyEntry[] = [];
+    const seenNames = new Set<string>();
+    let level = await this.getTypeDefinitions(query.repository, { ids: rootIds });
+    let truncated = false;
+
+    for (let depth = 0; level.length > 0; depth++) {
+      if (signal?.aborted) {
+        throw new GraphServiceCancelledError("getTypeHierarchy");
+      }
+      for (const row of level) seenNames.add(row.name);
+      const impls = await this.getTraitImpls(query.repository, [
+        ...new Set(level.map((row) => row.name)),
+      ]);
+
+      const entries = level.map((row) => this.toTypeHierarchyEntry(row, depth, impls));
+
+      // Supertypes named at this level that are defined in the repository
+      const supertypeNames = [
+        ...new Set(
+          entries
+            .flatMap((entry) => [
+              ...(entry.superclass ? [entry.superclass] : []),
+              ...entry.interfaces,
+              ...entry.traits.filter((trait) => !trait.negative),
+              ...entry.supertraits,
+            ])
+            .map((reference) => baseTypeName(reference.name))
+            .filter((name): name is string => name !== undefined && !seenNames.has(name))
+        ),
+      ];
+      const next =
+        supertypeNames.length > 0
+          ? await this.getTypeDefinitions(query.repository, { names: supertypeNames })
+          : [];
+
+      // Link references to the definitions found at any level so far
+      const idsByName = new Map<string, string[]>();
+      for (const row of [...types, ...entries, ...next]) {
+        idsByName.set(row.name, [...(idsByName.get(row.name) ?? []), row.id]);
+      }
+      const link = (reference: TypeReference): void => {
+        const name = baseTypeName(reference.name);
+        reference.definition_ids = name !== undefined ? [...new Set(idsByName.get(name))] : [];
+      };
+      for (const entry of entries) {
+        if (entry.superclass) link(entry.superclass);
+        entry.interfaces.forEach(link);
+        entry.traits.forEach(link);
+        entry.supertraits.forEach(link);
+      }
+      types.push(...entries);
+
+      if (depth >= query.max_depth) {
+        truncated = next.length > 0;
+        break;
+      }
+      level = next;
+    }
+
+    const definitionCount = types.filter((entry) => entry.depth === 0).length;
+    return {
+      type_name: query.type_name,
+      repository: query.repository,
+      types,
+      metadata: {
+        definition_count: definitionCount,
+        supertype_count: types.length - definitionCount,
+        max_depth: query.max_depth,
+        truncated,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  /**
+   * Look up type definitions with their hierarchy properties, by node ID or
+   * by name
+   */
+  private async getTypeDefinitions(
+    repository: string,
+    filter: { ids?: string[]; names?: string[] }
+  ): Promise<TypeDefinitionRow[]> {
+    return this.graphAdapter.runQuery<TypeDefinitionRow>(
+      `
+      MATCH (t:Class {repository: $repository})
+      WHERE t.id IN $ids OR t.name IN $names
+      RETURN t.id AS id,
+             t.name AS name,
+             t.entityType AS entityType,
+             t.filePath AS filePath,
+             t.startLine AS startLine,
+             t.endLine AS endLine,
+             t.superclass AS superclass,
+             t.interfaces AS interfaces,
+             t.typeParameters AS typeParameters,
+             t.supertraits AS supertraits,
+             t.whereClause AS whereClause,
+             t.attributes AS attributes
+      ORDER BY filePath, startLine
+      `,
+      { repository, ids: filter.ids ?? [], names: filter.names ?? [] }
+    );
+  }
+
+  /**
+   * Look up the trait impls of the named types
+   *
+   * Impls are matched on the implementing type's base name, so types
+   * sharing a name share their impls.
+   */
+  private async getTraitImpls(repository: string, names: string[]): Promise<TraitImplRow[]> {
+    return this.graphAdapter.runQuery<TraitImplRow>(
+      `
+      MATCH (f:File {repository: $repository})-[:HAS_IMPL]->(i:Impl)
+      WHERE i.selfType IN $names AND i.traitName IS NOT NULL
+      RETURN i.selfType AS selfType,
+             i.traitName AS traitName,
+             i.filePath AS filePath,
+             i.line AS line,
+             i.typeParameters AS typeParameters,
+             i.whereClause AS whereClause,
+             i.isNegative AS isNegative
+      ORDER BY filePath, line
+      `,
+      { repository, names }
+    );
+  }
+
+  /**
+   * Build a hierarchy entry from a type definition and the repository's
+   * trait impls; reference definition IDs are linked by the caller
+   */
+  private toTypeHierarchyEntry(
+    row: TypeDefinitionRow,
+    depth: number,
+    impls: TraitImplRow[]
+  ): TypeHierarchyEntry {
+    const reference = (name: string): TypeReference => ({ name, definition_ids: [] });
+    const traits = impls
+      .filter((impl) => impl.selfType === row.name)
+      .map(
+        (impl): TraitImplementation => ({
+          ...reference(impl.traitName),
+          file_path: impl.filePath,
+          line: Number(impl.line),
+          conditions: parseGenericBounds(impl.typeParameters ?? [], impl.whereClause ?? []).filter(
+            (bound) => bound.bounds.length > 0
+          ),
+          negative: impl.isNegative === true,
+        })
+      );
+
+    return {
+      ...this.toSymbolLocation(row),
+      depth,
+      superclass: row.superclass ? reference(row.superclass) : null,
+      interfaces: splitSupertypes(row.interfaces ?? []).map(reference),
+      traits,
+      supertraits: (row.supertraits ?? []).map(reference),
+      derives: derivedTraits(row.attributes ?? []),
+      generic_bounds: parseGenericBounds(row.typeParameters ?? [], row.whereClause ?? undefined),
+    };
+  }
+
+  /**
+   * Load the repository's local IMPORTS edges resolved to file-to-file edges
+   *
+   * Imports whose specifier does not resolve to a file of the repository
+   * (missing files, path aliases, language-qualified paths) are skipped.
+   */
+  private async getFileImportEdges(repository: string): Promise<ModuleImportEdge[]> {
+    const fileRows = await this.graphAdapter.runQuery<{ path: string }>(
+      `
+      MATCH (f:File {repository: $repository})
+      RETURN f.path AS path
+      `,
+      { repository }
+    );
+    const knownFiles = new Set(fileRows.map((row) => row.path));
+
+    const importRows = await this.graphAdapter.runQuery<LocalImportRow>(
+      `
+      MATCH (f:File {repository: $repository})-[r:IMPORTS]->(m:Module {type: 'local'})
+      RETURN f.path AS source,
+             m.name AS module,
+             r.importType AS importType,
+             r.line AS line
+      ORDER BY source, line
+      `,
+      { repository }
+    );
+
+    const edges: ModuleImportEdge[] = [];
+    for (const row of importRows) {
+      const target = this.resolveLocalImport(row.source, row.module, knownFiles);
+      if (target) {
+        edges.push({
+          from: row.source,
+          to: target,
+          module: row.module,
+          line: row.line ?? null,
+          type_only: row.importType === "type",
+        });
+      }
+    }
+    return edges;
+  }
+
+  /**
+   * Resolve a relative module specifier to a repository file path
+   *
+   * Handles path specifiers (`./utils`, `../lib/index.js`) with extension and
+   * index-file probing, TypeScript's `.js`-for-`.ts` convention, and Python
+   * relative module specifiers (`.utils`, `..pkg.mod`).
+   *
+   * @returns Repository-relative file path, or null if no known file matches
+   */
+  private resolveLocalImport(
+    sourceFile: string,
+    specifier: string,
+    knownFiles: Set<string>
+  ): string | null {
+    const sourceDir = path.posix.dirname(sourceFile);
+
+    let base: string;
+    const pythonRelative = /^(\.+)([A-Za-z_][\w.]*)?$/.exec(specifier);
+    if (pythonRelative) {
+      // ".mod" is the current package, each extra dot goes up one level
+      const levelsUp = (pythonRelative[1] ?? ".").length - 1;
+      const modulePath = (pythonRelative[2] ?? "").replace(/\./g, "/");
+      base = path.posix.join(sourceDir, ...Array<string>(levelsUp).fill(".."), modulePath);
+    } else {
+      base = path.posix.join(sourceDir, specifier);
+    }
+    base = path.posix.normalize(base).replace(/^\.\//, "");
+
+    const candidates = [base];
+    const jsExtension = /\.(m|c)?jsx?$/.exec(base);
+    if (jsExtension) {
+      const stem = base.substring(0, jsExtension.index);
+      const tsExtension = `.${jsExtension[1] ?? ""}ts${base.endsWith("x") ? "x" : ""}`;
+      candidates.push(`${stem}${tsExtension}`);
+    }
+    candidates.push(...RESOLVABLE_EXTENSIONS.map((ext) => `${base}${ext}`));
+    candidates.push(...INDEX_FILES.map((file) => path.posix.join(base, file)));
+
+    return candidates.find((candidate) => knownFiles.has(candidate)) ?? null;
+  }
+}
@@ -0,0 +1,329 @@
+/**
+ * @module services/graph-analysis-imports-types
+ *
+ * Type definitions for the GraphAnalysisService import and dependency queries.
+ */
+
+import type { SymbolLocation } from "./graph-analysis-types.js";
+
+// =============================================================================
+// Symbol Imports
+// =============================================================================
+
+/**
+ * Query for the imports in scope for a symbol
+ */
+export interface SymbolImportsQuery {
+  /**
+   * Symbol reference: a plain name (`validateToken`), a qualified name
+   * (`crate::auth::validate_token`), a file-qualified name
+   * (`src/auth.ts::validateToken`) or a graph node ID.
+   */
+  symbol: string;
+
+  /** Repository containing the symbol */
+  repository: string;
+
+  /** Optional file path to disambiguate symbols defined in several files */
+  file_path?: string;
+}
+
+/**
+ * An alias introduced by an import statement
+ */
+export interface ImportAlias {
+  /** Name as exported by the source module (`default` / `*` for default/namespace imports) */
+  original: string;
+
+  /** Local name the import is bound to */
+  alias: string;
+}
+
+/**
+ * A single import statement of a file
+ */
+export interface ImportRecord {
+  /** Module specifier as written in source (e.g., "./utils", "std::io::Read") */
+  module: string;
+
+  /** Whether the module is external to the repository (package/crate) */
+  is_external: boolean;
+
+  /** Resolved repository-relative path for internal imports, when resolvable */
+  resolved_path: string | null;
+
+  /** Import kind recorded at ingestion (named, default, namespace, type, value, side-effect) */
+  import_type: string;
+
+  /** Names imported from the module */
+  imported_symbols: string[];
+
+  /** Aliases introduced by the import */
+  aliases: ImportAlias[];
+
+  /** Line of the import statement (1-based), when known */
+  line: number | null;
+}
+
+/**
+ * Imports of one file defining the requested symbol
+ */
+export interface SymbolFileImports {
+  /** The matching symbol definition */
+  symbol: SymbolLocation;
+
+  /** Import statements of the symbol's file, ordered by line */
+  imports: ImportRecord[];
+}
+
+/**
+ * Result of a symbol imports query
+ *
+ * A symbol name may match definitions in several files; each match is
+ * returned with its own file's imports.
+ */
+export interface SymbolImportsResult {
+  /** Symbol reference as requested */
+  symbol: string;
+
+  /** Repository searched */
+  repository: string;
+
+  /** One entry per matching symbol definition */
+  matches: SymbolFileImports[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of matching symbol definitions */
+    match_count: number;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// External Dependencies
+// =============================================================================
+
+/**
+ * Query for the external crates a Rust repository imports
+ */
+export interface ExternalDependenciesQuery {
+  /** Repository to inspect */
+  repository: string;
+
+  /**
+   * Whether to list standard library crates (`std`, `core`, `alloc`, ...)
+   * @default true
+   */
+  include_std?: boolean;
+}
+
+/**
+ * An external crate with the imports referring to it
+ */
+export interface ExternalDependency {
+  /** Crate name as used in paths (e.g., "serde", "std") */
+  name: string;
+
+  /** Whether the crate ships with the toolchain */
+  is_std: boolean;
+
+  /** Number of imported paths rooted at the crate, across all files */
+  reference_count: number;
+
+  /** Number of files importing from the crate */
+  file_count: number;
+
+  /** Distinct imported paths, sorted */
+  paths: string[];
+}
+
+/**
+ * Result of an external dependencies query
+ */
+export interface ExternalDependenciesResult {
+  /** Repository inspected */
+  repository: string;
+
+  /** External crates, most referenced first */
+  dependencies: ExternalDependency[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of standard library crates found (listed only when included) */
+    std_count: number;
+    /** Number of third-party crates listed */
+    third_party_count: number;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Import Suggestions
+// =============================================================================
+
+/**
+ * Query for the `use` declarations a Rust file needs to name a symbol
+ */
+export interface ImportSuggestionQuery {
+  /** Symbol name, qualified name, or graph node ID */
+  symbol: string;
+
+  /** Repository to search */
+  repository: string;
+
+  /** Rust file that will use the symbol (e.g., "src/render/canvas.rs") */
+  from_path: string;
+}
+
+/**
+ * Whether a symbol can be named from a file
+ *
+ * - `in_scope`: defined (or re-exported) in the file's own module
+ * - `importable`: visible, after adding the suggested `use` declarations
+ * - `inaccessible`: private or restricted to modules the file is not in
+ */
+export type ImportStatus = "in_scope" | "importable" | "inaccessible";
+
+/**
+ * One `use` declaration to add
+ */
+export interface ImportStatement {
+  /** Name the declaration brings into scope */
+  name: string;
+
+  /** Path imported (e.g., "crate::geometry::Point") */
+  path: string;
+
+  /** Declaration to add (e.g., "use crate::geometry::Point;") */
+  use_statement: string;
+
+  /** File of the `pub use` the path goes through, or null for the defining module */
+  via_reexport: string | null;
+}
+
+/**
+ * Imports needed for one definition of the queried symbol
+ */
+export interface SymbolImportSuggestion {
+  /** Definition of the symbol */
+  symbol: SymbolLocation;
+
+  /** Qualified path of the definition (e.g., "crate::geometry::Point::new") */
+  qualified_name: string;
+
+  /** Visibility as written (e.g., "pub(crate)"), or null for private items */
+  visibility: string | null;
+
+  /** Whether the symbol can be named from the file */
+  status: ImportStatus;
+
+  /**
+   * Declarations to add, shortest path first. Methods need their type, and
+   * trait methods the trait, in scope.
+   */
+  imports: ImportStatement[];
+
+  /** Why the symbol is inaccessible, or null */
+  reason: string | null;
+}
+
+/**
+ * Result of an import suggestion query
+ */
+export interface ImportSuggestionResult {
+  /** Symbol as queried */
+  symbol: string;
+
+  /** Repository searched */
+  repository: string;
+
+  /** File the imports are for */
+  from_path: string;
+
+  /** Module of the file (e.g., "crate::render::canvas") */
+  from_module: string;
+
+  /** One suggestion per definition the symbol resolved to */
+  suggestions: SymbolImportSuggestion[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of definitions the symbol resolved to */
+    total_count: number;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Leaky API
+// =============================================================================
+
+/**
+ * Query for public Rust functions whose signatures name non-public types
+ */
+export interface LeakyApiQuery {
+  /** Repository to search */
+  repository: string;
+
+  /** Restrict to a file or directory (relative to the repository root) */
+  path?: string;
+
+  /**
+   * Maximum number of leaks to return
+   * @default 100
+   */
+  limit?: number;
+}
+
+/**
+ * A non-public type named in the signature of a public function
+ */
+export interface ApiLeak {
+  /** Public function or method */
+  item: SymbolLocation;
+
+  /** Qualified path of the function (e.g., "crate::geometry::Shape::area") */
+  qualified_name: string;
+
+  /** Parameter whose type names the private type, or null for the return type */
+  parameter: string | null;
+
+  /** Parameter or return type as written (e.g., "&[Handle]") */
+  type_annotation: string;
+
+  /** Definition of the type */
+  private_type: SymbolLocation;
+
+  /** Qualified path of the type (e.g., "crate::geometry::Handle") */
+  private_type_qualified_name: string;
+
+  /** Visibility of the type as written (e.g., "pub(crate)"), or null for private types */
+  private_type_visibility: string | null;
+}
+
+/**
+ * Result of a leaky API query
+ */
+export interface LeakyApiResult {
+  /** Repository searched */
+  repository: string;
+
+  /** Leaks ordered by function location, parameters before the return type */
+  leaks: ApiLeak[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of public functions checked */
+    functions_checked: number;
+    /** Number of leaks found, before the limit */
+    total_count: number;
+    /** Whether the results were truncated by the limit */
+    truncated: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
@@ -0,0 +1,679 @@
+/**
+ * @module services/graph-analysis-imports
+ *
+ * Import queries of GraphAnalysisService: the imports in scope for a symbol,
+ * external dependencies, import suggestions and public API items leaking
+ * private types.
+ */
+
+import type {
+  ValidatedSymbolImportsQuery,
+  ValidatedExternalDependenciesQuery,
+  ValidatedImportSuggestionQuery,
+  ValidatedLeakyApiQuery,
+} from "./graph-analysis-validation.js";
+import { referencedTypeNames } from "../graph/parsing/type-matching.js";
+import { isRustVisibleFrom } from "../graph/parsing/rust-visibility.js";
+import { baseTypeName, rustModulePath } from "../graph/ingestion/call-resolution.js";
+import { rustCrateIdentifier } from "../graph/ingestion/crate-roots.js";
+import { EntityNotFoundError } from "./graph-service-errors.js";
+import type {
+  SymbolImportsResult,
+  ImportRecord,
+  ImportAlias,
+  ExternalDependenciesResult,
+  ExternalDependency,
+  ImportSuggestionResult,
+  ImportStatement,
+  SymbolImportSuggestion,
+  LeakyApiResult,
+  ApiLeak,
+} from "./graph-analysis-types.js";
+import {
+  GraphAnalysisQueries,
+  ENTITY_LABELS,
+  type NameCandidateRow,
+} from "./graph-analysis-queries.js";
+
+/**
+ * Crates distributed with the Rust toolchain
+ */
+const RUST_STD_CRATES = new Set(["std", "core", "alloc", "proc_macro", "test"]);
+
+/**
+ * Import row returned by the external dependencies query
+ */
+interface ExternalImportRow {
+  filePath: string;
+  module: string;
+}
+
+/**
+ * Rust item row returned by the import suggestion queries
+ */
+interface ImportTargetRow extends NameCandidateRow {
+  visibility: string | null;
+  isExported: boolean | null;
+  implementedTrait: string | null;
+}
+
+/**
+ * `pub use` edge row returned by the import suggestion query
+ */
+interface ReexportRow {
+  filePath: string;
+  crateName: string | null;
+  source: string;
+  importType: string | null;
+  aliases: string[] | null;
+  visibility: string;
+}
+
+/**
+ * Public Rust function row returned by the leaky API query
+ */
+interface PublicFunctionRow extends ImportTargetRow {
+  paramNames: string[] | null;
+  paramTypes: string[] | null;
+  returnType: string | null;
+}
+
+/**
+ * Raw import row returned by the imports query
+ */
+interface ImportRow {
+  module: string;
+  moduleType: string | null;
+  importType: string | null;
+  importedSymbols: string[] | null;
+  aliases: string[] | null;
+  resolvedPath: string | null;
+  line: number | null;
+}
+
+/**
+ * Import and API surface queries of GraphAnalysisService
+ */
+export class ImportQueries extends GraphAnalysisQueries {
+  async executeSymbolImportsQuery(
+    query: ValidatedSymbolImportsQuery
+  ): Promise<SymbolImportsResult> {
+    const symbols = await this.resolveSymbols(query.symbol, query.repository, query.file_path);
+    if (symbols.length === 0) {
+      throw new EntityNotFoundError("symbol", query.symbol, query.repository);
+    }
+
+    // Several definitions can live in the same file; fetch each file once
+    const importsByFile = new Map<string, ImportRecord[]>();
+    for (const symbol of symbols) {
+      if (!importsByFile.has(symbol.file_path)) {
+        importsByFile.set(
+          symbol.file_path,
+          await this.getFileImports(query.repository, symbol.file_path)
+        );
+      }
+    }
+
+    const matches = symbols.map((symbol) => ({
+      symbol,
+      imports: importsByFile.get(symbol.file_path) ?? [],
+    }));
+
+    return {
+      symbol: query.symbol,
+      repository: query.repository,
+      matches,
+      metadata: {
+        match_count: matches.length,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeExternalDependenciesQuery(
+    query: ValidatedExternalDependenciesQuery
+  ): Promise<ExternalDependenciesResult> {
+    const params = { repository: query.repository };
+
+    const importRows = await this.graphAdapter.runQuery<ExternalImportRow>(
+      `
+      MATCH (f:File {repository: $repository})-[:IMPORTS]->(m:Module)
+      WHERE f.path ENDS WITH '.rs'
+      RETURN f.path AS filePath, m.name AS module
+      `,
+      params
+    );
+
+    const fileRows = await this.graphAdapter.runQuery<{ path: string }>(
+      `
+      MATCH (f:File {repository: $repository})
+      WHERE f.path ENDS WITH '.rs'
+      RETURN f.path AS path
+      `,
+      params
+    );
+    const repositoryRows = await this.graphAdapter.runQuery<{ crateNames: string[] | null }>(
+      `
+      MATCH (r:Repository {name: $repository})
+      RETURN r.crateNames AS crateNames
+      `,
+      params
+    );
+
+    const internalRoots = new Set(["crate", "self", "super"]);
+    for (const name of repositoryRows[0]?.crateNames ?? []) {
+      internalRoots.add(rustCrateIdentifier(name));
+    }
+    for (const row of fileRows) {
+      const [module] = rustModulePath(row.path);
+      if (module) internalRoots.add(module);
+    }
+
+    const importsByRoot = new Map<string, ExternalImportRow[]>();
+    for (const row of importRows) {
+      const root = row.module.replace(/^::/, "").split("::")[0] ?? "";
+      if (root === "" || internalRoots.has(root)) continue;
+      const imports = importsByRoot.get(root) ?? [];
+      imports.push(row);
+      importsByRoot.set(root, imports);
+    }
+
+    // Roots naming an indexed symbol (e.g. `use Shape::Circle` of a local
+    // enum) resolve within the repository
+    const symbolRows =
+      importsByRoot.size === 0
+        ? []
+        : await this.graphAdapter.runQuery<{ name: string }>(
+            `
+            MATCH (e)
+            WHERE (${ENTITY_LABELS.map((label) => `e:${label}`).join(" OR ")})
+              AND e.repository = $repository AND e.name IN $roots
+            RETURN DISTINCT e.name AS name
+            `,
+            { ...params, roots: [...importsByRoot.keys()] }
+          );
+    for (const row of symbolRows) {
+      importsByRoot.delete(row.name);
+    }
+
+    const dependencies: ExternalDependency[] = [...importsByRoot].map(([name, imports]) => ({
+      name,
+      is_std: RUST_STD_CRATES.has(name),
+      reference_count: imports.length,
+      file_count: new Set(imports.map((row) => row.filePath)).size,
+      paths: [...new Set(imports.map((row) => row.module.replace(/^::/, "")))].sort(),
+    }));
+    const stdCount = dependencies.filter((dependency) => dependency.is_std).length;
+    const listed = dependencies
+      .filter((dependency) => query.include_std || !dependency.is_std)
+      .sort((a, b) => b.reference_count - a.reference_count || a.name.localeCompare(b.name));
+
+    return {
+      repository: query.repository,
+      dependencies: listed,
+      metadata: {
+        std_count: stdCount,
+        third_party_count: dependencies.length - stdCount,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeImportSuggestionQuery(
+    query: ValidatedImportSuggestionQuery
+  ): Promise<ImportSuggestionResult> {
+    const fromPath = query.from_path.replace(/\\/g, "/").replace(/^\.\//, "");
+    const callerRows = await this.graphAdapter.runQuery<{ crateName: string | null }>(
+      `
+      MATCH (f:File {repository: $repository, path: $path})
+      RETURN f.crateName AS crateName
+      `,
+      { repository: query.repository, path: fromPath }
+    );
+    const [callerFile] = callerRows;
+    if (!callerFile) {
+      throw new EntityNotFoundError("file", fromPath, query.repository);
+    }
+    const caller = { module: rustModulePath(fromPath), crateName: callerFile.crateName };
+
+    const resolved = (await this.resolveSymbols(query.symbol, query.repository)).filter(
+      (symbol) => symbol.file_path.endsWith(".rs")
+    );
+    if (resolved.length === 0) {
+      throw new EntityNotFoundError("symbol", query.symbol, query.repository);
+    }
+
+    const targets = await this.queryImportTargets(query.repository, "e.id IN $ids", {
+      ids: resolved.map((symbol) => symbol.id),
+    });
+    const ownerNames = new Set<string>();
+    for (const target of targets) {
+      for (const owner of [target.parentName, target.implementedTrait]) {
+        const name = owner !== null ? baseTypeName(owner) : undefined;
+        if (name !== undefined) ownerNames.add(name);
+      }
+    }
+    const owners =
+      ownerNames.size > 0
+        ? await this.queryImportTargets(query.repository, "e:Class AND e.name IN $names", {
+            names: [...ownerNames],
+          })
+        : [];
+
+    const reexports = await this.graphAdapter.runQuery<ReexportRow>(
+      `
+      MATCH (f:File {repository: $repository})-[r:IMPORTS]->(m:Module)
+      WHERE r.visibility IS NOT NULL AND f.path ENDS WITH '.rs'
+      RETURN f.path AS filePath,
+             f.crateName AS crateName,
+             m.name AS source,
+             r.importType AS importType,
+             r.aliases AS aliases,
+             r.visibility AS visibility
+      ORDER BY filePath
+      `,
+      { repository: query.repository }
+    );
+
+    const suggestions = targets.map((target) =>
+      this.suggestRustImport(target, owners, caller, reexports)
+    );
+
+    return {
+      symbol: query.symbol,
+      repository: query.repository,
+      from_path: fromPath,
+      from_module: ["crate", ...caller.module].join("::"),
+      suggestions,
+      metadata: {
+        total_count: suggestions.length,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  /**
+   * Fetch Rust items with the properties needed to import them
+   */
+  private async queryImportTargets(
+    repository: string,
+    condition: string,
+    params: Record<string, unknown>
+  ): Promise<ImportTargetRow[]> {
+    return this.graphAdapter.runQuery<ImportTargetRow>(
+      `
+      MATCH (e {repository: $repository})
+      WHERE ${condition} AND e.filePath ENDS WITH '.rs'
+      OPTIONAL MATCH (f:File {repository: $repository, path: e.filePath})
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.kind AS kind,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine,
+             e.parentName AS parentName,
+             e.visibility AS visibility,
+             e.isExported AS isExported,
+             e.implementedTrait AS implementedTrait,
+             f.crateName AS crateName
+      ORDER BY filePath, startLine
+      `,
+      { repository, ...params }
+    );
+  }
+
+  /**
+   * Work out the imports one definition needs: the item itself, or for a
+   * method its type (and trait, for trait methods) after checking the method
+   * is visible.
+   */
+  private suggestRustImport(
+    target: ImportTargetRow,
+    owners: ImportTargetRow[],
+    caller: { module: string[]; crateName: string | null },
+    reexports: ReexportRow[]
+  ): SymbolImportSuggestion {
+    const items: ImportTargetRow[] = [];
+    let reason: string | null = null;
+
+    if (target.parentName === null) {
+      items.push(target);
+    } else {
+      const findOwner = (name: string | undefined): ImportTargetRow | undefined => {
+        const candidates = owners.filter((owner) => owner.name === name);
+        return (
+          candidates.find((owner) => owner.filePath === target.filePath) ??
+          candidates.find((owner) => owner.crateName === target.crateName) ??
+          candidates[0]
+        );
+      };
+      const type = findOwner(baseTypeName(target.parentName));
+      const trait =
+        target.implementedTrait !== null
+          ? findOwner(baseTypeName(target.implementedTrait))
+          : undefined;
+      if (type) items.push(type);
+      if (trait) items.push(trait);
+
+      // Trait items take the visibility of the trait
+      if (target.implementedTrait === null && type?.kind !== "interface") {
+        reason = this.rustVisibilityProblem(target, caller);
+      }
+    }
+
+    const imports: ImportStatement[] = [];
+    for (const item of items) {
+      if (reason !== null) break;
+      const plan = this.planRustImport(item, caller, reexports);
+      if (typeof plan === "string") {
+        reason = plan;
+      } else if (plan !== null) {
+        imports.push(plan);
+      }
+    }
+
+    return {
+      symbol: this.toSymbolLocation(target),
+      qualified_name: this.qualifiedName(target),
+      visibility: this.effectiveRustVisibility(target) ?? null,
+      status: reason !== null ? "inaccessible" : imports.length > 0 ? "importable" : "in_scope",
+      imports: reason !== null ? [] : imports,
+      reason,
+    };
+  }
+
+  /**
+   * Shortest visible path to an item from the caller's module
+   *
+   * @returns The declaration to add, null when the item is already in scope,
+   *   or why the item cannot be named
+   */
+  private planRustImport(
+    item: ImportTargetRow,
+    caller: { module: string[]; crateName: string | null },
+    reexports: ReexportRow[]
+  ): ImportStatement | string | null {
+    const sameCrate = item.crateName === caller.crateName;
+    const itemModule = rustModulePath(item.filePath);
+    const visible = (visibility: string | undefined, module: string[]): boolean =>
+      sameCrate ? isRustVisibleFrom(visibility, module, caller.module) : visibility === "pub";
+
+    const paths: { module: string[]; name: string; viaReexport: string | null }[] = [];
+    if (visible(this.effectiveRustVisibility(item), itemModule)) {
+      paths.push({ module: itemModule, name: item.name, viaReexport: null });
+    }
+    for (const reexport of reexports) {
+      if (reexport.crateName !== item.crateName) continue;
+      const module = rustModulePath(reexport.filePath);
+      const source = this.resolveRustUsePath(reexport.source, module);
+      // Glob re-exports (`pub use shapes::*`) name the module rather than the item
+      const glob = reexport.importType === "namespace";
+      const expected = glob ? itemModule : [...itemModule, item.name];
+      if (source.join("::") !== expected.join("::") || !visible(reexport.visibility, module)) {
+        continue;
+      }
+      const alias = glob
+        ? undefined
+        : reexport.aliases?.find((entry) => entry.startsWith(`${item.name} as `));
+      const name = alias !== undefined ? alias.slice(item.name.length + 4) : item.name;
+      paths.push({ module, name, viaReexport: reexport.filePath });
+    }
+
+    if (paths.length === 0) {
+      return this.rustVisibilityProblem(item, caller) ?? `\`${item.name}\` cannot be named here`;
+    }
+    if (sameCrate && paths.some((path) => path.module.join("::") === caller.module.join("::"))) {
+      return null;
+    }
+
+    const root = sameCrate ? "crate" : item.crateName ? rustCrateIdentifier(item.crateName) : null;
+    if (root === null) {
+      return `\`${item.name}\` is defined outside a named crate and cannot be imported here`;
+    }
+    // The defining module comes first, so it wins ties with re-exports
+    const best = paths.reduce((a, b) => (b.module.length < a.module.length ? b : a));
+    const path = [root, ...best.module, best.name].join("::");
+    return { name: best.name, path, use_statement: `use ${path};`, via_reexport: best.viaReexport };
+  }
+
+  /**
+   * Why an item is not visible from the caller's module, or null when it is
+   */
+  private rustVisibilityProblem(
+    item: ImportTargetRow,
+    caller: { module: string[]; crateName: string | null }
+  ): string | null {
+    const visibility = this.effectiveRustVisibility(item);
+    const itemModule = rustModulePath(item.filePath);
+    const name = item.parentName !== null ? `${item.parentName}::${item.name}` : item.name;
+
+    if (item.crateName !== caller.crateName) {
+      return visibility === "pub"
+        ? null
+        : `\`${name}\` is ${visibility ?? "private"} and not visible outside its crate`;
+    }
+    if (isRustVisibleFrom(visibility, itemModule, caller.module)) {
+      return null;
+    }
+    const defined = ["crate", ...itemModule].join("::");
+    const from = ["crate", ...caller.module].join("::");
+    return `\`${name}\` is ${visibility ?? "private"} in ${defined} and not visible from ${from}`;
+  }
+
+  /**
+   * Visibility of a Rust item; graphs indexed before visibility was recorded
+   * only know whether the item is `pub`.
+   */
+  private effectiveRustVisibility(item: ImportTargetRow): string | undefined {
+    if (item.visibility !== null) return item.visibility;
+    return item.isExported ? "pub" : undefined;
+  }
+
+  /**
+   * Module path (below the crate root) a `use` path in a module refers to:
+   * `crate::`, `self::` and `super::` paths as written, and 2018-edition
+   * paths relative to the module
+   */
+  private resolveRustUsePath(source: string, module: string[]): string[] {
+    const segments = source.split("::");
+    if (segments[0] === "crate") {
+      return segments.slice(1);
+    }
+    const resolved = [...module];
+    let i = 0;
+    for (; segments[i] === "self" || segments[i] === "super"; i++) {
+      if (segments[i] === "super") resolved.pop();
+    }
+    return [...resolved, ...segments.slice(i)];
+  }
+
+  async executeLeakyApiQuery(query: ValidatedLeakyApiQuery): Promise<LeakyApiResult> {
+    const normalizedPath = query.path
+      ?.replace(/\\/g, "/")
+      .replace(/^\.\//, "")
+      .replace(/\/+$/, "");
+
+    const functions = await this.graphAdapter.runQuery<PublicFunctionRow>(
+      `
+      MATCH (e:Function {repository: $repository})
+      WHERE e.filePath ENDS WITH '.rs'
+        AND e.implementedTrait IS NULL
+        AND (e.visibility = 'pub' OR (e.visibility IS NULL AND e.isExported = true))
+        AND ($path IS NULL OR e.filePath = $path OR e.filePath STARTS WITH $dirPrefix)
+      OPTIONAL MATCH (f:File {repository: $repository, path: e.filePath})
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.kind AS kind,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine,
+             e.parentName AS parentName,
+             e.visibility AS visibility,
+             e.isExported AS isExported,
+             e.implementedTrait AS implementedTrait,
+             e.paramNames AS paramNames,
+             e.paramTypes AS paramTypes,
+             e.returnType AS returnType,
+             f.crateName AS crateName
+      ORDER BY filePath, startLine
+      `,
+      {
+        repository: query.repository,
+        path: normalizedPath ?? null,
+        dirPrefix: normalizedPath !== undefined ? `${normalizedPath}/` : null,
+      }
+    );
+
+    const typeNames = new Set<string>();
+    for (const fn of functions) {
+      const owner = fn.parentName !== null ? baseTypeName(fn.parentName) : undefined;
+      if (owner !== undefined) typeNames.add(owner);
+      for (const { annotation } of this.signatureAnnotations(fn)) {
+        for (const reference of referencedTypeNames(annotation)) {
+          typeNames.add(reference.split("::").pop() ?? reference);
+        }
+      }
+    }
+    const types =
+      typeNames.size > 0
+        ? await this.queryImportTargets(query.repository, "e:Class AND e.name IN $names", {
+            names: [...typeNames],
+          })
+        : [];
+
+    let functionsChecked = 0;
+    const leaks: ApiLeak[] = [];
+    for (const fn of functions) {
+      const owner = fn.parentName !== null ? baseTypeName(fn.parentName) : undefined;
+      const ownerType =
+        owner !== undefined ? this.resolveRustTypeReference(owner, fn, types) : undefined;
+      if (ownerType && this.effectiveRustVisibility(ownerType) !== "pub") {
+        continue;
+      }
+      functionsChecked++;
+
+      for (const { parameter, annotation } of this.signatureAnnotations(fn)) {
+        for (const reference of referencedTypeNames(annotation)) {
+          const type = this.resolveRustTypeReference(reference, fn, types);
+          if (!type || this.effectiveRustVisibility(type) === "pub") continue;
+          leaks.push({
+            item: this.toSymbolLocation(fn),
+            qualified_name: this.qualifiedName(fn),
+            parameter,
+            type_annotation: annotation,
+            private_type: this.toSymbolLocation(type),
+            private_type_qualified_name: this.qualifiedName(type),
+            private_type_visibility: this.effectiveRustVisibility(type) ?? null,
+          });
+        }
+      }
+    }
+
+    return {
+      repository: query.repository,
+      leaks: leaks.slice(0, query.limit),
+      metadata: {
+        functions_checked: functionsChecked,
+        total_count: leaks.length,
+        truncated: leaks.length > query.limit,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  /**
+   * Parameter and return type annotations of a function, parameters first;
+   * the return type has a null parameter name
+   */
+  private signatureAnnotations(
+    fn: PublicFunctionRow
+  ): Array<{ parameter: string | null; annotation: string }> {
+    const annotations = (fn.paramNames ?? []).map(
+      (name, i): { parameter: string | null; annotation: string } => ({
+        parameter: name,
+        annotation: fn.paramTypes?.[i] ?? "",
+      })
+    );
+    if (fn.returnType !== null) {
+      annotations.push({ parameter: null, annotation: fn.returnType });
+    }
+    return annotations;
+  }
+
+  /**
+   * Type of the function's crate a type name in its signature refers to,
+   * or undefined when it is not one (or cannot be told apart)
+   */
+  private resolveRustTypeReference(
+    reference: string,
+    fn: ImportTargetRow,
+    types: ImportTargetRow[]
+  ): ImportTargetRow | undefined {
+    const segments = reference.split("::");
+    const name = segments.pop();
+    if (name === "Self") {
+      return undefined;
+    }
+    const fnModule = rustModulePath(fn.filePath);
+    const candidates = types.filter(
+      (type) => type.name === name && type.crateName === fn.crateName
+    );
+
+    if (segments.length > 0) {
+      const module = this.resolveRustUsePath(segments.join("::"), fnModule).join("::");
+      return candidates.find((type) => rustModulePath(type.filePath).join("::") === module);
+    }
+    const visible = candidates.filter((type) =>
+      isRustVisibleFrom(
+        this.effectiveRustVisibility(type),
+        rustModulePath(type.filePath),
+        fnModule
+      )
+    );
+    const [only] = visible;
+    return (
+      visible.find((type) => type.filePath === fn.filePath) ??
+      (visible.length === 1 ? only : undefined)
+    );
+  }
+
+  /**
+   * Fetch the IMPORTS edges of a file
+   */
+  private async getFileImports(repository: string, filePath: string): Promise<ImportRecord[]> {
+    const rows = await this.graphAdapter.runQuery<ImportRow>(
+      `
+      MATCH (f:File {repository: $repository, path: $filePath})-[r:IMPORTS]->(m:Module)
+      RETURN m.name AS module,
+             m.type AS moduleType,
+             r.importType AS importType,
+             r.importedSymbols AS importedSymbols,
+             r.aliases AS aliases,
+             r.resolvedPath AS resolvedPath,
+             r.line AS line
+      ORDER BY r.line, m.name
+      `,
+      { repository, filePath }
+    );
+
+    return rows.map((row) => ({
+      module: row.module,
+      is_external: row.moduleType !== "local",
+      resolved_path: row.resolvedPath ?? null,
+      import_type: row.importType ?? "named",
+      imported_symbols: row.importedSymbols ?? [],
+      aliases: (row.aliases ?? []).map((entry) => this.parseAlias(entry)),
+      line: row.line ?? null,
+    }));
+  }
+
+  /**
+   * Parse an `"original as alias"` string written by GraphIngestionService
+   */
+  private parseAlias(entry: string): ImportAlias {
+    const [original, alias] = entry.split(" as ");
+    return { original: original ?? entry, alias: alias ?? original ?? entry };
+  }
+}
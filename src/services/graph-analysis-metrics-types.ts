@@ -0,0 +1,698 @@
+/**
+ * @module services/graph-analysis-metrics-types
+ *
+ * Type definitions for the GraphAnalysisService metric and hotspot queries.
+ */
+
+import type { SymbolLocation } from "./graph-analysis-types.js";
+
+// =============================================================================
+// Deprecation
+// =============================================================================
+
+/**
+ * Query for the deprecated symbols of a repository and their callers
+ */
+export interface DeprecationReportQuery {
+  /** Repository to report on */
+  repository: string;
+
+  /** File path, or directory path to cover every file beneath it */
+  path?: string;
+
+  /**
+   * Maximum number of deprecated symbols to return
+   * @default 100
+   */
+  limit?: number;
+}
+
+/**
+ * A call to a deprecated symbol
+ */
+export interface DeprecationCallSite {
+  /** Function containing the call */
+  caller: SymbolLocation;
+
+  /** Line of the first call within the caller (1-based), when recorded */
+  line: number | null;
+
+  /** Number of calls from this caller */
+  call_count: number;
+}
+
+/**
+ * A symbol marked `#[deprecated]` with the calls still referencing it
+ */
+export interface DeprecatedSymbol extends SymbolLocation {
+  /** The deprecation attribute as written inside `#[...]` */
+  attribute: string;
+
+  /** `note` argument (or `#[deprecated = "..."]` value), when given */
+  message: string | null;
+
+  /** `since` argument, when given */
+  since: string | null;
+
+  /** Calls referencing the symbol, ordered by caller location */
+  call_sites: DeprecationCallSite[];
+
+  /**
+   * Whether references to this symbol are tracked. Only calls are recorded
+   * in the graph, so deprecated types and constants report no call sites
+   * even when they are used.
+   */
+  references_tracked: boolean;
+
+  /** True for functions with no remaining callers in the repository */
+  safe_to_remove: boolean;
+}
+
+/**
+ * Result of a deprecation report
+ */
+export interface DeprecationReportResult {
+  /** Repository reported on */
+  repository: string;
+
+  /** Deprecated symbols, those with the most call sites first */
+  symbols: DeprecatedSymbol[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of deprecated symbols, before the limit */
+    total_deprecated: number;
+    /** Deprecated symbols with at least one call site */
+    in_use_count: number;
+    /** Deprecated functions with no call sites */
+    safe_to_remove_count: number;
+    /** Whether the limit cut off deprecated symbols */
+    truncated: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Unsafe Code
+// =============================================================================
+
+/**
+ * Query for the unsafe code of a repository
+ */
+export interface UnsafeCodeQuery {
+  /** Repository to list unsafe code for */
+  repository: string;
+
+  /** File path, or directory path to cover every file beneath it */
+  path?: string;
+
+  /**
+   * Maximum number of symbols, and separately of impls, to return
+   * @default 200
+   */
+  limit?: number;
+}
+
+/**
+ * Line range of an `unsafe { ... }` block
+ */
+export interface UnsafeBlockSpan {
+  /** First line of the block (1-based) */
+  start_line: number;
+
+  /** Last line of the block (1-based) */
+  end_line: number;
+}
+
+/**
+ * A function declared `unsafe`, an `unsafe trait`, or a function containing
+ * `unsafe` blocks
+ */
+export interface UnsafeSymbol extends SymbolLocation {
+  /** Whether the symbol itself is declared `unsafe` */
+  is_unsafe: boolean;
+
+  /** `unsafe { ... }` blocks in the function body, in source order */
+  unsafe_blocks: UnsafeBlockSpan[];
+}
+
+/**
+ * An `unsafe impl` block (e.g. `unsafe impl Send for Handle`)
+ */
+export interface UnsafeImpl {
+  /** Implemented trait as written */
+  trait_name: string | null;
+
+  /** Implementing type as written */
+  type_name: string;
+
+  /** File containing the impl, relative to the repository root */
+  file_path: string;
+
+  /** Line where the impl starts (1-based) */
+  line: number;
+}
+
+/**
+ * Result of an unsafe code listing query
+ */
+export interface UnsafeCodeResult {
+  /** Repository searched */
+  repository: string;
+
+  /** Unsafe symbols ordered by file path and line */
+  symbols: UnsafeSymbol[];
+
+  /** Unsafe impls ordered by file path and line */
+  impls: UnsafeImpl[];
+
+  /** Query metadata */
+  metadata: {
+    /** Symbols declared `unsafe`, before the limit */
+    unsafe_symbol_count: number;
+    /** `unsafe` blocks across all symbols, before the limit */
+    unsafe_block_count: number;
+    /** `unsafe impl` blocks, before the limit */
+    unsafe_impl_count: number;
+    /** Whether the limit cut off symbols or impls */
+    truncated: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Complexity
+// =============================================================================
+
+/**
+ * Query for the most complex functions of a repository
+ */
+export interface ComplexityQuery {
+  /** Repository to rank */
+  repository: string;
+
+  /**
+   * Minimum complexity a function needs to be listed
+   * @default 10
+   */
+  threshold?: number;
+
+  /** File path, or directory path to cover every file beneath it */
+  path?: string;
+
+  /**
+   * Maximum number of functions to return
+   * @default 50
+   */
+  limit?: number;
+}
+
+/**
+ * A function with its approximate cyclomatic complexity
+ */
+export interface ComplexSymbol extends SymbolLocation {
+  /** 1 plus the number of branch points in the body (see `graph/parsing/complexity`) */
+  complexity: number;
+}
+
+/**
+ * Result of a complexity query
+ */
+export interface ComplexityResult {
+  /** Repository ranked */
+  repository: string;
+
+  /** Threshold applied */
+  threshold: number;
+
+  /** Functions at or above the threshold, most complex first */
+  functions: ComplexSymbol[];
+
+  /** Query metadata */
+  metadata: {
+    /** Functions at or above the threshold, before the limit */
+    total_count: number;
+    /** Whether the limit cut off functions */
+    truncated: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Missing Docs
+// =============================================================================
+
+/**
+ * Query for the public symbols of a repository that have no documentation
+ */
+export interface MissingDocsQuery {
+  /** Repository to report on */
+  repository: string;
+
+  /** File path, or directory path to cover every file beneath it */
+  path?: string;
+
+  /**
+   * Symbols intentionally left undocumented, each given as a name, a
+   * qualified name (`Point::new`), a file-qualified name (`src/a.rs::foo`) or
+   * a graph node ID
+   */
+  allow?: string[];
+
+  /**
+   * Maximum number of undocumented symbols to return
+   * @default 200
+   */
+  limit?: number;
+}
+
+/**
+ * Undocumented public symbols of one module
+ */
+export interface MissingDocsModule {
+  /** File declaring the symbols, relative to the repository root */
+  module: string;
+
+  /** Public symbols in the module, excluding allowlisted ones */
+  public_count: number;
+
+  /** Public symbols in the module that are documented */
+  documented_count: number;
+
+  /** Undocumented public symbols, ordered by line */
+  symbols: SymbolLocation[];
+}
+
+/**
+ * Result of a missing docs query
+ */
+export interface MissingDocsResult {
+  /** Repository reported on */
+  repository: string;
+
+  /** Modules with undocumented public symbols, ordered by path */
+  modules: MissingDocsModule[];
+
+  /** Query metadata */
+  metadata: {
+    /** Public symbols in scope, excluding allowlisted ones */
+    public_count: number;
+    /** Public symbols in scope that are documented */
+    documented_count: number;
+    /** Undocumented public symbols, before the limit */
+    missing_count: number;
+    /** Undocumented symbols skipped because they are allowlisted */
+    allowed_count: number;
+    /** Share of public symbols that are documented (0 to 1; 1 when there are none) */
+    coverage: number;
+    /** Whether the limit cut off symbols */
+    truncated: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Large Types
+// =============================================================================
+
+/**
+ * Query for types that have accumulated many methods across their impls
+ */
+export interface LargeTypesQuery {
+  /** Repository to search */
+  repository: string;
+
+  /**
+   * Minimum member count a type needs to be listed
+   * @default 20
+   */
+  method_threshold?: number;
+
+  /**
+   * Whether associated consts and types count toward the member count
+   * @default false
+   */
+  include_associated_items?: boolean;
+
+  /**
+   * Sort direction on the member count
+   * @default "desc"
+   */
+  order?: "asc" | "desc";
+
+  /**
+   * Maximum number of types to return
+   * @default 50
+   */
+  limit?: number;
+}
+
+/**
+ * An impl block of a large type
+ */
+export interface ImplBlockSummary {
+  /** File containing the impl */
+  file_path: string;
+
+  /** Line of the `impl` keyword (1-based) */
+  start_line: number;
+
+  /** Last line of the impl; null when the graph predates impl extents */
+  end_line: number | null;
+
+  /** Implemented trait as written; null for inherent impls */
+  trait_name: string | null;
+
+  /** Methods defined in this impl */
+  method_count: number;
+
+  /** Associated consts and types defined in this impl */
+  associated_item_count: number;
+}
+
+/**
+ * A type with the members of all its impls counted
+ */
+export interface LargeType {
+  /** Type name (impls are matched on the implementing type's base name) */
+  name: string;
+
+  /** Count compared against the threshold and sorted on */
+  member_count: number;
+
+  /** Methods across all impls, inherent and trait */
+  method_count: number;
+
+  /** Associated consts and types across all impls */
+  associated_item_count: number;
+
+  /** Impl blocks of the type, by file and line */
+  impls: ImplBlockSummary[];
+}
+
+/**
+ * Result of a large types query
+ */
+export interface LargeTypesResult {
+  /** Repository searched */
+  repository: string;
+
+  /** Threshold applied */
+  method_threshold: number;
+
+  /** Types at or above the threshold, in the requested order */
+  types: LargeType[];
+
+  /** Query metadata */
+  metadata: {
+    /** Types at or above the threshold, before the limit */
+    total_count: number;
+    /** Whether the limit cut off types */
+    truncated: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Call Graph Coupling
+// =============================================================================
+
+/**
+ * Query for the call-graph coupling of a function
+ */
+export interface CouplingMetricsQuery {
+  /** Function reference, in any form accepted by SymbolImportsQuery.symbol */
+  symbol: string;
+
+  /** Repository containing the function */
+  repository: string;
+
+  /** Optional file path to disambiguate functions defined in several files */
+  file_path?: string;
+}
+
+/**
+ * Call-graph coupling of one function or method
+ */
+export interface FunctionCouplingMetrics extends SymbolLocation {
+  /** Number of distinct functions calling this one (self-calls excluded) */
+  fan_in: number;
+
+  /** Number of distinct functions this one calls (self-calls excluded) */
+  fan_out: number;
+
+  /** Combined degree, fan_in + fan_out */
+  coupling: number;
+
+  /** Whether the function calls itself directly */
+  recursive: boolean;
+
+  /** Whether the function is on a call cycle, including direct recursion */
+  in_cycle: boolean;
+}
+
+/**
+ * Result of a coupling metrics query
+ */
+export interface CouplingMetricsResult {
+  /** Function reference as given */
+  symbol: string;
+
+  /** Repository searched */
+  repository: string;
+
+  /** Metrics of each matching definition, ordered by file path and line */
+  matches: FunctionCouplingMetrics[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of matching definitions */
+    match_count: number;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+/**
+ * Query for the most coupled functions of a repository
+ */
+export interface MostCoupledQuery {
+  /** Repository to rank */
+  repository: string;
+
+  /** Only rank functions in this file or beneath this directory */
+  path?: string;
+
+  /**
+   * Maximum number of functions to return
+   * @default 20
+   */
+  limit?: number;
+}
+
+/**
+ * Result of a most-coupled ranking
+ */
+export interface MostCoupledResult {
+  /** Repository ranked */
+  repository: string;
+
+  /** Functions with at least one call edge, by descending coupling */
+  functions: FunctionCouplingMetrics[];
+
+  /** Query metadata */
+  metadata: {
+    /** Functions in scope with at least one call edge, before the limit */
+    total_count: number;
+    /** CALLS relationships in the repository */
+    call_edge_count: number;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+/**
+ * Query for the most referenced symbols of a repository
+ */
+export interface HotspotsQuery {
+  /** Repository to rank */
+  repository: string;
+
+  /**
+   * Maximum number of symbols to return
+   * @default 20
+   */
+  limit?: number;
+}
+
+/**
+ * A symbol with its incoming references
+ */
+export interface Hotspot extends SymbolLocation {
+  /** Incoming references, caller_count + importer_count */
+  fan_in: number;
+
+  /** Number of distinct functions calling this symbol (self-calls excluded) */
+  caller_count: number;
+
+  /** Number of distinct files importing this symbol by name */
+  importer_count: number;
+
+  /** Files of the callers and importers other than the symbol's own, sorted */
+  referencing_modules: string[];
+}
+
+/**
+ * Result of a hotspots ranking
+ */
+export interface HotspotsResult {
+  /** Repository ranked */
+  repository: string;
+
+  /** Symbols with at least one incoming reference, by descending fan-in */
+  hotspots: Hotspot[];
+
+  /** Query metadata */
+  metadata: {
+    /** Symbols with at least one incoming reference, before the limit */
+    total_count: number;
+    /** CALLS relationships in the repository */
+    call_edge_count: number;
+    /** Named imports resolved to a file of the repository */
+    import_reference_count: number;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+/**
+ * What counts as a module in a coupling report
+ *
+ * - `file`: each file is a module (a Rust module, a TypeScript ES module)
+ * - `directory`: files in the same directory form one module (a package)
+ */
+export type CouplingGranularity = "file" | "directory";
+
+/**
+ * Module metric a coupling report is sorted by
+ */
+export type CouplingReportSort = "instability" | "afferent" | "efferent" | "module";
+
+/**
+ * Query for the afferent/efferent coupling of the modules of a repository
+ */
+export interface CouplingReportQuery {
+  /** Repository to analyze */
+  repository: string;
+
+  /**
+   * What counts as a module
+   * @default "file"
+   */
+  granularity?: CouplingGranularity;
+
+  /** Only report modules in this file or beneath this directory */
+  path?: string;
+
+  /**
+   * Metric to sort modules by
+   * @default "instability"
+   */
+  sort_by?: CouplingReportSort;
+
+  /**
+   * Sort direction
+   * @default "desc"
+   */
+  order?: "asc" | "desc";
+
+  /**
+   * Maximum number of modules and of module pairs to return
+   * @default 50
+   */
+  limit?: number;
+}
+
+/**
+ * Coupling metrics of one module
+ *
+ * Dependencies are cross-module CALLS edges between functions and IMPORTS
+ * edges resolved to a file of the repository.
+ */
+export interface ModuleCoupling {
+  /** File path, or directory path for directory granularity ("." for the root) */
+  module: string;
+
+  /** Afferent coupling (Ca): number of other modules depending on this one */
+  afferent: number;
+
+  /** Efferent coupling (Ce): number of other modules this one depends on */
+  efferent: number;
+
+  /**
+   * Instability I = Ce / (Ce + Ca), rounded to three decimals: 0 for a
+   * module others only depend on, 1 for one that only depends on others
+   */
+  instability: number;
+
+  /** Call and import edges from other modules into this one */
+  incoming_edges: number;
+
+  /** Call and import edges from this module to other modules */
+  outgoing_edges: number;
+}
+
+/**
+ * Dependency edges from one module to another
+ */
+export interface ModuleCouplingPair {
+  /** Depending module */
+  from: string;
+
+  /** Module depended on */
+  to: string;
+
+  /** Calls from functions of `from` to functions of `to` */
+  calls: number;
+
+  /** Imports in `from` resolved to a file of `to` */
+  imports: number;
+}
+
+/**
+ * Result of a coupling report
+ */
+export interface CouplingReportResult {
+  /** Repository analyzed */
+  repository: string;
+
+  /** What counts as a module */
+  granularity: CouplingGranularity;
+
+  /** Modules with at least one cross-module dependency, in the requested order */
+  modules: ModuleCoupling[];
+
+  /** Module pairs with at least one dependency, most edges first */
+  pairs: ModuleCouplingPair[];
+
+  /** Query metadata */
+  metadata: {
+    /** Modules in scope with at least one cross-module dependency, before the limit */
+    module_count: number;
+    /** Module pairs touching a module in scope, before the limit */
+    pair_count: number;
+    /** CALLS relationships between functions of different modules */
+    cross_module_calls: number;
+    /** Resolved imports between files of different modules */
+    cross_module_imports: number;
+    /** Whether the limit cut off modules or pairs */
+    truncated: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
@@ -0,0 +1,944 @@
+/**
+ * @module services/graph-analysis-metrics
+ *
+ * Metrics queries of GraphAnalysisService: call graph coupling, hotspots,
+ * module coupling reports, complexity and type size rankings, and the
+ * unsafe code, missing docs and deprecation reports.
+ */
+
+import path from "node:path";
+import type {
+  ValidatedCouplingMetricsQuery,
+  ValidatedMostCoupledQuery,
+  ValidatedHotspotsQuery,
+  ValidatedCouplingReportQuery,
+  ValidatedDeprecationReportQuery,
+  ValidatedUnsafeCodeQuery,
+  ValidatedComplexityQuery,
+  ValidatedMissingDocsQuery,
+  ValidatedLargeTypesQuery,
+} from "./graph-analysis-validation.js";
+import { findStronglyConnectedComponents, type AdjacencyMap } from "./graph-algorithms.js";
+import { EntityNotFoundError } from "./graph-service-errors.js";
+import type {
+  SymbolLocation,
+  DeprecationReportResult,
+  DeprecatedSymbol,
+  DeprecationCallSite,
+  UnsafeCodeResult,
+  UnsafeSymbol,
+  ComplexityResult,
+  MissingDocsResult,
+  MissingDocsModule,
+  LargeTypesResult,
+  LargeType,
+  ImplBlockSummary,
+  CouplingMetricsResult,
+  MostCoupledResult,
+  HotspotsResult,
+  CouplingReportResult,
+  ModuleCoupling,
+  ModuleCouplingPair,
+  Hotspot,
+  FunctionCouplingMetrics,
+} from "./graph-analysis-types.js";
+import {
+  GraphAnalysisQueries,
+  ENTITY_LABELS,
+  type SymbolRow,
+  type CallEdgeRow,
+} from "./graph-analysis-queries.js";
+
+/**
+ * Raw row returned by the deprecated symbols query
+ */
+interface DeprecatedRow extends SymbolRow {
+  label: string;
+  attributes: string[];
+}
+
+/**
+ * Raw row returned by the deprecated symbol call sites query
+ */
+interface DeprecationCallSiteRow extends SymbolRow {
+  targetId: string;
+  line: number | null;
+  callCount: number | null;
+}
+
+/**
+ * Raw row returned by the unsafe symbols query
+ */
+interface UnsafeSymbolRow extends SymbolRow {
+  isUnsafe: boolean | null;
+  unsafeBlockStarts: number[] | null;
+  unsafeBlockEnds: number[] | null;
+}
+
+/**
+ * Raw row returned by the complexity query
+ */
+interface ComplexSymbolRow extends SymbolRow {
+  complexity: number;
+}
+
+/**
+ * Raw row returned by the public symbols query of the missing docs report
+ */
+interface PublicSymbolRow extends SymbolRow {
+  parentName: string | null;
+  attributes: string[] | null;
+  documented: boolean;
+}
+
+/**
+ * Raw row returned by the unsafe impls query
+ */
+interface UnsafeImplRow {
+  typeName: string;
+  traitName: string | null;
+  filePath: string;
+  line: number;
+}
+
+/**
+ * Impl block row returned by the large types query
+ */
+interface ImplBlockRow {
+  selfType: string;
+  traitName: string | null;
+  filePath: string;
+  line: number;
+  endLine: number | null;
+}
+
+/**
+ * Impl item row returned by the large types query
+ */
+interface ImplMemberRow {
+  parentName: string;
+  entityType: string;
+  filePath: string;
+  startLine: number;
+}
+
+/**
+ * Row shape for an IMPORTS edge resolved to a file of the repository
+ */
+interface ResolvedImportRow {
+  filePath: string;
+  resolvedPath: string;
+  importedSymbols: string[] | null;
+}
+
+/**
+ * Row shape for a call or import edge reduced to the files it connects
+ */
+interface FileEdgeRow {
+  fromFile: string;
+  toFile: string;
+}
+
+/**
+ * Call-graph degree of one function, before it is joined with its location
+ */
+interface CallDegree {
+  fanIn: number;
+  fanOut: number;
+  recursive: boolean;
+  inCycle: boolean;
+}
+
+/**
+ * Metrics and report queries of GraphAnalysisService
+ */
+export class MetricsQueries extends GraphAnalysisQueries {
+  async executeCouplingMetricsQuery(
+    query: ValidatedCouplingMetricsQuery
+  ): Promise<CouplingMetricsResult> {
+    const functions = (
+      await this.resolveSymbols(query.symbol, query.repository, query.file_path)
+    ).filter((symbol) => symbol.id.startsWith("Function:"));
+    if (functions.length === 0) {
+      throw new EntityNotFoundError("function", query.symbol, query.repository);
+    }
+
+    const degrees = this.computeCallDegrees(await this.getCallEdges(query.repository));
+    const matches = functions.map((symbol) =>
+      this.toCouplingMetrics(symbol, degrees.get(symbol.id))
+    );
+
+    return {
+      symbol: query.symbol,
+      repository: query.repository,
+      matches,
+      metadata: {
+        match_count: matches.length,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeMostCoupledQuery(query: ValidatedMostCoupledQuery): Promise<MostCoupledResult> {
+    const normalizedPath = query.path
+      ?.replace(/\\/g, "/")
+      .replace(/^\.\//, "")
+      .replace(/\/+$/, "");
+
+    const edges = await this.getCallEdges(query.repository);
+    const degrees = this.computeCallDegrees(edges);
+
+    const rows = await this.graphAdapter.runQuery<SymbolRow>(
+      `
+      MATCH (e:Function {repository: $repository})
+      WHERE e.id IN $ids
+        AND ($path IS NULL OR e.filePath = $path OR e.filePath STARTS WITH $dirPrefix)
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine
+      `,
+      {
+        repository: query.repository,
+        ids: [...degrees.keys()],
+        path: normalizedPath ?? null,
+        dirPrefix: normalizedPath !== undefined ? `${normalizedPath}/` : null,
+      }
+    );
+
+    const ranked = rows
+      .map((row) => this.toCouplingMetrics(this.toSymbolLocation(row), degrees.get(row.id)))
+      .sort(
+        (a, b) =>
+          b.coupling - a.coupling ||
+          b.fan_in - a.fan_in ||
+          a.name.localeCompare(b.name) ||
+          a.file_path.localeCompare(b.file_path)
+      );
+
+    return {
+      repository: query.repository,
+      functions: ranked.slice(0, query.limit),
+      metadata: {
+        total_count: ranked.length,
+        call_edge_count: edges.length,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeHotspotsQuery(query: ValidatedHotspotsQuery): Promise<HotspotsResult> {
+    // Callers of each function, self-calls excluded
+    const edges = await this.getCallEdges(query.repository);
+    const callers = new Map<string, Set<string>>();
+    for (const edge of edges) {
+      if (edge.fromId === edge.toId) continue;
+      const calledBy = callers.get(edge.toId) ?? new Set<string>();
+      calledBy.add(edge.fromId);
+      callers.set(edge.toId, calledBy);
+    }
+    const callerIds = [...callers.values()].flatMap((ids) => [...ids]);
+    const functions = await this.getFunctionLocations([
+      ...new Set([...callers.keys(), ...callerIds]),
+    ]);
+
+    // Files importing each (defining file, name) pair
+    const importRows = await this.graphAdapter.runQuery<ResolvedImportRow>(
+      `
+      MATCH (f:File {repository: $repository})-[r:IMPORTS]->(:Module)
+      WHERE r.resolvedPath IS NOT NULL
+      RETURN f.path AS filePath,
+             r.resolvedPath AS resolvedPath,
+             r.importedSymbols AS importedSymbols
+      `,
+      { repository: query.repository }
+    );
+    const importKey = (filePath: string, name: string): string => `${filePath}#${name}`;
+    const importers = new Map<string, Set<string>>();
+    let importReferenceCount = 0;
+    for (const row of importRows) {
+      for (const name of row.importedSymbols ?? []) {
+        const key = importKey(row.resolvedPath, name);
+        const files = importers.get(key) ?? new Set<string>();
+        files.add(row.filePath);
+        importers.set(key, files);
+        importReferenceCount++;
+      }
+    }
+
+    // Top-level symbols the imports name; methods cannot be imported
+    const importedRows =
+      importers.size === 0
+        ? []
+        : await this.graphAdapter.runQuery<SymbolRow>(
+            `
+            MATCH (e {repository: $repository})
+            WHERE (${ENTITY_LABELS.map((label) => `e:${label}`).join(" OR ")})
+              AND e.filePath IN $paths
+              AND e.name IN $names
+              AND e.parentName IS NULL
+            RETURN e.id AS id,
+                   e.name AS name,
+                   e.entityType AS entityType,
+                   e.filePath AS filePath,
+                   e.startLine AS startLine,
+                   e.endLine AS endLine
+            `,
+            {
+              repository: query.repository,
+              paths: [...new Set(importRows.map((row) => row.resolvedPath))],
+              names: [...new Set(importRows.flatMap((row) => row.importedSymbols ?? []))],
+            }
+          );
+
+    const symbols = new Map<string, SymbolLocation>();
+    for (const id of callers.keys()) {
+      const location = functions.get(id);
+      if (location) symbols.set(id, location);
+    }
+    for (const row of importedRows) {
+      if (importers.has(importKey(row.filePath, row.name))) {
+        symbols.set(row.id, this.toSymbolLocation(row));
+      }
+    }
+
+    const ranked = [...symbols.values()]
+      .map((symbol): Hotspot => {
+        const symbolCallers = callers.get(symbol.id) ?? new Set<string>();
+        const importingFiles = importers.get(importKey(symbol.file_path, symbol.name));
+        const modules = new Set(importingFiles);
+        for (const callerId of symbolCallers) {
+          const callerFile = functions.get(callerId)?.file_path;
+          if (callerFile) modules.add(callerFile);
+        }
+        modules.delete(symbol.file_path);
+        return {
+          ...symbol,
+          fan_in: symbolCallers.size + (importingFiles?.size ?? 0),
+          caller_count: symbolCallers.size,
+          importer_count: importingFiles?.size ?? 0,
+          referencing_modules: [...modules].sort(),
+        };
+      })
+      .sort(
+        (a, b) =>
+          b.fan_in - a.fan_in ||
+          b.referencing_modules.length - a.referencing_modules.length ||
+          a.name.localeCompare(b.name) ||
+          a.file_path.localeCompare(b.file_path)
+      );
+
+    return {
+      repository: query.repository,
+      hotspots: ranked.slice(0, query.limit),
+      metadata: {
+        total_count: ranked.length,
+        call_edge_count: edges.length,
+        import_reference_count: importReferenceCount,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeCouplingReportQuery(
+    query: ValidatedCouplingReportQuery
+  ): Promise<CouplingReportResult> {
+    const normalizedPath = query.path
+      ?.replace(/\\/g, "/")
+      .replace(/^\.\//, "")
+      .replace(/\/+$/, "");
+    const inScope = (module: string): boolean =>
+      normalizedPath === undefined ||
+      module === normalizedPath ||
+      module.startsWith(`${normalizedPath}/`);
+    const moduleOf = (filePath: string): string =>
+      query.granularity === "directory" ? path.posix.dirname(filePath) : filePath;
+
+    const callRows = await this.graphAdapter.runQuery<FileEdgeRow>(
+      `
+      MATCH (caller:Function {repository: $repository})-[:CALLS]->(callee:Function)
+      WHERE callee.repository = $repository
+      RETURN caller.filePath AS fromFile, callee.filePath AS toFile
+      `,
+      { repository: query.repository }
+    );
+    const importRows = await this.graphAdapter.runQuery<FileEdgeRow>(
+      `
+      MATCH (f:File {repository: $repository})-[r:IMPORTS]->(:Module)
+      WHERE r.resolvedPath IS NOT NULL
+      RETURN f.path AS fromFile, r.resolvedPath AS toFile
+      `,
+      { repository: query.repository }
+    );
+
+    // Cross-module edges per ordered module pair
+    const pairs = new Map<string, ModuleCouplingPair>();
+    const countEdges = (rows: FileEdgeRow[], kind: "calls" | "imports"): number => {
+      let crossModule = 0;
+      for (const row of rows) {
+        const from = moduleOf(row.fromFile);
+        const to = moduleOf(row.toFile);
+        if (from === to) continue;
+        const key = `${from}\0${to}`;
+        const pair = pairs.get(key) ?? { from, to, calls: 0, imports: 0 };
+        pair[kind]++;
+        pairs.set(key, pair);
+        crossModule++;
+      }
+      return crossModule;
+    };
+    const crossModuleCalls = countEdges(callRows, "calls");
+    const crossModuleImports = countEdges(importRows, "imports");
+
+    const dependents = new Map<string, Set<string>>();
+    const dependencies = new Map<string, Set<string>>();
+    const incoming = new Map<string, number>();
+    const outgoing = new Map<string, number>();
+    for (const pair of pairs.values()) {
+      const edges = pair.calls + pair.imports;
+      dependencies.set(pair.from, (dependencies.get(pair.from) ?? new Set<string>()).add(pair.to));
+      dependents.set(pair.to, (dependents.get(pair.to) ?? new Set<string>()).add(pair.from));
+      outgoing.set(pair.from, (outgoing.get(pair.from) ?? 0) + edges);
+      incoming.set(pair.to, (incoming.get(pair.to) ?? 0) + edges);
+    }
+
+    const direction = query.order === "asc" ? 1 : -1;
+    const modules: ModuleCoupling[] = [...new Set([...dependents.keys(), ...dependencies.keys()])]
+      .filter(inScope)
+      .map((module) => {
+        const afferent = dependents.get(module)?.size ?? 0;
+        const efferent = dependencies.get(module)?.size ?? 0;
+        return {
+          module,
+          afferent,
+          efferent,
+          instability: Math.round((efferent / (efferent + afferent)) * 1000) / 1000,
+          incoming_edges: incoming.get(module) ?? 0,
+          outgoing_edges: outgoing.get(module) ?? 0,
+        };
+      })
+      .sort((a, b) => {
+        const primary =
+          query.sort_by === "module"
+            ? a.module.localeCompare(b.module)
+            : a[query.sort_by] - b[query.sort_by];
+        return (
+          direction * primary ||
+          b.afferent + b.efferent - (a.afferent + a.efferent) ||
+          a.module.localeCompare(b.module)
+        );
+      });
+
+    const scopedPairs = [...pairs.values()]
+      .filter((pair) => inScope(pair.from) || inScope(pair.to))
+      .sort(
+        (a, b) =>
+          b.calls + b.imports - (a.calls + a.imports) ||
+          a.from.localeCompare(b.from) ||
+          a.to.localeCompare(b.to)
+      );
+
+    return {
+      repository: query.repository,
+      granularity: query.granularity,
+      modules: modules.slice(0, query.limit),
+      pairs: scopedPairs.slice(0, query.limit),
+      metadata: {
+        module_count: modules.length,
+        pair_count: scopedPairs.length,
+        cross_module_calls: crossModuleCalls,
+        cross_module_imports: crossModuleImports,
+        truncated: modules.length > query.limit || scopedPairs.length > query.limit,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  /**
+   * Compute distinct fan-in/fan-out and cycle membership per function
+   *
+   * @param edges - Call edges of the repository
+   * @returns Degrees keyed by node ID, for every function with an edge
+   */
+  private computeCallDegrees(edges: CallEdgeRow[]): Map<string, CallDegree> {
+    const callers = new Map<string, Set<string>>();
+    const graph: AdjacencyMap = new Map();
+    const recursive = new Set<string>();
+
+    for (const edge of edges) {
+      if (!graph.has(edge.fromId)) graph.set(edge.fromId, new Set());
+      if (!graph.has(edge.toId)) graph.set(edge.toId, new Set());
+      if (edge.fromId === edge.toId) {
+        recursive.add(edge.fromId);
+        continue;
+      }
+      graph.get(edge.fromId)?.add(edge.toId);
+      const calledBy = callers.get(edge.toId) ?? new Set<string>();
+      calledBy.add(edge.fromId);
+      callers.set(edge.toId, calledBy);
+    }
+
+    const inCycle = new Set(recursive);
+    for (const component of findStronglyConnectedComponents(graph)) {
+      if (component.length > 1) {
+        for (const id of component) inCycle.add(id);
+      }
+    }
+
+    const degrees = new Map<string, CallDegree>();
+    for (const [id, callees] of graph) {
+      degrees.set(id, {
+        fanIn: callers.get(id)?.size ?? 0,
+        fanOut: callees.size,
+        recursive: recursive.has(id),
+        inCycle: inCycle.has(id),
+      });
+    }
+    return degrees;
+  }
+
+  /**
+   * Join a function's location with its call degree
+   */
+  private toCouplingMetrics(
+    symbol: SymbolLocation,
+    degree: CallDegree | undefined
+  ): FunctionCouplingMetrics {
+    const fanIn = degree?.fanIn ?? 0;
+    const fanOut = degree?.fanOut ?? 0;
+    return {
+      ...symbol,
+      fan_in: fanIn,
+      fan_out: fanOut,
+      coupling: fanIn + fanOut,
+      recursive: degree?.recursive ?? false,
+      in_cycle: degree?.inCycle ?? false,
+    };
+  }
+
+  async executeComplexityQuery(query: ValidatedComplexityQuery): Promise<ComplexityResult> {
+    const normalizedPath = query.path
+      ?.replace(/\\/g, "/")
+      .replace(/^\.\//, "")
+      .replace(/\/+$/, "");
+
+    const rows = await this.graphAdapter.runQuery<ComplexSymbolRow>(
+      `
+      MATCH (e:Function {repository: $repository})
+      WHERE e.complexity >= $threshold
+        AND ($path IS NULL OR e.filePath = $path OR e.filePath STARTS WITH $dirPrefix)
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine,
+             e.complexity AS complexity
+      ORDER BY complexity DESC, filePath, startLine
+      `,
+      {
+        repository: query.repository,
+        threshold: query.threshold,
+        path: normalizedPath ?? null,
+        dirPrefix: normalizedPath !== undefined ? `${normalizedPath}/` : null,
+      }
+    );
+
+    return {
+      repository: query.repository,
+      threshold: query.threshold,
+      functions: rows.slice(0, query.limit).map((row) => ({
+        ...this.toSymbolLocation(row),
+        complexity: Number(row.complexity),
+      })),
+      metadata: {
+        total_count: rows.length,
+        truncated: rows.length > query.limit,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeLargeTypesQuery(query: ValidatedLargeTypesQuery): Promise<LargeTypesResult> {
+    const implRows = await this.graphAdapter.runQuery<ImplBlockRow>(
+      `
+      MATCH (i:Impl {repository: $repository})
+      WHERE i.selfType IS NOT NULL AND coalesce(i.isNegative, false) = false
+      RETURN i.selfType AS selfType,
+             i.traitName AS traitName,
+             i.filePath AS filePath,
+             i.line AS line,
+             i.endLine AS endLine
+      ORDER BY filePath, line
+      `,
+      { repository: query.repository }
+    );
+
+    const memberRows = await this.graphAdapter.runQuery<ImplMemberRow>(
+      `
+      MATCH (e {repository: $repository})
+      WHERE e.parentName IN $names
+        AND e.entityType IN ["function", "method", "variable", "type_alias"]
+      RETURN e.parentName AS parentName,
+             e.entityType AS entityType,
+             e.filePath AS filePath,
+             e.startLine AS startLine
+      `,
+      {
+        repository: query.repository,
+        names: [...new Set(implRows.map((row) => row.selfType))],
+      }
+    );
+
+    // Impls of each type per file, in line order
+    const implsByKey = new Map<string, ImplBlockSummary[]>();
+    const implsByType = new Map<string, ImplBlockSummary[]>();
+    for (const row of implRows) {
+      const impl: ImplBlockSummary = {
+        file_path: row.filePath,
+        start_line: Number(row.line),
+        end_line: row.endLine !== null ? Number(row.endLine) : null,
+        trait_name: row.traitName,
+        method_count: 0,
+        associated_item_count: 0,
+      };
+      const key = `${row.selfType}\0${row.filePath}`;
+      implsByKey.set(key, [...(implsByKey.get(key) ?? []), impl]);
+      implsByType.set(row.selfType, [...(implsByType.get(row.selfType) ?? []), impl]);
+    }
+
+    for (const member of memberRows) {
+      const line = Number(member.startLine);
+      const impl = (implsByKey.get(`${member.parentName}\0${member.filePath}`) ?? [])
+        .filter((candidate) => candidate.start_line <= line)
+        .pop();
+      if (!impl || (impl.end_line !== null && line > impl.end_line)) {
+        continue;
+      }
+      if (member.entityType === "function" || member.entityType === "method") {
+        impl.method_count++;
+      } else {
+        impl.associated_item_count++;
+      }
+    }
+
+    const direction = query.order === "asc" ? 1 : -1;
+    const types = [...implsByType]
+      .map(([name, impls]): LargeType => {
+        const methodCount = impls.reduce((sum, impl) => sum + impl.method_count, 0);
+        const associatedCount = impls.reduce((sum, impl) => sum + impl.associated_item_count, 0);
+        return {
+          name,
+          member_count: methodCount + (query.include_associated_items ? associatedCount : 0),
+          method_count: methodCount,
+          associated_item_count: associatedCount,
+          impls,
+        };
+      })
+      .filter((type) => type.member_count >= query.method_threshold)
+      .sort(
+        (a, b) => direction * (a.member_count - b.member_count) || a.name.localeCompare(b.name)
+      );
+
+    return {
+      repository: query.repository,
+      method_threshold: query.method_threshold,
+      types: types.slice(0, query.limit),
+      metadata: {
+        total_count: types.length,
+        truncated: types.length > query.limit,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeUnsafeCodeQuery(query: ValidatedUnsafeCodeQuery): Promise<UnsafeCodeResult> {
+    const normalizedPath = query.path
+      ?.replace(/\\/g, "/")
+      .replace(/^\.\//, "")
+      .replace(/\/+$/, "");
+    const pathParams = {
+      path: normalizedPath ?? null,
+      dirPrefix: normalizedPath !== undefined ? `${normalizedPath}/` : null,
+    };
+
+    const symbolRows = await this.graphAdapter.runQuery<UnsafeSymbolRow>(
+      `
+      MATCH (e {repository: $repository})
+      WHERE (${ENTITY_LABELS.map((label) => `e:${label}`).join(" OR ")})
+        AND (e.isUnsafe = true OR size(coalesce(e.unsafeBlockStarts, [])) > 0)
+        AND ($path IS NULL OR e.filePath = $path OR e.filePath STARTS WITH $dirPrefix)
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine,
+             e.isUnsafe AS isUnsafe,
+             e.unsafeBlockStarts AS unsafeBlockStarts,
+             e.unsafeBlockEnds AS unsafeBlockEnds
+      ORDER BY filePath, startLine
+      `,
+      { repository: query.repository, ...pathParams }
+    );
+
+    const implRows = await this.graphAdapter.runQuery<UnsafeImplRow>(
+      `
+      MATCH (i:Impl {repository: $repository})
+      WHERE i.isUnsafe = true
+        AND ($path IS NULL OR i.filePath = $path OR i.filePath STARTS WITH $dirPrefix)
+      RETURN i.typeName AS typeName,
+             i.traitName AS traitName,
+             i.filePath AS filePath,
+             i.line AS line
+      ORDER BY filePath, line
+      `,
+      { repository: query.repository, ...pathParams }
+    );
+
+    const symbols = symbolRows.map((row): UnsafeSymbol => {
+      const ends = row.unsafeBlockEnds ?? [];
+      return {
+        ...this.toSymbolLocation(row),
+        is_unsafe: row.isUnsafe === true,
+        unsafe_blocks: (row.unsafeBlockStarts ?? []).map((start, index) => ({
+          start_line: Number(start),
+          end_line: Number(ends[index] ?? start),
+        })),
+      };
+    });
+
+    return {
+      repository: query.repository,
+      symbols: symbols.slice(0, query.limit),
+      impls: implRows.slice(0, query.limit).map((row) => ({
+        trait_name: row.traitName,
+        type_name: row.typeName,
+        file_path: row.filePath,
+        line: Number(row.line),
+      })),
+      metadata: {
+        unsafe_symbol_count: symbols.filter((symbol) => symbol.is_unsafe).length,
+        unsafe_block_count: symbols.reduce((sum, symbol) => sum + symbol.unsafe_blocks.length, 0),
+        unsafe_impl_count: implRows.length,
+        truncated: symbols.length > query.limit || implRows.length > query.limit,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeMissingDocsQuery(query: ValidatedMissingDocsQuery): Promise<MissingDocsResult> {
+    const normalizedPath = query.path
+      ?.replace(/\\/g, "/")
+      .replace(/^\.\//, "")
+      .replace(/\/+$/, "");
+
+    const rows = await this.graphAdapter.runQuery<PublicSymbolRow>(
+      `
+      MATCH (e {repository: $repository})
+      WHERE (${ENTITY_LABELS.map((label) => `e:${label}`).join(" OR ")})
+        AND e.isExported = true
+        AND e.implementedTrait IS NULL
+        AND ($path IS NULL OR e.filePath = $path OR e.filePath STARTS WITH $dirPrefix)
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine,
+             e.parentName AS parentName,
+             e.attributes AS attributes,
+             e.doc IS NOT NULL AS documented
+      ORDER BY filePath, startLine
+      `,
+      {
+        repository: query.repository,
+        path: normalizedPath ?? null,
+        dirPrefix: normalizedPath !== undefined ? `${normalizedPath}/` : null,
+      }
+    );
+
+    const allowed = new Set(query.allow);
+    const isAllowed = (row: PublicSymbolRow): boolean =>
+      allowed.has(row.name) ||
+      allowed.has(row.id) ||
+      allowed.has(`${row.filePath}::${row.name}`) ||
+      (row.parentName !== null && allowed.has(`${row.parentName}::${row.name}`));
+
+    const modules = new Map<string, MissingDocsModule>();
+    let allowedCount = 0;
+    for (const row of rows) {
+      const attributes = row.attributes ?? [];
+      if (attributes.some((attribute) => this.matchesAttribute(attribute, "doc", "hidden"))) {
+        continue;
+      }
+      // `#[doc = "..."]` documents an item without a doc comment
+      const documented =
+        row.documented || attributes.some((attribute) => /^doc\s*=/.test(attribute));
+      if (!documented && isAllowed(row)) {
+        allowedCount++;
+        continue;
+      }
+
+      const module = modules.get(row.filePath) ?? {
+        module: row.filePath,
+        public_count: 0,
+        documented_count: 0,
+        symbols: [],
+      };
+      module.public_count++;
+      if (documented) {
+        module.documented_count++;
+      } else {
+        module.symbols.push(this.toSymbolLocation(row));
+      }
+      modules.set(row.filePath, module);
+    }
+
+    const all = [...modules.values()];
+    const publicCount = all.reduce((sum, module) => sum + module.public_count, 0);
+    const documentedCount = all.reduce((sum, module) => sum + module.documented_count, 0);
+    const missingCount = publicCount - documentedCount;
+
+    // The limit applies to symbols; modules are kept in path order
+    const reported: MissingDocsModule[] = [];
+    let remaining = query.limit;
+    for (const module of all) {
+      if (module.symbols.length === 0 || remaining === 0) continue;
+      const symbols = module.symbols.slice(0, remaining);
+      remaining -= symbols.length;
+      reported.push({ ...module, symbols });
+    }
+
+    return {
+      repository: query.repository,
+      modules: reported,
+      metadata: {
+        public_count: publicCount,
+        documented_count: documentedCount,
+        missing_count: missingCount,
+        allowed_count: allowedCount,
+        coverage:
+          publicCount > 0 ? Math.round((documentedCount / publicCount) * 1000) / 1000 : 1,
+        truncated: missingCount > query.limit,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeDeprecationReportQuery(
+    query: ValidatedDeprecationReportQuery
+  ): Promise<DeprecationReportResult> {
+    const normalizedPath = query.path
+      ?.replace(/\\/g, "/")
+      .replace(/^\.\//, "")
+      .replace(/\/+$/, "");
+
+    const rows = await this.graphAdapter.runQuery<DeprecatedRow>(
+      `
+      MATCH (e {repository: $repository})
+      WHERE (${ENTITY_LABELS.map((label) => `e:${label}`).join(" OR ")})
+        AND e.attributes IS NOT NULL
+        AND any(attribute IN e.attributes WHERE attribute CONTAINS 'deprecated')
+        AND ($path IS NULL OR e.filePath = $path OR e.filePath STARTS WITH $dirPrefix)
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine,
+             labels(e)[0] AS label,
+             e.attributes AS attributes
+      ORDER BY filePath, startLine
+      `,
+      {
+        repository: query.repository,
+        path: normalizedPath ?? null,
+        dirPrefix: normalizedPath !== undefined ? `${normalizedPath}/` : null,
+      }
+    );
+
+    const deprecated = rows.flatMap((row) => {
+      const attribute = row.attributes.find((a) => this.matchesAttribute(a, "deprecated"));
+      return attribute !== undefined ? [{ row, attribute }] : [];
+    });
+
+    const callSites = new Map<string, DeprecationCallSite[]>();
+    if (deprecated.length > 0) {
+      const siteRows = await this.graphAdapter.runQuery<DeprecationCallSiteRow>(
+        `
+        MATCH (caller:Function)-[c:CALLS]->(e)
+        WHERE e.id IN $ids
+        RETURN e.id AS targetId,
+               caller.id AS id,
+               caller.name AS name,
+               caller.entityType AS entityType,
+               caller.filePath AS filePath,
+               caller.startLine AS startLine,
+               caller.endLine AS endLine,
+               c.line AS line,
+               c.callCount AS callCount
+        ORDER BY filePath, startLine
+        `,
+        { ids: deprecated.map(({ row }) => row.id) }
+      );
+      for (const site of siteRows) {
+        const sites = callSites.get(site.targetId) ?? [];
+        sites.push({
+          caller: this.toSymbolLocation(site),
+          line: site.line !== null ? Number(site.line) : null,
+          call_count: Number(site.callCount ?? 1),
+        });
+        callSites.set(site.targetId, sites);
+      }
+    }
+
+    const symbols = deprecated
+      .map(({ row, attribute }): DeprecatedSymbol => {
+        const sites = callSites.get(row.id) ?? [];
+        const referencesTracked = row.label === "Function";
+        return {
+          ...this.toSymbolLocation(row),
+          attribute,
+          ...this.parseDeprecation(attribute),
+          call_sites: sites,
+          references_tracked: referencesTracked,
+          safe_to_remove: referencesTracked && sites.length === 0,
+        };
+      })
+      // Stable sort keeps location order among symbols with equal call counts
+      .sort((a, b) => b.call_sites.length - a.call_sites.length);
+
+    return {
+      repository: query.repository,
+      symbols: symbols.slice(0, query.limit),
+      metadata: {
+        total_deprecated: symbols.length,
+        in_use_count: symbols.filter((symbol) => symbol.call_sites.length > 0).length,
+        safe_to_remove_count: symbols.filter((symbol) => symbol.safe_to_remove).length,
+        truncated: symbols.length > query.limit,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  /**
+   * Extract `since` and `note` from a deprecation attribute
+   *
+   * Handles `deprecated`, `deprecated = "note"` and
+   * `deprecated(since = "1.2", note = "use bar instead")`.
+   */
+  private parseDeprecation(attribute: string): Pick<DeprecatedSymbol, "message" | "since"> {
+    const argumentValue = (key: string): string | null => {
+      const match = new RegExp(`\\b${key}\\s*=\\s*"((?:\\\\.|[^"\\\\])*)"`).exec(attribute);
+      return match?.[1] ?? null;
+    };
+    const bareValue = /^[^(=]*=\s*"((?:\\.|[^"\\])*)"/.exec(attribute);
+    return {
+      message: argumentValue("note") ?? bareValue?.[1] ?? null,
+      since: argumentValue("since"),
+    };
+  }
+}
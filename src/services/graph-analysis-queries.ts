@@ -0,0 +1,284 @@
+/**
+ * @module services/graph-analysis-queries
+ *
+ * Base class of the GraphAnalysisService query modules.
+ *
+ * GraphAnalysisServiceImpl validates each query, applies the timeout and
+ * maps errors; the Cypher queries themselves live in one class per feature
+ * area:
+ *
+ * - graph-analysis-search.ts: symbol, type, attribute and literal searches
+ * - graph-analysis-structure.ts: per-file symbol outlines and line lookups
+ * - graph-analysis-hierarchy.ts: type hierarchy, call graph and module order
+ * - graph-analysis-metrics.ts: coupling, hotspots and code health reports
+ * - graph-analysis-imports.ts: imports, external dependencies and API surface
+ *
+ * This class holds the graph adapter and the row mapping and symbol
+ * resolution helpers they share.
+ */
+
+import type { GraphStorageAdapter } from "../graph/adapters/types.js";
+import { attributePath } from "../graph/parsing/entry-points.js";
+import { rustModulePath } from "../graph/ingestion/call-resolution.js";
+import { rustCrateIdentifier } from "../graph/ingestion/crate-roots.js";
+import type { SymbolLocation, SymbolSearchMatch, SymbolParameter } from "./graph-analysis-types.js";
+
+/**
+ * Node labels written by GraphIngestionService for code entities.
+ */
+export const ENTITY_LABELS = ["Function", "Class", "Variable"] as const;
+
+/**
+ * Parsed form of a symbol reference string.
+ */
+interface SymbolReference {
+  /** Exact graph node ID, when the reference is a node ID */
+  id?: string;
+  /** Simple symbol name */
+  name: string;
+  /** File path, when the reference is file-qualified */
+  filePath?: string;
+}
+
+/**
+ * Raw symbol row returned by symbol lookup queries
+ */
+export interface SymbolRow {
+  id: string;
+  name: string;
+  entityType: string | null;
+  filePath: string;
+  startLine: number | null;
+  endLine: number | null;
+}
+
+/**
+ * Symbol row that also carries the parent link written at ingestion
+ */
+export interface NestedSymbolRow extends SymbolRow {
+  parentName: string | null;
+}
+
+/**
+ * Symbol row returned by the symbol search query
+ */
+export interface SymbolSearchRow extends SymbolRow {
+  kind: string | null;
+  paramNames: string[] | null;
+  paramTypes: string[] | null;
+  returnType: string | null;
+  isAsync: boolean | null;
+  buildOrMacro?: boolean | null;
+  cfg?: string | null;
+}
+
+/**
+ * Raw row returned by the name resolution query
+ */
+export interface NameCandidateRow extends NestedSymbolRow {
+  kind: string | null;
+  crateName: string | null;
+}
+
+/**
+ * Raw row returned by the call edge query
+ */
+export interface CallEdgeRow {
+  fromId: string;
+  toId: string;
+}
+
+/**
+ * Graph adapter and helpers shared by the GraphAnalysisService query classes
+ */
+export abstract class GraphAnalysisQueries {
+  /**
+   * @param graphAdapter - Graph storage adapter for graph operations
+   */
+  constructor(protected readonly graphAdapter: GraphStorageAdapter) {}
+
+  /**
+   * Convert a raw symbol row to a SymbolLocation
+   */
+  protected toSymbolLocation(row: SymbolRow): SymbolLocation {
+    return {
+      id: row.id,
+      name: row.name,
+      entity_type: row.entityType ?? "unknown",
+      file_path: row.filePath,
+      start_line: Number(row.startLine ?? 0),
+      end_line: Number(row.endLine ?? row.startLine ?? 0),
+    };
+  }
+
+  /**
+   * Rebuild structured parameters and return type from the parallel
+   * `paramNames`/`paramTypes` lists written by GraphIngestionService.
+   * Graphs ingested before these properties existed yield neither field.
+   */
+  protected toSignatureFields(
+    row: SymbolSearchRow
+  ): Pick<SymbolSearchMatch, "params" | "return_type" | "is_async"> {
+    const fields: Pick<SymbolSearchMatch, "params" | "return_type" | "is_async"> = {};
+    if (row.paramNames) {
+      fields.params = row.paramNames.map((name, i): SymbolParameter => {
+        const type = row.paramTypes?.[i];
+        return type ? { name, type } : { name };
+      });
+    }
+    if (row.returnType) {
+      fields.return_type = row.returnType;
+    }
+    if (typeof row.isAsync === "boolean") {
+      fields.is_async = row.isAsync;
+    }
+    return fields;
+  }
+
+  /**
+   * Resolve a symbol reference to matching entity nodes
+   *
+   * @param reference - Symbol reference (name, qualified name, `file::name` or node ID)
+   * @param repository - Repository to search
+   * @param filePath - Optional file path restriction
+   * @returns Matching symbols ordered by file path and line
+   */
+  protected async resolveSymbols(
+    reference: string,
+    repository: string,
+    filePath?: string
+  ): Promise<SymbolLocation[]> {
+    const parsed = this.parseSymbolReference(reference);
+    const effectiveFilePath = filePath ?? parsed.filePath ?? "";
+
+    const rows = await this.graphAdapter.runQuery<SymbolRow>(
+      `
+      MATCH (f:File {repository: $repository})-[d:DEFINES]->(e)
+      WHERE (${ENTITY_LABELS.map((label) => `e:${label}`).join(" OR ")})
+        AND ${parsed.id ? "e.id = $id" : "e.name = $name"}
+        AND ($filePath = '' OR f.path = $filePath)
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             f.path AS filePath,
+             coalesce(e.startLine, d.startLine) AS startLine,
+             coalesce(e.endLine, d.endLine) AS endLine
+      ORDER BY filePath, startLine
+      `,
+      {
+        repository,
+        id: parsed.id ?? "",
+        name: parsed.name,
+        filePath: effectiveFilePath,
+      }
+    );
+
+    return rows.map((row) => this.toSymbolLocation(row));
+  }
+
+  /**
+   * Parse a symbol reference string
+   *
+   * Accepted forms:
+   * - Graph node ID: `Function:repo:src/a.ts:foo:12`
+   * - File-qualified: `src/a.ts::foo`
+   * - Language-qualified: `crate::auth::validate` or `Point::new` (last segment is the name)
+   * - Plain name: `foo`
+   */
+  protected parseSymbolReference(reference: string): SymbolReference {
+    const trimmed = reference.trim();
+
+    const isNodeId = ENTITY_LABELS.some(
+      (label) => trimmed.startsWith(`${label}:`) && !trimmed.startsWith(`${label}::`)
+    );
+    if (isNodeId) {
+      const parts = trimmed.split(":");
+      // Label:repo:path:name:line -> name is the second-to-last segment
+      return { id: trimmed, name: parts[parts.length - 2] ?? trimmed };
+    }
+
+    const separator = trimmed.indexOf("::");
+    if (separator > 0) {
+      const head = trimmed.substring(0, separator);
+      const name = trimmed.substring(trimmed.lastIndexOf("::") + 2);
+      // A head containing a path separator or extension is a file path
+      if (head.includes("/") || head.includes(".")) {
+        return { name, filePath: head };
+      }
+      return { name };
+    }
+
+    return { name: trimmed };
+  }
+
+  /**
+   * Fetch every CALLS relationship of a repository
+   */
+  protected async getCallEdges(repository: string): Promise<CallEdgeRow[]> {
+    return this.graphAdapter.runQuery<CallEdgeRow>(
+      `
+      MATCH (caller:Function {repository: $repository})-[:CALLS]->(callee:Function)
+      RETURN caller.id AS fromId, callee.id AS toId
+      `,
+      { repository }
+    );
+  }
+
+  /**
+   * Look up the locations of functions by node ID
+   *
+   * Node IDs embed the repository, so no repository filter is needed.
+   */
+  protected async getFunctionLocations(ids: string[]): Promise<Map<string, SymbolLocation>> {
+    if (ids.length === 0) {
+      return new Map();
+    }
+
+    const rows = await this.graphAdapter.runQuery<SymbolRow>(
+      `
+      MATCH (e:Function)
+      WHERE e.id IN $ids
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine
+      `,
+      { ids }
+    );
+
+    return new Map(rows.map((row) => [row.id, this.toSymbolLocation(row)]));
+  }
+
+  /**
+   * Whether an attribute has the given path (or ends in it, for bare names)
+   * and, when requested, arguments containing the given text.
+   */
+  protected matchesAttribute(attribute: string, name: string, args?: string): boolean {
+    const path = attributePath(attribute);
+    if (path !== name && !(!name.includes("::") && path.endsWith(`::${name}`))) {
+      return false;
+    }
+    if (args === undefined) {
+      return true;
+    }
+    const argsStart = attribute.search(/[(=]/);
+    return argsStart !== -1 && attribute.slice(argsStart).includes(args);
+  }
+
+  /**
+   * Qualified path of a symbol: the crate module path for Rust
+   * (`crate::geometry::Point::new`), otherwise the file path followed by the
+   * parent and name (`src/shapes.ts::Point.scale`). Files of a Cargo
+   * workspace crate start with the crate's name instead of `crate`.
+   */
+  protected qualifiedName(row: NameCandidateRow): string {
+    const member = row.parentName !== null ? [row.parentName, row.name] : [row.name];
+    if (row.filePath.endsWith(".rs")) {
+      const crate = row.crateName ? rustCrateIdentifier(row.crateName) : "crate";
+      return [crate, ...rustModulePath(row.filePath), ...member].join("::");
+    }
+    return `${row.filePath}::${member.join(".")}`;
+  }
+}
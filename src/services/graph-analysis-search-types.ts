@@ -0,0 +1,937 @@
+/**
+ * @module services/graph-analysis-search-types
+ *
+ * Type definitions for the GraphAnalysisService symbol, text and attribute search queries.
+ */
+
+import type { NameMatchType } from "../graph/parsing/identifier-tokens.js";
+import type { SymbolKind } from "../graph/parsing/symbol-kinds.js";
+import type { TypeMatchType } from "../graph/parsing/type-matching.js";
+import type { DoctestMode, EntryPointKind, LiteralValueKind } from "../graph/parsing/types.js";
+import type { SymbolLocation, SymbolParameter } from "./graph-analysis-types.js";
+
+// =============================================================================
+// TODO Comments
+// =============================================================================
+
+/**
+ * Query for TODO/FIXME marker comments recorded at ingestion
+ */
+export interface TodoListQuery {
+  /** Repository to list markers for */
+  repository: string;
+
+  /** Only return markers with this tag (case-insensitive, e.g. "FIXME") */
+  tag?: string;
+
+  /** File path, or directory path to cover every file beneath it */
+  path?: string;
+
+  /**
+   * Maximum number of markers to return
+   * @default 100
+   */
+  limit?: number;
+}
+
+/**
+ * A marker comment found in a source file
+ */
+export interface TodoItem {
+  /** Marker tag (e.g. "TODO") */
+  tag: string;
+
+  /** Comment text following the tag */
+  message: string;
+
+  /** File path relative to the repository root */
+  file_path: string;
+
+  /** Line number of the marker (1-based) */
+  line: number;
+}
+
+/**
+ * Result of a TODO listing query
+ */
+export interface TodoListResult {
+  /** Repository searched */
+  repository: string;
+
+  /** Markers ordered by file path and line */
+  todos: TodoItem[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of markers matching the filters, before the limit */
+    total_count: number;
+    /** Matching markers per tag, before the limit */
+    tag_counts: Record<string, number>;
+    /** Whether the limit cut off matching markers */
+    truncated: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Symbol Search
+// =============================================================================
+
+/**
+ * Query for code symbols by name
+ */
+export interface SymbolSearchQuery {
+  /** Repository to search */
+  repository: string;
+
+  /**
+   * Name or name fragment to search for (e.g. "scale", "ScaleFactor").
+   *
+   * A `where:` prefix searches generic where-clause text instead of names,
+   * case-insensitively: "where:Hash" finds items bounded by `Hash`.
+   */
+  query: string;
+
+  /**
+   * Also match identifier subtokens split on underscores and camelCase
+   * boundaries, so "scale" finds "rescale_factor" and "autoScaleFactor"
+   * @default false
+   */
+  token_match?: boolean;
+
+  /** Only return symbols of this language-neutral kind (e.g. "method") */
+  kind?: SymbolKind;
+
+  /**
+   * Only return build-time code (build scripts and proc-macro crates) when
+   * true, or leave it out when false; both when unset
+   */
+  build_or_macro?: boolean;
+
+  /**
+   * Only return symbols that can be compiled for this target: a target
+   * triple (e.g. "x86_64-pc-windows-msvc"), an OS name (e.g. "linux") or a
+   * family ("unix", "windows", "wasm"). Symbols whose `cfg` condition rules
+   * the target out are left out; ungated symbols always match.
+   */
+  platform?: string;
+
+  /**
+   * Attach the doc summaries of each symbol and of its enclosing module
+   * @default false
+   */
+  include_context?: boolean;
+
+  /**
+   * Drop matches scoring below this (0.0-1.0) before the limit is applied.
+   * Defaults to 0.25 with `token_match`, so names sharing only a minority of
+   * the query's subtokens are left out, and to 0 otherwise.
+   */
+  min_score?: number;
+
+  /**
+   * Maximum number of symbols to return
+   * @default 20
+   */
+  limit?: number;
+}
+
+/**
+ * How a symbol matched a search: by name, or by where-clause text for
+ * `where:` queries
+ */
+export type SymbolMatchType = NameMatchType | "where_clause";
+
+/**
+ * A symbol matching a name search
+ */
+export interface SymbolSearchMatch extends SymbolLocation {
+  /** Language-neutral symbol kind, when recorded at ingestion */
+  kind?: string;
+
+  /** How the symbol matched the query */
+  match_type: SymbolMatchType;
+
+  /** Generic where clause as written, for `where:` queries */
+  where_clause?: string;
+
+  /** Parameters in declaration order (functions and methods only) */
+  params?: SymbolParameter[];
+
+  /**
+   * Return type as written, when declared (functions and methods only). For
+   * async functions this is the resolved type; the future is implicit.
+   */
+  return_type?: string;
+
+  /** Whether the function is async (functions and methods only) */
+  is_async?: boolean;
+
+  /** Present when the symbol is in a build script or proc-macro crate */
+  build_or_macro?: true;
+
+  /** Conditional compilation condition gating the symbol, e.g. `target_os = "windows"` */
+  cfg?: string;
+
+  /** First paragraph of the symbol's documentation, with `include_context` */
+  doc_summary?: string;
+
+  /**
+   * First paragraph of the enclosing module's documentation (Rust `//!`
+   * comments), with `include_context`
+   */
+  module_doc_summary?: string;
+
+  /** Relevance score (0.0-1.0); full-name matches outrank subtoken matches */
+  score: number;
+}
+
+/**
+ * Result of a symbol name search
+ */
+export interface SymbolSearchResult {
+  /** Search text */
+  query: string;
+
+  /** Repository searched */
+  repository: string;
+
+  /** Matching symbols ordered by score, then name and location */
+  matches: SymbolSearchMatch[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of matching symbols at or above the minimum score, before the limit */
+    total_matches: number;
+    /** Whether subtoken matching was enabled */
+    token_match: boolean;
+    /** Minimum score applied, given or defaulted */
+    min_score_applied: number;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Type Search
+// =============================================================================
+
+/**
+ * Where in a signature a searched type may appear
+ */
+export type TypePosition = "param" | "return" | "any";
+
+/**
+ * Query for functions by parameter or return type
+ */
+export interface TypeSearchQuery {
+  /** Repository to search */
+  repository: string;
+
+  /** Type to look for, as it would be written (e.g. "&str", "Point", "Result") */
+  type: string;
+
+  /**
+   * Signature position to match
+   * @default "any"
+   */
+  position?: TypePosition;
+
+  /**
+   * Also match through reference and generic wrappers, so "Result" matches a
+   * `Result<String, Error>` return and "Point" matches a `&mut Point` parameter
+   * @default false
+   */
+  contains?: boolean;
+
+  /**
+   * Maximum number of functions to return
+   * @default 20
+   */
+  limit?: number;
+}
+
+/**
+ * A function whose signature mentions the searched type
+ */
+export interface TypeSearchMatch extends SymbolLocation {
+  /** Language-neutral symbol kind, when recorded at ingestion */
+  kind?: string;
+
+  /** Parameters in declaration order */
+  params: SymbolParameter[];
+
+  /** Declared return type, when present; the resolved type for async functions */
+  return_type?: string;
+
+  /** Whether the function is async */
+  is_async?: boolean;
+
+  /** Names of the parameters whose type matched */
+  matched_params: string[];
+
+  /** Whether the return type matched */
+  matched_return: boolean;
+
+  /** Strongest match found in the signature */
+  match_type: TypeMatchType;
+}
+
+/**
+ * Result of a type search
+ */
+export interface TypeSearchResult {
+  /** Searched type */
+  type: string;
+
+  /** Repository searched */
+  repository: string;
+
+  /** Signature position that was matched */
+  position: TypePosition;
+
+  /** Matching functions, exact matches first, then by name and location */
+  matches: TypeSearchMatch[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of matching functions, before the limit */
+    total_matches: number;
+    /** Whether wrapper-aware matching was enabled */
+    contains: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Entry Points
+// =============================================================================
+
+/**
+ * Query for the entry points (main functions, tests, benchmarks) of a repository
+ */
+export interface EntryPointsQuery {
+  /** Repository to list entry points for */
+  repository: string;
+
+  /** Only return entry points of this kind */
+  kind?: EntryPointKind;
+
+  /** File path, or directory path to cover every file beneath it */
+  path?: string;
+
+  /**
+   * Maximum number of entry points to return across all kinds
+   * @default 200
+   */
+  limit?: number;
+}
+
+/**
+ * A function flagged as an entry point at ingestion
+ */
+export interface EntryPointSymbol extends SymbolLocation {
+  /** Attributes on the function as written inside `#[...]` */
+  attributes?: string[];
+}
+
+/**
+ * Entry points of one kind
+ */
+export interface EntryPointGroup {
+  /** Entry point kind shared by the group */
+  kind: EntryPointKind;
+
+  /** Entry points ordered by file path and line */
+  entry_points: EntryPointSymbol[];
+}
+
+/**
+ * Result of an entry point listing query
+ */
+export interface EntryPointsResult {
+  /** Repository searched */
+  repository: string;
+
+  /** Non-empty groups in kind order: main, async_main, test, bench */
+  groups: EntryPointGroup[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of entry points matching the filters, before the limit */
+    total_count: number;
+    /** Matching entry points per kind, before the limit */
+    kind_counts: Partial<Record<EntryPointKind, number>>;
+    /** Whether the limit cut off matching entry points */
+    truncated: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Attributes
+// =============================================================================
+
+/**
+ * Query for symbols annotated with an attribute (e.g. `#[deprecated]`)
+ */
+export interface AttributeSearchQuery {
+  /** Repository to search */
+  repository: string;
+
+  /**
+   * Attribute path as written inside `#[...]`, without arguments
+   * (e.g. "deprecated", "serde", "tokio::test"). A bare name also matches
+   * paths ending in it, so "async_trait" matches `#[async_trait::async_trait]`.
+   */
+  name: string;
+
+  /** Text the attribute's arguments must contain; omit to match regardless of arguments */
+  arguments?: string;
+
+  /** File path, or directory path to cover every file beneath it */
+  path?: string;
+
+  /**
+   * Maximum number of symbols to return
+   * @default 100
+   */
+  limit?: number;
+}
+
+/**
+ * A symbol carrying a matching attribute
+ */
+export interface AttributeMatch extends SymbolLocation {
+  /** Matching attributes as written inside `#[...]` */
+  attributes: string[];
+
+  /** Number of functions with a CALLS relationship to the symbol */
+  caller_count: number;
+}
+
+/**
+ * Result of an attribute search
+ */
+export interface AttributeSearchResult {
+  /** Attribute name searched for */
+  name: string;
+
+  /** Repository searched */
+  repository: string;
+
+  /** Matching symbols ordered by file path and line */
+  matches: AttributeMatch[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of matching symbols, before the limit */
+    total_matches: number;
+    /** Whether the limit cut off matching symbols */
+    truncated: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Similar Functions
+// =============================================================================
+
+/**
+ * Query for structurally duplicated functions of a repository
+ */
+export interface SimilarFunctionsQuery {
+  /** Repository to search */
+  repository: string;
+
+  /** File path, or directory path to cover every file beneath it */
+  path?: string;
+
+  /**
+   * Minimum estimated similarity for near clones, from 0.5 to 1; 1 reports
+   * exact clones only
+   * @default 0.8
+   */
+  min_similarity?: number;
+
+  /**
+   * Minimum body size in parse tree nodes; smaller bodies (getters, one-line
+   * wrappers) look alike without being duplicated logic
+   * @default 30
+   */
+  min_size?: number;
+
+  /**
+   * Maximum number of groups to return
+   * @default 20
+   */
+  limit?: number;
+}
+
+/**
+ * Whether a group's bodies have identical shapes or only similar ones
+ */
+export type CloneKind = "exact" | "near";
+
+/**
+ * A function in a group of potential duplicates
+ */
+export interface SimilarFunction extends SymbolLocation {
+  /** Structural hash of the body; members sharing it are exact clones of each other */
+  fingerprint: string;
+
+  /** Body size in parse tree nodes */
+  size: number;
+}
+
+/**
+ * Functions whose bodies have the same or a similar structure
+ */
+export interface SimilarFunctionGroup {
+  /** `exact` when every body has the same shape, `near` otherwise */
+  kind: CloneKind;
+
+  /**
+   * 1 for exact clones; for near clones the lowest estimated similarity
+   * among the pairs linking the group
+   */
+  similarity: number;
+
+  /** Members, ordered by file and line */
+  functions: SimilarFunction[];
+}
+
+/**
+ * Result of a similar functions query
+ */
+export interface SimilarFunctionsResult {
+  /** Repository searched */
+  repository: string;
+
+  /** Near-clone threshold applied */
+  min_similarity: number;
+
+  /** Groups, most similar first, then largest bodies first */
+  groups: SimilarFunctionGroup[];
+
+  /** Query metadata */
+  metadata: {
+    /** Functions at or above the minimum size that were compared */
+    functions_compared: number;
+    /** Exact clone groups found, before the limit */
+    exact_groups: number;
+    /** Near clone groups found, before the limit */
+    near_groups: number;
+    /** Whether the limit cut off groups */
+    truncated: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Constant Values
+// =============================================================================
+
+/**
+ * Query for numeric constants whose literal value lies in a range
+ */
+export interface ConstValueRangeQuery {
+  /** Repository to search */
+  repository: string;
+
+  /** Smallest value to include; unbounded below when omitted */
+  min?: number;
+
+  /** Largest value to include; unbounded above when omitted */
+  max?: number;
+
+  /** Restrict to integer or float constants (default: both) */
+  kind?: "integer" | "float";
+
+  /** File path, or directory path to cover every file beneath it */
+  path?: string;
+
+  /**
+   * Maximum number of constants to return
+   * @default 100
+   */
+  limit?: number;
+}
+
+/**
+ * A constant with its literal value
+ */
+export interface ConstValueMatch extends SymbolLocation {
+  /** Kind of the literal */
+  value_kind: LiteralValueKind;
+
+  /** Decoded value (see `graph/parsing/literal-values`) */
+  value: number | string | boolean;
+
+  /** Initializer as written, e.g. "1_024usize" */
+  value_text: string;
+}
+
+/**
+ * Result of a constant value range query
+ */
+export interface ConstValueRangeResult {
+  /** Repository searched */
+  repository: string;
+
+  /** Lower bound applied, null when unbounded */
+  min: number | null;
+
+  /** Upper bound applied, null when unbounded */
+  max: number | null;
+
+  /** Constants in the range, smallest value first */
+  constants: ConstValueMatch[];
+
+  /** Query metadata */
+  metadata: {
+    /** Constants in the range, before the limit */
+    total_count: number;
+    /** Whether the limit cut off constants */
+    truncated: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Doctests
+// =============================================================================
+
+/**
+ * Query for the doc comment examples of a symbol
+ */
+export interface DoctestSearchQuery {
+  /** Repository to search */
+  repository: string;
+
+  /** Symbol name: examples documenting it or calling it are returned */
+  symbol: string;
+
+  /** Only return examples tested in this mode */
+  mode?: DoctestMode;
+
+  /**
+   * Maximum number of examples to return
+   * @default 50
+   */
+  limit?: number;
+}
+
+/**
+ * A Rust code example from a doc comment
+ */
+export interface DoctestExample {
+  /** File containing the example, relative to the repository root */
+  file_path: string;
+
+  /** First code line of the example (1-based) */
+  start_line: number;
+
+  /** Last code line of the example (1-based) */
+  end_line: number;
+
+  /** Fence info string as written, e.g. "no_run" */
+  info: string;
+
+  /** How rustdoc tests the example */
+  mode: DoctestMode;
+
+  /** Example code with hidden-line markers removed */
+  code: string;
+
+  /** Whether the example is in the symbol's own documentation */
+  documents_symbol: boolean;
+
+  /** Whether the example calls the symbol */
+  calls_symbol: boolean;
+
+  /** The item whose doc comment holds the example */
+  documented: SymbolLocation;
+}
+
+/**
+ * Result of a doctest search query
+ */
+export interface DoctestSearchResult {
+  /** Repository searched */
+  repository: string;
+
+  /** Symbol name searched for */
+  symbol: string;
+
+  /** Examples in the symbol's own docs first, then by file path and line */
+  examples: DoctestExample[];
+
+  /** Query metadata */
+  metadata: {
+    /** Examples matching the query, before the limit */
+    total_count: number;
+    /** Whether the limit cut off examples */
+    truncated: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Name Resolution
+// =============================================================================
+
+/**
+ * Query resolving a short or partially qualified name to candidate symbols
+ */
+export interface NameResolutionQuery {
+  /** Repository to search */
+  repository: string;
+
+  /**
+   * Name to resolve: a symbol name (`Point`) or a suffix of its qualified path
+   * (`geometry::Point`, `Point::new`, `crate::geometry::Point`)
+   */
+  partial: string;
+
+  /**
+   * Maximum number of candidates to return
+   * @default 20
+   */
+  limit?: number;
+}
+
+/**
+ * How a candidate matched the partial name
+ *
+ * - `exact`: the name and any qualifying segments match as written
+ * - `case_insensitive`: they match only when case is ignored
+ */
+export type NameResolutionMatchType = "exact" | "case_insensitive";
+
+/**
+ * A symbol the partial name may refer to
+ */
+export interface NameResolutionCandidate extends SymbolLocation {
+  /**
+   * Qualified path of the symbol: the module path for Rust
+   * (`crate::geometry::Point::new`, or `crate_a::geometry::Point::new` for a
+   * repository ingested as a Cargo workspace), otherwise the file path
+   * followed by the parent and name (`src/shapes.ts::Point.scale`)
+   */
+  qualified_name: string;
+
+  /** Language-neutral symbol kind, when recorded at ingestion */
+  kind?: string;
+
+  /** Enclosing class, struct, impl or trait, if any */
+  parent_name?: string;
+
+  /** How the candidate matched */
+  match_type: NameResolutionMatchType;
+
+  /** Number of symbols in the repository with exactly this name */
+  name_count: number;
+}
+
+/**
+ * Result of a name resolution query
+ */
+export interface NameResolutionResult {
+  /** Name that was resolved */
+  partial: string;
+
+  /** Repository searched */
+  repository: string;
+
+  /**
+   * Candidates ordered by match type, then by rarity of their name (fewest
+   * symbols sharing it first), then by qualified name
+   */
+  candidates: NameResolutionCandidate[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of candidates, before the limit */
+    total_count: number;
+    /** Whether exactly one candidate matched exactly */
+    unambiguous: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Function Arity
+// =============================================================================
+
+/**
+ * Query for functions by number of parameters
+ */
+export interface ArityQuery {
+  /** Repository to search */
+  repository: string;
+
+  /**
+   * Minimum number of parameters
+   * @default 0
+   */
+  min_params?: number;
+
+  /** Maximum number of parameters; unbounded when omitted */
+  max_params?: number;
+
+  /**
+   * Whether a Rust `self` receiver counts as a parameter
+   * @default false
+   */
+  count_receiver?: boolean;
+
+  /**
+   * Only return exported (`pub`) functions
+   * @default false
+   */
+  exported_only?: boolean;
+
+  /** Restrict to a file or directory (relative to the repository root) */
+  path?: string;
+
+  /**
+   * Maximum number of functions to return
+   * @default 50
+   */
+  limit?: number;
+}
+
+/**
+ * A function whose parameter count falls within the queried range
+ */
+export interface ArityMatch extends SymbolLocation {
+  /** Language-neutral symbol kind, when recorded at ingestion */
+  kind?: string;
+
+  /** Parameters in declaration order, including any receiver */
+  params: SymbolParameter[];
+
+  /** Number of parameters counted against the range */
+  param_count: number;
+
+  /** Whether the first parameter is a `self` receiver */
+  has_receiver: boolean;
+
+  /** Whether the function is exported */
+  is_exported: boolean;
+
+  /** Declared return type, when present */
+  return_type?: string;
+}
+
+/**
+ * Result of an arity query
+ */
+export interface ArityResult {
+  /** Repository searched */
+  repository: string;
+
+  /** Minimum number of parameters matched */
+  min_params: number;
+
+  /** Maximum number of parameters matched, or null when unbounded */
+  max_params: number | null;
+
+  /** Matching functions, most parameters first, then by name and location */
+  functions: ArityMatch[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of matching functions, before the limit */
+    total_count: number;
+    /** Whether the results were truncated by the limit */
+    truncated: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// String Literals
+// =============================================================================
+
+/**
+ * Query for string literals recorded at ingestion
+ *
+ * Literals are only indexed when `GRAPH_INDEX_STRING_LITERALS` is enabled.
+ */
+export interface StringLiteralSearchQuery {
+  /** Repository to search */
+  repository: string;
+
+  /** Text the literal must contain */
+  query: string;
+
+  /**
+   * Whether matching is case-sensitive
+   * @default false
+   */
+  case_sensitive?: boolean;
+
+  /** File path, or directory path to cover every file beneath it */
+  path?: string;
+
+  /**
+   * Maximum number of literals to return
+   * @default 50
+   */
+  limit?: number;
+}
+
+/**
+ * A string literal containing the search text
+ */
+export interface StringLiteralMatch {
+  /** Literal contents without quotes (truncated to 500 characters) */
+  value: string;
+
+  /** File path relative to the repository root */
+  file_path: string;
+
+  /** Line number of the literal (1-based) */
+  line: number;
+
+  /** Column of the literal (0-based) */
+  column: number;
+
+  /** Innermost symbol containing the literal (e.g. "AuthService.refresh") */
+  enclosing_symbol?: string;
+}
+
+/**
+ * Result of a string literal search
+ */
+export interface StringLiteralSearchResult {
+  /** Search text */
+  query: string;
+
+  /** Repository searched */
+  repository: string;
+
+  /** Matching literals ordered by file path and location */
+  matches: StringLiteralMatch[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of matching literals, before the limit */
+    total_matches: number;
+    /** Whether the limit cut off matching literals */
+    truncated: boolean;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
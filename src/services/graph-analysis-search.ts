@@ -0,0 +1,1135 @@
+/**
+ * @module services/graph-analysis-search
+ *
+ * Search queries of GraphAnalysisService: symbols by name, type, attribute
+ * and arity, constants by value, string literals, doctests, TODO comments,
+ * entry points, structurally similar functions and qualified name
+ * resolution.
+ */
+
+import path from "node:path";
+import type {
+  ValidatedTodoListQuery,
+  ValidatedSymbolSearchQuery,
+  ValidatedStringLiteralSearchQuery,
+  ValidatedTypeSearchQuery,
+  ValidatedEntryPointsQuery,
+  ValidatedAttributeSearchQuery,
+  ValidatedSimilarFunctionsQuery,
+  ValidatedConstValueRangeQuery,
+  ValidatedDoctestSearchQuery,
+  ValidatedNameResolutionQuery,
+  ValidatedArityQuery,
+} from "./graph-analysis-validation.js";
+import {
+  DEFAULT_TOKEN_MATCH_MIN_SCORE,
+  scoreSymbolName,
+  splitIdentifier,
+} from "../graph/parsing/identifier-tokens.js";
+import { matchType, type TypeMatchType } from "../graph/parsing/type-matching.js";
+import { ENTRY_POINT_KINDS, attributePath } from "../graph/parsing/entry-points.js";
+import {
+  isCfgSatisfiable,
+  parseTargetPlatform,
+  type TargetPlatform,
+} from "../graph/parsing/cfg-predicates.js";
+import type { DoctestMode, EntryPointKind, StructuralFingerprint } from "../graph/parsing/types.js";
+import { docSummary } from "../graph/parsing/doc-comments.js";
+import { MIN_HASH_SLOTS, structuralSimilarity } from "../graph/parsing/structural-fingerprint.js";
+import { findStronglyConnectedComponents, type AdjacencyMap } from "./graph-algorithms.js";
+import type {
+  TodoListResult,
+  TodoItem,
+  SymbolSearchResult,
+  SymbolSearchMatch,
+  TypeSearchResult,
+  TypeSearchMatch,
+  EntryPointsResult,
+  EntryPointGroup,
+  AttributeSearchResult,
+  AttributeMatch,
+  SimilarFunctionsResult,
+  SimilarFunctionGroup,
+  SimilarFunction,
+  ConstValueRangeResult,
+  DoctestSearchResult,
+  NameResolutionResult,
+  NameResolutionCandidate,
+  NameResolutionMatchType,
+  ArityResult,
+  ArityMatch,
+  StringLiteralSearchResult,
+  StringLiteralMatch,
+} from "./graph-analysis-types.js";
+import {
+  GraphAnalysisQueries,
+  ENTITY_LABELS,
+  type SymbolRow,
+  type SymbolSearchRow,
+  type NameCandidateRow,
+} from "./graph-analysis-queries.js";
+
+/**
+ * Raw row returned by the TODO listing query
+ */
+interface TodoRow {
+  tag: string;
+  message: string | null;
+  filePath: string;
+  line: number;
+}
+
+/**
+ * Documentation row returned for symbol search results with `include_context`
+ */
+interface SymbolContextRow {
+  id: string;
+  doc: string | null;
+  moduleDoc: string | null;
+}
+
+/**
+ * Raw row returned by the where-clause search query
+ */
+interface WhereClauseSearchRow extends SymbolSearchRow {
+  whereClause: string;
+}
+
+/**
+ * Raw row returned by the entry point listing query
+ */
+interface EntryPointRow extends SymbolRow {
+  entryPointKind: EntryPointKind;
+  attributes: string[] | null;
+}
+
+/**
+ * Raw row returned by the attribute search query
+ */
+interface AttributeRow extends SymbolRow {
+  attributes: string[];
+  callerCount: number | null;
+}
+
+/**
+ * Raw row returned by the similar functions query
+ */
+interface StructureRow extends SymbolRow {
+  structureHash: string;
+  structureSize: number;
+  structureMinHash: number[] | null;
+}
+
+/**
+ * MinHash slots per locality-sensitive hashing band. Two bodies become
+ * near-clone candidates when any band matches; with 2 rows per band even
+ * pairs at the 0.5 threshold are almost always compared.
+ */
+const NEAR_CLONE_BAND_ROWS = 2;
+
+/**
+ * Raw row returned by the constant value range query
+ */
+interface ConstValueRow extends SymbolRow {
+  valueKind: "integer" | "float";
+  value: number;
+  valueText: string | null;
+}
+
+/**
+ * Raw row returned by the doctest search query, joined with the documented item
+ */
+interface DoctestRow extends SymbolRow {
+  doctestStartLine: number;
+  doctestEndLine: number;
+  info: string | null;
+  mode: DoctestMode;
+  code: string;
+  calls: string[] | null;
+}
+
+/**
+ * Query prefix that switches symbol search to where-clause text
+ */
+const WHERE_CLAUSE_QUERY_PREFIX = "where:";
+
+/**
+ * Raw row returned by the string literal search query
+ */
+interface StringLiteralRow {
+  value: string;
+  filePath: string;
+  line: number;
+  column: number | null;
+  enclosingSymbol: string | null;
+}
+
+/**
+ * Function row returned by the arity query
+ */
+interface ArityRow extends SymbolSearchRow {
+  isExported: boolean | null;
+}
+
+/**
+ * Search queries of GraphAnalysisService
+ */
+export class SearchQueries extends GraphAnalysisQueries {
+  async executeTodoListQuery(query: ValidatedTodoListQuery): Promise<TodoListResult> {
+    const normalizedPath = query.path
+      ?.replace(/\\/g, "/")
+      .replace(/^\.\//, "")
+      .replace(/\/+$/, "");
+
+    const rows = await this.graphAdapter.runQuery<TodoRow>(
+      `
+      MATCH (f:File {repository: $repository})-[:HAS_TODO]->(t:Todo)
+      WHERE ($tag IS NULL OR toUpper(t.tag) = toUpper($tag))
+        AND ($path IS NULL OR f.path = $path OR f.path STARTS WITH $dirPrefix)
+      RETURN t.tag AS tag,
+             t.message AS message,
+             f.path AS filePath,
+             t.line AS line
+      ORDER BY filePath, line
+      `,
+      {
+        repository: query.repository,
+        tag: query.tag ?? null,
+        path: normalizedPath ?? null,
+        dirPrefix: normalizedPath !== undefined ? `${normalizedPath}/` : null,
+      }
+    );
+
+    const tagCounts: Record<string, number> = {};
+    for (const row of rows) {
+      tagCounts[row.tag] = (tagCounts[row.tag] ?? 0) + 1;
+    }
+
+    const todos: TodoItem[] = rows.slice(0, query.limit).map((row) => ({
+      tag: row.tag,
+      message: row.message ?? "",
+      file_path: row.filePath,
+      line: row.line,
+    }));
+
+    return {
+      repository: query.repository,
+      todos,
+      metadata: {
+        total_count: rows.length,
+        tag_counts: tagCounts,
+        truncated: rows.length > todos.length,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeSymbolSearchQuery(query: ValidatedSymbolSearchQuery): Promise<SymbolSearchResult> {
+    if (query.query.toLowerCase().startsWith(WHERE_CLAUSE_QUERY_PREFIX)) {
+      return this.withSearchContext(query, await this.executeWhereClauseSearchQuery(query));
+    }
+
+    const queryTokens = query.token_match ? splitIdentifier(query.query) : [];
+
+    // Graphs ingested before nameTokens existed only match by substring
+    const rows = await this.graphAdapter.runQuery<SymbolSearchRow>(
+      `
+      MATCH (e {repository: $repository})
+      WHERE (${ENTITY_LABELS.map((label) => `e:${label}`).join(" OR ")})
+        AND ($kind IS NULL OR e.kind = $kind)
+        AND ($buildOrMacro IS NULL OR coalesce(e.buildOrMacro, false) = $buildOrMacro)
+        AND (toLower(e.name) CONTAINS toLower($query)
+             OR any(token IN $queryTokens WHERE token IN coalesce(e.nameTokens, [])))
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.kind AS kind,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine,
+             e.paramNames AS paramNames,
+             e.paramTypes AS paramTypes,
+             e.returnType AS returnType,
+             e.isAsync AS isAsync,
+             e.buildOrMacro AS buildOrMacro,
+             e.cfg AS cfg
+      `,
+      {
+        repository: query.repository,
+        query: query.query,
+        queryTokens,
+        kind: query.kind ?? null,
+        buildOrMacro: query.build_or_macro ?? null,
+      }
+    );
+
+    const platform = this.toTargetPlatform(query.platform);
+    const minScore = query.min_score ?? (query.token_match ? DEFAULT_TOKEN_MATCH_MIN_SCORE : 0);
+    const matches: SymbolSearchMatch[] = [];
+    for (const row of rows) {
+      const match = scoreSymbolName(row.name, query.query, query.token_match);
+      if (!match || match.score < minScore || !this.compilesFor(row.cfg, platform)) continue;
+      matches.push({
+        ...this.toSymbolLocation(row),
+        ...(row.kind !== null && { kind: row.kind }),
+        ...this.toSignatureFields(row),
+        ...(row.buildOrMacro && { build_or_macro: true as const }),
+        ...(row.cfg && { cfg: row.cfg }),
+        match_type: match.type,
+        score: match.score,
+      });
+    }
+
+    matches.sort(
+      (a, b) =>
+        b.score - a.score ||
+        a.name.localeCompare(b.name) ||
+        a.file_path.localeCompare(b.file_path) ||
+        a.start_line - b.start_line
+    );
+
+    return this.withSearchContext(query, {
+      query: query.query,
+      repository: query.repository,
+      matches: matches.slice(0, query.limit),
+      metadata: {
+        total_matches: matches.length,
+        token_match: query.token_match,
+        min_score_applied: minScore,
+        query_time_ms: 0, // Updated by caller
+      },
+    });
+  }
+
+  /**
+   * Parse the `platform` of a search; validation has already rejected
+   * unrecognized platforms.
+   */
+  private toTargetPlatform(platform: string | undefined): TargetPlatform | undefined {
+    return platform !== undefined ? parseTargetPlatform(platform) : undefined;
+  }
+
+  /**
+   * Whether a symbol with a `cfg` condition can be compiled for a platform.
+   * Ungated symbols, and every symbol when no platform is given, match.
+   */
+  private compilesFor(
+    cfg: string | null | undefined,
+    platform: TargetPlatform | undefined
+  ): boolean {
+    return !cfg || platform === undefined || isCfgSatisfiable(cfg, platform);
+  }
+
+  /**
+   * Attach the doc summaries of the returned symbols and of their modules
+   * when `include_context` is set. Reads the `doc` and `moduleDoc`
+   * properties written at ingestion; symbols without them get neither field.
+   */
+  private async withSearchContext(
+    query: ValidatedSymbolSearchQuery,
+    result: SymbolSearchResult
+  ): Promise<SymbolSearchResult> {
+    if (!query.include_context || result.matches.length === 0) {
+      return result;
+    }
+
+    const rows = await this.graphAdapter.runQuery<SymbolContextRow>(
+      `
+      MATCH (e {repository: $repository})
+      WHERE e.id IN $ids
+      OPTIONAL MATCH (f:File {repository: $repository, path: e.filePath})
+      RETURN e.id AS id,
+             e.doc AS doc,
+             f.moduleDoc AS moduleDoc
+      `,
+      { repository: query.repository, ids: result.matches.map((match) => match.id) }
+    );
+
+    const contexts = new Map(rows.map((row) => [row.id, row]));
+    const summarize = (doc: string | null | undefined): string | undefined => {
+      const summary = doc ? docSummary(doc) : "";
+      return summary !== "" ? summary : undefined;
+    };
+    return {
+      ...result,
+      matches: result.matches.map((match) => {
+        const context = contexts.get(match.id);
+        const docText = summarize(context?.doc);
+        const moduleDocText = summarize(context?.moduleDoc);
+        return {
+          ...match,
+          ...(docText !== undefined && { doc_summary: docText }),
+          ...(moduleDocText !== undefined && { module_doc_summary: moduleDocText }),
+        };
+      }),
+    };
+  }
+
+  /**
+   * Search where-clause text, e.g. "where:Hash" for items bounded by Hash.
+   * An empty bound ("where:") matches every item with a where clause.
+   */
+  private async executeWhereClauseSearchQuery(
+    query: ValidatedSymbolSearchQuery
+  ): Promise<SymbolSearchResult> {
+    const bound = query.query.slice(WHERE_CLAUSE_QUERY_PREFIX.length).trim();
+
+    const rows = await this.graphAdapter.runQuery<WhereClauseSearchRow>(
+      `
+      MATCH (e {repository: $repository})
+      WHERE (${ENTITY_LABELS.map((label) => `e:${label}`).join(" OR ")})
+        AND e.whereClause IS NOT NULL
+        AND ($kind IS NULL OR e.kind = $kind)
+        AND ($buildOrMacro IS NULL OR coalesce(e.buildOrMacro, false) = $buildOrMacro)
+        AND toLower(e.whereClause) CONTAINS toLower($bound)
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.kind AS kind,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine,
+             e.paramNames AS paramNames,
+             e.paramTypes AS paramTypes,
+             e.returnType AS returnType,
+             e.isAsync AS isAsync,
+             e.buildOrMacro AS buildOrMacro,
+             e.cfg AS cfg,
+             e.whereClause AS whereClause
+      ORDER BY name, filePath, startLine
+      `,
+      {
+        repository: query.repository,
+        bound,
+        kind: query.kind ?? null,
+        buildOrMacro: query.build_or_macro ?? null,
+      }
+    );
+
+    const platform = this.toTargetPlatform(query.platform);
+    const matches = rows
+      .filter((row) => this.compilesFor(row.cfg, platform))
+      .map((row): SymbolSearchMatch => ({
+        ...this.toSymbolLocation(row),
+        ...(row.kind !== null && { kind: row.kind }),
+        ...this.toSignatureFields(row),
+        ...(row.buildOrMacro && { build_or_macro: true as const }),
+        ...(row.cfg && { cfg: row.cfg }),
+        match_type: "where_clause",
+        score: 1,
+        where_clause: row.whereClause,
+      }));
+
+    // Where-clause matches are exact text matches, all scoring 1
+    return {
+      query: query.query,
+      repository: query.repository,
+      matches: matches.slice(0, query.limit),
+      metadata: {
+        total_matches: matches.length,
+        token_match: query.token_match,
+        min_score_applied: query.min_score ?? 0,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeTypeSearchQuery(query: ValidatedTypeSearchQuery): Promise<TypeSearchResult> {
+    const rows = await this.graphAdapter.runQuery<SymbolSearchRow>(
+      `
+      MATCH (e:Function {repository: $repository})
+      WHERE e.paramTypes IS NOT NULL
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.kind AS kind,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine,
+             e.paramNames AS paramNames,
+             e.paramTypes AS paramTypes,
+             e.returnType AS returnType,
+             e.isAsync AS isAsync
+      `,
+      { repository: query.repository }
+    );
+
+    const matchParams = query.position !== "return";
+    const matchReturn = query.position !== "param";
+
+    const matches: TypeSearchMatch[] = [];
+    for (const row of rows) {
+      const paramNames = row.paramNames ?? [];
+      const paramTypes = matchParams ? (row.paramTypes ?? []) : [];
+      const found: TypeMatchType[] = [];
+
+      const matchedParams: string[] = [];
+      paramTypes.forEach((type, i) => {
+        const match = matchType(type, query.type, query.contains);
+        if (match) {
+          found.push(match);
+          matchedParams.push(paramNames[i] ?? `#${i}`);
+        }
+      });
+
+      const returnMatch =
+        matchReturn && row.returnType
+          ? matchType(row.returnType, query.type, query.contains)
+          : null;
+      if (returnMatch) found.push(returnMatch);
+
+      if (found.length === 0) continue;
+      const { params, return_type, is_async } = this.toSignatureFields(row);
+      matches.push({
+        ...this.toSymbolLocation(row),
+        ...(row.kind !== null && { kind: row.kind }),
+        params: params ?? [],
+        ...(return_type !== undefined && { return_type }),
+        ...(is_async !== undefined && { is_async }),
+        matched_params: matchedParams,
+        matched_return: returnMatch !== null,
+        match_type: found.includes("exact") ? "exact" : "contains",
+      });
+    }
+
+    matches.sort(
+      (a, b) =>
+        Number(b.match_type === "exact") - Number(a.match_type === "exact") ||
+        a.name.localeCompare(b.name) ||
+        a.file_path.localeCompare(b.file_path) ||
+        a.start_line - b.start_line
+    );
+
+    return {
+      type: query.type,
+      repository: query.repository,
+      position: query.position,
+      matches: matches.slice(0, query.limit),
+      metadata: {
+        total_matches: matches.length,
+        contains: query.contains,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeEntryPointsQuery(query: ValidatedEntryPointsQuery): Promise<EntryPointsResult> {
+    const normalizedPath = query.path
+      ?.replace(/\\/g, "/")
+      .replace(/^\.\//, "")
+      .replace(/\/+$/, "");
+
+    const rows = await this.graphAdapter.runQuery<EntryPointRow>(
+      `
+      MATCH (e:Function {repository: $repository})
+      WHERE e.entryPointKind IS NOT NULL
+        AND ($kind IS NULL OR e.entryPointKind = $kind)
+        AND ($path IS NULL OR e.filePath = $path OR e.filePath STARTS WITH $dirPrefix)
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine,
+             e.entryPointKind AS entryPointKind,
+             e.attributes AS attributes
+      ORDER BY filePath, startLine
+      `,
+      {
+        repository: query.repository,
+        kind: query.kind ?? null,
+        path: normalizedPath ?? null,
+        dirPrefix: normalizedPath !== undefined ? `${normalizedPath}/` : null,
+      }
+    );
+
+    // Group in kind order; rows are already ordered by location within a kind
+    const ordered = ENTRY_POINT_KINDS.flatMap((kind) =>
+      rows.filter((row) => row.entryPointKind === kind)
+    );
+
+    const kindCounts: Partial<Record<EntryPointKind, number>> = {};
+    for (const row of ordered) {
+      kindCounts[row.entryPointKind] = (kindCounts[row.entryPointKind] ?? 0) + 1;
+    }
+
+    const groups: EntryPointGroup[] = [];
+    for (const row of ordered.slice(0, query.limit)) {
+      let group = groups[groups.length - 1];
+      if (group?.kind !== row.entryPointKind) {
+        group = { kind: row.entryPointKind, entry_points: [] };
+        groups.push(group);
+      }
+      group.entry_points.push({
+        ...this.toSymbolLocation(row),
+        ...(row.attributes !== null && row.attributes.length > 0 && { attributes: row.attributes }),
+      });
+    }
+
+    return {
+      repository: query.repository,
+      groups,
+      metadata: {
+        total_count: ordered.length,
+        kind_counts: kindCounts,
+        truncated: ordered.length > query.limit,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeAttributeSearchQuery(
+    query: ValidatedAttributeSearchQuery
+  ): Promise<AttributeSearchResult> {
+    // Accept the attribute as written in source, e.g. "#[serde]"
+    const name = attributePath(query.name.replace(/^#!?\[/, "").replace(/\]$/, ""));
+    const normalizedPath = query.path
+      ?.replace(/\\/g, "/")
+      .replace(/^\.\//, "")
+      .replace(/\/+$/, "");
+
+    // CONTAINS narrows the candidates; path and argument matching happen below
+    const rows = await this.graphAdapter.runQuery<AttributeRow>(
+      `
+      MATCH (e {repository: $repository})
+      WHERE (${ENTITY_LABELS.map((label) => `e:${label}`).join(" OR ")})
+        AND e.attributes IS NOT NULL
+        AND any(attribute IN e.attributes WHERE attribute CONTAINS $name)
+        AND ($path IS NULL OR e.filePath = $path OR e.filePath STARTS WITH $dirPrefix)
+      OPTIONAL MATCH (caller:Function)-[:CALLS]->(e)
+      WITH e, count(DISTINCT caller) AS callerCount
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine,
+             e.attributes AS attributes,
+             callerCount
+      ORDER BY filePath, startLine
+      `,
+      {
+        repository: query.repository,
+        name,
+        path: normalizedPath ?? null,
+        dirPrefix: normalizedPath !== undefined ? `${normalizedPath}/` : null,
+      }
+    );
+
+    const matches: AttributeMatch[] = [];
+    for (const row of rows) {
+      const attributes = row.attributes.filter((attribute) =>
+        this.matchesAttribute(attribute, name, query.arguments)
+      );
+      if (attributes.length > 0) {
+        matches.push({
+          ...this.toSymbolLocation(row),
+          attributes,
+          caller_count: Number(row.callerCount ?? 0),
+        });
+      }
+    }
+
+    return {
+      name,
+      repository: query.repository,
+      matches: matches.slice(0, query.limit),
+      metadata: {
+        total_matches: matches.length,
+        truncated: matches.length > query.limit,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeArityQuery(query: ValidatedArityQuery): Promise<ArityResult> {
+    const normalizedPath = query.path
+      ?.replace(/\\/g, "/")
+      .replace(/^\.\//, "")
+      .replace(/\/+$/, "");
+
+    const rows = await this.graphAdapter.runQuery<ArityRow>(
+      `
+      MATCH (e:Function {repository: $repository})
+      WHERE e.paramNames IS NOT NULL
+        AND ($exportedOnly = false OR e.isExported = true)
+        AND ($path IS NULL OR e.filePath = $path OR e.filePath STARTS WITH $dirPrefix)
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.kind AS kind,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine,
+             e.paramNames AS paramNames,
+             e.paramTypes AS paramTypes,
+             e.returnType AS returnType,
+             e.isAsync AS isAsync,
+             e.isExported AS isExported
+      `,
+      {
+        repository: query.repository,
+        exportedOnly: query.exported_only,
+        path: normalizedPath ?? null,
+        dirPrefix: normalizedPath !== undefined ? `${normalizedPath}/` : null,
+      }
+    );
+
+    const functions: ArityMatch[] = [];
+    for (const row of rows) {
+      const paramNames = row.paramNames ?? [];
+      const hasReceiver = paramNames[0] === "self";
+      const paramCount = paramNames.length - (hasReceiver && !query.count_receiver ? 1 : 0);
+      if (
+        paramCount < query.min_params ||
+        (query.max_params !== undefined && paramCount > query.max_params)
+      ) {
+        continue;
+      }
+
+      const { params, return_type } = this.toSignatureFields(row);
+      functions.push({
+        ...this.toSymbolLocation(row),
+        ...(row.kind !== null && { kind: row.kind }),
+        params: params ?? [],
+        param_count: paramCount,
+        has_receiver: hasReceiver,
+        is_exported: row.isExported === true,
+        ...(return_type !== undefined && { return_type }),
+      });
+    }
+
+    functions.sort(
+      (a, b) =>
+        b.param_count - a.param_count ||
+        a.name.localeCompare(b.name) ||
+        a.file_path.localeCompare(b.file_path) ||
+        a.start_line - b.start_line
+    );
+
+    return {
+      repository: query.repository,
+      min_params: query.min_params,
+      max_params: query.max_params ?? null,
+      functions: functions.slice(0, query.limit),
+      metadata: {
+        total_count: functions.length,
+        truncated: functions.length > query.limit,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeStringLiteralSearchQuery(
+    query: ValidatedStringLiteralSearchQuery
+  ): Promise<StringLiteralSearchResult> {
+    const normalizedPath = query.path
+      ?.replace(/\\/g, "/")
+      .replace(/^\.\//, "")
+      .replace(/\/+$/, "");
+
+    const rows = await this.graphAdapter.runQuery<StringLiteralRow>(
+      `
+      MATCH (f:File {repository: $repository})-[:HAS_STRING_LITERAL]->(s:StringLiteral)
+      WHERE (($caseSensitive AND s.value CONTAINS $query)
+             OR (NOT $caseSensitive AND toLower(s.value) CONTAINS toLower($query)))
+        AND ($path IS NULL OR f.path = $path OR f.path STARTS WITH $dirPrefix)
+      RETURN s.value AS value,
+             f.path AS filePath,
+             s.line AS line,
+             s.column AS column,
+             s.enclosingSymbol AS enclosingSymbol
+      ORDER BY filePath, line, column
+      `,
+      {
+        repository: query.repository,
+        query: query.query,
+        caseSensitive: query.case_sensitive,
+        path: normalizedPath ?? null,
+        dirPrefix: normalizedPath !== undefined ? `${normalizedPath}/` : null,
+      }
+    );
+
+    const matches: StringLiteralMatch[] = rows.slice(0, query.limit).map((row) => ({
+      value: row.value,
+      file_path: row.filePath,
+      line: row.line,
+      column: row.column ?? 0,
+      ...(row.enclosingSymbol !== null && { enclosing_symbol: row.enclosingSymbol }),
+    }));
+
+    return {
+      query: query.query,
+      repository: query.repository,
+      matches,
+      metadata: {
+        total_matches: rows.length,
+        truncated: rows.length > matches.length,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeConstValueRangeQuery(
+    query: ValidatedConstValueRangeQuery
+  ): Promise<ConstValueRangeResult> {
+    const normalizedPath = query.path
+      ?.replace(/\\/g, "/")
+      .replace(/^\.\//, "")
+      .replace(/\/+$/, "");
+
+    const rows = await this.graphAdapter.runQuery<ConstValueRow>(
+      `
+      MATCH (e:Variable {repository: $repository})
+      WHERE e.valueKind IN $kinds
+        AND ($min IS NULL OR e.value >= $min)
+        AND ($max IS NULL OR e.value <= $max)
+        AND ($path IS NULL OR e.filePath = $path OR e.filePath STARTS WITH $dirPrefix)
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine,
+             e.valueKind AS valueKind,
+             e.value AS value,
+             e.valueText AS valueText
+      ORDER BY value, filePath, startLine
+      `,
+      {
+        repository: query.repository,
+        kinds: query.kind ? [query.kind] : ["integer", "float"],
+        min: query.min ?? null,
+        max: query.max ?? null,
+        path: normalizedPath ?? null,
+        dirPrefix: normalizedPath !== undefined ? `${normalizedPath}/` : null,
+      }
+    );
+
+    return {
+      repository: query.repository,
+      min: query.min ?? null,
+      max: query.max ?? null,
+      constants: rows.slice(0, query.limit).map((row) => ({
+        ...this.toSymbolLocation(row),
+        value_kind: row.valueKind,
+        value: Number(row.value),
+        value_text: row.valueText ?? String(row.value),
+      })),
+      metadata: {
+        total_count: rows.length,
+        truncated: rows.length > query.limit,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeDoctestSearchQuery(
+    query: ValidatedDoctestSearchQuery
+  ): Promise<DoctestSearchResult> {
+    const rows = await this.graphAdapter.runQuery<DoctestRow>(
+      `
+      MATCH (d:Doctest {repository: $repository})-[:DOCTEST_OF]->(e)
+      WHERE (e.name = $symbol OR $symbol IN coalesce(d.calls, []))
+        AND ($mode IS NULL OR d.mode = $mode)
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine,
+             d.startLine AS doctestStartLine,
+             d.endLine AS doctestEndLine,
+             d.info AS info,
+             d.mode AS mode,
+             d.code AS code,
+             d.calls AS calls
+      ORDER BY filePath, doctestStartLine
+      `,
+      { repository: query.repository, symbol: query.symbol, mode: query.mode ?? null }
+    );
+
+    const examples = rows
+      .map((row) => ({
+        file_path: row.filePath,
+        start_line: Number(row.doctestStartLine),
+        end_line: Number(row.doctestEndLine),
+        info: row.info ?? "",
+        mode: row.mode,
+        code: row.code,
+        documents_symbol: row.name === query.symbol,
+        calls_symbol: (row.calls ?? []).includes(query.symbol),
+        documented: this.toSymbolLocation(row),
+      }))
+      // Stable sort keeps file order within each group
+      .sort((a, b) => Number(b.documents_symbol) - Number(a.documents_symbol));
+
+    return {
+      repository: query.repository,
+      symbol: query.symbol,
+      examples: examples.slice(0, query.limit),
+      metadata: {
+        total_count: examples.length,
+        truncated: examples.length > query.limit,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeSimilarFunctionsQuery(
+    query: ValidatedSimilarFunctionsQuery
+  ): Promise<SimilarFunctionsResult> {
+    const normalizedPath = query.path
+      ?.replace(/\\/g, "/")
+      .replace(/^\.\//, "")
+      .replace(/\/+$/, "");
+
+    const rows = await this.graphAdapter.runQuery<StructureRow>(
+      `
+      MATCH (e:Function {repository: $repository})
+      WHERE e.structureHash IS NOT NULL
+        AND e.structureSize >= $minSize
+        AND ($path IS NULL OR e.filePath = $path OR e.filePath STARTS WITH $dirPrefix)
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine,
+             e.structureHash AS structureHash,
+             e.structureSize AS structureSize,
+             e.structureMinHash AS structureMinHash
+      ORDER BY filePath, startLine
+      `,
+      {
+        repository: query.repository,
+        minSize: query.min_size,
+        path: normalizedPath ?? null,
+        dirPrefix: normalizedPath !== undefined ? `${normalizedPath}/` : null,
+      }
+    );
+
+    // Exact clones share a structural hash
+    const shapes = new Map<string, StructureRow[]>();
+    for (const row of rows) {
+      const members = shapes.get(row.structureHash) ?? [];
+      members.push(row);
+      shapes.set(row.structureHash, members);
+    }
+
+    const toSimilarFunction = (row: StructureRow): SimilarFunction => ({
+      ...this.toSymbolLocation(row),
+      fingerprint: row.structureHash,
+      size: Number(row.structureSize),
+    });
+
+    const exactGroups: SimilarFunctionGroup[] = [];
+    for (const members of shapes.values()) {
+      if (members.length > 1) {
+        exactGroups.push({
+          kind: "exact",
+          similarity: 1,
+          functions: members.map(toSimilarFunction),
+        });
+      }
+    }
+
+    const nearGroups: SimilarFunctionGroup[] = [];
+    if (query.min_similarity < 1) {
+      for (const { hashes, similarity } of this.linkNearClones(shapes, query.min_similarity)) {
+        const members = hashes
+          .flatMap((hash) => shapes.get(hash) ?? [])
+          .sort(
+            (a, b) =>
+              a.filePath.localeCompare(b.filePath) || Number(a.startLine) - Number(b.startLine)
+          );
+        nearGroups.push({ kind: "near", similarity, functions: members.map(toSimilarFunction) });
+      }
+    }
+
+    const largestBody = (group: SimilarFunctionGroup): number =>
+      Math.max(...group.functions.map((fn) => fn.size));
+    const groups = [...exactGroups, ...nearGroups].sort(
+      (a, b) =>
+        b.similarity - a.similarity ||
+        largestBody(b) - largestBody(a) ||
+        b.functions.length - a.functions.length
+    );
+
+    return {
+      repository: query.repository,
+      min_similarity: query.min_similarity,
+      groups: groups.slice(0, query.limit),
+      metadata: {
+        functions_compared: rows.length,
+        exact_groups: exactGroups.length,
+        near_groups: nearGroups.length,
+        truncated: groups.length > query.limit,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  /**
+   * Link distinct body shapes whose estimated similarity meets the threshold
+   * and return the connected groups of shapes.
+   *
+   * Candidate pairs come from locality-sensitive hashing: shapes sharing a
+   * band of MinHash slots are compared, so the work stays close to linear
+   * instead of comparing every pair.
+   *
+   * @returns Groups of structural hashes with the lowest similarity among their links
+   */
+  private linkNearClones(
+    shapes: Map<string, StructureRow[]>,
+    minSimilarity: number
+  ): Array<{ hashes: string[]; similarity: number }> {
+    const fingerprints = new Map<string, StructuralFingerprint>();
+    const buckets = new Map<string, string[]>();
+    for (const [hash, members] of shapes) {
+      const minHash = (members[0]?.structureMinHash ?? []).map(Number);
+      if (minHash.length !== MIN_HASH_SLOTS) continue;
+      fingerprints.set(hash, { hash, size: Number(members[0]?.structureSize ?? 0), minHash });
+
+      for (let band = 0; band < MIN_HASH_SLOTS; band += NEAR_CLONE_BAND_ROWS) {
+        const key = `${band}:${minHash.slice(band, band + NEAR_CLONE_BAND_ROWS).join(",")}`;
+        const bucket = buckets.get(key) ?? [];
+        bucket.push(hash);
+        buckets.set(key, bucket);
+      }
+    }
+
+    // Links are symmetric, so strongly-connected components are the connected groups
+    const graph: AdjacencyMap = new Map();
+    const linkSimilarity = new Map<string, number>();
+    const link = (a: string, b: string): void => {
+      const successors = graph.get(a) ?? new Set<string>();
+      successors.add(b);
+      graph.set(a, successors);
+    };
+    for (const bucket of buckets.values()) {
+      for (let i = 0; i < bucket.length; i++) {
+        for (let j = i + 1; j < bucket.length; j++) {
+          const [a, b] = [bucket[i] ?? "", bucket[j] ?? ""].sort() as [string, string];
+          const pair = `${a}|${b}`;
+          const first = fingerprints.get(a);
+          const second = fingerprints.get(b);
+          if (linkSimilarity.has(pair) || !first || !second) continue;
+
+          const similarity = structuralSimilarity(first, second);
+          linkSimilarity.set(pair, similarity);
+          if (similarity >= minSimilarity) {
+            link(a, b);
+            link(b, a);
+          }
+        }
+      }
+    }
+
+    return findStronglyConnectedComponents(graph).map((hashes) => {
+      let similarity = 1;
+      for (const a of hashes) {
+        for (const b of graph.get(a) ?? []) {
+          const score = linkSimilarity.get(a < b ? `${a}|${b}` : `${b}|${a}`) ?? 1;
+          similarity = Math.min(similarity, score);
+        }
+      }
+      return { hashes, similarity: Math.round(similarity * 100) / 100 };
+    });
+  }
+
+  async executeNameResolutionQuery(
+    query: ValidatedNameResolutionQuery
+  ): Promise<NameResolutionResult> {
+    // `::` and `.` both separate segments, so `Point::new` also finds `Point.new`
+    const partial = query.partial.replace(/::/g, ".");
+    const name = partial.slice(partial.lastIndexOf(".") + 1);
+
+    const rows = await this.graphAdapter.runQuery<NameCandidateRow>(
+      `
+      MATCH (e {repository: $repository})
+      WHERE (${ENTITY_LABELS.map((label) => `e:${label}`).join(" OR ")})
+        AND toLower(e.name) = toLower($name)
+      OPTIONAL MATCH (f:File {repository: $repository, path: e.filePath})
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.kind AS kind,
+             e.filePath AS filePath,
+             e.startLine AS startLine,
+             e.endLine AS endLine,
+             e.parentName AS parentName,
+             f.crateName AS crateName
+      `,
+      { repository: query.repository, name }
+    );
+
+    const nameCounts = new Map<string, number>();
+    for (const row of rows) {
+      nameCounts.set(row.name, (nameCounts.get(row.name) ?? 0) + 1);
+    }
+
+    const candidates: NameResolutionCandidate[] = [];
+    for (const row of rows) {
+      const qualifiedName = this.qualifiedName(row);
+      const matchType = this.matchQualifiedName(qualifiedName, row.filePath, partial);
+      if (matchType === undefined) continue;
+      candidates.push({
+        ...this.toSymbolLocation(row),
+        qualified_name: qualifiedName,
+        ...(row.kind !== null && { kind: row.kind }),
+        ...(row.parentName !== null && { parent_name: row.parentName }),
+        match_type: matchType,
+        name_count: nameCounts.get(row.name) ?? 1,
+      });
+    }
+
+    candidates.sort(
+      (a, b) =>
+        Number(a.match_type !== "exact") - Number(b.match_type !== "exact") ||
+        a.name_count - b.name_count ||
+        a.qualified_name.localeCompare(b.qualified_name) ||
+        a.start_line - b.start_line
+    );
+
+    return {
+      partial: query.partial,
+      repository: query.repository,
+      candidates: candidates.slice(0, query.limit),
+      metadata: {
+        total_count: candidates.length,
+        unambiguous: candidates.filter((c) => c.match_type === "exact").length === 1,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  /**
+   * Whether a qualified name ends with a partial name (`::` already replaced
+   * by `.`) at a segment boundary, first as written and then ignoring case.
+   * The file extension may be left out (`shapes.Point` for `src/shapes.py`).
+   */
+  private matchQualifiedName(
+    qualifiedName: string,
+    filePath: string,
+    partial: string
+  ): NameResolutionMatchType | undefined {
+    const dotted = qualifiedName.replace(/::/g, ".");
+    const forms = [dotted];
+    const extension = path.posix.extname(filePath);
+    if (extension !== "" && dotted.startsWith(`${filePath}.`)) {
+      forms.push(filePath.slice(0, -extension.length) + dotted.slice(filePath.length));
+    }
+
+    const endsWith = (form: string, suffix: string): boolean =>
+      form === suffix || form.endsWith(`.${suffix}`) || form.endsWith(`/${suffix}`);
+    if (forms.some((form) => endsWith(form, partial))) {
+      return "exact";
+    }
+    const lowerPartial = partial.toLowerCase();
+    if (forms.some((form) => endsWith(form.toLowerCase(), lowerPartial))) {
+      return "case_insensitive";
+    }
+    return undefined;
+  }
+}
@@ -0,0 +1,1550 @@
+/**
+ * @module services/graph-analysis-service
+ *
+ * GraphAnalysisService implementation for symbol-level graph queries.
+ *
+ * Where GraphService answers traversal questions (dependencies, dependents,
+ * paths, architecture) through the adapter's high-level traversal API, this
+ * service issues targeted Cypher queries against the entity nodes written by
+ * GraphIngestionService:
+ *
+ * - `(:File)-[:DEFINES]->(:Function|:Class|:Variable)`
+ * - `(:File)-[:IMPORTS]->(:Module)`
+ *
+ * Whole-repository analyses (e.g. import cycles) load the relevant edges once
+ * and run the algorithm in memory (see graph-algorithms.ts).
+ *
+ * The queries live in one class per feature area (see
+ * graph-analysis-queries.ts); this class validates input, applies the
+ * timeout, logs and maps errors.
+ *
+ * Results are not cached: the queries are single-hop lookups and caching
+ * would require hooking into GraphService's per-repository invalidation.
+ */
+
+import { z } from "zod";
+import type { Logger } from "pino";
+import type { GraphStorageAdapter } from "../graph/adapters/types.js";
+import { isRetryableGraphError } from "../graph/errors.js";
+import { getComponentLogger } from "../logging/index.js";
+import {
+  SymbolImportsQuerySchema,
+  ModuleCyclesQuerySchema,
+  TopLevelSymbolsQuerySchema,
+  DocumentSymbolsQuerySchema,
+  TodoListQuerySchema,
+  SymbolSearchQuerySchema,
+  StringLiteralSearchQuerySchema,
+  CouplingMetricsQuerySchema,
+  MostCoupledQuerySchema,
+  HotspotsQuerySchema,
+  CouplingReportQuerySchema,
+  TypeSearchQuerySchema,
+  EntryPointsQuerySchema,
+  AttributeSearchQuerySchema,
+  CallPathQuerySchema,
+  DeprecationReportQuerySchema,
+  UnsafeCodeQuerySchema,
+  ComplexityQuerySchema,
+  SimilarFunctionsQuerySchema,
+  ConstValueRangeQuerySchema,
+  DoctestSearchQuerySchema,
+  MissingDocsQuerySchema,
+  NameResolutionQuerySchema,
+  TopologicalOrderQuerySchema,
+  LocalBindingsQuerySchema,
+  LargeTypesQuerySchema,
+  SymbolsAtLinesQuerySchema,
+  ExternalDependenciesQuerySchema,
+  ArityQuerySchema,
+  CallSitesQuerySchema,
+  ImportSuggestionQuerySchema,
+  LeakyApiQuerySchema,
+  TypeHierarchyQuerySchema,
+} from "./graph-analysis-validation.js";
+import {
+  GraphServiceValidationError,
+  GraphServiceOperationError,
+  GraphServiceTimeoutError,
+  GraphServiceCancelledError,
+  EntityNotFoundError,
+} from "./graph-service-errors.js";
+import type {
+  GraphAnalysisService,
+  SymbolImportsQuery,
+  SymbolImportsResult,
+  ModuleCyclesQuery,
+  ModuleCyclesResult,
+  TopLevelSymbolsQuery,
+  TopLevelSymbolsResult,
+  DocumentSymbolsQuery,
+  DocumentSymbolsResult,
+  TodoListQuery,
+  TodoListResult,
+  SymbolSearchQuery,
+  SymbolSearchResult,
+  TypeSearchQuery,
+  TypeSearchResult,
+  EntryPointsQuery,
+  EntryPointsResult,
+  AttributeSearchQuery,
+  AttributeSearchResult,
+  DeprecationReportQuery,
+  DeprecationReportResult,
+  UnsafeCodeQuery,
+  UnsafeCodeResult,
+  ComplexityQuery,
+  ComplexityResult,
+  SimilarFunctionsQuery,
+  SimilarFunctionsResult,
+  ConstValueRangeQuery,
+  ConstValueRangeResult,
+  DoctestSearchQuery,
+  DoctestSearchResult,
+  MissingDocsQuery,
+  MissingDocsResult,
+  NameResolutionQuery,
+  NameResolutionResult,
+  TopologicalOrderQuery,
+  TopologicalOrderResult,
+  LocalBindingsQuery,
+  LocalBindingsResult,
+  LargeTypesQuery,
+  LargeTypesResult,
+  SymbolsAtLinesQuery,
+  SymbolsAtLinesResult,
+  ExternalDependenciesQuery,
+  ExternalDependenciesResult,
+  ArityQuery,
+  ArityResult,
+  CallSitesQuery,
+  CallSitesResult,
+  ImportSuggestionQuery,
+  ImportSuggestionResult,
+  LeakyApiQuery,
+  LeakyApiResult,
+  StringLiteralSearchQuery,
+  StringLiteralSearchResult,
+  CouplingMetricsQuery,
+  CouplingMetricsResult,
+  MostCoupledQuery,
+  MostCoupledResult,
+  HotspotsQuery,
+  HotspotsResult,
+  CouplingReportQuery,
+  CouplingReportResult,
+  CallPathQuery,
+  CallPathResult,
+  TypeHierarchyQuery,
+  TypeHierarchyResult,
+} from "./graph-analysis-types.js";
+import { SearchQueries } from "./graph-analysis-search.js";
+import { StructureQueries } from "./graph-analysis-structure.js";
+import { HierarchyQueries } from "./graph-analysis-hierarchy.js";
+import { MetricsQueries } from "./graph-analysis-metrics.js";
+import { ImportQueries } from "./graph-analysis-imports.js";
+
+// =============================================================================
+// Configuration
+// =============================================================================
+
+/**
+ * Configuration for GraphAnalysisService
+ */
+export interface GraphAnalysisServiceConfig {
+  /**
+   * Query timeout in milliseconds
+   * @default 30000
+   */
+  timeoutMs: number;
+}
+
+/**
+ * Default GraphAnalysisService configuration
+ */
+export const DEFAULT_GRAPH_ANALYSIS_SERVICE_CONFIG: GraphAnalysisServiceConfig = {
+  timeoutMs: 30000, // 30 seconds
+};
+
+// =============================================================================
+// GraphAnalysisService Implementation
+// =============================================================================
+
+/**
+ * Implementation of GraphAnalysisService using Cypher queries
+ *
+ * @example
+ * ```typescript
+ * const analysis = new GraphAnalysisServiceImpl(graphAdapter);
+ *
+ * // Which imports are available where `validateToken` is defined?
+ * const result = await analysis.getSymbolImports({
+ *   symbol: "validateToken",
+ *   repository: "my-project",
+ * });
+ * ```
+ */
+export class GraphAnalysisServiceImpl implements GraphAnalysisService {
+  private _logger: Logger | null = null;
+  private readonly config: GraphAnalysisServiceConfig;
+  private readonly search: SearchQueries;
+  private readonly structure: StructureQueries;
+  private readonly hierarchy: HierarchyQueries;
+  private readonly metrics: MetricsQueries;
+  private readonly imports: ImportQueries;
+
+  /**
+   * Create a new GraphAnalysisService instance
+   *
+   * @param graphAdapter - Graph storage adapter for graph operations
+   * @param config - Optional configuration overrides
+   */
+  constructor(graphAdapter: GraphStorageAdapter, config: Partial<GraphAnalysisServiceConfig> = {}) {
+    this.config = { ...DEFAULT_GRAPH_ANALYSIS_SERVICE_CONFIG, ...config };
+    this.search = new SearchQueries(graphAdapter);
+    this.structure = new StructureQueries(graphAdapter);
+    this.hierarchy = new HierarchyQueries(graphAdapter);
+    this.metrics = new MetricsQueries(graphAdapter);
+    this.imports = new ImportQueries(graphAdapter);
+  }
+
+  /**
+   * Lazy-initialized logger
+   */
+  private get logger(): Logger {
+    if (!this._logger) {
+      this._logger = getComponentLogger("services:graph-analysis");
+    }
+    return this._logger;
+  }
+
+  // ===========================================================================
+  // Public API Methods
+  // ===========================================================================
+
+  /**
+   * Get the import statements of the file(s) defining a symbol
+   */
+  async getSymbolImports(query: SymbolImportsQuery): Promise<SymbolImportsResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(SymbolImportsQuerySchema, query, "symbol imports query");
+
+      const result = await this.withTimeout(
+        this.imports.executeSymbolImportsQuery(validated),
+        "getSymbolImports"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          symbol: validated.symbol,
+          repository: validated.repository,
+          match_count: result.matches.length,
+          query_time_ms: queryTimeMs,
+        },
+        "getSymbolImports completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "getSymbolImports", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Find circular dependencies in a repository's file import graph
+   */
+  async findModuleCycles(query: ModuleCyclesQuery): Promise<ModuleCyclesResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(ModuleCyclesQuerySchema, query, "module cycles query");
+
+      const result = await this.withTimeout(
+        this.hierarchy.executeModuleCyclesQuery(validated),
+        "findModuleCycles"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          cycle_count: result.metadata.cycle_count,
+          self_import_count: result.self_imports.length,
+          query_time_ms: queryTimeMs,
+        },
+        "findModuleCycles completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "findModuleCycles", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * List the top-level declarations of a file or directory
+   */
+  async getTopLevelSymbols(query: TopLevelSymbolsQuery): Promise<TopLevelSymbolsResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(TopLevelSymbolsQuerySchema, query, "top-level symbols query");
+
+      const result = await this.withTimeout(
+        this.structure.executeTopLevelSymbolsQuery(validated),
+        "getTopLevelSymbols"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          path: validated.path,
+          symbol_count: result.metadata.symbol_count,
+          query_time_ms: queryTimeMs,
+        },
+        "getTopLevelSymbols completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "getTopLevelSymbols", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Build the LSP-style symbol tree of a file
+   */
+  async getDocumentSymbols(query: DocumentSymbolsQuery): Promise<DocumentSymbolsResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(DocumentSymbolsQuerySchema, query, "document symbols query");
+
+      const result = await this.withTimeout(
+        this.structure.executeDocumentSymbolsQuery(validated),
+        "getDocumentSymbols"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          path: validated.path,
+          symbol_count: result.metadata.symbol_count,
+          query_time_ms: queryTimeMs,
+        },
+        "getDocumentSymbols completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "getDocumentSymbols", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * List TODO/FIXME marker comments of a repository
+   *
+   * Markers are stored as `(:File)-[:HAS_TODO]->(:Todo)` at ingestion, using
+   * the tags configured for the parser.
+   */
+  async listTodos(query: TodoListQuery): Promise<TodoListResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(TodoListQuerySchema, query, "TODO listing query");
+
+      const result = await this.withTimeout(
+        this.search.executeTodoListQuery(validated),
+        "listTodos"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          tag: validated.tag,
+          total_count: result.metadata.total_count,
+          query_time_ms: queryTimeMs,
+        },
+        "listTodos completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "listTodos", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Search code symbols by name
+   *
+   * Candidates are fetched by case-insensitive substring match, plus subtoken
+   * overlap when `token_match` is enabled, and ranked in memory so exact and
+   * prefix matches come before substring and subtoken matches. Matches below
+   * `min_score` are dropped before the limit, so weak subtoken matches do not
+   * crowd out the page.
+   */
+  async searchSymbols(query: SymbolSearchQuery): Promise<SymbolSearchResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(SymbolSearchQuerySchema, query, "symbol search query");
+
+      const result = await this.withTimeout(
+        this.search.executeSymbolSearchQuery(validated),
+        "searchSymbols"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          query: validated.query,
+          token_match: validated.token_match,
+          total_matches: result.metadata.total_matches,
+          query_time_ms: queryTimeMs,
+        },
+        "searchSymbols completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "searchSymbols", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Find functions by parameter or return type
+   *
+   * Reads the `paramTypes`/`returnType` properties written at ingestion and
+   * matches them in memory, so reference and generic wrappers can be looked
+   * through. Graphs ingested before these properties existed find nothing.
+   */
+  async searchByType(query: TypeSearchQuery): Promise<TypeSearchResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(TypeSearchQuerySchema, query, "type search query");
+
+      const result = await this.withTimeout(
+        this.search.executeTypeSearchQuery(validated),
+        "searchByType"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          type: validated.type,
+          position: validated.position,
+          contains: validated.contains,
+          total_matches: result.metadata.total_matches,
+          query_time_ms: queryTimeMs,
+        },
+        "searchByType completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "searchByType", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * List the entry points of a repository grouped by kind
+   *
+   * Reads the `entryPointKind` property written at ingestion for `fn main`,
+   * runtime `#[...::main]`, `#[test]` and `#[bench]` functions. Graphs
+   * ingested before the property existed find nothing.
+   */
+  async listEntryPoints(query: EntryPointsQuery): Promise<EntryPointsResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(EntryPointsQuerySchema, query, "entry point query");
+
+      const result = await this.withTimeout(
+        this.search.executeEntryPointsQuery(validated),
+        "listEntryPoints"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          kind: validated.kind,
+          total_count: result.metadata.total_count,
+          query_time_ms: queryTimeMs,
+        },
+        "listEntryPoints completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "listEntryPoints", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Find symbols annotated with an attribute
+   *
+   * Reads the `attributes` property written at ingestion for Rust items.
+   * Attributes are matched on their path, so `deprecated` finds both
+   * `#[deprecated]` and `#[deprecated(since = "1.2")]` unless an argument
+   * filter is given.
+   */
+  async findByAttribute(query: AttributeSearchQuery): Promise<AttributeSearchResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(AttributeSearchQuerySchema, query, "attribute search query");
+
+      const result = await this.withTimeout(
+        this.search.executeAttributeSearchQuery(validated),
+        "findByAttribute"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          name: validated.name,
+          total_matches: result.metadata.total_matches,
+          query_time_ms: queryTimeMs,
+        },
+        "findByAttribute completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "findByAttribute", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Report deprecated symbols with the calls still referencing them
+   *
+   * Deprecated symbols are those carrying a `deprecated` attribute recorded
+   * at ingestion; call sites come from the CALLS edges pointing at them.
+   */
+  async getDeprecationReport(query: DeprecationReportQuery): Promise<DeprecationReportResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(
+        DeprecationReportQuerySchema,
+        query,
+        "deprecation report query"
+      );
+
+      const result = await this.withTimeout(
+        this.metrics.executeDeprecationReportQuery(validated),
+        "getDeprecationReport"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          total_deprecated: result.metadata.total_deprecated,
+          in_use_count: result.metadata.in_use_count,
+          query_time_ms: queryTimeMs,
+        },
+        "getDeprecationReport completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "getDeprecationReport", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * List unsafe functions, traits, impls and `unsafe` blocks
+   *
+   * Reads the `isUnsafe` flag and unsafe block spans written at ingestion
+   * for Rust functions and traits, and the `isUnsafe` flag of Impl nodes.
+   * Graphs ingested before these properties existed find nothing.
+   */
+  async listUnsafe(query: UnsafeCodeQuery): Promise<UnsafeCodeResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(UnsafeCodeQuerySchema, query, "unsafe code query");
+
+      const result = await this.withTimeout(
+        this.metrics.executeUnsafeCodeQuery(validated),
+        "listUnsafe"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          unsafe_symbol_count: result.metadata.unsafe_symbol_count,
+          unsafe_block_count: result.metadata.unsafe_block_count,
+          unsafe_impl_count: result.metadata.unsafe_impl_count,
+          query_time_ms: queryTimeMs,
+        },
+        "listUnsafe completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "listUnsafe", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * List functions whose approximate cyclomatic complexity meets a threshold
+   *
+   * Reads the `complexity` property written at ingestion for Rust functions
+   * with a body. Graphs ingested before the property existed find nothing.
+   */
+  async listComplex(query: ComplexityQuery): Promise<ComplexityResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(ComplexityQuerySchema, query, "complexity query");
+
+      const result = await this.withTimeout(
+        this.metrics.executeComplexityQuery(validated),
+        "listComplex"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          threshold: validated.threshold,
+          total_count: result.metadata.total_count,
+          query_time_ms: queryTimeMs,
+        },
+        "listComplex completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "listComplex", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Group functions whose bodies are structurally identical or similar
+   *
+   * Bodies are compared by the structural fingerprints written at ingestion
+   * (see `graph/parsing/structural-fingerprint`): equal hashes make exact
+   * clones, and distinct shapes whose estimated similarity meets the
+   * threshold are linked into near-clone groups. Exact clones of a shape in
+   * a near group are listed in both groups.
+   */
+  async findSimilarFunctions(query: SimilarFunctionsQuery): Promise<SimilarFunctionsResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(
+        SimilarFunctionsQuerySchema,
+        query,
+        "similar functions query"
+      );
+
+      const result = await this.withTimeout(
+        this.search.executeSimilarFunctionsQuery(validated),
+        "findSimilarFunctions"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          min_similarity: validated.min_similarity,
+          exact_groups: result.metadata.exact_groups,
+          near_groups: result.metadata.near_groups,
+          query_time_ms: queryTimeMs,
+        },
+        "findSimilarFunctions completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "findSimilarFunctions", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Find constants initialized with a number in a range
+   *
+   * Only `const`/`static` items whose initializer is a literal carry a value
+   * (decoded at ingestion, see `graph/parsing/literal-values`); computed
+   * constants such as `1 << 10` are never matched.
+   */
+  async findConstsByValueRange(query: ConstValueRangeQuery): Promise<ConstValueRangeResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(ConstValueRangeQuerySchema, query, "constant value query");
+
+      const result = await this.withTimeout(
+        this.search.executeConstValueRangeQuery(validated),
+        "findConstsByValueRange"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          min: validated.min,
+          max: validated.max,
+          total_count: result.metadata.total_count,
+          query_time_ms: queryTimeMs,
+        },
+        "findConstsByValueRange completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "findConstsByValueRange", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Find the doc comment code examples documenting or calling a symbol
+   *
+   * Examples are stored as `(:Doctest)-[:DOCTEST_OF]->(entity)` at ingestion,
+   * with the names each example calls, so usage examples of a function are
+   * found both in its own docs and in the docs of other items.
+   */
+  async findDoctests(query: DoctestSearchQuery): Promise<DoctestSearchResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(DoctestSearchQuerySchema, query, "doctest search query");
+
+      const result = await this.withTimeout(
+        this.search.executeDoctestSearchQuery(validated),
+        "findDoctests"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          symbol: validated.symbol,
+          total_count: result.metadata.total_count,
+          query_time_ms: queryTimeMs,
+        },
+        "findDoctests completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "findDoctests", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * List the public symbols that have no documentation, grouped by module
+   *
+   * Visibility and the normalized doc text are stored on entity nodes at
+   * ingestion. Methods of trait impls are skipped because they inherit the
+   * trait's documentation, as are `#[doc(hidden)]` items. Graphs ingested
+   * before these properties existed report no public symbols.
+   */
+  async listMissingDocs(query: MissingDocsQuery): Promise<MissingDocsResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(MissingDocsQuerySchema, query, "missing docs query");
+
+      const result = await this.withTimeout(
+        this.metrics.executeMissingDocsQuery(validated),
+        "listMissingDocs"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          path: validated.path,
+          missing_count: result.metadata.missing_count,
+          coverage: result.metadata.coverage,
+          query_time_ms: queryTimeMs,
+        },
+        "listMissingDocs completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "listMissingDocs", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Resolve a short or partially qualified name to the symbols it may refer to
+   *
+   * Every symbol whose name, or a suffix of whose qualified path, matches the
+   * partial name is a candidate. Names shared by fewer symbols rank higher, so
+   * the most specific choices come first.
+   */
+  async resolveName(query: NameResolutionQuery): Promise<NameResolutionResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(NameResolutionQuerySchema, query, "name resolution query");
+
+      const result = await this.withTimeout(
+        this.search.executeNameResolutionQuery(validated),
+        "resolveName"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          partial: validated.partial,
+          total_count: result.metadata.total_count,
+          query_time_ms: queryTimeMs,
+        },
+        "resolveName completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "resolveName", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Order functions so that callees come before their callers
+   *
+   * Built from the `(:Function)-[:CALLS]->(:Function)` edges written at
+   * ingestion. Mutually recursive functions are condensed into one group;
+   * the condensed graph is emitted leaves first, with ties broken by file and
+   * line so the order is stable across runs.
+   */
+  async getTopologicalOrder(query: TopologicalOrderQuery): Promise<TopologicalOrderResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(
+        TopologicalOrderQuerySchema,
+        query,
+        "topological order query"
+      );
+
+      const result = await this.withTimeout(
+        this.hierarchy.executeTopologicalOrderQuery(validated),
+        "getTopologicalOrder"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          path: validated.path,
+          symbol_count: result.metadata.symbol_count,
+          cyclic_group_count: result.metadata.cyclic_group_count,
+          query_time_ms: queryTimeMs,
+        },
+        "getTopologicalOrder completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "getTopologicalOrder", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * List the local variables a function binds, with their inferred types
+   *
+   * Bindings are stored on the function node at ingestion as parallel
+   * `localNames`/`localTypes`/`localLines` lists (Rust only).
+   */
+  async getLocalBindings(query: LocalBindingsQuery): Promise<LocalBindingsResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(LocalBindingsQuerySchema, query, "local bindings query");
+
+      const result = await this.withTimeout(
+        this.structure.executeLocalBindingsQuery(validated),
+        "getLocalBindings"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          symbol: validated.symbol,
+          repository: validated.repository,
+          function_count: result.metadata.function_count,
+          local_count: result.metadata.local_count,
+          query_time_ms: queryTimeMs,
+        },
+        "getLocalBindings completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "getLocalBindings", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * List types whose impls define many methods
+   *
+   * Impl items are attributed to the `Impl` node of the same file and
+   * implementing type whose line range encloses them; items of trait
+   * definitions and blanket impls are not counted. Types are matched by
+   * base name, so types sharing a name share their impls.
+   */
+  async findLargeTypes(query: LargeTypesQuery): Promise<LargeTypesResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(LargeTypesQuerySchema, query, "large types query");
+
+      const result = await this.withTimeout(
+        this.metrics.executeLargeTypesQuery(validated),
+        "findLargeTypes"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          method_threshold: validated.method_threshold,
+          total_count: result.metadata.total_count,
+          query_time_ms: queryTimeMs,
+        },
+        "findLargeTypes completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "findLargeTypes", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * List the symbols whose span overlaps a line range of a file
+   *
+   * Maps a stack trace line or diff hunk back to the code responsible:
+   * a line inside a method returns the method, then its impl block.
+   */
+  async getSymbolsAtLines(query: SymbolsAtLinesQuery): Promise<SymbolsAtLinesResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(SymbolsAtLinesQuerySchema, query, "symbols at lines query");
+
+      const result = await this.withTimeout(
+        this.structure.executeSymbolsAtLinesQuery(validated),
+        "getSymbolsAtLines"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          path: validated.path,
+          start_line: result.start_line,
+          end_line: result.end_line,
+          symbol_count: result.metadata.symbol_count,
+          query_time_ms: queryTimeMs,
+        },
+        "getSymbolsAtLines completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "getSymbolsAtLines", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * List the external crates a Rust repository imports from
+   *
+   * Derived from `use` paths that do not resolve within the repository:
+   * paths rooted at `crate`, `self`, `super`, a workspace crate, a local
+   * module or an indexed symbol are internal; every other root is a crate.
+   */
+  async findExternalDependencies(
+    query: ExternalDependenciesQuery
+  ): Promise<ExternalDependenciesResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(
+        ExternalDependenciesQuerySchema,
+        query,
+        "external dependencies query"
+      );
+
+      const result = await this.withTimeout(
+        this.imports.executeExternalDependenciesQuery(validated),
+        "findExternalDependencies"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          std_count: result.metadata.std_count,
+          third_party_count: result.metadata.third_party_count,
+          query_time_ms: queryTimeMs,
+        },
+        "findExternalDependencies completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "findExternalDependencies", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Find functions whose parameter count falls within a range
+   *
+   * Counts the `paramNames` property written at ingestion. A Rust `self`
+   * receiver is left out of the count unless `count_receiver` is set, so
+   * methods and free functions taking the same arguments compare equal.
+   */
+  async findFunctionsByArity(query: ArityQuery): Promise<ArityResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(ArityQuerySchema, query, "arity query");
+
+      const result = await this.withTimeout(
+        this.search.executeArityQuery(validated),
+        "findFunctionsByArity"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          min_params: validated.min_params,
+          max_params: validated.max_params,
+          total_count: result.metadata.total_count,
+          query_time_ms: queryTimeMs,
+        },
+        "findFunctionsByArity completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "findFunctionsByArity", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * List the call sites of a function with the arguments each passes
+   *
+   * Arguments are read from the `callSiteLines`/`callSiteArguments` lists
+   * written on CALLS edges when `GRAPH_CAPTURE_CALL_ARGUMENTS` is enabled.
+   * Edges written without them report their first call site with null
+   * arguments, and never match an argument filter.
+   */
+  async findCallSites(query: CallSitesQuery): Promise<CallSitesResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(CallSitesQuerySchema, query, "call sites query");
+
+      const result = await this.withTimeout(
+        this.hierarchy.executeCallSitesQuery(validated),
+        "findCallSites"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          symbol: validated.symbol,
+          repository: validated.repository,
+          total_count: result.metadata.total_count,
+          query_time_ms: queryTimeMs,
+        },
+        "findCallSites completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "findCallSites", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Suggest the `use` declarations a Rust file needs to name a symbol
+   *
+   * Paths go through the defining module or a `pub use` re-export of the
+   * item, and are checked against the visibility of the item and the
+   * re-export. Modules along a path are assumed reachable, since module
+   * declarations are not indexed.
+   */
+  async suggestImport(query: ImportSuggestionQuery): Promise<ImportSuggestionResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(
+        ImportSuggestionQuerySchema,
+        query,
+        "import suggestion query"
+      );
+
+      const result = await this.withTimeout(
+        this.imports.executeImportSuggestionQuery(validated),
+        "suggestImport"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          symbol: validated.symbol,
+          repository: validated.repository,
+          from_path: validated.from_path,
+          total_count: result.metadata.total_count,
+          query_time_ms: queryTimeMs,
+        },
+        "suggestImport completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "suggestImport", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Find public Rust functions whose parameter or return types name
+   * non-public types of the crate
+   *
+   * Functions and methods declared `pub` are checked, except methods of
+   * types that are not `pub` themselves and trait impl methods, which
+   * follow the trait. Each named type is resolved in the function's crate:
+   * qualified names through their module, plain names to a type visible
+   * from the function's module, preferring one in the same file. Plain
+   * names matching several such types are skipped, as `use` declarations
+   * are not consulted.
+   */
+  async findLeakyApi(query: LeakyApiQuery): Promise<LeakyApiResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(LeakyApiQuerySchema, query, "leaky API query");
+
+      const result = await this.withTimeout(
+        this.imports.executeLeakyApiQuery(validated),
+        "findLeakyApi"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          functions_checked: result.metadata.functions_checked,
+          total_count: result.metadata.total_count,
+          query_time_ms: queryTimeMs,
+        },
+        "findLeakyApi completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "findLeakyApi", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Search the contents of indexed string literals
+   *
+   * Literals are stored as `(:File)-[:HAS_STRING_LITERAL]->(:StringLiteral)`
+   * at ingestion when `GRAPH_INDEX_STRING_LITERALS` is enabled; otherwise the
+   * search finds nothing.
+   */
+  async grepStrings(query: StringLiteralSearchQuery): Promise<StringLiteralSearchResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(
+        StringLiteralSearchQuerySchema,
+        query,
+        "string literal search query"
+      );
+
+      const result = await this.withTimeout(
+        this.search.executeStringLiteralSearchQuery(validated),
+        "grepStrings"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          case_sensitive: validated.case_sensitive,
+          total_matches: result.metadata.total_matches,
+          query_time_ms: queryTimeMs,
+        },
+        "grepStrings completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "grepStrings", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Get the fan-in, fan-out and cycle membership of a function
+   *
+   * Computed from the `(:Function)-[:CALLS]->(:Function)` edges written at
+   * ingestion. Cycle membership needs the whole call graph, so all call edges
+   * of the repository are loaded and grouped into strongly-connected
+   * components.
+   */
+  async getCouplingMetrics(query: CouplingMetricsQuery): Promise<CouplingMetricsResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(CouplingMetricsQuerySchema, query, "coupling metrics query");
+
+      const result = await this.withTimeout(
+        this.metrics.executeCouplingMetricsQuery(validated),
+        "getCouplingMetrics"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          symbol: validated.symbol,
+          match_count: result.metadata.match_count,
+          query_time_ms: queryTimeMs,
+        },
+        "getCouplingMetrics completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "getCouplingMetrics", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Rank the functions of a repository by combined fan-in and fan-out
+   *
+   * Functions without any call edge are left out of the ranking.
+   */
+  async findMostCoupled(query: MostCoupledQuery): Promise<MostCoupledResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(MostCoupledQuerySchema, query, "most coupled query");
+
+      const result = await this.withTimeout(
+        this.metrics.executeMostCoupledQuery(validated),
+        "findMostCoupled"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          total_count: result.metadata.total_count,
+          call_edge_count: result.metadata.call_edge_count,
+          query_time_ms: queryTimeMs,
+        },
+        "findMostCoupled completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "findMostCoupled", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Rank the symbols of a repository by incoming references
+   *
+   * A reference is a call from another function or a named import from
+   * another file. Symbols nobody references are left out of the ranking.
+   */
+  async findHotspots(query: HotspotsQuery): Promise<HotspotsResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(HotspotsQuerySchema, query, "hotspots query");
+
+      const result = await this.withTimeout(
+        this.metrics.executeHotspotsQuery(validated),
+        "findHotspots"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          total_count: result.metadata.total_count,
+          call_edge_count: result.metadata.call_edge_count,
+          import_reference_count: result.metadata.import_reference_count,
+          query_time_ms: queryTimeMs,
+        },
+        "findHotspots completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "findHotspots", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Compute the afferent and efferent coupling and instability of each module
+   *
+   * Uses Robert C. Martin's package metrics over files or directories: Ca
+   * counts the modules depending on a module, Ce the modules it depends on,
+   * and I = Ce / (Ce + Ca). A dependency is a CALLS edge between functions or
+   * an IMPORTS edge resolved to a file, crossing a module boundary. Modules
+   * without any cross-module dependency are left out.
+   */
+  async getCouplingReport(query: CouplingReportQuery): Promise<CouplingReportResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(CouplingReportQuerySchema, query, "coupling report query");
+
+      const result = await this.withTimeout(
+        this.metrics.executeCouplingReportQuery(validated),
+        "getCouplingReport"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          granularity: validated.granularity,
+          module_count: result.metadata.module_count,
+          pair_count: result.metadata.pair_count,
+          query_time_ms: queryTimeMs,
+        },
+        "getCouplingReport completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "getCouplingReport", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Find the shortest chains of calls from one function to another
+   *
+   * Searches the `(:Function)-[:CALLS]->(:Function)` edges breadth-first in
+   * call direction. When a reference matches several definitions, paths may
+   * start or end at any of them.
+   */
+  async findCallPaths(query: CallPathQuery): Promise<CallPathResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(CallPathQuerySchema, query, "call path query");
+
+      const result = await this.withTimeout(
+        this.hierarchy.executeCallPathQuery(validated),
+        "findCallPaths"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          from: validated.from,
+          to: validated.to,
+          path_count: result.metadata.path_count,
+          query_time_ms: queryTimeMs,
+        },
+        "findCallPaths completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "findCallPaths", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Assemble the inheritance and trait hierarchy of a type
+   *
+   * Supertypes come from the extends/implements clauses stored on type
+   * nodes, traits from `(:File)-[:HAS_IMPL]->(:Impl)` nodes matched on the
+   * implementing type's name, and derives and generic bounds from the type's
+   * own attributes and generic parameters. Supertypes defined in the
+   * repository are followed level by level up to `max_depth`; an aborted
+   * `signal` stops the walk before the next level.
+   */
+  async getTypeHierarchy(
+    query: TypeHierarchyQuery,
+    signal?: AbortSignal
+  ): Promise<TypeHierarchyResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(TypeHierarchyQuerySchema, query, "type hierarchy query");
+
+      const result = await this.withTimeout(
+        this.hierarchy.executeTypeHierarchyQuery(validated, signal),
+        "getTypeHierarchy"
+      );
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          type_name: validated.type_name,
+          supertype_count: result.metadata.supertype_count,
+          query_time_ms: queryTimeMs,
+        },
+        "getTypeHierarchy completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "getTypeHierarchy", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  // ===========================================================================
+  // Private: Validation, Timeout and Error Handling
+  // ===========================================================================
+
+  /**
+   * Validate a query against its schema
+   *
+   * @throws {GraphServiceValidationError} If validation fails
+   */
+  private validate<S extends z.ZodType>(schema: S, query: unknown, label: string): z.infer<S> {
+    try {
+      return schema.parse(query);
+    } catch (error) {
+      if (error instanceof z.ZodError) {
+        const errors = error.issues.map((e) => `${e.path.join(".")}: ${e.message}`);
+        throw new GraphServiceValidationError(`Invalid ${label}: ${errors.join("; ")}`, errors);
+      }
+      throw error;
+    }
+  }
+
+  /**
+   * Wrap an operation with timeout handling
+   *
+   * Uses Promise.race with proper cleanup to avoid timer leaks.
+   */
+  private async withTimeout<T>(operation: Promise<T>, operationName: string): Promise<T> {
+    let timeoutId: ReturnType<typeof setTimeout> | undefined;
+
+    const timeoutPromise = new Promise<never>((_, reject) => {
+      timeoutId = setTimeout(() => {
+        reject(
+          new GraphServiceTimeoutError(
+            `${operationName} timed out after ${this.config.timeoutMs}ms`,
+            this.config.timeoutMs
+          )
+        );
+      }, this.config.timeoutMs);
+    });
+
+    try {
+      return await Promise.race([operation, timeoutPromise]);
+    } finally {
+      if (timeoutId !== undefined) {
+        clearTimeout(timeoutId);
+      }
+    }
+  }
+
+  /**
+   * Handle and log errors
+   *
+   * Known service errors are logged and rethrown by the caller; unexpected
+   * errors are wrapped in GraphServiceOperationError.
+   */
+  private handleError(error: unknown, operation: string, durationMs: number): void {
+    const duration = Math.round(durationMs);
+
+    if (
+      error instanceof GraphServiceValidationError ||
+      error instanceof GraphServiceTimeoutError ||
+      error instanceof GraphServiceCancelledError ||
+      error instanceof EntityNotFoundError
+    ) {
+      this.logger.warn(
+        {
+          error_type: error.constructor.name,
+          message: error.message,
+          retryable: error.retryable,
+          duration_ms: duration,
+        },
+        `${operation} failed with known error`
+      );
+    } else if (error instanceof Error) {
+      const retryable = isRetryableGraphError(error);
+      this.logger.error(
+        {
+          error_type: error.constructor.name,
+          message: error.message,
+          retryable,
+          duration_ms: duration,
+          stack: error.stack,
+        },
+        `${operation} failed with unexpected error`
+      );
+
+      if (!(error instanceof GraphServiceOperationError)) {
+        throw new GraphServiceOperationError(
+          `${operation} failed: ${error.message}`,
+          retryable,
+          error
+        );
+      }
+    }
+  }
+}
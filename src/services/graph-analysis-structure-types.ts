@@ -0,0 +1,299 @@
+/**
+ * @module services/graph-analysis-structure-types
+ *
+ * Type definitions for the GraphAnalysisService file structure queries.
+ */
+
+import type { SymbolLocation } from "./graph-analysis-types.js";
+
+// =============================================================================
+// Top-Level Symbols
+// =============================================================================
+
+/**
+ * Query for the top-level declarations of a file or directory
+ */
+export interface TopLevelSymbolsQuery {
+  /** Repository containing the file(s) */
+  repository: string;
+
+  /** File path, or directory path to cover every file beneath it */
+  path: string;
+
+  /**
+   * Whether to include the direct children (methods, fields, nested types)
+   * of each top-level symbol
+   * @default false
+   */
+  expand?: boolean;
+}
+
+/**
+ * A top-level symbol, optionally with its direct children
+ */
+export interface TopLevelSymbol extends SymbolLocation {
+  /** Direct children, present only when the query expands one level */
+  children?: SymbolLocation[];
+}
+
+/**
+ * Top-level symbols of one file
+ */
+export interface FileTopLevelSymbols {
+  /** File path relative to the repository root */
+  file_path: string;
+
+  /** Top-level symbols ordered by line */
+  symbols: TopLevelSymbol[];
+}
+
+/**
+ * Result of a top-level symbols query
+ */
+export interface TopLevelSymbolsResult {
+  /** Repository searched */
+  repository: string;
+
+  /** Path as requested */
+  path: string;
+
+  /** Files with at least one symbol, ordered by path */
+  files: FileTopLevelSymbols[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of files returned */
+    file_count: number;
+    /** Number of top-level symbols returned */
+    symbol_count: number;
+    /** Number of nested symbols not returned */
+    nested_omitted: number;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Document Symbols
+// =============================================================================
+
+/**
+ * Query for the symbol tree of a single file
+ */
+export interface DocumentSymbolsQuery {
+  /** Repository containing the file */
+  repository: string;
+
+  /** File path relative to the repository root */
+  path: string;
+}
+
+/**
+ * A position in a file, as in LSP (both 0-based)
+ */
+export interface LspPosition {
+  /** Line (0-based) */
+  line: number;
+
+  /** Column (0-based); a byte offset, which matches LSP characters on ASCII lines */
+  character: number;
+}
+
+/**
+ * A range in a file, as in LSP (end exclusive)
+ */
+export interface LspRange {
+  start: LspPosition;
+  end: LspPosition;
+}
+
+/**
+ * A symbol shaped like an LSP `DocumentSymbol`
+ *
+ * Keys follow the LSP spelling rather than snake_case so results can be
+ * returned from a `textDocument/documentSymbol` handler unchanged.
+ */
+export interface DocumentSymbol {
+  /** Symbol name; Rust impl blocks are named like `impl Trait for Type` */
+  name: string;
+
+  /** Signature, when recorded */
+  detail?: string;
+
+  /** Numeric LSP `SymbolKind` (see `graph/parsing/lsp-symbol-kinds`) */
+  kind: number;
+
+  /** Full extent of the symbol */
+  range: LspRange;
+
+  /** Extent of the symbol's name, within `range` */
+  selectionRange: LspRange;
+
+  /** Nested symbols ordered by position, omitted when there are none */
+  children?: DocumentSymbol[];
+}
+
+/**
+ * Result of a document symbols query
+ */
+export interface DocumentSymbolsResult {
+  /** Repository searched */
+  repository: string;
+
+  /** File path relative to the repository root */
+  file_path: string;
+
+  /** Top-level symbols ordered by position, with nested symbols as children */
+  symbols: DocumentSymbol[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of symbols at all nesting levels */
+    symbol_count: number;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Local Bindings
+// =============================================================================
+
+/**
+ * Query for the local variables of a function
+ */
+export interface LocalBindingsQuery {
+  /** Function, in any form accepted by SymbolImportsQuery.symbol (e.g., a node ID) */
+  symbol: string;
+
+  /** Repository containing the function */
+  repository: string;
+}
+
+/**
+ * A local variable bound by `let`
+ */
+export interface LocalVariable {
+  /** Bound name */
+  name: string;
+
+  /**
+   * Declared type, or the type the initializer constructs; null when neither
+   * says (e.g., the result of a plain function call)
+   */
+  type: string | null;
+
+  /** Line of the `let` (1-based) */
+  line: number;
+}
+
+/**
+ * A function with the locals it binds
+ */
+export interface FunctionLocals extends SymbolLocation {
+  /** Locals in source order; shadowed names appear once per binding */
+  locals: LocalVariable[];
+}
+
+/**
+ * Result of a local bindings query
+ */
+export interface LocalBindingsResult {
+  /** Function reference as given */
+  symbol: string;
+
+  /** Repository searched */
+  repository: string;
+
+  /** Every function matching the reference */
+  functions: FunctionLocals[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of functions returned */
+    function_count: number;
+    /** Number of locals across the functions */
+    local_count: number;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Symbols at Lines
+// =============================================================================
+
+/**
+ * Query for the symbols covering a line range of a file, e.g. a stack trace
+ * line or a diff hunk
+ */
+export interface SymbolsAtLinesQuery {
+  /** Repository containing the file */
+  repository: string;
+
+  /** File path relative to the repository root */
+  path: string;
+
+  /** First line of the range (1-based) */
+  start_line: number;
+
+  /**
+   * Last line of the range (1-based, inclusive)
+   * @default start_line
+   */
+  end_line?: number;
+}
+
+/**
+ * A symbol whose span overlaps the queried range
+ */
+export interface LineRangeSymbol {
+  /** Graph node ID of the entity or impl block */
+  id: string;
+
+  /** Symbol name; impl blocks are named like `impl Trait for Type` */
+  name: string;
+
+  /** Parser entity type, or "impl" for an impl block */
+  entity_type: string;
+
+  /** First line of the symbol (1-based) */
+  start_line: number;
+
+  /** Last line of the symbol (1-based) */
+  end_line: number;
+
+  /** Enclosing type or impl target, for members */
+  parent_name: string | null;
+
+  /** Whether the symbol spans the whole range rather than part of it */
+  covers_range: boolean;
+}
+
+/**
+ * Result of a symbols at lines query
+ */
+export interface SymbolsAtLinesResult {
+  /** Repository searched */
+  repository: string;
+
+  /** File path relative to the repository root */
+  file_path: string;
+
+  /** First line of the range */
+  start_line: number;
+
+  /** Last line of the range */
+  end_line: number;
+
+  /** Overlapping symbols, innermost (shortest span) first */
+  symbols: LineRangeSymbol[];
+
+  /** Query metadata */
+  metadata: {
+    /** Number of symbols returned */
+    symbol_count: number;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
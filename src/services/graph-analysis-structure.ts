@@ -0,0 +1,663 @@
+/**
+ * @module services/graph-analysis-structure
+ *
+ * File structure queries of GraphAnalysisService: top-level symbols, LSP
+ * document symbol trees, the symbols covering given lines and the local
+ * bindings of a function.
+ */
+
+import path from "node:path";
+import type {
+  ValidatedTopLevelSymbolsQuery,
+  ValidatedDocumentSymbolsQuery,
+  ValidatedLocalBindingsQuery,
+  ValidatedSymbolsAtLinesQuery,
+} from "./graph-analysis-validation.js";
+import { getLanguageFromExtension } from "../graph/parsing/types.js";
+import { LSP_SYMBOL_KIND, toLspSymbolKind } from "../graph/parsing/lsp-symbol-kinds.js";
+import { DEFAULT_SYMBOL_KINDS, type SymbolKind } from "../graph/parsing/symbol-kinds.js";
+import { EntityNotFoundError } from "./graph-service-errors.js";
+import type {
+  SymbolLocation,
+  TopLevelSymbolsResult,
+  TopLevelSymbol,
+  FileTopLevelSymbols,
+  DocumentSymbolsResult,
+  DocumentSymbol,
+  LspPosition,
+  LspRange,
+  LocalBindingsResult,
+  SymbolsAtLinesResult,
+  LineRangeSymbol,
+} from "./graph-analysis-types.js";
+import {
+  GraphAnalysisQueries,
+  ENTITY_LABELS,
+  type NestedSymbolRow,
+} from "./graph-analysis-queries.js";
+
+/**
+ * Symbol row returned by the document symbols query
+ */
+interface DocumentSymbolRow {
+  name: string;
+  entityType: string | null;
+  kind: string | null;
+  signature: string | null;
+  parentName: string | null;
+  startLine: number;
+  endLine: number | null;
+  startColumn: number | null;
+  endColumn: number | null;
+  nameLine: number | null;
+  nameStartColumn: number | null;
+  nameEndColumn: number | null;
+  variantNames: string[] | null;
+  variantStartLines: number[] | null;
+  variantStartColumns: number[] | null;
+  variantEndLines: number[] | null;
+  variantEndColumns: number[] | null;
+}
+
+/**
+ * Impl block row returned by the document symbols query
+ */
+interface DocumentImplRow {
+  typeName: string;
+  traitName: string | null;
+  isNegative: boolean | null;
+  line: number;
+  endLine: number | null;
+  startColumn: number | null;
+  endColumn: number | null;
+}
+
+/**
+ * A document symbol with what is needed to find its parent
+ */
+interface DocumentSymbolEntry {
+  symbol: DocumentSymbol;
+  /** Parent link written at ingestion, for symbols outside their parent's range */
+  parentName: string | null;
+  /** Whether other symbols can name this one as their parent */
+  isType: boolean;
+}
+
+/**
+ * Local variable lists of a function, as stored at ingestion
+ */
+interface LocalBindingsRow {
+  id: string;
+  localNames: string[] | null;
+  localTypes: string[] | null;
+  localLines: number[] | null;
+}
+
+/**
+ * Entity row returned by the symbols at lines query
+ */
+interface LineRangeEntityRow {
+  id: string;
+  name: string;
+  entityType: string;
+  startLine: number;
+  endLine: number;
+  parentName: string | null;
+}
+
+/**
+ * Impl block row returned by the symbols at lines query
+ */
+interface LineRangeImplRow {
+  id: string;
+  typeName: string;
+  traitName: string | null;
+  isNegative: boolean | null;
+  line: number;
+  endLine: number;
+}
+
+/**
+ * File structure queries of GraphAnalysisService
+ */
+export class StructureQueries extends GraphAnalysisQueries {
+  async executeTopLevelSymbolsQuery(
+    query: ValidatedTopLevelSymbolsQuery
+  ): Promise<TopLevelSymbolsResult> {
+    const normalizedPath = query.path.replace(/\\/g, "/").replace(/^\.\//, "").replace(/\/+$/, "");
+    const params = {
+      repository: query.repository,
+      path: normalizedPath,
+      dirPrefix: `${normalizedPath}/`,
+    };
+
+    const fileRows = await this.graphAdapter.runQuery<{ path: string }>(
+      `
+      MATCH (f:File {repository: $repository})
+      WHERE f.path = $path OR f.path STARTS WITH $dirPrefix
+      RETURN f.path AS path
+      `,
+      params
+    );
+    if (fileRows.length === 0) {
+      throw new EntityNotFoundError("file", query.path, query.repository);
+    }
+
+    const rows = await this.graphAdapter.runQuery<NestedSymbolRow>(
+      `
+      MATCH (f:File {repository: $repository})-[d:DEFINES]->(e)
+      WHERE (f.path = $path OR f.path STARTS WITH $dirPrefix)
+        AND (${ENTITY_LABELS.map((label) => `e:${label}`).join(" OR ")})
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             f.path AS filePath,
+             coalesce(e.startLine, d.startLine) AS startLine,
+             coalesce(e.endLine, d.endLine) AS endLine,
+             e.parentName AS parentName
+      ORDER BY filePath, startLine
+      `,
+      params
+    );
+
+    const rowsByFile = new Map<string, NestedSymbolRow[]>();
+    for (const row of rows) {
+      const fileRowsForPath = rowsByFile.get(row.filePath) ?? [];
+      fileRowsForPath.push(row);
+      rowsByFile.set(row.filePath, fileRowsForPath);
+    }
+
+    const files: FileTopLevelSymbols[] = [];
+    let nestedOmitted = 0;
+    for (const [filePath, fileSymbols] of rowsByFile) {
+      const { symbols, omitted } = this.groupTopLevelSymbols(fileSymbols, query.expand);
+      nestedOmitted += omitted;
+      if (symbols.length > 0) {
+        files.push({ file_path: filePath, symbols });
+      }
+    }
+
+    return {
+      repository: query.repository,
+      path: query.path,
+      files,
+      metadata: {
+        file_count: files.length,
+        symbol_count: files.reduce((sum, file) => sum + file.symbols.length, 0),
+        nested_omitted: nestedOmitted,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeDocumentSymbolsQuery(
+    query: ValidatedDocumentSymbolsQuery
+  ): Promise<DocumentSymbolsResult> {
+    const filePath = query.path.replace(/\\/g, "/").replace(/^\.\//, "");
+    const params = { repository: query.repository, path: filePath };
+
+    const fileRows = await this.graphAdapter.runQuery<{ path: string }>(
+      `
+      MATCH (f:File {repository: $repository, path: $path})
+      RETURN f.path AS path
+      `,
+      params
+    );
+    if (fileRows.length === 0) {
+      throw new EntityNotFoundError("file", query.path, query.repository);
+    }
+
+    const rows = await this.graphAdapter.runQuery<DocumentSymbolRow>(
+      `
+      MATCH (f:File {repository: $repository, path: $path})-[d:DEFINES]->(e)
+      WHERE ${ENTITY_LABELS.map((label) => `e:${label}`).join(" OR ")}
+      RETURN e.name AS name,
+             e.entityType AS entityType,
+             e.kind AS kind,
+             coalesce(e.rawSignature, e.signature) AS signature,
+             e.parentName AS parentName,
+             coalesce(e.startLine, d.startLine) AS startLine,
+             coalesce(e.endLine, d.endLine) AS endLine,
+             e.startColumn AS startColumn,
+             e.endColumn AS endColumn,
+             e.nameLine AS nameLine,
+             e.nameStartColumn AS nameStartColumn,
+             e.nameEndColumn AS nameEndColumn,
+             e.variantNames AS variantNames,
+             e.variantStartLines AS variantStartLines,
+             e.variantStartColumns AS variantStartColumns,
+             e.variantEndLines AS variantEndLines,
+             e.variantEndColumns AS variantEndColumns
+      ORDER BY startLine
+      `,
+      params
+    );
+
+    const implRows = await this.graphAdapter.runQuery<DocumentImplRow>(
+      `
+      MATCH (f:File {repository: $repository, path: $path})-[:HAS_IMPL]->(i:Impl)
+      RETURN i.typeName AS typeName,
+             i.traitName AS traitName,
+             i.isNegative AS isNegative,
+             i.line AS line,
+             i.endLine AS endLine,
+             i.startColumn AS startColumn,
+             i.endColumn AS endColumn
+      ORDER BY line
+      `,
+      params
+    );
+
+    const language = getLanguageFromExtension(path.extname(filePath)) ?? undefined;
+    const entries: DocumentSymbolEntry[] = rows.map((row) => {
+      const kind = (row.kind ??
+        DEFAULT_SYMBOL_KINDS[row.entityType as keyof typeof DEFAULT_SYMBOL_KINDS] ??
+        "variable") as SymbolKind;
+      const range = this.toLspRange(row.startLine, row.startColumn, row.endLine, row.endColumn);
+      const hasName =
+        row.nameLine !== null && row.nameStartColumn !== null && row.nameEndColumn !== null;
+      const variants = this.toEnumMemberSymbols(row);
+      return {
+        symbol: {
+          name: row.name,
+          ...(row.signature && { detail: row.signature }),
+          kind: toLspSymbolKind(kind, language),
+          range,
+          selectionRange: hasName
+            ? this.toLspRange(row.nameLine, row.nameStartColumn, row.nameLine, row.nameEndColumn)
+            : range,
+          ...(variants.length > 0 && { children: variants }),
+        },
+        parentName: row.parentName,
+        isType: kind === "class" || kind === "interface" || kind === "enum",
+      };
+    });
+
+    // Impl blocks ingested before their extent was recorded cannot enclose
+    // anything; their methods still nest under the type through parentName
+    for (const impl of implRows) {
+      if (impl.endLine === null) continue;
+      const range = this.toLspRange(impl.line, impl.startColumn, impl.endLine, impl.endColumn);
+      const trait = impl.traitName ? `${impl.isNegative ? "!" : ""}${impl.traitName} for ` : "";
+      entries.push({
+        symbol: {
+          name: `impl ${trait}${impl.typeName}`,
+          kind: LSP_SYMBOL_KIND.Object,
+          range,
+          selectionRange: range,
+        },
+        parentName: null,
+        isType: false,
+      });
+    }
+
+    const symbols = this.nestDocumentSymbols(entries);
+    const countSymbols = (list: DocumentSymbol[]): number =>
+      list.reduce((sum, symbol) => sum + 1 + countSymbols(symbol.children ?? []), 0);
+
+    return {
+      repository: query.repository,
+      file_path: filePath,
+      symbols,
+      metadata: {
+        symbol_count: countSymbols(symbols),
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeSymbolsAtLinesQuery(
+    query: ValidatedSymbolsAtLinesQuery
+  ): Promise<SymbolsAtLinesResult> {
+    const filePath = query.path.replace(/\\/g, "/").replace(/^\.\//, "");
+    const startLine = query.start_line;
+    const endLine = query.end_line ?? query.start_line;
+    const params = { repository: query.repository, path: filePath, startLine, endLine };
+
+    const fileRows = await this.graphAdapter.runQuery<{ path: string }>(
+      `
+      MATCH (f:File {repository: $repository, path: $path})
+      RETURN f.path AS path
+      `,
+      params
+    );
+    if (fileRows.length === 0) {
+      throw new EntityNotFoundError("file", query.path, query.repository);
+    }
+
+    const entityRows = await this.graphAdapter.runQuery<LineRangeEntityRow>(
+      `
+      MATCH (f:File {repository: $repository, path: $path})-[d:DEFINES]->(e)
+      WHERE ${ENTITY_LABELS.map((label) => `e:${label}`).join(" OR ")}
+      WITH e,
+           coalesce(e.startLine, d.startLine) AS startLine,
+           coalesce(e.endLine, d.endLine) AS endLine
+      WHERE startLine <= $endLine AND endLine >= $startLine
+      RETURN e.id AS id,
+             e.name AS name,
+             e.entityType AS entityType,
+             startLine,
+             endLine,
+             e.parentName AS parentName
+      `,
+      params
+    );
+
+    // Impl blocks ingested before their extent was recorded cannot overlap anything
+    const implRows = await this.graphAdapter.runQuery<LineRangeImplRow>(
+      `
+      MATCH (f:File {repository: $repository, path: $path})-[:HAS_IMPL]->(i:Impl)
+      WHERE i.endLine IS NOT NULL AND i.line <= $endLine AND i.endLine >= $startLine
+      RETURN i.id AS id,
+             i.typeName AS typeName,
+             i.traitName AS traitName,
+             i.isNegative AS isNegative,
+             i.line AS line,
+             i.endLine AS endLine
+      `,
+      params
+    );
+
+    const covers = (start: number, end: number): boolean => start <= startLine && end >= endLine;
+    const symbols: LineRangeSymbol[] = [
+      ...entityRows.map((row) => ({
+        id: row.id,
+        name: row.name,
+        entity_type: row.entityType,
+        start_line: row.startLine,
+        end_line: row.endLine,
+        parent_name: row.parentName,
+        covers_range: covers(row.startLine, row.endLine),
+      })),
+      ...implRows.map((row) => {
+        const trait = row.traitName ? `${row.isNegative ? "!" : ""}${row.traitName} for ` : "";
+        return {
+          id: row.id,
+          name: `impl ${trait}${row.typeName}`,
+          entity_type: "impl",
+          start_line: row.line,
+          end_line: row.endLine,
+          parent_name: null,
+          covers_range: covers(row.line, row.endLine),
+        };
+      }),
+    ];
+
+    // Innermost first: a nested symbol spans fewer lines and starts no earlier
+    // than the symbols enclosing it
+    symbols.sort(
+      (a, b) =>
+        a.end_line - a.start_line - (b.end_line - b.start_line) ||
+        b.start_line - a.start_line ||
+        a.name.localeCompare(b.name)
+    );
+
+    return {
+      repository: query.repository,
+      file_path: filePath,
+      start_line: startLine,
+      end_line: endLine,
+      symbols,
+      metadata: {
+        symbol_count: symbols.length,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  async executeLocalBindingsQuery(
+    query: ValidatedLocalBindingsQuery
+  ): Promise<LocalBindingsResult> {
+    const functions = (await this.resolveSymbols(query.symbol, query.repository)).filter(
+      (symbol) => symbol.id.startsWith("Function:")
+    );
+    if (functions.length === 0) {
+      throw new EntityNotFoundError("function", query.symbol, query.repository);
+    }
+
+    const rows = await this.graphAdapter.runQuery<LocalBindingsRow>(
+      `
+      MATCH (e:Function {repository: $repository})
+      WHERE e.id IN $ids
+      RETURN e.id AS id,
+             e.localNames AS localNames,
+             e.localTypes AS localTypes,
+             e.localLines AS localLines
+      `,
+      { repository: query.repository, ids: functions.map((symbol) => symbol.id) }
+    );
+    const rowsById = new Map(rows.map((row) => [row.id, row]));
+
+    const results = functions.map((symbol) => {
+      const row = rowsById.get(symbol.id);
+      return {
+        ...symbol,
+        locals: (row?.localNames ?? []).map((name, i) => ({
+          name,
+          // Untyped locals are stored with an empty type
+          type: row?.localTypes?.[i] || null,
+          line: row?.localLines?.[i] ?? 0,
+        })),
+      };
+    });
+
+    return {
+      symbol: query.symbol,
+      repository: query.repository,
+      functions: results,
+      metadata: {
+        function_count: results.length,
+        local_count: results.reduce((count, fn) => count + fn.locals.length, 0),
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  /**
+   * Split a file's symbols into top-level symbols and their nested members
+   *
+   * A symbol's parent is taken from the `parentName` link written at
+   * ingestion (which also covers Rust impl items, whose impl block is not
+   * inside the type definition). Graphs ingested before parent links existed
+   * fall back to the smallest definition whose line range encloses the symbol.
+   *
+   * @param rows - Symbols of one file ordered by start line
+   * @param expand - Whether to attach direct children to top-level symbols
+   * @returns Top-level symbols and the number of nested symbols omitted
+   */
+  private groupTopLevelSymbols(
+    rows: NestedSymbolRow[],
+    expand: boolean
+  ): { symbols: TopLevelSymbol[]; omitted: number } {
+    const symbols = rows.map((row) => this.toSymbolLocation(row));
+    const parentIndex = rows.map((row, i) => this.findParentIndex(rows, symbols, row, i));
+
+    const topLevel: TopLevelSymbol[] = [];
+    const topLevelByIndex = new Map<number, TopLevelSymbol>();
+    symbols.forEach((symbol, i) => {
+      if (parentIndex[i] === null && !rows[i]?.parentName) {
+        const entry: TopLevelSymbol = expand ? { ...symbol, children: [] } : { ...symbol };
+        topLevel.push(entry);
+        topLevelByIndex.set(i, entry);
+      }
+    });
+
+    let omitted = 0;
+    symbols.forEach((symbol, i) => {
+      if (topLevelByIndex.has(i)) return;
+      const parent = topLevelByIndex.get(parentIndex[i] ?? -1);
+      if (expand && parent?.children) {
+        parent.children.push(symbol);
+      } else {
+        omitted++;
+      }
+    });
+
+    return { symbols: topLevel, omitted };
+  }
+
+  /**
+   * Nest document symbols into a tree
+   *
+   * A symbol's parent is the innermost symbol whose range encloses it, so
+   * Rust methods land in their impl block and nested items in their module
+   * or function. Symbols enclosed by nothing fall back to the type named by
+   * their parent link (methods of graphs ingested before impl extents were
+   * recorded).
+   *
+   * @param entries - Symbols of one file in any order
+   * @returns Top-level symbols, each level ordered by position
+   */
+  private nestDocumentSymbols(entries: DocumentSymbolEntry[]): DocumentSymbol[] {
+    const compare = (a: LspPosition, b: LspPosition): number =>
+      a.line - b.line || a.character - b.character;
+    const encloses = (outer: LspRange, inner: LspRange): boolean =>
+      compare(outer.start, inner.start) <= 0 &&
+      compare(inner.end, outer.end) <= 0 &&
+      (compare(outer.start, inner.start) < 0 || compare(inner.end, outer.end) < 0);
+
+    // Outer symbols sort before the symbols they enclose
+    const sorted = [...entries].sort(
+      (a, b) =>
+        compare(a.symbol.range.start, b.symbol.range.start) ||
+        compare(b.symbol.range.end, a.symbol.range.end)
+    );
+
+    const parents = new Map<DocumentSymbolEntry, DocumentSymbolEntry>();
+    const open: DocumentSymbolEntry[] = [];
+    for (const entry of sorted) {
+      let parent = open[open.length - 1];
+      while (parent && !encloses(parent.symbol.range, entry.symbol.range)) {
+        open.pop();
+        parent = open[open.length - 1];
+      }
+      if (parent) {
+        parents.set(entry, parent);
+      }
+      open.push(entry);
+    }
+
+    for (const entry of sorted) {
+      if (parents.has(entry) || !entry.parentName) continue;
+      const owner = sorted.find(
+        (candidate) =>
+          candidate !== entry &&
+          candidate.isType &&
+          candidate.parentName === null &&
+          candidate.symbol.name === entry.parentName
+      );
+      if (owner) {
+        parents.set(entry, owner);
+      }
+    }
+
+    const roots: DocumentSymbol[] = [];
+    for (const entry of sorted) {
+      const parent = parents.get(entry);
+      if (parent) {
+        (parent.symbol.children ??= []).push(entry.symbol);
+      } else {
+        roots.push(entry.symbol);
+      }
+    }
+
+    const sortChildren = (symbols: DocumentSymbol[]): void => {
+      for (const symbol of symbols) {
+        if (symbol.children) {
+          symbol.children.sort((a, b) => compare(a.range.start, b.range.start));
+          sortChildren(symbol.children);
+        }
+      }
+    };
+    sortChildren(roots);
+    return roots;
+  }
+
+  /**
+   * Rebuild the variants of an enum from the parallel lists written by
+   * GraphIngestionService, as `EnumMember` symbols selected by name
+   */
+  private toEnumMemberSymbols(row: DocumentSymbolRow): DocumentSymbol[] {
+    return (row.variantNames ?? []).map((name, i) => {
+      const startLine = Number(row.variantStartLines?.[i] ?? row.startLine);
+      const startColumn = Number(row.variantStartColumns?.[i] ?? 0);
+      return {
+        name,
+        kind: LSP_SYMBOL_KIND.EnumMember,
+        range: this.toLspRange(
+          startLine,
+          startColumn,
+          row.variantEndLines?.[i] ?? startLine,
+          row.variantEndColumns?.[i] ?? null
+        ),
+        selectionRange: this.toLspRange(
+          startLine,
+          startColumn,
+          startLine,
+          startColumn + name.length
+        ),
+      };
+    });
+  }
+
+  /**
+   * Convert a 1-based line span with optional 0-based columns to an LSP range
+   *
+   * Graphs ingested before columns were recorded get whole-line ranges: from
+   * the start of the first line to the start of the line after the last.
+   */
+  private toLspRange(
+    startLine: number | null,
+    startColumn: number | null,
+    endLine: number | null,
+    endColumn: number | null
+  ): LspRange {
+    const firstLine = Number(startLine ?? 1);
+    const lastLine = Number(endLine ?? firstLine);
+    return {
+      start: { line: firstLine - 1, character: Number(startColumn ?? 0) },
+      end:
+        endColumn === null
+          ? { line: lastLine, character: 0 }
+          : { line: lastLine - 1, character: Number(endColumn) },
+    };
+  }
+
+  /**
+   * Find the index of a symbol's parent among the symbols of its file
+   *
+   * @returns Parent index, or null when the parent is unknown or not in the file
+   */
+  private findParentIndex(
+    rows: NestedSymbolRow[],
+    symbols: SymbolLocation[],
+    row: NestedSymbolRow,
+    index: number
+  ): number | null {
+    const self = symbols[index];
+    if (!self) return null;
+
+    const encloses = (candidate: SymbolLocation): boolean =>
+      candidate.start_line <= self.start_line &&
+      candidate.end_line >= self.end_line &&
+      (candidate.start_line < self.start_line || candidate.end_line > self.end_line);
+
+    let best: number | null = null;
+    for (let i = 0; i < symbols.length; i++) {
+      const candidate = symbols[i];
+      if (!candidate || i === index) continue;
+      if (row.parentName ? rows[i]?.name !== row.parentName : !encloses(candidate)) continue;
+
+      // Prefer enclosing candidates, and among those the innermost (latest-starting) one
+      const current = best === null ? undefined : symbols[best];
+      if (
+        !current ||
+        (encloses(candidate) && !encloses(current)) ||
+        (encloses(candidate) === encloses(current) && candidate.start_line > current.start_line)
+      ) {
+        best = i;
+      }
+    }
+    return best;
+  }
+}
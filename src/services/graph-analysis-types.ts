@@ -0,0 +1,456 @@
+/**
+ * @module services/graph-analysis-types
+ *
+ * Type definitions for GraphAnalysisService.
+ *
+ * GraphAnalysisService answers symbol-centric questions about the knowledge
+ * graph (imports in scope for a symbol, structural metrics, cycles, ...).
+ * It complements GraphService, which focuses on dependency traversal and
+ * architecture, and shares its error types.
+ *
+ * The query and result types of each feature area live in
+ * graph-analysis-<feature>-types.ts and are re-exported from here.
+ */
+
+import type {
+  ArityQuery,
+  ArityResult,
+  AttributeSearchQuery,
+  AttributeSearchResult,
+  ConstValueRangeQuery,
+  ConstValueRangeResult,
+  DoctestSearchQuery,
+  DoctestSearchResult,
+  EntryPointsQuery,
+  EntryPointsResult,
+  NameResolutionQuery,
+  NameResolutionResult,
+  SimilarFunctionsQuery,
+  SimilarFunctionsResult,
+  StringLiteralSearchQuery,
+  StringLiteralSearchResult,
+  SymbolSearchQuery,
+  SymbolSearchResult,
+  TodoListQuery,
+  TodoListResult,
+  TypeSearchQuery,
+  TypeSearchResult,
+} from "./graph-analysis-search-types.js";
+import type {
+  DocumentSymbolsQuery,
+  DocumentSymbolsResult,
+  LocalBindingsQuery,
+  LocalBindingsResult,
+  SymbolsAtLinesQuery,
+  SymbolsAtLinesResult,
+  TopLevelSymbolsQuery,
+  TopLevelSymbolsResult,
+} from "./graph-analysis-structure-types.js";
+import type {
+  CallPathQuery,
+  CallPathResult,
+  CallSitesQuery,
+  CallSitesResult,
+  ModuleCyclesQuery,
+  ModuleCyclesResult,
+  TopologicalOrderQuery,
+  TopologicalOrderResult,
+  TypeHierarchyQuery,
+  TypeHierarchyResult,
+} from "./graph-analysis-hierarchy-types.js";
+import type {
+  ComplexityQuery,
+  ComplexityResult,
+  CouplingMetricsQuery,
+  CouplingMetricsResult,
+  CouplingReportQuery,
+  CouplingReportResult,
+  DeprecationReportQuery,
+  DeprecationReportResult,
+  HotspotsQuery,
+  HotspotsResult,
+  LargeTypesQuery,
+  LargeTypesResult,
+  MissingDocsQuery,
+  MissingDocsResult,
+  MostCoupledQuery,
+  MostCoupledResult,
+  UnsafeCodeQuery,
+  UnsafeCodeResult,
+} from "./graph-analysis-metrics-types.js";
+import type {
+  ExternalDependenciesQuery,
+  ExternalDependenciesResult,
+  ImportSuggestionQuery,
+  ImportSuggestionResult,
+  LeakyApiQuery,
+  LeakyApiResult,
+  SymbolImportsQuery,
+  SymbolImportsResult,
+} from "./graph-analysis-imports-types.js";
+
+export * from "./graph-analysis-search-types.js";
+export * from "./graph-analysis-structure-types.js";
+export * from "./graph-analysis-hierarchy-types.js";
+export * from "./graph-analysis-metrics-types.js";
+export * from "./graph-analysis-imports-types.js";
+
+// =============================================================================
+// Shared Types
+// =============================================================================
+
+/**
+ * A code symbol (function, class, variable) located in the knowledge graph.
+ */
+export interface SymbolLocation {
+  /** Graph node ID (e.g., "Function:my-repo:src/auth.ts:validate:12") */
+  id: string;
+
+  /** Symbol name */
+  name: string;
+
+  /** Parser entity type (function, method, class, interface, enum, ...) */
+  entity_type: string;
+
+  /** File containing the symbol, relative to the repository root */
+  file_path: string;
+
+  /** First line of the symbol definition (1-based) */
+  start_line: number;
+
+  /** Last line of the symbol definition (1-based) */
+  end_line: number;
+}
+
+/**
+ * A function or method parameter recorded at ingestion
+ */
+export interface SymbolParameter {
+  /** Parameter name (e.g. "name", "self") */
+  name: string;
+
+  /** Type as written in the source (e.g. "&str"), when annotated */
+  type?: string;
+}
+
+// =============================================================================
+// Service Interface
+// =============================================================================
+
+/**
+ * GraphAnalysisService interface
+ *
+ * Symbol-level analysis queries over the knowledge graph.
+ */
+export interface GraphAnalysisService {
+  /**
+   * Get the import statements in scope for a symbol's file
+   *
+   * @param query - Symbol reference and repository
+   * @returns Matching definitions with their files' imports
+   * @throws {GraphServiceValidationError} If the query is invalid
+   * @throws {EntityNotFoundError} If no matching symbol exists
+   */
+  getSymbolImports(query: SymbolImportsQuery): Promise<SymbolImportsResult>;
+
+  /**
+   * Find circular dependencies in a repository's file import graph
+   *
+   * @param query - Repository and cycle filters
+   * @returns Strongly-connected components with more than one file, plus self-imports
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  findModuleCycles(query: ModuleCyclesQuery): Promise<ModuleCyclesResult>;
+
+  /**
+   * List the top-level declarations of a file or directory
+   *
+   * @param query - Repository, path and expansion option
+   * @returns Top-level symbols per file, nested members omitted unless expanded
+   * @throws {GraphServiceValidationError} If the query is invalid
+   * @throws {EntityNotFoundError} If no file matches the path
+   */
+  getTopLevelSymbols(query: TopLevelSymbolsQuery): Promise<TopLevelSymbolsResult>;
+
+  /**
+   * Build the LSP-style symbol tree of a file
+   *
+   * @param query - Repository and file path
+   * @returns Symbols nested by containment, with impl blocks and enum variants
+   * @throws {GraphServiceValidationError} If the query is invalid
+   * @throws {EntityNotFoundError} If the file is not indexed
+   */
+  getDocumentSymbols(query: DocumentSymbolsQuery): Promise<DocumentSymbolsResult>;
+
+  /**
+   * List TODO/FIXME marker comments of a repository
+   *
+   * @param query - Repository with optional tag and path filters
+   * @returns Markers ordered by location, with per-tag counts
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  listTodos(query: TodoListQuery): Promise<TodoListResult>;
+
+  /**
+   * Search code symbols by name
+   *
+   * @param query - Repository, search text and matching options
+   * @returns Matching symbols ordered by relevance
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  searchSymbols(query: SymbolSearchQuery): Promise<SymbolSearchResult>;
+
+  /**
+   * Find functions by parameter or return type
+   *
+   * @param query - Repository, type and matching options
+   * @returns Functions whose signature mentions the type
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  searchByType(query: TypeSearchQuery): Promise<TypeSearchResult>;
+
+  /**
+   * List the entry points of a repository grouped by kind
+   *
+   * @param query - Repository with optional kind and path filters
+   * @returns Main functions, runtime mains, tests and benchmarks
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  listEntryPoints(query: EntryPointsQuery): Promise<EntryPointsResult>;
+
+  /**
+   * Find symbols annotated with an attribute
+   *
+   * @param query - Repository, attribute name and optional argument and path filters
+   * @returns Annotated symbols with their matching attributes and caller counts
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  findByAttribute(query: AttributeSearchQuery): Promise<AttributeSearchResult>;
+
+  /**
+   * Report deprecated symbols with the calls still referencing them
+   *
+   * @param query - Repository with optional path filter and limit
+   * @returns Deprecated symbols with their messages, call sites and removal safety
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  getDeprecationReport(query: DeprecationReportQuery): Promise<DeprecationReportResult>;
+
+  /**
+   * List unsafe functions, traits, impls and `unsafe` blocks
+   *
+   * @param query - Repository with optional path filter and limit
+   * @returns Unsafe symbols with their block spans, and unsafe impls
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  listUnsafe(query: UnsafeCodeQuery): Promise<UnsafeCodeResult>;
+
+  /**
+   * List functions whose approximate cyclomatic complexity meets a threshold
+   *
+   * @param query - Repository with threshold, optional path filter and limit
+   * @returns Functions ordered by complexity, most complex first
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  listComplex(query: ComplexityQuery): Promise<ComplexityResult>;
+
+  /**
+   * Group functions whose bodies are structurally identical or similar
+   *
+   * @param query - Repository with similarity and size thresholds, optional path and limit
+   * @returns Exact and near clone groups, most similar first
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  findSimilarFunctions(query: SimilarFunctionsQuery): Promise<SimilarFunctionsResult>;
+
+  /**
+   * Find constants initialized with a number in a range
+   *
+   * @param query - Repository, optional bounds, kind and path filters
+   * @returns Constants with their decoded and written values, smallest first
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  findConstsByValueRange(query: ConstValueRangeQuery): Promise<ConstValueRangeResult>;
+
+  /**
+   * Find the doc comment code examples documenting or calling a symbol
+   *
+   * @param query - Repository, symbol name and optional mode filter
+   * @returns Examples with their code and the item they document
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  findDoctests(query: DoctestSearchQuery): Promise<DoctestSearchResult>;
+
+  /**
+   * List the public symbols that have no documentation, grouped by module
+   *
+   * @param query - Repository, optional path filter and allowlist
+   * @returns Undocumented symbols per module with coverage counts
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  listMissingDocs(query: MissingDocsQuery): Promise<MissingDocsResult>;
+
+  /**
+   * Resolve a short or partially qualified name to the symbols it may refer to
+   *
+   * @param query - Repository and partial name
+   * @returns Candidates with their qualified paths, rarest names first
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  resolveName(query: NameResolutionQuery): Promise<NameResolutionResult>;
+
+  /**
+   * Order functions so that callees come before their callers
+   *
+   * @param query - Repository and optional path filter
+   * @returns Groups of functions in dependency order, cycles grouped together
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  getTopologicalOrder(query: TopologicalOrderQuery): Promise<TopologicalOrderResult>;
+
+  /**
+   * List the local variables a function binds, with their inferred types
+   *
+   * @param query - Function reference and repository
+   * @returns Every function matching the reference with its `let` bindings
+   * @throws {GraphServiceValidationError} If the query is invalid
+   * @throws {EntityNotFoundError} If no function matches the reference
+   */
+  getLocalBindings(query: LocalBindingsQuery): Promise<LocalBindingsResult>;
+
+  /**
+   * List types whose impls define many methods
+   *
+   * @param query - Repository with threshold, sort order and limit
+   * @returns Types at or above the threshold with their impl blocks
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  findLargeTypes(query: LargeTypesQuery): Promise<LargeTypesResult>;
+
+  /**
+   * List the symbols whose span overlaps a line range of a file
+   *
+   * @param query - Repository, file path and line range
+   * @returns Overlapping symbols and impl blocks, innermost first
+   * @throws {GraphServiceValidationError} If the query is invalid
+   * @throws {EntityNotFoundError} If the file is not indexed
+   */
+  getSymbolsAtLines(query: SymbolsAtLinesQuery): Promise<SymbolsAtLinesResult>;
+
+  /**
+   * List the external crates a Rust repository imports from
+   *
+   * @param query - Repository and whether to include the standard library
+   * @returns Crates grouped from unresolved import paths, most referenced first
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  findExternalDependencies(query: ExternalDependenciesQuery): Promise<ExternalDependenciesResult>;
+
+  /**
+   * Find functions whose parameter count falls within a range
+   *
+   * @param query - Repository, parameter count range and filters
+   * @returns Matching functions, most parameters first
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  findFunctionsByArity(query: ArityQuery): Promise<ArityResult>;
+
+  /**
+   * List the call sites of a function with the arguments each passes
+   *
+   * @param query - Function, repository and optional argument filter
+   * @returns Call sites ordered by caller file and line
+   * @throws {GraphServiceValidationError} If the query is invalid
+   * @throws {EntityNotFoundError} If the symbol does not resolve to a function
+   */
+  findCallSites(query: CallSitesQuery): Promise<CallSitesResult>;
+
+  /**
+   * Suggest the `use` declarations a Rust file needs to name a symbol
+   *
+   * @param query - Symbol, repository and the file that will use it
+   * @returns The shortest visible path for each definition, or why it is inaccessible
+   * @throws {GraphServiceValidationError} If the query is invalid
+   * @throws {EntityNotFoundError} If the file is not indexed or the symbol is not found
+   */
+  suggestImport(query: ImportSuggestionQuery): Promise<ImportSuggestionResult>;
+
+  /**
+   * Find public Rust functions whose parameter or return types name
+   * non-public types of the crate
+   *
+   * @param query - Repository and optional path filter
+   * @returns Leaks ordered by function location
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  findLeakyApi(query: LeakyApiQuery): Promise<LeakyApiResult>;
+
+  /**
+   * Search the contents of indexed string literals
+   *
+   * @param query - Repository, search text and optional path filter
+   * @returns Literals containing the text, ordered by location
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  grepStrings(query: StringLiteralSearchQuery): Promise<StringLiteralSearchResult>;
+
+  /**
+   * Get the fan-in, fan-out and cycle membership of a function
+   *
+   * @param query - Function reference and repository
+   * @returns Metrics for each matching definition
+   * @throws {GraphServiceValidationError} If the query is invalid
+   * @throws {EntityNotFoundError} If no function matches the reference
+   */
+  getCouplingMetrics(query: CouplingMetricsQuery): Promise<CouplingMetricsResult>;
+
+  /**
+   * Rank the functions of a repository by combined fan-in and fan-out
+   *
+   * @param query - Repository with optional path filter and limit
+   * @returns The most coupled functions first
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  findMostCoupled(query: MostCoupledQuery): Promise<MostCoupledResult>;
+
+  /**
+   * Rank the symbols of a repository by incoming calls and imports
+   *
+   * @param query - Repository and limit
+   * @returns The most referenced symbols first
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  findHotspots(query: HotspotsQuery): Promise<HotspotsResult>;
+
+  /**
+   * Compute the afferent and efferent coupling and instability of each module
+   *
+   * @param query - Repository, module granularity, scope, sort order and limit
+   * @returns Modules in the requested order and the most coupled module pairs
+   * @throws {GraphServiceValidationError} If the query is invalid
+   */
+  getCouplingReport(query: CouplingReportQuery): Promise<CouplingReportResult>;
+
+  /**
+   * Find the shortest chains of calls from one function to another
+   *
+   * @param query - Calling and called function references, depth cap and limit
+   * @returns Shortest paths over CALLS edges, or none when the target is unreachable
+   * @throws {GraphServiceValidationError} If the query is invalid
+   * @throws {EntityNotFoundError} If either reference matches no function
+   */
+  findCallPaths(query: CallPathQuery): Promise<CallPathResult>;
+
+  /**
+   * Assemble the inheritance and trait hierarchy of a type
+   *
+   * @param query - Type reference, repository and supertype depth
+   * @param signal - Stops the walk before its next level when aborted
+   * @returns The type and its in-repository supertypes with implemented
+   *   traits, derives and generic bounds
+   * @throws {GraphServiceValidationError} If the query is invalid
+   * @throws {EntityNotFoundError} If the reference matches no type
+   * @throws {GraphServiceCancelledError} If the signal aborts during the walk
+   */
+  getTypeHierarchy(query: TypeHierarchyQuery, signal?: AbortSignal): Promise<TypeHierarchyResult>;
+}
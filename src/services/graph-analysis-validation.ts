@@ -0,0 +1,618 @@
+/**
+ * @module services/graph-analysis-validation
+ *
+ * Zod validation schemas for GraphAnalysisService query inputs.
+ *
+ * Follows the conventions of graph-service-validation.ts: strict object
+ * schemas, trimmed non-empty strings, and defaults applied at parse time.
+ */
+
+import { z } from "zod";
+import { SYMBOL_KINDS, type SymbolKind } from "../graph/parsing/symbol-kinds.js";
+import { ENTRY_POINT_KINDS } from "../graph/parsing/entry-points.js";
+import { DOCTEST_MODES } from "../graph/parsing/doctests.js";
+import { parseTargetPlatform } from "../graph/parsing/cfg-predicates.js";
+import type { DoctestMode, EntryPointKind } from "../graph/parsing/types.js";
+
+// =============================================================================
+// Shared Schemas
+// =============================================================================
+
+/**
+ * Non-empty string schema for required string fields
+ */
+const nonEmptyString = (fieldName: string): z.ZodString =>
+  z.string().trim().min(1, `${fieldName} must not be empty`);
+
+// =============================================================================
+// Query Schemas
+// =============================================================================
+
+/**
+ * Validation schema for SymbolImportsQuery
+ *
+ * @example
+ * ```typescript
+ * const validated = SymbolImportsQuerySchema.parse({
+ *   symbol: "validateToken",
+ *   repository: "my-project",
+ * });
+ * ```
+ */
+export const SymbolImportsQuerySchema = z
+  .object({
+    symbol: nonEmptyString("Symbol"),
+    repository: nonEmptyString("Repository"),
+    file_path: z.string().trim().min(1).optional(),
+  })
+  .strict();
+
+/**
+ * Validation schema for ModuleCyclesQuery
+ */
+export const ModuleCyclesQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    include_type_only: z.boolean().default(true),
+    limit: z.number().int().min(1).max(100).default(20),
+  })
+  .strict();
+
+/**
+ * Validation schema for StringLiteralSearchQuery
+ */
+export const StringLiteralSearchQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    query: z.string().min(1, "Query must not be empty"),
+    case_sensitive: z.boolean().default(false),
+    path: z.string().trim().min(1).optional(),
+    limit: z.number().int().min(1).max(500).default(50),
+  })
+  .strict();
+
+/**
+ * Validation schema for CouplingMetricsQuery
+ */
+export const CouplingMetricsQuerySchema = z
+  .object({
+    symbol: nonEmptyString("Symbol"),
+    repository: nonEmptyString("Repository"),
+    file_path: z.string().trim().min(1).optional(),
+  })
+  .strict();
+
+/**
+ * Validation schema for MostCoupledQuery
+ */
+export const MostCoupledQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    path: z.string().trim().min(1).optional(),
+    limit: z.number().int().min(1).max(100).default(20),
+  })
+  .strict();
+
+/**
+ * Validation schema for HotspotsQuery
+ */
+export const HotspotsQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    limit: z.number().int().min(1).max(100).default(20),
+  })
+  .strict();
+
+/**
+ * Validation schema for CouplingReportQuery
+ */
+export const CouplingReportQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    granularity: z.enum(["file", "directory"]).default("file"),
+    path: z.string().trim().min(1).optional(),
+    sort_by: z.enum(["instability", "afferent", "efferent", "module"]).default("instability"),
+    order: z.enum(["asc", "desc"]).default("desc"),
+    limit: z.number().int().min(1).max(500).default(50),
+  })
+  .strict();
+
+/**
+ * Validation schema for CallPathQuery
+ */
+export const CallPathQuerySchema = z
+  .object({
+    from: nonEmptyString("From"),
+    to: nonEmptyString("To"),
+    repository: nonEmptyString("Repository"),
+    max_depth: z.number().int().min(1).max(20).default(10),
+    limit: z.number().int().min(1).max(50).default(5),
+  })
+  .strict();
+
+/**
+ * Validation schema for TypeHierarchyQuery
+ */
+export const TypeHierarchyQuerySchema = z
+  .object({
+    type_name: nonEmptyString("Type name"),
+    repository: nonEmptyString("Repository"),
+    max_depth: z.number().int().min(1).max(10).default(3),
+  })
+  .strict();
+
+/**
+ * Validation schema for TopLevelSymbolsQuery
+ */
+export const TopLevelSymbolsQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    path: nonEmptyString("Path"),
+    expand: z.boolean().default(false),
+  })
+  .strict();
+
+/**
+ * Validation schema for DocumentSymbolsQuery
+ */
+export const DocumentSymbolsQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    path: nonEmptyString("Path"),
+  })
+  .strict();
+
+/**
+ * Validation schema for TodoListQuery
+ */
+export const TodoListQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    tag: z.string().trim().min(1).optional(),
+    path: z.string().trim().min(1).optional(),
+    limit: z.number().int().min(1).max(500).default(100),
+  })
+  .strict();
+
+/**
+ * Validation schema for SymbolSearchQuery
+ */
+export const SymbolSearchQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    query: nonEmptyString("Query"),
+    token_match: z.boolean().default(false),
+    kind: z.enum(SYMBOL_KINDS as [SymbolKind, ...SymbolKind[]]).optional(),
+    build_or_macro: z.boolean().optional(),
+    platform: z
+      .string()
+      .trim()
+      .min(1)
+      .refine((platform) => parseTargetPlatform(platform) !== undefined, {
+        message: "Unrecognized target platform",
+      })
+      .optional(),
+    include_context: z.boolean().default(false),
+    min_score: z.number().min(0).max(1).optional(),
+    limit: z.number().int().min(1).max(100).default(20),
+  })
+  .strict();
+
+/**
+ * Validation schema for TypeSearchQuery
+ */
+export const TypeSearchQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    type: nonEmptyString("Type"),
+    position: z.enum(["param", "return", "any"]).default("any"),
+    contains: z.boolean().default(false),
+    limit: z.number().int().min(1).max(100).default(20),
+  })
+  .strict();
+
+/**
+ * Validation schema for EntryPointsQuery
+ */
+export const EntryPointsQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    kind: z.enum(ENTRY_POINT_KINDS as [EntryPointKind, ...EntryPointKind[]]).optional(),
+    path: z.string().trim().min(1).optional(),
+    limit: z.number().int().min(1).max(1000).default(200),
+  })
+  .strict();
+
+/**
+ * Validation schema for AttributeSearchQuery
+ */
+export const AttributeSearchQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    name: nonEmptyString("Attribute name"),
+    arguments: z.string().trim().min(1).optional(),
+    path: z.string().trim().min(1).optional(),
+    limit: z.number().int().min(1).max(1000).default(100),
+  })
+  .strict();
+
+/**
+ * Validation schema for DeprecationReportQuery
+ */
+export const DeprecationReportQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    path: z.string().trim().min(1).optional(),
+    limit: z.number().int().min(1).max(500).default(100),
+  })
+  .strict();
+
+/**
+ * Validation schema for UnsafeCodeQuery
+ */
+export const UnsafeCodeQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    path: z.string().trim().min(1).optional(),
+    limit: z.number().int().min(1).max(1000).default(200),
+  })
+  .strict();
+
+/**
+ * Validation schema for ComplexityQuery
+ */
+export const ComplexityQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    threshold: z.number().int().min(1).default(10),
+    path: z.string().trim().min(1).optional(),
+    limit: z.number().int().min(1).max(500).default(50),
+  })
+  .strict();
+
+/**
+ * Validation schema for SimilarFunctionsQuery
+ */
+export const SimilarFunctionsQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    path: z.string().trim().min(1).optional(),
+    min_similarity: z.number().min(0.5).max(1).default(0.8),
+    min_size: z.number().int().min(1).default(30),
+    limit: z.number().int().min(1).max(200).default(20),
+  })
+  .strict();
+
+/**
+ * Validation schema for ConstValueRangeQuery
+ */
+export const ConstValueRangeQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    min: z.number().finite().optional(),
+    max: z.number().finite().optional(),
+    kind: z.enum(["integer", "float"]).optional(),
+    path: z.string().trim().min(1).optional(),
+    limit: z.number().int().min(1).max(500).default(100),
+  })
+  .strict()
+  .refine((query) => query.min === undefined || query.max === undefined || query.min <= query.max, {
+    message: "min must not exceed max",
+  });
+
+/**
+ * Validation schema for DoctestSearchQuery
+ */
+export const DoctestSearchQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    symbol: nonEmptyString("Symbol"),
+    mode: z.enum(DOCTEST_MODES as [DoctestMode, ...DoctestMode[]]).optional(),
+    limit: z.number().int().min(1).max(500).default(50),
+  })
+  .strict();
+
+/**
+ * Validation schema for MissingDocsQuery
+ */
+export const MissingDocsQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    path: z.string().trim().min(1).optional(),
+    allow: z.array(z.string().trim().min(1)).default([]),
+    limit: z.number().int().min(1).max(1000).default(200),
+  })
+  .strict();
+
+/**
+ * Validation schema for NameResolutionQuery
+ */
+export const NameResolutionQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    partial: nonEmptyString("Partial name"),
+    limit: z.number().int().min(1).max(200).default(20),
+  })
+  .strict();
+
+/**
+ * Validation schema for TopologicalOrderQuery
+ */
+export const TopologicalOrderQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    path: z.string().trim().min(1).optional(),
+    limit: z.number().int().min(1).max(5000).default(500),
+  })
+  .strict();
+
+/**
+ * Validation schema for LocalBindingsQuery
+ */
+export const LocalBindingsQuerySchema = z
+  .object({
+    symbol: nonEmptyString("Symbol"),
+    repository: nonEmptyString("Repository"),
+  })
+  .strict();
+
+/**
+ * Validation schema for LargeTypesQuery
+ */
+export const LargeTypesQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    method_threshold: z.number().int().min(1).default(20),
+    include_associated_items: z.boolean().default(false),
+    order: z.enum(["asc", "desc"]).default("desc"),
+    limit: z.number().int().min(1).max(500).default(50),
+  })
+  .strict();
+
+/**
+ * Validation schema for SymbolsAtLinesQuery
+ */
+export const SymbolsAtLinesQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    path: nonEmptyString("Path"),
+    start_line: z.number().int().min(1),
+    end_line: z.number().int().min(1).optional(),
+  })
+  .strict()
+  .refine((query) => query.end_line === undefined || query.start_line <= query.end_line, {
+    message: "start_line must not exceed end_line",
+  });
+
+/**
+ * Validation schema for ExternalDependenciesQuery
+ */
+export const ExternalDependenciesQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    include_std: z.boolean().default(true),
+  })
+  .strict();
+
+/**
+ * Validation schema for ArityQuery
+ */
+export const ArityQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    min_params: z.number().int().min(0).default(0),
+    max_params: z.number().int().min(0).optional(),
+    count_receiver: z.boolean().default(false),
+    exported_only: z.boolean().default(false),
+    path: z.string().trim().min(1).optional(),
+    limit: z.number().int().min(1).max(500).default(50),
+  })
+  .strict()
+  .refine((query) => query.max_params === undefined || query.min_params <= query.max_params, {
+    message: "min_params must not exceed max_params",
+  });
+
+/**
+ * Validation schema for CallSitesQuery
+ */
+export const CallSitesQuerySchema = z
+  .object({
+    symbol: nonEmptyString("Symbol"),
+    repository: nonEmptyString("Repository"),
+    argument_contains: z.string().min(1).optional(),
+    limit: z.number().int().min(1).max(500).default(100),
+  })
+  .strict();
+
+/**
+ * Validation schema for ImportSuggestionQuery
+ */
+export const ImportSuggestionQuerySchema = z
+  .object({
+    symbol: nonEmptyString("Symbol"),
+    repository: nonEmptyString("Repository"),
+    from_path: nonEmptyString("From path").refine((path) => path.endsWith(".rs"), {
+      message: "From path must be a Rust source file",
+    }),
+  })
+  .strict();
+
+/**
+ * Validation schema for LeakyApiQuery
+ */
+export const LeakyApiQuerySchema = z
+  .object({
+    repository: nonEmptyString("Repository"),
+    path: z.string().trim().min(1).optional(),
+    limit: z.number().int().min(1).max(500).default(100),
+  })
+  .strict();
+
+// =============================================================================
+// Validated Types
+// =============================================================================
+
+/**
+ * Validated SymbolImportsQuery after schema parsing
+ */
+export type ValidatedSymbolImportsQuery = z.infer<typeof SymbolImportsQuerySchema>;
+
+/**
+ * Validated ModuleCyclesQuery after schema parsing
+ */
+export type ValidatedModuleCyclesQuery = z.infer<typeof ModuleCyclesQuerySchema>;
+
+/**
+ * Validated TopLevelSymbolsQuery after schema parsing
+ */
+export type ValidatedTopLevelSymbolsQuery = z.infer<typeof TopLevelSymbolsQuerySchema>;
+
+/**
+ * Validated DocumentSymbolsQuery after schema parsing
+ */
+export type ValidatedDocumentSymbolsQuery = z.infer<typeof DocumentSymbolsQuerySchema>;
+
+/**
+ * Validated TodoListQuery after schema parsing
+ */
+export type ValidatedTodoListQuery = z.infer<typeof TodoListQuerySchema>;
+
+/**
+ * Validated SymbolSearchQuery after schema parsing
+ */
+export type ValidatedSymbolSearchQuery = z.infer<typeof SymbolSearchQuerySchema>;
+
+/**
+ * Validated StringLiteralSearchQuery after schema parsing
+ */
+export type ValidatedStringLiteralSearchQuery = z.infer<typeof StringLiteralSearchQuerySchema>;
+
+/**
+ * Validated CouplingMetricsQuery after schema parsing
+ */
+export type ValidatedCouplingMetricsQuery = z.infer<typeof CouplingMetricsQuerySchema>;
+
+/**
+ * Validated MostCoupledQuery after schema parsing
+ */
+export type ValidatedMostCoupledQuery = z.infer<typeof MostCoupledQuerySchema>;
+
+/**
+ * Validated HotspotsQuery after schema parsing
+ */
+export type ValidatedHotspotsQuery = z.infer<typeof HotspotsQuerySchema>;
+
+/**
+ * Validated CouplingReportQuery after schema parsing
+ */
+export type ValidatedCouplingReportQuery = z.infer<typeof CouplingReportQuerySchema>;
+
+/**
+ * Validated CallPathQuery after schema parsing
+ */
+export type ValidatedCallPathQuery = z.infer<typeof CallPathQuerySchema>;
+
+/**
+ * Validated TypeHierarchyQuery after schema parsing
+ */
+export type ValidatedTypeHierarchyQuery = z.infer<typeof TypeHierarchyQuerySchema>;
+
+/**
+ * Validated TypeSearchQuery after schema parsing
+ */
+export type ValidatedTypeSearchQuery = z.infer<typeof TypeSearchQuerySchema>;
+
+/**
+ * Validated EntryPointsQuery after schema parsing
+ */
+export type ValidatedEntryPointsQuery = z.infer<typeof EntryPointsQuerySchema>;
+
+/**
+ * Validated AttributeSearchQuery after schema parsing
+ */
+export type ValidatedAttributeSearchQuery = z.infer<typeof AttributeSearchQuerySchema>;
+
+/**
+ * Validated DeprecationReportQuery after schema parsing
+ */
+export type ValidatedDeprecationReportQuery = z.infer<typeof DeprecationReportQuerySchema>;
+
+/**
+ * Validated UnsafeCodeQuery after schema parsing
+ */
+export type ValidatedUnsafeCodeQuery = z.infer<typeof UnsafeCodeQuerySchema>;
+
+/**
+ * Validated ComplexityQuery after schema parsing
+ */
+export type ValidatedComplexityQuery = z.infer<typeof ComplexityQuerySchema>;
+
+/**
+ * Validated SimilarFunctionsQuery after schema parsing
+ */
+export type ValidatedSimilarFunctionsQuery = z.infer<typeof SimilarFunctionsQuerySchema>;
+
+/**
+ * Validated ConstValueRangeQuery after schema parsing
+ */
+export type ValidatedConstValueRangeQuery = z.infer<typeof ConstValueRangeQuerySchema>;
+
+/**
+ * Validated DoctestSearchQuery after schema parsing
+ */
+export type ValidatedDoctestSearchQuery = z.infer<typeof DoctestSearchQuerySchema>;
+
+/**
+ * Validated MissingDocsQuery after schema parsing
+ */
+export type ValidatedMissingDocsQuery = z.infer<typeof MissingDocsQuerySchema>;
+
+/**
+ * Validated NameResolutionQuery after schema parsing
+ */
+export type ValidatedNameResolutionQuery = z.infer<typeof NameResolutionQuerySchema>;
+
+/**
+ * Validated TopologicalOrderQuery after schema parsing
+ */
+export type ValidatedTopologicalOrderQuery = z.infer<typeof TopologicalOrderQuerySchema>;
+
+/**
+ * Validated LocalBindingsQuery after schema parsing
+ */
+export type ValidatedLocalBindingsQuery = z.infer<typeof LocalBindingsQuerySchema>;
+
+/**
+ * Validated LargeTypesQuery after schema parsing
+ */
+export type ValidatedLargeTypesQuery = z.infer<typeof LargeTypesQuerySchema>;
+
+/**
+ * Validated SymbolsAtLinesQuery after schema parsing
+ */
+export type ValidatedSymbolsAtLinesQuery = z.infer<typeof SymbolsAtLinesQuerySchema>;
+
+/**
+ * Validated ExternalDependenciesQuery after schema parsing
+ */
+export type ValidatedExternalDependenciesQuery = z.infer<typeof ExternalDependenciesQuerySchema>;
+
+/**
+ * Validated ArityQuery after schema parsing
+ */
+export type ValidatedArityQuery = z.infer<typeof ArityQuerySchema>;
+
+/**
+ * Validated CallSitesQuery after schema parsing
+ */
+export type ValidatedCallSitesQuery = z.infer<typeof CallSitesQuerySchema>;
+
+/**
+ * Validated ImportSuggestionQuery after schema parsing
+ */
+export type ValidatedImportSuggestionQuery = z.infer<typeof ImportSuggestionQuerySchema>;
+
+/**
+ * Validated LeakyApiQuery after schema parsing
+ */
+export type ValidatedLeakyApiQuery = z.infer<typeof LeakyApiQuerySchema>;
@@ -5,6 +5,11 @@
  *
  * This module provides a simple, efficient cache with time-based expiration
  * and size limits to improve query performance for repeated requests.
+ *
+ * Large deployments can additionally bound the cache by estimated memory use
+ * (`maxMemoryBytes`). Entries are then evicted least-recently-queried first;
+ * an evicted result is simply re-fetched from the graph database on its next
+ * query.
  */
 
 // =============================================================================
@@ -26,6 +31,15 @@ export interface CacheConfig {
    * @default 100
    */
   maxEntries: number;
+
+  /**
+   * Memory budget for cached values in bytes (0 disables the budget)
+   *
+   * Value sizes are estimated from their JSON serialization, so the budget
+   * approximates rather than exactly bounds heap usage.
+   * @default 0
+   */
+  maxMemoryBytes: number;
 }
 
 /**
@@ -34,6 +48,7 @@ export interface CacheConfig {
 export const DEFAULT_CACHE_CONFIG: CacheConfig = {
   ttlMs: 5 * 60 * 1000, // 5 minutes
   maxEntries: 100,
+  maxMemoryBytes: 0, // No memory budget
 };
 
 // =============================================================================
@@ -73,6 +88,21 @@ export interface CacheStats {
    * Hit rate (0-1)
    */
   hitRate: number;
+
+  /**
+   * Estimated memory used by cached values in bytes
+   */
+  memoryBytes: number;
+
+  /**
+   * Memory budget in bytes (0 when no budget is configured)
+   */
+  maxMemoryBytes: number;
+
+  /**
+   * Number of entries evicted to stay within the capacity or memory budget
+   */
+  evictions: number;
 }
 
 // =============================================================================
@@ -97,6 +127,11 @@ interface CacheEntry<T> {
    * Timestamp when the entry was created
    */
   createdAt: number;
+
+  /**
+   * Estimated size of the value in bytes
+   */
+  sizeBytes: number;
 }
 
 // =============================================================================
@@ -106,8 +141,9 @@ interface CacheEntry<T> {
 /**
  * Simple in-memory cache with TTL and size limits
  *
- * Uses a Map for O(1) lookups and maintains insertion order
- * for LRU-like eviction when the cache is full.
+ * Uses a Map for O(1) lookups and maintains access order (entries are moved
+ * to the end on a hit) for LRU eviction when the cache is full or over its
+ * memory budget.
  *
  * @example
  * ```typescript
@@ -130,6 +166,8 @@ export class QueryCache<T> {
   private readonly config: CacheConfig;
   private hits: number = 0;
   private misses: number = 0;
+  private evictions: number = 0;
+  private memoryBytes: number = 0;
 
   /**
    * Secondary index mapping prefixes to cache keys for O(1) prefix-based invalidation.
@@ -189,12 +227,15 @@ export class QueryCache<T> {
 
     // Check if entry has expired
     if (Date.now() > entry.expiresAt) {
-      this.cache.delete(key);
-      this.removeFromIndex(key);
+      this.removeEntry(key);
       this.misses++;
       return undefined;
     }
 
+    // Move to the end so eviction removes least-recently-queried entries first
+    this.cache.delete(key);
+    this.cache.set(key, entry);
+
     this.hits++;
     return entry.value;
   }
@@ -213,8 +254,7 @@ export class QueryCache<T> {
     }
 
     if (Date.now() > entry.expiresAt) {
-      this.cache.delete(key);
-      this.removeFromIndex(key);
+      this.removeEntry(key);
       return false;
     }
 
@@ -224,15 +264,25 @@ export class QueryCache<T> {
   /**
    * Set a value in the cache
    *
-   * If the cache is at capacity, older entries will be evicted.
+   * If the cache is at capacity or over its memory budget, least-recently-
+   * queried entries will be evicted. A value larger than the whole memory
+   * budget is not cached.
    *
    * @param key - Cache key
    * @param value - Value to cache
    */
   set(key: string, value: T): void {
-    // Evict expired and oldest entries if at capacity
-    if (this.cache.size >= this.config.maxEntries) {
-      this.evict();
+    // Replacing an entry frees its previous size first
+    this.removeEntry(key);
+
+    const sizeBytes = this.config.maxMemoryBytes > 0 ? QueryCache.estimateSize(value) : 0;
+    if (this.config.maxMemoryBytes > 0 && sizeBytes > this.config.maxMemoryBytes) {
+      return;
+    }
+
+    // Evict expired and oldest entries if at capacity or over budget
+    if (this.cache.size >= this.config.maxEntries || this.exceedsMemoryBudget(sizeBytes)) {
+      this.evict(sizeBytes);
     }
 
     const now = Date.now();
@@ -240,12 +290,57 @@ export class QueryCache<T> {
       value,
       expiresAt: now + this.config.ttlMs,
       createdAt: now,
+      sizeBytes,
     });
+    this.memoryBytes += sizeBytes;
 
     // Update prefix index for O(1) prefix-based invalidation
     this.addToIndex(key);
   }
 
+  /**
+   * Estimate the in-memory size of a value in bytes
+   *
+   * Uses the JSON serialization length at two bytes per character (UTF-16).
+   * Values that cannot be serialized count as zero bytes.
+   *
+   * @param value - Value to measure
+   * @returns Estimated size in bytes
+   */
+  static estimateSize(value: unknown): number {
+    try {
+      return (JSON.stringify(value)?.length ?? 0) * 2;
+    } catch {
+      return 0;
+    }
+  }
+
+  /**
+   * Check whether adding an entry of the given size would exceed the memory budget
+   */
+  private exceedsMemoryBudget(additionalBytes: number): boolean {
+    return (
+      this.config.maxMemoryBytes > 0 &&
+      this.memoryBytes + additionalBytes > this.config.maxMemoryBytes
+    );
+  }
+
+  /**
+   * Remove an entry and release its index and memory accounting
+   *
+   * @returns true if an entry was removed
+   */
+  private removeEntry(key: string): boolean {
+    const entry = this.cache.get(key);
+    if (!entry) {
+      return false;
+    }
+    this.cache.delete(key);
+    this.removeFromIndex(key);
+    this.memoryBytes -= entry.sizeBytes;
+    return true;
+  }
+
   /**
    * Extract the prefix from a cache key.
    * Keys follow the format "{queryType}:{repository}:{hash}".
@@ -306,11 +401,7 @@ export class QueryCache<T> {
    * @returns true if entry was deleted
    */
   delete(key: string): boolean {
-    const deleted = this.cache.delete(key);
-    if (deleted) {
-      this.removeFromIndex(key);
-    }
-    return deleted;
+    return this.removeEntry(key);
   }
 
   /**
@@ -321,6 +412,8 @@ export class QueryCache<T> {
     this.prefixIndex.clear();
     this.hits = 0;
     this.misses = 0;
+    this.evictions = 0;
+    this.memoryBytes = 0;
   }
 
   /**
@@ -337,6 +430,9 @@ export class QueryCache<T> {
       hits: this.hits,
       misses: this.misses,
       hitRate: total > 0 ? this.hits / total : 0,
+      memoryBytes: this.memoryBytes,
+      maxMemoryBytes: this.config.maxMemoryBytes,
+      evictions: this.evictions,
     };
   }
 
@@ -359,30 +455,20 @@ export class QueryCache<T> {
   }
 
   /**
-   * Remove expired entries and oldest entries if at capacity
+   * Remove expired entries, then least-recently-queried entries while at
+   * capacity or while the incoming entry would exceed the memory budget
+   *
+   * @param incomingBytes - Estimated size of the entry about to be added
    */
-  private evict(): void {
-    const now = Date.now();
-
-    // First pass: collect and remove expired entries
-    const expiredKeys: string[] = [];
-    for (const [key, entry] of this.cache.entries()) {
-      if (now > entry.expiresAt) {
-        expiredKeys.push(key);
-      }
-    }
-    for (const key of expiredKeys) {
-      this.cache.delete(key);
-      this.removeFromIndex(key);
-    }
+  private evict(incomingBytes: number = 0): void {
+    this.cleanup();
 
-    // If still at capacity, remove oldest entries
-    // Map maintains insertion order, so first entries are oldest
-    while (this.cache.size >= this.config.maxEntries) {
+    // Map maintains access order, so first entries are least recently queried
+    while (this.cache.size >= this.config.maxEntries || this.exceedsMemoryBudget(incomingBytes)) {
       const firstKey = this.cache.keys().next().value;
       if (firstKey !== undefined) {
-        this.cache.delete(firstKey);
-        this.removeFromIndex(firstKey);
+        this.removeEntry(firstKey);
+        this.evictions++;
       } else {
         break;
       }
@@ -408,8 +494,7 @@ export class QueryCache<T> {
     }
 
     for (const key of expiredKeys) {
-      this.cache.delete(key);
-      this.removeFromIndex(key);
+      this.removeEntry(key);
     }
 
     return expiredKeys.length;
@@ -432,8 +517,7 @@ export class QueryCache<T> {
       // Copy the set to avoid mutation during iteration
       const keysToDelete = [...indexedKeys];
       for (const key of keysToDelete) {
-        this.cache.delete(key);
-        // removeFromIndex is called by delete(), which cleans up the set
+        this.removeEntry(key);
       }
       return keysToDelete.length;
     }
@@ -447,7 +531,7 @@ export class QueryCache<T> {
     }
 
     for (const key of keysToDelete) {
-      this.cache.delete(key);
+      this.removeEntry(key);
     }
 
     return keysToDelete.length;
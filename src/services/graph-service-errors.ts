@@ -145,6 +145,22 @@ export class GraphServiceTimeoutError extends GraphServiceError {
   }
 }
 
+// =============================================================================
+// Cancellation Errors
+// =============================================================================
+
+/**
+ * Thrown when a multi-round query is stopped through its abort signal
+ *
+ * Raised between query rounds, such as the levels of a hierarchy walk.
+ * Retryable - the query can simply be issued again.
+ */
+export class GraphServiceCancelledError extends GraphServiceError {
+  constructor(operation: string) {
+    super(`${operation} was cancelled`, true);
+  }
+}
+
 // =============================================================================
 // Cache Errors
 // =============================================================================
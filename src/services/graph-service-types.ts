@@ -80,6 +80,19 @@ export interface DependencyQuery {
    * @default false
    */
   include_transitive?: boolean;
+
+  /**
+   * Collapse entities reached through several relationships or paths into
+   * one entry, at their shortest depth
+   * @default true
+   */
+  dedup?: boolean;
+
+  /**
+   * List the paths by which each entity was reached
+   * @default false
+   */
+  include_paths?: boolean;
 }
 
 /**
@@ -116,6 +129,19 @@ export interface DependentQuery {
    * @default false
    */
   include_cross_repo?: boolean;
+
+  /**
+   * Collapse entities reached through several relationships or paths into
+   * one entry, at their shortest depth
+   * @default true
+   */
+  dedup?: boolean;
+
+  /**
+   * List the paths by which each entity was reached
+   * @default false
+   */
+  include_paths?: boolean;
 }
 
 /**
@@ -250,6 +276,13 @@ export interface DependencyItem {
    */
   depth: number;
 
+  /**
+   * Paths by which the entity was reached, when requested with
+   * `include_paths`: the entities between the queried entity and this one,
+   * nearest first, so `[]` is a direct relationship. Shortest paths first.
+   */
+  paths?: string[][];
+
   /**
    * Additional metadata about the dependency
    */
@@ -80,6 +80,8 @@ export const DependencyQuerySchema = z
     depth: depthSchema,
     relationship_types: z.array(RelationshipTypeSchema).optional(),
     include_transitive: z.boolean().default(false),
+    dedup: z.boolean().default(true),
+    include_paths: z.boolean().default(false),
   })
   .strict();
 
@@ -102,6 +104,8 @@ export const DependentQuerySchema = z
     repository: z.string().trim().min(1).optional(),
     depth: depthSchema,
     include_cross_repo: z.boolean().default(false),
+    dedup: z.boolean().default(true),
+    include_paths: z.boolean().default(false),
   })
   .strict();
 
@@ -13,7 +13,7 @@
 import { z } from "zod";
 import type { Logger } from "pino";
 import type { GraphStorageAdapter } from "../graph/adapters/types.js";
-import { RelationshipType } from "../graph/types.js";
+import { RelationshipType, type DependencyInfo } from "../graph/types.js";
 import { isRetryableGraphError } from "../graph/errors.js";
 import { getComponentLogger } from "../logging/index.js";
 import { QueryCache, type CacheConfig, DEFAULT_CACHE_CONFIG } from "./graph-service-cache.js";
@@ -628,27 +628,22 @@ export class GraphServiceImpl implements GraphService {
       direction: "dependsOn",
       transitive: query.include_transitive,
       maxDepth: query.depth,
+      dedup: false,
     });
 
-    // Transform to GraphService result format
-    const dependencies: DependencyItem[] = result.direct.map((dep) => ({
-      type: dep.type,
-      path: dep.identifier,
-      relationship_type: dep.relationshipType,
-      depth: dep.depth,
-    }));
-
-    // Add transitive if included in query
-    if (query.include_transitive && result.transitive && result.transitive.length > 0) {
-      for (const dep of result.transitive) {
-        dependencies.push({
-          type: dep.type,
-          path: dep.identifier,
-          relationship_type: dep.relationshipType,
-          depth: dep.depth,
-        });
-      }
-    }
+    // Transform to GraphService result format, adding transitive if included in query
+    const found = query.include_transitive
+      ? [...result.direct, ...(result.transitive ?? [])]
+      : result.direct;
+    const dependencies: DependencyItem[] = this.collapseDependencies(found, query).map(
+      ({ dep, paths }) => ({
+        type: dep.type,
+        path: dep.identifier,
+        relationship_type: dep.relationshipType,
+        depth: dep.depth,
+        ...(paths && { paths }),
+      })
+    );
 
     return {
       entity: {
@@ -681,29 +676,21 @@ export class GraphServiceImpl implements GraphService {
       direction: "dependedOnBy",
       transitive: query.depth > 1,
       maxDepth: query.depth,
+      dedup: false,
     });
 
-    // Transform to GraphService result format
-    const dependents: DependentItem[] = result.direct.map((dep) => ({
-      type: dep.type,
-      path: dep.identifier,
-      repository: dep.repository,
-      relationship_type: dep.relationshipType,
-      depth: dep.depth,
-    }));
-
-    // Add transitive if included
-    if (result.transitive && result.transitive.length > 0) {
-      for (const dep of result.transitive) {
-        dependents.push({
-          type: dep.type,
-          path: dep.identifier,
-          repository: dep.repository,
-          relationship_type: dep.relationshipType,
-          depth: dep.depth,
-        });
-      }
-    }
+    // Transform to GraphService result format, including transitive dependents
+    const found = [...result.direct, ...(result.transitive ?? [])];
+    const dependents: DependentItem[] = this.collapseDependencies(found, query).map(
+      ({ dep, paths }) => ({
+        type: dep.type,
+        path: dep.identifier,
+        repository: dep.repository,
+        relationship_type: dep.relationshipType,
+        depth: dep.depth,
+        ...(paths && { paths }),
+      })
+    );
 
     const repositoriesSearched = query.include_cross_repo
       ? ["all"]
@@ -731,6 +718,43 @@ export class GraphServiceImpl implements GraphService {
     };
   }
 
+  /**
+   * Order dependencies by depth and, with `dedup`, collapse each entity into
+   * its shallowest entry. The sort is stable and the first entry of an
+   * entity is kept, so the order follows the adapter's within a depth.
+   *
+   * @returns Entries with the paths that reached them when `include_paths` is set
+   */
+  private collapseDependencies(
+    found: DependencyInfo[],
+    options: { dedup: boolean; include_paths: boolean }
+  ): Array<{ dep: DependencyInfo; paths?: string[][] }> {
+    const ordered = [...found].sort((a, b) => a.depth - b.depth);
+    if (!options.dedup) {
+      return ordered.map((dep) => ({
+        dep,
+        ...(options.include_paths && { paths: [dep.via ?? []] }),
+      }));
+    }
+
+    const entries = new Map<string, { dep: DependencyInfo; paths: string[][] }>();
+    for (const dep of ordered) {
+      const key = dep.id ?? `${dep.type}:${dep.repository}:${dep.identifier}`;
+      let entry = entries.get(key);
+      if (!entry) {
+        entry = { dep, paths: [] };
+        entries.set(key, entry);
+      }
+      const path = dep.via ?? [];
+      if (!entry.paths.some((seen) => seen.join("\0") === path.join("\0"))) {
+        entry.paths.push(path);
+      }
+    }
+    return [...entries.values()].map(({ dep, paths }) =>
+      options.include_paths ? { dep, paths } : { dep }
+    );
+  }
+
   /**
    * Execute path query using Neo4jClient traverse
    */
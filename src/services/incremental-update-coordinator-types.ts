@@ -58,6 +58,20 @@ export interface CoordinatorConfig {
   gitPat?: string;
 }
 
+/**
+ * Per-call options for an incremental update.
+ */
+export interface CoordinatorUpdateOptions {
+  /**
+   * Signal that stops the update at the next file or embedding batch.
+   *
+   * A cancelled update throws `IndexingCancelledError` and leaves the
+   * repository's last indexed commit (or manifest) where it was, so the next
+   * update re-applies the same changes.
+   */
+  signal?: AbortSignal;
+}
+
 /**
  * Result status for an incremental update operation.
  *
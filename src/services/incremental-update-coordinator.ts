@@ -32,6 +32,7 @@ import { GitHubNotFoundError } from "./github-client-errors.js";
 import type {
   CoordinatorConfig,
   CoordinatorResult,
+  CoordinatorUpdateOptions,
 } from "./incremental-update-coordinator-types.js";
 import type { IndexCompletenessChecker } from "./index-completeness-checker.js";
 import type { CompletenessCheckResult } from "./index-completeness-types.js";
@@ -209,6 +210,7 @@ export class IncrementalUpdateCoordinator {
    * 9. Updates metadata with new commit SHA
    *
    * @param repositoryName - Name of repository to update (metadata key)
+   * @param options - Per-call options (cancellation signal)
    * @returns Result with status, statistics, and any errors
    *
    * @throws {RepositoryNotFoundError} If repository not in metadata store
@@ -216,6 +218,7 @@ export class IncrementalUpdateCoordinator {
    * @throws {ForcePushDetectedError} If base commit no longer exists (force push)
    * @throws {ChangeThresholdExceededError} If changes exceed 500 files
    * @throws {GitPullError} If local clone update fails
+   * @throws {IndexingCancelledError} If `options.signal` aborts while changes are processed
    * @throws {Error} For GitHub API errors, network issues, etc.
    *
    * @example
@@ -226,7 +229,10 @@ export class IncrementalUpdateCoordinator {
    * console.log(`Files modified: ${result.stats.filesModified}`);
    * ```
    */
-  async updateRepository(repositoryName: string): Promise<CoordinatorResult> {
+  async updateRepository(
+    repositoryName: string,
+    options: CoordinatorUpdateOptions = {}
+  ): Promise<CoordinatorResult> {
     const startTime = Date.now();
 
     // Generate correlation ID for tracing this update operation
@@ -489,6 +495,7 @@ export class IncrementalUpdateCoordinator {
           model: repo.embeddingModel,
           dimensions: repo.embeddingDimensions,
         },
+        signal: options.signal,
       });
 
       logger.info(
@@ -608,6 +615,9 @@ export class IncrementalUpdateCoordinator {
         ...(pipelineResult.stats.chunksSkipped !== undefined && {
           chunksSkipped: pipelineResult.stats.chunksSkipped,
         }),
+        ...(pipelineResult.stats.chunksTruncated !== undefined && {
+          chunksTruncated: pipelineResult.stats.chunksTruncated,
+        }),
         skippedFileCount: pipelineResult.filterStats.skippedChanges,
         eligibleFileCount: pipelineResult.filterStats.eligibleChanges,
         // Include graph stats if graph service was configured
@@ -31,7 +31,13 @@ import type { InternalChunk } from "./ingestion-types.js";
 import type { GraphIngestionService } from "../graph/ingestion/GraphIngestionService.js";
 import { EntityExtractor } from "../graph/extraction/EntityExtractor.js";
 import { DEFAULT_EXTENSIONS } from "../ingestion/default-extensions.js";
-import { estimateTokens } from "../ingestion/chunk-utils.js";
+import {
+  prepareEmbeddingInput,
+  resolveEmbeddingInputTokens,
+} from "../ingestion/embedding-input.js";
+import { decodeSourceText, type DecodedText } from "../ingestion/encoding-detector.js";
+import { UndecodableFileError } from "../ingestion/errors.js";
+import { IndexingCancelledError } from "./ingestion-errors.js";
 import type { DocumentTypeDetector } from "../documents/DocumentTypeDetector.js";
 import type { DocumentChunker } from "../documents/DocumentChunker.js";
 import type { ExtractionResult } from "../documents/types.js";
@@ -80,26 +86,6 @@ export class IncrementalUpdatePipeline {
    */
   private readonly EMBEDDING_BATCH_SIZE = 100;
 
-  /**
-   * Maximum estimated tokens for a single chunk sent to the embedding provider.
-   *
-   * OpenAI's hard input limit is 8,192 tokens per text. `estimateTokens` uses a
-   * chars/4 heuristic that can under-estimate for token-dense content (tables,
-   * code), so this ceiling is kept below 8,192. Correctly chunked content is at
-   * most ~`CHUNK_MAX_TOKENS` estimated tokens; anything above this threshold is
-   * pathological (issue #589) and is skipped with an error rather than poisoning
-   * its whole embedding batch.
-   *
-   * Derived from the operator's `CHUNK_MAX_TOKENS` override (the same env var the
-   * FileChunker/DocumentChunker honor) so that raising the chunk size does not
-   * silently cause every chunk to exceed a hard-coded skip threshold — that
-   * would turn a valid config into total data loss. The ceiling is `2x` the
-   * configured chunk size to leave headroom for the chars/4 estimator's
-   * under-counting, with a floor of 6,000 (the historical default) and a cap of
-   * 8,000 to stay below the provider's 8,192 hard limit.
-   */
-  private readonly MAX_EMBEDDING_INPUT_TOKENS: number;
-
   /**
    * Doc-graph helper instance — stateless aside from cached extractor objects
    * so we don't re-instantiate `DocEntityExtractor` / `PdfDocxEntityExtractor`
@@ -131,16 +117,7 @@ export class IncrementalUpdatePipeline {
     private readonly documentTypeDetector?: DocumentTypeDetector,
     private readonly documentChunker?: DocumentChunker,
     private readonly providerResolver?: RepositoryEmbeddingProviderResolver
-  ) {
-    // Derive the per-chunk skip ceiling from the operator's CHUNK_MAX_TOKENS
-    // override so a larger configured chunk size doesn't cause every chunk to be
-    // skipped against a fixed limit. Floor at 6,000 (historical default), cap at
-    // 8,000 to stay under the provider's 8,192 hard input limit.
-    const envMax = process.env["CHUNK_MAX_TOKENS"];
-    const parsed = envMax ? parseInt(envMax, 10) : NaN;
-    const configured = !isNaN(parsed) && parsed > 0 ? parsed : 500;
-    this.MAX_EMBEDDING_INPUT_TOKENS = Math.min(8000, Math.max(6000, configured * 2));
-  }
+  ) {}
 
   /**
    * Validate file path to prevent path traversal attacks.
@@ -177,6 +154,24 @@ export class IncrementalUpdatePipeline {
     return absolutePath;
   }
 
+  /**
+   * Read a code file for chunking, decoding invalid UTF-8 lossily.
+   *
+   * @param absolutePath - Validated absolute file path
+   * @param relativePath - Repository-relative path, for logging
+   * @throws {UndecodableFileError} If too much of the file is invalid UTF-8
+   */
+  private async readCodeFile(absolutePath: string, relativePath: string): Promise<DecodedText> {
+    const decoded = decodeSourceText(new Uint8Array(await Bun.file(absolutePath).arrayBuffer()));
+    if (decoded.encodingWarning) {
+      this.logger.warn(
+        { path: relativePath, detail: decoded.encodingWarning.detail },
+        "File is not valid UTF-8, indexing a lossy decode"
+      );
+    }
+    return decoded;
+  }
+
   /**
    * Process file changes and update the vector index incrementally.
    *
@@ -192,6 +187,7 @@ export class IncrementalUpdatePipeline {
    * @param changes - List of file changes to process
    * @param options - Update configuration and filtering rules
    * @returns Result with statistics and any errors encountered
+   * @throws {IndexingCancelledError} If `options.signal` aborts before a file or embedding batch
    *
    * @example
    * ```typescript
@@ -229,6 +225,7 @@ export class IncrementalUpdatePipeline {
       chunksUpserted: 0,
       chunksDeleted: 0,
       chunksSkipped: 0,
+      chunksTruncated: 0,
       durationMs: 0,
     };
 
@@ -403,6 +400,7 @@ export class IncrementalUpdatePipeline {
 
     // Process each change
     for (const change of filteredChanges) {
+      this.throwIfCancelled(options, logger);
       try {
         switch (change.status) {
           case "added":
@@ -447,6 +445,13 @@ export class IncrementalUpdatePipeline {
             this.logger.warn({ change }, "Unknown change status, skipping");
         }
       } catch (error) {
+        if (error instanceof UndecodableFileError) {
+          logger.warn(
+            { path: change.path, status: change.status, reason: error.message },
+            "Skipping file that could not be decoded as text"
+          );
+          continue;
+        }
         // Collect error and continue processing other files
         const errorMessage = error instanceof Error ? error.message : String(error);
         const errorType = error instanceof Error ? error.constructor.name : "Unknown";
@@ -478,9 +483,13 @@ export class IncrementalUpdatePipeline {
           stats,
           errors,
           logger,
-          embeddingProvider
+          embeddingProvider,
+          options
         );
       } catch (error) {
+        if (error instanceof IndexingCancelledError) {
+          throw error;
+        }
         const errorMessage = error instanceof Error ? error.message : String(error);
         const errorType = error instanceof Error ? error.constructor.name : "Unknown";
         logger.error(
@@ -576,6 +585,21 @@ export class IncrementalUpdatePipeline {
     return { stats, errors, filterStats };
   }
 
+  /**
+   * List the distinct file paths with chunks indexed for a repository.
+   *
+   * Lets a caller whose change list cannot be trusted (the local-folder
+   * coordinator after discarding a corrupt manifest) find indexed files that
+   * are no longer on disk.
+   *
+   * @param collectionName - Collection holding the repository's chunks
+   * @param repository - Repository name to filter chunks by
+   * @returns Set of indexed file paths
+   */
+  async listIndexedFilePaths(collectionName: string, repository: string): Promise<Set<string>> {
+    return this.storageClient.listIndexedFilePaths(collectionName, repository);
+  }
+
   /**
    * Check if a file should be processed based on extension and exclusion patterns.
    *
@@ -638,13 +662,14 @@ export class IncrementalUpdatePipeline {
    * Only TypeScript/JavaScript files are processed for graph extraction.
    *
    * @param operation - Type of graph operation: 'ingest' or 'delete'
-   * @param file - File information with path and optional content
+   * @param file - File information with path and optional content; `reingest`
+   *   marks a delete that the same file's ingest follows
    * @param repositoryName - Repository name for graph node IDs
    * @param graphStats - Statistics to update
    */
   private async processGraphUpdate(
     operation: "ingest" | "delete",
-    file: { path: string; content?: string },
+    file: { path: string; content?: string; encodingWarning?: string; reingest?: boolean },
     repositoryName: string,
     graphStats: GraphUpdateStats
   ): Promise<void> {
@@ -663,7 +688,11 @@ export class IncrementalUpdatePipeline {
       const startTime = performance.now();
 
       if (operation === "delete") {
-        const result = await this.graphIngestionService.deleteFileData(repositoryName, file.path);
+        const result = file.reingest
+          ? await this.graphIngestionService.deleteFileData(repositoryName, file.path, {
+              reingest: true,
+            })
+          : await this.graphIngestionService.deleteFileData(repositoryName, file.path);
         graphStats.graphNodesDeleted += result.nodesDeleted;
         graphStats.graphRelationshipsDeleted += result.relationshipsDeleted;
         if (!result.success) {
@@ -683,7 +712,7 @@ export class IncrementalUpdatePipeline {
         }
 
         const result = await this.graphIngestionService.ingestFile(
-          { path: file.path, content: file.content },
+          { path: file.path, content: file.content, encodingWarning: file.encodingWarning },
           repositoryName
         );
         graphStats.graphNodesCreated += result.nodesCreated;
@@ -759,7 +788,7 @@ export class IncrementalUpdatePipeline {
         docExtractionResults.push(docExtraction);
       }
     } else {
-      const content = await Bun.file(absolutePath).text();
+      const { text: content, encodingWarning } = await this.readCodeFile(absolutePath, change.path);
 
       const fileInfo: FileInfo = {
         relativePath: change.path,
@@ -776,7 +805,7 @@ export class IncrementalUpdatePipeline {
       if (graphStats) {
         await this.processGraphUpdate(
           "ingest",
-          { path: change.path, content },
+          { path: change.path, content, encodingWarning: encodingWarning?.detail },
           options.repository,
           graphStats
         );
@@ -828,7 +857,8 @@ export class IncrementalUpdatePipeline {
         try {
           const result = await this.graphIngestionService.deleteFileData(
             options.repository,
-            change.path
+            change.path,
+            { reingest: true }
           );
           graphStats.graphNodesDeleted += result.nodesDeleted;
           graphStats.graphRelationshipsDeleted += result.relationshipsDeleted;
@@ -860,14 +890,14 @@ export class IncrementalUpdatePipeline {
       if (graphStats) {
         await this.processGraphUpdate(
           "delete",
-          { path: change.path },
+          { path: change.path, reingest: true },
           options.repository,
           graphStats
         );
       }
 
       // Read and chunk new content
-      const content = await Bun.file(absolutePath).text();
+      const { text: content, encodingWarning } = await this.readCodeFile(absolutePath, change.path);
 
       const fileInfo: FileInfo = {
         relativePath: change.path,
@@ -884,7 +914,7 @@ export class IncrementalUpdatePipeline {
       if (graphStats) {
         await this.processGraphUpdate(
           "ingest",
-          { path: change.path, content },
+          { path: change.path, content, encodingWarning: encodingWarning?.detail },
           options.repository,
           graphStats
         );
@@ -1017,7 +1047,7 @@ export class IncrementalUpdatePipeline {
         );
       }
 
-      const content = await Bun.file(absolutePath).text();
+      const { text: content, encodingWarning } = await this.readCodeFile(absolutePath, change.path);
 
       const fileInfo: FileInfo = {
         relativePath: change.path,
@@ -1034,7 +1064,7 @@ export class IncrementalUpdatePipeline {
       if (graphStats) {
         await this.processGraphUpdate(
           "ingest",
-          { path: change.path, content },
+          { path: change.path, content, encodingWarning: encodingWarning?.detail },
           options.repository,
           graphStats
         );
@@ -1053,8 +1083,10 @@ export class IncrementalUpdatePipeline {
    * update (issue #589: a single oversized chunk previously caused every chunk
    * in the update to be lost after their predecessors were already deleted).
    *
-   * Chunks whose estimated token count exceeds MAX_EMBEDDING_INPUT_TOKENS are
-   * skipped up-front with a per-chunk error so they cannot poison a batch.
+   * Chunks whose estimated token count exceeds the embedding input budget
+   * (see `resolveEmbeddingInputTokens`) are embedded from a deterministically
+   * truncated prefix so they cannot poison a batch; the full content is still
+   * stored.
    *
    * @param chunks - All chunks to embed and store
    * @param collectionName - Target ChromaDB collection
@@ -1063,6 +1095,8 @@ export class IncrementalUpdatePipeline {
    * @param logger - Correlation-aware logger
    * @param embeddingProvider - Provider selected for this update (#591) — the
    *   repository's recorded provider when resolvable, else the default
+   * @param options - Update options, for the cancellation signal
+   * @throws {IndexingCancelledError} If the signal aborts between batches
    */
   private async embedAndStoreChunks(
     chunks: InternalChunk[],
@@ -1070,44 +1104,42 @@ export class IncrementalUpdatePipeline {
     stats: UpdateStats,
     errors: FileProcessingError[],
     logger: Logger,
-    embeddingProvider: EmbeddingProvider
+    embeddingProvider: EmbeddingProvider,
+    options: UpdateOptions
   ): Promise<void> {
     const startTime = Date.now();
 
-    // Safety net: skip pathologically large chunks instead of letting them
-    // fail an entire embedding request. With correct chunking this never
-    // trips, but a chunker regression must degrade per-chunk, not per-update.
-    const safeChunks: InternalChunk[] = [];
+    // Safety net: fit pathologically large chunks to the input budget instead
+    // of letting them fail an entire embedding request. With correct chunking
+    // this never trips, but a chunker regression must degrade per-chunk, not
+    // per-update.
+    const maxInputTokens = resolveEmbeddingInputTokens(
+      embeddingProvider.getCapabilities().maxTokensPerText
+    );
+    const inputs = new Map<string, string>();
     for (const chunk of chunks) {
-      const estimatedTokens = estimateTokens(chunk.content);
-      if (estimatedTokens > this.MAX_EMBEDDING_INPUT_TOKENS) {
-        stats.chunksSkipped = (stats.chunksSkipped ?? 0) + 1;
-        errors.push({
-          path: chunk.filePath,
-          error:
-            `Chunk ${chunk.id} skipped: estimated ${estimatedTokens} tokens exceeds ` +
-            `embedding input limit (${this.MAX_EMBEDDING_INPUT_TOKENS})`,
-        });
+      const input = prepareEmbeddingInput(chunk.content, maxInputTokens);
+      if (input.truncated) {
+        stats.chunksTruncated = (stats.chunksTruncated ?? 0) + 1;
         logger.warn(
           {
             operation: "pipeline_embed_chunks",
             chunkId: chunk.id,
             filePath: chunk.filePath,
-            estimatedTokens,
-            limit: this.MAX_EMBEDDING_INPUT_TOKENS,
+            estimatedTokens: input.originalTokens,
+            limit: maxInputTokens,
           },
-          "Skipping oversized chunk - exceeds embedding input limit"
+          "Truncating oversized chunk to the embedding input limit"
         );
-        continue;
       }
-      safeChunks.push(chunk);
+      inputs.set(chunk.id, input.text);
     }
 
     logger.info(
       {
         operation: "pipeline_embed_chunks",
-        chunkCount: safeChunks.length,
-        skippedOversized: chunks.length - safeChunks.length,
+        chunkCount: chunks.length,
+        truncatedOversized: stats.chunksTruncated ?? 0,
       },
       "Generating embeddings for chunks"
     );
@@ -1115,10 +1147,11 @@ export class IncrementalUpdatePipeline {
     // Embed and upsert per batch (max 100 texts per request). Batches are
     // independent: a failed batch is recorded as an error and the remaining
     // batches still get stored.
-    const batchCount = Math.ceil(safeChunks.length / this.EMBEDDING_BATCH_SIZE);
+    const batchCount = Math.ceil(chunks.length / this.EMBEDDING_BATCH_SIZE);
 
-    for (let i = 0; i < safeChunks.length; i += this.EMBEDDING_BATCH_SIZE) {
-      const batch = safeChunks.slice(i, i + this.EMBEDDING_BATCH_SIZE);
+    for (let i = 0; i < chunks.length; i += this.EMBEDDING_BATCH_SIZE) {
+      this.throwIfCancelled(options, logger);
+      const batch = chunks.slice(i, i + this.EMBEDDING_BATCH_SIZE);
       const batchIndex = Math.floor(i / this.EMBEDDING_BATCH_SIZE) + 1;
       const batchStartTime = Date.now();
 
@@ -1133,7 +1166,9 @@ export class IncrementalUpdatePipeline {
       );
 
       try {
-        const embeddings = await embeddingProvider.generateEmbeddings(batch.map((c) => c.content));
+        const embeddings = await embeddingProvider.generateEmbeddings(
+          batch.map((c) => inputs.get(c.id) ?? c.content)
+        );
 
         // Create DocumentInput objects for this batch
         const documents: DocumentInput[] = batch.map((chunk, index) => {
@@ -1216,7 +1251,7 @@ export class IncrementalUpdatePipeline {
       {
         operation: "pipeline_upsert_documents",
         upsertedCount: stats.chunksUpserted,
-        skippedOversized: chunks.length - safeChunks.length,
+        truncatedOversized: stats.chunksTruncated ?? 0,
         totalDurationMs: Date.now() - startTime,
       },
       "Embed and store completed"
@@ -1337,4 +1372,19 @@ export class IncrementalUpdatePipeline {
       fileModifiedAt: chunk.metadata.fileModifiedAt,
     }));
   }
+
+  /**
+   * Stop an update at a safe point once its abort signal has fired
+   *
+   * @throws {IndexingCancelledError} If the signal is aborted
+   */
+  private throwIfCancelled(options: UpdateOptions, logger: Logger): void {
+    if (options.signal?.aborted) {
+      logger.info(
+        { operation: "pipeline_process_changes", repository: options.repository },
+        "Incremental update cancelled"
+      );
+      throw new IndexingCancelledError(options.repository);
+    }
+  }
 }
@@ -157,6 +157,15 @@ export interface UpdateOptions {
     /** Embedding vector dimensions the repository was indexed with. */
     dimensions?: number;
   };
+
+  /**
+   * Signal that stops the update early.
+   *
+   * Checked before each file and each embedding batch; a cancelled update
+   * throws `IndexingCancelledError`. Changes already applied stay in the
+   * index, so callers must not advance the repository's baseline.
+   */
+  signal?: AbortSignal;
 }
 
 // =============================================================================
@@ -370,17 +379,28 @@ export interface UpdateStats {
 
   /**
    * Number of chunks skipped because their estimated token count exceeded the
-   * embedding input ceiling (MAX_EMBEDDING_INPUT_TOKENS).
+   * embedding input ceiling.
    *
    * Optional so existing partial-stats objects (mocks, the coordinator's
-   * zero-stats helpers) remain valid. A non-zero value indicates pathological
-   * chunks were dropped before embedding (issue #589) rather than poisoning
-   * their batch — the affected files also appear in `errors`.
+   * zero-stats helpers) remain valid. Oversized chunks are now truncated
+   * instead (see `chunksTruncated`), so the pipeline reports 0 here.
    *
-   * @example 1
+   * @example 0
    */
   chunksSkipped?: number;
 
+  /**
+   * Number of chunks whose embedding input was truncated to the token budget
+   * (see `resolveEmbeddingInputTokens`).
+   *
+   * The full chunk content is still stored; only the embedded text is cut, so
+   * a pathological chunk (issue #589) degrades its own search quality instead
+   * of failing its batch. Optional for the same reason as `chunksSkipped`.
+   *
+   * @example 1
+   */
+  chunksTruncated?: number;
+
   /**
    * Graph database update statistics (optional).
    *
@@ -0,0 +1,262 @@
+/**
+ * Index Checkpoint Store Implementation
+ *
+ * Per-repository progress records for interrupted index builds. While
+ * `IngestionService.indexRepository` runs it periodically saves which files
+ * have completed the chunk → embed → store pipeline; a later run of the same
+ * build loads the checkpoint and skips those files instead of re-embedding
+ * them. The checkpoint is deleted once the build completes.
+ *
+ * Follows the singleton + atomic-write pattern of `file-manifest-store.ts`.
+ * Each repository gets its own checkpoint file under
+ * `{DATA_PATH}/checkpoints/<sanitized-repo-name>_<hash>.json`.
+ *
+ * @module services/index-checkpoint-store
+ */
+
+import { join, dirname } from "path";
+import { rename, unlink, mkdir } from "fs/promises";
+import type { Logger } from "pino";
+import { z } from "zod";
+import { getComponentLogger } from "../logging/index.js";
+import { sanitizeCollectionName } from "../repositories/metadata-store.js";
+
+/**
+ * Schema version literal for persisted checkpoints.
+ */
+export const INDEX_CHECKPOINT_VERSION = "1.0";
+export type IndexCheckpointVersion = typeof INDEX_CHECKPOINT_VERSION;
+
+/**
+ * Fingerprint of a completed file, used to check that it is unchanged when
+ * the build resumes.
+ */
+export interface IndexCheckpointFile {
+  /** File size in bytes at the time it was indexed. */
+  sizeBytes: number;
+  /** Modification time in milliseconds at the time it was indexed. */
+  mtimeMs: number;
+}
+
+/**
+ * Persisted progress of an index build for a single repository.
+ *
+ * A checkpoint only applies to a build of the same source: `url`, `branch`,
+ * `commitSha` and the embedding model must all match the resuming run.
+ */
+export interface IndexCheckpoint {
+  /** Schema version (see `INDEX_CHECKPOINT_VERSION`). */
+  version: IndexCheckpointVersion;
+  /** Repository name this checkpoint belongs to. */
+  repository: string;
+  /** Repository URL or local path passed to `indexRepository`. */
+  url: string;
+  /** Branch that was indexed. */
+  branch: string;
+  /** Commit that was indexed; undefined for local folders. */
+  commitSha?: string;
+  /** Embedding provider and model, as `"<providerId>:<modelId>"`. */
+  embeddingModel: string;
+  /** ISO 8601 timestamp of when the build started. */
+  startedAt: string;
+  /** ISO 8601 timestamp of the last save. */
+  updatedAt: string;
+  /** POSIX-relative paths of completed files mapped to their fingerprints. */
+  files: Record<string, IndexCheckpointFile>;
+  /** Totals for the completed files, carried into the resumed run's stats. */
+  stats: {
+    filesProcessed: number;
+    chunksCreated: number;
+    embeddingsGenerated: number;
+    documentsStored: number;
+  };
+}
+
+/**
+ * Service interface for persisting index build checkpoints.
+ */
+export interface IndexCheckpointStore {
+  /**
+   * Load the checkpoint for a repository.
+   *
+   * Returns null when there is no checkpoint. A checkpoint that cannot be
+   * parsed is logged, removed, and reported as absent so the build starts over.
+   *
+   * @param repository - Repository name (matches `RepositoryInfo.name`).
+   */
+  loadCheckpoint(repository: string): Promise<IndexCheckpoint | null>;
+
+  /**
+   * Persist a checkpoint using an atomic temp-file + rename.
+   *
+   * @param checkpoint - Checkpoint to save, keyed by `checkpoint.repository`.
+   */
+  saveCheckpoint(checkpoint: IndexCheckpoint): Promise<void>;
+
+  /**
+   * Remove the checkpoint for a repository.
+   *
+   * Idempotent — succeeds with no error when the checkpoint does not exist.
+   */
+  deleteCheckpoint(repository: string): Promise<void>;
+}
+
+/** Zod schema for the persisted checkpoint file format (validated on read). */
+const IndexCheckpointSchema = z.object({
+  version: z.literal(INDEX_CHECKPOINT_VERSION),
+  repository: z.string(),
+  url: z.string(),
+  branch: z.string(),
+  commitSha: z.string().optional(),
+  embeddingModel: z.string(),
+  startedAt: z.string(),
+  updatedAt: z.string(),
+  files: z.record(
+    z.string(),
+    z.object({
+      sizeBytes: z.number(),
+      mtimeMs: z.number(),
+    })
+  ),
+  stats: z.object({
+    filesProcessed: z.number(),
+    chunksCreated: z.number(),
+    embeddingsGenerated: z.number(),
+    documentsStored: z.number(),
+  }),
+});
+
+/**
+ * Singleton implementation of the index checkpoint store.
+ *
+ * Manages per-repository checkpoint persistence under `{DATA_PATH}/checkpoints/`.
+ * Writes are not queued: only the single in-progress indexing run writes a
+ * repository's checkpoint.
+ */
+export class IndexCheckpointStoreImpl implements IndexCheckpointStore {
+  private static instance: IndexCheckpointStoreImpl | null = null;
+
+  private readonly checkpointsDir: string;
+  private _logger: Logger | null = null;
+
+  private constructor(dataPath: string) {
+    this.checkpointsDir = join(dataPath, "checkpoints");
+  }
+
+  /** Lazy-initialized component logger. */
+  private get logger(): Logger {
+    if (!this._logger) {
+      this._logger = getComponentLogger("services:index-checkpoint-store");
+    }
+    return this._logger;
+  }
+
+  /**
+   * Get the singleton instance of the checkpoint store.
+   *
+   * @param dataPath - Optional data directory (defaults to `process.env.DATA_PATH || "./data"`).
+   */
+  public static getInstance(dataPath?: string): IndexCheckpointStoreImpl {
+    if (!IndexCheckpointStoreImpl.instance) {
+      const resolvedPath = dataPath || process.env["DATA_PATH"] || "./data";
+      IndexCheckpointStoreImpl.instance = new IndexCheckpointStoreImpl(resolvedPath);
+    } else if (dataPath !== undefined) {
+      const logger = getComponentLogger("services:index-checkpoint-store");
+      logger.warn(
+        { requestedPath: dataPath },
+        "getInstance called with dataPath after singleton already initialized - ignoring new path"
+      );
+    }
+    return IndexCheckpointStoreImpl.instance;
+  }
+
+  /**
+   * Reset the singleton instance.
+   *
+   * **FOR TESTING ONLY**.
+   *
+   * @internal
+   */
+  public static resetInstance(): void {
+    IndexCheckpointStoreImpl.instance = null;
+  }
+
+  /**
+   * Resolve the on-disk path for a repository's checkpoint file.
+   *
+   * Uses the same sanitized-name + hash-suffix scheme as
+   * `FileManifestStoreImpl.getManifestPath` so names differing only in case
+   * do not collide.
+   */
+  public getCheckpointPath(repository: string): string {
+    const base = sanitizeCollectionName(repository);
+    const suffix = Bun.hash(repository).toString(16).padStart(16, "0").substring(0, 8);
+    return join(this.checkpointsDir, `${base}_${suffix}.json`);
+  }
+
+  async loadCheckpoint(repository: string): Promise<IndexCheckpoint | null> {
+    const filePath = this.getCheckpointPath(repository);
+    const file = Bun.file(filePath);
+
+    if (!(await file.exists())) {
+      return null;
+    }
+
+    try {
+      const parsed: unknown = JSON.parse(await file.text());
+      const checkpoint = IndexCheckpointSchema.parse(parsed);
+      this.logger.debug(
+        { filePath, repository, fileCount: Object.keys(checkpoint.files).length },
+        "Checkpoint loaded from disk"
+      );
+      return checkpoint;
+    } catch (error) {
+      this.logger.warn(
+        { filePath, repository, error: error instanceof Error ? error.message : String(error) },
+        "Discarding unreadable checkpoint - the build will start over"
+      );
+      await this.deleteCheckpoint(repository);
+      return null;
+    }
+  }
+
+  async saveCheckpoint(checkpoint: IndexCheckpoint): Promise<void> {
+    const filePath = this.getCheckpointPath(checkpoint.repository);
+    const tempPath = `${filePath}.tmp`;
+
+    await mkdir(dirname(filePath), { recursive: true });
+
+    try {
+      await Bun.write(tempPath, JSON.stringify(checkpoint, null, 2));
+      await rename(tempPath, filePath);
+      this.logger.debug(
+        {
+          filePath,
+          repository: checkpoint.repository,
+          fileCount: Object.keys(checkpoint.files).length,
+        },
+        "Checkpoint saved to disk"
+      );
+    } catch (error) {
+      try {
+        await unlink(tempPath);
+      } catch {
+        // Ignore cleanup errors
+      }
+      throw error;
+    }
+  }
+
+  async deleteCheckpoint(repository: string): Promise<void> {
+    const filePath = this.getCheckpointPath(repository);
+    try {
+      await unlink(filePath);
+      this.logger.debug({ filePath, repository }, "Checkpoint deleted");
+    } catch (error) {
+      // Idempotent: missing file is not an error
+      if ((error as NodeJS.ErrnoException).code !== "ENOENT") {
+        throw error;
+      }
+    }
+  }
+}
@@ -105,6 +105,7 @@ export class IndexCompletenessChecker {
       const eligibleFiles = await this.fileScanner.scanFiles(repo.localPath, {
         includeExtensions: repo.includeExtensions,
         excludePatterns: repo.excludePatterns,
+        respectGitignore: repo.respectGitignore,
       });
 
       const eligibleFileCount = eligibleFiles.length;
@@ -144,6 +144,7 @@ export class IndexRepairService {
     const eligible = await this.fileScanner.scanFiles(repo.localPath, {
       includeExtensions: repo.includeExtensions,
       excludePatterns: repo.excludePatterns,
+      respectGitignore: repo.respectGitignore,
     });
     const diskSet = new Set(eligible.map((f) => toPosix(f.relativePath)));
 
@@ -61,9 +61,16 @@ export {
   IngestionError,
   RepositoryAlreadyExistsError,
   IndexingInProgressError,
+  IndexingCancelledError,
   CloneError,
   CollectionCreationError,
 } from "./ingestion-errors.js";
+export type {
+  IndexCheckpoint,
+  IndexCheckpointFile,
+  IndexCheckpointStore,
+} from "./index-checkpoint-store.js";
+export { IndexCheckpointStoreImpl, INDEX_CHECKPOINT_VERSION } from "./index-checkpoint-store.js";
 
 // GitHubClient exports
 export type {
@@ -117,6 +124,7 @@ export type {
   CoordinatorConfig,
   CoordinatorResult,
   CoordinatorStatus,
+  CoordinatorUpdateOptions,
   GitHubRepoInfo,
 } from "./incremental-update-coordinator-types.js";
 export {
@@ -211,6 +219,172 @@ export {
 export { QueryCache, DEFAULT_CACHE_CONFIG } from "./graph-service-cache.js";
 export type { CacheConfig, CacheStats } from "./graph-service-cache.js";
 
+// GraphAnalysisService exports
+export type {
+  GraphAnalysisService,
+  SymbolLocation,
+  SymbolParameter,
+  SymbolImportsQuery,
+  SymbolImportsResult,
+  SymbolFileImports,
+  ImportRecord,
+  ImportAlias,
+  ModuleCyclesQuery,
+  ModuleCyclesResult,
+  ModuleCycle,
+  ModuleImportEdge,
+  TopLevelSymbolsQuery,
+  TopLevelSymbolsResult,
+  TopLevelSymbol,
+  FileTopLevelSymbols,
+  TodoListQuery,
+  TodoListResult,
+  TodoItem,
+  SymbolSearchQuery,
+  SymbolSearchResult,
+  SymbolSearchMatch,
+  SymbolMatchType,
+  TypePosition,
+  TypeSearchQuery,
+  TypeSearchMatch,
+  TypeSearchResult,
+  EntryPointsQuery,
+  EntryPointSymbol,
+  EntryPointGroup,
+  EntryPointsResult,
+  AttributeSearchQuery,
+  AttributeMatch,
+  AttributeSearchResult,
+  DeprecationReportQuery,
+  DeprecationCallSite,
+  DeprecatedSymbol,
+  DeprecationReportResult,
+  UnsafeCodeQuery,
+  UnsafeBlockSpan,
+  UnsafeSymbol,
+  UnsafeImpl,
+  UnsafeCodeResult,
+  DoctestSearchQuery,
+  DoctestExample,
+  DoctestSearchResult,
+  StringLiteralSearchQuery,
+  StringLiteralSearchResult,
+  StringLiteralMatch,
+  CouplingMetricsQuery,
+  CouplingMetricsResult,
+  MostCoupledQuery,
+  MostCoupledResult,
+  HotspotsQuery,
+  Hotspot,
+  HotspotsResult,
+  FunctionCouplingMetrics,
+  CallPathQuery,
+  CallPath,
+  CallPathResult,
+  TypeHierarchyQuery,
+  TypeReference,
+  TraitImplementation,
+  TypeHierarchyEntry,
+  TypeHierarchyResult,
+} from "./graph-analysis-types.js";
+
+export {
+  GraphAnalysisServiceImpl,
+  DEFAULT_GRAPH_ANALYSIS_SERVICE_CONFIG,
+} from "./graph-analysis-service.js";
+export type { GraphAnalysisServiceConfig } from "./graph-analysis-service.js";
+export {
+  SymbolImportsQuerySchema,
+  ModuleCyclesQuerySchema,
+  TopLevelSymbolsQuerySchema,
+  TodoListQuerySchema,
+  SymbolSearchQuerySchema,
+  StringLiteralSearchQuerySchema,
+  CouplingMetricsQuerySchema,
+  MostCoupledQuerySchema,
+  HotspotsQuerySchema,
+  EntryPointsQuerySchema,
+  AttributeSearchQuerySchema,
+  DeprecationReportQuerySchema,
+  UnsafeCodeQuerySchema,
+  DoctestSearchQuerySchema,
+  CallPathQuerySchema,
+  TypeHierarchyQuerySchema,
+} from "./graph-analysis-validation.js";
+export type {
+  ValidatedSymbolImportsQuery,
+  ValidatedModuleCyclesQuery,
+  ValidatedTopLevelSymbolsQuery,
+  ValidatedTodoListQuery,
+  ValidatedSymbolSearchQuery,
+  ValidatedStringLiteralSearchQuery,
+  ValidatedCouplingMetricsQuery,
+  ValidatedMostCoupledQuery,
+  ValidatedHotspotsQuery,
+  ValidatedEntryPointsQuery,
+  ValidatedAttributeSearchQuery,
+  ValidatedDeprecationReportQuery,
+  ValidatedUnsafeCodeQuery,
+  ValidatedDoctestSearchQuery,
+  ValidatedCallPathQuery,
+  ValidatedTypeHierarchyQuery,
+} from "./graph-analysis-validation.js";
+export { findStronglyConnectedComponents, findShortestPaths } from "./graph-algorithms.js";
+export type { AdjacencyMap, ShortestPaths } from "./graph-algorithms.js";
+
+// Symbol Diff exports
+export type {
+  SymbolDiffService,
+  SymbolDiffQuery,
+  SymbolDiffResult,
+  SymbolChange,
+  SymbolChangeType,
+  SymbolLineRange,
+  FileSymbolDiff,
+  DiffScopeQuery,
+  DiffScopeResult,
+  FileDiffScope,
+  ScopedSymbol,
+  SymbolSnapshotQuery,
+  SymbolSnapshotResult,
+  WhatChangedQuery,
+  WhatChangedResult,
+  SnapshotSymbolChange,
+  RecordSnapshotQuery,
+  RecordSnapshotResult,
+  SnapshotHistoryQuery,
+  SnapshotHistoryEntry,
+  SnapshotHistoryResult,
+} from "./symbol-diff-types.js";
+export {
+  SymbolDiffServiceImpl,
+  DEFAULT_SNAPSHOT_HISTORY_RETENTION,
+} from "./symbol-diff-service.js";
+export type { SymbolDiffServiceConfig } from "./symbol-diff-service.js";
+export {
+  SymbolDiffQuerySchema,
+  DiffScopeQuerySchema,
+  SymbolSnapshotQuerySchema,
+  WhatChangedQuerySchema,
+  RecordSnapshotQuerySchema,
+  SnapshotHistoryQuerySchema,
+} from "./symbol-diff-validation.js";
+export type {
+  ValidatedSymbolDiffQuery,
+  ValidatedDiffScopeQuery,
+  ValidatedSymbolSnapshotQuery,
+  ValidatedWhatChangedQuery,
+  ValidatedRecordSnapshotQuery,
+  ValidatedSnapshotHistoryQuery,
+} from "./symbol-diff-validation.js";
+export type {
+  SymbolSnapshot,
+  SymbolSnapshotStore,
+  SymbolHistory,
+  SymbolHistoryEntry,
+} from "./symbol-snapshot-store.js";
+export { SymbolSnapshotStoreImpl, SYMBOL_SNAPSHOT_VERSION } from "./symbol-snapshot-store.js";
+
 // Graph Metrics exports
 export type {
   GraphQueryType,
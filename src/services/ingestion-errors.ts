@@ -95,6 +95,30 @@ export class IndexingInProgressError extends IngestionError {
   }
 }
 
+/**
+ * Error thrown when an indexing run is stopped through its abort signal
+ *
+ * Raised at the next safe point after the signal fires: between files while
+ * chunking, between embedding requests, or before the graph step. Work
+ * already stored stays in the collection and repository metadata is not
+ * updated, so the index should be rebuilt with another (re)index. When a
+ * checkpoint store is configured, that run resumes from the saved progress.
+ *
+ * Incremental updates raise it too, before each changed file or embedding
+ * batch; the last indexed commit is not advanced, so the next update
+ * re-applies the same changes.
+ */
+export class IndexingCancelledError extends IngestionError {
+  override name = "IndexingCancelledError";
+
+  constructor(repository: string) {
+    super(
+      `Indexing of repository '${repository}' was cancelled`,
+      true // Retryable - the run can simply be started again
+    );
+  }
+}
+
 /**
  * Error thrown when repository cloning fails
  */
@@ -13,6 +13,8 @@ import { stat, readdir } from "node:fs/promises";
 import { isLocalPath, canonicalizePathForComparison } from "../utils/path-utils.js";
 import simpleGit from "simple-git";
 import { GitignoreFilter } from "../ingestion/gitignore-filter.js";
+import { readSourceText } from "../ingestion/file-source.js";
+import { UndecodableFileError } from "../ingestion/errors.js";
 import {
   shouldDescendDir,
   shouldIncludeFile,
@@ -26,6 +28,11 @@ import {
   type FileManifestEntry,
   FILE_MANIFEST_VERSION,
 } from "./file-manifest-store.js";
+import {
+  INDEX_CHECKPOINT_VERSION,
+  type IndexCheckpoint,
+  type IndexCheckpointStore,
+} from "./index-checkpoint-store.js";
 import type { Logger } from "pino";
 import type { RepositoryCloner } from "../ingestion/repository-cloner.js";
 import type { CloneResult, FileInfo, FileChunk } from "../ingestion/types.js";
@@ -40,6 +47,10 @@ import type {
 import type { RepositoryMetadataService, RepositoryInfo } from "../repositories/types.js";
 import { getComponentLogger } from "../logging/index.js";
 import { DEFAULT_EXTENSIONS } from "../ingestion/default-extensions.js";
+import {
+  prepareEmbeddingInput,
+  resolveEmbeddingInputTokens,
+} from "../ingestion/embedding-input.js";
 import type {
   IndexOptions,
   IndexProgress,
@@ -53,6 +64,7 @@ import {
   IngestionError,
   RepositoryAlreadyExistsError,
   IndexingInProgressError,
+  IndexingCancelledError,
   CollectionCreationError,
   LocalFolderPublicTierRefusedError,
   LocalFolderPathAlreadyRegisteredError,
@@ -64,6 +76,12 @@ import type { ExtractionResult } from "../documents/types.js";
 import type { GraphIngestionService } from "../graph/ingestion/GraphIngestionService.js";
 import type { DocExtractionResult } from "../graph/extraction/doc-types.js";
 import type { FileInput } from "../graph/ingestion/types.js";
+import {
+  detectBuildOrMacroPaths,
+  resolveCrateRoots,
+  type CargoWorkspaceSetting,
+  type CrateRoot,
+} from "../graph/ingestion/crate-roots.js";
 import { DocGraphBatcher } from "../graph/extraction/doc-graph-batch.js";
 
 /**
@@ -119,6 +137,11 @@ export class IngestionService {
    */
   private readonly EMBEDDING_TIMEOUT_MS = 120000;
 
+  /**
+   * Default number of file batches between checkpoint saves
+   */
+  private readonly DEFAULT_CHECKPOINT_INTERVAL = 5;
+
   /**
    * Optional document chunker for document-aware chunking.
    * When provided alongside documentTypeDetector, document files
@@ -141,6 +164,20 @@ export class IngestionService {
    */
   private readonly graphIngestionService?: GraphIngestionService;
 
+  /**
+   * Optional store for index build checkpoints. When configured, progress is
+   * saved during `indexRepository` so an interrupted build can resume
+   * without re-embedding completed files.
+   */
+  private readonly checkpointStore?: IndexCheckpointStore;
+
+  /**
+   * How the crates of a Cargo workspace are found when populating the code
+   * graph. When unset, Rust paths are resolved as if each repository were a
+   * single crate.
+   */
+  private readonly cargoWorkspace?: CargoWorkspaceSetting;
+
   /** Lazily created on first use during `processFileBatch`. */
   private readonly docGraphBatcher = new DocGraphBatcher();
 
@@ -155,11 +192,15 @@ export class IngestionService {
       documentChunker?: DocumentChunker;
       documentTypeDetector?: DocumentTypeDetector;
       graphIngestionService?: GraphIngestionService;
+      checkpointStore?: IndexCheckpointStore;
+      cargoWorkspace?: CargoWorkspaceSetting;
     }
   ) {
     this.documentChunker = options?.documentChunker;
     this.documentTypeDetector = options?.documentTypeDetector;
     this.graphIngestionService = options?.graphIngestionService;
+    this.checkpointStore = options?.checkpointStore;
+    this.cargoWorkspace = options?.cargoWorkspace;
   }
 
   /**
@@ -182,12 +223,18 @@ export class IngestionService {
    * 4. Process files in batches (chunk, embed, store)
    * 5. Update repository metadata
    *
+   * When a checkpoint store is configured, completed files are checkpointed
+   * every `options.checkpointInterval` batches and when the run is cancelled
+   * or fails. A later run of the same build resumes from the checkpoint
+   * unless `options.resume` is false.
+   *
    * @param url - Git repository URL (e.g., https://github.com/user/repo.git)
    * @param options - Indexing options (branch, extensions, progress callback, force)
    * @returns IndexResult with status, stats, and any errors
    *
    * @throws {RepositoryAlreadyExistsError} If repository exists and force is false
    * @throws {IndexingInProgressError} If another indexing operation is in progress
+   * @throws {IndexingCancelledError} If `options.signal` aborts before the run completes
    *
    * @example
    * ```typescript
@@ -204,10 +251,15 @@ export class IngestionService {
   async indexRepository(url: string, options: IndexOptions = {}): Promise<IndexResult> {
     const startTime = performance.now();
     const errors: IndexError[] = [];
-    const stats = {
+    const stats: IndexResult["stats"] & {
+      filesSkipped: number;
+      filesWithEncodingWarnings: number;
+    } = {
       filesScanned: 0,
       filesProcessed: 0,
       filesFailed: 0,
+      filesSkipped: 0,
+      filesWithEncodingWarnings: 0,
       chunksCreated: 0,
       embeddingsGenerated: 0,
       documentsStored: 0,
@@ -218,6 +270,7 @@ export class IngestionService {
     let collectionName = "";
     let clonePath: string | null = null; // Track cloned directory for cleanup
     let indexingSucceeded = false; // Track if indexing completed successfully
+    let checkpoint: IndexCheckpoint | null = null; // Saved if the run is interrupted
 
     try {
       // Pre-flight checks
@@ -394,12 +447,16 @@ export class IngestionService {
       const fileInfos = await this.fileScanner.scanFiles(cloneResult.path, {
         includeExtensions: options.includeExtensions,
         excludePatterns: options.excludePatterns,
+        respectGitignore: options.respectGitignore,
         // Walk every nested .gitignore for local sources — users routinely have
         // them in monorepos and vendored docs. Git-remote shallow clones already
         // exclude ignored files at clone time, so the cheap root-only path is
         // preserved there.
         respectNestedGitignore:
           effectiveSource === "local-folder" || effectiveSource === "local-git",
+        onSkip: () => {
+          stats.filesSkipped++;
+        },
         onProgress: (scanned) => {
           this.updateProgress(
             {
@@ -421,8 +478,22 @@ export class IngestionService {
         fileCount: fileInfos.length,
       });
 
+      // Resume detection: a checkpoint left by an interrupted build of the same
+      // source lets this run skip the files that build already stored. A
+      // checkpoint that cannot be resumed means the collection holds a partial
+      // build, so it is cleared as for a forced reindex.
+      const checkpointState = await this.prepareCheckpoint(
+        repositoryName,
+        url,
+        cloneResult,
+        fileInfos,
+        options
+      );
+      checkpoint = checkpointState?.checkpoint ?? null;
+      const resuming = checkpointState?.resumed ?? false;
+
       // Phase 3: Create collection (delete if reindexing)
-      if (options.force) {
+      if ((options.force || checkpointState?.discarded) && !resuming) {
         try {
           await this.storageClient.deleteCollection(collectionName);
           this.logger.info("Deleted existing collection for reindexing", {
@@ -450,12 +521,22 @@ export class IngestionService {
       this.logger.info("ChromaDB collection ready", { collectionName });
 
       // Phase 4: Batch process files
-      const fileBatches = this.createBatches(fileInfos, this.FILE_BATCH_SIZE);
+      const pendingFileInfos =
+        resuming && checkpoint
+          ? fileInfos.filter((info) => checkpoint?.files[info.relativePath] === undefined)
+          : fileInfos;
+      const fileBatches = this.createBatches(pendingFileInfos, this.FILE_BATCH_SIZE);
       const totalBatches = fileBatches.length;
+      const checkpointInterval = Math.max(
+        1,
+        Math.floor(options.checkpointInterval ?? this.DEFAULT_CHECKPOINT_INTERVAL)
+      );
+      const fileInfoByPath = new Map(fileInfos.map((info) => [info.relativePath, info]));
 
       this.logger.info("Starting batch processing", {
         repository: repositoryName,
-        totalFiles: fileInfos.length,
+        totalFiles: pendingFileInfos.length,
+        filesResumed: fileInfos.length - pendingFileInfos.length,
         totalBatches,
         batchSize: this.FILE_BATCH_SIZE,
       });
@@ -473,6 +554,36 @@ export class IngestionService {
       const codeFilesForGraph: FileInput[] = [];
       const docExtractionResults: DocExtractionResult[] = [];
 
+      // Files completed by the interrupted build count toward this run's
+      // results; only their graph inputs are rebuilt, since the graph step
+      // runs once after all batches.
+      if (resuming && checkpoint) {
+        stats.filesProcessed += checkpoint.stats.filesProcessed;
+        stats.chunksCreated += checkpoint.stats.chunksCreated;
+        stats.embeddingsGenerated += checkpoint.stats.embeddingsGenerated;
+        stats.documentsStored += checkpoint.stats.documentsStored;
+        stats.filesResumed = fileInfos.length - pendingFileInfos.length;
+        processedRelativePaths.push(...Object.keys(checkpoint.files));
+
+        if (this.graphIngestionService) {
+          const resumedFileInfos = fileInfos.filter(
+            (info) => checkpoint?.files[info.relativePath] !== undefined
+          );
+          await this.collectResumedGraphInputs(
+            resumedFileInfos,
+            repositoryName,
+            codeFilesForGraph,
+            docExtractionResults
+          );
+        }
+
+        this.logger.info("Resuming interrupted index build", {
+          repository: repositoryName,
+          filesResumed: stats.filesResumed,
+          filesRemaining: pendingFileInfos.length,
+        });
+      }
+
       for (let batchIndex = 0; batchIndex < totalBatches; batchIndex++) {
         const batch = fileBatches[batchIndex];
         if (!batch) continue; // Skip if batch is undefined (shouldn't happen)
@@ -486,6 +597,8 @@ export class IngestionService {
             {
               batchIndex,
               totalBatches,
+              signal: options.signal,
+              upsert: resuming,
               onProgress: (phase, details) => {
                 const basePercentage = 25 + (batchIndex / totalBatches) * 70;
                 this.updateProgress(
@@ -493,8 +606,12 @@ export class IngestionService {
                     phase,
                     repository: repositoryName,
                     percentage: Math.round(basePercentage),
+                    // File counts are reported repository-wide rather than
+                    // per batch so callers can render files processed / total.
                     details: {
                       ...details,
+                      filesProcessed: stats.filesProcessed + stats.filesFailed,
+                      totalFiles: fileInfos.length,
                       currentBatch: batchIndex + 1,
                       totalBatches,
                     },
@@ -509,6 +626,8 @@ export class IngestionService {
           // Accumulate stats
           stats.filesProcessed += batchResult.filesProcessed;
           stats.filesFailed += batchResult.filesFailed;
+          stats.filesSkipped += batchResult.filesSkipped;
+          stats.filesWithEncodingWarnings += batchResult.filesWithEncodingWarnings;
           stats.chunksCreated += batchResult.chunksCreated;
           stats.embeddingsGenerated += batchResult.embeddingsGenerated;
           stats.documentsStored += batchResult.documentsStored;
@@ -516,7 +635,29 @@ export class IngestionService {
           processedRelativePaths.push(...batchResult.processedRelativePaths);
           codeFilesForGraph.push(...batchResult.codeFilesForGraph);
           docExtractionResults.push(...batchResult.docExtractionResults);
+
+          if (checkpoint) {
+            for (const relativePath of batchResult.processedRelativePaths) {
+              const info = fileInfoByPath.get(relativePath);
+              if (info) {
+                checkpoint.files[relativePath] = {
+                  sizeBytes: info.sizeBytes,
+                  mtimeMs: info.modifiedAt.getTime(),
+                };
+              }
+            }
+            checkpoint.stats.filesProcessed += batchResult.processedRelativePaths.length;
+            checkpoint.stats.chunksCreated += batchResult.chunksCreated;
+            checkpoint.stats.embeddingsGenerated += batchResult.embeddingsGenerated;
+            checkpoint.stats.documentsStored += batchResult.documentsStored;
+            if ((batchIndex + 1) % checkpointInterval === 0) {
+              await this.saveCheckpoint(checkpoint);
+            }
+          }
         } catch (batchError) {
+          if (batchError instanceof IndexingCancelledError) {
+            throw batchError;
+          }
           // Log batch error but continue with next batch
           this.logger.error(`Batch ${batchIndex + 1}/${totalBatches} failed, continuing...`, {
             error: batchError,
@@ -548,10 +689,12 @@ export class IngestionService {
       // `IndexError`s on the result, mirroring the per-file resilience used
       // throughout the rest of the indexing pipeline so that ChromaDB stays
       // populated even if FalkorDB is unhealthy.
+      this.throwIfCancelled(repositoryName, options.signal);
       if (this.graphIngestionService) {
-        await this.runGraphIngestion(
+        stats.symbolsIndexed = await this.runGraphIngestion(
           repositoryName,
           url,
+          cloneResult.path,
           codeFilesForGraph,
           docExtractionResults,
           options,
@@ -621,6 +764,12 @@ export class IngestionService {
         repository: repositoryName,
       });
 
+      // The build is complete, so there is nothing left to resume
+      if (checkpoint) {
+        await this.deleteCheckpoint(repositoryName);
+        checkpoint = null;
+      }
+
       // Determine final status
       const status: "success" | "partial" | "failed" =
         errors.length === 0 ? "success" : stats.filesProcessed > 0 ? "partial" : "failed";
@@ -657,6 +806,11 @@ export class IngestionService {
         durationMs,
       });
 
+      // Keep the progress made so far so the next run can resume from it
+      if (checkpoint && Object.keys(checkpoint.files).length > 0) {
+        await this.saveCheckpoint(checkpoint);
+      }
+
       // Record fatal error
       const fatalError: IndexError = {
         type: "fatal_error",
@@ -672,6 +826,7 @@ export class IngestionService {
       if (
         error instanceof RepositoryAlreadyExistsError ||
         error instanceof IndexingInProgressError ||
+        error instanceof IndexingCancelledError ||
         error instanceof LocalFolderPathAlreadyRegisteredError
       ) {
         throw error;
@@ -777,6 +932,12 @@ export class IngestionService {
         });
       }
 
+      // A leftover checkpoint would make a later registration of the same
+      // name skip files that are no longer in the (deleted) collection.
+      if (this.checkpointStore) {
+        await this.deleteCheckpoint(name);
+      }
+
       // Remove metadata
       await this.repositoryService.removeRepository(name);
 
@@ -826,8 +987,9 @@ export class IngestionService {
    * @param _repoPath - Local repository path (unused, kept for future use)
    * @param repositoryName - Repository name
    * @param collectionName - ChromaDB collection name
-   * @param context - Batch context (index, total, progress callback)
+   * @param context - Batch context (index, total, abort signal, upsert flag, progress callback)
    * @returns BatchResult with stats and errors
+   * @throws {IndexingCancelledError} If the signal aborts between files or embedding requests
    */
   private async processFileBatch(
     files: FileInfo[],
@@ -837,6 +999,9 @@ export class IngestionService {
     context: {
       batchIndex: number;
       totalBatches: number;
+      signal?: AbortSignal;
+      /** Upsert rather than add, so chunks stored by an interrupted build are overwritten */
+      upsert?: boolean;
       onProgress: (
         phase: "chunking" | "embedding" | "storing",
         details: Record<string, number>
@@ -846,6 +1011,8 @@ export class IngestionService {
     const result: BatchResult = {
       filesProcessed: 0,
       filesFailed: 0,
+      filesSkipped: 0,
+      filesWithEncodingWarnings: 0,
       chunksCreated: 0,
       embeddingsGenerated: 0,
       documentsStored: 0,
@@ -871,6 +1038,7 @@ export class IngestionService {
     });
 
     for (const fileInfo of files) {
+      this.throwIfCancelled(repositoryName, context.signal);
       try {
         // Check if file is a document type and we have document processing capabilities
         if (this.isDocumentFile(fileInfo)) {
@@ -888,7 +1056,14 @@ export class IngestionService {
           }
         } else {
           // Existing code path: read as text → FileChunker
-          const content = await Bun.file(fileInfo.absolutePath).text();
+          const { text: content, encodingWarning } = await readSourceText(fileInfo);
+          if (encodingWarning) {
+            this.logger.warn("File is not valid UTF-8, indexing a lossy decode", {
+              file: fileInfo.relativePath,
+              detail: encodingWarning.detail,
+            });
+            result.filesWithEncodingWarnings++;
+          }
           const chunks = this.fileChunker.chunkFile(content, fileInfo, repositoryName);
           allChunks.push(...this.convertFileChunksToInternal(chunks));
           result.filesProcessed++;
@@ -901,10 +1076,19 @@ export class IngestionService {
             result.codeFilesForGraph.push({
               path: fileInfo.relativePath,
               content,
+              encodingWarning: encodingWarning?.detail,
             });
           }
         }
       } catch (error) {
+        if (error instanceof UndecodableFileError) {
+          this.logger.warn("Skipping file that could not be decoded as text", {
+            file: fileInfo.relativePath,
+            reason: error.message,
+          });
+          result.filesSkipped++;
+          continue;
+        }
         // Individual file error - log and continue
         this.logger.warn("Failed to chunk file", {
           file: fileInfo.relativePath,
@@ -936,14 +1120,33 @@ export class IngestionService {
     // Phase 2: Generate embeddings (in batches of EMBEDDING_BATCH_SIZE)
     context.onProgress("embedding", { chunksCreated: allChunks.length });
 
+    // Oversized chunks are embedded from a truncated prefix; the full content
+    // is still stored below
+    const maxInputTokens = resolveEmbeddingInputTokens(
+      this.embeddingProvider.getCapabilities().maxTokensPerText
+    );
+    const inputs = allChunks.map((c) => prepareEmbeddingInput(c.content, maxInputTokens));
+    const truncatedCount = inputs.filter((input) => input.truncated).length;
+    if (truncatedCount > 0) {
+      this.logger.warn(
+        {
+          batchIndex: context.batchIndex,
+          truncatedCount,
+          limit: maxInputTokens,
+        },
+        "Truncated oversized chunks to the embedding input limit"
+      );
+    }
+
     const embeddingBatches = this.createBatches(
-      allChunks.map((c) => c.content),
+      inputs.map((input) => input.text),
       this.EMBEDDING_BATCH_SIZE
     );
 
     const allEmbeddings: number[][] = [];
 
     for (const embeddingBatch of embeddingBatches) {
+      this.throwIfCancelled(repositoryName, context.signal);
       const embeddings = await this.withTimeout(
         this.embeddingProvider.generateEmbeddings(embeddingBatch),
         this.EMBEDDING_TIMEOUT_MS,
@@ -1004,7 +1207,11 @@ export class IngestionService {
       };
     });
 
-    await this.storageClient.addDocuments(collectionName, documents);
+    if (context.upsert) {
+      await this.storageClient.upsertDocuments(collectionName, documents);
+    } else {
+      await this.storageClient.addDocuments(collectionName, documents);
+    }
     result.documentsStored = documents.length;
 
     // Storage succeeded — every file whose chunks landed in `allChunks` is
@@ -1112,6 +1319,58 @@ export class IngestionService {
     return { chunks, docExtraction };
   }
 
+  /**
+   * Find the Cargo workspace crates of a repository, when workspace mode is
+   * configured.
+   *
+   * A failed search is logged and the repository is ingested as a single
+   * crate rather than failing the graph step.
+   *
+   * @returns Crate roots, or undefined when workspace mode is off or the search failed
+   */
+  private async resolveWorkspaceCrates(
+    repository: string,
+    repositoryPath: string
+  ): Promise<CrateRoot[] | undefined> {
+    if (this.cargoWorkspace === undefined) return undefined;
+    try {
+      const crateRoots = await resolveCrateRoots(this.cargoWorkspace, repositoryPath);
+      this.logger.debug({ repository, crateCount: crateRoots.length }, "Resolved workspace crates");
+      return crateRoots;
+    } catch (error) {
+      this.logger.warn(
+        { repository, error: error instanceof Error ? error.message : String(error) },
+        "Failed to find Cargo workspace crates - ingesting as a single crate"
+      );
+      return undefined;
+    }
+  }
+
+  /**
+   * Find the build scripts and proc-macro crates of a Rust repository, so
+   * their files can be told apart from application code.
+   *
+   * A failed search is logged and only the path-based `build.rs` rule applies.
+   *
+   * @returns Build-time code paths, or undefined when there is no Rust code or the search failed
+   */
+  private async resolveBuildOrMacroPaths(
+    repository: string,
+    repositoryPath: string,
+    codeFiles: readonly FileInput[]
+  ): Promise<string[] | undefined> {
+    if (!codeFiles.some((file) => file.path.endsWith(".rs"))) return undefined;
+    try {
+      return await detectBuildOrMacroPaths(repositoryPath);
+    } catch (error) {
+      this.logger.warn(
+        { repository, error: error instanceof Error ? error.message : String(error) },
+        "Failed to find build scripts and proc-macro crates"
+      );
+      return undefined;
+    }
+  }
+
   /**
    * Populate the knowledge graph for a repository after the chunk → embed →
    * store pipeline has completed (issue #580).
@@ -1127,33 +1386,47 @@ export class IngestionService {
    * @param url - Repository URL or local path; required by `ingestFiles` for
    *              the Repository node. Empty strings are tolerated by the
    *              graph layer for local-folder sources.
+   * @param repositoryPath - Local checkout, searched for Cargo workspace crates.
    * @param codeFiles - Code-file `FileInput`s captured during chunking.
    * @param docResults - Per-doc-file `DocExtractionResult`s captured during
    *                     chunking.
    * @param options - Indexing options (used to forward the progress callback
    *                  through to the graph progress events).
    * @param errors - Indexing error accumulator; mutated on graph failure.
+   * @returns Number of function and class nodes written to the graph
    */
   private async runGraphIngestion(
     repository: string,
     url: string,
+    repositoryPath: string,
     codeFiles: readonly FileInput[],
     docResults: readonly DocExtractionResult[],
     options: IndexOptions,
     errors: IndexError[]
-  ): Promise<void> {
-    if (!this.graphIngestionService) return;
+  ): Promise<number> {
+    if (!this.graphIngestionService) return 0;
+    let symbolsIndexed = 0;
 
     if (codeFiles.length > 0) {
       try {
+        const crateRoots = await this.resolveWorkspaceCrates(repository, repositoryPath);
+        const buildOrMacroPaths = await this.resolveBuildOrMacroPaths(
+          repository,
+          repositoryPath,
+          codeFiles
+        );
         const ingestResult = await this.graphIngestionService.ingestFiles([...codeFiles], {
           repository,
           repositoryUrl: url,
           force: options.force ?? false,
+          ...(crateRoots && { crateRoots }),
+          ...(buildOrMacroPaths && { buildOrMacroPaths }),
         });
         // L6: a returned (not thrown) "failed" status must surface as an
         // IndexError too — otherwise an entirely-failed graph ingest would
         // produce a `success` IndexResult.
+        const nodesByType = ingestResult.stats.nodesByType;
+        symbolsIndexed = (nodesByType?.function ?? 0) + (nodesByType?.class ?? 0);
         if (ingestResult.status === "failed") {
           errors.push({
             type: "batch_error",
@@ -1209,6 +1482,8 @@ export class IngestionService {
         });
       }
     }
+
+    return symbolsIndexed;
   }
 
   /**
@@ -1381,6 +1656,18 @@ export class IngestionService {
     }
   }
 
+  /**
+   * Stop an indexing run at a safe point once its abort signal has fired
+   *
+   * @throws {IndexingCancelledError} If the signal is aborted
+   */
+  private throwIfCancelled(repositoryName: string, signal: AbortSignal | undefined): void {
+    if (signal?.aborted) {
+      this.logger.info("Indexing cancelled", { repository: repositoryName });
+      throw new IndexingCancelledError(repositoryName);
+    }
+  }
+
   /**
    * Create batches from an array
    *
@@ -1447,6 +1734,7 @@ export class IngestionService {
         ? params.options.includeExtensions
         : [...DEFAULT_EXTENSIONS],
       excludePatterns: params.options.excludePatterns || [],
+      ...(params.options.respectGitignore === false && { respectGitignore: false }),
       // Embedding provider metadata
       embeddingProvider: this.embeddingProvider.providerId,
       embeddingModel: this.embeddingProvider.modelId,
@@ -1524,7 +1812,10 @@ export class IngestionService {
   ): Promise<{ fileCount: number; totalBytes: number; softWarn: boolean }> {
     const { softFileLimit, softByteLimit, hardFileLimit, hardByteLimit } = thresholds;
 
-    const filter = await GitignoreFilter.load(rootPath);
+    const filter =
+      options.respectGitignore === false
+        ? GitignoreFilter.empty(rootPath)
+        : await GitignoreFilter.load(rootPath);
     const extensions: Set<string> =
       options.includeExtensions && options.includeExtensions.length > 0
         ? new Set(options.includeExtensions.map((e) => e.toLowerCase()))
@@ -1603,6 +1894,178 @@ export class IngestionService {
     return { fileCount, totalBytes, softWarn };
   }
 
+  /**
+   * Load the checkpoint for a build and decide whether it can be resumed.
+   *
+   * A checkpoint is resumable when `options.resume` is not false, it was
+   * written for the same URL, branch, commit and embedding model, and every
+   * file it lists was scanned again unchanged. A checkpoint that is not
+   * resumed is deleted and reported as `discarded`.
+   *
+   * @returns The checkpoint to update during this run (the loaded one when
+   *          resuming, otherwise a fresh one), or null when no checkpoint
+   *          store is configured
+   */
+  private async prepareCheckpoint(
+    repositoryName: string,
+    url: string,
+    cloneResult: CloneResult,
+    fileInfos: FileInfo[],
+    options: IndexOptions
+  ): Promise<{ checkpoint: IndexCheckpoint; resumed: boolean; discarded: boolean } | null> {
+    if (!this.checkpointStore) {
+      return null;
+    }
+
+    const now = new Date().toISOString();
+    const fresh: IndexCheckpoint = {
+      version: INDEX_CHECKPOINT_VERSION,
+      repository: repositoryName,
+      url,
+      branch: cloneResult.branch,
+      commitSha: cloneResult.commitSha,
+      embeddingModel: `${this.embeddingProvider.providerId}:${this.embeddingProvider.modelId}`,
+      startedAt: now,
+      updatedAt: now,
+      files: {},
+      stats: { filesProcessed: 0, chunksCreated: 0, embeddingsGenerated: 0, documentsStored: 0 },
+    };
+
+    let existing: IndexCheckpoint | null = null;
+    try {
+      existing = await this.checkpointStore.loadCheckpoint(repositoryName);
+    } catch (err) {
+      this.logger.warn("Checkpoint load failed (starting over)", {
+        repository: repositoryName,
+        error: err,
+      });
+    }
+
+    if (!existing) {
+      return { checkpoint: fresh, resumed: false, discarded: false };
+    }
+
+    if (options.resume !== false && this.isCheckpointResumable(existing, fresh, fileInfos)) {
+      return { checkpoint: existing, resumed: true, discarded: false };
+    }
+
+    this.logger.info("Discarding checkpoint of an earlier build", {
+      repository: repositoryName,
+      reason: options.resume === false ? "resume disabled" : "source or files changed",
+    });
+    await this.deleteCheckpoint(repositoryName);
+    return { checkpoint: fresh, resumed: false, discarded: true };
+  }
+
+  /**
+   * Check that a checkpoint belongs to the build about to run.
+   *
+   * File sizes must match. Modification times are compared only when there is
+   * no commit to pin the content, since a fresh clone rewrites every mtime.
+   */
+  private isCheckpointResumable(
+    checkpoint: IndexCheckpoint,
+    current: IndexCheckpoint,
+    fileInfos: FileInfo[]
+  ): boolean {
+    if (
+      checkpoint.url !== current.url ||
+      checkpoint.branch !== current.branch ||
+      checkpoint.commitSha !== current.commitSha ||
+      checkpoint.embeddingModel !== current.embeddingModel
+    ) {
+      return false;
+    }
+
+    const fileInfoByPath = new Map(fileInfos.map((info) => [info.relativePath, info]));
+    return Object.entries(checkpoint.files).every(([relativePath, entry]) => {
+      const info = fileInfoByPath.get(relativePath);
+      return (
+        info !== undefined &&
+        info.sizeBytes === entry.sizeBytes &&
+        (current.commitSha !== undefined || info.modifiedAt.getTime() === entry.mtimeMs)
+      );
+    });
+  }
+
+  /**
+   * Persist a checkpoint. Failures are logged rather than thrown — losing a
+   * checkpoint only costs the next run some repeated work.
+   */
+  private async saveCheckpoint(checkpoint: IndexCheckpoint): Promise<void> {
+    if (!this.checkpointStore) {
+      return;
+    }
+    checkpoint.updatedAt = new Date().toISOString();
+    try {
+      await this.checkpointStore.saveCheckpoint(checkpoint);
+      this.logger.debug("Checkpoint saved", {
+        repository: checkpoint.repository,
+        filesCompleted: Object.keys(checkpoint.files).length,
+      });
+    } catch (err) {
+      this.logger.warn("Checkpoint save failed (continuing)", {
+        repository: checkpoint.repository,
+        error: err,
+      });
+    }
+  }
+
+  /**
+   * Delete a repository's checkpoint, logging rather than throwing on failure.
+   */
+  private async deleteCheckpoint(repositoryName: string): Promise<void> {
+    try {
+      await this.checkpointStore?.deleteCheckpoint(repositoryName);
+    } catch (err) {
+      this.logger.warn("Checkpoint deletion failed (continuing)", {
+        repository: repositoryName,
+        error: err,
+      });
+    }
+  }
+
+  /**
+   * Rebuild the graph-step inputs for files completed by an interrupted build.
+   *
+   * Their chunks are already stored, so code files are only re-read and
+   * documents only re-extracted. Files that cannot be read are left out of
+   * the graph with a warning.
+   */
+  private async collectResumedGraphInputs(
+    files: FileInfo[],
+    repositoryName: string,
+    codeFiles: FileInput[],
+    docResults: DocExtractionResult[]
+  ): Promise<void> {
+    for (const fileInfo of files) {
+      try {
+        if (this.isDocumentFile(fileInfo)) {
+          const { docExtraction } = await this.processDocumentFile(
+            fileInfo.absolutePath,
+            fileInfo.relativePath,
+            repositoryName
+          );
+          if (docExtraction) {
+            docResults.push(docExtraction);
+          }
+        } else {
+          const { text, encodingWarning } = await readSourceText(fileInfo);
+          codeFiles.push({
+            path: fileInfo.relativePath,
+            content: text,
+            encodingWarning: encodingWarning?.detail,
+          });
+        }
+      } catch (error) {
+        this.logger.warn("Failed to read resumed file for graph ingestion", {
+          file: fileInfo.relativePath,
+          error,
+        });
+      }
+    }
+  }
+
   /**
    * Compute per-file fingerprints for a freshly-scanned local-folder repo and
    * persist a `FileManifest` so a future incremental update has a baseline to
@@ -37,6 +37,16 @@ export interface IndexOptions {
    */
   excludePatterns?: string[];
 
+  /**
+   * Whether files and directories ignored by the repository's `.gitignore`
+   * files are skipped. Combines with `excludePatterns`; set to `false` to
+   * rely on the exclude globs alone. Persisted on
+   * `RepositoryInfo.respectGitignore` so incremental updates agree.
+   *
+   * @default true
+   */
+  respectGitignore?: boolean;
+
   /**
    * Progress callback invoked at each pipeline phase
    * Receives progress updates throughout the indexing process
@@ -84,6 +94,37 @@ export interface IndexOptions {
    * @default false
    */
   followSymlinks?: boolean;
+
+  /**
+   * Cancels the run when aborted.
+   *
+   * Checked between files and embedding requests; a cancelled run throws
+   * `IndexingCancelledError` without updating repository metadata.
+   */
+  signal?: AbortSignal;
+
+  /**
+   * Whether to resume from the checkpoint of an interrupted build.
+   *
+   * Only applies when the service is configured with a checkpoint store.
+   * When true, a checkpoint for the same URL, branch, commit and embedding
+   * model is detected automatically and its completed files are skipped.
+   * When false, any checkpoint is discarded and the build starts over.
+   *
+   * @default true
+   */
+  resume?: boolean;
+
+  /**
+   * Number of file batches between checkpoint saves.
+   *
+   * Lower values lose less work when a build is interrupted; higher values
+   * write the checkpoint less often. A checkpoint is also saved when the run
+   * is cancelled or fails.
+   *
+   * @default 5
+   */
+  checkpointInterval?: number;
 }
 
 /**
@@ -213,6 +254,30 @@ export interface IndexResult {
      */
     filesFailed: number;
 
+    /**
+     * Number of files excluded by the scanner as oversized, minified or
+     * binary, or skipped while chunking because they could not be decoded
+     */
+    filesSkipped?: number;
+
+    /**
+     * Number of files indexed from a lossy decode because they were not
+     * valid UTF-8
+     */
+    filesWithEncodingWarnings?: number;
+
+    /**
+     * Number of files skipped because an interrupted build had already
+     * indexed them (included in `filesProcessed`)
+     */
+    filesResumed?: number;
+
+    /**
+     * Number of functions and classes written to the knowledge graph
+     * (only set when graph storage is configured)
+     */
+    symbolsIndexed?: number;
+
     /**
      * Total number of chunks created
      */
@@ -378,6 +443,17 @@ export interface BatchResult {
    */
   filesFailed: number;
 
+  /**
+   * Number of files skipped in this batch because they could not be decoded
+   */
+  filesSkipped: number;
+
+  /**
+   * Number of files in this batch decoded lossily because they were not
+   * valid UTF-8
+   */
+  filesWithEncodingWarnings: number;
+
   /**
    * Number of chunks created in this batch
    */
@@ -502,6 +502,7 @@ async function executeFullReindexRecovery(
     branch: repository.branch,
     force: true,
     tier: repository.tier,
+    respectGitignore: repository.respectGitignore,
   });
 
   const durationMs = Date.now() - startTime;
@@ -39,6 +39,8 @@ import {
   DEFAULT_MAX_FILE_SIZE_BYTES,
   type DirEntryLike,
 } from "../ingestion/file-eligibility.js";
+import { DEFAULT_MINIFIED_THRESHOLDS } from "../ingestion/minified-detector.js";
+import { DEFAULT_BINARY_DETECTION_BYTES } from "../ingestion/binary-detector.js";
 import { streamSha256 } from "../ingestion/sha256-stream.js";
 import {
   FileManifestStoreImpl,
@@ -71,6 +73,11 @@ export interface ChangeDetectionResult {
   changes: FileChange[];
   /** New per-file fingerprints, keyed by POSIX-relative path. */
   nextManifestFiles: Record<string, FileManifestEntry>;
+  /**
+   * True when the prior manifest was corrupt and discarded. `changes` then
+   * lists every file as added and omits files deleted since the last update.
+   */
+  manifestWasReset: boolean;
 }
 
 /**
@@ -98,8 +105,11 @@ export class LocalFolderChangeDetector {
    */
   async detect(repo: RepositoryInfo, opts: DetectOptions = {}): Promise<ChangeDetectionResult> {
     const startMs = Date.now();
-    const prior = await this.manifestStore.loadManifest(repo.name);
-    const filter = await GitignoreFilter.load(repo.localPath);
+    const { manifest: prior, wasReset } = await this.manifestStore.loadManifestOrReset(repo.name);
+    const filter =
+      repo.respectGitignore === false
+        ? GitignoreFilter.empty(repo.localPath)
+        : await GitignoreFilter.load(repo.localPath);
     const extensions: Set<string> = new Set(
       (repo.includeExtensions.length > 0 ? repo.includeExtensions : DEFAULT_EXTENSIONS).map((e) =>
         e.toLowerCase()
@@ -189,7 +199,7 @@ export class LocalFolderChangeDetector {
       "Local folder change detection complete"
     );
 
-    return { changes, nextManifestFiles };
+    return { changes, nextManifestFiles, manifestWasReset: wasReset };
   }
 
   /**
@@ -240,6 +250,8 @@ export class LocalFolderChangeDetector {
         gitignore: filter,
         extensions,
         maxSizeBytes: DEFAULT_MAX_FILE_SIZE_BYTES,
+        minifiedThresholds: DEFAULT_MINIFIED_THRESHOLDS,
+        binaryDetectionBytes: DEFAULT_BINARY_DETECTION_BYTES,
       });
       if (!verdict.eligible || !verdict.stats) continue;
 
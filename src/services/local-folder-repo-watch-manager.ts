@@ -58,7 +58,10 @@ export class LocalFolderWatchValidationError extends Error {
 export class LocalFolderRepoWatchManager implements LocalFolderWatchManager {
   private _logger: Logger | null = null;
 
-  constructor(private readonly folderWatcherService: FolderWatcherService) {}
+  constructor(
+    private readonly folderWatcherService: FolderWatcherService,
+    private readonly defaultDebounceMs: number = DEFAULT_LOCAL_FOLDER_DEBOUNCE_MS
+  ) {}
 
   private get logger(): Logger {
     if (!this._logger)
@@ -127,7 +130,7 @@ export class LocalFolderRepoWatchManager implements LocalFolderWatchManager {
       enabled: true,
       includePatterns: null, // local-folder repos use IngestionService's allowlist; watcher emits raw and routes to coordinator
       excludePatterns: null,
-      debounceMs: repo.watchDebounceMs ?? DEFAULT_LOCAL_FOLDER_DEBOUNCE_MS,
+      debounceMs: repo.watchDebounceMs ?? this.defaultDebounceMs,
       createdAt: new Date(),
       lastScanAt: null,
       fileCount: 0,
@@ -22,6 +22,10 @@
  *   - `updated`        → pipeline ran; manifest rewritten; metadata advanced.
  *   - `failed`         → pipeline rejected ALL files; manifest left untouched.
  *
+ * A corrupt manifest is discarded and the diff is rebuilt against the files
+ * indexed in the collection: indexed files still on disk are replaced, and
+ * indexed files gone from disk have their documents and graph data purged.
+ *
  * @module services/local-folder-update-coordinator
  */
 
@@ -34,13 +38,21 @@ import type {
   UpdateHistoryEntry,
 } from "../repositories/types.js";
 import type { IncrementalUpdatePipeline } from "./incremental-update-pipeline.js";
+import type { FileChange } from "./incremental-update-types.js";
 import { addHistoryEntry } from "../repositories/metadata-store.js";
-import type { CoordinatorResult } from "./incremental-update-coordinator-types.js";
+import type {
+  CoordinatorResult,
+  CoordinatorUpdateOptions,
+} from "./incremental-update-coordinator-types.js";
 import {
   RepositoryNotFoundError,
   ConcurrentUpdateError,
 } from "./incremental-update-coordinator-errors.js";
-import { FileManifestStoreImpl, FILE_MANIFEST_EMPTY_GENERATED_AT } from "./file-manifest-store.js";
+import {
+  FileManifestStoreImpl,
+  FILE_MANIFEST_EMPTY_GENERATED_AT,
+  diffManifestFiles,
+} from "./file-manifest-store.js";
 import { LocalFolderChangeDetector } from "./local-folder-change-detector.js";
 
 /** Configuration accepted by the coordinator constructor. */
@@ -137,9 +149,15 @@ export class LocalFolderUpdateCoordinator {
    * safe to call directly from tests and future call sites.
    *
    * @param repositoryName - Name from `RepositoryInfo.name`.
+   * @param options - Per-call options (cancellation signal).
    * @returns `CoordinatorResult` with the same shape the git coordinator emits.
+   * @throws {IndexingCancelledError} If `options.signal` aborts while changes are processed;
+   *   the manifest is left as it was.
    */
-  async updateRepository(repositoryName: string): Promise<CoordinatorResult> {
+  async updateRepository(
+    repositoryName: string,
+    options: CoordinatorUpdateOptions = {}
+  ): Promise<CoordinatorResult> {
     const startTime = Date.now();
     const logger = this.logger.child({ repository: repositoryName });
 
@@ -211,9 +229,20 @@ export class LocalFolderUpdateCoordinator {
       });
       inProgressFlagSet = true;
 
-      // Detect changes against the prior manifest.
-      const priorManifest = await this.manifestStore.loadManifest(repositoryName);
-      const { changes, nextManifestFiles } = await this.changeDetector.detect(repo);
+      // Detect changes against the prior manifest. A corrupt manifest is discarded,
+      // so the detected diff lists every file as added and misses deletions.
+      const priorLoad = await this.manifestStore.loadManifestOrReset(repositoryName);
+      const priorManifest = priorLoad.manifest;
+      const detection = await this.changeDetector.detect(repo);
+      const { nextManifestFiles } = detection;
+      let changes = detection.changes;
+      if (priorLoad.wasReset || detection.manifestWasReset) {
+        changes = await this.reconcileWithIndex(repo, changes);
+        logger.warn(
+          { changeCount: changes.length },
+          "Manifest was reset - diff rebuilt against the indexed files"
+        );
+      }
 
       if (changes.length === 0) {
         // No changes — clear in-progress and bail. We deliberately do NOT rewrite
@@ -250,6 +279,7 @@ export class LocalFolderUpdateCoordinator {
           model: repo.embeddingModel,
           dimensions: repo.embeddingDimensions,
         },
+        signal: options.signal,
       });
 
       const totalFilesProcessed =
@@ -288,11 +318,16 @@ export class LocalFolderUpdateCoordinator {
       // Rewrite the manifest ONLY if the pipeline didn't outright fail. Leaving
       // the prior manifest in place on full failure means the next update sees
       // the same diff and can retry, rather than silently advancing the
-      // baseline past unprocessed files.
+      // baseline past unprocessed files. Only the changed entries are
+      // written, as appends to the manifest's update log.
       const manifestRewritten = historyStatus !== "failed";
       const newManifest = this.changeDetector.buildNextManifest(repositoryName, nextManifestFiles);
       if (manifestRewritten) {
-        await this.manifestStore.saveManifest(repositoryName, newManifest);
+        await this.manifestStore.appendUpdates(
+          repositoryName,
+          diffManifestFiles(priorManifest.files, nextManifestFiles),
+          newManifest.generatedAt
+        );
       }
 
       // Synthetic SHA markers per Phase A's relaxed schema. The "previous"
@@ -320,6 +355,9 @@ export class LocalFolderUpdateCoordinator {
         ...(pipelineResult.stats.chunksSkipped !== undefined && {
           chunksSkipped: pipelineResult.stats.chunksSkipped,
         }),
+        ...(pipelineResult.stats.chunksTruncated !== undefined && {
+          chunksTruncated: pipelineResult.stats.chunksTruncated,
+        }),
         skippedFileCount: pipelineResult.filterStats.skippedChanges,
         eligibleFileCount: pipelineResult.filterStats.eligibleChanges,
         ...(pipelineResult.stats.graph && {
@@ -412,6 +450,35 @@ export class LocalFolderUpdateCoordinator {
     }
   }
 
+  /**
+   * Rebuild a diff detected against a discarded manifest.
+   *
+   * Every file on disk arrives as `added`. Those already indexed become
+   * `modified`, so their old chunks and graph data are removed before they
+   * are re-ingested; indexed files missing from the diff are no longer on
+   * disk and become `deleted`, which purges their documents and graph data.
+   */
+  private async reconcileWithIndex(
+    repo: RepositoryInfo,
+    changes: FileChange[]
+  ): Promise<FileChange[]> {
+    const indexed = await this.updatePipeline.listIndexedFilePaths(repo.collectionName, repo.name);
+    const onDisk = new Set(changes.map((change) => change.path));
+
+    const reconciled = changes.map(
+      (change): FileChange =>
+        change.status === "added" && indexed.has(change.path)
+          ? { ...change, status: "modified" }
+          : change
+    );
+    for (const path of indexed) {
+      if (!onDisk.has(path)) {
+        reconciled.push({ path, status: "deleted" });
+      }
+    }
+    return reconciled;
+  }
+
   private async localPathExistsAsDirectory(absPath: string): Promise<boolean> {
     try {
       const st = await stat(absPath);
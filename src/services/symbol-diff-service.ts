@@ -0,0 +1,1326 @@
+/**
+ * @module services/symbol-diff-service
+ *
+ * SymbolDiffService implementation for symbol-level diffs between git refs.
+ *
+ * For a pair of refs the service:
+ *
+ * 1. Resolves both refs to commits in the repository's local clone
+ * 2. Lists the changed files with `git diff --name-status -M`
+ * 3. Reads both versions of each changed file from git blobs (the working
+ *    tree is never checked out, so a running watcher is unaffected)
+ * 4. Extracts the symbols of each version and matches them by parent, name
+ *    and kind to find added, removed and modified symbols
+ *
+ * Unchanged files are never read or parsed. When the base ref is the commit
+ * the repository was last indexed at, base symbols are taken from the graph
+ * instead of being parsed again; only the head side is parsed.
+ *
+ * `scopeToDiff` reuses the same file list together with the `git diff -U0`
+ * hunks: it parses only the head version of each changed file and keeps the
+ * symbols whose spans overlap a changed line range.
+ *
+ * `createSnapshot` and `whatChanged` do not use git: they fingerprint the
+ * symbols currently in the graph (qualified path → signature hash), persist
+ * the fingerprint under a name, and later diff the graph against it.
+ * `recordSnapshot` appends the same fingerprint to a rolling history instead,
+ * storing only its change counts against the previous entry, and
+ * `getSnapshotHistory` reads the counts back as a trend.
+ */
+
+import simpleGit from "simple-git";
+import type { SimpleGit } from "simple-git";
+import { z } from "zod";
+import type { Logger } from "pino";
+import type { GraphStorageAdapter } from "../graph/adapters/types.js";
+import { EntityExtractor } from "../graph/extraction/EntityExtractor.js";
+import type { EntityType } from "../graph/parsing/types.js";
+import { getSymbolKind, type SymbolKind } from "../graph/parsing/symbol-kinds.js";
+import { getComponentLogger } from "../logging/index.js";
+import type { RepositoryMetadataService, RepositoryInfo } from "../repositories/types.js";
+import { RepositoryNotFoundError } from "./errors.js";
+import { GraphServiceValidationError, GraphServiceOperationError } from "./graph-service-errors.js";
+import type { FileChange } from "./incremental-update-types.js";
+import {
+  SymbolDiffQuerySchema,
+  DiffScopeQuerySchema,
+  SymbolSnapshotQuerySchema,
+  WhatChangedQuerySchema,
+  RecordSnapshotQuerySchema,
+  SnapshotHistoryQuerySchema,
+  type ValidatedSymbolDiffQuery,
+  type ValidatedDiffScopeQuery,
+  type ValidatedSymbolSnapshotQuery,
+  type ValidatedWhatChangedQuery,
+  type ValidatedRecordSnapshotQuery,
+  type ValidatedSnapshotHistoryQuery,
+} from "./symbol-diff-validation.js";
+import {
+  SymbolSnapshotStoreImpl,
+  SYMBOL_SNAPSHOT_VERSION,
+  type SymbolSnapshotStore,
+} from "./symbol-snapshot-store.js";
+import type {
+  SymbolDiffService,
+  SymbolDiffQuery,
+  SymbolDiffResult,
+  SymbolChange,
+  SymbolChangeType,
+  FileSymbolDiff,
+  DiffScopeQuery,
+  DiffScopeResult,
+  FileDiffScope,
+  ScopedSymbol,
+  SymbolLineRange,
+  SymbolSnapshotQuery,
+  SymbolSnapshotResult,
+  WhatChangedQuery,
+  WhatChangedResult,
+  SnapshotSymbolChange,
+  RecordSnapshotQuery,
+  RecordSnapshotResult,
+  SnapshotHistoryQuery,
+  SnapshotHistoryResult,
+} from "./symbol-diff-types.js";
+
+// =============================================================================
+// Configuration
+// =============================================================================
+
+/**
+ * Default number of entries kept in a repository's snapshot history
+ */
+export const DEFAULT_SNAPSHOT_HISTORY_RETENTION = 100;
+
+/**
+ * Configuration for SymbolDiffService
+ */
+export interface SymbolDiffServiceConfig {
+  /**
+   * Factory for git instances (defaults to simple-git)
+   *
+   * Injected by tests to avoid touching real repositories.
+   */
+  simpleGitFactory?: (path: string) => SimpleGit;
+
+  /**
+   * Store for named symbol snapshots
+   *
+   * Defaults to the `SymbolSnapshotStoreImpl` singleton.
+   */
+  snapshotStore?: SymbolSnapshotStore;
+
+  /**
+   * Maximum number of entries kept in a snapshot history; the oldest are
+   * dropped when a new entry would exceed it. Values that are not a positive
+   * integer fall back to the default.
+   *
+   * @default DEFAULT_SNAPSHOT_HISTORY_RETENTION
+   */
+  historyRetention?: number;
+}
+
+/**
+ * A symbol on one side of the diff
+ */
+interface DiffSymbol {
+  name: string;
+  kind: SymbolKind;
+  parentName?: string;
+  startLine: number;
+  endLine: number;
+}
+
+/**
+ * Changed regions of one file from a `git diff -U0` hunk list
+ */
+interface FileHunks {
+  /** Head-ref line ranges that were added or modified */
+  ranges: SymbolLineRange[];
+  /** Head-ref lines after which base lines were removed without replacement */
+  deletionPoints: number[];
+}
+
+/**
+ * Raw symbol row returned by the indexed symbols query
+ */
+interface IndexedSymbolRow {
+  name: string;
+  entityType: string | null;
+  kind: SymbolKind | null;
+  parentName: string | null;
+  startLine: number | null;
+  endLine: number | null;
+}
+
+/**
+ * Raw symbol row returned by the snapshot fingerprint query
+ */
+interface FingerprintRow {
+  filePath: string;
+  name: string;
+  entityType: string | null;
+  kind: SymbolKind | null;
+  parentName: string | null;
+  startLine: number | null;
+  endLine: number | null;
+  signature: string | null;
+  whereClause: string | null;
+  superclass: string | null;
+  interfaces: string[] | null;
+  typeParameters: string[] | null;
+  isUnsafe: boolean | null;
+}
+
+/**
+ * An indexed symbol with its snapshot fingerprint
+ */
+interface FingerprintedSymbol {
+  filePath: string;
+  name: string;
+  kind: SymbolKind;
+  startLine: number;
+  endLine: number;
+  hash: string;
+}
+
+// =============================================================================
+// SymbolDiffService Implementation
+// =============================================================================
+
+/**
+ * Implementation of SymbolDiffService using git blobs and the entity extractor
+ *
+ * @example
+ * ```typescript
+ * const diffService = new SymbolDiffServiceImpl(repositoryService, entityExtractor, graphAdapter);
+ *
+ * // Which symbols does the feature branch change relative to main?
+ * const result = await diffService.diffSymbols({
+ *   repository: "my-project",
+ *   base_ref: "main",
+ *   head_ref: "feature/auth",
+ * });
+ * ```
+ */
+export class SymbolDiffServiceImpl implements SymbolDiffService {
+  private _logger: Logger | null = null;
+  private readonly simpleGitFactory?: (path: string) => SimpleGit;
+  private readonly snapshotStore: SymbolSnapshotStore;
+  private readonly historyRetention: number;
+
+  /**
+   * Create a new SymbolDiffService instance
+   *
+   * @param repositoryService - Repository metadata for local clone paths and index state
+   * @param entityExtractor - Extractor used to parse both versions of changed files
+   * @param graphAdapter - Optional graph adapter for reusing indexed base symbols
+   * @param config - Optional configuration overrides
+   */
+  constructor(
+    private readonly repositoryService: RepositoryMetadataService,
+    private readonly entityExtractor: EntityExtractor,
+    private readonly graphAdapter?: GraphStorageAdapter,
+    config: SymbolDiffServiceConfig = {}
+  ) {
+    this.simpleGitFactory = config.simpleGitFactory;
+    this.snapshotStore = config.snapshotStore ?? SymbolSnapshotStoreImpl.getInstance();
+    const retention = config.historyRetention ?? DEFAULT_SNAPSHOT_HISTORY_RETENTION;
+    this.historyRetention =
+      Number.isInteger(retention) && retention > 0 ? retention : DEFAULT_SNAPSHOT_HISTORY_RETENTION;
+  }
+
+  /**
+   * Lazy-initialized logger
+   */
+  private get logger(): Logger {
+    if (!this._logger) {
+      this._logger = getComponentLogger("services:symbol-diff");
+    }
+    return this._logger;
+  }
+
+  /**
+   * Diff the symbols of the files changed between two git refs
+   */
+  async diffSymbols(query: SymbolDiffQuery): Promise<SymbolDiffResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(SymbolDiffQuerySchema, query, "symbol diff query");
+      const result = await this.executeSymbolDiff(validated);
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          base_sha: result.base_sha,
+          head_sha: result.head_sha,
+          files_analyzed: result.metadata.files_analyzed,
+          base_from_index: result.metadata.base_from_index,
+          query_time_ms: queryTimeMs,
+        },
+        "diffSymbols completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "diffSymbols", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * List the head-ref symbols whose spans overlap the lines changed since a ref
+   */
+  async scopeToDiff(query: DiffScopeQuery): Promise<DiffScopeResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(DiffScopeQuerySchema, query, "diff scope query");
+      const result = await this.executeDiffScope(validated);
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          base_sha: result.base_sha,
+          head_sha: result.head_sha,
+          files_analyzed: result.metadata.files_analyzed,
+          symbol_count: result.metadata.symbol_count,
+          query_time_ms: queryTimeMs,
+        },
+        "scopeToDiff completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "scopeToDiff", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Record a named snapshot of the repository's indexed symbols
+   */
+  async createSnapshot(query: SymbolSnapshotQuery): Promise<SymbolSnapshotResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(SymbolSnapshotQuerySchema, query, "snapshot query");
+      const result = await this.executeCreateSnapshot(validated);
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          name: validated.name,
+          symbol_count: result.symbol_count,
+          replaced: result.replaced,
+          query_time_ms: queryTimeMs,
+        },
+        "createSnapshot completed"
+      );
+
+      return { ...result, metadata: { query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "createSnapshot", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Compare the repository's current indexed symbols against a named snapshot
+   */
+  async whatChanged(query: WhatChangedQuery): Promise<WhatChangedResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(WhatChangedQuerySchema, query, "what changed query");
+      const result = await this.executeWhatChanged(validated);
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          name: validated.name,
+          ...result.summary,
+          query_time_ms: queryTimeMs,
+        },
+        "whatChanged completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "whatChanged", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Append the repository's current fingerprint to its snapshot history
+   */
+  async recordSnapshot(query: RecordSnapshotQuery): Promise<RecordSnapshotResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(RecordSnapshotQuerySchema, query, "record snapshot query");
+      const result = await this.executeRecordSnapshot(validated);
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          symbol_count: result.symbol_count,
+          ...result.changes,
+          history_length: result.history_length,
+          pruned: result.pruned,
+          query_time_ms: queryTimeMs,
+        },
+        "recordSnapshot completed"
+      );
+
+      return { ...result, metadata: { query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "recordSnapshot", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  /**
+   * Read the symbol-count and change-count trend of a repository's snapshot history
+   */
+  async getSnapshotHistory(query: SnapshotHistoryQuery): Promise<SnapshotHistoryResult> {
+    const startTime = performance.now();
+
+    try {
+      const validated = this.validate(SnapshotHistoryQuerySchema, query, "snapshot history query");
+      const result = await this.executeSnapshotHistory(validated);
+
+      const queryTimeMs = Math.round(performance.now() - startTime);
+      this.logger.info(
+        {
+          repository: validated.repository,
+          entry_count: result.entries.length,
+          total_entries: result.metadata.total_entries,
+          query_time_ms: queryTimeMs,
+        },
+        "getSnapshotHistory completed"
+      );
+
+      return { ...result, metadata: { ...result.metadata, query_time_ms: queryTimeMs } };
+    } catch (error) {
+      this.handleError(error, "getSnapshotHistory", performance.now() - startTime);
+      throw error;
+    }
+  }
+
+  // ===========================================================================
+  // Private: Diff Execution
+  // ===========================================================================
+
+  private async executeSymbolDiff(query: ValidatedSymbolDiffQuery): Promise<SymbolDiffResult> {
+    const { repo, git } = await this.openRepository(query.repository);
+    const baseSha = await this.resolveRef(git, query.base_ref);
+    const headSha = await this.resolveRef(git, query.head_ref);
+
+    const changedFiles = await this.getChangedFiles(git, baseSha, headSha);
+    const changes = this.filterByPath(changedFiles, query.path);
+    const reuseIndex = this.graphAdapter !== undefined && repo.lastIndexedCommitSha === baseSha;
+
+    const files: FileSymbolDiff[] = [];
+    let filesSkipped = 0;
+    let baseFromIndex = 0;
+
+    for (const change of changes) {
+      if (!EntityExtractor.isSupported(change.path)) {
+        filesSkipped++;
+        continue;
+      }
+
+      const basePath = change.previousPath ?? change.path;
+      const baseContent =
+        change.status === "added" ? null : await this.readBlob(git, baseSha, basePath);
+      const headContent =
+        change.status === "deleted" ? null : await this.readBlob(git, headSha, change.path);
+
+      let baseSymbols: DiffSymbol[] | null = [];
+      if (baseContent !== null) {
+        const indexed = reuseIndex ? await this.getIndexedSymbols(repo.name, basePath) : [];
+        if (indexed.length > 0) {
+          baseSymbols = indexed;
+          baseFromIndex++;
+        } else {
+          baseSymbols = await this.extractSymbols(baseContent, basePath);
+        }
+      }
+      const headSymbols =
+        headContent !== null ? await this.extractSymbols(headContent, change.path) : [];
+
+      if (baseSymbols === null || headSymbols === null) {
+        filesSkipped++;
+        continue;
+      }
+
+      const symbols = this.compareSymbols(
+        baseSymbols,
+        baseContent ?? "",
+        headSymbols,
+        headContent ?? ""
+      );
+      if (symbols.length > 0) {
+        files.push({
+          file_path: change.path,
+          ...(change.previousPath !== undefined && { previous_path: change.previousPath }),
+          status: change.status,
+          symbols,
+        });
+      }
+    }
+
+    files.sort((a, b) => a.file_path.localeCompare(b.file_path));
+
+    const summary: Record<SymbolChangeType, number> = { added: 0, removed: 0, modified: 0 };
+    for (const file of files) {
+      for (const symbol of file.symbols) {
+        summary[symbol.change]++;
+      }
+    }
+
+    return {
+      repository: query.repository,
+      base_ref: query.base_ref,
+      head_ref: query.head_ref,
+      base_sha: baseSha,
+      head_sha: headSha,
+      files,
+      summary,
+      metadata: {
+        files_changed: changes.length,
+        files_analyzed: changes.length - filesSkipped,
+        files_skipped: filesSkipped,
+        base_from_index: baseFromIndex,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  private async executeDiffScope(query: ValidatedDiffScopeQuery): Promise<DiffScopeResult> {
+    const { git } = await this.openRepository(query.repository);
+    const baseSha = await this.resolveRef(git, query.base_ref);
+    const headSha = await this.resolveRef(git, query.head_ref);
+
+    const changes = this.filterByPath(
+      await this.getChangedFiles(git, baseSha, headSha),
+      query.path
+    );
+    const hunks = await this.getChangedHunks(git, baseSha, headSha);
+    const nameFilter = query.query?.toLowerCase();
+
+    const files: FileDiffScope[] = [];
+    let filesSkipped = 0;
+
+    for (const change of changes) {
+      if (change.status === "deleted" || !EntityExtractor.isSupported(change.path)) {
+        filesSkipped++;
+        continue;
+      }
+
+      const headSymbols = await this.extractSymbols(
+        await this.readBlob(git, headSha, change.path),
+        change.path
+      );
+      if (headSymbols === null) {
+        filesSkipped++;
+        continue;
+      }
+
+      const fileHunks = hunks.get(change.path) ?? { ranges: [], deletionPoints: [] };
+      const symbols = this.scopeSymbols(headSymbols, fileHunks).filter(
+        (symbol) =>
+          (nameFilter === undefined || symbol.name.toLowerCase().includes(nameFilter)) &&
+          (query.kind === undefined || symbol.kind === query.kind)
+      );
+      if (symbols.length > 0) {
+        files.push({
+          file_path: change.path,
+          ...(change.previousPath !== undefined && { previous_path: change.previousPath }),
+          status: change.status,
+          changed_ranges: fileHunks.ranges,
+          symbols,
+        });
+      }
+    }
+
+    files.sort((a, b) => a.file_path.localeCompare(b.file_path));
+
+    return {
+      scope: "diff",
+      repository: query.repository,
+      base_ref: query.base_ref,
+      head_ref: query.head_ref,
+      base_sha: baseSha,
+      head_sha: headSha,
+      files,
+      metadata: {
+        files_changed: changes.length,
+        files_analyzed: changes.length - filesSkipped,
+        files_skipped: filesSkipped,
+        symbol_count: files.reduce((sum, file) => sum + file.symbols.length, 0),
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  /**
+   * Keep the symbols that overlap a changed range or enclose a deletion point
+   *
+   * A deletion after line N touches a symbol only when the symbol continues
+   * past N, so removing lines just after a function does not pull it in.
+   */
+  private scopeSymbols(symbols: DiffSymbol[], hunks: FileHunks): ScopedSymbol[] {
+    const scoped: ScopedSymbol[] = [];
+
+    for (const symbol of [...symbols].sort((a, b) => a.startLine - b.startLine)) {
+      let changedLines = 0;
+      for (const range of hunks.ranges) {
+        const overlap =
+          Math.min(range.end_line, symbol.endLine) - Math.max(range.start_line, symbol.startLine);
+        if (overlap >= 0) changedLines += overlap + 1;
+      }
+      const enclosesDeletion = hunks.deletionPoints.some(
+        (line) => line >= symbol.startLine && line < symbol.endLine
+      );
+
+      if (changedLines > 0 || enclosesDeletion) {
+        scoped.push({
+          name: symbol.name,
+          kind: symbol.kind,
+          ...(symbol.parentName !== undefined && { parent_name: symbol.parentName }),
+          start_line: symbol.startLine,
+          end_line: symbol.endLine,
+          changed_lines: changedLines,
+        });
+      }
+    }
+
+    return scoped;
+  }
+
+  /**
+   * Match base and head symbols and classify the differences
+   *
+   * Symbols are matched by parent name, name and kind; repeated keys (e.g.
+   * overloads) are matched in order of appearance. A matched symbol is
+   * modified when its source text differs, ignoring trailing whitespace, so
+   * symbols that only moved are not reported.
+   */
+  private compareSymbols(
+    baseSymbols: DiffSymbol[],
+    baseContent: string,
+    headSymbols: DiffSymbol[],
+    headContent: string
+  ): SymbolChange[] {
+    const baseLines = baseContent.split(/\r?\n/);
+    const headLines = headContent.split(/\r?\n/);
+    const baseByKey = this.keySymbols(baseSymbols);
+    const headByKey = this.keySymbols(headSymbols);
+    const changes: SymbolChange[] = [];
+
+    for (const [key, head] of headByKey) {
+      const base = baseByKey.get(key);
+      if (!base) {
+        changes.push(this.toSymbolChange(head, "added", undefined, head));
+      } else if (this.symbolText(baseLines, base) !== this.symbolText(headLines, head)) {
+        changes.push(this.toSymbolChange(head, "modified", base, head));
+      }
+    }
+    for (const [key, base] of baseByKey) {
+      if (!headByKey.has(key)) {
+        changes.push(this.toSymbolChange(base, "removed", base, undefined));
+      }
+    }
+
+    return changes.sort(
+      (a, b) =>
+        (a.head?.start_line ?? a.base?.start_line ?? 0) -
+        (b.head?.start_line ?? b.base?.start_line ?? 0)
+    );
+  }
+
+  /**
+   * Key symbols by parent, name, kind and occurrence
+   */
+  private keySymbols(symbols: DiffSymbol[]): Map<string, DiffSymbol> {
+    const keyed = new Map<string, DiffSymbol>();
+    const occurrences = new Map<string, number>();
+
+    for (const symbol of [...symbols].sort((a, b) => a.startLine - b.startLine)) {
+      const baseKey = `${symbol.parentName ?? ""}.${symbol.name}:${symbol.kind}`;
+      const occurrence = occurrences.get(baseKey) ?? 0;
+      occurrences.set(baseKey, occurrence + 1);
+      keyed.set(`${baseKey}#${occurrence}`, symbol);
+    }
+
+    return keyed;
+  }
+
+  /**
+   * Source text of a symbol with trailing whitespace removed from each line
+   */
+  private symbolText(lines: string[], symbol: DiffSymbol): string {
+    return lines
+      .slice(symbol.startLine - 1, symbol.endLine)
+      .map((line) => line.trimEnd())
+      .join("\n");
+  }
+
+  /**
+   * Build a SymbolChange from the matched base and head symbols
+   */
+  private toSymbolChange(
+    symbol: DiffSymbol,
+    change: SymbolChangeType,
+    base: DiffSymbol | undefined,
+    head: DiffSymbol | undefined
+  ): SymbolChange {
+    return {
+      name: symbol.name,
+      kind: symbol.kind,
+      ...(symbol.parentName !== undefined && { parent_name: symbol.parentName }),
+      change,
+      ...(base && { base: { start_line: base.startLine, end_line: base.endLine } }),
+      ...(head && { head: { start_line: head.startLine, end_line: head.endLine } }),
+    };
+  }
+
+  // ===========================================================================
+  // Private: Snapshots
+  // ===========================================================================
+
+  private async executeCreateSnapshot(
+    query: ValidatedSymbolSnapshotQuery
+  ): Promise<SymbolSnapshotResult> {
+    const repo = await this.getSnapshotRepository(query.repository);
+    const symbols = await this.fingerprintIndexedSymbols(repo.name);
+    const previous = await this.snapshotStore.loadSnapshot(repo.name, query.name);
+    const createdAt = new Date().toISOString();
+    const commitSha = repo.lastIndexedCommitSha;
+
+    await this.snapshotStore.saveSnapshot({
+      version: SYMBOL_SNAPSHOT_VERSION,
+      repository: repo.name,
+      name: query.name,
+      createdAt,
+      ...(commitSha !== undefined && { commitSha }),
+      symbols: Object.fromEntries(
+        Array.from(symbols, ([symbolPath, symbol]) => [symbolPath, symbol.hash])
+      ),
+    });
+
+    return {
+      repository: repo.name,
+      name: query.name,
+      created_at: createdAt,
+      ...(commitSha !== undefined && { commit_sha: commitSha }),
+      symbol_count: symbols.size,
+      replaced: previous !== null,
+      metadata: {
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  private async executeWhatChanged(query: ValidatedWhatChangedQuery): Promise<WhatChangedResult> {
+    const repo = await this.getSnapshotRepository(query.repository);
+    const snapshot = await this.snapshotStore.loadSnapshot(repo.name, query.name);
+    if (!snapshot) {
+      throw new GraphServiceValidationError(
+        `Snapshot '${query.name}' not found for repository '${repo.name}'`
+      );
+    }
+
+    const inScope = this.pathMatcher(query.path);
+    const current = await this.fingerprintIndexedSymbols(repo.name);
+    const changes: SnapshotSymbolChange[] = [];
+    let currentCount = 0;
+    let snapshotCount = 0;
+
+    for (const [symbolPath, symbol] of current) {
+      if (!inScope(symbol.filePath)) continue;
+      currentCount++;
+
+      const previousHash = snapshot.symbols[symbolPath];
+      if (previousHash === symbol.hash) continue;
+      changes.push({
+        symbol: symbolPath,
+        file_path: symbol.filePath,
+        name: symbol.name,
+        change: previousHash === undefined ? "added" : "modified",
+        kind: symbol.kind,
+        location: { start_line: symbol.startLine, end_line: symbol.endLine },
+      });
+    }
+
+    for (const symbolPath of Object.keys(snapshot.symbols)) {
+      const segments = symbolPath.split("::");
+      const filePath = segments[0] ?? symbolPath;
+      if (!inScope(filePath)) continue;
+      snapshotCount++;
+
+      if (!current.has(symbolPath)) {
+        changes.push({
+          symbol: symbolPath,
+          file_path: filePath,
+          name: (segments[segments.length - 1] ?? symbolPath).replace(/#\d+$/, ""),
+          change: "removed",
+        });
+      }
+    }
+
+    changes.sort((a, b) => a.symbol.localeCompare(b.symbol));
+
+    const summary: Record<SymbolChangeType, number> = { added: 0, removed: 0, modified: 0 };
+    for (const change of changes) {
+      summary[change.change]++;
+    }
+
+    return {
+      repository: repo.name,
+      name: query.name,
+      snapshot_created_at: snapshot.createdAt,
+      ...(snapshot.commitSha !== undefined && { snapshot_commit_sha: snapshot.commitSha }),
+      ...(repo.lastIndexedCommitSha !== undefined && {
+        current_commit_sha: repo.lastIndexedCommitSha,
+      }),
+      changes,
+      summary,
+      metadata: {
+        snapshot_symbol_count: snapshotCount,
+        current_symbol_count: currentCount,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  private async executeRecordSnapshot(
+    query: ValidatedRecordSnapshotQuery
+  ): Promise<RecordSnapshotResult> {
+    const repo = await this.getSnapshotRepository(query.repository);
+    const symbols = await this.fingerprintIndexedSymbols(repo.name);
+    const history = await this.snapshotStore.loadHistory(repo.name);
+    const recordedAt = new Date().toISOString();
+    const commitSha = repo.lastIndexedCommitSha;
+
+    const latestSymbols: Record<string, string> = Object.fromEntries(
+      Array.from(symbols, ([symbolPath, symbol]) => [symbolPath, symbol.hash])
+    );
+    const changes: Record<SymbolChangeType, number> = { added: 0, removed: 0, modified: 0 };
+    if (history) {
+      for (const [symbolPath, hash] of Object.entries(latestSymbols)) {
+        const previousHash = history.latestSymbols[symbolPath];
+        if (previousHash === undefined) changes.added++;
+        else if (previousHash !== hash) changes.modified++;
+      }
+      for (const symbolPath of Object.keys(history.latestSymbols)) {
+        if (latestSymbols[symbolPath] === undefined) changes.removed++;
+      }
+    }
+
+    const sortedEntries = Object.entries(latestSymbols).sort(([a], [b]) => a.localeCompare(b));
+    const digest = Bun.hash(JSON.stringify(sortedEntries)).toString(16).padStart(16, "0");
+
+    const entries = [
+      ...(history?.entries ?? []),
+      {
+        recordedAt,
+        ...(commitSha !== undefined && { commitSha }),
+        digest,
+        symbolCount: symbols.size,
+        ...changes,
+      },
+    ];
+    const pruned = Math.max(0, entries.length - this.historyRetention);
+
+    await this.snapshotStore.saveHistory({
+      version: SYMBOL_SNAPSHOT_VERSION,
+      repository: repo.name,
+      entries: entries.slice(pruned),
+      latestSymbols,
+    });
+
+    return {
+      repository: repo.name,
+      recorded_at: recordedAt,
+      ...(commitSha !== undefined && { commit_sha: commitSha }),
+      digest,
+      symbol_count: symbols.size,
+      changes,
+      history_length: entries.length - pruned,
+      pruned,
+      metadata: {
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  private async executeSnapshotHistory(
+    query: ValidatedSnapshotHistoryQuery
+  ): Promise<SnapshotHistoryResult> {
+    const repo = await this.repositoryService.getRepository(query.repository);
+    if (!repo) {
+      throw new RepositoryNotFoundError(query.repository);
+    }
+
+    const stored = (await this.snapshotStore.loadHistory(repo.name))?.entries ?? [];
+    const start = Math.max(0, stored.length - query.limit);
+    const entries = stored.slice(start).map((entry, i) => {
+      const previous = stored[start + i - 1];
+      return {
+        recorded_at: entry.recordedAt,
+        ...(entry.commitSha !== undefined && { commit_sha: entry.commitSha }),
+        digest: entry.digest,
+        symbol_count: entry.symbolCount,
+        symbol_delta: previous ? entry.symbolCount - previous.symbolCount : 0,
+        changes: { added: entry.added, removed: entry.removed, modified: entry.modified },
+        change_count: entry.added + entry.removed + entry.modified,
+      };
+    });
+
+    const first = entries[0];
+    const last = entries[entries.length - 1];
+    const windowChanges: Record<SymbolChangeType, number> = { added: 0, removed: 0, modified: 0 };
+    for (const entry of entries.slice(1)) {
+      windowChanges.added += entry.changes.added;
+      windowChanges.removed += entry.changes.removed;
+      windowChanges.modified += entry.changes.modified;
+    }
+
+    return {
+      repository: repo.name,
+      entries,
+      trend:
+        first && last
+          ? {
+              from: first.recorded_at,
+              to: last.recorded_at,
+              symbol_count_delta: last.symbol_count - first.symbol_count,
+              changes: windowChanges,
+            }
+          : null,
+      metadata: {
+        total_entries: stored.length,
+        retention: this.historyRetention,
+        query_time_ms: 0, // Updated by caller
+      },
+    };
+  }
+
+  /**
+   * Look up a registered repository whose symbols can be read from the graph
+   *
+   * @throws {RepositoryNotFoundError} If the repository is not registered
+   * @throws {GraphServiceOperationError} If graph storage is unavailable
+   */
+  private async getSnapshotRepository(name: string): Promise<RepositoryInfo> {
+    const repo = await this.repositoryService.getRepository(name);
+    if (!repo) {
+      throw new RepositoryNotFoundError(name);
+    }
+    if (!this.graphAdapter) {
+      throw new GraphServiceOperationError("Symbol snapshots require graph storage", false);
+    }
+    return repo;
+  }
+
+  /**
+   * Fingerprint every indexed symbol of a repository
+   *
+   * Keys are qualified paths (`<file>::<parent>::<name>`, with a `#<n>` suffix
+   * for the n-th repeat of a path in source order). The hash covers the
+   * symbol's kind and signature-level properties, not its location or body.
+   */
+  private async fingerprintIndexedSymbols(
+    repository: string
+  ): Promise<Map<string, FingerprintedSymbol>> {
+    if (!this.graphAdapter) {
+      return new Map();
+    }
+
+    const rows = await this.graphAdapter.runQuery<FingerprintRow>(
+      `
+      MATCH (f:File {repository: $repository})-[:DEFINES]->(e)
+      RETURN f.path AS filePath,
+             e.name AS name,
+             e.entityType AS entityType,
+             e.kind AS kind,
+             e.parentName AS parentName,
+             e.startLine AS startLine,
+             e.endLine AS endLine,
+             e.signature AS signature,
+             e.whereClause AS whereClause,
+             e.superclass AS superclass,
+             e.interfaces AS interfaces,
+             e.typeParameters AS typeParameters,
+             e.isUnsafe AS isUnsafe
+      `,
+      { repository }
+    );
+
+    const sorted = [...rows].sort(
+      (a, b) =>
+        a.filePath.localeCompare(b.filePath) || Number(a.startLine ?? 0) - Number(b.startLine ?? 0)
+    );
+    const symbols = new Map<string, FingerprintedSymbol>();
+    const occurrences = new Map<string, number>();
+
+    for (const row of sorted) {
+      const kind = getSymbolKind({
+        type: (row.entityType ?? "variable") as EntityType,
+        kind: row.kind ?? undefined,
+      });
+      const basePath = [row.filePath, row.parentName, row.name]
+        .filter((segment): segment is string => !!segment)
+        .join("::");
+      const occurrence = (occurrences.get(basePath) ?? 0) + 1;
+      occurrences.set(basePath, occurrence);
+
+      const fingerprint = JSON.stringify([
+        kind,
+        row.signature,
+        row.whereClause,
+        row.superclass,
+        row.interfaces,
+        row.typeParameters,
+        row.isUnsafe ?? false,
+      ]);
+      const startLine = Number(row.startLine ?? 0);
+
+      symbols.set(occurrence === 1 ? basePath : `${basePath}#${occurrence}`, {
+        filePath: row.filePath,
+        name: row.name,
+        kind,
+        startLine,
+        endLine: Number(row.endLine ?? startLine),
+        hash: Bun.hash(fingerprint).toString(16).padStart(16, "0"),
+      });
+    }
+
+    return symbols;
+  }
+
+  // ===========================================================================
+  // Private: Symbol Sources
+  // ===========================================================================
+
+  /**
+   * Extract the symbols of one version of a file
+   *
+   * @returns Symbols, or null when the file cannot be parsed (e.g. too large)
+   */
+  private async extractSymbols(content: string, filePath: string): Promise<DiffSymbol[] | null> {
+    try {
+      const result = await this.entityExtractor.extractFromContent(content, filePath);
+      return result.entities.map((entity) => ({
+        name: entity.name,
+        kind: getSymbolKind(entity),
+        ...(entity.parentName !== undefined && { parentName: entity.parentName }),
+        startLine: entity.lineStart,
+        endLine: entity.lineEnd,
+      }));
+    } catch (error) {
+      this.logger.warn({ filePath, err: error }, "Skipping file that could not be parsed");
+      return null;
+    }
+  }
+
+  /**
+   * Read the symbols of a file from the current index
+   *
+   * Returns no symbols when the graph query fails, so the caller falls back
+   * to parsing the base version.
+   */
+  private async getIndexedSymbols(repository: string, filePath: string): Promise<DiffSymbol[]> {
+    if (!this.graphAdapter) {
+      return [];
+    }
+
+    try {
+      const rows = await this.graphAdapter.runQuery<IndexedSymbolRow>(
+        `
+        MATCH (f:File {repository: $repository, path: $filePath})-[:DEFINES]->(e)
+        RETURN e.name AS name,
+               e.entityType AS entityType,
+               e.kind AS kind,
+               e.parentName AS parentName,
+               e.startLine AS startLine,
+               e.endLine AS endLine
+        `,
+        { repository, filePath }
+      );
+
+      return rows.map((row) => ({
+        name: row.name,
+        kind: getSymbolKind({
+          type: (row.entityType ?? "variable") as EntityType,
+          kind: row.kind ?? undefined,
+        }),
+        ...(row.parentName !== null && { parentName: row.parentName }),
+        startLine: Number(row.startLine ?? 0),
+        endLine: Number(row.endLine ?? row.startLine ?? 0),
+      }));
+    } catch (error) {
+      this.logger.warn(
+        { repository, filePath, err: error },
+        "Failed to read indexed symbols; parsing base version instead"
+      );
+      return [];
+    }
+  }
+
+  // ===========================================================================
+  // Private: Git Access
+  // ===========================================================================
+
+  /**
+   * Look up a git-backed repository and open its local clone
+   *
+   * @throws {RepositoryNotFoundError} If the repository is not registered
+   * @throws {GraphServiceValidationError} If the repository has no git history
+   */
+  private async openRepository(name: string): Promise<{ repo: RepositoryInfo; git: SimpleGit }> {
+    const repo = await this.repositoryService.getRepository(name);
+    if (!repo) {
+      throw new RepositoryNotFoundError(name);
+    }
+    if (repo.source === "local-folder") {
+      throw new GraphServiceValidationError(
+        `Repository '${name}' is a local folder without git history`
+      );
+    }
+
+    return { repo, git: this.createGit(repo.localPath) };
+  }
+
+  /**
+   * Create a git instance for a repository clone
+   */
+  private createGit(localPath: string): SimpleGit {
+    return this.simpleGitFactory ? this.simpleGitFactory(localPath) : simpleGit(localPath);
+  }
+
+  /**
+   * Resolve a ref to a commit SHA
+   *
+   * @throws {GraphServiceValidationError} If the ref does not name a commit
+   */
+  private async resolveRef(git: SimpleGit, ref: string): Promise<string> {
+    try {
+      return (await git.revparse(["--verify", `${ref}^{commit}`])).trim();
+    } catch {
+      throw new GraphServiceValidationError(`Unknown git ref: ${ref}`);
+    }
+  }
+
+  /**
+   * List files changed between two commits
+   *
+   * Copies are reported as additions of the destination path.
+   */
+  private async getChangedFiles(
+    git: SimpleGit,
+    baseSha: string,
+    headSha: string
+  ): Promise<FileChange[]> {
+    const diffOutput = await this.runGit(
+      () => git.diff(["--name-status", "-M", baseSha, headSha]),
+      "git diff"
+    );
+
+    const files: FileChange[] = [];
+    for (const line of diffOutput.split("\n")) {
+      const trimmed = line.trim();
+      if (!trimmed) continue;
+
+      const parts = trimmed.split(/\t+/);
+      const statusCode = parts[0]?.charAt(0);
+      if (!statusCode || !parts[1]) continue;
+
+      if (statusCode === "R" && parts[2]) {
+        files.push({ path: parts[2], status: "renamed", previousPath: parts[1] });
+      } else if (statusCode === "C" && parts[2]) {
+        files.push({ path: parts[2], status: "added" });
+      } else {
+        const status = statusCode === "A" ? "added" : statusCode === "D" ? "deleted" : "modified";
+        files.push({ path: parts[1], status });
+      }
+    }
+
+    return files;
+  }
+
+  /**
+   * Head-side changed regions of each file from a zero-context diff
+   *
+   * Hunk headers read `@@ -a,b +c,d @@`: lines c..c+d-1 of the head version
+   * replaced the base lines, and d = 0 marks a pure deletion after line c.
+   * Files are keyed by their head path.
+   */
+  private async getChangedHunks(
+    git: SimpleGit,
+    baseSha: string,
+    headSha: string
+  ): Promise<Map<string, FileHunks>> {
+    const diffOutput = await this.runGit(
+      () =>
+        git.diff([
+          "-U0",
+          "-M",
+          "--no-color",
+          "--no-ext-diff",
+          "--src-prefix=a/",
+          "--dst-prefix=b/",
+          baseSha,
+          headSha,
+        ]),
+      "git diff"
+    );
+
+    const hunks = new Map<string, FileHunks>();
+    let current: FileHunks | null = null;
+    let inHeader = false;
+
+    for (const line of diffOutput.split("\n")) {
+      if (line.startsWith("diff --git ")) {
+        current = null;
+        inHeader = true;
+      } else if (inHeader && line.startsWith("+++ ")) {
+        const target = line.slice(4).trim();
+        if (target.startsWith("b/")) {
+          current = { ranges: [], deletionPoints: [] };
+          hunks.set(target.slice(2), current);
+        }
+      } else if (line.startsWith("@@")) {
+        inHeader = false;
+        const match = /^@@ -\d+(?:,\d+)? \+(\d+)(?:,(\d+))? @@/.exec(line);
+        if (!current || !match) continue;
+
+        const start = Number(match[1]);
+        const count = match[2] === undefined ? 1 : Number(match[2]);
+        if (count === 0) {
+          current.deletionPoints.push(start);
+        } else {
+          current.ranges.push({ start_line: start, end_line: start + count - 1 });
+        }
+      }
+    }
+
+    return hunks;
+  }
+
+  /**
+   * Read a file's content at a commit without checking it out
+   */
+  private async readBlob(git: SimpleGit, sha: string, filePath: string): Promise<string> {
+    return this.runGit(() => git.show([`${sha}:${filePath}`]), "git show");
+  }
+
+  /**
+   * Run a git command, wrapping failures in GraphServiceOperationError
+   */
+  private async runGit(command: () => Promise<string>, label: string): Promise<string> {
+    try {
+      return await command();
+    } catch (error) {
+      throw new GraphServiceOperationError(
+        `${label} failed: ${error instanceof Error ? error.message : String(error)}`,
+        false,
+        error instanceof Error ? error : undefined
+      );
+    }
+  }
+
+  /**
+   * Keep changes at or beneath a path (either side of a rename may match)
+   */
+  private filterByPath(changes: FileChange[], filterPath: string | undefined): FileChange[] {
+    if (!filterPath) {
+      return changes;
+    }
+
+    const matches = this.pathMatcher(filterPath);
+    return changes.filter(
+      (change) =>
+        matches(change.path) || (change.previousPath !== undefined && matches(change.previousPath))
+    );
+  }
+
+  /**
+   * Build a predicate matching file paths at or beneath a path
+   *
+   * An absent or empty path matches every file.
+   */
+  private pathMatcher(filterPath: string | undefined): (filePath: string) => boolean {
+    const normalized = filterPath
+      ?.replace(/\\/g, "/")
+      .replace(/^\.\//, "")
+      .replace(/\/+$/, "");
+    if (!normalized) {
+      return () => true;
+    }
+
+    return (filePath) => filePath === normalized || filePath.startsWith(`${normalized}/`);
+  }
+
+  // ===========================================================================
+  // Private: Validation and Error Handling
+  // ===========================================================================
+
+  /**
+   * Validate a query against its schema
+   *
+   * @throws {GraphServiceValidationError} If validation fails
+   */
+  private validate<S extends z.ZodType>(schema: S, query: unknown, label: string): z.infer<S> {
+    try {
+      return schema.parse(query);
+    } catch (error) {
+      if (error instanceof z.ZodError) {
+        const errors = error.issues.map((e) => `${e.path.join(".")}: ${e.message}`);
+        throw new GraphServiceValidationError(`Invalid ${label}: ${errors.join("; ")}`, errors);
+      }
+      throw error;
+    }
+  }
+
+  /**
+   * Handle and log errors
+   *
+   * Known service errors are logged and rethrown by the caller; unexpected
+   * errors are wrapped in GraphServiceOperationError.
+   */
+  private handleError(error: unknown, operation: string, durationMs: number): void {
+    const duration = Math.round(durationMs);
+
+    if (error instanceof GraphServiceValidationError || error instanceof RepositoryNotFoundError) {
+      this.logger.warn(
+        { error_type: error.constructor.name, message: error.message, duration_ms: duration },
+        `${operation} failed with known error`
+      );
+    } else if (error instanceof Error) {
+      this.logger.error(
+        {
+          error_type: error.constructor.name,
+          message: error.message,
+          duration_ms: duration,
+          stack: error.stack,
+        },
+        `${operation} failed with unexpected error`
+      );
+
+      if (!(error instanceof GraphServiceOperationError)) {
+        throw new GraphServiceOperationError(`${operation} failed: ${error.message}`, false, error);
+      }
+    }
+  }
+}
@@ -0,0 +1,588 @@
+/**
+ * @module services/symbol-diff-types
+ *
+ * Type definitions for SymbolDiffService.
+ *
+ * SymbolDiffService compares two git refs of an indexed repository at the
+ * symbol level: it takes the `git diff --name-status` file list, extracts the
+ * symbols of each changed file on both sides, and reports which functions,
+ * classes and variables were added, removed or modified. It can also narrow a
+ * repository to the symbols overlapping the lines changed since a ref, for CI
+ * checks that should only look at what a PR touches.
+ *
+ * Independently of git, the service can record a named snapshot of a
+ * repository's indexed symbols (qualified path → signature hash) and later
+ * report which symbols were added, removed or changed signature since then.
+ * It can also append timestamped fingerprints to a rolling per-repository
+ * history and report symbol-count and change-count trends across it.
+ */
+
+import type { SymbolKind } from "../graph/parsing/symbol-kinds.js";
+
+// =============================================================================
+// Query Types
+// =============================================================================
+
+/**
+ * Query for the symbol-level diff between two git refs
+ */
+export interface SymbolDiffQuery {
+  /** Repository to diff (must be a git-backed repository) */
+  repository: string;
+
+  /** Base ref (branch, tag or commit SHA), e.g. "main" */
+  base_ref: string;
+
+  /**
+   * Head ref (branch, tag or commit SHA)
+   * @default "HEAD"
+   */
+  head_ref?: string;
+
+  /** Only diff files at or beneath this path */
+  path?: string;
+}
+
+/**
+ * Query for the symbols touched by the changes since a git ref
+ */
+export interface DiffScopeQuery {
+  /** Repository to scope (must be a git-backed repository) */
+  repository: string;
+
+  /** Base ref the changes are measured from, e.g. "main" */
+  base_ref: string;
+
+  /**
+   * Head ref whose symbols are returned
+   * @default "HEAD"
+   */
+  head_ref?: string;
+
+  /** Only scope files at or beneath this path */
+  path?: string;
+
+  /** Only return symbols whose name contains this text (case-insensitive) */
+  query?: string;
+
+  /** Only return symbols of this kind */
+  kind?: SymbolKind;
+}
+
+/**
+ * Query to record a named snapshot of a repository's indexed symbols
+ */
+export interface SymbolSnapshotQuery {
+  /** Repository to snapshot (must be indexed in the graph) */
+  repository: string;
+
+  /** Snapshot name; an existing snapshot with this name is replaced */
+  name: string;
+}
+
+/**
+ * Query for the symbols changed since a named snapshot
+ */
+export interface WhatChangedQuery {
+  /** Repository the snapshot was taken of */
+  repository: string;
+
+  /** Name of the snapshot to compare against */
+  name: string;
+
+  /** Only report symbols in files at or beneath this path */
+  path?: string;
+}
+
+/**
+ * Query to append the repository's current fingerprint to its snapshot history
+ */
+export interface RecordSnapshotQuery {
+  /** Repository to record (must be indexed in the graph) */
+  repository: string;
+}
+
+/**
+ * Query for the snapshot history of a repository
+ */
+export interface SnapshotHistoryQuery {
+  /** Repository whose history to read */
+  repository: string;
+
+  /**
+   * Number of most recent entries to return
+   * @default 50
+   */
+  limit?: number;
+}
+
+// =============================================================================
+// Result Types
+// =============================================================================
+
+/**
+ * Kind of change to a symbol between the two refs
+ */
+export type SymbolChangeType = "added" | "removed" | "modified";
+
+/**
+ * Line range of a symbol on one side of the diff
+ */
+export interface SymbolLineRange {
+  /** First line of the symbol definition (1-based) */
+  start_line: number;
+
+  /** Last line of the symbol definition (1-based) */
+  end_line: number;
+}
+
+/**
+ * A symbol that differs between the two refs
+ */
+export interface SymbolChange {
+  /** Symbol name */
+  name: string;
+
+  /** Language-neutral symbol kind */
+  kind: SymbolKind;
+
+  /** Name of the enclosing symbol, for nested symbols */
+  parent_name?: string;
+
+  /** How the symbol changed */
+  change: SymbolChangeType;
+
+  /** Location in the base ref (absent for added symbols) */
+  base?: SymbolLineRange;
+
+  /** Location in the head ref (absent for removed symbols) */
+  head?: SymbolLineRange;
+}
+
+/**
+ * Symbol changes of one file
+ */
+export interface FileSymbolDiff {
+  /** File path in the head ref (base path for deleted files) */
+  file_path: string;
+
+  /** Path in the base ref, for renamed files */
+  previous_path?: string;
+
+  /** File-level change reported by git */
+  status: "added" | "modified" | "deleted" | "renamed";
+
+  /** Changed symbols ordered by location */
+  symbols: SymbolChange[];
+}
+
+/**
+ * Result of a symbol diff query
+ */
+export interface SymbolDiffResult {
+  /** Repository diffed */
+  repository: string;
+
+  /** Base ref as given */
+  base_ref: string;
+
+  /** Head ref as given */
+  head_ref: string;
+
+  /** Resolved base commit SHA */
+  base_sha: string;
+
+  /** Resolved head commit SHA */
+  head_sha: string;
+
+  /** Files with at least one changed symbol, ordered by path */
+  files: FileSymbolDiff[];
+
+  /** Number of changed symbols by change type */
+  summary: Record<SymbolChangeType, number>;
+
+  /** Query metadata */
+  metadata: {
+    /** Files reported by git diff (after path filtering) */
+    files_changed: number;
+    /** Changed files in a supported language that were compared */
+    files_analyzed: number;
+    /** Changed files skipped because their language is not supported */
+    files_skipped: number;
+    /** Files whose base symbols were read from the current index instead of parsed */
+    base_from_index: number;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+/**
+ * A head-ref symbol whose span overlaps the changed lines
+ */
+export interface ScopedSymbol {
+  /** Symbol name */
+  name: string;
+
+  /** Language-neutral symbol kind */
+  kind: SymbolKind;
+
+  /** Name of the enclosing symbol, for nested symbols */
+  parent_name?: string;
+
+  /** First line of the symbol definition in the head ref (1-based) */
+  start_line: number;
+
+  /** Last line of the symbol definition in the head ref (1-based) */
+  end_line: number;
+
+  /** Number of added or modified lines within the symbol's span */
+  changed_lines: number;
+}
+
+/**
+ * Changed lines and touched symbols of one file
+ */
+export interface FileDiffScope {
+  /** File path in the head ref */
+  file_path: string;
+
+  /** Path in the base ref, for renamed files */
+  previous_path?: string;
+
+  /** File-level change reported by git */
+  status: "added" | "modified" | "renamed";
+
+  /**
+   * Head-ref line ranges that were added or modified. Pure deletions have no
+   * head lines; they still put the symbol enclosing them in scope.
+   */
+  changed_ranges: SymbolLineRange[];
+
+  /** Symbols overlapping the changes, ordered by location */
+  symbols: ScopedSymbol[];
+}
+
+/**
+ * Result of a diff scope query
+ *
+ * Unlike other symbol listings this is not drawn from the repository index:
+ * it covers only the files changed since `base_ref`, parsed at `head_ref`.
+ */
+export interface DiffScopeResult {
+  /** Always "diff": results are limited to symbols touched by the diff */
+  scope: "diff";
+
+  /** Repository scoped */
+  repository: string;
+
+  /** Base ref as given */
+  base_ref: string;
+
+  /** Head ref as given */
+  head_ref: string;
+
+  /** Resolved base commit SHA */
+  base_sha: string;
+
+  /** Resolved head commit SHA */
+  head_sha: string;
+
+  /** Files with at least one symbol in scope, ordered by path */
+  files: FileDiffScope[];
+
+  /** Query metadata */
+  metadata: {
+    /** Files reported by git diff (after path filtering) */
+    files_changed: number;
+    /** Changed files in a supported language that were parsed at the head ref */
+    files_analyzed: number;
+    /** Changed files skipped because they were deleted, unsupported or unparsable */
+    files_skipped: number;
+    /** Symbols in scope across all files */
+    symbol_count: number;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+/**
+ * Result of recording a symbol snapshot
+ */
+export interface SymbolSnapshotResult {
+  /** Repository snapshotted */
+  repository: string;
+
+  /** Snapshot name */
+  name: string;
+
+  /** ISO 8601 timestamp of the snapshot */
+  created_at: string;
+
+  /** Commit the repository was indexed at, if known */
+  commit_sha?: string;
+
+  /** Number of symbols fingerprinted */
+  symbol_count: number;
+
+  /** Whether an earlier snapshot with the same name was replaced */
+  replaced: boolean;
+
+  /** Query metadata */
+  metadata: {
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+/**
+ * A symbol that differs between a snapshot and the current index
+ */
+export interface SnapshotSymbolChange {
+  /**
+   * Qualified symbol path, `<file>::<parent>::<name>` (the parent segment is
+   * omitted for top-level symbols). Repeated paths such as overloads carry a
+   * `#<n>` suffix from the second occurrence on.
+   */
+  symbol: string;
+
+  /** File defining the symbol */
+  file_path: string;
+
+  /** Symbol name */
+  name: string;
+
+  /** How the symbol changed */
+  change: SymbolChangeType;
+
+  /** Language-neutral symbol kind (absent for removed symbols) */
+  kind?: SymbolKind;
+
+  /** Current location (absent for removed symbols) */
+  location?: SymbolLineRange;
+}
+
+/**
+ * Result of a what-changed query
+ *
+ * A symbol is modified when its signature (kind, parameters, return type,
+ * supertypes, type parameters and modifiers) changed; body-only edits are not
+ * reported.
+ */
+export interface WhatChangedResult {
+  /** Repository compared */
+  repository: string;
+
+  /** Snapshot name */
+  name: string;
+
+  /** ISO 8601 timestamp of the snapshot */
+  snapshot_created_at: string;
+
+  /** Commit the repository was indexed at when the snapshot was taken, if known */
+  snapshot_commit_sha?: string;
+
+  /** Commit the repository is currently indexed at, if known */
+  current_commit_sha?: string;
+
+  /** Changed symbols ordered by qualified path */
+  changes: SnapshotSymbolChange[];
+
+  /** Number of changed symbols by change type */
+  summary: Record<SymbolChangeType, number>;
+
+  /** Query metadata */
+  metadata: {
+    /** Symbols in the snapshot (after path filtering) */
+    snapshot_symbol_count: number;
+    /** Symbols in the current index (after path filtering) */
+    current_symbol_count: number;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+/**
+ * Result of appending a fingerprint to the snapshot history
+ */
+export interface RecordSnapshotResult {
+  /** Repository recorded */
+  repository: string;
+
+  /** ISO 8601 timestamp of the new entry */
+  recorded_at: string;
+
+  /** Commit the repository was indexed at, if known */
+  commit_sha?: string;
+
+  /** Digest of the whole fingerprint; unchanged when no signature changed */
+  digest: string;
+
+  /** Number of symbols fingerprinted */
+  symbol_count: number;
+
+  /** Changed symbols since the previous entry by change type (all 0 for the first) */
+  changes: Record<SymbolChangeType, number>;
+
+  /** Number of entries in the history, including the new one */
+  history_length: number;
+
+  /** Number of oldest entries dropped to stay within the retention limit */
+  pruned: number;
+
+  /** Query metadata */
+  metadata: {
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+/**
+ * One entry of a repository's snapshot history
+ */
+export interface SnapshotHistoryEntry {
+  /** ISO 8601 timestamp of the entry */
+  recorded_at: string;
+
+  /** Commit the repository was indexed at, if known */
+  commit_sha?: string;
+
+  /** Digest of the whole fingerprint */
+  digest: string;
+
+  /** Number of symbols fingerprinted */
+  symbol_count: number;
+
+  /** Change in symbol count since the previous entry (0 for the first) */
+  symbol_delta: number;
+
+  /** Changed symbols since the previous entry by change type */
+  changes: Record<SymbolChangeType, number>;
+
+  /** Total number of changed symbols since the previous entry */
+  change_count: number;
+}
+
+/**
+ * Result of a snapshot history query
+ */
+export interface SnapshotHistoryResult {
+  /** Repository whose history was read */
+  repository: string;
+
+  /** Most recent entries, oldest first */
+  entries: SnapshotHistoryEntry[];
+
+  /**
+   * Trend across the returned entries: the change in symbol count from the
+   * first to the last, and the changes recorded after the first. Null when
+   * nothing was recorded.
+   */
+  trend: {
+    /** Timestamp of the first returned entry */
+    from: string;
+    /** Timestamp of the last returned entry */
+    to: string;
+    /** Symbol count of the last entry minus that of the first */
+    symbol_count_delta: number;
+    /** Changed symbols across the window by change type */
+    changes: Record<SymbolChangeType, number>;
+  } | null;
+
+  /** Query metadata */
+  metadata: {
+    /** Number of entries stored in the history */
+    total_entries: number;
+    /** Maximum number of entries the history keeps */
+    retention: number;
+    /** Query execution time in milliseconds */
+    query_time_ms: number;
+  };
+}
+
+// =============================================================================
+// Service Interface
+// =============================================================================
+
+/**
+ * SymbolDiffService interface
+ *
+ * Symbol-level comparison of two git refs of an indexed repository.
+ */
+export interface SymbolDiffService {
+  /**
+   * Diff the symbols of the files changed between two git refs
+   *
+   * @param query - Repository, refs and optional path filter
+   * @returns Added, removed and modified symbols per changed file
+   * @throws {GraphServiceValidationError} If the query is invalid, the repository
+   *         is not git-backed, or a ref cannot be resolved
+   * @throws {RepositoryNotFoundError} If the repository is not registered
+   * @throws {GraphServiceOperationError} If a git command fails
+   */
+  diffSymbols(query: SymbolDiffQuery): Promise<SymbolDiffResult>;
+
+  /**
+   * List the head-ref symbols whose spans overlap the lines changed since a ref
+   *
+   * Only the changed files are parsed, and nothing is read from or written to
+   * the index, so the result is a throwaway view of what the diff touches.
+   *
+   * @param query - Repository, refs and optional path, name and kind filters
+   * @returns Changed line ranges and touched symbols per changed file
+   * @throws {GraphServiceValidationError} If the query is invalid, the repository
+   *         is not git-backed, or a ref cannot be resolved
+   * @throws {RepositoryNotFoundError} If the repository is not registered
+   * @throws {GraphServiceOperationError} If a git command fails
+   */
+  scopeToDiff(query: DiffScopeQuery): Promise<DiffScopeResult>;
+
+  /**
+   * Record a named snapshot of the repository's indexed symbols
+   *
+   * Stores only a fingerprint per symbol (qualified path → signature hash),
+   * replacing any earlier snapshot with the same name.
+   *
+   * @param query - Repository and snapshot name
+   * @returns Snapshot summary
+   * @throws {GraphServiceValidationError} If the query is invalid
+   * @throws {RepositoryNotFoundError} If the repository is not registered
+   * @throws {GraphServiceOperationError} If graph storage is unavailable
+   */
+  createSnapshot(query: SymbolSnapshotQuery): Promise<SymbolSnapshotResult>;
+
+  /**
+   * Compare the repository's current indexed symbols against a named snapshot
+   *
+   * @param query - Repository, snapshot name and optional path filter
+   * @returns Added, removed and modified symbols since the snapshot
+   * @throws {GraphServiceValidationError} If the query is invalid or the snapshot
+   *         does not exist
+   * @throws {RepositoryNotFoundError} If the repository is not registered
+   * @throws {GraphServiceOperationError} If graph storage is unavailable
+   */
+  whatChanged(query: WhatChangedQuery): Promise<WhatChangedResult>;
+
+  /**
+   * Append the repository's current fingerprint to its snapshot history
+   *
+   * Each entry records the symbol count and the changes since the previous
+   * entry; the oldest entries are dropped beyond the retention limit.
+   *
+   * @param query - Repository to record
+   * @returns The new entry and the resulting history length
+   * @throws {GraphServiceValidationError} If the query is invalid
+   * @throws {RepositoryNotFoundError} If the repository is not registered
+   * @throws {GraphServiceOperationError} If graph storage is unavailable
+   */
+  recordSnapshot(query: RecordSnapshotQuery): Promise<RecordSnapshotResult>;
+
+  /**
+   * Read the symbol-count and change-count trend of a repository's snapshot history
+   *
+   * @param query - Repository and number of recent entries
+   * @returns Recent entries, oldest first, with the trend across them
+   * @throws {GraphServiceValidationError} If the query is invalid
+   * @throws {RepositoryNotFoundError} If the repository is not registered
+   */
+  getSnapshotHistory(query: SnapshotHistoryQuery): Promise<SnapshotHistoryResult>;
+}
@@ -0,0 +1,150 @@
+/**
+ * @module services/symbol-diff-validation
+ *
+ * Zod validation schemas for SymbolDiffService query inputs.
+ *
+ * Follows the conventions of graph-analysis-validation.ts: strict object
+ * schemas, trimmed non-empty strings, and defaults applied at parse time.
+ */
+
+import { z } from "zod";
+import { SYMBOL_KINDS, type SymbolKind } from "../graph/parsing/symbol-kinds.js";
+
+// =============================================================================
+// Shared Schemas
+// =============================================================================
+
+/**
+ * Git ref schema
+ *
+ * Refs are passed to git as arguments, so a leading "-" (which git would read
+ * as an option), whitespace and range syntax are rejected.
+ */
+const gitRef = (fieldName: string): z.ZodString =>
+  z
+    .string()
+    .trim()
+    .min(1, `${fieldName} must not be empty`)
+    .max(250, `${fieldName} exceeds maximum length of 250 characters`)
+    .regex(/^[^-\s][^\s]*$/, `${fieldName} must be a single ref without whitespace`)
+    .refine((ref) => !ref.includes(".."), `${fieldName} must be a single ref, not a range`);
+
+/**
+ * Snapshot name schema
+ *
+ * Names become part of a file name, so they are limited to a conservative
+ * character set.
+ */
+const snapshotName = z
+  .string()
+  .trim()
+  .min(1, "Snapshot name must not be empty")
+  .max(100, "Snapshot name exceeds maximum length of 100 characters")
+  .regex(
+    /^[A-Za-z0-9][A-Za-z0-9._-]*$/,
+    "Snapshot name may only contain letters, digits, '.', '_' and '-'"
+  );
+
+// =============================================================================
+// Query Schemas
+// =============================================================================
+
+/**
+ * Validation schema for SymbolDiffQuery
+ */
+export const SymbolDiffQuerySchema = z
+  .object({
+    repository: z.string().trim().min(1, "Repository must not be empty"),
+    base_ref: gitRef("Base ref"),
+    head_ref: gitRef("Head ref").default("HEAD"),
+    path: z.string().trim().min(1).optional(),
+  })
+  .strict();
+
+/**
+ * Validation schema for DiffScopeQuery
+ */
+export const DiffScopeQuerySchema = z
+  .object({
+    repository: z.string().trim().min(1, "Repository must not be empty"),
+    base_ref: gitRef("Base ref"),
+    head_ref: gitRef("Head ref").default("HEAD"),
+    path: z.string().trim().min(1).optional(),
+    query: z.string().trim().min(1).optional(),
+    kind: z.enum(SYMBOL_KINDS as [SymbolKind, ...SymbolKind[]]).optional(),
+  })
+  .strict();
+
+/**
+ * Validation schema for SymbolSnapshotQuery
+ */
+export const SymbolSnapshotQuerySchema = z
+  .object({
+    repository: z.string().trim().min(1, "Repository must not be empty"),
+    name: snapshotName,
+  })
+  .strict();
+
+/**
+ * Validation schema for WhatChangedQuery
+ */
+export const WhatChangedQuerySchema = z
+  .object({
+    repository: z.string().trim().min(1, "Repository must not be empty"),
+    name: snapshotName,
+    path: z.string().trim().min(1).optional(),
+  })
+  .strict();
+
+/**
+ * Validation schema for RecordSnapshotQuery
+ */
+export const RecordSnapshotQuerySchema = z
+  .object({
+    repository: z.string().trim().min(1, "Repository must not be empty"),
+  })
+  .strict();
+
+/**
+ * Validation schema for SnapshotHistoryQuery
+ */
+export const SnapshotHistoryQuerySchema = z
+  .object({
+    repository: z.string().trim().min(1, "Repository must not be empty"),
+    limit: z.number().int().min(1).max(1000).default(50),
+  })
+  .strict();
+
+// =============================================================================
+// Validated Types
+// =============================================================================
+
+/**
+ * Validated SymbolDiffQuery after schema parsing
+ */
+export type ValidatedSymbolDiffQuery = z.infer<typeof SymbolDiffQuerySchema>;
+
+/**
+ * Validated DiffScopeQuery after schema parsing
+ */
+export type ValidatedDiffScopeQuery = z.infer<typeof DiffScopeQuerySchema>;
+
+/**
+ * Validated SymbolSnapshotQuery after schema parsing
+ */
+export type ValidatedSymbolSnapshotQuery = z.infer<typeof SymbolSnapshotQuerySchema>;
+
+/**
+ * Validated WhatChangedQuery after schema parsing
+ */
+export type ValidatedWhatChangedQuery = z.infer<typeof WhatChangedQuerySchema>;
+
+/**
+ * Validated RecordSnapshotQuery after schema parsing
+ */
+export type ValidatedRecordSnapshotQuery = z.infer<typeof RecordSnapshotQuerySchema>;
+
+/**
+ * Validated SnapshotHistoryQuery after schema parsing
+ */
+export type ValidatedSnapshotHistoryQuery = z.infer<typeof SnapshotHistoryQuerySchema>;
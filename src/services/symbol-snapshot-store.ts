@@ -0,0 +1,363 @@
+/**
+ * Symbol Snapshot Store Implementation
+ *
+ * Named, per-repository fingerprints of the symbol index. A snapshot maps each
+ * indexed symbol's qualified path (`<file>::<parent>::<name>`) to a hash of
+ * its signature, which is enough to later report which symbols were added,
+ * removed or changed signature without keeping a second full index around.
+ *
+ * Besides named snapshots, a repository has an optional rolling history of
+ * timestamped entries for charting symbol counts and churn over time. Each
+ * entry keeps only a digest of the whole fingerprint and its change counts
+ * against the previous entry; the full fingerprint is kept for the latest
+ * entry alone, which is all the next entry is compared against.
+ *
+ * Follows the singleton + atomic-write pattern of `file-manifest-store.ts`.
+ * Each snapshot gets its own file under
+ * `{DATA_PATH}/snapshots/<sanitized-repo-name>_<hash>/<sanitized-name>_<hash>.json`;
+ * the history is `history.json` in the same directory, which cannot collide
+ * with a snapshot file because those always carry a hash suffix.
+ *
+ * @module services/symbol-snapshot-store
+ */
+
+import { join, dirname } from "path";
+import { rename, unlink, mkdir } from "fs/promises";
+import type { Logger } from "pino";
+import { z } from "zod";
+import { getComponentLogger } from "../logging/index.js";
+import { sanitizeCollectionName } from "../repositories/metadata-store.js";
+
+/**
+ * Schema version literal for persisted snapshots.
+ */
+export const SYMBOL_SNAPSHOT_VERSION = "1.0";
+export type SymbolSnapshotVersion = typeof SYMBOL_SNAPSHOT_VERSION;
+
+/**
+ * Persisted symbol fingerprint of a repository at a point in time.
+ */
+export interface SymbolSnapshot {
+  /** Schema version (see `SYMBOL_SNAPSHOT_VERSION`). */
+  version: SymbolSnapshotVersion;
+  /** Repository name this snapshot belongs to. */
+  repository: string;
+  /** Snapshot name, unique per repository. */
+  name: string;
+  /** ISO 8601 timestamp of when the snapshot was taken. */
+  createdAt: string;
+  /** Commit the repository was indexed at when the snapshot was taken, if known. */
+  commitSha?: string;
+  /** Qualified symbol paths mapped to their signature hashes. */
+  symbols: Record<string, string>;
+}
+
+/**
+ * One recorded point of a repository's snapshot history.
+ */
+export interface SymbolHistoryEntry {
+  /** ISO 8601 timestamp of when the entry was recorded. */
+  recordedAt: string;
+  /** Commit the repository was indexed at, if known. */
+  commitSha?: string;
+  /** Digest of the whole fingerprint; equal digests mean no signature changed. */
+  digest: string;
+  /** Number of symbols fingerprinted. */
+  symbolCount: number;
+  /** Symbols added since the previous entry (0 for the first entry). */
+  added: number;
+  /** Symbols removed since the previous entry. */
+  removed: number;
+  /** Symbols whose signature changed since the previous entry. */
+  modified: number;
+}
+
+/**
+ * Persisted snapshot history of a repository.
+ */
+export interface SymbolHistory {
+  /** Schema version (see `SYMBOL_SNAPSHOT_VERSION`). */
+  version: SymbolSnapshotVersion;
+  /** Repository name this history belongs to. */
+  repository: string;
+  /** Recorded entries, oldest first. */
+  entries: SymbolHistoryEntry[];
+  /** Fingerprint of the latest entry, compared against by the next one. */
+  latestSymbols: Record<string, string>;
+}
+
+/**
+ * Service interface for persisting symbol snapshots.
+ */
+export interface SymbolSnapshotStore {
+  /**
+   * Load a named snapshot of a repository.
+   *
+   * Returns null when the snapshot does not exist or cannot be parsed.
+   *
+   * @param repository - Repository name (matches `RepositoryInfo.name`).
+   * @param name - Snapshot name.
+   */
+  loadSnapshot(repository: string, name: string): Promise<SymbolSnapshot | null>;
+
+  /**
+   * Persist a snapshot using an atomic temp-file + rename, replacing any
+   * snapshot of the same repository and name.
+   *
+   * @param snapshot - Snapshot to save, keyed by `repository` and `name`.
+   */
+  saveSnapshot(snapshot: SymbolSnapshot): Promise<void>;
+
+  /**
+   * Remove a named snapshot.
+   *
+   * Idempotent — succeeds with no error when the snapshot does not exist.
+   */
+  deleteSnapshot(repository: string, name: string): Promise<void>;
+
+  /**
+   * Load the snapshot history of a repository.
+   *
+   * Returns null when nothing was recorded yet or the file cannot be parsed.
+   *
+   * @param repository - Repository name (matches `RepositoryInfo.name`).
+   */
+  loadHistory(repository: string): Promise<SymbolHistory | null>;
+
+  /**
+   * Persist a snapshot history using an atomic temp-file + rename, replacing
+   * the previous history of the repository.
+   *
+   * @param history - History to save, keyed by `repository`.
+   */
+  saveHistory(history: SymbolHistory): Promise<void>;
+}
+
+/** Zod schema for the persisted snapshot file format (validated on read). */
+const SymbolSnapshotSchema = z.object({
+  version: z.literal(SYMBOL_SNAPSHOT_VERSION),
+  repository: z.string(),
+  name: z.string(),
+  createdAt: z.string(),
+  commitSha: z.string().optional(),
+  symbols: z.record(z.string(), z.string()),
+});
+
+/** Zod schema for the persisted history file format (validated on read). */
+const SymbolHistorySchema = z.object({
+  version: z.literal(SYMBOL_SNAPSHOT_VERSION),
+  repository: z.string(),
+  entries: z.array(
+    z.object({
+      recordedAt: z.string(),
+      commitSha: z.string().optional(),
+      digest: z.string(),
+      symbolCount: z.number(),
+      added: z.number(),
+      removed: z.number(),
+      modified: z.number(),
+    })
+  ),
+  latestSymbols: z.record(z.string(), z.string()),
+});
+
+/**
+ * Singleton implementation of the symbol snapshot store.
+ *
+ * Manages snapshot persistence under `{DATA_PATH}/snapshots/`.
+ */
+export class SymbolSnapshotStoreImpl implements SymbolSnapshotStore {
+  private static instance: SymbolSnapshotStoreImpl | null = null;
+
+  private readonly snapshotsDir: string;
+  private _logger: Logger | null = null;
+
+  private constructor(dataPath: string) {
+    this.snapshotsDir = join(dataPath, "snapshots");
+  }
+
+  /** Lazy-initialized component logger. */
+  private get logger(): Logger {
+    if (!this._logger) {
+      this._logger = getComponentLogger("services:symbol-snapshot-store");
+    }
+    return this._logger;
+  }
+
+  /**
+   * Get the singleton instance of the snapshot store.
+   *
+   * @param dataPath - Optional data directory (defaults to `process.env.DATA_PATH || "./data"`).
+   */
+  public static getInstance(dataPath?: string): SymbolSnapshotStoreImpl {
+    if (!SymbolSnapshotStoreImpl.instance) {
+      const resolvedPath = dataPath || process.env["DATA_PATH"] || "./data";
+      SymbolSnapshotStoreImpl.instance = new SymbolSnapshotStoreImpl(resolvedPath);
+    } else if (dataPath !== undefined) {
+      const logger = getComponentLogger("services:symbol-snapshot-store");
+      logger.warn(
+        { requestedPath: dataPath },
+        "getInstance called with dataPath after singleton already initialized - ignoring new path"
+      );
+    }
+    return SymbolSnapshotStoreImpl.instance;
+  }
+
+  /**
+   * Reset the singleton instance.
+   *
+   * **FOR TESTING ONLY**.
+   *
+   * @internal
+   */
+  public static resetInstance(): void {
+    SymbolSnapshotStoreImpl.instance = null;
+  }
+
+  /**
+   * Resolve the on-disk path for a named snapshot.
+   *
+   * Both the repository directory and the file name use the sanitized-name +
+   * hash-suffix scheme of `FileManifestStoreImpl.getManifestPath`, so names
+   * differing only in case do not collide.
+   */
+  public getSnapshotPath(repository: string, name: string): string {
+    return join(this.snapshotsDir, this.toFileStem(repository), `${this.toFileStem(name)}.json`);
+  }
+
+  /**
+   * Resolve the on-disk path of a repository's snapshot history.
+   */
+  public getHistoryPath(repository: string): string {
+    return join(this.snapshotsDir, this.toFileStem(repository), "history.json");
+  }
+
+  async loadSnapshot(repository: string, name: string): Promise<SymbolSnapshot | null> {
+    const filePath = this.getSnapshotPath(repository, name);
+    const file = Bun.file(filePath);
+
+    if (!(await file.exists())) {
+      return null;
+    }
+
+    try {
+      const parsed: unknown = JSON.parse(await file.text());
+      const snapshot = SymbolSnapshotSchema.parse(parsed);
+      this.logger.debug(
+        { filePath, repository, name, symbolCount: Object.keys(snapshot.symbols).length },
+        "Snapshot loaded from disk"
+      );
+      return snapshot;
+    } catch (error) {
+      this.logger.warn(
+        {
+          filePath,
+          repository,
+          name,
+          error: error instanceof Error ? error.message : String(error),
+        },
+        "Ignoring unreadable snapshot"
+      );
+      return null;
+    }
+  }
+
+  async saveSnapshot(snapshot: SymbolSnapshot): Promise<void> {
+    const filePath = this.getSnapshotPath(snapshot.repository, snapshot.name);
+    const tempPath = `${filePath}.tmp`;
+
+    await mkdir(dirname(filePath), { recursive: true });
+
+    try {
+      await Bun.write(tempPath, JSON.stringify(snapshot, null, 2));
+      await rename(tempPath, filePath);
+      this.logger.debug(
+        {
+          filePath,
+          repository: snapshot.repository,
+          name: snapshot.name,
+          symbolCount: Object.keys(snapshot.symbols).length,
+        },
+        "Snapshot saved to disk"
+      );
+    } catch (error) {
+      try {
+        await unlink(tempPath);
+      } catch {
+        // Ignore cleanup errors
+      }
+      throw error;
+    }
+  }
+
+  async deleteSnapshot(repository: string, name: string): Promise<void> {
+    const filePath = this.getSnapshotPath(repository, name);
+    try {
+      await unlink(filePath);
+      this.logger.debug({ filePath, repository, name }, "Snapshot deleted");
+    } catch (error) {
+      // Idempotent: missing file is not an error
+      if ((error as NodeJS.ErrnoException).code !== "ENOENT") {
+        throw error;
+      }
+    }
+  }
+
+  async loadHistory(repository: string): Promise<SymbolHistory | null> {
+    const filePath = this.getHistoryPath(repository);
+    const file = Bun.file(filePath);
+
+    if (!(await file.exists())) {
+      return null;
+    }
+
+    try {
+      const parsed: unknown = JSON.parse(await file.text());
+      const history = SymbolHistorySchema.parse(parsed);
+      this.logger.debug(
+        { filePath, repository, entryCount: history.entries.length },
+        "Snapshot history loaded from disk"
+      );
+      return history;
+    } catch (error) {
+      this.logger.warn(
+        {
+          filePath,
+          repository,
+          error: error instanceof Error ? error.message : String(error),
+        },
+        "Ignoring unreadable snapshot history"
+      );
+      return null;
+    }
+  }
+
+  async saveHistory(history: SymbolHistory): Promise<void> {
+    const filePath = this.getHistoryPath(history.repository);
+    const tempPath = `${filePath}.tmp`;
+
+    await mkdir(dirname(filePath), { recursive: true });
+
+    try {
+      await Bun.write(tempPath, JSON.stringify(history, null, 2));
+      await rename(tempPath, filePath);
+      this.logger.debug(
+        { filePath, repository: history.repository, entryCount: history.entries.length },
+        "Snapshot history saved to disk"
+      );
+    } catch (error) {
+      try {
+        await unlink(tempPath);
+      } catch {
+        // Ignore cleanup errors
+      }
+      throw error;
+    }
+  }
+
+  /** Sanitized name plus an 8-character hash of the original. */
+  private toFileStem(value: string): string {
+    const suffix = Bun.hash(value).toString(16).padStart(16, "0").substring(0, 8);
+    return `${sanitizeCollectionName(value)}_${suffix}`;
+  }
+}
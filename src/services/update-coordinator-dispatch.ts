@@ -14,7 +14,10 @@
  */
 
 import type { RepositoryInfo } from "../repositories/types.js";
-import type { CoordinatorResult } from "./incremental-update-coordinator-types.js";
+import type {
+  CoordinatorResult,
+  CoordinatorUpdateOptions,
+} from "./incremental-update-coordinator-types.js";
 import type { IncrementalUpdateCoordinator } from "./incremental-update-coordinator.js";
 import type { LocalFolderUpdateCoordinator } from "./local-folder-update-coordinator.js";
 
@@ -24,7 +27,10 @@ import type { LocalFolderUpdateCoordinator } from "./local-folder-update-coordin
  * this implicitly via duck typing.
  */
 export interface UpdateCoordinatorLike {
-  updateRepository(repositoryName: string): Promise<CoordinatorResult>;
+  updateRepository(
+    repositoryName: string,
+    options?: CoordinatorUpdateOptions
+  ): Promise<CoordinatorResult>;
 }
 
 /**
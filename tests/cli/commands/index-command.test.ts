@@ -167,6 +167,33 @@ describe("Index Command", () => {
     });
   });
 
+  describe("Resume options", () => {
+    it("should pass resume options and an abort signal to ingestion service", async () => {
+      mockIndexRepository.mockResolvedValue(createMockIndexResult());
+
+      const options: IndexCommandOptions = { resume: false, checkpointInterval: 2 };
+      await indexCommand("https://github.com/user/repo.git", options, mockDeps);
+
+      expect(mockIndexRepository).toHaveBeenCalledWith(
+        expect.any(String),
+        expect.objectContaining({
+          resume: false,
+          checkpointInterval: 2,
+          signal: expect.any(AbortSignal),
+        })
+      );
+    });
+
+    it("should stop listening for SIGINT once indexing finishes", async () => {
+      mockIndexRepository.mockResolvedValue(createMockIndexResult());
+      const listenersBefore = process.listenerCount("SIGINT");
+
+      await indexCommand("https://github.com/user/repo.git", {}, mockDeps);
+
+      expect(process.listenerCount("SIGINT")).toBe(listenersBefore);
+    });
+  });
+
   describe("Branch option", () => {
     it("should pass branch option to ingestion service", async () => {
       mockIndexRepository.mockResolvedValue(createMockIndexResult());
@@ -124,4 +124,50 @@ describe("IndexCommandOptionsSchema", () => {
       }
     });
   });
+
+  describe("resume options", () => {
+    it("should parse --no-resume and --checkpoint-interval", () => {
+      const result = IndexCommandOptionsSchema.safeParse({
+        resume: false,
+        checkpointInterval: "10",
+      });
+      expect(result.success).toBe(true);
+      if (result.success) {
+        expect(result.data.resume).toBe(false);
+        expect(result.data.checkpointInterval).toBe(10);
+      }
+    });
+
+    it("should leave checkpointInterval undefined when not provided", () => {
+      const result = IndexCommandOptionsSchema.safeParse({});
+      expect(result.success).toBe(true);
+      if (result.success) {
+        expect(result.data.checkpointInterval).toBeUndefined();
+      }
+    });
+
+    it("should reject a checkpoint interval below 1", () => {
+      const result = IndexCommandOptionsSchema.safeParse({ checkpointInterval: "0" });
+      expect(result.success).toBe(false);
+    });
+  });
+
+  describe("exclusion options", () => {
+    it("should parse --exclude globs together with --no-gitignore", () => {
+      const result = IndexCommandOptionsSchema.safeParse({
+        exclude: ["docs/**", "*.snap"],
+        gitignore: false,
+      });
+      expect(result.success).toBe(true);
+      if (result.success) {
+        expect(result.data.exclude).toEqual(["docs/**", "*.snap"]);
+        expect(result.data.gitignore).toBe(false);
+      }
+    });
+
+    it("should reject an empty exclude pattern", () => {
+      const result = IndexCommandOptionsSchema.safeParse({ exclude: [" "] });
+      expect(result.success).toBe(false);
+    });
+  });
 });
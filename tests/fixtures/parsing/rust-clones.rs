@@ -0,0 +1,49 @@
+//! Fixture for structural fingerprints: renamed copies, an edited copy and
+//! an unrelated function.
+
+pub struct Order {
+    pub price: u64,
+    pub quantity: u64,
+}
+
+pub fn order_total(orders: &[Order]) -> u64 {
+    let mut total = 0;
+    for order in orders {
+        if order.quantity > 0 {
+            total += order.price * order.quantity;
+        }
+    }
+    total
+}
+
+/// Renamed copy with different literals: an exact structural clone.
+pub fn shipment_weight(parcels: &[Order]) -> u64 {
+    // Comments do not count
+    let mut weight = 10;
+    for parcel in parcels {
+        if parcel.quantity > 1 {
+            weight += parcel.price * parcel.quantity;
+        }
+    }
+    weight
+}
+
+/// Copy with one extra statement: a near clone.
+pub fn order_total_capped(orders: &[Order], cap: u64) -> u64 {
+    let mut total = 0;
+    for order in orders {
+        if order.quantity > 0 {
+            total += order.price * order.quantity;
+        }
+    }
+    let capped = total.min(cap);
+    capped
+}
+
+pub fn parse_header(line: &str) -> Option<(String, String)> {
+    let (key, value) = line.split_once(':')?;
+    match key.trim() {
+        "" => None,
+        name => Some((name.to_lowercase(), value.trim().to_string())),
+    }
+}
@@ -0,0 +1,49 @@
+//! Fixture for approximate cyclomatic complexity.
+
+pub fn straight(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+pub fn classify(n: i32) -> &'static str {
+    if n < 0 {
+        "negative"
+    } else if n == 0 {
+        "zero"
+    } else {
+        "positive"
+    }
+}
+
+pub fn describe(value: Option<i32>) -> String {
+    match value {
+        Some(x) if x > 100 => "large".to_string(),
+        Some(x) => x.to_string(),
+        None => "none".to_string(),
+    }
+}
+
+pub fn load(path: &str, strict: bool, verbose: bool) -> std::io::Result<usize> {
+    let text = std::fs::read_to_string(path)?;
+    let mut count = 0;
+    for line in text.lines() {
+        while count < 10 && (strict || verbose) {
+            count += 1;
+        }
+    }
+    let check = |n: usize| if n > 5 { n } else { 0 };
+    fn helper(flag: bool) -> bool {
+        if flag { !flag } else { flag }
+    }
+    loop {
+        break;
+    }
+    Ok(check(count))
+}
+
+pub trait Scorer {
+    fn score(&self) -> u32;
+
+    fn boosted(&self) -> u32 {
+        if self.score() > 10 { 2 } else { 1 }
+    }
+}
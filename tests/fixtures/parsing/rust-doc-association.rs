@@ -0,0 +1,32 @@
+//! Fixture for doc-comment association around attributes and blank lines.
+
+/// A point documented above its derive attribute.
+#[derive(Debug, Clone)]
+pub struct DocBeforeDerive {
+    pub x: i32,
+}
+
+/// Documented with a blank line and two attributes before the item.
+
+#[derive(Debug)]
+#[allow(dead_code)]
+pub struct DocWithBlankLine;
+
+/// Too far from the item to be its documentation.
+
+
+pub fn far_from_doc() {}
+
+/// Belongs to nothing: unrelated code follows.
+const UNRELATED: u32 = 1;
+
+pub fn after_unrelated_code() -> u32 {
+    UNRELATED
+}
+
+pub fn has_trailing_comment() {} /// trailing note, not docs for the next item
+pub fn after_trailing_comment() {}
+
+#[inline]
+/// Documented after its attribute.
+pub fn attribute_then_doc() {}
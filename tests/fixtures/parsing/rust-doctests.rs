@@ -0,0 +1,39 @@
+/// Adds two numbers.
+///
+/// ```
+/// # use mycrate::add;
+/// let total = add(1, 2);
+/// assert_eq!(add(total, 3), 6);
+/// ```
+pub fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+/// Opens a connection.
+///
+/// ```no_run
+/// fn main() {
+///     let conn = Connection::open("db.sqlite");
+///     conn.close();
+/// }
+/// ```
+///
+/// ```text
+/// not_code(1)
+/// ```
+///
+/// ```ignore-wasm32,should_panic
+/// Connection::open("");
+/// ```
+pub struct Connection;
+
+/**
+ * Doubles a value.
+ *
+ * ~~~rust,compile_fail,E0308
+ * let x: u8 = double("2");
+ * ~~~
+ */
+pub fn double(x: u8) -> u8 {
+    x * 2
+}
@@ -0,0 +1,35 @@
+//! Fixture for entry point detection.
+
+struct Server;
+
+impl Server {
+    /// Associated function named main: not an entry point.
+    fn main() {}
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    Server::main();
+}
+
+fn helper() {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calls_helper() {
+        helper();
+    }
+
+    /// Runtime test with a doc comment between attributes.
+    #[tokio::test]
+    #[ignore]
+    async fn runs_async() {}
+
+    #[bench]
+    fn bench_helper(b: &mut test::Bencher) {
+        b.iter(helper);
+    }
+}
@@ -0,0 +1,42 @@
+//! Fixture: string literals that contain braces, quotes and raw delimiters.
+//! Each item's span must come from the parse tree, not from counting braces.
+
+pub const TEMPLATE: &str = "fn {name}() {{ }}";
+
+pub fn raw_pattern() -> &'static str {
+    r#"^\s*fn\s+(\w+)\s*\{"#
+}
+
+pub fn after_raw() -> usize {
+    raw_pattern().len()
+}
+
+pub fn hashes() -> &'static str {
+    r##"a "# still inside } { "##
+}
+
+pub fn escaped() -> String {
+    let open = '{';
+    let close = '}';
+    format!("\"{}\" {open}{close} \\\"", "}")
+}
+
+pub struct Parser {
+    pattern: String,
+}
+
+impl Parser {
+    pub fn new() -> Self {
+        Parser {
+            pattern: String::from(r"\{[^}]*\}"),
+        }
+    }
+
+    pub fn embedded(&self) -> &str {
+        "impl Foo { fn bar() { } }"
+    }
+}
+
+pub fn last() -> bool {
+    b"}{" == br#"}{"#
+}
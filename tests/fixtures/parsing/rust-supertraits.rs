@@ -0,0 +1,28 @@
+//! Fixture for trait supertrait extraction.
+
+use std::fmt::{Debug, Display};
+
+pub trait Animal {
+    fn name(&self) -> String;
+}
+
+pub trait Pet: Animal + Clone {
+    fn owner(&self) -> String;
+}
+
+pub trait Shown: std::fmt::Display + PartialEq<Self> {}
+
+pub trait Named
+where
+    Self: Debug + Animal,
+{
+    fn label(&self) -> String;
+}
+
+pub trait Handle: 'static + Send + ?Sized {}
+
+pub trait Loud: Pet
+where
+    Self: Display + Pet,
+{
+}
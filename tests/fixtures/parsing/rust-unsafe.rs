@@ -0,0 +1,40 @@
+//! Fixture for unsafe code detection.
+
+pub unsafe fn raw_copy(dst: *mut u8, src: *const u8, len: usize) {
+    std::ptr::copy_nonoverlapping(src, dst, len);
+}
+
+pub fn read_header(bytes: &[u8]) -> u32 {
+    let first = unsafe { *bytes.as_ptr() };
+    let rest = unsafe {
+        let ptr = bytes.as_ptr().add(1);
+        unsafe { *ptr }
+    };
+    u32::from(first) + u32::from(rest)
+}
+
+pub fn with_closure(values: &[i32]) -> i32 {
+    let get = |i: usize| unsafe { *values.get_unchecked(i) };
+
+    fn nested(ptr: *const i32) -> i32 {
+        unsafe { *ptr }
+    }
+
+    get(0) + nested(values.as_ptr())
+}
+
+pub fn safe_sum(values: &[i32]) -> i32 {
+    values.iter().sum()
+}
+
+pub unsafe trait Zeroable {}
+
+pub struct Handle(*mut u8);
+
+unsafe impl Send for Handle {}
+
+impl Handle {
+    pub unsafe fn from_raw(ptr: *mut u8) -> Self {
+        Handle(ptr)
+    }
+}
@@ -237,3 +237,21 @@ pub(crate) fn crate_visible_function() -> i32 {
 pub(super) fn super_visible_function() -> i32 {
     24
 }
+
+/// A generic wrapper used for conditional trait impls.
+pub struct Wrapper<T> {
+    pub inner: T,
+}
+
+impl<T> Animal for Wrapper<T>
+where
+    T: Animal,
+{
+    fn speak(&self) -> String {
+        self.inner.speak()
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+}
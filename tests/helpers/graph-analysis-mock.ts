@@ -0,0 +1,118 @@
+/**
+ * Test doubles for GraphAnalysisService and its graph adapter
+ *
+ * Provides:
+ * - A GraphStorageAdapter whose `runQuery` is routed through a responder
+ *   function, so service tests can return rows based on the Cypher text
+ * - A GraphAnalysisService whose methods throw unless overridden, so MCP
+ *   tool tests only stub the method under test
+ *
+ * @module tests/helpers/graph-analysis-mock
+ */
+
+import { mock } from "bun:test";
+import type { GraphStorageAdapter } from "../../src/graph/adapters/types.js";
+import type { GraphAnalysisService } from "../../src/services/graph-analysis-types.js";
+
+/**
+ * Responder invoked for every `runQuery` call
+ */
+export type CypherResponder = (
+  cypher: string,
+  params: Record<string, unknown>
+) => unknown[] | Promise<unknown[]>;
+
+/**
+ * Create a GraphStorageAdapter mock that answers `runQuery` via a responder
+ *
+ * @param responder - Returns rows for a Cypher query (defaults to no rows)
+ */
+export function createCypherMockAdapter(
+  responder: CypherResponder = () => []
+): GraphStorageAdapter & { runQuery: ReturnType<typeof mock> } {
+  const runQuery = mock((cypher: string, params?: Record<string, unknown>) =>
+    Promise.resolve(responder(cypher, params ?? {}))
+  );
+
+  return {
+    connect: mock(() => Promise.resolve()),
+    disconnect: mock(() => Promise.resolve()),
+    healthCheck: mock(() => Promise.resolve(true)),
+    runQuery,
+    upsertNode: mock(() => Promise.resolve({} as never)),
+    deleteNode: mock(() => Promise.resolve(true)),
+    createRelationship: mock(() => Promise.resolve({} as never)),
+    deleteRelationship: mock(() => Promise.resolve(true)),
+    traverse: mock(() =>
+      Promise.resolve({
+        nodes: [],
+        relationships: [],
+        metadata: { nodesCount: 0, relationshipsCount: 0, queryTimeMs: 0 },
+      })
+    ),
+    analyzeDependencies: mock(() =>
+      Promise.resolve({
+        direct: [],
+        transitive: [],
+        impactScore: 0,
+        metadata: { directCount: 0, transitiveCount: 0, queryTimeMs: 0 },
+      })
+    ),
+    getContext: mock(() =>
+      Promise.resolve({
+        context: [],
+        metadata: { seedsProcessed: 0, contextItemsFound: 0, queryTimeMs: 0 },
+      })
+    ),
+  } as GraphStorageAdapter & { runQuery: ReturnType<typeof mock> };
+}
+
+/**
+ * Create a GraphAnalysisService mock
+ *
+ * Every method rejects with "Not implemented in mock" unless overridden.
+ *
+ * @param overrides - Method implementations for the test at hand
+ */
+export function createMockGraphAnalysisService(
+  overrides: Partial<GraphAnalysisService> = {}
+): GraphAnalysisService {
+  const notImplemented = (): Promise<never> => Promise.reject(new Error("Not implemented in mock"));
+
+  return {
+    getSymbolImports: notImplemented,
+    findModuleCycles: notImplemented,
+    getTopLevelSymbols: notImplemented,
+    getDocumentSymbols: notImplemented,
+    listTodos: notImplemented,
+    searchSymbols: notImplemented,
+    searchByType: notImplemented,
+    listEntryPoints: notImplemented,
+    findByAttribute: notImplemented,
+    getDeprecationReport: notImplemented,
+    listUnsafe: notImplemented,
+    listComplex: notImplemented,
+    findSimilarFunctions: notImplemented,
+    findConstsByValueRange: notImplemented,
+    findDoctests: notImplemented,
+    listMissingDocs: notImplemented,
+    resolveName: notImplemented,
+    getTopologicalOrder: notImplemented,
+    getLocalBindings: notImplemented,
+    findLargeTypes: notImplemented,
+    getSymbolsAtLines: notImplemented,
+    findExternalDependencies: notImplemented,
+    findFunctionsByArity: notImplemented,
+    findCallSites: notImplemented,
+    suggestImport: notImplemented,
+    findLeakyApi: notImplemented,
+    grepStrings: notImplemented,
+    getCouplingMetrics: notImplemented,
+    findMostCoupled: notImplemented,
+    findHotspots: notImplemented,
+    getCouplingReport: notImplemented,
+    findCallPaths: notImplemented,
+    getTypeHierarchy: notImplemented,
+    ...overrides,
+  };
+}
@@ -96,6 +96,16 @@ describe("GitignoreFilter", () => {
     expect(filter.isIgnored("C:/some/other/path/file.ts")).toBe(true);
   });
 
+  it("empty() ignores nothing inside the root but still rejects escapes", async () => {
+    await writeFile(join(testDir, ".gitignore"), "*.log\n");
+
+    const filter = GitignoreFilter.empty(testDir);
+
+    expect(filter.isIgnored(join(testDir, "app.log"))).toBe(false);
+    expect(filter.isIgnored("C:/some/other/path/file.ts")).toBe(true);
+    expect(filter.ruleFileCount).toBe(0);
+  });
+
   it("filterAbsolute returns only paths that survive the filter", async () => {
     await writeFile(join(testDir, ".gitignore"), "node_modules/\n");
     await mkdir(join(testDir, "node_modules"), { recursive: true });
@@ -0,0 +1,202 @@
+/**
+ * Tests for MCP Tool Concurrency Limiter
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  ToolConcurrencyLimiter,
+  ConcurrencyLimitError,
+  DEFAULT_TOOL_CONCURRENCY_CONFIG,
+  loadToolConcurrencyConfig,
+} from "../../src/mcp/concurrency-limiter.js";
+import { initializeLogger, resetLogger } from "../../src/logging/index.js";
+
+/**
+ * Create an operation that stays in flight until released
+ */
+function deferred(): { promise: Promise<string>; resolve: (value: string) => void } {
+  let resolve: (value: string) => void = () => {};
+  const promise = new Promise<string>((r) => {
+    resolve = r;
+  });
+  return { promise, resolve };
+}
+
+describe("ToolConcurrencyLimiter", () => {
+  beforeEach(() => {
+    try {
+      initializeLogger({ level: "silent", format: "json" });
+    } catch {
+      // Logger already initialized
+    }
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should run invocations immediately while slots are free", async () => {
+    const limiter = new ToolConcurrencyLimiter({ maxConcurrent: 2 });
+
+    const result = await limiter.run("semantic_search", () => Promise.resolve("ok"));
+
+    expect(result).toBe("ok");
+    expect(limiter.getStats().general).toEqual({ active: 0, queued: 0, limit: 2 });
+  });
+
+  it("should queue invocations beyond the limit and run them in order", async () => {
+    const limiter = new ToolConcurrencyLimiter({ maxConcurrent: 1 });
+    const first = deferred();
+    const order: string[] = [];
+
+    const running = limiter.run("semantic_search", async () => {
+      order.push("first");
+      return first.promise;
+    });
+    const queued = limiter.run("semantic_search", () => {
+      order.push("second");
+      return Promise.resolve("second");
+    });
+
+    await new Promise((resolve) => setTimeout(resolve, 0));
+    expect(limiter.getStats().general).toEqual({ active: 1, queued: 1, limit: 1 });
+    expect(order).toEqual(["first"]);
+
+    first.resolve("first");
+    expect(await running).toBe("first");
+    expect(await queued).toBe("second");
+    expect(order).toEqual(["first", "second"]);
+    expect(limiter.getStats().general.active).toBe(0);
+  });
+
+  it("should reject invocations when the queue is full", async () => {
+    const limiter = new ToolConcurrencyLimiter({ maxConcurrent: 1, maxQueued: 1 });
+    const blocker = deferred();
+
+    const running = limiter.run("semantic_search", () => blocker.promise);
+    const queued = limiter.run("semantic_search", () => Promise.resolve("queued"));
+
+    await expect(limiter.run("semantic_search", () => Promise.resolve("x"))).rejects.toThrow(
+      ConcurrencyLimitError
+    );
+
+    blocker.resolve("done");
+    await running;
+    expect(await queued).toBe("queued");
+  });
+
+  it("should reject invocations that wait longer than the queue timeout", async () => {
+    const limiter = new ToolConcurrencyLimiter({ maxConcurrent: 1, queueTimeoutMs: 20 });
+    const blocker = deferred();
+
+    const running = limiter.run("semantic_search", () => blocker.promise);
+
+    try {
+      await limiter.run("semantic_search", () => Promise.resolve("late"));
+      expect.unreachable();
+    } catch (error) {
+      expect(error).toBeInstanceOf(ConcurrencyLimitError);
+      expect((error as ConcurrencyLimitError).reason).toBe("queue_timeout");
+    }
+    expect(limiter.getStats().general.queued).toBe(0);
+
+    blocker.resolve("done");
+    await running;
+  });
+
+  it("should drop queued invocations whose request is cancelled", async () => {
+    const limiter = new ToolConcurrencyLimiter({ maxConcurrent: 1 });
+    const blocker = deferred();
+    const controller = new AbortController();
+    let ran = false;
+
+    const running = limiter.run("semantic_search", () => blocker.promise);
+    const cancelled = limiter.run(
+      "semantic_search",
+      () => {
+        ran = true;
+        return Promise.resolve("cancelled");
+      },
+      controller.signal
+    );
+    await new Promise((resolve) => setTimeout(resolve, 0));
+    expect(limiter.getStats().general.queued).toBe(1);
+
+    controller.abort();
+
+    try {
+      await cancelled;
+      expect.unreachable();
+    } catch (error) {
+      expect((error as ConcurrencyLimitError).reason).toBe("cancelled");
+    }
+    expect(limiter.getStats().general.queued).toBe(0);
+
+    blocker.resolve("done");
+    await running;
+    expect(ran).toBe(false);
+    expect(limiter.getStats().general.active).toBe(0);
+  });
+
+  it("should reject invocations whose request is already cancelled", async () => {
+    const limiter = new ToolConcurrencyLimiter({ maxConcurrent: 1 });
+
+    await expect(
+      limiter.run("semantic_search", () => Promise.resolve("x"), AbortSignal.abort())
+    ).rejects.toThrow(/cancelled/);
+    expect(limiter.getStats().general.active).toBe(0);
+  });
+
+  it("should release the slot when an invocation throws", async () => {
+    const limiter = new ToolConcurrencyLimiter({ maxConcurrent: 1 });
+
+    await expect(
+      limiter.run("semantic_search", () => Promise.reject(new Error("boom")))
+    ).rejects.toThrow("boom");
+
+    expect(await limiter.run("semantic_search", () => Promise.resolve("next"))).toBe("next");
+  });
+
+  it("should not let long-running tools take general slots", async () => {
+    const limiter = new ToolConcurrencyLimiter({ maxConcurrent: 1 });
+    const reindex = deferred();
+
+    const running = limiter.run("trigger_incremental_update", () => reindex.promise);
+
+    expect(limiter.getLane("trigger_incremental_update")).toBe("long_running");
+    expect(await limiter.run("semantic_search", () => Promise.resolve("fast"))).toBe("fast");
+    expect(limiter.getStats().long_running.active).toBe(1);
+
+    reindex.resolve("done");
+    await running;
+  });
+});
+
+describe("loadToolConcurrencyConfig", () => {
+  const saved = { ...process.env };
+
+  afterEach(() => {
+    process.env = { ...saved };
+  });
+
+  it("should use defaults when no environment variables are set", () => {
+    delete process.env["MCP_MAX_CONCURRENT_TOOLS"];
+    delete process.env["MCP_MAX_QUEUED_TOOLS"];
+    delete process.env["MCP_TOOL_QUEUE_TIMEOUT_MS"];
+    delete process.env["MCP_MAX_CONCURRENT_LONG_RUNNING_TOOLS"];
+
+    expect(loadToolConcurrencyConfig()).toEqual(DEFAULT_TOOL_CONCURRENCY_CONFIG);
+  });
+
+  it("should read limits from environment variables and ignore invalid values", () => {
+    process.env["MCP_MAX_CONCURRENT_TOOLS"] = "3";
+    process.env["MCP_MAX_QUEUED_TOOLS"] = "zero";
+    process.env["MCP_MAX_CONCURRENT_LONG_RUNNING_TOOLS"] = "0";
+
+    const config = loadToolConcurrencyConfig();
+
+    expect(config.maxConcurrent).toBe(3);
+    expect(config.maxQueued).toBe(DEFAULT_TOOL_CONCURRENCY_CONFIG.maxQueued);
+    expect(config.maxConcurrentLongRunning).toBe(1);
+  });
+});
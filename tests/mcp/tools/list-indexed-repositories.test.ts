@@ -108,9 +108,11 @@ describe("listIndexedRepositoriesToolDefinition definition", () => {
     }
   });
 
-  it("should have empty input schema (no required parameters)", () => {
+  it("should have only the optional absolute_paths parameter", () => {
     expect(listIndexedRepositoriesToolDefinition.inputSchema.type).toBe("object");
-    expect(listIndexedRepositoriesToolDefinition.inputSchema.properties).toEqual({});
+    expect(
+      Object.keys(listIndexedRepositoriesToolDefinition.inputSchema.properties ?? {})
+    ).toEqual(["absolute_paths"]);
     expect(listIndexedRepositoriesToolDefinition.inputSchema.required).toEqual([]);
   });
 });
@@ -381,6 +383,45 @@ describe("createListRepositoriesHandler", () => {
       expect(gitResp?.local_path).toBe("/Users/dev/projects/my-app");
     });
 
+    it("emits local_path relative to the project root unless absolute_paths is set", async () => {
+      const inside = createMockRepo("app", 1, 1, {
+        source: "local-git",
+        url: null,
+        localPath: "/Users/dev/projects/my-app",
+      });
+      const outside = createMockRepo("notes", 1, 1, {
+        source: "local-folder",
+        url: null,
+        localPath: "/Volumes/shared/notes",
+      });
+      mockRepositoryService.listRepositories = mock(() => Promise.resolve([inside, outside]));
+      const rootedHandler = createListRepositoriesHandler(
+        mockRepositoryService,
+        "/Users/dev/projects"
+      );
+
+      const relative = parseResponse(getTextContent((await rootedHandler({})).content));
+      const absolute = parseResponse(
+        getTextContent((await rootedHandler({ absolute_paths: true })).content)
+      );
+
+      expect(relative.repositories.map((r) => r.local_path)).toEqual([
+        "my-app",
+        "/Volumes/shared/notes",
+      ]);
+      expect(absolute.repositories.map((r) => r.local_path)).toEqual([
+        "/Users/dev/projects/my-app",
+        "/Volumes/shared/notes",
+      ]);
+    });
+
+    it("rejects a non-boolean absolute_paths", async () => {
+      const result = await handler({ absolute_paths: "yes" });
+
+      expect(result.isError).toBe(true);
+      expect(getTextContent(result.content)).toContain("absolute_paths");
+    });
+
     it("surfaces doc_graph_coverage when populated and omits it otherwise (Phase D / #567)", async () => {
       const withDocs = createMockRepo("withdocs", 1, 1, {
         docGraphCoverage: ["markdown", "pdf"],
@@ -82,12 +82,12 @@ describe("list_watched_folders Tool", () => {
       expect(listWatchedFoldersToolDefinition.description!.length).toBeGreaterThan(30);
     });
 
-    it("should define input schema with no properties", () => {
+    it("should define input schema with only the absolute_paths flag", () => {
       expect(listWatchedFoldersToolDefinition.inputSchema).toBeDefined();
       expect(listWatchedFoldersToolDefinition.inputSchema.type).toBe("object");
       expect(
         Object.keys(listWatchedFoldersToolDefinition.inputSchema.properties || {})
-      ).toHaveLength(0);
+      ).toEqual(["absolute_paths"]);
     });
 
     it("should not require any parameters", () => {
@@ -241,6 +241,40 @@ describe("list_watched_folders Tool", () => {
 
         expect(result.isError).toBe(false);
       });
+
+      it("should emit folder paths relative to the project root unless absolute_paths is set", async () => {
+        const folder = {
+          id: "folder-1",
+          name: "Study Notes",
+          enabled: true,
+          documentCount: 1,
+          imageCount: 0,
+          watcherStatus: "active" as const,
+          includePatterns: [],
+          excludePatterns: [],
+        };
+        mockService.setMockResponse({
+          folders: [
+            { ...folder, path: "/home/user/projects/notes" },
+            { ...folder, id: "folder-2", path: "/mnt/archive" },
+          ],
+        });
+        const handler = createListWatchedFoldersHandler(mockService, "/home/user/projects");
+
+        const relative = JSON.parse(
+          ((await handler({})).content[0] as TextContent).text
+        ) as ListWatchedFoldersResponseJSON;
+        const absolute = JSON.parse(
+          ((await handler({ absolute_paths: true })).content[0] as TextContent).text
+        ) as ListWatchedFoldersResponseJSON;
+
+        // Paths outside the root are left absolute
+        expect(relative.folders.map((f) => f.path)).toEqual(["notes", "/mnt/archive"]);
+        expect(absolute.folders.map((f) => f.path)).toEqual([
+          "/home/user/projects/notes",
+          "/mnt/archive",
+        ]);
+      });
     });
 
     describe("error handling", () => {
@@ -0,0 +1,285 @@
+/**
+ * Unit tests for the reindex MCP tool.
+ *
+ * Covers: argument validation, unknown repositories, the completion summary
+ * (symbols indexed / files skipped / errors), progress notification
+ * forwarding, cancellation, and the single-flight "already in progress" guard
+ * in both the handler and the admission check.
+ *
+ * @module tests/mcp/tools/reindex
+ */
+
+/* eslint-disable @typescript-eslint/no-explicit-any */
+/* eslint-disable @typescript-eslint/no-unsafe-assignment */
+/* eslint-disable @typescript-eslint/no-unsafe-member-access */
+
+import { describe, it, expect, beforeEach, afterEach, mock } from "bun:test";
+import type { TextContent } from "@modelcontextprotocol/sdk/types.js";
+import {
+  reindexToolDefinition,
+  createReindexHandler,
+  createReindexAdmission,
+  createReindexFlight,
+} from "../../../src/mcp/tools/reindex.js";
+import { initializeLogger, resetLogger } from "../../../src/logging/index.js";
+import {
+  IndexingInProgressError,
+  IndexingCancelledError,
+} from "../../../src/services/ingestion-errors.js";
+import type { IngestionService } from "../../../src/services/ingestion-service.js";
+import type { RepositoryMetadataService, RepositoryInfo } from "../../../src/repositories/types.js";
+import type { IndexOptions, IndexResult } from "../../../src/services/ingestion-types.js";
+
+const REPO: RepositoryInfo = {
+  name: "my-api",
+  source: "git-remote",
+  url: "https://github.com/user/my-api.git",
+  localPath: "./data/repos/my-api",
+  collectionName: "repo_my_api",
+  fileCount: 3,
+  chunkCount: 12,
+  lastIndexedAt: new Date().toISOString(),
+  indexDurationMs: 100,
+  status: "ready",
+  branch: "main",
+  includeExtensions: [".rs"],
+  excludePatterns: [],
+  tier: "work",
+};
+
+function successResult(): IndexResult {
+  return {
+    status: "partial",
+    repository: "my-api",
+    collectionName: "repo_my_api",
+    stats: {
+      filesScanned: 4,
+      filesProcessed: 3,
+      filesFailed: 1,
+      filesSkipped: 2,
+      symbolsIndexed: 42,
+      chunksCreated: 12,
+      embeddingsGenerated: 12,
+      documentsStored: 12,
+      durationMs: 250.4,
+    },
+    errors: [{ type: "file_error", filePath: "src/bad.rs", message: "read failed" }],
+    completedAt: new Date(),
+  };
+}
+
+function makeMetadataStub(repo?: RepositoryInfo): RepositoryMetadataService {
+  return {
+    getRepository: mock(async (name: string) => (repo && repo.name === name ? repo : null)),
+  } as unknown as RepositoryMetadataService;
+}
+
+function parseTextResponse(content: TextContent[]): Record<string, unknown> {
+  const text = content[0]?.type === "text" ? content[0].text : "";
+  return JSON.parse(text) as Record<string, unknown>;
+}
+
+describe("reindex MCP tool", () => {
+  beforeEach(() => initializeLogger({ level: "silent", format: "json" }));
+  afterEach(() => resetLogger());
+
+  it("declares the expected name and required `repository` argument", () => {
+    expect(reindexToolDefinition.name).toBe("reindex");
+    expect(reindexToolDefinition.inputSchema.required).toEqual(["repository"]);
+  });
+
+  it("rejects a missing repository argument", async () => {
+    const handler = createReindexHandler({
+      ingestionService: { indexRepository: mock() } as unknown as IngestionService,
+      repositoryService: makeMetadataStub(REPO),
+    });
+
+    const result = await handler({});
+
+    expect(result.isError).toBe(true);
+    expect(parseTextResponse(result.content as TextContent[])["error"]).toBe("invalid_argument");
+  });
+
+  it("returns repository_not_found for unknown repositories", async () => {
+    const indexRepository = mock();
+    const handler = createReindexHandler({
+      ingestionService: { indexRepository } as unknown as IngestionService,
+      repositoryService: makeMetadataStub(REPO),
+    });
+
+    const result = await handler({ repository: "missing" });
+
+    expect(result.isError).toBe(true);
+    expect(parseTextResponse(result.content as TextContent[])["error"]).toBe(
+      "repository_not_found"
+    );
+    expect(indexRepository).not.toHaveBeenCalled();
+  });
+
+  it("forces a full rebuild and returns a summary", async () => {
+    const indexRepository = mock(async () => successResult());
+    const handler = createReindexHandler({
+      ingestionService: { indexRepository } as unknown as IngestionService,
+      repositoryService: makeMetadataStub(REPO),
+    });
+
+    const result = await handler({ repository: "my-api" });
+
+    expect(result.isError).toBe(false);
+    const [source, options] = (indexRepository as any).mock.calls[0];
+    expect(source).toBe(REPO.url);
+    expect(options).toMatchObject({ name: "my-api", branch: "main", force: true, tier: "work" });
+
+    const body = parseTextResponse(result.content as TextContent[]);
+    expect(body["status"]).toBe("partial");
+    expect(body["symbols_indexed"]).toBe(42);
+    expect(body["files_skipped"]).toBe(2);
+    expect(body["files_failed"]).toBe(1);
+    expect(body["duration_ms"]).toBe(250);
+    expect(body["errors"]).toEqual(["src/bad.rs: read failed"]);
+  });
+
+  it("forwards files processed / total as progress notifications", async () => {
+    const indexRepository = mock(async (_url: string, options: IndexOptions) => {
+      options.onProgress?.({
+        phase: "scanning",
+        repository: "my-api",
+        percentage: 15,
+        details: { filesScanned: 4 },
+        timestamp: new Date(),
+      });
+      options.onProgress?.({
+        phase: "chunking",
+        repository: "my-api",
+        percentage: 25,
+        details: { filesProcessed: 0, totalFiles: 4 },
+        timestamp: new Date(),
+      });
+      return successResult();
+    });
+    const reportProgress = mock();
+    const handler = createReindexHandler({
+      ingestionService: { indexRepository } as unknown as IngestionService,
+      repositoryService: makeMetadataStub(REPO),
+    });
+
+    await handler({ repository: "my-api" }, { reportProgress });
+
+    // Scanning events carry no file totals and are not forwarded
+    expect(reportProgress.mock.calls).toEqual([
+      [0, 4, "chunking: 0/4 files"],
+      [4, 4, "complete"],
+    ]);
+  });
+
+  it("rejects a second reindex while one is running", async () => {
+    let release: () => void = () => {};
+    const gate = new Promise<void>((resolve) => {
+      release = resolve;
+    });
+    const indexRepository = mock(async () => {
+      await gate;
+      return successResult();
+    });
+    const handler = createReindexHandler({
+      ingestionService: { indexRepository } as unknown as IngestionService,
+      repositoryService: makeMetadataStub(REPO),
+    });
+
+    const first = handler({ repository: "my-api" });
+    const second = await handler({ repository: "my-api" });
+
+    expect(second.isError).toBe(true);
+    const body = parseTextResponse(second.content as TextContent[]);
+    expect(body["error"]).toBe("reindex_in_progress");
+    expect(String(body["message"])).toContain("already in progress");
+
+    release();
+    expect((await first).isError).toBe(false);
+
+    // Guard is released once the first run finishes
+    expect((await handler({ repository: "my-api" })).isError).toBe(false);
+  });
+
+  it("rejects a duplicate at admission while one is queued or running", async () => {
+    let release: () => void = () => {};
+    const gate = new Promise<void>((resolve) => {
+      release = resolve;
+    });
+    const flight = createReindexFlight();
+    const admit = createReindexAdmission(flight);
+    const handler = createReindexHandler(
+      {
+        ingestionService: {
+          indexRepository: mock(async () => {
+            await gate;
+            return successResult();
+          }),
+        } as unknown as IngestionService,
+        repositoryService: makeMetadataStub(REPO),
+      },
+      flight
+    );
+
+    // First call is admitted and would now wait for a long-running slot
+    const first = admit({ repository: "my-api" });
+    expect("release" in first).toBe(true);
+
+    const queued = admit({ repository: "my-api" });
+    if (!("rejected" in queued)) throw new Error("expected a rejection while queued");
+    const body = parseTextResponse(queued.rejected.content as TextContent[]);
+    expect(body["error"]).toBe("reindex_in_progress");
+    expect(String(body["message"])).toContain("'my-api'");
+
+    // Still rejected once the admitted call is running
+    const running = handler({ repository: "my-api" });
+    expect("rejected" in admit({ repository: "my-api" })).toBe(true);
+
+    release();
+    expect((await running).isError).toBe(false);
+    if ("release" in first) first.release();
+
+    expect("release" in admit({ repository: "my-api" })).toBe(true);
+  });
+
+  it("maps a busy ingestion service to reindex_in_progress", async () => {
+    const handler = createReindexHandler({
+      ingestionService: {
+        indexRepository: mock(async () => {
+          throw new IndexingInProgressError("other-repo");
+        }),
+      } as unknown as IngestionService,
+      repositoryService: makeMetadataStub(REPO),
+    });
+
+    const result = await handler({ repository: "my-api" });
+
+    expect(result.isError).toBe(true);
+    expect(parseTextResponse(result.content as TextContent[])["error"]).toBe("reindex_in_progress");
+  });
+
+  it("passes the request signal through and maps cancellation to reindex_cancelled", async () => {
+    const controller = new AbortController();
+    const indexRepository = mock(async (_url: string, options: IndexOptions) => {
+      controller.abort();
+      if (options.signal?.aborted) {
+        throw new IndexingCancelledError("my-api");
+      }
+      return successResult();
+    });
+    const handler = createReindexHandler({
+      ingestionService: { indexRepository } as unknown as IngestionService,
+      repositoryService: makeMetadataStub(REPO),
+    });
+
+    const result = await handler({ repository: "my-api" }, { signal: controller.signal });
+
+    expect(result.isError).toBe(true);
+    const body = parseTextResponse(result.content as TextContent[]);
+    expect(body["error"]).toBe("reindex_cancelled");
+    expect(String(body["message"])).toContain("run reindex again");
+
+    // Guard is released after a cancelled run
+    expect((await handler({ repository: "my-api" })).isError).toBe(false);
+  });
+});
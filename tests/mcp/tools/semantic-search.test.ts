@@ -54,6 +54,7 @@ interface SemanticSearchResponse {
     embedding_time_ms: number;
     search_time_ms: number;
     repositories_searched: string[];
+    threshold_applied?: number;
     warnings?: Array<{ type: string; repository: string; message: string }>;
   };
 }
@@ -77,6 +78,7 @@ interface MergedSearchResponse {
     search_time_ms: number;
     repositories_searched: string[];
     document_folders_searched?: string[];
+    threshold_applied?: number;
     warnings?: string[];
   };
 }
@@ -479,6 +481,51 @@ describe("semantic_search Tool", () => {
 
         expect(responseData.metadata.warnings).toBeUndefined();
       });
+
+      it("should render Markdown with language-tagged code fences when requested", async () => {
+        mockService.setMockResponse({
+          results: [
+            {
+              file_path: "src/lib.rs",
+              repository: "backend-api",
+              content_snippet: "pub fn authenticate(token: &str) -> bool {\n    true\n}",
+              similarity_score: 0.9123,
+              chunk_index: 2,
+              metadata: {
+                file_extension: ".rs",
+                file_size_bytes: 512,
+                indexed_at: "2025-01-01T00:00:00Z",
+              },
+            },
+          ],
+          metadata: {
+            total_matches: 1,
+            query_time_ms: 20,
+            embedding_time_ms: 10,
+            search_time_ms: 10,
+            repositories_searched: ["backend-api"],
+          },
+        });
+        const handler = createSemanticSearchHandler(mockService);
+
+        const result = await handler({ query: "authenticate", output_format: "markdown" });
+
+        expect(result.isError).toBe(false);
+        const text = (result.content[0] as TextContent).text;
+        expect(text).toContain("## 1. `src/lib.rs` (backend-api)");
+        expect(text).toContain("- **score:** 0.912");
+        expect(text).toContain("```rust\npub fn authenticate(token: &str) -> bool {");
+        expect(mockService.lastQuery).not.toHaveProperty("output_format");
+      });
+
+      it("should reject an unknown output_format", async () => {
+        const handler = createSemanticSearchHandler(mockService);
+
+        const result = await handler({ query: "test", output_format: "html" });
+
+        expect(result.isError).toBe(true);
+        expect(mockService.callCount).toBe(0);
+      });
     });
 
     describe("error handling", () => {
@@ -620,6 +667,28 @@ describe("semantic_search Tool", () => {
         expect(result.isError).toBe(true);
         expect((result.content[0] as { text: string }).text).toContain("Error");
       });
+
+      it("should echo the default threshold in metadata", async () => {
+        const handler = createSemanticSearchHandler(mockService);
+
+        const result = await handler({ query: "test" });
+
+        const responseData = JSON.parse(
+          (result.content[0] as TextContent).text
+        ) as SemanticSearchResponse;
+        expect(responseData.metadata.threshold_applied).toBe(0.7);
+      });
+
+      it("should echo a custom threshold in metadata", async () => {
+        const handler = createSemanticSearchHandler(mockService);
+
+        const result = await handler({ query: "test", threshold: 0.85 });
+
+        const responseData = JSON.parse(
+          (result.content[0] as TextContent).text
+        ) as SemanticSearchResponse;
+        expect(responseData.metadata.threshold_applied).toBe(0.85);
+      });
     });
   });
 
@@ -923,6 +992,27 @@ describe("semantic_search Tool", () => {
       expect(responseData.metadata.total_matches).toBe(3);
     });
 
+    it("should drop merged results below the threshold", async () => {
+      mockSearchService.setMockResponse(codeResult);
+      mockDocSearchService.setMockResponse(docResult);
+      const handler = createSemanticSearchHandler(mockSearchService, mockDocSearchService);
+
+      const result = await handler({
+        query: "authentication",
+        threshold: 0.9,
+        include_documents: true,
+      });
+
+      const responseData = JSON.parse(
+        (result.content[0] as TextContent).text
+      ) as MergedSearchResponse;
+
+      // Code result scores 0.92, document result 0.88
+      expect(responseData.results).toHaveLength(1);
+      expect(responseData.results[0]!.source_type).toBe("code");
+      expect(responseData.metadata.threshold_applied).toBe(0.9);
+    });
+
     it("should call both services in parallel", async () => {
       mockSearchService.setMockResponse(codeResult);
       mockDocSearchService.setMockResponse(docResult);
@@ -16,6 +16,7 @@ import {
 import { MCPRateLimiter } from "../../../src/mcp/rate-limiter.js";
 import { JobTracker } from "../../../src/mcp/job-tracker.js";
 import { initializeLogger, resetLogger } from "../../../src/logging/index.js";
+import { IndexingCancelledError } from "../../../src/services/ingestion-errors.js";
 
 /**
  * Response type interfaces for type-safe JSON parsing
@@ -529,5 +530,33 @@ describe("createTriggerUpdateHandler", () => {
       const response = JSON.parse(getTextContent(result.content)) as ErrorResponse;
       expect(response.error).toBe("timeout");
     });
+
+    it("should stop a synchronous update when the request is cancelled", async () => {
+      const controller = new AbortController();
+      let received: AbortSignal | undefined;
+      let started!: () => void;
+      const running = new Promise<void>((resolve) => (started = resolve));
+      mockUpdateCoordinator.updateRepository = mock(
+        (_name: string, options?: { signal?: AbortSignal }) =>
+          new Promise<CoordinatorResult>((_, reject) => {
+            received = options?.signal;
+            received?.addEventListener("abort", () =>
+              reject(new IndexingCancelledError("test-repo"))
+            );
+            started();
+          })
+      );
+
+      const pending = handler({ repository: "test-repo" }, { signal: controller.signal });
+      await running;
+      controller.abort();
+      const result = await pending;
+
+      expect(received?.aborted).toBe(true);
+      expect(result.isError).toBe(true);
+      const response = JSON.parse(getTextContent(result.content)) as ErrorResponse;
+      expect(response.error).toBe("update_cancelled");
+      expect(rateLimiter.isInProgress("test-repo")).toBe(false);
+    });
   });
 });
@@ -0,0 +1,42 @@
+/**
+ * Unit tests for the result field projection shared by list-style tools.
+ *
+ * @module tests/mcp/tools/utils/field-projection
+ */
+
+import { describe, it, expect } from "bun:test";
+import { fieldsProperty, projectFields } from "../../../../src/mcp/tools/utils/field-projection.js";
+
+describe("field projection helpers", () => {
+  describe("projectFields", () => {
+    const record = { name: "scale", file_path: "src/scale.ts", start_line: 3, score: 0.8 };
+
+    it("should return the record unchanged without a projection", () => {
+      expect(projectFields(record, undefined)).toBe(record);
+    });
+
+    it("should keep the requested keys in record order", () => {
+      expect(projectFields(record, ["start_line", "name"])).toEqual({
+        name: "scale",
+        start_line: 3,
+      });
+      expect(Object.keys(projectFields(record, ["start_line", "name"]))).toEqual([
+        "name",
+        "start_line",
+      ]);
+    });
+
+    it("should leave requested keys missing from the record absent", () => {
+      expect(projectFields(record, ["name", "where_clause"])).toEqual({ name: "scale" });
+    });
+  });
+
+  describe("fieldsProperty", () => {
+    it("should enumerate the selectable fields", () => {
+      const property = fieldsProperty(["name", "file_path"]);
+
+      expect(property["type"]).toBe("array");
+      expect(property["items"]).toEqual({ type: "string", enum: ["name", "file_path"] });
+    });
+  });
+});
@@ -0,0 +1,65 @@
+/**
+ * Unit tests for the Markdown rendering helpers shared by tools that accept
+ * `output_format: "markdown"`.
+ *
+ * @module tests/mcp/tools/utils/markdown
+ */
+
+import { describe, it, expect } from "bun:test";
+import {
+  codeFence,
+  fenceLanguage,
+  formatDetails,
+  formatLocation,
+  formatParams,
+} from "../../../../src/mcp/tools/utils/markdown.js";
+
+describe("markdown helpers", () => {
+  describe("fenceLanguage", () => {
+    it("should use the parser language for source files", () => {
+      expect(fenceLanguage("src/lib.rs")).toBe("rust");
+      expect(fenceLanguage("src/App.TSX")).toBe("tsx");
+      expect(fenceLanguage("include/util.hpp")).toBe("cpp");
+    });
+
+    it("should fall back to the extension or no tag", () => {
+      expect(fenceLanguage("config/app.yaml")).toBe("yaml");
+      expect(fenceLanguage("Makefile")).toBe("");
+      expect(fenceLanguage("some.dir/README")).toBe("");
+    });
+  });
+
+  describe("codeFence", () => {
+    it("should wrap content with a language tag", () => {
+      expect(codeFence("let x = 1;\n", "rust")).toBe("```rust\nlet x = 1;\n```");
+    });
+
+    it("should lengthen the fence past embedded backtick runs", () => {
+      expect(codeFence("```ts\nx\n```", "markdown")).toBe("````markdown\n```ts\nx\n```\n````");
+    });
+  });
+
+  describe("formatLocation", () => {
+    it("should render file:line and file:start-end", () => {
+      expect(formatLocation("src/a.ts")).toBe("`src/a.ts`");
+      expect(formatLocation("src/a.ts", 4)).toBe("`src/a.ts:4`");
+      expect(formatLocation("src/a.ts", 4, 4)).toBe("`src/a.ts:4`");
+      expect(formatLocation("src/a.ts", 4, 9)).toBe("`src/a.ts:4-9`");
+    });
+  });
+
+  describe("formatDetails and formatParams", () => {
+    it("should skip undefined detail values", () => {
+      expect(formatDetails({ score: 0.5, page: undefined, exact: false })).toBe(
+        "- **score:** 0.5\n- **exact:** false"
+      );
+    });
+
+    it("should render typed and untyped parameters", () => {
+      expect(formatParams([{ name: "self" }, { name: "p", type: "&Point" }])).toBe(
+        "`self`, `p: &Point`"
+      );
+      expect(formatParams([])).toBe("none");
+    });
+  });
+});
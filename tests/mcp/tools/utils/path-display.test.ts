@@ -0,0 +1,57 @@
+/**
+ * Unit tests for the path rendering helpers shared by tools that report
+ * host filesystem paths.
+ *
+ * @module tests/mcp/tools/utils/path-display
+ */
+
+import { describe, it, expect, afterEach } from "bun:test";
+import path from "node:path";
+import {
+  PROJECT_ROOT_ENV,
+  displayPath,
+  getProjectRoot,
+} from "../../../../src/mcp/tools/utils/path-display.js";
+
+describe("path display helpers", () => {
+  describe("displayPath", () => {
+    const root = path.resolve("/srv/projects");
+
+    it("should render paths beneath the root relative to it", () => {
+      expect(displayPath(path.join(root, "api", "src"), root)).toBe("api/src");
+      expect(displayPath(root, root)).toBe(".");
+    });
+
+    it("should leave paths outside the root unchanged", () => {
+      const sibling = path.resolve("/srv/projects-archive/old");
+      expect(displayPath(sibling, root)).toBe(sibling);
+      expect(displayPath("/home/user/notes", root)).toBe("/home/user/notes");
+    });
+
+    it("should return the path as given when absolute or unrooted", () => {
+      const inside = path.join(root, "api");
+      expect(displayPath(inside, root, true)).toBe(inside);
+      expect(displayPath(inside, undefined)).toBe(inside);
+    });
+  });
+
+  describe("getProjectRoot", () => {
+    const original = process.env[PROJECT_ROOT_ENV];
+
+    afterEach(() => {
+      if (original === undefined) {
+        delete process.env[PROJECT_ROOT_ENV];
+      } else {
+        process.env[PROJECT_ROOT_ENV] = original;
+      }
+    });
+
+    it("should resolve the configured root and ignore blank values", () => {
+      process.env[PROJECT_ROOT_ENV] = "./projects";
+      expect(getProjectRoot()).toBe(path.resolve("projects"));
+
+      process.env[PROJECT_ROOT_ENV] = "  ";
+      expect(getProjectRoot()).toBeUndefined();
+    });
+  });
+});
@@ -1,9 +1,10 @@
 /**
  * Unit tests for FolderEventRouter (issue #566 / T5.1).
  *
- * Covers: id-prefix routing precedence, debounce coalescing, per-repo
- * debounce overrides, missing-repo handling, non-local-folder source
- * rejection, and shutdown cancelling pending timers.
+ * Covers: id-prefix routing precedence, debounce coalescing, per-file
+ * event coalescing, serialized dispatch, per-repo debounce overrides,
+ * missing-repo handling, non-local-folder source rejection, and shutdown
+ * cancelling pending timers.
  *
  * @module tests/services/folder-event-router
  */
@@ -21,16 +22,22 @@ import {
   localFolderIdFor,
   repositoryNameFromLocalFolderId,
   DEFAULT_LOCAL_FOLDER_DEBOUNCE_MS,
+  coalesceFileEventType,
+  resolveLocalFolderDebounceMs,
 } from "../../src/services/folder-event-router.js";
-import type { FileEvent } from "../../src/services/folder-watcher-types.js";
+import type { FileEvent, FileEventType } from "../../src/services/folder-watcher-types.js";
 import type { RepositoryMetadataService, RepositoryInfo } from "../../src/repositories/types.js";
 import { initializeLogger, resetLogger } from "../../src/logging/index.js";
 
-function buildEvent(folderId: string): FileEvent {
+function buildEvent(
+  folderId: string,
+  type: FileEventType = "change",
+  relativePath = "file.txt"
+): FileEvent {
   return {
-    type: "change",
-    absolutePath: "/some/abs/file.txt",
-    relativePath: "file.txt",
+    type,
+    absolutePath: `/some/abs/${relativePath}`,
+    relativePath,
     extension: "txt",
     folderId,
     folderPath: "/some/abs",
@@ -100,6 +107,42 @@ describe("FolderEventRouter id-prefix helpers", () => {
   });
 });
 
+describe("coalesceFileEventType", () => {
+  it("keeps the first event for a file", () => {
+    expect(coalesceFileEventType(undefined, "change")).toBe("change");
+  });
+
+  it("collapses repeated events", () => {
+    expect(coalesceFileEventType("change", "change")).toBe("change");
+    expect(coalesceFileEventType("add", "change")).toBe("add");
+  });
+
+  it("lets a delete supersede earlier changes", () => {
+    expect(coalesceFileEventType("change", "unlink")).toBe("unlink");
+  });
+
+  it("drops files created and deleted within the window", () => {
+    expect(coalesceFileEventType("add", "unlink")).toBeNull();
+  });
+
+  it("treats a delete followed by a re-create as a change", () => {
+    expect(coalesceFileEventType("unlink", "add")).toBe("change");
+  });
+});
+
+describe("resolveLocalFolderDebounceMs", () => {
+  it("falls back to the default when unset or not a number", () => {
+    expect(resolveLocalFolderDebounceMs(undefined)).toBe(DEFAULT_LOCAL_FOLDER_DEBOUNCE_MS);
+    expect(resolveLocalFolderDebounceMs("soon")).toBe(DEFAULT_LOCAL_FOLDER_DEBOUNCE_MS);
+  });
+
+  it("parses and clamps configured values", () => {
+    expect(resolveLocalFolderDebounceMs("500")).toBe(500);
+    expect(resolveLocalFolderDebounceMs("5")).toBe(100);
+    expect(resolveLocalFolderDebounceMs("999999")).toBe(300000);
+  });
+});
+
 describe("FolderEventRouter routing", () => {
   beforeEach(() => initializeLogger({ level: "silent", format: "json" }));
   afterEach(() => resetLogger());
@@ -191,6 +234,77 @@ describe("FolderEventRouter routing", () => {
     expect(dispatch.mock.calls.length).toBe(0);
   });
 
+  it("dispatches one net change per file", async () => {
+    const dispatch = mock((_repo: RepositoryInfo, _changes: FileEvent[]) => undefined);
+    const repo = buildLocalFolderRepo("fmt");
+    const router = new FolderEventRouter(buildMetadata([repo]), dispatch as any, {
+      defaultDebounceMs: 10,
+    });
+
+    const id = localFolderIdFor("fmt");
+    await router.route(buildEvent(id, "change", "src/lib.rs"));
+    await router.route(buildEvent(id, "change", "src/main.rs"));
+    await router.route(buildEvent(id, "change", "src/lib.rs"));
+    await router.route(buildEvent(id, "change", "src/old.rs"));
+    await router.route(buildEvent(id, "unlink", "src/old.rs"));
+    await router.route(buildEvent(id, "add", "src/lib.rs.tmp"));
+    await router.route(buildEvent(id, "unlink", "src/lib.rs.tmp"));
+
+    await new Promise((r) => setTimeout(r, 30));
+
+    expect(dispatch.mock.calls.length).toBe(1);
+    const changes = dispatch.mock.calls[0]?.[1] ?? [];
+    expect(changes.map((c) => [c.relativePath, c.type])).toEqual([
+      ["src/lib.rs", "change"],
+      ["src/main.rs", "change"],
+      ["src/old.rs", "unlink"],
+    ]);
+  });
+
+  it("skips the dispatch when a burst nets out to no changes", async () => {
+    const dispatch = mock((_repo: RepositoryInfo) => undefined);
+    const repo = buildLocalFolderRepo("scratch");
+    const router = new FolderEventRouter(buildMetadata([repo]), dispatch as any, {
+      defaultDebounceMs: 10,
+    });
+
+    const id = localFolderIdFor("scratch");
+    await router.route(buildEvent(id, "add", "notes.tmp"));
+    await router.route(buildEvent(id, "unlink", "notes.tmp"));
+
+    await new Promise((r) => setTimeout(r, 30));
+    expect(dispatch.mock.calls.length).toBe(0);
+  });
+
+  it("holds events arriving during a dispatch until it finishes", async () => {
+    let running = 0;
+    let maxRunning = 0;
+    const batches: string[][] = [];
+    const dispatch = mock(async (_repo: RepositoryInfo, changes: FileEvent[]) => {
+      running++;
+      maxRunning = Math.max(maxRunning, running);
+      batches.push(changes.map((c) => c.relativePath));
+      await new Promise((r) => setTimeout(r, 40));
+      running--;
+    });
+    const repo = buildLocalFolderRepo("busy");
+    const router = new FolderEventRouter(buildMetadata([repo]), dispatch as any, {
+      defaultDebounceMs: 10,
+    });
+
+    const id = localFolderIdFor("busy");
+    await router.route(buildEvent(id, "change", "a.rs"));
+    await new Promise((r) => setTimeout(r, 20));
+    // First dispatch is running; these land in the next batch
+    await router.route(buildEvent(id, "change", "b.rs"));
+    await router.route(buildEvent(id, "change", "c.rs"));
+
+    await new Promise((r) => setTimeout(r, 120));
+
+    expect(maxRunning).toBe(1);
+    expect(batches).toEqual([["a.rs"], ["b.rs", "c.rs"]]);
+  });
+
   it("shutdown cancels pending timers", async () => {
     const dispatch = mock((_repo: RepositoryInfo) => undefined);
     const repo = buildLocalFolderRepo("cancelme");
@@ -18,6 +18,7 @@ import type { ChromaStorageClient } from "../../src/storage/index.js";
 import type { FileChange, UpdateOptions } from "../../src/services/incremental-update-types.js";
 import type { GraphIngestionService } from "../../src/graph/ingestion/GraphIngestionService.js";
 import { UpdateDimensionMismatchError } from "../../src/services/incremental-update-coordinator-errors.js";
+import { IndexingCancelledError } from "../../src/services/ingestion-errors.js";
 
 describe("IncrementalUpdatePipeline", () => {
   let pipeline: IncrementalUpdatePipeline;
@@ -335,6 +336,24 @@ describe("IncrementalUpdatePipeline", () => {
       expect(mockEmbeddingProvider.generateEmbeddings).toHaveBeenCalled();
     });
 
+    it("should stop before the next file once the signal aborts", async () => {
+      const controller = new AbortController();
+      mockStorageClient.deleteDocumentsByFilePrefix = mock(async () => {
+        controller.abort();
+        return 1;
+      });
+
+      const changes: FileChange[] = [
+        { path: "src/a.ts", status: "deleted" },
+        { path: "src/b.ts", status: "deleted" },
+      ];
+
+      await expect(
+        pipeline.processChanges(changes, { ...baseOptions, signal: controller.signal })
+      ).rejects.toThrow(IndexingCancelledError);
+      expect(mockStorageClient.deleteDocumentsByFilePrefix).toHaveBeenCalledTimes(1);
+    });
+
     it("should continue after file read error", async () => {
       // Create only one of two files
       await mkdir(join(testDir, "src"), { recursive: true });
@@ -415,6 +434,38 @@ describe("IncrementalUpdatePipeline", () => {
       expect(result.errors).toHaveLength(0);
     });
 
+    it("should index a lossy decode of a file that is not valid UTF-8", async () => {
+      await mkdir(join(testDir, "src"), { recursive: true });
+      // "café" in Latin-1: the 0xE9 byte is not valid UTF-8
+      await writeFile(
+        join(testDir, "src/latin1.ts"),
+        Buffer.from("// caf\xe9 menu\nexport const price = 3;\n", "latin1")
+      );
+
+      const changes: FileChange[] = [{ path: "src/latin1.ts", status: "added" }];
+      const options: UpdateOptions = { ...baseOptions, localPath: testDir };
+
+      const result = await pipeline.processChanges(changes, options);
+
+      expect(result.stats.filesAdded).toBe(1);
+      expect(result.stats.chunksUpserted).toBeGreaterThan(0);
+      expect(result.errors).toHaveLength(0);
+    });
+
+    it("should skip a file that cannot be decoded without reporting an error", async () => {
+      await mkdir(join(testDir, "src"), { recursive: true });
+      await writeFile(join(testDir, "src/garbage.ts"), Buffer.alloc(64, 0xff));
+
+      const changes: FileChange[] = [{ path: "src/garbage.ts", status: "added" }];
+      const options: UpdateOptions = { ...baseOptions, localPath: testDir };
+
+      const result = await pipeline.processChanges(changes, options);
+
+      expect(result.stats.filesAdded).toBe(0);
+      expect(result.stats.chunksUpserted).toBe(0);
+      expect(result.errors).toHaveLength(0);
+    });
+
     it("should validate UpdateResult structure", async () => {
       const result = await pipeline.processChanges([], baseOptions);
 
@@ -740,7 +791,7 @@ describe("IncrementalUpdatePipeline", () => {
       expect(mockStorageClient.upsertDocuments).toHaveBeenCalled();
     });
 
-    it("should skip oversized chunks instead of failing the batch (issue #589)", async () => {
+    it("should truncate oversized chunks instead of failing the batch (issue #589)", async () => {
       // A single-line file produces a single chunk regardless of size (line-based
       // chunking cannot split it), far above the embedding input limit.
       await mkdir(join(testDir, "src"), { recursive: true });
@@ -756,23 +807,56 @@ describe("IncrementalUpdatePipeline", () => {
 
       const result = await pipeline.processChanges(changes, options);
 
-      // The oversized chunk is skipped with a per-chunk error
-      expect(result.errors).toHaveLength(1);
-      expect(result.errors[0]?.path).toBe("src/minified.ts");
-      expect(result.errors[0]?.error).toContain("exceeds embedding input limit");
-
-      // The skip is surfaced in the stats (issue #589, review L1)
-      expect(result.stats.chunksSkipped).toBe(1);
+      // The oversized chunk is truncated, not dropped or reported as an error
+      expect(result.errors).toHaveLength(0);
+      expect(result.stats.chunksSkipped).toBe(0);
+      expect(result.stats.chunksTruncated).toBe(1);
 
-      // The normal file's chunk was still embedded and stored
-      expect(result.stats.chunksUpserted).toBeGreaterThan(0);
+      // Both files' chunks were embedded and stored
+      expect(result.stats.chunksUpserted).toBe(2);
 
-      // The oversized content was never sent to the embedding provider
+      // Only the first 6000 estimated tokens were sent to the embedding provider
       const sentTexts = (
         mockEmbeddingProvider.generateEmbeddings as ReturnType<typeof mock>
       ).mock.calls.flatMap((c) => c[0] as string[]);
+      expect(sentTexts).toHaveLength(2);
       for (const text of sentTexts) {
-        expect(text.length).toBeLessThan(24000);
+        expect(text.length).toBeLessThanOrEqual(24000);
+      }
+      expect(sentTexts).toContain(giantLine.slice(0, 24000));
+
+      // The stored document keeps the full content
+      const storedContents = (
+        mockStorageClient.upsertDocuments as ReturnType<typeof mock>
+      ).mock.calls.flatMap((c) => (c[1] as Array<{ content: string }>).map((d) => d.content));
+      expect(storedContents.some((content) => content.includes(giantLine))).toBe(true);
+    });
+
+    it("uses EMBEDDING_MAX_INPUT_TOKENS as the truncation budget", async () => {
+      const savedMaxInputTokens = process.env["EMBEDDING_MAX_INPUT_TOKENS"];
+      process.env["EMBEDDING_MAX_INPUT_TOKENS"] = "100";
+      try {
+        await mkdir(join(testDir, "src"), { recursive: true });
+        const line = `export const blob = "${"a".repeat(1000)}";`;
+        await writeFile(join(testDir, "src/long.ts"), line);
+
+        const changes: FileChange[] = [{ path: "src/long.ts", status: "added" }];
+        const options: UpdateOptions = { ...baseOptions, localPath: testDir };
+
+        const result = await pipeline.processChanges(changes, options);
+
+        expect(result.errors).toHaveLength(0);
+        expect(result.stats.chunksTruncated).toBe(1);
+        const sentTexts = (
+          mockEmbeddingProvider.generateEmbeddings as ReturnType<typeof mock>
+        ).mock.calls.flatMap((c) => c[0] as string[]);
+        expect(sentTexts).toEqual([line.slice(0, 400)]);
+      } finally {
+        if (savedMaxInputTokens === undefined) {
+          delete process.env["EMBEDDING_MAX_INPUT_TOKENS"];
+        } else {
+          process.env["EMBEDDING_MAX_INPUT_TOKENS"] = savedMaxInputTokens;
+        }
       }
     });
 
@@ -807,9 +891,10 @@ describe("IncrementalUpdatePipeline", () => {
 
         const result = await envPipeline.processChanges(changes, options);
 
-        // Chunk was embedded and stored, not skipped.
+        // Chunk was embedded whole, not skipped or truncated.
         expect(result.errors).toHaveLength(0);
         expect(result.stats.chunksSkipped).toBe(0);
+        expect(result.stats.chunksTruncated).toBe(0);
         expect(result.stats.chunksUpserted).toBeGreaterThan(0);
         expect(mockStorageClient.upsertDocuments).toHaveBeenCalled();
       } finally {
@@ -1109,6 +1194,22 @@ describe("IncrementalUpdatePipeline", () => {
       expect(result.stats.graph?.graphFilesProcessed).toBe(1);
     });
 
+    it("should pass the encoding warning of a lossily decoded file to the graph", async () => {
+      await mkdir(join(testDir, "src"), { recursive: true });
+      await writeFile(
+        join(testDir, "src/latin1.ts"),
+        Buffer.from("// caf\xe9 menu\nexport const price = 3;\n", "latin1")
+      );
+
+      const changes: FileChange[] = [{ path: "src/latin1.ts", status: "added" }];
+      const options: UpdateOptions = { ...baseOptions, localPath: testDir };
+
+      await pipelineWithGraph.processChanges(changes, options);
+
+      const [input] = mockGraphService.ingestFile.mock.calls[0] as [{ encodingWarning?: string }];
+      expect(input.encodingWarning).toBeDefined();
+    });
+
     it("should call deleteFileData then ingestFile for modified files", async () => {
       // Create test file
       const testFile = "src/modified.ts";
@@ -1124,6 +1225,9 @@ describe("IncrementalUpdatePipeline", () => {
       expect(result.stats.filesModified).toBe(1);
       // Should delete then ingest
       expect(mockGraphService.deleteFileData).toHaveBeenCalledTimes(1);
+      expect(mockGraphService.deleteFileData).toHaveBeenCalledWith("test-repo", testFile, {
+        reingest: true,
+      });
       expect(mockGraphService.ingestFile).toHaveBeenCalledTimes(1);
       expect(result.stats.graph?.graphNodesDeleted).toBe(3);
       expect(result.stats.graph?.graphNodesCreated).toBe(5);
@@ -1137,6 +1241,7 @@ describe("IncrementalUpdatePipeline", () => {
 
       expect(result.stats.filesDeleted).toBe(1);
       expect(mockGraphService.deleteFileData).toHaveBeenCalledTimes(1);
+      expect(mockGraphService.deleteFileData).toHaveBeenCalledWith("test-repo", "src/deleted.ts");
       expect(mockGraphService.ingestFile).not.toHaveBeenCalled();
       expect(result.stats.graph?.graphNodesDeleted).toBe(3);
       expect(result.stats.graph?.graphRelationshipsDeleted).toBe(4);
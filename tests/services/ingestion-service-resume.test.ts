@@ -0,0 +1,256 @@
+/* eslint-disable @typescript-eslint/no-unsafe-argument */
+/* eslint-disable @typescript-eslint/no-unsafe-member-access */
+/* eslint-disable @typescript-eslint/no-unsafe-assignment */
+/* eslint-disable @typescript-eslint/no-explicit-any */
+/* eslint-disable @typescript-eslint/explicit-function-return-type */
+
+/**
+ * Resume tests for `IngestionService` index builds.
+ *
+ * An interrupted build checkpoints the files that completed chunk → embed →
+ * store; a later build of the same source skips them, and the checkpoint is
+ * discarded when the source changed or resuming is disabled.
+ */
+
+import { describe, it, expect, beforeEach, beforeAll, afterAll, mock } from "bun:test";
+import { IngestionService } from "../../src/services/ingestion-service.js";
+import { IndexingCancelledError } from "../../src/services/ingestion-errors.js";
+import type {
+  IndexCheckpoint,
+  IndexCheckpointStore,
+} from "../../src/services/index-checkpoint-store.js";
+import type { EmbeddingProvider } from "../../src/providers/types.js";
+import type { RepositoryInfo } from "../../src/repositories/types.js";
+import type { FileInfo, FileChunk } from "../../src/ingestion/types.js";
+import type { FileSource } from "../../src/ingestion/file-source.js";
+import { initializeLogger, resetLogger } from "../../src/logging/index.js";
+
+const URL = "https://github.com/user/big-repo.git";
+
+class InMemoryCheckpointStore implements IndexCheckpointStore {
+  checkpoints = new Map<string, IndexCheckpoint>();
+  saves = 0;
+  async loadCheckpoint(repository: string) {
+    const checkpoint = this.checkpoints.get(repository);
+    return checkpoint ? structuredClone(checkpoint) : null;
+  }
+  async saveCheckpoint(checkpoint: IndexCheckpoint) {
+    this.saves++;
+    this.checkpoints.set(checkpoint.repository, structuredClone(checkpoint));
+  }
+  async deleteCheckpoint(repository: string) {
+    this.checkpoints.delete(repository);
+  }
+}
+
+const fakeSource = {
+  readText: async (relativePath: string) => `// ${relativePath}\n`,
+  readBytes: async (relativePath: string) => new TextEncoder().encode(`// ${relativePath}\n`),
+} as unknown as FileSource;
+
+function makeFiles(count: number): FileInfo[] {
+  return Array.from({ length: count }, (_, index) => ({
+    relativePath: `src/file-${index}.ts`,
+    absolutePath: `/tmp/big-repo/src/file-${index}.ts`,
+    extension: ".ts",
+    sizeBytes: 100 + index,
+    modifiedAt: new Date(1700000000000 + index),
+    source: fakeSource,
+  }));
+}
+
+function makeChunker() {
+  return {
+    chunkFile: mock((content: string, fileInfo: FileInfo, repository: string): FileChunk[] => [
+      {
+        id: `${repository}:${fileInfo.relativePath}:0`,
+        content,
+        repository,
+        filePath: fileInfo.relativePath,
+        chunkIndex: 0,
+        totalChunks: 1,
+        startLine: 1,
+        endLine: 1,
+        metadata: {
+          extension: fileInfo.extension,
+          language: "typescript",
+          fileSizeBytes: fileInfo.sizeBytes,
+          contentHash: "h",
+          fileModifiedAt: fileInfo.modifiedAt,
+        },
+      },
+    ]),
+  };
+}
+
+function makeProvider(): EmbeddingProvider {
+  return {
+    providerId: "mock",
+    modelId: "mock-model",
+    dimensions: 4,
+    generateEmbedding: async () => [0.1, 0.1, 0.1, 0.1],
+    generateEmbeddings: async (texts: string[]) => texts.map(() => [0.1, 0.1, 0.1, 0.1]),
+    healthCheck: async () => true,
+    getCapabilities: () => ({
+      maxBatchSize: 100,
+      maxTokensPerText: 8191,
+      supportsGPU: false,
+      requiresNetwork: false,
+      estimatedLatencyMs: 1,
+    }),
+  } as EmbeddingProvider;
+}
+
+function makeStorage() {
+  return {
+    getOrCreateCollection: mock(async (name: string) => ({ name })),
+    deleteCollection: mock(async () => {}),
+    addDocuments: mock(async () => {}),
+    upsertDocuments: mock(async () => {}),
+  };
+}
+
+function makeMetadata() {
+  const repos = new Map<string, RepositoryInfo>();
+  return {
+    getRepository: async (name: string) => repos.get(name) ?? null,
+    listRepositories: async () => Array.from(repos.values()),
+    updateRepository: async (info: RepositoryInfo) => {
+      repos.set(info.name, info);
+    },
+    removeRepository: async (name: string) => {
+      repos.delete(name);
+    },
+  };
+}
+
+describe("IngestionService resume", () => {
+  let files: FileInfo[];
+  let commitSha: string;
+  let chunker: ReturnType<typeof makeChunker>;
+  let storage: ReturnType<typeof makeStorage>;
+  let checkpointStore: InMemoryCheckpointStore;
+  let service: IngestionService;
+
+  beforeAll(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterAll(() => {
+    resetLogger();
+  });
+
+  beforeEach(() => {
+    // 120 files → three batches of 50, 50 and 20
+    files = makeFiles(120);
+    commitSha = "abc1234567890abcdef1234567890abcdef12345";
+    chunker = makeChunker();
+    storage = makeStorage();
+    checkpointStore = new InMemoryCheckpointStore();
+    service = new IngestionService(
+      {
+        clone: async () => ({ path: "/tmp/big-repo", name: "big-repo", branch: "main", commitSha }),
+        cleanup: async () => {},
+      } as any,
+      { scanFiles: async () => files } as any,
+      chunker as any,
+      makeProvider(),
+      storage as any,
+      makeMetadata() as any,
+      { checkpointStore }
+    );
+  });
+
+  /** Run a build that is cancelled once the first batch has been stored. */
+  async function interruptAfterFirstBatch(): Promise<void> {
+    const controller = new AbortController();
+    storage.addDocuments.mockImplementationOnce(async () => controller.abort());
+    await expect(service.indexRepository(URL, { signal: controller.signal })).rejects.toThrow(
+      IndexingCancelledError
+    );
+  }
+
+  it("saves a checkpoint of completed files when the build is cancelled", async () => {
+    await interruptAfterFirstBatch();
+
+    const checkpoint = checkpointStore.checkpoints.get("big-repo");
+    expect(checkpoint).toBeDefined();
+    expect(Object.keys(checkpoint?.files ?? {})).toHaveLength(50);
+    expect(checkpoint?.files["src/file-0.ts"]).toEqual({ sizeBytes: 100, mtimeMs: 1700000000000 });
+    expect(checkpoint?.stats).toEqual({
+      filesProcessed: 50,
+      chunksCreated: 50,
+      embeddingsGenerated: 50,
+      documentsStored: 50,
+    });
+    expect(checkpoint?.embeddingModel).toBe("mock:mock-model");
+  });
+
+  it("resumes from the checkpoint without reprocessing completed files", async () => {
+    await interruptAfterFirstBatch();
+    chunker.chunkFile.mockClear();
+    storage.deleteCollection.mockClear();
+
+    const result = await service.indexRepository(URL);
+
+    expect(result.status).toBe("success");
+    expect(chunker.chunkFile).toHaveBeenCalledTimes(70);
+    expect(storage.upsertDocuments).toHaveBeenCalledTimes(2);
+    expect(storage.deleteCollection).not.toHaveBeenCalled();
+    expect(result.stats.filesProcessed).toBe(120);
+    expect(result.stats.filesResumed).toBe(50);
+    expect(result.stats.documentsStored).toBe(120);
+    expect(checkpointStore.checkpoints.has("big-repo")).toBe(false);
+  });
+
+  it("starts over when resume is disabled", async () => {
+    await interruptAfterFirstBatch();
+    chunker.chunkFile.mockClear();
+
+    const result = await service.indexRepository(URL, { resume: false });
+
+    expect(chunker.chunkFile).toHaveBeenCalledTimes(120);
+    expect(storage.deleteCollection).toHaveBeenCalledTimes(1);
+    expect(result.stats.filesResumed).toBeUndefined();
+  });
+
+  it("starts over when the commit changed since the checkpoint", async () => {
+    await interruptAfterFirstBatch();
+    chunker.chunkFile.mockClear();
+    commitSha = "def4567890abcdef1234567890abcdef12345678";
+
+    await service.indexRepository(URL);
+
+    expect(chunker.chunkFile).toHaveBeenCalledTimes(120);
+    expect(storage.upsertDocuments).not.toHaveBeenCalled();
+  });
+
+  it("starts over when a completed file changed size", async () => {
+    await interruptAfterFirstBatch();
+    chunker.chunkFile.mockClear();
+    files = makeFiles(120).map((file, index) =>
+      index === 3 ? { ...file, sizeBytes: file.sizeBytes + 1 } : file
+    );
+
+    await service.indexRepository(URL);
+
+    expect(chunker.chunkFile).toHaveBeenCalledTimes(120);
+  });
+
+  it("saves checkpoints at the configured batch interval", async () => {
+    const controller = new AbortController();
+    storage.addDocuments
+      .mockImplementationOnce(async () => {})
+      .mockImplementationOnce(async () => controller.abort());
+
+    await expect(
+      service.indexRepository(URL, { signal: controller.signal, checkpointInterval: 1 })
+    ).rejects.toThrow(IndexingCancelledError);
+
+    // One save after each of the two stored batches, one on cancellation
+    expect(checkpointStore.saves).toBe(3);
+    expect(Object.keys(checkpointStore.checkpoints.get("big-repo")?.files ?? {})).toHaveLength(
+      100
+    );
+  });
+});
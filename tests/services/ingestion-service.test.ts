@@ -28,6 +28,7 @@ import {
   IngestionError,
   RepositoryAlreadyExistsError,
   IndexingInProgressError,
+  IndexingCancelledError,
 } from "../../src/services/ingestion-errors.js";
 import type { IndexProgress } from "../../src/services/ingestion-types.js";
 import type { EmbeddingProvider } from "../../src/providers/types.js";
@@ -537,6 +538,35 @@ describe("IngestionService", () => {
       (Bun as any).file = originalBunFile;
     });
 
+    it("should stop indexing when the abort signal fires", async () => {
+      const mockFiles = [createMockFile("src/file1.ts"), createMockFile("src/file2.ts")];
+      mockScanner.setMockFiles(mockFiles);
+
+      const originalBunFile = Bun.file;
+      (Bun as any).file = (_path: string) => ({
+        text: async () => "content",
+      });
+
+      let embeddingCalls = 0;
+      mockEmbedding.generateEmbeddings = async (texts: string[]) => {
+        embeddingCalls++;
+        return texts.map(() => [0.1, 0.2, 0.3]);
+      };
+
+      const controller = new AbortController();
+      controller.abort();
+
+      await expect(service.indexRepository(testUrl, { signal: controller.signal })).rejects.toThrow(
+        IndexingCancelledError
+      );
+
+      (Bun as any).file = originalBunFile;
+
+      // No embeddings requested and the lock released for the next run
+      expect(embeddingCalls).toBe(0);
+      expect(service.getStatus().isIndexing).toBe(false);
+    });
+
     it("should invoke progress callback at each phase", async () => {
       const mockFiles = [createMockFile("src/file1.ts")];
       mockScanner.setMockFiles(mockFiles);
@@ -234,6 +234,25 @@ describe("LocalFolderChangeDetector", () => {
     expect(paths).toEqual(["src/in.ts"]);
   });
 
+  it("falls back to reporting every file as added when the manifest is corrupt", async () => {
+    await writeFile(join(testDir, "a.ts"), "v1");
+    const repo = makeRepo("test", testDir);
+    const detector = new LocalFolderChangeDetector(store);
+    const initial = await detector.detect(repo);
+    await store.saveManifest(
+      repo.name,
+      detector.buildNextManifest(repo.name, initial.nextManifestFiles)
+    );
+
+    // Simulate a torn write from outside the store's atomic rename path.
+    await writeFile(store.getManifestPath(repo.name), "{\"version\": \"1.0\", \"files\": {");
+    FileManifestStoreImpl.resetInstance();
+    const freshStore = FileManifestStoreImpl.getInstance(dataDir);
+
+    const result = await new LocalFolderChangeDetector(freshStore).detect(repo);
+    expect(result.changes).toEqual([{ path: "a.ts", status: "added" }]);
+  });
+
   it("buildNextManifest returns a well-formed manifest envelope", () => {
     const detector = new LocalFolderChangeDetector(store);
     const m: FileManifest = detector.buildNextManifest("repo", {
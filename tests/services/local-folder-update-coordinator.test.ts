@@ -15,7 +15,7 @@ import { LocalFolderChangeDetector } from "../../src/services/local-folder-chang
 import { FileManifestStoreImpl } from "../../src/services/file-manifest-store.js";
 import type { IncrementalUpdatePipeline } from "../../src/services/incremental-update-pipeline.js";
 import type { RepositoryInfo, RepositoryMetadataService } from "../../src/repositories/types.js";
-import type { UpdateResult } from "../../src/services/incremental-update-types.js";
+import type { FileChange, UpdateResult } from "../../src/services/incremental-update-types.js";
 import { initializeLogger, resetLogger } from "../../src/logging/index.js";
 
 function makeRepo(name: string, localPath: string): RepositoryInfo {
@@ -249,6 +249,48 @@ describe("LocalFolderUpdateCoordinator", () => {
     expect(persisted.generatedAt).toBe(seedManifest.generatedAt);
   });
 
+  it("purges files deleted while the manifest was corrupt", async () => {
+    await writeFile(join(testDir, "a.ts"), "v1");
+    await writeFile(join(testDir, "gone.ts"), "v1");
+    const repo = makeRepo("corrupt", testDir);
+    const detector = new LocalFolderChangeDetector(store);
+
+    const seed = await detector.detect(repo);
+    await store.saveManifest(repo.name, {
+      version: "1.0",
+      repository: repo.name,
+      generatedAt: new Date().toISOString(),
+      files: seed.nextManifestFiles,
+    });
+
+    // The file is deleted and the manifest is damaged before the next update.
+    await rm(join(testDir, "gone.ts"));
+    await writeFile(store.getManifestPath(repo.name), "{ not json", "utf-8");
+
+    let received: FileChange[] = [];
+    const metadata = makeMetadataService(repo);
+    const pipeline = {
+      listIndexedFilePaths: mock(async () => new Set(["a.ts", "gone.ts"])),
+      processChanges: mock(async (changes: FileChange[]) => {
+        received = changes;
+        return emptyUpdateResult();
+      }),
+    } as unknown as IncrementalUpdatePipeline;
+    const coord = new LocalFolderUpdateCoordinator(metadata, pipeline, detector, store);
+
+    const result = await coord.updateRepository("corrupt");
+
+    expect(result.status).toBe("updated");
+    expect(pipeline.listIndexedFilePaths).toHaveBeenCalledWith("repo_corrupt", "corrupt");
+    // The indexed file still on disk is replaced; the deleted one is purged.
+    expect(received).toEqual([
+      { path: "a.ts", status: "modified" },
+      { path: "gone.ts", status: "deleted" },
+    ]);
+    const persisted = await store.loadManifest(repo.name);
+    expect(Object.keys(persisted.files)).toEqual(["a.ts"]);
+  });
+
   it("preserves prior fingerprint for files the pipeline reported errors on (partial success)", async () => {
     // Three files modified on disk; pipeline succeeds on two (count=2) and errors
     // on one. With errors < processed, historyStatus is "partial" → manifest IS
@@ -0,0 +1,678 @@
+/**
+ * Tests for SymbolDiffService
+ *
+ * Git access is mocked through `simpleGitFactory`; file versions are parsed
+ * with the real EntityExtractor.
+ *
+ * @module tests/services/symbol-diff-service
+ */
+
+import { describe, it, expect, beforeAll, afterAll, mock } from "bun:test";
+import type { SimpleGit } from "simple-git";
+import { SymbolDiffServiceImpl } from "../../src/services/symbol-diff-service.js";
+import { RepositoryNotFoundError } from "../../src/services/errors.js";
+import {
+  GraphServiceValidationError,
+  GraphServiceOperationError,
+} from "../../src/services/graph-service-errors.js";
+import type {
+  SymbolSnapshot,
+  SymbolSnapshotStore,
+  SymbolHistory,
+} from "../../src/services/symbol-snapshot-store.js";
+import { EntityExtractor } from "../../src/graph/extraction/EntityExtractor.js";
+import { LanguageLoader } from "../../src/graph/parsing/LanguageLoader.js";
+import type { RepositoryMetadataService, RepositoryInfo } from "../../src/repositories/types.js";
+import { initializeLogger, resetLogger } from "../../src/logging/index.js";
+import { createCypherMockAdapter } from "../helpers/graph-analysis-mock.js";
+
+const BASE_SHA = "1111111111111111111111111111111111111111";
+const HEAD_SHA = "2222222222222222222222222222222222222222";
+
+const BASE_AUTH = [
+  "export function login(user: string): boolean {",
+  "  return user.length > 0;",
+  "}",
+  "",
+  "export function logout(): void {}",
+  "",
+  "export class Session {",
+  "  refresh(): void {}",
+  "}",
+].join("\n");
+
+const HEAD_AUTH = [
+  "// Authentication helpers",
+  "export function login(user: string): boolean {",
+  "  return user.length > 0;",
+  "}",
+  "",
+  "export function logout(reason: string): void {",
+  "  console.log(reason);",
+  "}",
+  "",
+  "export class Session {",
+  "  refresh(): void {}",
+  "  expire(): void {}",
+  "}",
+].join("\n");
+
+const BLOBS: Record<string, string> = {
+  [`${BASE_SHA}:src/auth.ts`]: BASE_AUTH,
+  [`${HEAD_SHA}:src/auth.ts`]: HEAD_AUTH,
+  [`${BASE_SHA}:src/old.ts`]: "export function gone(): void {}\n",
+  [`${HEAD_SHA}:docs/readme.md`]: "# Readme\n",
+};
+
+const testRepo: RepositoryInfo = {
+  name: "test-repo",
+  source: "local-git",
+  url: null,
+  localPath: "/repos/test-repo",
+  collectionName: "repo_test_repo",
+  fileCount: 3,
+  chunkCount: 10,
+  lastIndexedAt: "2024-12-01T00:00:00.000Z",
+  indexDurationMs: 1000,
+  status: "ready",
+  branch: "main",
+  includeExtensions: [".ts", ".md"],
+  excludePatterns: [],
+};
+
+// Zero-context hunks of the auth.ts change, as printed by `git diff -U0`
+const AUTH_HUNKS = [
+  "@@ -0,0 +1 @@",
+  "+// Authentication helpers",
+  "@@ -5 +6,3 @@ export function login(user: string): boolean {",
+  "-export function logout(): void {}",
+  "+export function logout(reason: string): void {",
+  "+  console.log(reason);",
+  "+}",
+  "@@ -8,0 +12 @@ export class Session {",
+  "+  expire(): void {}",
+];
+
+function unifiedDiff(authHunks: string[]): string {
+  return [
+    "diff --git a/src/auth.ts b/src/auth.ts",
+    "index 1111111..2222222 100644",
+    "--- a/src/auth.ts",
+    "+++ b/src/auth.ts",
+    ...authHunks,
+    "diff --git a/docs/readme.md b/docs/readme.md",
+    "new file mode 100644",
+    "--- /dev/null",
+    "+++ b/docs/readme.md",
+    "@@ -0,0 +1 @@",
+    "+# Readme",
+    "diff --git a/src/old.ts b/src/old.ts",
+    "deleted file mode 100644",
+    "--- a/src/old.ts",
+    "+++ /dev/null",
+    "@@ -1 +0,0 @@",
+    "-export function gone(): void {}",
+    "",
+  ].join("\n");
+}
+
+function createMockGit(
+  authHunks: string[] = AUTH_HUNKS
+): SimpleGit & { show: ReturnType<typeof mock> } {
+  const refs: Record<string, string> = {
+    "main^{commit}": BASE_SHA,
+    "HEAD^{commit}": HEAD_SHA,
+  };
+
+  return {
+    revparse: mock(async (args: string[]) => {
+      const sha = refs[args[args.length - 1] ?? ""];
+      if (!sha) throw new Error("fatal: Needed a single revision");
+      return `${sha}\n`;
+    }),
+    diff: mock(async (args: string[]) =>
+      args.includes("-U0")
+        ? unifiedDiff(authHunks)
+        : "M\tsrc/auth.ts\nA\tdocs/readme.md\nD\tsrc/old.ts\n"
+    ),
+    show: mock(async (args: string[]) => {
+      const blob = BLOBS[args[0] ?? ""];
+      if (blob === undefined) throw new Error("fatal: path does not exist");
+      return blob;
+    }),
+  } as unknown as SimpleGit & { show: ReturnType<typeof mock> };
+}
+
+function createRepositoryService(repo: RepositoryInfo): RepositoryMetadataService {
+  return {
+    listRepositories: mock(async () => [repo]),
+    getRepository: mock(async (name: string) => (name === repo.name ? repo : null)),
+    updateRepository: mock(async () => {}),
+    removeRepository: mock(async () => {}),
+  };
+}
+
+class InMemorySnapshotStore implements SymbolSnapshotStore {
+  snapshots = new Map<string, SymbolSnapshot>();
+  async loadSnapshot(repository: string, name: string) {
+    const snapshot = this.snapshots.get(`${repository}/${name}`);
+    return snapshot ? structuredClone(snapshot) : null;
+  }
+  async saveSnapshot(snapshot: SymbolSnapshot) {
+    this.snapshots.set(`${snapshot.repository}/${snapshot.name}`, structuredClone(snapshot));
+  }
+  async deleteSnapshot(repository: string, name: string) {
+    this.snapshots.delete(`${repository}/${name}`);
+  }
+  histories = new Map<string, SymbolHistory>();
+  async loadHistory(repository: string) {
+    const history = this.histories.get(repository);
+    return history ? structuredClone(history) : null;
+  }
+  async saveHistory(history: SymbolHistory) {
+    this.histories.set(history.repository, structuredClone(history));
+  }
+}
+
+describe("SymbolDiffService", () => {
+  let extractor: EntityExtractor;
+
+  beforeAll(() => {
+    initializeLogger({ level: "silent", format: "json" });
+    extractor = new EntityExtractor();
+  });
+
+  afterAll(() => {
+    LanguageLoader.resetInstance();
+    resetLogger();
+  });
+
+  function createService(
+    repo: RepositoryInfo = testRepo,
+    git: SimpleGit = createMockGit(),
+    graphAdapter?: ReturnType<typeof createCypherMockAdapter>
+  ): SymbolDiffServiceImpl {
+    return new SymbolDiffServiceImpl(createRepositoryService(repo), extractor, graphAdapter, {
+      simpleGitFactory: () => git,
+    });
+  }
+
+  describe("diffSymbols", () => {
+    it("reports added, removed and modified symbols of changed files", async () => {
+      const result = await createService().diffSymbols({
+        repository: "test-repo",
+        base_ref: "main",
+      });
+
+      expect(result.base_sha).toBe(BASE_SHA);
+      expect(result.head_sha).toBe(HEAD_SHA);
+      expect(result.files.map((f) => [f.file_path, f.status])).toEqual([
+        ["src/auth.ts", "modified"],
+        ["src/old.ts", "deleted"],
+      ]);
+
+      const auth = result.files[0]!.symbols.map((s) => [s.name, s.change]);
+      expect(auth).toContainEqual(["logout", "modified"]);
+      expect(auth).toContainEqual(["Session", "modified"]);
+      expect(auth).toContainEqual(["expire", "added"]);
+      // login only moved down one line
+      expect(auth.find(([name]) => name === "login")).toBeUndefined();
+
+      expect(result.files[1]!.symbols).toEqual([
+        { name: "gone", kind: "function", change: "removed", base: { start_line: 1, end_line: 1 } },
+      ]);
+    });
+
+    it("skips unsupported files and records counts", async () => {
+      const result = await createService().diffSymbols({
+        repository: "test-repo",
+        base_ref: "main",
+      });
+
+      expect(result.metadata).toMatchObject({
+        files_changed: 3,
+        files_analyzed: 2,
+        files_skipped: 1,
+        base_from_index: 0,
+      });
+      expect(result.summary.removed).toBe(1);
+    });
+
+    it("reuses indexed base symbols when the base is the indexed commit", async () => {
+      const row = (name: string, kind: string, startLine: number, endLine: number) => ({
+        name,
+        entityType: kind,
+        kind,
+        parentName: kind === "method" ? "Session" : null,
+        startLine,
+        endLine,
+      });
+      const adapter = createCypherMockAdapter((_cypher, params) =>
+        params["filePath"] === "src/auth.ts"
+          ? [
+              row("login", "function", 1, 3),
+              row("logout", "function", 5, 5),
+              row("Session", "class", 7, 9),
+              row("refresh", "method", 8, 8),
+            ]
+          : []
+      );
+      const service = createService(
+        { ...testRepo, lastIndexedCommitSha: BASE_SHA },
+        createMockGit(),
+        adapter
+      );
+
+      const result = await service.diffSymbols({ repository: "test-repo", base_ref: "main" });
+
+      expect(result.metadata.base_from_index).toBe(1);
+      const auth = result.files[0]!.symbols.map((s) => [s.name, s.change]);
+      expect(auth).toContainEqual(["logout", "modified"]);
+      expect(auth).toContainEqual(["expire", "added"]);
+      const queried = adapter.runQuery.mock.calls.map(
+        (call) => (call[1] as Record<string, unknown>)["filePath"]
+      );
+      expect(queried).toEqual(["src/auth.ts", "src/old.ts"]);
+    });
+
+    it("only diffs files beneath the path filter", async () => {
+      const git = createMockGit();
+      const result = await createService(testRepo, git).diffSymbols({
+        repository: "test-repo",
+        base_ref: "main",
+        path: "./src/old.ts",
+      });
+
+      expect(result.files.map((f) => f.file_path)).toEqual(["src/old.ts"]);
+      expect(result.metadata.files_changed).toBe(1);
+      expect(git.show).toHaveBeenCalledTimes(1);
+    });
+
+    it("rejects unknown refs", async () => {
+      await expect(
+        createService().diffSymbols({ repository: "test-repo", base_ref: "missing" })
+      ).rejects.toThrow(GraphServiceValidationError);
+    });
+
+    it("rejects refs that look like git options or ranges", async () => {
+      const service = createService();
+
+      await expect(
+        service.diffSymbols({ repository: "test-repo", base_ref: "--output=/tmp/x" })
+      ).rejects.toThrow(GraphServiceValidationError);
+      await expect(
+        service.diffSymbols({ repository: "test-repo", base_ref: "main..HEAD" })
+      ).rejects.toThrow(GraphServiceValidationError);
+    });
+
+    it("rejects local-folder repositories", async () => {
+      const service = createService({ ...testRepo, source: "local-folder" });
+
+      await expect(
+        service.diffSymbols({ repository: "test-repo", base_ref: "main" })
+      ).rejects.toThrow(/local folder without git history/);
+    });
+
+    it("throws RepositoryNotFoundError for unknown repositories", async () => {
+      await expect(
+        createService().diffSymbols({ repository: "other-repo", base_ref: "main" })
+      ).rejects.toThrow(RepositoryNotFoundError);
+    });
+  });
+
+  describe("scopeToDiff", () => {
+    it("returns head symbols overlapping the changed lines", async () => {
+      const result = await createService().scopeToDiff({
+        repository: "test-repo",
+        base_ref: "main",
+      });
+
+      expect(result.scope).toBe("diff");
+      expect(result.head_sha).toBe(HEAD_SHA);
+      expect(result.files).toHaveLength(1);
+      expect(result.files[0]!.changed_ranges).toEqual([
+        { start_line: 1, end_line: 1 },
+        { start_line: 6, end_line: 8 },
+        { start_line: 12, end_line: 12 },
+      ]);
+      // login only moved down one line; refresh is untouched inside Session
+      expect(result.files[0]!.symbols.map((s) => [s.name, s.changed_lines])).toEqual([
+        ["logout", 3],
+        ["Session", 1],
+        ["expire", 1],
+      ]);
+      expect(result.metadata).toMatchObject({
+        files_changed: 3,
+        files_analyzed: 1,
+        files_skipped: 2,
+        symbol_count: 3,
+      });
+    });
+
+    it("includes symbols enclosing a pure deletion", async () => {
+      const git = createMockGit(["@@ -9 +11,0 @@ export class Session {", "-  stale(): void {}"]);
+
+      const result = await createService(testRepo, git).scopeToDiff({
+        repository: "test-repo",
+        base_ref: "main",
+      });
+
+      expect(result.files[0]!.changed_ranges).toEqual([]);
+      expect(result.files[0]!.symbols.map((s) => [s.name, s.changed_lines])).toEqual([
+        ["Session", 0],
+      ]);
+    });
+
+    it("filters scoped symbols by name and kind", async () => {
+      const service = createService();
+
+      const byName = await service.scopeToDiff({
+        repository: "test-repo",
+        base_ref: "main",
+        query: "EXP",
+      });
+      const byKind = await service.scopeToDiff({
+        repository: "test-repo",
+        base_ref: "main",
+        kind: "class",
+      });
+
+      expect(byName.files[0]!.symbols.map((s) => s.name)).toEqual(["expire"]);
+      expect(byKind.files[0]!.symbols.map((s) => s.name)).toEqual(["Session"]);
+    });
+
+    it("rejects local-folder repositories", async () => {
+      const service = createService({ ...testRepo, source: "local-folder" });
+
+      await expect(
+        service.scopeToDiff({ repository: "test-repo", base_ref: "main" })
+      ).rejects.toThrow(/local folder without git history/);
+    });
+  });
+
+  describe("snapshots", () => {
+    const symbolRow = (
+      filePath: string,
+      name: string,
+      startLine: number,
+      overrides: Record<string, unknown> = {}
+    ) => ({
+      filePath,
+      name,
+      entityType: "function",
+      kind: "function",
+      parentName: null,
+      startLine,
+      endLine: startLine + 2,
+      signature: `${name}(): void`,
+      whereClause: null,
+      superclass: null,
+      interfaces: null,
+      typeParameters: null,
+      isUnsafe: false,
+      ...overrides,
+    });
+
+    function createSnapshotService(rows: { current: Record<string, unknown>[] }) {
+      const store = new InMemorySnapshotStore();
+      const adapter = createCypherMockAdapter(() => rows.current);
+      const service = new SymbolDiffServiceImpl(
+        createRepositoryService({ ...testRepo, lastIndexedCommitSha: BASE_SHA }),
+        extractor,
+        adapter,
+        { snapshotStore: store }
+      );
+      return { service, store };
+    }
+
+    it("stores a fingerprint of every indexed symbol", async () => {
+      const rows = {
+        current: [
+          symbolRow("src/auth.ts", "login", 1),
+          symbolRow("src/auth.ts", "refresh", 8, { kind: "method", parentName: "Session" }),
+        ],
+      };
+      const { service, store } = createSnapshotService(rows);
+
+      const result = await service.createSnapshot({ repository: "test-repo", name: "v1" });
+
+      expect(result).toMatchObject({
+        repository: "test-repo",
+        name: "v1",
+        commit_sha: BASE_SHA,
+        symbol_count: 2,
+        replaced: false,
+      });
+      const saved = store.snapshots.get("test-repo/v1");
+      expect(Object.keys(saved?.symbols ?? {}).sort()).toEqual([
+        "src/auth.ts::Session::refresh",
+        "src/auth.ts::login",
+      ]);
+
+      const again = await service.createSnapshot({ repository: "test-repo", name: "v1" });
+      expect(again.replaced).toBe(true);
+    });
+
+    it("reports symbols added, removed and changed in signature since a snapshot", async () => {
+      const rows = {
+        current: [
+          symbolRow("src/auth.ts", "login", 1),
+          symbolRow("src/auth.ts", "logout", 5),
+          symbolRow("src/util.ts", "helper", 1),
+        ],
+      };
+      const { service } = createSnapshotService(rows);
+      await service.createSnapshot({ repository: "test-repo", name: "v1" });
+
+      rows.current = [
+        // Moved and body-only edits keep the fingerprint
+        symbolRow("src/auth.ts", "login", 10, { endLine: 30 }),
+        symbolRow("src/auth.ts", "logout", 20, { signature: "logout(reason: string): void" }),
+        symbolRow("src/util.ts", "format", 1),
+      ];
+      const result = await service.whatChanged({ repository: "test-repo", name: "v1" });
+
+      expect(result.changes.map((c) => [c.symbol, c.change])).toEqual([
+        ["src/auth.ts::logout", "modified"],
+        ["src/util.ts::format", "added"],
+        ["src/util.ts::helper", "removed"],
+      ]);
+      expect(result.changes[0]).toMatchObject({
+        file_path: "src/auth.ts",
+        name: "logout",
+        kind: "function",
+        location: { start_line: 20, end_line: 22 },
+      });
+      expect(result.changes[2]).toEqual({
+        symbol: "src/util.ts::helper",
+        file_path: "src/util.ts",
+        name: "helper",
+        change: "removed",
+      });
+      expect(result.summary).toEqual({ added: 1, removed: 1, modified: 1 });
+      expect(result.snapshot_commit_sha).toBe(BASE_SHA);
+    });
+
+    it("keys repeated symbol paths by occurrence", async () => {
+      const rows = {
+        current: [symbolRow("src/a.ts", "parse", 1), symbolRow("src/a.ts", "parse", 10)],
+      };
+      const { service, store } = createSnapshotService(rows);
+
+      await service.createSnapshot({ repository: "test-repo", name: "v1" });
+
+      expect(Object.keys(store.snapshots.get("test-repo/v1")?.symbols ?? {})).toEqual([
+        "src/a.ts::parse",
+        "src/a.ts::parse#2",
+      ]);
+    });
+
+    it("only compares symbols beneath the path filter", async () => {
+      const rows = { current: [symbolRow("src/auth.ts", "login", 1)] };
+      const { service } = createSnapshotService(rows);
+      await service.createSnapshot({ repository: "test-repo", name: "v1" });
+
+      rows.current = [symbolRow("lib/other.ts", "added", 1)];
+      const result = await service.whatChanged({
+        repository: "test-repo",
+        name: "v1",
+        path: "src",
+      });
+
+      expect(result.changes.map((c) => [c.symbol, c.change])).toEqual([
+        ["src/auth.ts::login", "removed"],
+      ]);
+      expect(result.metadata).toMatchObject({ snapshot_symbol_count: 1, current_symbol_count: 0 });
+    });
+
+    it("rejects unknown snapshots and invalid names", async () => {
+      const { service } = createSnapshotService({ current: [] });
+
+      await expect(service.whatChanged({ repository: "test-repo", name: "v9" })).rejects.toThrow(
+        /Snapshot 'v9' not found/
+      );
+      await expect(
+        service.createSnapshot({ repository: "test-repo", name: "../escape" })
+      ).rejects.toThrow(GraphServiceValidationError);
+    });
+
+    it("requires graph storage", async () => {
+      const service = new SymbolDiffServiceImpl(
+        createRepositoryService(testRepo),
+        extractor,
+        undefined,
+        { snapshotStore: new InMemorySnapshotStore() }
+      );
+
+      await expect(service.createSnapshot({ repository: "test-repo", name: "v1" })).rejects.toThrow(
+        GraphServiceOperationError
+      );
+    });
+
+    it("throws RepositoryNotFoundError for unknown repositories", async () => {
+      const { service } = createSnapshotService({ current: [] });
+
+      await expect(service.createSnapshot({ repository: "missing", name: "v1" })).rejects.toThrow(
+        RepositoryNotFoundError
+      );
+    });
+  });
+
+  describe("snapshot history", () => {
+    const symbolRow = (filePath: string, name: string, signature = `${name}(): void`) => ({
+      filePath,
+      name,
+      entityType: "function",
+      kind: "function",
+      parentName: null,
+      startLine: 1,
+      endLine: 3,
+      signature,
+      whereClause: null,
+      superclass: null,
+      interfaces: null,
+      typeParameters: null,
+      isUnsafe: false,
+    });
+
+    function createHistoryService(
+      rows: { current: Record<string, unknown>[] },
+      historyRetention?: number
+    ) {
+      const store = new InMemorySnapshotStore();
+      const service = new SymbolDiffServiceImpl(
+        createRepositoryService({ ...testRepo, lastIndexedCommitSha: BASE_SHA }),
+        extractor,
+        createCypherMockAdapter(() => rows.current),
+        { snapshotStore: store, historyRetention }
+      );
+      return { service, store };
+    }
+
+    it("records symbol counts and changes since the previous entry", async () => {
+      const rows = { current: [symbolRow("src/a.ts", "login"), symbolRow("src/a.ts", "logout")] };
+      const { service, store } = createHistoryService(rows);
+
+      const first = await service.recordSnapshot({ repository: "test-repo" });
+      rows.current = [
+        symbolRow("src/a.ts", "login", "login(user: string): void"),
+        symbolRow("src/b.ts", "format"),
+        symbolRow("src/b.ts", "parse"),
+      ];
+      const second = await service.recordSnapshot({ repository: "test-repo" });
+      const third = await service.recordSnapshot({ repository: "test-repo" });
+
+      expect(first).toMatchObject({
+        symbol_count: 2,
+        commit_sha: BASE_SHA,
+        changes: { added: 0, removed: 0, modified: 0 },
+        history_length: 1,
+      });
+      expect(second.changes).toEqual({ added: 2, removed: 1, modified: 1 });
+      expect(third.changes).toEqual({ added: 0, removed: 0, modified: 0 });
+      expect(third.digest).toBe(second.digest);
+      expect(second.digest).not.toBe(first.digest);
+      expect(Object.keys(store.histories.get("test-repo")?.latestSymbols ?? {}).sort()).toEqual([
+        "src/a.ts::login",
+        "src/b.ts::format",
+        "src/b.ts::parse",
+      ]);
+    });
+
+    it("drops the oldest entries beyond the retention limit", async () => {
+      const { service, store } = createHistoryService({ current: [] }, 2);
+
+      await service.recordSnapshot({ repository: "test-repo" });
+      await service.recordSnapshot({ repository: "test-repo" });
+      const result = await service.recordSnapshot({ repository: "test-repo" });
+
+      expect(result).toMatchObject({ history_length: 2, pruned: 1 });
+      expect(store.histories.get("test-repo")?.entries).toHaveLength(2);
+    });
+
+    it("reports the trend across the most recent entries", async () => {
+      const rows = { current: [symbolRow("src/a.ts", "login")] };
+      const { service } = createHistoryService(rows);
+      await service.recordSnapshot({ repository: "test-repo" });
+      rows.current = [symbolRow("src/a.ts", "login"), symbolRow("src/a.ts", "logout")];
+      await service.recordSnapshot({ repository: "test-repo" });
+      rows.current = [
+        symbolRow("src/a.ts", "logout", "logout(reason: string): void"),
+        symbolRow("src/b.ts", "format"),
+        symbolRow("src/b.ts", "parse"),
+      ];
+      await service.recordSnapshot({ repository: "test-repo" });
+
+      const all = await service.getSnapshotHistory({ repository: "test-repo" });
+      const recent = await service.getSnapshotHistory({ repository: "test-repo", limit: 2 });
+
+      expect(all.entries.map((e) => [e.symbol_count, e.symbol_delta, e.change_count])).toEqual([
+        [1, 0, 0],
+        [2, 1, 1],
+        [3, 1, 4],
+      ]);
+      expect(all.trend).toMatchObject({
+        symbol_count_delta: 2,
+        changes: { added: 3, removed: 1, modified: 1 },
+      });
+      expect(recent.entries.map((e) => e.symbol_delta)).toEqual([1, 1]);
+      expect(recent.trend).toMatchObject({
+        symbol_count_delta: 1,
+        changes: { added: 2, removed: 1, modified: 1 },
+      });
+      expect(recent.metadata).toMatchObject({ total_entries: 3, retention: 100 });
+    });
+
+    it("returns an empty history before anything is recorded", async () => {
+      const { service } = createHistoryService({ current: [] });
+
+      const result = await service.getSnapshotHistory({ repository: "test-repo" });
+
+      expect(result.entries).toEqual([]);
+      expect(result.trend).toBeNull();
+      await expect(service.getSnapshotHistory({ repository: "missing" })).rejects.toThrow(
+        RepositoryNotFoundError
+      );
+    });
+  });
+});
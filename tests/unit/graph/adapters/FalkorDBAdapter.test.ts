@@ -860,6 +860,28 @@ describe("FalkorDBAdapter", () => {
       expect(result.impactScore).toBeLessThanOrEqual(1);
     });
 
+    test("should keep repeated entities when dedup is false", async () => {
+      mockGraph.setQueryResult("MATCH", {
+        metadata: [],
+        data: [
+          { dep: sampleMockFalkorNodes.function, relType: "CALLS", depth: 1 },
+          { dep: sampleMockFalkorNodes.function, relType: "REFERENCES", depth: 1 },
+        ],
+      });
+      const input = {
+        target: { type: "file" as const, identifier: "src/index.ts", repository: "test-repo" },
+        direction: "dependedOnBy" as const,
+      };
+
+      const deduplicated = await adapter.analyzeDependencies(input);
+      const repeated = await adapter.analyzeDependencies({ ...input, dedup: false });
+
+      expect(deduplicated.direct).toHaveLength(1);
+      expect(repeated.direct.map((dep) => dep.relationshipType)).toEqual(["CALLS", "REFERENCES"]);
+      expect(repeated.metadata.directCount).toBe(1);
+      expect(repeated.impactScore).toBe(deduplicated.impactScore);
+    });
+
     test("should throw when not connected", async () => {
       const disconnectedAdapter = new FalkorDBAdapter(testFalkorConfig);
 
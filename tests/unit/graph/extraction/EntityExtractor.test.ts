@@ -8,10 +8,12 @@
 import { describe, it, expect, beforeAll, afterAll } from "bun:test";
 import path from "node:path";
 import { EntityExtractor } from "../../../../src/graph/extraction/EntityExtractor.js";
+import { parseFileGuards, parseTodoTags } from "../../../../src/graph/extraction/types.js";
 import { LanguageLoader } from "../../../../src/graph/parsing/LanguageLoader.js";
 import {
   LanguageNotSupportedError,
   FileTooLargeError,
+  FileTooLongError,
 } from "../../../../src/graph/parsing/errors.js";
 import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
 
@@ -98,6 +100,22 @@ describe("EntityExtractor", () => {
       expect(result.entities.every((e) => e.type === "class")).toBe(true);
     });
 
+    it("should filter by symbol kind across languages", async () => {
+      const rust = await extractor.extractFromContent(
+        "struct Bot;\nimpl Bot { fn run(&self) {} }\nfn helper() {}",
+        "bot.rs",
+        { kinds: ["method"] }
+      );
+      expect(rust.entities.map((e) => e.name)).toEqual(["run"]);
+
+      const python = await extractor.extractFromContent(
+        "class Bot:\n    def run(self):\n        pass\n\ndef helper():\n    pass",
+        "bot.py",
+        { kinds: ["method"] }
+      );
+      expect(python.entities.map((e) => e.name)).toEqual(["run"]);
+    });
+
     it("should filter for exported entities only", async () => {
       const content = await Bun.file(path.join(FIXTURES_DIR, "simple-function.ts")).text();
       const result = await extractor.extractFromContent(content, "simple-function.ts", {
@@ -124,6 +142,53 @@ describe("EntityExtractor", () => {
     });
   });
 
+  describe("TODO comments", () => {
+    it("should pass parser TODO markers through to the result", async () => {
+      const result = await extractor.extractFromContent("// FIXME: leak\nconst a = 1;", "a.ts");
+
+      expect(result.todos).toEqual([{ tag: "FIXME", message: "leak", line: 1 }]);
+    });
+
+    it("should parse comma-separated tag lists", () => {
+      expect(parseTodoTags("TODO, HACK,,XXX ")).toEqual(["TODO", "HACK", "XXX"]);
+      expect(parseTodoTags("")).toEqual([]);
+      expect(parseTodoTags(undefined)).toEqual(["TODO", "FIXME"]);
+    });
+  });
+
+  describe("file guards", () => {
+    it("should apply per-language guards over the global defaults", async () => {
+      const guardedExtractor = new EntityExtractor({
+        maxFileSizeBytes: 100,
+        languageGuards: {
+          rust: { maxFileSizeBytes: 10000 },
+          javascript: { maxLines: 3 },
+        },
+      });
+      const rust = `fn helper() {}\n${"// padding\n".repeat(20)}`;
+
+      const result = await guardedExtractor.extractFromContent(rust, "big.rs");
+
+      expect(result.entities.map((e) => e.name)).toEqual(["helper"]);
+      await expect(
+        guardedExtractor.extractFromContent("a();\nb();\nc();\nd();", "bundle.js")
+      ).rejects.toBeInstanceOf(FileTooLongError);
+      await expect(guardedExtractor.extractFromContent(rust, "big.py")).rejects.toBeInstanceOf(
+        FileTooLargeError
+      );
+    });
+
+    it("should parse guards keyed by language with a default entry", () => {
+      expect(
+        parseFileGuards('{"default":{"maxLines":20000},"rust":{"maxFileSizeBytes":4194304}}')
+      ).toEqual({ maxLines: 20000, languageGuards: { rust: { maxFileSizeBytes: 4194304 } } });
+      expect(parseFileGuards(undefined)).toEqual({});
+      expect(() => parseFileGuards('{"cobol":{"maxLines":1}}')).toThrow("Unknown language");
+      expect(() => parseFileGuards('{"rust":{"maxLines":-1}}')).toThrow("non-negative integer");
+      expect(() => parseFileGuards("{")).toThrow("Invalid file guards JSON");
+    });
+  });
+
   describe("extractFunctions", () => {
     it("should extract only functions and methods", async () => {
       const content = await Bun.file(path.join(FIXTURES_DIR, "simple-function.ts")).text();
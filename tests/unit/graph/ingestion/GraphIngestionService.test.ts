@@ -409,6 +409,84 @@ describe("GraphIngestionService", () => {
       relSpy.mockRestore();
     });
 
+    it("should record workspace crates on repository and file nodes", async () => {
+      const options: GraphIngestionOptions = {
+        repository: "test-repo",
+        repositoryUrl: "https://github.com/test/test-repo",
+        crateRoots: [
+          { name: "crate-a", path: "crate-a" },
+          { name: "crate-b", path: "crate-b" },
+        ],
+      };
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        createSampleExtractionResult("crate-b/src/lib.rs")
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("crate-b/src/lib.rs")
+      );
+
+      await service.ingestFiles([createSampleFileInput("crate-b/src/lib.rs")], options);
+
+      const repositoryCall = runQuery.mock.calls.find((call) =>
+        String(call[0]).includes("MERGE (r:Repository")
+      );
+      expect(repositoryCall?.[1]).toMatchObject({
+        crateNames: ["crate-a", "crate-b"],
+        cratePaths: ["crate-a", "crate-b"],
+      });
+      const fileCall = runQuery.mock.calls.find((call) =>
+        String(call[0]).includes("MERGE (f:File")
+      );
+      expect(fileCall?.[1]).toMatchObject({ path: "crate-b/src/lib.rs", crateName: "crate-b" });
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should tag build scripts and proc-macro crates as build-time code", async () => {
+      const options: GraphIngestionOptions = {
+        repository: "test-repo",
+        repositoryUrl: "https://github.com/test/test-repo",
+        buildOrMacroPaths: ["macros"],
+      };
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+      const paths = ["build.rs", "macros/src/lib.rs", "src/build.rs"];
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockImplementation(
+        (_content: string, filePath: string) =>
+          Promise.resolve(createSampleExtractionResult(filePath))
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockImplementation(
+        (_content: string, filePath: string) =>
+          Promise.resolve(createSampleRelationshipResult(filePath))
+      );
+
+      await service.ingestFiles(paths.map((path) => createSampleFileInput(path)), options);
+
+      const repositoryCall = runQuery.mock.calls.find((call) =>
+        String(call[0]).includes("MERGE (r:Repository")
+      );
+      expect(repositoryCall?.[1]).toMatchObject({ buildOrMacroPaths: ["macros"] });
+      const fileTags = runQuery.mock.calls
+        .filter((call) => String(call[0]).includes("MERGE (f:File"))
+        .map((call) => [call[1].path, call[1].buildOrMacro]);
+      expect(fileTags).toEqual([
+        ["build.rs", true],
+        ["macros/src/lib.rs", true],
+        ["src/build.rs", false],
+      ]);
+      const entityTags = runQuery.mock.calls
+        .filter((call) => String(call[0]).includes("e.buildOrMacro = $buildOrMacro"))
+        .map((call) => [call[1].filePath, call[1].buildOrMacro]);
+      expect(entityTags).toContainEqual(["macros/src/lib.rs", true]);
+      expect(entityTags).toContainEqual(["src/build.rs", false]);
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
     it("should ingest files successfully", async () => {
       const progressUpdates: GraphIngestionProgress[] = [];
       const options: GraphIngestionOptions = {
@@ -592,6 +670,974 @@ describe("GraphIngestionService", () => {
       relSpy.mockRestore();
     });
 
+    it("should batch Todo nodes linked to the file into one query", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue({
+        ...createSampleExtractionResult("test.ts"),
+        todos: [
+          { tag: "FIXME", message: "handle overflow", line: 3 },
+          { tag: "TODO", message: "cache", line: 3 },
+          { tag: "TODO", message: "and invalidate", line: 3 },
+        ],
+      });
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("test.ts")
+      );
+
+      await service.ingestFile(createSampleFileInput("test.ts"), "test-repo");
+
+      const todoCalls = runQuery.mock.calls.filter((call) =>
+        String(call[0]).includes("MERGE (t:Todo")
+      );
+      expect(todoCalls).toHaveLength(1);
+      expect(String(todoCalls[0]?.[0])).toContain("MERGE (f)-[:HAS_TODO]->(t)");
+      const params = todoCalls[0]?.[1] as { todos: Array<Record<string, unknown>> };
+      // Same line and tag: the ordinal keeps the ids apart
+      expect(params.todos).toEqual([
+        {
+          id: "Todo:test-repo:test.ts:3:FIXME:0",
+          tag: "FIXME",
+          message: "handle overflow",
+          line: 3,
+        },
+        { id: "Todo:test-repo:test.ts:3:TODO:0", tag: "TODO", message: "cache", line: 3 },
+        { id: "Todo:test-repo:test.ts:3:TODO:1", tag: "TODO", message: "and invalidate", line: 3 },
+      ]);
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should batch string literals into one query per file", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue({
+        ...createSampleExtractionResult("test.ts"),
+        strings: [
+          { value: "Session expired", line: 5, column: 10, enclosingSymbol: "refresh" },
+          { value: "v1", line: 1, column: 14 },
+        ],
+      });
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("test.ts")
+      );
+
+      await service.ingestFile(createSampleFileInput("test.ts"), "test-repo");
+
+      const literalCalls = runQuery.mock.calls.filter((call) =>
+        String(call[0]).includes("MERGE (s:StringLiteral")
+      );
+      expect(literalCalls).toHaveLength(1);
+      expect(String(literalCalls[0]?.[0])).toContain("MERGE (f)-[:HAS_STRING_LITERAL]->(s)");
+      const params = literalCalls[0]?.[1] as { literals: Array<Record<string, unknown>> };
+      expect(params.literals).toEqual([
+        {
+          id: "StringLiteral:test-repo:test.ts:5:10",
+          value: "Session expired",
+          line: 5,
+          column: 10,
+          enclosingSymbol: "refresh",
+        },
+        {
+          id: "StringLiteral:test-repo:test.ts:1:14",
+          value: "v1",
+          line: 1,
+          column: 14,
+          enclosingSymbol: null,
+        },
+      ]);
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should create CALLS relationships between functions of the file", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const base = createSampleExtractionResult("test.ts");
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue({
+        ...base,
+        entities: [
+          ...base.entities,
+          {
+            name: "caller",
+            type: "function",
+            filePath: "test.ts",
+            lineStart: 22,
+            lineEnd: 26,
+            isExported: false,
+          },
+        ],
+        calls: [
+          {
+            calledName: "testFunction",
+            calledExpression: "testFunction",
+            isAsync: false,
+            line: 24,
+          },
+        ],
+      });
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("test.ts")
+      );
+
+      await service.ingestFile(createSampleFileInput("test.ts"), "test-repo");
+
+      const callsQuery = runQuery.mock.calls.find((c) => String(c[0]).includes("[r:CALLS]"));
+      expect(callsQuery).toBeDefined();
+      expect(callsQuery?.[1]).toEqual({
+        edges: [
+          {
+            fromId: "Function:test-repo:test.ts:caller:22",
+            toId: "Function:test-repo:test.ts:testFunction:1",
+            callCount: 1,
+            line: 24,
+            typeArguments: [],
+            callSiteLines: null,
+            callSiteArguments: null,
+          },
+        ],
+      });
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should store call site arguments when capture is enabled", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const capturingService = new GraphIngestionService(
+        mockNeo4jClient,
+        mockEntityExtractor,
+        mockRelationshipExtractor,
+        { captureCallArguments: true }
+      );
+      const base = createSampleExtractionResult("test.ts");
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue({
+        ...base,
+        entities: [
+          ...base.entities,
+          {
+            name: "caller",
+            type: "function",
+            filePath: "test.ts",
+            lineStart: 22,
+            lineEnd: 27,
+            isExported: false,
+          },
+        ],
+        calls: [
+          {
+            calledName: "testFunction",
+            calledExpression: "testFunction",
+            isAsync: false,
+            line: 25,
+            arguments: ["x".repeat(250)],
+          },
+          {
+            calledName: "testFunction",
+            calledExpression: "testFunction",
+            isAsync: false,
+            line: 24,
+            arguments: ['"World"', "3"],
+          },
+        ],
+      });
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("test.ts")
+      );
+
+      await capturingService.ingestFile(createSampleFileInput("test.ts"), "test-repo");
+
+      const callsQuery = runQuery.mock.calls.find((c) => String(c[0]).includes("[r:CALLS]"));
+      const params = callsQuery?.[1] as { edges: Record<string, unknown>[] } | undefined;
+      expect(params?.edges[0]).toMatchObject({
+        callCount: 2,
+        line: 24,
+        callSiteLines: [24, 25],
+        callSiteArguments: ['["\\"World\\"","3"]', JSON.stringify(["x".repeat(200)])],
+      });
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should store identifier subtokens on entity nodes", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        createSampleExtractionResult("test.ts")
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("test.ts")
+      );
+
+      await service.ingestFile(createSampleFileInput("test.ts"), "test-repo");
+
+      const entityCall = runQuery.mock.calls.find((call) =>
+        String(call[0]).includes("e.nameTokens = $nameTokens")
+      );
+      expect(entityCall?.[1]).toMatchObject({
+        name: "testFunction",
+        nameTokens: ["test", "function"],
+      });
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should store structured parameters and return types on function nodes", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        createSampleExtractionResult("test.ts")
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("test.ts")
+      );
+
+      await service.ingestFile(createSampleFileInput("test.ts"), "test-repo");
+
+      const entityCalls = runQuery.mock.calls.filter((call) =>
+        String(call[0]).includes("e.paramNames = $paramNames")
+      );
+      expect(entityCalls.find((call) => call[1].name === "testFunction")?.[1]).toMatchObject({
+        paramNames: ["x"],
+        paramTypes: ["number"],
+        returnType: "number",
+        isAsync: false,
+      });
+      expect(entityCalls.find((call) => call[1].name === "TestClass")?.[1]).toMatchObject({
+        paramNames: null,
+        paramTypes: null,
+        returnType: null,
+        isAsync: null,
+      });
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should store normalized signatures and keep the raw text", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+      const extraction: ExtractionResult = {
+        ...createSampleExtractionResult("lib.rs"),
+        entities: [
+          {
+            name: "testFunction",
+            type: "function",
+            filePath: "lib.rs",
+            lineStart: 1,
+            lineEnd: 5,
+            isExported: true,
+            metadata: {
+              parameters: [
+                {
+                  name: "pair",
+                  type: "Pair< K ,V >",
+                  hasDefault: false,
+                  isOptional: false,
+                  isRest: false,
+                },
+              ],
+              returnType: "Result<(),Error>",
+              whereClause: "where K : Clone,\n    V: Send",
+            },
+          },
+        ],
+      };
+      const rawService = new GraphIngestionService(
+        mockNeo4jClient,
+        mockEntityExtractor,
+        mockRelationshipExtractor,
+        { normalizeSignatures: false }
+      );
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        extraction
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("lib.rs")
+      );
+      const signatureParams = (): Record<string, unknown> | undefined =>
+        runQuery.mock.calls
+          .filter((call) => String(call[0]).includes("e.rawSignature = $rawSignature"))
+          .map((call) => call[1] as Record<string, unknown>)
+          .find((params) => params["name"] === "testFunction");
+
+      await service.ingestFile(createSampleFileInput("lib.rs"), "test-repo");
+      expect(signatureParams()).toMatchObject({
+        signature: "testFunction(pair: Pair<K, V>): Result<(), Error>",
+        rawSignature: "testFunction(pair: Pair< K ,V >): Result<(),Error>",
+        whereClause: "where K: Clone, V: Send",
+      });
+
+      runQuery.mockClear();
+      await rawService.ingestFile(createSampleFileInput("lib.rs"), "test-repo");
+      expect(signatureParams()).toMatchObject({
+        signature: "testFunction(pair: Pair< K ,V >): Result<(),Error>",
+        whereClause: "where K : Clone,\n    V: Send",
+      });
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should flag async functions and keep their declared return type", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const extraction = createSampleExtractionResult("fetch.rs");
+      extraction.entities[0]!.metadata = {
+        isAsync: true,
+        returnType: "Result<String, io::Error>",
+      };
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        extraction
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("fetch.rs")
+      );
+
+      await service.ingestFile(createSampleFileInput("fetch.rs"), "test-repo");
+
+      const entityCall = runQuery.mock.calls.find(
+        (call) =>
+          String(call[0]).includes("e.isAsync = $isAsync") && call[1].name === "testFunction"
+      );
+      expect(entityCall?.[1]).toMatchObject({
+        isAsync: true,
+        returnType: "Result<String, io::Error>",
+      });
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should store let bindings as parallel lists on function nodes", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const extraction = createSampleExtractionResult("calls.rs");
+      extraction.entities[0]!.metadata = {
+        locals: [
+          { name: "result", line: 3 },
+          { name: "point", type: "Point", line: 4 },
+        ],
+      };
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        extraction
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("calls.rs")
+      );
+
+      await service.ingestFile(createSampleFileInput("calls.rs"), "test-repo");
+
+      const entityCalls = runQuery.mock.calls.filter((call) =>
+        String(call[0]).includes("e.localNames = $localNames")
+      );
+      expect(entityCalls.find((call) => call[1].name === "testFunction")?.[1]).toMatchObject({
+        localNames: ["result", "point"],
+        localTypes: ["", "Point"],
+        localLines: [3, 4],
+      });
+      expect(entityCalls.find((call) => call[1].name === "TestClass")?.[1]).toMatchObject({
+        localNames: null,
+        localTypes: null,
+        localLines: null,
+      });
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should store attributes, cfg and entry point kind on entity nodes", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const extraction = createSampleExtractionResult("main.rs");
+      extraction.entities[0]!.metadata = {
+        attributes: ["cfg(unix)", "tokio::main"],
+        cfg: "unix",
+        entryPoint: { kind: "async_main", attribute: "tokio::main" },
+      };
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        extraction
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("main.rs")
+      );
+
+      await service.ingestFile(createSampleFileInput("main.rs"), "test-repo");
+
+      const entityCalls = runQuery.mock.calls.filter((call) =>
+        String(call[0]).includes("e.entryPointKind = $entryPointKind")
+      );
+      expect(entityCalls.find((call) => call[1].name === "testFunction")?.[1]).toMatchObject({
+        attributes: ["cfg(unix)", "tokio::main"],
+        cfg: "unix",
+        entryPointKind: "async_main",
+      });
+      expect(entityCalls.find((call) => call[1].name === "TestClass")?.[1]).toMatchObject({
+        attributes: null,
+        cfg: null,
+        entryPointKind: null,
+      });
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should store visibility on entity nodes and re-export edges", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const extraction = createSampleExtractionResult("lib.rs");
+      extraction.entities[0]!.metadata = { visibility: "pub(crate)" };
+      const relationships = createSampleRelationshipResult("lib.rs");
+      relationships.imports[0]!.importInfo.visibility = "pub";
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        extraction
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        relationships
+      );
+
+      await service.ingestFile(createSampleFileInput("lib.rs"), "test-repo");
+
+      const entityCalls = runQuery.mock.calls.filter((call) =>
+        String(call[0]).includes("e.visibility = $visibility")
+      );
+      expect(entityCalls.find((call) => call[1].name === "testFunction")?.[1].visibility).toBe(
+        "pub(crate)"
+      );
+      expect(entityCalls.find((call) => call[1].name === "TestClass")?.[1].visibility).toBeNull();
+      const importCall = runQuery.mock.calls.find((call) =>
+        String(call[0]).includes("r.visibility = $visibility")
+      );
+      expect(importCall?.[1].visibility).toBe("pub");
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should store unsafe, complexity and fingerprint properties on entity nodes", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const extraction = createSampleExtractionResult("ffi.rs");
+      extraction.entities[0]!.metadata = {
+        isUnsafe: true,
+        unsafeBlocks: [
+          { lineStart: 2, lineEnd: 2 },
+          { lineStart: 3, lineEnd: 4 },
+        ],
+        complexity: 3,
+        structure: { hash: "0123456789abcdef", size: 42, minHash: [7, 9] },
+      };
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        extraction
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("ffi.rs")
+      );
+
+      await service.ingestFile(createSampleFileInput("ffi.rs"), "test-repo");
+
+      const entityCalls = runQuery.mock.calls.filter((call) =>
+        String(call[0]).includes("e.isUnsafe = $isUnsafe")
+      );
+      expect(entityCalls.find((call) => call[1].name === "testFunction")?.[1]).toMatchObject({
+        isUnsafe: true,
+        unsafeBlockStarts: [2, 3],
+        unsafeBlockEnds: [2, 4],
+        complexity: 3,
+        structureHash: "0123456789abcdef",
+        structureSize: 42,
+        structureMinHash: [7, 9],
+      });
+      expect(entityCalls.find((call) => call[1].name === "TestClass")?.[1]).toMatchObject({
+        isUnsafe: false,
+        unsafeBlockStarts: [],
+        unsafeBlockEnds: [],
+        complexity: null,
+        structureHash: null,
+        structureMinHash: null,
+      });
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should store literal constant values on entity nodes", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const extraction = createSampleExtractionResult("config.rs");
+      extraction.entities[0]!.metadata = {
+        value: { kind: "integer", value: 1024 },
+        valueText: "1_024usize",
+      };
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        extraction
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("config.rs")
+      );
+
+      await service.ingestFile(createSampleFileInput("config.rs"), "test-repo");
+
+      const entityCalls = runQuery.mock.calls.filter((call) =>
+        String(call[0]).includes("e.valueKind = $valueKind")
+      );
+      expect(entityCalls.find((call) => call[1].name === "testFunction")?.[1]).toMatchObject({
+        valueKind: "integer",
+        value: 1024,
+        valueText: "1_024usize",
+      });
+      expect(entityCalls.find((call) => call[1].name === "TestClass")?.[1]).toMatchObject({
+        valueKind: null,
+        value: null,
+        valueText: null,
+      });
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should store visibility and documentation on entity nodes", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const extraction = createSampleExtractionResult("shapes.rs");
+      extraction.entities[0]!.metadata = {
+        documentation: "/// Draws the shape.",
+        doc: "Draws the shape.",
+        implementedTrait: "Draw",
+      };
+      extraction.entities[1]!.isExported = false;
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        extraction
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("shapes.rs")
+      );
+
+      await service.ingestFile(createSampleFileInput("shapes.rs"), "test-repo");
+
+      const entityCalls = runQuery.mock.calls.filter((call) =>
+        String(call[0]).includes("e.doc = $doc")
+      );
+      expect(entityCalls.find((call) => call[1].name === "testFunction")?.[1]).toMatchObject({
+        isExported: true,
+        doc: "Draws the shape.",
+        implementedTrait: "Draw",
+      });
+      expect(entityCalls.find((call) => call[1].name === "TestClass")?.[1]).toMatchObject({
+        isExported: false,
+        doc: null,
+        implementedTrait: null,
+      });
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should store the module doc on the File node", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue({
+        ...createSampleExtractionResult("vector.rs"),
+        moduleDoc: "Vector arithmetic.",
+      });
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("vector.rs")
+      );
+
+      await service.ingestFile(createSampleFileInput("vector.rs"), "test-repo");
+
+      const fileCall = runQuery.mock.calls.find((call) =>
+        String(call[0]).includes("f.moduleDoc = $moduleDoc")
+      );
+      expect(fileCall?.[1].moduleDoc).toBe("Vector arithmetic.");
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should store name ranges, columns and enum variant ranges on entity nodes", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const extraction = createSampleExtractionResult("shapes.rs");
+      extraction.entities[1] = {
+        ...extraction.entities[1]!,
+        type: "enum",
+        columnStart: 0,
+        columnEnd: 1,
+        nameRange: { lineStart: 1, lineEnd: 1, columnStart: 9, columnEnd: 18 },
+        metadata: {
+          variants: [
+            {
+              name: "Circle",
+              line: 2,
+              range: { lineStart: 2, lineEnd: 2, columnStart: 4, columnEnd: 15 },
+            },
+            { name: "Square", line: 3 },
+          ],
+        },
+      };
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        extraction
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("shapes.rs")
+      );
+
+      await service.ingestFile(createSampleFileInput("shapes.rs"), "test-repo");
+
+      const entityCalls = runQuery.mock.calls.filter((call) =>
+        String(call[0]).includes("e.variantNames = $variantNames")
+      );
+      expect(entityCalls.find((call) => call[1].name === "TestClass")?.[1]).toMatchObject({
+        startColumn: 0,
+        endColumn: 1,
+        nameLine: 1,
+        nameStartColumn: 9,
+        nameEndColumn: 18,
+        variantNames: ["Circle", "Square"],
+        variantStartLines: [2, 3],
+        variantStartColumns: [4, 0],
+        variantEndLines: [2, 3],
+        variantEndColumns: [15, 0],
+      });
+      expect(entityCalls.find((call) => call[1].name === "testFunction")?.[1]).toMatchObject({
+        nameLine: null,
+        variantNames: null,
+        variantStartLines: null,
+      });
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should store only line numbers for files decoded lossily", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const extraction = createSampleExtractionResult("latin1.rs");
+      extraction.entities[0] = {
+        ...extraction.entities[0]!,
+        columnStart: 0,
+        columnEnd: 1,
+        nameRange: { lineStart: 1, lineEnd: 1, columnStart: 7, columnEnd: 19 },
+      };
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        extraction
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("latin1.rs")
+      );
+
+      await service.ingestFile(
+        {
+          ...createSampleFileInput("latin1.rs"),
+          encodingWarning: "1 invalid UTF-8 byte on 1 line, first on line 1",
+        },
+        "test-repo"
+      );
+
+      const fileCall = runQuery.mock.calls.find((call) =>
+        String(call[0]).includes("f.encodingWarning = $encodingWarning")
+      );
+      expect(fileCall?.[1].encodingWarning).toBe("1 invalid UTF-8 byte on 1 line, first on line 1");
+      const entityCall = runQuery.mock.calls.find(
+        (call) =>
+          String(call[0]).includes("e.startColumn = $startColumn") &&
+          call[1].name === "testFunction"
+      );
+      expect(entityCall?.[1]).toMatchObject({
+        startLine: extraction.entities[0]!.lineStart,
+        startColumn: null,
+        endColumn: null,
+        nameLine: 1,
+        nameStartColumn: null,
+        nameEndColumn: null,
+      });
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should store supertypes and generic parameters on type nodes", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const extraction = createSampleExtractionResult("Dog.java");
+      extraction.entities[1]!.metadata = {
+        extends: "extends Animal",
+        implements: ["implements", "Pet, Comparable<Dog>"],
+        typeParameters: ["T extends Food"],
+      };
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        extraction
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("Dog.java")
+      );
+
+      await service.ingestFile(createSampleFileInput("Dog.java"), "test-repo");
+
+      const entityCalls = runQuery.mock.calls.filter((call) =>
+        String(call[0]).includes("e.superclass = $superclass")
+      );
+      expect(entityCalls.find((call) => call[1].name === "TestClass")?.[1]).toMatchObject({
+        superclass: "Animal",
+        interfaces: ["Pet", "Comparable<Dog>"],
+        typeParameters: ["T extends Food"],
+        supertraits: [],
+      });
+      expect(entityCalls.find((call) => call[1].name === "testFunction")?.[1]).toMatchObject({
+        superclass: null,
+        interfaces: null,
+        typeParameters: null,
+        supertraits: null,
+      });
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should link traits to the supertraits defined in the repository", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockImplementation(async (query: string) => {
+        if (query.includes("t.supertraits AS supertraits")) {
+          return [
+            {
+              id: "Class:test-repo:lib.rs:Pet",
+              filePath: "lib.rs",
+              supertraits: ["Animal", "std::fmt::Debug", "Clone", "crate::Animal"],
+            },
+          ];
+        }
+        if (query.includes("SUPERTRAIT_OF")) {
+          return [{ linked: 1 }];
+        }
+        return [];
+      });
+
+      const extraction = createSampleExtractionResult("lib.rs");
+      extraction.entities.push({ ...extraction.entities[1]!, name: "Pet", type: "interface" });
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        extraction
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("lib.rs")
+      );
+
+      const result = await service.ingestFile(createSampleFileInput("lib.rs"), "test-repo");
+
+      const linkCall = runQuery.mock.calls.find((call) =>
+        String(call[0]).includes("MERGE (sup)-[:SUPERTRAIT_OF]->(sub)")
+      );
+      expect(linkCall).toBeDefined();
+      expect(linkCall?.[1]).toEqual({
+        repository: "test-repo",
+        edges: [
+          { subId: "Class:test-repo:lib.rs:Pet", name: "Animal" },
+          { subId: "Class:test-repo:lib.rs:Pet", name: "Debug" },
+          { subId: "Class:test-repo:lib.rs:Pet", name: "Clone" },
+        ],
+      });
+      expect(result.success).toBe(true);
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should only relink supertraits that involve the re-ingested file's traits", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockImplementation(async (query: string) => {
+        if (query.includes("t.supertraits AS supertraits")) {
+          return [
+            {
+              id: "Class:test-repo:lib.rs:Pet:3",
+              filePath: "lib.rs",
+              supertraits: ["animal::Animal", "Clone"],
+            },
+            {
+              id: "Class:test-repo:animal.rs:Animal:1",
+              filePath: "animal.rs",
+              supertraits: ["std::fmt::Debug"],
+            },
+          ];
+        }
+        return [];
+      });
+
+      const extraction = createSampleExtractionResult("animal.rs");
+      extraction.entities.push({ ...extraction.entities[1]!, name: "Animal", type: "interface" });
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        extraction
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("animal.rs")
+      );
+
+      await service.ingestFile(createSampleFileInput("animal.rs"), "test-repo");
+
+      const traitQuery = runQuery.mock.calls.find((call) =>
+        String(call[0]).includes("t.supertraits AS supertraits")
+      );
+      expect(traitQuery?.[1]).toEqual({
+        repository: "test-repo",
+        filePath: "animal.rs",
+        traitNames: ["Animal"],
+      });
+      const linkCall = runQuery.mock.calls.find((call) =>
+        String(call[0]).includes("MERGE (sup)-[:SUPERTRAIT_OF]->(sub)")
+      );
+      // Pet's other supertrait (Clone) lives outside this file and is left alone
+      expect(linkCall?.[1]).toEqual({
+        repository: "test-repo",
+        edges: [
+          { subId: "Class:test-repo:lib.rs:Pet:3", name: "Animal" },
+          { subId: "Class:test-repo:animal.rs:Animal:1", name: "Debug" },
+        ],
+      });
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should skip supertrait linking for files that define no traits", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        createSampleExtractionResult("lib.rs")
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("lib.rs")
+      );
+
+      await service.ingestFile(createSampleFileInput("lib.rs"), "test-repo");
+
+      expect(
+        runQuery.mock.calls.some((call) => String(call[0]).includes("t.supertraits AS supertraits"))
+      ).toBe(false);
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should create Impl nodes keyed by the implementing type", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue({
+        ...createSampleExtractionResult("lib.rs"),
+        implementations: [
+          {
+            traitName: "Animal",
+            typeName: "Wrapper<T>",
+            typeParameters: ["T"],
+            whereClause: ["T: Animal"],
+            isNegative: false,
+            isUnsafe: false,
+            line: 12,
+            range: { lineStart: 12, lineEnd: 20, columnStart: 0, columnEnd: 1 },
+          },
+          {
+            traitName: "ToText",
+            typeName: "T",
+            typeParameters: ["T: Display"],
+            whereClause: [],
+            isNegative: false,
+            isUnsafe: false,
+            line: 30,
+          },
+        ],
+      });
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("lib.rs")
+      );
+
+      await service.ingestFile(createSampleFileInput("lib.rs"), "test-repo");
+
+      const implCalls = runQuery.mock.calls.filter((call) =>
+        String(call[0]).includes("MERGE (i:Impl")
+      );
+      expect(implCalls).toHaveLength(1);
+      expect(String(implCalls[0]?.[0])).toContain("MERGE (f)-[:HAS_IMPL]->(i)");
+      const params = implCalls[0]?.[1] as { impls: Array<Record<string, unknown>> };
+      const summary = params.impls.map((impl) => [impl["id"], impl["selfType"], impl["traitName"]]);
+      expect(summary).toEqual([
+        ["Impl:test-repo:lib.rs:12", "Wrapper", "Animal"],
+        ["Impl:test-repo:lib.rs:30", null, "ToText"],
+      ]);
+      expect(params.impls[0]).toMatchObject({ endLine: 20, startColumn: 0, endColumn: 1 });
+      expect(params.impls[1]).toMatchObject({ endLine: null, startColumn: null, endColumn: null });
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should create Doctest nodes linked to the documented entity", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      const extraction = createSampleExtractionResult("lib.rs");
+      extraction.entities[0]!.metadata = {
+        doctests: [
+          {
+            info: "no_run",
+            mode: "no_run",
+            code: "let total = add(1, 2);",
+            lineStart: 3,
+            lineEnd: 3,
+            calls: ["add"],
+            definedSymbols: [],
+          },
+        ],
+      };
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        extraction
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("lib.rs")
+      );
+
+      await service.ingestFile(createSampleFileInput("lib.rs"), "test-repo");
+
+      const doctestCalls = runQuery.mock.calls.filter((call) =>
+        String(call[0]).includes("MERGE (d:Doctest")
+      );
+      expect(doctestCalls).toHaveLength(1);
+      expect(String(doctestCalls[0]?.[0])).toContain("MERGE (d)-[:DOCTEST_OF]->(e)");
+      const params = doctestCalls[0]?.[1] as { doctests: Array<Record<string, unknown>> };
+      expect(params.doctests).toHaveLength(1);
+      expect(params.doctests[0]).toMatchObject({
+        id: "Doctest:test-repo:lib.rs:3",
+        mode: "no_run",
+        calls: ["add"],
+        startLine: 3,
+      });
+      expect(String(params.doctests[0]?.["entityId"])).toContain(":lib.rs:testFunction:");
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
     it("should handle errors in single file ingestion", async () => {
       // Mock runQuery to throw
       (mockNeo4jClient.runQuery as ReturnType<typeof mock>).mockRejectedValue(
@@ -726,6 +1772,140 @@ describe("GraphIngestionService", () => {
       );
       expect(fileCall?.[1]).toEqual({ fileId: "File:my-project:src/components/Button.tsx" });
     });
+
+    it("should store relationships into the file in the graph when it will be re-ingested", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      await service.deleteFileData("test-repo", "test.ts", { reingest: true });
+
+      const deleteQuery = String(runQuery.mock.calls[0]?.[0]);
+      expect(deleteQuery).toContain("CREATE (p:PendingInboundEdge)");
+      expect(deleteQuery).toContain("DETACH DELETE f");
+      // Nothing removes the captured relationships before the ingest
+      const cleanup = /MATCH \(p:PendingInboundEdge[^)]*\)\s+DELETE p/;
+      expect(runQuery.mock.calls.some((c) => cleanup.test(String(c[0])))).toBe(false);
+    });
+
+    it("should drop captured relationships when the file is deleted without re-ingest", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      runQuery.mockResolvedValue([]);
+
+      await service.deleteFileData("test-repo", "test.ts");
+
+      expect(String(runQuery.mock.calls[0]?.[0])).not.toContain("PendingInboundEdge");
+      const cleanup = runQuery.mock.calls.find((c) =>
+        /MATCH \(p:PendingInboundEdge \{pendingFileId: \$fileId\}\)\s+DELETE p/.test(String(c[0]))
+      );
+      expect(cleanup?.[1]).toEqual({ fileId: "File:test-repo:test.ts" });
+    });
+
+    it("should restore calls from other files when the file is re-ingested", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      let pending = [
+        {
+          edge: {
+            callCount: 2,
+            line: 5,
+            pendingFileId: "File:test-repo:test.ts",
+            pendingRepository: "test-repo",
+            pendingSourceId: "Function:test-repo:a.ts:caller:3",
+            pendingType: "CALLS",
+            pendingTargetLabel: "Function",
+            pendingTargetName: "testFunction",
+            pendingTargetLine: 1,
+          },
+        },
+      ];
+      runQuery.mockImplementation(async (query: string) => {
+        if (query.includes("RETURN properties(p) AS edge")) {
+          return pending;
+        }
+        if (/PendingInboundEdge[^)]*\)\s+DELETE p/.test(query)) {
+          pending = [];
+        }
+        return [];
+      });
+
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        createSampleExtractionResult("test.ts")
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("test.ts")
+      );
+
+      await service.ingestFile(createSampleFileInput("test.ts"), "test-repo");
+
+      const readQuery = runQuery.mock.calls.find((c) =>
+        String(c[0]).includes("RETURN properties(p) AS edge")
+      );
+      expect(readQuery?.[1]).toEqual({ fileId: "File:test-repo:test.ts" });
+      const restoreQuery = runQuery.mock.calls.find((c) => String(c[0]).includes("[r:CALLS]"));
+      expect(restoreQuery?.[1]).toEqual({
+        edges: [
+          {
+            sourceId: "Function:test-repo:a.ts:caller:3",
+            targetId: "Function:test-repo:test.ts:testFunction:1",
+            properties: { callCount: 2, line: 5 },
+          },
+        ],
+      });
+
+      // The pending relationships are removed, so a second re-ingest has nothing to restore
+      runQuery.mockClear();
+      await service.ingestFile(createSampleFileInput("test.ts"), "test-repo");
+      expect(runQuery.mock.calls.some((c) => String(c[0]).includes("[r:CALLS]"))).toBe(false);
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
+
+    it("should restore implementations of a re-ingested type from other files", async () => {
+      const runQuery = mockNeo4jClient.runQuery as ReturnType<typeof mock>;
+      const implementation = (sourceId: string, targetName: string, targetLine: number) => ({
+        edge: {
+          pendingFileId: "File:test-repo:test.ts",
+          pendingRepository: "test-repo",
+          pendingSourceId: sourceId,
+          pendingType: "IMPLEMENTS",
+          pendingTargetLabel: "Class",
+          pendingTargetName: targetName,
+          pendingTargetLine: targetLine,
+        },
+      });
+      runQuery.mockImplementation(async (query: string) =>
+        query.includes("RETURN properties(p) AS edge")
+          ? [
+              implementation("Class:test-repo:impl.ts:Widget:2", "TestClass", 3),
+              implementation("Class:test-repo:impl.ts:Gadget:9", "RemovedClass", 12),
+            ]
+          : []
+      );
+
+      const entitySpy = spyOn(mockEntityExtractor, "extractFromContent").mockResolvedValue(
+        createSampleExtractionResult("test.ts")
+      );
+      const relSpy = spyOn(mockRelationshipExtractor, "extractFromContent").mockResolvedValue(
+        createSampleRelationshipResult("test.ts")
+      );
+
+      await service.ingestFile(createSampleFileInput("test.ts"), "test-repo");
+
+      // The edge to the type the file no longer defines is dropped
+      const restoreQuery = runQuery.mock.calls.find((c) => String(c[0]).includes("[r:IMPLEMENTS]"));
+      expect(restoreQuery?.[1]).toEqual({
+        edges: [
+          {
+            sourceId: "Class:test-repo:impl.ts:Widget:2",
+            targetId: "Class:test-repo:test.ts:TestClass:7",
+            properties: {},
+          },
+        ],
+      });
+
+      entitySpy.mockRestore();
+      relSpy.mockRestore();
+    });
   });
 
   describe("progress reporting", () => {
@@ -874,5 +2054,7 @@ describe("DEFAULT_GRAPH_INGESTION_CONFIG", () => {
     expect(DEFAULT_GRAPH_INGESTION_CONFIG.nodeBatchSize).toBe(20);
     expect(DEFAULT_GRAPH_INGESTION_CONFIG.relationshipBatchSize).toBe(50);
     expect(DEFAULT_GRAPH_INGESTION_CONFIG.transactionTimeoutMs).toBe(30000);
+    expect(DEFAULT_GRAPH_INGESTION_CONFIG.normalizeSignatures).toBe(true);
+    expect(DEFAULT_GRAPH_INGESTION_CONFIG.captureCallArguments).toBe(false);
   });
 });
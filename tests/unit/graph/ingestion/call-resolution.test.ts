@@ -0,0 +1,321 @@
+/**
+ * Tests for CALLS relationship resolution
+ */
+
+import { describe, it, expect } from "bun:test";
+import {
+  buildImportScope,
+  isInModule,
+  resolveCallEdges,
+  type CallableSymbol,
+} from "../../../../src/graph/ingestion/call-resolution.js";
+import type { CallInfo, ImportInfo } from "../../../../src/graph/parsing/types.js";
+
+const callable = (
+  name: string,
+  filePath: string,
+  startLine: number,
+  endLine: number
+): CallableSymbol => ({
+  id: `Function:repo:${filePath}:${name}:${startLine}`,
+  name,
+  filePath,
+  startLine,
+  endLine,
+});
+
+const method = (
+  name: string,
+  parentName: string,
+  filePath: string,
+  startLine: number,
+  endLine: number
+): CallableSymbol => ({ ...callable(name, filePath, startLine, endLine), parentName });
+
+const rustImport = (source: string, aliases?: Record<string, string>): ImportInfo => ({
+  source,
+  isRelative: source.startsWith("crate"),
+  importedNames: [source.split("::").pop()!],
+  ...(aliases && { aliases }),
+  isTypeOnly: false,
+  isSideEffect: false,
+  line: 1,
+});
+
+const call = (calledName: string, line: number, typeArguments?: string[]): CallInfo => ({
+  calledName,
+  calledExpression: calledName,
+  isAsync: false,
+  line,
+  ...(typeArguments && { typeArguments }),
+});
+
+describe("resolveCallEdges", () => {
+  const run = callable("run", "src/a.ts", 1, 10);
+  const helper = callable("helper", "src/a.ts", 12, 15);
+  const remote = callable("save", "src/db.ts", 1, 5);
+
+  it("attributes calls to the innermost enclosing function", () => {
+    const inner = callable("inner", "src/a.ts", 3, 5);
+
+    const edges = resolveCallEdges(
+      "src/a.ts",
+      [call("helper", 4)],
+      [run, inner, helper],
+      () => []
+    );
+
+    expect(edges).toEqual([
+      {
+        fromId: inner.id,
+        toId: helper.id,
+        callCount: 1,
+        line: 4,
+        typeArguments: [],
+        sites: [{ line: 4 }],
+      },
+    ]);
+  });
+
+  it("aggregates repeated calls and their type arguments into one edge", () => {
+    const edges = resolveCallEdges(
+      "src/a.ts",
+      [call("helper", 5, ["u32"]), call("helper", 2, ["String"]), call("helper", 7, ["u32"])],
+      [run, helper],
+      () => []
+    );
+
+    expect(edges).toHaveLength(1);
+    expect(edges[0]).toMatchObject({ callCount: 3, line: 2, typeArguments: ["u32", "String"] });
+  });
+
+  it("keeps every call site with its arguments, ordered by line", () => {
+    const edges = resolveCallEdges(
+      "src/a.ts",
+      [
+        { ...call("helper", 6), arguments: ['"World"', "3"] },
+        { ...call("helper", 3), arguments: [] },
+      ],
+      [run, helper],
+      () => []
+    );
+
+    expect(edges[0]?.sites).toEqual([
+      { line: 3, arguments: [] },
+      { line: 6, arguments: ['"World"', "3"] },
+    ]);
+  });
+
+  it("resolves names not defined locally against the rest of the repository", () => {
+    const edges = resolveCallEdges("src/a.ts", [call("save", 3)], [run], (name) =>
+      name === "save" ? [remote] : []
+    );
+
+    expect(edges[0]?.toId).toBe(remote.id);
+  });
+
+  it("skips ambiguous, unknown and top-level calls", () => {
+    const other = callable("save", "src/cache.ts", 1, 4);
+
+    const edges = resolveCallEdges(
+      "src/a.ts",
+      [call("save", 3), call("console.log", 4), call("helper", 20)],
+      [run, helper],
+      () => [remote, other]
+    );
+
+    expect(edges).toEqual([]);
+  });
+
+  it("records direct recursion as a self edge", () => {
+    const edges = resolveCallEdges("src/a.ts", [call("run", 6)], [run, helper], () => []);
+
+    expect(edges[0]).toMatchObject({ fromId: run.id, toId: run.id });
+  });
+});
+
+describe("resolveCallEdges with receiver types", () => {
+  const userSave = method("save", "User", "src/models/user.rs", 10, 20);
+  const orderSave = method("save", "Order", "src/models/order.rs", 10, 20);
+  const byName = (name: string): CallableSymbol[] =>
+    [userSave, orderSave].filter((c) => c.name === name);
+
+  const methodCall = (calledName: string, calledExpression: string, line: number): CallInfo => ({
+    calledName,
+    calledExpression,
+    isAsync: false,
+    line,
+  });
+
+  it("binds calls on a typed parameter to the method of that type in another file", () => {
+    const handler: CallableSymbol = {
+      ...callable("handle", "src/api.rs", 1, 10),
+      params: [{ name: "user", type: "&mut User" }],
+    };
+    const scope = buildImportScope([rustImport("crate::models::user::User")]);
+
+    const edges = resolveCallEdges(
+      "src/api.rs",
+      [methodCall("save", "user.save", 3)],
+      [handler],
+      byName,
+      scope
+    );
+
+    expect(edges[0]?.toId).toBe(userSave.id);
+  });
+
+  it("binds calls on a typed local bound before the call", () => {
+    const handler: CallableSymbol = {
+      ...callable("handle", "src/api.rs", 1, 10),
+      params: [{ name: "record", type: "Order" }],
+      locals: [
+        { name: "record", type: "User", line: 4 },
+        { name: "draft", line: 5 },
+      ],
+    };
+
+    const edges = resolveCallEdges(
+      "src/api.rs",
+      [
+        methodCall("save", "record.save", 3),
+        methodCall("save", "record.save", 6),
+        methodCall("save", "draft.save", 7),
+      ],
+      [handler],
+      byName
+    );
+
+    expect(edges.map((edge) => [edge.toId, edge.line])).toEqual([
+      [orderSave.id, 3],
+      [userSave.id, 6],
+    ]);
+  });
+
+  it("prefers the receiver type over a same-named local function", () => {
+    const handler = callable("handle", "src/api.rs", 1, 10);
+    const localSave = callable("save", "src/api.rs", 12, 15);
+
+    const edges = resolveCallEdges(
+      "src/api.rs",
+      [methodCall("save", "Order::save", 3)],
+      [handler, localSave],
+      byName
+    );
+
+    expect(edges[0]?.toId).toBe(orderSave.id);
+  });
+
+  it("resolves self receivers to the enclosing impl type", () => {
+    const process = method("process", "Order", "src/models/order_ops.rs", 1, 10);
+
+    const edges = resolveCallEdges(
+      "src/models/order_ops.rs",
+      [methodCall("save", "self.save", 4)],
+      [process],
+      byName
+    );
+
+    expect(edges[0]?.toId).toBe(orderSave.id);
+  });
+
+  it("follows import aliases and turbofish-qualified paths", () => {
+    const handler = callable("handle", "src/api.rs", 1, 10);
+    const scope = buildImportScope([rustImport("crate::models::user::User", { User: "Account" })]);
+
+    const edges = resolveCallEdges(
+      "src/api.rs",
+      [methodCall("save", "Account::<u8>::save", 3)],
+      [handler],
+      byName,
+      scope
+    );
+
+    expect(edges[0]?.toId).toBe(userSave.id);
+  });
+
+  it("picks the type declared in the imported module when the name is declared twice", () => {
+    const handler = callable("handle", "src/api.rs", 1, 10);
+    const legacySave = method("save", "User", "src/legacy/user.rs", 5, 9);
+    const scope = buildImportScope([rustImport("crate::models::user::User")]);
+
+    const edges = resolveCallEdges(
+      "src/api.rs",
+      [methodCall("save", "User::save", 3)],
+      [handler],
+      (name) => (name === "save" ? [userSave, legacySave] : []),
+      scope
+    );
+
+    expect(edges[0]?.toId).toBe(userSave.id);
+  });
+
+  it("resolves crate paths within the importing crate of a workspace", () => {
+    const crates = [
+      { name: "crate-a", path: "crate-a" },
+      { name: "crate-b", path: "crate-b" },
+    ];
+    const handler = callable("handle", "crate-a/src/api.rs", 1, 10);
+    const saveA = method("save", "User", "crate-a/src/models/user.rs", 5, 9);
+    const saveB = method("save", "User", "crate-b/src/models/user.rs", 5, 9);
+    const resolve = (source: string): string | undefined =>
+      resolveCallEdges(
+        "crate-a/src/api.rs",
+        [methodCall("save", "User::save", 3)],
+        [handler],
+        (name) => (name === "save" ? [saveA, saveB] : []),
+        buildImportScope([rustImport(source)]),
+        crates
+      )[0]?.toId;
+
+    expect(resolve("crate::models::user::User")).toBe(saveA.id);
+    expect(resolve("crate_b::models::user::User")).toBe(saveB.id);
+  });
+
+  it("falls back to name-only resolution for receivers of external types", () => {
+    const handler = callable("handle", "src/api.rs", 1, 10);
+    const flush = callable("flush", "src/io.rs", 1, 5);
+
+    const edges = resolveCallEdges(
+      "src/api.rs",
+      [methodCall("flush", "std::io::stdout::flush", 3), methodCall("save", "client.save", 4)],
+      [handler],
+      (name) => (name === "flush" ? [flush] : byName(name))
+    );
+
+    expect(edges.map((e) => e.toId)).toEqual([flush.id]);
+  });
+});
+
+describe("isInModule", () => {
+  it("matches crate, self and super paths against Rust module files", () => {
+    expect(isInModule("src/models/mod.rs", "crate::models", "src/main.rs")).toBe(true);
+    expect(isInModule("src/models.rs", "crate::models", "src/main.rs")).toBe(true);
+    expect(isInModule("src/api/db.rs", "super::db", "src/api/handlers.rs")).toBe(true);
+    expect(isInModule("src/api/handlers/db.rs", "self::db", "src/api/handlers.rs")).toBe(true);
+    expect(isInModule("src/db.rs", "super::db", "src/api/handlers.rs")).toBe(false);
+  });
+
+  it("only matches files of the crate a path refers to in a workspace", () => {
+    const crates = [
+      { name: "app", path: "" },
+      { name: "crate-b", path: "crates/b" },
+    ];
+    expect(isInModule("src/models.rs", "crate::models", "src/main.rs", crates)).toBe(true);
+    expect(isInModule("crates/b/src/models.rs", "crate::models", "src/main.rs", crates)).toBe(
+      false
+    );
+    expect(isInModule("crates/b/src/models.rs", "crate_b::models", "src/main.rs", crates)).toBe(
+      true
+    );
+    expect(isInModule("crates/b/src/db.rs", "super::db", "crates/b/src/api/x.rs", crates)).toBe(
+      true
+    );
+  });
+
+  it("matches relative specifiers against file paths", () => {
+    expect(isInModule("src/models/index.ts", "./models", "src/app.ts")).toBe(true);
+    expect(isInModule("src/models.ts", "../models", "src/api/app.ts")).toBe(true);
+    expect(isInModule("src/models.ts", "lodash", "src/app.ts")).toBe(false);
+  });
+});
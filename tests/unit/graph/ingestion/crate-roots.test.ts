@@ -0,0 +1,194 @@
+/**
+ * Unit tests for Cargo workspace crate roots
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import * as path from "node:path";
+import * as fs from "node:fs/promises";
+import { tmpdir } from "node:os";
+import {
+  detectBuildOrMacroPaths,
+  detectCrateRoots,
+  findOwningCrate,
+  isBuildOrMacroPath,
+  parseCargoBuildTargets,
+  parseCargoPackageName,
+  parseCargoWorkspace,
+  resolveCrateRoots,
+  rustCrateIdentifier,
+} from "../../../../src/graph/ingestion/crate-roots.js";
+
+describe("parseCargoWorkspace", () => {
+  it("leaves workspace mode off when unset", () => {
+    expect(parseCargoWorkspace(undefined)).toBeUndefined();
+    expect(parseCargoWorkspace("")).toBeUndefined();
+    expect(parseCargoWorkspace("false")).toBeUndefined();
+  });
+
+  it("detects crates when asked to", () => {
+    expect(parseCargoWorkspace("detect")).toBe("detect");
+    expect(parseCargoWorkspace("true")).toBe("detect");
+  });
+
+  it("parses an explicit crate list", () => {
+    expect(parseCargoWorkspace("crate-a=./crates/a/, crate-b=crates/b,app=.")).toEqual([
+      { name: "crate-a", path: "crates/a" },
+      { name: "crate-b", path: "crates/b" },
+      { name: "app", path: "" },
+    ]);
+  });
+
+  it("rejects entries that are not name=path pairs", () => {
+    expect(() => parseCargoWorkspace("crate-a")).toThrow("expected name=path");
+    expect(() => parseCargoWorkspace("=crates/a")).toThrow("expected name=path");
+  });
+});
+
+describe("parseCargoPackageName", () => {
+  it("reads the name of the package section", () => {
+    const manifest = [
+      "[dependencies]",
+      'name = "not-this"',
+      "",
+      "[package]",
+      'name = "crate-a" # the crate',
+      'version = "0.1.0"',
+    ].join("\n");
+
+    expect(parseCargoPackageName(manifest)).toBe("crate-a");
+  });
+
+  it("returns undefined for a virtual workspace manifest", () => {
+    expect(parseCargoPackageName('[workspace]\nmembers = ["crates/*"]\n')).toBeUndefined();
+  });
+});
+
+describe("parseCargoBuildTargets", () => {
+  it("reads the proc-macro flag and the build script", () => {
+    const manifest = [
+      "[package]",
+      'name = "derive"',
+      'build = "tools/gen.rs"',
+      "",
+      "[lib]",
+      "proc-macro = true",
+    ].join("\n");
+
+    expect(parseCargoBuildTargets(manifest)).toEqual({
+      procMacro: true,
+      buildScript: "tools/gen.rs",
+    });
+  });
+
+  it("ignores keys outside their section", () => {
+    const manifest = '[package]\nproc-macro = true\n\n[dependencies]\nbuild = "1.0"\n';
+
+    expect(parseCargoBuildTargets(manifest)).toEqual({ procMacro: false });
+  });
+});
+
+describe("isBuildOrMacroPath", () => {
+  it("tags build.rs scripts outside src", () => {
+    expect(isBuildOrMacroPath("build.rs", [])).toBe(true);
+    expect(isBuildOrMacroPath("crates/a/build.rs", [])).toBe(true);
+    expect(isBuildOrMacroPath("src/build.rs", [])).toBe(false);
+  });
+
+  it("tags Rust files at or beneath a detected path", () => {
+    const paths = ["crates/derive", "tools/gen.rs"];
+
+    expect(isBuildOrMacroPath("crates/derive/src/lib.rs", paths)).toBe(true);
+    expect(isBuildOrMacroPath("tools/gen.rs", paths)).toBe(true);
+    expect(isBuildOrMacroPath("crates/derive-utils/src/lib.rs", paths)).toBe(false);
+    expect(isBuildOrMacroPath("crates/derive/README.md", paths)).toBe(false);
+  });
+});
+
+describe("findOwningCrate", () => {
+  const roots = [
+    { name: "app", path: "" },
+    { name: "crate-a", path: "crates/a" },
+    { name: "crate-a-macros", path: "crates/a/macros" },
+  ];
+
+  it("picks the innermost crate containing the file", () => {
+    expect(findOwningCrate("crates/a/src/lib.rs", roots)?.name).toBe("crate-a");
+    expect(findOwningCrate("crates/a/macros/src/lib.rs", roots)?.name).toBe("crate-a-macros");
+    expect(findOwningCrate("src/main.rs", roots)?.name).toBe("app");
+  });
+
+  it("does not match directories that only share a prefix", () => {
+    expect(findOwningCrate("crates/ab/src/lib.rs", roots)?.name).toBe("app");
+    expect(findOwningCrate("crates/ab/src/lib.rs", roots.slice(1))).toBeUndefined();
+  });
+});
+
+describe("rustCrateIdentifier", () => {
+  it("replaces dashes with underscores", () => {
+    expect(rustCrateIdentifier("crate-a-macros")).toBe("crate_a_macros");
+  });
+});
+
+describe("detectCrateRoots", () => {
+  let repositoryPath: string;
+
+  const write = async (relativePath: string, content: string): Promise<void> => {
+    const filePath = path.join(repositoryPath, relativePath);
+    await fs.mkdir(path.dirname(filePath), { recursive: true });
+    await fs.writeFile(filePath, content);
+  };
+
+  beforeEach(async () => {
+    repositoryPath = await fs.mkdtemp(path.join(tmpdir(), "crate-roots-"));
+  });
+
+  afterEach(async () => {
+    await fs.rm(repositoryPath, { recursive: true, force: true });
+  });
+
+  it("finds every package manifest outside skipped directories", async () => {
+    await write("Cargo.toml", '[workspace]\nmembers = ["crate-a", "crate-b"]\n');
+    await write("crate-b/Cargo.toml", '[package]\nname = "crate-b"\n');
+    await write("crate-a/Cargo.toml", '[package]\nname = "crate-a"\n');
+    await write("target/package/Cargo.toml", '[package]\nname = "packaged"\n');
+    await write(".cargo/registry/Cargo.toml", '[package]\nname = "vendored"\n');
+
+    expect(await resolveCrateRoots("detect", repositoryPath)).toEqual([
+      { name: "crate-a", path: "crate-a" },
+      { name: "crate-b", path: "crate-b" },
+    ]);
+  });
+
+  it("treats a root package as the crate at the empty path", async () => {
+    await write("Cargo.toml", '[package]\nname = "app"\n');
+
+    expect(await detectCrateRoots(repositoryPath)).toEqual([{ name: "app", path: "" }]);
+  });
+});
+
+describe("detectBuildOrMacroPaths", () => {
+  let repositoryPath: string;
+
+  beforeEach(async () => {
+    repositoryPath = await fs.mkdtemp(path.join(tmpdir(), "crate-roots-"));
+  });
+
+  afterEach(async () => {
+    await fs.rm(repositoryPath, { recursive: true, force: true });
+  });
+
+  it("finds proc-macro crates and custom build scripts", async () => {
+    await fs.mkdir(path.join(repositoryPath, "derive"));
+    await fs.mkdir(path.join(repositoryPath, "app"));
+    await fs.writeFile(
+      path.join(repositoryPath, "derive/Cargo.toml"),
+      '[package]\nname = "derive"\n\n[lib]\nproc-macro = true\n'
+    );
+    await fs.writeFile(
+      path.join(repositoryPath, "app/Cargo.toml"),
+      '[package]\nname = "app"\nbuild = "./tools/gen.rs"\n'
+    );
+
+    expect(await detectBuildOrMacroPaths(repositoryPath)).toEqual(["app/tools/gen.rs", "derive"]);
+  });
+});
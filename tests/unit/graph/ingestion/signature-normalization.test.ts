@@ -0,0 +1,40 @@
+/**
+ * Unit tests for signature normalization
+ */
+
+import { describe, it, expect } from "bun:test";
+import { normalizeSignature } from "../../../../src/graph/ingestion/signature-normalization.js";
+
+describe("normalizeSignature", () => {
+  it.each([
+    ["Pair< K ,V >", "Pair<K, V>"],
+    ["Pair<K,V>", "Pair<K, V>"],
+    ["HashMap<K, Vec<V>,>", "HashMap<K, Vec<V>>"],
+    ["Vec <Option< u8 > >", "Vec<Option<u8>>"],
+    ["fn(a:u8)->Result< () >", "fn(a: u8) -> Result<()>"],
+    ["std :: io :: Error", "std::io::Error"],
+    ["where T : Clone,\n    U: Send", "where T: Clone, U: Send"],
+    ["[ u8 ;4 ]", "[u8; 4]"],
+    ["<T as Iterator>::Item", "<T as Iterator>::Item"],
+    ["run(cb: impl Fn (u8) -> u8): bool", "run(cb: impl Fn(u8) -> u8): bool"],
+  ])("normalizes %p", (raw, expected) => {
+    expect(normalizeSignature(raw)).toBe(expected);
+  });
+
+  it("makes formatting variants of a signature identical", () => {
+    const variants = [
+      "merge(a: Pair<K, V>, b: &Pair<K, V>): Pair<K, V>",
+      "merge(a:Pair<K,V>,b:&Pair<K,V>):Pair<K,V>",
+      "merge( a : Pair< K, V >,\n  b : &Pair<K , V>, ) : Pair<K,V>",
+    ];
+
+    expect(new Set(variants.map(normalizeSignature))).toEqual(new Set([variants[0]]));
+  });
+
+  it("is idempotent", () => {
+    const once = normalizeSignature("f<'a, T :'a>(x : &'a T ,) -> Box< dyn Fn(T)->T + 'a >");
+
+    expect(once).toBe("f<'a, T: 'a>(x: &'a T) -> Box<dyn Fn(T) -> T + 'a>");
+    expect(normalizeSignature(once)).toBe(once);
+  });
+});
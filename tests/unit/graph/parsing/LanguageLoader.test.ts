@@ -246,6 +246,41 @@ describe("LanguageLoader", () => {
       }
     });
 
+    it("should disable only the language whose grammar fails to load", async () => {
+      const defaults = new LanguageLoader().getStatus().wasmPaths.languages;
+      const loader = new LanguageLoader({
+        languages: { ...defaults, rust: "/nonexistent/rust.wasm" },
+      });
+
+      const results = await loader.preloadAllLanguages();
+
+      expect(results.get("rust")).toBe(false);
+      expect(results.get("typescript")).toBe(true);
+      expect(loader.isLanguageDisabled("rust")).toBe(true);
+      expect(loader.isLanguageDisabled("typescript")).toBe(false);
+
+      const status = loader.getStatus();
+      expect(status.loadedLanguages).toContain("python");
+      expect(status.loadedLanguages).not.toContain("rust");
+      expect(Object.keys(status.disabledLanguages)).toEqual(["rust"]);
+
+      await expect(loader.getLanguage("rust")).rejects.toBeInstanceOf(LanguageLoadError);
+    });
+
+    it("should re-enable disabled languages after cleanup", async () => {
+      const defaults = new LanguageLoader().getStatus().wasmPaths.languages;
+      const loader = new LanguageLoader({
+        languages: { ...defaults, go: "/nonexistent/go.wasm" },
+      });
+      await expect(loader.getLanguage("go")).rejects.toBeInstanceOf(LanguageLoadError);
+      expect(loader.isLanguageDisabled("go")).toBe(true);
+
+      loader.cleanup();
+
+      expect(loader.isLanguageDisabled("go")).toBe(false);
+      expect(loader.getStatus().disabledLanguages).toEqual({});
+    });
+
     // Note: This test is removed because web-tree-sitter.Parser.init() is a global
     // operation that only runs once per process. Once successfully initialized,
     // subsequent calls with different paths will still succeed because the WASM
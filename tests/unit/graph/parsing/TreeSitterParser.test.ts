@@ -12,6 +12,10 @@ import {
   LanguageNotSupportedError,
   FileTooLargeError,
 } from "../../../../src/graph/parsing/errors.js";
+import {
+  MIN_HASH_SLOTS,
+  structuralSimilarity,
+} from "../../../../src/graph/parsing/structural-fingerprint.js";
 import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
 
 // Path to test fixtures
@@ -208,6 +212,17 @@ describe("TreeSitterParser", () => {
       expect(createSparrow?.type).toBe("method");
       expect(createSparrow?.metadata?.isStatic).toBe(true);
     });
+
+    it("should link class members to their enclosing class", async () => {
+      const content = await Bun.file(path.join(FIXTURES_DIR, "complex-class.ts")).text();
+      const result = await parser.parseFile(content, "complex-class.ts");
+
+      const getName = result.entities.find((e) => e.name === "getName");
+      expect(getName?.parentName).toBe("Animal");
+
+      const animal = result.entities.find((e) => e.name === "Animal");
+      expect(animal?.parentName).toBeUndefined();
+    });
   });
 
   describe("parseFile - Imports and Exports", () => {
@@ -385,6 +400,30 @@ describe("TreeSitterParser", () => {
       }
     });
 
+    it("should skip files of a language whose grammar failed to load", async () => {
+      const defaults = new LanguageLoader().getStatus().wasmPaths.languages;
+      const loader = new LanguageLoader({
+        languages: { ...defaults, ruby: "/nonexistent/ruby.wasm" },
+      });
+      const degradedParser = new TreeSitterParser(loader);
+
+      // First parse discovers the broken grammar and reports a failed result
+      const first = await degradedParser.parseFile("def greet; end", "greet.rb");
+      expect(first.success).toBe(false);
+
+      // Later files of that language are rejected as unsupported
+      try {
+        await degradedParser.parseFile("def greet; end", "other.rb");
+        expect(true).toBe(false); // Should not reach here
+      } catch (error) {
+        expect(error).toBeInstanceOf(LanguageNotSupportedError);
+      }
+
+      // Other languages are unaffected
+      const ts = await degradedParser.parseFile("const x = 1;", "ok.ts");
+      expect(ts.success).toBe(true);
+    });
+
     it("should throw for files exceeding max size", async () => {
       // Create parser with small max size
       const smallParser = new TreeSitterParser(undefined, {
@@ -478,6 +517,18 @@ export class Bar {
       expect(anotherMethodCall).toBeDefined();
     });
 
+    it("should capture the source text of each argument", async () => {
+      const content = `
+function run() {
+  format(user.name, { upper: true }, ...rest);
+}
+`;
+      const result = await parser.parseFile(content, "args.ts");
+
+      const formatCall = result.calls.find((c) => c.calledName === "format");
+      expect(formatCall?.arguments).toEqual(["user.name", "{ upper: true }", "...rest"]);
+    });
+
     it("should detect async calls (await expressions)", async () => {
       const content = await Bun.file(path.join(FIXTURES_DIR, "function-calls.ts")).text();
       const result = await parser.parseFile(content, "function-calls.ts");
@@ -806,6 +857,89 @@ export function documented(): void {}
       expect(fn?.metadata?.documentation).toBeDefined();
       expect(fn?.metadata?.documentation).toContain("documented function");
     });
+
+    it("should honor configured doc comment styles per language", async () => {
+      const content = `
+/// Line docs.
+pub fn lines() {}
+
+/** Block docs. */
+pub fn block() {}
+`;
+
+      const lineDocsParser = new TreeSitterParser(undefined, {
+        docCommentStyles: { rust: ["doc_line"] },
+      });
+
+      const result = await lineDocsParser.parseFile(content, "docs.rs");
+
+      expect(result.entities.find((e) => e.name === "lines")?.metadata?.doc).toBe("Line docs.");
+      const block = result.entities.find((e) => e.name === "block");
+      expect(block?.metadata?.doc).toBeUndefined();
+      // The raw documentation is still collected
+      expect(block?.metadata?.documentation).toContain("Block docs.");
+    });
+  });
+
+  describe("parseFile - Documentation Text", () => {
+    it("should populate doc from a JSDoc block", async () => {
+      const content = await Bun.file(path.join(FIXTURES_DIR, "simple-function.ts")).text();
+      const result = await parser.parseFile(content, "simple-function.ts");
+
+      const doubleNumber = result.entities.find((e) => e.name === "doubleNumber");
+      expect(doubleNumber?.metadata?.doc).toStartWith("A simple exported function");
+      expect(doubleNumber?.metadata?.doc).not.toContain("*");
+    });
+
+    it("should populate doc from a Python docstring", async () => {
+      const content = await Bun.file(path.join(FIXTURES_DIR, "simple-python.py")).text();
+      const result = await parser.parseFile(content, "simple-python.py");
+
+      const simple = result.entities.find((e) => e.name === "simple_function");
+      expect(simple?.metadata?.doc).toBe("A simple function with no parameters.");
+      const withParams = result.entities.find((e) => e.name === "function_with_params");
+      expect(withParams?.metadata?.doc).toStartWith("A function with typed parameters");
+      expect(withParams?.metadata?.doc).toContain("\nArgs:\n    name: The name to greet");
+    });
+
+    it("should populate doc from Rust doc comments", async () => {
+      const content = await Bun.file(path.join(FIXTURES_DIR, "simple-rust.rs")).text();
+      const result = await parser.parseFile(content, "simple-rust.rs");
+
+      const simpleFunc = result.entities.find((e) => e.name === "simple_function");
+      expect(simpleFunc?.metadata?.doc).toContain("simple public function");
+      expect(simpleFunc?.metadata?.doc).not.toContain("///");
+    });
+
+    it("should leave doc undefined for undocumented entities", async () => {
+      const result = await parser.parseFile("export function bare(): void {}", "bare.ts");
+
+      expect(result.entities.find((e) => e.name === "bare")?.metadata?.doc).toBeUndefined();
+    });
+
+    it("should extract the module doc from leading inner doc comments", async () => {
+      const content = [
+        "//! Vector arithmetic.",
+        "//!",
+        "//! Supports 2D and 3D vectors.",
+        "#![deny(missing_docs)]",
+        "",
+        "/// Adds two numbers.",
+        "pub fn add(a: i32, b: i32) -> i32 { a + b }",
+        "",
+        "//! Not a module doc: it follows an item.",
+      ].join("\n");
+
+      const result = await parser.parseFile(content, "src/vector.rs");
+
+      expect(result.moduleDoc).toBe("Vector arithmetic.\n\nSupports 2D and 3D vectors.");
+    });
+
+    it("should leave the module doc undefined without inner doc comments", async () => {
+      const result = await parser.parseFile("/// Adds.\npub fn add() {}", "src/add.rs");
+
+      expect(result.moduleDoc).toBeUndefined();
+    });
   });
 
   // ==================== Python Parsing Tests ====================
@@ -890,6 +1024,25 @@ export function documented(): void {}
       expect(dog?.metadata?.extends).toBe("Animal");
     });
 
+    it("should record further Python base classes as implemented", async () => {
+      const content = [
+        "class Service(Base, abc.ABC, LoggingMixin, metaclass=Registry):",
+        "    pass",
+        "",
+        "class Plain:",
+        "    pass",
+      ].join("\n");
+      const result = await parser.parseFile(content, "service.py");
+
+      const service = result.entities.find((e) => e.name === "Service");
+      expect(service?.metadata?.extends).toBe("Base");
+      expect(service?.metadata?.implements).toEqual(["abc.ABC", "LoggingMixin"]);
+
+      const plain = result.entities.find((e) => e.name === "Plain");
+      expect(plain?.metadata?.extends).toBeUndefined();
+      expect(plain?.metadata?.implements).toBeUndefined();
+    });
+
     it("should parse decorated Python classes", async () => {
       const content = await Bun.file(path.join(FIXTURES_DIR, "simple-python.py")).text();
       const result = await parser.parseFile(content, "simple-python.py");
@@ -981,6 +1134,17 @@ export function documented(): void {}
       const addCall = result.calls.find((c) => c.calledName === "add");
       expect(addCall).toBeDefined();
     });
+
+    it("should capture positional and keyword argument text", async () => {
+      const content = `
+def run():
+    greet("World", times=3)
+`;
+      const result = await parser.parseFile(content, "args.py");
+
+      const greetCall = result.calls.find((c) => c.calledName === "greet");
+      expect(greetCall?.arguments).toEqual(['"World"', "times=3"]);
+    });
   });
 
   describe("parseFile - Python Exports", () => {
@@ -1414,6 +1578,25 @@ export function documented(): void {}
       expect(simpleFunc?.metadata?.documentation).toBeDefined();
       expect(simpleFunc?.metadata?.documentation).toContain("simple function");
     });
+
+    it("should not attach trailing or distant Go comments", async () => {
+      const content = `package main
+
+func First() {} // trailing note about First
+func Second() {}
+
+// Separated from Third by too many blank lines.
+
+
+func Third() {}
+`;
+      const result = await parser.parseFile(content, "trailing.go");
+
+      const second = result.entities.find((e) => e.name === "Second");
+      expect(second?.metadata?.documentation).toBeUndefined();
+      const third = result.entities.find((e) => e.name === "Third");
+      expect(third?.metadata?.documentation).toBeUndefined();
+    });
   });
 
   describe("parseFile - Go Extension Support", () => {
@@ -1470,6 +1653,7 @@ export function documented(): void {}
       expect(paramFunc?.metadata?.parameters?.[0]?.type).toBe("&str");
       expect(paramFunc?.metadata?.parameters?.[1]?.name).toBe("count");
       expect(paramFunc?.metadata?.parameters?.[1]?.type).toBe("i32");
+      expect(paramFunc?.metadata?.returnType).toBe("String");
     });
 
     it("should parse async functions", async () => {
@@ -1480,6 +1664,72 @@ export function documented(): void {}
       const asyncFunc = result.entities.find((e) => e.name === "async_function");
       expect(asyncFunc).toBeDefined();
       expect(asyncFunc?.metadata?.isAsync).toBe(true);
+      // The declared type is the resolved one; the future is implicit
+      expect(asyncFunc?.metadata?.returnType).toBe("Result<String, io::Error>");
+
+      const constFunc = result.entities.find((e) => e.name === "const_function");
+      expect(constFunc?.metadata?.isAsync).toBe(false);
+    });
+
+    it("should detect async alongside other function modifiers", async () => {
+      const content = [
+        "pub async unsafe fn read_raw(ptr: *const u8) -> u8 { *ptr }",
+        "pub(crate) async fn fetch() -> Vec<u8> { Vec::new() }",
+        "pub unsafe fn plain() {}",
+      ].join("\n");
+
+      const result = await parser.parseFile(content, "modifiers.rs");
+
+      const isAsync = (name: string): boolean | undefined =>
+        result.entities.find((e) => e.name === name)?.metadata?.isAsync;
+      expect(isAsync("read_raw")).toBe(true);
+      expect(isAsync("fetch")).toBe(true);
+      expect(isAsync("plain")).toBe(false);
+    });
+
+    it("should list let bindings with their inferred types", async () => {
+      const content = await Bun.file(path.join(FIXTURES_DIR, "simple-rust.rs")).text();
+      const result = await parser.parseFile(content, "simple-rust.rs");
+
+      const withCalls = result.entities.find((e) => e.name === "function_with_calls");
+      expect(withCalls?.metadata?.locals).toEqual([
+        { name: "result", line: 182 },
+        { name: "greeting", line: 186 },
+        { name: "point", type: "Point", line: 190 },
+        { name: "distance", line: 191 },
+        { name: "dog", type: "Dog", line: 195 },
+        { name: "sound", line: 196 },
+        { name: "map", type: "HashMap<String, i32>", line: 200 },
+        { name: "items", line: 204 },
+        { name: "joined", line: 205 },
+      ]);
+    });
+
+    it("should type tuple, turbofish, struct and fallible bindings", async () => {
+      const content = [
+        "fn load(path: &str) -> io::Result<()> {",
+        "    let (mut a, b): (u32, String) = (1, String::new());",
+        "    let items = Vec::<u8>::new();",
+        "    let user = models::User { id: 1 };",
+        "    let file = File::open(path)?;",
+        "    let text = std::fs::read_to_string(path)?;",
+        "    let Some(x) = maybe() else { return Ok(()) };",
+        "    fn nested() { let inner = 1; }",
+        "    Ok(())",
+        "}",
+      ].join("\n");
+
+      const result = await parser.parseFile(content, "load.rs");
+
+      const load = result.entities.find((e) => e.name === "load");
+      expect(load?.metadata?.locals).toEqual([
+        { name: "a", type: "u32", line: 2 },
+        { name: "b", type: "String", line: 2 },
+        { name: "items", type: "Vec<u8>", line: 3 },
+        { name: "user", type: "models::User", line: 4 },
+        { name: "file", type: "File", line: 5 },
+        { name: "text", line: 6 },
+      ]);
     });
 
     it("should parse const functions", async () => {
@@ -1501,6 +1751,452 @@ export function documented(): void {}
       expect(simpleFunc?.metadata?.documentation).toBeDefined();
       expect(simpleFunc?.metadata?.documentation).toContain("simple public function");
     });
+
+    it("should record the trait implemented by a method's impl block", async () => {
+      const content = `
+pub struct Circle;
+pub trait Draw {
+    fn draw(&self);
+}
+impl Draw for Circle {
+    fn draw(&self) {}
+}
+impl Circle {
+    pub fn new() -> Self {
+        Circle
+    }
+}
+`;
+      const result = await parser.parseFile(content, "shapes.rs");
+
+      const draw = result.entities.find((e) => e.name === "draw" && e.parentName === "Circle");
+      expect(draw?.metadata?.implementedTrait).toBe("Draw");
+      const constructor = result.entities.find((e) => e.name === "new");
+      expect(constructor).toBeDefined();
+      expect(constructor?.metadata?.implementedTrait).toBeUndefined();
+    });
+
+    describe("doc comment association", () => {
+      const docOf = async (
+        name: string,
+        config?: ConstructorParameters<typeof TreeSitterParser>[1]
+      ): Promise<string | undefined> => {
+        const content = await Bun.file(path.join(FIXTURES_DIR, "rust-doc-association.rs")).text();
+        const docParser = config ? new TreeSitterParser(undefined, config) : parser;
+        const result = await docParser.parseFile(content, "rust-doc-association.rs");
+        return result.entities.find((e) => e.name === name)?.metadata?.documentation;
+      };
+
+      it("should attach docs across intervening attributes", async () => {
+        expect(await docOf("DocBeforeDerive")).toContain("documented above its derive");
+        expect(await docOf("attribute_then_doc")).toContain("Documented after its attribute");
+      });
+
+      it("should allow up to maxDocCommentBlankLines blank lines", async () => {
+        expect(await docOf("DocWithBlankLine")).toContain("blank line and two attributes");
+        expect(await docOf("far_from_doc")).toBeUndefined();
+        expect(await docOf("far_from_doc", { maxDocCommentBlankLines: 2 })).toContain(
+          "Too far from the item"
+        );
+        expect(await docOf("DocWithBlankLine", { maxDocCommentBlankLines: 0 })).toBeUndefined();
+      });
+
+      it("should not attach docs separated by unrelated code", async () => {
+        expect(await docOf("after_unrelated_code")).toBeUndefined();
+      });
+
+      it("should not attribute trailing comments to the next item", async () => {
+        expect(await docOf("after_trailing_comment")).toBeUndefined();
+      });
+    });
+  });
+
+  describe("parseFile - Rust Entry Points", () => {
+    const parseEntryPoints = async () => {
+      const content = await Bun.file(path.join(FIXTURES_DIR, "rust-entry-points.rs")).text();
+      return parser.parseFile(content, "rust-entry-points.rs");
+    };
+
+    it("should extract attributes in source order", async () => {
+      const result = await parseEntryPoints();
+
+      const runsAsync = result.entities.find((e) => e.name === "runs_async");
+      expect(runsAsync?.metadata?.attributes).toEqual(["tokio::test", "ignore"]);
+      const main = result.entities.find((e) => e.name === "main" && !e.parentName);
+      expect(main?.metadata?.attributes).toEqual(['tokio::main(flavor = "current_thread")']);
+    });
+
+    it("should flag main, runtime main, test and bench functions", async () => {
+      const result = await parseEntryPoints();
+      const entryPoints = result.entities
+        .filter((e) => e.metadata?.entryPoint)
+        .map((e) => [e.name, e.metadata?.entryPoint?.kind]);
+
+      expect(entryPoints).toEqual([
+        ["main", "async_main"],
+        ["calls_helper", "test"],
+        ["runs_async", "test"],
+        ["bench_helper", "bench"],
+      ]);
+    });
+
+    it("should not flag associated functions named main or ordinary functions", async () => {
+      const result = await parseEntryPoints();
+
+      const associatedMain = result.entities.find((e) => e.name === "main" && e.parentName);
+      expect(associatedMain).toBeDefined();
+      expect(associatedMain?.metadata?.entryPoint).toBeUndefined();
+      const helper = result.entities.find((e) => e.name === "helper");
+      expect(helper?.metadata?.entryPoint).toBeUndefined();
+    });
+  });
+
+  describe("parseFile - Rust Conditional Compilation", () => {
+    it("should combine the cfg attributes of an item and its enclosing items", async () => {
+      const content = `
+#[cfg(unix)]
+fn home_dir() -> PathBuf { todo!() }
+
+#[cfg(target_os = "windows")]
+fn home_dir() -> PathBuf { todo!() }
+
+#[cfg(any(unix,windows))]
+impl Paths {
+    #[cfg(feature = "serde")]
+    fn save(&self) {}
+}
+
+fn portable() {}
+`;
+      const result = await parser.parseFile(content, "paths.rs");
+
+      expect(result.success).toBe(true);
+      const homeDirs = result.entities.filter((e) => e.name === "home_dir");
+      expect(homeDirs.map((e) => e.metadata?.cfg)).toEqual(["unix", 'target_os = "windows"']);
+      const save = result.entities.find((e) => e.name === "save");
+      expect(save?.metadata?.cfg).toBe('all(any(unix, windows), feature = "serde")');
+      const portable = result.entities.find((e) => e.name === "portable");
+      expect(portable?.metadata).not.toHaveProperty("cfg");
+    });
+  });
+
+  describe("parseFile - Rust Visibility", () => {
+    it("should record the visibility of items and re-exports", async () => {
+      const content = `
+pub use shapes::{Circle, Point as P};
+pub(crate) use shapes::*;
+use std::fmt;
+
+pub struct Canvas;
+
+pub(in crate::render) fn flush() {}
+
+impl Canvas {
+    pub ( crate ) fn clear(&self) {}
+    fn reset(&self) {}
+}
+`;
+      const result = await parser.parseFile(content, "src/render/mod.rs");
+
+      expect(result.success).toBe(true);
+      const visibilityOf = (name: string) =>
+        result.entities.find((e) => e.name === name)?.metadata?.visibility;
+      expect(visibilityOf("Canvas")).toBe("pub");
+      expect(visibilityOf("flush")).toBe("pub(in crate::render)");
+      expect(visibilityOf("clear")).toBe("pub(crate)");
+      expect(visibilityOf("reset")).toBeUndefined();
+
+      expect(result.imports.map((i) => [i.source, i.visibility])).toEqual([
+        ["shapes::Circle", "pub"],
+        ["shapes::Point", "pub"],
+        ["shapes", "pub(crate)"],
+        ["std::fmt", undefined],
+      ]);
+    });
+  });
+
+  describe("parseFile - Rust Unsafe Code", () => {
+    const parseUnsafe = async () => {
+      const content = await Bun.file(path.join(FIXTURES_DIR, "rust-unsafe.rs")).text();
+      return parser.parseFile(content, "rust-unsafe.rs");
+    };
+
+    it("should flag unsafe functions and traits", async () => {
+      const result = await parseUnsafe();
+
+      const flagged = result.entities.filter((e) => e.metadata?.isUnsafe).map((e) => e.name);
+      expect(flagged).toEqual(["raw_copy", "Zeroable", "from_raw"]);
+      const safeSum = result.entities.find((e) => e.name === "safe_sum");
+      expect(safeSum?.metadata?.isUnsafe).toBeUndefined();
+      expect(safeSum?.metadata?.unsafeBlocks).toBeUndefined();
+    });
+
+    it("should record unsafe blocks on the enclosing function", async () => {
+      const result = await parseUnsafe();
+      const blocksOf = (name: string) =>
+        result.entities.find((e) => e.name === name)?.metadata?.unsafeBlocks;
+
+      // The inner block of read_header is covered by the outer span
+      expect(blocksOf("read_header")).toEqual([
+        { lineStart: 8, lineEnd: 8 },
+        { lineStart: 9, lineEnd: 12 },
+      ]);
+      // Closure blocks count toward the function; nested fn blocks do not
+      expect(blocksOf("with_closure")).toEqual([{ lineStart: 17, lineEnd: 17 }]);
+      expect(blocksOf("nested")).toEqual([{ lineStart: 20, lineEnd: 20 }]);
+    });
+
+    it("should flag unsafe impls", async () => {
+      const result = await parseUnsafe();
+
+      const sendImpl = result.implementations?.find((i) => i.traitName === "Send");
+      expect(sendImpl?.isUnsafe).toBe(true);
+      const inherent = result.implementations?.find((i) => i.traitName === undefined);
+      expect(inherent?.isUnsafe).toBe(false);
+    });
+  });
+
+  describe("parseFile - Rust Complexity", () => {
+    const parseComplexity = async () => {
+      const content = await Bun.file(path.join(FIXTURES_DIR, "rust-complexity.rs")).text();
+      return parser.parseFile(content, "rust-complexity.rs");
+    };
+
+    it("should compute approximate cyclomatic complexity of function bodies", async () => {
+      const result = await parseComplexity();
+      const complexityOf = (name: string) =>
+        result.entities.find((e) => e.name === name)?.metadata?.complexity;
+
+      expect(complexityOf("straight")).toBe(1);
+      // if + else if
+      expect(complexityOf("classify")).toBe(3);
+      // three arms (+2) and one guard (+1)
+      expect(complexityOf("describe")).toBe(4);
+      expect(complexityOf("boosted")).toBe(2);
+    });
+
+    it("should count closures but not nested functions", async () => {
+      const result = await parseComplexity();
+      const complexityOf = (name: string) =>
+        result.entities.find((e) => e.name === name)?.metadata?.complexity;
+
+      // ? + for + while + && + || + closure if + loop
+      expect(complexityOf("load")).toBe(8);
+      expect(complexityOf("helper")).toBe(2);
+    });
+  });
+
+  describe("parseFile - Rust Structural Fingerprints", () => {
+    const parseClones = async () => {
+      const content = await Bun.file(path.join(FIXTURES_DIR, "rust-clones.rs")).text();
+      const result = await parser.parseFile(content, "rust-clones.rs");
+      return (name: string) => result.entities.find((e) => e.name === name)?.metadata?.structure;
+    };
+
+    it("should give renamed copies the same fingerprint", async () => {
+      const structureOf = await parseClones();
+
+      const original = structureOf("order_total");
+      expect(original?.hash).toMatch(/^[0-9a-f]{16}$/);
+      expect(original?.minHash).toHaveLength(MIN_HASH_SLOTS);
+      // Names, literals and comments are ignored
+      expect(structureOf("shipment_weight")).toEqual(original);
+    });
+
+    it("should score near clones above unrelated functions", async () => {
+      const structureOf = await parseClones();
+      const original = structureOf("order_total")!;
+      const edited = structureOf("order_total_capped")!;
+      const unrelated = structureOf("parse_header")!;
+
+      expect(edited.hash).not.toBe(original.hash);
+      const nearScore = structuralSimilarity(original, edited);
+      expect(nearScore).toBeGreaterThanOrEqual(0.5);
+      expect(nearScore).toBeLessThan(1);
+      expect(structuralSimilarity(original, unrelated)).toBeLessThan(nearScore);
+    });
+
+    it("should not fingerprint functions without a body", async () => {
+      const content = "pub trait Shape {\n    fn area(&self) -> f64;\n}\n";
+      const result = await parser.parseFile(content, "shape.rs");
+
+      expect(result.entities.every((e) => e.metadata?.structure === undefined)).toBe(true);
+    });
+  });
+
+  describe("parseFile - Rust Source Ranges", () => {
+    const content = [
+      "pub struct Point {",
+      "    x: f64,",
+      "}",
+      "",
+      "impl Point {",
+      "    pub fn new() -> Self {",
+      "        Point { x: 0.0 }",
+      "    }",
+      "}",
+    ].join("\n");
+
+    it("should record the range of each entity's name", async () => {
+      const result = await parser.parseFile(content, "point.rs");
+
+      const point = result.entities.find((e) => e.name === "Point");
+      expect(point?.nameRange).toEqual({
+        lineStart: 1,
+        lineEnd: 1,
+        columnStart: 11,
+        columnEnd: 16,
+      });
+      const newFn = result.entities.find((e) => e.name === "new");
+      expect(newFn?.nameRange).toEqual({
+        lineStart: 6,
+        lineEnd: 6,
+        columnStart: 11,
+        columnEnd: 14,
+      });
+    });
+
+    it("should record the full range of impl blocks", async () => {
+      const result = await parser.parseFile(content, "point.rs");
+
+      expect(result.implementations?.[0]?.range).toEqual({
+        lineStart: 5,
+        lineEnd: 9,
+        columnStart: 0,
+        columnEnd: 1,
+      });
+    });
+  });
+
+  describe("parseFile - Rust Constant Values", () => {
+    const content = [
+      "pub const MAX_SIZE: usize = 1_024usize;",
+      "const MASK: u32 = 0xFF;",
+      "const OFFSET: i64 = -(16);",
+      "const RATIO: f64 = 2.5e-1;",
+      "const WHOLE: f32 = 1f32;",
+      'const GREETING: &str = "hi\\tthere";',
+      'const RAW: &str = r#"a "b""#;',
+      "static VERBOSE: bool = false;",
+      "const BUFFER: usize = 4 * 1024;",
+      "const HUGE: u64 = u64::MAX;",
+      "const TOO_BIG: u128 = 1_000_000_000_000_000_000;",
+    ].join("\n");
+
+    const valueOf = async (name: string) => {
+      const result = await parser.parseFile(content, "config.rs");
+      return result.entities.find((e) => e.name === name)?.metadata;
+    };
+
+    it("should decode integer literals", async () => {
+      expect((await valueOf("MAX_SIZE"))?.value).toEqual({ kind: "integer", value: 1024 });
+      expect((await valueOf("MAX_SIZE"))?.valueText).toBe("1_024usize");
+      expect((await valueOf("MASK"))?.value).toEqual({ kind: "integer", value: 255 });
+      expect((await valueOf("OFFSET"))?.value).toEqual({ kind: "integer", value: -16 });
+    });
+
+    it("should decode float, string and bool literals", async () => {
+      expect((await valueOf("RATIO"))?.value).toEqual({ kind: "float", value: 0.25 });
+      expect((await valueOf("WHOLE"))?.value).toEqual({ kind: "float", value: 1 });
+      expect((await valueOf("GREETING"))?.value).toEqual({ kind: "string", value: "hi\tthere" });
+      expect((await valueOf("RAW"))?.value).toEqual({ kind: "string", value: 'a "b"' });
+      expect((await valueOf("VERBOSE"))?.value).toEqual({ kind: "bool", value: false });
+    });
+
+    it("should keep only the text of non-literal initializers", async () => {
+      const buffer = await valueOf("BUFFER");
+      expect(buffer?.value).toBeUndefined();
+      expect(buffer?.valueText).toBe("4 * 1024");
+      expect((await valueOf("HUGE"))?.value).toBeUndefined();
+      expect((await valueOf("TOO_BIG"))?.value).toBeUndefined();
+    });
+  });
+
+  describe("parseFile - Rust Supertraits", () => {
+    const parseSupertraits = async () => {
+      const content = await Bun.file(path.join(FIXTURES_DIR, "rust-supertraits.rs")).text();
+      return parser.parseFile(content, "rust-supertraits.rs");
+    };
+
+    it("should extract supertrait bounds of traits", async () => {
+      const result = await parseSupertraits();
+      const supertraitsOf = (name: string) =>
+        result.entities.find((e) => e.name === name)?.metadata?.supertraits;
+
+      expect(supertraitsOf("Animal")).toBeUndefined();
+      expect(supertraitsOf("Pet")).toEqual(["Animal", "Clone"]);
+      expect(supertraitsOf("Shown")).toEqual(["std::fmt::Display", "PartialEq<Self>"]);
+    });
+
+    it("should include Self bounds from where clauses", async () => {
+      const result = await parseSupertraits();
+      const supertraitsOf = (name: string) =>
+        result.entities.find((e) => e.name === name)?.metadata?.supertraits;
+
+      expect(supertraitsOf("Named")).toEqual(["Debug", "Animal"]);
+      // Bounds repeated in the where clause are not duplicated
+      expect(supertraitsOf("Loud")).toEqual(["Pet", "Display"]);
+    });
+
+    it("should skip lifetime and ?Sized bounds", async () => {
+      const result = await parseSupertraits();
+
+      const handle = result.entities.find((e) => e.name === "Handle");
+      expect(handle?.metadata?.supertraits).toEqual(["Send"]);
+    });
+  });
+
+  describe("parseFile - Rust Doctests", () => {
+    const parseDoctests = async () => {
+      const content = await Bun.file(path.join(FIXTURES_DIR, "rust-doctests.rs")).text();
+      return parser.parseFile(content, "rust-doctests.rs");
+    };
+
+    it("should attach doc comment examples to the documented item", async () => {
+      const result = await parseDoctests();
+
+      const add = result.entities.find((e) => e.name === "add");
+      expect(add?.metadata?.doctests).toEqual([
+        {
+          info: "",
+          mode: "run",
+          code: "use mycrate::add;\nlet total = add(1, 2);\nassert_eq!(add(total, 3), 6);",
+          lineStart: 4,
+          lineEnd: 6,
+          calls: ["add"],
+          definedSymbols: [],
+        },
+      ]);
+      // The documentation text itself is unchanged
+      expect(add?.metadata?.documentation).toContain("/// ```");
+    });
+
+    it("should classify blocks by their fence info string", async () => {
+      const result = await parseDoctests();
+
+      const doctests = result.entities.find((e) => e.name === "Connection")?.metadata?.doctests;
+      // The `text` block is not Rust and is skipped
+      expect(doctests?.map((d) => [d.info, d.mode])).toEqual([
+        ["no_run", "no_run"],
+        ["ignore-wasm32,should_panic", "ignore"],
+      ]);
+      expect(doctests?.[0]?.definedSymbols).toEqual(["main"]);
+      expect(doctests?.[0]?.calls).toEqual(["open", "close"]);
+    });
+
+    it("should read examples from block doc comments", async () => {
+      const result = await parseDoctests();
+
+      const doctests = result.entities.find((e) => e.name === "double")?.metadata?.doctests;
+      expect(doctests).toHaveLength(1);
+      expect(doctests?.[0]).toMatchObject({
+        mode: "compile_fail",
+        code: 'let x: u8 = double("2");',
+        lineStart: 34,
+        lineEnd: 34,
+        calls: ["double"],
+      });
+    });
   });
 
   describe("parseFile - Rust Types (Structs, Traits, Enums)", () => {
@@ -1540,6 +2236,47 @@ export function documented(): void {}
       expect(color?.isExported).toBe(true);
     });
 
+    it("should extract enum variants without discriminants", async () => {
+      const content = await Bun.file(path.join(FIXTURES_DIR, "simple-rust.rs")).text();
+      const result = await parser.parseFile(content, "simple-rust.rs");
+
+      const color = result.entities.find((e) => e.name === "Color");
+      expect(color?.metadata?.variants?.map((v) => v.name)).toEqual([
+        "Red",
+        "Green",
+        "Blue",
+        "Custom",
+      ]);
+      expect(color?.metadata?.variants?.every((v) => v.discriminant === undefined)).toBe(true);
+    });
+
+    it("should extract explicit enum discriminants as written", async () => {
+      const content = [
+        "#[repr(u8)]",
+        "pub enum Opcode {",
+        "    Nop = 0,",
+        "    Load = 0x10,",
+        "    Implicit,",
+        "    Flag = 1 << 3,",
+        "}",
+      ].join("\n");
+      const result = await parser.parseFile(content, "opcode.rs");
+
+      const opcode = result.entities.find((e) => e.name === "Opcode");
+      const range = (line: number, columnEnd: number): Record<string, number> => ({
+        lineStart: line,
+        lineEnd: line,
+        columnStart: 4,
+        columnEnd,
+      });
+      expect(opcode?.metadata?.variants).toEqual([
+        { name: "Nop", discriminant: "0", line: 3, range: range(3, 11) },
+        { name: "Load", discriminant: "0x10", line: 4, range: range(4, 14) },
+        { name: "Implicit", line: 5, range: range(5, 12) },
+        { name: "Flag", discriminant: "1 << 3", line: 6, range: range(6, 15) },
+      ]);
+    });
+
     it("should parse Rust type aliases", async () => {
       const content = await Bun.file(path.join(FIXTURES_DIR, "simple-rust.rs")).text();
       const result = await parser.parseFile(content, "simple-rust.rs");
@@ -1562,6 +2299,19 @@ export function documented(): void {}
       expect(pair?.metadata?.typeParameters).toBeDefined();
       expect(pair?.metadata?.typeParameters?.length).toBeGreaterThan(0);
     });
+
+    it("should preserve the where clause verbatim", async () => {
+      const content = await Bun.file(path.join(FIXTURES_DIR, "simple-rust.rs")).text();
+      const result = await parser.parseFile(content, "simple-rust.rs");
+
+      const pair = result.entities.find((e) => e.name === "Pair" && e.type === "class");
+      expect(pair?.metadata?.whereClause).toMatch(/^where\s+K: Eq \+ std::hash::Hash/);
+
+      // The clause belongs to the impl block, not to the methods inside it
+      const pairNew = result.entities.find((e) => e.name === "new" && e.parentName === "Pair");
+      expect(pairNew).toBeDefined();
+      expect(pairNew?.metadata?.whereClause).toBeUndefined();
+    });
   });
 
   describe("parseFile - Rust Variables (const/static)", () => {
@@ -1695,6 +2445,333 @@ export function documented(): void {}
         expect(call.isAsync).toBe(false);
       }
     });
+
+    it("should capture turbofish type arguments when present", async () => {
+      const content = `
+fn run(input: &str) {
+    let n = input.parse::<u32>();
+    let v = Vec::<String>::new();
+    let m = convert::<Foo, Bar>(n);
+    plain(n);
+}
+`;
+      const result = await parser.parseFile(content, "turbofish.rs");
+
+      expect(result.success).toBe(true);
+      const parseCall = result.calls.find((c) => c.calledName === "parse");
+      expect(parseCall?.typeArguments).toEqual(["u32"]);
+      const newCall = result.calls.find((c) => c.calledName === "new");
+      expect(newCall?.typeArguments).toEqual(["String"]);
+      const convertCall = result.calls.find((c) => c.calledName === "convert");
+      expect(convertCall?.typeArguments).toEqual(["Foo", "Bar"]);
+
+      const plainCall = result.calls.find((c) => c.calledName === "plain");
+      expect(plainCall).toBeDefined();
+      expect(plainCall).not.toHaveProperty("typeArguments");
+    });
+
+    it("should capture the source text of each argument", async () => {
+      const content = `
+fn run() {
+    function_with_params("World", 3);
+    point.scale(2.0 /* factor */, |x| x + 1);
+    reset();
+}
+`;
+      const result = await parser.parseFile(content, "args.rs");
+
+      expect(result.success).toBe(true);
+      const paramsCall = result.calls.find((c) => c.calledName === "function_with_params");
+      expect(paramsCall?.arguments).toEqual(['"World"', "3"]);
+      const scaleCall = result.calls.find((c) => c.calledName === "scale");
+      expect(scaleCall?.arguments).toEqual(["2.0", "|x| x + 1"]);
+      const resetCall = result.calls.find((c) => c.calledName === "reset");
+      expect(resetCall?.arguments).toEqual([]);
+    });
+  });
+
+  describe("parseFile - Rust Impl Blocks", () => {
+    it("should extract inherent impls without a trait", async () => {
+      const content = await Bun.file(path.join(FIXTURES_DIR, "simple-rust.rs")).text();
+      const result = await parser.parseFile(content, "simple-rust.rs");
+
+      const pointImpl = result.implementations?.find((i) => i.typeName === "Point");
+      expect(pointImpl).toBeDefined();
+      expect(pointImpl?.traitName).toBeUndefined();
+      expect(pointImpl?.typeParameters).toHaveLength(0);
+      expect(pointImpl?.whereClause).toHaveLength(0);
+    });
+
+    it("should extract trait impls", async () => {
+      const content = await Bun.file(path.join(FIXTURES_DIR, "simple-rust.rs")).text();
+      const result = await parser.parseFile(content, "simple-rust.rs");
+
+      const dogImpl = result.implementations?.find(
+        (i) => i.typeName === "Dog" && i.traitName === "Animal"
+      );
+      expect(dogImpl).toBeDefined();
+      expect(dogImpl?.isNegative).toBe(false);
+      expect(dogImpl?.whereClause).toHaveLength(0);
+    });
+
+    it("should capture generic params and where clause of conditional impls", async () => {
+      const content = await Bun.file(path.join(FIXTURES_DIR, "simple-rust.rs")).text();
+      const result = await parser.parseFile(content, "simple-rust.rs");
+
+      const wrapperImpl = result.implementations?.find((i) => i.typeName === "Wrapper<T>");
+      expect(wrapperImpl).toBeDefined();
+      expect(wrapperImpl?.traitName).toBe("Animal");
+      expect(wrapperImpl?.typeParameters).toEqual(["T"]);
+      expect(wrapperImpl?.whereClause).toEqual(["T: Animal"]);
+    });
+
+    it("should normalize multi-bound where predicates on inherent impls", async () => {
+      const content = await Bun.file(path.join(FIXTURES_DIR, "simple-rust.rs")).text();
+      const result = await parser.parseFile(content, "simple-rust.rs");
+
+      const pairImpl = result.implementations?.find((i) => i.typeName === "Pair<K, V>");
+      expect(pairImpl).toBeDefined();
+      expect(pairImpl?.traitName).toBeUndefined();
+      expect(pairImpl?.typeParameters).toEqual(["K", "V"]);
+      expect(pairImpl?.whereClause).toEqual(["K: Eq + std::hash::Hash"]);
+    });
+
+    it("should extract blanket and negative impls", async () => {
+      const content = [
+        "impl<T: Display> ToText for T {}",
+        "impl !Send for Handle {}",
+      ].join("\n");
+      const result = await parser.parseFile(content, "impls.rs");
+
+      const blanket = result.implementations?.find((i) => i.traitName === "ToText");
+      expect(blanket?.typeName).toBe("T");
+      expect(blanket?.typeParameters).toEqual(["T: Display"]);
+
+      const negative = result.implementations?.find((i) => i.typeName === "Handle");
+      expect(negative?.traitName).toBe("Send");
+      expect(negative?.isNegative).toBe(true);
+      expect(negative?.line).toBe(2);
+    });
+
+    it("should link impl items to the implementing type", async () => {
+      const content = await Bun.file(path.join(FIXTURES_DIR, "simple-rust.rs")).text();
+      const result = await parser.parseFile(content, "simple-rust.rs");
+
+      const breed = result.entities.find((e) => e.name === "breed");
+      expect(breed?.parentName).toBe("Dog");
+
+      const pairNew = result.entities.find((e) => e.name === "new" && e.parentName === "Pair");
+      expect(pairNew).toBeDefined();
+
+      const point = result.entities.find((e) => e.name === "Point" && e.type === "class");
+      expect(point?.parentName).toBeUndefined();
+    });
+
+    it("should not report implementations for non-Rust files", async () => {
+      const result = await parser.parseFile("class Foo {}", "foo.ts");
+
+      expect(result.implementations).toBeUndefined();
+    });
+  });
+
+  describe("parseFile - TODO Comments", () => {
+    it("should extract TODO and FIXME markers from line and block comments", async () => {
+      const content = [
+        "// TODO: cache the result",
+        "export function load() {",
+        "  /* FIXME(auth): token refresh races with logout */",
+        "  return 1; // TODOS are not markers",
+        "}",
+      ].join("\n");
+      const result = await parser.parseFile(content, "load.ts");
+
+      expect(result.todos).toEqual([
+        { tag: "TODO", message: "cache the result", line: 1 },
+        { tag: "FIXME", message: "token refresh races with logout", line: 3 },
+      ]);
+    });
+
+    it("should report the line of each marker inside a multi-line block comment", async () => {
+      const content = ["/*", " * TODO first", " * FIXME second", " */", "struct A;"].join("\n");
+      const result = await parser.parseFile(content, "a.rs");
+
+      expect(result.todos?.map((t) => [t.tag, t.line])).toEqual([
+        ["TODO", 2],
+        ["FIXME", 3],
+      ]);
+    });
+
+    it("should extract Python hash comments", async () => {
+      const result = await parser.parseFile("x = 1  # TODO handle None\n", "x.py");
+
+      expect(result.todos).toEqual([{ tag: "TODO", message: "handle None", line: 1 }]);
+    });
+
+    it("should honor configured tags", async () => {
+      const custom = new TreeSitterParser(undefined, { todoTags: ["HACK"] });
+      const result = await custom.parseFile("// HACK: skip\n// TODO: ignored\n", "h.ts");
+
+      expect(result.todos).toEqual([{ tag: "HACK", message: "skip", line: 1 }]);
+    });
+  });
+
+  describe("parseFile - String Literals", () => {
+    it("should not extract string literals by default", async () => {
+      const result = await parser.parseFile('const greeting = "hello";\n', "g.ts");
+
+      expect(result.strings).toBeUndefined();
+    });
+
+    it("should extract literals with their enclosing symbol when enabled", async () => {
+      const indexing = new TreeSitterParser(undefined, { indexStringLiterals: true });
+      const content = [
+        'import { api } from "./api";',
+        'const VERSION = "v1";',
+        "export function login() {",
+        '  throw new Error("Session expired, please log in again");',
+        "}",
+        'const empty = "";',
+      ].join("\n");
+      const result = await indexing.parseFile(content, "login.ts");
+
+      expect(result.strings).toEqual([
+        { value: "v1", line: 2, column: 16, enclosingSymbol: "VERSION" },
+        {
+          value: "Session expired, please log in again",
+          line: 4,
+          column: 18,
+          enclosingSymbol: "login",
+        },
+      ]);
+    });
+
+    it("should strip Rust raw string delimiters and qualify impl methods", async () => {
+      const indexing = new TreeSitterParser(undefined, { indexStringLiterals: true });
+      const content = [
+        "struct Bot;",
+        "impl Bot {",
+        "    fn name() -> &'static str {",
+        '        r#"say "hi""#',
+        "    }",
+        "}",
+      ].join("\n");
+      const result = await indexing.parseFile(content, "bot.rs");
+
+      expect(result.strings).toEqual([
+        { value: 'say "hi"', line: 4, column: 8, enclosingSymbol: "Bot.name" },
+      ]);
+    });
+  });
+
+  describe("parseFile - Symbol Kinds", () => {
+    it("should classify Rust impl and trait functions as methods", async () => {
+      const content = [
+        "trait Greeter { fn greet(&self) {} }",
+        "struct Bot;",
+        "impl Bot { fn new() -> Self { Bot } }",
+        "fn helper() {}",
+      ].join("\n");
+      const result = await parser.parseFile(content, "kinds.rs");
+
+      expect(result.entities.find((e) => e.name === "greet")?.kind).toBe("method");
+      expect(result.entities.find((e) => e.name === "new")?.kind).toBe("method");
+      expect(result.entities.find((e) => e.name === "helper")?.kind).toBe("function");
+      expect(result.entities.find((e) => e.name === "Greeter")?.kind).toBe("interface");
+      expect(result.entities.find((e) => e.name === "Bot")?.kind).toBe("class");
+    });
+
+    it("should classify Python class functions as methods, including decorated ones", async () => {
+      const content = [
+        "class Service:",
+        "    def run(self):",
+        "        def inner():",
+        "            pass",
+        "    @staticmethod",
+        "    def create():",
+        "        pass",
+        "",
+        "def main():",
+        "    pass",
+      ].join("\n");
+      const result = await parser.parseFile(content, "kinds.py");
+
+      expect(result.entities.find((e) => e.name === "run")?.kind).toBe("method");
+      expect(result.entities.find((e) => e.name === "create")?.kind).toBe("method");
+      expect(result.entities.find((e) => e.name === "inner")?.kind).toBe("function");
+      expect(result.entities.find((e) => e.name === "main")?.kind).toBe("function");
+    });
+
+    it("should distinguish Go interfaces from structs and keep receiver methods", async () => {
+      const content = [
+        "package kinds",
+        "type Reader interface { Read() }",
+        "type File struct {}",
+        "func (f *File) Read() {}",
+      ].join("\n");
+      const result = await parser.parseFile(content, "kinds.go");
+
+      expect(result.entities.find((e) => e.name === "Reader")?.kind).toBe("interface");
+      expect(result.entities.find((e) => e.name === "File")?.kind).toBe("class");
+      expect(result.entities.find((e) => e.name === "Read")?.kind).toBe("method");
+    });
+
+    it("should classify Ruby modules as namespaces and TypeScript properties as fields", async () => {
+      const ruby = await parser.parseFile("module Util\n  def self.run\n  end\nend", "kinds.rb");
+      expect(ruby.entities.find((e) => e.name === "Util")?.kind).toBe("namespace");
+
+      const ts = await parser.parseFile("class A { count = 0; run() {} }", "kinds.ts");
+      expect(ts.entities.find((e) => e.name === "count")?.kind).toBe("field");
+      expect(ts.entities.find((e) => e.name === "run")?.kind).toBe("method");
+    });
+  });
+
+  describe("parseFile - Rust Strings Containing Braces", () => {
+    const loadFixture = (): Promise<string> =>
+      Bun.file(path.join(FIXTURES_DIR, "rust-strings.rs")).text();
+
+    it("should take symbol spans from node boundaries", async () => {
+      const result = await parser.parseFile(await loadFixture(), "rust-strings.rs");
+
+      const spans = result.entities.map((e) => [e.name, e.lineStart, e.lineEnd]);
+      expect(spans).toEqual(
+        expect.arrayContaining([
+          ["TEMPLATE", 4, 4],
+          ["raw_pattern", 6, 8],
+          ["after_raw", 10, 12],
+          ["hashes", 14, 16],
+          ["escaped", 18, 22],
+          ["Parser", 24, 26],
+          ["new", 29, 33],
+          ["embedded", 35, 37],
+          ["last", 40, 42],
+        ])
+      );
+      // Code inside string literals is not extracted
+      expect(result.entities.find((e) => e.name === "Foo" || e.name === "bar")).toBeUndefined();
+      expect(result.errors).toHaveLength(0);
+    });
+
+    it("should attribute calls after a raw string to the right function", async () => {
+      const result = await parser.parseFile(await loadFixture(), "rust-strings.rs");
+
+      const call = result.calls.find((c) => c.calledName === "raw_pattern");
+      expect(call).toMatchObject({ line: 11, callerName: "after_raw" });
+    });
+
+    it("should extract raw and brace-containing literals intact", async () => {
+      const indexing = new TreeSitterParser(undefined, { indexStringLiterals: true });
+      const result = await indexing.parseFile(await loadFixture(), "rust-strings.rs");
+
+      const values = result.strings?.map((s) => [s.value, s.enclosingSymbol]);
+      expect(values).toEqual(
+        expect.arrayContaining([
+          ["fn {name}() {{ }}", "TEMPLATE"],
+          ["^\\s*fn\\s+(\\w+)\\s*\\{", "raw_pattern"],
+          ['a "# still inside } { ', "hashes"],
+          ["impl Foo { fn bar() { } }", "Parser.embedded"],
+        ])
+      );
+    });
   });
 
   describe("parseFile - Rust Exports", () => {
@@ -0,0 +1,104 @@
+/**
+ * Unit tests for conditional compilation predicates.
+ */
+
+import { describe, it, expect } from "bun:test";
+import {
+  cfgConditionFromAttributes,
+  isCfgSatisfiable,
+  parseCfgPredicate,
+  parseTargetPlatform,
+  type TargetPlatform,
+} from "../../../../src/graph/parsing/cfg-predicates.js";
+
+const platform = (spec: string): TargetPlatform => {
+  const parsed = parseTargetPlatform(spec);
+  if (!parsed) throw new Error(`unrecognized platform ${spec}`);
+  return parsed;
+};
+
+describe("parseCfgPredicate", () => {
+  it("parses options, key-value pairs and nested combinators", () => {
+    expect(parseCfgPredicate("unix")).toEqual({ kind: "option", name: "unix" });
+    expect(parseCfgPredicate('all(unix, not(target_os="macos"))')).toEqual({
+      kind: "all",
+      items: [
+        { kind: "option", name: "unix" },
+        { kind: "not", item: { kind: "option", name: "target_os", value: "macos" } },
+      ],
+    });
+  });
+
+  it("rejects malformed predicates", () => {
+    expect(parseCfgPredicate("all(unix")).toBeUndefined();
+    expect(parseCfgPredicate("not(unix, windows)")).toBeUndefined();
+    expect(parseCfgPredicate("maybe(unix)")).toBeUndefined();
+    expect(parseCfgPredicate("target_os = linux")).toBeUndefined();
+  });
+});
+
+describe("cfgConditionFromAttributes", () => {
+  it("joins several cfg attributes with all, in canonical form", () => {
+    expect(cfgConditionFromAttributes(["derive(Debug)", "cfg(unix)"])).toBe("unix");
+    expect(
+      cfgConditionFromAttributes(["cfg(any(unix,windows))", 'cfg(feature="serde")', "inline"])
+    ).toBe('all(any(unix, windows), feature = "serde")');
+  });
+
+  it("ignores cfg_attr and items without a cfg", () => {
+    expect(cfgConditionFromAttributes(["cfg_attr(test, derive(Debug))"])).toBeUndefined();
+    expect(cfgConditionFromAttributes([])).toBeUndefined();
+  });
+});
+
+describe("parseTargetPlatform", () => {
+  it("reads target triples", () => {
+    expect(parseTargetPlatform("x86_64-pc-windows-msvc")).toEqual({
+      os: "windows",
+      families: ["windows"],
+      arch: "x86_64",
+      vendor: "pc",
+      env: "msvc",
+      pointerWidth: "64",
+    });
+    expect(parseTargetPlatform("aarch64-apple-darwin")).toMatchObject({
+      os: "macos",
+      families: ["unix"],
+      env: "",
+    });
+    expect(parseTargetPlatform("wasm32-unknown-unknown")).toMatchObject({
+      families: ["wasm"],
+      pointerWidth: "32",
+    });
+    expect(parseTargetPlatform("aarch64-linux-android")).toMatchObject({
+      os: "android",
+      vendor: "unknown",
+    });
+    expect(parseTargetPlatform("thumbv7em-none-eabihf")).toMatchObject({ os: "none", arch: "arm" });
+  });
+
+  it("reads OS names and families, and rejects unknown platforms", () => {
+    expect(parseTargetPlatform("Linux")).toEqual({ os: "linux", families: ["unix"] });
+    expect(parseTargetPlatform("unix")).toEqual({ families: ["unix"] });
+    expect(parseTargetPlatform("amiga")).toBeUndefined();
+  });
+});
+
+describe("isCfgSatisfiable", () => {
+  it("rules out platform variants for other targets", () => {
+    expect(isCfgSatisfiable("unix", platform("x86_64-unknown-linux-gnu"))).toBe(true);
+    expect(isCfgSatisfiable("unix", platform("windows"))).toBe(false);
+    expect(isCfgSatisfiable('target_os = "windows"', platform("linux"))).toBe(false);
+    expect(isCfgSatisfiable('all(unix, not(target_os = "macos"))', platform("macos"))).toBe(false);
+    expect(isCfgSatisfiable('target_pointer_width = "32"', platform("i686-pc-windows-msvc"))).toBe(
+      true
+    );
+  });
+
+  it("keeps conditions that depend on unknown predicates", () => {
+    expect(isCfgSatisfiable('feature = "serde"', platform("windows"))).toBe(true);
+    expect(isCfgSatisfiable('all(unix, feature = "serde")', platform("windows"))).toBe(false);
+    expect(isCfgSatisfiable('any(windows, feature = "serde")', platform("linux"))).toBe(true);
+    expect(isCfgSatisfiable('target_os = "linux"', platform("unix"))).toBe(true);
+  });
+});
@@ -0,0 +1,151 @@
+/**
+ * Unit tests for per-language doc comment normalization.
+ */
+
+import { describe, it, expect } from "bun:test";
+import {
+  DOC_COMMENT_STYLES,
+  DEFAULT_DOC_COMMENT_STYLES,
+  classifyDocComment,
+  docSummary,
+  extractDocText,
+  splitDocComments,
+} from "../../../../src/graph/parsing/doc-comments.js";
+
+describe("classifyDocComment", () => {
+  it("classifies comments by their opening marker", () => {
+    expect(classifyDocComment("/** Docs. */")).toBe("doc_block");
+    expect(classifyDocComment("/// Docs.")).toBe("doc_line");
+    expect(classifyDocComment("//! Module docs.")).toBe("inner_doc");
+    expect(classifyDocComment("/*! Module docs. */")).toBe("inner_doc");
+    expect(classifyDocComment("// Note.")).toBe("line");
+    expect(classifyDocComment("# Note.")).toBe("line");
+    expect(classifyDocComment("/* Note. */")).toBe("block");
+    expect(classifyDocComment('"""Docs."""')).toBe("docstring");
+    expect(classifyDocComment("r'''Docs.'''")).toBe("docstring");
+  });
+
+  it("treats empty blocks and four slashes as ordinary comments", () => {
+    expect(classifyDocComment("/**/")).toBe("block");
+    expect(classifyDocComment("//// Separator")).toBe("line");
+  });
+
+  it("returns null for text that is not a comment", () => {
+    expect(classifyDocComment("fn main() {}")).toBeNull();
+  });
+});
+
+describe("splitDocComments", () => {
+  it("keeps block comments spanning several lines together", () => {
+    expect(splitDocComments("/// First.\n/**\n * Second.\n */\n// Third.")).toEqual([
+      "/// First.",
+      "/**\n * Second.\n */",
+      "// Third.",
+    ]);
+  });
+
+  it("keeps a docstring whole", () => {
+    expect(splitDocComments('"""\n    # Not a comment.\n    """')).toHaveLength(1);
+  });
+});
+
+describe("extractDocText", () => {
+  it("honors at least one known style by default in every language", () => {
+    for (const styles of Object.values(DEFAULT_DOC_COMMENT_STYLES)) {
+      expect(styles.length).toBeGreaterThan(0);
+      for (const style of styles) {
+        expect(DOC_COMMENT_STYLES).toContain(style);
+      }
+    }
+  });
+
+  describe("Rust", () => {
+    it("strips line doc markers", () => {
+      expect(extractDocText("rust", "/// Adds two numbers.\n///\n/// # Panics")).toBe(
+        "Adds two numbers.\n\n# Panics"
+      );
+    });
+
+    it("strips block doc decoration", () => {
+      expect(extractDocText("rust", "/**\n     * Adds.\n     *\n     * More.\n     */")).toBe(
+        "Adds.\n\nMore."
+      );
+    });
+
+    it("honors only the configured styles", () => {
+      const raw = "//! Module.\n/// Item.";
+      expect(extractDocText("rust", raw)).toBe("Module.\nItem.");
+      expect(extractDocText("rust", raw, ["doc_line"])).toBe("Item.");
+      expect(extractDocText("rust", raw, ["docstring"])).toBeUndefined();
+    });
+  });
+
+  describe("TypeScript and JavaScript", () => {
+    it("strips JSDoc markers and keeps tags", () => {
+      const raw = "/**\n   * Doubles a number.\n   * @param x - The input\n   */";
+      expect(extractDocText("typescript", raw)).toBe("Doubles a number.\n@param x - The input");
+      expect(extractDocText("javascript", "/** One line. */")).toBe("One line.");
+    });
+
+    it("ignores plain comments", () => {
+      expect(extractDocText("typescript", "// Not docs.")).toBeUndefined();
+    });
+  });
+
+  describe("Python", () => {
+    it("dedents docstrings following PEP 257", () => {
+      const raw = '"""\n    Greets.\n\n    Args:\n        name: Who\n    """';
+      expect(extractDocText("python", raw)).toBe("Greets.\n\nArgs:\n    name: Who");
+    });
+
+    it("handles single-line and prefixed docstrings", () => {
+      expect(extractDocText("python", '"""Returns hello."""')).toBe("Returns hello.");
+      expect(extractDocText("python", "r'''Raw \\d docs.'''")).toBe("Raw \\d docs.");
+    });
+
+    it("returns undefined for an empty docstring", () => {
+      expect(extractDocText("python", '""" """')).toBeUndefined();
+    });
+  });
+
+  describe("Java and PHP", () => {
+    it("strips Javadoc and PHPDoc markers", () => {
+      expect(extractDocText("java", "/**\n * Simple class.\n */")).toBe("Simple class.");
+      expect(extractDocText("php", "/**\n * @return string\n */")).toBe("@return string");
+    });
+  });
+
+  describe("Go", () => {
+    it("strips line and block comment markers", () => {
+      expect(extractDocText("go", "// Add adds.\n// It is pure.")).toBe("Add adds.\nIt is pure.");
+      expect(extractDocText("go", "/* Add adds. */")).toBe("Add adds.");
+    });
+  });
+
+  describe("C and C++", () => {
+    it("accepts Doxygen and plain comments", () => {
+      expect(extractDocText("c", "/*! Brief. */")).toBe("Brief.");
+      expect(extractDocText("cpp", "/// Brief.\n// Details.")).toBe("Brief.\nDetails.");
+    });
+  });
+
+  describe("Ruby", () => {
+    it("strips hash markers", () => {
+      expect(extractDocText("ruby", "# Greets.\n#\n# @return [String]")).toBe(
+        "Greets.\n\n@return [String]"
+      );
+    });
+  });
+});
+
+describe("docSummary", () => {
+  it("returns the first paragraph on one line", () => {
+    expect(docSummary("Parses a config\nfile.\n\n# Errors\n\nFails on bad input.")).toBe(
+      "Parses a config file."
+    );
+  });
+
+  it("returns single-paragraph text unchanged apart from whitespace", () => {
+    expect(docSummary("  Vector   arithmetic. ")).toBe("Vector arithmetic.");
+  });
+});
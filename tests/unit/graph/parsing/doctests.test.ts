@@ -0,0 +1,96 @@
+/**
+ * Unit tests for doctest extraction from Rust doc comments.
+ */
+
+import { describe, it, expect } from "bun:test";
+import {
+  classifyRustFence,
+  docCommentLines,
+  findFencedCodeBlocks,
+  unhideDoctestLines,
+} from "../../../../src/graph/parsing/doctests.js";
+
+describe("docCommentLines", () => {
+  it("strips line comment markers and one space", () => {
+    expect(docCommentLines("///  indented", 4)).toEqual([{ text: " indented", line: 4 }]);
+    expect(docCommentLines("//! crate docs\n", 1)).toEqual([{ text: "crate docs", line: 1 }]);
+  });
+
+  it("strips block comment markers and star decoration", () => {
+    expect(docCommentLines("/**\n * first\n *\n * last\n */", 10)).toEqual([
+      { text: "", line: 10 },
+      { text: "first", line: 11 },
+      { text: "", line: 12 },
+      { text: "last", line: 13 },
+      { text: " ", line: 14 },
+    ]);
+  });
+
+  it("ignores regular comments", () => {
+    expect(docCommentLines("// not docs", 1)).toEqual([]);
+  });
+});
+
+describe("findFencedCodeBlocks", () => {
+  const lines = (...texts: string[]) => texts.map((text, index) => ({ text, line: index + 1 }));
+
+  it("finds backtick and tilde fences with their info strings", () => {
+    const blocks = findFencedCodeBlocks(
+      lines("Example:", "```no_run", "run();", "```", "", "~~~ text", "output", "~~~")
+    );
+
+    expect(blocks).toEqual([
+      { info: "no_run", code: "run();", lineStart: 3, lineEnd: 3 },
+      { info: "text", code: "output", lineStart: 7, lineEnd: 7 },
+    ]);
+  });
+
+  it("only closes a block at a matching fence", () => {
+    const blocks = findFencedCodeBlocks(lines("````", "```", "~~~", "````"));
+
+    expect(blocks).toEqual([{ info: "", code: "```\n~~~", lineStart: 2, lineEnd: 3 }]);
+  });
+
+  it("runs an unclosed block to the end and skips empty blocks", () => {
+    expect(findFencedCodeBlocks(lines("```", "", "```", "```", "tail()"))).toEqual([
+      { info: "", code: "tail()", lineStart: 5, lineEnd: 5 },
+    ]);
+  });
+});
+
+describe("classifyRustFence", () => {
+  it("treats untagged and rustdoc-attribute blocks as Rust", () => {
+    expect(classifyRustFence("")).toEqual({ isRust: true, mode: "run" });
+    expect(classifyRustFence("rust")).toEqual({ isRust: true, mode: "run" });
+    expect(classifyRustFence("rust,no_run")).toEqual({ isRust: true, mode: "no_run" });
+    expect(classifyRustFence("should_panic edition2021")).toEqual({
+      isRust: true,
+      mode: "should_panic",
+    });
+    expect(classifyRustFence("compile_fail,E0308")).toEqual({
+      isRust: true,
+      mode: "compile_fail",
+    });
+  });
+
+  it("lets ignore take precedence over other modes", () => {
+    expect(classifyRustFence("ignore,no_run").mode).toBe("ignore");
+    expect(classifyRustFence("ignore-windows").mode).toBe("ignore");
+  });
+
+  it("treats other languages as not Rust", () => {
+    expect(classifyRustFence("text").isRust).toBe(false);
+    expect(classifyRustFence("toml").isRust).toBe(false);
+    expect(classifyRustFence("rust,text").isRust).toBe(false);
+  });
+});
+
+describe("unhideDoctestLines", () => {
+  it("removes hidden-line markers and keeps lines one-for-one", () => {
+    const code = "# use std::io;\n#\nlet x = 1;\n    # let y = 2;\n## not hidden\n#[derive(Debug)]";
+
+    expect(unhideDoctestLines(code)).toBe(
+      "use std::io;\n\nlet x = 1;\nlet y = 2;\n# not hidden\n#[derive(Debug)]"
+    );
+  });
+});
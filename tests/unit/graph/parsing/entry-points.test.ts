@@ -0,0 +1,42 @@
+/**
+ * Unit tests for entry point detection.
+ */
+
+import { describe, it, expect } from "bun:test";
+import { attributePath, detectRustEntryPoint } from "../../../../src/graph/parsing/entry-points.js";
+
+describe("attributePath", () => {
+  it("strips arguments and whitespace from attribute contents", () => {
+    expect(attributePath("test")).toBe("test");
+    expect(attributePath('tokio::main(flavor = "current_thread")')).toBe("tokio::main");
+    expect(attributePath('doc = "text"')).toBe("doc");
+  });
+});
+
+describe("detectRustEntryPoint", () => {
+  it("flags free fn main but not associated functions named main", () => {
+    expect(detectRustEntryPoint("main", [], false)).toEqual({ kind: "main" });
+    expect(detectRustEntryPoint("main", [], true)).toBeNull();
+    expect(detectRustEntryPoint("run", [], false)).toBeNull();
+  });
+
+  it("prefers runtime attributes over the main name", () => {
+    expect(detectRustEntryPoint("main", ["tokio::main"], false)).toEqual({
+      kind: "async_main",
+      attribute: "tokio::main",
+    });
+    expect(detectRustEntryPoint("main", ["actix_web::main"], false)?.kind).toBe("async_main");
+  });
+
+  it("flags test and bench functions", () => {
+    expect(detectRustEntryPoint("parses", ["test"], false)?.kind).toBe("test");
+    expect(detectRustEntryPoint("times_out", ["tokio::test(start_paused = true)"], false)).toEqual(
+      { kind: "test", attribute: "tokio::test" }
+    );
+    expect(detectRustEntryPoint("parse_speed", ["bench"], false)?.kind).toBe("bench");
+  });
+
+  it("ignores unrelated attributes", () => {
+    expect(detectRustEntryPoint("helper", ["inline", "allow(dead_code)"], false)).toBeNull();
+  });
+});
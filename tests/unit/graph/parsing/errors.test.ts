@@ -13,6 +13,7 @@ import {
   ParserInitializationError,
   ParseTimeoutError,
   FileTooLargeError,
+  FileTooLongError,
   ExtractionError,
   isRetryableParsingError,
 } from "../../../../src/graph/parsing/errors.js";
@@ -188,6 +189,21 @@ describe("FileTooLargeError", () => {
   });
 });
 
+describe("FileTooLongError", () => {
+  test("should create file too long error", () => {
+    const error = new FileTooLongError("dist/bundle.js", 120000, 20000);
+
+    expect(error).toBeInstanceOf(ParsingError);
+    expect(error.name).toBe("FileTooLongError");
+    expect(error.code).toBe("FILE_TOO_LONG_ERROR");
+    expect(error.filePath).toBe("dist/bundle.js");
+    expect(error.lineCount).toBe(120000);
+    expect(error.maxLines).toBe(20000);
+    expect(error.message).toBe("File has 120000 lines, exceeding maximum 20000");
+    expect(error.retryable).toBe(false);
+  });
+});
+
 describe("ExtractionError", () => {
   test("should create extraction error without node type", () => {
     const error = new ExtractionError("Failed to extract entities", "src/complex.ts");
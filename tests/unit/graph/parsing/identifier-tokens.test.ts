@@ -0,0 +1,69 @@
+/**
+ * Unit tests for identifier tokenization and symbol name scoring.
+ */
+
+import { describe, it, expect } from "bun:test";
+import {
+  NAME_MATCH_SCORES,
+  scoreSymbolName,
+  splitIdentifier,
+} from "../../../../src/graph/parsing/identifier-tokens.js";
+
+describe("splitIdentifier", () => {
+  it("splits snake_case, kebab-case and SCREAMING_CASE", () => {
+    expect(splitIdentifier("scale_factor")).toEqual(["scale", "factor"]);
+    expect(splitIdentifier("scale-factor")).toEqual(["scale", "factor"]);
+    expect(splitIdentifier("MAX_SCALE_FACTOR")).toEqual(["max", "scale", "factor"]);
+  });
+
+  it("splits camelCase and PascalCase boundaries", () => {
+    expect(splitIdentifier("autoScaleFactor")).toEqual(["auto", "scale", "factor"]);
+    expect(splitIdentifier("ScaleFactor")).toEqual(["scale", "factor"]);
+  });
+
+  it("keeps acronym runs together", () => {
+    expect(splitIdentifier("HTTPServer")).toEqual(["http", "server"]);
+    expect(splitIdentifier("parseHTTPResponse")).toEqual(["parse", "http", "response"]);
+  });
+
+  it("separates digits and drops leading separators", () => {
+    expect(splitIdentifier("__init__")).toEqual(["init"]);
+    expect(splitIdentifier("$scope")).toEqual(["scope"]);
+    expect(splitIdentifier("utf8Decode")).toEqual(["utf", "8", "decode"]);
+  });
+});
+
+describe("scoreSymbolName", () => {
+  it("ranks exact, case-insensitive, prefix and substring matches in that order", () => {
+    expect(scoreSymbolName("scale", "scale", false)?.type).toBe("exact");
+    expect(scoreSymbolName("Scale", "scale", false)?.type).toBe("exact_ci");
+    expect(scoreSymbolName("ScaleFactor", "scale", false)?.type).toBe("prefix");
+    expect(scoreSymbolName("rescale", "scale", false)?.type).toBe("substring");
+  });
+
+  it("ignores subtoken matches unless token matching is enabled", () => {
+    expect(scoreSymbolName("factor_scale_x", "scaleX", false)).toBeNull();
+    expect(scoreSymbolName("factor_scale_x", "scaleX", true)).toEqual({
+      type: "token",
+      score: NAME_MATCH_SCORES.token,
+    });
+  });
+
+  it("scales token scores by the fraction of query subtokens found", () => {
+    const match = scoreSymbolName("scale_value", "scaleFactor", true);
+
+    expect(match?.type).toBe("token");
+    expect(match?.score).toBe(0.25);
+  });
+
+  it("always scores full-name matches above token matches", () => {
+    const substring = scoreSymbolName("rescale", "scale", true);
+    const token = scoreSymbolName("factor_scale", "scaleFactor", true);
+
+    expect(substring?.score).toBeGreaterThan(token?.score ?? 1);
+  });
+
+  it("returns null when nothing matches", () => {
+    expect(scoreSymbolName("loadConfig", "scale", true)).toBeNull();
+  });
+});
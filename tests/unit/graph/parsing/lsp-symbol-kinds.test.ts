@@ -0,0 +1,37 @@
+/**
+ * Unit tests for the LSP symbol kind mapping.
+ */
+
+import { describe, it, expect } from "bun:test";
+import { SYMBOL_KINDS } from "../../../../src/graph/parsing/symbol-kinds.js";
+import {
+  LSP_SYMBOL_KIND,
+  toLspSymbolKind,
+} from "../../../../src/graph/parsing/lsp-symbol-kinds.js";
+
+describe("toLspSymbolKind", () => {
+  it("maps every symbol kind", () => {
+    for (const kind of SYMBOL_KINDS) {
+      expect(Object.values(LSP_SYMBOL_KIND)).toContain(toLspSymbolKind(kind));
+    }
+  });
+
+  it("uses the LSP protocol numbers", () => {
+    expect(toLspSymbolKind("function")).toBe(12);
+    expect(toLspSymbolKind("method")).toBe(6);
+    expect(toLspSymbolKind("enum")).toBe(10);
+    expect(toLspSymbolKind("interface", "rust")).toBe(11);
+  });
+
+  it("maps classes to structs in languages without classes", () => {
+    expect(toLspSymbolKind("class", "rust")).toBe(LSP_SYMBOL_KIND.Struct);
+    expect(toLspSymbolKind("class", "go")).toBe(LSP_SYMBOL_KIND.Struct);
+    expect(toLspSymbolKind("class", "typescript")).toBe(LSP_SYMBOL_KIND.Class);
+    expect(toLspSymbolKind("class")).toBe(LSP_SYMBOL_KIND.Class);
+  });
+
+  it("maps Rust namespaces to modules", () => {
+    expect(toLspSymbolKind("namespace", "rust")).toBe(LSP_SYMBOL_KIND.Module);
+    expect(toLspSymbolKind("namespace", "ruby")).toBe(LSP_SYMBOL_KIND.Namespace);
+  });
+});
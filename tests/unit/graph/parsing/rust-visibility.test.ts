@@ -0,0 +1,49 @@
+/**
+ * Unit tests for Rust visibility rules.
+ */
+
+import { describe, it, expect } from "bun:test";
+import {
+  isRustVisibleFrom,
+  normalizeRustVisibility,
+  rustVisibilityScope,
+} from "../../../../src/graph/parsing/rust-visibility.js";
+
+describe("normalizeRustVisibility", () => {
+  it("removes whitespace inside the modifier", () => {
+    expect(normalizeRustVisibility("pub")).toBe("pub");
+    expect(normalizeRustVisibility("pub ( crate )")).toBe("pub(crate)");
+    expect(normalizeRustVisibility("pub(in crate :: geometry)")).toBe("pub(in crate::geometry)");
+  });
+});
+
+describe("rustVisibilityScope", () => {
+  const module = ["geometry", "shapes"];
+
+  it("resolves each visibility to the module it is restricted to", () => {
+    expect(rustVisibilityScope("pub", module)).toBeUndefined();
+    expect(rustVisibilityScope("pub(crate)", module)).toEqual([]);
+    expect(rustVisibilityScope("pub(super)", module)).toEqual(["geometry"]);
+    expect(rustVisibilityScope("pub(self)", module)).toEqual(module);
+    expect(rustVisibilityScope(undefined, module)).toEqual(module);
+  });
+
+  it("resolves pub(in path) relative to the crate or the defining module", () => {
+    expect(rustVisibilityScope("pub(in crate::geometry)", module)).toEqual(["geometry"]);
+    expect(rustVisibilityScope("pub(in super::super)", module)).toEqual([]);
+    expect(rustVisibilityScope("pub(in self)", module)).toEqual(module);
+  });
+});
+
+describe("isRustVisibleFrom", () => {
+  const module = ["geometry", "shapes"];
+
+  it("allows the restricting module and its descendants", () => {
+    expect(isRustVisibleFrom(undefined, module, ["geometry", "shapes", "tests"])).toBe(true);
+    expect(isRustVisibleFrom(undefined, module, ["geometry"])).toBe(false);
+    expect(isRustVisibleFrom("pub(super)", module, ["geometry", "render"])).toBe(true);
+    expect(isRustVisibleFrom("pub(super)", module, ["render"])).toBe(false);
+    expect(isRustVisibleFrom("pub(crate)", module, [])).toBe(true);
+    expect(isRustVisibleFrom("pub", module, ["render"])).toBe(true);
+  });
+});
@@ -0,0 +1,49 @@
+/**
+ * Unit tests for language-neutral symbol kind resolution.
+ */
+
+import { describe, it, expect } from "bun:test";
+import {
+  DEFAULT_SYMBOL_KINDS,
+  getSymbolKind,
+  resolveSymbolKind,
+} from "../../../../src/graph/parsing/symbol-kinds.js";
+
+describe("resolveSymbolKind", () => {
+  it("maps entity types to their default kinds", () => {
+    expect(resolveSymbolKind("function", "typescript")).toBe("function");
+    expect(resolveSymbolKind("property", "java")).toBe("field");
+    expect(resolveSymbolKind("type_alias", "rust")).toBe("type_alias");
+  });
+
+  it("promotes functions nested directly in classes or interfaces to methods", () => {
+    expect(resolveSymbolKind("function", "python", { parentKind: "class" })).toBe("method");
+    expect(resolveSymbolKind("function", "rust", { parentKind: "interface" })).toBe("method");
+  });
+
+  it("keeps functions nested in functions, methods, or namespaces as functions", () => {
+    expect(resolveSymbolKind("function", "python", { parentKind: "method" })).toBe("function");
+    expect(resolveSymbolKind("function", "python", { parentKind: "function" })).toBe("function");
+    expect(resolveSymbolKind("function", "cpp", { parentKind: "namespace" })).toBe("function");
+  });
+
+  it("applies per-language node type overrides", () => {
+    expect(resolveSymbolKind("class", "ruby", { nodeType: "module" })).toBe("namespace");
+    expect(resolveSymbolKind("class", "go", { nodeType: "interface_type" })).toBe("interface");
+    expect(resolveSymbolKind("class", "go", { nodeType: "struct_type" })).toBe("class");
+  });
+
+  it("ignores overrides registered for other languages", () => {
+    expect(resolveSymbolKind("class", "python", { nodeType: "module" })).toBe("class");
+  });
+});
+
+describe("getSymbolKind", () => {
+  it("prefers the kind recorded by the parser", () => {
+    expect(getSymbolKind({ type: "function", kind: "method" })).toBe("method");
+  });
+
+  it("falls back to the default kind for the entity type", () => {
+    expect(getSymbolKind({ type: "property" })).toBe(DEFAULT_SYMBOL_KINDS.property);
+  });
+});
@@ -0,0 +1,65 @@
+/**
+ * Unit tests for type hierarchy helpers.
+ */
+
+import { describe, it, expect } from "bun:test";
+import {
+  splitSupertypes,
+  derivedTraits,
+  parseGenericBounds,
+} from "../../../../src/graph/parsing/type-hierarchy.js";
+
+describe("splitSupertypes", () => {
+  it("strips clause keywords and splits on top-level commas", () => {
+    expect(splitSupertypes(["extends BaseHandler"])).toEqual(["BaseHandler"]);
+    expect(splitSupertypes(["implements Runnable, Comparable<Map<K, V>>", "Flyable"])).toEqual([
+      "Runnable",
+      "Comparable<Map<K, V>>",
+      "Flyable",
+    ]);
+    expect(splitSupertypes([])).toEqual([]);
+  });
+});
+
+describe("derivedTraits", () => {
+  it("lists derive arguments and ignores other attributes", () => {
+    expect(
+      derivedTraits([
+        "derive(Debug, Clone, serde::Serialize)",
+        'serde(rename_all = "camelCase")',
+        "derive(PartialEq)",
+      ])
+    ).toEqual(["Debug", "Clone", "serde::Serialize", "PartialEq"]);
+  });
+});
+
+describe("parseGenericBounds", () => {
+  it("merges inline bounds with where predicates", () => {
+    expect(parseGenericBounds(["K", "V: Clone"], "where\n    K: Eq + Hash,")).toEqual([
+      { name: "K", bounds: ["Eq", "Hash"] },
+      { name: "V", bounds: ["Clone"] },
+    ]);
+  });
+
+  it("keeps nested generics and closure signatures intact", () => {
+    expect(
+      parseGenericBounds(
+        ["'a", "T: Iterator<Item = u8> + 'a = Empty", "const N: usize", "F: Fn(A, B) -> C"],
+        ["Vec<T>: Debug"]
+      )
+    ).toEqual([
+      { name: "'a", bounds: [] },
+      { name: "T", bounds: ["Iterator<Item = u8>", "'a"] },
+      { name: "N", bounds: [] },
+      { name: "F", bounds: ["Fn(A, B) -> C"] },
+      { name: "Vec<T>", bounds: ["Debug"] },
+    ]);
+  });
+
+  it("reads extends-style bounds joined with &", () => {
+    expect(parseGenericBounds(["T extends Base & Comparable<T>", "U = string"])).toEqual([
+      { name: "T", bounds: ["Base", "Comparable<T>"] },
+      { name: "U", bounds: [] },
+    ]);
+  });
+});
@@ -0,0 +1,73 @@
+/**
+ * Unit tests for type-string matching used by type-based symbol search.
+ */
+
+import { describe, it, expect } from "bun:test";
+import {
+  matchType,
+  referencedTypeNames,
+} from "../../../../src/graph/parsing/type-matching.js";
+
+describe("matchType", () => {
+  it("matches whole annotations exactly, ignoring whitespace", () => {
+    expect(matchType("&str", "&str", false)).toBe("exact");
+    expect(matchType("Result< String , Error >", "Result<String, Error>", false)).toBe("exact");
+    expect(matchType("String", "&str", false)).toBeNull();
+  });
+
+  it("does not look through wrappers without contains", () => {
+    expect(matchType("Result<String, Error>", "Result", false)).toBeNull();
+    expect(matchType("&mut Point", "Point", false)).toBeNull();
+  });
+
+  it("looks through references and lifetimes with contains", () => {
+    expect(matchType("&mut Point", "Point", true)).toBe("contains");
+    expect(matchType("&'a mut Point", "Point", true)).toBe("contains");
+    expect(matchType("*const u8", "u8", true)).toBe("contains");
+    expect(matchType("Box<dyn Error>", "Error", true)).toBe("contains");
+  });
+
+  it("matches generic base names and arguments with contains", () => {
+    expect(matchType("Result<String, Error>", "Result", true)).toBe("contains");
+    expect(matchType("Result<Vec<Point>, Error>", "Point", true)).toBe("contains");
+    expect(matchType("Result<String, Error>", "Result<String>", true)).toBe("contains");
+    expect(matchType("Result<String, Error>", "Result<i32>", true)).toBeNull();
+  });
+
+  it("matches tuple, slice and array elements and path-qualified names", () => {
+    expect(matchType("(i32, Point)", "Point", true)).toBe("contains");
+    expect(matchType("&[u8; 4]", "u8", true)).toBe("contains");
+    expect(matchType("Vec<std::string::String>", "String", true)).toBe("contains");
+  });
+
+  it("does not match partial identifiers", () => {
+    expect(matchType("PointCloud", "Point", true)).toBeNull();
+    expect(matchType("Vec<Points>", "Point", true)).toBeNull();
+  });
+});
+
+describe("referencedTypeNames", () => {
+  it("lists named types through references, generics, tuples and slices", () => {
+    expect(referencedTypeNames("Result<Vec<Point>, Error>")).toEqual([
+      "Result",
+      "Vec",
+      "Point",
+      "Error",
+    ]);
+    expect(referencedTypeNames("&'a mut [Option<geometry::Point>]")).toEqual([
+      "Option",
+      "geometry::Point",
+    ]);
+    expect(referencedTypeNames("(u8, HashMap<Key, Key>)")).toEqual(["u8", "HashMap", "Key"]);
+  });
+
+  it("includes trait object bounds and associated types, and skips lifetimes", () => {
+    expect(referencedTypeNames("Box<dyn Shape + Send + 'static>")).toEqual([
+      "Box",
+      "Shape",
+      "Send",
+    ]);
+    expect(referencedTypeNames("impl Iterator<Item = Point>")).toEqual(["Iterator", "Point"]);
+    expect(referencedTypeNames("")).toEqual([]);
+  });
+});
@@ -131,6 +131,37 @@ describe("Health Route", () => {
     });
   });
 
+  test("should report active and disabled languages when configured", async () => {
+    deps.getLanguageStatus = () => ({
+      active: ["typescript", "python"],
+      disabled: { rust: "ABI version mismatch" },
+    });
+    const router = createHealthRouter(deps);
+    const handler = getRouteHandler(router, "/health");
+    const mockNext = mock(() => {}) as unknown as NextFunction;
+
+    await handler(mockRequest as Request, mockResponse as Response, mockNext);
+
+    expect(statusCode).toBe(200);
+    expect(jsonData).toMatchObject({
+      status: "healthy",
+      languages: {
+        active: ["typescript", "python"],
+        disabled: { rust: "ABI version mismatch" },
+      },
+    });
+  });
+
+  test("should omit languages when not configured", async () => {
+    const router = createHealthRouter(deps);
+    const handler = getRouteHandler(router, "/health");
+    const mockNext = mock(() => {}) as unknown as NextFunction;
+
+    await handler(mockRequest as Request, mockResponse as Response, mockNext);
+
+    expect((jsonData as { languages?: unknown }).languages).toBeUndefined();
+  });
+
   test("should include uptime in response", async () => {
     const router = createHealthRouter(deps);
     const handler = getRouteHandler(router, "/health");
@@ -0,0 +1,70 @@
+/**
+ * Unit tests for binary file detection.
+ */
+import { describe, test, expect, beforeAll, afterAll } from "bun:test";
+import { join } from "path";
+import { mkdir, rm, writeFile } from "fs/promises";
+import {
+  detectBinary,
+  readFileHead,
+  DEFAULT_BINARY_DETECTION_BYTES,
+} from "../../../src/ingestion/binary-detector.js";
+
+describe("detectBinary", () => {
+  test("should return null for text content", () => {
+    const bytes = new TextEncoder().encode("fn main() {\n    println!(\"hi\");\n}\n");
+    expect(detectBinary(bytes)).toBeNull();
+  });
+
+  test("should flag a NUL byte and report its offset", () => {
+    const detection = detectBinary(new Uint8Array([0x7f, 0x45, 0x4c, 0x46, 0x00, 0x01]));
+
+    expect(detection).not.toBeNull();
+    expect(detection!.offset).toBe(4);
+    expect(detection!.detail).toContain("offset 4");
+  });
+
+  test("should accept decoded text samples", () => {
+    expect(detectBinary("abc\u0000def")?.offset).toBe(3);
+    expect(detectBinary("plain text")).toBeNull();
+  });
+
+  test("should ignore NUL bytes beyond the sample size", () => {
+    const bytes = new Uint8Array(100).fill(0x61);
+    bytes[50] = 0;
+
+    expect(detectBinary(bytes, 50)).toBeNull();
+    expect(detectBinary(bytes, 51)).not.toBeNull();
+  });
+
+  test("should be disabled by a sample size of 0", () => {
+    expect(detectBinary(new Uint8Array([0, 0, 0]), 0)).toBeNull();
+  });
+
+  test("should default to an 8 KiB sample", () => {
+    expect(DEFAULT_BINARY_DETECTION_BYTES).toBe(8192);
+  });
+});
+
+describe("readFileHead", () => {
+  const dir = join(__dirname, "../../../test-temp", `binary-head-${Date.now()}`);
+
+  beforeAll(async () => {
+    await mkdir(dir, { recursive: true });
+    await writeFile(join(dir, "data.txt"), "0123456789");
+  });
+
+  afterAll(async () => {
+    await rm(dir, { recursive: true, force: true });
+  });
+
+  test("should read only the requested number of leading bytes", async () => {
+    const head = await readFileHead(join(dir, "data.txt"), 4);
+    expect(new TextDecoder().decode(head)).toBe("0123");
+  });
+
+  test("should return the whole file when it is shorter than the limit", async () => {
+    const head = await readFileHead(join(dir, "data.txt"), 100);
+    expect(head.length).toBe(10);
+  });
+});
@@ -0,0 +1,106 @@
+/**
+ * Unit tests for embedding input truncation.
+ */
+import { describe, test, expect, beforeEach, afterEach } from "bun:test";
+import {
+  prepareEmbeddingInput,
+  resolveEmbeddingInputTokens,
+  truncateToTokens,
+  EMBEDDING_MAX_INPUT_TOKENS_ENV,
+} from "../../../src/ingestion/embedding-input.js";
+
+describe("truncateToTokens", () => {
+  test("should return text that fits unchanged", () => {
+    expect(truncateToTokens("fn main() {}", 10)).toBe("fn main() {}");
+  });
+
+  test("should cut a single long line at the budget", () => {
+    expect(truncateToTokens("x".repeat(100), 10)).toBe("x".repeat(40));
+  });
+
+  test("should back off to the last line break", () => {
+    const text = `${"a".repeat(30)}\n${"b".repeat(30)}`;
+
+    expect(truncateToTokens(text, 10)).toBe("a".repeat(30));
+  });
+
+  test("should not split surrogate pairs", () => {
+    const text = "😀".repeat(10);
+
+    expect(truncateToTokens(text, 1)).toBe("😀".repeat(4));
+  });
+});
+
+describe("prepareEmbeddingInput", () => {
+  test("should return text that fits unchanged", () => {
+    const input = prepareEmbeddingInput("fn add(a: i32, b: i32) -> i32 {\n    a + b\n}", 100);
+
+    expect(input.text).toBe("fn add(a: i32, b: i32) -> i32 {\n    a + b\n}");
+    expect(input.truncated).toBe(false);
+  });
+
+  test("should cut text over the budget at a line boundary", () => {
+    const text = `${"a".repeat(30)}\n${"b".repeat(400)}`;
+
+    const input = prepareEmbeddingInput(text, 10);
+
+    expect(input.truncated).toBe(true);
+    expect(input.originalTokens).toBe(108);
+    expect(input.text).toBe("a".repeat(30));
+  });
+
+  test("should be deterministic", () => {
+    const text = "line\n".repeat(500);
+
+    expect(prepareEmbeddingInput(text, 50)).toEqual(prepareEmbeddingInput(text, 50));
+  });
+});
+
+describe("resolveEmbeddingInputTokens", () => {
+  let savedOverride: string | undefined;
+  let savedChunkMax: string | undefined;
+
+  beforeEach(() => {
+    savedOverride = process.env[EMBEDDING_MAX_INPUT_TOKENS_ENV];
+    savedChunkMax = process.env["CHUNK_MAX_TOKENS"];
+    delete process.env[EMBEDDING_MAX_INPUT_TOKENS_ENV];
+    delete process.env["CHUNK_MAX_TOKENS"];
+  });
+
+  afterEach(() => {
+    for (const [key, value] of [
+      [EMBEDDING_MAX_INPUT_TOKENS_ENV, savedOverride],
+      ["CHUNK_MAX_TOKENS", savedChunkMax],
+    ] as const) {
+      if (value === undefined) {
+        delete process.env[key];
+      } else {
+        process.env[key] = value;
+      }
+    }
+  });
+
+  test("should default to 6000 tokens", () => {
+    expect(resolveEmbeddingInputTokens()).toBe(6000);
+  });
+
+  test("should derive the budget from CHUNK_MAX_TOKENS", () => {
+    process.env["CHUNK_MAX_TOKENS"] = "3500";
+
+    expect(resolveEmbeddingInputTokens()).toBe(7000);
+  });
+
+  test("should use the override when it is a positive integer", () => {
+    process.env[EMBEDDING_MAX_INPUT_TOKENS_ENV] = "512";
+    expect(resolveEmbeddingInputTokens()).toBe(512);
+
+    process.env[EMBEDDING_MAX_INPUT_TOKENS_ENV] = "-1";
+    expect(resolveEmbeddingInputTokens()).toBe(6000);
+  });
+
+  test("should not exceed the provider limit", () => {
+    process.env[EMBEDDING_MAX_INPUT_TOKENS_ENV] = "16000";
+
+    expect(resolveEmbeddingInputTokens(512)).toBe(512);
+  });
+});
@@ -0,0 +1,72 @@
+/**
+ * Unit tests for decoding files that are not valid UTF-8.
+ */
+import { describe, test, expect } from "bun:test";
+import {
+  decodeSourceText,
+  DEFAULT_MAX_INVALID_BYTE_RATIO,
+} from "../../../src/ingestion/encoding-detector.js";
+import { UndecodableFileError } from "../../../src/ingestion/errors.js";
+
+const latin1 = (text: string): Uint8Array => new Uint8Array(Buffer.from(text, "latin1"));
+
+describe("decodeSourceText", () => {
+  test("should decode valid UTF-8 without a warning", () => {
+    const decoded = decodeSourceText(new TextEncoder().encode("let café = \"☕\";\n"));
+
+    expect(decoded.text).toBe('let café = "☕";\n');
+    expect(decoded.encodingWarning).toBeNull();
+  });
+
+  test("should strip a UTF-8 byte order mark", () => {
+    const bytes = new Uint8Array([0xef, 0xbb, 0xbf, ...new TextEncoder().encode("fn main() {}")]);
+
+    expect(decodeSourceText(bytes).text).toBe("fn main() {}");
+  });
+
+  test("should decode Latin-1 content lossily and report the lines", () => {
+    const decoded = decodeSourceText(
+      latin1("// Gr\xfc\xdfe\nfn main() {}\n// na\xefve\nfn other() {}\n")
+    );
+
+    expect(decoded.text).toBe("// Gr��e\nfn main() {}\n// na�ve\nfn other() {}\n");
+    expect(decoded.encodingWarning).toEqual({
+      invalidBytes: 3,
+      firstLine: 1,
+      detail: "3 invalid UTF-8 bytes on 2 lines, first on line 1",
+    });
+  });
+
+  test("should keep line numbers intact after a lossy decode", () => {
+    const decoded = decodeSourceText(latin1("a\nb\nc \xe9\nd\n"));
+
+    expect(decoded.text.split("\n")).toHaveLength(5);
+    expect(decoded.encodingWarning?.firstLine).toBe(3);
+    expect(decoded.encodingWarning?.detail).toBe("1 invalid UTF-8 byte on 1 line, first on line 3");
+  });
+
+  test("should treat overlong encodings and surrogates as invalid", () => {
+    const text = "x".repeat(100);
+    const overlong = new Uint8Array([...new TextEncoder().encode(text), 0xc0, 0xaf]);
+    const surrogate = new Uint8Array([...new TextEncoder().encode(text), 0xed, 0xa0, 0x80]);
+
+    expect(decodeSourceText(overlong).encodingWarning?.invalidBytes).toBe(2);
+    expect(decodeSourceText(surrogate).encodingWarning?.invalidBytes).toBe(3);
+  });
+
+  test("should reject content that is mostly invalid", () => {
+    const bytes = new Uint8Array(64).fill(0xff);
+
+    expect(() => decodeSourceText(bytes)).toThrow(UndecodableFileError);
+    expect(() => decodeSourceText(bytes)).toThrow(/64 invalid UTF-8 bytes/);
+  });
+
+  test("should apply the configured ratio", () => {
+    // 1 invalid byte in 5
+    const bytes = latin1("abc\xe9\n");
+
+    expect(() => decodeSourceText(bytes)).toThrow(UndecodableFileError);
+    expect(decodeSourceText(bytes, 0.5).encodingWarning?.invalidBytes).toBe(1);
+    expect(DEFAULT_MAX_INVALID_BYTE_RATIO).toBe(0.1);
+  });
+});
@@ -5,10 +5,10 @@
 import { describe, test, expect, beforeAll, afterAll, beforeEach, afterEach } from "bun:test";
 import { FileScanner } from "../../../src/ingestion/file-scanner.js";
 import { ValidationError } from "../../../src/ingestion/errors.js";
-import type { ScanOptions } from "../../../src/ingestion/types.js";
+import type { ScanOptions, SkippedFile } from "../../../src/ingestion/types.js";
 import { initializeLogger, resetLogger } from "../../../src/logging/index.js";
 import { resolve, join } from "path";
-import { writeFile, unlink } from "fs/promises";
+import { writeFile, unlink, mkdir, rm } from "fs/promises";
 
 describe("FileScanner", () => {
   let scanner: FileScanner;
@@ -320,6 +320,158 @@ describe("FileScanner", () => {
     });
   });
 
+  describe("minified file detection", () => {
+    let minifiedRepo: string;
+
+    beforeEach(async () => {
+      minifiedRepo = join(__dirname, "../../../test-temp", `minified-${Date.now()}`);
+      await mkdir(minifiedRepo, { recursive: true });
+      await writeFile(join(minifiedRepo, "bundle.js"), "var a=1;".repeat(5000));
+      await writeFile(join(minifiedRepo, "app.js"), "const a = 1;\nconst b = 2;\n");
+    });
+
+    afterEach(async () => {
+      await rm(minifiedRepo, { recursive: true, force: true });
+    });
+
+    test("should skip single-line bundles by default and record the reason", async () => {
+      const skipped: SkippedFile[] = [];
+      const files = await scanner.scanFiles(minifiedRepo, {
+        onSkip: (file) => skipped.push(file),
+      });
+
+      expect(files.map((f) => f.relativePath)).toEqual(["app.js"]);
+      expect(skipped).toHaveLength(1);
+      expect(skipped[0]!.relativePath).toBe("bundle.js");
+      expect(skipped[0]!.reason).toBe("minified");
+      expect(skipped[0]!.detail).toContain("longest line");
+    });
+
+    test("should include minified files when includeMinified is set", async () => {
+      const files = await scanner.scanFiles(minifiedRepo, { includeMinified: true });
+
+      expect(files.map((f) => f.relativePath)).toEqual(["app.js", "bundle.js"]);
+    });
+
+    test("should honor custom minified thresholds", async () => {
+      const lenientScanner = new FileScanner({
+        minifiedThresholds: { maxLineBytes: 100_000, maxAverageLineLength: 100_000 },
+      });
+
+      const files = await lenientScanner.scanFiles(minifiedRepo);
+
+      expect(files).toHaveLength(2);
+    });
+
+    test("should report oversized files through onSkip", async () => {
+      const smallScanner = new FileScanner({ maxFileSizeBytes: 100 });
+      const skipped: SkippedFile[] = [];
+
+      await smallScanner.scanFiles(minifiedRepo, { onSkip: (file) => skipped.push(file) });
+
+      expect(skipped).toEqual([
+        expect.objectContaining({ relativePath: "bundle.js", reason: "oversized" }),
+      ]);
+    });
+  });
+
+  describe("empty and binary file detection", () => {
+    let contentRepo: string;
+
+    beforeEach(async () => {
+      contentRepo = join(__dirname, "../../../test-temp", `binary-${Date.now()}`);
+      await mkdir(contentRepo, { recursive: true });
+      await writeFile(join(contentRepo, "empty.rs"), "");
+      await writeFile(join(contentRepo, "garbage.rs"), Buffer.from([0x7f, 0x45, 0x00, 0x01, 0x02]));
+      await writeFile(
+        join(contentRepo, "lib.rs"),
+        "pub fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n"
+      );
+    });
+
+    afterEach(async () => {
+      await rm(contentRepo, { recursive: true, force: true });
+    });
+
+    test("should skip binary files with a recorded reason and empty files silently", async () => {
+      const skipped: SkippedFile[] = [];
+      const files = await scanner.scanFiles(contentRepo, {
+        onSkip: (file) => skipped.push(file),
+      });
+
+      expect(files.map((f) => f.relativePath)).toEqual(["lib.rs"]);
+      expect(skipped).toHaveLength(1);
+      expect(skipped[0]!.relativePath).toBe("garbage.rs");
+      expect(skipped[0]!.reason).toBe("binary");
+      expect(skipped[0]!.detail).toContain("offset 2");
+    });
+
+    test("should only inspect binaryDetectionBytes leading bytes", async () => {
+      const shallowScanner = new FileScanner({ binaryDetectionBytes: 2 });
+
+      const files = await shallowScanner.scanFiles(contentRepo);
+
+      expect(files.map((f) => f.relativePath)).toEqual(["garbage.rs", "lib.rs"]);
+    });
+
+    test("should disable binary detection when binaryDetectionBytes is 0", async () => {
+      const skipped: SkippedFile[] = [];
+      const files = await new FileScanner({ binaryDetectionBytes: 0 }).scanFiles(contentRepo, {
+        onSkip: (file) => skipped.push(file),
+      });
+
+      expect(files.map((f) => f.relativePath)).toEqual(["garbage.rs", "lib.rs"]);
+      expect(skipped).toHaveLength(0);
+    });
+  });
+
+  describe(".gitignore handling", () => {
+    let ignoreRepo: string;
+
+    beforeEach(async () => {
+      ignoreRepo = join(__dirname, "../../../test-temp", `gitignore-${Date.now()}`);
+      await mkdir(join(ignoreRepo, "generated"), { recursive: true });
+      await mkdir(join(ignoreRepo, "src"), { recursive: true });
+      await writeFile(join(ignoreRepo, ".gitignore"), "generated/\n");
+      await writeFile(join(ignoreRepo, "generated", "api.ts"), "export const api = 1;\n");
+      await writeFile(join(ignoreRepo, "src", ".gitignore"), "*.local.ts\n");
+      await writeFile(join(ignoreRepo, "src", "config.local.ts"), "export const key = 1;\n");
+      await writeFile(join(ignoreRepo, "src", "app.ts"), "export const app = 1;\n");
+    });
+
+    afterEach(async () => {
+      await rm(ignoreRepo, { recursive: true, force: true });
+    });
+
+    test("should skip files ignored by root and nested .gitignore files", async () => {
+      const files = await scanner.scanFiles(ignoreRepo, { respectNestedGitignore: true });
+
+      expect(files.map((f) => f.relativePath)).toEqual(["src/app.ts"]);
+    });
+
+    test("should index ignored files when respectGitignore is false", async () => {
+      const files = await scanner.scanFiles(ignoreRepo, {
+        respectGitignore: false,
+        respectNestedGitignore: true,
+      });
+
+      expect(files.map((f) => f.relativePath)).toEqual([
+        "generated/api.ts",
+        "src/app.ts",
+        "src/config.local.ts",
+      ]);
+    });
+
+    test("should still apply exclude globs when respectGitignore is false", async () => {
+      const files = await scanner.scanFiles(ignoreRepo, {
+        respectGitignore: false,
+        excludePatterns: ["generated/**"],
+      });
+
+      expect(files.map((f) => f.relativePath)).toEqual(["src/app.ts", "src/config.local.ts"]);
+    });
+  });
+
   describe("custom configuration", () => {
     test("should respect custom maxFileSizeBytes", async () => {
       const repoPath = resolve(__dirname, "../../fixtures/sample-repo");
@@ -0,0 +1,221 @@
+/**
+ * Unit tests for FileSource implementations
+ *
+ * Archives are built in memory and written to a temp directory per test.
+ */
+import { describe, test, expect, beforeEach, afterEach } from "bun:test";
+import { gzipSync } from "zlib";
+import { mkdtemp, mkdir, rm, writeFile } from "fs/promises";
+import { tmpdir } from "os";
+import { join } from "path";
+import JSZip from "jszip";
+import {
+  ArchiveFileSource,
+  DirectoryFileSource,
+  detectArchiveFormat,
+  openFileSource,
+  readFileContent,
+  readSourceText,
+} from "../../../src/ingestion/file-source.js";
+import { FileScanner } from "../../../src/ingestion/file-scanner.js";
+import { FileScanError, ValidationError } from "../../../src/ingestion/errors.js";
+import { initializeLogger, resetLogger } from "../../../src/logging/index.js";
+
+/**
+ * Build an uncompressed ustar archive from path -> content pairs
+ */
+function buildTar(files: Record<string, string>): Uint8Array {
+  const blocks: Uint8Array[] = [];
+  const encoder = new TextEncoder();
+  const writeField = (header: Uint8Array, offset: number, value: string): void => {
+    header.set(encoder.encode(value), offset);
+  };
+
+  for (const [name, content] of Object.entries(files)) {
+    const data = encoder.encode(content);
+    const header = new Uint8Array(512);
+    writeField(header, 0, name);
+    writeField(header, 100, "0000644\0");
+    writeField(header, 124, `${data.length.toString(8).padStart(11, "0")}\0`);
+    writeField(header, 136, `${(1700000000).toString(8).padStart(11, "0")}\0`);
+    writeField(header, 156, "0");
+    writeField(header, 257, "ustar\0");
+    writeField(header, 263, "00");
+    blocks.push(header);
+
+    const padded = new Uint8Array(Math.ceil(data.length / 512) * 512);
+    padded.set(data);
+    blocks.push(padded);
+  }
+  blocks.push(new Uint8Array(1024)); // End-of-archive marker
+
+  const tar = new Uint8Array(blocks.reduce((sum, block) => sum + block.length, 0));
+  let offset = 0;
+  for (const block of blocks) {
+    tar.set(block, offset);
+    offset += block.length;
+  }
+  return tar;
+}
+
+const CRATE_FILES = {
+  "demo-0.1.0/Cargo.toml": '[package]\nname = "demo"\n',
+  "demo-0.1.0/src/lib.rs": "pub fn greet() -> &'static str {\n    \"hello\"\n}\n",
+  "demo-0.1.0/src/util/mod.rs": "pub mod strings;\n",
+};
+
+describe("FileSource", () => {
+  let tempDir: string;
+
+  beforeEach(async () => {
+    initializeLogger({ level: "silent", format: "json" });
+    tempDir = await mkdtemp(join(tmpdir(), "file-source-"));
+  });
+
+  afterEach(async () => {
+    resetLogger();
+    await rm(tempDir, { recursive: true, force: true });
+  });
+
+  describe("detectArchiveFormat()", () => {
+    test("should recognize supported archive extensions", () => {
+      expect(detectArchiveFormat("serde-1.0.0.crate")).toBe("tar.gz");
+      expect(detectArchiveFormat("release.TAR.GZ")).toBe("tar.gz");
+      expect(detectArchiveFormat("release.tgz")).toBe("tar.gz");
+      expect(detectArchiveFormat("release.tar")).toBe("tar");
+      expect(detectArchiveFormat("release.zip")).toBe("zip");
+      expect(detectArchiveFormat("src/lib.rs")).toBeNull();
+    });
+  });
+
+  describe("ArchiveFileSource", () => {
+    test("should list .crate entries relative to the crate root", async () => {
+      const cratePath = join(tempDir, "demo-0.1.0.crate");
+      await writeFile(cratePath, gzipSync(buildTar(CRATE_FILES)));
+
+      const source = await ArchiveFileSource.open(cratePath);
+      const entries = await source.listEntries();
+
+      expect(source.format).toBe("tar.gz");
+      expect(entries.map((e) => e.relativePath)).toEqual([
+        "Cargo.toml",
+        "src/lib.rs",
+        "src/util/mod.rs",
+      ]);
+      expect(entries[0]!.modifiedAt.getTime()).toBe(1700000000 * 1000);
+      expect(await source.readText("src/lib.rs")).toContain("pub fn greet");
+      expect(source.displayPath("src/lib.rs")).toBe(`${cratePath}!/src/lib.rs`);
+    });
+
+    test("should read zip entries and keep paths when there is no shared root", async () => {
+      const zip = new JSZip();
+      zip.file("README.md", "# Demo\n");
+      zip.file("src/index.ts", "export const x = 1;\n");
+      zip.folder("empty");
+      const zipPath = join(tempDir, "demo.zip");
+      await writeFile(zipPath, await zip.generateAsync({ type: "uint8array" }));
+
+      const source = await ArchiveFileSource.open(zipPath);
+      const entries = await source.listEntries();
+
+      expect(entries.map((e) => e.relativePath)).toEqual(["README.md", "src/index.ts"]);
+      expect(entries[1]!.sizeBytes).toBe(20);
+      expect(await source.readText("src/index.ts")).toBe("export const x = 1;\n");
+    });
+
+    test("should ignore entries that escape the archive root", async () => {
+      const tarPath = join(tempDir, "hostile.tar");
+      await writeFile(
+        tarPath,
+        buildTar({ "../outside.ts": "x", "/etc/passwd.ts": "x", "ok.ts": "x" })
+      );
+
+      const source = await ArchiveFileSource.open(tarPath);
+
+      expect((await source.listEntries()).map((e) => e.relativePath)).toEqual(["ok.ts"]);
+    });
+
+    test("should reject unsupported formats and missing entries", async () => {
+      await expect(ArchiveFileSource.open(join(tempDir, "notes.txt"))).rejects.toBeInstanceOf(
+        ValidationError
+      );
+
+      const tarPath = join(tempDir, "demo.tar");
+      await writeFile(tarPath, buildTar({ "a.ts": "x" }));
+      const source = await ArchiveFileSource.open(tarPath);
+      await expect(source.readText("missing.ts")).rejects.toBeInstanceOf(FileScanError);
+    });
+
+    test("should wrap malformed archives in FileScanError", async () => {
+      const badPath = join(tempDir, "broken.tgz");
+      await writeFile(badPath, "not gzip data");
+
+      await expect(ArchiveFileSource.open(badPath)).rejects.toBeInstanceOf(FileScanError);
+    });
+  });
+
+  describe("DirectoryFileSource", () => {
+    test("should list files with POSIX paths, skipping .git", async () => {
+      await mkdir(join(tempDir, "src"), { recursive: true });
+      await mkdir(join(tempDir, ".git"), { recursive: true });
+      await writeFile(join(tempDir, "src", "app.ts"), "export {};\n");
+      await writeFile(join(tempDir, ".git", "HEAD"), "ref: refs/heads/main\n");
+
+      const source = await openFileSource(tempDir);
+      const entries = await source.listEntries();
+
+      expect(source).toBeInstanceOf(DirectoryFileSource);
+      expect(entries.map((e) => e.relativePath)).toEqual(["src/app.ts"]);
+      expect(await source.readText("src/app.ts")).toBe("export {};\n");
+    });
+  });
+
+  describe("FileScanner.scanSource()", () => {
+    test("should filter archive entries and read them through the source", async () => {
+      const cratePath = join(tempDir, "demo-0.1.0.crate");
+      await writeFile(
+        cratePath,
+        gzipSync(
+          buildTar({
+            ...CRATE_FILES,
+            "demo-0.1.0/.gitignore": "generated/\n",
+            "demo-0.1.0/generated/out.rs": "// generated\n",
+            "demo-0.1.0/target/debug/build.rs": "// build output\n",
+            "demo-0.1.0/.cargo_vcs_info.json": "{}\n",
+          })
+        )
+      );
+      const source = await ArchiveFileSource.open(cratePath);
+
+      const files = await new FileScanner().scanSource(source, {
+        includeExtensions: [".rs", ".toml"],
+        excludePatterns: ["target/**"],
+      });
+
+      expect(files.map((f) => f.relativePath)).toEqual([
+        "Cargo.toml",
+        "src/lib.rs",
+        "src/util/mod.rs",
+      ]);
+      expect(files[1]!.extension).toBe(".rs");
+      expect(files[1]!.source).toBe(source);
+      expect(await readFileContent(files[1]!)).toContain("pub fn greet");
+    });
+  });
+
+  describe("readSourceText", () => {
+    test("should decode invalid UTF-8 lossily with a warning", async () => {
+      await writeFile(
+        join(tempDir, "latin1.rs"),
+        Buffer.from("// caf\xe9\nfn main() {}\n", "latin1")
+      );
+      const source = new DirectoryFileSource(tempDir);
+      const [file] = await new FileScanner().scanSource(source, { includeExtensions: [".rs"] });
+
+      const decoded = await readSourceText(file!);
+
+      expect(decoded.text).toBe("// caf\uFFFD\nfn main() {}\n");
+      expect(decoded.encodingWarning?.firstLine).toBe(1);
+    });
+  });
+});
@@ -0,0 +1,67 @@
+/**
+ * Unit tests for minified/generated file detection.
+ */
+import { describe, test, expect } from "bun:test";
+import {
+  detectMinified,
+  mayExceedMinifiedThresholds,
+  DEFAULT_MINIFIED_THRESHOLDS,
+} from "../../../src/ingestion/minified-detector.js";
+
+describe("detectMinified", () => {
+  test("should return null for empty content", () => {
+    expect(detectMinified("")).toBeNull();
+  });
+
+  test("should return null for ordinary formatted source", () => {
+    const content = Array.from({ length: 200 }, (_, i) => `const value${i} = ${i};`).join("\n");
+    expect(detectMinified(content)).toBeNull();
+  });
+
+  test("should flag a single line exceeding maxLineBytes", () => {
+    const content = "var a=1;".repeat(5000); // 40,000 characters on one line
+    const detection = detectMinified(content);
+
+    expect(detection).not.toBeNull();
+    expect(detection!.lineCount).toBe(1);
+    expect(detection!.longestLine).toBe(40000);
+    expect(detection!.detail).toContain("longest line");
+  });
+
+  test("should flag a high average line length", () => {
+    const line = "x".repeat(1500);
+    const content = [line, line, line].join("\n");
+    const detection = detectMinified(content, { maxAverageLineLength: 1000, maxLineBytes: 0 });
+
+    expect(detection).not.toBeNull();
+    expect(detection!.lineCount).toBe(3);
+    expect(detection!.detail).toContain("average line length");
+  });
+
+  test("should not count a trailing newline as an extra line", () => {
+    const detection = detectMinified("x".repeat(30000) + "\n");
+    expect(detection!.lineCount).toBe(1);
+  });
+
+  test("should respect disabled thresholds", () => {
+    const content = "x".repeat(50000);
+    expect(detectMinified(content, { maxAverageLineLength: 0, maxLineBytes: 0 })).toBeNull();
+  });
+});
+
+describe("mayExceedMinifiedThresholds", () => {
+  test("should skip content no larger than the smallest enabled threshold", () => {
+    expect(mayExceedMinifiedThresholds(DEFAULT_MINIFIED_THRESHOLDS.maxAverageLineLength)).toBe(
+      false
+    );
+    expect(mayExceedMinifiedThresholds(DEFAULT_MINIFIED_THRESHOLDS.maxAverageLineLength + 1)).toBe(
+      true
+    );
+  });
+
+  test("should return false when all thresholds are disabled", () => {
+    expect(
+      mayExceedMinifiedThresholds(1_000_000, { maxAverageLineLength: 0, maxLineBytes: 0 })
+    ).toBe(false);
+  });
+});
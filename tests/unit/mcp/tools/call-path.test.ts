@@ -0,0 +1,140 @@
+/**
+ * Unit tests for call_path MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  callPathToolDefinition,
+  createCallPathHandler,
+} from "../../../../src/mcp/tools/call-path.js";
+import type {
+  CallPathQuery,
+  CallPathResult,
+  SymbolLocation,
+} from "../../../../src/services/graph-analysis-types.js";
+import { EntityNotFoundError } from "../../../../src/services/graph-service-errors.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+function fn(name: string, file: string, line: number): SymbolLocation {
+  return {
+    id: `Function:my-project:${file}:${name}:${line}`,
+    name,
+    entity_type: "function",
+    file_path: file,
+    start_line: line,
+    end_line: line + 10,
+  };
+}
+
+const SAMPLE_RESULT: CallPathResult = {
+  from: "handleLogin",
+  to: "findUser",
+  repository: "my-project",
+  paths: [
+    {
+      length: 2,
+      functions: [
+        fn("handleLogin", "src/routes/api.ts", 5),
+        fn("authenticate", "src/auth.ts", 12),
+        fn("findUser", "src/db/users.ts", 3),
+      ],
+    },
+  ],
+  metadata: { path_count: 1, truncated: false, max_depth: 10, query_time_ms: 4 },
+};
+
+describe("call_path MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require from, to and repository", () => {
+    expect(callPathToolDefinition.name).toBe("call_path");
+    expect(callPathToolDefinition.inputSchema.required).toEqual(["from", "to", "repository"]);
+  });
+
+  it("should forward the references with defaults and return the paths", async () => {
+    let received: CallPathQuery | undefined;
+    const handler = createCallPathHandler(
+      createMockGraphAnalysisService({
+        findCallPaths: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ from: "handleLogin", to: "findUser", repository: "my-project" });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({
+      from: "handleLogin",
+      to: "findUser",
+      repository: "my-project",
+      max_depth: 10,
+      limit: 5,
+    });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.found).toBe(true);
+    expect(parsed.paths[0].functions.map((f: { name: string }) => f.name)).toEqual([
+      "handleLogin",
+      "authenticate",
+      "findUser",
+    ]);
+    expect(parsed.message).toBeUndefined();
+  });
+
+  it("should report when no path exists", async () => {
+    const handler = createCallPathHandler(
+      createMockGraphAnalysisService({
+        findCallPaths: () =>
+          Promise.resolve({
+            ...SAMPLE_RESULT,
+            paths: [],
+            metadata: { ...SAMPLE_RESULT.metadata, path_count: 0, max_depth: 3 },
+          }),
+      })
+    );
+
+    const result = await handler({
+      from: "findUser",
+      to: "handleLogin",
+      repository: "my-project",
+      max_depth: 3,
+    });
+
+    expect(result.isError).toBe(false);
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.found).toBe(false);
+    expect(parsed.message).toContain("within 3 calls");
+  });
+
+  it("should reject a depth above the cap", async () => {
+    const handler = createCallPathHandler(createMockGraphAnalysisService());
+
+    const result = await handler({ from: "a", to: "b", repository: "my-project", max_depth: 50 });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain("Max depth cannot exceed 20");
+  });
+
+  it("should return an error result when a function is unknown", async () => {
+    const handler = createCallPathHandler(
+      createMockGraphAnalysisService({
+        findCallPaths: () =>
+          Promise.reject(new EntityNotFoundError("function", "missing", "my-project")),
+      })
+    );
+
+    const result = await handler({ from: "missing", to: "findUser", repository: "my-project" });
+
+    expect(result.isError).toBe(true);
+  });
+});
@@ -0,0 +1,116 @@
+/**
+ * Unit tests for coupling_report MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  couplingReportToolDefinition,
+  createCouplingReportHandler,
+} from "../../../../src/mcp/tools/coupling-report.js";
+import type {
+  CouplingReportQuery,
+  CouplingReportResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: CouplingReportResult = {
+  repository: "my-project",
+  granularity: "file",
+  modules: [
+    {
+      module: "src/api.rs",
+      afferent: 1,
+      efferent: 3,
+      instability: 0.75,
+      incoming_edges: 2,
+      outgoing_edges: 9,
+    },
+  ],
+  pairs: [{ from: "src/api.rs", to: "src/db.rs", calls: 5, imports: 1 }],
+  metadata: {
+    module_count: 1,
+    pair_count: 1,
+    cross_module_calls: 5,
+    cross_module_imports: 1,
+    truncated: false,
+    query_time_ms: 4,
+  },
+};
+
+describe("coupling_report MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require a repository", () => {
+    expect(couplingReportToolDefinition.name).toBe("coupling_report");
+    expect(couplingReportToolDefinition.inputSchema.required).toEqual(["repository"]);
+  });
+
+  it("should forward the query with defaults and return the report", async () => {
+    let received: CouplingReportQuery | undefined;
+    const handler = createCouplingReportHandler(
+      createMockGraphAnalysisService({
+        getCouplingReport: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ repository: "my-project" });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({
+      repository: "my-project",
+      granularity: "file",
+      path: undefined,
+      sort_by: "instability",
+      order: "desc",
+      limit: 50,
+    });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.modules).toEqual(SAMPLE_RESULT.modules);
+    expect(parsed.pairs).toEqual(SAMPLE_RESULT.pairs);
+    expect(parsed.metadata.cross_module_calls).toBe(5);
+  });
+
+  it("should pass the sort order through", async () => {
+    let received: CouplingReportQuery | undefined;
+    const handler = createCouplingReportHandler(
+      createMockGraphAnalysisService({
+        getCouplingReport: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    await handler({
+      repository: "my-project",
+      granularity: "directory",
+      sort_by: "afferent",
+      order: "asc",
+    });
+
+    expect(received).toMatchObject({ granularity: "directory", sort_by: "afferent", order: "asc" });
+  });
+
+  it("should reject an unknown sort metric", async () => {
+    const handler = createCouplingReportHandler(createMockGraphAnalysisService());
+
+    const result = await handler({ repository: "my-project", sort_by: "fan_in" });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain(
+      "sort_by must be one of: instability, afferent, efferent, module"
+    );
+  });
+});
@@ -0,0 +1,122 @@
+/**
+ * Unit tests for deprecation_report MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  deprecationReportToolDefinition,
+  createDeprecationReportHandler,
+} from "../../../../src/mcp/tools/deprecation-report.js";
+import type {
+  DeprecationReportQuery,
+  DeprecationReportResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: DeprecationReportResult = {
+  repository: "my-project",
+  symbols: [
+    {
+      id: "Function:my-project:src/lib.rs:old_parse:10",
+      name: "old_parse",
+      entity_type: "function",
+      file_path: "src/lib.rs",
+      start_line: 10,
+      end_line: 14,
+      attribute: 'deprecated(since = "1.2", note = "use parse")',
+      message: "use parse",
+      since: "1.2",
+      call_sites: [
+        {
+          caller: {
+            id: "Function:my-project:src/main.rs:main:1",
+            name: "main",
+            entity_type: "function",
+            file_path: "src/main.rs",
+            start_line: 1,
+            end_line: 9,
+          },
+          line: 4,
+          call_count: 2,
+        },
+      ],
+      references_tracked: true,
+      safe_to_remove: false,
+    },
+    {
+      id: "Function:my-project:src/lib.rs:legacy:20",
+      name: "legacy",
+      entity_type: "function",
+      file_path: "src/lib.rs",
+      start_line: 20,
+      end_line: 22,
+      attribute: "deprecated",
+      message: null,
+      since: null,
+      call_sites: [],
+      references_tracked: true,
+      safe_to_remove: true,
+    },
+  ],
+  metadata: {
+    total_deprecated: 2,
+    in_use_count: 1,
+    safe_to_remove_count: 1,
+    truncated: false,
+    query_time_ms: 3,
+  },
+};
+
+describe("deprecation_report MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require a repository", () => {
+    expect(deprecationReportToolDefinition.name).toBe("deprecation_report");
+    expect(deprecationReportToolDefinition.inputSchema.required).toEqual(["repository"]);
+  });
+
+  it("should forward the query with defaults and return the report", async () => {
+    let received: DeprecationReportQuery | undefined;
+    const handler = createDeprecationReportHandler(
+      createMockGraphAnalysisService({
+        getDeprecationReport: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ repository: "my-project" });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({ repository: "my-project", path: undefined, limit: 100 });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.symbols[0]).toMatchObject({
+      name: "old_parse",
+      message: "use parse",
+      since: "1.2",
+      safe_to_remove: false,
+      call_sites: [{ caller: "main", file_path: "src/main.rs", line: 4, call_count: 2 }],
+    });
+    expect(parsed.symbols[1]).toMatchObject({ name: "legacy", safe_to_remove: true });
+    expect(parsed.metadata.safe_to_remove_count).toBe(1);
+  });
+
+  it("should reject a limit above the cap", async () => {
+    const handler = createDeprecationReportHandler(createMockGraphAnalysisService());
+
+    const result = await handler({ repository: "my-project", limit: 1000 });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain("Limit cannot exceed 500");
+  });
+});
@@ -0,0 +1,140 @@
+/**
+ * Unit tests for diff_symbols_between_refs MCP tool handler
+ *
+ * Uses a mock SymbolDiffService to isolate the MCP layer from git and the parser.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  diffSymbolsBetweenRefsToolDefinition,
+  createDiffSymbolsBetweenRefsHandler,
+} from "../../../../src/mcp/tools/diff-symbols-between-refs.js";
+import { validateDiffSymbolsBetweenRefsArgs } from "../../../../src/mcp/validation.js";
+import type {
+  SymbolDiffQuery,
+  SymbolDiffResult,
+  SymbolDiffService,
+} from "../../../../src/services/symbol-diff-types.js";
+import { RepositoryNotFoundError } from "../../../../src/services/errors.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+
+const SAMPLE_RESULT: SymbolDiffResult = {
+  repository: "my-project",
+  base_ref: "main",
+  head_ref: "HEAD",
+  base_sha: "1111111111111111111111111111111111111111",
+  head_sha: "2222222222222222222222222222222222222222",
+  files: [
+    {
+      file_path: "src/auth.ts",
+      status: "modified",
+      symbols: [
+        {
+          name: "logout",
+          kind: "function",
+          change: "modified",
+          base: { start_line: 5, end_line: 5 },
+          head: { start_line: 6, end_line: 8 },
+        },
+      ],
+    },
+  ],
+  summary: { added: 0, removed: 0, modified: 1 },
+  metadata: {
+    files_changed: 1,
+    files_analyzed: 1,
+    files_skipped: 0,
+    base_from_index: 1,
+    query_time_ms: 12,
+  },
+};
+
+describe("diff_symbols_between_refs MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  describe("Tool Definition", () => {
+    it("should have correct tool name", () => {
+      expect(diffSymbolsBetweenRefsToolDefinition.name).toBe("diff_symbols_between_refs");
+    });
+
+    it("should require repository and base_ref", () => {
+      expect(diffSymbolsBetweenRefsToolDefinition.inputSchema.required).toEqual([
+        "repository",
+        "base_ref",
+      ]);
+    });
+  });
+
+  describe("Argument Validation", () => {
+    it("should default head_ref to HEAD", () => {
+      const validated = validateDiffSymbolsBetweenRefsArgs({ repository: "r", base_ref: "main" });
+
+      expect(validated.head_ref).toBe("HEAD");
+    });
+
+    it("should reject refs starting with a dash", () => {
+      expect(() =>
+        validateDiffSymbolsBetweenRefsArgs({ repository: "r", base_ref: "--all" })
+      ).toThrow(/single ref without whitespace/);
+    });
+
+    it("should reject ref ranges", () => {
+      expect(() =>
+        validateDiffSymbolsBetweenRefsArgs({ repository: "r", base_ref: "main...HEAD" })
+      ).toThrow(/not a range/);
+    });
+  });
+
+  describe("Handler Execution", () => {
+    it("should forward refs and return the symbol diff", async () => {
+      let received: SymbolDiffQuery | undefined;
+      const service: SymbolDiffService = {
+        diffSymbols: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+        scopeToDiff: () => Promise.reject(new Error("not used")),
+        createSnapshot: () => Promise.reject(new Error("not used")),
+        whatChanged: () => Promise.reject(new Error("not used")),
+        recordSnapshot: () => Promise.reject(new Error("not used")),
+        getSnapshotHistory: () => Promise.reject(new Error("not used")),
+      };
+      const handler = createDiffSymbolsBetweenRefsHandler(service);
+
+      const result = await handler({ repository: "my-project", base_ref: "main", path: "src" });
+
+      expect(result.isError).toBe(false);
+      expect(received).toEqual({
+        repository: "my-project",
+        base_ref: "main",
+        head_ref: "HEAD",
+        path: "src",
+      });
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.files[0].symbols[0].change).toBe("modified");
+      expect(parsed.summary).toEqual({ added: 0, removed: 0, modified: 1 });
+    });
+
+    it("should map service errors to an error result", async () => {
+      const handler = createDiffSymbolsBetweenRefsHandler({
+        diffSymbols: () => Promise.reject(new RepositoryNotFoundError("missing")),
+        scopeToDiff: () => Promise.reject(new Error("not used")),
+        createSnapshot: () => Promise.reject(new Error("not used")),
+        whatChanged: () => Promise.reject(new Error("not used")),
+        recordSnapshot: () => Promise.reject(new Error("not used")),
+        getSnapshotHistory: () => Promise.reject(new Error("not used")),
+      });
+
+      const result = await handler({ repository: "missing", base_ref: "main" });
+
+      expect(result.isError).toBe(true);
+      expect((result.content[0] as { text: string }).text).toContain("'missing' not found");
+    });
+  });
+});
@@ -0,0 +1,105 @@
+/**
+ * Unit tests for document_symbols MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  documentSymbolsToolDefinition,
+  createDocumentSymbolsHandler,
+} from "../../../../src/mcp/tools/document-symbols.js";
+import { validateDocumentSymbolsArgs } from "../../../../src/mcp/validation.js";
+import type { DocumentSymbolsResult } from "../../../../src/services/graph-analysis-types.js";
+import { EntityNotFoundError } from "../../../../src/services/graph-service-errors.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: DocumentSymbolsResult = {
+  repository: "my-project",
+  file_path: "src/shapes.rs",
+  symbols: [
+    {
+      name: "Shape",
+      kind: 10,
+      range: { start: { line: 0, character: 0 }, end: { line: 3, character: 1 } },
+      selectionRange: { start: { line: 0, character: 9 }, end: { line: 0, character: 14 } },
+      children: [
+        {
+          name: "Circle",
+          kind: 22,
+          range: { start: { line: 1, character: 4 }, end: { line: 1, character: 15 } },
+          selectionRange: { start: { line: 1, character: 4 }, end: { line: 1, character: 10 } },
+        },
+      ],
+    },
+  ],
+  metadata: { symbol_count: 2, query_time_ms: 3 },
+};
+
+describe("document_symbols MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  describe("Tool Definition", () => {
+    it("should have correct tool name", () => {
+      expect(documentSymbolsToolDefinition.name).toBe("document_symbols");
+    });
+
+    it("should require repository and path", () => {
+      expect(documentSymbolsToolDefinition.inputSchema.required).toEqual(["repository", "path"]);
+    });
+  });
+
+  describe("Argument Validation", () => {
+    it("should reject empty path", () => {
+      expect(() => validateDocumentSymbolsArgs({ repository: "r", path: " " })).toThrow(
+        /Path cannot be empty/
+      );
+    });
+
+    it("should reject unknown arguments", () => {
+      expect(() =>
+        validateDocumentSymbolsArgs({ repository: "r", path: "src/lib.rs", expand: true })
+      ).toThrow(/Invalid document_symbols arguments/);
+    });
+  });
+
+  describe("Handler Execution", () => {
+    it("should return symbols with their LSP field names", async () => {
+      const handler = createDocumentSymbolsHandler(
+        createMockGraphAnalysisService({
+          getDocumentSymbols: () => Promise.resolve(SAMPLE_RESULT),
+        })
+      );
+
+      const result = await handler({ repository: "my-project", path: "src/shapes.rs" });
+
+      expect(result.isError).toBe(false);
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.file_path).toBe("src/shapes.rs");
+      expect(parsed.symbols).toEqual(SAMPLE_RESULT.symbols);
+      expect(parsed.symbols[0].children[0].selectionRange.end.character).toBe(10);
+      expect(parsed.metadata.symbol_count).toBe(2);
+    });
+
+    it("should return an error result when the file is not indexed", async () => {
+      const handler = createDocumentSymbolsHandler(
+        createMockGraphAnalysisService({
+          getDocumentSymbols: () =>
+            Promise.reject(new EntityNotFoundError("file", "src/missing.rs", "my-project")),
+        })
+      );
+
+      const result = await handler({ repository: "my-project", path: "src/missing.rs" });
+
+      expect(result.isError).toBe(true);
+      expect((result.content[0] as { text: string }).text).toContain("Error:");
+    });
+  });
+});
@@ -0,0 +1,86 @@
+/**
+ * Unit tests for external_dependencies MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  externalDependenciesToolDefinition,
+  createExternalDependenciesHandler,
+} from "../../../../src/mcp/tools/external-dependencies.js";
+import type {
+  ExternalDependenciesQuery,
+  ExternalDependenciesResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: ExternalDependenciesResult = {
+  repository: "my-project",
+  dependencies: [
+    {
+      name: "serde",
+      is_std: false,
+      reference_count: 3,
+      file_count: 2,
+      paths: ["serde::Deserialize", "serde::Serialize"],
+    },
+  ],
+  metadata: {
+    std_count: 1,
+    third_party_count: 1,
+    query_time_ms: 5,
+  },
+};
+
+describe("external_dependencies MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require only a repository", () => {
+    expect(externalDependenciesToolDefinition.name).toBe("external_dependencies");
+    expect(externalDependenciesToolDefinition.inputSchema.required).toEqual(["repository"]);
+  });
+
+  it("should forward the query and return dependencies", async () => {
+    let received: ExternalDependenciesQuery | undefined;
+    const handler = createExternalDependenciesHandler(
+      createMockGraphAnalysisService({
+        findExternalDependencies: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ repository: "my-project", include_std: false });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({ repository: "my-project", include_std: false });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.dependencies[0].name).toBe("serde");
+    expect(parsed.metadata.std_count).toBe(1);
+  });
+
+  it("should include the standard library by default", async () => {
+    let received: ExternalDependenciesQuery | undefined;
+    const handler = createExternalDependenciesHandler(
+      createMockGraphAnalysisService({
+        findExternalDependencies: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    await handler({ repository: "my-project" });
+
+    expect(received?.include_std).toBe(true);
+  });
+});
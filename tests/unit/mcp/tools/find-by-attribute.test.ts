@@ -0,0 +1,116 @@
+/**
+ * Unit tests for find_by_attribute MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  findByAttributeToolDefinition,
+  createFindByAttributeHandler,
+} from "../../../../src/mcp/tools/find-by-attribute.js";
+import { validateFindByAttributeArgs } from "../../../../src/mcp/validation.js";
+import type {
+  AttributeSearchQuery,
+  AttributeSearchResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: AttributeSearchResult = {
+  name: "deprecated",
+  repository: "my-crate",
+  matches: [
+    {
+      id: "Function:my-crate:src/parse.rs:old_parse:12",
+      name: "old_parse",
+      entity_type: "function",
+      file_path: "src/parse.rs",
+      start_line: 12,
+      end_line: 20,
+      attributes: ['deprecated(since = "1.2")'],
+      caller_count: 3,
+    },
+  ],
+  metadata: {
+    total_matches: 1,
+    truncated: false,
+    query_time_ms: 2,
+  },
+};
+
+describe("find_by_attribute MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  describe("Tool Definition", () => {
+    it("should have correct tool name", () => {
+      expect(findByAttributeToolDefinition.name).toBe("find_by_attribute");
+    });
+
+    it("should require repository and name", () => {
+      expect(findByAttributeToolDefinition.inputSchema.required).toEqual(["repository", "name"]);
+    });
+  });
+
+  describe("Argument Validation", () => {
+    it("should default limit to 100", () => {
+      expect(validateFindByAttributeArgs({ repository: "r", name: "deprecated" }).limit).toBe(100);
+    });
+
+    it("should reject a missing attribute name", () => {
+      expect(() => validateFindByAttributeArgs({ repository: "r" })).toThrow(
+        /Invalid find_by_attribute arguments/
+      );
+    });
+  });
+
+  describe("Handler Execution", () => {
+    it("should forward filters and return matches with caller counts", async () => {
+      let received: AttributeSearchQuery | undefined;
+      const handler = createFindByAttributeHandler(
+        createMockGraphAnalysisService({
+          findByAttribute: (query) => {
+            received = query;
+            return Promise.resolve(SAMPLE_RESULT);
+          },
+        })
+      );
+
+      const result = await handler({ repository: "my-crate", name: "deprecated", path: "src" });
+
+      expect(result.isError).toBe(false);
+      expect(received).toEqual({
+        repository: "my-crate",
+        name: "deprecated",
+        arguments: undefined,
+        path: "src",
+        limit: 100,
+      });
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.matches[0]).toEqual({
+        name: "old_parse",
+        entity_type: "function",
+        file_path: "src/parse.rs",
+        start_line: 12,
+        end_line: 20,
+        attributes: ['deprecated(since = "1.2")'],
+        caller_count: 3,
+      });
+    });
+
+    it("should return an error result for invalid arguments", async () => {
+      const handler = createFindByAttributeHandler(createMockGraphAnalysisService());
+
+      const result = await handler({});
+
+      expect(result.isError).toBe(true);
+      expect((result.content[0] as { text: string }).text).toContain("Error:");
+    });
+  });
+});
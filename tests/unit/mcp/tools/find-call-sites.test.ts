@@ -0,0 +1,102 @@
+/**
+ * Unit tests for find_call_sites MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  findCallSitesToolDefinition,
+  createFindCallSitesHandler,
+} from "../../../../src/mcp/tools/find-call-sites.js";
+import type {
+  CallSitesQuery,
+  CallSitesResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: CallSitesResult = {
+  symbol: "function_with_params",
+  repository: "my-project",
+  call_sites: [
+    {
+      caller: {
+        id: "Function:my-project:src/main.rs:main:1",
+        name: "main",
+        entity_type: "function",
+        file_path: "src/main.rs",
+        start_line: 1,
+        end_line: 10,
+      },
+      callee_id: "Function:my-project:src/lib.rs:function_with_params:40",
+      line: 3,
+      arguments: ['"World"', "3"],
+    },
+  ],
+  metadata: {
+    callee_count: 1,
+    total_count: 1,
+    arguments_captured: true,
+    truncated: false,
+    query_time_ms: 2,
+  },
+};
+
+describe("find_call_sites MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require a symbol and repository", () => {
+    expect(findCallSitesToolDefinition.name).toBe("find_call_sites");
+    expect(findCallSitesToolDefinition.inputSchema.required).toEqual(["symbol", "repository"]);
+  });
+
+  it("should forward the filter with defaults and return call sites", async () => {
+    let received: CallSitesQuery | undefined;
+    const handler = createFindCallSitesHandler(
+      createMockGraphAnalysisService({
+        findCallSites: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({
+      symbol: "function_with_params",
+      repository: "my-project",
+      argument_contains: "World",
+    });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({
+      symbol: "function_with_params",
+      repository: "my-project",
+      argument_contains: "World",
+      limit: 100,
+    });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.call_sites[0].arguments).toEqual(['"World"', "3"]);
+  });
+
+  it("should reject an empty argument filter", async () => {
+    const handler = createFindCallSitesHandler(createMockGraphAnalysisService({}));
+
+    const result = await handler({
+      symbol: "function_with_params",
+      repository: "my-project",
+      argument_contains: "",
+    });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain(
+      "Argument filter cannot be empty"
+    );
+  });
+});
@@ -0,0 +1,113 @@
+/**
+ * Unit tests for find_consts_by_value_range MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  findConstsByValueRangeToolDefinition,
+  createFindConstsByValueRangeHandler,
+} from "../../../../src/mcp/tools/find-consts-by-value-range.js";
+import type {
+  ConstValueRangeQuery,
+  ConstValueRangeResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: ConstValueRangeResult = {
+  repository: "my-project",
+  min: 1024,
+  max: null,
+  constants: [
+    {
+      id: "Variable:my-project:src/config.rs:MAX_SIZE:3",
+      name: "MAX_SIZE",
+      entity_type: "variable",
+      file_path: "src/config.rs",
+      start_line: 3,
+      end_line: 3,
+      value_kind: "integer",
+      value: 1024,
+      value_text: "1_024usize",
+    },
+  ],
+  metadata: { total_count: 1, truncated: false, query_time_ms: 2 },
+};
+
+describe("find_consts_by_value_range MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require a repository", () => {
+    expect(findConstsByValueRangeToolDefinition.name).toBe("find_consts_by_value_range");
+    expect(findConstsByValueRangeToolDefinition.inputSchema.required).toEqual(["repository"]);
+  });
+
+  it("should forward the query with defaults and return the constants", async () => {
+    let received: ConstValueRangeQuery | undefined;
+    const handler = createFindConstsByValueRangeHandler(
+      createMockGraphAnalysisService({
+        findConstsByValueRange: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ repository: "my-project", min: 1024 });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({
+      repository: "my-project",
+      min: 1024,
+      max: undefined,
+      kind: undefined,
+      path: undefined,
+      limit: 100,
+    });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.min).toBe(1024);
+    expect(parsed.max).toBeNull();
+    expect(parsed.constants).toEqual([
+      {
+        name: "MAX_SIZE",
+        entity_type: "variable",
+        file_path: "src/config.rs",
+        start_line: 3,
+        end_line: 3,
+        value_kind: "integer",
+        value: 1024,
+        value_text: "1_024usize",
+      },
+    ]);
+  });
+
+  it("should return only the requested fields of each constant", async () => {
+    const handler = createFindConstsByValueRangeHandler(
+      createMockGraphAnalysisService({
+        findConstsByValueRange: () => Promise.resolve(SAMPLE_RESULT),
+      })
+    );
+
+    const result = await handler({ repository: "my-project", fields: ["name", "value"] });
+
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.constants).toEqual([{ name: "MAX_SIZE", value: 1024 }]);
+  });
+
+  it("should reject a min above the max", async () => {
+    const handler = createFindConstsByValueRangeHandler(createMockGraphAnalysisService());
+
+    const result = await handler({ repository: "my-project", min: 10, max: 1 });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain("min must not exceed max");
+  });
+});
@@ -0,0 +1,119 @@
+/**
+ * Unit tests for find_cycles MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+/* eslint-disable @typescript-eslint/no-non-null-assertion */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  findCyclesToolDefinition,
+  createFindCyclesHandler,
+} from "../../../../src/mcp/tools/find-cycles.js";
+import { validateFindCyclesArgs } from "../../../../src/mcp/validation.js";
+import type {
+  ModuleCyclesQuery,
+  ModuleCyclesResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: ModuleCyclesResult = {
+  repository: "my-project",
+  cycles: [
+    {
+      kind: "mutual",
+      runtime: false,
+      files: ["src/models.ts", "src/types.ts"],
+      edges: [
+        { from: "src/models.ts", to: "src/types.ts", module: "./types", line: 1, type_only: true },
+        { from: "src/types.ts", to: "src/models.ts", module: "./models", line: 2, type_only: true },
+      ],
+    },
+  ],
+  self_imports: [
+    { from: "src/leaf.ts", to: "src/leaf.ts", module: "./leaf", line: 3, type_only: false },
+  ],
+  metadata: {
+    files_analyzed: 3,
+    edges_analyzed: 3,
+    cycle_count: 1,
+    truncated: false,
+    query_time_ms: 5,
+  },
+};
+
+describe("find_cycles MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  describe("Tool Definition", () => {
+    it("should have correct tool name", () => {
+      expect(findCyclesToolDefinition.name).toBe("find_cycles");
+    });
+
+    it("should require repository", () => {
+      expect(findCyclesToolDefinition.inputSchema.required).toEqual(["repository"]);
+    });
+  });
+
+  describe("Argument Validation", () => {
+    it("should apply defaults", () => {
+      const validated = validateFindCyclesArgs({ repository: "my-project" });
+
+      expect(validated.include_type_only).toBe(true);
+      expect(validated.limit).toBe(20);
+    });
+
+    it("should reject out-of-range limits", () => {
+      expect(() => validateFindCyclesArgs({ repository: "r", limit: 0 })).toThrow(
+        /Limit must be at least 1/
+      );
+      expect(() => validateFindCyclesArgs({ repository: "r", limit: 101 })).toThrow(
+        /Limit cannot exceed 100/
+      );
+    });
+  });
+
+  describe("Handler Execution", () => {
+    it("should pass validated arguments and format cycles", async () => {
+      let receivedQuery: ModuleCyclesQuery | null = null;
+      const handler = createFindCyclesHandler(
+        createMockGraphAnalysisService({
+          findModuleCycles: (query) => {
+            receivedQuery = query;
+            return Promise.resolve(SAMPLE_RESULT);
+          },
+        })
+      );
+
+      const result = await handler({ repository: "my-project", include_type_only: false });
+
+      expect(result.isError).toBe(false);
+      expect(receivedQuery!).toEqual({
+        repository: "my-project",
+        include_type_only: false,
+        limit: 20,
+      });
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.cycles[0].kind).toBe("mutual");
+      expect(parsed.cycles[0].runtime).toBe(false);
+      expect(parsed.cycles[0].edges[0].type_only).toBe(true);
+      expect(parsed.self_imports[0].file).toBe("src/leaf.ts");
+    });
+
+    it("should return an error result for invalid arguments", async () => {
+      const handler = createFindCyclesHandler(createMockGraphAnalysisService());
+
+      const result = await handler({});
+
+      expect(result.isError).toBe(true);
+      expect((result.content[0] as { text: string }).text).toContain("Error:");
+    });
+  });
+});
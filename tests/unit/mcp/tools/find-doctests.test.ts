@@ -0,0 +1,93 @@
+/**
+ * Unit tests for find_doctests MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  findDoctestsToolDefinition,
+  createFindDoctestsHandler,
+} from "../../../../src/mcp/tools/find-doctests.js";
+import type {
+  DoctestSearchQuery,
+  DoctestSearchResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: DoctestSearchResult = {
+  repository: "my-project",
+  symbol: "add",
+  examples: [
+    {
+      file_path: "src/lib.rs",
+      start_line: 4,
+      end_line: 5,
+      info: "",
+      mode: "run",
+      code: "let total = add(1, 2);\nassert_eq!(total, 3);",
+      documents_symbol: true,
+      calls_symbol: true,
+      documented: {
+        id: "Function:my-project:src/lib.rs:add:8",
+        name: "add",
+        entity_type: "function",
+        file_path: "src/lib.rs",
+        start_line: 8,
+        end_line: 10,
+      },
+    },
+  ],
+  metadata: { total_count: 1, truncated: false, query_time_ms: 3 },
+};
+
+describe("find_doctests MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require a repository and symbol", () => {
+    expect(findDoctestsToolDefinition.name).toBe("find_doctests");
+    expect(findDoctestsToolDefinition.inputSchema.required).toEqual(["repository", "symbol"]);
+  });
+
+  it("should forward the query with defaults and return examples", async () => {
+    let received: DoctestSearchQuery | undefined;
+    const handler = createFindDoctestsHandler(
+      createMockGraphAnalysisService({
+        findDoctests: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ repository: "my-project", symbol: "add" });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({ repository: "my-project", symbol: "add", limit: 50 });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.examples[0]).toMatchObject({
+      mode: "run",
+      documents_symbol: true,
+      documented: { name: "add", entity_type: "function", start_line: 8 },
+    });
+    expect(parsed.examples[0].code).toContain("add(1, 2)");
+  });
+
+  it("should reject an unknown mode", async () => {
+    const handler = createFindDoctestsHandler(createMockGraphAnalysisService());
+
+    const result = await handler({ repository: "my-project", symbol: "add", mode: "text" });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain(
+      "Invalid find_doctests arguments"
+    );
+  });
+});
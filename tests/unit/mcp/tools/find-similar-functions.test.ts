@@ -0,0 +1,107 @@
+/**
+ * Unit tests for find_similar_functions MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  findSimilarFunctionsToolDefinition,
+  createFindSimilarFunctionsHandler,
+} from "../../../../src/mcp/tools/find-similar-functions.js";
+import type {
+  SimilarFunctionsQuery,
+  SimilarFunctionsResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const cloneOf = (name: string, filePath: string, line: number) => ({
+  id: `Function:my-project:${filePath}:${name}:${line}`,
+  name,
+  entity_type: "function",
+  file_path: filePath,
+  start_line: line,
+  end_line: line + 12,
+  fingerprint: "aaaaaaaaaaaaaaaa",
+  size: 64,
+});
+
+const SAMPLE_RESULT: SimilarFunctionsResult = {
+  repository: "my-project",
+  min_similarity: 0.8,
+  groups: [
+    {
+      kind: "exact",
+      similarity: 1,
+      functions: [
+        cloneOf("order_total", "src/billing.rs", 10),
+        cloneOf("shipment_weight", "src/shipping.rs", 8),
+      ],
+    },
+  ],
+  metadata: {
+    functions_compared: 12,
+    exact_groups: 1,
+    near_groups: 0,
+    truncated: false,
+    query_time_ms: 5,
+  },
+};
+
+describe("find_similar_functions MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require a repository", () => {
+    expect(findSimilarFunctionsToolDefinition.name).toBe("find_similar_functions");
+    expect(findSimilarFunctionsToolDefinition.inputSchema.required).toEqual(["repository"]);
+  });
+
+  it("should forward the query with defaults and return the groups", async () => {
+    let received: SimilarFunctionsQuery | undefined;
+    const handler = createFindSimilarFunctionsHandler(
+      createMockGraphAnalysisService({
+        findSimilarFunctions: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ repository: "my-project" });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({
+      repository: "my-project",
+      path: undefined,
+      min_similarity: 0.8,
+      min_size: 30,
+      limit: 20,
+    });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.groups[0].kind).toBe("exact");
+    expect(parsed.groups[0].functions.map((f: { name: string }) => f.name)).toEqual([
+      "order_total",
+      "shipment_weight",
+    ]);
+    expect(parsed.groups[0].functions[0].fingerprint).toBe("aaaaaaaaaaaaaaaa");
+    expect(parsed.metadata.functions_compared).toBe(12);
+  });
+
+  it("should reject a similarity threshold below 0.5", async () => {
+    const handler = createFindSimilarFunctionsHandler(createMockGraphAnalysisService());
+
+    const result = await handler({ repository: "my-project", min_similarity: 0.2 });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain(
+      "min_similarity must be between 0.5 and 1.0"
+    );
+  });
+});
@@ -0,0 +1,98 @@
+/**
+ * Unit tests for functions_by_arity MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  functionsByArityToolDefinition,
+  createFunctionsByArityHandler,
+} from "../../../../src/mcp/tools/functions-by-arity.js";
+import type { ArityQuery, ArityResult } from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: ArityResult = {
+  repository: "my-project",
+  min_params: 6,
+  max_params: null,
+  functions: [
+    {
+      id: "Function:my-project:src/render.rs:blend:12",
+      name: "blend",
+      entity_type: "function",
+      file_path: "src/render.rs",
+      start_line: 12,
+      end_line: 30,
+      params: [
+        { name: "a", type: "u8" },
+        { name: "b", type: "u8" },
+        { name: "c", type: "u8" },
+        { name: "d", type: "u8" },
+        { name: "e", type: "u8" },
+        { name: "f", type: "u8" },
+      ],
+      param_count: 6,
+      has_receiver: false,
+      is_exported: true,
+    },
+  ],
+  metadata: {
+    total_count: 1,
+    truncated: false,
+    query_time_ms: 3,
+  },
+};
+
+describe("functions_by_arity MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require only a repository", () => {
+    expect(functionsByArityToolDefinition.name).toBe("functions_by_arity");
+    expect(functionsByArityToolDefinition.inputSchema.required).toEqual(["repository"]);
+  });
+
+  it("should forward the range and filters and return functions", async () => {
+    let received: ArityQuery | undefined;
+    const handler = createFunctionsByArityHandler(
+      createMockGraphAnalysisService({
+        findFunctionsByArity: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ repository: "my-project", min_params: 6, exported_only: true });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({
+      repository: "my-project",
+      min_params: 6,
+      count_receiver: false,
+      exported_only: true,
+      limit: 50,
+    });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.functions[0].name).toBe("blend");
+    expect(parsed.functions[0].param_count).toBe(6);
+  });
+
+  it("should reject a minimum above the maximum", async () => {
+    const handler = createFunctionsByArityHandler(createMockGraphAnalysisService({}));
+
+    const result = await handler({ repository: "my-project", min_params: 4, max_params: 2 });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain(
+      "min_params must not exceed max_params"
+    );
+  });
+});
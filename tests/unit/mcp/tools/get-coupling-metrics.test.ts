@@ -0,0 +1,90 @@
+/**
+ * Unit tests for get_coupling_metrics MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  getCouplingMetricsToolDefinition,
+  createGetCouplingMetricsHandler,
+} from "../../../../src/mcp/tools/get-coupling-metrics.js";
+import type {
+  CouplingMetricsQuery,
+  CouplingMetricsResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { EntityNotFoundError } from "../../../../src/services/graph-service-errors.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: CouplingMetricsResult = {
+  symbol: "parse",
+  repository: "my-project",
+  matches: [
+    {
+      id: "Function:my-project:src/parse.ts:parse:3",
+      name: "parse",
+      entity_type: "function",
+      file_path: "src/parse.ts",
+      start_line: 3,
+      end_line: 40,
+      fan_in: 4,
+      fan_out: 2,
+      coupling: 6,
+      recursive: false,
+      in_cycle: true,
+    },
+  ],
+  metadata: { match_count: 1, query_time_ms: 5 },
+};
+
+describe("get_coupling_metrics MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require symbol and repository", () => {
+    expect(getCouplingMetricsToolDefinition.name).toBe("get_coupling_metrics");
+    expect(getCouplingMetricsToolDefinition.inputSchema.required).toEqual([
+      "symbol",
+      "repository",
+    ]);
+  });
+
+  it("should forward the reference and return metrics", async () => {
+    let received: CouplingMetricsQuery | undefined;
+    const handler = createGetCouplingMetricsHandler(
+      createMockGraphAnalysisService({
+        getCouplingMetrics: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ symbol: "parse", repository: "my-project" });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({ symbol: "parse", repository: "my-project", file_path: undefined });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.matches[0].fan_in).toBe(4);
+    expect(parsed.matches[0].in_cycle).toBe(true);
+  });
+
+  it("should return an error result when the function is unknown", async () => {
+    const handler = createGetCouplingMetricsHandler(
+      createMockGraphAnalysisService({
+        getCouplingMetrics: () =>
+          Promise.reject(new EntityNotFoundError("function", "missing", "my-project")),
+      })
+    );
+
+    const result = await handler({ symbol: "missing", repository: "my-project" });
+
+    expect(result.isError).toBe(true);
+  });
+});
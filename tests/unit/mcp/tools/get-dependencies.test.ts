@@ -179,6 +179,17 @@ describe("get_dependencies MCP Tool", () => {
       expect(validated.relationship_types).toBeUndefined();
     });
 
+    it("should deduplicate without paths by default", () => {
+      const validated = validateGetDependenciesArgs({
+        entity_type: "file",
+        entity_path: "src/test.ts",
+        repository: "test-repo",
+      });
+
+      expect(validated.dedup).toBe(true);
+      expect(validated.include_paths).toBe(false);
+    });
+
     it("should trim whitespace from string fields", () => {
       const args = {
         entity_type: "file",
@@ -488,6 +499,47 @@ describe("get_dependencies MCP Tool", () => {
       // Should use response.metadata.depth_searched (2), not args.depth (5)
       expect(parsed.metadata.max_depth_reached).toBe(2);
     });
+
+    it("should include the paths to each dependency when present", async () => {
+      mockGraphService.setDependencyResult({
+        entity: {
+          type: "file",
+          path: "src/test.ts",
+          repository: "test-repo",
+          display_name: "test.ts",
+        },
+        dependencies: [
+          {
+            type: "file",
+            path: "src/utils/crypto.ts",
+            relationship_type: RelationshipType.IMPORTS,
+            depth: 1,
+            paths: [[], ["src/utils/hash.ts"]],
+          },
+        ],
+        metadata: {
+          total_count: 1,
+          query_time_ms: 5,
+          from_cache: false,
+          depth_searched: 2,
+        },
+      });
+
+      const handler = createGetDependenciesHandler(mockGraphService);
+
+      const result = await handler({
+        entity_type: "file",
+        entity_path: "src/test.ts",
+        repository: "test-repo",
+        depth: 2,
+        include_paths: true,
+      });
+
+      const textContent = result.content[0] as { type: "text"; text: string };
+      const parsed = JSON.parse(textContent.text);
+
+      expect(parsed.dependencies[0].paths).toEqual([[], ["src/utils/hash.ts"]]);
+    });
   });
 
   describe("Relationship Type Mapping", () => {
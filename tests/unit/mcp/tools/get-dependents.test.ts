@@ -198,6 +198,16 @@ describe("get_dependents MCP Tool", () => {
       expect(validated.include_cross_repo).toBe(false);
     });
 
+    it("should deduplicate without paths by default", () => {
+      const validated = validateGetDependentsArgs({
+        entity_type: "function",
+        entity_path: "myFunction",
+      });
+
+      expect(validated.dedup).toBe(true);
+      expect(validated.include_paths).toBe(false);
+    });
+
     it("should allow repository to be omitted", () => {
       const args = {
         entity_type: "file",
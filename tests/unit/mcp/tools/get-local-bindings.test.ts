@@ -0,0 +1,94 @@
+/**
+ * Unit tests for get_local_bindings MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  getLocalBindingsToolDefinition,
+  createGetLocalBindingsHandler,
+} from "../../../../src/mcp/tools/get-local-bindings.js";
+import type {
+  LocalBindingsQuery,
+  LocalBindingsResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { EntityNotFoundError } from "../../../../src/services/graph-service-errors.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: LocalBindingsResult = {
+  symbol: "function_with_calls",
+  repository: "my-project",
+  functions: [
+    {
+      id: "Function:my-project:src/lib.rs:function_with_calls:180",
+      name: "function_with_calls",
+      entity_type: "function",
+      file_path: "src/lib.rs",
+      start_line: 180,
+      end_line: 207,
+      locals: [
+        { name: "result", type: null, line: 182 },
+        { name: "point", type: "Point", line: 190 },
+      ],
+    },
+  ],
+  metadata: {
+    function_count: 1,
+    local_count: 2,
+    query_time_ms: 2,
+  },
+};
+
+describe("get_local_bindings MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require a symbol and a repository", () => {
+    expect(getLocalBindingsToolDefinition.name).toBe("get_local_bindings");
+    expect(getLocalBindingsToolDefinition.inputSchema.required).toEqual(["symbol", "repository"]);
+  });
+
+  it("should forward the query and return locals per function", async () => {
+    let received: LocalBindingsQuery | undefined;
+    const handler = createGetLocalBindingsHandler(
+      createMockGraphAnalysisService({
+        getLocalBindings: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ symbol: " function_with_calls ", repository: "my-project" });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({ symbol: "function_with_calls", repository: "my-project" });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.functions[0].locals).toEqual([
+      { name: "result", type: null, line: 182 },
+      { name: "point", type: "Point", line: 190 },
+    ]);
+    expect(parsed.metadata.local_count).toBe(2);
+  });
+
+  it("should report a missing function as an error", async () => {
+    const handler = createGetLocalBindingsHandler(
+      createMockGraphAnalysisService({
+        getLocalBindings: () =>
+          Promise.reject(new EntityNotFoundError("function", "missing", "my-project")),
+      })
+    );
+
+    const result = await handler({ symbol: "missing", repository: "my-project" });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain("missing");
+  });
+});
@@ -0,0 +1,133 @@
+/**
+ * Unit tests for get_symbol_imports MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  getSymbolImportsToolDefinition,
+  createGetSymbolImportsHandler,
+} from "../../../../src/mcp/tools/get-symbol-imports.js";
+import { validateGetSymbolImportsArgs } from "../../../../src/mcp/validation.js";
+import type { SymbolImportsResult } from "../../../../src/services/graph-analysis-types.js";
+import { EntityNotFoundError } from "../../../../src/services/graph-service-errors.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: SymbolImportsResult = {
+  symbol: "validateToken",
+  repository: "my-project",
+  matches: [
+    {
+      symbol: {
+        id: "Function:my-project:src/auth.ts:validateToken:10",
+        name: "validateToken",
+        entity_type: "function",
+        file_path: "src/auth.ts",
+        start_line: 10,
+        end_line: 25,
+      },
+      imports: [
+        {
+          module: "./config",
+          is_external: false,
+          resolved_path: "src/config",
+          import_type: "named",
+          imported_symbols: ["secret"],
+          aliases: [],
+          line: 1,
+        },
+      ],
+    },
+  ],
+  metadata: { match_count: 1, query_time_ms: 4 },
+};
+
+describe("get_symbol_imports MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  describe("Tool Definition", () => {
+    it("should have correct tool name", () => {
+      expect(getSymbolImportsToolDefinition.name).toBe("get_symbol_imports");
+    });
+
+    it("should require symbol and repository", () => {
+      expect(getSymbolImportsToolDefinition.inputSchema.required).toEqual([
+        "symbol",
+        "repository",
+      ]);
+    });
+  });
+
+  describe("Argument Validation", () => {
+    it("should accept optional file_path and trim strings", () => {
+      const validated = validateGetSymbolImportsArgs({
+        symbol: "  validateToken ",
+        repository: "my-project",
+        file_path: "src/auth.ts",
+      });
+
+      expect(validated.symbol).toBe("validateToken");
+      expect(validated.file_path).toBe("src/auth.ts");
+    });
+
+    it("should reject empty symbol", () => {
+      expect(() => validateGetSymbolImportsArgs({ symbol: "", repository: "r" })).toThrow(
+        /Symbol cannot be empty/
+      );
+    });
+
+    it("should reject unknown properties (strict mode)", () => {
+      expect(() =>
+        validateGetSymbolImportsArgs({ symbol: "a", repository: "r", extra: true })
+      ).toThrow();
+    });
+  });
+
+  describe("Handler Execution", () => {
+    it("should return formatted imports for the symbol's file", async () => {
+      const handler = createGetSymbolImportsHandler(
+        createMockGraphAnalysisService({
+          getSymbolImports: () => Promise.resolve(SAMPLE_RESULT),
+        })
+      );
+
+      const result = await handler({ symbol: "validateToken", repository: "my-project" });
+
+      expect(result.isError).toBe(false);
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.matches[0].symbol.file_path).toBe("src/auth.ts");
+      expect(parsed.matches[0].imports[0].resolved_path).toBe("src/config");
+      expect(parsed.metadata.match_count).toBe(1);
+    });
+
+    it("should return an error result when the symbol is not found", async () => {
+      const handler = createGetSymbolImportsHandler(
+        createMockGraphAnalysisService({
+          getSymbolImports: () =>
+            Promise.reject(new EntityNotFoundError("symbol", "missing", "my-project")),
+        })
+      );
+
+      const result = await handler({ symbol: "missing", repository: "my-project" });
+
+      expect(result.isError).toBe(true);
+      expect((result.content[0] as { text: string }).text).toContain("Error:");
+    });
+
+    it("should return an error result for invalid arguments", async () => {
+      const handler = createGetSymbolImportsHandler(createMockGraphAnalysisService());
+
+      const result = await handler({ repository: "my-project" });
+
+      expect(result.isError).toBe(true);
+    });
+  });
+});
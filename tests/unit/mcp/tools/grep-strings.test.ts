@@ -0,0 +1,110 @@
+/**
+ * Unit tests for grep_strings MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  grepStringsToolDefinition,
+  createGrepStringsHandler,
+} from "../../../../src/mcp/tools/grep-strings.js";
+import { validateGrepStringsArgs } from "../../../../src/mcp/validation.js";
+import type {
+  StringLiteralSearchQuery,
+  StringLiteralSearchResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: StringLiteralSearchResult = {
+  query: "session expired",
+  repository: "my-project",
+  matches: [
+    {
+      value: "Session expired, please log in again",
+      file_path: "src/auth.ts",
+      line: 87,
+      column: 20,
+      enclosing_symbol: "AuthService.refresh",
+    },
+  ],
+  metadata: { total_matches: 1, truncated: false, query_time_ms: 3 },
+};
+
+describe("grep_strings MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  describe("Tool Definition", () => {
+    it("should have correct tool name", () => {
+      expect(grepStringsToolDefinition.name).toBe("grep_strings");
+    });
+
+    it("should require repository and query", () => {
+      expect(grepStringsToolDefinition.inputSchema.required).toEqual(["repository", "query"]);
+    });
+  });
+
+  describe("Argument Validation", () => {
+    it("should apply defaults", () => {
+      const validated = validateGrepStringsArgs({ repository: "r", query: "oops" });
+
+      expect(validated.limit).toBe(50);
+      expect(validated.case_sensitive).toBe(false);
+    });
+
+    it("should keep surrounding whitespace in the query", () => {
+      const validated = validateGrepStringsArgs({ repository: "r", query: " not found" });
+
+      expect(validated.query).toBe(" not found");
+    });
+
+    it("should reject an empty query", () => {
+      expect(() => validateGrepStringsArgs({ repository: "r", query: "" })).toThrow(
+        /Query cannot be empty/
+      );
+    });
+  });
+
+  describe("Handler Execution", () => {
+    it("should forward the query and return matches with context", async () => {
+      let received: StringLiteralSearchQuery | undefined;
+      const handler = createGrepStringsHandler(
+        createMockGraphAnalysisService({
+          grepStrings: (query) => {
+            received = query;
+            return Promise.resolve(SAMPLE_RESULT);
+          },
+        })
+      );
+
+      const result = await handler({ repository: "my-project", query: "session expired" });
+
+      expect(result.isError).toBe(false);
+      expect(received).toEqual({
+        repository: "my-project",
+        query: "session expired",
+        case_sensitive: false,
+        path: undefined,
+        limit: 50,
+      });
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.matches[0].enclosing_symbol).toBe("AuthService.refresh");
+    });
+
+    it("should return an error result for invalid arguments", async () => {
+      const handler = createGrepStringsHandler(createMockGraphAnalysisService());
+
+      const result = await handler({ repository: "my-project" });
+
+      expect(result.isError).toBe(true);
+      expect((result.content[0] as { text: string }).text).toContain("Error:");
+    });
+  });
+});
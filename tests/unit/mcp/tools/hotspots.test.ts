@@ -0,0 +1,113 @@
+/**
+ * Unit tests for hotspots MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  hotspotsToolDefinition,
+  createHotspotsHandler,
+} from "../../../../src/mcp/tools/hotspots.js";
+import type {
+  HotspotsQuery,
+  HotspotsResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: HotspotsResult = {
+  repository: "my-project",
+  hotspots: [
+    {
+      id: "Function:my-project:src/db.ts:query:3",
+      name: "query",
+      entity_type: "function",
+      file_path: "src/db.ts",
+      start_line: 3,
+      end_line: 20,
+      fan_in: 5,
+      caller_count: 3,
+      importer_count: 2,
+      referencing_modules: ["src/api.ts", "src/jobs.ts"],
+    },
+  ],
+  metadata: {
+    total_count: 1,
+    call_edge_count: 4,
+    import_reference_count: 2,
+    query_time_ms: 6,
+  },
+};
+
+describe("hotspots MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require a repository", () => {
+    expect(hotspotsToolDefinition.name).toBe("hotspots");
+    expect(hotspotsToolDefinition.inputSchema.required).toEqual(["repository"]);
+  });
+
+  it("should forward the query with defaults and return the ranking", async () => {
+    let received: HotspotsQuery | undefined;
+    const handler = createHotspotsHandler(
+      createMockGraphAnalysisService({
+        findHotspots: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ repository: "my-project" });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({ repository: "my-project", limit: 20 });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.hotspots[0]).toEqual({
+      name: "query",
+      entity_type: "function",
+      file_path: "src/db.ts",
+      start_line: 3,
+      end_line: 20,
+      fan_in: 5,
+      caller_count: 3,
+      importer_count: 2,
+      referencing_modules: ["src/api.ts", "src/jobs.ts"],
+    });
+    expect(parsed.metadata.import_reference_count).toBe(2);
+  });
+
+  it("should return only the requested fields of each symbol", async () => {
+    const handler = createHotspotsHandler(
+      createMockGraphAnalysisService({
+        findHotspots: () => Promise.resolve(SAMPLE_RESULT),
+      })
+    );
+
+    const result = await handler({
+      repository: "my-project",
+      fields: ["name", "file_path", "fan_in"],
+    });
+
+    expect(result.isError).toBe(false);
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.hotspots).toEqual([{ name: "query", file_path: "src/db.ts", fan_in: 5 }]);
+    expect(parsed.metadata.total_count).toBe(1);
+  });
+
+  it("should reject a limit above the cap", async () => {
+    const handler = createHotspotsHandler(createMockGraphAnalysisService());
+
+    const result = await handler({ repository: "my-project", limit: 500 });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain("Limit cannot exceed 100");
+  });
+});
@@ -0,0 +1,103 @@
+/**
+ * Unit tests for large_types MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  largeTypesToolDefinition,
+  createLargeTypesHandler,
+} from "../../../../src/mcp/tools/large-types.js";
+import type {
+  LargeTypesQuery,
+  LargeTypesResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: LargeTypesResult = {
+  repository: "my-project",
+  method_threshold: 20,
+  types: [
+    {
+      name: "Engine",
+      member_count: 24,
+      method_count: 24,
+      associated_item_count: 1,
+      impls: [
+        {
+          file_path: "src/engine.rs",
+          start_line: 10,
+          end_line: 300,
+          trait_name: null,
+          method_count: 22,
+          associated_item_count: 1,
+        },
+        {
+          file_path: "src/fmt.rs",
+          start_line: 5,
+          end_line: 30,
+          trait_name: "Display",
+          method_count: 2,
+          associated_item_count: 0,
+        },
+      ],
+    },
+  ],
+  metadata: {
+    total_count: 1,
+    truncated: false,
+    query_time_ms: 4,
+  },
+};
+
+describe("large_types MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require only a repository", () => {
+    expect(largeTypesToolDefinition.name).toBe("large_types");
+    expect(largeTypesToolDefinition.inputSchema.required).toEqual(["repository"]);
+  });
+
+  it("should forward the query with defaults and return types", async () => {
+    let received: LargeTypesQuery | undefined;
+    const handler = createLargeTypesHandler(
+      createMockGraphAnalysisService({
+        findLargeTypes: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ repository: "my-project" });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({
+      repository: "my-project",
+      method_threshold: 20,
+      include_associated_items: false,
+      order: "desc",
+      limit: 50,
+    });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.types[0].name).toBe("Engine");
+    expect(parsed.types[0].impls).toHaveLength(2);
+  });
+
+  it("should reject an unknown order", async () => {
+    const handler = createLargeTypesHandler(createMockGraphAnalysisService({}));
+
+    const result = await handler({ repository: "my-project", order: "size" });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain("Order must be 'asc' or 'desc'");
+  });
+});
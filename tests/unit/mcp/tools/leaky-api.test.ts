@@ -0,0 +1,95 @@
+/**
+ * Unit tests for leaky_api MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  leakyApiToolDefinition,
+  createLeakyApiHandler,
+} from "../../../../src/mcp/tools/leaky-api.js";
+import type {
+  LeakyApiQuery,
+  LeakyApiResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: LeakyApiResult = {
+  repository: "my-project",
+  leaks: [
+    {
+      item: {
+        id: "Function:my-project:src/pool.rs:acquire:12",
+        name: "acquire",
+        entity_type: "function",
+        file_path: "src/pool.rs",
+        start_line: 12,
+        end_line: 18,
+      },
+      qualified_name: "crate::pool::acquire",
+      parameter: null,
+      type_annotation: "Option<Handle>",
+      private_type: {
+        id: "Class:my-project:src/pool.rs:Handle:3",
+        name: "Handle",
+        entity_type: "class",
+        file_path: "src/pool.rs",
+        start_line: 3,
+        end_line: 5,
+      },
+      private_type_qualified_name: "crate::pool::Handle",
+      private_type_visibility: "pub(crate)",
+    },
+  ],
+  metadata: {
+    functions_checked: 4,
+    total_count: 1,
+    truncated: false,
+    query_time_ms: 3,
+  },
+};
+
+describe("leaky_api MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require only a repository", () => {
+    expect(leakyApiToolDefinition.name).toBe("leaky_api");
+    expect(leakyApiToolDefinition.inputSchema.required).toEqual(["repository"]);
+  });
+
+  it("should forward the query and return the leaks", async () => {
+    let received: LeakyApiQuery | undefined;
+    const handler = createLeakyApiHandler(
+      createMockGraphAnalysisService({
+        findLeakyApi: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ repository: "my-project", path: "src" });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({ repository: "my-project", path: "src", limit: 100 });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.leaks[0].private_type_qualified_name).toBe("crate::pool::Handle");
+  });
+
+  it("should reject an out-of-range limit", async () => {
+    const handler = createLeakyApiHandler(createMockGraphAnalysisService({}));
+
+    const result = await handler({ repository: "my-project", limit: 0 });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain("Limit must be at least 1");
+  });
+});
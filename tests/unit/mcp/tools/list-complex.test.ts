@@ -0,0 +1,100 @@
+/**
+ * Unit tests for list_complex MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  listComplexToolDefinition,
+  createListComplexHandler,
+} from "../../../../src/mcp/tools/list-complex.js";
+import type {
+  ComplexityQuery,
+  ComplexityResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: ComplexityResult = {
+  repository: "my-project",
+  threshold: 10,
+  functions: [
+    {
+      id: "Function:my-project:src/parser.rs:parse_expr:40",
+      name: "parse_expr",
+      entity_type: "function",
+      file_path: "src/parser.rs",
+      start_line: 40,
+      end_line: 120,
+      complexity: 23,
+    },
+  ],
+  metadata: { total_count: 1, truncated: false, query_time_ms: 3 },
+};
+
+describe("list_complex MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require a repository", () => {
+    expect(listComplexToolDefinition.name).toBe("list_complex");
+    expect(listComplexToolDefinition.inputSchema.required).toEqual(["repository"]);
+  });
+
+  it("should forward the query with defaults and return the ranking", async () => {
+    let received: ComplexityQuery | undefined;
+    const handler = createListComplexHandler(
+      createMockGraphAnalysisService({
+        listComplex: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ repository: "my-project", path: "src" });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({ repository: "my-project", threshold: 10, path: "src", limit: 50 });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.threshold).toBe(10);
+    expect(parsed.functions).toEqual([
+      {
+        name: "parse_expr",
+        entity_type: "function",
+        file_path: "src/parser.rs",
+        start_line: 40,
+        end_line: 120,
+        complexity: 23,
+      },
+    ]);
+  });
+
+  it("should return only the requested fields of each function", async () => {
+    const handler = createListComplexHandler(
+      createMockGraphAnalysisService({
+        listComplex: () => Promise.resolve(SAMPLE_RESULT),
+      })
+    );
+
+    const result = await handler({ repository: "my-project", fields: ["name", "complexity"] });
+
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.functions).toEqual([{ name: "parse_expr", complexity: 23 }]);
+  });
+
+  it("should reject a threshold below 1", async () => {
+    const handler = createListComplexHandler(createMockGraphAnalysisService());
+
+    const result = await handler({ repository: "my-project", threshold: 0 });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain("Threshold must be at least 1");
+  });
+});
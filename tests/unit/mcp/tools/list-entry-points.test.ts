@@ -0,0 +1,118 @@
+/**
+ * Unit tests for list_entry_points MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  listEntryPointsToolDefinition,
+  createListEntryPointsHandler,
+} from "../../../../src/mcp/tools/list-entry-points.js";
+import { validateListEntryPointsArgs } from "../../../../src/mcp/validation.js";
+import type {
+  EntryPointsQuery,
+  EntryPointsResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: EntryPointsResult = {
+  repository: "my-crate",
+  groups: [
+    {
+      kind: "async_main",
+      entry_points: [
+        {
+          id: "Function:my-crate:src/main.rs:main:4",
+          name: "main",
+          entity_type: "function",
+          file_path: "src/main.rs",
+          start_line: 4,
+          end_line: 9,
+          attributes: ["tokio::main"],
+        },
+      ],
+    },
+  ],
+  metadata: {
+    total_count: 1,
+    kind_counts: { async_main: 1 },
+    truncated: false,
+    query_time_ms: 2,
+  },
+};
+
+describe("list_entry_points MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  describe("Tool Definition", () => {
+    it("should have correct tool name", () => {
+      expect(listEntryPointsToolDefinition.name).toBe("list_entry_points");
+    });
+
+    it("should require repository", () => {
+      expect(listEntryPointsToolDefinition.inputSchema.required).toEqual(["repository"]);
+    });
+  });
+
+  describe("Argument Validation", () => {
+    it("should default limit to 200", () => {
+      expect(validateListEntryPointsArgs({ repository: "r" }).limit).toBe(200);
+    });
+
+    it("should reject unknown kinds", () => {
+      expect(() => validateListEntryPointsArgs({ repository: "r", kind: "library" })).toThrow(
+        /Invalid list_entry_points arguments/
+      );
+    });
+  });
+
+  describe("Handler Execution", () => {
+    it("should forward filters and return entry points grouped by kind", async () => {
+      let received: EntryPointsQuery | undefined;
+      const handler = createListEntryPointsHandler(
+        createMockGraphAnalysisService({
+          listEntryPoints: (query) => {
+            received = query;
+            return Promise.resolve(SAMPLE_RESULT);
+          },
+        })
+      );
+
+      const result = await handler({ repository: "my-crate", kind: "async_main" });
+
+      expect(result.isError).toBe(false);
+      expect(received).toEqual({
+        repository: "my-crate",
+        kind: "async_main",
+        path: undefined,
+        limit: 200,
+      });
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.groups[0].kind).toBe("async_main");
+      expect(parsed.groups[0].entry_points[0]).toEqual({
+        name: "main",
+        file_path: "src/main.rs",
+        start_line: 4,
+        end_line: 9,
+        attributes: ["tokio::main"],
+      });
+    });
+
+    it("should return an error result for invalid arguments", async () => {
+      const handler = createListEntryPointsHandler(createMockGraphAnalysisService());
+
+      const result = await handler({});
+
+      expect(result.isError).toBe(true);
+      expect((result.content[0] as { text: string }).text).toContain("Error:");
+    });
+  });
+});
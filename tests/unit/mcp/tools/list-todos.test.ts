@@ -0,0 +1,94 @@
+/**
+ * Unit tests for list_todos MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  listTodosToolDefinition,
+  createListTodosHandler,
+} from "../../../../src/mcp/tools/list-todos.js";
+import { validateListTodosArgs } from "../../../../src/mcp/validation.js";
+import type {
+  TodoListQuery,
+  TodoListResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: TodoListResult = {
+  repository: "my-project",
+  todos: [{ tag: "FIXME", message: "race on logout", file_path: "src/auth.ts", line: 20 }],
+  metadata: { total_count: 1, tag_counts: { FIXME: 1 }, truncated: false, query_time_ms: 2 },
+};
+
+describe("list_todos MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  describe("Tool Definition", () => {
+    it("should have correct tool name", () => {
+      expect(listTodosToolDefinition.name).toBe("list_todos");
+    });
+
+    it("should require only repository", () => {
+      expect(listTodosToolDefinition.inputSchema.required).toEqual(["repository"]);
+    });
+  });
+
+  describe("Argument Validation", () => {
+    it("should default limit to 100", () => {
+      const validated = validateListTodosArgs({ repository: "r" });
+
+      expect(validated.limit).toBe(100);
+      expect(validated.tag).toBeUndefined();
+    });
+
+    it("should reject limits above 500", () => {
+      expect(() => validateListTodosArgs({ repository: "r", limit: 501 })).toThrow(
+        /Limit cannot exceed 500/
+      );
+    });
+
+    it("should reject unknown arguments", () => {
+      expect(() => validateListTodosArgs({ repository: "r", kind: "todo" })).toThrow();
+    });
+  });
+
+  describe("Handler Execution", () => {
+    it("should forward filters and return markers", async () => {
+      let received: TodoListQuery | undefined;
+      const handler = createListTodosHandler(
+        createMockGraphAnalysisService({
+          listTodos: (query) => {
+            received = query;
+            return Promise.resolve(SAMPLE_RESULT);
+          },
+        })
+      );
+
+      const result = await handler({ repository: "my-project", tag: "FIXME", path: "src" });
+
+      expect(result.isError).toBe(false);
+      expect(received).toEqual({ repository: "my-project", tag: "FIXME", path: "src", limit: 100 });
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.todos[0].message).toBe("race on logout");
+      expect(parsed.metadata.tag_counts).toEqual({ FIXME: 1 });
+    });
+
+    it("should return an error result for invalid arguments", async () => {
+      const handler = createListTodosHandler(createMockGraphAnalysisService());
+
+      const result = await handler({});
+
+      expect(result.isError).toBe(true);
+      expect((result.content[0] as { text: string }).text).toContain("Error:");
+    });
+  });
+});
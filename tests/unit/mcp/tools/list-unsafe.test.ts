@@ -0,0 +1,104 @@
+/**
+ * Unit tests for list_unsafe MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  listUnsafeToolDefinition,
+  createListUnsafeHandler,
+} from "../../../../src/mcp/tools/list-unsafe.js";
+import type {
+  UnsafeCodeQuery,
+  UnsafeCodeResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: UnsafeCodeResult = {
+  repository: "my-project",
+  symbols: [
+    {
+      id: "Function:my-project:src/ffi.rs:raw_copy:3",
+      name: "raw_copy",
+      entity_type: "function",
+      file_path: "src/ffi.rs",
+      start_line: 3,
+      end_line: 5,
+      is_unsafe: true,
+      unsafe_blocks: [],
+    },
+    {
+      id: "Function:my-project:src/ffi.rs:read_header:7",
+      name: "read_header",
+      entity_type: "function",
+      file_path: "src/ffi.rs",
+      start_line: 7,
+      end_line: 14,
+      is_unsafe: false,
+      unsafe_blocks: [{ start_line: 8, end_line: 8 }],
+    },
+  ],
+  impls: [{ trait_name: "Send", type_name: "Handle", file_path: "src/ffi.rs", line: 34 }],
+  metadata: {
+    unsafe_symbol_count: 1,
+    unsafe_block_count: 1,
+    unsafe_impl_count: 1,
+    truncated: false,
+    query_time_ms: 4,
+  },
+};
+
+describe("list_unsafe MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require a repository", () => {
+    expect(listUnsafeToolDefinition.name).toBe("list_unsafe");
+    expect(listUnsafeToolDefinition.inputSchema.required).toEqual(["repository"]);
+  });
+
+  it("should forward the query with defaults and return unsafe code", async () => {
+    let received: UnsafeCodeQuery | undefined;
+    const handler = createListUnsafeHandler(
+      createMockGraphAnalysisService({
+        listUnsafe: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ repository: "my-project", path: "src" });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({ repository: "my-project", path: "src", limit: 200 });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.symbols[0]).toEqual({
+      name: "raw_copy",
+      entity_type: "function",
+      file_path: "src/ffi.rs",
+      start_line: 3,
+      end_line: 5,
+      is_unsafe: true,
+      unsafe_blocks: [],
+    });
+    expect(parsed.symbols[1].unsafe_blocks).toEqual([{ start_line: 8, end_line: 8 }]);
+    expect(parsed.impls[0]).toMatchObject({ trait_name: "Send", type_name: "Handle" });
+  });
+
+  it("should reject a limit above the cap", async () => {
+    const handler = createListUnsafeHandler(createMockGraphAnalysisService());
+
+    const result = await handler({ repository: "my-project", limit: 5000 });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain("Limit cannot exceed 1000");
+  });
+});
@@ -0,0 +1,117 @@
+/**
+ * Unit tests for missing_docs MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  missingDocsToolDefinition,
+  createMissingDocsHandler,
+} from "../../../../src/mcp/tools/missing-docs.js";
+import type {
+  MissingDocsQuery,
+  MissingDocsResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: MissingDocsResult = {
+  repository: "my-project",
+  modules: [
+    {
+      module: "src/geometry.rs",
+      public_count: 3,
+      documented_count: 1,
+      symbols: [
+        {
+          id: "Function:my-project:src/geometry.rs:scale:20",
+          name: "scale",
+          entity_type: "function",
+          file_path: "src/geometry.rs",
+          start_line: 20,
+          end_line: 24,
+        },
+      ],
+    },
+  ],
+  metadata: {
+    public_count: 3,
+    documented_count: 1,
+    missing_count: 1,
+    allowed_count: 1,
+    coverage: 0.333,
+    truncated: false,
+    query_time_ms: 2,
+  },
+};
+
+describe("missing_docs MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require a repository", () => {
+    expect(missingDocsToolDefinition.name).toBe("missing_docs");
+    expect(missingDocsToolDefinition.inputSchema.required).toEqual(["repository"]);
+  });
+
+  it("should forward the query with defaults and return symbols per module", async () => {
+    let received: MissingDocsQuery | undefined;
+    const handler = createMissingDocsHandler(
+      createMockGraphAnalysisService({
+        listMissingDocs: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ repository: "my-project" });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({
+      repository: "my-project",
+      path: undefined,
+      allow: [],
+      limit: 200,
+    });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.modules[0].module).toBe("src/geometry.rs");
+    expect(parsed.modules[0].symbols).toEqual([
+      { name: "scale", entity_type: "function", start_line: 20, end_line: 24 },
+    ]);
+    expect(parsed.metadata.coverage).toBe(0.333);
+  });
+
+  it("should pass the path filter and allowlist", async () => {
+    let received: MissingDocsQuery | undefined;
+    const handler = createMissingDocsHandler(
+      createMockGraphAnalysisService({
+        listMissingDocs: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    await handler({ repository: "my-project", path: "src/", allow: [" Point::new "], limit: 10 });
+
+    expect(received).toMatchObject({ path: "src/", allow: ["Point::new"], limit: 10 });
+  });
+
+  it("should reject empty allowlist entries", async () => {
+    const handler = createMissingDocsHandler(createMockGraphAnalysisService({}));
+
+    const result = await handler({ repository: "my-project", allow: [""] });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain(
+      "Allowlist entries cannot be empty"
+    );
+  });
+});
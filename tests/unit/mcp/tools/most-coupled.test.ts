@@ -0,0 +1,63 @@
+/**
+ * Unit tests for most_coupled MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  mostCoupledToolDefinition,
+  createMostCoupledHandler,
+} from "../../../../src/mcp/tools/most-coupled.js";
+import { validateMostCoupledArgs } from "../../../../src/mcp/validation.js";
+import type {
+  MostCoupledQuery,
+  MostCoupledResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: MostCoupledResult = {
+  repository: "my-project",
+  functions: [],
+  metadata: { total_count: 0, call_edge_count: 0, query_time_ms: 1 },
+};
+
+describe("most_coupled MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require only repository", () => {
+    expect(mostCoupledToolDefinition.name).toBe("most_coupled");
+    expect(mostCoupledToolDefinition.inputSchema.required).toEqual(["repository"]);
+  });
+
+  it("should default limit to 20 and reject limits above 100", () => {
+    expect(validateMostCoupledArgs({ repository: "r" }).limit).toBe(20);
+    expect(() => validateMostCoupledArgs({ repository: "r", limit: 101 })).toThrow(
+      /Limit cannot exceed 100/
+    );
+  });
+
+  it("should forward the path filter", async () => {
+    let received: MostCoupledQuery | undefined;
+    const handler = createMostCoupledHandler(
+      createMockGraphAnalysisService({
+        findMostCoupled: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ repository: "my-project", path: "src/parse" });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({ repository: "my-project", path: "src/parse", limit: 20 });
+  });
+});
@@ -0,0 +1,99 @@
+/**
+ * Unit tests for resolve_name MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  resolveNameToolDefinition,
+  createResolveNameHandler,
+} from "../../../../src/mcp/tools/resolve-name.js";
+import type {
+  NameResolutionQuery,
+  NameResolutionResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: NameResolutionResult = {
+  partial: "Point",
+  repository: "my-project",
+  candidates: [
+    {
+      id: "Class:my-project:src/geometry.rs:Point:3",
+      name: "Point",
+      entity_type: "struct",
+      file_path: "src/geometry.rs",
+      start_line: 3,
+      end_line: 6,
+      qualified_name: "crate::geometry::Point",
+      kind: "class",
+      match_type: "exact",
+      name_count: 1,
+    },
+  ],
+  metadata: {
+    total_count: 1,
+    unambiguous: true,
+    query_time_ms: 2,
+  },
+};
+
+describe("resolve_name MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require a repository and a partial name", () => {
+    expect(resolveNameToolDefinition.name).toBe("resolve_name");
+    expect(resolveNameToolDefinition.inputSchema.required).toEqual(["repository", "partial"]);
+  });
+
+  it("should forward the query with defaults and return candidates", async () => {
+    let received: NameResolutionQuery | undefined;
+    const handler = createResolveNameHandler(
+      createMockGraphAnalysisService({
+        resolveName: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ repository: "my-project", partial: " Point " });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({ repository: "my-project", partial: "Point", limit: 20 });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.candidates).toEqual([
+      {
+        qualified_name: "crate::geometry::Point",
+        name: "Point",
+        kind: "class",
+        entity_type: "struct",
+        file_path: "src/geometry.rs",
+        start_line: 3,
+        end_line: 6,
+        match_type: "exact",
+        name_count: 1,
+      },
+    ]);
+    expect(parsed.metadata.unambiguous).toBe(true);
+  });
+
+  it("should reject an empty partial name", async () => {
+    const handler = createResolveNameHandler(createMockGraphAnalysisService({}));
+
+    const result = await handler({ repository: "my-project", partial: "  " });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain(
+      "Partial name cannot be empty"
+    );
+  });
+});
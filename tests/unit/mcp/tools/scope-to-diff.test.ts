@@ -0,0 +1,135 @@
+/**
+ * Unit tests for scope_to_diff MCP tool handler
+ *
+ * Uses a mock SymbolDiffService to isolate the MCP layer from git and the parser.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  scopeToDiffToolDefinition,
+  createScopeToDiffHandler,
+} from "../../../../src/mcp/tools/scope-to-diff.js";
+import { validateScopeToDiffArgs } from "../../../../src/mcp/validation.js";
+import type {
+  DiffScopeQuery,
+  DiffScopeResult,
+  SymbolDiffService,
+} from "../../../../src/services/symbol-diff-types.js";
+import { RepositoryNotFoundError } from "../../../../src/services/errors.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+
+const SAMPLE_RESULT: DiffScopeResult = {
+  scope: "diff",
+  repository: "my-project",
+  base_ref: "main",
+  head_ref: "HEAD",
+  base_sha: "1111111111111111111111111111111111111111",
+  head_sha: "2222222222222222222222222222222222222222",
+  files: [
+    {
+      file_path: "src/auth.ts",
+      status: "modified",
+      changed_ranges: [{ start_line: 6, end_line: 8 }],
+      symbols: [{ name: "logout", kind: "function", start_line: 6, end_line: 8, changed_lines: 3 }],
+    },
+  ],
+  metadata: {
+    files_changed: 1,
+    files_analyzed: 1,
+    files_skipped: 0,
+    symbol_count: 1,
+    query_time_ms: 9,
+  },
+};
+
+function createService(scopeToDiff: SymbolDiffService["scopeToDiff"]): SymbolDiffService {
+  return {
+    diffSymbols: () => Promise.reject(new Error("not used")),
+    scopeToDiff,
+    createSnapshot: () => Promise.reject(new Error("not used")),
+    whatChanged: () => Promise.reject(new Error("not used")),
+    recordSnapshot: () => Promise.reject(new Error("not used")),
+    getSnapshotHistory: () => Promise.reject(new Error("not used")),
+  };
+}
+
+describe("scope_to_diff MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  describe("Tool Definition", () => {
+    it("should have correct tool name", () => {
+      expect(scopeToDiffToolDefinition.name).toBe("scope_to_diff");
+    });
+
+    it("should require repository and base_ref", () => {
+      expect(scopeToDiffToolDefinition.inputSchema.required).toEqual(["repository", "base_ref"]);
+    });
+  });
+
+  describe("Argument Validation", () => {
+    it("should default head_ref to HEAD", () => {
+      const validated = validateScopeToDiffArgs({ repository: "r", base_ref: "main" });
+
+      expect(validated.head_ref).toBe("HEAD");
+    });
+
+    it("should reject ref ranges", () => {
+      expect(() => validateScopeToDiffArgs({ repository: "r", base_ref: "main..HEAD" })).toThrow(
+        /not a range/
+      );
+    });
+
+    it("should reject unknown symbol kinds", () => {
+      expect(() =>
+        validateScopeToDiffArgs({ repository: "r", base_ref: "main", kind: "widget" })
+      ).toThrow(/Invalid scope_to_diff arguments/);
+    });
+  });
+
+  describe("Handler Execution", () => {
+    it("should forward filters and mark the output as diff-scoped", async () => {
+      let received: DiffScopeQuery | undefined;
+      const handler = createScopeToDiffHandler(
+        createService((query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        })
+      );
+
+      const result = await handler({
+        repository: "my-project",
+        base_ref: "main",
+        kind: "function",
+      });
+
+      expect(result.isError).toBe(false);
+      expect(received).toEqual({
+        repository: "my-project",
+        base_ref: "main",
+        head_ref: "HEAD",
+        kind: "function",
+      });
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.scope).toBe("diff");
+      expect(parsed.scope_description).toContain("between main (111111111111) and HEAD");
+      expect(parsed.files[0].symbols[0].changed_lines).toBe(3);
+    });
+
+    it("should map service errors to an error result", async () => {
+      const handler = createScopeToDiffHandler(
+        createService(() => Promise.reject(new RepositoryNotFoundError("missing")))
+      );
+
+      const result = await handler({ repository: "missing", base_ref: "main" });
+
+      expect(result.isError).toBe(true);
+      expect((result.content[0] as { text: string }).text).toContain("'missing' not found");
+    });
+  });
+});
@@ -0,0 +1,154 @@
+/**
+ * Unit tests for search_by_type MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  searchByTypeToolDefinition,
+  createSearchByTypeHandler,
+} from "../../../../src/mcp/tools/search-by-type.js";
+import { validateSearchByTypeArgs } from "../../../../src/mcp/validation.js";
+import type {
+  TypeSearchQuery,
+  TypeSearchResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: TypeSearchResult = {
+  type: "&str",
+  repository: "my-project",
+  position: "param",
+  matches: [
+    {
+      id: "Function:my-project:src/lib.rs:function_with_params:30",
+      name: "function_with_params",
+      entity_type: "function",
+      kind: "function",
+      file_path: "src/lib.rs",
+      start_line: 30,
+      end_line: 32,
+      params: [
+        { name: "name", type: "&str" },
+        { name: "count", type: "i32" },
+      ],
+      return_type: "String",
+      matched_params: ["name"],
+      matched_return: false,
+      match_type: "exact",
+    },
+  ],
+  metadata: { total_matches: 1, contains: false, query_time_ms: 2 },
+};
+
+describe("search_by_type MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  describe("Tool Definition", () => {
+    it("should have correct tool name", () => {
+      expect(searchByTypeToolDefinition.name).toBe("search_by_type");
+    });
+
+    it("should require repository and type", () => {
+      expect(searchByTypeToolDefinition.inputSchema.required).toEqual(["repository", "type"]);
+    });
+  });
+
+  describe("Argument Validation", () => {
+    it("should default position to any, contains to false and limit to 20", () => {
+      const validated = validateSearchByTypeArgs({ repository: "r", type: "Point" });
+
+      expect(validated.position).toBe("any");
+      expect(validated.contains).toBe(false);
+      expect(validated.limit).toBe(20);
+    });
+
+    it("should reject unknown positions", () => {
+      expect(() =>
+        validateSearchByTypeArgs({ repository: "r", type: "Point", position: "field" })
+      ).toThrow(/Invalid search_by_type arguments/);
+    });
+
+    it("should reject unknown output formats", () => {
+      expect(() =>
+        validateSearchByTypeArgs({ repository: "r", type: "Point", output_format: "html" })
+      ).toThrow(/Invalid search_by_type arguments/);
+    });
+
+    it("should reject an empty type", () => {
+      expect(() => validateSearchByTypeArgs({ repository: "r", type: "  " })).toThrow(
+        /Type cannot be empty/
+      );
+    });
+  });
+
+  describe("Handler Execution", () => {
+    it("should forward options and return matches", async () => {
+      let received: TypeSearchQuery | undefined;
+      const handler = createSearchByTypeHandler(
+        createMockGraphAnalysisService({
+          searchByType: (query) => {
+            received = query;
+            return Promise.resolve(SAMPLE_RESULT);
+          },
+        })
+      );
+
+      const result = await handler({ repository: "my-project", type: "&str", position: "param" });
+
+      expect(result.isError).toBe(false);
+      expect(received).toEqual({
+        repository: "my-project",
+        type: "&str",
+        position: "param",
+        contains: false,
+        limit: 20,
+      });
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.matches[0]).toMatchObject({
+        name: "function_with_params",
+        return_type: "String",
+        matched_params: ["name"],
+        match_type: "exact",
+      });
+    });
+
+    it("should return an error result for invalid arguments", async () => {
+      const handler = createSearchByTypeHandler(createMockGraphAnalysisService());
+
+      const result = await handler({ repository: "my-project" });
+
+      expect(result.isError).toBe(true);
+      expect((result.content[0] as { text: string }).text).toContain("Error:");
+    });
+
+    it("should render Markdown when output_format is markdown", async () => {
+      const handler = createSearchByTypeHandler(
+        createMockGraphAnalysisService({
+          searchByType: () => Promise.resolve(SAMPLE_RESULT),
+        })
+      );
+
+      const result = await handler({
+        repository: "my-project",
+        type: "&str",
+        output_format: "markdown",
+      });
+
+      expect(result.isError).toBe(false);
+      const text = (result.content[0] as { text: string }).text;
+      expect(text).toContain("## `function_with_params` (function)");
+      expect(text).toContain("`src/lib.rs:30-32`");
+      expect(text).toContain("- **params:** `name: &str`, `count: i32`");
+      expect(text).toContain("- **returns:** `String`");
+    });
+  });
+});
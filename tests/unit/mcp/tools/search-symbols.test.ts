@@ -0,0 +1,281 @@
+/**
+ * Unit tests for search_symbols MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  searchSymbolsToolDefinition,
+  createSearchSymbolsHandler,
+} from "../../../../src/mcp/tools/search-symbols.js";
+import { validateSearchSymbolsArgs } from "../../../../src/mcp/validation.js";
+import type {
+  SymbolSearchQuery,
+  SymbolSearchResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: SymbolSearchResult = {
+  query: "scale",
+  repository: "my-project",
+  matches: [
+    {
+      id: "Function:my-project:src/scale.ts:scale_factor:3",
+      name: "scale_factor",
+      entity_type: "function",
+      kind: "function",
+      file_path: "src/scale.ts",
+      start_line: 3,
+      end_line: 8,
+      match_type: "prefix",
+      score: 0.8,
+    },
+  ],
+  metadata: { total_matches: 1, token_match: true, min_score_applied: 0.25, query_time_ms: 3 },
+};
+
+describe("search_symbols MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  describe("Tool Definition", () => {
+    it("should have correct tool name", () => {
+      expect(searchSymbolsToolDefinition.name).toBe("search_symbols");
+    });
+
+    it("should require repository and query", () => {
+      expect(searchSymbolsToolDefinition.inputSchema.required).toEqual(["repository", "query"]);
+    });
+  });
+
+  describe("Argument Validation", () => {
+    it("should default token_match to false and limit to 20", () => {
+      const validated = validateSearchSymbolsArgs({ repository: "r", query: "scale" });
+
+      expect(validated.token_match).toBe(false);
+      expect(validated.limit).toBe(20);
+      expect(validated.output_format).toBe("json");
+      expect(validated.min_score).toBeUndefined();
+    });
+
+    it("should reject a min_score outside 0.0-1.0", () => {
+      expect(() =>
+        validateSearchSymbolsArgs({ repository: "r", query: "scale", min_score: 1.5 })
+      ).toThrow(/Minimum score must be between 0.0 and 1.0/);
+    });
+
+    it("should reject unknown kinds", () => {
+      expect(() =>
+        validateSearchSymbolsArgs({ repository: "r", query: "scale", kind: "trait" })
+      ).toThrow(/Invalid search_symbols arguments/);
+    });
+
+    it("should reject unknown fields and list the valid ones", () => {
+      expect(() =>
+        validateSearchSymbolsArgs({ repository: "r", query: "scale", fields: ["name", "path"] })
+      ).toThrow(/Unknown field 'path'; valid fields are name, type, kind, file_path/);
+      expect(() => validateSearchSymbolsArgs({ repository: "r", query: "x", fields: [] })).toThrow(
+        /Fields cannot be empty/
+      );
+    });
+
+    it("should reject an empty query", () => {
+      expect(() => validateSearchSymbolsArgs({ repository: "r", query: "  " })).toThrow(
+        /Query cannot be empty/
+      );
+    });
+  });
+
+  describe("Handler Execution", () => {
+    it("should forward options and return matches", async () => {
+      let received: SymbolSearchQuery | undefined;
+      const handler = createSearchSymbolsHandler(
+        createMockGraphAnalysisService({
+          searchSymbols: (query) => {
+            received = query;
+            return Promise.resolve(SAMPLE_RESULT);
+          },
+        })
+      );
+
+      const result = await handler({ repository: "my-project", query: "scale", token_match: true });
+
+      expect(result.isError).toBe(false);
+      expect(received).toEqual({
+        repository: "my-project",
+        query: "scale",
+        token_match: true,
+        include_context: false,
+        limit: 20,
+      });
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.matches[0]).toMatchObject({
+        name: "scale_factor",
+        kind: "function",
+        match_type: "prefix",
+      });
+    });
+
+    it("should forward include_context and return doc summaries", async () => {
+      let received: SymbolSearchQuery | undefined;
+      const handler = createSearchSymbolsHandler(
+        createMockGraphAnalysisService({
+          searchSymbols: (query) => {
+            received = query;
+            return Promise.resolve({
+              ...SAMPLE_RESULT,
+              matches: SAMPLE_RESULT.matches.map((match) => ({
+                ...match,
+                doc_summary: "Scale a vector by a factor.",
+                module_doc_summary: "Vector arithmetic.",
+              })),
+            });
+          },
+        })
+      );
+
+      const result = await handler({
+        repository: "my-project",
+        query: "scale",
+        include_context: true,
+      });
+
+      expect(received?.include_context).toBe(true);
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.matches[0]).toMatchObject({
+        doc_summary: "Scale a vector by a factor.",
+        module_doc_summary: "Vector arithmetic.",
+      });
+    });
+
+    it("should forward the build_or_macro filter and flag build-time symbols", async () => {
+      let received: SymbolSearchQuery | undefined;
+      const handler = createSearchSymbolsHandler(
+        createMockGraphAnalysisService({
+          searchSymbols: (query) => {
+            received = query;
+            return Promise.resolve({
+              ...SAMPLE_RESULT,
+              matches: SAMPLE_RESULT.matches.map((match) => ({
+                ...match,
+                build_or_macro: true as const,
+              })),
+            });
+          },
+        })
+      );
+
+      const result = await handler({
+        repository: "my-project",
+        query: "scale",
+        build_or_macro: true,
+      });
+
+      expect(received?.build_or_macro).toBe(true);
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.matches[0].build_or_macro).toBe(true);
+    });
+
+    it("should forward the platform and report cfg conditions", async () => {
+      let received: SymbolSearchQuery | undefined;
+      const handler = createSearchSymbolsHandler(
+        createMockGraphAnalysisService({
+          searchSymbols: (query) => {
+            received = query;
+            return Promise.resolve({
+              ...SAMPLE_RESULT,
+              matches: SAMPLE_RESULT.matches.map((match) => ({ ...match, cfg: "unix" })),
+            });
+          },
+        })
+      );
+
+      const result = await handler({ repository: "my-project", query: "scale", platform: "linux" });
+
+      expect(received?.platform).toBe("linux");
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.matches[0].cfg).toBe("unix");
+    });
+
+    it("should forward min_score and echo the applied minimum", async () => {
+      let received: SymbolSearchQuery | undefined;
+      const handler = createSearchSymbolsHandler(
+        createMockGraphAnalysisService({
+          searchSymbols: (query) => {
+            received = query;
+            return Promise.resolve({
+              ...SAMPLE_RESULT,
+              metadata: { ...SAMPLE_RESULT.metadata, min_score_applied: 0.7 },
+            });
+          },
+        })
+      );
+
+      const result = await handler({ repository: "my-project", query: "scale", min_score: 0.7 });
+
+      expect(received?.min_score).toBe(0.7);
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.metadata.min_score_applied).toBe(0.7);
+    });
+
+    it("should project matches onto the requested fields", async () => {
+      const handler = createSearchSymbolsHandler(
+        createMockGraphAnalysisService({
+          searchSymbols: () => Promise.resolve(SAMPLE_RESULT),
+        })
+      );
+
+      const result = await handler({
+        repository: "my-project",
+        query: "scale",
+        fields: ["name", "file_path", "start_line", "where_clause"],
+      });
+
+      expect(result.isError).toBe(false);
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      // Optional fields absent from a match stay absent
+      expect(parsed.matches).toEqual([
+        { name: "scale_factor", file_path: "src/scale.ts", start_line: 3 },
+      ]);
+      expect(parsed.metadata.total_matches).toBe(1);
+    });
+
+    it("should return an error result for invalid arguments", async () => {
+      const handler = createSearchSymbolsHandler(createMockGraphAnalysisService());
+
+      const result = await handler({ repository: "my-project" });
+
+      expect(result.isError).toBe(true);
+      expect((result.content[0] as { text: string }).text).toContain("Error:");
+    });
+
+    it("should render Markdown when output_format is markdown", async () => {
+      const handler = createSearchSymbolsHandler(
+        createMockGraphAnalysisService({
+          searchSymbols: () => Promise.resolve(SAMPLE_RESULT),
+        })
+      );
+
+      const result = await handler({
+        repository: "my-project",
+        query: "scale",
+        output_format: "markdown",
+      });
+
+      expect(result.isError).toBe(false);
+      const text = (result.content[0] as { text: string }).text;
+      expect(text).toContain("# Symbols matching `scale` in my-project");
+      expect(text).toContain("## `scale_factor` (function)");
+      expect(text).toContain("`src/scale.ts:3-8`");
+      expect(text).toContain("1 of 1 matches scoring at least 0.25");
+      expect(() => JSON.parse(text) as unknown).toThrow();
+    });
+  });
+});
@@ -0,0 +1,238 @@
+/**
+ * Unit tests for snapshot, what_changed, record_snapshot and snapshot_history
+ * MCP tool handlers
+ *
+ * Uses a mock SymbolDiffService to isolate the MCP layer from the graph and
+ * the snapshot store.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  snapshotToolDefinition,
+  createSnapshotHandler,
+} from "../../../../src/mcp/tools/snapshot.js";
+import {
+  whatChangedToolDefinition,
+  createWhatChangedHandler,
+} from "../../../../src/mcp/tools/what-changed.js";
+import {
+  recordSnapshotToolDefinition,
+  createRecordSnapshotHandler,
+} from "../../../../src/mcp/tools/record-snapshot.js";
+import {
+  snapshotHistoryToolDefinition,
+  createSnapshotHistoryHandler,
+} from "../../../../src/mcp/tools/snapshot-history.js";
+import { validateSnapshotArgs, validateWhatChangedArgs } from "../../../../src/mcp/validation.js";
+import type {
+  SymbolDiffService,
+  SymbolSnapshotResult,
+  WhatChangedQuery,
+  WhatChangedResult,
+  RecordSnapshotResult,
+  SnapshotHistoryQuery,
+  SnapshotHistoryResult,
+} from "../../../../src/services/symbol-diff-types.js";
+import { GraphServiceValidationError } from "../../../../src/services/graph-service-errors.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+
+const SNAPSHOT_RESULT: SymbolSnapshotResult = {
+  repository: "my-project",
+  name: "v1",
+  created_at: "2026-01-01T00:00:00.000Z",
+  symbol_count: 42,
+  replaced: false,
+  metadata: { query_time_ms: 5 },
+};
+
+const WHAT_CHANGED_RESULT: WhatChangedResult = {
+  repository: "my-project",
+  name: "v1",
+  snapshot_created_at: "2026-01-01T00:00:00.000Z",
+  changes: [
+    {
+      symbol: "src/auth.ts::logout",
+      file_path: "src/auth.ts",
+      name: "logout",
+      change: "modified",
+      kind: "function",
+      location: { start_line: 6, end_line: 8 },
+    },
+  ],
+  summary: { added: 0, removed: 0, modified: 1 },
+  metadata: { snapshot_symbol_count: 42, current_symbol_count: 42, query_time_ms: 7 },
+};
+
+const RECORD_RESULT: RecordSnapshotResult = {
+  repository: "my-project",
+  recorded_at: "2026-02-01T00:00:00.000Z",
+  digest: "00000000deadbeef",
+  symbol_count: 44,
+  changes: { added: 3, removed: 1, modified: 2 },
+  history_length: 2,
+  pruned: 0,
+  metadata: { query_time_ms: 6 },
+};
+
+const HISTORY_RESULT: SnapshotHistoryResult = {
+  repository: "my-project",
+  entries: [
+    {
+      recorded_at: "2026-01-01T00:00:00.000Z",
+      digest: "00000000cafebabe",
+      symbol_count: 42,
+      symbol_delta: 0,
+      changes: { added: 0, removed: 0, modified: 0 },
+      change_count: 0,
+    },
+    {
+      recorded_at: "2026-02-01T00:00:00.000Z",
+      digest: "00000000deadbeef",
+      symbol_count: 44,
+      symbol_delta: 2,
+      changes: { added: 3, removed: 1, modified: 2 },
+      change_count: 6,
+    },
+  ],
+  trend: {
+    from: "2026-01-01T00:00:00.000Z",
+    to: "2026-02-01T00:00:00.000Z",
+    symbol_count_delta: 2,
+    changes: { added: 3, removed: 1, modified: 2 },
+  },
+  metadata: { total_entries: 2, retention: 100, query_time_ms: 2 },
+};
+
+function createService(overrides: Partial<SymbolDiffService>): SymbolDiffService {
+  return {
+    diffSymbols: () => Promise.reject(new Error("not used")),
+    scopeToDiff: () => Promise.reject(new Error("not used")),
+    createSnapshot: () => Promise.reject(new Error("not used")),
+    whatChanged: () => Promise.reject(new Error("not used")),
+    recordSnapshot: () => Promise.reject(new Error("not used")),
+    getSnapshotHistory: () => Promise.reject(new Error("not used")),
+    ...overrides,
+  };
+}
+
+describe("snapshot and what_changed MCP Tools", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  describe("Tool Definitions", () => {
+    it("should have correct tool names", () => {
+      expect(snapshotToolDefinition.name).toBe("snapshot");
+      expect(whatChangedToolDefinition.name).toBe("what_changed");
+      expect(recordSnapshotToolDefinition.name).toBe("record_snapshot");
+      expect(snapshotHistoryToolDefinition.name).toBe("snapshot_history");
+    });
+
+    it("should require repository and name", () => {
+      expect(snapshotToolDefinition.inputSchema.required).toEqual(["repository", "name"]);
+      expect(whatChangedToolDefinition.inputSchema.required).toEqual(["repository", "name"]);
+    });
+  });
+
+  describe("Argument Validation", () => {
+    it("should accept dotted version names", () => {
+      expect(validateSnapshotArgs({ repository: "r", name: "v1.2.0" }).name).toBe("v1.2.0");
+    });
+
+    it("should reject names that are not plain file names", () => {
+      expect(() => validateSnapshotArgs({ repository: "r", name: "../x" })).toThrow(
+        /Invalid snapshot arguments/
+      );
+      expect(() => validateWhatChangedArgs({ repository: "r", name: "a b" })).toThrow(
+        /Invalid what_changed arguments/
+      );
+    });
+  });
+
+  describe("Handler Execution", () => {
+    it("should record a snapshot", async () => {
+      const handler = createSnapshotHandler(
+        createService({ createSnapshot: () => Promise.resolve(SNAPSHOT_RESULT) })
+      );
+
+      const result = await handler({ repository: "my-project", name: "v1" });
+
+      expect(result.isError).toBe(false);
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.symbol_count).toBe(42);
+    });
+
+    it("should forward the path filter and return changed symbols", async () => {
+      let received: WhatChangedQuery | undefined;
+      const handler = createWhatChangedHandler(
+        createService({
+          whatChanged: (query) => {
+            received = query;
+            return Promise.resolve(WHAT_CHANGED_RESULT);
+          },
+        })
+      );
+
+      const result = await handler({ repository: "my-project", name: "v1", path: "src" });
+
+      expect(result.isError).toBe(false);
+      expect(received).toEqual({ repository: "my-project", name: "v1", path: "src" });
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.changes[0].symbol).toBe("src/auth.ts::logout");
+    });
+
+    it("should append to the snapshot history", async () => {
+      const handler = createRecordSnapshotHandler(
+        createService({ recordSnapshot: () => Promise.resolve(RECORD_RESULT) })
+      );
+
+      const result = await handler({ repository: "my-project" });
+
+      expect(result.isError).toBe(false);
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.changes).toEqual({ added: 3, removed: 1, modified: 2 });
+      expect(parsed.history_length).toBe(2);
+    });
+
+    it("should forward the history limit with its default", async () => {
+      const received: SnapshotHistoryQuery[] = [];
+      const handler = createSnapshotHistoryHandler(
+        createService({
+          getSnapshotHistory: (query) => {
+            received.push(query);
+            return Promise.resolve(HISTORY_RESULT);
+          },
+        })
+      );
+
+      const result = await handler({ repository: "my-project" });
+      await handler({ repository: "my-project", limit: 10 });
+
+      expect(result.isError).toBe(false);
+      expect(received).toEqual([
+        { repository: "my-project", limit: 50 },
+        { repository: "my-project", limit: 10 },
+      ]);
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.trend.symbol_count_delta).toBe(2);
+    });
+
+    it("should map service errors to an error result", async () => {
+      const handler = createWhatChangedHandler(
+        createService({
+          whatChanged: () =>
+            Promise.reject(new GraphServiceValidationError("Snapshot 'v9' not found")),
+        })
+      );
+
+      const result = await handler({ repository: "my-project", name: "v9" });
+
+      expect(result.isError).toBe(true);
+      expect((result.content[0] as { text: string }).text).toContain("Snapshot 'v9' not found");
+    });
+  });
+});
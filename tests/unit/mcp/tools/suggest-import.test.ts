@@ -0,0 +1,113 @@
+/**
+ * Unit tests for suggest_import MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  suggestImportToolDefinition,
+  createSuggestImportHandler,
+} from "../../../../src/mcp/tools/suggest-import.js";
+import type {
+  ImportSuggestionQuery,
+  ImportSuggestionResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: ImportSuggestionResult = {
+  symbol: "Point",
+  repository: "my-project",
+  from_path: "src/render/canvas.rs",
+  from_module: "crate::render::canvas",
+  suggestions: [
+    {
+      symbol: {
+        id: "Class:my-project:src/geometry/shapes.rs:Point:3",
+        name: "Point",
+        entity_type: "class",
+        file_path: "src/geometry/shapes.rs",
+        start_line: 3,
+        end_line: 6,
+      },
+      qualified_name: "crate::geometry::shapes::Point",
+      visibility: "pub",
+      status: "importable",
+      imports: [
+        {
+          name: "Point",
+          path: "crate::Point",
+          use_statement: "use crate::Point;",
+          via_reexport: "src/lib.rs",
+        },
+      ],
+      reason: null,
+    },
+  ],
+  metadata: {
+    total_count: 1,
+    query_time_ms: 2,
+  },
+};
+
+describe("suggest_import MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require a symbol, repository and file", () => {
+    expect(suggestImportToolDefinition.name).toBe("suggest_import");
+    expect(suggestImportToolDefinition.inputSchema.required).toEqual([
+      "symbol",
+      "repository",
+      "from_path",
+    ]);
+  });
+
+  it("should forward the query and return the use declarations", async () => {
+    let received: ImportSuggestionQuery | undefined;
+    const handler = createSuggestImportHandler(
+      createMockGraphAnalysisService({
+        suggestImport: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({
+      symbol: "Point",
+      repository: "my-project",
+      from_path: "src/render/canvas.rs",
+    });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({
+      symbol: "Point",
+      repository: "my-project",
+      from_path: "src/render/canvas.rs",
+    });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.suggestions[0].imports[0].use_statement).toBe("use crate::Point;");
+  });
+
+  it("should reject files that are not Rust sources", async () => {
+    const handler = createSuggestImportHandler(createMockGraphAnalysisService({}));
+
+    const result = await handler({
+      symbol: "Point",
+      repository: "my-project",
+      from_path: "src/render/canvas.ts",
+    });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain(
+      "File path must be a Rust source file"
+    );
+  });
+});
@@ -0,0 +1,103 @@
+/**
+ * Unit tests for symbols_at_lines MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  symbolsAtLinesToolDefinition,
+  createSymbolsAtLinesHandler,
+} from "../../../../src/mcp/tools/symbols-at-lines.js";
+import type {
+  SymbolsAtLinesQuery,
+  SymbolsAtLinesResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: SymbolsAtLinesResult = {
+  repository: "my-project",
+  file_path: "src/geometry.rs",
+  start_line: 14,
+  end_line: 14,
+  symbols: [
+    {
+      id: "Function:my-project:src/geometry.rs:distance:12",
+      name: "distance",
+      entity_type: "method",
+      start_line: 12,
+      end_line: 16,
+      parent_name: "Point",
+      covers_range: true,
+    },
+    {
+      id: "Impl:my-project:src/geometry.rs:10",
+      name: "impl Point",
+      entity_type: "impl",
+      start_line: 10,
+      end_line: 30,
+      parent_name: null,
+      covers_range: true,
+    },
+  ],
+  metadata: {
+    symbol_count: 2,
+    query_time_ms: 3,
+  },
+};
+
+describe("symbols_at_lines MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require a repository, path and start line", () => {
+    expect(symbolsAtLinesToolDefinition.name).toBe("symbols_at_lines");
+    expect(symbolsAtLinesToolDefinition.inputSchema.required).toEqual([
+      "repository",
+      "path",
+      "start",
+    ]);
+  });
+
+  it("should forward the line range and return symbols innermost first", async () => {
+    let received: SymbolsAtLinesQuery | undefined;
+    const handler = createSymbolsAtLinesHandler(
+      createMockGraphAnalysisService({
+        getSymbolsAtLines: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ repository: "my-project", path: "src/geometry.rs", start: 14 });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({ repository: "my-project", path: "src/geometry.rs", start_line: 14 });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.symbols.map((s: { name: string }) => s.name)).toEqual([
+      "distance",
+      "impl Point",
+    ]);
+  });
+
+  it("should reject a range that ends before it starts", async () => {
+    const handler = createSymbolsAtLinesHandler(createMockGraphAnalysisService({}));
+
+    const result = await handler({
+      repository: "my-project",
+      path: "src/geometry.rs",
+      start: 20,
+      end: 10,
+    });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain("start must not exceed end");
+  });
+});
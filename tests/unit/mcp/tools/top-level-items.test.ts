@@ -0,0 +1,113 @@
+/**
+ * Unit tests for top_level_items MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  topLevelItemsToolDefinition,
+  createTopLevelItemsHandler,
+} from "../../../../src/mcp/tools/top-level-items.js";
+import { validateTopLevelItemsArgs } from "../../../../src/mcp/validation.js";
+import type { TopLevelSymbolsResult } from "../../../../src/services/graph-analysis-types.js";
+import { EntityNotFoundError } from "../../../../src/services/graph-service-errors.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: TopLevelSymbolsResult = {
+  repository: "my-project",
+  path: "src/auth.ts",
+  files: [
+    {
+      file_path: "src/auth.ts",
+      symbols: [
+        {
+          id: "Class:my-project:src/auth.ts:AuthService:1",
+          name: "AuthService",
+          entity_type: "class",
+          file_path: "src/auth.ts",
+          start_line: 1,
+          end_line: 30,
+          children: [
+            {
+              id: "Function:my-project:src/auth.ts:login:5",
+              name: "login",
+              entity_type: "method",
+              file_path: "src/auth.ts",
+              start_line: 5,
+              end_line: 12,
+            },
+          ],
+        },
+      ],
+    },
+  ],
+  metadata: { file_count: 1, symbol_count: 1, nested_omitted: 0, query_time_ms: 3 },
+};
+
+describe("top_level_items MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  describe("Tool Definition", () => {
+    it("should have correct tool name", () => {
+      expect(topLevelItemsToolDefinition.name).toBe("top_level_items");
+    });
+
+    it("should require repository and path", () => {
+      expect(topLevelItemsToolDefinition.inputSchema.required).toEqual(["repository", "path"]);
+    });
+  });
+
+  describe("Argument Validation", () => {
+    it("should default expand to false", () => {
+      const validated = validateTopLevelItemsArgs({ repository: "r", path: "src" });
+
+      expect(validated.expand).toBe(false);
+    });
+
+    it("should reject empty path", () => {
+      expect(() => validateTopLevelItemsArgs({ repository: "r", path: " " })).toThrow(
+        /Path cannot be empty/
+      );
+    });
+  });
+
+  describe("Handler Execution", () => {
+    it("should return items with expanded children", async () => {
+      const handler = createTopLevelItemsHandler(
+        createMockGraphAnalysisService({
+          getTopLevelSymbols: () => Promise.resolve(SAMPLE_RESULT),
+        })
+      );
+
+      const result = await handler({ repository: "my-project", path: "src/auth.ts", expand: true });
+
+      expect(result.isError).toBe(false);
+      const parsed = JSON.parse((result.content[0] as { text: string }).text);
+      expect(parsed.files[0].items[0].name).toBe("AuthService");
+      expect(parsed.files[0].items[0].children[0].name).toBe("login");
+      expect(parsed.metadata.symbol_count).toBe(1);
+    });
+
+    it("should return an error result when the path does not exist", async () => {
+      const handler = createTopLevelItemsHandler(
+        createMockGraphAnalysisService({
+          getTopLevelSymbols: () =>
+            Promise.reject(new EntityNotFoundError("file", "src/missing.ts", "my-project")),
+        })
+      );
+
+      const result = await handler({ repository: "my-project", path: "src/missing.ts" });
+
+      expect(result.isError).toBe(true);
+      expect((result.content[0] as { text: string }).text).toContain("Error:");
+    });
+  });
+});
@@ -0,0 +1,103 @@
+/**
+ * Unit tests for topological_order MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  topologicalOrderToolDefinition,
+  createTopologicalOrderHandler,
+} from "../../../../src/mcp/tools/topological-order.js";
+import type {
+  TopologicalOrderQuery,
+  TopologicalOrderResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: TopologicalOrderResult = {
+  repository: "my-project",
+  groups: [
+    {
+      level: 0,
+      cyclic: false,
+      symbols: [
+        {
+          id: "Function:my-project:src/lexer.rs:lex:4",
+          name: "lex",
+          entity_type: "function",
+          file_path: "src/lexer.rs",
+          start_line: 4,
+          end_line: 20,
+        },
+      ],
+    },
+  ],
+  metadata: {
+    symbol_count: 1,
+    group_count: 1,
+    cyclic_group_count: 0,
+    edge_count: 0,
+    truncated: false,
+    query_time_ms: 3,
+  },
+};
+
+describe("topological_order MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require only a repository", () => {
+    expect(topologicalOrderToolDefinition.name).toBe("topological_order");
+    expect(topologicalOrderToolDefinition.inputSchema.required).toEqual(["repository"]);
+  });
+
+  it("should forward the query with defaults and return groups", async () => {
+    let received: TopologicalOrderQuery | undefined;
+    const handler = createTopologicalOrderHandler(
+      createMockGraphAnalysisService({
+        getTopologicalOrder: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ repository: "my-project", path: "src" });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({ repository: "my-project", path: "src", limit: 500 });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.groups).toEqual([
+      {
+        level: 0,
+        cyclic: false,
+        symbols: [
+          {
+            name: "lex",
+            entity_type: "function",
+            file_path: "src/lexer.rs",
+            start_line: 4,
+            end_line: 20,
+          },
+        ],
+      },
+    ]);
+    expect(parsed.metadata.truncated).toBe(false);
+  });
+
+  it("should reject a limit above the maximum", async () => {
+    const handler = createTopologicalOrderHandler(createMockGraphAnalysisService({}));
+
+    const result = await handler({ repository: "my-project", limit: 10000 });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain("Limit cannot exceed 5000");
+  });
+});
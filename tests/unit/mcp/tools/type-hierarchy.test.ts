@@ -0,0 +1,113 @@
+/**
+ * Unit tests for type_hierarchy MCP tool handler
+ *
+ * Uses a mock GraphAnalysisService to isolate the MCP layer from the graph database.
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import {
+  typeHierarchyToolDefinition,
+  createTypeHierarchyHandler,
+} from "../../../../src/mcp/tools/type-hierarchy.js";
+import type {
+  TypeHierarchyQuery,
+  TypeHierarchyResult,
+} from "../../../../src/services/graph-analysis-types.js";
+import { EntityNotFoundError } from "../../../../src/services/graph-service-errors.js";
+import { initializeLogger, resetLogger } from "../../../../src/logging/index.js";
+import { createMockGraphAnalysisService } from "../../../helpers/graph-analysis-mock.js";
+
+const SAMPLE_RESULT: TypeHierarchyResult = {
+  type_name: "Circle",
+  repository: "my-project",
+  types: [
+    {
+      id: "Class:my-project:src/shapes.rs:Circle:5",
+      name: "Circle",
+      entity_type: "class",
+      file_path: "src/shapes.rs",
+      start_line: 5,
+      end_line: 9,
+      depth: 0,
+      superclass: null,
+      interfaces: [],
+      traits: [
+        {
+          name: "Shape",
+          definition_ids: ["Class:my-project:src/shapes.rs:Shape:1"],
+          file_path: "src/shapes.rs",
+          line: 20,
+          conditions: [],
+          negative: false,
+        },
+      ],
+      supertraits: [],
+      derives: ["Debug", "Clone"],
+      generic_bounds: [{ name: "T", bounds: ["Float"] }],
+    },
+  ],
+  metadata: {
+    definition_count: 1,
+    supertype_count: 0,
+    max_depth: 3,
+    truncated: false,
+    query_time_ms: 2,
+  },
+};
+
+describe("type_hierarchy MCP Tool", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  it("should require type_name and repository", () => {
+    expect(typeHierarchyToolDefinition.name).toBe("type_hierarchy");
+    expect(typeHierarchyToolDefinition.inputSchema.required).toEqual(["type_name", "repository"]);
+  });
+
+  it("should forward the query with defaults and return the hierarchy", async () => {
+    let received: TypeHierarchyQuery | undefined;
+    const handler = createTypeHierarchyHandler(
+      createMockGraphAnalysisService({
+        getTypeHierarchy: (query) => {
+          received = query;
+          return Promise.resolve(SAMPLE_RESULT);
+        },
+      })
+    );
+
+    const result = await handler({ type_name: "Circle", repository: "my-project" });
+
+    expect(result.isError).toBe(false);
+    expect(received).toEqual({ type_name: "Circle", repository: "my-project", max_depth: 3 });
+    const parsed = JSON.parse((result.content[0] as { text: string }).text);
+    expect(parsed.types[0].derives).toEqual(["Debug", "Clone"]);
+    expect(parsed.types[0].traits[0].name).toBe("Shape");
+  });
+
+  it("should reject a depth above the cap", async () => {
+    const handler = createTypeHierarchyHandler(createMockGraphAnalysisService());
+
+    const result = await handler({ type_name: "Circle", repository: "my-project", max_depth: 20 });
+
+    expect(result.isError).toBe(true);
+    expect((result.content[0] as { text: string }).text).toContain("Max depth cannot exceed 10");
+  });
+
+  it("should return an error result when the type is unknown", async () => {
+    const handler = createTypeHierarchyHandler(
+      createMockGraphAnalysisService({
+        getTypeHierarchy: () =>
+          Promise.reject(new EntityNotFoundError("type", "Missing", "my-project")),
+      })
+    );
+
+    const result = await handler({ type_name: "Missing", repository: "my-project" });
+
+    expect(result.isError).toBe(true);
+  });
+});
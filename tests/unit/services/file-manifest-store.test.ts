@@ -18,7 +18,10 @@ import * as fs from "node:fs";
 import { initializeLogger, resetLogger } from "../../../src/logging/index.js";
 import {
   FileManifestStoreImpl,
+  FileManifestCorruptError,
   FILE_MANIFEST_EMPTY_GENERATED_AT,
+  FILE_MANIFEST_COMPACTION_THRESHOLD,
+  diffManifestFiles,
   type FileManifest,
   type FileManifestEntry,
 } from "../../../src/services/file-manifest-store.js";
@@ -193,6 +196,116 @@ describe("FileManifestStoreImpl", () => {
     });
   });
 
+  describe("Checksum verification", () => {
+    /** Save a manifest, then return its path and parsed on-disk JSON. */
+    async function savedPayload(
+      store: FileManifestStoreImpl,
+      repository: string
+    ): Promise<{ filePath: string; payload: Record<string, any> }> {
+      await store.saveManifest(
+        repository,
+        buildManifest(repository, {
+          "a.ts": { sha256: "a".repeat(64), sizeBytes: 10, mtimeMs: 1 },
+        })
+      );
+      const filePath = store.getManifestPath(repository);
+      return { filePath, payload: JSON.parse(fs.readFileSync(filePath, "utf-8")) };
+    }
+
+    it("writes a sha256 checksum alongside the payload", async () => {
+      const store = FileManifestStoreImpl.getInstance(tmpDir);
+      const { payload } = await savedPayload(store, "checked");
+
+      expect(payload.checksum).toMatch(/^[0-9a-f]{64}$/);
+    });
+
+    it("does not expose the checksum on the loaded manifest", async () => {
+      const store = FileManifestStoreImpl.getInstance(tmpDir);
+      await savedPayload(store, "hidden");
+
+      FileManifestStoreImpl.resetInstance();
+      const reloaded = await FileManifestStoreImpl.getInstance(tmpDir).loadManifest("hidden");
+      expect(Object.keys(reloaded).sort()).toEqual([
+        "files",
+        "generatedAt",
+        "repository",
+        "version",
+      ]);
+    });
+
+    it("throws FileManifestCorruptError when the content no longer matches the checksum", async () => {
+      const store = FileManifestStoreImpl.getInstance(tmpDir);
+      const { filePath, payload } = await savedPayload(store, "tampered");
+      payload.files["a.ts"].sizeBytes = 11;
+      fs.writeFileSync(filePath, JSON.stringify(payload), "utf-8");
+
+      FileManifestStoreImpl.resetInstance();
+      const store2 = FileManifestStoreImpl.getInstance(tmpDir);
+      const error = await store2.loadManifest("tampered").catch((e: unknown) => e);
+      expect(error).toBeInstanceOf(FileManifestCorruptError);
+      expect((error as FileManifestCorruptError).repository).toBe("tampered");
+      expect((error as FileManifestCorruptError).message).toContain("checksum mismatch");
+    });
+
+    it("throws FileManifestCorruptError for a truncated file", async () => {
+      const store = FileManifestStoreImpl.getInstance(tmpDir);
+      const { filePath } = await savedPayload(store, "truncated");
+      const content = fs.readFileSync(filePath, "utf-8");
+      fs.writeFileSync(filePath, content.slice(0, content.length / 2), "utf-8");
+
+      FileManifestStoreImpl.resetInstance();
+      await expect(
+        FileManifestStoreImpl.getInstance(tmpDir).loadManifest("truncated")
+      ).rejects.toBeInstanceOf(FileManifestCorruptError);
+    });
+
+    it("loads legacy manifests written without a checksum", async () => {
+      const store = FileManifestStoreImpl.getInstance(tmpDir);
+      const filePath = store.getManifestPath("legacy");
+      fs.mkdirSync(path.dirname(filePath), { recursive: true });
+      fs.writeFileSync(
+        filePath,
+        JSON.stringify(
+          buildManifest("legacy", {
+            "b.ts": { sha256: "b".repeat(64), sizeBytes: 2, mtimeMs: 2 },
+          })
+        ),
+        "utf-8"
+      );
+
+      const manifest = await store.loadManifest("legacy");
+      expect(manifest.files["b.ts"]?.sizeBytes).toBe(2);
+    });
+
+    it("loadManifestOrReset discards a corrupt manifest and returns an empty one", async () => {
+      const store = FileManifestStoreImpl.getInstance(tmpDir);
+      const { filePath, payload } = await savedPayload(store, "reset");
+      payload.generatedAt = "2000-01-01T00:00:00.000Z";
+      fs.writeFileSync(filePath, JSON.stringify(payload), "utf-8");
+
+      FileManifestStoreImpl.resetInstance();
+      const store2 = FileManifestStoreImpl.getInstance(tmpDir);
+      const { manifest, wasReset } = await store2.loadManifestOrReset("reset");
+
+      expect(wasReset).toBe(true);
+      expect(manifest.files).toEqual({});
+      expect(manifest.generatedAt).toBe(FILE_MANIFEST_EMPTY_GENERATED_AT);
+      expect(fs.existsSync(filePath)).toBe(false);
+    });
+
+    it("loadManifestOrReset returns intact manifests unchanged", async () => {
+      const store = FileManifestStoreImpl.getInstance(tmpDir);
+      await savedPayload(store, "intact");
+
+      FileManifestStoreImpl.resetInstance();
+      const { manifest, wasReset } = await FileManifestStoreImpl.getInstance(
+        tmpDir
+      ).loadManifestOrReset("intact");
+      expect(wasReset).toBe(false);
+      expect(manifest.files["a.ts"]?.sizeBytes).toBe(10);
+    });
+  });
+
   describe("saveManifest", () => {
     it("auto-creates the manifests/ directory and writes a JSON file", async () => {
       const store = FileManifestStoreImpl.getInstance(tmpDir);
@@ -485,6 +598,246 @@ describe("FileManifestStoreImpl", () => {
       fs.rmdirSync(filePath);
     });
   });
+
+  describe("Update log", () => {
+    const entry = (c: string, mtimeMs = 1): FileManifestEntry => ({
+      sha256: c.repeat(64),
+      sizeBytes: 1,
+      mtimeMs,
+    });
+
+    it("appends updates to the log without rewriting the snapshot", async () => {
+      const store = FileManifestStoreImpl.getInstance(tmpDir);
+      await store.saveManifest("log-repo", buildManifest("log-repo", { "a.ts": entry("a") }));
+      const snapshotBefore = fs.readFileSync(store.getManifestPath("log-repo"), "utf-8");
+
+      await store.appendUpdates(
+        "log-repo",
+        [
+          { path: "b.ts", entry: entry("b") },
+          { path: "a.ts", entry: null },
+        ],
+        "2026-06-01T00:00:00.000Z"
+      );
+
+      expect(fs.readFileSync(store.getManifestPath("log-repo"), "utf-8")).toBe(snapshotBefore);
+      const lines = fs.readFileSync(store.getLogPath("log-repo"), "utf-8").split("\n");
+      expect(lines).toHaveLength(3);
+      expect(lines[2]).toBe("");
+      expect(JSON.parse(lines[0] ?? "").seq).toBe(1);
+      expect(JSON.parse(lines[1] ?? "").seq).toBe(2);
+    });
+
+    it("replays the log over the snapshot after a restart", async () => {
+      const store = FileManifestStoreImpl.getInstance(tmpDir);
+      await store.saveManifest("log-repo", buildManifest("log-repo", { "a.ts": entry("a") }));
+      await store.appendUpdates(
+        "log-repo",
+        [{ path: "b.ts", entry: entry("b") }],
+        "2026-06-01T00:00:00.000Z"
+      );
+      await store.appendUpdates(
+        "log-repo",
+        [
+          { path: "a.ts", entry: null },
+          { path: "b.ts", entry: entry("b", 2) },
+        ],
+        "2026-06-02T00:00:00.000Z"
+      );
+
+      FileManifestStoreImpl.resetInstance();
+      const reloaded = await FileManifestStoreImpl.getInstance(tmpDir).loadManifest("log-repo");
+
+      expect(reloaded).toEqual({
+        version: "1.0",
+        repository: "log-repo",
+        generatedAt: "2026-06-02T00:00:00.000Z",
+        files: { "b.ts": entry("b", 2) },
+      });
+    });
+
+    it("rebuilds a manifest from the log alone when no snapshot exists", async () => {
+      const store = FileManifestStoreImpl.getInstance(tmpDir);
+      await store.appendUpdates(
+        "log-only",
+        [{ path: "a.ts", entry: entry("a") }],
+        "2026-06-01T00:00:00.000Z"
+      );
+
+      expect(fs.existsSync(store.getManifestPath("log-only"))).toBe(false);
+      FileManifestStoreImpl.resetInstance();
+      const reloaded = FileManifestStoreImpl.getInstance(tmpDir);
+      expect((await reloaded.loadManifest("log-only")).files).toEqual({ "a.ts": entry("a") });
+      expect(await reloaded.listManifests()).toEqual(["log-only"]);
+    });
+
+    it("discards a torn trailing record and overwrites it on the next append", async () => {
+      const store = FileManifestStoreImpl.getInstance(tmpDir);
+      await store.appendUpdates(
+        "torn",
+        [{ path: "a.ts", entry: entry("a") }],
+        "2026-06-01T00:00:00.000Z"
+      );
+      const logPath = store.getLogPath("torn");
+      fs.appendFileSync(logPath, '{"seq":2,"repository":"torn","genera');
+
+      FileManifestStoreImpl.resetInstance();
+      const recovered = FileManifestStoreImpl.getInstance(tmpDir);
+      const manifest = await recovered.loadManifest("torn");
+      expect(manifest.files).toEqual({ "a.ts": entry("a") });
+      expect(manifest.generatedAt).toBe("2026-06-01T00:00:00.000Z");
+
+      await recovered.appendUpdates(
+        "torn",
+        [{ path: "b.ts", entry: entry("b") }],
+        "2026-06-02T00:00:00.000Z"
+      );
+      const lines = fs.readFileSync(logPath, "utf-8").trimEnd().split("\n");
+      expect(lines.map((line) => JSON.parse(line).seq)).toEqual([1, 2]);
+
+      FileManifestStoreImpl.resetInstance();
+      const again = await FileManifestStoreImpl.getInstance(tmpDir).loadManifest("torn");
+      expect(Object.keys(again.files)).toEqual(["a.ts", "b.ts"]);
+    });
+
+    it("throws FileManifestCorruptError for a damaged record before the end of the log", async () => {
+      const store = FileManifestStoreImpl.getInstance(tmpDir);
+      await store.appendUpdates(
+        "damaged",
+        [
+          { path: "a.ts", entry: entry("a") },
+          { path: "b.ts", entry: entry("b") },
+        ],
+        "2026-06-01T00:00:00.000Z"
+      );
+      const logPath = store.getLogPath("damaged");
+      const content = fs.readFileSync(logPath, "utf-8");
+      fs.writeFileSync(logPath, content.replace('"path":"a.ts"', '"path":"z.ts"'));
+
+      FileManifestStoreImpl.resetInstance();
+      const reloaded = FileManifestStoreImpl.getInstance(tmpDir);
+      const error = await reloaded.loadManifest("damaged").catch((e: unknown) => e);
+      expect(error).toBeInstanceOf(FileManifestCorruptError);
+      expect((error as FileManifestCorruptError).filePath).toBe(logPath);
+    });
+
+    it("compacts the log into the snapshot", async () => {
+      const store = FileManifestStoreImpl.getInstance(tmpDir);
+      await store.appendUpdates(
+        "compact",
+        [{ path: "a.ts", entry: entry("a") }],
+        "2026-06-01T00:00:00.000Z"
+      );
+
+      await store.compactManifest("compact");
+
+      expect(fs.existsSync(store.getLogPath("compact"))).toBe(false);
+      const snapshot = JSON.parse(fs.readFileSync(store.getManifestPath("compact"), "utf-8"));
+      expect(snapshot.logSequence).toBe(1);
+      expect(snapshot.files).toEqual({ "a.ts": entry("a") });
+
+      FileManifestStoreImpl.resetInstance();
+      const reloaded = FileManifestStoreImpl.getInstance(tmpDir);
+      expect((await reloaded.loadManifest("compact")).files).toEqual({ "a.ts": entry("a") });
+      await reloaded.appendUpdates(
+        "compact",
+        [{ path: "b.ts", entry: entry("b") }],
+        "2026-06-02T00:00:00.000Z"
+      );
+      const record = JSON.parse(fs.readFileSync(reloaded.getLogPath("compact"), "utf-8"));
+      expect(record.seq).toBe(2);
+    });
+
+    it("compacts automatically once the log reaches the threshold", async () => {
+      const store = FileManifestStoreImpl.getInstance(tmpDir);
+      const updates = Array.from({ length: FILE_MANIFEST_COMPACTION_THRESHOLD }, (_, i) => ({
+        path: `f${i}.ts`,
+        entry: entry("a"),
+      }));
+
+      await store.appendUpdates("auto", updates, "2026-06-01T00:00:00.000Z");
+
+      expect(fs.existsSync(store.getLogPath("auto"))).toBe(false);
+      const snapshot = JSON.parse(fs.readFileSync(store.getManifestPath("auto"), "utf-8"));
+      expect(Object.keys(snapshot.files)).toHaveLength(FILE_MANIFEST_COMPACTION_THRESHOLD);
+    });
+
+    it("skips records already compacted when the log outlived a compaction", async () => {
+      const store = FileManifestStoreImpl.getInstance(tmpDir);
+      await store.appendUpdates(
+        "crash",
+        [{ path: "a.ts", entry: entry("a") }],
+        "2026-06-01T00:00:00.000Z"
+      );
+      const logPath = store.getLogPath("crash");
+      const staleLog = fs.readFileSync(logPath, "utf-8");
+      await store.appendUpdates(
+        "crash",
+        [{ path: "a.ts", entry: null }],
+        "2026-06-02T00:00:00.000Z"
+      );
+      await store.compactManifest("crash");
+      // Simulate a crash between writing the snapshot and removing the log
+      fs.writeFileSync(logPath, staleLog);
+
+      FileManifestStoreImpl.resetInstance();
+      const reloaded = await FileManifestStoreImpl.getInstance(tmpDir).loadManifest("crash");
+      expect(reloaded.files).toEqual({});
+      expect(reloaded.generatedAt).toBe("2026-06-02T00:00:00.000Z");
+    });
+
+    it("saveManifest supersedes the log", async () => {
+      const store = FileManifestStoreImpl.getInstance(tmpDir);
+      await store.appendUpdates(
+        "resave",
+        [{ path: "a.ts", entry: entry("a") }],
+        "2026-06-01T00:00:00.000Z"
+      );
+
+      await store.saveManifest("resave", buildManifest("resave", { "b.ts": entry("b") }));
+
+      expect(fs.existsSync(store.getLogPath("resave"))).toBe(false);
+      FileManifestStoreImpl.resetInstance();
+      const reloaded = await FileManifestStoreImpl.getInstance(tmpDir).loadManifest("resave");
+      expect(reloaded.files).toEqual({ "b.ts": entry("b") });
+    });
+
+    it("deleteManifest removes the log as well", async () => {
+      const store = FileManifestStoreImpl.getInstance(tmpDir);
+      await store.appendUpdates(
+        "gone",
+        [{ path: "a.ts", entry: entry("a") }],
+        "2026-06-01T00:00:00.000Z"
+      );
+
+      await store.deleteManifest("gone");
+
+      expect(fs.existsSync(store.getLogPath("gone"))).toBe(false);
+      expect((await store.loadManifest("gone")).files).toEqual({});
+    });
+  });
+});
+
+describe("diffManifestFiles", () => {
+  const a: FileManifestEntry = { sha256: "a".repeat(64), sizeBytes: 1, mtimeMs: 1 };
+  const b: FileManifestEntry = { sha256: "b".repeat(64), sizeBytes: 1, mtimeMs: 1 };
+
+  it("lists added, changed and removed paths", () => {
+    const prior = { "same.ts": a, "changed.ts": a, "removed.ts": a };
+    const next = { "same.ts": a, "changed.ts": b, "added.ts": a };
+
+    expect(diffManifestFiles(prior, next)).toEqual([
+      { path: "changed.ts", entry: b },
+      { path: "added.ts", entry: a },
+      { path: "removed.ts", entry: null },
+    ]);
+  });
+
+  it("treats an mtime-only change as an update", () => {
+    expect(diffManifestFiles({ "a.ts": a }, { "a.ts": { ...a, mtimeMs: 2 } })).toEqual([
+      { path: "a.ts", entry: { ...a, mtimeMs: 2 } },
+    ]);
+  });
 });
 
 function buildManifest(repository: string, files: Record<string, FileManifestEntry>): FileManifest {
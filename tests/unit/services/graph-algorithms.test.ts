@@ -0,0 +1,165 @@
+/**
+ * Unit tests for graph algorithms used by GraphAnalysisService
+ */
+/* eslint-disable @typescript-eslint/no-non-null-assertion */
+
+import { describe, test, expect } from "bun:test";
+import {
+  findStronglyConnectedComponents,
+  findShortestPaths,
+  findTopologicalOrder,
+  type AdjacencyMap,
+} from "../../../src/services/graph-algorithms.js";
+
+function graphOf(edges: Array<[string, string]>, extraNodes: string[] = []): AdjacencyMap {
+  const graph: AdjacencyMap = new Map();
+  for (const node of extraNodes) graph.set(node, new Set());
+  for (const [from, to] of edges) {
+    if (!graph.has(from)) graph.set(from, new Set());
+    if (!graph.has(to)) graph.set(to, new Set());
+    graph.get(from)!.add(to);
+  }
+  return graph;
+}
+
+describe("findStronglyConnectedComponents", () => {
+  test("returns singleton components for an acyclic graph", () => {
+    const components = findStronglyConnectedComponents(
+      graphOf([
+        ["a", "b"],
+        ["b", "c"],
+      ])
+    );
+
+    expect(components).toHaveLength(3);
+    expect(components.every((c) => c.length === 1)).toBe(true);
+  });
+
+  test("groups nodes of each cycle together", () => {
+    const components = findStronglyConnectedComponents(
+      graphOf(
+        [
+          ["a", "b"],
+          ["b", "a"],
+          ["b", "c"],
+          ["c", "d"],
+          ["d", "e"],
+          ["e", "c"],
+        ],
+        ["isolated"]
+      )
+    );
+
+    const sorted = components.map((c) => [...c].sort()).sort((x, y) => y.length - x.length);
+    expect(sorted[0]).toEqual(["c", "d", "e"]);
+    expect(sorted[1]).toEqual(["a", "b"]);
+    expect(sorted[2]).toEqual(["isolated"]);
+  });
+
+  test("handles long chains without recursion", () => {
+    const edges: Array<[string, string]> = [];
+    for (let i = 0; i < 20_000; i++) {
+      edges.push([`n${i}`, `n${i + 1}`]);
+    }
+    edges.push(["n20000", "n0"]);
+
+    const components = findStronglyConnectedComponents(graphOf(edges));
+
+    expect(components).toHaveLength(1);
+    expect(components[0]).toHaveLength(20_001);
+  });
+});
+
+describe("findShortestPaths", () => {
+  // a -> b -> d -> e and a -> c -> d -> e, plus a longer a -> x -> y -> z -> e
+  const graph = graphOf([
+    ["a", "b"],
+    ["a", "c"],
+    ["b", "d"],
+    ["c", "d"],
+    ["d", "e"],
+    ["a", "x"],
+    ["x", "y"],
+    ["y", "z"],
+    ["z", "e"],
+  ]);
+
+  test("returns every shortest path and skips longer ones", () => {
+    const result = findShortestPaths(graph, ["a"], new Set(["e"]), 10, 10);
+
+    expect(result.paths).toEqual([
+      ["a", "b", "d", "e"],
+      ["a", "c", "d", "e"],
+    ]);
+    expect(result.truncated).toBe(false);
+  });
+
+  test("flags truncation when more paths exist than the limit", () => {
+    const result = findShortestPaths(graph, ["a"], new Set(["e"]), 10, 1);
+
+    expect(result.paths).toEqual([["a", "b", "d", "e"]]);
+    expect(result.truncated).toBe(true);
+  });
+
+  test("follows edge direction and respects the depth cap", () => {
+    expect(findShortestPaths(graph, ["e"], new Set(["a"]), 10, 10).paths).toEqual([]);
+    expect(findShortestPaths(graph, ["a"], new Set(["e"]), 2, 10).paths).toEqual([]);
+  });
+
+  test("matches a node that is both source and target only through a cycle", () => {
+    const cyclic = graphOf([
+      ["f", "g"],
+      ["g", "f"],
+    ]);
+
+    expect(findShortestPaths(cyclic, ["f"], new Set(["f"]), 10, 10).paths).toEqual([
+      ["f", "g", "f"],
+    ]);
+  });
+});
+
+describe("findTopologicalOrder", () => {
+  test("places successors before the nodes pointing at them, level by level", () => {
+    const order = findTopologicalOrder(
+      graphOf([
+        ["main", "parse"],
+        ["main", "run"],
+        ["run", "parse"],
+        ["parse", "lex"],
+      ])
+    );
+
+    expect(order).toEqual([
+      { nodes: ["lex"], level: 0 },
+      { nodes: ["parse"], level: 1 },
+      { nodes: ["run"], level: 2 },
+      { nodes: ["main"], level: 3 },
+    ]);
+  });
+
+  test("groups cycles and orders independent groups deterministically", () => {
+    const edges: Array<[string, string]> = [
+      ["even", "odd"],
+      ["odd", "even"],
+      ["odd", "base"],
+      ["check", "even"],
+    ];
+    const reversed = graphOf([...edges].reverse(), ["alone"]);
+
+    const order = findTopologicalOrder(graphOf(edges, ["alone"]));
+
+    expect(order).toEqual([
+      { nodes: ["alone"], level: 0 },
+      { nodes: ["base"], level: 0 },
+      { nodes: ["even", "odd"], level: 1 },
+      { nodes: ["check"], level: 2 },
+    ]);
+    expect(findTopologicalOrder(reversed)).toEqual(order);
+  });
+
+  test("orders nodes with the given comparator", () => {
+    const order = findTopologicalOrder(graphOf([], ["a", "b", "c"]), (x, y) => y.localeCompare(x));
+
+    expect(order.map((group) => group.nodes[0])).toEqual(["c", "b", "a"]);
+  });
+});
@@ -0,0 +1,3477 @@
+/**
+ * Unit tests for GraphAnalysisServiceImpl
+ *
+ * Exercises the Cypher-backed analysis queries against a mocked graph adapter
+ * that answers based on the query text.
+ */
+/* eslint-disable @typescript-eslint/no-non-null-assertion */
+
+import { describe, test, expect, beforeEach, afterEach } from "bun:test";
+
+import { GraphAnalysisServiceImpl } from "../../../src/services/graph-analysis-service.js";
+import {
+  GraphServiceValidationError,
+  GraphServiceOperationError,
+  GraphServiceCancelledError,
+  EntityNotFoundError,
+} from "../../../src/services/graph-service-errors.js";
+import { initializeLogger, resetLogger } from "../../../src/logging/index.js";
+import { createCypherMockAdapter } from "../../helpers/graph-analysis-mock.js";
+
+// =============================================================================
+// Test Fixtures
+// =============================================================================
+
+const SYMBOL_ROW = {
+  id: "Function:test-repo:src/auth.ts:validateToken:10",
+  name: "validateToken",
+  entityType: "function",
+  filePath: "src/auth.ts",
+  startLine: 10,
+  endLine: 25,
+};
+
+const IMPORT_ROWS = [
+  {
+    module: "jsonwebtoken",
+    moduleType: "npm",
+    importType: "default",
+    importedSymbols: [],
+    aliases: ["default as jwt"],
+    resolvedPath: null,
+    line: 1,
+  },
+  {
+    module: "./config",
+    moduleType: "local",
+    importType: "named",
+    importedSymbols: ["secret", "issuer"],
+    aliases: ["issuer as iss"],
+    resolvedPath: "src/config",
+    line: 2,
+  },
+];
+
+describe("GraphAnalysisServiceImpl", () => {
+  beforeEach(() => {
+    initializeLogger({ level: "silent", format: "json" });
+  });
+
+  afterEach(() => {
+    resetLogger();
+  });
+
+  describe("getSymbolImports", () => {
+    test("returns the imports of the symbol's file", async () => {
+      const adapter = createCypherMockAdapter((cypher) => {
+        if (cypher.includes("DEFINES")) return [SYMBOL_ROW];
+        if (cypher.includes("IMPORTS")) return IMPORT_ROWS;
+        return [];
+      });
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.getSymbolImports({
+        symbol: "validateToken",
+        repository: "test-repo",
+      });
+
+      expect(result.matches).toHaveLength(1);
+      const match = result.matches[0]!;
+      expect(match.symbol.file_path).toBe("src/auth.ts");
+      expect(match.symbol.start_line).toBe(10);
+      expect(match.imports).toHaveLength(2);
+      expect(match.imports[0]).toEqual({
+        module: "jsonwebtoken",
+        is_external: true,
+        resolved_path: null,
+        import_type: "default",
+        imported_symbols: [],
+        aliases: [{ original: "default", alias: "jwt" }],
+        line: 1,
+      });
+      expect(match.imports[1]!.is_external).toBe(false);
+      expect(match.imports[1]!.resolved_path).toBe("src/config");
+      expect(result.metadata.match_count).toBe(1);
+    });
+
+    test("parses file-qualified references into name and file filter", async () => {
+      const adapter = createCypherMockAdapter((cypher) =>
+        cypher.includes("DEFINES") ? [SYMBOL_ROW] : []
+      );
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      await service.getSymbolImports({
+        symbol: "src/auth.ts::validateToken",
+        repository: "test-repo",
+      });
+
+      const [, params] = adapter.runQuery.mock.calls[0] as [string, Record<string, unknown>];
+      expect(params["name"]).toBe("validateToken");
+      expect(params["filePath"]).toBe("src/auth.ts");
+    });
+
+    test("uses the last segment of language-qualified names", async () => {
+      const adapter = createCypherMockAdapter((cypher) =>
+        cypher.includes("DEFINES") ? [SYMBOL_ROW] : []
+      );
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      await service.getSymbolImports({ symbol: "crate::auth::validate", repository: "test-repo" });
+
+      const [, params] = adapter.runQuery.mock.calls[0] as [string, Record<string, unknown>];
+      expect(params["name"]).toBe("validate");
+      expect(params["filePath"]).toBe("");
+    });
+
+    test("matches graph node IDs exactly", async () => {
+      const adapter = createCypherMockAdapter((cypher) =>
+        cypher.includes("DEFINES") ? [SYMBOL_ROW] : []
+      );
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      await service.getSymbolImports({ symbol: SYMBOL_ROW.id, repository: "test-repo" });
+
+      const [cypher, params] = adapter.runQuery.mock.calls[0] as [string, Record<string, unknown>];
+      expect(cypher).toContain("e.id = $id");
+      expect(params["id"]).toBe(SYMBOL_ROW.id);
+    });
+
+    test("fetches imports once per file for multiple definitions", async () => {
+      const adapter = createCypherMockAdapter((cypher) => {
+        if (cypher.includes("DEFINES")) {
+          return [SYMBOL_ROW, { ...SYMBOL_ROW, id: "other", startLine: 40, endLine: 50 }];
+        }
+        return IMPORT_ROWS;
+      });
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.getSymbolImports({
+        symbol: "validateToken",
+        repository: "test-repo",
+      });
+
+      expect(result.matches).toHaveLength(2);
+      // One symbol lookup + one imports lookup
+      expect(adapter.runQuery).toHaveBeenCalledTimes(2);
+    });
+
+    test("throws EntityNotFoundError when the symbol does not exist", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter());
+
+      await expect(
+        service.getSymbolImports({ symbol: "missing", repository: "test-repo" })
+      ).rejects.toThrow(EntityNotFoundError);
+    });
+
+    test("throws GraphServiceValidationError for empty symbol", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter());
+
+      await expect(
+        service.getSymbolImports({ symbol: "  ", repository: "test-repo" })
+      ).rejects.toThrow(GraphServiceValidationError);
+    });
+
+    test("wraps adapter failures in GraphServiceOperationError", async () => {
+      const adapter = createCypherMockAdapter(() => {
+        throw new Error("connection reset");
+      });
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      await expect(
+        service.getSymbolImports({ symbol: "validateToken", repository: "test-repo" })
+      ).rejects.toThrow(GraphServiceOperationError);
+    });
+  });
+
+  describe("findModuleCycles", () => {
+    const FILE_ROWS = [
+      { path: "src/a.ts" },
+      { path: "src/b.ts" },
+      { path: "src/c.ts" },
+      { path: "src/types.ts" },
+      { path: "src/models.ts" },
+      { path: "src/leaf.ts" },
+      { path: "pkg/__init__.py" },
+      { path: "pkg/mod.py" },
+    ];
+
+    const createService = (importRows: unknown[]): GraphAnalysisServiceImpl =>
+      new GraphAnalysisServiceImpl(
+        createCypherMockAdapter((cypher) => {
+          if (cypher.includes("IMPORTS")) return importRows;
+          if (cypher.includes("RETURN f.path AS path")) return FILE_ROWS;
+          return [];
+        })
+      );
+
+    test("reports multi-file cycles with the edges forming them", async () => {
+      const service = createService([
+        { source: "src/a.ts", module: "./b.js", importType: "named", line: 1 },
+        { source: "src/b.ts", module: "./c", importType: "named", line: 2 },
+        { source: "src/c.ts", module: "./a", importType: "default", line: 3 },
+        { source: "src/c.ts", module: "./leaf", importType: "named", line: 4 },
+      ]);
+
+      const result = await service.findModuleCycles({ repository: "test-repo" });
+
+      expect(result.cycles).toHaveLength(1);
+      const cycle = result.cycles[0]!;
+      expect(cycle.kind).toBe("cycle");
+      expect(cycle.runtime).toBe(true);
+      expect(cycle.files).toEqual(["src/a.ts", "src/b.ts", "src/c.ts"]);
+      expect(cycle.edges.map((e) => `${e.from}->${e.to}`)).toEqual([
+        "src/a.ts->src/b.ts",
+        "src/b.ts->src/c.ts",
+        "src/c.ts->src/a.ts",
+      ]);
+      expect(result.metadata.cycle_count).toBe(1);
+      expect(result.metadata.edges_analyzed).toBe(4);
+    });
+
+    test("separates self-imports and marks type-only mutual references", async () => {
+      const service = createService([
+        { source: "src/types.ts", module: "./models", importType: "type", line: 1 },
+        { source: "src/models.ts", module: "./types", importType: "type", line: 1 },
+        { source: "src/leaf.ts", module: "./leaf", importType: "named", line: 5 },
+      ]);
+
+      const result = await service.findModuleCycles({ repository: "test-repo" });
+
+      expect(result.cycles).toHaveLength(1);
+      expect(result.cycles[0]!.kind).toBe("mutual");
+      expect(result.cycles[0]!.runtime).toBe(false);
+      expect(result.self_imports).toHaveLength(1);
+      expect(result.self_imports[0]!.from).toBe("src/leaf.ts");
+    });
+
+    test("ignores type-only edges when include_type_only is false", async () => {
+      const service = createService([
+        { source: "src/types.ts", module: "./models", importType: "type", line: 1 },
+        { source: "src/models.ts", module: "./types", importType: "named", line: 1 },
+      ]);
+
+      const result = await service.findModuleCycles({
+        repository: "test-repo",
+        include_type_only: false,
+      });
+
+      expect(result.cycles).toHaveLength(0);
+    });
+
+    test("resolves Python relative module specifiers", async () => {
+      const service = createService([
+        { source: "pkg/__init__.py", module: ".mod", importType: "named", line: 1 },
+        { source: "pkg/mod.py", module: ".", importType: "named", line: 1 },
+      ]);
+
+      const result = await service.findModuleCycles({ repository: "test-repo" });
+
+      expect(result.cycles).toHaveLength(1);
+      expect(result.cycles[0]!.files).toEqual(["pkg/__init__.py", "pkg/mod.py"]);
+    });
+
+    test("skips imports that do not resolve to repository files", async () => {
+      const service = createService([
+        { source: "src/a.ts", module: "./missing", importType: "named", line: 1 },
+      ]);
+
+      const result = await service.findModuleCycles({ repository: "test-repo" });
+
+      expect(result.cycles).toHaveLength(0);
+      expect(result.metadata.edges_analyzed).toBe(0);
+    });
+
+    test("applies the limit and reports truncation", async () => {
+      const service = createService([
+        { source: "src/a.ts", module: "./b", importType: "named", line: 1 },
+        { source: "src/b.ts", module: "./a", importType: "named", line: 1 },
+        { source: "src/types.ts", module: "./models", importType: "named", line: 1 },
+        { source: "src/models.ts", module: "./types", importType: "named", line: 1 },
+      ]);
+
+      const result = await service.findModuleCycles({ repository: "test-repo", limit: 1 });
+
+      expect(result.cycles).toHaveLength(1);
+      expect(result.metadata.cycle_count).toBe(2);
+      expect(result.metadata.truncated).toBe(true);
+    });
+
+    test("rejects invalid queries", async () => {
+      const service = createService([]);
+
+      await expect(service.findModuleCycles({ repository: "" })).rejects.toThrow(
+        GraphServiceValidationError
+      );
+    });
+  });
+
+  describe("getTopLevelSymbols", () => {
+    const entity = (
+      name: string,
+      entityType: string,
+      startLine: number,
+      endLine: number,
+      parentName: string | null = null,
+      filePath = "src/shapes.rs"
+    ): Record<string, unknown> => ({
+      id: `Entity:test-repo:${filePath}:${name}:${startLine}`,
+      name,
+      entityType,
+      filePath,
+      startLine,
+      endLine,
+      parentName,
+    });
+
+    const ENTITY_ROWS = [
+      entity("Point", "class", 1, 4),
+      entity("ORIGIN", "variable", 6, 6),
+      // impl Point { fn new() .. fn distance() .. } - outside the struct's range
+      entity("new", "function", 9, 11, "Point"),
+      entity("distance", "function", 13, 17, "Point"),
+      entity("scale", "function", 15, 15, "distance"),
+      // impl Foreign for Point where Foreign is defined elsewhere
+      entity("helper", "function", 20, 22, "ExternalType"),
+    ];
+
+    const createService = (
+      entityRows: unknown[],
+      fileRows: unknown[] = [{ path: "src/shapes.rs" }]
+    ): GraphAnalysisServiceImpl =>
+      new GraphAnalysisServiceImpl(
+        createCypherMockAdapter((cypher) => {
+          if (cypher.includes("DEFINES")) return entityRows;
+          if (cypher.includes("RETURN f.path AS path")) return fileRows;
+          return [];
+        })
+      );
+
+    test("returns only symbols without a parent", async () => {
+      const service = createService(ENTITY_ROWS);
+
+      const result = await service.getTopLevelSymbols({
+        repository: "test-repo",
+        path: "src/shapes.rs",
+      });
+
+      expect(result.files).toHaveLength(1);
+      expect(result.files[0]!.symbols.map((s) => s.name)).toEqual(["Point", "ORIGIN"]);
+      expect(result.files[0]!.symbols[0]!.children).toBeUndefined();
+      expect(result.metadata.symbol_count).toBe(2);
+      expect(result.metadata.nested_omitted).toBe(4);
+    });
+
+    test("expands direct children one level", async () => {
+      const service = createService(ENTITY_ROWS);
+
+      const result = await service.getTopLevelSymbols({
+        repository: "test-repo",
+        path: "src/shapes.rs",
+        expand: true,
+      });
+
+      const point = result.files[0]!.symbols[0]!;
+      expect(point.children!.map((c) => c.name)).toEqual(["new", "distance"]);
+      expect(result.files[0]!.symbols[1]!.children).toEqual([]);
+      // "scale" is a grandchild, "helper" belongs to a type outside the file
+      expect(result.metadata.nested_omitted).toBe(2);
+    });
+
+    test("falls back to line-range nesting when parent links are missing", async () => {
+      const service = createService([
+        entity("AuthService", "class", 1, 30, null, "src/auth.ts"),
+        entity("login", "method", 5, 12, null, "src/auth.ts"),
+        entity("token", "variable", 7, 7, null, "src/auth.ts"),
+        entity("createAuth", "function", 32, 40, null, "src/auth.ts"),
+      ]);
+
+      const result = await service.getTopLevelSymbols({
+        repository: "test-repo",
+        path: "src/auth.ts",
+        expand: true,
+      });
+
+      const symbols = result.files[0]!.symbols;
+      expect(symbols.map((s) => s.name)).toEqual(["AuthService", "createAuth"]);
+      expect(symbols[0]!.children!.map((c) => c.name)).toEqual(["login"]);
+    });
+
+    test("covers every file beneath a directory path", async () => {
+      const adapter = createCypherMockAdapter((cypher) => {
+        if (cypher.includes("DEFINES")) {
+          return [
+            entity("a", "function", 1, 2, null, "src/auth/a.ts"),
+            entity("b", "function", 1, 2, null, "src/auth/b.ts"),
+          ];
+        }
+        return [{ path: "src/auth/a.ts" }, { path: "src/auth/b.ts" }];
+      });
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.getTopLevelSymbols({
+        repository: "test-repo",
+        path: "src/auth/",
+      });
+
+      expect(result.files.map((f) => f.file_path)).toEqual(["src/auth/a.ts", "src/auth/b.ts"]);
+      const [, params] = adapter.runQuery.mock.calls[0] as [string, Record<string, unknown>];
+      expect(params["path"]).toBe("src/auth");
+      expect(params["dirPrefix"]).toBe("src/auth/");
+    });
+
+    test("throws EntityNotFoundError when no file matches the path", async () => {
+      const service = createService([], []);
+
+      await expect(
+        service.getTopLevelSymbols({ repository: "test-repo", path: "src/missing.ts" })
+      ).rejects.toThrow(EntityNotFoundError);
+    });
+  });
+
+  describe("getDocumentSymbols", () => {
+    const symbolRow = (
+      name: string,
+      kind: string,
+      lines: [number, number],
+      columns: [number, number] | null,
+      extra: Record<string, unknown> = {}
+    ): Record<string, unknown> => ({
+      name,
+      entityType: kind === "method" ? "function" : kind,
+      kind,
+      signature: null,
+      parentName: null,
+      startLine: lines[0],
+      endLine: lines[1],
+      startColumn: columns?.[0] ?? null,
+      endColumn: columns?.[1] ?? null,
+      nameLine: null,
+      nameStartColumn: null,
+      nameEndColumn: null,
+      variantNames: null,
+      variantStartLines: null,
+      variantStartColumns: null,
+      variantEndLines: null,
+      variantEndColumns: null,
+      ...extra,
+    });
+
+    const createService = (
+      entityRows: unknown[],
+      implRows: unknown[] = [],
+      fileRows: unknown[] = [{ path: "src/shapes.rs" }]
+    ): GraphAnalysisServiceImpl =>
+      new GraphAnalysisServiceImpl(
+        createCypherMockAdapter((cypher) => {
+          if (cypher.includes("HAS_IMPL")) return implRows;
+          if (cypher.includes("DEFINES")) return entityRows;
+          if (cypher.includes("RETURN f.path AS path")) return fileRows;
+          return [];
+        })
+      );
+
+    const range = (
+      startLine: number,
+      startCharacter: number,
+      endLine: number,
+      endCharacter: number
+    ): Record<string, unknown> => ({
+      start: { line: startLine, character: startCharacter },
+      end: { line: endLine, character: endCharacter },
+    });
+
+    test("nests methods in impl blocks and variants in enums", async () => {
+      const service = createService(
+        [
+          symbolRow("Point", "class", [1, 3], [0, 1], {
+            nameLine: 1,
+            nameStartColumn: 11,
+            nameEndColumn: 16,
+          }),
+          symbolRow("Shape", "enum", [5, 8], [0, 1], {
+            variantNames: ["Circle", "Square"],
+            variantStartLines: [6, 7],
+            variantStartColumns: [4, 4],
+            variantEndLines: [6, 7],
+            variantEndColumns: [15, 25],
+          }),
+          symbolRow("new", "method", [11, 11], [4, 40], {
+            parentName: "Point",
+            signature: "pub fn new() -> Self",
+          }),
+          symbolRow("fmt", "method", [14, 16], [4, 5], { parentName: "Point" }),
+        ],
+        [
+          {
+            typeName: "Point",
+            traitName: null,
+            line: 10,
+            endLine: 12,
+            startColumn: 0,
+            endColumn: 1,
+          },
+          {
+            typeName: "Point",
+            traitName: "Display",
+            isNegative: false,
+            line: 13,
+            endLine: 17,
+            startColumn: 0,
+            endColumn: 1,
+          },
+        ]
+      );
+
+      const result = await service.getDocumentSymbols({
+        repository: "test-repo",
+        path: "src/shapes.rs",
+      });
+
+      expect(result.symbols.map((s) => [s.name, s.kind])).toEqual([
+        ["Point", 23],
+        ["Shape", 10],
+        ["impl Point", 19],
+        ["impl Display for Point", 19],
+      ]);
+      const [point, shape, inherent, display] = result.symbols;
+      expect(point!.range).toEqual(range(0, 0, 2, 1));
+      expect(point!.selectionRange).toEqual(range(0, 11, 0, 16));
+      expect(point!.children).toBeUndefined();
+
+      expect(shape!.children).toEqual([
+        { name: "Circle", kind: 22, range: range(5, 4, 5, 15), selectionRange: range(5, 4, 5, 10) },
+        { name: "Square", kind: 22, range: range(6, 4, 6, 25), selectionRange: range(6, 4, 6, 10) },
+      ]);
+
+      expect(inherent!.children).toEqual([
+        {
+          name: "new",
+          detail: "pub fn new() -> Self",
+          kind: 6,
+          range: range(10, 4, 10, 40),
+          selectionRange: range(10, 4, 10, 40),
+        },
+      ]);
+      expect(display!.children!.map((c) => c.name)).toEqual(["fmt"]);
+      expect(result.metadata.symbol_count).toBe(8);
+    });
+
+    test("falls back to parent links and whole-line ranges for older graphs", async () => {
+      const service = createService(
+        [
+          symbolRow("Point", "class", [1, 3], null),
+          symbolRow("new", "method", [11, 11], null, { parentName: "Point" }),
+          symbolRow("helper", "function", [20, 22], null, { parentName: "ExternalType" }),
+        ],
+        [{ typeName: "Point", traitName: null, line: 10, endLine: null }]
+      );
+
+      const result = await service.getDocumentSymbols({
+        repository: "test-repo",
+        path: "src/shapes.rs",
+      });
+
+      expect(result.symbols.map((s) => s.name)).toEqual(["Point", "helper"]);
+      expect(result.symbols[0]!.range).toEqual(range(0, 0, 3, 0));
+      expect(result.symbols[0]!.selectionRange).toEqual(range(0, 0, 3, 0));
+      expect(result.symbols[0]!.children!.map((c) => c.name)).toEqual(["new"]);
+    });
+
+    test("nests by containment and derives kinds from entity types", async () => {
+      const service = createService(
+        [
+          { ...symbolRow("AuthService", "class", [1, 30], [0, 1]), kind: null },
+          { ...symbolRow("login", "method", [5, 12], [2, 3]), entityType: "method", kind: null },
+          symbolRow("token", "variable", [7, 7], [4, 20]),
+          symbolRow("createAuth", "function", [32, 40], [0, 1]),
+        ],
+        [],
+        [{ path: "src/auth.ts" }]
+      );
+
+      const result = await service.getDocumentSymbols({
+        repository: "test-repo",
+        path: "./src/auth.ts",
+      });
+
+      expect(result.file_path).toBe("src/auth.ts");
+      expect(result.symbols.map((s) => [s.name, s.kind])).toEqual([
+        ["AuthService", 5],
+        ["createAuth", 12],
+      ]);
+      const login = result.symbols[0]!.children![0]!;
+      expect([login.name, login.kind]).toEqual(["login", 6]);
+      expect(login.children!.map((c) => [c.name, c.kind])).toEqual([["token", 13]]);
+    });
+
+    test("throws EntityNotFoundError when the file is not indexed", async () => {
+      const service = createService([], [], []);
+
+      await expect(
+        service.getDocumentSymbols({ repository: "test-repo", path: "src/missing.rs" })
+      ).rejects.toThrow(EntityNotFoundError);
+    });
+  });
+
+  describe("listTodos", () => {
+    const TODO_ROWS = [
+      { tag: "TODO", message: "cache tokens", filePath: "src/auth.ts", line: 4 },
+      { tag: "FIXME", message: "race on logout", filePath: "src/auth.ts", line: 20 },
+      { tag: "TODO", message: null, filePath: "src/db.ts", line: 2 },
+    ];
+
+    test("returns markers with per-tag counts", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => TODO_ROWS));
+
+      const result = await service.listTodos({ repository: "test-repo" });
+
+      expect(result.todos).toHaveLength(3);
+      expect(result.todos[1]).toEqual({
+        tag: "FIXME",
+        message: "race on logout",
+        file_path: "src/auth.ts",
+        line: 20,
+      });
+      expect(result.todos[2]!.message).toBe("");
+      expect(result.metadata.tag_counts).toEqual({ TODO: 2, FIXME: 1 });
+      expect(result.metadata.truncated).toBe(false);
+    });
+
+    test("passes tag and normalized path filters to the query", async () => {
+      const adapter = createCypherMockAdapter(() => []);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      await service.listTodos({ repository: "test-repo", tag: "fixme", path: "./src/auth/" });
+
+      const [cypher, params] = adapter.runQuery.mock.calls[0] as [string, Record<string, unknown>];
+      expect(cypher).toContain("HAS_TODO");
+      expect(params["tag"]).toBe("fixme");
+      expect(params["path"]).toBe("src/auth");
+      expect(params["dirPrefix"]).toBe("src/auth/");
+    });
+
+    test("leaves filters null when not provided", async () => {
+      const adapter = createCypherMockAdapter(() => []);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      await service.listTodos({ repository: "test-repo" });
+
+      const [, params] = adapter.runQuery.mock.calls[0] as [string, Record<string, unknown>];
+      expect(params["tag"]).toBeNull();
+      expect(params["path"]).toBeNull();
+    });
+
+    test("truncates to the limit while counting every match", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => TODO_ROWS));
+
+      const result = await service.listTodos({ repository: "test-repo", limit: 1 });
+
+      expect(result.todos).toHaveLength(1);
+      expect(result.metadata.total_count).toBe(3);
+      expect(result.metadata.truncated).toBe(true);
+    });
+
+    test("rejects an empty repository", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter());
+
+      await expect(service.listTodos({ repository: " " })).rejects.toThrow(
+        GraphServiceValidationError
+      );
+    });
+  });
+
+  describe("searchSymbols", () => {
+    const symbolRow = (name: string, line: number, kind: string | null = "function") => ({
+      id: `Function:test-repo:src/scale.ts:${name}:${line}`,
+      name,
+      entityType: "function",
+      kind,
+      filePath: "src/scale.ts",
+      startLine: line,
+      endLine: line + 2,
+    });
+
+    test("ranks full-name matches above subtoken matches", async () => {
+      const rows = [
+        symbolRow("factor_scale", 1),
+        symbolRow("rescale", 5),
+        symbolRow("ScaleFactor", 9, null),
+        symbolRow("scaleFactor", 13),
+      ];
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => rows));
+
+      const result = await service.searchSymbols({
+        repository: "test-repo",
+        query: "scaleFactor",
+        token_match: true,
+      });
+
+      expect(result.matches.map((m) => [m.name, m.match_type])).toEqual([
+        ["scaleFactor", "exact"],
+        ["ScaleFactor", "exact_ci"],
+        ["factor_scale", "token"],
+      ]);
+      expect(result.matches[1]!.kind).toBeUndefined();
+      expect(result.metadata.total_matches).toBe(3);
+      expect(result.metadata.token_match).toBe(true);
+    });
+
+    test("drops weak subtoken matches before the limit by default", async () => {
+      const rows = [
+        symbolRow("scale_vector", 1),
+        symbolRow("bytes_per_pixel", 5),
+        symbolRow("pixel_scale", 9),
+      ];
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => rows));
+
+      const fuzzy = await service.searchSymbols({
+        repository: "test-repo",
+        query: "pixelScaleFactor",
+        token_match: true,
+        limit: 1,
+      });
+      const loose = await service.searchSymbols({
+        repository: "test-repo",
+        query: "pixelScaleFactor",
+        token_match: true,
+        min_score: 0,
+      });
+
+      // pixel_scale matches 2 of 3 query subtokens (0.333), the others 1 (0.167)
+      expect(fuzzy.matches.map((m) => m.name)).toEqual(["pixel_scale"]);
+      expect(fuzzy.metadata.total_matches).toBe(1);
+      expect(fuzzy.metadata.min_score_applied).toBe(0.25);
+      expect(loose.matches).toHaveLength(3);
+      expect(loose.metadata.min_score_applied).toBe(0);
+    });
+
+    test("keeps every full-name match without token matching", async () => {
+      const service = new GraphAnalysisServiceImpl(
+        createCypherMockAdapter(() => [symbolRow("rescale", 1), symbolRow("scale", 5)])
+      );
+
+      const exact = await service.searchSymbols({ repository: "test-repo", query: "scale" });
+      const strict = await service.searchSymbols({
+        repository: "test-repo",
+        query: "scale",
+        min_score: 0.8,
+      });
+
+      expect(exact.matches.map((m) => m.name)).toEqual(["scale", "rescale"]);
+      expect(exact.metadata.min_score_applied).toBe(0);
+      expect(strict.matches.map((m) => m.name)).toEqual(["scale"]);
+    });
+
+    test("sends query subtokens only when token matching is enabled", async () => {
+      const adapter = createCypherMockAdapter(() => []);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      await service.searchSymbols({ repository: "test-repo", query: "scaleFactor" });
+      await service.searchSymbols({
+        repository: "test-repo",
+        query: "scaleFactor",
+        token_match: true,
+        kind: "method",
+      });
+
+      const [cypher, plain] = adapter.runQuery.mock.calls[0] as [string, Record<string, unknown>];
+      const [, tokenized] = adapter.runQuery.mock.calls[1] as [string, Record<string, unknown>];
+      expect(cypher).toContain("e.nameTokens");
+      expect(plain["queryTokens"]).toEqual([]);
+      expect(plain["kind"]).toBeNull();
+      expect(tokenized["queryTokens"]).toEqual(["scale", "factor"]);
+      expect(tokenized["kind"]).toBe("method");
+    });
+
+    test("filters on and flags build-time code", async () => {
+      const adapter = createCypherMockAdapter(() => [
+        { ...symbolRow("scale_macro", 1), buildOrMacro: true },
+        { ...symbolRow("scale", 5), buildOrMacro: null },
+      ]);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.searchSymbols({ repository: "test-repo", query: "scale" });
+      await service.searchSymbols({
+        repository: "test-repo",
+        query: "scale",
+        build_or_macro: false,
+      });
+
+      expect(result.matches.map((m) => [m.name, m.build_or_macro])).toEqual([
+        ["scale", undefined],
+        ["scale_macro", true],
+      ]);
+      const [cypher, unfiltered] = adapter.runQuery.mock.calls[0] as [
+        string,
+        Record<string, unknown>,
+      ];
+      const [, excluded] = adapter.runQuery.mock.calls[1] as [string, Record<string, unknown>];
+      expect(cypher).toContain("coalesce(e.buildOrMacro, false) = $buildOrMacro");
+      expect(unfiltered["buildOrMacro"]).toBeNull();
+      expect(excluded["buildOrMacro"]).toBe(false);
+    });
+
+    test("keeps the platform variants compiled for a target", async () => {
+      const service = new GraphAnalysisServiceImpl(
+        createCypherMockAdapter(() => [
+          { ...symbolRow("home_dir", 3), cfg: "unix" },
+          { ...symbolRow("home_dir", 9), cfg: 'target_os = "windows"' },
+          { ...symbolRow("home_dir_serde", 15), cfg: 'feature = "serde"' },
+        ])
+      );
+
+      const all = await service.searchSymbols({ repository: "test-repo", query: "home_dir" });
+      const linux = await service.searchSymbols({
+        repository: "test-repo",
+        query: "home_dir",
+        platform: "x86_64-unknown-linux-gnu",
+      });
+
+      expect(all.matches.map((m) => m.cfg)).toEqual([
+        "unix",
+        'target_os = "windows"',
+        'feature = "serde"',
+      ]);
+      expect(linux.matches.map((m) => [m.name, m.start_line])).toEqual([
+        ["home_dir", 3],
+        ["home_dir_serde", 15],
+      ]);
+    });
+
+    test("rejects an unrecognized platform", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => []));
+
+      await expect(
+        service.searchSymbols({ repository: "test-repo", query: "home_dir", platform: "amiga" })
+      ).rejects.toThrow(GraphServiceValidationError);
+    });
+
+    test("drops candidates that only match subtokens without token matching", async () => {
+      const service = new GraphAnalysisServiceImpl(
+        createCypherMockAdapter(() => [symbolRow("factor_scale", 1), symbolRow("scale", 4)])
+      );
+
+      const result = await service.searchSymbols({ repository: "test-repo", query: "scale" });
+
+      expect(result.matches.map((m) => m.name)).toEqual(["scale", "factor_scale"]);
+      expect(result.matches[1]!.match_type).toBe("substring");
+    });
+
+    test("truncates to the limit while counting every match", async () => {
+      const rows = [symbolRow("scaleA", 1), symbolRow("scaleB", 4), symbolRow("scaleC", 7)];
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => rows));
+
+      const result = await service.searchSymbols({
+        repository: "test-repo",
+        query: "scale",
+        limit: 2,
+      });
+
+      expect(result.matches.map((m) => m.name)).toEqual(["scaleA", "scaleB"]);
+      expect(result.metadata.total_matches).toBe(3);
+    });
+
+    test("returns structured parameters and return types", async () => {
+      const rows = [
+        {
+          ...symbolRow("function_with_params", 30),
+          paramNames: ["name", "count"],
+          paramTypes: ["&str", "i32"],
+          returnType: "String",
+          isAsync: true,
+        },
+        { ...symbolRow("untyped_params", 40), paramNames: ["a"], paramTypes: [""], returnType: null },
+      ];
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => rows));
+
+      const result = await service.searchSymbols({ repository: "test-repo", query: "params" });
+
+      expect(result.matches[0]).toMatchObject({
+        name: "function_with_params",
+        params: [
+          { name: "name", type: "&str" },
+          { name: "count", type: "i32" },
+        ],
+        return_type: "String",
+        is_async: true,
+      });
+      expect(result.matches[1]!.params).toEqual([{ name: "a" }]);
+      expect(result.matches[1]!.return_type).toBeUndefined();
+      // Graphs ingested before async flags existed report none
+      expect(result.matches[1]!.is_async).toBeUndefined();
+    });
+
+    test("searches where-clause text for where: queries", async () => {
+      const adapter = createCypherMockAdapter(() => [
+        {
+          ...symbolRow("Pair", 143, "struct"),
+          whereClause: "where\n    K: Eq + std::hash::Hash,",
+        },
+      ]);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.searchSymbols({ repository: "test-repo", query: "where:Hash" });
+
+      const [cypher, params] = adapter.runQuery.mock.calls[0] as [string, Record<string, unknown>];
+      expect(cypher).toContain("toLower(e.whereClause) CONTAINS toLower($bound)");
+      expect(params["bound"]).toBe("Hash");
+      expect(result.matches).toHaveLength(1);
+      expect(result.matches[0]).toMatchObject({
+        name: "Pair",
+        match_type: "where_clause",
+        where_clause: "where\n    K: Eq + std::hash::Hash,",
+      });
+    });
+
+    test("attaches symbol and module doc summaries with include_context", async () => {
+      const adapter = createCypherMockAdapter((cypher) =>
+        cypher.includes("moduleDoc")
+          ? [
+              {
+                id: "Function:test-repo:src/scale.ts:scale:1",
+                doc: "Scale a vector\nby a factor.\n\nPanics on NaN.",
+                moduleDoc: "Vector arithmetic.",
+              },
+              { id: "Function:test-repo:src/scale.ts:scaled:4", doc: null, moduleDoc: null },
+            ]
+          : [symbolRow("scale", 1), symbolRow("scaled", 4)]
+      );
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.searchSymbols({
+        repository: "test-repo",
+        query: "scale",
+        include_context: true,
+      });
+
+      expect(adapter.runQuery.mock.calls[1]?.[1]).toMatchObject({
+        ids: [
+          "Function:test-repo:src/scale.ts:scale:1",
+          "Function:test-repo:src/scale.ts:scaled:4",
+        ],
+      });
+      expect(result.matches[0]).toMatchObject({
+        doc_summary: "Scale a vector by a factor.",
+        module_doc_summary: "Vector arithmetic.",
+      });
+      expect(result.matches[1]!.doc_summary).toBeUndefined();
+      expect(result.matches[1]!.module_doc_summary).toBeUndefined();
+    });
+
+    test("skips the context query unless include_context is set", async () => {
+      const adapter = createCypherMockAdapter(() => [symbolRow("scale", 1)]);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.searchSymbols({ repository: "test-repo", query: "scale" });
+
+      expect(adapter.runQuery).toHaveBeenCalledTimes(1);
+      expect(result.matches[0]!.doc_summary).toBeUndefined();
+    });
+
+    test("rejects an unknown kind", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter());
+
+      await expect(
+        service.searchSymbols({
+          repository: "test-repo",
+          query: "scale",
+          kind: "trait" as never,
+        })
+      ).rejects.toThrow(GraphServiceValidationError);
+    });
+  });
+
+  describe("searchByType", () => {
+    const fnRow = (
+      name: string,
+      line: number,
+      params: [string, string][],
+      returnType: string | null
+    ) => ({
+      id: `Function:test-repo:src/lib.rs:${name}:${line}`,
+      name,
+      entityType: "function",
+      kind: "function",
+      filePath: "src/lib.rs",
+      startLine: line,
+      endLine: line + 2,
+      paramNames: params.map(([n]) => n),
+      paramTypes: params.map(([, t]) => t),
+      returnType,
+    });
+
+    const ROWS = [
+      fnRow("greet", 10, [["name", "&str"]], "String"),
+      fnRow("origin", 20, [], "Point"),
+      fnRow("parse_point", 30, [["input", "&str"]], "Result<Point, ParseError>"),
+      fnRow("translate", 40, [["p", "&mut Point"], ["dx", "i32"]], null),
+    ];
+
+    test("matches parameter types exactly by default", async () => {
+      const adapter = createCypherMockAdapter(() => ROWS);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.searchByType({
+        repository: "test-repo",
+        type: "&str",
+        position: "param",
+      });
+
+      const [cypher] = adapter.runQuery.mock.calls[0] as [string];
+      expect(cypher).toContain("e.paramTypes IS NOT NULL");
+      expect(result.matches.map((m) => [m.name, m.matched_params])).toEqual([
+        ["greet", ["name"]],
+        ["parse_point", ["input"]],
+      ]);
+      expect(result.matches[0]).toMatchObject({
+        params: [{ name: "name", type: "&str" }],
+        return_type: "String",
+        matched_return: false,
+        match_type: "exact",
+      });
+    });
+
+    test("restricts matching to return types", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => ROWS));
+
+      const result = await service.searchByType({
+        repository: "test-repo",
+        type: "Point",
+        position: "return",
+      });
+
+      expect(result.matches.map((m) => m.name)).toEqual(["origin"]);
+      expect(result.matches[0]!.matched_return).toBe(true);
+    });
+
+    test("looks through wrappers with contains and ranks exact matches first", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => ROWS));
+
+      const result = await service.searchByType({
+        repository: "test-repo",
+        type: "Point",
+        contains: true,
+      });
+
+      expect(result.matches.map((m) => [m.name, m.match_type])).toEqual([
+        ["origin", "exact"],
+        ["parse_point", "contains"],
+        ["translate", "contains"],
+      ]);
+      expect(result.metadata).toMatchObject({ total_matches: 3, contains: true });
+    });
+
+    test("matches async functions by their resolved return type", async () => {
+      const rows = [
+        {
+          ...fnRow("async_function", 44, [["url", "&str"]], "Result<String, io::Error>"),
+          isAsync: true,
+        },
+        { ...fnRow("read_config", 50, [], "Result<String, io::Error>"), isAsync: false },
+      ];
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => rows));
+
+      const result = await service.searchByType({
+        repository: "test-repo",
+        type: "Result<String, io::Error>",
+        position: "return",
+      });
+
+      expect(result.matches.map((m) => [m.name, m.is_async, m.match_type])).toEqual([
+        ["async_function", true, "exact"],
+        ["read_config", false, "exact"],
+      ]);
+    });
+
+    test("rejects an unknown position", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter());
+
+      await expect(
+        service.searchByType({
+          repository: "test-repo",
+          type: "Point",
+          position: "field" as never,
+        })
+      ).rejects.toThrow(GraphServiceValidationError);
+    });
+  });
+
+  describe("listEntryPoints", () => {
+    const entryRow = (
+      name: string,
+      filePath: string,
+      line: number,
+      entryPointKind: string,
+      attributes: string[] | null = null
+    ) => ({
+      id: `Function:test-repo:${filePath}:${name}:${line}`,
+      name,
+      entityType: "function",
+      filePath,
+      startLine: line,
+      endLine: line + 3,
+      entryPointKind,
+      attributes,
+    });
+
+    const ROWS = [
+      entryRow("main", "src/bin/cli.rs", 5, "main"),
+      entryRow("parses_input", "src/lib.rs", 40, "test", ["test"]),
+      entryRow("main", "src/main.rs", 3, "async_main", ["tokio::main"]),
+      entryRow("handles_timeout", "src/lib.rs", 52, "test", ["tokio::test"]),
+      entryRow("bench_parse", "src/lib.rs", 70, "bench", ["bench"]),
+    ];
+
+    test("groups entry points by kind in kind order", async () => {
+      const adapter = createCypherMockAdapter(() => ROWS);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.listEntryPoints({ repository: "test-repo" });
+
+      const [cypher, params] = adapter.runQuery.mock.calls[0] as [string, Record<string, unknown>];
+      expect(cypher).toContain("e.entryPointKind IS NOT NULL");
+      expect(params["kind"]).toBeNull();
+      expect(
+        result.groups.map((g) => [g.kind, g.entry_points.map((e) => `${e.file_path}:${e.name}`)])
+      ).toEqual([
+        ["main", ["src/bin/cli.rs:main"]],
+        ["async_main", ["src/main.rs:main"]],
+        ["test", ["src/lib.rs:parses_input", "src/lib.rs:handles_timeout"]],
+        ["bench", ["src/lib.rs:bench_parse"]],
+      ]);
+      expect(result.groups[1]!.entry_points[0]!.attributes).toEqual(["tokio::main"]);
+      expect(result.groups[0]!.entry_points[0]!.attributes).toBeUndefined();
+      expect(result.metadata).toMatchObject({
+        total_count: 5,
+        kind_counts: { main: 1, async_main: 1, test: 2, bench: 1 },
+        truncated: false,
+      });
+    });
+
+    test("passes kind and path filters and applies the limit across groups", async () => {
+      const adapter = createCypherMockAdapter(() =>
+        ROWS.filter((row) => row.entryPointKind === "test")
+      );
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.listEntryPoints({
+        repository: "test-repo",
+        kind: "test",
+        path: "./src/",
+        limit: 1,
+      });
+
+      const [, params] = adapter.runQuery.mock.calls[0] as [string, Record<string, unknown>];
+      expect(params).toMatchObject({ kind: "test", path: "src", dirPrefix: "src/" });
+      expect(result.groups).toHaveLength(1);
+      expect(result.groups[0]!.entry_points.map((e) => e.name)).toEqual(["parses_input"]);
+      expect(result.metadata).toMatchObject({ total_count: 2, truncated: true });
+    });
+
+    test("rejects an unknown kind", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter());
+
+      await expect(
+        service.listEntryPoints({ repository: "test-repo", kind: "library" as never })
+      ).rejects.toThrow(GraphServiceValidationError);
+    });
+  });
+
+  describe("findByAttribute", () => {
+    const attributeRow = (
+      name: string,
+      line: number,
+      attributes: string[],
+      callerCount = 0,
+      entityType = "function"
+    ) => ({
+      id: `Function:test-repo:src/lib.rs:${name}:${line}`,
+      name,
+      entityType,
+      filePath: "src/lib.rs",
+      startLine: line,
+      endLine: line + 3,
+      attributes,
+      callerCount,
+    });
+
+    const ROWS = [
+      attributeRow("old_parse", 10, ["deprecated(since = \"1.2\", note = \"use parse\")"], 3),
+      attributeRow("legacy", 20, ["inline", "deprecated"]),
+      attributeRow(
+        "Config",
+        30,
+        ["derive(Debug)", "serde(rename_all = \"camelCase\")"],
+        0,
+        "class"
+      ),
+      attributeRow("not_deprecated_helper", 40, ["doc = \"deprecated in spirit\""]),
+      attributeRow("Store", 50, ["async_trait::async_trait"], 1, "class"),
+    ];
+
+    test("matches the attribute path regardless of arguments", async () => {
+      const adapter = createCypherMockAdapter(() => ROWS);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.findByAttribute({ repository: "test-repo", name: "deprecated" });
+
+      const [cypher, params] = adapter.runQuery.mock.calls[0] as [string, Record<string, unknown>];
+      expect(cypher).toContain("attribute CONTAINS $name");
+      expect(params).toMatchObject({ name: "deprecated", path: null });
+      expect(result.matches.map((m) => [m.name, m.attributes, m.caller_count])).toEqual([
+        ["old_parse", ['deprecated(since = "1.2", note = "use parse")'], 3],
+        ["legacy", ["deprecated"], 0],
+      ]);
+      expect(result.metadata).toMatchObject({ total_matches: 2, truncated: false });
+    });
+
+    test("filters on argument text and accepts the attribute as written", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => ROWS));
+
+      const result = await service.findByAttribute({
+        repository: "test-repo",
+        name: "#[serde]",
+        arguments: "rename_all",
+      });
+
+      expect(result.name).toBe("serde");
+      expect(result.matches.map((m) => m.name)).toEqual(["Config"]);
+    });
+
+    test("matches bare names against the last path segment", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => ROWS));
+
+      const result = await service.findByAttribute({ repository: "test-repo", name: "async_trait" });
+
+      expect(result.matches.map((m) => [m.name, m.entity_type])).toEqual([["Store", "class"]]);
+    });
+
+    test("applies path filter and limit", async () => {
+      const adapter = createCypherMockAdapter(() => ROWS);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.findByAttribute({
+        repository: "test-repo",
+        name: "deprecated",
+        path: "./src/",
+        limit: 1,
+      });
+
+      const [, params] = adapter.runQuery.mock.calls[0] as [string, Record<string, unknown>];
+      expect(params).toMatchObject({ path: "src", dirPrefix: "src/" });
+      expect(result.matches.map((m) => m.name)).toEqual(["old_parse"]);
+      expect(result.metadata).toMatchObject({ total_matches: 2, truncated: true });
+    });
+
+    test("rejects an empty attribute name", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter());
+
+      await expect(service.findByAttribute({ repository: "test-repo", name: "" })).rejects.toThrow(
+        GraphServiceValidationError
+      );
+    });
+  });
+
+  describe("getDeprecationReport", () => {
+    const deprecatedRow = (
+      name: string,
+      line: number,
+      attributes: string[],
+      label = "Function"
+    ) => ({
+      id: `${label}:test-repo:src/lib.rs:${name}:${line}`,
+      name,
+      entityType: label === "Function" ? "function" : "class",
+      filePath: "src/lib.rs",
+      startLine: line,
+      endLine: line + 3,
+      label,
+      attributes,
+    });
+
+    const DEPRECATED_ROWS = [
+      deprecatedRow("old_parse", 10, ['deprecated(since = "1.2", note = "use parse")']),
+      deprecatedRow("legacy", 20, ["inline", 'deprecated = "use modern"']),
+      deprecatedRow("OldConfig", 30, ["deprecated"], "Class"),
+      deprecatedRow("helper", 40, ['doc = "deprecated in spirit"']),
+    ];
+
+    const CALL_SITE_ROWS = [
+      {
+        targetId: "Function:test-repo:src/lib.rs:legacy:20",
+        id: "Function:test-repo:src/main.rs:main:1",
+        name: "main",
+        entityType: "function",
+        filePath: "src/main.rs",
+        startLine: 1,
+        endLine: 9,
+        line: 4,
+        callCount: 2,
+      },
+    ];
+
+    const reportAdapter = () =>
+      createCypherMockAdapter((cypher) =>
+        cypher.includes("[c:CALLS]") ? CALL_SITE_ROWS : DEPRECATED_ROWS
+      );
+
+    test("reports messages, call sites and removal safety", async () => {
+      const adapter = reportAdapter();
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.getDeprecationReport({ repository: "test-repo" });
+
+      const [, siteParams] = adapter.runQuery.mock.calls[1] as [string, Record<string, unknown>];
+      expect(siteParams["ids"]).toEqual([
+        "Function:test-repo:src/lib.rs:old_parse:10",
+        "Function:test-repo:src/lib.rs:legacy:20",
+        "Class:test-repo:src/lib.rs:OldConfig:30",
+      ]);
+      expect(
+        result.symbols.map((s) => [
+          s.name,
+          s.message,
+          s.since,
+          s.call_sites.length,
+          s.safe_to_remove,
+        ])
+      ).toEqual([
+        ["legacy", "use modern", null, 1, false],
+        ["old_parse", "use parse", "1.2", 0, true],
+        ["OldConfig", null, null, 0, false],
+      ]);
+      expect(result.symbols[0]!.call_sites[0]).toMatchObject({
+        caller: { name: "main", file_path: "src/main.rs" },
+        line: 4,
+        call_count: 2,
+      });
+      expect(result.symbols[2]!.references_tracked).toBe(false);
+      expect(result.metadata).toMatchObject({
+        total_deprecated: 3,
+        in_use_count: 1,
+        safe_to_remove_count: 1,
+        truncated: false,
+      });
+    });
+
+    test("skips the call site query when nothing is deprecated", async () => {
+      const adapter = createCypherMockAdapter(() => []);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.getDeprecationReport({ repository: "test-repo", path: "src/" });
+
+      expect(adapter.runQuery).toHaveBeenCalledTimes(1);
+      const [, params] = adapter.runQuery.mock.calls[0] as [string, Record<string, unknown>];
+      expect(params).toMatchObject({ path: "src", dirPrefix: "src/" });
+      expect(result.symbols).toEqual([]);
+      expect(result.metadata.total_deprecated).toBe(0);
+    });
+
+    test("applies the limit after ordering by call sites", async () => {
+      const service = new GraphAnalysisServiceImpl(reportAdapter());
+
+      const result = await service.getDeprecationReport({ repository: "test-repo", limit: 1 });
+
+      expect(result.symbols.map((s) => s.name)).toEqual(["legacy"]);
+      expect(result.metadata).toMatchObject({ total_deprecated: 3, truncated: true });
+    });
+  });
+
+  describe("listUnsafe", () => {
+    const unsafeRow = (
+      name: string,
+      line: number,
+      isUnsafe: boolean | null,
+      blockStarts: number[] | null = null,
+      blockEnds: number[] | null = null
+    ) => ({
+      id: `Function:test-repo:src/ffi.rs:${name}:${line}`,
+      name,
+      entityType: "function",
+      filePath: "src/ffi.rs",
+      startLine: line,
+      endLine: line + 10,
+      isUnsafe,
+      unsafeBlockStarts: blockStarts,
+      unsafeBlockEnds: blockEnds,
+    });
+
+    const SYMBOL_ROWS = [
+      unsafeRow("raw_copy", 5, true),
+      unsafeRow("read_header", 20, false, [23, 27], [25, 29]),
+      unsafeRow("with_closure", 40, null, [44], [44]),
+    ];
+
+    const IMPL_ROWS = [{ typeName: "Handle", traitName: "Send", filePath: "src/ffi.rs", line: 60 }];
+
+    const unsafeAdapter = () =>
+      createCypherMockAdapter((cypher) => (cypher.includes("(i:Impl") ? IMPL_ROWS : SYMBOL_ROWS));
+
+    test("lists unsafe symbols with block spans and unsafe impls", async () => {
+      const service = new GraphAnalysisServiceImpl(unsafeAdapter());
+
+      const result = await service.listUnsafe({ repository: "test-repo" });
+
+      expect(result.symbols.map((s) => [s.name, s.is_unsafe, s.unsafe_blocks])).toEqual([
+        ["raw_copy", true, []],
+        [
+          "read_header",
+          false,
+          [
+            { start_line: 23, end_line: 25 },
+            { start_line: 27, end_line: 29 },
+          ],
+        ],
+        ["with_closure", false, [{ start_line: 44, end_line: 44 }]],
+      ]);
+      expect(result.impls).toEqual([
+        { trait_name: "Send", type_name: "Handle", file_path: "src/ffi.rs", line: 60 },
+      ]);
+      expect(result.metadata).toMatchObject({
+        unsafe_symbol_count: 1,
+        unsafe_block_count: 3,
+        unsafe_impl_count: 1,
+        truncated: false,
+      });
+    });
+
+    test("passes the path filter to both queries and applies the limit", async () => {
+      const adapter = unsafeAdapter();
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.listUnsafe({
+        repository: "test-repo",
+        path: "./src/",
+        limit: 2,
+      });
+
+      for (const call of adapter.runQuery.mock.calls) {
+        expect(call[1]).toMatchObject({ path: "src", dirPrefix: "src/" });
+      }
+      expect(result.symbols.map((s) => s.name)).toEqual(["raw_copy", "read_header"]);
+      expect(result.metadata).toMatchObject({ unsafe_block_count: 3, truncated: true });
+    });
+
+    test("rejects a limit above the maximum", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter());
+
+      await expect(service.listUnsafe({ repository: "test-repo", limit: 5000 })).rejects.toThrow(
+        GraphServiceValidationError
+      );
+    });
+  });
+
+  describe("listComplex", () => {
+    const complexRow = (name: string, line: number, complexity: number) => ({
+      id: `Function:test-repo:src/parser.rs:${name}:${line}`,
+      name,
+      entityType: "function",
+      filePath: "src/parser.rs",
+      startLine: line,
+      endLine: line + 30,
+      complexity,
+    });
+
+    const ROWS = [complexRow("parse_expr", 40, 23), complexRow("parse_stmt", 90, 12)];
+
+    test("lists functions at or above the default threshold", async () => {
+      const adapter = createCypherMockAdapter(() => ROWS);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.listComplex({ repository: "test-repo" });
+
+      expect(adapter.runQuery.mock.calls[0]?.[1]).toMatchObject({
+        repository: "test-repo",
+        threshold: 10,
+        path: null,
+      });
+      expect(result.threshold).toBe(10);
+      expect(result.functions.map((f) => [f.name, f.file_path, f.complexity])).toEqual([
+        ["parse_expr", "src/parser.rs", 23],
+        ["parse_stmt", "src/parser.rs", 12],
+      ]);
+      expect(result.metadata).toMatchObject({ total_count: 2, truncated: false });
+    });
+
+    test("passes the threshold and path filter and applies the limit", async () => {
+      const adapter = createCypherMockAdapter(() => ROWS);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.listComplex({
+        repository: "test-repo",
+        threshold: 5,
+        path: "./src/",
+        limit: 1,
+      });
+
+      expect(adapter.runQuery.mock.calls[0]?.[1]).toMatchObject({
+        threshold: 5,
+        path: "src",
+        dirPrefix: "src/",
+      });
+      expect(result.functions.map((f) => f.name)).toEqual(["parse_expr"]);
+      expect(result.metadata).toMatchObject({ total_count: 2, truncated: true });
+    });
+
+    test("rejects a threshold below 1", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter());
+
+      await expect(service.listComplex({ repository: "test-repo", threshold: 0 })).rejects.toThrow(
+        GraphServiceValidationError
+      );
+    });
+  });
+
+  describe("findSimilarFunctions", () => {
+    const baseMinHash = Array.from({ length: 32 }, (_, i) => 1000 + i);
+    // Differs from the base signature in 4 of 32 slots
+    const editedMinHash = baseMinHash.map((value, i) => (i < 4 ? value + 500 : value));
+    const unrelatedMinHash = baseMinHash.map((value) => value + 9000);
+
+    const structureRow = (
+      name: string,
+      filePath: string,
+      line: number,
+      hash: string,
+      minHash: number[]
+    ) => ({
+      id: `Function:test-repo:${filePath}:${name}:${line}`,
+      name,
+      entityType: "function",
+      filePath,
+      startLine: line,
+      endLine: line + 12,
+      structureHash: hash,
+      structureSize: 64,
+      structureMinHash: minHash,
+    });
+
+    const ROWS = [
+      structureRow("order_total", "src/billing.rs", 10, "aaaaaaaaaaaaaaaa", baseMinHash),
+      structureRow("order_total_capped", "src/billing.rs", 30, "bbbbbbbbbbbbbbbb", editedMinHash),
+      structureRow("parse_header", "src/http.rs", 5, "cccccccccccccccc", unrelatedMinHash),
+      structureRow("shipment_weight", "src/shipping.rs", 8, "aaaaaaaaaaaaaaaa", baseMinHash),
+    ];
+
+    test("groups exact clones and links near clones", async () => {
+      const adapter = createCypherMockAdapter(() => ROWS);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.findSimilarFunctions({ repository: "test-repo" });
+
+      expect(adapter.runQuery.mock.calls[0]?.[1]).toMatchObject({
+        repository: "test-repo",
+        minSize: 30,
+        path: null,
+      });
+      expect(result.min_similarity).toBe(0.8);
+      expect(result.groups.map((g) => [g.kind, g.similarity])).toEqual([
+        ["exact", 1],
+        ["near", 0.88],
+      ]);
+      expect(result.groups.map((g) => g.functions.map((f) => f.name))).toEqual([
+        ["order_total", "shipment_weight"],
+        ["order_total", "order_total_capped", "shipment_weight"],
+      ]);
+      expect(result.groups[0]?.functions[0]).toMatchObject({
+        file_path: "src/billing.rs",
+        fingerprint: "aaaaaaaaaaaaaaaa",
+        size: 64,
+      });
+      expect(result.metadata).toMatchObject({
+        functions_compared: 4,
+        exact_groups: 1,
+        near_groups: 1,
+        truncated: false,
+      });
+    });
+
+    test("drops near clones below the similarity threshold", async () => {
+      const adapter = createCypherMockAdapter(() => ROWS);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.findSimilarFunctions({
+        repository: "test-repo",
+        min_similarity: 0.9,
+        min_size: 50,
+        path: "./src/",
+      });
+
+      expect(adapter.runQuery.mock.calls[0]?.[1]).toMatchObject({
+        minSize: 50,
+        path: "src",
+        dirPrefix: "src/",
+      });
+      expect(result.groups.map((g) => g.kind)).toEqual(["exact"]);
+      expect(result.metadata.near_groups).toBe(0);
+    });
+
+    test("applies the group limit", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => ROWS));
+
+      const result = await service.findSimilarFunctions({ repository: "test-repo", limit: 1 });
+
+      expect(result.groups.map((g) => g.kind)).toEqual(["exact"]);
+      expect(result.metadata.truncated).toBe(true);
+    });
+
+    test("rejects a similarity threshold below 0.5", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter());
+
+      await expect(
+        service.findSimilarFunctions({ repository: "test-repo", min_similarity: 0.3 })
+      ).rejects.toThrow(GraphServiceValidationError);
+    });
+  });
+
+  describe("findConstsByValueRange", () => {
+    const constRow = (name: string, line: number, value: number, valueText: string) => ({
+      id: `Variable:test-repo:src/config.rs:${name}:${line}`,
+      name,
+      entityType: "variable",
+      filePath: "src/config.rs",
+      startLine: line,
+      endLine: line,
+      valueKind: "integer",
+      value,
+      valueText,
+    });
+
+    const ROWS = [constRow("MAX_SIZE", 3, 1024, "1_024"), constRow("MAX_FRAME", 9, 4096, "4096")];
+
+    test("returns constants with their decoded values", async () => {
+      const adapter = createCypherMockAdapter(() => ROWS);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.findConstsByValueRange({ repository: "test-repo", min: 1024 });
+
+      expect(adapter.runQuery.mock.calls[0]?.[1]).toMatchObject({
+        repository: "test-repo",
+        kinds: ["integer", "float"],
+        min: 1024,
+        max: null,
+        path: null,
+      });
+      expect(result.min).toBe(1024);
+      expect(result.max).toBeNull();
+      expect(result.constants.map((c) => [c.name, c.value, c.value_text])).toEqual([
+        ["MAX_SIZE", 1024, "1_024"],
+        ["MAX_FRAME", 4096, "4096"],
+      ]);
+      expect(result.metadata).toMatchObject({ total_count: 2, truncated: false });
+    });
+
+    test("passes the kind and path filter and applies the limit", async () => {
+      const adapter = createCypherMockAdapter(() => ROWS);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.findConstsByValueRange({
+        repository: "test-repo",
+        kind: "integer",
+        path: "./src/",
+        limit: 1,
+      });
+
+      expect(adapter.runQuery.mock.calls[0]?.[1]).toMatchObject({
+        kinds: ["integer"],
+        path: "src",
+        dirPrefix: "src/",
+      });
+      expect(result.constants.map((c) => c.name)).toEqual(["MAX_SIZE"]);
+      expect(result.metadata).toMatchObject({ total_count: 2, truncated: true });
+    });
+
+    test("rejects a min above the max", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter());
+
+      await expect(
+        service.findConstsByValueRange({ repository: "test-repo", min: 10, max: 1 })
+      ).rejects.toThrow(GraphServiceValidationError);
+    });
+  });
+
+  describe("findDoctests", () => {
+    const doctestRow = (name: string, line: number, mode: string, calls: string[]) => ({
+      id: `Function:test-repo:src/lib.rs:${name}:${line}`,
+      name,
+      entityType: "function",
+      filePath: "src/lib.rs",
+      startLine: line,
+      endLine: line + 5,
+      doctestStartLine: line - 4,
+      doctestEndLine: line - 2,
+      info: mode === "run" ? "" : mode,
+      mode,
+      code: `let v = ${calls[0] ?? "x"}(1);`,
+      calls,
+    });
+
+    const DOCTEST_ROWS = [
+      doctestRow("sum", 10, "run", ["add", "sum"]),
+      doctestRow("add", 30, "no_run", ["add"]),
+    ];
+
+    test("lists examples from the symbol's own docs before examples calling it", async () => {
+      const adapter = createCypherMockAdapter(() => DOCTEST_ROWS);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.findDoctests({ repository: "test-repo", symbol: "add" });
+
+      expect(String(adapter.runQuery.mock.calls[0]?.[0])).toContain("[:DOCTEST_OF]");
+      expect(adapter.runQuery.mock.calls[0]?.[1]).toMatchObject({ symbol: "add", mode: null });
+      expect(
+        result.examples.map((e) => [e.documented.name, e.documents_symbol, e.calls_symbol])
+      ).toEqual([
+        ["add", true, true],
+        ["sum", false, true],
+      ]);
+      expect(result.examples[0]).toMatchObject({
+        file_path: "src/lib.rs",
+        start_line: 26,
+        end_line: 28,
+        info: "no_run",
+        mode: "no_run",
+      });
+      expect(result.metadata).toMatchObject({ total_count: 2, truncated: false });
+    });
+
+    test("passes the mode filter and applies the limit", async () => {
+      const adapter = createCypherMockAdapter(() => DOCTEST_ROWS);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.findDoctests({
+        repository: "test-repo",
+        symbol: "add",
+        mode: "no_run",
+        limit: 1,
+      });
+
+      expect(adapter.runQuery.mock.calls[0]?.[1]).toMatchObject({ mode: "no_run" });
+      expect(result.examples).toHaveLength(1);
+      expect(result.metadata).toMatchObject({ total_count: 2, truncated: true });
+    });
+
+    test("rejects an unknown mode", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter());
+
+      await expect(
+        service.findDoctests({
+          repository: "test-repo",
+          symbol: "add",
+          mode: "sometimes" as never,
+        })
+      ).rejects.toThrow(GraphServiceValidationError);
+    });
+  });
+
+  describe("listMissingDocs", () => {
+    const publicRow = (
+      filePath: string,
+      name: string,
+      line: number,
+      documented: boolean,
+      extra: { parentName?: string; attributes?: string[] } = {}
+    ) => ({
+      id: `Function:test-repo:${filePath}:${name}:${line}`,
+      name,
+      entityType: extra.parentName ? "method" : "function",
+      filePath,
+      startLine: line,
+      endLine: line + 3,
+      parentName: extra.parentName ?? null,
+      attributes: extra.attributes ?? null,
+      documented,
+    });
+
+    const PUBLIC_ROWS = [
+      publicRow("src/geometry.rs", "area", 3, true),
+      publicRow("src/geometry.rs", "new", 10, false, { parentName: "Point" }),
+      publicRow("src/geometry.rs", "scale", 20, false),
+      publicRow("src/lib.rs", "run", 1, false),
+      publicRow("src/lib.rs", "internal", 8, false, { attributes: ["doc(hidden)"] }),
+      publicRow("src/lib.rs", "VERSION", 12, false, { attributes: ['doc = "Crate version."'] }),
+    ];
+
+    test("groups undocumented public symbols by module with coverage", async () => {
+      const adapter = createCypherMockAdapter(() => PUBLIC_ROWS);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.listMissingDocs({ repository: "test-repo" });
+
+      const cypher = String(adapter.runQuery.mock.calls[0]?.[0]);
+      expect(cypher).toContain("e.isExported = true");
+      expect(cypher).toContain("e.implementedTrait IS NULL");
+      expect(
+        result.modules.map((module) => [module.module, module.symbols.map((s) => s.name)])
+      ).toEqual([
+        ["src/geometry.rs", ["new", "scale"]],
+        ["src/lib.rs", ["run"]],
+      ]);
+      expect(result.modules[0]).toMatchObject({ public_count: 3, documented_count: 1 });
+      // doc(hidden) items are skipped and #[doc = "..."] counts as documentation
+      expect(result.modules[1]).toMatchObject({ public_count: 2, documented_count: 1 });
+      expect(result.metadata).toMatchObject({
+        public_count: 5,
+        documented_count: 2,
+        missing_count: 3,
+        allowed_count: 0,
+        coverage: 0.4,
+        truncated: false,
+      });
+    });
+
+    test("skips allowlisted symbols by name, qualified name or file", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => PUBLIC_ROWS));
+
+      const result = await service.listMissingDocs({
+        repository: "test-repo",
+        allow: ["Point::new", "src/lib.rs::run", "area"],
+      });
+
+      expect(result.modules.flatMap((module) => module.symbols.map((s) => s.name))).toEqual([
+        "scale",
+      ]);
+      // Documented symbols stay counted even when allowlisted
+      expect(result.metadata).toMatchObject({
+        public_count: 3,
+        documented_count: 2,
+        missing_count: 1,
+        allowed_count: 2,
+      });
+    });
+
+    test("passes the path filter and applies the limit across modules", async () => {
+      const adapter = createCypherMockAdapter(() => PUBLIC_ROWS);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.listMissingDocs({
+        repository: "test-repo",
+        path: "./src/",
+        limit: 2,
+      });
+
+      expect(adapter.runQuery.mock.calls[0]?.[1]).toMatchObject({
+        path: "src",
+        dirPrefix: "src/",
+      });
+      expect(result.modules.map((module) => module.module)).toEqual(["src/geometry.rs"]);
+      expect(result.metadata).toMatchObject({ missing_count: 3, truncated: true });
+    });
+
+    test("reports full coverage when there are no public symbols", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => []));
+
+      const result = await service.listMissingDocs({ repository: "test-repo" });
+
+      expect(result.modules).toEqual([]);
+      expect(result.metadata).toMatchObject({ public_count: 0, coverage: 1 });
+    });
+  });
+
+  describe("resolveName", () => {
+    const nameRow = (
+      filePath: string,
+      name: string,
+      line: number,
+      extra: { parentName?: string; kind?: string; crateName?: string } = {}
+    ) => ({
+      id: `Function:test-repo:${filePath}:${name}:${line}`,
+      name,
+      entityType: extra.parentName ? "method" : "function",
+      kind: extra.kind ?? null,
+      filePath,
+      startLine: line,
+      endLine: line + 3,
+      parentName: extra.parentName ?? null,
+      crateName: extra.crateName ?? null,
+    });
+
+    const POINT_ROWS = [
+      nameRow("src/geometry/mod.rs", "Point", 3, { kind: "class" }),
+      nameRow("src/render.rs", "point", 10),
+      nameRow("src/render.rs", "point", 30, { parentName: "Canvas", kind: "method" }),
+      nameRow("src/shapes.ts", "Point", 5, { kind: "class" }),
+      nameRow("src/lib.rs", "Point", 8, { kind: "class" }),
+    ];
+
+    test("ranks exact matches first, rarer names before shared ones", async () => {
+      const adapter = createCypherMockAdapter(() => POINT_ROWS);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.resolveName({ repository: "test-repo", partial: "point" });
+
+      expect(adapter.runQuery.mock.calls[0]?.[1]).toMatchObject({ name: "point" });
+      expect(result.candidates.map((c) => [c.qualified_name, c.match_type, c.name_count])).toEqual([
+        ["crate::render::Canvas::point", "exact", 2],
+        ["crate::render::point", "exact", 2],
+        ["crate::geometry::Point", "case_insensitive", 3],
+        ["crate::Point", "case_insensitive", 3],
+        ["src/shapes.ts::Point", "case_insensitive", 3],
+      ]);
+      expect(result.candidates[0]).toMatchObject({ kind: "method", parent_name: "Canvas" });
+      expect(result.metadata).toMatchObject({ total_count: 5, unambiguous: false });
+    });
+
+    test("matches qualified path suffixes with either separator", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => POINT_ROWS));
+
+      const rust = await service.resolveName({
+        repository: "test-repo",
+        partial: "geometry::Point",
+      });
+      const dotted = await service.resolveName({
+        repository: "test-repo",
+        partial: "Canvas.point",
+      });
+      const file = await service.resolveName({ repository: "test-repo", partial: "shapes::Point" });
+
+      expect(rust.candidates.map((c) => c.qualified_name)).toEqual(["crate::geometry::Point"]);
+      expect(rust.metadata.unambiguous).toBe(true);
+      expect(dotted.candidates.map((c) => c.qualified_name)).toEqual([
+        "crate::render::Canvas::point",
+      ]);
+      expect(file.candidates.map((c) => c.qualified_name)).toEqual(["src/shapes.ts::Point"]);
+    });
+
+    test("prefixes Rust paths of workspace crates with the crate name", async () => {
+      const rows = [
+        nameRow("crate-a/src/geometry.rs", "Point", 3, { kind: "class", crateName: "crate-a" }),
+        nameRow("crate-b/src/geometry.rs", "Point", 3, { kind: "class", crateName: "crate-b" }),
+      ];
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => rows));
+
+      const result = await service.resolveName({
+        repository: "test-repo",
+        partial: "crate_b::geometry::Point",
+      });
+
+      expect(result.candidates.map((c) => c.qualified_name)).toEqual(["crate_b::geometry::Point"]);
+      expect(result.metadata.unambiguous).toBe(true);
+    });
+
+    test("queries the last segment and applies the limit", async () => {
+      const adapter = createCypherMockAdapter(() => POINT_ROWS);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.resolveName({
+        repository: "test-repo",
+        partial: "Point",
+        limit: 1,
+      });
+
+      expect(adapter.runQuery.mock.calls[0]?.[1]).toMatchObject({ name: "Point" });
+      expect(result.candidates.map((c) => c.qualified_name)).toEqual(["crate::geometry::Point"]);
+      expect(result.metadata.total_count).toBe(5);
+    });
+
+    test("rejects an empty partial name", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => []));
+
+      await expect(service.resolveName({ repository: "test-repo", partial: " " })).rejects.toThrow(
+        GraphServiceValidationError
+      );
+    });
+  });
+
+  describe("getTopologicalOrder", () => {
+    const fnRow = (filePath: string, name: string, line: number) => ({
+      id: `Function:test-repo:${filePath}:${name}:${line}`,
+      name,
+      entityType: "function",
+      filePath,
+      startLine: line,
+      endLine: line + 5,
+    });
+    const idOf = (row: { id: string }) => row.id;
+
+    const MAIN = fnRow("src/main.rs", "main", 1);
+    const PARSE = fnRow("src/parser.rs", "parse", 10);
+    const EXPR = fnRow("src/parser.rs", "parse_expr", 30);
+    const TERM = fnRow("src/parser.rs", "parse_term", 50);
+    const LEX = fnRow("src/lexer.rs", "lex", 4);
+    const EXTERNAL = "Function:test-repo:vendor/io.rs:read:1";
+
+    const mockGraph = (rows: ReturnType<typeof fnRow>[]) =>
+      createCypherMockAdapter((cypher) => {
+        if (cypher.includes("[:CALLS]")) {
+          return [
+            { fromId: idOf(MAIN), toId: idOf(PARSE) },
+            { fromId: idOf(PARSE), toId: idOf(EXPR) },
+            { fromId: idOf(EXPR), toId: idOf(TERM) },
+            { fromId: idOf(TERM), toId: idOf(EXPR) },
+            { fromId: idOf(TERM), toId: idOf(LEX) },
+            { fromId: idOf(PARSE), toId: idOf(LEX) },
+            { fromId: idOf(LEX), toId: EXTERNAL },
+          ];
+        }
+        return rows;
+      });
+
+    test("orders callees first and groups mutual recursion", async () => {
+      const service = new GraphAnalysisServiceImpl(mockGraph([MAIN, PARSE, EXPR, TERM, LEX]));
+
+      const result = await service.getTopologicalOrder({ repository: "test-repo" });
+
+      expect(result.groups.map((g) => [g.level, g.cyclic, g.symbols.map((s) => s.name)])).toEqual([
+        [0, false, ["lex"]],
+        [1, true, ["parse_expr", "parse_term"]],
+        [2, false, ["parse"]],
+        [3, false, ["main"]],
+      ]);
+      expect(result.metadata).toMatchObject({
+        symbol_count: 5,
+        group_count: 4,
+        cyclic_group_count: 1,
+        edge_count: 6,
+        truncated: false,
+      });
+    });
+
+    test("ignores calls leaving the path filter", async () => {
+      const scoped = mockGraph([PARSE, EXPR, TERM]);
+      const service = new GraphAnalysisServiceImpl(scoped);
+
+      const result = await service.getTopologicalOrder({
+        repository: "test-repo",
+        path: "./src/parser.rs",
+      });
+
+      expect(scoped.runQuery.mock.calls[0]?.[1]).toMatchObject({
+        path: "src/parser.rs",
+        dirPrefix: "src/parser.rs/",
+      });
+      expect(result.groups.map((g) => g.symbols.map((s) => s.name))).toEqual([
+        ["parse_expr", "parse_term"],
+        ["parse"],
+      ]);
+      expect(result.metadata.edge_count).toBe(3);
+    });
+
+    test("applies the limit without splitting groups", async () => {
+      const service = new GraphAnalysisServiceImpl(mockGraph([MAIN, PARSE, EXPR, TERM, LEX]));
+
+      const result = await service.getTopologicalOrder({ repository: "test-repo", limit: 2 });
+
+      expect(result.groups.map((g) => g.symbols.map((s) => s.name))).toEqual([["lex"]]);
+      expect(result.metadata).toMatchObject({ symbol_count: 5, truncated: true });
+    });
+  });
+
+  describe("getLocalBindings", () => {
+    const FUNCTION_ID = "Function:test-repo:src/lib.rs:function_with_calls:180";
+
+    test("returns each binding with its type, null when untyped", async () => {
+      const adapter = createCypherMockAdapter((cypher) => {
+        if (cypher.includes("DEFINES")) {
+          return [
+            {
+              id: FUNCTION_ID,
+              name: "function_with_calls",
+              entityType: "function",
+              filePath: "src/lib.rs",
+              startLine: 180,
+              endLine: 207,
+            },
+          ];
+        }
+        return [
+          {
+            id: FUNCTION_ID,
+            localNames: ["result", "point"],
+            localTypes: ["", "Point"],
+            localLines: [182, 190],
+          },
+        ];
+      });
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.getLocalBindings({
+        symbol: FUNCTION_ID,
+        repository: "test-repo",
+      });
+
+      expect(adapter.runQuery.mock.calls[0]?.[1]).toMatchObject({ id: FUNCTION_ID });
+      expect(result.functions).toHaveLength(1);
+      expect(result.functions[0]?.locals).toEqual([
+        { name: "result", type: null, line: 182 },
+        { name: "point", type: "Point", line: 190 },
+      ]);
+      expect(result.metadata).toMatchObject({ function_count: 1, local_count: 2 });
+    });
+
+    test("throws EntityNotFoundError when no function matches", async () => {
+      const service = new GraphAnalysisServiceImpl(
+        createCypherMockAdapter(() => [
+          {
+            id: "Class:test-repo:src/lib.rs:Point:3",
+            name: "Point",
+            entityType: "struct",
+            filePath: "src/lib.rs",
+            startLine: 3,
+            endLine: 6,
+          },
+        ])
+      );
+
+      await expect(
+        service.getLocalBindings({ symbol: "Point", repository: "test-repo" })
+      ).rejects.toThrow(EntityNotFoundError);
+    });
+  });
+
+  describe("findLargeTypes", () => {
+    const impls = [
+      { selfType: "Engine", traitName: null, filePath: "src/engine.rs", line: 10, endLine: 40 },
+      { selfType: "Engine", traitName: "Display", filePath: "src/fmt.rs", line: 5, endLine: 12 },
+      { selfType: "Wheel", traitName: null, filePath: "src/engine.rs", line: 50, endLine: 60 },
+    ];
+    const members = [
+      { parentName: "Engine", entityType: "function", filePath: "src/engine.rs", startLine: 12 },
+      { parentName: "Engine", entityType: "function", filePath: "src/engine.rs", startLine: 20 },
+      { parentName: "Engine", entityType: "variable", filePath: "src/engine.rs", startLine: 11 },
+      { parentName: "Engine", entityType: "type_alias", filePath: "src/engine.rs", startLine: 15 },
+      { parentName: "Engine", entityType: "function", filePath: "src/fmt.rs", startLine: 6 },
+      // Past the end of the impl above it, so not counted
+      { parentName: "Engine", entityType: "function", filePath: "src/engine.rs", startLine: 45 },
+      { parentName: "Wheel", entityType: "function", filePath: "src/engine.rs", startLine: 52 },
+    ];
+    const createAdapter = (): ReturnType<typeof createCypherMockAdapter> =>
+      createCypherMockAdapter((cypher) => (cypher.includes("(i:Impl") ? impls : members));
+
+    test("counts methods across inherent and trait impls", async () => {
+      const service = new GraphAnalysisServiceImpl(createAdapter());
+
+      const result = await service.findLargeTypes({ repository: "test-repo", method_threshold: 2 });
+
+      expect(result.types).toHaveLength(1);
+      expect(result.types[0]).toEqual({
+        name: "Engine",
+        member_count: 3,
+        method_count: 3,
+        associated_item_count: 2,
+        impls: [
+          {
+            file_path: "src/engine.rs",
+            start_line: 10,
+            end_line: 40,
+            trait_name: null,
+            method_count: 2,
+            associated_item_count: 2,
+          },
+          {
+            file_path: "src/fmt.rs",
+            start_line: 5,
+            end_line: 12,
+            trait_name: "Display",
+            method_count: 1,
+            associated_item_count: 0,
+          },
+        ],
+      });
+      expect(result.metadata).toMatchObject({ total_count: 1, truncated: false });
+    });
+
+    test("counts associated items when asked and sorts ascending", async () => {
+      const service = new GraphAnalysisServiceImpl(createAdapter());
+
+      const result = await service.findLargeTypes({
+        repository: "test-repo",
+        method_threshold: 1,
+        include_associated_items: true,
+        order: "asc",
+      });
+
+      expect(result.types.map((type) => [type.name, type.member_count])).toEqual([
+        ["Wheel", 1],
+        ["Engine", 5],
+      ]);
+    });
+
+    test("truncates to the limit", async () => {
+      const service = new GraphAnalysisServiceImpl(createAdapter());
+
+      const result = await service.findLargeTypes({
+        repository: "test-repo",
+        method_threshold: 1,
+        limit: 1,
+      });
+
+      expect(result.types.map((type) => type.name)).toEqual(["Engine"]);
+      expect(result.metadata).toMatchObject({ total_count: 2, truncated: true });
+    });
+  });
+
+  describe("getSymbolsAtLines", () => {
+    const entities = [
+      {
+        id: "Function:test-repo:src/geometry.rs:distance:12",
+        name: "distance",
+        entityType: "method",
+        startLine: 12,
+        endLine: 16,
+        parentName: "Point",
+      },
+      {
+        id: "Function:test-repo:src/geometry.rs:square:13",
+        name: "square",
+        entityType: "function",
+        startLine: 13,
+        endLine: 14,
+        parentName: null,
+      },
+    ];
+    const impls = [
+      {
+        id: "Impl:test-repo:src/geometry.rs:10",
+        typeName: "Point",
+        traitName: null,
+        isNegative: null,
+        line: 10,
+        endLine: 30,
+      },
+    ];
+    const createAdapter = (
+      files = [{ path: "src/geometry.rs" }]
+    ): ReturnType<typeof createCypherMockAdapter> =>
+      createCypherMockAdapter((cypher) => {
+        if (cypher.includes("(i:Impl")) return impls;
+        if (cypher.includes("[d:DEFINES]")) return entities;
+        return files;
+      });
+
+    test("orders overlapping symbols innermost first", async () => {
+      const adapter = createAdapter();
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.getSymbolsAtLines({
+        repository: "test-repo",
+        path: "./src/geometry.rs",
+        start_line: 14,
+      });
+
+      expect(adapter.runQuery.mock.calls[1]?.[1]).toMatchObject({
+        path: "src/geometry.rs",
+        startLine: 14,
+        endLine: 14,
+      });
+      expect(result.symbols.map((s) => [s.name, s.entity_type, s.covers_range])).toEqual([
+        ["square", "function", true],
+        ["distance", "method", true],
+        ["impl Point", "impl", true],
+      ]);
+      expect(result.metadata.symbol_count).toBe(3);
+    });
+
+    test("marks symbols that only partly cover the range", async () => {
+      const service = new GraphAnalysisServiceImpl(createAdapter());
+
+      const result = await service.getSymbolsAtLines({
+        repository: "test-repo",
+        path: "src/geometry.rs",
+        start_line: 14,
+        end_line: 20,
+      });
+
+      const byName = new Map(result.symbols.map((s) => [s.name, s.covers_range]));
+      expect(byName.get("square")).toBe(false);
+      expect(byName.get("distance")).toBe(false);
+      expect(byName.get("impl Point")).toBe(true);
+      expect(result.end_line).toBe(20);
+    });
+
+    test("rejects a range that ends before it starts", async () => {
+      const service = new GraphAnalysisServiceImpl(createAdapter());
+
+      await expect(
+        service.getSymbolsAtLines({
+          repository: "test-repo",
+          path: "src/geometry.rs",
+          start_line: 20,
+          end_line: 10,
+        })
+      ).rejects.toThrow(GraphServiceValidationError);
+    });
+
+    test("throws EntityNotFoundError when the file is not indexed", async () => {
+      const service = new GraphAnalysisServiceImpl(createAdapter([]));
+
+      await expect(
+        service.getSymbolsAtLines({
+          repository: "test-repo",
+          path: "src/missing.rs",
+          start_line: 1,
+        })
+      ).rejects.toThrow(EntityNotFoundError);
+    });
+  });
+
+  describe("findExternalDependencies", () => {
+    const imports = [
+      { filePath: "src/main.rs", module: "std::collections::HashMap" },
+      { filePath: "src/main.rs", module: "serde::Deserialize" },
+      { filePath: "src/api.rs", module: "serde::Serialize" },
+      { filePath: "src/api.rs", module: "::serde::Deserialize" },
+      { filePath: "src/api.rs", module: "tokio::sync::Mutex" },
+      { filePath: "src/api.rs", module: "crate::models::User" },
+      { filePath: "src/api.rs", module: "super::db" },
+      { filePath: "src/main.rs", module: "models::User" },
+      { filePath: "src/main.rs", module: "shared::Config" },
+      { filePath: "src/shapes.rs", module: "Shape::Circle" },
+    ];
+    const files = ["src/main.rs", "src/api.rs", "src/models/mod.rs", "src/shapes.rs"];
+    const createAdapter = (): ReturnType<typeof createCypherMockAdapter> =>
+      createCypherMockAdapter((cypher) => {
+        if (cypher.includes("[:IMPORTS]")) return imports;
+        if (cypher.includes("(r:Repository")) return [{ crateNames: ["shared"] }];
+        if (cypher.includes("e.name IN $roots")) return [{ name: "Shape" }];
+        return files.map((path) => ({ path }));
+      });
+
+    test("groups unresolved import roots into crates", async () => {
+      const adapter = createAdapter();
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.findExternalDependencies({ repository: "test-repo" });
+
+      expect(result.dependencies).toEqual([
+        {
+          name: "serde",
+          is_std: false,
+          reference_count: 3,
+          file_count: 2,
+          paths: ["serde::Deserialize", "serde::Serialize"],
+        },
+        {
+          name: "std",
+          is_std: true,
+          reference_count: 1,
+          file_count: 1,
+          paths: ["std::collections::HashMap"],
+        },
+        {
+          name: "tokio",
+          is_std: false,
+          reference_count: 1,
+          file_count: 1,
+          paths: ["tokio::sync::Mutex"],
+        },
+      ]);
+      expect(result.metadata).toMatchObject({ std_count: 1, third_party_count: 2 });
+      const symbolQuery = adapter.runQuery.mock.calls.find((call) =>
+        String(call[0]).includes("e.name IN $roots")
+      );
+      expect(symbolQuery?.[1]).toMatchObject({ roots: ["std", "serde", "tokio", "Shape"] });
+    });
+
+    test("leaves out the standard library when asked to", async () => {
+      const service = new GraphAnalysisServiceImpl(createAdapter());
+
+      const result = await service.findExternalDependencies({
+        repository: "test-repo",
+        include_std: false,
+      });
+
+      expect(result.dependencies.map((d) => d.name)).toEqual(["serde", "tokio"]);
+      expect(result.metadata.std_count).toBe(1);
+    });
+  });
+
+  describe("findFunctionsByArity", () => {
+    const fnRow = (name: string, paramNames: string[], isExported = true) => ({
+      id: `Function:test-repo:src/lib.rs:${name}:1`,
+      name,
+      entityType: paramNames[0] === "self" ? "method" : "function",
+      kind: null,
+      filePath: "src/lib.rs",
+      startLine: 1,
+      endLine: 3,
+      paramNames,
+      paramTypes: paramNames.map((param) => (param === "self" ? "&self" : "u32")),
+      returnType: null,
+      isAsync: false,
+      isExported,
+    });
+    const rows = [
+      fnRow("new", []),
+      fnRow("scale", ["self", "factor"]),
+      fnRow("blend", ["a", "b", "c", "d", "e", "f"]),
+      fnRow("draw", ["self", "x", "y", "w", "h", "color"], false),
+    ];
+
+    test("filters on the parameter count, leaving out self receivers", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => rows));
+
+      const result = await service.findFunctionsByArity({ repository: "test-repo", min_params: 5 });
+
+      expect(result.functions.map((f) => [f.name, f.param_count, f.has_receiver])).toEqual([
+        ["blend", 6, false],
+        ["draw", 5, true],
+      ]);
+      expect(result.functions[1]?.params[0]).toEqual({ name: "self", type: "&self" });
+      expect(result.max_params).toBeNull();
+    });
+
+    test("counts receivers when asked and finds nullary functions", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => rows));
+
+      const nullary = await service.findFunctionsByArity({
+        repository: "test-repo",
+        max_params: 0,
+      });
+      const withReceiver = await service.findFunctionsByArity({
+        repository: "test-repo",
+        min_params: 2,
+        max_params: 2,
+        count_receiver: true,
+      });
+
+      expect(nullary.functions.map((f) => f.name)).toEqual(["new"]);
+      expect(withReceiver.functions.map((f) => [f.name, f.param_count])).toEqual([["scale", 2]]);
+    });
+
+    test("passes the export and path filters to the query", async () => {
+      const adapter = createCypherMockAdapter(() => []);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      await service.findFunctionsByArity({
+        repository: "test-repo",
+        exported_only: true,
+        path: "./src/",
+      });
+
+      const [cypher, params] = adapter.runQuery.mock.calls[0] as [string, Record<string, unknown>];
+      expect(cypher).toContain("$exportedOnly = false OR e.isExported = true");
+      expect(params).toMatchObject({ exportedOnly: true, path: "src", dirPrefix: "src/" });
+    });
+
+    test("rejects a minimum above the maximum", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => []));
+
+      await expect(
+        service.findFunctionsByArity({ repository: "test-repo", min_params: 3, max_params: 1 })
+      ).rejects.toThrow(GraphServiceValidationError);
+    });
+  });
+
+  describe("findCallSites", () => {
+    const CALLEE_ID = "Function:test-repo:src/lib.rs:function_with_params:40";
+    const calleeRow = {
+      id: CALLEE_ID,
+      name: "function_with_params",
+      entityType: "function",
+      filePath: "src/lib.rs",
+      startLine: 40,
+      endLine: 44,
+    };
+    const callerRow = (name: string, filePath: string, startLine: number) => ({
+      calleeId: CALLEE_ID,
+      id: `Function:test-repo:${filePath}:${name}:${startLine}`,
+      name,
+      entityType: "function",
+      filePath,
+      startLine,
+      endLine: startLine + 10,
+    });
+    const adapterFor = (edges: Record<string, unknown>[]) =>
+      createCypherMockAdapter((cypher) => (cypher.includes("DEFINES") ? [calleeRow] : edges));
+
+    test("expands captured call sites with their arguments", async () => {
+      const service = new GraphAnalysisServiceImpl(
+        adapterFor([
+          {
+            ...callerRow("main", "src/main.rs", 1),
+            line: 3,
+            callSiteLines: [7, 3],
+            callSiteArguments: ['["\\"Rust\\"","1"]', '["\\"World\\"","3"]'],
+          },
+          {
+            ...callerRow("run", "src/cli.rs", 20),
+            line: 25,
+            callSiteLines: null,
+            callSiteArguments: null,
+          },
+        ])
+      );
+
+      const result = await service.findCallSites({ symbol: CALLEE_ID, repository: "test-repo" });
+
+      const sites = result.call_sites.map((site) => [site.caller.name, site.line, site.arguments]);
+      expect(sites).toEqual([
+        ["run", 25, null],
+        ["main", 3, ['"World"', "3"]],
+        ["main", 7, ['"Rust"', "1"]],
+      ]);
+      expect(result.metadata).toMatchObject({
+        callee_count: 1,
+        total_count: 3,
+        arguments_captured: true,
+      });
+    });
+
+    test("filters on argument text, skipping sites without captured arguments", async () => {
+      const service = new GraphAnalysisServiceImpl(
+        adapterFor([
+          {
+            ...callerRow("main", "src/main.rs", 1),
+            line: 3,
+            callSiteLines: [3, 7],
+            callSiteArguments: ['["\\"World\\"","3"]', '["name","1"]'],
+          },
+          {
+            ...callerRow("run", "src/cli.rs", 20),
+            line: 25,
+            callSiteLines: null,
+            callSiteArguments: null,
+          },
+        ])
+      );
+
+      const result = await service.findCallSites({
+        symbol: CALLEE_ID,
+        repository: "test-repo",
+        argument_contains: "World",
+      });
+
+      expect(result.call_sites).toHaveLength(1);
+      expect(result.call_sites[0]).toMatchObject({ line: 3, arguments: ['"World"', "3"] });
+    });
+
+    test("throws EntityNotFoundError when no function matches", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => []));
+
+      await expect(
+        service.findCallSites({ symbol: "missing", repository: "test-repo" })
+      ).rejects.toThrow(EntityNotFoundError);
+    });
+  });
+
+  describe("suggestImport", () => {
+    const rustItem = (
+      label: string,
+      name: string,
+      filePath: string,
+      props: Record<string, unknown> = {}
+    ) => ({
+      id: `${label}:test-repo:${filePath}:${name}:1`,
+      name,
+      entityType: label === "Function" ? "function" : "class",
+      kind: null,
+      filePath,
+      startLine: 1,
+      endLine: 5,
+      parentName: null,
+      visibility: "pub",
+      isExported: true,
+      implementedTrait: null,
+      crateName: null,
+      ...props,
+    });
+    const adapterFor = (fixture: {
+      targets: Record<string, unknown>[];
+      owners?: Record<string, unknown>[];
+      reexports?: Record<string, unknown>[];
+      callerCrate?: string;
+    }) =>
+      createCypherMockAdapter((cypher) => {
+        if (cypher.includes("IMPORTS")) return fixture.reexports ?? [];
+        if (cypher.includes("DEFINES") || cypher.includes("e.id IN $ids")) return fixture.targets;
+        if (cypher.includes("e.name IN $names")) return fixture.owners ?? [];
+        return [{ crateName: fixture.callerCrate ?? null }];
+      });
+
+    test("prefers a shorter path through a pub use re-export", async () => {
+      const service = new GraphAnalysisServiceImpl(
+        adapterFor({
+          targets: [rustItem("Class", "Point", "src/geometry/shapes.rs")],
+          reexports: [
+            {
+              filePath: "src/lib.rs",
+              crateName: null,
+              source: "geometry::shapes::Point",
+              importType: "value",
+              aliases: [],
+              visibility: "pub",
+            },
+          ],
+        })
+      );
+
+      const result = await service.suggestImport({
+        symbol: "Point",
+        repository: "test-repo",
+        from_path: "src/render/canvas.rs",
+      });
+
+      expect(result.from_module).toBe("crate::render::canvas");
+      expect(result.suggestions).toHaveLength(1);
+      expect(result.suggestions[0]).toMatchObject({
+        qualified_name: "crate::geometry::shapes::Point",
+        status: "importable",
+        imports: [
+          {
+            name: "Point",
+            path: "crate::Point",
+            use_statement: "use crate::Point;",
+            via_reexport: "src/lib.rs",
+          },
+        ],
+        reason: null,
+      });
+    });
+
+    test("reports items private to another module as inaccessible", async () => {
+      const service = new GraphAnalysisServiceImpl(
+        adapterFor({
+          targets: [
+            rustItem("Function", "helper", "src/geometry/shapes.rs", {
+              visibility: null,
+              isExported: false,
+            }),
+          ],
+        })
+      );
+
+      const outside = await service.suggestImport({
+        symbol: "helper",
+        repository: "test-repo",
+        from_path: "src/render/canvas.rs",
+      });
+      const inside = await service.suggestImport({
+        symbol: "helper",
+        repository: "test-repo",
+        from_path: "src/geometry/shapes.rs",
+      });
+
+      expect(outside.suggestions[0]).toMatchObject({ status: "inaccessible", imports: [] });
+      expect(outside.suggestions[0]?.reason).toBe(
+        "`helper` is private in crate::geometry::shapes and not visible from crate::render::canvas"
+      );
+      expect(inside.suggestions[0]).toMatchObject({
+        status: "in_scope",
+        imports: [],
+        reason: null,
+      });
+    });
+
+    test("imports the type and trait of a trait method", async () => {
+      const service = new GraphAnalysisServiceImpl(
+        adapterFor({
+          targets: [
+            rustItem("Function", "area", "src/geometry/shapes.rs", {
+              parentName: "Circle",
+              implementedTrait: "Shape",
+              visibility: null,
+              isExported: false,
+            }),
+          ],
+          owners: [
+            rustItem("Class", "Circle", "src/geometry/shapes.rs"),
+            rustItem("Class", "Shape", "src/geometry/mod.rs", { kind: "interface" }),
+          ],
+        })
+      );
+
+      const result = await service.suggestImport({
+        symbol: "area",
+        repository: "test-repo",
+        from_path: "src/main.rs",
+      });
+
+      expect(result.suggestions[0]?.status).toBe("importable");
+      expect(result.suggestions[0]?.imports.map((entry) => entry.use_statement)).toEqual([
+        "use crate::geometry::shapes::Circle;",
+        "use crate::geometry::Shape;",
+      ]);
+    });
+
+    test("only allows pub items from other crates, named by the crate", async () => {
+      const service = new GraphAnalysisServiceImpl(
+        adapterFor({
+          targets: [
+            rustItem("Class", "Point", "crates/geo-core/src/point.rs", { crateName: "geo-core" }),
+            rustItem("Function", "round", "crates/geo-core/src/point.rs", {
+              crateName: "geo-core",
+              visibility: "pub(crate)",
+            }),
+          ],
+          callerCrate: "app",
+        })
+      );
+
+      const result = await service.suggestImport({
+        symbol: "Point",
+        repository: "test-repo",
+        from_path: "crates/app/src/main.rs",
+      });
+
+      expect(result.suggestions.map((entry) => entry.imports[0]?.path ?? entry.reason)).toEqual([
+        "geo_core::point::Point",
+        "`round` is pub(crate) and not visible outside its crate",
+      ]);
+    });
+
+    test("throws EntityNotFoundError when the file is not indexed", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => []));
+
+      await expect(
+        service.suggestImport({
+          symbol: "Point",
+          repository: "test-repo",
+          from_path: "src/missing.rs",
+        })
+      ).rejects.toThrow(EntityNotFoundError);
+    });
+  });
+
+  describe("findLeakyApi", () => {
+    const rustItem = (
+      label: string,
+      name: string,
+      filePath: string,
+      props: Record<string, unknown> = {}
+    ) => ({
+      id: `${label}:test-repo:${filePath}:${name}:1`,
+      name,
+      entityType: label === "Function" ? "function" : "class",
+      kind: null,
+      filePath,
+      startLine: 1,
+      endLine: 5,
+      parentName: null,
+      visibility: "pub",
+      isExported: true,
+      implementedTrait: null,
+      paramNames: label === "Function" ? [] : null,
+      paramTypes: label === "Function" ? [] : null,
+      returnType: null,
+      crateName: null,
+      ...props,
+    });
+    const adapterFor = (fixture: {
+      functions: Record<string, unknown>[];
+      types: Record<string, unknown>[];
+    }) =>
+      createCypherMockAdapter((cypher) =>
+        cypher.includes("e.name IN $names") ? fixture.types : fixture.functions
+      );
+
+    test("reports pub functions whose signatures name non-public types", async () => {
+      const adapter = adapterFor({
+        functions: [
+          rustItem("Function", "acquire", "src/pool.rs", {
+            paramNames: ["pool", "timeout"],
+            paramTypes: ["&Pool", "Duration"],
+            returnType: "Option<Handle>",
+          }),
+        ],
+        types: [
+          rustItem("Class", "Pool", "src/pool.rs"),
+          rustItem("Class", "Handle", "src/pool.rs", { visibility: "pub(crate)" }),
+        ],
+      });
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      const result = await service.findLeakyApi({ repository: "test-repo", path: "src/" });
+
+      expect(result.metadata).toMatchObject({ functions_checked: 1, total_count: 1 });
+      expect(result.leaks[0]).toMatchObject({
+        item: { name: "acquire", file_path: "src/pool.rs" },
+        qualified_name: "crate::pool::acquire",
+        parameter: null,
+        type_annotation: "Option<Handle>",
+        private_type: { name: "Handle", file_path: "src/pool.rs" },
+        private_type_qualified_name: "crate::pool::Handle",
+        private_type_visibility: "pub(crate)",
+      });
+      expect(adapter.runQuery.mock.calls[0]?.[1]).toMatchObject({
+        path: "src",
+        dirPrefix: "src/",
+      });
+    });
+
+    test("resolves qualified names by module and skips ambiguous plain names", async () => {
+      const service = new GraphAnalysisServiceImpl(
+        adapterFor({
+          functions: [
+            rustItem("Function", "open", "src/lib.rs", {
+              paramNames: ["config", "id"],
+              paramTypes: ["crate::config::Config", "Id"],
+            }),
+          ],
+          types: [
+            rustItem("Class", "Config", "src/config.rs", { visibility: "pub(crate)" }),
+            rustItem("Class", "Config", "src/legacy.rs", { visibility: "pub(crate)" }),
+            rustItem("Class", "Id", "src/a.rs", { visibility: "pub(crate)" }),
+            rustItem("Class", "Id", "src/b.rs", { visibility: "pub(crate)" }),
+          ],
+        })
+      );
+
+      const result = await service.findLeakyApi({ repository: "test-repo" });
+
+      expect(result.leaks.map((leak) => [leak.parameter, leak.private_type.file_path])).toEqual([
+        ["config", "src/config.rs"],
+      ]);
+    });
+
+    test("skips methods of non-public types", async () => {
+      const service = new GraphAnalysisServiceImpl(
+        adapterFor({
+          functions: [
+            rustItem("Function", "new", "src/pool.rs", {
+              parentName: "Handle",
+              returnType: "Handle",
+            }),
+            rustItem("Function", "handle", "src/pool.rs", {
+              parentName: "Pool",
+              paramNames: ["self"],
+              paramTypes: ["&self"],
+              returnType: "&Handle",
+            }),
+          ],
+          types: [
+            rustItem("Class", "Pool", "src/pool.rs"),
+            rustItem("Class", "Handle", "src/pool.rs", { visibility: null, isExported: false }),
+          ],
+        })
+      );
+
+      const result = await service.findLeakyApi({ repository: "test-repo" });
+
+      expect(result.metadata.functions_checked).toBe(1);
+      expect(result.leaks).toHaveLength(1);
+      expect(result.leaks[0]).toMatchObject({
+        qualified_name: "crate::pool::Pool::handle",
+        private_type_visibility: null,
+      });
+    });
+  });
+
+  describe("grepStrings", () => {
+    const LITERAL_ROWS = [
+      {
+        value: "Session expired",
+        filePath: "src/auth.ts",
+        line: 12,
+        column: 18,
+        enclosingSymbol: "AuthService.refresh",
+      },
+      {
+        value: "session expired: %s",
+        filePath: "src/log.ts",
+        line: 3,
+        column: 0,
+        enclosingSymbol: null,
+      },
+    ];
+
+    test("returns literals with their enclosing symbol", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => LITERAL_ROWS));
+
+      const result = await service.grepStrings({ repository: "test-repo", query: "expired" });
+
+      expect(result.matches).toHaveLength(2);
+      expect(result.matches[0]).toEqual({
+        value: "Session expired",
+        file_path: "src/auth.ts",
+        line: 12,
+        column: 18,
+        enclosing_symbol: "AuthService.refresh",
+      });
+      expect(result.matches[1]).not.toHaveProperty("enclosing_symbol");
+      expect(result.metadata.total_matches).toBe(2);
+    });
+
+    test("passes case sensitivity and normalized path to the query", async () => {
+      const adapter = createCypherMockAdapter(() => []);
+      const service = new GraphAnalysisServiceImpl(adapter);
+
+      await service.grepStrings({
+        repository: "test-repo",
+        query: "Expired",
+        case_sensitive: true,
+        path: "./src/",
+      });
+
+      const [cypher, params] = adapter.runQuery.mock.calls[0] as [string, Record<string, unknown>];
+      expect(cypher).toContain("HAS_STRING_LITERAL");
+      expect(params["caseSensitive"]).toBe(true);
+      expect(params["query"]).toBe("Expired");
+      expect(params["path"]).toBe("src");
+      expect(params["dirPrefix"]).toBe("src/");
+    });
+
+    test("truncates to the limit while counting every match", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => LITERAL_ROWS));
+
+      const result = await service.grepStrings({ repository: "test-repo", query: "e", limit: 1 });
+
+      expect(result.matches).toHaveLength(1);
+      expect(result.metadata.truncated).toBe(true);
+    });
+
+    test("rejects an empty query", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter());
+
+      await expect(service.grepStrings({ repository: "test-repo", query: "" })).rejects.toThrow(
+        GraphServiceValidationError
+      );
+    });
+  });
+
+  describe("call graph coupling", () => {
+    const fn = (name: string) => `Function:test-repo:src/app.ts:${name}:1`;
+    const functionRow = (name: string) => ({
+      id: fn(name),
+      name,
+      entityType: "function",
+      filePath: "src/app.ts",
+      startLine: 1,
+      endLine: 5,
+    });
+    // main -> parse -> validate -> parse (cycle); main -> log; walk -> walk (recursion)
+    const CALL_EDGES = [
+      { fromId: fn("main"), toId: fn("parse") },
+      { fromId: fn("main"), toId: fn("log") },
+      { fromId: fn("parse"), toId: fn("validate") },
+      { fromId: fn("validate"), toId: fn("parse") },
+      { fromId: fn("walk"), toId: fn("walk") },
+    ];
+    const responder = (cypher: string, params: Record<string, unknown>): unknown[] => {
+      if (cypher.includes("[:CALLS]")) return CALL_EDGES;
+      if (cypher.includes("DEFINES")) return [functionRow(String(params["name"]))];
+      const ids = (params["ids"] as string[] | undefined) ?? [];
+      return ids.map((id) => functionRow(id.split(":")[3] ?? ""));
+    };
+
+    test("reports fan-in, fan-out and cycle membership of a function", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(responder));
+
+      const result = await service.getCouplingMetrics({
+        symbol: "parse",
+        repository: "test-repo",
+      });
+
+      expect(result.matches).toHaveLength(1);
+      expect(result.matches[0]).toMatchObject({
+        name: "parse",
+        fan_in: 2,
+        fan_out: 1,
+        coupling: 3,
+        recursive: false,
+        in_cycle: true,
+      });
+    });
+
+    test("excludes self-calls from degrees but flags recursion", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(responder));
+
+      const result = await service.getCouplingMetrics({
+        symbol: "walk",
+        repository: "test-repo",
+      });
+
+      expect(result.matches[0]).toMatchObject({
+        fan_in: 0,
+        fan_out: 0,
+        recursive: true,
+        in_cycle: true,
+      });
+    });
+
+    test("throws when no function matches", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => []));
+
+      await expect(
+        service.getCouplingMetrics({ symbol: "missing", repository: "test-repo" })
+      ).rejects.toThrow(EntityNotFoundError);
+    });
+
+    test("ranks functions by combined degree", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(responder));
+
+      const result = await service.findMostCoupled({ repository: "test-repo", limit: 2 });
+
+      expect(result.functions.map((f) => [f.name, f.coupling])).toEqual([
+        ["parse", 3],
+        ["validate", 2],
+      ]);
+      expect(result.metadata.total_count).toBe(5);
+      expect(result.metadata.call_edge_count).toBe(5);
+    });
+
+    test("finds the shortest call path in call direction", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(responder));
+
+      const result = await service.findCallPaths({
+        from: "main",
+        to: "validate",
+        repository: "test-repo",
+      });
+
+      expect(result.paths).toHaveLength(1);
+      expect(result.paths[0]!.length).toBe(2);
+      expect(result.paths[0]!.functions.map((f) => f.name)).toEqual(["main", "parse", "validate"]);
+      expect(result.metadata).toMatchObject({ path_count: 1, truncated: false, max_depth: 10 });
+    });
+
+    test("reports no path against call direction or beyond the depth cap", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(responder));
+
+      const reversed = await service.findCallPaths({
+        from: "log",
+        to: "main",
+        repository: "test-repo",
+      });
+      const tooDeep = await service.findCallPaths({
+        from: "main",
+        to: "validate",
+        repository: "test-repo",
+        max_depth: 1,
+      });
+
+      expect(reversed.paths).toEqual([]);
+      expect(tooDeep.paths).toEqual([]);
+    });
+
+    test("throws when an endpoint matches no function", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => []));
+
+      await expect(
+        service.findCallPaths({ from: "main", to: "missing", repository: "test-repo" })
+      ).rejects.toThrow(EntityNotFoundError);
+    });
+  });
+
+  describe("findHotspots", () => {
+    const symbolRow = (filePath: string, name: string, entityType = "function") => ({
+      id: `${entityType === "class" ? "Class" : "Function"}:test-repo:${filePath}:${name}:1`,
+      name,
+      entityType,
+      filePath,
+      startLine: 1,
+      endLine: 5,
+    });
+    const FUNCTIONS = [
+      symbolRow("src/db.ts", "query"),
+      symbolRow("src/api.ts", "getUser"),
+      symbolRow("src/api.ts", "listUsers"),
+      symbolRow("src/jobs.ts", "sync"),
+    ];
+    const fnId = (name: string) => FUNCTIONS.find((row) => row.name === name)?.id ?? "";
+    // getUser, listUsers and sync call query; sync calls getUser; query recurses
+    const CALL_EDGES = [
+      { fromId: fnId("getUser"), toId: fnId("query") },
+      { fromId: fnId("listUsers"), toId: fnId("query") },
+      { fromId: fnId("sync"), toId: fnId("query") },
+      { fromId: fnId("sync"), toId: fnId("getUser") },
+      { fromId: fnId("query"), toId: fnId("query") },
+    ];
+    const IMPORTS = [
+      { filePath: "src/api.ts", resolvedPath: "src/db.ts", importedSymbols: ["query", "Pool"] },
+      { filePath: "src/jobs.ts", resolvedPath: "src/db.ts", importedSymbols: ["query"] },
+      { filePath: "src/jobs.ts", resolvedPath: "src/api.ts", importedSymbols: ["getUser"] },
+      { filePath: "src/cli.ts", resolvedPath: "src/db.ts", importedSymbols: ["Pool"] },
+    ];
+    const responder = (cypher: string, params: Record<string, unknown>): unknown[] => {
+      if (cypher.includes("[:CALLS]")) return CALL_EDGES;
+      if (cypher.includes("[r:IMPORTS]")) return IMPORTS;
+      if (cypher.includes("parentName IS NULL")) {
+        return [...FUNCTIONS, symbolRow("src/db.ts", "Pool", "class")].filter(
+          (row) =>
+            (params["paths"] as string[]).includes(row.filePath) &&
+            (params["names"] as string[]).includes(row.name)
+        );
+      }
+      const ids = (params["ids"] as string[] | undefined) ?? [];
+      return FUNCTIONS.filter((row) => ids.includes(row.id));
+    };
+
+    test("ranks symbols by callers plus importing files", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(responder));
+
+      const result = await service.findHotspots({ repository: "test-repo" });
+
+      expect(result.hotspots.map((h) => [h.name, h.fan_in])).toEqual([
+        ["query", 5],
+        // Ties are broken by the number of referencing modules
+        ["Pool", 2],
+        ["getUser", 2],
+      ]);
+      expect(result.hotspots[0]).toMatchObject({
+        file_path: "src/db.ts",
+        caller_count: 3,
+        importer_count: 2,
+        referencing_modules: ["src/api.ts", "src/jobs.ts"],
+      });
+      expect(result.hotspots[1]).toMatchObject({
+        entity_type: "class",
+        caller_count: 0,
+        referencing_modules: ["src/api.ts", "src/cli.ts"],
+      });
+      expect(result.metadata).toMatchObject({
+        total_count: 3,
+        call_edge_count: 5,
+        import_reference_count: 5,
+      });
+    });
+
+    test("applies the limit after ranking", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(responder));
+
+      const result = await service.findHotspots({ repository: "test-repo", limit: 1 });
+
+      expect(result.hotspots.map((h) => h.name)).toEqual(["query"]);
+      expect(result.metadata.total_count).toBe(3);
+    });
+
+    test("returns no hotspots for a repository without references", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => []));
+
+      const result = await service.findHotspots({ repository: "test-repo" });
+
+      expect(result.hotspots).toEqual([]);
+      expect(result.metadata.total_count).toBe(0);
+    });
+  });
+
+  describe("getCouplingReport", () => {
+    // api and jobs call into db; jobs also calls api; db recurses
+    const CALLS = [
+      { fromFile: "src/api.ts", toFile: "src/db.ts" },
+      { fromFile: "src/api.ts", toFile: "src/db.ts" },
+      { fromFile: "src/jobs.ts", toFile: "src/db.ts" },
+      { fromFile: "src/jobs.ts", toFile: "src/api.ts" },
+      { fromFile: "src/db.ts", toFile: "src/db.ts" },
+    ];
+    const IMPORTS = [
+      { fromFile: "src/api.ts", toFile: "src/db.ts" },
+      { fromFile: "src/jobs.ts", toFile: "src/db.ts" },
+      { fromFile: "src/jobs.ts", toFile: "src/api.ts" },
+      { fromFile: "src/cli.ts", toFile: "src/db.ts" },
+    ];
+    const responder = (cypher: string): unknown[] => {
+      if (cypher.includes("[:CALLS]")) return CALLS;
+      if (cypher.includes("[r:IMPORTS]")) return IMPORTS;
+      return [];
+    };
+
+    test("scores each file by afferent and efferent coupling, most unstable first", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(responder));
+
+      const result = await service.getCouplingReport({ repository: "test-repo" });
+
+      expect(result.granularity).toBe("file");
+      expect(result.modules).toEqual([
+        {
+          module: "src/jobs.ts",
+          afferent: 0,
+          efferent: 2,
+          instability: 1,
+          incoming_edges: 0,
+          outgoing_edges: 4,
+        },
+        {
+          module: "src/cli.ts",
+          afferent: 0,
+          efferent: 1,
+          instability: 1,
+          incoming_edges: 0,
+          outgoing_edges: 1,
+        },
+        {
+          module: "src/api.ts",
+          afferent: 1,
+          efferent: 1,
+          instability: 0.5,
+          incoming_edges: 2,
+          outgoing_edges: 3,
+        },
+        {
+          module: "src/db.ts",
+          afferent: 3,
+          efferent: 0,
+          instability: 0,
+          incoming_edges: 6,
+          outgoing_edges: 0,
+        },
+      ]);
+      expect(result.pairs[0]).toEqual({
+        from: "src/api.ts",
+        to: "src/db.ts",
+        calls: 2,
+        imports: 1,
+      });
+      expect(result.metadata).toMatchObject({
+        module_count: 4,
+        pair_count: 4,
+        cross_module_calls: 4,
+        cross_module_imports: 4,
+        truncated: false,
+      });
+    });
+
+    test("sorts by the requested metric and direction", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(responder));
+
+      const result = await service.getCouplingReport({
+        repository: "test-repo",
+        sort_by: "afferent",
+        order: "asc",
+        limit: 2,
+      });
+
+      expect(result.modules.map((m) => m.module)).toEqual(["src/jobs.ts", "src/cli.ts"]);
+      expect(result.metadata).toMatchObject({ module_count: 4, truncated: true });
+    });
+
+    test("reports only modules and pairs in the path scope", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(responder));
+
+      const result = await service.getCouplingReport({
+        repository: "test-repo",
+        path: "./src/api.ts",
+      });
+
+      expect(result.modules.map((m) => m.module)).toEqual(["src/api.ts"]);
+      expect(result.pairs.map((p) => [p.from, p.to])).toEqual([
+        ["src/api.ts", "src/db.ts"],
+        ["src/jobs.ts", "src/api.ts"],
+      ]);
+    });
+
+    test("groups files by directory at directory granularity", async () => {
+      const service = new GraphAnalysisServiceImpl(
+        createCypherMockAdapter((cypher) =>
+          cypher.includes("[:CALLS]")
+            ? [
+                { fromFile: "src/api/users.rs", toFile: "src/db/pool.rs" },
+                { fromFile: "src/api/users.rs", toFile: "src/api/auth.rs" },
+                { fromFile: "src/lib.rs", toFile: "src/api/users.rs" },
+              ]
+            : []
+        )
+      );
+
+      const result = await service.getCouplingReport({
+        repository: "test-repo",
+        granularity: "directory",
+        sort_by: "module",
+        order: "asc",
+      });
+
+      expect(result.modules.map((m) => [m.module, m.afferent, m.efferent])).toEqual([
+        ["src", 0, 1],
+        ["src/api", 1, 1],
+        ["src/db", 1, 0],
+      ]);
+      expect(result.metadata.cross_module_calls).toBe(2);
+    });
+
+    test("rejects an unknown sort metric", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter());
+
+      await expect(
+        service.getCouplingReport({
+          repository: "test-repo",
+          sort_by: "fan_in" as "afferent",
+        })
+      ).rejects.toThrow(GraphServiceValidationError);
+    });
+  });
+
+  describe("getTypeHierarchy", () => {
+    const typeRow = (
+      name: string,
+      filePath: string,
+      line: number,
+      properties: Partial<{
+        entityType: string;
+        superclass: string;
+        interfaces: string[];
+        typeParameters: string[];
+        supertraits: string[];
+        whereClause: string;
+        attributes: string[];
+      }> = {}
+    ) => ({
+      id: `Class:test-repo:${filePath}:${name}:${line}`,
+      name,
+      entityType: properties.entityType ?? "class",
+      filePath,
+      startLine: line,
+      endLine: line + 5,
+      superclass: properties.superclass ?? null,
+      interfaces: properties.interfaces ?? [],
+      typeParameters: properties.typeParameters ?? [],
+      supertraits: properties.supertraits ?? null,
+      whereClause: properties.whereClause ?? null,
+      attributes: properties.attributes ?? null,
+    });
+
+    const TYPES = [
+      typeRow("Circle", "src/shapes.rs", 5, {
+        typeParameters: ["T: Float"],
+        whereClause: "where\n    T: Copy,",
+        attributes: ["derive(Debug, Clone)", "non_exhaustive"],
+      }),
+      typeRow("Shape", "src/shapes.rs", 1, { entityType: "interface" }),
+      typeRow("Dog", "src/Dog.java", 3, { superclass: "Animal", interfaces: ["Pet"] }),
+      typeRow("Animal", "src/Animal.java", 1, { superclass: "LivingThing" }),
+      typeRow("LivingThing", "src/LivingThing.java", 1),
+      typeRow("Companion", "src/pets.rs", 10, {
+        entityType: "interface",
+        supertraits: ["Creature", "std::fmt::Debug"],
+      }),
+      typeRow("Creature", "src/pets.rs", 1, { entityType: "interface" }),
+    ];
+
+    const IMPLS = [
+      {
+        selfType: "Circle",
+        traitName: "Shape",
+        filePath: "src/shapes.rs",
+        line: 20,
+        typeParameters: [],
+        whereClause: [],
+        isNegative: false,
+      },
+      {
+        selfType: "Circle",
+        traitName: "From<T>",
+        filePath: "src/convert.rs",
+        line: 4,
+        typeParameters: ["T: Into<f64>"],
+        whereClause: [],
+        isNegative: false,
+      },
+      {
+        selfType: "Circle",
+        traitName: "Sync",
+        filePath: "src/shapes.rs",
+        line: 30,
+        typeParameters: [],
+        whereClause: [],
+        isNegative: true,
+      },
+    ];
+
+    const responder = (cypher: string, params: Record<string, unknown>): unknown[] => {
+      if (cypher.includes("DEFINES")) {
+        return TYPES.filter((row) => row.name === params["name"]);
+      }
+      if (cypher.includes("HAS_IMPL")) {
+        return IMPLS.filter((impl) => (params["names"] as string[]).includes(impl.selfType));
+      }
+      if (cypher.includes("t.superclass")) {
+        return TYPES.filter(
+          (row) =>
+            (params["ids"] as string[]).includes(row.id) ||
+            (params["names"] as string[]).includes(row.name)
+        );
+      }
+      return [];
+    };
+
+    test("assembles Rust traits, derives and generic bounds", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(responder));
+
+      const result = await service.getTypeHierarchy({
+        type_name: "Circle",
+        repository: "test-repo",
+      });
+
+      expect(result.types.map((t) => [t.name, t.depth])).toEqual([
+        ["Circle", 0],
+        ["Shape", 1],
+      ]);
+      const circle = result.types[0]!;
+      expect(circle.derives).toEqual(["Debug", "Clone"]);
+      expect(circle.generic_bounds).toEqual([{ name: "T", bounds: ["Float", "Copy"] }]);
+      expect(circle.traits.map((t) => [t.name, t.definition_ids, t.negative])).toEqual([
+        ["Shape", ["Class:test-repo:src/shapes.rs:Shape:1"], false],
+        ["From<T>", [], false],
+        ["Sync", [], true],
+      ]);
+      expect(circle.traits[1]!.conditions).toEqual([{ name: "T", bounds: ["Into<f64>"] }]);
+      expect(circle.traits[0]!.conditions).toEqual([]);
+      expect(result.metadata).toMatchObject({
+        definition_count: 1,
+        supertype_count: 1,
+        truncated: false,
+      });
+    });
+
+    test("follows superclasses and interfaces up to max_depth", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(responder));
+
+      const result = await service.getTypeHierarchy({
+        type_name: "Dog",
+        repository: "test-repo",
+        max_depth: 1,
+      });
+
+      expect(result.types.map((t) => [t.name, t.depth])).toEqual([
+        ["Dog", 0],
+        ["Animal", 1],
+      ]);
+      expect(result.types[0]!.superclass).toEqual({
+        name: "Animal",
+        definition_ids: ["Class:test-repo:src/Animal.java:Animal:1"],
+      });
+      expect(result.types[0]!.interfaces).toEqual([{ name: "Pet", definition_ids: [] }]);
+      expect(result.types[1]!.superclass?.definition_ids).toEqual([
+        "Class:test-repo:src/LivingThing.java:LivingThing:1",
+      ]);
+      expect(result.metadata).toMatchObject({ supertype_count: 1, max_depth: 1, truncated: true });
+    });
+
+    test("follows the supertraits a trait requires", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(responder));
+
+      const result = await service.getTypeHierarchy({
+        type_name: "Companion",
+        repository: "test-repo",
+      });
+
+      expect(result.types.map((t) => [t.name, t.depth])).toEqual([
+        ["Companion", 0],
+        ["Creature", 1],
+      ]);
+      expect(result.types[0]!.supertraits).toEqual([
+        { name: "Creature", definition_ids: ["Class:test-repo:src/pets.rs:Creature:1"] },
+        { name: "std::fmt::Debug", definition_ids: [] },
+      ]);
+      expect(result.types[1]!.supertraits).toEqual([]);
+    });
+
+    test("throws when no type matches", async () => {
+      const service = new GraphAnalysisServiceImpl(createCypherMockAdapter(() => []));
+
+      await expect(
+        service.getTypeHierarchy({ type_name: "Missing", repository: "test-repo" })
+      ).rejects.toThrow(EntityNotFoundError);
+    });
+
+    test("stops before the next level once the signal aborts", async () => {
+      const controller = new AbortController();
+      const queries: string[] = [];
+      const service = new GraphAnalysisServiceImpl(
+        createCypherMockAdapter((cypher, params) => {
+          queries.push(cypher);
+          // Cancel while the first level is being assembled
+          if (cypher.includes("HAS_IMPL")) controller.abort();
+          return responder(cypher, params);
+        })
+      );
+
+      await expect(
+        service.getTypeHierarchy(
+          { type_name: "Dog", repository: "test-repo", max_depth: 3 },
+          controller.signal
+        )
+      ).rejects.toThrow(GraphServiceCancelledError);
+      expect(queries.filter((cypher) => cypher.includes("HAS_IMPL"))).toHaveLength(1);
+    });
+  });
+});
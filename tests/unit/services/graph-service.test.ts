@@ -165,6 +165,43 @@ const MOCK_DEPENDENCY_RESULT = {
   metadata: { directCount: 2, transitiveCount: 1, queryTimeMs: 25 },
 };
 
+/** helpers.ts imports the target directly and through validator.ts */
+const MOCK_REPEATED_DEPENDENT_RESULT = {
+  direct: [
+    {
+      type: "file" as const,
+      identifier: "src/utils/helpers.ts",
+      repository: "test-repo",
+      relationshipType: RelationshipType.IMPORTS,
+      depth: 1,
+      id: "file:test-repo:src/utils/helpers.ts",
+      via: [],
+    },
+    {
+      type: "file" as const,
+      identifier: "src/utils/validator.ts",
+      repository: "test-repo",
+      relationshipType: RelationshipType.IMPORTS,
+      depth: 1,
+      id: "file:test-repo:src/utils/validator.ts",
+      via: [],
+    },
+  ],
+  transitive: [
+    {
+      type: "file" as const,
+      identifier: "src/utils/helpers.ts",
+      repository: "test-repo",
+      relationshipType: RelationshipType.IMPORTS,
+      depth: 2,
+      id: "file:test-repo:src/utils/helpers.ts",
+      via: ["src/utils/validator.ts"],
+    },
+  ],
+  impactScore: 0.1,
+  metadata: { directCount: 2, transitiveCount: 0, queryTimeMs: 20 },
+};
+
 const MOCK_TRAVERSE_RESULT = {
   nodes: [
     {
@@ -483,6 +520,61 @@ describe("GraphServiceImpl", () => {
         expect(result.impact_analysis.transitive_impact_count).toBe(1);
         expect(typeof result.impact_analysis.impact_score).toBe("number");
       });
+
+      test("requests every relationship from the adapter", async () => {
+        const analyzeSpy = spyOn(mockAdapter, "analyzeDependencies");
+
+        await service.getDependents(TEST_QUERIES.dependent.valid);
+
+        expect(analyzeSpy.mock.calls[0]?.[0]?.dedup).toBe(false);
+      });
+
+      test("returns an entity reached by several relationships once", async () => {
+        mockAdapter.analyzeDependencies = mock(() =>
+          Promise.resolve(MOCK_REPEATED_DEPENDENT_RESULT)
+        );
+
+        const result = await service.getDependents({ ...TEST_QUERIES.dependent.valid, depth: 2 });
+
+        expect(result.dependents.map((dep) => [dep.path, dep.depth])).toEqual([
+          ["src/utils/helpers.ts", 1],
+          ["src/utils/validator.ts", 1],
+        ]);
+        expect(result.dependents[0]?.paths).toBeUndefined();
+      });
+
+      test("lists the paths to a deduplicated entity when include_paths is set", async () => {
+        mockAdapter.analyzeDependencies = mock(() =>
+          Promise.resolve(MOCK_REPEATED_DEPENDENT_RESULT)
+        );
+
+        const result = await service.getDependents({
+          ...TEST_QUERIES.dependent.valid,
+          depth: 2,
+          include_paths: true,
+        });
+
+        expect(result.dependents[0]?.paths).toEqual([[], ["src/utils/validator.ts"]]);
+        expect(result.dependents[1]?.paths).toEqual([[]]);
+      });
+
+      test("keeps every relationship in depth order when dedup is false", async () => {
+        mockAdapter.analyzeDependencies = mock(() =>
+          Promise.resolve(MOCK_REPEATED_DEPENDENT_RESULT)
+        );
+
+        const result = await service.getDependents({
+          ...TEST_QUERIES.dependent.valid,
+          depth: 2,
+          dedup: false,
+        });
+
+        expect(result.dependents.map((dep) => [dep.path, dep.depth])).toEqual([
+          ["src/utils/helpers.ts", 1],
+          ["src/utils/validator.ts", 1],
+          ["src/utils/helpers.ts", 2],
+        ]);
+      });
     });
 
     describe("caching", () => {
@@ -1064,6 +1156,63 @@ describe("QueryCache", () => {
       expect(cache.has("key1")).toBe(false);
       expect(cache.has("key4")).toBe(true);
     });
+
+    test("evicts least recently queried entries first", () => {
+      const cache = new QueryCache<number>({ maxEntries: 3 });
+
+      cache.set("key1", 1);
+      cache.set("key2", 2);
+      cache.set("key3", 3);
+      cache.get("key1"); // key2 is now least recently queried
+      cache.set("key4", 4);
+
+      expect(cache.has("key1")).toBe(true);
+      expect(cache.has("key2")).toBe(false);
+    });
+
+    test("evicts entries to stay within the memory budget", () => {
+      const entrySize = QueryCache.estimateSize("x".repeat(100));
+      const cache = new QueryCache<string>({ maxMemoryBytes: entrySize * 2 });
+
+      cache.set("key1", "x".repeat(100));
+      cache.set("key2", "y".repeat(100));
+      cache.set("key3", "z".repeat(100)); // Should evict key1
+
+      const stats = cache.stats();
+      expect(cache.has("key1")).toBe(false);
+      expect(cache.has("key3")).toBe(true);
+      expect(stats.memoryBytes).toBe(entrySize * 2);
+      expect(stats.evictions).toBe(1);
+    });
+
+    test("does not cache values larger than the memory budget", () => {
+      const cache = new QueryCache<string>({ maxMemoryBytes: 50 });
+      cache.set("small", "a");
+
+      cache.set("huge", "x".repeat(1000));
+
+      expect(cache.has("huge")).toBe(false);
+      expect(cache.has("small")).toBe(true);
+    });
+
+    test("releases memory accounting when entries are replaced or deleted", () => {
+      const cache = new QueryCache<string>({ maxMemoryBytes: 10_000 });
+
+      cache.set("key", "x".repeat(100));
+      cache.set("key", "x".repeat(10));
+      expect(cache.stats().memoryBytes).toBe(QueryCache.estimateSize("x".repeat(10)));
+
+      cache.delete("key");
+      expect(cache.stats().memoryBytes).toBe(0);
+    });
+
+    test("does not track memory when no budget is configured", () => {
+      const cache = new QueryCache<string>();
+      cache.set("key", "x".repeat(100));
+
+      expect(cache.stats().memoryBytes).toBe(0);
+      expect(cache.stats().maxMemoryBytes).toBe(0);
+    });
   });
 
   describe("clear", () => {
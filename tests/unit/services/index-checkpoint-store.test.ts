@@ -0,0 +1,116 @@
+/**
+ * Unit tests for IndexCheckpointStoreImpl
+ *
+ * Real-disk tests using a per-test tmpdir, mirroring the file-manifest-store
+ * unit tests.
+ */
+
+import { describe, it, expect, beforeEach, afterEach, beforeAll, afterAll } from "bun:test";
+import * as os from "node:os";
+import * as path from "node:path";
+import * as fs from "node:fs";
+import { initializeLogger, resetLogger } from "../../../src/logging/index.js";
+import {
+  IndexCheckpointStoreImpl,
+  INDEX_CHECKPOINT_VERSION,
+  type IndexCheckpoint,
+} from "../../../src/services/index-checkpoint-store.js";
+
+beforeAll(() => {
+  initializeLogger({ level: "silent", format: "json" });
+});
+
+afterAll(() => {
+  resetLogger();
+});
+
+function sampleCheckpoint(repository = "my-repo"): IndexCheckpoint {
+  return {
+    version: INDEX_CHECKPOINT_VERSION,
+    repository,
+    url: "https://github.com/user/my-repo.git",
+    branch: "main",
+    commitSha: "abc123",
+    embeddingModel: "openai:text-embedding-3-small",
+    startedAt: "2026-01-01T00:00:00.000Z",
+    updatedAt: "2026-01-01T00:05:00.000Z",
+    files: {
+      "src/a.ts": { sizeBytes: 120, mtimeMs: 1700000000000 },
+      "docs/readme.md": { sizeBytes: 40, mtimeMs: 1700000000500 },
+    },
+    stats: { filesProcessed: 2, chunksCreated: 5, embeddingsGenerated: 5, documentsStored: 5 },
+  };
+}
+
+describe("IndexCheckpointStoreImpl", () => {
+  let tmpDir: string;
+
+  beforeEach(() => {
+    IndexCheckpointStoreImpl.resetInstance();
+    tmpDir = path.join(
+      os.tmpdir(),
+      `index-checkpoint-store-test-${Date.now()}-${Math.random().toString(36).slice(2, 8)}`
+    );
+    fs.mkdirSync(tmpDir, { recursive: true });
+  });
+
+  afterEach(() => {
+    IndexCheckpointStoreImpl.resetInstance();
+    try {
+      fs.rmSync(tmpDir, { recursive: true, force: true });
+    } catch {
+      // Ignore cleanup errors
+    }
+  });
+
+  it("returns the same instance on multiple getInstance calls", () => {
+    const first = IndexCheckpointStoreImpl.getInstance(tmpDir);
+    expect(IndexCheckpointStoreImpl.getInstance()).toBe(first);
+  });
+
+  it("stores checkpoints under checkpoints/ with case-distinct file names", () => {
+    const store = IndexCheckpointStoreImpl.getInstance(tmpDir);
+
+    const upper = store.getCheckpointPath("My-Repo");
+    const lower = store.getCheckpointPath("my-repo");
+
+    expect(path.dirname(upper)).toBe(path.join(tmpDir, "checkpoints"));
+    expect(upper).not.toBe(lower);
+  });
+
+  it("returns null when no checkpoint exists", async () => {
+    const store = IndexCheckpointStoreImpl.getInstance(tmpDir);
+
+    expect(await store.loadCheckpoint("missing")).toBeNull();
+  });
+
+  it("round-trips a saved checkpoint without leaving a temp file", async () => {
+    const store = IndexCheckpointStoreImpl.getInstance(tmpDir);
+    const checkpoint = sampleCheckpoint();
+
+    await store.saveCheckpoint(checkpoint);
+
+    expect(await store.loadCheckpoint("my-repo")).toEqual(checkpoint);
+    expect(fs.existsSync(`${store.getCheckpointPath("my-repo")}.tmp`)).toBe(false);
+  });
+
+  it("discards an unreadable checkpoint", async () => {
+    const store = IndexCheckpointStoreImpl.getInstance(tmpDir);
+    const filePath = store.getCheckpointPath("my-repo");
+    fs.mkdirSync(path.dirname(filePath), { recursive: true });
+    fs.writeFileSync(filePath, '{"version":"1.0","repository":');
+
+    expect(await store.loadCheckpoint("my-repo")).toBeNull();
+    expect(fs.existsSync(filePath)).toBe(false);
+  });
+
+  it("deletes checkpoints idempotently", async () => {
+    const store = IndexCheckpointStoreImpl.getInstance(tmpDir);
+    await store.saveCheckpoint(sampleCheckpoint());
+
+    await store.deleteCheckpoint("my-repo");
+    await store.deleteCheckpoint("my-repo");
+
+    expect(await store.loadCheckpoint("my-repo")).toBeNull();
+  });
+});
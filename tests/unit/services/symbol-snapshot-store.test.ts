@@ -0,0 +1,158 @@
+/**
+ * Unit tests for SymbolSnapshotStoreImpl
+ *
+ * Real-disk tests using a per-test tmpdir, mirroring the file-manifest-store
+ * unit tests.
+ */
+
+import { describe, it, expect, beforeEach, afterEach, beforeAll, afterAll } from "bun:test";
+import * as os from "node:os";
+import * as path from "node:path";
+import * as fs from "node:fs";
+import { initializeLogger, resetLogger } from "../../../src/logging/index.js";
+import {
+  SymbolSnapshotStoreImpl,
+  SYMBOL_SNAPSHOT_VERSION,
+  type SymbolSnapshot,
+  type SymbolHistory,
+} from "../../../src/services/symbol-snapshot-store.js";
+
+beforeAll(() => {
+  initializeLogger({ level: "silent", format: "json" });
+});
+
+afterAll(() => {
+  resetLogger();
+});
+
+function sampleSnapshot(name = "v1"): SymbolSnapshot {
+  return {
+    version: SYMBOL_SNAPSHOT_VERSION,
+    repository: "my-repo",
+    name,
+    createdAt: "2026-01-01T00:00:00.000Z",
+    commitSha: "abc123",
+    symbols: {
+      "src/a.ts::login": "00000000deadbeef",
+      "src/a.ts::Session::refresh": "00000000cafebabe",
+    },
+  };
+}
+
+function sampleHistory(): SymbolHistory {
+  return {
+    version: SYMBOL_SNAPSHOT_VERSION,
+    repository: "my-repo",
+    entries: [
+      {
+        recordedAt: "2026-01-01T00:00:00.000Z",
+        commitSha: "abc123",
+        digest: "00000000deadbeef",
+        symbolCount: 2,
+        added: 0,
+        removed: 0,
+        modified: 0,
+      },
+    ],
+    latestSymbols: { "src/a.ts::login": "00000000deadbeef" },
+  };
+}
+
+describe("SymbolSnapshotStoreImpl", () => {
+  let tmpDir: string;
+
+  beforeEach(() => {
+    SymbolSnapshotStoreImpl.resetInstance();
+    tmpDir = path.join(
+      os.tmpdir(),
+      `symbol-snapshot-store-test-${Date.now()}-${Math.random().toString(36).slice(2, 8)}`
+    );
+    fs.mkdirSync(tmpDir, { recursive: true });
+  });
+
+  afterEach(() => {
+    SymbolSnapshotStoreImpl.resetInstance();
+    try {
+      fs.rmSync(tmpDir, { recursive: true, force: true });
+    } catch {
+      // Ignore cleanup errors
+    }
+  });
+
+  it("returns the same instance on multiple getInstance calls", () => {
+    const first = SymbolSnapshotStoreImpl.getInstance(tmpDir);
+    expect(SymbolSnapshotStoreImpl.getInstance()).toBe(first);
+  });
+
+  it("stores snapshots per repository with case-distinct file names", () => {
+    const store = SymbolSnapshotStoreImpl.getInstance(tmpDir);
+
+    const upper = store.getSnapshotPath("my-repo", "V1");
+    const lower = store.getSnapshotPath("my-repo", "v1");
+
+    expect(path.dirname(path.dirname(upper))).toBe(path.join(tmpDir, "snapshots"));
+    expect(path.dirname(upper)).toBe(path.dirname(lower));
+    expect(upper).not.toBe(lower);
+  });
+
+  it("returns null when no snapshot exists", async () => {
+    const store = SymbolSnapshotStoreImpl.getInstance(tmpDir);
+
+    expect(await store.loadSnapshot("my-repo", "missing")).toBeNull();
+  });
+
+  it("round-trips a saved snapshot without leaving a temp file", async () => {
+    const store = SymbolSnapshotStoreImpl.getInstance(tmpDir);
+    const snapshot = sampleSnapshot();
+
+    await store.saveSnapshot(snapshot);
+
+    expect(await store.loadSnapshot("my-repo", "v1")).toEqual(snapshot);
+    expect(fs.existsSync(`${store.getSnapshotPath("my-repo", "v1")}.tmp`)).toBe(false);
+  });
+
+  it("treats an unreadable snapshot as absent", async () => {
+    const store = SymbolSnapshotStoreImpl.getInstance(tmpDir);
+    const filePath = store.getSnapshotPath("my-repo", "v1");
+    fs.mkdirSync(path.dirname(filePath), { recursive: true });
+    fs.writeFileSync(filePath, '{"version":"1.0","repository":');
+
+    expect(await store.loadSnapshot("my-repo", "v1")).toBeNull();
+  });
+
+  it("round-trips the snapshot history beside the named snapshots", async () => {
+    const store = SymbolSnapshotStoreImpl.getInstance(tmpDir);
+    const history = sampleHistory();
+
+    expect(await store.loadHistory("my-repo")).toBeNull();
+    await store.saveHistory(history);
+    await store.saveSnapshot(sampleSnapshot("history"));
+
+    expect(await store.loadHistory("my-repo")).toEqual(history);
+    expect(path.dirname(store.getHistoryPath("my-repo"))).toBe(
+      path.dirname(store.getSnapshotPath("my-repo", "history"))
+    );
+    expect(store.getHistoryPath("my-repo")).not.toBe(store.getSnapshotPath("my-repo", "history"));
+  });
+
+  it("treats an unreadable history as absent", async () => {
+    const store = SymbolSnapshotStoreImpl.getInstance(tmpDir);
+    const filePath = store.getHistoryPath("my-repo");
+    fs.mkdirSync(path.dirname(filePath), { recursive: true });
+    fs.writeFileSync(filePath, '{"version":"1.0","entries":[]}');
+
+    expect(await store.loadHistory("my-repo")).toBeNull();
+  });
+
+  it("deletes snapshots idempotently", async () => {
+    const store = SymbolSnapshotStoreImpl.getInstance(tmpDir);
+    await store.saveSnapshot(sampleSnapshot());
+    await store.saveSnapshot(sampleSnapshot("v2"));
+
+    await store.deleteSnapshot("my-repo", "v1");
+    await store.deleteSnapshot("my-repo", "v1");
+
+    expect(await store.loadSnapshot("my-repo", "v1")).toBeNull();
+    expect(await store.loadSnapshot("my-repo", "v2")).not.toBeNull();
+  });
+});